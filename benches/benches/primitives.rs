@@ -0,0 +1,161 @@
+//! Micro-benchmarks for the wire primitives and generated message code.
+//!
+//! Run with `cargo bench --manifest-path benches/Cargo.toml`. Besides the
+//! criterion timings, each benchmark prints the heap allocations a single
+//! call performs, so pre-allocation and zero-copy changes show up as a
+//! count rather than only as noise in the timings.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use protobuf_runtime_bench::example::{TransactionBatch, UserProfile};
+use protobuf_runtime_bench::protobuf_runtime::*;
+use protobuf_runtime_bench::{sample_batch, sample_profile};
+
+// ── Allocation counting ──────────────────────────────────────────────
+
+struct CountingAlloc;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static REALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Run `f` once and print how many allocations and reallocations it made.
+fn report_allocations<R>(name: &str, f: impl FnOnce() -> R) {
+    let allocs = ALLOCS.load(Ordering::Relaxed);
+    let reallocs = REALLOCS.load(Ordering::Relaxed);
+    black_box(f());
+    println!(
+        "{name}: {} allocs, {} reallocs per call",
+        ALLOCS.load(Ordering::Relaxed) - allocs,
+        REALLOCS.load(Ordering::Relaxed) - reallocs,
+    );
+}
+
+// ── Varint ───────────────────────────────────────────────────────────
+
+/// One value per encoded width the compute budget cares about.
+const VARINT_CASES: [(&str, u64); 5] = [
+    ("1_byte", 1),
+    ("2_bytes", 300),
+    ("5_bytes", u32::MAX as u64),
+    ("8_bytes", (1 << 56) - 1),
+    ("10_bytes", u64::MAX),
+];
+
+fn bench_varint(c: &mut Criterion) {
+    let mut group = c.benchmark_group("varint");
+    for (name, value) in VARINT_CASES {
+        let mut buf = Vec::with_capacity(10);
+        report_allocations(&format!("varint/encode/{name}"), || {
+            buf.clear();
+            encode_varint(&mut buf, value);
+        });
+        group.bench_with_input(BenchmarkId::new("encode", name), &value, |b, &value| {
+            b.iter(|| {
+                buf.clear();
+                encode_varint(&mut buf, black_box(value));
+            })
+        });
+
+        // Trailing input as inside a message, so the eight-byte load applies
+        let mut encoded = Vec::new();
+        encode_varint(&mut encoded, value);
+        encoded.resize(16, 0);
+        group.bench_with_input(BenchmarkId::new("decode", name), &encoded, |b, encoded| {
+            b.iter(|| decode_varint(black_box(encoded), 0).unwrap())
+        });
+    }
+    group.finish();
+}
+
+// ── Length-delimited ─────────────────────────────────────────────────
+
+fn bench_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bytes");
+    for len in [32usize, 1024] {
+        let mut encoded = Vec::new();
+        encode_bytes(&mut encoded, &vec![0xA5; len]);
+        group.throughput(Throughput::Bytes(len as u64));
+
+        report_allocations(&format!("bytes/decode_bytes/{len}"), || {
+            decode_bytes(&encoded, 0).unwrap()
+        });
+        group.bench_with_input(BenchmarkId::new("decode_bytes", len), &encoded, |b, encoded| {
+            b.iter(|| decode_bytes(black_box(encoded), 0).unwrap())
+        });
+
+        report_allocations(&format!("bytes/decode_bytes_ref/{len}"), || {
+            decode_bytes_ref(&encoded, 0).unwrap().1
+        });
+        group.bench_with_input(BenchmarkId::new("decode_bytes_ref", len), &encoded, |b, encoded| {
+            b.iter(|| decode_bytes_ref(black_box(encoded), 0).unwrap().1)
+        });
+    }
+    group.finish();
+}
+
+// ── Generated messages ───────────────────────────────────────────────
+
+fn bench_messages(c: &mut Criterion) {
+    let mut group = c.benchmark_group("message");
+
+    // Sixteen transactions are more than fit in one, so the batch is
+    // decoded with the account-sized message cap
+    let batch = sample_batch(16);
+    let batch_bytes = batch.encode();
+    group.throughput(Throughput::Bytes(batch_bytes.len() as u64));
+    report_allocations("message/encode/transaction_batch", || batch.encode());
+    group.bench_function("encode/transaction_batch", |b| b.iter(|| black_box(&batch).encode()));
+    report_allocations("message/decode/transaction_batch", || {
+        TransactionBatch::decode_with(&batch_bytes, &DecodeOptions::ACCOUNT).unwrap()
+    });
+    group.bench_function("decode/transaction_batch", |b| {
+        b.iter(|| TransactionBatch::decode_with(black_box(&batch_bytes), &DecodeOptions::ACCOUNT).unwrap())
+    });
+
+    let profile = sample_profile();
+    let profile_bytes = profile.encode();
+    group.throughput(Throughput::Bytes(profile_bytes.len() as u64));
+    report_allocations("message/encode/user_profile", || profile.encode());
+    group.bench_function("encode/user_profile", |b| b.iter(|| black_box(&profile).encode()));
+    report_allocations("message/decode/user_profile", || {
+        UserProfile::decode(&profile_bytes).unwrap()
+    });
+    group.bench_function("decode/user_profile", |b| {
+        b.iter(|| UserProfile::decode(black_box(&profile_bytes)).unwrap())
+    });
+    let id_only = [UserProfile::ID_FIELD_NUMBER];
+    report_allocations("message/decode_fields/user_profile", || {
+        UserProfile::decode_fields(&profile_bytes, &id_only).unwrap()
+    });
+    group.bench_function("decode_fields/user_profile", |b| {
+        b.iter(|| UserProfile::decode_fields(black_box(&profile_bytes), &id_only).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_varint, bench_bytes, bench_messages);
+criterion_main!(benches);
@@ -0,0 +1,216 @@
+//! Harness program for measuring what the runtime and generated code cost
+//! on-chain.
+//!
+//! An instruction is one `Case` tag byte followed by the case's encoded
+//! input. The program runs the case once between two
+//! `sol_remaining_compute_units` readings and returns the difference as
+//! little-endian `u64` return data. Anything an encode case needs first,
+//! such as decoding its input into a message, happens before the first
+//! reading, and the result is dropped after the second, so only the
+//! operation itself is counted. `tests/cu.rs` drives every case through
+//! `solana-program-test` and prints the report.
+
+use std::hint::black_box;
+
+use protobuf_runtime_bench::example::{TransactionBatch, UserProfile};
+use protobuf_runtime_bench::protobuf_runtime::*;
+use protobuf_runtime_bench::{sample_batch, sample_profile};
+use solana_program::account_info::AccountInfo;
+use solana_program::compute_units::sol_remaining_compute_units;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::set_return_data;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+/// One measured operation. Batches stop at four transactions so the
+/// encoded input still fits in a single transaction's 1232 bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Case {
+    /// Nothing between the readings: the cost of measuring, which the
+    /// report subtracts from every other case
+    Baseline = 0,
+    DecodeProfile = 1,
+    EncodeProfile = 2,
+    EncodeToSliceProfile = 3,
+    DecodeBatch1 = 4,
+    EncodeBatch1 = 5,
+    DecodeBatch4 = 6,
+    EncodeBatch4 = 7,
+    EncodedLenBatch4 = 8,
+    DecodeVarints = 9,
+    /// `DecodeVarints` through `decode_varint_bytewise`, the loop the
+    /// runtime decoded every varint with before its eight-byte fast path
+    DecodeVarintsBytewise = 10,
+    /// `decode_fields` of the profile's `id` alone
+    DecodeFieldsProfile = 11,
+}
+
+impl Case {
+    pub const ALL: [Case; 12] = [
+        Case::Baseline,
+        Case::DecodeProfile,
+        Case::EncodeProfile,
+        Case::EncodeToSliceProfile,
+        Case::DecodeBatch1,
+        Case::EncodeBatch1,
+        Case::DecodeBatch4,
+        Case::EncodeBatch4,
+        Case::EncodedLenBatch4,
+        Case::DecodeVarints,
+        Case::DecodeVarintsBytewise,
+        Case::DecodeFieldsProfile,
+    ];
+
+    pub fn from_u8(tag: u8) -> Option<Case> {
+        Case::ALL.get(tag as usize).copied()
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Case::Baseline => "baseline",
+            Case::DecodeProfile => "decode UserProfile",
+            Case::EncodeProfile => "encode UserProfile",
+            Case::EncodeToSliceProfile => "encode_to_slice UserProfile",
+            Case::DecodeBatch1 => "decode TransactionBatch (1 txn)",
+            Case::EncodeBatch1 => "encode TransactionBatch (1 txn)",
+            Case::DecodeBatch4 => "decode TransactionBatch (4 txns)",
+            Case::EncodeBatch4 => "encode TransactionBatch (4 txns)",
+            Case::EncodedLenBatch4 => "encoded_len TransactionBatch (4 txns)",
+            Case::DecodeVarints => "decode_varint x80 (1-10 bytes)",
+            Case::DecodeVarintsBytewise => "decode_varint_bytewise x80 (1-10 bytes)",
+            Case::DecodeFieldsProfile => "decode_fields UserProfile (id)",
+        }
+    }
+
+    /// The encoded message the case's instruction carries after its tag.
+    pub fn input(self) -> Vec<u8> {
+        match self {
+            Case::Baseline => Vec::new(),
+            Case::DecodeProfile
+            | Case::EncodeProfile
+            | Case::EncodeToSliceProfile
+            | Case::DecodeFieldsProfile => {
+                sample_profile().encode()
+            }
+            Case::DecodeBatch1 | Case::EncodeBatch1 => sample_batch(1).encode(),
+            Case::DecodeBatch4 | Case::EncodeBatch4 | Case::EncodedLenBatch4 => sample_batch(4).encode(),
+            Case::DecodeVarints | Case::DecodeVarintsBytewise => sample_varints(),
+        }
+    }
+
+    /// The instruction data that runs this case.
+    pub fn instruction_data(self) -> Vec<u8> {
+        let mut data = vec![self as u8];
+        data.extend(self.input());
+        data
+    }
+}
+
+pub fn process_instruction(_program_id: &Pubkey, _accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (&tag, input) = data.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+    let case = Case::from_u8(tag).ok_or(ProgramError::InvalidInstructionData)?;
+    let consumed = measure(case, input).map_err(|_| ProgramError::InvalidInstructionData)?;
+    set_return_data(&consumed.to_le_bytes());
+    Ok(())
+}
+
+/// Compute units `f` consumes, not counting the drop of its result.
+fn span<R>(f: impl FnOnce() -> R) -> u64 {
+    let before = sol_remaining_compute_units();
+    let out = black_box(f());
+    let consumed = before - sol_remaining_compute_units();
+    drop(out);
+    consumed
+}
+
+fn measure(case: Case, input: &[u8]) -> Result<u64, DecodeError> {
+    Ok(match case {
+        Case::Baseline => span(|| ()),
+        Case::DecodeProfile => {
+            // Decoded once up front so a bad input fails the instruction
+            UserProfile::decode(input)?;
+            span(|| UserProfile::decode(black_box(input)))
+        }
+        Case::EncodeProfile => {
+            let msg = UserProfile::decode(input)?;
+            span(|| msg.encode())
+        }
+        Case::EncodeToSliceProfile => {
+            let msg = UserProfile::decode(input)?;
+            let mut buf = vec![0u8; msg.encoded_len()];
+            span(|| msg.encode_to_slice(&mut buf))
+        }
+        Case::DecodeBatch1 | Case::DecodeBatch4 => {
+            TransactionBatch::decode(input)?;
+            span(|| TransactionBatch::decode(black_box(input)))
+        }
+        Case::EncodeBatch1 | Case::EncodeBatch4 => {
+            let msg = TransactionBatch::decode(input)?;
+            span(|| msg.encode())
+        }
+        Case::EncodedLenBatch4 => {
+            let msg = TransactionBatch::decode(input)?;
+            span(|| msg.encoded_len())
+        }
+        Case::DecodeVarints => {
+            decode_all(input, decode_varint)?;
+            span(|| decode_all(black_box(input), decode_varint))
+        }
+        Case::DecodeVarintsBytewise => {
+            decode_all(input, decode_varint_bytewise)?;
+            span(|| decode_all(black_box(input), decode_varint_bytewise))
+        }
+        Case::DecodeFieldsProfile => {
+            let id_only = [UserProfile::ID_FIELD_NUMBER];
+            UserProfile::decode_fields(input, &id_only)?;
+            span(|| UserProfile::decode_fields(black_box(input), &id_only))
+        }
+    })
+}
+
+/// Eight rounds of one varint per encoded width, 1 to 10 bytes.
+fn sample_varints() -> Vec<u8> {
+    let mut buf = Vec::new();
+    for _ in 0..8 {
+        for width in 1..=10 {
+            encode_varint(&mut buf, u64::MAX >> (64 - (7 * width).min(64)));
+        }
+    }
+    buf
+}
+
+/// Decode back-to-back varints until `input` runs out, summing them.
+fn decode_all(
+    input: &[u8],
+    decode: fn(&[u8], usize) -> Result<(u64, usize), DecodeError>,
+) -> Result<u64, DecodeError> {
+    let (mut sum, mut pos) = (0u64, 0);
+    while pos < input.len() {
+        let (value, next) = decode(input, pos)?;
+        sum = sum.wrapping_add(value);
+        pos = next;
+    }
+    Ok(sum)
+}
+
+/// The byte-at-a-time reference `DecodeVarintsBytewise` measures.
+fn decode_varint_bytewise(data: &[u8], mut pos: usize) -> Result<(u64, usize), DecodeError> {
+    let mut result: u64 = 0;
+    let mut shift: u32 = 0;
+    loop {
+        let b = *data.get(pos).ok_or(DecodeError::BufferOverflow)?;
+        pos += 1;
+        result |= ((b & 0x7F) as u64) << shift;
+        if b & 0x80 == 0 {
+            return Ok((result, pos));
+        }
+        shift += 7;
+        if shift > 63 {
+            return Err(DecodeError::InvalidVarint);
+        }
+    }
+}
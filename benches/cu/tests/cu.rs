@@ -0,0 +1,51 @@
+//! Runs every `Case` through the SBF build of the harness program and
+//! prints the compute units each one consumed, net of the baseline cost
+//! of measuring. `cargo test-sbf` builds the program and points
+//! `solana-program-test` at it:
+//!
+//! ```bash
+//! cargo test-sbf --manifest-path benches/cu/Cargo.toml -- --nocapture
+//! ```
+//!
+//! Set `CU_REPORT=<path>` to also write the report as a Markdown table,
+//! e.g. to diff against a previous run.
+
+use protobuf_runtime_cu_bench::Case;
+use solana_program_test::ProgramTest;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+
+#[tokio::test]
+async fn compute_units() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = ProgramTest::new("protobuf_runtime_cu_bench", program_id, None);
+    // Native execution does not meter compute units, so require the .so
+    program_test.prefer_bpf(true);
+    let (banks, payer, recent_blockhash) = program_test.start().await;
+
+    let mut measured = Vec::new();
+    for case in Case::ALL {
+        let data = case.instruction_data();
+        let ix = Instruction::new_with_bytes(program_id, &data, vec![]);
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[&payer], recent_blockhash);
+        let outcome = banks.process_transaction_with_metadata(tx).await.unwrap();
+        if let Err(err) = outcome.result {
+            panic!("{}: {}", case.name(), err);
+        }
+        let return_data = outcome.metadata.and_then(|m| m.return_data).expect("no return data");
+        let units = u64::from_le_bytes(return_data.data.as_slice().try_into().expect("not a u64"));
+        measured.push((case, data.len() - 1, units));
+    }
+
+    let baseline = measured[0].2;
+    let mut report = String::from("| Case | Input bytes | CU |\n|---|---:|---:|\n");
+    for &(case, input_len, units) in &measured[1..] {
+        report.push_str(&format!("| {} | {} | {} |\n", case.name(), input_len, units - baseline));
+    }
+    println!("{report}");
+    if let Ok(path) = std::env::var("CU_REPORT") {
+        std::fs::write(&path, &report).unwrap_or_else(|e| panic!("writing {path}: {e}"));
+    }
+}
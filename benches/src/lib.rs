@@ -0,0 +1,62 @@
+//! Bench fixtures: the shipped runtime plus code generated from
+//! `tests/protos/example.proto`.
+//!
+//! `example.rs` is checked in so the benches build without protoc. After a
+//! generator change, refresh it from `pnpm generate:test`:
+//!
+//! ```bash
+//! cp dist/tests/generated/example/example.rs benches/src/example.rs
+//! ```
+
+#[path = "../../rs/protobuf_runtime.rs"]
+pub mod protobuf_runtime;
+
+pub mod example;
+
+use std::collections::BTreeMap;
+
+use example::{Address, Transaction, TransactionBatch, UserProfile};
+
+/// A batch of `count` transactions with Solana-sized payloads: 32-byte
+/// addresses and 128 bytes of instruction data each.
+pub fn sample_batch(count: usize) -> TransactionBatch {
+    TransactionBatch {
+        chain_id: 101,
+        txns: (0..count as u64)
+            .map(|i| Transaction {
+                to: vec![i as u8; 32],
+                data: vec![0xA5; 128],
+                value: 1_000_000_000 * (i + 1),
+                gas_limit: 200_000,
+                nonce: i,
+            })
+            .collect(),
+        checksum: 0xDEAD_BEEF,
+    }
+}
+
+/// A profile exercising every scalar kind plus a nested message.
+pub fn sample_profile() -> UserProfile {
+    UserProfile {
+        id: 42,
+        name: "validator-operator".to_string(),
+        email: "ops@example.com".to_string(),
+        active: true,
+        role: 3,
+        address: Address {
+            street: "1 Ledger Way".to_string(),
+            city: "Lisbon".to_string(),
+            state: "LX".to_string(),
+            zip: "1000-001".to_string(),
+            floor: -2,
+        },
+        tags: vec!["staking".to_string(), "rpc".to_string(), "archive".to_string()],
+        metadata: BTreeMap::from([
+            ("region".to_string(), "eu-west".to_string()),
+            ("tier".to_string(), "1".to_string()),
+        ]),
+        avatar_hash: vec![0x5A; 32],
+        created_at: 1_700_000_000,
+        nonce: u64::MAX,
+    }
+}
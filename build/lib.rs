@@ -0,0 +1,249 @@
+//! Generate Rust from `.proto` files in a `build.rs`, with
+//! `protoc-gen-solana compile`: protoc does not need to be installed.
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() -> std::io::Result<()> {
+//!     protoc_gen_solana_build::compile_protos(&["proto/vault.proto"], &["proto"])
+//! }
+//! ```
+//!
+//! The output is one file, `bundle.rs` in `OUT_DIR`, holding the runtime
+//! and a module per generated file (`vault/vault.rs` for `package vault;`
+//! in `vault.proto` is `vault::vault`):
+//!
+//! ```ignore
+//! mod proto {
+//!     include!(concat!(env!("OUT_DIR"), "/bundle.rs"));
+//! }
+//!
+//! use proto::vault::vault::Deposit;
+//! ```
+//!
+//! [`Config`] sets plugin options and where the output goes:
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() -> std::io::Result<()> {
+//!     protoc_gen_solana_build::Config::new()
+//!         .include("proto")
+//!         .anchor(true)
+//!         .option("derive=Eq+Hash")
+//!         .compile(&["proto/vault.proto"])
+//! }
+//! ```
+//!
+//! Cargo reruns the build script when any `.proto` file read changes.
+//! The plugin is `protoc-gen-solana` from `PATH`, or the executable named
+//! by the `PROTOC_GEN_SOLANA` environment variable.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Generate `OUT_DIR/bundle.rs` from `protos`, looking for them and their
+/// imports in `includes` (protoc's `-I` directories).
+pub fn compile_protos(protos: &[impl AsRef<Path>], includes: &[impl AsRef<Path>]) -> io::Result<()> {
+    let mut config = Config::new();
+    for include in includes {
+        config.include(include);
+    }
+    config.compile(protos)
+}
+
+/// How [`Config::compile`] runs the plugin: include directories, plugin
+/// options, and the output file.
+#[derive(Clone, Debug)]
+pub struct Config {
+    includes: Vec<PathBuf>,
+    options: Vec<String>,
+    out_dir: Option<PathBuf>,
+    bundle: String,
+    plugin: Option<PathBuf>,
+    rerun_if_changed: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            includes: Vec::new(),
+            options: Vec::new(),
+            out_dir: None,
+            bundle: "bundle.rs".to_string(),
+            plugin: None,
+            rerun_if_changed: true,
+        }
+    }
+}
+
+impl Config {
+    /// No include directories (the plugin then searches `.`), no options,
+    /// output to `OUT_DIR/bundle.rs`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Search `dir` for the inputs and their imports, after the
+    /// directories added before it.
+    pub fn include(&mut self, dir: impl AsRef<Path>) -> &mut Self {
+        self.includes.push(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// A plugin option as `--solana_opt` takes it: `key=value`, or a bare
+    /// `key` for `key=true`.
+    pub fn option(&mut self, option: impl Into<String>) -> &mut Self {
+        self.options.push(option.into());
+        self
+    }
+
+    /// The `anchor` option: Anchor account traits and discriminators.
+    pub fn anchor(&mut self, enabled: bool) -> &mut Self {
+        self.flag("anchor", enabled)
+    }
+
+    /// The `serde` option: `Serialize` / `Deserialize` impls behind the
+    /// generated crate's `serde` feature.
+    pub fn serde(&mut self, enabled: bool) -> &mut Self {
+        self.flag("serde", enabled)
+    }
+
+    /// The `ref_views` option: zero-copy `<Message>Ref<'a>` views.
+    pub fn ref_views(&mut self, enabled: bool) -> &mut Self {
+        self.flag("ref_views", enabled)
+    }
+
+    /// The `unknown_fields` option: keep unknown fields and re-encode them.
+    pub fn unknown_fields(&mut self, enabled: bool) -> &mut Self {
+        self.flag("unknown_fields", enabled)
+    }
+
+    /// The `no_std` option: code for `#![no_std]` crates with `alloc`.
+    pub fn no_std(&mut self, enabled: bool) -> &mut Self {
+        self.flag("no_std", enabled)
+    }
+
+    /// Write to `dir` instead of `OUT_DIR`.
+    pub fn out_dir(&mut self, dir: impl AsRef<Path>) -> &mut Self {
+        self.out_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Name the output file `file` (a `.rs` file name) instead of `bundle.rs`.
+    pub fn bundle(&mut self, file: impl Into<String>) -> &mut Self {
+        self.bundle = file.into();
+        self
+    }
+
+    /// Run the plugin at `path` whatever `PROTOC_GEN_SOLANA` and `PATH` say.
+    pub fn plugin(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        self.plugin = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Whether to print `cargo:rerun-if-changed` for every `.proto` file
+    /// read (on by default). Cargo otherwise reruns the build script when
+    /// any file in the package changes.
+    pub fn emit_rerun_if_changed(&mut self, enabled: bool) -> &mut Self {
+        self.rerun_if_changed = enabled;
+        self
+    }
+
+    fn flag(&mut self, name: &str, enabled: bool) -> &mut Self {
+        self.option(format!("{name}={enabled}"))
+    }
+
+    /// Generate the output file from `protos`, paths on disk under an
+    /// include directory or names relative to one. As with protoc, only
+    /// these files are generated: list the ones they import too, except
+    /// `google/protobuf/` and `solana/options.proto`.
+    pub fn compile(&self, protos: &[impl AsRef<Path>]) -> io::Result<()> {
+        let out_dir = match &self.out_dir {
+            Some(dir) => dir.clone(),
+            None => env::var_os("OUT_DIR").map(PathBuf::from).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "OUT_DIR is not set; call compile from build.rs or set out_dir")
+            })?,
+        };
+        let plugin = match &self.plugin {
+            Some(path) => path.clone().into_os_string(),
+            None => env::var_os("PROTOC_GEN_SOLANA").unwrap_or_else(|| OsString::from("protoc-gen-solana")),
+        };
+        let dependencies = out_dir.join(format!("{}.d", self.bundle));
+
+        let mut command = Command::new(&plugin);
+        command.arg("compile");
+        for include in &self.includes {
+            command.arg("-I").arg(include);
+        }
+        command.arg(format!("--solana_opt=bundle={}", self.bundle));
+        for option in &self.options {
+            command.arg(format!("--solana_opt={option}"));
+        }
+        command.arg("--solana_out").arg(&out_dir);
+        if self.rerun_if_changed {
+            command.arg("--dependency_out").arg(&dependencies);
+        }
+        for proto in protos {
+            command.arg(proto.as_ref());
+        }
+
+        let status = command.status().map_err(|err| {
+            if err.kind() == io::ErrorKind::NotFound {
+                io::Error::new(
+                    err.kind(),
+                    format!(
+                        "{} not found: install @wireio/protoc-gen-solana, or set PROTOC_GEN_SOLANA to the plugin",
+                        plugin.to_string_lossy()
+                    ),
+                )
+            } else {
+                err
+            }
+        })?;
+        if !status.success() {
+            return Err(io::Error::other(format!("{} compile failed ({status})", plugin.to_string_lossy())));
+        }
+        if self.rerun_if_changed {
+            if self.plugin.is_none() {
+                println!("cargo:rerun-if-env-changed=PROTOC_GEN_SOLANA");
+            }
+            for path in prerequisites(&fs::read_to_string(&dependencies)?) {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The prerequisites of the make rule `compile --dependency_out` writes:
+/// the words after the targets' `:`, with `\` escapes and line
+/// continuations undone.
+fn prerequisites(rule: &str) -> Vec<PathBuf> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut chars = rule.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('\n') | None => {}
+                Some(escaped) => word.push(escaped),
+            },
+            c if c.is_whitespace() => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            c => word.push(c),
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    let Some(colon) = words.iter().position(|word| word.ends_with(':')) else {
+        return Vec::new();
+    };
+    words.split_off(colon + 1).into_iter().map(PathBuf::from).collect()
+}
@@ -0,0 +1,2231 @@
+// Auto-generated by protoc-gen-solana from conformance.proto
+// DO NOT EDIT
+
+#![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+use crate::protobuf_runtime::*;
+use std::collections::BTreeMap;
+
+/// `conformance.WireFormat`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(i32)]
+pub enum WireFormat {
+    Unspecified = 0,
+    Protobuf = 1,
+    Json = 2,
+    Jspb = 3,
+    TextFormat = 4,
+}
+
+impl WireFormat {
+    /// The proto3 default, numbered 0, for `const` and `static` items.
+    pub const DEFAULT: WireFormat = WireFormat::Unspecified;
+
+    /// The value's name as declared in the .proto file.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            WireFormat::Unspecified => "UNSPECIFIED",
+            WireFormat::Protobuf => "PROTOBUF",
+            WireFormat::Json => "JSON",
+            WireFormat::Jspb => "JSPB",
+            WireFormat::TextFormat => "TEXT_FORMAT",
+        }
+    }
+
+    pub fn from_str_name(name: &str) -> Option<Self> {
+        match name {
+            "UNSPECIFIED" => Some(WireFormat::Unspecified),
+            "PROTOBUF" => Some(WireFormat::Protobuf),
+            "JSON" => Some(WireFormat::Json),
+            "JSPB" => Some(WireFormat::Jspb),
+            "TEXT_FORMAT" => Some(WireFormat::TextFormat),
+            _ => None,
+        }
+    }
+}
+
+impl Default for WireFormat {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<WireFormat> for i32 {
+    fn from(value: WireFormat) -> i32 {
+        value as i32
+    }
+}
+
+/// A number this build does not know is handed back as the error.
+impl TryFrom<i32> for WireFormat {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(WireFormat::Unspecified),
+            1 => Ok(WireFormat::Protobuf),
+            2 => Ok(WireFormat::Json),
+            3 => Ok(WireFormat::Jspb),
+            4 => Ok(WireFormat::TextFormat),
+            _ => Err(value),
+        }
+    }
+}
+
+/// `conformance.TestCategory`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(i32)]
+pub enum TestCategory {
+    UnspecifiedTest = 0,
+    BinaryTest = 1,
+    JsonTest = 2,
+    JsonIgnoreUnknownParsingTest = 3,
+    JspbTest = 4,
+    TextFormatTest = 5,
+}
+
+impl TestCategory {
+    /// The proto3 default, numbered 0, for `const` and `static` items.
+    pub const DEFAULT: TestCategory = TestCategory::UnspecifiedTest;
+
+    /// The value's name as declared in the .proto file.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TestCategory::UnspecifiedTest => "UNSPECIFIED_TEST",
+            TestCategory::BinaryTest => "BINARY_TEST",
+            TestCategory::JsonTest => "JSON_TEST",
+            TestCategory::JsonIgnoreUnknownParsingTest => "JSON_IGNORE_UNKNOWN_PARSING_TEST",
+            TestCategory::JspbTest => "JSPB_TEST",
+            TestCategory::TextFormatTest => "TEXT_FORMAT_TEST",
+        }
+    }
+
+    pub fn from_str_name(name: &str) -> Option<Self> {
+        match name {
+            "UNSPECIFIED_TEST" => Some(TestCategory::UnspecifiedTest),
+            "BINARY_TEST" => Some(TestCategory::BinaryTest),
+            "JSON_TEST" => Some(TestCategory::JsonTest),
+            "JSON_IGNORE_UNKNOWN_PARSING_TEST" => Some(TestCategory::JsonIgnoreUnknownParsingTest),
+            "JSPB_TEST" => Some(TestCategory::JspbTest),
+            "TEXT_FORMAT_TEST" => Some(TestCategory::TextFormatTest),
+            _ => None,
+        }
+    }
+}
+
+impl Default for TestCategory {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<TestCategory> for i32 {
+    fn from(value: TestCategory) -> i32 {
+        value as i32
+    }
+}
+
+/// A number this build does not know is handed back as the error.
+impl TryFrom<i32> for TestCategory {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(TestCategory::UnspecifiedTest),
+            1 => Ok(TestCategory::BinaryTest),
+            2 => Ok(TestCategory::JsonTest),
+            3 => Ok(TestCategory::JsonIgnoreUnknownParsingTest),
+            4 => Ok(TestCategory::JspbTest),
+            5 => Ok(TestCategory::TextFormatTest),
+            _ => Err(value),
+        }
+    }
+}
+
+/// The runner's first request asks for this message; the testee answers
+/// with an empty one and keeps its known failures in failure_list.txt.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct FailureSet {
+    pub failure: Vec<String>,
+    pub unknown_fields: UnknownFields,
+}
+
+impl FailureSet {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = FailureSet {
+        failure: Vec::new(),
+        unknown_fields: UnknownFields::new(),
+    };
+}
+
+impl Default for FailureSet {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl FailureSet {
+    pub const FAILURE_FIELD_NUMBER: u32 = 1;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 2, packed: false, name: "FailureSet.failure" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        self.unknown_fields.write_reverse(w);
+
+        // field 1: failure
+        for elem in self.failure.iter().rev() {
+            w.write_string(elem);
+            w.write_key(0x0a);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        for elem in &self.failure {
+            len += key_len(1) + bytes_len(elem.len());
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        for elem in &self.failure {
+            w.write_key(0x0a)?;
+            w.write_string(elem)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                10 => {
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    if self.failure.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.failure.push(v);
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                    self.unknown_fields.push_raw(&data[field_start..pos]);
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The element of repeated field `number` being decoded, for `field_error`.
+    fn next_index(&self, number: u64) -> Option<usize> {
+        match number {
+            1 => Some(self.failure.len()),
+            _ => None,
+        }
+    }
+}
+
+impl Message for FailureSet {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        FailureSet::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        FailureSet::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        FailureSet::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        FailureSet::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        FailureSet::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        FailureSet::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        FailureSet::merge_with(self, data, opts)
+    }
+
+    fn encode_canonical(&self) -> Vec<u8> {
+        let mut msg = self.clone();
+        msg.clear_unknown_fields();
+        msg.encode()
+    }
+
+    fn clear_unknown_fields(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl TryFrom<&[u8]> for FailureSet {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        FailureSet::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<FailureSet> for Vec<u8> {
+    fn from(msg: FailureSet) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for FailureSet {
+    const FULL_NAME: &'static str = "conformance.FailureSet";
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FailureSetBuilder {
+    inner: FailureSet,
+}
+
+impl FailureSet {
+    pub fn builder() -> FailureSetBuilder {
+        FailureSetBuilder::default()
+    }
+}
+
+impl FailureSetBuilder {
+    pub fn with_failure(mut self, value: Vec<String>) -> Self {
+        self.inner.failure = value;
+        self
+    }
+
+    pub fn add_failure(mut self, value: String) -> Self {
+        self.inner.failure.push(value);
+        self
+    }
+
+    pub fn build(self) -> FailureSet {
+        self.inner
+    }
+}
+
+impl FailureSet {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        for elem in &self.failure {
+            out.write_str("failure", elem);
+        }
+    }
+}
+
+impl core::fmt::Display for FailureSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl FailureSet {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if !self.failure.is_empty() {
+            out.key("failure");
+            out.begin_array();
+            for elem in &self.failure {
+                out.write_str(elem);
+            }
+            out.end_array();
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "failure" => {
+                    for elem in json_array(value)? {
+                        msg.failure.push(json_to_string(elem)?);
+                    }
+                }
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub enum ConformanceRequestPayload {
+    Unset,
+    ProtobufPayload(Vec<u8>),
+    JsonPayload(String),
+    JspbPayload(String),
+    TextPayload(String),
+}
+
+impl ConformanceRequestPayload {
+    pub const DEFAULT: Self = ConformanceRequestPayload::Unset;
+}
+
+impl Default for ConformanceRequestPayload {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct ConformanceRequest {
+    pub payload: ConformanceRequestPayload,
+    pub requested_output_format: i32,
+    pub message_type: String,
+    pub test_category: i32,
+    pub jspb_encoding_options: JspbEncodingConfig,
+    pub print_unknown_fields: bool,
+    pub unknown_fields: UnknownFields,
+}
+
+impl ConformanceRequest {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = ConformanceRequest {
+        payload: ConformanceRequestPayload::Unset,
+        requested_output_format: 0,
+        message_type: String::new(),
+        test_category: 0,
+        jspb_encoding_options: JspbEncodingConfig::DEFAULT,
+        print_unknown_fields: false,
+        unknown_fields: UnknownFields::new(),
+    };
+}
+
+impl Default for ConformanceRequest {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ConformanceRequest {
+    pub const PROTOBUF_PAYLOAD_FIELD_NUMBER: u32 = 1;
+    pub const JSON_PAYLOAD_FIELD_NUMBER: u32 = 2;
+    pub const JSPB_PAYLOAD_FIELD_NUMBER: u32 = 7;
+    pub const TEXT_PAYLOAD_FIELD_NUMBER: u32 = 8;
+    pub const REQUESTED_OUTPUT_FORMAT_FIELD_NUMBER: u32 = 3;
+    pub const MESSAGE_TYPE_FIELD_NUMBER: u32 = 4;
+    pub const TEST_CATEGORY_FIELD_NUMBER: u32 = 5;
+    pub const JSPB_ENCODING_OPTIONS_FIELD_NUMBER: u32 = 6;
+    pub const PRINT_UNKNOWN_FIELDS_FIELD_NUMBER: u32 = 9;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 2, packed: false, name: "ConformanceRequest.protobuf_payload" },
+        FieldWireType { number: 2, wire_type: 2, packed: false, name: "ConformanceRequest.json_payload" },
+        FieldWireType { number: 7, wire_type: 2, packed: false, name: "ConformanceRequest.jspb_payload" },
+        FieldWireType { number: 8, wire_type: 2, packed: false, name: "ConformanceRequest.text_payload" },
+        FieldWireType { number: 3, wire_type: 0, packed: false, name: "ConformanceRequest.requested_output_format" },
+        FieldWireType { number: 4, wire_type: 2, packed: false, name: "ConformanceRequest.message_type" },
+        FieldWireType { number: 5, wire_type: 0, packed: false, name: "ConformanceRequest.test_category" },
+        FieldWireType { number: 6, wire_type: 2, packed: false, name: "ConformanceRequest.jspb_encoding_options" },
+        FieldWireType { number: 9, wire_type: 0, packed: false, name: "ConformanceRequest.print_unknown_fields" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        self.unknown_fields.write_reverse(w);
+
+        // field 9: print_unknown_fields
+        if self.print_unknown_fields {
+            w.write_bool(self.print_unknown_fields);
+            w.write_key(0x48);
+        }
+
+        // field 8: text_payload
+        if let ConformanceRequestPayload::TextPayload(elem) = &self.payload {
+            w.write_string(elem);
+            w.write_key(0x42);
+        }
+
+        // field 7: jspb_payload
+        if let ConformanceRequestPayload::JspbPayload(elem) = &self.payload {
+            w.write_string(elem);
+            w.write_key(0x3a);
+        }
+
+        // field 6: jspb_encoding_options
+        w.write_message(6, &self.jspb_encoding_options);
+
+        // field 5: test_category
+        if self.test_category != 0 {
+            w.write_int32(self.test_category);
+            w.write_key(0x28);
+        }
+
+        // field 4: message_type
+        if !self.message_type.is_empty() {
+            w.write_string(&self.message_type);
+            w.write_key(0x22);
+        }
+
+        // field 3: requested_output_format
+        if self.requested_output_format != 0 {
+            w.write_int32(self.requested_output_format);
+            w.write_key(0x18);
+        }
+
+        // field 2: json_payload
+        if let ConformanceRequestPayload::JsonPayload(elem) = &self.payload {
+            w.write_string(elem);
+            w.write_key(0x12);
+        }
+
+        // field 1: protobuf_payload
+        if let ConformanceRequestPayload::ProtobufPayload(elem) = &self.payload {
+            w.write_bytes(elem);
+            w.write_key(0x0a);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if let ConformanceRequestPayload::ProtobufPayload(elem) = &self.payload {
+            len += key_len(1) + bytes_len(elem.len());
+        }
+        if let ConformanceRequestPayload::JsonPayload(elem) = &self.payload {
+            len += key_len(2) + bytes_len(elem.len());
+        }
+        if self.requested_output_format != 0 {
+            len += key_len(3) + int32_len(self.requested_output_format);
+        }
+        if !self.message_type.is_empty() {
+            len += key_len(4) + bytes_len(self.message_type.len());
+        }
+        if self.test_category != 0 {
+            len += key_len(5) + int32_len(self.test_category);
+        }
+        len += key_len(6) + bytes_len(self.jspb_encoding_options.encoded_len());
+        if let ConformanceRequestPayload::JspbPayload(elem) = &self.payload {
+            len += key_len(7) + bytes_len(elem.len());
+        }
+        if let ConformanceRequestPayload::TextPayload(elem) = &self.payload {
+            len += key_len(8) + bytes_len(elem.len());
+        }
+        if self.print_unknown_fields {
+            len += key_len(9) + 1;
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if let ConformanceRequestPayload::ProtobufPayload(elem) = &self.payload {
+            w.write_key(0x0a)?;
+            w.write_bytes(elem)?;
+        }
+        if let ConformanceRequestPayload::JsonPayload(elem) = &self.payload {
+            w.write_key(0x12)?;
+            w.write_string(elem)?;
+        }
+        if self.requested_output_format != 0 {
+            w.write_key(0x18)?;
+            w.write_int32(self.requested_output_format)?;
+        }
+        if !self.message_type.is_empty() {
+            w.write_key(0x22)?;
+            w.write_string(&self.message_type)?;
+        }
+        if self.test_category != 0 {
+            w.write_key(0x28)?;
+            w.write_int32(self.test_category)?;
+        }
+        w.write_message(6, &self.jspb_encoding_options)?;
+        if let ConformanceRequestPayload::JspbPayload(elem) = &self.payload {
+            w.write_key(0x3a)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceRequestPayload::TextPayload(elem) = &self.payload {
+            w.write_key(0x42)?;
+            w.write_string(elem)?;
+        }
+        if self.print_unknown_fields {
+            w.write_key(0x48)?;
+            w.write_bool(self.print_unknown_fields)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        let mut len = 0;
+        if let ConformanceRequestPayload::ProtobufPayload(elem) = &self.payload {
+            len += key_len(1) + bytes_len(elem.len());
+        }
+        if let ConformanceRequestPayload::JsonPayload(elem) = &self.payload {
+            len += key_len(2) + bytes_len(elem.len());
+        }
+        if self.requested_output_format != 0 {
+            len += key_len(3) + int32_len(self.requested_output_format);
+        }
+        if !self.message_type.is_empty() {
+            len += key_len(4) + bytes_len(self.message_type.len());
+        }
+        if self.test_category != 0 {
+            len += key_len(5) + int32_len(self.test_category);
+        }
+        len += key_len(6) + bytes_len(sizes.record(&self.jspb_encoding_options));
+        if let ConformanceRequestPayload::JspbPayload(elem) = &self.payload {
+            len += key_len(7) + bytes_len(elem.len());
+        }
+        if let ConformanceRequestPayload::TextPayload(elem) = &self.payload {
+            len += key_len(8) + bytes_len(elem.len());
+        }
+        if self.print_unknown_fields {
+            len += key_len(9) + 1;
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        if let ConformanceRequestPayload::ProtobufPayload(elem) = &self.payload {
+            w.write_key(0x0a)?;
+            w.write_bytes(elem)?;
+        }
+        if let ConformanceRequestPayload::JsonPayload(elem) = &self.payload {
+            w.write_key(0x12)?;
+            w.write_string(elem)?;
+        }
+        if self.requested_output_format != 0 {
+            w.write_key(0x18)?;
+            w.write_int32(self.requested_output_format)?;
+        }
+        if !self.message_type.is_empty() {
+            w.write_key(0x22)?;
+            w.write_string(&self.message_type)?;
+        }
+        if self.test_category != 0 {
+            w.write_key(0x28)?;
+            w.write_int32(self.test_category)?;
+        }
+        w.write_message_sized(6, &self.jspb_encoding_options, sizes)?;
+        if let ConformanceRequestPayload::JspbPayload(elem) = &self.payload {
+            w.write_key(0x3a)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceRequestPayload::TextPayload(elem) = &self.payload {
+            w.write_key(0x42)?;
+            w.write_string(elem)?;
+        }
+        if self.print_unknown_fields {
+            w.write_key(0x48)?;
+            w.write_bool(self.print_unknown_fields)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                10 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.payload = ConformanceRequestPayload::ProtobufPayload(v);
+                    pos = new_pos;
+                }
+                18 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.payload = ConformanceRequestPayload::JsonPayload(v);
+                    pos = new_pos;
+                }
+                58 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.payload = ConformanceRequestPayload::JspbPayload(v);
+                    pos = new_pos;
+                }
+                66 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.payload = ConformanceRequestPayload::TextPayload(v);
+                    pos = new_pos;
+                }
+                24 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.requested_output_format = v;
+                    pos = new_pos;
+                }
+                34 => {
+                    mark_singular(&mut seen, 2, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.message_type = v;
+                    pos = new_pos;
+                }
+                40 => {
+                    mark_singular(&mut seen, 3, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.test_category = v;
+                    pos = new_pos;
+                }
+                50 => {
+                    mark_singular(&mut seen, 4, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.jspb_encoding_options.merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                72 => {
+                    mark_singular(&mut seen, 5, opts)?;
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    self.print_unknown_fields = v;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                    self.unknown_fields.push_raw(&data[field_start..pos]);
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for ConformanceRequest {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        ConformanceRequest::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ConformanceRequest::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        ConformanceRequest::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        ConformanceRequest::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        ConformanceRequest::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        ConformanceRequest::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        ConformanceRequest::merge_with(self, data, opts)
+    }
+
+    fn encode_canonical(&self) -> Vec<u8> {
+        let mut msg = self.clone();
+        msg.clear_unknown_fields();
+        msg.encode()
+    }
+
+    fn clear_unknown_fields(&mut self) {
+        self.unknown_fields.clear();
+        self.jspb_encoding_options.clear_unknown_fields();
+    }
+}
+
+impl TryFrom<&[u8]> for ConformanceRequest {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        ConformanceRequest::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<ConformanceRequest> for Vec<u8> {
+    fn from(msg: ConformanceRequest) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for ConformanceRequest {
+    const FULL_NAME: &'static str = "conformance.ConformanceRequest";
+}
+
+impl ConformanceRequest {
+    pub fn protobuf_payload(&self) -> Option<&Vec<u8>> {
+        match &self.payload {
+            ConformanceRequestPayload::ProtobufPayload(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_protobuf_payload(&mut self, value: Vec<u8>) {
+        self.payload = ConformanceRequestPayload::ProtobufPayload(value);
+    }
+
+    pub fn json_payload(&self) -> Option<&String> {
+        match &self.payload {
+            ConformanceRequestPayload::JsonPayload(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_json_payload(&mut self, value: String) {
+        self.payload = ConformanceRequestPayload::JsonPayload(value);
+    }
+
+    pub fn jspb_payload(&self) -> Option<&String> {
+        match &self.payload {
+            ConformanceRequestPayload::JspbPayload(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_jspb_payload(&mut self, value: String) {
+        self.payload = ConformanceRequestPayload::JspbPayload(value);
+    }
+
+    pub fn text_payload(&self) -> Option<&String> {
+        match &self.payload {
+            ConformanceRequestPayload::TextPayload(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_text_payload(&mut self, value: String) {
+        self.payload = ConformanceRequestPayload::TextPayload(value);
+    }
+}
+
+impl ConformanceRequest {
+    pub fn requested_output_format(&self) -> Result<WireFormat, i32> {
+        WireFormat::try_from(self.requested_output_format)
+    }
+
+    pub fn set_requested_output_format(&mut self, value: WireFormat) {
+        self.requested_output_format = value.into();
+    }
+
+    pub fn test_category(&self) -> Result<TestCategory, i32> {
+        TestCategory::try_from(self.test_category)
+    }
+
+    pub fn set_test_category(&mut self, value: TestCategory) {
+        self.test_category = value.into();
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConformanceRequestBuilder {
+    inner: ConformanceRequest,
+}
+
+impl ConformanceRequest {
+    pub fn builder() -> ConformanceRequestBuilder {
+        ConformanceRequestBuilder::default()
+    }
+}
+
+impl ConformanceRequestBuilder {
+    pub fn with_protobuf_payload(mut self, value: Vec<u8>) -> Self {
+        self.inner.payload = ConformanceRequestPayload::ProtobufPayload(value);
+        self
+    }
+
+    pub fn with_json_payload(mut self, value: String) -> Self {
+        self.inner.payload = ConformanceRequestPayload::JsonPayload(value);
+        self
+    }
+
+    pub fn with_jspb_payload(mut self, value: String) -> Self {
+        self.inner.payload = ConformanceRequestPayload::JspbPayload(value);
+        self
+    }
+
+    pub fn with_text_payload(mut self, value: String) -> Self {
+        self.inner.payload = ConformanceRequestPayload::TextPayload(value);
+        self
+    }
+
+    pub fn with_requested_output_format(mut self, value: i32) -> Self {
+        self.inner.requested_output_format = value;
+        self
+    }
+
+    pub fn with_message_type(mut self, value: String) -> Self {
+        self.inner.message_type = value;
+        self
+    }
+
+    pub fn with_test_category(mut self, value: i32) -> Self {
+        self.inner.test_category = value;
+        self
+    }
+
+    pub fn with_jspb_encoding_options(mut self, value: JspbEncodingConfig) -> Self {
+        self.inner.jspb_encoding_options = value;
+        self
+    }
+
+    pub fn with_print_unknown_fields(mut self, value: bool) -> Self {
+        self.inner.print_unknown_fields = value;
+        self
+    }
+
+    pub fn build(self) -> ConformanceRequest {
+        self.inner
+    }
+}
+
+impl ConformanceRequest {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if let ConformanceRequestPayload::ProtobufPayload(elem) = &self.payload {
+            out.write_bytes("protobuf_payload", elem);
+        }
+        if let ConformanceRequestPayload::JsonPayload(elem) = &self.payload {
+            out.write_str("json_payload", elem);
+        }
+        if self.requested_output_format != 0 {
+            out.write_enum("requested_output_format", self.requested_output_format, &[("UNSPECIFIED", 0), ("PROTOBUF", 1), ("JSON", 2), ("JSPB", 3), ("TEXT_FORMAT", 4)]);
+        }
+        if !self.message_type.is_empty() {
+            out.write_str("message_type", &self.message_type);
+        }
+        if self.test_category != 0 {
+            out.write_enum("test_category", self.test_category, &[("UNSPECIFIED_TEST", 0), ("BINARY_TEST", 1), ("JSON_TEST", 2), ("JSON_IGNORE_UNKNOWN_PARSING_TEST", 3), ("JSPB_TEST", 4), ("TEXT_FORMAT_TEST", 5)]);
+        }
+        if self.jspb_encoding_options != JspbEncodingConfig::default() {
+            out.begin_message("jspb_encoding_options");
+            self.jspb_encoding_options.write_text(out);
+            out.end_message();
+        }
+        if let ConformanceRequestPayload::JspbPayload(elem) = &self.payload {
+            out.write_str("jspb_payload", elem);
+        }
+        if let ConformanceRequestPayload::TextPayload(elem) = &self.payload {
+            out.write_str("text_payload", elem);
+        }
+        if self.print_unknown_fields {
+            out.write_scalar("print_unknown_fields", self.print_unknown_fields);
+        }
+    }
+}
+
+impl core::fmt::Display for ConformanceRequest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl ConformanceRequest {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if let ConformanceRequestPayload::ProtobufPayload(elem) = &self.payload {
+            out.key("protobufPayload");
+            out.write_bytes(elem);
+        }
+        if let ConformanceRequestPayload::JsonPayload(elem) = &self.payload {
+            out.key("jsonPayload");
+            out.write_str(elem);
+        }
+        if let ConformanceRequestPayload::JspbPayload(elem) = &self.payload {
+            out.key("jspbPayload");
+            out.write_str(elem);
+        }
+        if let ConformanceRequestPayload::TextPayload(elem) = &self.payload {
+            out.key("textPayload");
+            out.write_str(elem);
+        }
+        if self.requested_output_format != 0 {
+            out.key("requestedOutputFormat");
+            out.write_enum(self.requested_output_format, &[("UNSPECIFIED", 0), ("PROTOBUF", 1), ("JSON", 2), ("JSPB", 3), ("TEXT_FORMAT", 4)]);
+        }
+        if !self.message_type.is_empty() {
+            out.key("messageType");
+            out.write_str(&self.message_type);
+        }
+        if self.test_category != 0 {
+            out.key("testCategory");
+            out.write_enum(self.test_category, &[("UNSPECIFIED_TEST", 0), ("BINARY_TEST", 1), ("JSON_TEST", 2), ("JSON_IGNORE_UNKNOWN_PARSING_TEST", 3), ("JSPB_TEST", 4), ("TEXT_FORMAT_TEST", 5)]);
+        }
+        if self.jspb_encoding_options != JspbEncodingConfig::default() {
+            out.key("jspbEncodingOptions");
+            self.jspb_encoding_options.write_json(out);
+        }
+        if self.print_unknown_fields {
+            out.key("printUnknownFields");
+            out.write_bool(self.print_unknown_fields);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "protobufPayload" | "protobuf_payload" => msg.payload = ConformanceRequestPayload::ProtobufPayload(json_to_bytes(value)?),
+                "jsonPayload" | "json_payload" => msg.payload = ConformanceRequestPayload::JsonPayload(json_to_string(value)?),
+                "jspbPayload" | "jspb_payload" => msg.payload = ConformanceRequestPayload::JspbPayload(json_to_string(value)?),
+                "textPayload" | "text_payload" => msg.payload = ConformanceRequestPayload::TextPayload(json_to_string(value)?),
+                "requestedOutputFormat" | "requested_output_format" => msg.requested_output_format = json_to_enum(value, &[("UNSPECIFIED", 0), ("PROTOBUF", 1), ("JSON", 2), ("JSPB", 3), ("TEXT_FORMAT", 4)])?,
+                "messageType" | "message_type" => msg.message_type = json_to_string(value)?,
+                "testCategory" | "test_category" => msg.test_category = json_to_enum(value, &[("UNSPECIFIED_TEST", 0), ("BINARY_TEST", 1), ("JSON_TEST", 2), ("JSON_IGNORE_UNKNOWN_PARSING_TEST", 3), ("JSPB_TEST", 4), ("TEXT_FORMAT_TEST", 5)])?,
+                "jspbEncodingOptions" | "jspb_encoding_options" => msg.jspb_encoding_options = JspbEncodingConfig::from_json_value(value)?,
+                "printUnknownFields" | "print_unknown_fields" => msg.print_unknown_fields = json_to_bool(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub enum ConformanceResponseResult {
+    Unset,
+    ParseError(String),
+    SerializeError(String),
+    TimeoutError(String),
+    RuntimeError(String),
+    ProtobufPayload(Vec<u8>),
+    JsonPayload(String),
+    Skipped(String),
+    JspbPayload(String),
+    TextPayload(String),
+}
+
+impl ConformanceResponseResult {
+    pub const DEFAULT: Self = ConformanceResponseResult::Unset;
+}
+
+impl Default for ConformanceResponseResult {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct ConformanceResponse {
+    pub result: ConformanceResponseResult,
+    pub unknown_fields: UnknownFields,
+}
+
+impl ConformanceResponse {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = ConformanceResponse {
+        result: ConformanceResponseResult::Unset,
+        unknown_fields: UnknownFields::new(),
+    };
+}
+
+impl Default for ConformanceResponse {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ConformanceResponse {
+    pub const PARSE_ERROR_FIELD_NUMBER: u32 = 1;
+    pub const SERIALIZE_ERROR_FIELD_NUMBER: u32 = 6;
+    pub const TIMEOUT_ERROR_FIELD_NUMBER: u32 = 9;
+    pub const RUNTIME_ERROR_FIELD_NUMBER: u32 = 2;
+    pub const PROTOBUF_PAYLOAD_FIELD_NUMBER: u32 = 3;
+    pub const JSON_PAYLOAD_FIELD_NUMBER: u32 = 4;
+    pub const SKIPPED_FIELD_NUMBER: u32 = 5;
+    pub const JSPB_PAYLOAD_FIELD_NUMBER: u32 = 7;
+    pub const TEXT_PAYLOAD_FIELD_NUMBER: u32 = 8;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 2, packed: false, name: "ConformanceResponse.parse_error" },
+        FieldWireType { number: 6, wire_type: 2, packed: false, name: "ConformanceResponse.serialize_error" },
+        FieldWireType { number: 9, wire_type: 2, packed: false, name: "ConformanceResponse.timeout_error" },
+        FieldWireType { number: 2, wire_type: 2, packed: false, name: "ConformanceResponse.runtime_error" },
+        FieldWireType { number: 3, wire_type: 2, packed: false, name: "ConformanceResponse.protobuf_payload" },
+        FieldWireType { number: 4, wire_type: 2, packed: false, name: "ConformanceResponse.json_payload" },
+        FieldWireType { number: 5, wire_type: 2, packed: false, name: "ConformanceResponse.skipped" },
+        FieldWireType { number: 7, wire_type: 2, packed: false, name: "ConformanceResponse.jspb_payload" },
+        FieldWireType { number: 8, wire_type: 2, packed: false, name: "ConformanceResponse.text_payload" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        self.unknown_fields.write_reverse(w);
+
+        // field 9: timeout_error
+        if let ConformanceResponseResult::TimeoutError(elem) = &self.result {
+            w.write_string(elem);
+            w.write_key(0x4a);
+        }
+
+        // field 8: text_payload
+        if let ConformanceResponseResult::TextPayload(elem) = &self.result {
+            w.write_string(elem);
+            w.write_key(0x42);
+        }
+
+        // field 7: jspb_payload
+        if let ConformanceResponseResult::JspbPayload(elem) = &self.result {
+            w.write_string(elem);
+            w.write_key(0x3a);
+        }
+
+        // field 6: serialize_error
+        if let ConformanceResponseResult::SerializeError(elem) = &self.result {
+            w.write_string(elem);
+            w.write_key(0x32);
+        }
+
+        // field 5: skipped
+        if let ConformanceResponseResult::Skipped(elem) = &self.result {
+            w.write_string(elem);
+            w.write_key(0x2a);
+        }
+
+        // field 4: json_payload
+        if let ConformanceResponseResult::JsonPayload(elem) = &self.result {
+            w.write_string(elem);
+            w.write_key(0x22);
+        }
+
+        // field 3: protobuf_payload
+        if let ConformanceResponseResult::ProtobufPayload(elem) = &self.result {
+            w.write_bytes(elem);
+            w.write_key(0x1a);
+        }
+
+        // field 2: runtime_error
+        if let ConformanceResponseResult::RuntimeError(elem) = &self.result {
+            w.write_string(elem);
+            w.write_key(0x12);
+        }
+
+        // field 1: parse_error
+        if let ConformanceResponseResult::ParseError(elem) = &self.result {
+            w.write_string(elem);
+            w.write_key(0x0a);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if let ConformanceResponseResult::ParseError(elem) = &self.result {
+            len += key_len(1) + bytes_len(elem.len());
+        }
+        if let ConformanceResponseResult::RuntimeError(elem) = &self.result {
+            len += key_len(2) + bytes_len(elem.len());
+        }
+        if let ConformanceResponseResult::ProtobufPayload(elem) = &self.result {
+            len += key_len(3) + bytes_len(elem.len());
+        }
+        if let ConformanceResponseResult::JsonPayload(elem) = &self.result {
+            len += key_len(4) + bytes_len(elem.len());
+        }
+        if let ConformanceResponseResult::Skipped(elem) = &self.result {
+            len += key_len(5) + bytes_len(elem.len());
+        }
+        if let ConformanceResponseResult::SerializeError(elem) = &self.result {
+            len += key_len(6) + bytes_len(elem.len());
+        }
+        if let ConformanceResponseResult::JspbPayload(elem) = &self.result {
+            len += key_len(7) + bytes_len(elem.len());
+        }
+        if let ConformanceResponseResult::TextPayload(elem) = &self.result {
+            len += key_len(8) + bytes_len(elem.len());
+        }
+        if let ConformanceResponseResult::TimeoutError(elem) = &self.result {
+            len += key_len(9) + bytes_len(elem.len());
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if let ConformanceResponseResult::ParseError(elem) = &self.result {
+            w.write_key(0x0a)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceResponseResult::RuntimeError(elem) = &self.result {
+            w.write_key(0x12)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceResponseResult::ProtobufPayload(elem) = &self.result {
+            w.write_key(0x1a)?;
+            w.write_bytes(elem)?;
+        }
+        if let ConformanceResponseResult::JsonPayload(elem) = &self.result {
+            w.write_key(0x22)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceResponseResult::Skipped(elem) = &self.result {
+            w.write_key(0x2a)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceResponseResult::SerializeError(elem) = &self.result {
+            w.write_key(0x32)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceResponseResult::JspbPayload(elem) = &self.result {
+            w.write_key(0x3a)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceResponseResult::TextPayload(elem) = &self.result {
+            w.write_key(0x42)?;
+            w.write_string(elem)?;
+        }
+        if let ConformanceResponseResult::TimeoutError(elem) = &self.result {
+            w.write_key(0x4a)?;
+            w.write_string(elem)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                10 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::ParseError(v);
+                    pos = new_pos;
+                }
+                50 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::SerializeError(v);
+                    pos = new_pos;
+                }
+                74 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::TimeoutError(v);
+                    pos = new_pos;
+                }
+                18 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::RuntimeError(v);
+                    pos = new_pos;
+                }
+                26 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::ProtobufPayload(v);
+                    pos = new_pos;
+                }
+                34 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::JsonPayload(v);
+                    pos = new_pos;
+                }
+                42 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::Skipped(v);
+                    pos = new_pos;
+                }
+                58 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::JspbPayload(v);
+                    pos = new_pos;
+                }
+                66 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.result = ConformanceResponseResult::TextPayload(v);
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                    self.unknown_fields.push_raw(&data[field_start..pos]);
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for ConformanceResponse {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        ConformanceResponse::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ConformanceResponse::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        ConformanceResponse::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        ConformanceResponse::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        ConformanceResponse::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        ConformanceResponse::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        ConformanceResponse::merge_with(self, data, opts)
+    }
+
+    fn encode_canonical(&self) -> Vec<u8> {
+        let mut msg = self.clone();
+        msg.clear_unknown_fields();
+        msg.encode()
+    }
+
+    fn clear_unknown_fields(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl TryFrom<&[u8]> for ConformanceResponse {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        ConformanceResponse::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<ConformanceResponse> for Vec<u8> {
+    fn from(msg: ConformanceResponse) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for ConformanceResponse {
+    const FULL_NAME: &'static str = "conformance.ConformanceResponse";
+}
+
+impl ConformanceResponse {
+    pub fn parse_error(&self) -> Option<&String> {
+        match &self.result {
+            ConformanceResponseResult::ParseError(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_parse_error(&mut self, value: String) {
+        self.result = ConformanceResponseResult::ParseError(value);
+    }
+
+    pub fn serialize_error(&self) -> Option<&String> {
+        match &self.result {
+            ConformanceResponseResult::SerializeError(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_serialize_error(&mut self, value: String) {
+        self.result = ConformanceResponseResult::SerializeError(value);
+    }
+
+    pub fn timeout_error(&self) -> Option<&String> {
+        match &self.result {
+            ConformanceResponseResult::TimeoutError(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_timeout_error(&mut self, value: String) {
+        self.result = ConformanceResponseResult::TimeoutError(value);
+    }
+
+    pub fn runtime_error(&self) -> Option<&String> {
+        match &self.result {
+            ConformanceResponseResult::RuntimeError(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_runtime_error(&mut self, value: String) {
+        self.result = ConformanceResponseResult::RuntimeError(value);
+    }
+
+    pub fn protobuf_payload(&self) -> Option<&Vec<u8>> {
+        match &self.result {
+            ConformanceResponseResult::ProtobufPayload(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_protobuf_payload(&mut self, value: Vec<u8>) {
+        self.result = ConformanceResponseResult::ProtobufPayload(value);
+    }
+
+    pub fn json_payload(&self) -> Option<&String> {
+        match &self.result {
+            ConformanceResponseResult::JsonPayload(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_json_payload(&mut self, value: String) {
+        self.result = ConformanceResponseResult::JsonPayload(value);
+    }
+
+    pub fn skipped(&self) -> Option<&String> {
+        match &self.result {
+            ConformanceResponseResult::Skipped(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_skipped(&mut self, value: String) {
+        self.result = ConformanceResponseResult::Skipped(value);
+    }
+
+    pub fn jspb_payload(&self) -> Option<&String> {
+        match &self.result {
+            ConformanceResponseResult::JspbPayload(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_jspb_payload(&mut self, value: String) {
+        self.result = ConformanceResponseResult::JspbPayload(value);
+    }
+
+    pub fn text_payload(&self) -> Option<&String> {
+        match &self.result {
+            ConformanceResponseResult::TextPayload(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_text_payload(&mut self, value: String) {
+        self.result = ConformanceResponseResult::TextPayload(value);
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConformanceResponseBuilder {
+    inner: ConformanceResponse,
+}
+
+impl ConformanceResponse {
+    pub fn builder() -> ConformanceResponseBuilder {
+        ConformanceResponseBuilder::default()
+    }
+}
+
+impl ConformanceResponseBuilder {
+    pub fn with_parse_error(mut self, value: String) -> Self {
+        self.inner.result = ConformanceResponseResult::ParseError(value);
+        self
+    }
+
+    pub fn with_serialize_error(mut self, value: String) -> Self {
+        self.inner.result = ConformanceResponseResult::SerializeError(value);
+        self
+    }
+
+    pub fn with_timeout_error(mut self, value: String) -> Self {
+        self.inner.result = ConformanceResponseResult::TimeoutError(value);
+        self
+    }
+
+    pub fn with_runtime_error(mut self, value: String) -> Self {
+        self.inner.result = ConformanceResponseResult::RuntimeError(value);
+        self
+    }
+
+    pub fn with_protobuf_payload(mut self, value: Vec<u8>) -> Self {
+        self.inner.result = ConformanceResponseResult::ProtobufPayload(value);
+        self
+    }
+
+    pub fn with_json_payload(mut self, value: String) -> Self {
+        self.inner.result = ConformanceResponseResult::JsonPayload(value);
+        self
+    }
+
+    pub fn with_skipped(mut self, value: String) -> Self {
+        self.inner.result = ConformanceResponseResult::Skipped(value);
+        self
+    }
+
+    pub fn with_jspb_payload(mut self, value: String) -> Self {
+        self.inner.result = ConformanceResponseResult::JspbPayload(value);
+        self
+    }
+
+    pub fn with_text_payload(mut self, value: String) -> Self {
+        self.inner.result = ConformanceResponseResult::TextPayload(value);
+        self
+    }
+
+    pub fn build(self) -> ConformanceResponse {
+        self.inner
+    }
+}
+
+impl ConformanceResponse {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if let ConformanceResponseResult::ParseError(elem) = &self.result {
+            out.write_str("parse_error", elem);
+        }
+        if let ConformanceResponseResult::RuntimeError(elem) = &self.result {
+            out.write_str("runtime_error", elem);
+        }
+        if let ConformanceResponseResult::ProtobufPayload(elem) = &self.result {
+            out.write_bytes("protobuf_payload", elem);
+        }
+        if let ConformanceResponseResult::JsonPayload(elem) = &self.result {
+            out.write_str("json_payload", elem);
+        }
+        if let ConformanceResponseResult::Skipped(elem) = &self.result {
+            out.write_str("skipped", elem);
+        }
+        if let ConformanceResponseResult::SerializeError(elem) = &self.result {
+            out.write_str("serialize_error", elem);
+        }
+        if let ConformanceResponseResult::JspbPayload(elem) = &self.result {
+            out.write_str("jspb_payload", elem);
+        }
+        if let ConformanceResponseResult::TextPayload(elem) = &self.result {
+            out.write_str("text_payload", elem);
+        }
+        if let ConformanceResponseResult::TimeoutError(elem) = &self.result {
+            out.write_str("timeout_error", elem);
+        }
+    }
+}
+
+impl core::fmt::Display for ConformanceResponse {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl ConformanceResponse {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if let ConformanceResponseResult::ParseError(elem) = &self.result {
+            out.key("parseError");
+            out.write_str(elem);
+        }
+        if let ConformanceResponseResult::SerializeError(elem) = &self.result {
+            out.key("serializeError");
+            out.write_str(elem);
+        }
+        if let ConformanceResponseResult::TimeoutError(elem) = &self.result {
+            out.key("timeoutError");
+            out.write_str(elem);
+        }
+        if let ConformanceResponseResult::RuntimeError(elem) = &self.result {
+            out.key("runtimeError");
+            out.write_str(elem);
+        }
+        if let ConformanceResponseResult::ProtobufPayload(elem) = &self.result {
+            out.key("protobufPayload");
+            out.write_bytes(elem);
+        }
+        if let ConformanceResponseResult::JsonPayload(elem) = &self.result {
+            out.key("jsonPayload");
+            out.write_str(elem);
+        }
+        if let ConformanceResponseResult::Skipped(elem) = &self.result {
+            out.key("skipped");
+            out.write_str(elem);
+        }
+        if let ConformanceResponseResult::JspbPayload(elem) = &self.result {
+            out.key("jspbPayload");
+            out.write_str(elem);
+        }
+        if let ConformanceResponseResult::TextPayload(elem) = &self.result {
+            out.key("textPayload");
+            out.write_str(elem);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "parseError" | "parse_error" => msg.result = ConformanceResponseResult::ParseError(json_to_string(value)?),
+                "serializeError" | "serialize_error" => msg.result = ConformanceResponseResult::SerializeError(json_to_string(value)?),
+                "timeoutError" | "timeout_error" => msg.result = ConformanceResponseResult::TimeoutError(json_to_string(value)?),
+                "runtimeError" | "runtime_error" => msg.result = ConformanceResponseResult::RuntimeError(json_to_string(value)?),
+                "protobufPayload" | "protobuf_payload" => msg.result = ConformanceResponseResult::ProtobufPayload(json_to_bytes(value)?),
+                "jsonPayload" | "json_payload" => msg.result = ConformanceResponseResult::JsonPayload(json_to_string(value)?),
+                "skipped" => msg.result = ConformanceResponseResult::Skipped(json_to_string(value)?),
+                "jspbPayload" | "jspb_payload" => msg.result = ConformanceResponseResult::JspbPayload(json_to_string(value)?),
+                "textPayload" | "text_payload" => msg.result = ConformanceResponseResult::TextPayload(json_to_string(value)?),
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct JspbEncodingConfig {
+    pub use_jspb_array_any_format: bool,
+    pub unknown_fields: UnknownFields,
+}
+
+impl JspbEncodingConfig {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = JspbEncodingConfig {
+        use_jspb_array_any_format: false,
+        unknown_fields: UnknownFields::new(),
+    };
+}
+
+impl Default for JspbEncodingConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl JspbEncodingConfig {
+    pub const USE_JSPB_ARRAY_ANY_FORMAT_FIELD_NUMBER: u32 = 1;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 0, packed: false, name: "JspbEncodingConfig.use_jspb_array_any_format" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        self.unknown_fields.write_reverse(w);
+
+        // field 1: use_jspb_array_any_format
+        if self.use_jspb_array_any_format {
+            w.write_bool(self.use_jspb_array_any_format);
+            w.write_key(0x08);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if self.use_jspb_array_any_format {
+            len += key_len(1) + 1;
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if self.use_jspb_array_any_format {
+            w.write_key(0x08)?;
+            w.write_bool(self.use_jspb_array_any_format)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                8 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    self.use_jspb_array_any_format = v;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                    self.unknown_fields.push_raw(&data[field_start..pos]);
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for JspbEncodingConfig {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        JspbEncodingConfig::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        JspbEncodingConfig::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        JspbEncodingConfig::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        JspbEncodingConfig::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        JspbEncodingConfig::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        JspbEncodingConfig::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        JspbEncodingConfig::merge_with(self, data, opts)
+    }
+
+    fn encode_canonical(&self) -> Vec<u8> {
+        let mut msg = self.clone();
+        msg.clear_unknown_fields();
+        msg.encode()
+    }
+
+    fn clear_unknown_fields(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl TryFrom<&[u8]> for JspbEncodingConfig {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        JspbEncodingConfig::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<JspbEncodingConfig> for Vec<u8> {
+    fn from(msg: JspbEncodingConfig) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for JspbEncodingConfig {
+    const FULL_NAME: &'static str = "conformance.JspbEncodingConfig";
+}
+
+impl JspbEncodingConfig {
+    /// Longest encoding of a `JspbEncodingConfig` whose fields stay within
+    /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+    /// counting unknown fields.
+    pub const MAX_ENCODED_LEN: usize = 2;
+
+    /// Account space for any such value in the `encode_account_data`
+    /// layout, e.g. for `Rent::minimum_balance`.
+    pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JspbEncodingConfigBuilder {
+    inner: JspbEncodingConfig,
+}
+
+impl JspbEncodingConfig {
+    pub fn builder() -> JspbEncodingConfigBuilder {
+        JspbEncodingConfigBuilder::default()
+    }
+}
+
+impl JspbEncodingConfigBuilder {
+    pub fn with_use_jspb_array_any_format(mut self, value: bool) -> Self {
+        self.inner.use_jspb_array_any_format = value;
+        self
+    }
+
+    pub fn build(self) -> JspbEncodingConfig {
+        self.inner
+    }
+}
+
+impl JspbEncodingConfig {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if self.use_jspb_array_any_format {
+            out.write_scalar("use_jspb_array_any_format", self.use_jspb_array_any_format);
+        }
+    }
+}
+
+impl core::fmt::Display for JspbEncodingConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl JspbEncodingConfig {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if self.use_jspb_array_any_format {
+            out.key("useJspbArrayAnyFormat");
+            out.write_bool(self.use_jspb_array_any_format);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "useJspbArrayAnyFormat" | "use_jspb_array_any_format" => msg.use_jspb_array_any_format = json_to_bool(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+/// Every message in this file, for dispatching `Any` payloads by type URL.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyMessage {
+    FailureSet(FailureSet),
+    ConformanceRequest(ConformanceRequest),
+    ConformanceResponse(ConformanceResponse),
+    JspbEncodingConfig(JspbEncodingConfig),
+}
+
+impl AnyMessage {
+    /// Decode `any` as the message its type URL names, or `None` if that
+    /// message is not in this file.
+    pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+        Ok(Some(match any.type_name() {
+            FailureSet::FULL_NAME => AnyMessage::FailureSet(FailureSet::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            ConformanceRequest::FULL_NAME => AnyMessage::ConformanceRequest(ConformanceRequest::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            ConformanceResponse::FULL_NAME => AnyMessage::ConformanceResponse(ConformanceResponse::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            JspbEncodingConfig::FULL_NAME => AnyMessage::JspbEncodingConfig(JspbEncodingConfig::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            _ => return Ok(None),
+        }))
+    }
+
+    pub fn pack(&self) -> Any {
+        match self {
+            AnyMessage::FailureSet(msg) => Any::pack(msg),
+            AnyMessage::ConformanceRequest(msg) => Any::pack(msg),
+            AnyMessage::ConformanceResponse(msg) => Any::pack(msg),
+            AnyMessage::JspbEncodingConfig(msg) => Any::pack(msg),
+        }
+    }
+}
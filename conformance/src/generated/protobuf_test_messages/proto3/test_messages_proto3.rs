@@ -0,0 +1,9048 @@
+// Auto-generated by protoc-gen-solana from test_messages_proto3.proto
+// DO NOT EDIT
+
+#![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+use crate::protobuf_runtime::*;
+use std::collections::BTreeMap;
+
+/// `protobuf_test_messages.proto3.ForeignEnum`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(i32)]
+pub enum ForeignEnum {
+    ForeignFoo = 0,
+    ForeignBar = 1,
+    ForeignBaz = 2,
+}
+
+impl ForeignEnum {
+    /// The proto3 default, numbered 0, for `const` and `static` items.
+    pub const DEFAULT: ForeignEnum = ForeignEnum::ForeignFoo;
+
+    /// The value's name as declared in the .proto file.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ForeignEnum::ForeignFoo => "FOREIGN_FOO",
+            ForeignEnum::ForeignBar => "FOREIGN_BAR",
+            ForeignEnum::ForeignBaz => "FOREIGN_BAZ",
+        }
+    }
+
+    pub fn from_str_name(name: &str) -> Option<Self> {
+        match name {
+            "FOREIGN_FOO" => Some(ForeignEnum::ForeignFoo),
+            "FOREIGN_BAR" => Some(ForeignEnum::ForeignBar),
+            "FOREIGN_BAZ" => Some(ForeignEnum::ForeignBaz),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ForeignEnum {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<ForeignEnum> for i32 {
+    fn from(value: ForeignEnum) -> i32 {
+        value as i32
+    }
+}
+
+/// A number this build does not know is handed back as the error.
+impl TryFrom<i32> for ForeignEnum {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(ForeignEnum::ForeignFoo),
+            1 => Ok(ForeignEnum::ForeignBar),
+            2 => Ok(ForeignEnum::ForeignBaz),
+            _ => Err(value),
+        }
+    }
+}
+
+/// `protobuf_test_messages.proto3.TestAllTypesProto3.NestedEnum`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(i32)]
+pub enum NestedEnum {
+    Foo = 0,
+    Bar = 1,
+    Baz = 2,
+    Neg = -1,
+}
+
+impl NestedEnum {
+    /// The proto3 default, numbered 0, for `const` and `static` items.
+    pub const DEFAULT: NestedEnum = NestedEnum::Foo;
+
+    /// The value's name as declared in the .proto file.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NestedEnum::Foo => "FOO",
+            NestedEnum::Bar => "BAR",
+            NestedEnum::Baz => "BAZ",
+            NestedEnum::Neg => "NEG",
+        }
+    }
+
+    pub fn from_str_name(name: &str) -> Option<Self> {
+        match name {
+            "FOO" => Some(NestedEnum::Foo),
+            "BAR" => Some(NestedEnum::Bar),
+            "BAZ" => Some(NestedEnum::Baz),
+            "NEG" => Some(NestedEnum::Neg),
+            _ => None,
+        }
+    }
+}
+
+impl Default for NestedEnum {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<NestedEnum> for i32 {
+    fn from(value: NestedEnum) -> i32 {
+        value as i32
+    }
+}
+
+/// A number this build does not know is handed back as the error.
+impl TryFrom<i32> for NestedEnum {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(NestedEnum::Foo),
+            1 => Ok(NestedEnum::Bar),
+            2 => Ok(NestedEnum::Baz),
+            -1 => Ok(NestedEnum::Neg),
+            _ => Err(value),
+        }
+    }
+}
+
+/// `protobuf_test_messages.proto3.TestAllTypesProto3.AliasedEnum`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(i32)]
+pub enum AliasedEnum {
+    AliasFoo = 0,
+    AliasBar = 1,
+    AliasBaz = 2,
+}
+
+impl AliasedEnum {
+    /// The proto3 default, numbered 0, for `const` and `static` items.
+    pub const DEFAULT: AliasedEnum = AliasedEnum::AliasFoo;
+
+    /// `MOO`, an alias of `AliasBaz`
+    #[allow(non_upper_case_globals)]
+    pub const Moo: AliasedEnum = AliasedEnum::AliasBaz;
+
+    /// `bAz`, an alias of `AliasBaz`
+    #[allow(non_upper_case_globals)]
+    pub const Baz: AliasedEnum = AliasedEnum::AliasBaz;
+
+    /// The value's name as declared in the .proto file.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AliasedEnum::AliasFoo => "ALIAS_FOO",
+            AliasedEnum::AliasBar => "ALIAS_BAR",
+            AliasedEnum::AliasBaz => "ALIAS_BAZ",
+        }
+    }
+
+    pub fn from_str_name(name: &str) -> Option<Self> {
+        match name {
+            "ALIAS_FOO" => Some(AliasedEnum::AliasFoo),
+            "ALIAS_BAR" => Some(AliasedEnum::AliasBar),
+            "ALIAS_BAZ" => Some(AliasedEnum::AliasBaz),
+            "MOO" => Some(AliasedEnum::AliasBaz),
+            "moo" => Some(AliasedEnum::AliasBaz),
+            "bAz" => Some(AliasedEnum::AliasBaz),
+            _ => None,
+        }
+    }
+}
+
+impl Default for AliasedEnum {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<AliasedEnum> for i32 {
+    fn from(value: AliasedEnum) -> i32 {
+        value as i32
+    }
+}
+
+/// A number this build does not know is handed back as the error.
+impl TryFrom<i32> for AliasedEnum {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(AliasedEnum::AliasFoo),
+            1 => Ok(AliasedEnum::AliasBar),
+            2 => Ok(AliasedEnum::AliasBaz),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub enum TestAllTypesProto3OneofField {
+    Unset,
+    OneofUint32(u32),
+    OneofNestedMessage(Box<NestedMessage>),
+    OneofString(String),
+    OneofBytes(Vec<u8>),
+    OneofBool(bool),
+    OneofUint64(u64),
+    OneofFloat(f32),
+    OneofDouble(f64),
+    OneofEnum(i32),
+}
+
+impl TestAllTypesProto3OneofField {
+    pub const DEFAULT: Self = TestAllTypesProto3OneofField::Unset;
+}
+
+impl Default for TestAllTypesProto3OneofField {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct TestAllTypesProto3 {
+    /// Singular
+    pub optional_int32: i32,
+    pub optional_int64: i64,
+    pub optional_uint32: u32,
+    pub optional_uint64: u64,
+    pub optional_sint32: i32,
+    pub optional_sint64: i64,
+    pub optional_fixed32: u32,
+    pub optional_fixed64: u64,
+    pub optional_sfixed32: i32,
+    pub optional_sfixed64: i64,
+    pub optional_float: f32,
+    pub optional_double: f64,
+    pub optional_bool: bool,
+    pub optional_string: String,
+    pub optional_bytes: Vec<u8>,
+    pub optional_nested_message: Option<Box<NestedMessage>>,
+    pub optional_foreign_message: ForeignMessage,
+    pub optional_nested_enum: i32,
+    pub optional_foreign_enum: i32,
+    pub optional_aliased_enum: i32,
+    pub optional_string_piece: String,
+    pub optional_cord: String,
+    pub recursive_message: Option<Box<TestAllTypesProto3>>,
+    /// Repeated
+    pub repeated_int32: Vec<i32>,
+    pub repeated_int64: Vec<i64>,
+    pub repeated_uint32: Vec<u32>,
+    pub repeated_uint64: Vec<u64>,
+    pub repeated_sint32: Vec<i32>,
+    pub repeated_sint64: Vec<i64>,
+    pub repeated_fixed32: Vec<u32>,
+    pub repeated_fixed64: Vec<u64>,
+    pub repeated_sfixed32: Vec<i32>,
+    pub repeated_sfixed64: Vec<i64>,
+    pub repeated_float: Vec<f32>,
+    pub repeated_double: Vec<f64>,
+    pub repeated_bool: Vec<bool>,
+    pub repeated_string: Vec<String>,
+    pub repeated_bytes: Vec<Vec<u8>>,
+    pub repeated_nested_message: Vec<NestedMessage>,
+    pub repeated_foreign_message: Vec<ForeignMessage>,
+    pub repeated_nested_enum: Vec<i32>,
+    pub repeated_foreign_enum: Vec<i32>,
+    pub repeated_string_piece: Vec<String>,
+    pub repeated_cord: Vec<String>,
+    /// Packed
+    pub packed_int32: Vec<i32>,
+    pub packed_int64: Vec<i64>,
+    pub packed_uint32: Vec<u32>,
+    pub packed_uint64: Vec<u64>,
+    pub packed_sint32: Vec<i32>,
+    pub packed_sint64: Vec<i64>,
+    pub packed_fixed32: Vec<u32>,
+    pub packed_fixed64: Vec<u64>,
+    pub packed_sfixed32: Vec<i32>,
+    pub packed_sfixed64: Vec<i64>,
+    pub packed_float: Vec<f32>,
+    pub packed_double: Vec<f64>,
+    pub packed_bool: Vec<bool>,
+    pub packed_nested_enum: Vec<i32>,
+    /// Unpacked
+    pub unpacked_int32: Vec<i32>,
+    pub unpacked_int64: Vec<i64>,
+    pub unpacked_uint32: Vec<u32>,
+    pub unpacked_uint64: Vec<u64>,
+    pub unpacked_sint32: Vec<i32>,
+    pub unpacked_sint64: Vec<i64>,
+    pub unpacked_fixed32: Vec<u32>,
+    pub unpacked_fixed64: Vec<u64>,
+    pub unpacked_sfixed32: Vec<i32>,
+    pub unpacked_sfixed64: Vec<i64>,
+    pub unpacked_float: Vec<f32>,
+    pub unpacked_double: Vec<f64>,
+    pub unpacked_bool: Vec<bool>,
+    pub unpacked_nested_enum: Vec<i32>,
+    /// Map
+    pub map_int32_int32: BTreeMap<i32, i32>,
+    pub map_int64_int64: BTreeMap<i64, i64>,
+    pub map_uint32_uint32: BTreeMap<u32, u32>,
+    pub map_uint64_uint64: BTreeMap<u64, u64>,
+    pub map_sint32_sint32: BTreeMap<i32, i32>,
+    pub map_sint64_sint64: BTreeMap<i64, i64>,
+    pub map_fixed32_fixed32: BTreeMap<u32, u32>,
+    pub map_fixed64_fixed64: BTreeMap<u64, u64>,
+    pub map_sfixed32_sfixed32: BTreeMap<i32, i32>,
+    pub map_sfixed64_sfixed64: BTreeMap<i64, i64>,
+    pub map_int32_float: BTreeMap<i32, f32>,
+    pub map_int32_double: BTreeMap<i32, f64>,
+    pub map_bool_bool: BTreeMap<bool, bool>,
+    pub map_string_string: BTreeMap<String, String>,
+    pub map_string_bytes: BTreeMap<String, Vec<u8>>,
+    pub map_string_nested_message: BTreeMap<String, NestedMessage>,
+    pub map_string_foreign_message: BTreeMap<String, ForeignMessage>,
+    pub map_string_nested_enum: BTreeMap<String, i32>,
+    pub map_string_foreign_enum: BTreeMap<String, i32>,
+    pub oneof_field: TestAllTypesProto3OneofField,
+    /// Well-known types
+    pub optional_bool_wrapper: Option<bool>,
+    pub optional_int32_wrapper: Option<i32>,
+    pub optional_int64_wrapper: Option<i64>,
+    pub optional_uint32_wrapper: Option<u32>,
+    pub optional_uint64_wrapper: Option<u64>,
+    pub optional_float_wrapper: Option<f32>,
+    pub optional_double_wrapper: Option<f64>,
+    pub optional_string_wrapper: Option<String>,
+    pub optional_bytes_wrapper: Option<Vec<u8>>,
+    pub repeated_bool_wrapper: Vec<BoolValue>,
+    pub repeated_int32_wrapper: Vec<Int32Value>,
+    pub repeated_int64_wrapper: Vec<Int64Value>,
+    pub repeated_uint32_wrapper: Vec<UInt32Value>,
+    pub repeated_uint64_wrapper: Vec<UInt64Value>,
+    pub repeated_float_wrapper: Vec<FloatValue>,
+    pub repeated_double_wrapper: Vec<DoubleValue>,
+    pub repeated_string_wrapper: Vec<StringValue>,
+    pub repeated_bytes_wrapper: Vec<BytesValue>,
+    pub optional_duration: Duration,
+    pub optional_timestamp: Timestamp,
+    pub optional_any: Any,
+    pub repeated_duration: Vec<Duration>,
+    pub repeated_timestamp: Vec<Timestamp>,
+    pub repeated_any: Vec<Any>,
+    pub unknown_fields: UnknownFields,
+}
+
+impl TestAllTypesProto3 {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = TestAllTypesProto3 {
+        optional_int32: 0,
+        optional_int64: 0,
+        optional_uint32: 0,
+        optional_uint64: 0,
+        optional_sint32: 0,
+        optional_sint64: 0,
+        optional_fixed32: 0,
+        optional_fixed64: 0,
+        optional_sfixed32: 0,
+        optional_sfixed64: 0,
+        optional_float: 0.0,
+        optional_double: 0.0,
+        optional_bool: false,
+        optional_string: String::new(),
+        optional_bytes: Vec::new(),
+        optional_nested_message: None,
+        optional_foreign_message: ForeignMessage::DEFAULT,
+        optional_nested_enum: 0,
+        optional_foreign_enum: 0,
+        optional_aliased_enum: 0,
+        optional_string_piece: String::new(),
+        optional_cord: String::new(),
+        recursive_message: None,
+        repeated_int32: Vec::new(),
+        repeated_int64: Vec::new(),
+        repeated_uint32: Vec::new(),
+        repeated_uint64: Vec::new(),
+        repeated_sint32: Vec::new(),
+        repeated_sint64: Vec::new(),
+        repeated_fixed32: Vec::new(),
+        repeated_fixed64: Vec::new(),
+        repeated_sfixed32: Vec::new(),
+        repeated_sfixed64: Vec::new(),
+        repeated_float: Vec::new(),
+        repeated_double: Vec::new(),
+        repeated_bool: Vec::new(),
+        repeated_string: Vec::new(),
+        repeated_bytes: Vec::new(),
+        repeated_nested_message: Vec::new(),
+        repeated_foreign_message: Vec::new(),
+        repeated_nested_enum: Vec::new(),
+        repeated_foreign_enum: Vec::new(),
+        repeated_string_piece: Vec::new(),
+        repeated_cord: Vec::new(),
+        packed_int32: Vec::new(),
+        packed_int64: Vec::new(),
+        packed_uint32: Vec::new(),
+        packed_uint64: Vec::new(),
+        packed_sint32: Vec::new(),
+        packed_sint64: Vec::new(),
+        packed_fixed32: Vec::new(),
+        packed_fixed64: Vec::new(),
+        packed_sfixed32: Vec::new(),
+        packed_sfixed64: Vec::new(),
+        packed_float: Vec::new(),
+        packed_double: Vec::new(),
+        packed_bool: Vec::new(),
+        packed_nested_enum: Vec::new(),
+        unpacked_int32: Vec::new(),
+        unpacked_int64: Vec::new(),
+        unpacked_uint32: Vec::new(),
+        unpacked_uint64: Vec::new(),
+        unpacked_sint32: Vec::new(),
+        unpacked_sint64: Vec::new(),
+        unpacked_fixed32: Vec::new(),
+        unpacked_fixed64: Vec::new(),
+        unpacked_sfixed32: Vec::new(),
+        unpacked_sfixed64: Vec::new(),
+        unpacked_float: Vec::new(),
+        unpacked_double: Vec::new(),
+        unpacked_bool: Vec::new(),
+        unpacked_nested_enum: Vec::new(),
+        map_int32_int32: BTreeMap::new(),
+        map_int64_int64: BTreeMap::new(),
+        map_uint32_uint32: BTreeMap::new(),
+        map_uint64_uint64: BTreeMap::new(),
+        map_sint32_sint32: BTreeMap::new(),
+        map_sint64_sint64: BTreeMap::new(),
+        map_fixed32_fixed32: BTreeMap::new(),
+        map_fixed64_fixed64: BTreeMap::new(),
+        map_sfixed32_sfixed32: BTreeMap::new(),
+        map_sfixed64_sfixed64: BTreeMap::new(),
+        map_int32_float: BTreeMap::new(),
+        map_int32_double: BTreeMap::new(),
+        map_bool_bool: BTreeMap::new(),
+        map_string_string: BTreeMap::new(),
+        map_string_bytes: BTreeMap::new(),
+        map_string_nested_message: BTreeMap::new(),
+        map_string_foreign_message: BTreeMap::new(),
+        map_string_nested_enum: BTreeMap::new(),
+        map_string_foreign_enum: BTreeMap::new(),
+        oneof_field: TestAllTypesProto3OneofField::Unset,
+        optional_bool_wrapper: None,
+        optional_int32_wrapper: None,
+        optional_int64_wrapper: None,
+        optional_uint32_wrapper: None,
+        optional_uint64_wrapper: None,
+        optional_float_wrapper: None,
+        optional_double_wrapper: None,
+        optional_string_wrapper: None,
+        optional_bytes_wrapper: None,
+        repeated_bool_wrapper: Vec::new(),
+        repeated_int32_wrapper: Vec::new(),
+        repeated_int64_wrapper: Vec::new(),
+        repeated_uint32_wrapper: Vec::new(),
+        repeated_uint64_wrapper: Vec::new(),
+        repeated_float_wrapper: Vec::new(),
+        repeated_double_wrapper: Vec::new(),
+        repeated_string_wrapper: Vec::new(),
+        repeated_bytes_wrapper: Vec::new(),
+        optional_duration: Duration::DEFAULT,
+        optional_timestamp: Timestamp::DEFAULT,
+        optional_any: Any::DEFAULT,
+        repeated_duration: Vec::new(),
+        repeated_timestamp: Vec::new(),
+        repeated_any: Vec::new(),
+        unknown_fields: UnknownFields::new(),
+    };
+}
+
+impl Default for TestAllTypesProto3 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl TestAllTypesProto3 {
+    pub const OPTIONAL_INT32_FIELD_NUMBER: u32 = 1;
+    pub const OPTIONAL_INT64_FIELD_NUMBER: u32 = 2;
+    pub const OPTIONAL_UINT32_FIELD_NUMBER: u32 = 3;
+    pub const OPTIONAL_UINT64_FIELD_NUMBER: u32 = 4;
+    pub const OPTIONAL_SINT32_FIELD_NUMBER: u32 = 5;
+    pub const OPTIONAL_SINT64_FIELD_NUMBER: u32 = 6;
+    pub const OPTIONAL_FIXED32_FIELD_NUMBER: u32 = 7;
+    pub const OPTIONAL_FIXED64_FIELD_NUMBER: u32 = 8;
+    pub const OPTIONAL_SFIXED32_FIELD_NUMBER: u32 = 9;
+    pub const OPTIONAL_SFIXED64_FIELD_NUMBER: u32 = 10;
+    pub const OPTIONAL_FLOAT_FIELD_NUMBER: u32 = 11;
+    pub const OPTIONAL_DOUBLE_FIELD_NUMBER: u32 = 12;
+    pub const OPTIONAL_BOOL_FIELD_NUMBER: u32 = 13;
+    pub const OPTIONAL_STRING_FIELD_NUMBER: u32 = 14;
+    pub const OPTIONAL_BYTES_FIELD_NUMBER: u32 = 15;
+    pub const OPTIONAL_NESTED_MESSAGE_FIELD_NUMBER: u32 = 18;
+    pub const OPTIONAL_FOREIGN_MESSAGE_FIELD_NUMBER: u32 = 19;
+    pub const OPTIONAL_NESTED_ENUM_FIELD_NUMBER: u32 = 21;
+    pub const OPTIONAL_FOREIGN_ENUM_FIELD_NUMBER: u32 = 22;
+    pub const OPTIONAL_ALIASED_ENUM_FIELD_NUMBER: u32 = 23;
+    pub const OPTIONAL_STRING_PIECE_FIELD_NUMBER: u32 = 24;
+    pub const OPTIONAL_CORD_FIELD_NUMBER: u32 = 25;
+    pub const RECURSIVE_MESSAGE_FIELD_NUMBER: u32 = 27;
+    pub const REPEATED_INT32_FIELD_NUMBER: u32 = 31;
+    pub const REPEATED_INT64_FIELD_NUMBER: u32 = 32;
+    pub const REPEATED_UINT32_FIELD_NUMBER: u32 = 33;
+    pub const REPEATED_UINT64_FIELD_NUMBER: u32 = 34;
+    pub const REPEATED_SINT32_FIELD_NUMBER: u32 = 35;
+    pub const REPEATED_SINT64_FIELD_NUMBER: u32 = 36;
+    pub const REPEATED_FIXED32_FIELD_NUMBER: u32 = 37;
+    pub const REPEATED_FIXED64_FIELD_NUMBER: u32 = 38;
+    pub const REPEATED_SFIXED32_FIELD_NUMBER: u32 = 39;
+    pub const REPEATED_SFIXED64_FIELD_NUMBER: u32 = 40;
+    pub const REPEATED_FLOAT_FIELD_NUMBER: u32 = 41;
+    pub const REPEATED_DOUBLE_FIELD_NUMBER: u32 = 42;
+    pub const REPEATED_BOOL_FIELD_NUMBER: u32 = 43;
+    pub const REPEATED_STRING_FIELD_NUMBER: u32 = 44;
+    pub const REPEATED_BYTES_FIELD_NUMBER: u32 = 45;
+    pub const REPEATED_NESTED_MESSAGE_FIELD_NUMBER: u32 = 48;
+    pub const REPEATED_FOREIGN_MESSAGE_FIELD_NUMBER: u32 = 49;
+    pub const REPEATED_NESTED_ENUM_FIELD_NUMBER: u32 = 51;
+    pub const REPEATED_FOREIGN_ENUM_FIELD_NUMBER: u32 = 52;
+    pub const REPEATED_STRING_PIECE_FIELD_NUMBER: u32 = 54;
+    pub const REPEATED_CORD_FIELD_NUMBER: u32 = 55;
+    pub const PACKED_INT32_FIELD_NUMBER: u32 = 75;
+    pub const PACKED_INT64_FIELD_NUMBER: u32 = 76;
+    pub const PACKED_UINT32_FIELD_NUMBER: u32 = 77;
+    pub const PACKED_UINT64_FIELD_NUMBER: u32 = 78;
+    pub const PACKED_SINT32_FIELD_NUMBER: u32 = 79;
+    pub const PACKED_SINT64_FIELD_NUMBER: u32 = 80;
+    pub const PACKED_FIXED32_FIELD_NUMBER: u32 = 81;
+    pub const PACKED_FIXED64_FIELD_NUMBER: u32 = 82;
+    pub const PACKED_SFIXED32_FIELD_NUMBER: u32 = 83;
+    pub const PACKED_SFIXED64_FIELD_NUMBER: u32 = 84;
+    pub const PACKED_FLOAT_FIELD_NUMBER: u32 = 85;
+    pub const PACKED_DOUBLE_FIELD_NUMBER: u32 = 86;
+    pub const PACKED_BOOL_FIELD_NUMBER: u32 = 87;
+    pub const PACKED_NESTED_ENUM_FIELD_NUMBER: u32 = 88;
+    pub const UNPACKED_INT32_FIELD_NUMBER: u32 = 89;
+    pub const UNPACKED_INT64_FIELD_NUMBER: u32 = 90;
+    pub const UNPACKED_UINT32_FIELD_NUMBER: u32 = 91;
+    pub const UNPACKED_UINT64_FIELD_NUMBER: u32 = 92;
+    pub const UNPACKED_SINT32_FIELD_NUMBER: u32 = 93;
+    pub const UNPACKED_SINT64_FIELD_NUMBER: u32 = 94;
+    pub const UNPACKED_FIXED32_FIELD_NUMBER: u32 = 95;
+    pub const UNPACKED_FIXED64_FIELD_NUMBER: u32 = 96;
+    pub const UNPACKED_SFIXED32_FIELD_NUMBER: u32 = 97;
+    pub const UNPACKED_SFIXED64_FIELD_NUMBER: u32 = 98;
+    pub const UNPACKED_FLOAT_FIELD_NUMBER: u32 = 99;
+    pub const UNPACKED_DOUBLE_FIELD_NUMBER: u32 = 100;
+    pub const UNPACKED_BOOL_FIELD_NUMBER: u32 = 101;
+    pub const UNPACKED_NESTED_ENUM_FIELD_NUMBER: u32 = 102;
+    pub const MAP_INT32_INT32_FIELD_NUMBER: u32 = 56;
+    pub const MAP_INT64_INT64_FIELD_NUMBER: u32 = 57;
+    pub const MAP_UINT32_UINT32_FIELD_NUMBER: u32 = 58;
+    pub const MAP_UINT64_UINT64_FIELD_NUMBER: u32 = 59;
+    pub const MAP_SINT32_SINT32_FIELD_NUMBER: u32 = 60;
+    pub const MAP_SINT64_SINT64_FIELD_NUMBER: u32 = 61;
+    pub const MAP_FIXED32_FIXED32_FIELD_NUMBER: u32 = 62;
+    pub const MAP_FIXED64_FIXED64_FIELD_NUMBER: u32 = 63;
+    pub const MAP_SFIXED32_SFIXED32_FIELD_NUMBER: u32 = 64;
+    pub const MAP_SFIXED64_SFIXED64_FIELD_NUMBER: u32 = 65;
+    pub const MAP_INT32_FLOAT_FIELD_NUMBER: u32 = 66;
+    pub const MAP_INT32_DOUBLE_FIELD_NUMBER: u32 = 67;
+    pub const MAP_BOOL_BOOL_FIELD_NUMBER: u32 = 68;
+    pub const MAP_STRING_STRING_FIELD_NUMBER: u32 = 69;
+    pub const MAP_STRING_BYTES_FIELD_NUMBER: u32 = 70;
+    pub const MAP_STRING_NESTED_MESSAGE_FIELD_NUMBER: u32 = 71;
+    pub const MAP_STRING_FOREIGN_MESSAGE_FIELD_NUMBER: u32 = 72;
+    pub const MAP_STRING_NESTED_ENUM_FIELD_NUMBER: u32 = 73;
+    pub const MAP_STRING_FOREIGN_ENUM_FIELD_NUMBER: u32 = 74;
+    pub const ONEOF_UINT32_FIELD_NUMBER: u32 = 111;
+    pub const ONEOF_NESTED_MESSAGE_FIELD_NUMBER: u32 = 112;
+    pub const ONEOF_STRING_FIELD_NUMBER: u32 = 113;
+    pub const ONEOF_BYTES_FIELD_NUMBER: u32 = 114;
+    pub const ONEOF_BOOL_FIELD_NUMBER: u32 = 115;
+    pub const ONEOF_UINT64_FIELD_NUMBER: u32 = 116;
+    pub const ONEOF_FLOAT_FIELD_NUMBER: u32 = 117;
+    pub const ONEOF_DOUBLE_FIELD_NUMBER: u32 = 118;
+    pub const ONEOF_ENUM_FIELD_NUMBER: u32 = 119;
+    pub const OPTIONAL_BOOL_WRAPPER_FIELD_NUMBER: u32 = 201;
+    pub const OPTIONAL_INT32_WRAPPER_FIELD_NUMBER: u32 = 202;
+    pub const OPTIONAL_INT64_WRAPPER_FIELD_NUMBER: u32 = 203;
+    pub const OPTIONAL_UINT32_WRAPPER_FIELD_NUMBER: u32 = 204;
+    pub const OPTIONAL_UINT64_WRAPPER_FIELD_NUMBER: u32 = 205;
+    pub const OPTIONAL_FLOAT_WRAPPER_FIELD_NUMBER: u32 = 206;
+    pub const OPTIONAL_DOUBLE_WRAPPER_FIELD_NUMBER: u32 = 207;
+    pub const OPTIONAL_STRING_WRAPPER_FIELD_NUMBER: u32 = 208;
+    pub const OPTIONAL_BYTES_WRAPPER_FIELD_NUMBER: u32 = 209;
+    pub const REPEATED_BOOL_WRAPPER_FIELD_NUMBER: u32 = 211;
+    pub const REPEATED_INT32_WRAPPER_FIELD_NUMBER: u32 = 212;
+    pub const REPEATED_INT64_WRAPPER_FIELD_NUMBER: u32 = 213;
+    pub const REPEATED_UINT32_WRAPPER_FIELD_NUMBER: u32 = 214;
+    pub const REPEATED_UINT64_WRAPPER_FIELD_NUMBER: u32 = 215;
+    pub const REPEATED_FLOAT_WRAPPER_FIELD_NUMBER: u32 = 216;
+    pub const REPEATED_DOUBLE_WRAPPER_FIELD_NUMBER: u32 = 217;
+    pub const REPEATED_STRING_WRAPPER_FIELD_NUMBER: u32 = 218;
+    pub const REPEATED_BYTES_WRAPPER_FIELD_NUMBER: u32 = 219;
+    pub const OPTIONAL_DURATION_FIELD_NUMBER: u32 = 301;
+    pub const OPTIONAL_TIMESTAMP_FIELD_NUMBER: u32 = 302;
+    pub const OPTIONAL_ANY_FIELD_NUMBER: u32 = 305;
+    pub const REPEATED_DURATION_FIELD_NUMBER: u32 = 311;
+    pub const REPEATED_TIMESTAMP_FIELD_NUMBER: u32 = 312;
+    pub const REPEATED_ANY_FIELD_NUMBER: u32 = 315;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_int32" },
+        FieldWireType { number: 2, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_int64" },
+        FieldWireType { number: 3, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_uint32" },
+        FieldWireType { number: 4, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_uint64" },
+        FieldWireType { number: 5, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_sint32" },
+        FieldWireType { number: 6, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_sint64" },
+        FieldWireType { number: 7, wire_type: 5, packed: false, name: "TestAllTypesProto3.optional_fixed32" },
+        FieldWireType { number: 8, wire_type: 1, packed: false, name: "TestAllTypesProto3.optional_fixed64" },
+        FieldWireType { number: 9, wire_type: 5, packed: false, name: "TestAllTypesProto3.optional_sfixed32" },
+        FieldWireType { number: 10, wire_type: 1, packed: false, name: "TestAllTypesProto3.optional_sfixed64" },
+        FieldWireType { number: 11, wire_type: 5, packed: false, name: "TestAllTypesProto3.optional_float" },
+        FieldWireType { number: 12, wire_type: 1, packed: false, name: "TestAllTypesProto3.optional_double" },
+        FieldWireType { number: 13, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_bool" },
+        FieldWireType { number: 14, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_string" },
+        FieldWireType { number: 15, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_bytes" },
+        FieldWireType { number: 18, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_nested_message" },
+        FieldWireType { number: 19, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_foreign_message" },
+        FieldWireType { number: 21, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_nested_enum" },
+        FieldWireType { number: 22, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_foreign_enum" },
+        FieldWireType { number: 23, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_aliased_enum" },
+        FieldWireType { number: 24, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_string_piece" },
+        FieldWireType { number: 25, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_cord" },
+        FieldWireType { number: 27, wire_type: 2, packed: false, name: "TestAllTypesProto3.recursive_message" },
+        FieldWireType { number: 31, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_int32" },
+        FieldWireType { number: 32, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_int64" },
+        FieldWireType { number: 33, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_uint32" },
+        FieldWireType { number: 34, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_uint64" },
+        FieldWireType { number: 35, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_sint32" },
+        FieldWireType { number: 36, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_sint64" },
+        FieldWireType { number: 37, wire_type: 5, packed: true, name: "TestAllTypesProto3.repeated_fixed32" },
+        FieldWireType { number: 38, wire_type: 1, packed: true, name: "TestAllTypesProto3.repeated_fixed64" },
+        FieldWireType { number: 39, wire_type: 5, packed: true, name: "TestAllTypesProto3.repeated_sfixed32" },
+        FieldWireType { number: 40, wire_type: 1, packed: true, name: "TestAllTypesProto3.repeated_sfixed64" },
+        FieldWireType { number: 41, wire_type: 5, packed: true, name: "TestAllTypesProto3.repeated_float" },
+        FieldWireType { number: 42, wire_type: 1, packed: true, name: "TestAllTypesProto3.repeated_double" },
+        FieldWireType { number: 43, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_bool" },
+        FieldWireType { number: 44, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_string" },
+        FieldWireType { number: 45, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_bytes" },
+        FieldWireType { number: 48, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_nested_message" },
+        FieldWireType { number: 49, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_foreign_message" },
+        FieldWireType { number: 51, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_nested_enum" },
+        FieldWireType { number: 52, wire_type: 0, packed: true, name: "TestAllTypesProto3.repeated_foreign_enum" },
+        FieldWireType { number: 54, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_string_piece" },
+        FieldWireType { number: 55, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_cord" },
+        FieldWireType { number: 75, wire_type: 0, packed: true, name: "TestAllTypesProto3.packed_int32" },
+        FieldWireType { number: 76, wire_type: 0, packed: true, name: "TestAllTypesProto3.packed_int64" },
+        FieldWireType { number: 77, wire_type: 0, packed: true, name: "TestAllTypesProto3.packed_uint32" },
+        FieldWireType { number: 78, wire_type: 0, packed: true, name: "TestAllTypesProto3.packed_uint64" },
+        FieldWireType { number: 79, wire_type: 0, packed: true, name: "TestAllTypesProto3.packed_sint32" },
+        FieldWireType { number: 80, wire_type: 0, packed: true, name: "TestAllTypesProto3.packed_sint64" },
+        FieldWireType { number: 81, wire_type: 5, packed: true, name: "TestAllTypesProto3.packed_fixed32" },
+        FieldWireType { number: 82, wire_type: 1, packed: true, name: "TestAllTypesProto3.packed_fixed64" },
+        FieldWireType { number: 83, wire_type: 5, packed: true, name: "TestAllTypesProto3.packed_sfixed32" },
+        FieldWireType { number: 84, wire_type: 1, packed: true, name: "TestAllTypesProto3.packed_sfixed64" },
+        FieldWireType { number: 85, wire_type: 5, packed: true, name: "TestAllTypesProto3.packed_float" },
+        FieldWireType { number: 86, wire_type: 1, packed: true, name: "TestAllTypesProto3.packed_double" },
+        FieldWireType { number: 87, wire_type: 0, packed: true, name: "TestAllTypesProto3.packed_bool" },
+        FieldWireType { number: 88, wire_type: 0, packed: true, name: "TestAllTypesProto3.packed_nested_enum" },
+        FieldWireType { number: 89, wire_type: 0, packed: true, name: "TestAllTypesProto3.unpacked_int32" },
+        FieldWireType { number: 90, wire_type: 0, packed: true, name: "TestAllTypesProto3.unpacked_int64" },
+        FieldWireType { number: 91, wire_type: 0, packed: true, name: "TestAllTypesProto3.unpacked_uint32" },
+        FieldWireType { number: 92, wire_type: 0, packed: true, name: "TestAllTypesProto3.unpacked_uint64" },
+        FieldWireType { number: 93, wire_type: 0, packed: true, name: "TestAllTypesProto3.unpacked_sint32" },
+        FieldWireType { number: 94, wire_type: 0, packed: true, name: "TestAllTypesProto3.unpacked_sint64" },
+        FieldWireType { number: 95, wire_type: 5, packed: true, name: "TestAllTypesProto3.unpacked_fixed32" },
+        FieldWireType { number: 96, wire_type: 1, packed: true, name: "TestAllTypesProto3.unpacked_fixed64" },
+        FieldWireType { number: 97, wire_type: 5, packed: true, name: "TestAllTypesProto3.unpacked_sfixed32" },
+        FieldWireType { number: 98, wire_type: 1, packed: true, name: "TestAllTypesProto3.unpacked_sfixed64" },
+        FieldWireType { number: 99, wire_type: 5, packed: true, name: "TestAllTypesProto3.unpacked_float" },
+        FieldWireType { number: 100, wire_type: 1, packed: true, name: "TestAllTypesProto3.unpacked_double" },
+        FieldWireType { number: 101, wire_type: 0, packed: true, name: "TestAllTypesProto3.unpacked_bool" },
+        FieldWireType { number: 102, wire_type: 0, packed: true, name: "TestAllTypesProto3.unpacked_nested_enum" },
+        FieldWireType { number: 56, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_int32_int32" },
+        FieldWireType { number: 57, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_int64_int64" },
+        FieldWireType { number: 58, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_uint32_uint32" },
+        FieldWireType { number: 59, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_uint64_uint64" },
+        FieldWireType { number: 60, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_sint32_sint32" },
+        FieldWireType { number: 61, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_sint64_sint64" },
+        FieldWireType { number: 62, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_fixed32_fixed32" },
+        FieldWireType { number: 63, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_fixed64_fixed64" },
+        FieldWireType { number: 64, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_sfixed32_sfixed32" },
+        FieldWireType { number: 65, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_sfixed64_sfixed64" },
+        FieldWireType { number: 66, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_int32_float" },
+        FieldWireType { number: 67, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_int32_double" },
+        FieldWireType { number: 68, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_bool_bool" },
+        FieldWireType { number: 69, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_string_string" },
+        FieldWireType { number: 70, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_string_bytes" },
+        FieldWireType { number: 71, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_string_nested_message" },
+        FieldWireType { number: 72, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_string_foreign_message" },
+        FieldWireType { number: 73, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_string_nested_enum" },
+        FieldWireType { number: 74, wire_type: 2, packed: false, name: "TestAllTypesProto3.map_string_foreign_enum" },
+        FieldWireType { number: 111, wire_type: 0, packed: false, name: "TestAllTypesProto3.oneof_uint32" },
+        FieldWireType { number: 112, wire_type: 2, packed: false, name: "TestAllTypesProto3.oneof_nested_message" },
+        FieldWireType { number: 113, wire_type: 2, packed: false, name: "TestAllTypesProto3.oneof_string" },
+        FieldWireType { number: 114, wire_type: 2, packed: false, name: "TestAllTypesProto3.oneof_bytes" },
+        FieldWireType { number: 115, wire_type: 0, packed: false, name: "TestAllTypesProto3.oneof_bool" },
+        FieldWireType { number: 116, wire_type: 0, packed: false, name: "TestAllTypesProto3.oneof_uint64" },
+        FieldWireType { number: 117, wire_type: 5, packed: false, name: "TestAllTypesProto3.oneof_float" },
+        FieldWireType { number: 118, wire_type: 1, packed: false, name: "TestAllTypesProto3.oneof_double" },
+        FieldWireType { number: 119, wire_type: 0, packed: false, name: "TestAllTypesProto3.oneof_enum" },
+        FieldWireType { number: 201, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_bool_wrapper" },
+        FieldWireType { number: 202, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_int32_wrapper" },
+        FieldWireType { number: 203, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_int64_wrapper" },
+        FieldWireType { number: 204, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_uint32_wrapper" },
+        FieldWireType { number: 205, wire_type: 0, packed: false, name: "TestAllTypesProto3.optional_uint64_wrapper" },
+        FieldWireType { number: 206, wire_type: 5, packed: false, name: "TestAllTypesProto3.optional_float_wrapper" },
+        FieldWireType { number: 207, wire_type: 1, packed: false, name: "TestAllTypesProto3.optional_double_wrapper" },
+        FieldWireType { number: 208, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_string_wrapper" },
+        FieldWireType { number: 209, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_bytes_wrapper" },
+        FieldWireType { number: 211, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_bool_wrapper" },
+        FieldWireType { number: 212, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_int32_wrapper" },
+        FieldWireType { number: 213, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_int64_wrapper" },
+        FieldWireType { number: 214, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_uint32_wrapper" },
+        FieldWireType { number: 215, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_uint64_wrapper" },
+        FieldWireType { number: 216, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_float_wrapper" },
+        FieldWireType { number: 217, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_double_wrapper" },
+        FieldWireType { number: 218, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_string_wrapper" },
+        FieldWireType { number: 219, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_bytes_wrapper" },
+        FieldWireType { number: 301, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_duration" },
+        FieldWireType { number: 302, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_timestamp" },
+        FieldWireType { number: 305, wire_type: 2, packed: false, name: "TestAllTypesProto3.optional_any" },
+        FieldWireType { number: 311, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_duration" },
+        FieldWireType { number: 312, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_timestamp" },
+        FieldWireType { number: 315, wire_type: 2, packed: false, name: "TestAllTypesProto3.repeated_any" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        self.unknown_fields.write_reverse(w);
+
+        // field 315: repeated_any
+        for elem in self.repeated_any.iter().rev() {
+            w.write_message(315, elem);
+        }
+
+        // field 312: repeated_timestamp
+        for elem in self.repeated_timestamp.iter().rev() {
+            w.write_message(312, elem);
+        }
+
+        // field 311: repeated_duration
+        for elem in self.repeated_duration.iter().rev() {
+            w.write_message(311, elem);
+        }
+
+        // field 305: optional_any
+        w.write_message(305, &self.optional_any);
+
+        // field 302: optional_timestamp
+        w.write_message(302, &self.optional_timestamp);
+
+        // field 301: optional_duration
+        w.write_message(301, &self.optional_duration);
+
+        // field 219: repeated_bytes_wrapper
+        for elem in self.repeated_bytes_wrapper.iter().rev() {
+            w.write_message(219, elem);
+        }
+
+        // field 218: repeated_string_wrapper
+        for elem in self.repeated_string_wrapper.iter().rev() {
+            w.write_message(218, elem);
+        }
+
+        // field 217: repeated_double_wrapper
+        for elem in self.repeated_double_wrapper.iter().rev() {
+            w.write_message(217, elem);
+        }
+
+        // field 216: repeated_float_wrapper
+        for elem in self.repeated_float_wrapper.iter().rev() {
+            w.write_message(216, elem);
+        }
+
+        // field 215: repeated_uint64_wrapper
+        for elem in self.repeated_uint64_wrapper.iter().rev() {
+            w.write_message(215, elem);
+        }
+
+        // field 214: repeated_uint32_wrapper
+        for elem in self.repeated_uint32_wrapper.iter().rev() {
+            w.write_message(214, elem);
+        }
+
+        // field 213: repeated_int64_wrapper
+        for elem in self.repeated_int64_wrapper.iter().rev() {
+            w.write_message(213, elem);
+        }
+
+        // field 212: repeated_int32_wrapper
+        for elem in self.repeated_int32_wrapper.iter().rev() {
+            w.write_message(212, elem);
+        }
+
+        // field 211: repeated_bool_wrapper
+        for elem in self.repeated_bool_wrapper.iter().rev() {
+            w.write_message(211, elem);
+        }
+
+        // field 209: optional_bytes_wrapper
+        if let Some(elem) = &self.optional_bytes_wrapper {
+            w.write_nested(209, |w| BytesValue::write_value_reverse(elem, w));
+        }
+
+        // field 208: optional_string_wrapper
+        if let Some(elem) = &self.optional_string_wrapper {
+            w.write_nested(208, |w| StringValue::write_value_reverse(elem, w));
+        }
+
+        // field 207: optional_double_wrapper
+        if let Some(elem) = &self.optional_double_wrapper {
+            w.write_nested(207, |w| DoubleValue::write_value_reverse(elem, w));
+        }
+
+        // field 206: optional_float_wrapper
+        if let Some(elem) = &self.optional_float_wrapper {
+            w.write_nested(206, |w| FloatValue::write_value_reverse(elem, w));
+        }
+
+        // field 205: optional_uint64_wrapper
+        if let Some(elem) = &self.optional_uint64_wrapper {
+            w.write_nested(205, |w| UInt64Value::write_value_reverse(elem, w));
+        }
+
+        // field 204: optional_uint32_wrapper
+        if let Some(elem) = &self.optional_uint32_wrapper {
+            w.write_nested(204, |w| UInt32Value::write_value_reverse(elem, w));
+        }
+
+        // field 203: optional_int64_wrapper
+        if let Some(elem) = &self.optional_int64_wrapper {
+            w.write_nested(203, |w| Int64Value::write_value_reverse(elem, w));
+        }
+
+        // field 202: optional_int32_wrapper
+        if let Some(elem) = &self.optional_int32_wrapper {
+            w.write_nested(202, |w| Int32Value::write_value_reverse(elem, w));
+        }
+
+        // field 201: optional_bool_wrapper
+        if let Some(elem) = &self.optional_bool_wrapper {
+            w.write_nested(201, |w| BoolValue::write_value_reverse(elem, w));
+        }
+
+        // field 119: oneof_enum
+        if let TestAllTypesProto3OneofField::OneofEnum(elem) = &self.oneof_field {
+            w.write_int32(*elem);
+            w.write_key(0x3b8);
+        }
+
+        // field 118: oneof_double
+        if let TestAllTypesProto3OneofField::OneofDouble(elem) = &self.oneof_field {
+            w.write_double(*elem);
+            w.write_key(0x3b1);
+        }
+
+        // field 117: oneof_float
+        if let TestAllTypesProto3OneofField::OneofFloat(elem) = &self.oneof_field {
+            w.write_float(*elem);
+            w.write_key(0x3ad);
+        }
+
+        // field 116: oneof_uint64
+        if let TestAllTypesProto3OneofField::OneofUint64(elem) = &self.oneof_field {
+            w.write_varint(*elem);
+            w.write_key(0x3a0);
+        }
+
+        // field 115: oneof_bool
+        if let TestAllTypesProto3OneofField::OneofBool(elem) = &self.oneof_field {
+            w.write_bool(*elem);
+            w.write_key(0x398);
+        }
+
+        // field 114: oneof_bytes
+        if let TestAllTypesProto3OneofField::OneofBytes(elem) = &self.oneof_field {
+            w.write_bytes(elem);
+            w.write_key(0x392);
+        }
+
+        // field 113: oneof_string
+        if let TestAllTypesProto3OneofField::OneofString(elem) = &self.oneof_field {
+            w.write_string(elem);
+            w.write_key(0x38a);
+        }
+
+        // field 112: oneof_nested_message
+        if let TestAllTypesProto3OneofField::OneofNestedMessage(elem) = &self.oneof_field {
+            w.write_message(112, &**elem);
+        }
+
+        // field 111: oneof_uint32
+        if let TestAllTypesProto3OneofField::OneofUint32(elem) = &self.oneof_field {
+            w.write_varint(*elem as u64);
+            w.write_key(0x378);
+        }
+
+        // field 102: unpacked_nested_enum
+        for elem in self.unpacked_nested_enum.iter().rev() {
+            w.write_int32(*elem);
+            w.write_key(0x330);
+        }
+
+        // field 101: unpacked_bool
+        for elem in self.unpacked_bool.iter().rev() {
+            w.write_bool(*elem);
+            w.write_key(0x328);
+        }
+
+        // field 100: unpacked_double
+        for elem in self.unpacked_double.iter().rev() {
+            w.write_double(*elem);
+            w.write_key(0x321);
+        }
+
+        // field 99: unpacked_float
+        for elem in self.unpacked_float.iter().rev() {
+            w.write_float(*elem);
+            w.write_key(0x31d);
+        }
+
+        // field 98: unpacked_sfixed64
+        for elem in self.unpacked_sfixed64.iter().rev() {
+            w.write_sfixed64(*elem);
+            w.write_key(0x311);
+        }
+
+        // field 97: unpacked_sfixed32
+        for elem in self.unpacked_sfixed32.iter().rev() {
+            w.write_sfixed32(*elem);
+            w.write_key(0x30d);
+        }
+
+        // field 96: unpacked_fixed64
+        for elem in self.unpacked_fixed64.iter().rev() {
+            w.write_fixed64(*elem);
+            w.write_key(0x301);
+        }
+
+        // field 95: unpacked_fixed32
+        for elem in self.unpacked_fixed32.iter().rev() {
+            w.write_fixed32(*elem);
+            w.write_key(0x2fd);
+        }
+
+        // field 94: unpacked_sint64
+        for elem in self.unpacked_sint64.iter().rev() {
+            w.write_zigzag64(*elem);
+            w.write_key(0x2f0);
+        }
+
+        // field 93: unpacked_sint32
+        for elem in self.unpacked_sint32.iter().rev() {
+            w.write_zigzag32(*elem);
+            w.write_key(0x2e8);
+        }
+
+        // field 92: unpacked_uint64
+        for elem in self.unpacked_uint64.iter().rev() {
+            w.write_varint(*elem);
+            w.write_key(0x2e0);
+        }
+
+        // field 91: unpacked_uint32
+        for elem in self.unpacked_uint32.iter().rev() {
+            w.write_varint(*elem as u64);
+            w.write_key(0x2d8);
+        }
+
+        // field 90: unpacked_int64
+        for elem in self.unpacked_int64.iter().rev() {
+            w.write_int64(*elem);
+            w.write_key(0x2d0);
+        }
+
+        // field 89: unpacked_int32
+        for elem in self.unpacked_int32.iter().rev() {
+            w.write_int32(*elem);
+            w.write_key(0x2c8);
+        }
+
+        // field 88: packed_nested_enum
+        if !self.packed_nested_enum.is_empty() {
+            w.write_nested(88, |w| {
+                for elem in self.packed_nested_enum.iter().rev() {
+                    w.write_int32(*elem);
+                }
+            });
+        }
+
+        // field 87: packed_bool
+        if !self.packed_bool.is_empty() {
+            w.write_nested(87, |w| {
+                for elem in self.packed_bool.iter().rev() {
+                    w.write_bool(*elem);
+                }
+            });
+        }
+
+        // field 86: packed_double
+        if !self.packed_double.is_empty() {
+            w.write_nested(86, |w| {
+                for elem in self.packed_double.iter().rev() {
+                    w.write_double(*elem);
+                }
+            });
+        }
+
+        // field 85: packed_float
+        if !self.packed_float.is_empty() {
+            w.write_nested(85, |w| {
+                for elem in self.packed_float.iter().rev() {
+                    w.write_float(*elem);
+                }
+            });
+        }
+
+        // field 84: packed_sfixed64
+        if !self.packed_sfixed64.is_empty() {
+            w.write_nested(84, |w| {
+                for elem in self.packed_sfixed64.iter().rev() {
+                    w.write_sfixed64(*elem);
+                }
+            });
+        }
+
+        // field 83: packed_sfixed32
+        if !self.packed_sfixed32.is_empty() {
+            w.write_nested(83, |w| {
+                for elem in self.packed_sfixed32.iter().rev() {
+                    w.write_sfixed32(*elem);
+                }
+            });
+        }
+
+        // field 82: packed_fixed64
+        if !self.packed_fixed64.is_empty() {
+            w.write_nested(82, |w| {
+                for elem in self.packed_fixed64.iter().rev() {
+                    w.write_fixed64(*elem);
+                }
+            });
+        }
+
+        // field 81: packed_fixed32
+        if !self.packed_fixed32.is_empty() {
+            w.write_nested(81, |w| {
+                for elem in self.packed_fixed32.iter().rev() {
+                    w.write_fixed32(*elem);
+                }
+            });
+        }
+
+        // field 80: packed_sint64
+        if !self.packed_sint64.is_empty() {
+            w.write_nested(80, |w| {
+                for elem in self.packed_sint64.iter().rev() {
+                    w.write_zigzag64(*elem);
+                }
+            });
+        }
+
+        // field 79: packed_sint32
+        if !self.packed_sint32.is_empty() {
+            w.write_nested(79, |w| {
+                for elem in self.packed_sint32.iter().rev() {
+                    w.write_zigzag32(*elem);
+                }
+            });
+        }
+
+        // field 78: packed_uint64
+        if !self.packed_uint64.is_empty() {
+            w.write_nested(78, |w| {
+                for elem in self.packed_uint64.iter().rev() {
+                    w.write_varint(*elem);
+                }
+            });
+        }
+
+        // field 77: packed_uint32
+        if !self.packed_uint32.is_empty() {
+            w.write_nested(77, |w| {
+                for elem in self.packed_uint32.iter().rev() {
+                    w.write_varint(*elem as u64);
+                }
+            });
+        }
+
+        // field 76: packed_int64
+        if !self.packed_int64.is_empty() {
+            w.write_nested(76, |w| {
+                for elem in self.packed_int64.iter().rev() {
+                    w.write_int64(*elem);
+                }
+            });
+        }
+
+        // field 75: packed_int32
+        if !self.packed_int32.is_empty() {
+            w.write_nested(75, |w| {
+                for elem in self.packed_int32.iter().rev() {
+                    w.write_int32(*elem);
+                }
+            });
+        }
+
+        // field 74: map_string_foreign_enum
+        for (key, value) in self.map_string_foreign_enum.iter().rev() {
+            w.write_nested(74, |w| {
+                w.write_int32(*value);
+                w.write_key(0x10);
+                w.write_string(key);
+                w.write_key(0x0a);
+            });
+        }
+
+        // field 73: map_string_nested_enum
+        for (key, value) in self.map_string_nested_enum.iter().rev() {
+            w.write_nested(73, |w| {
+                w.write_int32(*value);
+                w.write_key(0x10);
+                w.write_string(key);
+                w.write_key(0x0a);
+            });
+        }
+
+        // field 72: map_string_foreign_message
+        for (key, value) in self.map_string_foreign_message.iter().rev() {
+            w.write_nested(72, |w| {
+                w.write_message(2, value);
+                w.write_string(key);
+                w.write_key(0x0a);
+            });
+        }
+
+        // field 71: map_string_nested_message
+        for (key, value) in self.map_string_nested_message.iter().rev() {
+            w.write_nested(71, |w| {
+                w.write_message(2, value);
+                w.write_string(key);
+                w.write_key(0x0a);
+            });
+        }
+
+        // field 70: map_string_bytes
+        for (key, value) in self.map_string_bytes.iter().rev() {
+            w.write_nested(70, |w| {
+                w.write_bytes(value);
+                w.write_key(0x12);
+                w.write_string(key);
+                w.write_key(0x0a);
+            });
+        }
+
+        // field 69: map_string_string
+        for (key, value) in self.map_string_string.iter().rev() {
+            w.write_nested(69, |w| {
+                w.write_string(value);
+                w.write_key(0x12);
+                w.write_string(key);
+                w.write_key(0x0a);
+            });
+        }
+
+        // field 68: map_bool_bool
+        for (key, value) in self.map_bool_bool.iter().rev() {
+            w.write_nested(68, |w| {
+                w.write_bool(*value);
+                w.write_key(0x10);
+                w.write_bool(*key);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 67: map_int32_double
+        for (key, value) in self.map_int32_double.iter().rev() {
+            w.write_nested(67, |w| {
+                w.write_double(*value);
+                w.write_key(0x11);
+                w.write_int32(*key);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 66: map_int32_float
+        for (key, value) in self.map_int32_float.iter().rev() {
+            w.write_nested(66, |w| {
+                w.write_float(*value);
+                w.write_key(0x15);
+                w.write_int32(*key);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 65: map_sfixed64_sfixed64
+        for (key, value) in self.map_sfixed64_sfixed64.iter().rev() {
+            w.write_nested(65, |w| {
+                w.write_sfixed64(*value);
+                w.write_key(0x11);
+                w.write_sfixed64(*key);
+                w.write_key(0x09);
+            });
+        }
+
+        // field 64: map_sfixed32_sfixed32
+        for (key, value) in self.map_sfixed32_sfixed32.iter().rev() {
+            w.write_nested(64, |w| {
+                w.write_sfixed32(*value);
+                w.write_key(0x15);
+                w.write_sfixed32(*key);
+                w.write_key(0x0d);
+            });
+        }
+
+        // field 63: map_fixed64_fixed64
+        for (key, value) in self.map_fixed64_fixed64.iter().rev() {
+            w.write_nested(63, |w| {
+                w.write_fixed64(*value);
+                w.write_key(0x11);
+                w.write_fixed64(*key);
+                w.write_key(0x09);
+            });
+        }
+
+        // field 62: map_fixed32_fixed32
+        for (key, value) in self.map_fixed32_fixed32.iter().rev() {
+            w.write_nested(62, |w| {
+                w.write_fixed32(*value);
+                w.write_key(0x15);
+                w.write_fixed32(*key);
+                w.write_key(0x0d);
+            });
+        }
+
+        // field 61: map_sint64_sint64
+        for (key, value) in self.map_sint64_sint64.iter().rev() {
+            w.write_nested(61, |w| {
+                w.write_zigzag64(*value);
+                w.write_key(0x10);
+                w.write_zigzag64(*key);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 60: map_sint32_sint32
+        for (key, value) in self.map_sint32_sint32.iter().rev() {
+            w.write_nested(60, |w| {
+                w.write_zigzag32(*value);
+                w.write_key(0x10);
+                w.write_zigzag32(*key);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 59: map_uint64_uint64
+        for (key, value) in self.map_uint64_uint64.iter().rev() {
+            w.write_nested(59, |w| {
+                w.write_varint(*value);
+                w.write_key(0x10);
+                w.write_varint(*key);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 58: map_uint32_uint32
+        for (key, value) in self.map_uint32_uint32.iter().rev() {
+            w.write_nested(58, |w| {
+                w.write_varint(*value as u64);
+                w.write_key(0x10);
+                w.write_varint(*key as u64);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 57: map_int64_int64
+        for (key, value) in self.map_int64_int64.iter().rev() {
+            w.write_nested(57, |w| {
+                w.write_int64(*value);
+                w.write_key(0x10);
+                w.write_int64(*key);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 56: map_int32_int32
+        for (key, value) in self.map_int32_int32.iter().rev() {
+            w.write_nested(56, |w| {
+                w.write_int32(*value);
+                w.write_key(0x10);
+                w.write_int32(*key);
+                w.write_key(0x08);
+            });
+        }
+
+        // field 55: repeated_cord
+        for elem in self.repeated_cord.iter().rev() {
+            w.write_string(elem);
+            w.write_key(0x1ba);
+        }
+
+        // field 54: repeated_string_piece
+        for elem in self.repeated_string_piece.iter().rev() {
+            w.write_string(elem);
+            w.write_key(0x1b2);
+        }
+
+        // field 52: repeated_foreign_enum
+        if !self.repeated_foreign_enum.is_empty() {
+            w.write_nested(52, |w| {
+                for elem in self.repeated_foreign_enum.iter().rev() {
+                    w.write_int32(*elem);
+                }
+            });
+        }
+
+        // field 51: repeated_nested_enum
+        if !self.repeated_nested_enum.is_empty() {
+            w.write_nested(51, |w| {
+                for elem in self.repeated_nested_enum.iter().rev() {
+                    w.write_int32(*elem);
+                }
+            });
+        }
+
+        // field 49: repeated_foreign_message
+        for elem in self.repeated_foreign_message.iter().rev() {
+            w.write_message(49, elem);
+        }
+
+        // field 48: repeated_nested_message
+        for elem in self.repeated_nested_message.iter().rev() {
+            w.write_message(48, elem);
+        }
+
+        // field 45: repeated_bytes
+        for elem in self.repeated_bytes.iter().rev() {
+            w.write_bytes(elem);
+            w.write_key(0x16a);
+        }
+
+        // field 44: repeated_string
+        for elem in self.repeated_string.iter().rev() {
+            w.write_string(elem);
+            w.write_key(0x162);
+        }
+
+        // field 43: repeated_bool
+        if !self.repeated_bool.is_empty() {
+            w.write_nested(43, |w| {
+                for elem in self.repeated_bool.iter().rev() {
+                    w.write_bool(*elem);
+                }
+            });
+        }
+
+        // field 42: repeated_double
+        if !self.repeated_double.is_empty() {
+            w.write_nested(42, |w| {
+                for elem in self.repeated_double.iter().rev() {
+                    w.write_double(*elem);
+                }
+            });
+        }
+
+        // field 41: repeated_float
+        if !self.repeated_float.is_empty() {
+            w.write_nested(41, |w| {
+                for elem in self.repeated_float.iter().rev() {
+                    w.write_float(*elem);
+                }
+            });
+        }
+
+        // field 40: repeated_sfixed64
+        if !self.repeated_sfixed64.is_empty() {
+            w.write_nested(40, |w| {
+                for elem in self.repeated_sfixed64.iter().rev() {
+                    w.write_sfixed64(*elem);
+                }
+            });
+        }
+
+        // field 39: repeated_sfixed32
+        if !self.repeated_sfixed32.is_empty() {
+            w.write_nested(39, |w| {
+                for elem in self.repeated_sfixed32.iter().rev() {
+                    w.write_sfixed32(*elem);
+                }
+            });
+        }
+
+        // field 38: repeated_fixed64
+        if !self.repeated_fixed64.is_empty() {
+            w.write_nested(38, |w| {
+                for elem in self.repeated_fixed64.iter().rev() {
+                    w.write_fixed64(*elem);
+                }
+            });
+        }
+
+        // field 37: repeated_fixed32
+        if !self.repeated_fixed32.is_empty() {
+            w.write_nested(37, |w| {
+                for elem in self.repeated_fixed32.iter().rev() {
+                    w.write_fixed32(*elem);
+                }
+            });
+        }
+
+        // field 36: repeated_sint64
+        if !self.repeated_sint64.is_empty() {
+            w.write_nested(36, |w| {
+                for elem in self.repeated_sint64.iter().rev() {
+                    w.write_zigzag64(*elem);
+                }
+            });
+        }
+
+        // field 35: repeated_sint32
+        if !self.repeated_sint32.is_empty() {
+            w.write_nested(35, |w| {
+                for elem in self.repeated_sint32.iter().rev() {
+                    w.write_zigzag32(*elem);
+                }
+            });
+        }
+
+        // field 34: repeated_uint64
+        if !self.repeated_uint64.is_empty() {
+            w.write_nested(34, |w| {
+                for elem in self.repeated_uint64.iter().rev() {
+                    w.write_varint(*elem);
+                }
+            });
+        }
+
+        // field 33: repeated_uint32
+        if !self.repeated_uint32.is_empty() {
+            w.write_nested(33, |w| {
+                for elem in self.repeated_uint32.iter().rev() {
+                    w.write_varint(*elem as u64);
+                }
+            });
+        }
+
+        // field 32: repeated_int64
+        if !self.repeated_int64.is_empty() {
+            w.write_nested(32, |w| {
+                for elem in self.repeated_int64.iter().rev() {
+                    w.write_int64(*elem);
+                }
+            });
+        }
+
+        // field 31: repeated_int32
+        if !self.repeated_int32.is_empty() {
+            w.write_nested(31, |w| {
+                for elem in self.repeated_int32.iter().rev() {
+                    w.write_int32(*elem);
+                }
+            });
+        }
+
+        // field 27: recursive_message
+        if let Some(elem) = &self.recursive_message {
+            w.write_message(27, &**elem);
+        }
+
+        // field 25: optional_cord
+        if !self.optional_cord.is_empty() {
+            w.write_string(&self.optional_cord);
+            w.write_key(0xca);
+        }
+
+        // field 24: optional_string_piece
+        if !self.optional_string_piece.is_empty() {
+            w.write_string(&self.optional_string_piece);
+            w.write_key(0xc2);
+        }
+
+        // field 23: optional_aliased_enum
+        if self.optional_aliased_enum != 0 {
+            w.write_int32(self.optional_aliased_enum);
+            w.write_key(0xb8);
+        }
+
+        // field 22: optional_foreign_enum
+        if self.optional_foreign_enum != 0 {
+            w.write_int32(self.optional_foreign_enum);
+            w.write_key(0xb0);
+        }
+
+        // field 21: optional_nested_enum
+        if self.optional_nested_enum != 0 {
+            w.write_int32(self.optional_nested_enum);
+            w.write_key(0xa8);
+        }
+
+        // field 19: optional_foreign_message
+        w.write_message(19, &self.optional_foreign_message);
+
+        // field 18: optional_nested_message
+        if let Some(elem) = &self.optional_nested_message {
+            w.write_message(18, &**elem);
+        }
+
+        // field 15: optional_bytes
+        if !self.optional_bytes.is_empty() {
+            w.write_bytes(&self.optional_bytes);
+            w.write_key(0x7a);
+        }
+
+        // field 14: optional_string
+        if !self.optional_string.is_empty() {
+            w.write_string(&self.optional_string);
+            w.write_key(0x72);
+        }
+
+        // field 13: optional_bool
+        if self.optional_bool {
+            w.write_bool(self.optional_bool);
+            w.write_key(0x68);
+        }
+
+        // field 12: optional_double
+        if self.optional_double.to_bits() != 0 {
+            w.write_double(self.optional_double);
+            w.write_key(0x61);
+        }
+
+        // field 11: optional_float
+        if self.optional_float.to_bits() != 0 {
+            w.write_float(self.optional_float);
+            w.write_key(0x5d);
+        }
+
+        // field 10: optional_sfixed64
+        if self.optional_sfixed64 != 0 {
+            w.write_sfixed64(self.optional_sfixed64);
+            w.write_key(0x51);
+        }
+
+        // field 9: optional_sfixed32
+        if self.optional_sfixed32 != 0 {
+            w.write_sfixed32(self.optional_sfixed32);
+            w.write_key(0x4d);
+        }
+
+        // field 8: optional_fixed64
+        if self.optional_fixed64 != 0 {
+            w.write_fixed64(self.optional_fixed64);
+            w.write_key(0x41);
+        }
+
+        // field 7: optional_fixed32
+        if self.optional_fixed32 != 0 {
+            w.write_fixed32(self.optional_fixed32);
+            w.write_key(0x3d);
+        }
+
+        // field 6: optional_sint64
+        if self.optional_sint64 != 0 {
+            w.write_zigzag64(self.optional_sint64);
+            w.write_key(0x30);
+        }
+
+        // field 5: optional_sint32
+        if self.optional_sint32 != 0 {
+            w.write_zigzag32(self.optional_sint32);
+            w.write_key(0x28);
+        }
+
+        // field 4: optional_uint64
+        if self.optional_uint64 != 0 {
+            w.write_varint(self.optional_uint64);
+            w.write_key(0x20);
+        }
+
+        // field 3: optional_uint32
+        if self.optional_uint32 != 0 {
+            w.write_varint(self.optional_uint32 as u64);
+            w.write_key(0x18);
+        }
+
+        // field 2: optional_int64
+        if self.optional_int64 != 0 {
+            w.write_int64(self.optional_int64);
+            w.write_key(0x10);
+        }
+
+        // field 1: optional_int32
+        if self.optional_int32 != 0 {
+            w.write_int32(self.optional_int32);
+            w.write_key(0x08);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if self.optional_int32 != 0 {
+            len += key_len(1) + int32_len(self.optional_int32);
+        }
+        if self.optional_int64 != 0 {
+            len += key_len(2) + int64_len(self.optional_int64);
+        }
+        if self.optional_uint32 != 0 {
+            len += key_len(3) + varint_len(self.optional_uint32 as u64);
+        }
+        if self.optional_uint64 != 0 {
+            len += key_len(4) + varint_len(self.optional_uint64);
+        }
+        if self.optional_sint32 != 0 {
+            len += key_len(5) + zigzag32_len(self.optional_sint32);
+        }
+        if self.optional_sint64 != 0 {
+            len += key_len(6) + zigzag64_len(self.optional_sint64);
+        }
+        if self.optional_fixed32 != 0 {
+            len += key_len(7) + 4;
+        }
+        if self.optional_fixed64 != 0 {
+            len += key_len(8) + 8;
+        }
+        if self.optional_sfixed32 != 0 {
+            len += key_len(9) + 4;
+        }
+        if self.optional_sfixed64 != 0 {
+            len += key_len(10) + 8;
+        }
+        if self.optional_float.to_bits() != 0 {
+            len += key_len(11) + 4;
+        }
+        if self.optional_double.to_bits() != 0 {
+            len += key_len(12) + 8;
+        }
+        if self.optional_bool {
+            len += key_len(13) + 1;
+        }
+        if !self.optional_string.is_empty() {
+            len += key_len(14) + bytes_len(self.optional_string.len());
+        }
+        if !self.optional_bytes.is_empty() {
+            len += key_len(15) + bytes_len(self.optional_bytes.len());
+        }
+        if let Some(elem) = &self.optional_nested_message {
+            len += key_len(18) + bytes_len(elem.encoded_len());
+        }
+        len += key_len(19) + bytes_len(self.optional_foreign_message.encoded_len());
+        if self.optional_nested_enum != 0 {
+            len += key_len(21) + int32_len(self.optional_nested_enum);
+        }
+        if self.optional_foreign_enum != 0 {
+            len += key_len(22) + int32_len(self.optional_foreign_enum);
+        }
+        if self.optional_aliased_enum != 0 {
+            len += key_len(23) + int32_len(self.optional_aliased_enum);
+        }
+        if !self.optional_string_piece.is_empty() {
+            len += key_len(24) + bytes_len(self.optional_string_piece.len());
+        }
+        if !self.optional_cord.is_empty() {
+            len += key_len(25) + bytes_len(self.optional_cord.len());
+        }
+        if let Some(elem) = &self.recursive_message {
+            len += key_len(27) + bytes_len(elem.encoded_len());
+        }
+        if !self.repeated_int32.is_empty() {
+            len += key_len(31) + bytes_len(self.repeated_int32.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_int64.is_empty() {
+            len += key_len(32) + bytes_len(self.repeated_int64.iter().map(|elem| int64_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_uint32.is_empty() {
+            len += key_len(33) + bytes_len(self.repeated_uint32.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+        }
+        if !self.repeated_uint64.is_empty() {
+            len += key_len(34) + bytes_len(self.repeated_uint64.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_sint32.is_empty() {
+            len += key_len(35) + bytes_len(self.repeated_sint32.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_sint64.is_empty() {
+            len += key_len(36) + bytes_len(self.repeated_sint64.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_fixed32.is_empty() {
+            len += key_len(37) + bytes_len(self.repeated_fixed32.len() * 4);
+        }
+        if !self.repeated_fixed64.is_empty() {
+            len += key_len(38) + bytes_len(self.repeated_fixed64.len() * 8);
+        }
+        if !self.repeated_sfixed32.is_empty() {
+            len += key_len(39) + bytes_len(self.repeated_sfixed32.len() * 4);
+        }
+        if !self.repeated_sfixed64.is_empty() {
+            len += key_len(40) + bytes_len(self.repeated_sfixed64.len() * 8);
+        }
+        if !self.repeated_float.is_empty() {
+            len += key_len(41) + bytes_len(self.repeated_float.len() * 4);
+        }
+        if !self.repeated_double.is_empty() {
+            len += key_len(42) + bytes_len(self.repeated_double.len() * 8);
+        }
+        if !self.repeated_bool.is_empty() {
+            len += key_len(43) + bytes_len(self.repeated_bool.len());
+        }
+        for elem in &self.repeated_string {
+            len += key_len(44) + bytes_len(elem.len());
+        }
+        for elem in &self.repeated_bytes {
+            len += key_len(45) + bytes_len(elem.len());
+        }
+        for elem in &self.repeated_nested_message {
+            len += key_len(48) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_foreign_message {
+            len += key_len(49) + bytes_len(elem.encoded_len());
+        }
+        if !self.repeated_nested_enum.is_empty() {
+            len += key_len(51) + bytes_len(self.repeated_nested_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_foreign_enum.is_empty() {
+            len += key_len(52) + bytes_len(self.repeated_foreign_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        for elem in &self.repeated_string_piece {
+            len += key_len(54) + bytes_len(elem.len());
+        }
+        for elem in &self.repeated_cord {
+            len += key_len(55) + bytes_len(elem.len());
+        }
+        for (key, value) in &self.map_int32_int32 {
+            len += key_len(56) + bytes_len(key_len(1) + int32_len(*key) + key_len(2) + int32_len(*value));
+        }
+        for (key, value) in &self.map_int64_int64 {
+            len += key_len(57) + bytes_len(key_len(1) + int64_len(*key) + key_len(2) + int64_len(*value));
+        }
+        for (key, value) in &self.map_uint32_uint32 {
+            len += key_len(58) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + varint_len(*value as u64));
+        }
+        for (key, value) in &self.map_uint64_uint64 {
+            len += key_len(59) + bytes_len(key_len(1) + varint_len(*key) + key_len(2) + varint_len(*value));
+        }
+        for (key, value) in &self.map_sint32_sint32 {
+            len += key_len(60) + bytes_len(key_len(1) + zigzag32_len(*key) + key_len(2) + zigzag32_len(*value));
+        }
+        for (key, value) in &self.map_sint64_sint64 {
+            len += key_len(61) + bytes_len(key_len(1) + zigzag64_len(*key) + key_len(2) + zigzag64_len(*value));
+        }
+        len += self.map_fixed32_fixed32.len() * (key_len(62) + bytes_len(key_len(1) + 4 + key_len(2) + 4));
+        len += self.map_fixed64_fixed64.len() * (key_len(63) + bytes_len(key_len(1) + 8 + key_len(2) + 8));
+        len += self.map_sfixed32_sfixed32.len() * (key_len(64) + bytes_len(key_len(1) + 4 + key_len(2) + 4));
+        len += self.map_sfixed64_sfixed64.len() * (key_len(65) + bytes_len(key_len(1) + 8 + key_len(2) + 8));
+        for key in self.map_int32_float.keys() {
+            len += key_len(66) + bytes_len(key_len(1) + int32_len(*key) + key_len(2) + 4);
+        }
+        for key in self.map_int32_double.keys() {
+            len += key_len(67) + bytes_len(key_len(1) + int32_len(*key) + key_len(2) + 8);
+        }
+        len += self.map_bool_bool.len() * (key_len(68) + bytes_len(key_len(1) + 1 + key_len(2) + 1));
+        for (key, value) in &self.map_string_string {
+            len += key_len(69) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+        }
+        for (key, value) in &self.map_string_bytes {
+            len += key_len(70) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+        }
+        for (key, value) in &self.map_string_nested_message {
+            len += key_len(71) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len()));
+        }
+        for (key, value) in &self.map_string_foreign_message {
+            len += key_len(72) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len()));
+        }
+        for (key, value) in &self.map_string_nested_enum {
+            len += key_len(73) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value));
+        }
+        for (key, value) in &self.map_string_foreign_enum {
+            len += key_len(74) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value));
+        }
+        if !self.packed_int32.is_empty() {
+            len += key_len(75) + bytes_len(self.packed_int32.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_int64.is_empty() {
+            len += key_len(76) + bytes_len(self.packed_int64.iter().map(|elem| int64_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_uint32.is_empty() {
+            len += key_len(77) + bytes_len(self.packed_uint32.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+        }
+        if !self.packed_uint64.is_empty() {
+            len += key_len(78) + bytes_len(self.packed_uint64.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_sint32.is_empty() {
+            len += key_len(79) + bytes_len(self.packed_sint32.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_sint64.is_empty() {
+            len += key_len(80) + bytes_len(self.packed_sint64.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_fixed32.is_empty() {
+            len += key_len(81) + bytes_len(self.packed_fixed32.len() * 4);
+        }
+        if !self.packed_fixed64.is_empty() {
+            len += key_len(82) + bytes_len(self.packed_fixed64.len() * 8);
+        }
+        if !self.packed_sfixed32.is_empty() {
+            len += key_len(83) + bytes_len(self.packed_sfixed32.len() * 4);
+        }
+        if !self.packed_sfixed64.is_empty() {
+            len += key_len(84) + bytes_len(self.packed_sfixed64.len() * 8);
+        }
+        if !self.packed_float.is_empty() {
+            len += key_len(85) + bytes_len(self.packed_float.len() * 4);
+        }
+        if !self.packed_double.is_empty() {
+            len += key_len(86) + bytes_len(self.packed_double.len() * 8);
+        }
+        if !self.packed_bool.is_empty() {
+            len += key_len(87) + bytes_len(self.packed_bool.len());
+        }
+        if !self.packed_nested_enum.is_empty() {
+            len += key_len(88) + bytes_len(self.packed_nested_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        for elem in &self.unpacked_int32 {
+            len += key_len(89) + int32_len(*elem);
+        }
+        for elem in &self.unpacked_int64 {
+            len += key_len(90) + int64_len(*elem);
+        }
+        for elem in &self.unpacked_uint32 {
+            len += key_len(91) + varint_len(*elem as u64);
+        }
+        for elem in &self.unpacked_uint64 {
+            len += key_len(92) + varint_len(*elem);
+        }
+        for elem in &self.unpacked_sint32 {
+            len += key_len(93) + zigzag32_len(*elem);
+        }
+        for elem in &self.unpacked_sint64 {
+            len += key_len(94) + zigzag64_len(*elem);
+        }
+        len += self.unpacked_fixed32.len() * (key_len(95) + 4);
+        len += self.unpacked_fixed64.len() * (key_len(96) + 8);
+        len += self.unpacked_sfixed32.len() * (key_len(97) + 4);
+        len += self.unpacked_sfixed64.len() * (key_len(98) + 8);
+        len += self.unpacked_float.len() * (key_len(99) + 4);
+        len += self.unpacked_double.len() * (key_len(100) + 8);
+        len += self.unpacked_bool.len() * (key_len(101) + 1);
+        for elem in &self.unpacked_nested_enum {
+            len += key_len(102) + int32_len(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofUint32(elem) = &self.oneof_field {
+            len += key_len(111) + varint_len(*elem as u64);
+        }
+        if let TestAllTypesProto3OneofField::OneofNestedMessage(elem) = &self.oneof_field {
+            len += key_len(112) + bytes_len(elem.encoded_len());
+        }
+        if let TestAllTypesProto3OneofField::OneofString(elem) = &self.oneof_field {
+            len += key_len(113) + bytes_len(elem.len());
+        }
+        if let TestAllTypesProto3OneofField::OneofBytes(elem) = &self.oneof_field {
+            len += key_len(114) + bytes_len(elem.len());
+        }
+        if let TestAllTypesProto3OneofField::OneofBool(_) = &self.oneof_field {
+            len += key_len(115) + 1;
+        }
+        if let TestAllTypesProto3OneofField::OneofUint64(elem) = &self.oneof_field {
+            len += key_len(116) + varint_len(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofFloat(_) = &self.oneof_field {
+            len += key_len(117) + 4;
+        }
+        if let TestAllTypesProto3OneofField::OneofDouble(_) = &self.oneof_field {
+            len += key_len(118) + 8;
+        }
+        if let TestAllTypesProto3OneofField::OneofEnum(elem) = &self.oneof_field {
+            len += key_len(119) + int32_len(*elem);
+        }
+        if let Some(elem) = &self.optional_bool_wrapper {
+            len += key_len(201) + bytes_len(BoolValue::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_int32_wrapper {
+            len += key_len(202) + bytes_len(Int32Value::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_int64_wrapper {
+            len += key_len(203) + bytes_len(Int64Value::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_uint32_wrapper {
+            len += key_len(204) + bytes_len(UInt32Value::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_uint64_wrapper {
+            len += key_len(205) + bytes_len(UInt64Value::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_float_wrapper {
+            len += key_len(206) + bytes_len(FloatValue::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_double_wrapper {
+            len += key_len(207) + bytes_len(DoubleValue::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_string_wrapper {
+            len += key_len(208) + bytes_len(StringValue::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_bytes_wrapper {
+            len += key_len(209) + bytes_len(BytesValue::value_len(elem));
+        }
+        for elem in &self.repeated_bool_wrapper {
+            len += key_len(211) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_int32_wrapper {
+            len += key_len(212) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_int64_wrapper {
+            len += key_len(213) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_uint32_wrapper {
+            len += key_len(214) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_uint64_wrapper {
+            len += key_len(215) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_float_wrapper {
+            len += key_len(216) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_double_wrapper {
+            len += key_len(217) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_string_wrapper {
+            len += key_len(218) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_bytes_wrapper {
+            len += key_len(219) + bytes_len(elem.encoded_len());
+        }
+        len += key_len(301) + bytes_len(self.optional_duration.encoded_len());
+        len += key_len(302) + bytes_len(self.optional_timestamp.encoded_len());
+        len += key_len(305) + bytes_len(self.optional_any.encoded_len());
+        for elem in &self.repeated_duration {
+            len += key_len(311) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_timestamp {
+            len += key_len(312) + bytes_len(elem.encoded_len());
+        }
+        for elem in &self.repeated_any {
+            len += key_len(315) + bytes_len(elem.encoded_len());
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if self.optional_int32 != 0 {
+            w.write_key(0x08)?;
+            w.write_int32(self.optional_int32)?;
+        }
+        if self.optional_int64 != 0 {
+            w.write_key(0x10)?;
+            w.write_int64(self.optional_int64)?;
+        }
+        if self.optional_uint32 != 0 {
+            w.write_key(0x18)?;
+            w.write_varint(self.optional_uint32 as u64)?;
+        }
+        if self.optional_uint64 != 0 {
+            w.write_key(0x20)?;
+            w.write_varint(self.optional_uint64)?;
+        }
+        if self.optional_sint32 != 0 {
+            w.write_key(0x28)?;
+            w.write_zigzag32(self.optional_sint32)?;
+        }
+        if self.optional_sint64 != 0 {
+            w.write_key(0x30)?;
+            w.write_zigzag64(self.optional_sint64)?;
+        }
+        if self.optional_fixed32 != 0 {
+            w.write_key(0x3d)?;
+            w.write_fixed32(self.optional_fixed32)?;
+        }
+        if self.optional_fixed64 != 0 {
+            w.write_key(0x41)?;
+            w.write_fixed64(self.optional_fixed64)?;
+        }
+        if self.optional_sfixed32 != 0 {
+            w.write_key(0x4d)?;
+            w.write_sfixed32(self.optional_sfixed32)?;
+        }
+        if self.optional_sfixed64 != 0 {
+            w.write_key(0x51)?;
+            w.write_sfixed64(self.optional_sfixed64)?;
+        }
+        if self.optional_float.to_bits() != 0 {
+            w.write_key(0x5d)?;
+            w.write_float(self.optional_float)?;
+        }
+        if self.optional_double.to_bits() != 0 {
+            w.write_key(0x61)?;
+            w.write_double(self.optional_double)?;
+        }
+        if self.optional_bool {
+            w.write_key(0x68)?;
+            w.write_bool(self.optional_bool)?;
+        }
+        if !self.optional_string.is_empty() {
+            w.write_key(0x72)?;
+            w.write_string(&self.optional_string)?;
+        }
+        if !self.optional_bytes.is_empty() {
+            w.write_key(0x7a)?;
+            w.write_bytes(&self.optional_bytes)?;
+        }
+        if let Some(elem) = &self.optional_nested_message {
+            w.write_message(18, &**elem)?;
+        }
+        w.write_message(19, &self.optional_foreign_message)?;
+        if self.optional_nested_enum != 0 {
+            w.write_key(0xa8)?;
+            w.write_int32(self.optional_nested_enum)?;
+        }
+        if self.optional_foreign_enum != 0 {
+            w.write_key(0xb0)?;
+            w.write_int32(self.optional_foreign_enum)?;
+        }
+        if self.optional_aliased_enum != 0 {
+            w.write_key(0xb8)?;
+            w.write_int32(self.optional_aliased_enum)?;
+        }
+        if !self.optional_string_piece.is_empty() {
+            w.write_key(0xc2)?;
+            w.write_string(&self.optional_string_piece)?;
+        }
+        if !self.optional_cord.is_empty() {
+            w.write_key(0xca)?;
+            w.write_string(&self.optional_cord)?;
+        }
+        if let Some(elem) = &self.recursive_message {
+            w.write_message(27, &**elem)?;
+        }
+        if !self.repeated_int32.is_empty() {
+            w.write_key(0xfa)?;
+            w.write_varint(self.repeated_int32.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_int32 {
+                w.write_int32(*elem)?;
+            }
+        }
+        if !self.repeated_int64.is_empty() {
+            w.write_key(0x102)?;
+            w.write_varint(self.repeated_int64.iter().map(|elem| int64_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_int64 {
+                w.write_int64(*elem)?;
+            }
+        }
+        if !self.repeated_uint32.is_empty() {
+            w.write_key(0x10a)?;
+            w.write_varint(self.repeated_uint32.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_uint32 {
+                w.write_varint(*elem as u64)?;
+            }
+        }
+        if !self.repeated_uint64.is_empty() {
+            w.write_key(0x112)?;
+            w.write_varint(self.repeated_uint64.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_uint64 {
+                w.write_varint(*elem)?;
+            }
+        }
+        if !self.repeated_sint32.is_empty() {
+            w.write_key(0x11a)?;
+            w.write_varint(self.repeated_sint32.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_sint32 {
+                w.write_zigzag32(*elem)?;
+            }
+        }
+        if !self.repeated_sint64.is_empty() {
+            w.write_key(0x122)?;
+            w.write_varint(self.repeated_sint64.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_sint64 {
+                w.write_zigzag64(*elem)?;
+            }
+        }
+        if !self.repeated_fixed32.is_empty() {
+            w.write_key(0x12a)?;
+            w.write_varint((self.repeated_fixed32.len() * 4) as u64)?;
+            for elem in &self.repeated_fixed32 {
+                w.write_fixed32(*elem)?;
+            }
+        }
+        if !self.repeated_fixed64.is_empty() {
+            w.write_key(0x132)?;
+            w.write_varint((self.repeated_fixed64.len() * 8) as u64)?;
+            for elem in &self.repeated_fixed64 {
+                w.write_fixed64(*elem)?;
+            }
+        }
+        if !self.repeated_sfixed32.is_empty() {
+            w.write_key(0x13a)?;
+            w.write_varint((self.repeated_sfixed32.len() * 4) as u64)?;
+            for elem in &self.repeated_sfixed32 {
+                w.write_sfixed32(*elem)?;
+            }
+        }
+        if !self.repeated_sfixed64.is_empty() {
+            w.write_key(0x142)?;
+            w.write_varint((self.repeated_sfixed64.len() * 8) as u64)?;
+            for elem in &self.repeated_sfixed64 {
+                w.write_sfixed64(*elem)?;
+            }
+        }
+        if !self.repeated_float.is_empty() {
+            w.write_key(0x14a)?;
+            w.write_varint((self.repeated_float.len() * 4) as u64)?;
+            for elem in &self.repeated_float {
+                w.write_float(*elem)?;
+            }
+        }
+        if !self.repeated_double.is_empty() {
+            w.write_key(0x152)?;
+            w.write_varint((self.repeated_double.len() * 8) as u64)?;
+            for elem in &self.repeated_double {
+                w.write_double(*elem)?;
+            }
+        }
+        if !self.repeated_bool.is_empty() {
+            w.write_key(0x15a)?;
+            w.write_varint(self.repeated_bool.len() as u64)?;
+            for elem in &self.repeated_bool {
+                w.write_bool(*elem)?;
+            }
+        }
+        for elem in &self.repeated_string {
+            w.write_key(0x162)?;
+            w.write_string(elem)?;
+        }
+        for elem in &self.repeated_bytes {
+            w.write_key(0x16a)?;
+            w.write_bytes(elem)?;
+        }
+        for elem in &self.repeated_nested_message {
+            w.write_message(48, elem)?;
+        }
+        for elem in &self.repeated_foreign_message {
+            w.write_message(49, elem)?;
+        }
+        if !self.repeated_nested_enum.is_empty() {
+            w.write_key(0x19a)?;
+            w.write_varint(self.repeated_nested_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_nested_enum {
+                w.write_int32(*elem)?;
+            }
+        }
+        if !self.repeated_foreign_enum.is_empty() {
+            w.write_key(0x1a2)?;
+            w.write_varint(self.repeated_foreign_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_foreign_enum {
+                w.write_int32(*elem)?;
+            }
+        }
+        for elem in &self.repeated_string_piece {
+            w.write_key(0x1b2)?;
+            w.write_string(elem)?;
+        }
+        for elem in &self.repeated_cord {
+            w.write_key(0x1ba)?;
+            w.write_string(elem)?;
+        }
+        for (key, value) in &self.map_int32_int32 {
+            w.write_key(0x1c2)?;
+            w.write_varint((key_len(1) + int32_len(*key) + key_len(2) + int32_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_int32(*key)?;
+            w.write_key(0x10)?;
+            w.write_int32(*value)?;
+        }
+        for (key, value) in &self.map_int64_int64 {
+            w.write_key(0x1ca)?;
+            w.write_varint((key_len(1) + int64_len(*key) + key_len(2) + int64_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_int64(*key)?;
+            w.write_key(0x10)?;
+            w.write_int64(*value)?;
+        }
+        for (key, value) in &self.map_uint32_uint32 {
+            w.write_key(0x1d2)?;
+            w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + varint_len(*value as u64)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_varint(*key as u64)?;
+            w.write_key(0x10)?;
+            w.write_varint(*value as u64)?;
+        }
+        for (key, value) in &self.map_uint64_uint64 {
+            w.write_key(0x1da)?;
+            w.write_varint((key_len(1) + varint_len(*key) + key_len(2) + varint_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_varint(*key)?;
+            w.write_key(0x10)?;
+            w.write_varint(*value)?;
+        }
+        for (key, value) in &self.map_sint32_sint32 {
+            w.write_key(0x1e2)?;
+            w.write_varint((key_len(1) + zigzag32_len(*key) + key_len(2) + zigzag32_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_zigzag32(*key)?;
+            w.write_key(0x10)?;
+            w.write_zigzag32(*value)?;
+        }
+        for (key, value) in &self.map_sint64_sint64 {
+            w.write_key(0x1ea)?;
+            w.write_varint((key_len(1) + zigzag64_len(*key) + key_len(2) + zigzag64_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_zigzag64(*key)?;
+            w.write_key(0x10)?;
+            w.write_zigzag64(*value)?;
+        }
+        for (key, value) in &self.map_fixed32_fixed32 {
+            w.write_key(0x1f2)?;
+            w.write_varint((key_len(1) + 4 + key_len(2) + 4) as u64)?;
+            w.write_key(0x0d)?;
+            w.write_fixed32(*key)?;
+            w.write_key(0x15)?;
+            w.write_fixed32(*value)?;
+        }
+        for (key, value) in &self.map_fixed64_fixed64 {
+            w.write_key(0x1fa)?;
+            w.write_varint((key_len(1) + 8 + key_len(2) + 8) as u64)?;
+            w.write_key(0x09)?;
+            w.write_fixed64(*key)?;
+            w.write_key(0x11)?;
+            w.write_fixed64(*value)?;
+        }
+        for (key, value) in &self.map_sfixed32_sfixed32 {
+            w.write_key(0x202)?;
+            w.write_varint((key_len(1) + 4 + key_len(2) + 4) as u64)?;
+            w.write_key(0x0d)?;
+            w.write_sfixed32(*key)?;
+            w.write_key(0x15)?;
+            w.write_sfixed32(*value)?;
+        }
+        for (key, value) in &self.map_sfixed64_sfixed64 {
+            w.write_key(0x20a)?;
+            w.write_varint((key_len(1) + 8 + key_len(2) + 8) as u64)?;
+            w.write_key(0x09)?;
+            w.write_sfixed64(*key)?;
+            w.write_key(0x11)?;
+            w.write_sfixed64(*value)?;
+        }
+        for (key, value) in &self.map_int32_float {
+            w.write_key(0x212)?;
+            w.write_varint((key_len(1) + int32_len(*key) + key_len(2) + 4) as u64)?;
+            w.write_key(0x08)?;
+            w.write_int32(*key)?;
+            w.write_key(0x15)?;
+            w.write_float(*value)?;
+        }
+        for (key, value) in &self.map_int32_double {
+            w.write_key(0x21a)?;
+            w.write_varint((key_len(1) + int32_len(*key) + key_len(2) + 8) as u64)?;
+            w.write_key(0x08)?;
+            w.write_int32(*key)?;
+            w.write_key(0x11)?;
+            w.write_double(*value)?;
+        }
+        for (key, value) in &self.map_bool_bool {
+            w.write_key(0x222)?;
+            w.write_varint((key_len(1) + 1 + key_len(2) + 1) as u64)?;
+            w.write_key(0x08)?;
+            w.write_bool(*key)?;
+            w.write_key(0x10)?;
+            w.write_bool(*value)?;
+        }
+        for (key, value) in &self.map_string_string {
+            w.write_key(0x22a)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x12)?;
+            w.write_string(value)?;
+        }
+        for (key, value) in &self.map_string_bytes {
+            w.write_key(0x232)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x12)?;
+            w.write_bytes(value)?;
+        }
+        for (key, value) in &self.map_string_nested_message {
+            w.write_key(0x23a)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_message(2, value)?;
+        }
+        for (key, value) in &self.map_string_foreign_message {
+            w.write_key(0x242)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_message(2, value)?;
+        }
+        for (key, value) in &self.map_string_nested_enum {
+            w.write_key(0x24a)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value)) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x10)?;
+            w.write_int32(*value)?;
+        }
+        for (key, value) in &self.map_string_foreign_enum {
+            w.write_key(0x252)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value)) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x10)?;
+            w.write_int32(*value)?;
+        }
+        if !self.packed_int32.is_empty() {
+            w.write_key(0x25a)?;
+            w.write_varint(self.packed_int32.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_int32 {
+                w.write_int32(*elem)?;
+            }
+        }
+        if !self.packed_int64.is_empty() {
+            w.write_key(0x262)?;
+            w.write_varint(self.packed_int64.iter().map(|elem| int64_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_int64 {
+                w.write_int64(*elem)?;
+            }
+        }
+        if !self.packed_uint32.is_empty() {
+            w.write_key(0x26a)?;
+            w.write_varint(self.packed_uint32.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+            for elem in &self.packed_uint32 {
+                w.write_varint(*elem as u64)?;
+            }
+        }
+        if !self.packed_uint64.is_empty() {
+            w.write_key(0x272)?;
+            w.write_varint(self.packed_uint64.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_uint64 {
+                w.write_varint(*elem)?;
+            }
+        }
+        if !self.packed_sint32.is_empty() {
+            w.write_key(0x27a)?;
+            w.write_varint(self.packed_sint32.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_sint32 {
+                w.write_zigzag32(*elem)?;
+            }
+        }
+        if !self.packed_sint64.is_empty() {
+            w.write_key(0x282)?;
+            w.write_varint(self.packed_sint64.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_sint64 {
+                w.write_zigzag64(*elem)?;
+            }
+        }
+        if !self.packed_fixed32.is_empty() {
+            w.write_key(0x28a)?;
+            w.write_varint((self.packed_fixed32.len() * 4) as u64)?;
+            for elem in &self.packed_fixed32 {
+                w.write_fixed32(*elem)?;
+            }
+        }
+        if !self.packed_fixed64.is_empty() {
+            w.write_key(0x292)?;
+            w.write_varint((self.packed_fixed64.len() * 8) as u64)?;
+            for elem in &self.packed_fixed64 {
+                w.write_fixed64(*elem)?;
+            }
+        }
+        if !self.packed_sfixed32.is_empty() {
+            w.write_key(0x29a)?;
+            w.write_varint((self.packed_sfixed32.len() * 4) as u64)?;
+            for elem in &self.packed_sfixed32 {
+                w.write_sfixed32(*elem)?;
+            }
+        }
+        if !self.packed_sfixed64.is_empty() {
+            w.write_key(0x2a2)?;
+            w.write_varint((self.packed_sfixed64.len() * 8) as u64)?;
+            for elem in &self.packed_sfixed64 {
+                w.write_sfixed64(*elem)?;
+            }
+        }
+        if !self.packed_float.is_empty() {
+            w.write_key(0x2aa)?;
+            w.write_varint((self.packed_float.len() * 4) as u64)?;
+            for elem in &self.packed_float {
+                w.write_float(*elem)?;
+            }
+        }
+        if !self.packed_double.is_empty() {
+            w.write_key(0x2b2)?;
+            w.write_varint((self.packed_double.len() * 8) as u64)?;
+            for elem in &self.packed_double {
+                w.write_double(*elem)?;
+            }
+        }
+        if !self.packed_bool.is_empty() {
+            w.write_key(0x2ba)?;
+            w.write_varint(self.packed_bool.len() as u64)?;
+            for elem in &self.packed_bool {
+                w.write_bool(*elem)?;
+            }
+        }
+        if !self.packed_nested_enum.is_empty() {
+            w.write_key(0x2c2)?;
+            w.write_varint(self.packed_nested_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_nested_enum {
+                w.write_int32(*elem)?;
+            }
+        }
+        for elem in &self.unpacked_int32 {
+            w.write_key(0x2c8)?;
+            w.write_int32(*elem)?;
+        }
+        for elem in &self.unpacked_int64 {
+            w.write_key(0x2d0)?;
+            w.write_int64(*elem)?;
+        }
+        for elem in &self.unpacked_uint32 {
+            w.write_key(0x2d8)?;
+            w.write_varint(*elem as u64)?;
+        }
+        for elem in &self.unpacked_uint64 {
+            w.write_key(0x2e0)?;
+            w.write_varint(*elem)?;
+        }
+        for elem in &self.unpacked_sint32 {
+            w.write_key(0x2e8)?;
+            w.write_zigzag32(*elem)?;
+        }
+        for elem in &self.unpacked_sint64 {
+            w.write_key(0x2f0)?;
+            w.write_zigzag64(*elem)?;
+        }
+        for elem in &self.unpacked_fixed32 {
+            w.write_key(0x2fd)?;
+            w.write_fixed32(*elem)?;
+        }
+        for elem in &self.unpacked_fixed64 {
+            w.write_key(0x301)?;
+            w.write_fixed64(*elem)?;
+        }
+        for elem in &self.unpacked_sfixed32 {
+            w.write_key(0x30d)?;
+            w.write_sfixed32(*elem)?;
+        }
+        for elem in &self.unpacked_sfixed64 {
+            w.write_key(0x311)?;
+            w.write_sfixed64(*elem)?;
+        }
+        for elem in &self.unpacked_float {
+            w.write_key(0x31d)?;
+            w.write_float(*elem)?;
+        }
+        for elem in &self.unpacked_double {
+            w.write_key(0x321)?;
+            w.write_double(*elem)?;
+        }
+        for elem in &self.unpacked_bool {
+            w.write_key(0x328)?;
+            w.write_bool(*elem)?;
+        }
+        for elem in &self.unpacked_nested_enum {
+            w.write_key(0x330)?;
+            w.write_int32(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofUint32(elem) = &self.oneof_field {
+            w.write_key(0x378)?;
+            w.write_varint(*elem as u64)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofNestedMessage(elem) = &self.oneof_field {
+            w.write_message(112, &**elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofString(elem) = &self.oneof_field {
+            w.write_key(0x38a)?;
+            w.write_string(elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofBytes(elem) = &self.oneof_field {
+            w.write_key(0x392)?;
+            w.write_bytes(elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofBool(elem) = &self.oneof_field {
+            w.write_key(0x398)?;
+            w.write_bool(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofUint64(elem) = &self.oneof_field {
+            w.write_key(0x3a0)?;
+            w.write_varint(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofFloat(elem) = &self.oneof_field {
+            w.write_key(0x3ad)?;
+            w.write_float(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofDouble(elem) = &self.oneof_field {
+            w.write_key(0x3b1)?;
+            w.write_double(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofEnum(elem) = &self.oneof_field {
+            w.write_key(0x3b8)?;
+            w.write_int32(*elem)?;
+        }
+        if let Some(elem) = &self.optional_bool_wrapper {
+            w.write_key(0x64a)?;
+            w.write_varint(BoolValue::value_len(elem) as u64)?;
+            BoolValue::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_int32_wrapper {
+            w.write_key(0x652)?;
+            w.write_varint(Int32Value::value_len(elem) as u64)?;
+            Int32Value::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_int64_wrapper {
+            w.write_key(0x65a)?;
+            w.write_varint(Int64Value::value_len(elem) as u64)?;
+            Int64Value::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_uint32_wrapper {
+            w.write_key(0x662)?;
+            w.write_varint(UInt32Value::value_len(elem) as u64)?;
+            UInt32Value::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_uint64_wrapper {
+            w.write_key(0x66a)?;
+            w.write_varint(UInt64Value::value_len(elem) as u64)?;
+            UInt64Value::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_float_wrapper {
+            w.write_key(0x672)?;
+            w.write_varint(FloatValue::value_len(elem) as u64)?;
+            FloatValue::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_double_wrapper {
+            w.write_key(0x67a)?;
+            w.write_varint(DoubleValue::value_len(elem) as u64)?;
+            DoubleValue::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_string_wrapper {
+            w.write_key(0x682)?;
+            w.write_varint(StringValue::value_len(elem) as u64)?;
+            StringValue::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_bytes_wrapper {
+            w.write_key(0x68a)?;
+            w.write_varint(BytesValue::value_len(elem) as u64)?;
+            BytesValue::write_value(elem, w)?;
+        }
+        for elem in &self.repeated_bool_wrapper {
+            w.write_message(211, elem)?;
+        }
+        for elem in &self.repeated_int32_wrapper {
+            w.write_message(212, elem)?;
+        }
+        for elem in &self.repeated_int64_wrapper {
+            w.write_message(213, elem)?;
+        }
+        for elem in &self.repeated_uint32_wrapper {
+            w.write_message(214, elem)?;
+        }
+        for elem in &self.repeated_uint64_wrapper {
+            w.write_message(215, elem)?;
+        }
+        for elem in &self.repeated_float_wrapper {
+            w.write_message(216, elem)?;
+        }
+        for elem in &self.repeated_double_wrapper {
+            w.write_message(217, elem)?;
+        }
+        for elem in &self.repeated_string_wrapper {
+            w.write_message(218, elem)?;
+        }
+        for elem in &self.repeated_bytes_wrapper {
+            w.write_message(219, elem)?;
+        }
+        w.write_message(301, &self.optional_duration)?;
+        w.write_message(302, &self.optional_timestamp)?;
+        w.write_message(305, &self.optional_any)?;
+        for elem in &self.repeated_duration {
+            w.write_message(311, elem)?;
+        }
+        for elem in &self.repeated_timestamp {
+            w.write_message(312, elem)?;
+        }
+        for elem in &self.repeated_any {
+            w.write_message(315, elem)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        let mut len = 0;
+        if self.optional_int32 != 0 {
+            len += key_len(1) + int32_len(self.optional_int32);
+        }
+        if self.optional_int64 != 0 {
+            len += key_len(2) + int64_len(self.optional_int64);
+        }
+        if self.optional_uint32 != 0 {
+            len += key_len(3) + varint_len(self.optional_uint32 as u64);
+        }
+        if self.optional_uint64 != 0 {
+            len += key_len(4) + varint_len(self.optional_uint64);
+        }
+        if self.optional_sint32 != 0 {
+            len += key_len(5) + zigzag32_len(self.optional_sint32);
+        }
+        if self.optional_sint64 != 0 {
+            len += key_len(6) + zigzag64_len(self.optional_sint64);
+        }
+        if self.optional_fixed32 != 0 {
+            len += key_len(7) + 4;
+        }
+        if self.optional_fixed64 != 0 {
+            len += key_len(8) + 8;
+        }
+        if self.optional_sfixed32 != 0 {
+            len += key_len(9) + 4;
+        }
+        if self.optional_sfixed64 != 0 {
+            len += key_len(10) + 8;
+        }
+        if self.optional_float.to_bits() != 0 {
+            len += key_len(11) + 4;
+        }
+        if self.optional_double.to_bits() != 0 {
+            len += key_len(12) + 8;
+        }
+        if self.optional_bool {
+            len += key_len(13) + 1;
+        }
+        if !self.optional_string.is_empty() {
+            len += key_len(14) + bytes_len(self.optional_string.len());
+        }
+        if !self.optional_bytes.is_empty() {
+            len += key_len(15) + bytes_len(self.optional_bytes.len());
+        }
+        if let Some(elem) = &self.optional_nested_message {
+            len += key_len(18) + bytes_len(sizes.record(&**elem));
+        }
+        len += key_len(19) + bytes_len(sizes.record(&self.optional_foreign_message));
+        if self.optional_nested_enum != 0 {
+            len += key_len(21) + int32_len(self.optional_nested_enum);
+        }
+        if self.optional_foreign_enum != 0 {
+            len += key_len(22) + int32_len(self.optional_foreign_enum);
+        }
+        if self.optional_aliased_enum != 0 {
+            len += key_len(23) + int32_len(self.optional_aliased_enum);
+        }
+        if !self.optional_string_piece.is_empty() {
+            len += key_len(24) + bytes_len(self.optional_string_piece.len());
+        }
+        if !self.optional_cord.is_empty() {
+            len += key_len(25) + bytes_len(self.optional_cord.len());
+        }
+        if let Some(elem) = &self.recursive_message {
+            len += key_len(27) + bytes_len(sizes.record(&**elem));
+        }
+        if !self.repeated_int32.is_empty() {
+            len += key_len(31) + bytes_len(self.repeated_int32.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_int64.is_empty() {
+            len += key_len(32) + bytes_len(self.repeated_int64.iter().map(|elem| int64_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_uint32.is_empty() {
+            len += key_len(33) + bytes_len(self.repeated_uint32.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+        }
+        if !self.repeated_uint64.is_empty() {
+            len += key_len(34) + bytes_len(self.repeated_uint64.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_sint32.is_empty() {
+            len += key_len(35) + bytes_len(self.repeated_sint32.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_sint64.is_empty() {
+            len += key_len(36) + bytes_len(self.repeated_sint64.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_fixed32.is_empty() {
+            len += key_len(37) + bytes_len(self.repeated_fixed32.len() * 4);
+        }
+        if !self.repeated_fixed64.is_empty() {
+            len += key_len(38) + bytes_len(self.repeated_fixed64.len() * 8);
+        }
+        if !self.repeated_sfixed32.is_empty() {
+            len += key_len(39) + bytes_len(self.repeated_sfixed32.len() * 4);
+        }
+        if !self.repeated_sfixed64.is_empty() {
+            len += key_len(40) + bytes_len(self.repeated_sfixed64.len() * 8);
+        }
+        if !self.repeated_float.is_empty() {
+            len += key_len(41) + bytes_len(self.repeated_float.len() * 4);
+        }
+        if !self.repeated_double.is_empty() {
+            len += key_len(42) + bytes_len(self.repeated_double.len() * 8);
+        }
+        if !self.repeated_bool.is_empty() {
+            len += key_len(43) + bytes_len(self.repeated_bool.len());
+        }
+        for elem in &self.repeated_string {
+            len += key_len(44) + bytes_len(elem.len());
+        }
+        for elem in &self.repeated_bytes {
+            len += key_len(45) + bytes_len(elem.len());
+        }
+        for elem in &self.repeated_nested_message {
+            len += key_len(48) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_foreign_message {
+            len += key_len(49) + bytes_len(sizes.record(elem));
+        }
+        if !self.repeated_nested_enum.is_empty() {
+            len += key_len(51) + bytes_len(self.repeated_nested_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        if !self.repeated_foreign_enum.is_empty() {
+            len += key_len(52) + bytes_len(self.repeated_foreign_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        for elem in &self.repeated_string_piece {
+            len += key_len(54) + bytes_len(elem.len());
+        }
+        for elem in &self.repeated_cord {
+            len += key_len(55) + bytes_len(elem.len());
+        }
+        for (key, value) in &self.map_int32_int32 {
+            len += key_len(56) + bytes_len(key_len(1) + int32_len(*key) + key_len(2) + int32_len(*value));
+        }
+        for (key, value) in &self.map_int64_int64 {
+            len += key_len(57) + bytes_len(key_len(1) + int64_len(*key) + key_len(2) + int64_len(*value));
+        }
+        for (key, value) in &self.map_uint32_uint32 {
+            len += key_len(58) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + varint_len(*value as u64));
+        }
+        for (key, value) in &self.map_uint64_uint64 {
+            len += key_len(59) + bytes_len(key_len(1) + varint_len(*key) + key_len(2) + varint_len(*value));
+        }
+        for (key, value) in &self.map_sint32_sint32 {
+            len += key_len(60) + bytes_len(key_len(1) + zigzag32_len(*key) + key_len(2) + zigzag32_len(*value));
+        }
+        for (key, value) in &self.map_sint64_sint64 {
+            len += key_len(61) + bytes_len(key_len(1) + zigzag64_len(*key) + key_len(2) + zigzag64_len(*value));
+        }
+        len += self.map_fixed32_fixed32.len() * (key_len(62) + bytes_len(key_len(1) + 4 + key_len(2) + 4));
+        len += self.map_fixed64_fixed64.len() * (key_len(63) + bytes_len(key_len(1) + 8 + key_len(2) + 8));
+        len += self.map_sfixed32_sfixed32.len() * (key_len(64) + bytes_len(key_len(1) + 4 + key_len(2) + 4));
+        len += self.map_sfixed64_sfixed64.len() * (key_len(65) + bytes_len(key_len(1) + 8 + key_len(2) + 8));
+        for key in self.map_int32_float.keys() {
+            len += key_len(66) + bytes_len(key_len(1) + int32_len(*key) + key_len(2) + 4);
+        }
+        for key in self.map_int32_double.keys() {
+            len += key_len(67) + bytes_len(key_len(1) + int32_len(*key) + key_len(2) + 8);
+        }
+        len += self.map_bool_bool.len() * (key_len(68) + bytes_len(key_len(1) + 1 + key_len(2) + 1));
+        for (key, value) in &self.map_string_string {
+            len += key_len(69) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+        }
+        for (key, value) in &self.map_string_bytes {
+            len += key_len(70) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+        }
+        for (key, value) in &self.map_string_nested_message {
+            len += key_len(71) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.record(value)));
+        }
+        for (key, value) in &self.map_string_foreign_message {
+            len += key_len(72) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.record(value)));
+        }
+        for (key, value) in &self.map_string_nested_enum {
+            len += key_len(73) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value));
+        }
+        for (key, value) in &self.map_string_foreign_enum {
+            len += key_len(74) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value));
+        }
+        if !self.packed_int32.is_empty() {
+            len += key_len(75) + bytes_len(self.packed_int32.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_int64.is_empty() {
+            len += key_len(76) + bytes_len(self.packed_int64.iter().map(|elem| int64_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_uint32.is_empty() {
+            len += key_len(77) + bytes_len(self.packed_uint32.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+        }
+        if !self.packed_uint64.is_empty() {
+            len += key_len(78) + bytes_len(self.packed_uint64.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_sint32.is_empty() {
+            len += key_len(79) + bytes_len(self.packed_sint32.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_sint64.is_empty() {
+            len += key_len(80) + bytes_len(self.packed_sint64.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+        }
+        if !self.packed_fixed32.is_empty() {
+            len += key_len(81) + bytes_len(self.packed_fixed32.len() * 4);
+        }
+        if !self.packed_fixed64.is_empty() {
+            len += key_len(82) + bytes_len(self.packed_fixed64.len() * 8);
+        }
+        if !self.packed_sfixed32.is_empty() {
+            len += key_len(83) + bytes_len(self.packed_sfixed32.len() * 4);
+        }
+        if !self.packed_sfixed64.is_empty() {
+            len += key_len(84) + bytes_len(self.packed_sfixed64.len() * 8);
+        }
+        if !self.packed_float.is_empty() {
+            len += key_len(85) + bytes_len(self.packed_float.len() * 4);
+        }
+        if !self.packed_double.is_empty() {
+            len += key_len(86) + bytes_len(self.packed_double.len() * 8);
+        }
+        if !self.packed_bool.is_empty() {
+            len += key_len(87) + bytes_len(self.packed_bool.len());
+        }
+        if !self.packed_nested_enum.is_empty() {
+            len += key_len(88) + bytes_len(self.packed_nested_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+        }
+        for elem in &self.unpacked_int32 {
+            len += key_len(89) + int32_len(*elem);
+        }
+        for elem in &self.unpacked_int64 {
+            len += key_len(90) + int64_len(*elem);
+        }
+        for elem in &self.unpacked_uint32 {
+            len += key_len(91) + varint_len(*elem as u64);
+        }
+        for elem in &self.unpacked_uint64 {
+            len += key_len(92) + varint_len(*elem);
+        }
+        for elem in &self.unpacked_sint32 {
+            len += key_len(93) + zigzag32_len(*elem);
+        }
+        for elem in &self.unpacked_sint64 {
+            len += key_len(94) + zigzag64_len(*elem);
+        }
+        len += self.unpacked_fixed32.len() * (key_len(95) + 4);
+        len += self.unpacked_fixed64.len() * (key_len(96) + 8);
+        len += self.unpacked_sfixed32.len() * (key_len(97) + 4);
+        len += self.unpacked_sfixed64.len() * (key_len(98) + 8);
+        len += self.unpacked_float.len() * (key_len(99) + 4);
+        len += self.unpacked_double.len() * (key_len(100) + 8);
+        len += self.unpacked_bool.len() * (key_len(101) + 1);
+        for elem in &self.unpacked_nested_enum {
+            len += key_len(102) + int32_len(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofUint32(elem) = &self.oneof_field {
+            len += key_len(111) + varint_len(*elem as u64);
+        }
+        if let TestAllTypesProto3OneofField::OneofNestedMessage(elem) = &self.oneof_field {
+            len += key_len(112) + bytes_len(sizes.record(&**elem));
+        }
+        if let TestAllTypesProto3OneofField::OneofString(elem) = &self.oneof_field {
+            len += key_len(113) + bytes_len(elem.len());
+        }
+        if let TestAllTypesProto3OneofField::OneofBytes(elem) = &self.oneof_field {
+            len += key_len(114) + bytes_len(elem.len());
+        }
+        if let TestAllTypesProto3OneofField::OneofBool(_) = &self.oneof_field {
+            len += key_len(115) + 1;
+        }
+        if let TestAllTypesProto3OneofField::OneofUint64(elem) = &self.oneof_field {
+            len += key_len(116) + varint_len(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofFloat(_) = &self.oneof_field {
+            len += key_len(117) + 4;
+        }
+        if let TestAllTypesProto3OneofField::OneofDouble(_) = &self.oneof_field {
+            len += key_len(118) + 8;
+        }
+        if let TestAllTypesProto3OneofField::OneofEnum(elem) = &self.oneof_field {
+            len += key_len(119) + int32_len(*elem);
+        }
+        if let Some(elem) = &self.optional_bool_wrapper {
+            len += key_len(201) + bytes_len(BoolValue::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_int32_wrapper {
+            len += key_len(202) + bytes_len(Int32Value::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_int64_wrapper {
+            len += key_len(203) + bytes_len(Int64Value::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_uint32_wrapper {
+            len += key_len(204) + bytes_len(UInt32Value::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_uint64_wrapper {
+            len += key_len(205) + bytes_len(UInt64Value::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_float_wrapper {
+            len += key_len(206) + bytes_len(FloatValue::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_double_wrapper {
+            len += key_len(207) + bytes_len(DoubleValue::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_string_wrapper {
+            len += key_len(208) + bytes_len(StringValue::value_len(elem));
+        }
+        if let Some(elem) = &self.optional_bytes_wrapper {
+            len += key_len(209) + bytes_len(BytesValue::value_len(elem));
+        }
+        for elem in &self.repeated_bool_wrapper {
+            len += key_len(211) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_int32_wrapper {
+            len += key_len(212) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_int64_wrapper {
+            len += key_len(213) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_uint32_wrapper {
+            len += key_len(214) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_uint64_wrapper {
+            len += key_len(215) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_float_wrapper {
+            len += key_len(216) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_double_wrapper {
+            len += key_len(217) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_string_wrapper {
+            len += key_len(218) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_bytes_wrapper {
+            len += key_len(219) + bytes_len(sizes.record(elem));
+        }
+        len += key_len(301) + bytes_len(sizes.record(&self.optional_duration));
+        len += key_len(302) + bytes_len(sizes.record(&self.optional_timestamp));
+        len += key_len(305) + bytes_len(sizes.record(&self.optional_any));
+        for elem in &self.repeated_duration {
+            len += key_len(311) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_timestamp {
+            len += key_len(312) + bytes_len(sizes.record(elem));
+        }
+        for elem in &self.repeated_any {
+            len += key_len(315) + bytes_len(sizes.record(elem));
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        if self.optional_int32 != 0 {
+            w.write_key(0x08)?;
+            w.write_int32(self.optional_int32)?;
+        }
+        if self.optional_int64 != 0 {
+            w.write_key(0x10)?;
+            w.write_int64(self.optional_int64)?;
+        }
+        if self.optional_uint32 != 0 {
+            w.write_key(0x18)?;
+            w.write_varint(self.optional_uint32 as u64)?;
+        }
+        if self.optional_uint64 != 0 {
+            w.write_key(0x20)?;
+            w.write_varint(self.optional_uint64)?;
+        }
+        if self.optional_sint32 != 0 {
+            w.write_key(0x28)?;
+            w.write_zigzag32(self.optional_sint32)?;
+        }
+        if self.optional_sint64 != 0 {
+            w.write_key(0x30)?;
+            w.write_zigzag64(self.optional_sint64)?;
+        }
+        if self.optional_fixed32 != 0 {
+            w.write_key(0x3d)?;
+            w.write_fixed32(self.optional_fixed32)?;
+        }
+        if self.optional_fixed64 != 0 {
+            w.write_key(0x41)?;
+            w.write_fixed64(self.optional_fixed64)?;
+        }
+        if self.optional_sfixed32 != 0 {
+            w.write_key(0x4d)?;
+            w.write_sfixed32(self.optional_sfixed32)?;
+        }
+        if self.optional_sfixed64 != 0 {
+            w.write_key(0x51)?;
+            w.write_sfixed64(self.optional_sfixed64)?;
+        }
+        if self.optional_float.to_bits() != 0 {
+            w.write_key(0x5d)?;
+            w.write_float(self.optional_float)?;
+        }
+        if self.optional_double.to_bits() != 0 {
+            w.write_key(0x61)?;
+            w.write_double(self.optional_double)?;
+        }
+        if self.optional_bool {
+            w.write_key(0x68)?;
+            w.write_bool(self.optional_bool)?;
+        }
+        if !self.optional_string.is_empty() {
+            w.write_key(0x72)?;
+            w.write_string(&self.optional_string)?;
+        }
+        if !self.optional_bytes.is_empty() {
+            w.write_key(0x7a)?;
+            w.write_bytes(&self.optional_bytes)?;
+        }
+        if let Some(elem) = &self.optional_nested_message {
+            w.write_message_sized(18, &**elem, sizes)?;
+        }
+        w.write_message_sized(19, &self.optional_foreign_message, sizes)?;
+        if self.optional_nested_enum != 0 {
+            w.write_key(0xa8)?;
+            w.write_int32(self.optional_nested_enum)?;
+        }
+        if self.optional_foreign_enum != 0 {
+            w.write_key(0xb0)?;
+            w.write_int32(self.optional_foreign_enum)?;
+        }
+        if self.optional_aliased_enum != 0 {
+            w.write_key(0xb8)?;
+            w.write_int32(self.optional_aliased_enum)?;
+        }
+        if !self.optional_string_piece.is_empty() {
+            w.write_key(0xc2)?;
+            w.write_string(&self.optional_string_piece)?;
+        }
+        if !self.optional_cord.is_empty() {
+            w.write_key(0xca)?;
+            w.write_string(&self.optional_cord)?;
+        }
+        if let Some(elem) = &self.recursive_message {
+            w.write_message_sized(27, &**elem, sizes)?;
+        }
+        if !self.repeated_int32.is_empty() {
+            w.write_key(0xfa)?;
+            w.write_varint(self.repeated_int32.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_int32 {
+                w.write_int32(*elem)?;
+            }
+        }
+        if !self.repeated_int64.is_empty() {
+            w.write_key(0x102)?;
+            w.write_varint(self.repeated_int64.iter().map(|elem| int64_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_int64 {
+                w.write_int64(*elem)?;
+            }
+        }
+        if !self.repeated_uint32.is_empty() {
+            w.write_key(0x10a)?;
+            w.write_varint(self.repeated_uint32.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_uint32 {
+                w.write_varint(*elem as u64)?;
+            }
+        }
+        if !self.repeated_uint64.is_empty() {
+            w.write_key(0x112)?;
+            w.write_varint(self.repeated_uint64.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_uint64 {
+                w.write_varint(*elem)?;
+            }
+        }
+        if !self.repeated_sint32.is_empty() {
+            w.write_key(0x11a)?;
+            w.write_varint(self.repeated_sint32.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_sint32 {
+                w.write_zigzag32(*elem)?;
+            }
+        }
+        if !self.repeated_sint64.is_empty() {
+            w.write_key(0x122)?;
+            w.write_varint(self.repeated_sint64.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_sint64 {
+                w.write_zigzag64(*elem)?;
+            }
+        }
+        if !self.repeated_fixed32.is_empty() {
+            w.write_key(0x12a)?;
+            w.write_varint((self.repeated_fixed32.len() * 4) as u64)?;
+            for elem in &self.repeated_fixed32 {
+                w.write_fixed32(*elem)?;
+            }
+        }
+        if !self.repeated_fixed64.is_empty() {
+            w.write_key(0x132)?;
+            w.write_varint((self.repeated_fixed64.len() * 8) as u64)?;
+            for elem in &self.repeated_fixed64 {
+                w.write_fixed64(*elem)?;
+            }
+        }
+        if !self.repeated_sfixed32.is_empty() {
+            w.write_key(0x13a)?;
+            w.write_varint((self.repeated_sfixed32.len() * 4) as u64)?;
+            for elem in &self.repeated_sfixed32 {
+                w.write_sfixed32(*elem)?;
+            }
+        }
+        if !self.repeated_sfixed64.is_empty() {
+            w.write_key(0x142)?;
+            w.write_varint((self.repeated_sfixed64.len() * 8) as u64)?;
+            for elem in &self.repeated_sfixed64 {
+                w.write_sfixed64(*elem)?;
+            }
+        }
+        if !self.repeated_float.is_empty() {
+            w.write_key(0x14a)?;
+            w.write_varint((self.repeated_float.len() * 4) as u64)?;
+            for elem in &self.repeated_float {
+                w.write_float(*elem)?;
+            }
+        }
+        if !self.repeated_double.is_empty() {
+            w.write_key(0x152)?;
+            w.write_varint((self.repeated_double.len() * 8) as u64)?;
+            for elem in &self.repeated_double {
+                w.write_double(*elem)?;
+            }
+        }
+        if !self.repeated_bool.is_empty() {
+            w.write_key(0x15a)?;
+            w.write_varint(self.repeated_bool.len() as u64)?;
+            for elem in &self.repeated_bool {
+                w.write_bool(*elem)?;
+            }
+        }
+        for elem in &self.repeated_string {
+            w.write_key(0x162)?;
+            w.write_string(elem)?;
+        }
+        for elem in &self.repeated_bytes {
+            w.write_key(0x16a)?;
+            w.write_bytes(elem)?;
+        }
+        for elem in &self.repeated_nested_message {
+            w.write_message_sized(48, elem, sizes)?;
+        }
+        for elem in &self.repeated_foreign_message {
+            w.write_message_sized(49, elem, sizes)?;
+        }
+        if !self.repeated_nested_enum.is_empty() {
+            w.write_key(0x19a)?;
+            w.write_varint(self.repeated_nested_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_nested_enum {
+                w.write_int32(*elem)?;
+            }
+        }
+        if !self.repeated_foreign_enum.is_empty() {
+            w.write_key(0x1a2)?;
+            w.write_varint(self.repeated_foreign_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.repeated_foreign_enum {
+                w.write_int32(*elem)?;
+            }
+        }
+        for elem in &self.repeated_string_piece {
+            w.write_key(0x1b2)?;
+            w.write_string(elem)?;
+        }
+        for elem in &self.repeated_cord {
+            w.write_key(0x1ba)?;
+            w.write_string(elem)?;
+        }
+        for (key, value) in &self.map_int32_int32 {
+            w.write_key(0x1c2)?;
+            w.write_varint((key_len(1) + int32_len(*key) + key_len(2) + int32_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_int32(*key)?;
+            w.write_key(0x10)?;
+            w.write_int32(*value)?;
+        }
+        for (key, value) in &self.map_int64_int64 {
+            w.write_key(0x1ca)?;
+            w.write_varint((key_len(1) + int64_len(*key) + key_len(2) + int64_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_int64(*key)?;
+            w.write_key(0x10)?;
+            w.write_int64(*value)?;
+        }
+        for (key, value) in &self.map_uint32_uint32 {
+            w.write_key(0x1d2)?;
+            w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + varint_len(*value as u64)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_varint(*key as u64)?;
+            w.write_key(0x10)?;
+            w.write_varint(*value as u64)?;
+        }
+        for (key, value) in &self.map_uint64_uint64 {
+            w.write_key(0x1da)?;
+            w.write_varint((key_len(1) + varint_len(*key) + key_len(2) + varint_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_varint(*key)?;
+            w.write_key(0x10)?;
+            w.write_varint(*value)?;
+        }
+        for (key, value) in &self.map_sint32_sint32 {
+            w.write_key(0x1e2)?;
+            w.write_varint((key_len(1) + zigzag32_len(*key) + key_len(2) + zigzag32_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_zigzag32(*key)?;
+            w.write_key(0x10)?;
+            w.write_zigzag32(*value)?;
+        }
+        for (key, value) in &self.map_sint64_sint64 {
+            w.write_key(0x1ea)?;
+            w.write_varint((key_len(1) + zigzag64_len(*key) + key_len(2) + zigzag64_len(*value)) as u64)?;
+            w.write_key(0x08)?;
+            w.write_zigzag64(*key)?;
+            w.write_key(0x10)?;
+            w.write_zigzag64(*value)?;
+        }
+        for (key, value) in &self.map_fixed32_fixed32 {
+            w.write_key(0x1f2)?;
+            w.write_varint((key_len(1) + 4 + key_len(2) + 4) as u64)?;
+            w.write_key(0x0d)?;
+            w.write_fixed32(*key)?;
+            w.write_key(0x15)?;
+            w.write_fixed32(*value)?;
+        }
+        for (key, value) in &self.map_fixed64_fixed64 {
+            w.write_key(0x1fa)?;
+            w.write_varint((key_len(1) + 8 + key_len(2) + 8) as u64)?;
+            w.write_key(0x09)?;
+            w.write_fixed64(*key)?;
+            w.write_key(0x11)?;
+            w.write_fixed64(*value)?;
+        }
+        for (key, value) in &self.map_sfixed32_sfixed32 {
+            w.write_key(0x202)?;
+            w.write_varint((key_len(1) + 4 + key_len(2) + 4) as u64)?;
+            w.write_key(0x0d)?;
+            w.write_sfixed32(*key)?;
+            w.write_key(0x15)?;
+            w.write_sfixed32(*value)?;
+        }
+        for (key, value) in &self.map_sfixed64_sfixed64 {
+            w.write_key(0x20a)?;
+            w.write_varint((key_len(1) + 8 + key_len(2) + 8) as u64)?;
+            w.write_key(0x09)?;
+            w.write_sfixed64(*key)?;
+            w.write_key(0x11)?;
+            w.write_sfixed64(*value)?;
+        }
+        for (key, value) in &self.map_int32_float {
+            w.write_key(0x212)?;
+            w.write_varint((key_len(1) + int32_len(*key) + key_len(2) + 4) as u64)?;
+            w.write_key(0x08)?;
+            w.write_int32(*key)?;
+            w.write_key(0x15)?;
+            w.write_float(*value)?;
+        }
+        for (key, value) in &self.map_int32_double {
+            w.write_key(0x21a)?;
+            w.write_varint((key_len(1) + int32_len(*key) + key_len(2) + 8) as u64)?;
+            w.write_key(0x08)?;
+            w.write_int32(*key)?;
+            w.write_key(0x11)?;
+            w.write_double(*value)?;
+        }
+        for (key, value) in &self.map_bool_bool {
+            w.write_key(0x222)?;
+            w.write_varint((key_len(1) + 1 + key_len(2) + 1) as u64)?;
+            w.write_key(0x08)?;
+            w.write_bool(*key)?;
+            w.write_key(0x10)?;
+            w.write_bool(*value)?;
+        }
+        for (key, value) in &self.map_string_string {
+            w.write_key(0x22a)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x12)?;
+            w.write_string(value)?;
+        }
+        for (key, value) in &self.map_string_bytes {
+            w.write_key(0x232)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x12)?;
+            w.write_bytes(value)?;
+        }
+        for (key, value) in &self.map_string_nested_message {
+            w.write_key(0x23a)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_message_sized(2, value, sizes)?;
+        }
+        for (key, value) in &self.map_string_foreign_message {
+            w.write_key(0x242)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_message_sized(2, value, sizes)?;
+        }
+        for (key, value) in &self.map_string_nested_enum {
+            w.write_key(0x24a)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value)) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x10)?;
+            w.write_int32(*value)?;
+        }
+        for (key, value) in &self.map_string_foreign_enum {
+            w.write_key(0x252)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value)) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x10)?;
+            w.write_int32(*value)?;
+        }
+        if !self.packed_int32.is_empty() {
+            w.write_key(0x25a)?;
+            w.write_varint(self.packed_int32.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_int32 {
+                w.write_int32(*elem)?;
+            }
+        }
+        if !self.packed_int64.is_empty() {
+            w.write_key(0x262)?;
+            w.write_varint(self.packed_int64.iter().map(|elem| int64_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_int64 {
+                w.write_int64(*elem)?;
+            }
+        }
+        if !self.packed_uint32.is_empty() {
+            w.write_key(0x26a)?;
+            w.write_varint(self.packed_uint32.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+            for elem in &self.packed_uint32 {
+                w.write_varint(*elem as u64)?;
+            }
+        }
+        if !self.packed_uint64.is_empty() {
+            w.write_key(0x272)?;
+            w.write_varint(self.packed_uint64.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_uint64 {
+                w.write_varint(*elem)?;
+            }
+        }
+        if !self.packed_sint32.is_empty() {
+            w.write_key(0x27a)?;
+            w.write_varint(self.packed_sint32.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_sint32 {
+                w.write_zigzag32(*elem)?;
+            }
+        }
+        if !self.packed_sint64.is_empty() {
+            w.write_key(0x282)?;
+            w.write_varint(self.packed_sint64.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_sint64 {
+                w.write_zigzag64(*elem)?;
+            }
+        }
+        if !self.packed_fixed32.is_empty() {
+            w.write_key(0x28a)?;
+            w.write_varint((self.packed_fixed32.len() * 4) as u64)?;
+            for elem in &self.packed_fixed32 {
+                w.write_fixed32(*elem)?;
+            }
+        }
+        if !self.packed_fixed64.is_empty() {
+            w.write_key(0x292)?;
+            w.write_varint((self.packed_fixed64.len() * 8) as u64)?;
+            for elem in &self.packed_fixed64 {
+                w.write_fixed64(*elem)?;
+            }
+        }
+        if !self.packed_sfixed32.is_empty() {
+            w.write_key(0x29a)?;
+            w.write_varint((self.packed_sfixed32.len() * 4) as u64)?;
+            for elem in &self.packed_sfixed32 {
+                w.write_sfixed32(*elem)?;
+            }
+        }
+        if !self.packed_sfixed64.is_empty() {
+            w.write_key(0x2a2)?;
+            w.write_varint((self.packed_sfixed64.len() * 8) as u64)?;
+            for elem in &self.packed_sfixed64 {
+                w.write_sfixed64(*elem)?;
+            }
+        }
+        if !self.packed_float.is_empty() {
+            w.write_key(0x2aa)?;
+            w.write_varint((self.packed_float.len() * 4) as u64)?;
+            for elem in &self.packed_float {
+                w.write_float(*elem)?;
+            }
+        }
+        if !self.packed_double.is_empty() {
+            w.write_key(0x2b2)?;
+            w.write_varint((self.packed_double.len() * 8) as u64)?;
+            for elem in &self.packed_double {
+                w.write_double(*elem)?;
+            }
+        }
+        if !self.packed_bool.is_empty() {
+            w.write_key(0x2ba)?;
+            w.write_varint(self.packed_bool.len() as u64)?;
+            for elem in &self.packed_bool {
+                w.write_bool(*elem)?;
+            }
+        }
+        if !self.packed_nested_enum.is_empty() {
+            w.write_key(0x2c2)?;
+            w.write_varint(self.packed_nested_enum.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+            for elem in &self.packed_nested_enum {
+                w.write_int32(*elem)?;
+            }
+        }
+        for elem in &self.unpacked_int32 {
+            w.write_key(0x2c8)?;
+            w.write_int32(*elem)?;
+        }
+        for elem in &self.unpacked_int64 {
+            w.write_key(0x2d0)?;
+            w.write_int64(*elem)?;
+        }
+        for elem in &self.unpacked_uint32 {
+            w.write_key(0x2d8)?;
+            w.write_varint(*elem as u64)?;
+        }
+        for elem in &self.unpacked_uint64 {
+            w.write_key(0x2e0)?;
+            w.write_varint(*elem)?;
+        }
+        for elem in &self.unpacked_sint32 {
+            w.write_key(0x2e8)?;
+            w.write_zigzag32(*elem)?;
+        }
+        for elem in &self.unpacked_sint64 {
+            w.write_key(0x2f0)?;
+            w.write_zigzag64(*elem)?;
+        }
+        for elem in &self.unpacked_fixed32 {
+            w.write_key(0x2fd)?;
+            w.write_fixed32(*elem)?;
+        }
+        for elem in &self.unpacked_fixed64 {
+            w.write_key(0x301)?;
+            w.write_fixed64(*elem)?;
+        }
+        for elem in &self.unpacked_sfixed32 {
+            w.write_key(0x30d)?;
+            w.write_sfixed32(*elem)?;
+        }
+        for elem in &self.unpacked_sfixed64 {
+            w.write_key(0x311)?;
+            w.write_sfixed64(*elem)?;
+        }
+        for elem in &self.unpacked_float {
+            w.write_key(0x31d)?;
+            w.write_float(*elem)?;
+        }
+        for elem in &self.unpacked_double {
+            w.write_key(0x321)?;
+            w.write_double(*elem)?;
+        }
+        for elem in &self.unpacked_bool {
+            w.write_key(0x328)?;
+            w.write_bool(*elem)?;
+        }
+        for elem in &self.unpacked_nested_enum {
+            w.write_key(0x330)?;
+            w.write_int32(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofUint32(elem) = &self.oneof_field {
+            w.write_key(0x378)?;
+            w.write_varint(*elem as u64)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofNestedMessage(elem) = &self.oneof_field {
+            w.write_message_sized(112, &**elem, sizes)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofString(elem) = &self.oneof_field {
+            w.write_key(0x38a)?;
+            w.write_string(elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofBytes(elem) = &self.oneof_field {
+            w.write_key(0x392)?;
+            w.write_bytes(elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofBool(elem) = &self.oneof_field {
+            w.write_key(0x398)?;
+            w.write_bool(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofUint64(elem) = &self.oneof_field {
+            w.write_key(0x3a0)?;
+            w.write_varint(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofFloat(elem) = &self.oneof_field {
+            w.write_key(0x3ad)?;
+            w.write_float(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofDouble(elem) = &self.oneof_field {
+            w.write_key(0x3b1)?;
+            w.write_double(*elem)?;
+        }
+        if let TestAllTypesProto3OneofField::OneofEnum(elem) = &self.oneof_field {
+            w.write_key(0x3b8)?;
+            w.write_int32(*elem)?;
+        }
+        if let Some(elem) = &self.optional_bool_wrapper {
+            w.write_key(0x64a)?;
+            w.write_varint(BoolValue::value_len(elem) as u64)?;
+            BoolValue::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_int32_wrapper {
+            w.write_key(0x652)?;
+            w.write_varint(Int32Value::value_len(elem) as u64)?;
+            Int32Value::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_int64_wrapper {
+            w.write_key(0x65a)?;
+            w.write_varint(Int64Value::value_len(elem) as u64)?;
+            Int64Value::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_uint32_wrapper {
+            w.write_key(0x662)?;
+            w.write_varint(UInt32Value::value_len(elem) as u64)?;
+            UInt32Value::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_uint64_wrapper {
+            w.write_key(0x66a)?;
+            w.write_varint(UInt64Value::value_len(elem) as u64)?;
+            UInt64Value::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_float_wrapper {
+            w.write_key(0x672)?;
+            w.write_varint(FloatValue::value_len(elem) as u64)?;
+            FloatValue::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_double_wrapper {
+            w.write_key(0x67a)?;
+            w.write_varint(DoubleValue::value_len(elem) as u64)?;
+            DoubleValue::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_string_wrapper {
+            w.write_key(0x682)?;
+            w.write_varint(StringValue::value_len(elem) as u64)?;
+            StringValue::write_value(elem, w)?;
+        }
+        if let Some(elem) = &self.optional_bytes_wrapper {
+            w.write_key(0x68a)?;
+            w.write_varint(BytesValue::value_len(elem) as u64)?;
+            BytesValue::write_value(elem, w)?;
+        }
+        for elem in &self.repeated_bool_wrapper {
+            w.write_message_sized(211, elem, sizes)?;
+        }
+        for elem in &self.repeated_int32_wrapper {
+            w.write_message_sized(212, elem, sizes)?;
+        }
+        for elem in &self.repeated_int64_wrapper {
+            w.write_message_sized(213, elem, sizes)?;
+        }
+        for elem in &self.repeated_uint32_wrapper {
+            w.write_message_sized(214, elem, sizes)?;
+        }
+        for elem in &self.repeated_uint64_wrapper {
+            w.write_message_sized(215, elem, sizes)?;
+        }
+        for elem in &self.repeated_float_wrapper {
+            w.write_message_sized(216, elem, sizes)?;
+        }
+        for elem in &self.repeated_double_wrapper {
+            w.write_message_sized(217, elem, sizes)?;
+        }
+        for elem in &self.repeated_string_wrapper {
+            w.write_message_sized(218, elem, sizes)?;
+        }
+        for elem in &self.repeated_bytes_wrapper {
+            w.write_message_sized(219, elem, sizes)?;
+        }
+        w.write_message_sized(301, &self.optional_duration, sizes)?;
+        w.write_message_sized(302, &self.optional_timestamp, sizes)?;
+        w.write_message_sized(305, &self.optional_any, sizes)?;
+        for elem in &self.repeated_duration {
+            w.write_message_sized(311, elem, sizes)?;
+        }
+        for elem in &self.repeated_timestamp {
+            w.write_message_sized(312, elem, sizes)?;
+        }
+        for elem in &self.repeated_any {
+            w.write_message_sized(315, elem, sizes)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                8 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.optional_int32 = v;
+                    pos = new_pos;
+                }
+                16 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_int64(data, pos)?;
+                    self.optional_int64 = v;
+                    pos = new_pos;
+                }
+                24 => {
+                    mark_singular(&mut seen, 2, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.optional_uint32 = v as u32;
+                    pos = new_pos;
+                }
+                32 => {
+                    mark_singular(&mut seen, 3, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.optional_uint64 = v;
+                    pos = new_pos;
+                }
+                40 => {
+                    mark_singular(&mut seen, 4, opts)?;
+                    let (v, new_pos) = decode_zigzag32(data, pos)?;
+                    self.optional_sint32 = v;
+                    pos = new_pos;
+                }
+                48 => {
+                    mark_singular(&mut seen, 5, opts)?;
+                    let (v, new_pos) = decode_zigzag64(data, pos)?;
+                    self.optional_sint64 = v;
+                    pos = new_pos;
+                }
+                61 => {
+                    mark_singular(&mut seen, 6, opts)?;
+                    let (v, new_pos) = decode_fixed32(data, pos)?;
+                    self.optional_fixed32 = v;
+                    pos = new_pos;
+                }
+                65 => {
+                    mark_singular(&mut seen, 7, opts)?;
+                    let (v, new_pos) = decode_fixed64(data, pos)?;
+                    self.optional_fixed64 = v;
+                    pos = new_pos;
+                }
+                77 => {
+                    mark_singular(&mut seen, 8, opts)?;
+                    let (v, new_pos) = decode_sfixed32(data, pos)?;
+                    self.optional_sfixed32 = v;
+                    pos = new_pos;
+                }
+                81 => {
+                    mark_singular(&mut seen, 9, opts)?;
+                    let (v, new_pos) = decode_sfixed64(data, pos)?;
+                    self.optional_sfixed64 = v;
+                    pos = new_pos;
+                }
+                93 => {
+                    mark_singular(&mut seen, 10, opts)?;
+                    let (v, new_pos) = decode_float(data, pos)?;
+                    self.optional_float = v;
+                    pos = new_pos;
+                }
+                97 => {
+                    mark_singular(&mut seen, 11, opts)?;
+                    let (v, new_pos) = decode_double(data, pos)?;
+                    self.optional_double = v;
+                    pos = new_pos;
+                }
+                104 => {
+                    mark_singular(&mut seen, 12, opts)?;
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    self.optional_bool = v;
+                    pos = new_pos;
+                }
+                114 => {
+                    mark_singular(&mut seen, 13, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.optional_string = v;
+                    pos = new_pos;
+                }
+                122 => {
+                    mark_singular(&mut seen, 14, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.optional_bytes = v;
+                    pos = new_pos;
+                }
+                146 => {
+                    mark_singular(&mut seen, 15, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.optional_nested_message.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                154 => {
+                    mark_singular(&mut seen, 16, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.optional_foreign_message.merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                168 => {
+                    mark_singular(&mut seen, 17, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.optional_nested_enum = v;
+                    pos = new_pos;
+                }
+                176 => {
+                    mark_singular(&mut seen, 18, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.optional_foreign_enum = v;
+                    pos = new_pos;
+                }
+                184 => {
+                    mark_singular(&mut seen, 19, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.optional_aliased_enum = v;
+                    pos = new_pos;
+                }
+                194 => {
+                    mark_singular(&mut seen, 20, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.optional_string_piece = v;
+                    pos = new_pos;
+                }
+                202 => {
+                    mark_singular(&mut seen, 21, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.optional_cord = v;
+                    pos = new_pos;
+                }
+                218 => {
+                    mark_singular(&mut seen, 22, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.recursive_message.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                248 => {
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    if self.repeated_int32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_int32.push(v);
+                    pos = new_pos;
+                }
+                250 => {
+                    let new_pos = decode_packed_int32_with(data, pos, &mut self.repeated_int32, opts)?;
+                    pos = new_pos;
+                }
+                256 => {
+                    let (v, new_pos) = decode_int64(data, pos)?;
+                    if self.repeated_int64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_int64.push(v);
+                    pos = new_pos;
+                }
+                258 => {
+                    let new_pos = decode_packed_int64_with(data, pos, &mut self.repeated_int64, opts)?;
+                    pos = new_pos;
+                }
+                264 => {
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    if self.repeated_uint32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_uint32.push(v as u32);
+                    pos = new_pos;
+                }
+                266 => {
+                    let new_pos = decode_packed_uint32_with(data, pos, &mut self.repeated_uint32, opts)?;
+                    pos = new_pos;
+                }
+                272 => {
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    if self.repeated_uint64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_uint64.push(v);
+                    pos = new_pos;
+                }
+                274 => {
+                    let new_pos = decode_packed_varint_with(data, pos, &mut self.repeated_uint64, opts)?;
+                    pos = new_pos;
+                }
+                280 => {
+                    let (v, new_pos) = decode_zigzag32(data, pos)?;
+                    if self.repeated_sint32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_sint32.push(v);
+                    pos = new_pos;
+                }
+                282 => {
+                    let new_pos = decode_packed_zigzag32_with(data, pos, &mut self.repeated_sint32, opts)?;
+                    pos = new_pos;
+                }
+                288 => {
+                    let (v, new_pos) = decode_zigzag64(data, pos)?;
+                    if self.repeated_sint64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_sint64.push(v);
+                    pos = new_pos;
+                }
+                290 => {
+                    let new_pos = decode_packed_zigzag64_with(data, pos, &mut self.repeated_sint64, opts)?;
+                    pos = new_pos;
+                }
+                301 => {
+                    let (v, new_pos) = decode_fixed32(data, pos)?;
+                    if self.repeated_fixed32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_fixed32.push(v);
+                    pos = new_pos;
+                }
+                298 => {
+                    let new_pos = decode_packed_fixed32_with(data, pos, &mut self.repeated_fixed32, opts)?;
+                    pos = new_pos;
+                }
+                305 => {
+                    let (v, new_pos) = decode_fixed64(data, pos)?;
+                    if self.repeated_fixed64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_fixed64.push(v);
+                    pos = new_pos;
+                }
+                306 => {
+                    let new_pos = decode_packed_fixed64_with(data, pos, &mut self.repeated_fixed64, opts)?;
+                    pos = new_pos;
+                }
+                317 => {
+                    let (v, new_pos) = decode_sfixed32(data, pos)?;
+                    if self.repeated_sfixed32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_sfixed32.push(v);
+                    pos = new_pos;
+                }
+                314 => {
+                    let new_pos = decode_packed_sfixed32_with(data, pos, &mut self.repeated_sfixed32, opts)?;
+                    pos = new_pos;
+                }
+                321 => {
+                    let (v, new_pos) = decode_sfixed64(data, pos)?;
+                    if self.repeated_sfixed64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_sfixed64.push(v);
+                    pos = new_pos;
+                }
+                322 => {
+                    let new_pos = decode_packed_sfixed64_with(data, pos, &mut self.repeated_sfixed64, opts)?;
+                    pos = new_pos;
+                }
+                333 => {
+                    let (v, new_pos) = decode_float(data, pos)?;
+                    if self.repeated_float.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_float.push(v);
+                    pos = new_pos;
+                }
+                330 => {
+                    let new_pos = decode_packed_float_with(data, pos, &mut self.repeated_float, opts)?;
+                    pos = new_pos;
+                }
+                337 => {
+                    let (v, new_pos) = decode_double(data, pos)?;
+                    if self.repeated_double.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_double.push(v);
+                    pos = new_pos;
+                }
+                338 => {
+                    let new_pos = decode_packed_double_with(data, pos, &mut self.repeated_double, opts)?;
+                    pos = new_pos;
+                }
+                344 => {
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    if self.repeated_bool.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_bool.push(v);
+                    pos = new_pos;
+                }
+                346 => {
+                    let new_pos = decode_packed_bool_with(data, pos, &mut self.repeated_bool, opts)?;
+                    pos = new_pos;
+                }
+                354 => {
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    if self.repeated_string.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_string.push(v);
+                    pos = new_pos;
+                }
+                362 => {
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    if self.repeated_bytes.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_bytes.push(v);
+                    pos = new_pos;
+                }
+                386 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_nested_message.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_nested_message.push(NestedMessage::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                394 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_foreign_message.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_foreign_message.push(ForeignMessage::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                408 => {
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    if self.repeated_nested_enum.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_nested_enum.push(v);
+                    pos = new_pos;
+                }
+                410 => {
+                    let new_pos = decode_packed_int32_with(data, pos, &mut self.repeated_nested_enum, opts)?;
+                    pos = new_pos;
+                }
+                416 => {
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    if self.repeated_foreign_enum.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_foreign_enum.push(v);
+                    pos = new_pos;
+                }
+                418 => {
+                    let new_pos = decode_packed_int32_with(data, pos, &mut self.repeated_foreign_enum, opts)?;
+                    pos = new_pos;
+                }
+                434 => {
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    if self.repeated_string_piece.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_string_piece.push(v);
+                    pos = new_pos;
+                }
+                442 => {
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    if self.repeated_cord.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_cord.push(v);
+                    pos = new_pos;
+                }
+                600 => {
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    if self.packed_int32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_int32.push(v);
+                    pos = new_pos;
+                }
+                602 => {
+                    let new_pos = decode_packed_int32_with(data, pos, &mut self.packed_int32, opts)?;
+                    pos = new_pos;
+                }
+                608 => {
+                    let (v, new_pos) = decode_int64(data, pos)?;
+                    if self.packed_int64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_int64.push(v);
+                    pos = new_pos;
+                }
+                610 => {
+                    let new_pos = decode_packed_int64_with(data, pos, &mut self.packed_int64, opts)?;
+                    pos = new_pos;
+                }
+                616 => {
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    if self.packed_uint32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_uint32.push(v as u32);
+                    pos = new_pos;
+                }
+                618 => {
+                    let new_pos = decode_packed_uint32_with(data, pos, &mut self.packed_uint32, opts)?;
+                    pos = new_pos;
+                }
+                624 => {
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    if self.packed_uint64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_uint64.push(v);
+                    pos = new_pos;
+                }
+                626 => {
+                    let new_pos = decode_packed_varint_with(data, pos, &mut self.packed_uint64, opts)?;
+                    pos = new_pos;
+                }
+                632 => {
+                    let (v, new_pos) = decode_zigzag32(data, pos)?;
+                    if self.packed_sint32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_sint32.push(v);
+                    pos = new_pos;
+                }
+                634 => {
+                    let new_pos = decode_packed_zigzag32_with(data, pos, &mut self.packed_sint32, opts)?;
+                    pos = new_pos;
+                }
+                640 => {
+                    let (v, new_pos) = decode_zigzag64(data, pos)?;
+                    if self.packed_sint64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_sint64.push(v);
+                    pos = new_pos;
+                }
+                642 => {
+                    let new_pos = decode_packed_zigzag64_with(data, pos, &mut self.packed_sint64, opts)?;
+                    pos = new_pos;
+                }
+                653 => {
+                    let (v, new_pos) = decode_fixed32(data, pos)?;
+                    if self.packed_fixed32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_fixed32.push(v);
+                    pos = new_pos;
+                }
+                650 => {
+                    let new_pos = decode_packed_fixed32_with(data, pos, &mut self.packed_fixed32, opts)?;
+                    pos = new_pos;
+                }
+                657 => {
+                    let (v, new_pos) = decode_fixed64(data, pos)?;
+                    if self.packed_fixed64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_fixed64.push(v);
+                    pos = new_pos;
+                }
+                658 => {
+                    let new_pos = decode_packed_fixed64_with(data, pos, &mut self.packed_fixed64, opts)?;
+                    pos = new_pos;
+                }
+                669 => {
+                    let (v, new_pos) = decode_sfixed32(data, pos)?;
+                    if self.packed_sfixed32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_sfixed32.push(v);
+                    pos = new_pos;
+                }
+                666 => {
+                    let new_pos = decode_packed_sfixed32_with(data, pos, &mut self.packed_sfixed32, opts)?;
+                    pos = new_pos;
+                }
+                673 => {
+                    let (v, new_pos) = decode_sfixed64(data, pos)?;
+                    if self.packed_sfixed64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_sfixed64.push(v);
+                    pos = new_pos;
+                }
+                674 => {
+                    let new_pos = decode_packed_sfixed64_with(data, pos, &mut self.packed_sfixed64, opts)?;
+                    pos = new_pos;
+                }
+                685 => {
+                    let (v, new_pos) = decode_float(data, pos)?;
+                    if self.packed_float.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_float.push(v);
+                    pos = new_pos;
+                }
+                682 => {
+                    let new_pos = decode_packed_float_with(data, pos, &mut self.packed_float, opts)?;
+                    pos = new_pos;
+                }
+                689 => {
+                    let (v, new_pos) = decode_double(data, pos)?;
+                    if self.packed_double.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_double.push(v);
+                    pos = new_pos;
+                }
+                690 => {
+                    let new_pos = decode_packed_double_with(data, pos, &mut self.packed_double, opts)?;
+                    pos = new_pos;
+                }
+                696 => {
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    if self.packed_bool.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_bool.push(v);
+                    pos = new_pos;
+                }
+                698 => {
+                    let new_pos = decode_packed_bool_with(data, pos, &mut self.packed_bool, opts)?;
+                    pos = new_pos;
+                }
+                704 => {
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    if self.packed_nested_enum.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.packed_nested_enum.push(v);
+                    pos = new_pos;
+                }
+                706 => {
+                    let new_pos = decode_packed_int32_with(data, pos, &mut self.packed_nested_enum, opts)?;
+                    pos = new_pos;
+                }
+                712 => {
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    if self.unpacked_int32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_int32.push(v);
+                    pos = new_pos;
+                }
+                714 => {
+                    let new_pos = decode_packed_int32_with(data, pos, &mut self.unpacked_int32, opts)?;
+                    pos = new_pos;
+                }
+                720 => {
+                    let (v, new_pos) = decode_int64(data, pos)?;
+                    if self.unpacked_int64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_int64.push(v);
+                    pos = new_pos;
+                }
+                722 => {
+                    let new_pos = decode_packed_int64_with(data, pos, &mut self.unpacked_int64, opts)?;
+                    pos = new_pos;
+                }
+                728 => {
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    if self.unpacked_uint32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_uint32.push(v as u32);
+                    pos = new_pos;
+                }
+                730 => {
+                    let new_pos = decode_packed_uint32_with(data, pos, &mut self.unpacked_uint32, opts)?;
+                    pos = new_pos;
+                }
+                736 => {
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    if self.unpacked_uint64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_uint64.push(v);
+                    pos = new_pos;
+                }
+                738 => {
+                    let new_pos = decode_packed_varint_with(data, pos, &mut self.unpacked_uint64, opts)?;
+                    pos = new_pos;
+                }
+                744 => {
+                    let (v, new_pos) = decode_zigzag32(data, pos)?;
+                    if self.unpacked_sint32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_sint32.push(v);
+                    pos = new_pos;
+                }
+                746 => {
+                    let new_pos = decode_packed_zigzag32_with(data, pos, &mut self.unpacked_sint32, opts)?;
+                    pos = new_pos;
+                }
+                752 => {
+                    let (v, new_pos) = decode_zigzag64(data, pos)?;
+                    if self.unpacked_sint64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_sint64.push(v);
+                    pos = new_pos;
+                }
+                754 => {
+                    let new_pos = decode_packed_zigzag64_with(data, pos, &mut self.unpacked_sint64, opts)?;
+                    pos = new_pos;
+                }
+                765 => {
+                    let (v, new_pos) = decode_fixed32(data, pos)?;
+                    if self.unpacked_fixed32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_fixed32.push(v);
+                    pos = new_pos;
+                }
+                762 => {
+                    let new_pos = decode_packed_fixed32_with(data, pos, &mut self.unpacked_fixed32, opts)?;
+                    pos = new_pos;
+                }
+                769 => {
+                    let (v, new_pos) = decode_fixed64(data, pos)?;
+                    if self.unpacked_fixed64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_fixed64.push(v);
+                    pos = new_pos;
+                }
+                770 => {
+                    let new_pos = decode_packed_fixed64_with(data, pos, &mut self.unpacked_fixed64, opts)?;
+                    pos = new_pos;
+                }
+                781 => {
+                    let (v, new_pos) = decode_sfixed32(data, pos)?;
+                    if self.unpacked_sfixed32.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_sfixed32.push(v);
+                    pos = new_pos;
+                }
+                778 => {
+                    let new_pos = decode_packed_sfixed32_with(data, pos, &mut self.unpacked_sfixed32, opts)?;
+                    pos = new_pos;
+                }
+                785 => {
+                    let (v, new_pos) = decode_sfixed64(data, pos)?;
+                    if self.unpacked_sfixed64.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_sfixed64.push(v);
+                    pos = new_pos;
+                }
+                786 => {
+                    let new_pos = decode_packed_sfixed64_with(data, pos, &mut self.unpacked_sfixed64, opts)?;
+                    pos = new_pos;
+                }
+                797 => {
+                    let (v, new_pos) = decode_float(data, pos)?;
+                    if self.unpacked_float.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_float.push(v);
+                    pos = new_pos;
+                }
+                794 => {
+                    let new_pos = decode_packed_float_with(data, pos, &mut self.unpacked_float, opts)?;
+                    pos = new_pos;
+                }
+                801 => {
+                    let (v, new_pos) = decode_double(data, pos)?;
+                    if self.unpacked_double.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_double.push(v);
+                    pos = new_pos;
+                }
+                802 => {
+                    let new_pos = decode_packed_double_with(data, pos, &mut self.unpacked_double, opts)?;
+                    pos = new_pos;
+                }
+                808 => {
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    if self.unpacked_bool.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_bool.push(v);
+                    pos = new_pos;
+                }
+                810 => {
+                    let new_pos = decode_packed_bool_with(data, pos, &mut self.unpacked_bool, opts)?;
+                    pos = new_pos;
+                }
+                816 => {
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    if self.unpacked_nested_enum.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.unpacked_nested_enum.push(v);
+                    pos = new_pos;
+                }
+                818 => {
+                    let new_pos = decode_packed_int32_with(data, pos, &mut self.unpacked_nested_enum, opts)?;
+                    pos = new_pos;
+                }
+                450 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_int32(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_int32(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_int32_int32.len() >= opts.max_repeated_len && !self.map_int32_int32.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_int32_int32.insert(key, value);
+                    pos = new_pos;
+                }
+                458 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_int64(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_int64(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_int64_int64.len() >= opts.max_repeated_len && !self.map_int64_int64.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_int64_int64.insert(key, value);
+                    pos = new_pos;
+                }
+                466 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_varint(entry, entry_pos)?;
+                                key = v as u32;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_varint(entry, entry_pos)?;
+                                value = v as u32;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_uint32_uint32.len() >= opts.max_repeated_len && !self.map_uint32_uint32.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_uint32_uint32.insert(key, value);
+                    pos = new_pos;
+                }
+                474 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_varint(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_varint(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_uint64_uint64.len() >= opts.max_repeated_len && !self.map_uint64_uint64.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_uint64_uint64.insert(key, value);
+                    pos = new_pos;
+                }
+                482 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_zigzag32(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_zigzag32(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_sint32_sint32.len() >= opts.max_repeated_len && !self.map_sint32_sint32.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_sint32_sint32.insert(key, value);
+                    pos = new_pos;
+                }
+                490 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_zigzag64(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_zigzag64(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_sint64_sint64.len() >= opts.max_repeated_len && !self.map_sint64_sint64.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_sint64_sint64.insert(key, value);
+                    pos = new_pos;
+                }
+                498 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            13 => {
+                                let (v, next) = decode_fixed32(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            21 => {
+                                let (v, next) = decode_fixed32(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_fixed32_fixed32.len() >= opts.max_repeated_len && !self.map_fixed32_fixed32.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_fixed32_fixed32.insert(key, value);
+                    pos = new_pos;
+                }
+                506 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            9 => {
+                                let (v, next) = decode_fixed64(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            17 => {
+                                let (v, next) = decode_fixed64(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_fixed64_fixed64.len() >= opts.max_repeated_len && !self.map_fixed64_fixed64.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_fixed64_fixed64.insert(key, value);
+                    pos = new_pos;
+                }
+                514 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            13 => {
+                                let (v, next) = decode_sfixed32(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            21 => {
+                                let (v, next) = decode_sfixed32(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_sfixed32_sfixed32.len() >= opts.max_repeated_len && !self.map_sfixed32_sfixed32.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_sfixed32_sfixed32.insert(key, value);
+                    pos = new_pos;
+                }
+                522 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            9 => {
+                                let (v, next) = decode_sfixed64(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            17 => {
+                                let (v, next) = decode_sfixed64(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_sfixed64_sfixed64.len() >= opts.max_repeated_len && !self.map_sfixed64_sfixed64.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_sfixed64_sfixed64.insert(key, value);
+                    pos = new_pos;
+                }
+                530 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0.0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_int32(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            21 => {
+                                let (v, next) = decode_float(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_int32_float.len() >= opts.max_repeated_len && !self.map_int32_float.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_int32_float.insert(key, value);
+                    pos = new_pos;
+                }
+                538 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = 0;
+                    let mut value = 0.0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_int32(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            17 => {
+                                let (v, next) = decode_double(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_int32_double.len() >= opts.max_repeated_len && !self.map_int32_double.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_int32_double.insert(key, value);
+                    pos = new_pos;
+                }
+                546 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = false;
+                    let mut value = false;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            8 => {
+                                let (v, next) = decode_bool(entry, entry_pos)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_bool(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_bool_bool.len() >= opts.max_repeated_len && !self.map_bool_bool.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_bool_bool.insert(key, value);
+                    pos = new_pos;
+                }
+                554 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = String::new();
+                    let mut value = String::new();
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            10 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            18 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_string_string.len() >= opts.max_repeated_len && !self.map_string_string.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_string_string.insert(key, value);
+                    pos = new_pos;
+                }
+                562 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = String::new();
+                    let mut value = Vec::new();
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            10 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            18 => {
+                                let (v, next) = decode_bytes_with(entry, entry_pos, opts)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_string_bytes.len() >= opts.max_repeated_len && !self.map_string_bytes.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_string_bytes.insert(key, value);
+                    pos = new_pos;
+                }
+                570 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = String::new();
+                    let mut value = NestedMessage::DEFAULT;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            10 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            18 => {
+                                let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                value.merge_with(sub, &opts.nested()?)?;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_string_nested_message.len() >= opts.max_repeated_len && !self.map_string_nested_message.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_string_nested_message.insert(key, value);
+                    pos = new_pos;
+                }
+                578 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = String::new();
+                    let mut value = ForeignMessage::DEFAULT;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            10 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            18 => {
+                                let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                value.merge_with(sub, &opts.nested()?)?;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_string_foreign_message.len() >= opts.max_repeated_len && !self.map_string_foreign_message.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_string_foreign_message.insert(key, value);
+                    pos = new_pos;
+                }
+                586 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = String::new();
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            10 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_int32(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_string_nested_enum.len() >= opts.max_repeated_len && !self.map_string_nested_enum.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_string_nested_enum.insert(key, value);
+                    pos = new_pos;
+                }
+                594 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = String::new();
+                    let mut value = 0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            10 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            16 => {
+                                let (v, next) = decode_int32(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.map_string_foreign_enum.len() >= opts.max_repeated_len && !self.map_string_foreign_enum.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.map_string_foreign_enum.insert(key, value);
+                    pos = new_pos;
+                }
+                888 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.oneof_field = TestAllTypesProto3OneofField::OneofUint32(v as u32);
+                    pos = new_pos;
+                }
+                898 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if let TestAllTypesProto3OneofField::OneofNestedMessage(existing) = &mut self.oneof_field {
+                        existing.merge_with(sub, &opts.nested()?)?;
+                    } else {
+                        self.oneof_field = TestAllTypesProto3OneofField::OneofNestedMessage(NestedMessage::decode_with(sub, &opts.nested()?)?.into());
+                    }
+                    pos = new_pos;
+                }
+                906 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.oneof_field = TestAllTypesProto3OneofField::OneofString(v);
+                    pos = new_pos;
+                }
+                914 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.oneof_field = TestAllTypesProto3OneofField::OneofBytes(v);
+                    pos = new_pos;
+                }
+                920 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    self.oneof_field = TestAllTypesProto3OneofField::OneofBool(v);
+                    pos = new_pos;
+                }
+                928 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.oneof_field = TestAllTypesProto3OneofField::OneofUint64(v);
+                    pos = new_pos;
+                }
+                941 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (v, new_pos) = decode_float(data, pos)?;
+                    self.oneof_field = TestAllTypesProto3OneofField::OneofFloat(v);
+                    pos = new_pos;
+                }
+                945 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (v, new_pos) = decode_double(data, pos)?;
+                    self.oneof_field = TestAllTypesProto3OneofField::OneofDouble(v);
+                    pos = new_pos;
+                }
+                952 => {
+                    mark_singular(&mut seen, 23, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.oneof_field = TestAllTypesProto3OneofField::OneofEnum(v);
+                    pos = new_pos;
+                }
+                1610 => {
+                    mark_singular(&mut seen, 24, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_bool_wrapper.get_or_insert_with(Default::default);
+                    BoolValue::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1618 => {
+                    mark_singular(&mut seen, 25, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_int32_wrapper.get_or_insert_with(Default::default);
+                    Int32Value::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1626 => {
+                    mark_singular(&mut seen, 26, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_int64_wrapper.get_or_insert_with(Default::default);
+                    Int64Value::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1634 => {
+                    mark_singular(&mut seen, 27, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_uint32_wrapper.get_or_insert_with(Default::default);
+                    UInt32Value::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1642 => {
+                    mark_singular(&mut seen, 28, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_uint64_wrapper.get_or_insert_with(Default::default);
+                    UInt64Value::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1650 => {
+                    mark_singular(&mut seen, 29, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_float_wrapper.get_or_insert_with(Default::default);
+                    FloatValue::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1658 => {
+                    mark_singular(&mut seen, 30, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_double_wrapper.get_or_insert_with(Default::default);
+                    DoubleValue::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1666 => {
+                    mark_singular(&mut seen, 31, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_string_wrapper.get_or_insert_with(Default::default);
+                    StringValue::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1674 => {
+                    mark_singular(&mut seen, 32, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.optional_bytes_wrapper.get_or_insert_with(Default::default);
+                    BytesValue::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                1690 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_bool_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_bool_wrapper.push(BoolValue::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                1698 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_int32_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_int32_wrapper.push(Int32Value::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                1706 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_int64_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_int64_wrapper.push(Int64Value::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                1714 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_uint32_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_uint32_wrapper.push(UInt32Value::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                1722 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_uint64_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_uint64_wrapper.push(UInt64Value::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                1730 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_float_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_float_wrapper.push(FloatValue::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                1738 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_double_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_double_wrapper.push(DoubleValue::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                1746 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_string_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_string_wrapper.push(StringValue::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                1754 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_bytes_wrapper.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_bytes_wrapper.push(BytesValue::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                2410 => {
+                    mark_singular(&mut seen, 33, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.optional_duration.merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                2418 => {
+                    mark_singular(&mut seen, 34, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.optional_timestamp.merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                2442 => {
+                    mark_singular(&mut seen, 35, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.optional_any.merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                2490 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_duration.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_duration.push(Duration::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                2498 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_timestamp.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_timestamp.push(Timestamp::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                2522 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.repeated_any.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.repeated_any.push(Any::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                    self.unknown_fields.push_raw(&data[field_start..pos]);
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The element of repeated field `number` being decoded, for `field_error`.
+    fn next_index(&self, number: u64) -> Option<usize> {
+        match number {
+            31 => Some(self.repeated_int32.len()),
+            32 => Some(self.repeated_int64.len()),
+            33 => Some(self.repeated_uint32.len()),
+            34 => Some(self.repeated_uint64.len()),
+            35 => Some(self.repeated_sint32.len()),
+            36 => Some(self.repeated_sint64.len()),
+            37 => Some(self.repeated_fixed32.len()),
+            38 => Some(self.repeated_fixed64.len()),
+            39 => Some(self.repeated_sfixed32.len()),
+            40 => Some(self.repeated_sfixed64.len()),
+            41 => Some(self.repeated_float.len()),
+            42 => Some(self.repeated_double.len()),
+            43 => Some(self.repeated_bool.len()),
+            44 => Some(self.repeated_string.len()),
+            45 => Some(self.repeated_bytes.len()),
+            48 => Some(self.repeated_nested_message.len()),
+            49 => Some(self.repeated_foreign_message.len()),
+            51 => Some(self.repeated_nested_enum.len()),
+            52 => Some(self.repeated_foreign_enum.len()),
+            54 => Some(self.repeated_string_piece.len()),
+            55 => Some(self.repeated_cord.len()),
+            75 => Some(self.packed_int32.len()),
+            76 => Some(self.packed_int64.len()),
+            77 => Some(self.packed_uint32.len()),
+            78 => Some(self.packed_uint64.len()),
+            79 => Some(self.packed_sint32.len()),
+            80 => Some(self.packed_sint64.len()),
+            81 => Some(self.packed_fixed32.len()),
+            82 => Some(self.packed_fixed64.len()),
+            83 => Some(self.packed_sfixed32.len()),
+            84 => Some(self.packed_sfixed64.len()),
+            85 => Some(self.packed_float.len()),
+            86 => Some(self.packed_double.len()),
+            87 => Some(self.packed_bool.len()),
+            88 => Some(self.packed_nested_enum.len()),
+            89 => Some(self.unpacked_int32.len()),
+            90 => Some(self.unpacked_int64.len()),
+            91 => Some(self.unpacked_uint32.len()),
+            92 => Some(self.unpacked_uint64.len()),
+            93 => Some(self.unpacked_sint32.len()),
+            94 => Some(self.unpacked_sint64.len()),
+            95 => Some(self.unpacked_fixed32.len()),
+            96 => Some(self.unpacked_fixed64.len()),
+            97 => Some(self.unpacked_sfixed32.len()),
+            98 => Some(self.unpacked_sfixed64.len()),
+            99 => Some(self.unpacked_float.len()),
+            100 => Some(self.unpacked_double.len()),
+            101 => Some(self.unpacked_bool.len()),
+            102 => Some(self.unpacked_nested_enum.len()),
+            211 => Some(self.repeated_bool_wrapper.len()),
+            212 => Some(self.repeated_int32_wrapper.len()),
+            213 => Some(self.repeated_int64_wrapper.len()),
+            214 => Some(self.repeated_uint32_wrapper.len()),
+            215 => Some(self.repeated_uint64_wrapper.len()),
+            216 => Some(self.repeated_float_wrapper.len()),
+            217 => Some(self.repeated_double_wrapper.len()),
+            218 => Some(self.repeated_string_wrapper.len()),
+            219 => Some(self.repeated_bytes_wrapper.len()),
+            311 => Some(self.repeated_duration.len()),
+            312 => Some(self.repeated_timestamp.len()),
+            315 => Some(self.repeated_any.len()),
+            _ => None,
+        }
+    }
+}
+
+impl Message for TestAllTypesProto3 {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        TestAllTypesProto3::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        TestAllTypesProto3::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        TestAllTypesProto3::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        TestAllTypesProto3::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        TestAllTypesProto3::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        TestAllTypesProto3::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        TestAllTypesProto3::merge_with(self, data, opts)
+    }
+
+    fn encode_canonical(&self) -> Vec<u8> {
+        let mut msg = self.clone();
+        msg.clear_unknown_fields();
+        msg.encode()
+    }
+
+    fn clear_unknown_fields(&mut self) {
+        self.unknown_fields.clear();
+        if let Some(v) = &mut self.optional_nested_message {
+            v.clear_unknown_fields();
+        }
+        self.optional_foreign_message.clear_unknown_fields();
+        if let Some(v) = &mut self.recursive_message {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_nested_message {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_foreign_message {
+            v.clear_unknown_fields();
+        }
+        for v in self.map_string_nested_message.values_mut() {
+            v.clear_unknown_fields();
+        }
+        for v in self.map_string_foreign_message.values_mut() {
+            v.clear_unknown_fields();
+        }
+        if let TestAllTypesProto3OneofField::OneofNestedMessage(v) = &mut self.oneof_field {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_bool_wrapper {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_int32_wrapper {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_int64_wrapper {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_uint32_wrapper {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_uint64_wrapper {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_float_wrapper {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_double_wrapper {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_string_wrapper {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_bytes_wrapper {
+            v.clear_unknown_fields();
+        }
+        self.optional_duration.clear_unknown_fields();
+        self.optional_timestamp.clear_unknown_fields();
+        self.optional_any.clear_unknown_fields();
+        for v in &mut self.repeated_duration {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_timestamp {
+            v.clear_unknown_fields();
+        }
+        for v in &mut self.repeated_any {
+            v.clear_unknown_fields();
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for TestAllTypesProto3 {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        TestAllTypesProto3::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<TestAllTypesProto3> for Vec<u8> {
+    fn from(msg: TestAllTypesProto3) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for TestAllTypesProto3 {
+    const FULL_NAME: &'static str = "protobuf_test_messages.proto3.TestAllTypesProto3";
+}
+
+impl TestAllTypesProto3 {
+    pub fn oneof_uint32(&self) -> Option<&u32> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofUint32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_uint32(&mut self, value: u32) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofUint32(value);
+    }
+
+    pub fn oneof_nested_message(&self) -> Option<&NestedMessage> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofNestedMessage(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_nested_message(&mut self, value: NestedMessage) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofNestedMessage(value.into());
+    }
+
+    pub fn oneof_string(&self) -> Option<&String> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofString(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_string(&mut self, value: String) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofString(value);
+    }
+
+    pub fn oneof_bytes(&self) -> Option<&Vec<u8>> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofBytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_bytes(&mut self, value: Vec<u8>) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofBytes(value);
+    }
+
+    pub fn oneof_bool(&self) -> Option<&bool> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofBool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_bool(&mut self, value: bool) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofBool(value);
+    }
+
+    pub fn oneof_uint64(&self) -> Option<&u64> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofUint64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_uint64(&mut self, value: u64) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofUint64(value);
+    }
+
+    pub fn oneof_float(&self) -> Option<&f32> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofFloat(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_float(&mut self, value: f32) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofFloat(value);
+    }
+
+    pub fn oneof_double(&self) -> Option<&f64> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofDouble(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_double(&mut self, value: f64) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofDouble(value);
+    }
+
+    pub fn oneof_enum(&self) -> Option<&i32> {
+        match &self.oneof_field {
+            TestAllTypesProto3OneofField::OneofEnum(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_oneof_enum(&mut self, value: i32) {
+        self.oneof_field = TestAllTypesProto3OneofField::OneofEnum(value);
+    }
+}
+
+impl TestAllTypesProto3 {
+    pub fn optional_nested_enum(&self) -> Result<NestedEnum, i32> {
+        NestedEnum::try_from(self.optional_nested_enum)
+    }
+
+    pub fn set_optional_nested_enum(&mut self, value: NestedEnum) {
+        self.optional_nested_enum = value.into();
+    }
+
+    pub fn optional_foreign_enum(&self) -> Result<ForeignEnum, i32> {
+        ForeignEnum::try_from(self.optional_foreign_enum)
+    }
+
+    pub fn set_optional_foreign_enum(&mut self, value: ForeignEnum) {
+        self.optional_foreign_enum = value.into();
+    }
+
+    pub fn optional_aliased_enum(&self) -> Result<AliasedEnum, i32> {
+        AliasedEnum::try_from(self.optional_aliased_enum)
+    }
+
+    pub fn set_optional_aliased_enum(&mut self, value: AliasedEnum) {
+        self.optional_aliased_enum = value.into();
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TestAllTypesProto3Builder {
+    inner: TestAllTypesProto3,
+}
+
+impl TestAllTypesProto3 {
+    pub fn builder() -> TestAllTypesProto3Builder {
+        TestAllTypesProto3Builder::default()
+    }
+}
+
+impl TestAllTypesProto3Builder {
+    pub fn with_optional_int32(mut self, value: i32) -> Self {
+        self.inner.optional_int32 = value;
+        self
+    }
+
+    pub fn with_optional_int64(mut self, value: i64) -> Self {
+        self.inner.optional_int64 = value;
+        self
+    }
+
+    pub fn with_optional_uint32(mut self, value: u32) -> Self {
+        self.inner.optional_uint32 = value;
+        self
+    }
+
+    pub fn with_optional_uint64(mut self, value: u64) -> Self {
+        self.inner.optional_uint64 = value;
+        self
+    }
+
+    pub fn with_optional_sint32(mut self, value: i32) -> Self {
+        self.inner.optional_sint32 = value;
+        self
+    }
+
+    pub fn with_optional_sint64(mut self, value: i64) -> Self {
+        self.inner.optional_sint64 = value;
+        self
+    }
+
+    pub fn with_optional_fixed32(mut self, value: u32) -> Self {
+        self.inner.optional_fixed32 = value;
+        self
+    }
+
+    pub fn with_optional_fixed64(mut self, value: u64) -> Self {
+        self.inner.optional_fixed64 = value;
+        self
+    }
+
+    pub fn with_optional_sfixed32(mut self, value: i32) -> Self {
+        self.inner.optional_sfixed32 = value;
+        self
+    }
+
+    pub fn with_optional_sfixed64(mut self, value: i64) -> Self {
+        self.inner.optional_sfixed64 = value;
+        self
+    }
+
+    pub fn with_optional_float(mut self, value: f32) -> Self {
+        self.inner.optional_float = value;
+        self
+    }
+
+    pub fn with_optional_double(mut self, value: f64) -> Self {
+        self.inner.optional_double = value;
+        self
+    }
+
+    pub fn with_optional_bool(mut self, value: bool) -> Self {
+        self.inner.optional_bool = value;
+        self
+    }
+
+    pub fn with_optional_string(mut self, value: String) -> Self {
+        self.inner.optional_string = value;
+        self
+    }
+
+    pub fn with_optional_bytes(mut self, value: Vec<u8>) -> Self {
+        self.inner.optional_bytes = value;
+        self
+    }
+
+    pub fn with_optional_nested_message(mut self, value: NestedMessage) -> Self {
+        self.inner.optional_nested_message = Some(value.into());
+        self
+    }
+
+    pub fn with_optional_foreign_message(mut self, value: ForeignMessage) -> Self {
+        self.inner.optional_foreign_message = value;
+        self
+    }
+
+    pub fn with_optional_nested_enum(mut self, value: i32) -> Self {
+        self.inner.optional_nested_enum = value;
+        self
+    }
+
+    pub fn with_optional_foreign_enum(mut self, value: i32) -> Self {
+        self.inner.optional_foreign_enum = value;
+        self
+    }
+
+    pub fn with_optional_aliased_enum(mut self, value: i32) -> Self {
+        self.inner.optional_aliased_enum = value;
+        self
+    }
+
+    pub fn with_optional_string_piece(mut self, value: String) -> Self {
+        self.inner.optional_string_piece = value;
+        self
+    }
+
+    pub fn with_optional_cord(mut self, value: String) -> Self {
+        self.inner.optional_cord = value;
+        self
+    }
+
+    pub fn with_recursive_message(mut self, value: TestAllTypesProto3) -> Self {
+        self.inner.recursive_message = Some(value.into());
+        self
+    }
+
+    pub fn with_repeated_int32(mut self, value: Vec<i32>) -> Self {
+        self.inner.repeated_int32 = value;
+        self
+    }
+
+    pub fn add_repeated_int32(mut self, value: i32) -> Self {
+        self.inner.repeated_int32.push(value);
+        self
+    }
+
+    pub fn with_repeated_int64(mut self, value: Vec<i64>) -> Self {
+        self.inner.repeated_int64 = value;
+        self
+    }
+
+    pub fn add_repeated_int64(mut self, value: i64) -> Self {
+        self.inner.repeated_int64.push(value);
+        self
+    }
+
+    pub fn with_repeated_uint32(mut self, value: Vec<u32>) -> Self {
+        self.inner.repeated_uint32 = value;
+        self
+    }
+
+    pub fn add_repeated_uint32(mut self, value: u32) -> Self {
+        self.inner.repeated_uint32.push(value);
+        self
+    }
+
+    pub fn with_repeated_uint64(mut self, value: Vec<u64>) -> Self {
+        self.inner.repeated_uint64 = value;
+        self
+    }
+
+    pub fn add_repeated_uint64(mut self, value: u64) -> Self {
+        self.inner.repeated_uint64.push(value);
+        self
+    }
+
+    pub fn with_repeated_sint32(mut self, value: Vec<i32>) -> Self {
+        self.inner.repeated_sint32 = value;
+        self
+    }
+
+    pub fn add_repeated_sint32(mut self, value: i32) -> Self {
+        self.inner.repeated_sint32.push(value);
+        self
+    }
+
+    pub fn with_repeated_sint64(mut self, value: Vec<i64>) -> Self {
+        self.inner.repeated_sint64 = value;
+        self
+    }
+
+    pub fn add_repeated_sint64(mut self, value: i64) -> Self {
+        self.inner.repeated_sint64.push(value);
+        self
+    }
+
+    pub fn with_repeated_fixed32(mut self, value: Vec<u32>) -> Self {
+        self.inner.repeated_fixed32 = value;
+        self
+    }
+
+    pub fn add_repeated_fixed32(mut self, value: u32) -> Self {
+        self.inner.repeated_fixed32.push(value);
+        self
+    }
+
+    pub fn with_repeated_fixed64(mut self, value: Vec<u64>) -> Self {
+        self.inner.repeated_fixed64 = value;
+        self
+    }
+
+    pub fn add_repeated_fixed64(mut self, value: u64) -> Self {
+        self.inner.repeated_fixed64.push(value);
+        self
+    }
+
+    pub fn with_repeated_sfixed32(mut self, value: Vec<i32>) -> Self {
+        self.inner.repeated_sfixed32 = value;
+        self
+    }
+
+    pub fn add_repeated_sfixed32(mut self, value: i32) -> Self {
+        self.inner.repeated_sfixed32.push(value);
+        self
+    }
+
+    pub fn with_repeated_sfixed64(mut self, value: Vec<i64>) -> Self {
+        self.inner.repeated_sfixed64 = value;
+        self
+    }
+
+    pub fn add_repeated_sfixed64(mut self, value: i64) -> Self {
+        self.inner.repeated_sfixed64.push(value);
+        self
+    }
+
+    pub fn with_repeated_float(mut self, value: Vec<f32>) -> Self {
+        self.inner.repeated_float = value;
+        self
+    }
+
+    pub fn add_repeated_float(mut self, value: f32) -> Self {
+        self.inner.repeated_float.push(value);
+        self
+    }
+
+    pub fn with_repeated_double(mut self, value: Vec<f64>) -> Self {
+        self.inner.repeated_double = value;
+        self
+    }
+
+    pub fn add_repeated_double(mut self, value: f64) -> Self {
+        self.inner.repeated_double.push(value);
+        self
+    }
+
+    pub fn with_repeated_bool(mut self, value: Vec<bool>) -> Self {
+        self.inner.repeated_bool = value;
+        self
+    }
+
+    pub fn add_repeated_bool(mut self, value: bool) -> Self {
+        self.inner.repeated_bool.push(value);
+        self
+    }
+
+    pub fn with_repeated_string(mut self, value: Vec<String>) -> Self {
+        self.inner.repeated_string = value;
+        self
+    }
+
+    pub fn add_repeated_string(mut self, value: String) -> Self {
+        self.inner.repeated_string.push(value);
+        self
+    }
+
+    pub fn with_repeated_bytes(mut self, value: Vec<Vec<u8>>) -> Self {
+        self.inner.repeated_bytes = value;
+        self
+    }
+
+    pub fn add_repeated_bytes(mut self, value: Vec<u8>) -> Self {
+        self.inner.repeated_bytes.push(value);
+        self
+    }
+
+    pub fn with_repeated_nested_message(mut self, value: Vec<NestedMessage>) -> Self {
+        self.inner.repeated_nested_message = value;
+        self
+    }
+
+    pub fn add_repeated_nested_message(mut self, value: NestedMessage) -> Self {
+        self.inner.repeated_nested_message.push(value);
+        self
+    }
+
+    pub fn with_repeated_foreign_message(mut self, value: Vec<ForeignMessage>) -> Self {
+        self.inner.repeated_foreign_message = value;
+        self
+    }
+
+    pub fn add_repeated_foreign_message(mut self, value: ForeignMessage) -> Self {
+        self.inner.repeated_foreign_message.push(value);
+        self
+    }
+
+    pub fn with_repeated_nested_enum(mut self, value: Vec<i32>) -> Self {
+        self.inner.repeated_nested_enum = value;
+        self
+    }
+
+    pub fn add_repeated_nested_enum(mut self, value: i32) -> Self {
+        self.inner.repeated_nested_enum.push(value);
+        self
+    }
+
+    pub fn with_repeated_foreign_enum(mut self, value: Vec<i32>) -> Self {
+        self.inner.repeated_foreign_enum = value;
+        self
+    }
+
+    pub fn add_repeated_foreign_enum(mut self, value: i32) -> Self {
+        self.inner.repeated_foreign_enum.push(value);
+        self
+    }
+
+    pub fn with_repeated_string_piece(mut self, value: Vec<String>) -> Self {
+        self.inner.repeated_string_piece = value;
+        self
+    }
+
+    pub fn add_repeated_string_piece(mut self, value: String) -> Self {
+        self.inner.repeated_string_piece.push(value);
+        self
+    }
+
+    pub fn with_repeated_cord(mut self, value: Vec<String>) -> Self {
+        self.inner.repeated_cord = value;
+        self
+    }
+
+    pub fn add_repeated_cord(mut self, value: String) -> Self {
+        self.inner.repeated_cord.push(value);
+        self
+    }
+
+    pub fn with_packed_int32(mut self, value: Vec<i32>) -> Self {
+        self.inner.packed_int32 = value;
+        self
+    }
+
+    pub fn add_packed_int32(mut self, value: i32) -> Self {
+        self.inner.packed_int32.push(value);
+        self
+    }
+
+    pub fn with_packed_int64(mut self, value: Vec<i64>) -> Self {
+        self.inner.packed_int64 = value;
+        self
+    }
+
+    pub fn add_packed_int64(mut self, value: i64) -> Self {
+        self.inner.packed_int64.push(value);
+        self
+    }
+
+    pub fn with_packed_uint32(mut self, value: Vec<u32>) -> Self {
+        self.inner.packed_uint32 = value;
+        self
+    }
+
+    pub fn add_packed_uint32(mut self, value: u32) -> Self {
+        self.inner.packed_uint32.push(value);
+        self
+    }
+
+    pub fn with_packed_uint64(mut self, value: Vec<u64>) -> Self {
+        self.inner.packed_uint64 = value;
+        self
+    }
+
+    pub fn add_packed_uint64(mut self, value: u64) -> Self {
+        self.inner.packed_uint64.push(value);
+        self
+    }
+
+    pub fn with_packed_sint32(mut self, value: Vec<i32>) -> Self {
+        self.inner.packed_sint32 = value;
+        self
+    }
+
+    pub fn add_packed_sint32(mut self, value: i32) -> Self {
+        self.inner.packed_sint32.push(value);
+        self
+    }
+
+    pub fn with_packed_sint64(mut self, value: Vec<i64>) -> Self {
+        self.inner.packed_sint64 = value;
+        self
+    }
+
+    pub fn add_packed_sint64(mut self, value: i64) -> Self {
+        self.inner.packed_sint64.push(value);
+        self
+    }
+
+    pub fn with_packed_fixed32(mut self, value: Vec<u32>) -> Self {
+        self.inner.packed_fixed32 = value;
+        self
+    }
+
+    pub fn add_packed_fixed32(mut self, value: u32) -> Self {
+        self.inner.packed_fixed32.push(value);
+        self
+    }
+
+    pub fn with_packed_fixed64(mut self, value: Vec<u64>) -> Self {
+        self.inner.packed_fixed64 = value;
+        self
+    }
+
+    pub fn add_packed_fixed64(mut self, value: u64) -> Self {
+        self.inner.packed_fixed64.push(value);
+        self
+    }
+
+    pub fn with_packed_sfixed32(mut self, value: Vec<i32>) -> Self {
+        self.inner.packed_sfixed32 = value;
+        self
+    }
+
+    pub fn add_packed_sfixed32(mut self, value: i32) -> Self {
+        self.inner.packed_sfixed32.push(value);
+        self
+    }
+
+    pub fn with_packed_sfixed64(mut self, value: Vec<i64>) -> Self {
+        self.inner.packed_sfixed64 = value;
+        self
+    }
+
+    pub fn add_packed_sfixed64(mut self, value: i64) -> Self {
+        self.inner.packed_sfixed64.push(value);
+        self
+    }
+
+    pub fn with_packed_float(mut self, value: Vec<f32>) -> Self {
+        self.inner.packed_float = value;
+        self
+    }
+
+    pub fn add_packed_float(mut self, value: f32) -> Self {
+        self.inner.packed_float.push(value);
+        self
+    }
+
+    pub fn with_packed_double(mut self, value: Vec<f64>) -> Self {
+        self.inner.packed_double = value;
+        self
+    }
+
+    pub fn add_packed_double(mut self, value: f64) -> Self {
+        self.inner.packed_double.push(value);
+        self
+    }
+
+    pub fn with_packed_bool(mut self, value: Vec<bool>) -> Self {
+        self.inner.packed_bool = value;
+        self
+    }
+
+    pub fn add_packed_bool(mut self, value: bool) -> Self {
+        self.inner.packed_bool.push(value);
+        self
+    }
+
+    pub fn with_packed_nested_enum(mut self, value: Vec<i32>) -> Self {
+        self.inner.packed_nested_enum = value;
+        self
+    }
+
+    pub fn add_packed_nested_enum(mut self, value: i32) -> Self {
+        self.inner.packed_nested_enum.push(value);
+        self
+    }
+
+    pub fn with_unpacked_int32(mut self, value: Vec<i32>) -> Self {
+        self.inner.unpacked_int32 = value;
+        self
+    }
+
+    pub fn add_unpacked_int32(mut self, value: i32) -> Self {
+        self.inner.unpacked_int32.push(value);
+        self
+    }
+
+    pub fn with_unpacked_int64(mut self, value: Vec<i64>) -> Self {
+        self.inner.unpacked_int64 = value;
+        self
+    }
+
+    pub fn add_unpacked_int64(mut self, value: i64) -> Self {
+        self.inner.unpacked_int64.push(value);
+        self
+    }
+
+    pub fn with_unpacked_uint32(mut self, value: Vec<u32>) -> Self {
+        self.inner.unpacked_uint32 = value;
+        self
+    }
+
+    pub fn add_unpacked_uint32(mut self, value: u32) -> Self {
+        self.inner.unpacked_uint32.push(value);
+        self
+    }
+
+    pub fn with_unpacked_uint64(mut self, value: Vec<u64>) -> Self {
+        self.inner.unpacked_uint64 = value;
+        self
+    }
+
+    pub fn add_unpacked_uint64(mut self, value: u64) -> Self {
+        self.inner.unpacked_uint64.push(value);
+        self
+    }
+
+    pub fn with_unpacked_sint32(mut self, value: Vec<i32>) -> Self {
+        self.inner.unpacked_sint32 = value;
+        self
+    }
+
+    pub fn add_unpacked_sint32(mut self, value: i32) -> Self {
+        self.inner.unpacked_sint32.push(value);
+        self
+    }
+
+    pub fn with_unpacked_sint64(mut self, value: Vec<i64>) -> Self {
+        self.inner.unpacked_sint64 = value;
+        self
+    }
+
+    pub fn add_unpacked_sint64(mut self, value: i64) -> Self {
+        self.inner.unpacked_sint64.push(value);
+        self
+    }
+
+    pub fn with_unpacked_fixed32(mut self, value: Vec<u32>) -> Self {
+        self.inner.unpacked_fixed32 = value;
+        self
+    }
+
+    pub fn add_unpacked_fixed32(mut self, value: u32) -> Self {
+        self.inner.unpacked_fixed32.push(value);
+        self
+    }
+
+    pub fn with_unpacked_fixed64(mut self, value: Vec<u64>) -> Self {
+        self.inner.unpacked_fixed64 = value;
+        self
+    }
+
+    pub fn add_unpacked_fixed64(mut self, value: u64) -> Self {
+        self.inner.unpacked_fixed64.push(value);
+        self
+    }
+
+    pub fn with_unpacked_sfixed32(mut self, value: Vec<i32>) -> Self {
+        self.inner.unpacked_sfixed32 = value;
+        self
+    }
+
+    pub fn add_unpacked_sfixed32(mut self, value: i32) -> Self {
+        self.inner.unpacked_sfixed32.push(value);
+        self
+    }
+
+    pub fn with_unpacked_sfixed64(mut self, value: Vec<i64>) -> Self {
+        self.inner.unpacked_sfixed64 = value;
+        self
+    }
+
+    pub fn add_unpacked_sfixed64(mut self, value: i64) -> Self {
+        self.inner.unpacked_sfixed64.push(value);
+        self
+    }
+
+    pub fn with_unpacked_float(mut self, value: Vec<f32>) -> Self {
+        self.inner.unpacked_float = value;
+        self
+    }
+
+    pub fn add_unpacked_float(mut self, value: f32) -> Self {
+        self.inner.unpacked_float.push(value);
+        self
+    }
+
+    pub fn with_unpacked_double(mut self, value: Vec<f64>) -> Self {
+        self.inner.unpacked_double = value;
+        self
+    }
+
+    pub fn add_unpacked_double(mut self, value: f64) -> Self {
+        self.inner.unpacked_double.push(value);
+        self
+    }
+
+    pub fn with_unpacked_bool(mut self, value: Vec<bool>) -> Self {
+        self.inner.unpacked_bool = value;
+        self
+    }
+
+    pub fn add_unpacked_bool(mut self, value: bool) -> Self {
+        self.inner.unpacked_bool.push(value);
+        self
+    }
+
+    pub fn with_unpacked_nested_enum(mut self, value: Vec<i32>) -> Self {
+        self.inner.unpacked_nested_enum = value;
+        self
+    }
+
+    pub fn add_unpacked_nested_enum(mut self, value: i32) -> Self {
+        self.inner.unpacked_nested_enum.push(value);
+        self
+    }
+
+    pub fn with_map_int32_int32(mut self, value: BTreeMap<i32, i32>) -> Self {
+        self.inner.map_int32_int32 = value;
+        self
+    }
+
+    pub fn insert_map_int32_int32(mut self, key: i32, value: i32) -> Self {
+        self.inner.map_int32_int32.insert(key, value);
+        self
+    }
+
+    pub fn with_map_int64_int64(mut self, value: BTreeMap<i64, i64>) -> Self {
+        self.inner.map_int64_int64 = value;
+        self
+    }
+
+    pub fn insert_map_int64_int64(mut self, key: i64, value: i64) -> Self {
+        self.inner.map_int64_int64.insert(key, value);
+        self
+    }
+
+    pub fn with_map_uint32_uint32(mut self, value: BTreeMap<u32, u32>) -> Self {
+        self.inner.map_uint32_uint32 = value;
+        self
+    }
+
+    pub fn insert_map_uint32_uint32(mut self, key: u32, value: u32) -> Self {
+        self.inner.map_uint32_uint32.insert(key, value);
+        self
+    }
+
+    pub fn with_map_uint64_uint64(mut self, value: BTreeMap<u64, u64>) -> Self {
+        self.inner.map_uint64_uint64 = value;
+        self
+    }
+
+    pub fn insert_map_uint64_uint64(mut self, key: u64, value: u64) -> Self {
+        self.inner.map_uint64_uint64.insert(key, value);
+        self
+    }
+
+    pub fn with_map_sint32_sint32(mut self, value: BTreeMap<i32, i32>) -> Self {
+        self.inner.map_sint32_sint32 = value;
+        self
+    }
+
+    pub fn insert_map_sint32_sint32(mut self, key: i32, value: i32) -> Self {
+        self.inner.map_sint32_sint32.insert(key, value);
+        self
+    }
+
+    pub fn with_map_sint64_sint64(mut self, value: BTreeMap<i64, i64>) -> Self {
+        self.inner.map_sint64_sint64 = value;
+        self
+    }
+
+    pub fn insert_map_sint64_sint64(mut self, key: i64, value: i64) -> Self {
+        self.inner.map_sint64_sint64.insert(key, value);
+        self
+    }
+
+    pub fn with_map_fixed32_fixed32(mut self, value: BTreeMap<u32, u32>) -> Self {
+        self.inner.map_fixed32_fixed32 = value;
+        self
+    }
+
+    pub fn insert_map_fixed32_fixed32(mut self, key: u32, value: u32) -> Self {
+        self.inner.map_fixed32_fixed32.insert(key, value);
+        self
+    }
+
+    pub fn with_map_fixed64_fixed64(mut self, value: BTreeMap<u64, u64>) -> Self {
+        self.inner.map_fixed64_fixed64 = value;
+        self
+    }
+
+    pub fn insert_map_fixed64_fixed64(mut self, key: u64, value: u64) -> Self {
+        self.inner.map_fixed64_fixed64.insert(key, value);
+        self
+    }
+
+    pub fn with_map_sfixed32_sfixed32(mut self, value: BTreeMap<i32, i32>) -> Self {
+        self.inner.map_sfixed32_sfixed32 = value;
+        self
+    }
+
+    pub fn insert_map_sfixed32_sfixed32(mut self, key: i32, value: i32) -> Self {
+        self.inner.map_sfixed32_sfixed32.insert(key, value);
+        self
+    }
+
+    pub fn with_map_sfixed64_sfixed64(mut self, value: BTreeMap<i64, i64>) -> Self {
+        self.inner.map_sfixed64_sfixed64 = value;
+        self
+    }
+
+    pub fn insert_map_sfixed64_sfixed64(mut self, key: i64, value: i64) -> Self {
+        self.inner.map_sfixed64_sfixed64.insert(key, value);
+        self
+    }
+
+    pub fn with_map_int32_float(mut self, value: BTreeMap<i32, f32>) -> Self {
+        self.inner.map_int32_float = value;
+        self
+    }
+
+    pub fn insert_map_int32_float(mut self, key: i32, value: f32) -> Self {
+        self.inner.map_int32_float.insert(key, value);
+        self
+    }
+
+    pub fn with_map_int32_double(mut self, value: BTreeMap<i32, f64>) -> Self {
+        self.inner.map_int32_double = value;
+        self
+    }
+
+    pub fn insert_map_int32_double(mut self, key: i32, value: f64) -> Self {
+        self.inner.map_int32_double.insert(key, value);
+        self
+    }
+
+    pub fn with_map_bool_bool(mut self, value: BTreeMap<bool, bool>) -> Self {
+        self.inner.map_bool_bool = value;
+        self
+    }
+
+    pub fn insert_map_bool_bool(mut self, key: bool, value: bool) -> Self {
+        self.inner.map_bool_bool.insert(key, value);
+        self
+    }
+
+    pub fn with_map_string_string(mut self, value: BTreeMap<String, String>) -> Self {
+        self.inner.map_string_string = value;
+        self
+    }
+
+    pub fn insert_map_string_string(mut self, key: String, value: String) -> Self {
+        self.inner.map_string_string.insert(key, value);
+        self
+    }
+
+    pub fn with_map_string_bytes(mut self, value: BTreeMap<String, Vec<u8>>) -> Self {
+        self.inner.map_string_bytes = value;
+        self
+    }
+
+    pub fn insert_map_string_bytes(mut self, key: String, value: Vec<u8>) -> Self {
+        self.inner.map_string_bytes.insert(key, value);
+        self
+    }
+
+    pub fn with_map_string_nested_message(mut self, value: BTreeMap<String, NestedMessage>) -> Self {
+        self.inner.map_string_nested_message = value;
+        self
+    }
+
+    pub fn insert_map_string_nested_message(mut self, key: String, value: NestedMessage) -> Self {
+        self.inner.map_string_nested_message.insert(key, value);
+        self
+    }
+
+    pub fn with_map_string_foreign_message(mut self, value: BTreeMap<String, ForeignMessage>) -> Self {
+        self.inner.map_string_foreign_message = value;
+        self
+    }
+
+    pub fn insert_map_string_foreign_message(mut self, key: String, value: ForeignMessage) -> Self {
+        self.inner.map_string_foreign_message.insert(key, value);
+        self
+    }
+
+    pub fn with_map_string_nested_enum(mut self, value: BTreeMap<String, i32>) -> Self {
+        self.inner.map_string_nested_enum = value;
+        self
+    }
+
+    pub fn insert_map_string_nested_enum(mut self, key: String, value: i32) -> Self {
+        self.inner.map_string_nested_enum.insert(key, value);
+        self
+    }
+
+    pub fn with_map_string_foreign_enum(mut self, value: BTreeMap<String, i32>) -> Self {
+        self.inner.map_string_foreign_enum = value;
+        self
+    }
+
+    pub fn insert_map_string_foreign_enum(mut self, key: String, value: i32) -> Self {
+        self.inner.map_string_foreign_enum.insert(key, value);
+        self
+    }
+
+    pub fn with_oneof_uint32(mut self, value: u32) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofUint32(value);
+        self
+    }
+
+    pub fn with_oneof_nested_message(mut self, value: NestedMessage) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofNestedMessage(value.into());
+        self
+    }
+
+    pub fn with_oneof_string(mut self, value: String) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofString(value);
+        self
+    }
+
+    pub fn with_oneof_bytes(mut self, value: Vec<u8>) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofBytes(value);
+        self
+    }
+
+    pub fn with_oneof_bool(mut self, value: bool) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofBool(value);
+        self
+    }
+
+    pub fn with_oneof_uint64(mut self, value: u64) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofUint64(value);
+        self
+    }
+
+    pub fn with_oneof_float(mut self, value: f32) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofFloat(value);
+        self
+    }
+
+    pub fn with_oneof_double(mut self, value: f64) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofDouble(value);
+        self
+    }
+
+    pub fn with_oneof_enum(mut self, value: i32) -> Self {
+        self.inner.oneof_field = TestAllTypesProto3OneofField::OneofEnum(value);
+        self
+    }
+
+    pub fn with_optional_bool_wrapper(mut self, value: bool) -> Self {
+        self.inner.optional_bool_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_optional_int32_wrapper(mut self, value: i32) -> Self {
+        self.inner.optional_int32_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_optional_int64_wrapper(mut self, value: i64) -> Self {
+        self.inner.optional_int64_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_optional_uint32_wrapper(mut self, value: u32) -> Self {
+        self.inner.optional_uint32_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_optional_uint64_wrapper(mut self, value: u64) -> Self {
+        self.inner.optional_uint64_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_optional_float_wrapper(mut self, value: f32) -> Self {
+        self.inner.optional_float_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_optional_double_wrapper(mut self, value: f64) -> Self {
+        self.inner.optional_double_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_optional_string_wrapper(mut self, value: String) -> Self {
+        self.inner.optional_string_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_optional_bytes_wrapper(mut self, value: Vec<u8>) -> Self {
+        self.inner.optional_bytes_wrapper = Some(value);
+        self
+    }
+
+    pub fn with_repeated_bool_wrapper(mut self, value: Vec<BoolValue>) -> Self {
+        self.inner.repeated_bool_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_bool_wrapper(mut self, value: BoolValue) -> Self {
+        self.inner.repeated_bool_wrapper.push(value);
+        self
+    }
+
+    pub fn with_repeated_int32_wrapper(mut self, value: Vec<Int32Value>) -> Self {
+        self.inner.repeated_int32_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_int32_wrapper(mut self, value: Int32Value) -> Self {
+        self.inner.repeated_int32_wrapper.push(value);
+        self
+    }
+
+    pub fn with_repeated_int64_wrapper(mut self, value: Vec<Int64Value>) -> Self {
+        self.inner.repeated_int64_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_int64_wrapper(mut self, value: Int64Value) -> Self {
+        self.inner.repeated_int64_wrapper.push(value);
+        self
+    }
+
+    pub fn with_repeated_uint32_wrapper(mut self, value: Vec<UInt32Value>) -> Self {
+        self.inner.repeated_uint32_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_uint32_wrapper(mut self, value: UInt32Value) -> Self {
+        self.inner.repeated_uint32_wrapper.push(value);
+        self
+    }
+
+    pub fn with_repeated_uint64_wrapper(mut self, value: Vec<UInt64Value>) -> Self {
+        self.inner.repeated_uint64_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_uint64_wrapper(mut self, value: UInt64Value) -> Self {
+        self.inner.repeated_uint64_wrapper.push(value);
+        self
+    }
+
+    pub fn with_repeated_float_wrapper(mut self, value: Vec<FloatValue>) -> Self {
+        self.inner.repeated_float_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_float_wrapper(mut self, value: FloatValue) -> Self {
+        self.inner.repeated_float_wrapper.push(value);
+        self
+    }
+
+    pub fn with_repeated_double_wrapper(mut self, value: Vec<DoubleValue>) -> Self {
+        self.inner.repeated_double_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_double_wrapper(mut self, value: DoubleValue) -> Self {
+        self.inner.repeated_double_wrapper.push(value);
+        self
+    }
+
+    pub fn with_repeated_string_wrapper(mut self, value: Vec<StringValue>) -> Self {
+        self.inner.repeated_string_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_string_wrapper(mut self, value: StringValue) -> Self {
+        self.inner.repeated_string_wrapper.push(value);
+        self
+    }
+
+    pub fn with_repeated_bytes_wrapper(mut self, value: Vec<BytesValue>) -> Self {
+        self.inner.repeated_bytes_wrapper = value;
+        self
+    }
+
+    pub fn add_repeated_bytes_wrapper(mut self, value: BytesValue) -> Self {
+        self.inner.repeated_bytes_wrapper.push(value);
+        self
+    }
+
+    pub fn with_optional_duration(mut self, value: Duration) -> Self {
+        self.inner.optional_duration = value;
+        self
+    }
+
+    pub fn with_optional_timestamp(mut self, value: Timestamp) -> Self {
+        self.inner.optional_timestamp = value;
+        self
+    }
+
+    pub fn with_optional_any(mut self, value: Any) -> Self {
+        self.inner.optional_any = value;
+        self
+    }
+
+    pub fn with_repeated_duration(mut self, value: Vec<Duration>) -> Self {
+        self.inner.repeated_duration = value;
+        self
+    }
+
+    pub fn add_repeated_duration(mut self, value: Duration) -> Self {
+        self.inner.repeated_duration.push(value);
+        self
+    }
+
+    pub fn with_repeated_timestamp(mut self, value: Vec<Timestamp>) -> Self {
+        self.inner.repeated_timestamp = value;
+        self
+    }
+
+    pub fn add_repeated_timestamp(mut self, value: Timestamp) -> Self {
+        self.inner.repeated_timestamp.push(value);
+        self
+    }
+
+    pub fn with_repeated_any(mut self, value: Vec<Any>) -> Self {
+        self.inner.repeated_any = value;
+        self
+    }
+
+    pub fn add_repeated_any(mut self, value: Any) -> Self {
+        self.inner.repeated_any.push(value);
+        self
+    }
+
+    pub fn build(self) -> TestAllTypesProto3 {
+        self.inner
+    }
+}
+
+impl TestAllTypesProto3 {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if self.optional_int32 != 0 {
+            out.write_scalar("optional_int32", self.optional_int32);
+        }
+        if self.optional_int64 != 0 {
+            out.write_scalar("optional_int64", self.optional_int64);
+        }
+        if self.optional_uint32 != 0 {
+            out.write_scalar("optional_uint32", self.optional_uint32);
+        }
+        if self.optional_uint64 != 0 {
+            out.write_scalar("optional_uint64", self.optional_uint64);
+        }
+        if self.optional_sint32 != 0 {
+            out.write_scalar("optional_sint32", self.optional_sint32);
+        }
+        if self.optional_sint64 != 0 {
+            out.write_scalar("optional_sint64", self.optional_sint64);
+        }
+        if self.optional_fixed32 != 0 {
+            out.write_scalar("optional_fixed32", self.optional_fixed32);
+        }
+        if self.optional_fixed64 != 0 {
+            out.write_scalar("optional_fixed64", self.optional_fixed64);
+        }
+        if self.optional_sfixed32 != 0 {
+            out.write_scalar("optional_sfixed32", self.optional_sfixed32);
+        }
+        if self.optional_sfixed64 != 0 {
+            out.write_scalar("optional_sfixed64", self.optional_sfixed64);
+        }
+        if self.optional_float.to_bits() != 0 {
+            out.write_f32("optional_float", self.optional_float);
+        }
+        if self.optional_double.to_bits() != 0 {
+            out.write_f64("optional_double", self.optional_double);
+        }
+        if self.optional_bool {
+            out.write_scalar("optional_bool", self.optional_bool);
+        }
+        if !self.optional_string.is_empty() {
+            out.write_str("optional_string", &self.optional_string);
+        }
+        if !self.optional_bytes.is_empty() {
+            out.write_bytes("optional_bytes", &self.optional_bytes);
+        }
+        if let Some(elem) = &self.optional_nested_message {
+            out.begin_message("optional_nested_message");
+            elem.write_text(out);
+            out.end_message();
+        }
+        if self.optional_foreign_message != ForeignMessage::default() {
+            out.begin_message("optional_foreign_message");
+            self.optional_foreign_message.write_text(out);
+            out.end_message();
+        }
+        if self.optional_nested_enum != 0 {
+            out.write_enum("optional_nested_enum", self.optional_nested_enum, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+        }
+        if self.optional_foreign_enum != 0 {
+            out.write_enum("optional_foreign_enum", self.optional_foreign_enum, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)]);
+        }
+        if self.optional_aliased_enum != 0 {
+            out.write_enum("optional_aliased_enum", self.optional_aliased_enum, &[("ALIAS_FOO", 0), ("ALIAS_BAR", 1), ("ALIAS_BAZ", 2)]);
+        }
+        if !self.optional_string_piece.is_empty() {
+            out.write_str("optional_string_piece", &self.optional_string_piece);
+        }
+        if !self.optional_cord.is_empty() {
+            out.write_str("optional_cord", &self.optional_cord);
+        }
+        if let Some(elem) = &self.recursive_message {
+            out.begin_message("recursive_message");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_int32 {
+            out.write_scalar("repeated_int32", *elem);
+        }
+        for elem in &self.repeated_int64 {
+            out.write_scalar("repeated_int64", *elem);
+        }
+        for elem in &self.repeated_uint32 {
+            out.write_scalar("repeated_uint32", *elem);
+        }
+        for elem in &self.repeated_uint64 {
+            out.write_scalar("repeated_uint64", *elem);
+        }
+        for elem in &self.repeated_sint32 {
+            out.write_scalar("repeated_sint32", *elem);
+        }
+        for elem in &self.repeated_sint64 {
+            out.write_scalar("repeated_sint64", *elem);
+        }
+        for elem in &self.repeated_fixed32 {
+            out.write_scalar("repeated_fixed32", *elem);
+        }
+        for elem in &self.repeated_fixed64 {
+            out.write_scalar("repeated_fixed64", *elem);
+        }
+        for elem in &self.repeated_sfixed32 {
+            out.write_scalar("repeated_sfixed32", *elem);
+        }
+        for elem in &self.repeated_sfixed64 {
+            out.write_scalar("repeated_sfixed64", *elem);
+        }
+        for elem in &self.repeated_float {
+            out.write_f32("repeated_float", *elem);
+        }
+        for elem in &self.repeated_double {
+            out.write_f64("repeated_double", *elem);
+        }
+        for elem in &self.repeated_bool {
+            out.write_scalar("repeated_bool", *elem);
+        }
+        for elem in &self.repeated_string {
+            out.write_str("repeated_string", elem);
+        }
+        for elem in &self.repeated_bytes {
+            out.write_bytes("repeated_bytes", elem);
+        }
+        for elem in &self.repeated_nested_message {
+            out.begin_message("repeated_nested_message");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_foreign_message {
+            out.begin_message("repeated_foreign_message");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_nested_enum {
+            out.write_enum("repeated_nested_enum", *elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+        }
+        for elem in &self.repeated_foreign_enum {
+            out.write_enum("repeated_foreign_enum", *elem, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)]);
+        }
+        for elem in &self.repeated_string_piece {
+            out.write_str("repeated_string_piece", elem);
+        }
+        for elem in &self.repeated_cord {
+            out.write_str("repeated_cord", elem);
+        }
+        for (key, value) in &self.map_int32_int32 {
+            out.begin_message("map_int32_int32");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_int64_int64 {
+            out.begin_message("map_int64_int64");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_uint32_uint32 {
+            out.begin_message("map_uint32_uint32");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_uint64_uint64 {
+            out.begin_message("map_uint64_uint64");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_sint32_sint32 {
+            out.begin_message("map_sint32_sint32");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_sint64_sint64 {
+            out.begin_message("map_sint64_sint64");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_fixed32_fixed32 {
+            out.begin_message("map_fixed32_fixed32");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_fixed64_fixed64 {
+            out.begin_message("map_fixed64_fixed64");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_sfixed32_sfixed32 {
+            out.begin_message("map_sfixed32_sfixed32");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_sfixed64_sfixed64 {
+            out.begin_message("map_sfixed64_sfixed64");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_int32_float {
+            out.begin_message("map_int32_float");
+            out.write_scalar("key", *key);
+            out.write_f32("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_int32_double {
+            out.begin_message("map_int32_double");
+            out.write_scalar("key", *key);
+            out.write_f64("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_bool_bool {
+            out.begin_message("map_bool_bool");
+            out.write_scalar("key", *key);
+            out.write_scalar("value", *value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_string_string {
+            out.begin_message("map_string_string");
+            out.write_str("key", key);
+            out.write_str("value", value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_string_bytes {
+            out.begin_message("map_string_bytes");
+            out.write_str("key", key);
+            out.write_bytes("value", value);
+            out.end_message();
+        }
+        for (key, value) in &self.map_string_nested_message {
+            out.begin_message("map_string_nested_message");
+            out.write_str("key", key);
+            out.begin_message("value");
+            value.write_text(out);
+            out.end_message();
+            out.end_message();
+        }
+        for (key, value) in &self.map_string_foreign_message {
+            out.begin_message("map_string_foreign_message");
+            out.write_str("key", key);
+            out.begin_message("value");
+            value.write_text(out);
+            out.end_message();
+            out.end_message();
+        }
+        for (key, value) in &self.map_string_nested_enum {
+            out.begin_message("map_string_nested_enum");
+            out.write_str("key", key);
+            out.write_enum("value", *value, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+            out.end_message();
+        }
+        for (key, value) in &self.map_string_foreign_enum {
+            out.begin_message("map_string_foreign_enum");
+            out.write_str("key", key);
+            out.write_enum("value", *value, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)]);
+            out.end_message();
+        }
+        for elem in &self.packed_int32 {
+            out.write_scalar("packed_int32", *elem);
+        }
+        for elem in &self.packed_int64 {
+            out.write_scalar("packed_int64", *elem);
+        }
+        for elem in &self.packed_uint32 {
+            out.write_scalar("packed_uint32", *elem);
+        }
+        for elem in &self.packed_uint64 {
+            out.write_scalar("packed_uint64", *elem);
+        }
+        for elem in &self.packed_sint32 {
+            out.write_scalar("packed_sint32", *elem);
+        }
+        for elem in &self.packed_sint64 {
+            out.write_scalar("packed_sint64", *elem);
+        }
+        for elem in &self.packed_fixed32 {
+            out.write_scalar("packed_fixed32", *elem);
+        }
+        for elem in &self.packed_fixed64 {
+            out.write_scalar("packed_fixed64", *elem);
+        }
+        for elem in &self.packed_sfixed32 {
+            out.write_scalar("packed_sfixed32", *elem);
+        }
+        for elem in &self.packed_sfixed64 {
+            out.write_scalar("packed_sfixed64", *elem);
+        }
+        for elem in &self.packed_float {
+            out.write_f32("packed_float", *elem);
+        }
+        for elem in &self.packed_double {
+            out.write_f64("packed_double", *elem);
+        }
+        for elem in &self.packed_bool {
+            out.write_scalar("packed_bool", *elem);
+        }
+        for elem in &self.packed_nested_enum {
+            out.write_enum("packed_nested_enum", *elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+        }
+        for elem in &self.unpacked_int32 {
+            out.write_scalar("unpacked_int32", *elem);
+        }
+        for elem in &self.unpacked_int64 {
+            out.write_scalar("unpacked_int64", *elem);
+        }
+        for elem in &self.unpacked_uint32 {
+            out.write_scalar("unpacked_uint32", *elem);
+        }
+        for elem in &self.unpacked_uint64 {
+            out.write_scalar("unpacked_uint64", *elem);
+        }
+        for elem in &self.unpacked_sint32 {
+            out.write_scalar("unpacked_sint32", *elem);
+        }
+        for elem in &self.unpacked_sint64 {
+            out.write_scalar("unpacked_sint64", *elem);
+        }
+        for elem in &self.unpacked_fixed32 {
+            out.write_scalar("unpacked_fixed32", *elem);
+        }
+        for elem in &self.unpacked_fixed64 {
+            out.write_scalar("unpacked_fixed64", *elem);
+        }
+        for elem in &self.unpacked_sfixed32 {
+            out.write_scalar("unpacked_sfixed32", *elem);
+        }
+        for elem in &self.unpacked_sfixed64 {
+            out.write_scalar("unpacked_sfixed64", *elem);
+        }
+        for elem in &self.unpacked_float {
+            out.write_f32("unpacked_float", *elem);
+        }
+        for elem in &self.unpacked_double {
+            out.write_f64("unpacked_double", *elem);
+        }
+        for elem in &self.unpacked_bool {
+            out.write_scalar("unpacked_bool", *elem);
+        }
+        for elem in &self.unpacked_nested_enum {
+            out.write_enum("unpacked_nested_enum", *elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+        }
+        if let TestAllTypesProto3OneofField::OneofUint32(elem) = &self.oneof_field {
+            out.write_scalar("oneof_uint32", *elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofNestedMessage(elem) = &self.oneof_field {
+            out.begin_message("oneof_nested_message");
+            elem.write_text(out);
+            out.end_message();
+        }
+        if let TestAllTypesProto3OneofField::OneofString(elem) = &self.oneof_field {
+            out.write_str("oneof_string", elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofBytes(elem) = &self.oneof_field {
+            out.write_bytes("oneof_bytes", elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofBool(elem) = &self.oneof_field {
+            out.write_scalar("oneof_bool", *elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofUint64(elem) = &self.oneof_field {
+            out.write_scalar("oneof_uint64", *elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofFloat(elem) = &self.oneof_field {
+            out.write_f32("oneof_float", *elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofDouble(elem) = &self.oneof_field {
+            out.write_f64("oneof_double", *elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofEnum(elem) = &self.oneof_field {
+            out.write_enum("oneof_enum", *elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+        }
+        if let Some(elem) = &self.optional_bool_wrapper {
+            out.begin_message("optional_bool_wrapper");
+            BoolValue::write_text_value(elem, out);
+            out.end_message();
+        }
+        if let Some(elem) = &self.optional_int32_wrapper {
+            out.begin_message("optional_int32_wrapper");
+            Int32Value::write_text_value(elem, out);
+            out.end_message();
+        }
+        if let Some(elem) = &self.optional_int64_wrapper {
+            out.begin_message("optional_int64_wrapper");
+            Int64Value::write_text_value(elem, out);
+            out.end_message();
+        }
+        if let Some(elem) = &self.optional_uint32_wrapper {
+            out.begin_message("optional_uint32_wrapper");
+            UInt32Value::write_text_value(elem, out);
+            out.end_message();
+        }
+        if let Some(elem) = &self.optional_uint64_wrapper {
+            out.begin_message("optional_uint64_wrapper");
+            UInt64Value::write_text_value(elem, out);
+            out.end_message();
+        }
+        if let Some(elem) = &self.optional_float_wrapper {
+            out.begin_message("optional_float_wrapper");
+            FloatValue::write_text_value(elem, out);
+            out.end_message();
+        }
+        if let Some(elem) = &self.optional_double_wrapper {
+            out.begin_message("optional_double_wrapper");
+            DoubleValue::write_text_value(elem, out);
+            out.end_message();
+        }
+        if let Some(elem) = &self.optional_string_wrapper {
+            out.begin_message("optional_string_wrapper");
+            StringValue::write_text_value(elem, out);
+            out.end_message();
+        }
+        if let Some(elem) = &self.optional_bytes_wrapper {
+            out.begin_message("optional_bytes_wrapper");
+            BytesValue::write_text_value(elem, out);
+            out.end_message();
+        }
+        for elem in &self.repeated_bool_wrapper {
+            out.begin_message("repeated_bool_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_int32_wrapper {
+            out.begin_message("repeated_int32_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_int64_wrapper {
+            out.begin_message("repeated_int64_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_uint32_wrapper {
+            out.begin_message("repeated_uint32_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_uint64_wrapper {
+            out.begin_message("repeated_uint64_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_float_wrapper {
+            out.begin_message("repeated_float_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_double_wrapper {
+            out.begin_message("repeated_double_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_string_wrapper {
+            out.begin_message("repeated_string_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_bytes_wrapper {
+            out.begin_message("repeated_bytes_wrapper");
+            elem.write_text(out);
+            out.end_message();
+        }
+        if self.optional_duration != Duration::default() {
+            out.begin_message("optional_duration");
+            self.optional_duration.write_text(out);
+            out.end_message();
+        }
+        if self.optional_timestamp != Timestamp::default() {
+            out.begin_message("optional_timestamp");
+            self.optional_timestamp.write_text(out);
+            out.end_message();
+        }
+        if self.optional_any != Any::default() {
+            out.begin_message("optional_any");
+            self.optional_any.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_duration {
+            out.begin_message("repeated_duration");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_timestamp {
+            out.begin_message("repeated_timestamp");
+            elem.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.repeated_any {
+            out.begin_message("repeated_any");
+            elem.write_text(out);
+            out.end_message();
+        }
+    }
+}
+
+impl core::fmt::Display for TestAllTypesProto3 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl TestAllTypesProto3 {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if self.optional_int32 != 0 {
+            out.key("optionalInt32");
+            out.write_i32(self.optional_int32);
+        }
+        if self.optional_int64 != 0 {
+            out.key("optionalInt64");
+            out.write_i64(self.optional_int64);
+        }
+        if self.optional_uint32 != 0 {
+            out.key("optionalUint32");
+            out.write_u32(self.optional_uint32);
+        }
+        if self.optional_uint64 != 0 {
+            out.key("optionalUint64");
+            out.write_u64(self.optional_uint64);
+        }
+        if self.optional_sint32 != 0 {
+            out.key("optionalSint32");
+            out.write_i32(self.optional_sint32);
+        }
+        if self.optional_sint64 != 0 {
+            out.key("optionalSint64");
+            out.write_i64(self.optional_sint64);
+        }
+        if self.optional_fixed32 != 0 {
+            out.key("optionalFixed32");
+            out.write_u32(self.optional_fixed32);
+        }
+        if self.optional_fixed64 != 0 {
+            out.key("optionalFixed64");
+            out.write_u64(self.optional_fixed64);
+        }
+        if self.optional_sfixed32 != 0 {
+            out.key("optionalSfixed32");
+            out.write_i32(self.optional_sfixed32);
+        }
+        if self.optional_sfixed64 != 0 {
+            out.key("optionalSfixed64");
+            out.write_i64(self.optional_sfixed64);
+        }
+        if self.optional_float.to_bits() != 0 {
+            out.key("optionalFloat");
+            out.write_f32(self.optional_float);
+        }
+        if self.optional_double.to_bits() != 0 {
+            out.key("optionalDouble");
+            out.write_f64(self.optional_double);
+        }
+        if self.optional_bool {
+            out.key("optionalBool");
+            out.write_bool(self.optional_bool);
+        }
+        if !self.optional_string.is_empty() {
+            out.key("optionalString");
+            out.write_str(&self.optional_string);
+        }
+        if !self.optional_bytes.is_empty() {
+            out.key("optionalBytes");
+            out.write_bytes(&self.optional_bytes);
+        }
+        if let Some(elem) = &self.optional_nested_message {
+            out.key("optionalNestedMessage");
+            elem.write_json(out);
+        }
+        if self.optional_foreign_message != ForeignMessage::default() {
+            out.key("optionalForeignMessage");
+            self.optional_foreign_message.write_json(out);
+        }
+        if self.optional_nested_enum != 0 {
+            out.key("optionalNestedEnum");
+            out.write_enum(self.optional_nested_enum, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+        }
+        if self.optional_foreign_enum != 0 {
+            out.key("optionalForeignEnum");
+            out.write_enum(self.optional_foreign_enum, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)]);
+        }
+        if self.optional_aliased_enum != 0 {
+            out.key("optionalAliasedEnum");
+            out.write_enum(self.optional_aliased_enum, &[("ALIAS_FOO", 0), ("ALIAS_BAR", 1), ("ALIAS_BAZ", 2)]);
+        }
+        if !self.optional_string_piece.is_empty() {
+            out.key("optionalStringPiece");
+            out.write_str(&self.optional_string_piece);
+        }
+        if !self.optional_cord.is_empty() {
+            out.key("optionalCord");
+            out.write_str(&self.optional_cord);
+        }
+        if let Some(elem) = &self.recursive_message {
+            out.key("recursiveMessage");
+            elem.write_json(out);
+        }
+        if !self.repeated_int32.is_empty() {
+            out.key("repeatedInt32");
+            out.begin_array();
+            for elem in &self.repeated_int32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_int64.is_empty() {
+            out.key("repeatedInt64");
+            out.begin_array();
+            for elem in &self.repeated_int64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_uint32.is_empty() {
+            out.key("repeatedUint32");
+            out.begin_array();
+            for elem in &self.repeated_uint32 {
+                out.write_u32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_uint64.is_empty() {
+            out.key("repeatedUint64");
+            out.begin_array();
+            for elem in &self.repeated_uint64 {
+                out.write_u64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_sint32.is_empty() {
+            out.key("repeatedSint32");
+            out.begin_array();
+            for elem in &self.repeated_sint32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_sint64.is_empty() {
+            out.key("repeatedSint64");
+            out.begin_array();
+            for elem in &self.repeated_sint64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_fixed32.is_empty() {
+            out.key("repeatedFixed32");
+            out.begin_array();
+            for elem in &self.repeated_fixed32 {
+                out.write_u32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_fixed64.is_empty() {
+            out.key("repeatedFixed64");
+            out.begin_array();
+            for elem in &self.repeated_fixed64 {
+                out.write_u64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_sfixed32.is_empty() {
+            out.key("repeatedSfixed32");
+            out.begin_array();
+            for elem in &self.repeated_sfixed32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_sfixed64.is_empty() {
+            out.key("repeatedSfixed64");
+            out.begin_array();
+            for elem in &self.repeated_sfixed64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_float.is_empty() {
+            out.key("repeatedFloat");
+            out.begin_array();
+            for elem in &self.repeated_float {
+                out.write_f32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_double.is_empty() {
+            out.key("repeatedDouble");
+            out.begin_array();
+            for elem in &self.repeated_double {
+                out.write_f64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_bool.is_empty() {
+            out.key("repeatedBool");
+            out.begin_array();
+            for elem in &self.repeated_bool {
+                out.write_bool(*elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_string.is_empty() {
+            out.key("repeatedString");
+            out.begin_array();
+            for elem in &self.repeated_string {
+                out.write_str(elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_bytes.is_empty() {
+            out.key("repeatedBytes");
+            out.begin_array();
+            for elem in &self.repeated_bytes {
+                out.write_bytes(elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_nested_message.is_empty() {
+            out.key("repeatedNestedMessage");
+            out.begin_array();
+            for elem in &self.repeated_nested_message {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_foreign_message.is_empty() {
+            out.key("repeatedForeignMessage");
+            out.begin_array();
+            for elem in &self.repeated_foreign_message {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_nested_enum.is_empty() {
+            out.key("repeatedNestedEnum");
+            out.begin_array();
+            for elem in &self.repeated_nested_enum {
+                out.write_enum(*elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+            }
+            out.end_array();
+        }
+        if !self.repeated_foreign_enum.is_empty() {
+            out.key("repeatedForeignEnum");
+            out.begin_array();
+            for elem in &self.repeated_foreign_enum {
+                out.write_enum(*elem, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)]);
+            }
+            out.end_array();
+        }
+        if !self.repeated_string_piece.is_empty() {
+            out.key("repeatedStringPiece");
+            out.begin_array();
+            for elem in &self.repeated_string_piece {
+                out.write_str(elem);
+            }
+            out.end_array();
+        }
+        if !self.repeated_cord.is_empty() {
+            out.key("repeatedCord");
+            out.begin_array();
+            for elem in &self.repeated_cord {
+                out.write_str(elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_int32.is_empty() {
+            out.key("packedInt32");
+            out.begin_array();
+            for elem in &self.packed_int32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_int64.is_empty() {
+            out.key("packedInt64");
+            out.begin_array();
+            for elem in &self.packed_int64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_uint32.is_empty() {
+            out.key("packedUint32");
+            out.begin_array();
+            for elem in &self.packed_uint32 {
+                out.write_u32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_uint64.is_empty() {
+            out.key("packedUint64");
+            out.begin_array();
+            for elem in &self.packed_uint64 {
+                out.write_u64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_sint32.is_empty() {
+            out.key("packedSint32");
+            out.begin_array();
+            for elem in &self.packed_sint32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_sint64.is_empty() {
+            out.key("packedSint64");
+            out.begin_array();
+            for elem in &self.packed_sint64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_fixed32.is_empty() {
+            out.key("packedFixed32");
+            out.begin_array();
+            for elem in &self.packed_fixed32 {
+                out.write_u32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_fixed64.is_empty() {
+            out.key("packedFixed64");
+            out.begin_array();
+            for elem in &self.packed_fixed64 {
+                out.write_u64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_sfixed32.is_empty() {
+            out.key("packedSfixed32");
+            out.begin_array();
+            for elem in &self.packed_sfixed32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_sfixed64.is_empty() {
+            out.key("packedSfixed64");
+            out.begin_array();
+            for elem in &self.packed_sfixed64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_float.is_empty() {
+            out.key("packedFloat");
+            out.begin_array();
+            for elem in &self.packed_float {
+                out.write_f32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_double.is_empty() {
+            out.key("packedDouble");
+            out.begin_array();
+            for elem in &self.packed_double {
+                out.write_f64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_bool.is_empty() {
+            out.key("packedBool");
+            out.begin_array();
+            for elem in &self.packed_bool {
+                out.write_bool(*elem);
+            }
+            out.end_array();
+        }
+        if !self.packed_nested_enum.is_empty() {
+            out.key("packedNestedEnum");
+            out.begin_array();
+            for elem in &self.packed_nested_enum {
+                out.write_enum(*elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_int32.is_empty() {
+            out.key("unpackedInt32");
+            out.begin_array();
+            for elem in &self.unpacked_int32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_int64.is_empty() {
+            out.key("unpackedInt64");
+            out.begin_array();
+            for elem in &self.unpacked_int64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_uint32.is_empty() {
+            out.key("unpackedUint32");
+            out.begin_array();
+            for elem in &self.unpacked_uint32 {
+                out.write_u32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_uint64.is_empty() {
+            out.key("unpackedUint64");
+            out.begin_array();
+            for elem in &self.unpacked_uint64 {
+                out.write_u64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_sint32.is_empty() {
+            out.key("unpackedSint32");
+            out.begin_array();
+            for elem in &self.unpacked_sint32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_sint64.is_empty() {
+            out.key("unpackedSint64");
+            out.begin_array();
+            for elem in &self.unpacked_sint64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_fixed32.is_empty() {
+            out.key("unpackedFixed32");
+            out.begin_array();
+            for elem in &self.unpacked_fixed32 {
+                out.write_u32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_fixed64.is_empty() {
+            out.key("unpackedFixed64");
+            out.begin_array();
+            for elem in &self.unpacked_fixed64 {
+                out.write_u64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_sfixed32.is_empty() {
+            out.key("unpackedSfixed32");
+            out.begin_array();
+            for elem in &self.unpacked_sfixed32 {
+                out.write_i32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_sfixed64.is_empty() {
+            out.key("unpackedSfixed64");
+            out.begin_array();
+            for elem in &self.unpacked_sfixed64 {
+                out.write_i64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_float.is_empty() {
+            out.key("unpackedFloat");
+            out.begin_array();
+            for elem in &self.unpacked_float {
+                out.write_f32(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_double.is_empty() {
+            out.key("unpackedDouble");
+            out.begin_array();
+            for elem in &self.unpacked_double {
+                out.write_f64(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_bool.is_empty() {
+            out.key("unpackedBool");
+            out.begin_array();
+            for elem in &self.unpacked_bool {
+                out.write_bool(*elem);
+            }
+            out.end_array();
+        }
+        if !self.unpacked_nested_enum.is_empty() {
+            out.key("unpackedNestedEnum");
+            out.begin_array();
+            for elem in &self.unpacked_nested_enum {
+                out.write_enum(*elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+            }
+            out.end_array();
+        }
+        if !self.map_int32_int32.is_empty() {
+            out.key("mapInt32Int32");
+            out.begin_object();
+            for (key, value) in &self.map_int32_int32 {
+                out.key(&key.to_string());
+                out.write_i32(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_int64_int64.is_empty() {
+            out.key("mapInt64Int64");
+            out.begin_object();
+            for (key, value) in &self.map_int64_int64 {
+                out.key(&key.to_string());
+                out.write_i64(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_uint32_uint32.is_empty() {
+            out.key("mapUint32Uint32");
+            out.begin_object();
+            for (key, value) in &self.map_uint32_uint32 {
+                out.key(&key.to_string());
+                out.write_u32(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_uint64_uint64.is_empty() {
+            out.key("mapUint64Uint64");
+            out.begin_object();
+            for (key, value) in &self.map_uint64_uint64 {
+                out.key(&key.to_string());
+                out.write_u64(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_sint32_sint32.is_empty() {
+            out.key("mapSint32Sint32");
+            out.begin_object();
+            for (key, value) in &self.map_sint32_sint32 {
+                out.key(&key.to_string());
+                out.write_i32(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_sint64_sint64.is_empty() {
+            out.key("mapSint64Sint64");
+            out.begin_object();
+            for (key, value) in &self.map_sint64_sint64 {
+                out.key(&key.to_string());
+                out.write_i64(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_fixed32_fixed32.is_empty() {
+            out.key("mapFixed32Fixed32");
+            out.begin_object();
+            for (key, value) in &self.map_fixed32_fixed32 {
+                out.key(&key.to_string());
+                out.write_u32(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_fixed64_fixed64.is_empty() {
+            out.key("mapFixed64Fixed64");
+            out.begin_object();
+            for (key, value) in &self.map_fixed64_fixed64 {
+                out.key(&key.to_string());
+                out.write_u64(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_sfixed32_sfixed32.is_empty() {
+            out.key("mapSfixed32Sfixed32");
+            out.begin_object();
+            for (key, value) in &self.map_sfixed32_sfixed32 {
+                out.key(&key.to_string());
+                out.write_i32(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_sfixed64_sfixed64.is_empty() {
+            out.key("mapSfixed64Sfixed64");
+            out.begin_object();
+            for (key, value) in &self.map_sfixed64_sfixed64 {
+                out.key(&key.to_string());
+                out.write_i64(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_int32_float.is_empty() {
+            out.key("mapInt32Float");
+            out.begin_object();
+            for (key, value) in &self.map_int32_float {
+                out.key(&key.to_string());
+                out.write_f32(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_int32_double.is_empty() {
+            out.key("mapInt32Double");
+            out.begin_object();
+            for (key, value) in &self.map_int32_double {
+                out.key(&key.to_string());
+                out.write_f64(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_bool_bool.is_empty() {
+            out.key("mapBoolBool");
+            out.begin_object();
+            for (key, value) in &self.map_bool_bool {
+                out.key(&key.to_string());
+                out.write_bool(*value);
+            }
+            out.end_object();
+        }
+        if !self.map_string_string.is_empty() {
+            out.key("mapStringString");
+            out.begin_object();
+            for (key, value) in &self.map_string_string {
+                out.key(key);
+                out.write_str(value);
+            }
+            out.end_object();
+        }
+        if !self.map_string_bytes.is_empty() {
+            out.key("mapStringBytes");
+            out.begin_object();
+            for (key, value) in &self.map_string_bytes {
+                out.key(key);
+                out.write_bytes(value);
+            }
+            out.end_object();
+        }
+        if !self.map_string_nested_message.is_empty() {
+            out.key("mapStringNestedMessage");
+            out.begin_object();
+            for (key, value) in &self.map_string_nested_message {
+                out.key(key);
+                value.write_json(out);
+            }
+            out.end_object();
+        }
+        if !self.map_string_foreign_message.is_empty() {
+            out.key("mapStringForeignMessage");
+            out.begin_object();
+            for (key, value) in &self.map_string_foreign_message {
+                out.key(key);
+                value.write_json(out);
+            }
+            out.end_object();
+        }
+        if !self.map_string_nested_enum.is_empty() {
+            out.key("mapStringNestedEnum");
+            out.begin_object();
+            for (key, value) in &self.map_string_nested_enum {
+                out.key(key);
+                out.write_enum(*value, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+            }
+            out.end_object();
+        }
+        if !self.map_string_foreign_enum.is_empty() {
+            out.key("mapStringForeignEnum");
+            out.begin_object();
+            for (key, value) in &self.map_string_foreign_enum {
+                out.key(key);
+                out.write_enum(*value, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)]);
+            }
+            out.end_object();
+        }
+        if let TestAllTypesProto3OneofField::OneofUint32(elem) = &self.oneof_field {
+            out.key("oneofUint32");
+            out.write_u32(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofNestedMessage(elem) = &self.oneof_field {
+            out.key("oneofNestedMessage");
+            elem.write_json(out);
+        }
+        if let TestAllTypesProto3OneofField::OneofString(elem) = &self.oneof_field {
+            out.key("oneofString");
+            out.write_str(elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofBytes(elem) = &self.oneof_field {
+            out.key("oneofBytes");
+            out.write_bytes(elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofBool(elem) = &self.oneof_field {
+            out.key("oneofBool");
+            out.write_bool(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofUint64(elem) = &self.oneof_field {
+            out.key("oneofUint64");
+            out.write_u64(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofFloat(elem) = &self.oneof_field {
+            out.key("oneofFloat");
+            out.write_f32(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofDouble(elem) = &self.oneof_field {
+            out.key("oneofDouble");
+            out.write_f64(*elem);
+        }
+        if let TestAllTypesProto3OneofField::OneofEnum(elem) = &self.oneof_field {
+            out.key("oneofEnum");
+            out.write_enum(*elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)]);
+        }
+        if let Some(elem) = &self.optional_bool_wrapper {
+            out.key("optionalBoolWrapper");
+            out.write_bool(*elem);
+        }
+        if let Some(elem) = &self.optional_int32_wrapper {
+            out.key("optionalInt32Wrapper");
+            out.write_i32(*elem);
+        }
+        if let Some(elem) = &self.optional_int64_wrapper {
+            out.key("optionalInt64Wrapper");
+            out.write_i64(*elem);
+        }
+        if let Some(elem) = &self.optional_uint32_wrapper {
+            out.key("optionalUint32Wrapper");
+            out.write_u32(*elem);
+        }
+        if let Some(elem) = &self.optional_uint64_wrapper {
+            out.key("optionalUint64Wrapper");
+            out.write_u64(*elem);
+        }
+        if let Some(elem) = &self.optional_float_wrapper {
+            out.key("optionalFloatWrapper");
+            out.write_f32(*elem);
+        }
+        if let Some(elem) = &self.optional_double_wrapper {
+            out.key("optionalDoubleWrapper");
+            out.write_f64(*elem);
+        }
+        if let Some(elem) = &self.optional_string_wrapper {
+            out.key("optionalStringWrapper");
+            out.write_str(elem);
+        }
+        if let Some(elem) = &self.optional_bytes_wrapper {
+            out.key("optionalBytesWrapper");
+            out.write_bytes(elem);
+        }
+        if !self.repeated_bool_wrapper.is_empty() {
+            out.key("repeatedBoolWrapper");
+            out.begin_array();
+            for elem in &self.repeated_bool_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_int32_wrapper.is_empty() {
+            out.key("repeatedInt32Wrapper");
+            out.begin_array();
+            for elem in &self.repeated_int32_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_int64_wrapper.is_empty() {
+            out.key("repeatedInt64Wrapper");
+            out.begin_array();
+            for elem in &self.repeated_int64_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_uint32_wrapper.is_empty() {
+            out.key("repeatedUint32Wrapper");
+            out.begin_array();
+            for elem in &self.repeated_uint32_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_uint64_wrapper.is_empty() {
+            out.key("repeatedUint64Wrapper");
+            out.begin_array();
+            for elem in &self.repeated_uint64_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_float_wrapper.is_empty() {
+            out.key("repeatedFloatWrapper");
+            out.begin_array();
+            for elem in &self.repeated_float_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_double_wrapper.is_empty() {
+            out.key("repeatedDoubleWrapper");
+            out.begin_array();
+            for elem in &self.repeated_double_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_string_wrapper.is_empty() {
+            out.key("repeatedStringWrapper");
+            out.begin_array();
+            for elem in &self.repeated_string_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_bytes_wrapper.is_empty() {
+            out.key("repeatedBytesWrapper");
+            out.begin_array();
+            for elem in &self.repeated_bytes_wrapper {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if self.optional_duration != Duration::default() {
+            out.key("optionalDuration");
+            self.optional_duration.write_json(out);
+        }
+        if self.optional_timestamp != Timestamp::default() {
+            out.key("optionalTimestamp");
+            self.optional_timestamp.write_json(out);
+        }
+        if self.optional_any != Any::default() {
+            out.key("optionalAny");
+            self.optional_any.write_json(out);
+        }
+        if !self.repeated_duration.is_empty() {
+            out.key("repeatedDuration");
+            out.begin_array();
+            for elem in &self.repeated_duration {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_timestamp.is_empty() {
+            out.key("repeatedTimestamp");
+            out.begin_array();
+            for elem in &self.repeated_timestamp {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if !self.repeated_any.is_empty() {
+            out.key("repeatedAny");
+            out.begin_array();
+            for elem in &self.repeated_any {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "optionalInt32" | "optional_int32" => msg.optional_int32 = json_to_i32(value)?,
+                "optionalInt64" | "optional_int64" => msg.optional_int64 = json_to_i64(value)?,
+                "optionalUint32" | "optional_uint32" => msg.optional_uint32 = json_to_u32(value)?,
+                "optionalUint64" | "optional_uint64" => msg.optional_uint64 = json_to_u64(value)?,
+                "optionalSint32" | "optional_sint32" => msg.optional_sint32 = json_to_i32(value)?,
+                "optionalSint64" | "optional_sint64" => msg.optional_sint64 = json_to_i64(value)?,
+                "optionalFixed32" | "optional_fixed32" => msg.optional_fixed32 = json_to_u32(value)?,
+                "optionalFixed64" | "optional_fixed64" => msg.optional_fixed64 = json_to_u64(value)?,
+                "optionalSfixed32" | "optional_sfixed32" => msg.optional_sfixed32 = json_to_i32(value)?,
+                "optionalSfixed64" | "optional_sfixed64" => msg.optional_sfixed64 = json_to_i64(value)?,
+                "optionalFloat" | "optional_float" => msg.optional_float = json_to_f32(value)?,
+                "optionalDouble" | "optional_double" => msg.optional_double = json_to_f64(value)?,
+                "optionalBool" | "optional_bool" => msg.optional_bool = json_to_bool(value)?,
+                "optionalString" | "optional_string" => msg.optional_string = json_to_string(value)?,
+                "optionalBytes" | "optional_bytes" => msg.optional_bytes = json_to_bytes(value)?,
+                "optionalNestedMessage" | "optional_nested_message" => msg.optional_nested_message = Some(NestedMessage::from_json_value(value)?.into()),
+                "optionalForeignMessage" | "optional_foreign_message" => msg.optional_foreign_message = ForeignMessage::from_json_value(value)?,
+                "optionalNestedEnum" | "optional_nested_enum" => msg.optional_nested_enum = json_to_enum(value, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)])?,
+                "optionalForeignEnum" | "optional_foreign_enum" => msg.optional_foreign_enum = json_to_enum(value, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)])?,
+                "optionalAliasedEnum" | "optional_aliased_enum" => msg.optional_aliased_enum = json_to_enum(value, &[("ALIAS_FOO", 0), ("ALIAS_BAR", 1), ("ALIAS_BAZ", 2)])?,
+                "optionalStringPiece" | "optional_string_piece" => msg.optional_string_piece = json_to_string(value)?,
+                "optionalCord" | "optional_cord" => msg.optional_cord = json_to_string(value)?,
+                "recursiveMessage" | "recursive_message" => msg.recursive_message = Some(TestAllTypesProto3::from_json_value(value)?.into()),
+                "repeatedInt32" | "repeated_int32" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_int32.push(json_to_i32(elem)?);
+                    }
+                }
+                "repeatedInt64" | "repeated_int64" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_int64.push(json_to_i64(elem)?);
+                    }
+                }
+                "repeatedUint32" | "repeated_uint32" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_uint32.push(json_to_u32(elem)?);
+                    }
+                }
+                "repeatedUint64" | "repeated_uint64" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_uint64.push(json_to_u64(elem)?);
+                    }
+                }
+                "repeatedSint32" | "repeated_sint32" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_sint32.push(json_to_i32(elem)?);
+                    }
+                }
+                "repeatedSint64" | "repeated_sint64" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_sint64.push(json_to_i64(elem)?);
+                    }
+                }
+                "repeatedFixed32" | "repeated_fixed32" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_fixed32.push(json_to_u32(elem)?);
+                    }
+                }
+                "repeatedFixed64" | "repeated_fixed64" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_fixed64.push(json_to_u64(elem)?);
+                    }
+                }
+                "repeatedSfixed32" | "repeated_sfixed32" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_sfixed32.push(json_to_i32(elem)?);
+                    }
+                }
+                "repeatedSfixed64" | "repeated_sfixed64" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_sfixed64.push(json_to_i64(elem)?);
+                    }
+                }
+                "repeatedFloat" | "repeated_float" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_float.push(json_to_f32(elem)?);
+                    }
+                }
+                "repeatedDouble" | "repeated_double" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_double.push(json_to_f64(elem)?);
+                    }
+                }
+                "repeatedBool" | "repeated_bool" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_bool.push(json_to_bool(elem)?);
+                    }
+                }
+                "repeatedString" | "repeated_string" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_string.push(json_to_string(elem)?);
+                    }
+                }
+                "repeatedBytes" | "repeated_bytes" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_bytes.push(json_to_bytes(elem)?);
+                    }
+                }
+                "repeatedNestedMessage" | "repeated_nested_message" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_nested_message.push(NestedMessage::from_json_value(elem)?);
+                    }
+                }
+                "repeatedForeignMessage" | "repeated_foreign_message" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_foreign_message.push(ForeignMessage::from_json_value(elem)?);
+                    }
+                }
+                "repeatedNestedEnum" | "repeated_nested_enum" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_nested_enum.push(json_to_enum(elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)])?);
+                    }
+                }
+                "repeatedForeignEnum" | "repeated_foreign_enum" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_foreign_enum.push(json_to_enum(elem, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)])?);
+                    }
+                }
+                "repeatedStringPiece" | "repeated_string_piece" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_string_piece.push(json_to_string(elem)?);
+                    }
+                }
+                "repeatedCord" | "repeated_cord" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_cord.push(json_to_string(elem)?);
+                    }
+                }
+                "packedInt32" | "packed_int32" => {
+                    for elem in json_array(value)? {
+                        msg.packed_int32.push(json_to_i32(elem)?);
+                    }
+                }
+                "packedInt64" | "packed_int64" => {
+                    for elem in json_array(value)? {
+                        msg.packed_int64.push(json_to_i64(elem)?);
+                    }
+                }
+                "packedUint32" | "packed_uint32" => {
+                    for elem in json_array(value)? {
+                        msg.packed_uint32.push(json_to_u32(elem)?);
+                    }
+                }
+                "packedUint64" | "packed_uint64" => {
+                    for elem in json_array(value)? {
+                        msg.packed_uint64.push(json_to_u64(elem)?);
+                    }
+                }
+                "packedSint32" | "packed_sint32" => {
+                    for elem in json_array(value)? {
+                        msg.packed_sint32.push(json_to_i32(elem)?);
+                    }
+                }
+                "packedSint64" | "packed_sint64" => {
+                    for elem in json_array(value)? {
+                        msg.packed_sint64.push(json_to_i64(elem)?);
+                    }
+                }
+                "packedFixed32" | "packed_fixed32" => {
+                    for elem in json_array(value)? {
+                        msg.packed_fixed32.push(json_to_u32(elem)?);
+                    }
+                }
+                "packedFixed64" | "packed_fixed64" => {
+                    for elem in json_array(value)? {
+                        msg.packed_fixed64.push(json_to_u64(elem)?);
+                    }
+                }
+                "packedSfixed32" | "packed_sfixed32" => {
+                    for elem in json_array(value)? {
+                        msg.packed_sfixed32.push(json_to_i32(elem)?);
+                    }
+                }
+                "packedSfixed64" | "packed_sfixed64" => {
+                    for elem in json_array(value)? {
+                        msg.packed_sfixed64.push(json_to_i64(elem)?);
+                    }
+                }
+                "packedFloat" | "packed_float" => {
+                    for elem in json_array(value)? {
+                        msg.packed_float.push(json_to_f32(elem)?);
+                    }
+                }
+                "packedDouble" | "packed_double" => {
+                    for elem in json_array(value)? {
+                        msg.packed_double.push(json_to_f64(elem)?);
+                    }
+                }
+                "packedBool" | "packed_bool" => {
+                    for elem in json_array(value)? {
+                        msg.packed_bool.push(json_to_bool(elem)?);
+                    }
+                }
+                "packedNestedEnum" | "packed_nested_enum" => {
+                    for elem in json_array(value)? {
+                        msg.packed_nested_enum.push(json_to_enum(elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)])?);
+                    }
+                }
+                "unpackedInt32" | "unpacked_int32" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_int32.push(json_to_i32(elem)?);
+                    }
+                }
+                "unpackedInt64" | "unpacked_int64" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_int64.push(json_to_i64(elem)?);
+                    }
+                }
+                "unpackedUint32" | "unpacked_uint32" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_uint32.push(json_to_u32(elem)?);
+                    }
+                }
+                "unpackedUint64" | "unpacked_uint64" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_uint64.push(json_to_u64(elem)?);
+                    }
+                }
+                "unpackedSint32" | "unpacked_sint32" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_sint32.push(json_to_i32(elem)?);
+                    }
+                }
+                "unpackedSint64" | "unpacked_sint64" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_sint64.push(json_to_i64(elem)?);
+                    }
+                }
+                "unpackedFixed32" | "unpacked_fixed32" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_fixed32.push(json_to_u32(elem)?);
+                    }
+                }
+                "unpackedFixed64" | "unpacked_fixed64" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_fixed64.push(json_to_u64(elem)?);
+                    }
+                }
+                "unpackedSfixed32" | "unpacked_sfixed32" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_sfixed32.push(json_to_i32(elem)?);
+                    }
+                }
+                "unpackedSfixed64" | "unpacked_sfixed64" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_sfixed64.push(json_to_i64(elem)?);
+                    }
+                }
+                "unpackedFloat" | "unpacked_float" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_float.push(json_to_f32(elem)?);
+                    }
+                }
+                "unpackedDouble" | "unpacked_double" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_double.push(json_to_f64(elem)?);
+                    }
+                }
+                "unpackedBool" | "unpacked_bool" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_bool.push(json_to_bool(elem)?);
+                    }
+                }
+                "unpackedNestedEnum" | "unpacked_nested_enum" => {
+                    for elem in json_array(value)? {
+                        msg.unpacked_nested_enum.push(json_to_enum(elem, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)])?);
+                    }
+                }
+                "mapInt32Int32" | "map_int32_int32" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_int32_int32.insert(json_map_key(key)?, json_to_i32(value)?);
+                    }
+                }
+                "mapInt64Int64" | "map_int64_int64" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_int64_int64.insert(json_map_key(key)?, json_to_i64(value)?);
+                    }
+                }
+                "mapUint32Uint32" | "map_uint32_uint32" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_uint32_uint32.insert(json_map_key(key)?, json_to_u32(value)?);
+                    }
+                }
+                "mapUint64Uint64" | "map_uint64_uint64" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_uint64_uint64.insert(json_map_key(key)?, json_to_u64(value)?);
+                    }
+                }
+                "mapSint32Sint32" | "map_sint32_sint32" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_sint32_sint32.insert(json_map_key(key)?, json_to_i32(value)?);
+                    }
+                }
+                "mapSint64Sint64" | "map_sint64_sint64" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_sint64_sint64.insert(json_map_key(key)?, json_to_i64(value)?);
+                    }
+                }
+                "mapFixed32Fixed32" | "map_fixed32_fixed32" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_fixed32_fixed32.insert(json_map_key(key)?, json_to_u32(value)?);
+                    }
+                }
+                "mapFixed64Fixed64" | "map_fixed64_fixed64" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_fixed64_fixed64.insert(json_map_key(key)?, json_to_u64(value)?);
+                    }
+                }
+                "mapSfixed32Sfixed32" | "map_sfixed32_sfixed32" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_sfixed32_sfixed32.insert(json_map_key(key)?, json_to_i32(value)?);
+                    }
+                }
+                "mapSfixed64Sfixed64" | "map_sfixed64_sfixed64" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_sfixed64_sfixed64.insert(json_map_key(key)?, json_to_i64(value)?);
+                    }
+                }
+                "mapInt32Float" | "map_int32_float" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_int32_float.insert(json_map_key(key)?, json_to_f32(value)?);
+                    }
+                }
+                "mapInt32Double" | "map_int32_double" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_int32_double.insert(json_map_key(key)?, json_to_f64(value)?);
+                    }
+                }
+                "mapBoolBool" | "map_bool_bool" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_bool_bool.insert(json_map_key(key)?, json_to_bool(value)?);
+                    }
+                }
+                "mapStringString" | "map_string_string" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_string_string.insert(json_map_key(key)?, json_to_string(value)?);
+                    }
+                }
+                "mapStringBytes" | "map_string_bytes" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_string_bytes.insert(json_map_key(key)?, json_to_bytes(value)?);
+                    }
+                }
+                "mapStringNestedMessage" | "map_string_nested_message" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_string_nested_message.insert(json_map_key(key)?, NestedMessage::from_json_value(value)?);
+                    }
+                }
+                "mapStringForeignMessage" | "map_string_foreign_message" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_string_foreign_message.insert(json_map_key(key)?, ForeignMessage::from_json_value(value)?);
+                    }
+                }
+                "mapStringNestedEnum" | "map_string_nested_enum" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_string_nested_enum.insert(json_map_key(key)?, json_to_enum(value, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)])?);
+                    }
+                }
+                "mapStringForeignEnum" | "map_string_foreign_enum" => {
+                    for (key, value) in json_object(value)? {
+                        msg.map_string_foreign_enum.insert(json_map_key(key)?, json_to_enum(value, &[("FOREIGN_FOO", 0), ("FOREIGN_BAR", 1), ("FOREIGN_BAZ", 2)])?);
+                    }
+                }
+                "oneofUint32" | "oneof_uint32" => msg.oneof_field = TestAllTypesProto3OneofField::OneofUint32(json_to_u32(value)?),
+                "oneofNestedMessage" | "oneof_nested_message" => msg.oneof_field = TestAllTypesProto3OneofField::OneofNestedMessage(NestedMessage::from_json_value(value)?.into()),
+                "oneofString" | "oneof_string" => msg.oneof_field = TestAllTypesProto3OneofField::OneofString(json_to_string(value)?),
+                "oneofBytes" | "oneof_bytes" => msg.oneof_field = TestAllTypesProto3OneofField::OneofBytes(json_to_bytes(value)?),
+                "oneofBool" | "oneof_bool" => msg.oneof_field = TestAllTypesProto3OneofField::OneofBool(json_to_bool(value)?),
+                "oneofUint64" | "oneof_uint64" => msg.oneof_field = TestAllTypesProto3OneofField::OneofUint64(json_to_u64(value)?),
+                "oneofFloat" | "oneof_float" => msg.oneof_field = TestAllTypesProto3OneofField::OneofFloat(json_to_f32(value)?),
+                "oneofDouble" | "oneof_double" => msg.oneof_field = TestAllTypesProto3OneofField::OneofDouble(json_to_f64(value)?),
+                "oneofEnum" | "oneof_enum" => msg.oneof_field = TestAllTypesProto3OneofField::OneofEnum(json_to_enum(value, &[("FOO", 0), ("BAR", 1), ("BAZ", 2), ("NEG", -1)])?),
+                "optionalBoolWrapper" | "optional_bool_wrapper" => msg.optional_bool_wrapper = Some(json_to_bool(value)?),
+                "optionalInt32Wrapper" | "optional_int32_wrapper" => msg.optional_int32_wrapper = Some(json_to_i32(value)?),
+                "optionalInt64Wrapper" | "optional_int64_wrapper" => msg.optional_int64_wrapper = Some(json_to_i64(value)?),
+                "optionalUint32Wrapper" | "optional_uint32_wrapper" => msg.optional_uint32_wrapper = Some(json_to_u32(value)?),
+                "optionalUint64Wrapper" | "optional_uint64_wrapper" => msg.optional_uint64_wrapper = Some(json_to_u64(value)?),
+                "optionalFloatWrapper" | "optional_float_wrapper" => msg.optional_float_wrapper = Some(json_to_f32(value)?),
+                "optionalDoubleWrapper" | "optional_double_wrapper" => msg.optional_double_wrapper = Some(json_to_f64(value)?),
+                "optionalStringWrapper" | "optional_string_wrapper" => msg.optional_string_wrapper = Some(json_to_string(value)?),
+                "optionalBytesWrapper" | "optional_bytes_wrapper" => msg.optional_bytes_wrapper = Some(json_to_bytes(value)?),
+                "repeatedBoolWrapper" | "repeated_bool_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_bool_wrapper.push(BoolValue::from_json_value(elem)?);
+                    }
+                }
+                "repeatedInt32Wrapper" | "repeated_int32_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_int32_wrapper.push(Int32Value::from_json_value(elem)?);
+                    }
+                }
+                "repeatedInt64Wrapper" | "repeated_int64_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_int64_wrapper.push(Int64Value::from_json_value(elem)?);
+                    }
+                }
+                "repeatedUint32Wrapper" | "repeated_uint32_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_uint32_wrapper.push(UInt32Value::from_json_value(elem)?);
+                    }
+                }
+                "repeatedUint64Wrapper" | "repeated_uint64_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_uint64_wrapper.push(UInt64Value::from_json_value(elem)?);
+                    }
+                }
+                "repeatedFloatWrapper" | "repeated_float_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_float_wrapper.push(FloatValue::from_json_value(elem)?);
+                    }
+                }
+                "repeatedDoubleWrapper" | "repeated_double_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_double_wrapper.push(DoubleValue::from_json_value(elem)?);
+                    }
+                }
+                "repeatedStringWrapper" | "repeated_string_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_string_wrapper.push(StringValue::from_json_value(elem)?);
+                    }
+                }
+                "repeatedBytesWrapper" | "repeated_bytes_wrapper" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_bytes_wrapper.push(BytesValue::from_json_value(elem)?);
+                    }
+                }
+                "optionalDuration" | "optional_duration" => msg.optional_duration = Duration::from_json_value(value)?,
+                "optionalTimestamp" | "optional_timestamp" => msg.optional_timestamp = Timestamp::from_json_value(value)?,
+                "optionalAny" | "optional_any" => msg.optional_any = Any::from_json_value(value)?,
+                "repeatedDuration" | "repeated_duration" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_duration.push(Duration::from_json_value(elem)?);
+                    }
+                }
+                "repeatedTimestamp" | "repeated_timestamp" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_timestamp.push(Timestamp::from_json_value(elem)?);
+                    }
+                }
+                "repeatedAny" | "repeated_any" => {
+                    for elem in json_array(value)? {
+                        msg.repeated_any.push(Any::from_json_value(elem)?);
+                    }
+                }
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+/// Upstream nests this in TestAllTypesProto3; the plugin only generates
+/// top-level messages, and the wire form does not depend on where it lives.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct NestedMessage {
+    pub a: i32,
+    pub corecursive: Option<Box<TestAllTypesProto3>>,
+    pub unknown_fields: UnknownFields,
+}
+
+impl NestedMessage {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = NestedMessage {
+        a: 0,
+        corecursive: None,
+        unknown_fields: UnknownFields::new(),
+    };
+}
+
+impl Default for NestedMessage {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl NestedMessage {
+    pub const A_FIELD_NUMBER: u32 = 1;
+    pub const CORECURSIVE_FIELD_NUMBER: u32 = 2;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 0, packed: false, name: "NestedMessage.a" },
+        FieldWireType { number: 2, wire_type: 2, packed: false, name: "NestedMessage.corecursive" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        self.unknown_fields.write_reverse(w);
+
+        // field 2: corecursive
+        if let Some(elem) = &self.corecursive {
+            w.write_message(2, &**elem);
+        }
+
+        // field 1: a
+        if self.a != 0 {
+            w.write_int32(self.a);
+            w.write_key(0x08);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if self.a != 0 {
+            len += key_len(1) + int32_len(self.a);
+        }
+        if let Some(elem) = &self.corecursive {
+            len += key_len(2) + bytes_len(elem.encoded_len());
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if self.a != 0 {
+            w.write_key(0x08)?;
+            w.write_int32(self.a)?;
+        }
+        if let Some(elem) = &self.corecursive {
+            w.write_message(2, &**elem)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        let mut len = 0;
+        if self.a != 0 {
+            len += key_len(1) + int32_len(self.a);
+        }
+        if let Some(elem) = &self.corecursive {
+            len += key_len(2) + bytes_len(sizes.record(&**elem));
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        if self.a != 0 {
+            w.write_key(0x08)?;
+            w.write_int32(self.a)?;
+        }
+        if let Some(elem) = &self.corecursive {
+            w.write_message_sized(2, &**elem, sizes)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                8 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.a = v;
+                    pos = new_pos;
+                }
+                18 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.corecursive.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                    self.unknown_fields.push_raw(&data[field_start..pos]);
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for NestedMessage {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        NestedMessage::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        NestedMessage::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        NestedMessage::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        NestedMessage::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        NestedMessage::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        NestedMessage::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        NestedMessage::merge_with(self, data, opts)
+    }
+
+    fn encode_canonical(&self) -> Vec<u8> {
+        let mut msg = self.clone();
+        msg.clear_unknown_fields();
+        msg.encode()
+    }
+
+    fn clear_unknown_fields(&mut self) {
+        self.unknown_fields.clear();
+        if let Some(v) = &mut self.corecursive {
+            v.clear_unknown_fields();
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for NestedMessage {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        NestedMessage::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<NestedMessage> for Vec<u8> {
+    fn from(msg: NestedMessage) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for NestedMessage {
+    const FULL_NAME: &'static str = "protobuf_test_messages.proto3.NestedMessage";
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NestedMessageBuilder {
+    inner: NestedMessage,
+}
+
+impl NestedMessage {
+    pub fn builder() -> NestedMessageBuilder {
+        NestedMessageBuilder::default()
+    }
+}
+
+impl NestedMessageBuilder {
+    pub fn with_a(mut self, value: i32) -> Self {
+        self.inner.a = value;
+        self
+    }
+
+    pub fn with_corecursive(mut self, value: TestAllTypesProto3) -> Self {
+        self.inner.corecursive = Some(value.into());
+        self
+    }
+
+    pub fn build(self) -> NestedMessage {
+        self.inner
+    }
+}
+
+impl NestedMessage {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if self.a != 0 {
+            out.write_scalar("a", self.a);
+        }
+        if let Some(elem) = &self.corecursive {
+            out.begin_message("corecursive");
+            elem.write_text(out);
+            out.end_message();
+        }
+    }
+}
+
+impl core::fmt::Display for NestedMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl NestedMessage {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if self.a != 0 {
+            out.key("a");
+            out.write_i32(self.a);
+        }
+        if let Some(elem) = &self.corecursive {
+            out.key("corecursive");
+            elem.write_json(out);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "a" => msg.a = json_to_i32(value)?,
+                "corecursive" => msg.corecursive = Some(TestAllTypesProto3::from_json_value(value)?.into()),
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct ForeignMessage {
+    pub c: i32,
+    pub unknown_fields: UnknownFields,
+}
+
+impl ForeignMessage {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = ForeignMessage {
+        c: 0,
+        unknown_fields: UnknownFields::new(),
+    };
+}
+
+impl Default for ForeignMessage {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ForeignMessage {
+    pub const C_FIELD_NUMBER: u32 = 1;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 0, packed: false, name: "ForeignMessage.c" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        self.unknown_fields.write_reverse(w);
+
+        // field 1: c
+        if self.c != 0 {
+            w.write_int32(self.c);
+            w.write_key(0x08);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if self.c != 0 {
+            len += key_len(1) + int32_len(self.c);
+        }
+        len += self.unknown_fields.encoded_len();
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if self.c != 0 {
+            w.write_key(0x08)?;
+            w.write_int32(self.c)?;
+        }
+        self.unknown_fields.write_to(w)?;
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                8 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.c = v;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                    self.unknown_fields.push_raw(&data[field_start..pos]);
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for ForeignMessage {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        ForeignMessage::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ForeignMessage::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        ForeignMessage::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        ForeignMessage::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        ForeignMessage::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        ForeignMessage::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        ForeignMessage::merge_with(self, data, opts)
+    }
+
+    fn encode_canonical(&self) -> Vec<u8> {
+        let mut msg = self.clone();
+        msg.clear_unknown_fields();
+        msg.encode()
+    }
+
+    fn clear_unknown_fields(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl TryFrom<&[u8]> for ForeignMessage {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        ForeignMessage::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<ForeignMessage> for Vec<u8> {
+    fn from(msg: ForeignMessage) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for ForeignMessage {
+    const FULL_NAME: &'static str = "protobuf_test_messages.proto3.ForeignMessage";
+}
+
+impl ForeignMessage {
+    /// Longest encoding of a `ForeignMessage` whose fields stay within
+    /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+    /// counting unknown fields.
+    pub const MAX_ENCODED_LEN: usize = 11;
+
+    /// Account space for any such value in the `encode_account_data`
+    /// layout, e.g. for `Rent::minimum_balance`.
+    pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ForeignMessageBuilder {
+    inner: ForeignMessage,
+}
+
+impl ForeignMessage {
+    pub fn builder() -> ForeignMessageBuilder {
+        ForeignMessageBuilder::default()
+    }
+}
+
+impl ForeignMessageBuilder {
+    pub fn with_c(mut self, value: i32) -> Self {
+        self.inner.c = value;
+        self
+    }
+
+    pub fn build(self) -> ForeignMessage {
+        self.inner
+    }
+}
+
+impl ForeignMessage {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if self.c != 0 {
+            out.write_scalar("c", self.c);
+        }
+    }
+}
+
+impl core::fmt::Display for ForeignMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl ForeignMessage {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if self.c != 0 {
+            out.key("c");
+            out.write_i32(self.c);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "c" => msg.c = json_to_i32(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+/// Every message in this file, for dispatching `Any` payloads by type URL.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyMessage {
+    TestAllTypesProto3(TestAllTypesProto3),
+    NestedMessage(NestedMessage),
+    ForeignMessage(ForeignMessage),
+}
+
+impl AnyMessage {
+    /// Decode `any` as the message its type URL names, or `None` if that
+    /// message is not in this file.
+    pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+        Ok(Some(match any.type_name() {
+            TestAllTypesProto3::FULL_NAME => AnyMessage::TestAllTypesProto3(TestAllTypesProto3::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            NestedMessage::FULL_NAME => AnyMessage::NestedMessage(NestedMessage::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            ForeignMessage::FULL_NAME => AnyMessage::ForeignMessage(ForeignMessage::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            _ => return Ok(None),
+        }))
+    }
+
+    pub fn pack(&self) -> Any {
+        match self {
+            AnyMessage::TestAllTypesProto3(msg) => Any::pack(msg),
+            AnyMessage::NestedMessage(msg) => Any::pack(msg),
+            AnyMessage::ForeignMessage(msg) => Any::pack(msg),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Testee for Google's protobuf conformance suite.
+//!
+//! `conformance_test_runner` starts this binary and writes requests to its
+//! stdin, each a little-endian `u32` length followed by an encoded
+//! `conformance.ConformanceRequest`; every request is answered on stdout
+//! with a `ConformanceResponse` framed the same way. Only the binary wire
+//! format is tested: JSON, JSPB and text format requests, and message
+//! types other than `TestAllTypesProto3`, are reported as skipped.
+//!
+//! `generated/` is checked in so the testee builds without protoc. It is
+//! generated with `unknown_fields`, which the suite needs to round-trip
+//! fields it sends but the schema omits. After a generator change,
+//! refresh it with `pnpm generate:conformance`.
+
+use std::io::{self, Read, Write};
+
+#[path = "../../rs/protobuf_runtime.rs"]
+pub mod protobuf_runtime;
+
+#[path = "generated/conformance/conformance.rs"]
+pub mod conformance;
+
+#[path = "generated/protobuf_test_messages/proto3/test_messages_proto3.rs"]
+pub mod test_messages_proto3;
+
+use conformance::{
+    ConformanceRequest, ConformanceRequestPayload, ConformanceResponse, ConformanceResponseResult,
+    FailureSet, WireFormat,
+};
+use protobuf_runtime::DecodeOptions;
+use test_messages_proto3::TestAllTypesProto3;
+
+/// The suite's inputs are not instruction data, so the whole-message cap
+/// is lifted; the per-field limits still apply.
+const OPTIONS: DecodeOptions = DecodeOptions { max_message_len: usize::MAX, ..DecodeOptions::DEFAULT };
+
+fn main() -> io::Result<()> {
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout().lock();
+    while let Some(request) = read_frame(&mut stdin)? {
+        let response = ConformanceResponse {
+            result: run(&request),
+            ..Default::default()
+        };
+        let out = response.encode();
+        stdout.write_all(&(out.len() as u32).to_le_bytes())?;
+        stdout.write_all(&out)?;
+        stdout.flush()?;
+    }
+    Ok(())
+}
+
+/// Read one length-prefixed request; `None` once the runner closes stdin.
+fn read_frame(input: &mut impl Read) -> io::Result<Option<Vec<u8>>> {
+    let mut len = [0u8; 4];
+    match input.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let mut buf = vec![0u8; u32::from_le_bytes(len) as usize];
+    input.read_exact(&mut buf)?;
+    Ok(Some(buf))
+}
+
+fn run(request: &[u8]) -> ConformanceResponseResult {
+    let request = match ConformanceRequest::decode_with(request, &OPTIONS) {
+        Ok(request) => request,
+        Err(e) => return ConformanceResponseResult::RuntimeError(format!("bad request: {e:?}")),
+    };
+
+    match request.message_type.as_str() {
+        // The runner asks for the expected failures first; they are kept
+        // in failure_list.txt and passed with --failure_list instead
+        "conformance.FailureSet" => {
+            return ConformanceResponseResult::ProtobufPayload(FailureSet::default().encode());
+        }
+        "protobuf_test_messages.proto3.TestAllTypesProto3" => {}
+        other => return ConformanceResponseResult::Skipped(format!("unsupported message type {other}")),
+    }
+
+    let payload = match &request.payload {
+        ConformanceRequestPayload::ProtobufPayload(payload) => payload,
+        _ => return ConformanceResponseResult::Skipped("only protobuf input is supported".into()),
+    };
+    if request.requested_output_format != WireFormat::Protobuf as i32 {
+        return ConformanceResponseResult::Skipped("only protobuf output is supported".into());
+    }
+
+    match TestAllTypesProto3::decode_with(payload, &OPTIONS) {
+        Ok(message) => ConformanceResponseResult::ProtobufPayload(message.encode()),
+        Err(e) => ConformanceResponseResult::ParseError(format!("{e:?}")),
+    }
+}
@@ -0,0 +1,649 @@
+// Auto-generated by protoc-gen-solana from floats.proto
+// DO NOT EDIT
+
+#![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+use crate::protobuf_runtime::*;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub enum PriceFeedSpread {
+    Unset,
+    Bps(f32),
+    Absolute(f64),
+}
+
+impl PriceFeedSpread {
+    pub const DEFAULT: Self = PriceFeedSpread::Unset;
+}
+
+impl Default for PriceFeedSpread {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Exercises: float and double fields in every position (rejected under
+/// the `reject_floats` option)
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct PriceFeed {
+    pub price: f64,
+    pub confidence: f32,
+    pub history: Vec<f64>,
+    pub spread: PriceFeedSpread,
+    pub twap: Option<f64>,
+    pub weights: BTreeMap<String, f32>,
+}
+
+impl PriceFeed {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = PriceFeed {
+        price: 0.0,
+        confidence: 0.0,
+        history: Vec::new(),
+        spread: PriceFeedSpread::Unset,
+        twap: None,
+        weights: BTreeMap::new(),
+    };
+}
+
+impl Default for PriceFeed {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl PriceFeed {
+    pub const PRICE_FIELD_NUMBER: u32 = 1;
+    pub const CONFIDENCE_FIELD_NUMBER: u32 = 2;
+    pub const HISTORY_FIELD_NUMBER: u32 = 3;
+    pub const BPS_FIELD_NUMBER: u32 = 4;
+    pub const ABSOLUTE_FIELD_NUMBER: u32 = 5;
+    pub const TWAP_FIELD_NUMBER: u32 = 6;
+    pub const WEIGHTS_FIELD_NUMBER: u32 = 7;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 1, packed: false, name: "PriceFeed.price" },
+        FieldWireType { number: 2, wire_type: 5, packed: false, name: "PriceFeed.confidence" },
+        FieldWireType { number: 3, wire_type: 1, packed: true, name: "PriceFeed.history" },
+        FieldWireType { number: 4, wire_type: 5, packed: false, name: "PriceFeed.bps" },
+        FieldWireType { number: 5, wire_type: 1, packed: false, name: "PriceFeed.absolute" },
+        FieldWireType { number: 6, wire_type: 1, packed: false, name: "PriceFeed.twap" },
+        FieldWireType { number: 7, wire_type: 2, packed: false, name: "PriceFeed.weights" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        // field 7: weights
+        for (key, value) in self.weights.iter().rev() {
+            w.write_nested(7, |w| {
+                w.write_float(*value);
+                w.write_key(0x15);
+                w.write_string(key);
+                w.write_key(0x0a);
+            });
+        }
+
+        // field 6: twap
+        if let Some(elem) = &self.twap {
+            w.write_nested(6, |w| DoubleValue::write_value_reverse(elem, w));
+        }
+
+        // field 5: absolute
+        if let PriceFeedSpread::Absolute(elem) = &self.spread {
+            w.write_double(*elem);
+            w.write_key(0x29);
+        }
+
+        // field 4: bps
+        if let PriceFeedSpread::Bps(elem) = &self.spread {
+            w.write_float(*elem);
+            w.write_key(0x25);
+        }
+
+        // field 3: history
+        for elem in self.history.iter().rev() {
+            w.write_double(*elem);
+            w.write_key(0x19);
+        }
+
+        // field 2: confidence
+        if self.confidence.to_bits() != 0 {
+            w.write_float(self.confidence);
+            w.write_key(0x15);
+        }
+
+        // field 1: price
+        if self.price.to_bits() != 0 {
+            w.write_double(self.price);
+            w.write_key(0x09);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if self.price.to_bits() != 0 {
+            len += key_len(1) + 8;
+        }
+        if self.confidence.to_bits() != 0 {
+            len += key_len(2) + 4;
+        }
+        len += self.history.len() * (key_len(3) + 8);
+        if let PriceFeedSpread::Bps(_) = &self.spread {
+            len += key_len(4) + 4;
+        }
+        if let PriceFeedSpread::Absolute(_) = &self.spread {
+            len += key_len(5) + 8;
+        }
+        if let Some(elem) = &self.twap {
+            len += key_len(6) + bytes_len(DoubleValue::value_len(elem));
+        }
+        for key in self.weights.keys() {
+            len += key_len(7) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + 4);
+        }
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if self.price.to_bits() != 0 {
+            w.write_key(0x09)?;
+            w.write_double(self.price)?;
+        }
+        if self.confidence.to_bits() != 0 {
+            w.write_key(0x15)?;
+            w.write_float(self.confidence)?;
+        }
+        for elem in &self.history {
+            w.write_key(0x19)?;
+            w.write_double(*elem)?;
+        }
+        if let PriceFeedSpread::Bps(elem) = &self.spread {
+            w.write_key(0x25)?;
+            w.write_float(*elem)?;
+        }
+        if let PriceFeedSpread::Absolute(elem) = &self.spread {
+            w.write_key(0x29)?;
+            w.write_double(*elem)?;
+        }
+        if let Some(elem) = &self.twap {
+            w.write_key(0x32)?;
+            w.write_varint(DoubleValue::value_len(elem) as u64)?;
+            DoubleValue::write_value(elem, w)?;
+        }
+        for (key, value) in &self.weights {
+            w.write_key(0x3a)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + 4) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x15)?;
+            w.write_float(*value)?;
+        }
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                9 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_double(data, pos)?;
+                    self.price = v;
+                    pos = new_pos;
+                }
+                21 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_float(data, pos)?;
+                    self.confidence = v;
+                    pos = new_pos;
+                }
+                25 => {
+                    let (v, new_pos) = decode_double(data, pos)?;
+                    if self.history.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.history.push(v);
+                    pos = new_pos;
+                }
+                26 => {
+                    let new_pos = decode_packed_double_with(data, pos, &mut self.history, opts)?;
+                    pos = new_pos;
+                }
+                37 => {
+                    mark_singular(&mut seen, 2, opts)?;
+                    let (v, new_pos) = decode_float(data, pos)?;
+                    self.spread = PriceFeedSpread::Bps(v);
+                    pos = new_pos;
+                }
+                41 => {
+                    mark_singular(&mut seen, 2, opts)?;
+                    let (v, new_pos) = decode_double(data, pos)?;
+                    self.spread = PriceFeedSpread::Absolute(v);
+                    pos = new_pos;
+                }
+                50 => {
+                    mark_singular(&mut seen, 3, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    let value = self.twap.get_or_insert_with(Default::default);
+                    DoubleValue::merge_value(value, sub, opts)?;
+                    pos = new_pos;
+                }
+                58 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = String::new();
+                    let mut value = 0.0;
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            10 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            21 => {
+                                let (v, next) = decode_float(entry, entry_pos)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.weights.len() >= opts.max_repeated_len && !self.weights.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.weights.insert(key, value);
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The element of repeated field `number` being decoded, for `field_error`.
+    fn next_index(&self, number: u64) -> Option<usize> {
+        match number {
+            3 => Some(self.history.len()),
+            _ => None,
+        }
+    }
+}
+
+impl Message for PriceFeed {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        PriceFeed::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        PriceFeed::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        PriceFeed::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        PriceFeed::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        PriceFeed::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        PriceFeed::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        PriceFeed::merge_with(self, data, opts)
+    }
+}
+
+impl TryFrom<&[u8]> for PriceFeed {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        PriceFeed::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<PriceFeed> for Vec<u8> {
+    fn from(msg: PriceFeed) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for PriceFeed {
+    const FULL_NAME: &'static str = "example.PriceFeed";
+}
+
+impl PriceFeed {
+    pub fn bps(&self) -> Option<&f32> {
+        match &self.spread {
+            PriceFeedSpread::Bps(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_bps(&mut self, value: f32) {
+        self.spread = PriceFeedSpread::Bps(value);
+    }
+
+    pub fn absolute(&self) -> Option<&f64> {
+        match &self.spread {
+            PriceFeedSpread::Absolute(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_absolute(&mut self, value: f64) {
+        self.spread = PriceFeedSpread::Absolute(value);
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PriceFeedBuilder {
+    inner: PriceFeed,
+}
+
+impl PriceFeed {
+    pub fn builder() -> PriceFeedBuilder {
+        PriceFeedBuilder::default()
+    }
+}
+
+impl PriceFeedBuilder {
+    pub fn with_price(mut self, value: f64) -> Self {
+        self.inner.price = value;
+        self
+    }
+
+    pub fn with_confidence(mut self, value: f32) -> Self {
+        self.inner.confidence = value;
+        self
+    }
+
+    pub fn with_history(mut self, value: Vec<f64>) -> Self {
+        self.inner.history = value;
+        self
+    }
+
+    pub fn add_history(mut self, value: f64) -> Self {
+        self.inner.history.push(value);
+        self
+    }
+
+    pub fn with_bps(mut self, value: f32) -> Self {
+        self.inner.spread = PriceFeedSpread::Bps(value);
+        self
+    }
+
+    pub fn with_absolute(mut self, value: f64) -> Self {
+        self.inner.spread = PriceFeedSpread::Absolute(value);
+        self
+    }
+
+    pub fn with_twap(mut self, value: f64) -> Self {
+        self.inner.twap = Some(value);
+        self
+    }
+
+    pub fn with_weights(mut self, value: BTreeMap<String, f32>) -> Self {
+        self.inner.weights = value;
+        self
+    }
+
+    pub fn insert_weights(mut self, key: String, value: f32) -> Self {
+        self.inner.weights.insert(key, value);
+        self
+    }
+
+    pub fn build(self) -> PriceFeed {
+        self.inner
+    }
+}
+
+impl PriceFeed {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if self.price.to_bits() != 0 {
+            out.write_f64("price", self.price);
+        }
+        if self.confidence.to_bits() != 0 {
+            out.write_f32("confidence", self.confidence);
+        }
+        for elem in &self.history {
+            out.write_f64("history", *elem);
+        }
+        if let PriceFeedSpread::Bps(elem) = &self.spread {
+            out.write_f32("bps", *elem);
+        }
+        if let PriceFeedSpread::Absolute(elem) = &self.spread {
+            out.write_f64("absolute", *elem);
+        }
+        if let Some(elem) = &self.twap {
+            out.begin_message("twap");
+            DoubleValue::write_text_value(elem, out);
+            out.end_message();
+        }
+        for (key, value) in &self.weights {
+            out.begin_message("weights");
+            out.write_str("key", key);
+            out.write_f32("value", *value);
+            out.end_message();
+        }
+    }
+}
+
+impl core::fmt::Display for PriceFeed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl PriceFeed {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if self.price.to_bits() != 0 {
+            out.key("price");
+            out.write_f64(self.price);
+        }
+        if self.confidence.to_bits() != 0 {
+            out.key("confidence");
+            out.write_f32(self.confidence);
+        }
+        if !self.history.is_empty() {
+            out.key("history");
+            out.begin_array();
+            for elem in &self.history {
+                out.write_f64(*elem);
+            }
+            out.end_array();
+        }
+        if let PriceFeedSpread::Bps(elem) = &self.spread {
+            out.key("bps");
+            out.write_f32(*elem);
+        }
+        if let PriceFeedSpread::Absolute(elem) = &self.spread {
+            out.key("absolute");
+            out.write_f64(*elem);
+        }
+        if let Some(elem) = &self.twap {
+            out.key("twap");
+            out.write_f64(*elem);
+        }
+        if !self.weights.is_empty() {
+            out.key("weights");
+            out.begin_object();
+            for (key, value) in &self.weights {
+                out.key(key);
+                out.write_f32(*value);
+            }
+            out.end_object();
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "price" => msg.price = json_to_f64(value)?,
+                "confidence" => msg.confidence = json_to_f32(value)?,
+                "history" => {
+                    for elem in json_array(value)? {
+                        msg.history.push(json_to_f64(elem)?);
+                    }
+                }
+                "bps" => msg.spread = PriceFeedSpread::Bps(json_to_f32(value)?),
+                "absolute" => msg.spread = PriceFeedSpread::Absolute(json_to_f64(value)?),
+                "twap" => msg.twap = Some(json_to_f64(value)?),
+                "weights" => {
+                    for (key, value) in json_object(value)? {
+                        msg.weights.insert(json_map_key(key)?, json_to_f32(value)?);
+                    }
+                }
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+/// Every message in this file, for dispatching `Any` payloads by type URL.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyMessage {
+    PriceFeed(PriceFeed),
+}
+
+impl AnyMessage {
+    /// Decode `any` as the message its type URL names, or `None` if that
+    /// message is not in this file.
+    pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+        Ok(Some(match any.type_name() {
+            PriceFeed::FULL_NAME => AnyMessage::PriceFeed(PriceFeed::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            _ => return Ok(None),
+        }))
+    }
+
+    pub fn pack(&self) -> Any {
+        match self {
+            AnyMessage::PriceFeed(msg) => Any::pack(msg),
+        }
+    }
+}
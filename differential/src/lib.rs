@@ -0,0 +1,18 @@
+//! Differential fixtures: the shipped runtime, code generated from
+//! `tests/protos/example.proto`, `packed.proto` and `floats.proto`, and
+//! the prost types for the same messages in [`reference`].
+//!
+//! `generated/` is checked in so the tests build without protoc. After a
+//! generator change, refresh it with `pnpm generate:differential`.
+
+#[path = "../../rs/protobuf_runtime.rs"]
+pub mod protobuf_runtime;
+
+#[path = "generated/example/example.rs"]
+pub mod example;
+#[path = "generated/example/floats.rs"]
+pub mod floats;
+#[path = "generated/example/packed.rs"]
+pub mod packed;
+
+pub mod reference;
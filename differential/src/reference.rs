@@ -0,0 +1,309 @@
+//! The prost types for the fixtures, as `prost-build` generates them from
+//! the same `.proto` files with `btree_map(["."])`, so map entries are
+//! written in key order as the generated code writes them. They are
+//! written out here, rather than by a build script, so the tests build
+//! without protoc.
+//!
+//! Each message also converts from its generated counterpart. A
+//! sub-message is always `Some`, since the generated encoder writes it
+//! even at its default, and an unset oneof becomes `None`.
+
+use std::collections::BTreeMap;
+
+use crate::{example, floats, packed};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Role {
+    Unspecified = 0,
+    User = 1,
+    Admin = 2,
+    Operator = 3,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserProfile {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(string, tag = "2")]
+    pub name: String,
+    #[prost(string, tag = "3")]
+    pub email: String,
+    #[prost(bool, tag = "4")]
+    pub active: bool,
+    #[prost(enumeration = "Role", tag = "5")]
+    pub role: i32,
+    #[prost(message, optional, tag = "6")]
+    pub address: Option<Address>,
+    #[prost(string, repeated, tag = "7")]
+    pub tags: Vec<String>,
+    #[prost(btree_map = "string, string", tag = "8")]
+    pub metadata: BTreeMap<String, String>,
+    #[prost(bytes = "vec", tag = "9")]
+    pub avatar_hash: Vec<u8>,
+    #[prost(int64, tag = "10")]
+    pub created_at: i64,
+    #[prost(fixed64, tag = "11")]
+    pub nonce: u64,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Address {
+    #[prost(string, tag = "1")]
+    pub street: String,
+    #[prost(string, tag = "2")]
+    pub city: String,
+    #[prost(string, tag = "3")]
+    pub state: String,
+    #[prost(string, tag = "4")]
+    pub zip: String,
+    #[prost(sint32, tag = "5")]
+    pub floor: i32,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolanaAccount {
+    #[prost(bytes = "vec", tag = "1")]
+    pub pubkey: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub lamports: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub owner: Vec<u8>,
+    #[prost(bool, tag = "4")]
+    pub executable: bool,
+    #[prost(uint64, tag = "5")]
+    pub rent_epoch: u64,
+    #[prost(bytes = "vec", tag = "6")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionBatch {
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub txns: Vec<Transaction>,
+    #[prost(fixed32, tag = "3")]
+    pub checksum: u32,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transaction {
+    #[prost(bytes = "vec", tag = "1")]
+    pub to: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub value: u64,
+    #[prost(uint64, tag = "4")]
+    pub gas_limit: u64,
+    #[prost(uint64, tag = "5")]
+    pub nonce: u64,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Instruction {
+    #[prost(uint64, tag = "1")]
+    pub nonce: u64,
+    #[prost(oneof = "instruction::Kind", tags = "2, 3, 4")]
+    pub kind: Option<instruction::Kind>,
+}
+
+pub mod instruction {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "2")]
+        Transfer(super::Transaction),
+        #[prost(uint64, tag = "3")]
+        BurnAmount(u64),
+        #[prost(string, tag = "4")]
+        Memo(String),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Level {
+    Low = 0,
+    High = 1,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Samples {
+    #[prost(double, repeated, tag = "1")]
+    pub doubles: Vec<f64>,
+    #[prost(float, repeated, tag = "2")]
+    pub floats: Vec<f32>,
+    #[prost(int64, repeated, tag = "3")]
+    pub int64s: Vec<i64>,
+    #[prost(uint64, repeated, tag = "4")]
+    pub uint64s: Vec<u64>,
+    #[prost(int32, repeated, tag = "5")]
+    pub int32s: Vec<i32>,
+    #[prost(fixed64, repeated, tag = "6")]
+    pub fixed64s: Vec<u64>,
+    #[prost(fixed32, repeated, tag = "7")]
+    pub fixed32s: Vec<u32>,
+    #[prost(bool, repeated, tag = "8")]
+    pub bools: Vec<bool>,
+    #[prost(uint32, repeated, tag = "9")]
+    pub uint32s: Vec<u32>,
+    #[prost(enumeration = "Level", repeated, tag = "10")]
+    pub levels: Vec<i32>,
+    #[prost(sfixed32, repeated, tag = "11")]
+    pub sfixed32s: Vec<i32>,
+    #[prost(sfixed64, repeated, tag = "12")]
+    pub sfixed64s: Vec<i64>,
+    #[prost(sint32, repeated, tag = "13")]
+    pub sint32s: Vec<i32>,
+    #[prost(sint64, repeated, tag = "14")]
+    pub sint64s: Vec<i64>,
+    #[prost(uint64, repeated, packed = "false", tag = "15")]
+    pub legacy: Vec<u64>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceFeed {
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    #[prost(float, tag = "2")]
+    pub confidence: f32,
+    #[prost(double, repeated, packed = "false", tag = "3")]
+    pub history: Vec<f64>,
+    #[prost(message, optional, tag = "6")]
+    pub twap: Option<f64>,
+    #[prost(btree_map = "string, float", tag = "7")]
+    pub weights: BTreeMap<String, f32>,
+    #[prost(oneof = "price_feed::Spread", tags = "4, 5")]
+    pub spread: Option<price_feed::Spread>,
+}
+
+pub mod price_feed {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Spread {
+        #[prost(float, tag = "4")]
+        Bps(f32),
+        #[prost(double, tag = "5")]
+        Absolute(f64),
+    }
+}
+
+impl From<&example::UserProfile> for UserProfile {
+    fn from(msg: &example::UserProfile) -> Self {
+        UserProfile {
+            id: msg.id,
+            name: msg.name.clone(),
+            email: msg.email.clone(),
+            active: msg.active,
+            role: msg.role,
+            address: Some((&msg.address).into()),
+            tags: msg.tags.clone(),
+            metadata: msg.metadata.clone(),
+            avatar_hash: msg.avatar_hash.clone(),
+            created_at: msg.created_at,
+            nonce: msg.nonce,
+        }
+    }
+}
+
+impl From<&example::Address> for Address {
+    fn from(msg: &example::Address) -> Self {
+        Address {
+            street: msg.street.clone(),
+            city: msg.city.clone(),
+            state: msg.state.clone(),
+            zip: msg.zip.clone(),
+            floor: msg.floor,
+        }
+    }
+}
+
+impl From<&example::SolanaAccount> for SolanaAccount {
+    fn from(msg: &example::SolanaAccount) -> Self {
+        SolanaAccount {
+            pubkey: msg.pubkey.clone(),
+            lamports: msg.lamports,
+            owner: msg.owner.clone(),
+            executable: msg.executable,
+            rent_epoch: msg.rent_epoch,
+            data: msg.data.clone(),
+        }
+    }
+}
+
+impl From<&example::TransactionBatch> for TransactionBatch {
+    fn from(msg: &example::TransactionBatch) -> Self {
+        TransactionBatch {
+            chain_id: msg.chain_id,
+            txns: msg.txns.iter().map(Transaction::from).collect(),
+            checksum: msg.checksum,
+        }
+    }
+}
+
+impl From<&example::Transaction> for Transaction {
+    fn from(msg: &example::Transaction) -> Self {
+        Transaction {
+            to: msg.to.clone(),
+            data: msg.data.clone(),
+            value: msg.value,
+            gas_limit: msg.gas_limit,
+            nonce: msg.nonce,
+        }
+    }
+}
+
+impl From<&example::Instruction> for Instruction {
+    fn from(msg: &example::Instruction) -> Self {
+        use example::InstructionKind;
+        Instruction {
+            nonce: msg.nonce,
+            kind: match &msg.kind {
+                InstructionKind::Unset => None,
+                InstructionKind::Transfer(txn) => Some(instruction::Kind::Transfer(txn.into())),
+                InstructionKind::BurnAmount(amount) => Some(instruction::Kind::BurnAmount(*amount)),
+                InstructionKind::Memo(memo) => Some(instruction::Kind::Memo(memo.clone())),
+            },
+        }
+    }
+}
+
+impl From<&packed::Samples> for Samples {
+    fn from(msg: &packed::Samples) -> Self {
+        Samples {
+            doubles: msg.doubles.clone(),
+            floats: msg.floats.clone(),
+            int64s: msg.int64s.clone(),
+            uint64s: msg.uint64s.clone(),
+            int32s: msg.int32s.clone(),
+            fixed64s: msg.fixed64s.clone(),
+            fixed32s: msg.fixed32s.clone(),
+            bools: msg.bools.clone(),
+            uint32s: msg.uint32s.clone(),
+            levels: msg.levels.clone(),
+            sfixed32s: msg.sfixed32s.clone(),
+            sfixed64s: msg.sfixed64s.clone(),
+            sint32s: msg.sint32s.clone(),
+            sint64s: msg.sint64s.clone(),
+            legacy: msg.legacy.clone(),
+        }
+    }
+}
+
+impl From<&floats::PriceFeed> for PriceFeed {
+    fn from(msg: &floats::PriceFeed) -> Self {
+        use floats::PriceFeedSpread;
+        PriceFeed {
+            price: msg.price,
+            confidence: msg.confidence,
+            history: msg.history.clone(),
+            twap: msg.twap,
+            weights: msg.weights.clone(),
+            spread: match msg.spread {
+                PriceFeedSpread::Unset => None,
+                PriceFeedSpread::Bps(bps) => Some(price_feed::Spread::Bps(bps)),
+                PriceFeedSpread::Absolute(absolute) => Some(price_feed::Spread::Absolute(absolute)),
+            },
+        }
+    }
+}
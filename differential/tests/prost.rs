@@ -0,0 +1,275 @@
+//! Differential tests against prost: any message built from arbitrary
+//! field values encodes to the same bytes as its prost counterpart, and
+//! each side decodes the other's encoding to the same value.
+//!
+//! ```bash
+//! cargo test --manifest-path differential/Cargo.toml
+//! ```
+//!
+//! The bytes are compared only where both encodings are canonical. prost
+//! leaves out a `-0.0` at implicit presence, since it compares with `!=
+//! 0.0`, while the generated code writes every value whose bits are not
+//! zero; and prost writes a map entry without its key or value when that
+//! is the default, while the generated code writes both. Both decode the
+//! same, which `known_differences_decode_alike` pins down.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use proptest::prelude::*;
+use protobuf_runtime_differential::example::{
+    Address, Instruction, InstructionKind, SolanaAccount, Transaction, TransactionBatch, UserProfile,
+};
+use protobuf_runtime_differential::floats::{PriceFeed, PriceFeedSpread};
+use protobuf_runtime_differential::packed::Samples;
+use protobuf_runtime_differential::protobuf_runtime::{encode_length_delimited, DecodeOptions, Message};
+use protobuf_runtime_differential::reference;
+
+/// Any float but NaN, which is not equal to itself.
+fn float64() -> impl Strategy<Value = f64> {
+    use prop::num::f64::*;
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE
+}
+
+fn float32() -> impl Strategy<Value = f32> {
+    use prop::num::f32::*;
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE
+}
+
+fn negative_zero(value: f64) -> bool {
+    value == 0.0 && value.is_sign_negative()
+}
+
+fn address() -> impl Strategy<Value = Address> {
+    (any::<String>(), any::<String>(), any::<String>(), any::<String>(), any::<i32>())
+        .prop_map(|(street, city, state, zip, floor)| Address { street, city, state, zip, floor })
+}
+
+fn user_profile() -> impl Strategy<Value = UserProfile> {
+    (
+        any::<u64>(),
+        any::<String>(),
+        any::<String>(),
+        any::<bool>(),
+        // Open enum: numbers outside `Role` must agree too
+        any::<i32>(),
+        prop_oneof![Just(Address::default()), address()],
+        prop::collection::vec(any::<String>(), 0..4),
+        prop::collection::btree_map(any::<String>(), any::<String>(), 0..4),
+        prop::collection::vec(any::<u8>(), 0..64),
+        any::<i64>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(id, name, email, active, role, address, tags, metadata, avatar_hash, created_at, nonce)| UserProfile {
+                id,
+                name,
+                email,
+                active,
+                role,
+                address,
+                tags,
+                metadata,
+                avatar_hash,
+                created_at,
+                nonce,
+            },
+        )
+}
+
+fn solana_account() -> impl Strategy<Value = SolanaAccount> {
+    (
+        prop::collection::vec(any::<u8>(), 0..33),
+        any::<u64>(),
+        prop::collection::vec(any::<u8>(), 0..33),
+        any::<bool>(),
+        any::<u64>(),
+        prop::collection::vec(any::<u8>(), 0..200),
+    )
+        .prop_map(|(pubkey, lamports, owner, executable, rent_epoch, data)| SolanaAccount {
+            pubkey,
+            lamports,
+            owner,
+            executable,
+            rent_epoch,
+            data,
+        })
+}
+
+fn transaction() -> impl Strategy<Value = Transaction> {
+    (
+        prop::collection::vec(any::<u8>(), 0..40),
+        prop::collection::vec(any::<u8>(), 0..200),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(|(to, data, value, gas_limit, nonce)| Transaction { to, data, value, gas_limit, nonce })
+}
+
+fn transaction_batch() -> impl Strategy<Value = TransactionBatch> {
+    (any::<u64>(), prop::collection::vec(transaction(), 0..8), any::<u32>())
+        .prop_map(|(chain_id, txns, checksum)| TransactionBatch { chain_id, txns, checksum })
+}
+
+fn instruction() -> impl Strategy<Value = Instruction> {
+    let kind = prop_oneof![
+        Just(InstructionKind::Unset),
+        // A oneof member at its default is still written
+        Just(InstructionKind::Transfer(Transaction::default())),
+        transaction().prop_map(InstructionKind::Transfer),
+        any::<u64>().prop_map(InstructionKind::BurnAmount),
+        any::<String>().prop_map(InstructionKind::Memo),
+    ];
+    (any::<u64>(), kind).prop_map(|(nonce, kind)| Instruction { nonce, kind })
+}
+
+fn samples() -> impl Strategy<Value = Samples> {
+    use prop::collection::vec;
+    (
+        (
+            vec(float64(), 0..8),
+            vec(float32(), 0..8),
+            vec(any::<i64>(), 0..8),
+            vec(any::<u64>(), 0..8),
+            vec(any::<i32>(), 0..8),
+            vec(any::<u64>(), 0..8),
+            vec(any::<u32>(), 0..8),
+            vec(any::<bool>(), 0..8),
+        ),
+        (
+            vec(any::<u32>(), 0..8),
+            vec(any::<i32>(), 0..8),
+            vec(any::<i32>(), 0..8),
+            vec(any::<i64>(), 0..8),
+            vec(any::<i32>(), 0..8),
+            vec(any::<i64>(), 0..8),
+            vec(any::<u64>(), 0..8),
+        ),
+    )
+        .prop_map(
+            |(
+                (doubles, floats, int64s, uint64s, int32s, fixed64s, fixed32s, bools),
+                (uint32s, levels, sfixed32s, sfixed64s, sint32s, sint64s, legacy),
+            )| Samples {
+                doubles,
+                floats,
+                int64s,
+                uint64s,
+                int32s,
+                fixed64s,
+                fixed32s,
+                bools,
+                uint32s,
+                levels,
+                sfixed32s,
+                sfixed64s,
+                sint32s,
+                sint64s,
+                legacy,
+            },
+        )
+}
+
+fn price_feed() -> impl Strategy<Value = PriceFeed> {
+    let spread = prop_oneof![
+        Just(PriceFeedSpread::Unset),
+        float32().prop_map(PriceFeedSpread::Bps),
+        float64().prop_map(PriceFeedSpread::Absolute),
+    ];
+    (
+        float64(),
+        float32(),
+        prop::collection::vec(float64(), 0..8),
+        spread,
+        prop::option::of(float64()),
+        prop::collection::btree_map(any::<String>(), float32(), 0..4),
+    )
+        .prop_map(|(price, confidence, history, spread, twap, weights)| PriceFeed {
+            price,
+            confidence,
+            history,
+            spread,
+            twap,
+            weights,
+        })
+}
+
+/// `msg` and its prost counterpart `P` agree: each decodes the other's
+/// encoding to the same value, and with `same_bytes` the two encodings,
+/// plain and length-delimited, are identical.
+fn assert_agree<M, P>(msg: &M, same_bytes: bool) -> Result<(), TestCaseError>
+where
+    M: Message + PartialEq + Debug,
+    P: prost::Message + Default + PartialEq + for<'a> From<&'a M>,
+{
+    let theirs = P::from(msg);
+    let ours = msg.encode();
+    let prost_bytes = theirs.encode_to_vec();
+    if same_bytes {
+        prop_assert_eq!(&ours, &prost_bytes);
+        let mut delimited = Vec::new();
+        encode_length_delimited(&mut delimited, msg);
+        prop_assert_eq!(delimited, theirs.encode_length_delimited_to_vec());
+    }
+    let decoded = P::decode(ours.as_slice()).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(&decoded, &theirs);
+    // Generated values can be larger than a transaction, so past `decode`'s cap
+    let decoded =
+        M::decode_with(&prost_bytes, &DecodeOptions::ACCOUNT).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(&decoded, msg);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn user_profile_agrees(msg in user_profile()) {
+        let same_bytes = msg.metadata.iter().all(|(key, value)| !key.is_empty() && !value.is_empty());
+        assert_agree::<_, reference::UserProfile>(&msg, same_bytes)?;
+    }
+
+    #[test]
+    fn solana_account_agrees(msg in solana_account()) {
+        assert_agree::<_, reference::SolanaAccount>(&msg, true)?;
+    }
+
+    #[test]
+    fn transaction_batch_agrees(msg in transaction_batch()) {
+        assert_agree::<_, reference::TransactionBatch>(&msg, true)?;
+    }
+
+    #[test]
+    fn instruction_agrees(msg in instruction()) {
+        assert_agree::<_, reference::Instruction>(&msg, true)?;
+    }
+
+    #[test]
+    fn samples_agree(msg in samples()) {
+        assert_agree::<_, reference::Samples>(&msg, true)?;
+    }
+
+    #[test]
+    fn price_feed_agrees(msg in price_feed()) {
+        let same_bytes = !negative_zero(msg.price)
+            && !negative_zero(msg.confidence.into())
+            && !msg.twap.is_some_and(negative_zero)
+            && msg.weights.iter().all(|(key, value)| !key.is_empty() && *value != 0.0);
+        assert_agree::<_, reference::PriceFeed>(&msg, same_bytes)?;
+    }
+}
+
+#[test]
+fn known_differences_decode_alike() {
+    let msg = PriceFeed {
+        price: -0.0,
+        twap: Some(-0.0),
+        weights: BTreeMap::from([(String::new(), 0.5), ("sol".to_string(), 0.0)]),
+        ..PriceFeed::default()
+    };
+    let theirs = reference::PriceFeed::from(&msg);
+    let ours = msg.encode();
+    let prost_bytes = prost::Message::encode_to_vec(&theirs);
+    assert_ne!(ours, prost_bytes);
+    assert_eq!(<reference::PriceFeed as prost::Message>::decode(ours.as_slice()).unwrap(), theirs);
+    assert_eq!(PriceFeed::decode(&prost_bytes).unwrap(), msg);
+}
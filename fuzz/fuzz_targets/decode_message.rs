@@ -0,0 +1,46 @@
+//! Arbitrary bytes decoded as the bench fixtures' generated messages.
+//! Decoding must never panic, and whatever decodes must survive a round
+//! trip: `decode(encode(m)) == m`, with the encoding canonical and as long
+//! as `encoded_len` says.
+
+#![no_main]
+
+use std::fmt::Debug;
+
+use libfuzzer_sys::fuzz_target;
+use protobuf_runtime_bench::example::{Instruction, SolanaAccount, TransactionBatch, UserProfile};
+use protobuf_runtime_bench::protobuf_runtime::*;
+
+/// Tight limits, so the fuzzer also reaches the limit checks
+const TIGHT: DecodeOptions = DecodeOptions {
+    max_alloc: 16,
+    max_repeated_len: 4,
+    max_depth: 1,
+    ..DecodeOptions::STRICT
+};
+
+fuzz_target!(|data: &[u8]| {
+    check::<UserProfile>(data);
+    check::<TransactionBatch>(data);
+    check::<SolanaAccount>(data);
+    check::<Instruction>(data);
+});
+
+fn check<M: Message + PartialEq + Debug>(data: &[u8]) {
+    let _ = M::decode_with(data, &DecodeOptions::STRICT);
+    let _ = M::decode_with(data, &TIGHT);
+
+    let Ok(msg) = M::decode(data) else { return };
+    let mut merged = M::default();
+    merged.merge(data).expect("merge failed where decode succeeded");
+    assert_eq!(merged, msg);
+
+    let encoded = msg.encode();
+    assert_eq!(encoded.len(), msg.encoded_len());
+    assert_eq!(M::decode(&encoded).expect("re-encoded message failed to decode"), msg);
+    assert!(verify_canonical::<M>(&encoded));
+
+    let mut buf = vec![0u8; encoded.len()];
+    assert_eq!(msg.encode_to_slice(&mut buf).ok(), Some(encoded.len()));
+    assert_eq!(buf, encoded);
+}
@@ -0,0 +1,33 @@
+//! Arbitrary bytes through the varint decoders. Every call either fails
+//! or returns a position that moved forward, stays inside the input and
+//! consumed at most ten bytes.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use protobuf_runtime_bench::protobuf_runtime::*;
+
+fuzz_target!(|data: &[u8]| {
+    let mut pos = 0;
+    while pos < data.len() {
+        match decode_varint(data, pos) {
+            Ok((value, new_pos)) => {
+                assert!(new_pos > pos && new_pos <= data.len());
+                assert!(new_pos - pos <= 10);
+                // The shortest form is never longer than what was read
+                assert!(varint_len(value) <= new_pos - pos);
+                pos = new_pos;
+            }
+            Err(_) => pos += 1,
+        }
+    }
+
+    // The typed wrappers share decode_varint's bounds checks
+    for pos in 0..data.len().min(16) {
+        let _ = decode_int32(data, pos);
+        let _ = decode_int64(data, pos);
+        let _ = decode_zigzag32(data, pos);
+        let _ = decode_zigzag64(data, pos);
+        let _ = decode_bool(data, pos);
+    }
+});
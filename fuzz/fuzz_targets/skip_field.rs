@@ -0,0 +1,27 @@
+//! Arbitrary bytes walked field by field, first with `decode_key` and
+//! `skip_field`, then with `Decoder`. Neither may panic or step outside
+//! the input, and both must stop at the same place.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use protobuf_runtime_bench::protobuf_runtime::*;
+
+fuzz_target!(|data: &[u8]| {
+    let mut pos = 0;
+    while pos < data.len() {
+        let Ok((tag, after_key)) = decode_key(data, pos) else { break };
+        let Ok(new_pos) = skip_field(data, after_key, tag) else { break };
+        assert!(new_pos >= after_key && new_pos <= data.len());
+        pos = new_pos;
+    }
+
+    let mut decoder = Decoder::new(data);
+    let mut end = 0;
+    while let Ok(Some(field)) = decoder.next_field() {
+        assert!(decoder.position() <= data.len());
+        assert_eq!(field.raw, &data[end..decoder.position()]);
+        end = decoder.position();
+    }
+    assert_eq!(end, pos);
+});
@@ -0,0 +1,140 @@
+//! Property tests over the bench fixtures: any message built from
+//! arbitrary field values survives `decode(encode(m)) == m`, and the wire
+//! primitives round-trip every value of their type.
+//!
+//! ```bash
+//! cargo test --manifest-path fuzz/Cargo.toml
+//! ```
+
+use proptest::prelude::*;
+use protobuf_runtime_bench::example::{Address, Instruction, InstructionKind, Transaction, TransactionBatch, UserProfile};
+use protobuf_runtime_bench::protobuf_runtime::*;
+
+fn address() -> impl Strategy<Value = Address> {
+    (any::<String>(), any::<String>(), any::<String>(), any::<String>(), any::<i32>())
+        .prop_map(|(street, city, state, zip, floor)| Address { street, city, state, zip, floor })
+}
+
+fn user_profile() -> impl Strategy<Value = UserProfile> {
+    (
+        any::<u64>(),
+        any::<String>(),
+        any::<String>(),
+        any::<bool>(),
+        // Open enum: numbers outside `Role` must survive too
+        any::<i32>(),
+        address(),
+        prop::collection::vec(any::<String>(), 0..4),
+        prop::collection::btree_map(any::<String>(), any::<String>(), 0..4),
+        prop::collection::vec(any::<u8>(), 0..64),
+        any::<i64>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(id, name, email, active, role, address, tags, metadata, avatar_hash, created_at, nonce)| UserProfile {
+                id,
+                name,
+                email,
+                active,
+                role,
+                address,
+                tags,
+                metadata,
+                avatar_hash,
+                created_at,
+                nonce,
+            },
+        )
+}
+
+fn transaction() -> impl Strategy<Value = Transaction> {
+    (
+        prop::collection::vec(any::<u8>(), 0..40),
+        prop::collection::vec(any::<u8>(), 0..200),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(|(to, data, value, gas_limit, nonce)| Transaction { to, data, value, gas_limit, nonce })
+}
+
+fn transaction_batch() -> impl Strategy<Value = TransactionBatch> {
+    (any::<u64>(), prop::collection::vec(transaction(), 0..8), any::<u32>())
+        .prop_map(|(chain_id, txns, checksum)| TransactionBatch { chain_id, txns, checksum })
+}
+
+fn instruction() -> impl Strategy<Value = Instruction> {
+    let kind = prop_oneof![
+        Just(InstructionKind::Unset),
+        transaction().prop_map(InstructionKind::Transfer),
+        any::<u64>().prop_map(InstructionKind::BurnAmount),
+        any::<String>().prop_map(InstructionKind::Memo),
+    ];
+    (any::<u64>(), kind).prop_map(|(nonce, kind)| Instruction { nonce, kind })
+}
+
+/// `msg` encodes to `encoded_len` canonical bytes that decode back to it.
+fn assert_roundtrip<M: Message + PartialEq + std::fmt::Debug>(msg: &M) -> Result<(), TestCaseError> {
+    let encoded = msg.encode();
+    prop_assert_eq!(encoded.len(), msg.encoded_len());
+    let decoded = M::decode(&encoded).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(&decoded, msg);
+    prop_assert!(verify_canonical::<M>(&encoded));
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn user_profile_roundtrips(msg in user_profile()) {
+        assert_roundtrip(&msg)?;
+    }
+
+    #[test]
+    fn transaction_batch_roundtrips(msg in transaction_batch()) {
+        assert_roundtrip(&msg)?;
+    }
+
+    #[test]
+    fn instruction_roundtrips(msg in instruction()) {
+        assert_roundtrip(&msg)?;
+    }
+
+    #[test]
+    fn concatenation_merges(first in transaction_batch(), second in transaction_batch()) {
+        let mut merged = first.clone();
+        merged.merge(&second.encode()).unwrap();
+        let concatenated = [first.encode(), second.encode()].concat();
+        prop_assert_eq!(TransactionBatch::decode(&concatenated).unwrap(), merged);
+    }
+
+    #[test]
+    fn varint_roundtrips(value in any::<u64>()) {
+        let mut buf = Vec::new();
+        encode_varint(&mut buf, value);
+        prop_assert_eq!(buf.len(), varint_len(value));
+        prop_assert_eq!(decode_varint(&buf, 0).unwrap(), (value, buf.len()));
+    }
+
+    #[test]
+    fn signed_varints_roundtrip(a in any::<i32>(), b in any::<i64>()) {
+        let mut buf = Vec::new();
+        encode_int32(&mut buf, a);
+        prop_assert_eq!(decode_int32(&buf, 0).unwrap().0, a);
+        buf.clear();
+        encode_int64(&mut buf, b);
+        prop_assert_eq!(decode_int64(&buf, 0).unwrap().0, b);
+        buf.clear();
+        encode_zigzag32(&mut buf, a);
+        prop_assert_eq!(decode_zigzag32(&buf, 0).unwrap().0, a);
+        buf.clear();
+        encode_zigzag64(&mut buf, b);
+        prop_assert_eq!(decode_zigzag64(&buf, 0).unwrap().0, b);
+    }
+
+    #[test]
+    fn arbitrary_bytes_never_panic(data in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = UserProfile::decode(&data);
+        let _ = TransactionBatch::decode(&data);
+        let _ = Instruction::decode(&data);
+    }
+}
@@ -0,0 +1,3567 @@
+// Auto-generated by protoc-gen-solana from example.proto
+// DO NOT EDIT
+
+#![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+use crate::protobuf_runtime::*;
+use std::collections::BTreeMap;
+
+/// `example.Role`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(i32)]
+pub enum Role {
+    Unspecified = 0,
+    User = 1,
+    Admin = 2,
+    Operator = 3,
+}
+
+impl Role {
+    /// The proto3 default, numbered 0, for `const` and `static` items.
+    pub const DEFAULT: Role = Role::Unspecified;
+
+    /// The value's name as declared in the .proto file.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Role::Unspecified => "ROLE_UNSPECIFIED",
+            Role::User => "ROLE_USER",
+            Role::Admin => "ROLE_ADMIN",
+            Role::Operator => "ROLE_OPERATOR",
+        }
+    }
+
+    pub fn from_str_name(name: &str) -> Option<Self> {
+        match name {
+            "ROLE_UNSPECIFIED" => Some(Role::Unspecified),
+            "ROLE_USER" => Some(Role::User),
+            "ROLE_ADMIN" => Some(Role::Admin),
+            "ROLE_OPERATOR" => Some(Role::Operator),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Role {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<Role> for i32 {
+    fn from(value: Role) -> i32 {
+        value as i32
+    }
+}
+
+/// A number this build does not know is handed back as the error.
+impl TryFrom<i32> for Role {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Role::Unspecified),
+            1 => Ok(Role::User),
+            2 => Ok(Role::Admin),
+            3 => Ok(Role::Operator),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Exercises: scalars, nested messages, repeated, enums, map, oneof
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct UserProfile {
+    pub id: u64,
+    pub name: String,
+    pub email: String,
+    pub active: bool,
+    pub role: i32,
+    pub address: Address,
+    pub tags: Vec<String>,
+    pub metadata: BTreeMap<String, String>,
+    pub avatar_hash: Vec<u8>,
+    pub created_at: i64,
+    pub nonce: u64,
+}
+
+impl UserProfile {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = UserProfile {
+        id: 0,
+        name: String::new(),
+        email: String::new(),
+        active: false,
+        role: 0,
+        address: Address::DEFAULT,
+        tags: Vec::new(),
+        metadata: BTreeMap::new(),
+        avatar_hash: Vec::new(),
+        created_at: 0,
+        nonce: 0,
+    };
+}
+
+impl Default for UserProfile {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl UserProfile {
+    pub const ID_FIELD_NUMBER: u32 = 1;
+    pub const NAME_FIELD_NUMBER: u32 = 2;
+    pub const EMAIL_FIELD_NUMBER: u32 = 3;
+    pub const ACTIVE_FIELD_NUMBER: u32 = 4;
+    pub const ROLE_FIELD_NUMBER: u32 = 5;
+    pub const ADDRESS_FIELD_NUMBER: u32 = 6;
+    pub const TAGS_FIELD_NUMBER: u32 = 7;
+    pub const METADATA_FIELD_NUMBER: u32 = 8;
+    pub const AVATAR_HASH_FIELD_NUMBER: u32 = 9;
+    pub const CREATED_AT_FIELD_NUMBER: u32 = 10;
+    pub const NONCE_FIELD_NUMBER: u32 = 11;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 0, packed: false, name: "UserProfile.id" },
+        FieldWireType { number: 2, wire_type: 2, packed: false, name: "UserProfile.name" },
+        FieldWireType { number: 3, wire_type: 2, packed: false, name: "UserProfile.email" },
+        FieldWireType { number: 4, wire_type: 0, packed: false, name: "UserProfile.active" },
+        FieldWireType { number: 5, wire_type: 0, packed: false, name: "UserProfile.role" },
+        FieldWireType { number: 6, wire_type: 2, packed: false, name: "UserProfile.address" },
+        FieldWireType { number: 7, wire_type: 2, packed: false, name: "UserProfile.tags" },
+        FieldWireType { number: 8, wire_type: 2, packed: false, name: "UserProfile.metadata" },
+        FieldWireType { number: 9, wire_type: 2, packed: false, name: "UserProfile.avatar_hash" },
+        FieldWireType { number: 10, wire_type: 0, packed: false, name: "UserProfile.created_at" },
+        FieldWireType { number: 11, wire_type: 1, packed: false, name: "UserProfile.nonce" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        // field 11: nonce
+        if self.nonce != 0 {
+            w.write_fixed64(self.nonce);
+            w.write_key(0x59);
+        }
+
+        // field 10: created_at
+        if self.created_at != 0 {
+            w.write_int64(self.created_at);
+            w.write_key(0x50);
+        }
+
+        // field 9: avatar_hash
+        if !self.avatar_hash.is_empty() {
+            w.write_bytes(&self.avatar_hash);
+            w.write_key(0x4a);
+        }
+
+        // field 8: metadata
+        for (key, value) in self.metadata.iter().rev() {
+            w.write_nested(8, |w| {
+                w.write_string(value);
+                w.write_key(0x12);
+                w.write_string(key);
+                w.write_key(0x0a);
+            });
+        }
+
+        // field 7: tags
+        for elem in self.tags.iter().rev() {
+            w.write_string(elem);
+            w.write_key(0x3a);
+        }
+
+        // field 6: address
+        w.write_message(6, &self.address);
+
+        // field 5: role
+        if self.role != 0 {
+            w.write_int32(self.role);
+            w.write_key(0x28);
+        }
+
+        // field 4: active
+        if self.active {
+            w.write_bool(self.active);
+            w.write_key(0x20);
+        }
+
+        // field 3: email
+        if !self.email.is_empty() {
+            w.write_string(&self.email);
+            w.write_key(0x1a);
+        }
+
+        // field 2: name
+        if !self.name.is_empty() {
+            w.write_string(&self.name);
+            w.write_key(0x12);
+        }
+
+        // field 1: id
+        if self.id != 0 {
+            w.write_varint(self.id);
+            w.write_key(0x08);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if self.id != 0 {
+            len += key_len(1) + varint_len(self.id);
+        }
+        if !self.name.is_empty() {
+            len += key_len(2) + bytes_len(self.name.len());
+        }
+        if !self.email.is_empty() {
+            len += key_len(3) + bytes_len(self.email.len());
+        }
+        if self.active {
+            len += key_len(4) + 1;
+        }
+        if self.role != 0 {
+            len += key_len(5) + int32_len(self.role);
+        }
+        len += key_len(6) + bytes_len(self.address.encoded_len());
+        for elem in &self.tags {
+            len += key_len(7) + bytes_len(elem.len());
+        }
+        for (key, value) in &self.metadata {
+            len += key_len(8) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+        }
+        if !self.avatar_hash.is_empty() {
+            len += key_len(9) + bytes_len(self.avatar_hash.len());
+        }
+        if self.created_at != 0 {
+            len += key_len(10) + int64_len(self.created_at);
+        }
+        if self.nonce != 0 {
+            len += key_len(11) + 8;
+        }
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if self.id != 0 {
+            w.write_key(0x08)?;
+            w.write_varint(self.id)?;
+        }
+        if !self.name.is_empty() {
+            w.write_key(0x12)?;
+            w.write_string(&self.name)?;
+        }
+        if !self.email.is_empty() {
+            w.write_key(0x1a)?;
+            w.write_string(&self.email)?;
+        }
+        if self.active {
+            w.write_key(0x20)?;
+            w.write_bool(self.active)?;
+        }
+        if self.role != 0 {
+            w.write_key(0x28)?;
+            w.write_int32(self.role)?;
+        }
+        w.write_message(6, &self.address)?;
+        for elem in &self.tags {
+            w.write_key(0x3a)?;
+            w.write_string(elem)?;
+        }
+        for (key, value) in &self.metadata {
+            w.write_key(0x42)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x12)?;
+            w.write_string(value)?;
+        }
+        if !self.avatar_hash.is_empty() {
+            w.write_key(0x4a)?;
+            w.write_bytes(&self.avatar_hash)?;
+        }
+        if self.created_at != 0 {
+            w.write_key(0x50)?;
+            w.write_int64(self.created_at)?;
+        }
+        if self.nonce != 0 {
+            w.write_key(0x59)?;
+            w.write_fixed64(self.nonce)?;
+        }
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        let mut len = 0;
+        if self.id != 0 {
+            len += key_len(1) + varint_len(self.id);
+        }
+        if !self.name.is_empty() {
+            len += key_len(2) + bytes_len(self.name.len());
+        }
+        if !self.email.is_empty() {
+            len += key_len(3) + bytes_len(self.email.len());
+        }
+        if self.active {
+            len += key_len(4) + 1;
+        }
+        if self.role != 0 {
+            len += key_len(5) + int32_len(self.role);
+        }
+        len += key_len(6) + bytes_len(sizes.record(&self.address));
+        for elem in &self.tags {
+            len += key_len(7) + bytes_len(elem.len());
+        }
+        for (key, value) in &self.metadata {
+            len += key_len(8) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+        }
+        if !self.avatar_hash.is_empty() {
+            len += key_len(9) + bytes_len(self.avatar_hash.len());
+        }
+        if self.created_at != 0 {
+            len += key_len(10) + int64_len(self.created_at);
+        }
+        if self.nonce != 0 {
+            len += key_len(11) + 8;
+        }
+        len
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        if self.id != 0 {
+            w.write_key(0x08)?;
+            w.write_varint(self.id)?;
+        }
+        if !self.name.is_empty() {
+            w.write_key(0x12)?;
+            w.write_string(&self.name)?;
+        }
+        if !self.email.is_empty() {
+            w.write_key(0x1a)?;
+            w.write_string(&self.email)?;
+        }
+        if self.active {
+            w.write_key(0x20)?;
+            w.write_bool(self.active)?;
+        }
+        if self.role != 0 {
+            w.write_key(0x28)?;
+            w.write_int32(self.role)?;
+        }
+        w.write_message_sized(6, &self.address, sizes)?;
+        for elem in &self.tags {
+            w.write_key(0x3a)?;
+            w.write_string(elem)?;
+        }
+        for (key, value) in &self.metadata {
+            w.write_key(0x42)?;
+            w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+            w.write_key(0x0a)?;
+            w.write_string(key)?;
+            w.write_key(0x12)?;
+            w.write_string(value)?;
+        }
+        if !self.avatar_hash.is_empty() {
+            w.write_key(0x4a)?;
+            w.write_bytes(&self.avatar_hash)?;
+        }
+        if self.created_at != 0 {
+            w.write_key(0x50)?;
+            w.write_int64(self.created_at)?;
+        }
+        if self.nonce != 0 {
+            w.write_key(0x59)?;
+            w.write_fixed64(self.nonce)?;
+        }
+        Ok(())
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                8 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.id = v;
+                    pos = new_pos;
+                }
+                18 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.name = v;
+                    pos = new_pos;
+                }
+                26 => {
+                    mark_singular(&mut seen, 2, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.email = v;
+                    pos = new_pos;
+                }
+                32 => {
+                    mark_singular(&mut seen, 3, opts)?;
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    self.active = v;
+                    pos = new_pos;
+                }
+                40 => {
+                    mark_singular(&mut seen, 4, opts)?;
+                    let (v, new_pos) = decode_int32(data, pos)?;
+                    self.role = v;
+                    pos = new_pos;
+                }
+                50 => {
+                    mark_singular(&mut seen, 5, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    self.address.merge_with(sub, &opts.nested()?)?;
+                    pos = new_pos;
+                }
+                58 => {
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    if self.tags.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.tags.push(v);
+                    pos = new_pos;
+                }
+                66 => {
+                    let (entry, new_pos) = decode_message_slice(data, pos)?;
+                    let mut key = String::new();
+                    let mut value = String::new();
+                    let mut entry_pos = 0usize;
+                    while entry_pos < entry.len() {
+                        let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                        entry_pos = next;
+                        match entry_tag {
+                            10 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                key = v;
+                                entry_pos = next;
+                            }
+                            18 => {
+                                let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                value = v;
+                                entry_pos = next;
+                            }
+                            _ => {
+                                entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                            }
+                        }
+                    }
+                    if self.metadata.len() >= opts.max_repeated_len && !self.metadata.contains_key(&key) {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.metadata.insert(key, value);
+                    pos = new_pos;
+                }
+                74 => {
+                    mark_singular(&mut seen, 6, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.avatar_hash = v;
+                    pos = new_pos;
+                }
+                80 => {
+                    mark_singular(&mut seen, 7, opts)?;
+                    let (v, new_pos) = decode_int64(data, pos)?;
+                    self.created_at = v;
+                    pos = new_pos;
+                }
+                89 => {
+                    mark_singular(&mut seen, 8, opts)?;
+                    let (v, new_pos) = decode_fixed64(data, pos)?;
+                    self.nonce = v;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The element of repeated field `number` being decoded, for `field_error`.
+    fn next_index(&self, number: u64) -> Option<usize> {
+        match number {
+            7 => Some(self.tags.len()),
+            _ => None,
+        }
+    }
+}
+
+impl Message for UserProfile {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        UserProfile::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        UserProfile::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        UserProfile::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        UserProfile::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        UserProfile::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        UserProfile::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        UserProfile::merge_with(self, data, opts)
+    }
+}
+
+impl TryFrom<&[u8]> for UserProfile {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        UserProfile::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<UserProfile> for Vec<u8> {
+    fn from(msg: UserProfile) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for UserProfile {
+    const FULL_NAME: &'static str = "example.UserProfile";
+}
+
+impl UserProfile {
+    pub fn role(&self) -> Result<Role, i32> {
+        Role::try_from(self.role)
+    }
+
+    pub fn set_role(&mut self, value: Role) {
+        self.role = value.into();
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserProfileBuilder {
+    inner: UserProfile,
+}
+
+impl UserProfile {
+    pub fn builder() -> UserProfileBuilder {
+        UserProfileBuilder::default()
+    }
+}
+
+impl UserProfileBuilder {
+    pub fn with_id(mut self, value: u64) -> Self {
+        self.inner.id = value;
+        self
+    }
+
+    pub fn with_name(mut self, value: String) -> Self {
+        self.inner.name = value;
+        self
+    }
+
+    pub fn with_email(mut self, value: String) -> Self {
+        self.inner.email = value;
+        self
+    }
+
+    pub fn with_active(mut self, value: bool) -> Self {
+        self.inner.active = value;
+        self
+    }
+
+    pub fn with_role(mut self, value: i32) -> Self {
+        self.inner.role = value;
+        self
+    }
+
+    pub fn with_address(mut self, value: Address) -> Self {
+        self.inner.address = value;
+        self
+    }
+
+    pub fn with_tags(mut self, value: Vec<String>) -> Self {
+        self.inner.tags = value;
+        self
+    }
+
+    pub fn add_tags(mut self, value: String) -> Self {
+        self.inner.tags.push(value);
+        self
+    }
+
+    pub fn with_metadata(mut self, value: BTreeMap<String, String>) -> Self {
+        self.inner.metadata = value;
+        self
+    }
+
+    pub fn insert_metadata(mut self, key: String, value: String) -> Self {
+        self.inner.metadata.insert(key, value);
+        self
+    }
+
+    pub fn with_avatar_hash(mut self, value: Vec<u8>) -> Self {
+        self.inner.avatar_hash = value;
+        self
+    }
+
+    pub fn with_created_at(mut self, value: i64) -> Self {
+        self.inner.created_at = value;
+        self
+    }
+
+    pub fn with_nonce(mut self, value: u64) -> Self {
+        self.inner.nonce = value;
+        self
+    }
+
+    pub fn build(self) -> UserProfile {
+        self.inner
+    }
+}
+
+impl UserProfile {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if self.id != 0 {
+            out.write_scalar("id", self.id);
+        }
+        if !self.name.is_empty() {
+            out.write_str("name", &self.name);
+        }
+        if !self.email.is_empty() {
+            out.write_str("email", &self.email);
+        }
+        if self.active {
+            out.write_scalar("active", self.active);
+        }
+        if self.role != 0 {
+            out.write_enum("role", self.role, &[("ROLE_UNSPECIFIED", 0), ("ROLE_USER", 1), ("ROLE_ADMIN", 2), ("ROLE_OPERATOR", 3)]);
+        }
+        if self.address != Address::default() {
+            out.begin_message("address");
+            self.address.write_text(out);
+            out.end_message();
+        }
+        for elem in &self.tags {
+            out.write_str("tags", elem);
+        }
+        for (key, value) in &self.metadata {
+            out.begin_message("metadata");
+            out.write_str("key", key);
+            out.write_str("value", value);
+            out.end_message();
+        }
+        if !self.avatar_hash.is_empty() {
+            out.write_bytes("avatar_hash", &self.avatar_hash);
+        }
+        if self.created_at != 0 {
+            out.write_scalar("created_at", self.created_at);
+        }
+        if self.nonce != 0 {
+            out.write_scalar("nonce", self.nonce);
+        }
+    }
+}
+
+impl core::fmt::Display for UserProfile {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl UserProfile {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if self.id != 0 {
+            out.key("id");
+            out.write_u64(self.id);
+        }
+        if !self.name.is_empty() {
+            out.key("name");
+            out.write_str(&self.name);
+        }
+        if !self.email.is_empty() {
+            out.key("email");
+            out.write_str(&self.email);
+        }
+        if self.active {
+            out.key("active");
+            out.write_bool(self.active);
+        }
+        if self.role != 0 {
+            out.key("role");
+            out.write_enum(self.role, &[("ROLE_UNSPECIFIED", 0), ("ROLE_USER", 1), ("ROLE_ADMIN", 2), ("ROLE_OPERATOR", 3)]);
+        }
+        if self.address != Address::default() {
+            out.key("address");
+            self.address.write_json(out);
+        }
+        if !self.tags.is_empty() {
+            out.key("tags");
+            out.begin_array();
+            for elem in &self.tags {
+                out.write_str(elem);
+            }
+            out.end_array();
+        }
+        if !self.metadata.is_empty() {
+            out.key("metadata");
+            out.begin_object();
+            for (key, value) in &self.metadata {
+                out.key(key);
+                out.write_str(value);
+            }
+            out.end_object();
+        }
+        if !self.avatar_hash.is_empty() {
+            out.key("avatarHash");
+            out.write_bytes(&self.avatar_hash);
+        }
+        if self.created_at != 0 {
+            out.key("createdAt");
+            out.write_i64(self.created_at);
+        }
+        if self.nonce != 0 {
+            out.key("nonce");
+            out.write_u64(self.nonce);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "id" => msg.id = json_to_u64(value)?,
+                "name" => msg.name = json_to_string(value)?,
+                "email" => msg.email = json_to_string(value)?,
+                "active" => msg.active = json_to_bool(value)?,
+                "role" => msg.role = json_to_enum(value, &[("ROLE_UNSPECIFIED", 0), ("ROLE_USER", 1), ("ROLE_ADMIN", 2), ("ROLE_OPERATOR", 3)])?,
+                "address" => msg.address = Address::from_json_value(value)?,
+                "tags" => {
+                    for elem in json_array(value)? {
+                        msg.tags.push(json_to_string(elem)?);
+                    }
+                }
+                "metadata" => {
+                    for (key, value) in json_object(value)? {
+                        msg.metadata.insert(json_map_key(key)?, json_to_string(value)?);
+                    }
+                }
+                "avatarHash" | "avatar_hash" => msg.avatar_hash = json_to_bytes(value)?,
+                "createdAt" | "created_at" => msg.created_at = json_to_i64(value)?,
+                "nonce" => msg.nonce = json_to_u64(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct UserProfileRef<'a> {
+    raw: &'a [u8],
+    pub id: u64,
+    pub name: &'a str,
+    pub email: &'a str,
+    pub active: bool,
+    pub role: i32,
+    pub address: Option<AddressRef<'a>>,
+    pub tags: RepeatedRef<'a, &'a str>,
+    pub avatar_hash: &'a [u8],
+    pub created_at: i64,
+    pub nonce: u64,
+}
+
+impl<'a> UserProfileRef<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut view = UserProfileRef {
+            raw: data,
+            id: 0,
+            name: "",
+            email: "",
+            active: false,
+            role: 0,
+            address: None,
+            tags: RepeatedRef::new(data, 58, decode_str_ref),
+            avatar_hash: &[],
+            created_at: 0,
+            nonce: 0,
+        };
+
+        let mut pos = 0usize;
+        while pos < data.len() {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+
+            match tag {
+            8 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.id = v;
+                pos = new_pos;
+            }
+            18 => {
+                let (v, new_pos) = decode_str_ref(data, pos)?;
+                view.name = v;
+                pos = new_pos;
+            }
+            26 => {
+                let (v, new_pos) = decode_str_ref(data, pos)?;
+                view.email = v;
+                pos = new_pos;
+            }
+            32 => {
+                let (v, new_pos) = decode_bool(data, pos)?;
+                view.active = v;
+                pos = new_pos;
+            }
+            40 => {
+                let (v, new_pos) = decode_int32(data, pos)?;
+                view.role = v;
+                pos = new_pos;
+            }
+            50 => {
+                let (v, new_pos) = decode_message_slice(data, pos)?;
+                view.address = Some(AddressRef::decode(v)?);
+                pos = new_pos;
+            }
+            58 => {
+                pos = skip_field(data, pos, 2)?;
+            }
+            74 => {
+                let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                view.avatar_hash = v;
+                pos = new_pos;
+            }
+            80 => {
+                let (v, new_pos) = decode_int64(data, pos)?;
+                view.created_at = v;
+                pos = new_pos;
+            }
+            89 => {
+                let (v, new_pos) = decode_fixed64(data, pos)?;
+                view.nonce = v;
+                pos = new_pos;
+            }
+            _ => {
+                pos = skip_field(data, pos, tag)?;
+            }
+            }
+            trace_field(UserProfile::FULL_NAME, tag, field_start, pos, None);
+        }
+
+        Ok(view)
+    }
+
+    /// The encoded message this view borrows from.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Decode the borrowed bytes into the owned message.
+    pub fn to_message(&self) -> Result<UserProfile, DecodeError> {
+        UserProfile::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub state: String,
+    pub zip: String,
+    pub floor: i32,
+}
+
+impl Address {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = Address {
+        street: String::new(),
+        city: String::new(),
+        state: String::new(),
+        zip: String::new(),
+        floor: 0,
+    };
+}
+
+impl Default for Address {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Address {
+    pub const STREET_FIELD_NUMBER: u32 = 1;
+    pub const CITY_FIELD_NUMBER: u32 = 2;
+    pub const STATE_FIELD_NUMBER: u32 = 3;
+    pub const ZIP_FIELD_NUMBER: u32 = 4;
+    pub const FLOOR_FIELD_NUMBER: u32 = 5;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 2, packed: false, name: "Address.street" },
+        FieldWireType { number: 2, wire_type: 2, packed: false, name: "Address.city" },
+        FieldWireType { number: 3, wire_type: 2, packed: false, name: "Address.state" },
+        FieldWireType { number: 4, wire_type: 2, packed: false, name: "Address.zip" },
+        FieldWireType { number: 5, wire_type: 0, packed: false, name: "Address.floor" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        // field 5: floor
+        if self.floor != 0 {
+            w.write_zigzag32(self.floor);
+            w.write_key(0x28);
+        }
+
+        // field 4: zip
+        if !self.zip.is_empty() {
+            w.write_string(&self.zip);
+            w.write_key(0x22);
+        }
+
+        // field 3: state
+        if !self.state.is_empty() {
+            w.write_string(&self.state);
+            w.write_key(0x1a);
+        }
+
+        // field 2: city
+        if !self.city.is_empty() {
+            w.write_string(&self.city);
+            w.write_key(0x12);
+        }
+
+        // field 1: street
+        if !self.street.is_empty() {
+            w.write_string(&self.street);
+            w.write_key(0x0a);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if !self.street.is_empty() {
+            len += key_len(1) + bytes_len(self.street.len());
+        }
+        if !self.city.is_empty() {
+            len += key_len(2) + bytes_len(self.city.len());
+        }
+        if !self.state.is_empty() {
+            len += key_len(3) + bytes_len(self.state.len());
+        }
+        if !self.zip.is_empty() {
+            len += key_len(4) + bytes_len(self.zip.len());
+        }
+        if self.floor != 0 {
+            len += key_len(5) + zigzag32_len(self.floor);
+        }
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if !self.street.is_empty() {
+            w.write_key(0x0a)?;
+            w.write_string(&self.street)?;
+        }
+        if !self.city.is_empty() {
+            w.write_key(0x12)?;
+            w.write_string(&self.city)?;
+        }
+        if !self.state.is_empty() {
+            w.write_key(0x1a)?;
+            w.write_string(&self.state)?;
+        }
+        if !self.zip.is_empty() {
+            w.write_key(0x22)?;
+            w.write_string(&self.zip)?;
+        }
+        if self.floor != 0 {
+            w.write_key(0x28)?;
+            w.write_zigzag32(self.floor)?;
+        }
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                10 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.street = v;
+                    pos = new_pos;
+                }
+                18 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.city = v;
+                    pos = new_pos;
+                }
+                26 => {
+                    mark_singular(&mut seen, 2, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.state = v;
+                    pos = new_pos;
+                }
+                34 => {
+                    mark_singular(&mut seen, 3, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.zip = v;
+                    pos = new_pos;
+                }
+                40 => {
+                    mark_singular(&mut seen, 4, opts)?;
+                    let (v, new_pos) = decode_zigzag32(data, pos)?;
+                    self.floor = v;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for Address {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        Address::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Address::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        Address::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        Address::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        Address::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        Address::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        Address::merge_with(self, data, opts)
+    }
+}
+
+impl TryFrom<&[u8]> for Address {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        Address::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<Address> for Vec<u8> {
+    fn from(msg: Address) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for Address {
+    const FULL_NAME: &'static str = "example.Address";
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AddressBuilder {
+    inner: Address,
+}
+
+impl Address {
+    pub fn builder() -> AddressBuilder {
+        AddressBuilder::default()
+    }
+}
+
+impl AddressBuilder {
+    pub fn with_street(mut self, value: String) -> Self {
+        self.inner.street = value;
+        self
+    }
+
+    pub fn with_city(mut self, value: String) -> Self {
+        self.inner.city = value;
+        self
+    }
+
+    pub fn with_state(mut self, value: String) -> Self {
+        self.inner.state = value;
+        self
+    }
+
+    pub fn with_zip(mut self, value: String) -> Self {
+        self.inner.zip = value;
+        self
+    }
+
+    pub fn with_floor(mut self, value: i32) -> Self {
+        self.inner.floor = value;
+        self
+    }
+
+    pub fn build(self) -> Address {
+        self.inner
+    }
+}
+
+impl Address {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if !self.street.is_empty() {
+            out.write_str("street", &self.street);
+        }
+        if !self.city.is_empty() {
+            out.write_str("city", &self.city);
+        }
+        if !self.state.is_empty() {
+            out.write_str("state", &self.state);
+        }
+        if !self.zip.is_empty() {
+            out.write_str("zip", &self.zip);
+        }
+        if self.floor != 0 {
+            out.write_scalar("floor", self.floor);
+        }
+    }
+}
+
+impl core::fmt::Display for Address {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl Address {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if !self.street.is_empty() {
+            out.key("street");
+            out.write_str(&self.street);
+        }
+        if !self.city.is_empty() {
+            out.key("city");
+            out.write_str(&self.city);
+        }
+        if !self.state.is_empty() {
+            out.key("state");
+            out.write_str(&self.state);
+        }
+        if !self.zip.is_empty() {
+            out.key("zip");
+            out.write_str(&self.zip);
+        }
+        if self.floor != 0 {
+            out.key("floor");
+            out.write_i32(self.floor);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "street" => msg.street = json_to_string(value)?,
+                "city" => msg.city = json_to_string(value)?,
+                "state" => msg.state = json_to_string(value)?,
+                "zip" => msg.zip = json_to_string(value)?,
+                "floor" => msg.floor = json_to_i32(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddressRef<'a> {
+    raw: &'a [u8],
+    pub street: &'a str,
+    pub city: &'a str,
+    pub state: &'a str,
+    pub zip: &'a str,
+    pub floor: i32,
+}
+
+impl<'a> AddressRef<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut view = AddressRef {
+            raw: data,
+            street: "",
+            city: "",
+            state: "",
+            zip: "",
+            floor: 0,
+        };
+
+        let mut pos = 0usize;
+        while pos < data.len() {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+
+            match tag {
+            10 => {
+                let (v, new_pos) = decode_str_ref(data, pos)?;
+                view.street = v;
+                pos = new_pos;
+            }
+            18 => {
+                let (v, new_pos) = decode_str_ref(data, pos)?;
+                view.city = v;
+                pos = new_pos;
+            }
+            26 => {
+                let (v, new_pos) = decode_str_ref(data, pos)?;
+                view.state = v;
+                pos = new_pos;
+            }
+            34 => {
+                let (v, new_pos) = decode_str_ref(data, pos)?;
+                view.zip = v;
+                pos = new_pos;
+            }
+            40 => {
+                let (v, new_pos) = decode_zigzag32(data, pos)?;
+                view.floor = v;
+                pos = new_pos;
+            }
+            _ => {
+                pos = skip_field(data, pos, tag)?;
+            }
+            }
+            trace_field(Address::FULL_NAME, tag, field_start, pos, None);
+        }
+
+        Ok(view)
+    }
+
+    /// The encoded message this view borrows from.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Decode the borrowed bytes into the owned message.
+    pub fn to_message(&self) -> Result<Address, DecodeError> {
+        Address::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+    }
+}
+
+/// Solana-specific: account metadata message
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct SolanaAccount {
+    pub pubkey: Vec<u8>,
+    pub lamports: u64,
+    pub owner: Vec<u8>,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub data: Vec<u8>,
+}
+
+impl SolanaAccount {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = SolanaAccount {
+        pubkey: Vec::new(),
+        lamports: 0,
+        owner: Vec::new(),
+        executable: false,
+        rent_epoch: 0,
+        data: Vec::new(),
+    };
+}
+
+impl Default for SolanaAccount {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl SolanaAccount {
+    pub const PUBKEY_FIELD_NUMBER: u32 = 1;
+    pub const LAMPORTS_FIELD_NUMBER: u32 = 2;
+    pub const OWNER_FIELD_NUMBER: u32 = 3;
+    pub const EXECUTABLE_FIELD_NUMBER: u32 = 4;
+    pub const RENT_EPOCH_FIELD_NUMBER: u32 = 5;
+    pub const DATA_FIELD_NUMBER: u32 = 6;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 2, packed: false, name: "SolanaAccount.pubkey" },
+        FieldWireType { number: 2, wire_type: 0, packed: false, name: "SolanaAccount.lamports" },
+        FieldWireType { number: 3, wire_type: 2, packed: false, name: "SolanaAccount.owner" },
+        FieldWireType { number: 4, wire_type: 0, packed: false, name: "SolanaAccount.executable" },
+        FieldWireType { number: 5, wire_type: 0, packed: false, name: "SolanaAccount.rent_epoch" },
+        FieldWireType { number: 6, wire_type: 2, packed: false, name: "SolanaAccount.data" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        // field 6: data
+        if !self.data.is_empty() {
+            w.write_bytes(&self.data);
+            w.write_key(0x32);
+        }
+
+        // field 5: rent_epoch
+        if self.rent_epoch != 0 {
+            w.write_varint(self.rent_epoch);
+            w.write_key(0x28);
+        }
+
+        // field 4: executable
+        if self.executable {
+            w.write_bool(self.executable);
+            w.write_key(0x20);
+        }
+
+        // field 3: owner
+        if !self.owner.is_empty() {
+            w.write_bytes(&self.owner);
+            w.write_key(0x1a);
+        }
+
+        // field 2: lamports
+        if self.lamports != 0 {
+            w.write_varint(self.lamports);
+            w.write_key(0x10);
+        }
+
+        // field 1: pubkey
+        if !self.pubkey.is_empty() {
+            w.write_bytes(&self.pubkey);
+            w.write_key(0x0a);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if !self.pubkey.is_empty() {
+            len += key_len(1) + bytes_len(self.pubkey.len());
+        }
+        if self.lamports != 0 {
+            len += key_len(2) + varint_len(self.lamports);
+        }
+        if !self.owner.is_empty() {
+            len += key_len(3) + bytes_len(self.owner.len());
+        }
+        if self.executable {
+            len += key_len(4) + 1;
+        }
+        if self.rent_epoch != 0 {
+            len += key_len(5) + varint_len(self.rent_epoch);
+        }
+        if !self.data.is_empty() {
+            len += key_len(6) + bytes_len(self.data.len());
+        }
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if !self.pubkey.is_empty() {
+            w.write_key(0x0a)?;
+            w.write_bytes(&self.pubkey)?;
+        }
+        if self.lamports != 0 {
+            w.write_key(0x10)?;
+            w.write_varint(self.lamports)?;
+        }
+        if !self.owner.is_empty() {
+            w.write_key(0x1a)?;
+            w.write_bytes(&self.owner)?;
+        }
+        if self.executable {
+            w.write_key(0x20)?;
+            w.write_bool(self.executable)?;
+        }
+        if self.rent_epoch != 0 {
+            w.write_key(0x28)?;
+            w.write_varint(self.rent_epoch)?;
+        }
+        if !self.data.is_empty() {
+            w.write_key(0x32)?;
+            w.write_bytes(&self.data)?;
+        }
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                10 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.pubkey = v;
+                    pos = new_pos;
+                }
+                16 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.lamports = v;
+                    pos = new_pos;
+                }
+                26 => {
+                    mark_singular(&mut seen, 2, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.owner = v;
+                    pos = new_pos;
+                }
+                32 => {
+                    mark_singular(&mut seen, 3, opts)?;
+                    let (v, new_pos) = decode_bool(data, pos)?;
+                    self.executable = v;
+                    pos = new_pos;
+                }
+                40 => {
+                    mark_singular(&mut seen, 4, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.rent_epoch = v;
+                    pos = new_pos;
+                }
+                50 => {
+                    mark_singular(&mut seen, 5, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.data = v;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for SolanaAccount {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        SolanaAccount::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        SolanaAccount::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        SolanaAccount::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        SolanaAccount::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        SolanaAccount::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        SolanaAccount::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        SolanaAccount::merge_with(self, data, opts)
+    }
+}
+
+impl TryFrom<&[u8]> for SolanaAccount {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        SolanaAccount::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<SolanaAccount> for Vec<u8> {
+    fn from(msg: SolanaAccount) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for SolanaAccount {
+    const FULL_NAME: &'static str = "example.SolanaAccount";
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SolanaAccountBuilder {
+    inner: SolanaAccount,
+}
+
+impl SolanaAccount {
+    pub fn builder() -> SolanaAccountBuilder {
+        SolanaAccountBuilder::default()
+    }
+}
+
+impl SolanaAccountBuilder {
+    pub fn with_pubkey(mut self, value: Vec<u8>) -> Self {
+        self.inner.pubkey = value;
+        self
+    }
+
+    pub fn with_lamports(mut self, value: u64) -> Self {
+        self.inner.lamports = value;
+        self
+    }
+
+    pub fn with_owner(mut self, value: Vec<u8>) -> Self {
+        self.inner.owner = value;
+        self
+    }
+
+    pub fn with_executable(mut self, value: bool) -> Self {
+        self.inner.executable = value;
+        self
+    }
+
+    pub fn with_rent_epoch(mut self, value: u64) -> Self {
+        self.inner.rent_epoch = value;
+        self
+    }
+
+    pub fn with_data(mut self, value: Vec<u8>) -> Self {
+        self.inner.data = value;
+        self
+    }
+
+    pub fn build(self) -> SolanaAccount {
+        self.inner
+    }
+}
+
+impl SolanaAccount {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if !self.pubkey.is_empty() {
+            out.write_bytes("pubkey", &self.pubkey);
+        }
+        if self.lamports != 0 {
+            out.write_scalar("lamports", self.lamports);
+        }
+        if !self.owner.is_empty() {
+            out.write_bytes("owner", &self.owner);
+        }
+        if self.executable {
+            out.write_scalar("executable", self.executable);
+        }
+        if self.rent_epoch != 0 {
+            out.write_scalar("rent_epoch", self.rent_epoch);
+        }
+        if !self.data.is_empty() {
+            out.write_bytes("data", &self.data);
+        }
+    }
+}
+
+impl core::fmt::Display for SolanaAccount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl SolanaAccount {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if !self.pubkey.is_empty() {
+            out.key("pubkey");
+            out.write_bytes(&self.pubkey);
+        }
+        if self.lamports != 0 {
+            out.key("lamports");
+            out.write_u64(self.lamports);
+        }
+        if !self.owner.is_empty() {
+            out.key("owner");
+            out.write_bytes(&self.owner);
+        }
+        if self.executable {
+            out.key("executable");
+            out.write_bool(self.executable);
+        }
+        if self.rent_epoch != 0 {
+            out.key("rentEpoch");
+            out.write_u64(self.rent_epoch);
+        }
+        if !self.data.is_empty() {
+            out.key("data");
+            out.write_bytes(&self.data);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "pubkey" => msg.pubkey = json_to_bytes(value)?,
+                "lamports" => msg.lamports = json_to_u64(value)?,
+                "owner" => msg.owner = json_to_bytes(value)?,
+                "executable" => msg.executable = json_to_bool(value)?,
+                "rentEpoch" | "rent_epoch" => msg.rent_epoch = json_to_u64(value)?,
+                "data" => msg.data = json_to_bytes(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SolanaAccountRef<'a> {
+    raw: &'a [u8],
+    pub pubkey: &'a [u8],
+    pub lamports: u64,
+    pub owner: &'a [u8],
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub data: &'a [u8],
+}
+
+impl<'a> SolanaAccountRef<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut view = SolanaAccountRef {
+            raw: data,
+            pubkey: &[],
+            lamports: 0,
+            owner: &[],
+            executable: false,
+            rent_epoch: 0,
+            data: &[],
+        };
+
+        let mut pos = 0usize;
+        while pos < data.len() {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+
+            match tag {
+            10 => {
+                let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                view.pubkey = v;
+                pos = new_pos;
+            }
+            16 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.lamports = v;
+                pos = new_pos;
+            }
+            26 => {
+                let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                view.owner = v;
+                pos = new_pos;
+            }
+            32 => {
+                let (v, new_pos) = decode_bool(data, pos)?;
+                view.executable = v;
+                pos = new_pos;
+            }
+            40 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.rent_epoch = v;
+                pos = new_pos;
+            }
+            50 => {
+                let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                view.data = v;
+                pos = new_pos;
+            }
+            _ => {
+                pos = skip_field(data, pos, tag)?;
+            }
+            }
+            trace_field(SolanaAccount::FULL_NAME, tag, field_start, pos, None);
+        }
+
+        Ok(view)
+    }
+
+    /// The encoded message this view borrows from.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Decode the borrowed bytes into the owned message.
+    pub fn to_message(&self) -> Result<SolanaAccount, DecodeError> {
+        SolanaAccount::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+    }
+}
+
+/// Nested message with repeated sub-messages
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct TransactionBatch {
+    pub chain_id: u64,
+    pub txns: Vec<Transaction>,
+    pub checksum: u32,
+}
+
+impl TransactionBatch {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = TransactionBatch {
+        chain_id: 0,
+        txns: Vec::new(),
+        checksum: 0,
+    };
+}
+
+impl Default for TransactionBatch {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl TransactionBatch {
+    pub const CHAIN_ID_FIELD_NUMBER: u32 = 1;
+    pub const TXNS_FIELD_NUMBER: u32 = 2;
+    pub const CHECKSUM_FIELD_NUMBER: u32 = 3;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 0, packed: false, name: "TransactionBatch.chain_id" },
+        FieldWireType { number: 2, wire_type: 2, packed: false, name: "TransactionBatch.txns" },
+        FieldWireType { number: 3, wire_type: 5, packed: false, name: "TransactionBatch.checksum" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        // field 3: checksum
+        if self.checksum != 0 {
+            w.write_fixed32(self.checksum);
+            w.write_key(0x1d);
+        }
+
+        // field 2: txns
+        for elem in self.txns.iter().rev() {
+            w.write_message(2, elem);
+        }
+
+        // field 1: chain_id
+        if self.chain_id != 0 {
+            w.write_varint(self.chain_id);
+            w.write_key(0x08);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if self.chain_id != 0 {
+            len += key_len(1) + varint_len(self.chain_id);
+        }
+        for elem in &self.txns {
+            len += key_len(2) + bytes_len(elem.encoded_len());
+        }
+        if self.checksum != 0 {
+            len += key_len(3) + 4;
+        }
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if self.chain_id != 0 {
+            w.write_key(0x08)?;
+            w.write_varint(self.chain_id)?;
+        }
+        for elem in &self.txns {
+            w.write_message(2, elem)?;
+        }
+        if self.checksum != 0 {
+            w.write_key(0x1d)?;
+            w.write_fixed32(self.checksum)?;
+        }
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        let mut len = 0;
+        if self.chain_id != 0 {
+            len += key_len(1) + varint_len(self.chain_id);
+        }
+        for elem in &self.txns {
+            len += key_len(2) + bytes_len(sizes.record(elem));
+        }
+        if self.checksum != 0 {
+            len += key_len(3) + 4;
+        }
+        len
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        if self.chain_id != 0 {
+            w.write_key(0x08)?;
+            w.write_varint(self.chain_id)?;
+        }
+        for elem in &self.txns {
+            w.write_message_sized(2, elem, sizes)?;
+        }
+        if self.checksum != 0 {
+            w.write_key(0x1d)?;
+            w.write_fixed32(self.checksum)?;
+        }
+        Ok(())
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                8 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.chain_id = v;
+                    pos = new_pos;
+                }
+                18 => {
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if self.txns.len() >= opts.max_repeated_len {
+                        return Err(DecodeError::SizeLimitExceeded);
+                    }
+                    self.txns.push(Transaction::decode_with(sub, &opts.nested()?)?);
+                    pos = new_pos;
+                }
+                29 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_fixed32(data, pos)?;
+                    self.checksum = v;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The element of repeated field `number` being decoded, for `field_error`.
+    fn next_index(&self, number: u64) -> Option<usize> {
+        match number {
+            2 => Some(self.txns.len()),
+            _ => None,
+        }
+    }
+}
+
+impl Message for TransactionBatch {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        TransactionBatch::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        TransactionBatch::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        TransactionBatch::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        TransactionBatch::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        TransactionBatch::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        TransactionBatch::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        TransactionBatch::merge_with(self, data, opts)
+    }
+}
+
+impl TryFrom<&[u8]> for TransactionBatch {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        TransactionBatch::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<TransactionBatch> for Vec<u8> {
+    fn from(msg: TransactionBatch) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for TransactionBatch {
+    const FULL_NAME: &'static str = "example.TransactionBatch";
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransactionBatchBuilder {
+    inner: TransactionBatch,
+}
+
+impl TransactionBatch {
+    pub fn builder() -> TransactionBatchBuilder {
+        TransactionBatchBuilder::default()
+    }
+}
+
+impl TransactionBatchBuilder {
+    pub fn with_chain_id(mut self, value: u64) -> Self {
+        self.inner.chain_id = value;
+        self
+    }
+
+    pub fn with_txns(mut self, value: Vec<Transaction>) -> Self {
+        self.inner.txns = value;
+        self
+    }
+
+    pub fn add_txns(mut self, value: Transaction) -> Self {
+        self.inner.txns.push(value);
+        self
+    }
+
+    pub fn with_checksum(mut self, value: u32) -> Self {
+        self.inner.checksum = value;
+        self
+    }
+
+    pub fn build(self) -> TransactionBatch {
+        self.inner
+    }
+}
+
+impl TransactionBatch {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if self.chain_id != 0 {
+            out.write_scalar("chain_id", self.chain_id);
+        }
+        for elem in &self.txns {
+            out.begin_message("txns");
+            elem.write_text(out);
+            out.end_message();
+        }
+        if self.checksum != 0 {
+            out.write_scalar("checksum", self.checksum);
+        }
+    }
+}
+
+impl core::fmt::Display for TransactionBatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl TransactionBatch {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if self.chain_id != 0 {
+            out.key("chainId");
+            out.write_u64(self.chain_id);
+        }
+        if !self.txns.is_empty() {
+            out.key("txns");
+            out.begin_array();
+            for elem in &self.txns {
+                elem.write_json(out);
+            }
+            out.end_array();
+        }
+        if self.checksum != 0 {
+            out.key("checksum");
+            out.write_u32(self.checksum);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "chainId" | "chain_id" => msg.chain_id = json_to_u64(value)?,
+                "txns" => {
+                    for elem in json_array(value)? {
+                        msg.txns.push(Transaction::from_json_value(elem)?);
+                    }
+                }
+                "checksum" => msg.checksum = json_to_u32(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TransactionBatchRef<'a> {
+    raw: &'a [u8],
+    pub chain_id: u64,
+    pub txns: RepeatedRef<'a, TransactionRef<'a>>,
+    pub checksum: u32,
+}
+
+impl<'a> TransactionBatchRef<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut view = TransactionBatchRef {
+            raw: data,
+            chain_id: 0,
+            txns: RepeatedRef::new(data, 18, |data, pos| {
+                let (v, new_pos) = decode_message_slice(data, pos)?;
+                Ok((TransactionRef::decode(v)?, new_pos))
+            }),
+            checksum: 0,
+        };
+
+        let mut pos = 0usize;
+        while pos < data.len() {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+
+            match tag {
+            8 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.chain_id = v;
+                pos = new_pos;
+            }
+            18 => {
+                pos = skip_field(data, pos, 2)?;
+            }
+            29 => {
+                let (v, new_pos) = decode_fixed32(data, pos)?;
+                view.checksum = v;
+                pos = new_pos;
+            }
+            _ => {
+                pos = skip_field(data, pos, tag)?;
+            }
+            }
+            trace_field(TransactionBatch::FULL_NAME, tag, field_start, pos, None);
+        }
+
+        Ok(view)
+    }
+
+    /// The encoded message this view borrows from.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Decode the borrowed bytes into the owned message.
+    pub fn to_message(&self) -> Result<TransactionBatch, DecodeError> {
+        TransactionBatch::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct Transaction {
+    pub to: Vec<u8>,
+    pub data: Vec<u8>,
+    pub value: u64,
+    pub gas_limit: u64,
+    pub nonce: u64,
+}
+
+impl Transaction {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = Transaction {
+        to: Vec::new(),
+        data: Vec::new(),
+        value: 0,
+        gas_limit: 0,
+        nonce: 0,
+    };
+}
+
+impl Default for Transaction {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Transaction {
+    pub const TO_FIELD_NUMBER: u32 = 1;
+    pub const DATA_FIELD_NUMBER: u32 = 2;
+    pub const VALUE_FIELD_NUMBER: u32 = 3;
+    pub const GAS_LIMIT_FIELD_NUMBER: u32 = 4;
+    pub const NONCE_FIELD_NUMBER: u32 = 5;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 2, packed: false, name: "Transaction.to" },
+        FieldWireType { number: 2, wire_type: 2, packed: false, name: "Transaction.data" },
+        FieldWireType { number: 3, wire_type: 0, packed: false, name: "Transaction.value" },
+        FieldWireType { number: 4, wire_type: 0, packed: false, name: "Transaction.gas_limit" },
+        FieldWireType { number: 5, wire_type: 0, packed: false, name: "Transaction.nonce" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        // field 5: nonce
+        if self.nonce != 0 {
+            w.write_varint(self.nonce);
+            w.write_key(0x28);
+        }
+
+        // field 4: gas_limit
+        if self.gas_limit != 0 {
+            w.write_varint(self.gas_limit);
+            w.write_key(0x20);
+        }
+
+        // field 3: value
+        if self.value != 0 {
+            w.write_varint(self.value);
+            w.write_key(0x18);
+        }
+
+        // field 2: data
+        if !self.data.is_empty() {
+            w.write_bytes(&self.data);
+            w.write_key(0x12);
+        }
+
+        // field 1: to
+        if !self.to.is_empty() {
+            w.write_bytes(&self.to);
+            w.write_key(0x0a);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if !self.to.is_empty() {
+            len += key_len(1) + bytes_len(self.to.len());
+        }
+        if !self.data.is_empty() {
+            len += key_len(2) + bytes_len(self.data.len());
+        }
+        if self.value != 0 {
+            len += key_len(3) + varint_len(self.value);
+        }
+        if self.gas_limit != 0 {
+            len += key_len(4) + varint_len(self.gas_limit);
+        }
+        if self.nonce != 0 {
+            len += key_len(5) + varint_len(self.nonce);
+        }
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if !self.to.is_empty() {
+            w.write_key(0x0a)?;
+            w.write_bytes(&self.to)?;
+        }
+        if !self.data.is_empty() {
+            w.write_key(0x12)?;
+            w.write_bytes(&self.data)?;
+        }
+        if self.value != 0 {
+            w.write_key(0x18)?;
+            w.write_varint(self.value)?;
+        }
+        if self.gas_limit != 0 {
+            w.write_key(0x20)?;
+            w.write_varint(self.gas_limit)?;
+        }
+        if self.nonce != 0 {
+            w.write_key(0x28)?;
+            w.write_varint(self.nonce)?;
+        }
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                10 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.to = v;
+                    pos = new_pos;
+                }
+                18 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                    self.data = v;
+                    pos = new_pos;
+                }
+                24 => {
+                    mark_singular(&mut seen, 2, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.value = v;
+                    pos = new_pos;
+                }
+                32 => {
+                    mark_singular(&mut seen, 3, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.gas_limit = v;
+                    pos = new_pos;
+                }
+                40 => {
+                    mark_singular(&mut seen, 4, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.nonce = v;
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for Transaction {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        Transaction::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Transaction::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        Transaction::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        Transaction::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        Transaction::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        Transaction::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        Transaction::merge_with(self, data, opts)
+    }
+}
+
+impl TryFrom<&[u8]> for Transaction {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        Transaction::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<Transaction> for Vec<u8> {
+    fn from(msg: Transaction) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for Transaction {
+    const FULL_NAME: &'static str = "example.Transaction";
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransactionBuilder {
+    inner: Transaction,
+}
+
+impl Transaction {
+    pub fn builder() -> TransactionBuilder {
+        TransactionBuilder::default()
+    }
+}
+
+impl TransactionBuilder {
+    pub fn with_to(mut self, value: Vec<u8>) -> Self {
+        self.inner.to = value;
+        self
+    }
+
+    pub fn with_data(mut self, value: Vec<u8>) -> Self {
+        self.inner.data = value;
+        self
+    }
+
+    pub fn with_value(mut self, value: u64) -> Self {
+        self.inner.value = value;
+        self
+    }
+
+    pub fn with_gas_limit(mut self, value: u64) -> Self {
+        self.inner.gas_limit = value;
+        self
+    }
+
+    pub fn with_nonce(mut self, value: u64) -> Self {
+        self.inner.nonce = value;
+        self
+    }
+
+    pub fn build(self) -> Transaction {
+        self.inner
+    }
+}
+
+impl Transaction {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if !self.to.is_empty() {
+            out.write_bytes("to", &self.to);
+        }
+        if !self.data.is_empty() {
+            out.write_bytes("data", &self.data);
+        }
+        if self.value != 0 {
+            out.write_scalar("value", self.value);
+        }
+        if self.gas_limit != 0 {
+            out.write_scalar("gas_limit", self.gas_limit);
+        }
+        if self.nonce != 0 {
+            out.write_scalar("nonce", self.nonce);
+        }
+    }
+}
+
+impl core::fmt::Display for Transaction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl Transaction {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if !self.to.is_empty() {
+            out.key("to");
+            out.write_bytes(&self.to);
+        }
+        if !self.data.is_empty() {
+            out.key("data");
+            out.write_bytes(&self.data);
+        }
+        if self.value != 0 {
+            out.key("value");
+            out.write_u64(self.value);
+        }
+        if self.gas_limit != 0 {
+            out.key("gasLimit");
+            out.write_u64(self.gas_limit);
+        }
+        if self.nonce != 0 {
+            out.key("nonce");
+            out.write_u64(self.nonce);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "to" => msg.to = json_to_bytes(value)?,
+                "data" => msg.data = json_to_bytes(value)?,
+                "value" => msg.value = json_to_u64(value)?,
+                "gasLimit" | "gas_limit" => msg.gas_limit = json_to_u64(value)?,
+                "nonce" => msg.nonce = json_to_u64(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TransactionRef<'a> {
+    raw: &'a [u8],
+    pub to: &'a [u8],
+    pub data: &'a [u8],
+    pub value: u64,
+    pub gas_limit: u64,
+    pub nonce: u64,
+}
+
+impl<'a> TransactionRef<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut view = TransactionRef {
+            raw: data,
+            to: &[],
+            data: &[],
+            value: 0,
+            gas_limit: 0,
+            nonce: 0,
+        };
+
+        let mut pos = 0usize;
+        while pos < data.len() {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+
+            match tag {
+            10 => {
+                let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                view.to = v;
+                pos = new_pos;
+            }
+            18 => {
+                let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                view.data = v;
+                pos = new_pos;
+            }
+            24 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.value = v;
+                pos = new_pos;
+            }
+            32 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.gas_limit = v;
+                pos = new_pos;
+            }
+            40 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.nonce = v;
+                pos = new_pos;
+            }
+            _ => {
+                pos = skip_field(data, pos, tag)?;
+            }
+            }
+            trace_field(Transaction::FULL_NAME, tag, field_start, pos, None);
+        }
+
+        Ok(view)
+    }
+
+    /// The encoded message this view borrows from.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Decode the borrowed bytes into the owned message.
+    pub fn to_message(&self) -> Result<Transaction, DecodeError> {
+        Transaction::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub enum InstructionKind {
+    Unset,
+    Transfer(Transaction),
+    BurnAmount(u64),
+    Memo(String),
+}
+
+impl InstructionKind {
+    pub const DEFAULT: Self = InstructionKind::Unset;
+}
+
+impl Default for InstructionKind {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Oneof: exactly one instruction payload is set
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct Instruction {
+    pub nonce: u64,
+    pub kind: InstructionKind,
+}
+
+impl Instruction {
+    /// Every field at its proto3 default, for `const` and `static` items.
+    pub const DEFAULT: Self = Instruction {
+        nonce: 0,
+        kind: InstructionKind::Unset,
+    };
+}
+
+impl Default for Instruction {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Instruction {
+    pub const NONCE_FIELD_NUMBER: u32 = 1;
+    pub const TRANSFER_FIELD_NUMBER: u32 = 2;
+    pub const BURN_AMOUNT_FIELD_NUMBER: u32 = 3;
+    pub const MEMO_FIELD_NUMBER: u32 = 4;
+
+    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+        FieldWireType { number: 1, wire_type: 0, packed: false, name: "Instruction.nonce" },
+        FieldWireType { number: 2, wire_type: 2, packed: false, name: "Instruction.transfer" },
+        FieldWireType { number: 3, wire_type: 0, packed: false, name: "Instruction.burn_amount" },
+        FieldWireType { number: 4, wire_type: 2, packed: false, name: "Instruction.memo" },
+    ];
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+        buf
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        // field 4: memo
+        if let InstructionKind::Memo(elem) = &self.kind {
+            w.write_string(elem);
+            w.write_key(0x22);
+        }
+
+        // field 3: burn_amount
+        if let InstructionKind::BurnAmount(elem) = &self.kind {
+            w.write_varint(*elem);
+            w.write_key(0x18);
+        }
+
+        // field 2: transfer
+        if let InstructionKind::Transfer(elem) = &self.kind {
+            w.write_message(2, elem);
+        }
+
+        // field 1: nonce
+        if self.nonce != 0 {
+            w.write_varint(self.nonce);
+            w.write_key(0x08);
+        }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if self.nonce != 0 {
+            len += key_len(1) + varint_len(self.nonce);
+        }
+        if let InstructionKind::Transfer(elem) = &self.kind {
+            len += key_len(2) + bytes_len(elem.encoded_len());
+        }
+        if let InstructionKind::BurnAmount(elem) = &self.kind {
+            len += key_len(3) + varint_len(*elem);
+        }
+        if let InstructionKind::Memo(elem) = &self.kind {
+            len += key_len(4) + bytes_len(elem.len());
+        }
+        len
+    }
+
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if self.nonce != 0 {
+            w.write_key(0x08)?;
+            w.write_varint(self.nonce)?;
+        }
+        if let InstructionKind::Transfer(elem) = &self.kind {
+            w.write_message(2, elem)?;
+        }
+        if let InstructionKind::BurnAmount(elem) = &self.kind {
+            w.write_key(0x18)?;
+            w.write_varint(*elem)?;
+        }
+        if let InstructionKind::Memo(elem) = &self.kind {
+            w.write_key(0x22)?;
+            w.write_string(elem)?;
+        }
+        Ok(())
+    }
+
+    pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        let mut len = 0;
+        if self.nonce != 0 {
+            len += key_len(1) + varint_len(self.nonce);
+        }
+        if let InstructionKind::Transfer(elem) = &self.kind {
+            len += key_len(2) + bytes_len(sizes.record(elem));
+        }
+        if let InstructionKind::BurnAmount(elem) = &self.kind {
+            len += key_len(3) + varint_len(*elem);
+        }
+        if let InstructionKind::Memo(elem) = &self.kind {
+            len += key_len(4) + bytes_len(elem.len());
+        }
+        len
+    }
+
+    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        if self.nonce != 0 {
+            w.write_key(0x08)?;
+            w.write_varint(self.nonce)?;
+        }
+        if let InstructionKind::Transfer(elem) = &self.kind {
+            w.write_message_sized(2, elem, sizes)?;
+        }
+        if let InstructionKind::BurnAmount(elem) = &self.kind {
+            w.write_key(0x18)?;
+            w.write_varint(*elem)?;
+        }
+        if let InstructionKind::Memo(elem) = &self.kind {
+            w.write_key(0x22)?;
+            w.write_string(elem)?;
+        }
+        Ok(())
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+    /// constants), skipping the rest of `data` without allocating for it.
+    /// Every other field keeps its default.
+    pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_selected(data, opts, Some(fields))?;
+        Ok(msg)
+    }
+
+    /// Reset every field to its proto3 default.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        self.merge_selected(data, opts, None)
+    }
+
+    fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+        let mut pos = 0usize;
+        let end = data.len();
+        let mut seen = [0u64; 1];
+
+        while pos < end {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+            if !is_selected(only, tag >> 3) {
+                pos = skip_field(data, pos, tag)?;
+                continue;
+            }
+
+            let mut field = || -> Result<(), DecodeError> {
+                match tag {
+                8 => {
+                    mark_singular(&mut seen, 0, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.nonce = v;
+                    pos = new_pos;
+                }
+                18 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (sub, new_pos) = decode_message_slice(data, pos)?;
+                    if let InstructionKind::Transfer(existing) = &mut self.kind {
+                        existing.merge_with(sub, &opts.nested()?)?;
+                    } else {
+                        self.kind = InstructionKind::Transfer(Transaction::decode_with(sub, &opts.nested()?)?);
+                    }
+                    pos = new_pos;
+                }
+                24 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_varint(data, pos)?;
+                    self.kind = InstructionKind::BurnAmount(v);
+                    pos = new_pos;
+                }
+                34 => {
+                    mark_singular(&mut seen, 1, opts)?;
+                    let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                    self.kind = InstructionKind::Memo(v);
+                    pos = new_pos;
+                }
+                _ => {
+                    if opts.reject_wire_type_mismatch {
+                        check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                    }
+                    pos = skip_field(data, pos, tag)?;
+                }
+                }
+                Ok(())
+            };
+            let result = field();
+            trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+            if let Err(e) = result {
+                return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Message for Instruction {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        Instruction::encode_into(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Instruction::encoded_len(self)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        Instruction::write_to(self, w)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        Instruction::write_reverse(self, w)
+    }
+
+    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+        Instruction::encoded_len_cached(self, sizes)
+    }
+
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        Instruction::write_to_sized(self, w, sizes)
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        Instruction::merge_with(self, data, opts)
+    }
+}
+
+impl TryFrom<&[u8]> for Instruction {
+    type Error = DecodeError;
+
+    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+        Instruction::decode_with(data, &DecodeOptions::ACCOUNT)
+    }
+}
+
+impl From<Instruction> for Vec<u8> {
+    fn from(msg: Instruction) -> Vec<u8> {
+        msg.encode()
+    }
+}
+
+impl MessageName for Instruction {
+    const FULL_NAME: &'static str = "example.Instruction";
+}
+
+impl Instruction {
+    pub fn transfer(&self) -> Option<&Transaction> {
+        match &self.kind {
+            InstructionKind::Transfer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_transfer(&mut self, value: Transaction) {
+        self.kind = InstructionKind::Transfer(value);
+    }
+
+    pub fn burn_amount(&self) -> Option<&u64> {
+        match &self.kind {
+            InstructionKind::BurnAmount(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_burn_amount(&mut self, value: u64) {
+        self.kind = InstructionKind::BurnAmount(value);
+    }
+
+    pub fn memo(&self) -> Option<&String> {
+        match &self.kind {
+            InstructionKind::Memo(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn set_memo(&mut self, value: String) {
+        self.kind = InstructionKind::Memo(value);
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstructionBuilder {
+    inner: Instruction,
+}
+
+impl Instruction {
+    pub fn builder() -> InstructionBuilder {
+        InstructionBuilder::default()
+    }
+}
+
+impl InstructionBuilder {
+    pub fn with_nonce(mut self, value: u64) -> Self {
+        self.inner.nonce = value;
+        self
+    }
+
+    pub fn with_transfer(mut self, value: Transaction) -> Self {
+        self.inner.kind = InstructionKind::Transfer(value);
+        self
+    }
+
+    pub fn with_burn_amount(mut self, value: u64) -> Self {
+        self.inner.kind = InstructionKind::BurnAmount(value);
+        self
+    }
+
+    pub fn with_memo(mut self, value: String) -> Self {
+        self.inner.kind = InstructionKind::Memo(value);
+        self
+    }
+
+    pub fn build(self) -> Instruction {
+        self.inner
+    }
+}
+
+impl Instruction {
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if self.nonce != 0 {
+            out.write_scalar("nonce", self.nonce);
+        }
+        if let InstructionKind::Transfer(elem) = &self.kind {
+            out.begin_message("transfer");
+            elem.write_text(out);
+            out.end_message();
+        }
+        if let InstructionKind::BurnAmount(elem) = &self.kind {
+            out.write_scalar("burn_amount", *elem);
+        }
+        if let InstructionKind::Memo(elem) = &self.kind {
+            out.write_str("memo", elem);
+        }
+    }
+}
+
+impl core::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = TextWriter::new(f.alternate());
+        self.write_text(&mut out);
+        f.write_str(&out.finish())
+    }
+}
+
+#[cfg(feature = "json")]
+impl Instruction {
+    pub fn to_json(&self) -> String {
+        let mut out = JsonWriter::new();
+        self.write_json(&mut out);
+        out.finish()
+    }
+
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        if self.nonce != 0 {
+            out.key("nonce");
+            out.write_u64(self.nonce);
+        }
+        if let InstructionKind::Transfer(elem) = &self.kind {
+            out.key("transfer");
+            elem.write_json(out);
+        }
+        if let InstructionKind::BurnAmount(elem) = &self.kind {
+            out.key("burnAmount");
+            out.write_u64(*elem);
+        }
+        if let InstructionKind::Memo(elem) = &self.kind {
+            out.key("memo");
+            out.write_str(elem);
+        }
+        out.end_object();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Self::from_json_value(&parse_json(json)?)
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut msg = Self::default();
+        for (key, value) in json_object(value)? {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "nonce" => msg.nonce = json_to_u64(value)?,
+                "transfer" => msg.kind = InstructionKind::Transfer(Transaction::from_json_value(value)?),
+                "burnAmount" | "burn_amount" => msg.kind = InstructionKind::BurnAmount(json_to_u64(value)?),
+                "memo" => msg.kind = InstructionKind::Memo(json_to_string(value)?),
+                _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct InstructionRef<'a> {
+    raw: &'a [u8],
+    pub nonce: u64,
+    pub transfer: Option<TransactionRef<'a>>,
+    pub burn_amount: Option<u64>,
+    pub memo: Option<&'a str>,
+}
+
+impl<'a> InstructionRef<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut view = InstructionRef {
+            raw: data,
+            nonce: 0,
+            transfer: None,
+            burn_amount: None,
+            memo: None,
+        };
+
+        let mut pos = 0usize;
+        while pos < data.len() {
+            let field_start = pos;
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = new_pos;
+
+            match tag {
+            8 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.nonce = v;
+                pos = new_pos;
+            }
+            18 => {
+                let (v, new_pos) = decode_message_slice(data, pos)?;
+                view.transfer = Some(TransactionRef::decode(v)?);
+                view.burn_amount = None;
+                view.memo = None;
+                pos = new_pos;
+            }
+            24 => {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                view.burn_amount = Some(v);
+                view.transfer = None;
+                view.memo = None;
+                pos = new_pos;
+            }
+            34 => {
+                let (v, new_pos) = decode_str_ref(data, pos)?;
+                view.memo = Some(v);
+                view.transfer = None;
+                view.burn_amount = None;
+                pos = new_pos;
+            }
+            _ => {
+                pos = skip_field(data, pos, tag)?;
+            }
+            }
+            trace_field(Instruction::FULL_NAME, tag, field_start, pos, None);
+        }
+
+        Ok(view)
+    }
+
+    /// The encoded message this view borrows from.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Decode the borrowed bytes into the owned message.
+    pub fn to_message(&self) -> Result<Instruction, DecodeError> {
+        Instruction::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+    }
+}
+
+/// Every message in this file, for dispatching `Any` payloads by type URL.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyMessage {
+    UserProfile(UserProfile),
+    Address(Address),
+    SolanaAccount(SolanaAccount),
+    TransactionBatch(TransactionBatch),
+    Transaction(Transaction),
+    Instruction(Instruction),
+}
+
+impl AnyMessage {
+    /// Decode `any` as the message its type URL names, or `None` if that
+    /// message is not in this file.
+    pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+        Ok(Some(match any.type_name() {
+            UserProfile::FULL_NAME => AnyMessage::UserProfile(UserProfile::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            Address::FULL_NAME => AnyMessage::Address(Address::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            SolanaAccount::FULL_NAME => AnyMessage::SolanaAccount(SolanaAccount::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            TransactionBatch::FULL_NAME => AnyMessage::TransactionBatch(TransactionBatch::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            Transaction::FULL_NAME => AnyMessage::Transaction(Transaction::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            Instruction::FULL_NAME => AnyMessage::Instruction(Instruction::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+            _ => return Ok(None),
+        }))
+    }
+
+    pub fn pack(&self) -> Any {
+        match self {
+            AnyMessage::UserProfile(msg) => Any::pack(msg),
+            AnyMessage::Address(msg) => Any::pack(msg),
+            AnyMessage::SolanaAccount(msg) => Any::pack(msg),
+            AnyMessage::TransactionBatch(msg) => Any::pack(msg),
+            AnyMessage::Transaction(msg) => Any::pack(msg),
+            AnyMessage::Instruction(msg) => Any::pack(msg),
+        }
+    }
+}
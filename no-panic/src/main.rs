@@ -0,0 +1,257 @@
+//! Link-time check that decoding cannot panic.
+//!
+//! A panic in an on-chain program is an opaque abort of the whole
+//! transaction, so the decode paths of the runtime and of generated code
+//! report every failure, overflow included, as a `DecodeError`. Each check
+//! below wraps one of those paths in a guard whose destructor calls a
+//! function that does not exist. The destructor only runs while unwinding,
+//! so once the release build (with overflow checks on) has optimized away
+//! every path that can panic, nothing references the missing function and
+//! the binary links. If a path can still panic, linking fails with
+//! `ERROR: a function may panic`, naming the checks that can.
+//!
+//! Views of repeated message fields read each element through a function
+//! pointer, which the optimizer cannot see through, so the element
+//! decoders are checked on their own instead of through the iterator.
+//!
+//! `generated/` is checked in so the check builds without protoc. It is
+//! generated from `tests/protos/example.proto` and `tests/protos/pod.proto`
+//! with `ref_views`; after a generator change, refresh it with
+//! `pnpm generate:no-panic`.
+
+use std::hint::black_box;
+
+#[path = "../../rs/protobuf_runtime.rs"]
+pub mod protobuf_runtime;
+
+#[path = "generated/example/example.rs"]
+pub mod example;
+#[path = "generated/example/pod.rs"]
+pub mod pod;
+
+use example::{
+    Address, AddressRef, InstructionRef, SolanaAccountRef, Transaction, TransactionBatch, TransactionBatchRef,
+    TransactionRef, UserProfile, UserProfileRef,
+};
+use pod::{ReserveState, ReserveStateRef};
+use protobuf_runtime::*;
+
+/// Dropped only by unwinding: every check `mem::forget`s it on the way out.
+struct PanicGuard;
+
+impl Drop for PanicGuard {
+    fn drop(&mut self) {
+        extern "C" {
+            #[link_name = "\n\nERROR: a function may panic\n\n"]
+            fn may_panic() -> !;
+        }
+        unsafe { may_panic() }
+    }
+}
+
+/// Define each check as a function that cannot be inlined into `main`,
+/// and list them all in `CHECKS`.
+macro_rules! no_panic {
+    ($($(#[$doc:meta])* fn $name:ident($data:ident) $body:block)*) => {
+        $(
+            $(#[$doc])*
+            #[inline(never)]
+            fn $name($data: &mut [u8]) -> Result<u64, DecodeError> {
+                let guard = PanicGuard;
+                let result = (|| -> Result<u64, DecodeError> { $body })();
+                core::mem::forget(guard);
+                result
+            }
+        )*
+
+        const CHECKS: &[(&str, fn(&mut [u8]) -> Result<u64, DecodeError>)] = &[$((stringify!($name), $name)),*];
+    };
+}
+
+no_panic! {
+    /// Every varint and fixed-width decoder, each over the whole input.
+    fn scalars(data) {
+        let mut sum = 0u64;
+        let mut pos = 0;
+        while pos < data.len() {
+            let (v, next) = decode_varint(data, pos)?;
+            sum ^= v;
+            pos = next;
+        }
+        sum ^= decode_key(data, 0)?.0;
+        sum ^= decode_bool(data, 0)?.0 as u64;
+        sum ^= decode_int32(data, 0)?.0 as u64;
+        sum ^= decode_int64(data, 0)?.0 as u64;
+        sum ^= decode_zigzag32(data, 0)?.0 as u64;
+        sum ^= decode_zigzag64(data, 0)?.0 as u64;
+        sum ^= decode_sint32_field(data, 1, 8)?.0 as u64;
+        sum ^= decode_sint64_field(data, 1, 8)?.0 as u64;
+        sum ^= decode_fixed32(data, 0)?.0 as u64;
+        sum ^= decode_sfixed32(data, 0)?.0 as u64;
+        sum ^= decode_float(data, 0)?.0.to_bits() as u64;
+        sum ^= decode_fixed64(data, 0)?.0;
+        sum ^= decode_sfixed64(data, 0)?.0 as u64;
+        sum ^= decode_double(data, 0)?.0.to_bits();
+        Ok(sum)
+    }
+
+    /// The borrowing length-delimited decoders.
+    fn length_delimited(data) {
+        let mut sum = decode_bytes_ref(data, 0)?.1 as u64;
+        sum ^= decode_str_ref(data, 0)?.0.len() as u64;
+        sum ^= decode_message_slice(data, 0)?.0.len() as u64;
+        sum ^= decode_fixed_bytes_ref::<32>(data, 0)?.0[31] as u64;
+        sum ^= decode_fixed_bytes::<8>(data, 0)?.1 as u64;
+        sum ^= within_max_len(decode_bytes_ref(data, 0)?.0, 64, 1)?.len() as u64;
+        Ok(sum)
+    }
+
+    /// Skipping and reading raw fields, groups included.
+    fn fields(data) {
+        let mut sum = 0u64;
+        let mut pos = 0;
+        while pos < data.len() {
+            let (tag, next) = decode_key(data, pos)?;
+            let (value, end) = decode_field_value(data, next, tag)?;
+            if let FieldValue::LengthDelimited(v) | FieldValue::Group(v) = value {
+                sum ^= v.len() as u64;
+            }
+            pos = skip_field(data, next, tag)?;
+            sum ^= (end ^ pos) as u64;
+        }
+        sum ^= extract_nested(data, &[2, 3])?.is_some() as u64;
+        Ok(sum)
+    }
+
+    /// `Decoder`, into sub-messages, and `Reader`.
+    fn cursors(data) {
+        let mut sum = 0u64;
+        let mut decoder = Decoder::new(data);
+        while let Some(field) = decoder.next_field()? {
+            sum ^= field.number() as u64 ^ field.raw.len() as u64;
+            if let Ok(mut inner) = field.decoder() {
+                sum ^= inner.remaining() as u64;
+                sum ^= inner.next_field()?.map_or(0, |f| f.tag);
+            }
+        }
+        let mut resumed = Decoder::resume(data, decoder.position())?;
+        sum ^= resumed.next_field()?.is_some() as u64;
+
+        let mut reader = Reader::new(data);
+        while !reader.is_eof() {
+            let tag = reader.read_key()?;
+            reader.skip_field(tag)?;
+        }
+        Ok(sum ^ reader.remaining() as u64)
+    }
+
+    /// What generated decoders run per field besides the decoders: the
+    /// wire type, duplicate and required checks and nested options.
+    fn field_checks(data) {
+        const FIELDS: &[FieldWireType] = &[
+            FieldWireType { number: 1, wire_type: 0, packed: false, name: "Check.id" },
+            FieldWireType { number: 2, wire_type: 2, packed: false, name: "Check.name" },
+            FieldWireType { number: 3, wire_type: 0, packed: true, name: "Check.history" },
+        ];
+        let (tag, _) = decode_key(data, 0)?;
+        check_wire_type(tag, 0, FIELDS)?;
+        let mut seen = [0u64; 1];
+        mark_singular(&mut seen, (tag >> 3) as usize, &DecodeOptions::STRICT)?;
+        check_required(&seen, 1, 1)?;
+        Ok(DecodeOptions::STRICT.nested()?.max_depth as u64)
+    }
+
+    /// In-place updates of account data.
+    fn patches(data) {
+        patch_varint(data, &[1], 7)?;
+        patch_fixed64(data, &[2, 1], 7)?;
+        patch_fixed32(data, &[3], 7)?;
+        patch_bytes(data, &[2, 2], &[0; 4])?;
+        let len = data.len() / 2;
+        Ok(splice_field_in(data, len, &[2, 1], &[1, 2, 3])? as u64)
+    }
+
+    /// Framed messages and account headers.
+    fn framing(data) {
+        let mut sum = 0u64;
+        for frame in DelimitedFrames::new(data) {
+            sum ^= frame?.len() as u64;
+        }
+        sum ^= account_payload(data)?.len() as u64;
+        sum ^= strip_discriminator(&[1, 2], data)?.len() as u64;
+        sum ^= split_version(data)?.0 as u64;
+        Ok(sum)
+    }
+
+    fn user_profile_view(data) {
+        let view = UserProfileRef::decode(data)?;
+        let mut sum = view.id ^ view.name.len() as u64 ^ view.avatar_hash.len() as u64;
+        if let Some(address) = view.address {
+            sum ^= address.floor as u64;
+        }
+        for tag in view.tags {
+            sum ^= tag?.len() as u64;
+        }
+        Ok(sum)
+    }
+
+    fn address_view(data) {
+        Ok(AddressRef::decode(data)?.zip.len() as u64)
+    }
+
+    fn solana_account_view(data) {
+        Ok(SolanaAccountRef::decode(data)?.lamports)
+    }
+
+    fn transaction_batch_view(data) {
+        let view = TransactionBatchRef::decode(data)?;
+        Ok(view.chain_id ^ view.checksum as u64 ^ view.txns.is_empty() as u64)
+    }
+
+    /// The element decoder of `TransactionBatchRef::txns`.
+    fn transaction_view(data) {
+        let (body, _) = decode_message_slice(data, 0)?;
+        Ok(TransactionRef::decode(body)?.value)
+    }
+
+    fn instruction_view(data) {
+        Ok(InstructionRef::decode(data)?.nonce)
+    }
+
+    fn reserve_state_view(data) {
+        Ok(ReserveStateRef::decode(data)?.balance)
+    }
+
+    /// An owned message of fixed-width fields allocates nothing to decode.
+    fn reserve_state(data) {
+        let mut state = ReserveState::decode_with(data, &DecodeOptions::STRICT)?;
+        state.merge(data)?;
+        Ok(state.balance ^ state.bump as u64)
+    }
+}
+
+fn main() {
+    let batch = TransactionBatch {
+        chain_id: 101,
+        txns: vec![Transaction { to: vec![1; 32], data: vec![2; 8], value: 3, gas_limit: 4, nonce: 5 }],
+        checksum: 6,
+    };
+    let profile = UserProfile {
+        id: 42,
+        name: "validator".to_string(),
+        address: Address { city: "Lisbon".to_string(), floor: -2, ..Default::default() },
+        tags: vec!["staking".to_string()],
+        ..Default::default()
+    };
+    let state = ReserveState { balance: 1_000, bump: 255, ..Default::default() };
+    let inputs = [batch.encode(), profile.encode(), state.encode(), vec![0xFF; 11], Vec::new()];
+
+    // Failing on these inputs is fine; the check is that the binary links
+    for (name, check) in CHECKS {
+        for input in &inputs {
+            let result = check(&mut input.clone());
+            let _ = black_box((name, result));
+        }
+    }
+    println!("{} decode paths cannot panic", CHECKS.len());
+}
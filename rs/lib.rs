@@ -0,0 +1,33 @@
+//! The protoc-gen-solana runtime as a crate, for generated code built
+//! with `--solana_opt=runtime_path=protoc_gen_solana_runtime`.
+//!
+//! `protobuf_runtime.rs` is the same file the plugin emits alongside
+//! generated codecs; this crate only adds the feature gates. Enable the
+//! same `borsh`, `serde` and `json` features here as in the crate holding
+//! the generated code.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("protoc-gen-solana-runtime needs the `alloc` feature");
+
+mod protobuf_runtime;
+
+pub use protobuf_runtime::*;
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+#[cfg(feature = "client")]
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Rpc(err) => Some(err.as_ref()),
+            FetchError::Decode { error, .. } => Some(error),
+            FetchError::AccountNotFound(_) => None,
+        }
+    }
+}
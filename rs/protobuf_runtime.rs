@@ -4,7 +4,17 @@
 // Optimized for Solana's compute budget: minimal allocations,
 // no unnecessary copies, and efficient varint handling.
 
-use std::fmt;
+// Only `core` and `alloc` are used, so the module builds unchanged in a
+// `#![no_std]` crate that links `alloc`.
+extern crate alloc;
+
+use alloc::borrow::Cow;
+#[cfg(feature = "error_context")]
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
 
 // ── Error type ───────────────────────────────────────────────────────
 
@@ -14,6 +24,32 @@ pub enum DecodeError {
     InvalidVarint,
     UnknownWireType(u64),
     InvalidData(&'static str),
+    BufferTooSmall,
+    /// Input over `max_message_len`, or a field over `max_alloc` or
+    /// `max_repeated_len`. The TS codecs throw it as `SizeLimitExceeded` too.
+    SizeLimitExceeded,
+    RecursionLimitExceeded,
+    WireTypeMismatch { expected: u64, found: u64 },
+    /// A length prefix too large to address, whose end would overflow `usize`.
+    LengthOverflow,
+    /// Field `field` is over its `(solana.max_len)` or `(solana.max_count)` bound.
+    LimitExceeded { field: u32 },
+    /// `(solana.required)` field `field` is absent from the input.
+    MissingField { field: u32 },
+    /// Versioned data written at a `(solana.version)` with no migration.
+    UnknownVersion { version: u8 },
+    /// A field key whose number is 0 or above `MAX_FIELD_NUMBER`, which no
+    /// schema can declare.
+    InvalidFieldNumber(u64),
+    /// Under `reject_wire_type_mismatch`: the key at byte `offset` of the
+    /// message being decoded names field `field` (`name`, as
+    /// `"Message.field"`) with wire type `found`, where its declared type
+    /// is encoded as `expected`.
+    FieldWireTypeMismatch { name: &'static str, field: u32, expected: u64, found: u64, offset: usize },
+    /// With the `error_context` feature, any error a generated decoder
+    /// raised inside a field, with where that field is.
+    #[cfg(feature = "error_context")]
+    InField(Box<ErrorContext>),
 }
 
 impl fmt::Display for DecodeError {
@@ -23,330 +59,10152 @@ impl fmt::Display for DecodeError {
             DecodeError::InvalidVarint => write!(f, "protobuf: invalid varint"),
             DecodeError::UnknownWireType(wt) => write!(f, "protobuf: unknown wire type {}", wt),
             DecodeError::InvalidData(msg) => write!(f, "protobuf: {}", msg),
+            DecodeError::BufferTooSmall => write!(f, "protobuf: output buffer too small"),
+            DecodeError::SizeLimitExceeded => write!(f, "protobuf: decode size limit exceeded"),
+            DecodeError::RecursionLimitExceeded => {
+                write!(f, "protobuf: message nesting exceeds the recursion limit")
+            }
+            DecodeError::WireTypeMismatch { expected, found } => {
+                write!(f, "protobuf: expected wire type {} but found {}", expected, found)
+            }
+            DecodeError::LengthOverflow => write!(f, "protobuf: length prefix overflows"),
+            DecodeError::LimitExceeded { field } => {
+                write!(f, "protobuf: field {} exceeds its declared bound", field)
+            }
+            DecodeError::MissingField { field } => write!(f, "protobuf: required field {} is missing", field),
+            DecodeError::UnknownVersion { version } => write!(f, "protobuf: no migration from version {}", version),
+            DecodeError::InvalidFieldNumber(number) => write!(f, "protobuf: invalid field number {}", number),
+            DecodeError::FieldWireTypeMismatch { name, field, expected, found, offset } => write!(
+                f,
+                "protobuf: field {} ({}) at byte {} has wire type {}, expected {}",
+                name, field, offset, found, expected
+            ),
+            #[cfg(feature = "error_context")]
+            DecodeError::InField(ctx) => {
+                write!(f, "{} at byte {}", ctx.error, ctx.offset)?;
+                if !ctx.path.is_empty() {
+                    write!(f, " in {}", ctx.field_path())?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
-// ── Key (tag) encode / decode ────────────────────────────────────────
-
-#[inline]
-pub fn encode_key(buf: &mut Vec<u8>, tag: u64) {
-    encode_varint(buf, tag);
-}
-
-#[inline]
-pub fn decode_key(data: &[u8], pos: usize) -> Result<(u64, usize), DecodeError> {
-    decode_varint(data, pos)
+/// Coarse grouping of `DecodeError` variants for callers that only need
+/// to pick between aborting and skipping or retrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+    /// The bytes are not valid protobuf for the expected message.
+    Malformed,
+    /// Well-formed input this decoder declines: an unknown wire type or a
+    /// field over the configured `DecodeOptions` limits or its schema bound.
+    Unsupported,
+    /// Input ended early, or an output buffer ran out of room.
+    Truncated,
 }
 
-// ── Wire Type 0: Varint ──────────────────────────────────────────────
-
-#[inline]
-pub fn encode_varint(buf: &mut Vec<u8>, mut value: u64) {
-    loop {
-        if value < 0x80 {
-            buf.push(value as u8);
-            return;
+impl DecodeError {
+    pub fn kind(&self) -> DecodeErrorKind {
+        match self {
+            DecodeError::BufferOverflow | DecodeError::BufferTooSmall => DecodeErrorKind::Truncated,
+            DecodeError::InvalidVarint
+            | DecodeError::InvalidData(_)
+            | DecodeError::WireTypeMismatch { .. }
+            | DecodeError::LengthOverflow
+            | DecodeError::MissingField { .. }
+            | DecodeError::InvalidFieldNumber(_)
+            | DecodeError::FieldWireTypeMismatch { .. } => DecodeErrorKind::Malformed,
+            DecodeError::UnknownWireType(_)
+            | DecodeError::SizeLimitExceeded
+            | DecodeError::RecursionLimitExceeded
+            | DecodeError::LimitExceeded { .. }
+            | DecodeError::UnknownVersion { .. } => DecodeErrorKind::Unsupported,
+            #[cfg(feature = "error_context")]
+            DecodeError::InField(ctx) => ctx.error.kind(),
         }
-        buf.push(((value & 0x7F) | 0x80) as u8);
-        value >>= 7;
     }
-}
 
-#[inline]
-pub fn decode_varint(data: &[u8], mut pos: usize) -> Result<(u64, usize), DecodeError> {
-    let mut result: u64 = 0;
-    let mut shift: u32 = 0;
-    loop {
-        if pos >= data.len() {
-            return Err(DecodeError::BufferOverflow);
+    /// True when the caller can get past the error without new input: an
+    /// unknown wire type can be skipped as an unknown field, and a full
+    /// output buffer can be retried with a larger one. Everything else means
+    /// the data cannot be decoded as-is.
+    pub fn is_recoverable(&self) -> bool {
+        match self {
+            DecodeError::UnknownWireType(_) | DecodeError::BufferTooSmall => true,
+            DecodeError::BufferOverflow
+            | DecodeError::InvalidVarint
+            | DecodeError::InvalidData(_)
+            | DecodeError::SizeLimitExceeded
+            | DecodeError::RecursionLimitExceeded
+            | DecodeError::WireTypeMismatch { .. }
+            | DecodeError::LengthOverflow
+            | DecodeError::LimitExceeded { .. }
+            | DecodeError::MissingField { .. }
+            | DecodeError::UnknownVersion { .. }
+            | DecodeError::InvalidFieldNumber(_)
+            | DecodeError::FieldWireTypeMismatch { .. } => false,
+            #[cfg(feature = "error_context")]
+            DecodeError::InField(ctx) => ctx.error.is_recoverable(),
         }
-        let b = data[pos];
-        pos += 1;
-        result |= ((b & 0x7F) as u64) << shift;
-        if b & 0x80 == 0 {
-            return Ok((result, pos));
+    }
+
+    /// The error itself, without the field context `error_context` adds,
+    /// for matching on the variant either way.
+    pub fn root(&self) -> &DecodeError {
+        match self {
+            #[cfg(feature = "error_context")]
+            DecodeError::InField(ctx) => &ctx.error,
+            err => err,
         }
-        shift += 7;
-        if shift > 63 {
-            return Err(DecodeError::InvalidVarint);
+    }
+
+    /// Where in the input a generated decoder raised the error, if known.
+    #[cfg(feature = "error_context")]
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            DecodeError::InField(ctx) => Some(ctx),
+            _ => None,
         }
     }
 }
 
-// ── Bool ─────────────────────────────────────────────────────────────
+/// One field of an `ErrorContext` path.
+#[cfg(feature = "error_context")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathSegment {
+    /// `"Message.field"`
+    pub name: &'static str,
+    /// The element of a repeated field being decoded; `None` for singular
+    /// fields, maps and packed runs.
+    pub index: Option<usize>,
+}
 
-#[inline]
-pub fn encode_bool(buf: &mut Vec<u8>, value: bool) {
-    buf.push(if value { 1 } else { 0 });
+/// Where the error in `DecodeError::InField` was raised.
+#[cfg(feature = "error_context")]
+#[derive(Debug, Clone)]
+pub struct ErrorContext {
+    pub error: DecodeError,
+    /// Byte offset, in the buffer passed to `decode`, of the key of the
+    /// innermost field the error was raised in.
+    pub offset: usize,
+    /// The fields leading to it, outermost first. Empty when it was raised
+    /// in a field the message does not declare.
+    pub path: Vec<PathSegment>,
 }
 
-#[inline]
-pub fn decode_bool(data: &[u8], pos: usize) -> Result<(bool, usize), DecodeError> {
-    let (v, new_pos) = decode_varint(data, pos)?;
-    Ok((v != 0, new_pos))
+#[cfg(feature = "error_context")]
+impl ErrorContext {
+    /// The path as one string, like `"OrderBook.orders[3].price"`.
+    pub fn field_path(&self) -> String {
+        let mut out = String::new();
+        for (i, segment) in self.path.iter().enumerate() {
+            match segment.name.split_once('.') {
+                Some((_, field)) if i > 0 => {
+                    out.push('.');
+                    out.push_str(field);
+                }
+                _ => out.push_str(segment.name),
+            }
+            if let Some(index) = segment.index {
+                out.push_str(&format!("[{}]", index));
+            }
+        }
+        out
+    }
 }
 
-// ── ZigZag (sint32/sint64) ───────────────────────────────────────────
+// ── Decode limits ────────────────────────────────────────────────────
 
-#[inline]
-pub fn encode_zigzag32(buf: &mut Vec<u8>, value: i32) {
-    let encoded = ((value << 1) ^ (value >> 31)) as u32;
-    encode_varint(buf, encoded as u64);
+/// Caps on what a decode may allocate for a single field, so a hostile
+/// length prefix or element count fails fast instead of exhausting the heap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeOptions {
+    /// Largest `bytes`/`string` payload a single field may allocate.
+    pub max_alloc: usize,
+    /// Most elements a single repeated or map field may hold.
+    pub max_repeated_len: usize,
+    /// Largest buffer a top-level decode accepts; longer input fails with
+    /// `SizeLimitExceeded` before any field is parsed.
+    pub max_message_len: usize,
+    /// Reject a singular field that appears more than once in one buffer
+    /// instead of keeping the last value.
+    pub reject_duplicate_fields: bool,
+    /// Most levels of sub-messages below the message being decoded, so a
+    /// recursive schema cannot overflow the BPF call stack.
+    pub max_depth: usize,
+    /// Fail with `FieldWireTypeMismatch` for a known field number with a
+    /// wire type its type cannot have, instead of skipping it as an
+    /// unknown field.
+    pub reject_wire_type_mismatch: bool,
 }
 
-#[inline]
-pub fn decode_zigzag32(data: &[u8], pos: usize) -> Result<(i32, usize), DecodeError> {
-    let (raw, new_pos) = decode_varint(data, pos)?;
-    let n = raw as u32;
-    let value = ((n >> 1) as i32) ^ (-((n & 1) as i32));
-    Ok((value, new_pos))
-}
+impl DecodeOptions {
+    /// 1232 bytes (a whole Solana transaction, so more than any
+    /// instruction's data) per message, 10 MiB (the largest Solana account)
+    /// per field, 2^20 elements per field and 32 levels of nesting.
+    pub const DEFAULT: DecodeOptions = DecodeOptions {
+        max_alloc: 10 * 1024 * 1024,
+        max_repeated_len: 1 << 20,
+        max_message_len: 1232,
+        reject_duplicate_fields: false,
+        max_depth: 32,
+        reject_wire_type_mismatch: false,
+    };
 
-#[inline]
-pub fn encode_zigzag64(buf: &mut Vec<u8>, value: i64) {
-    let encoded = ((value << 1) ^ (value >> 63)) as u64;
-    encode_varint(buf, encoded);
+    /// `DEFAULT` with duplicate singular fields and mismatched wire types
+    /// rejected.
+    pub const STRICT: DecodeOptions = DecodeOptions {
+        reject_duplicate_fields: true,
+        reject_wire_type_mismatch: true,
+        ..DecodeOptions::DEFAULT
+    };
+
+    /// `DEFAULT` with messages up to 10 MiB, for account data, which is
+    /// not bounded by a transaction's size.
+    pub const ACCOUNT: DecodeOptions = DecodeOptions { max_message_len: 10 * 1024 * 1024, ..DecodeOptions::DEFAULT };
+
+    /// The options for decoding a sub-message one level down, or
+    /// `RecursionLimitExceeded` if `max_depth` levels are already open.
+    #[inline]
+    pub fn nested(&self) -> Result<DecodeOptions, DecodeError> {
+        if self.max_depth == 0 {
+            return Err(DecodeError::RecursionLimitExceeded);
+        }
+        Ok(DecodeOptions { max_depth: self.max_depth - 1, ..*self })
+    }
 }
 
-#[inline]
-pub fn decode_zigzag64(data: &[u8], pos: usize) -> Result<(i64, usize), DecodeError> {
-    let (raw, new_pos) = decode_varint(data, pos)?;
-    let value = ((raw >> 1) as i64) ^ (-((raw & 1) as i64));
-    Ok((value, new_pos))
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
 }
 
-// ── Wire Type 1: 64-bit (little-endian) ─────────────────────────────
+const FIELD_INDEX_OUT_OF_RANGE: DecodeError = DecodeError::InvalidData("singular field index out of range");
 
+/// Record that the singular field with generated index `index` was seen.
+/// Generated decoders keep one bit per singular field in `seen`; under
+/// `reject_duplicate_fields` a second occurrence is an error.
 #[inline]
-pub fn encode_fixed64(buf: &mut Vec<u8>, value: u64) {
-    buf.extend_from_slice(&value.to_le_bytes());
+pub fn mark_singular(seen: &mut [u64], index: usize, opts: &DecodeOptions) -> Result<(), DecodeError> {
+    let word = seen.get_mut(index / 64).ok_or(FIELD_INDEX_OUT_OF_RANGE)?;
+    let bit = 1u64 << (index % 64);
+    if opts.reject_duplicate_fields && *word & bit != 0 {
+        return Err(DecodeError::InvalidData("duplicate singular field"));
+    }
+    *word |= bit;
+    Ok(())
 }
 
+/// Fail with `MissingField` for field number `field` unless the singular
+/// field with generated index `index` was marked in `seen`: the check
+/// generated decoders run for each `(solana.required)` field.
 #[inline]
-pub fn decode_fixed64(data: &[u8], pos: usize) -> Result<(u64, usize), DecodeError> {
-    if pos + 8 > data.len() {
-        return Err(DecodeError::BufferOverflow);
+pub fn check_required(seen: &[u64], index: usize, field: u32) -> Result<(), DecodeError> {
+    let word = seen.get(index / 64).ok_or(FIELD_INDEX_OUT_OF_RANGE)?;
+    if word & (1u64 << (index % 64)) == 0 {
+        return Err(DecodeError::MissingField { field });
     }
-    let value = u64::from_le_bytes(data[pos..pos + 8].try_into().unwrap());
-    Ok((value, pos + 8))
+    Ok(())
 }
 
-#[inline]
-pub fn encode_sfixed64(buf: &mut Vec<u8>, value: i64) {
-    encode_fixed64(buf, value as u64);
+/// A field of a generated message, for `check_wire_type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldWireType {
+    pub number: u32,
+    /// Wire type of one value of the field.
+    pub wire_type: u64,
+    /// Set for packable repeated scalars, which also take a packed run.
+    pub packed: bool,
+    /// `"Message.field"`
+    pub name: &'static str,
 }
 
-#[inline]
-pub fn decode_sfixed64(data: &[u8], pos: usize) -> Result<(i64, usize), DecodeError> {
-    let (raw, new_pos) = decode_fixed64(data, pos)?;
-    Ok((raw as i64, new_pos))
+/// Fail with `FieldWireTypeMismatch` if `tag`, read at byte `offset`,
+/// names one of `fields` with a wire type the field cannot have. The
+/// check generated decoders run under `reject_wire_type_mismatch` for
+/// keys no decode arm matched.
+pub fn check_wire_type(tag: u64, offset: usize, fields: &[FieldWireType]) -> Result<(), DecodeError> {
+    let found = tag & 0x07;
+    match fields.iter().find(|f| f.number as u64 == tag >> 3) {
+        Some(f) if found != f.wire_type && !(f.packed && found == 2) => Err(DecodeError::FieldWireTypeMismatch {
+            name: f.name,
+            field: f.number,
+            expected: f.wire_type,
+            found,
+            offset,
+        }),
+        _ => Ok(()),
+    }
 }
 
-// ── Wire Type 5: 32-bit (little-endian) ─────────────────────────────
-
-#[inline]
-pub fn encode_fixed32(buf: &mut Vec<u8>, value: u32) {
-    buf.extend_from_slice(&value.to_le_bytes());
+/// Attach the field whose key is at byte `field_start` of `data` to an
+/// error raised while decoding it, for generated decoders' error path.
+/// `index` is the element a repeated field was decoding. Without the
+/// `error_context` feature the error is returned unchanged.
+#[inline(always)]
+pub fn field_error(
+    err: DecodeError,
+    data: &[u8],
+    field_start: usize,
+    tag: u64,
+    fields: &[FieldWireType],
+    index: Option<usize>,
+) -> DecodeError {
+    #[cfg(feature = "error_context")]
+    {
+        let segment = fields.iter().find(|f| f.number as u64 == tag >> 3).map(|f| PathSegment {
+            name: f.name,
+            index: index.filter(|_| !(f.packed && tag & 0x07 == 2)),
+        });
+        match err {
+            DecodeError::InField(mut ctx) => {
+                // Raised in a sub-message, whose offsets count from its payload
+                let payload = decode_key(data, field_start)
+                    .and_then(|(_, pos)| decode_varint(data, pos))
+                    .map_or(field_start, |(_, pos)| pos);
+                ctx.offset += payload;
+                ctx.path.splice(0..0, segment);
+                DecodeError::InField(ctx)
+            }
+            err => DecodeError::InField(Box::new(ErrorContext {
+                error: err,
+                offset: field_start,
+                path: segment.into_iter().collect(),
+            })),
+        }
+    }
+    #[cfg(not(feature = "error_context"))]
+    {
+        let _ = (data, field_start, tag, fields, index);
+        err
+    }
 }
 
-#[inline]
-pub fn decode_fixed32(data: &[u8], pos: usize) -> Result<(u32, usize), DecodeError> {
-    if pos + 4 > data.len() {
-        return Err(DecodeError::BufferOverflow);
+/// Report a field a generated decoder has read from `message`: its number
+/// and wire type, and its size, from its key at `start` to the end of its
+/// value at `end`, or the `error` it failed with. With the `trace` feature
+/// this logs with `msg!` given `solana-program`, as on chain, and with
+/// `log::trace!` otherwise; without it, it does nothing.
+#[inline(always)]
+pub fn trace_field(message: &str, tag: u64, start: usize, end: usize, error: Option<&DecodeError>) {
+    #[cfg(feature = "trace")]
+    let (number, wire_type, len) = (tag >> 3, tag & 0x07, end.saturating_sub(start));
+    #[cfg(all(feature = "trace", feature = "solana-program"))]
+    match error {
+        None => solana_program::msg!("{} field {} wire type {}: {} bytes", message, number, wire_type, len),
+        Some(err) => solana_program::msg!("{} field {} wire type {}: {}", message, number, wire_type, err),
+    }
+    #[cfg(all(feature = "trace", not(feature = "solana-program")))]
+    match error {
+        None => log::trace!(target: "protobuf", "{} field {} wire type {}: {} bytes", message, number, wire_type, len),
+        Some(err) => log::trace!(target: "protobuf", "{} field {} wire type {}: {}", message, number, wire_type, err),
     }
-    let value = u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap());
-    Ok((value, pos + 4))
+    #[cfg(not(feature = "trace"))]
+    let _ = (message, tag, start, end, error);
 }
 
-#[inline]
-pub fn encode_sfixed32(buf: &mut Vec<u8>, value: i32) {
-    encode_fixed32(buf, value as u32);
-}
+// ── Unknown fields ───────────────────────────────────────────────────
 
-#[inline]
-pub fn decode_sfixed32(data: &[u8], pos: usize) -> Result<(i32, usize), DecodeError> {
-    let (raw, new_pos) = decode_fixed32(data, pos)?;
-    Ok((raw as i32, new_pos))
+/// Fields a decoder did not recognise, kept as their encoded key and value
+/// so that re-encoding replays them unchanged. Generated with the
+/// `unknown_fields` option; they are written after the known fields.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnknownFields {
+    #[cfg_attr(feature = "serde", serde(with = "serde_base64"))]
+    raw: Vec<u8>,
 }
 
-// ── Wire Type 2: Length-delimited ────────────────────────────────────
+impl UnknownFields {
+    /// No captured fields; `const`, for generated `DEFAULT`s.
+    pub const fn new() -> Self {
+        UnknownFields { raw: Vec::new() }
+    }
 
-#[inline]
-pub fn encode_bytes(buf: &mut Vec<u8>, value: &[u8]) {
-    encode_varint(buf, value.len() as u64);
-    buf.extend_from_slice(value);
-}
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
 
-#[inline]
-pub fn decode_bytes(data: &[u8], pos: usize) -> Result<(Vec<u8>, usize), DecodeError> {
-    let (len, pos) = decode_varint(data, pos)?;
-    let len = len as usize;
-    if pos + len > data.len() {
-        return Err(DecodeError::BufferOverflow);
+    /// Encoded size of all captured fields.
+    pub fn encoded_len(&self) -> usize {
+        self.raw.len()
     }
-    Ok((data[pos..pos + len].to_vec(), pos + len))
-}
 
-#[inline]
-pub fn encode_string(buf: &mut Vec<u8>, value: &str) {
-    encode_bytes(buf, value.as_bytes());
-}
+    pub fn clear(&mut self) {
+        self.raw.clear();
+    }
 
-#[inline]
-pub fn decode_string(data: &[u8], pos: usize) -> Result<(String, usize), DecodeError> {
-    let (raw, new_pos) = decode_bytes(data, pos)?;
-    String::from_utf8(raw)
-        .map(|s| (s, new_pos))
-        .map_err(|_| DecodeError::InvalidData("invalid UTF-8 in string field"))
-}
+    /// Append a field under `tag`; `value` is its wire form after the key
+    /// (e.g. the length prefix and payload for wire type 2).
+    pub fn push(&mut self, tag: u64, value: &[u8]) {
+        encode_key(&mut self.raw, tag);
+        self.raw.extend_from_slice(value);
+    }
 
-// ── Skip unknown fields ──────────────────────────────────────────────
+    /// Append one complete encoded field, key included, as a decoder
+    /// skipped it.
+    pub fn push_raw(&mut self, field: &[u8]) {
+        self.raw.extend_from_slice(field);
+    }
 
-#[inline]
-pub fn skip_field(data: &[u8], pos: usize, wire_type: u64) -> Result<usize, DecodeError> {
-    match wire_type {
-        0 => {
-            // Varint: skip until MSB is clear
-            let (_, new_pos) = decode_varint(data, pos)?;
-            Ok(new_pos)
-        }
-        1 => {
-            // 64-bit: skip 8 bytes
-            if pos + 8 > data.len() {
-                return Err(DecodeError::BufferOverflow);
-            }
-            Ok(pos + 8)
-        }
-        2 => {
-            // Length-delimited: read length, skip that many bytes
-            let (len, new_pos) = decode_varint(data, pos)?;
-            let end = new_pos + len as usize;
-            if end > data.len() {
-                return Err(DecodeError::BufferOverflow);
-            }
-            Ok(end)
-        }
-        5 => {
-            // 32-bit: skip 4 bytes
-            if pos + 4 > data.len() {
-                return Err(DecodeError::BufferOverflow);
+    /// The captured `(tag, value)` pairs in wire order.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &[u8])> + '_ {
+        let mut pos = 0;
+        core::iter::from_fn(move || {
+            if pos >= self.raw.len() {
+                return None;
             }
-            Ok(pos + 4)
-        }
-        _ => Err(DecodeError::UnknownWireType(wire_type)),
+            let (tag, start) = decode_key(&self.raw, pos).ok()?;
+            pos = skip_field(&self.raw, start, tag).ok()?;
+            Some((tag, &self.raw[start..pos]))
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.raw
+    }
+
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.raw);
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        w.write_raw(&self.raw)?;
+        Ok(())
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        w.write_raw(&self.raw);
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// ── Extensions ───────────────────────────────────────────────────────
 
-    #[test]
-    fn test_varint_roundtrip() {
-        for &val in &[0u64, 1, 127, 128, 255, 300, 16384, u64::MAX] {
-            let mut buf = Vec::new();
-            encode_varint(&mut buf, val);
-            let (decoded, pos) = decode_varint(&buf, 0).unwrap();
-            assert_eq!(decoded, val);
-            assert_eq!(pos, buf.len());
-        }
+/// The fields in a proto2 message's `extensions` ranges, kept as their
+/// encoded key and value, like `UnknownFields`, and read and written
+/// through typed `Extension`s. Generated for every message declaring an
+/// extension range; they are written after the declared fields.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtensionSet {
+    #[cfg_attr(feature = "serde", serde(with = "serde_base64"))]
+    raw: Vec<u8>,
+}
+
+impl ExtensionSet {
+    /// No extension fields; `const`, for generated `DEFAULT`s.
+    pub const fn new() -> Self {
+        ExtensionSet { raw: Vec::new() }
     }
 
-    #[test]
-    fn test_bool_roundtrip() {
-        for &val in &[true, false] {
-            let mut buf = Vec::new();
-            encode_bool(&mut buf, val);
-            let (decoded, _) = decode_bool(&buf, 0).unwrap();
-            assert_eq!(decoded, val);
-        }
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
     }
 
-    #[test]
-    fn test_zigzag32_roundtrip() {
-        for &val in &[0i32, 1, -1, 2, -2, i32::MAX, i32::MIN] {
-            let mut buf = Vec::new();
-            encode_zigzag32(&mut buf, val);
-            let (decoded, _) = decode_zigzag32(&buf, 0).unwrap();
-            assert_eq!(decoded, val);
-        }
+    /// Encoded size of all extension fields.
+    pub fn encoded_len(&self) -> usize {
+        self.raw.len()
     }
 
-    #[test]
-    fn test_zigzag64_roundtrip() {
-        for &val in &[0i64, 1, -1, 2, -2, i64::MAX, i64::MIN] {
-            let mut buf = Vec::new();
-            encode_zigzag64(&mut buf, val);
-            let (decoded, _) = decode_zigzag64(&buf, 0).unwrap();
-            assert_eq!(decoded, val);
-        }
+    pub fn clear(&mut self) {
+        self.raw.clear();
     }
 
-    #[test]
-    fn test_fixed64_roundtrip() {
-        for &val in &[0u64, 1, 0xDEAD_BEEF, u64::MAX] {
-            let mut buf = Vec::new();
-            encode_fixed64(&mut buf, val);
-            let (decoded, _) = decode_fixed64(&buf, 0).unwrap();
-            assert_eq!(decoded, val);
-        }
+    /// Append one complete encoded field, key included, as a decoder
+    /// found it.
+    pub fn push_raw(&mut self, field: &[u8]) {
+        self.raw.extend_from_slice(field);
     }
 
-    #[test]
-    fn test_fixed32_roundtrip() {
-        for &val in &[0u32, 1, 0xDEAD, u32::MAX] {
-            let mut buf = Vec::new();
-            encode_fixed32(&mut buf, val);
-            let (decoded, _) = decode_fixed32(&buf, 0).unwrap();
-            assert_eq!(decoded, val);
-        }
+    /// The `(tag, value)` pairs in wire order, to name with an
+    /// `ExtensionRegistry`.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &[u8])> + '_ {
+        self.spans().map(move |(tag, _, start, end)| (tag, &self.raw[start..end]))
     }
 
-    #[test]
-    fn test_string_roundtrip() {
-        for val in &["", "hello", "hello world 🌍"] {
-            let mut buf = Vec::new();
-            encode_string(&mut buf, val);
-            let (decoded, _) = decode_string(&buf, 0).unwrap();
-            assert_eq!(&decoded, val);
+    /// Whether field `number` occurs.
+    pub fn contains(&self, number: u32) -> bool {
+        self.spans().any(|(tag, ..)| tag >> 3 == number as u64)
+    }
+
+    /// The value of `ext`, or `None` if it does not occur: the last
+    /// occurrence of a singular scalar, every occurrence merged for a
+    /// message, and every element in order for a repeated extension.
+    pub fn get<M, T: Default>(&self, ext: &Extension<M, T>) -> Result<Option<T>, DecodeError> {
+        let info = &ext.info;
+        let wire_type = [FieldWireType { number: info.number, wire_type: info.wire_type, packed: info.packed, name: info.name }];
+        let mut value = None;
+        let mut pos = 0;
+        while pos < self.raw.len() {
+            let field_start = pos;
+            let (tag, start) = decode_key(&self.raw, pos)?;
+            pos = skip_field(&self.raw, start, tag)?;
+            if tag >> 3 != info.number as u64 {
+                continue;
+            }
+            check_wire_type(tag, field_start, &wire_type)?;
+            (ext.merge)(value.get_or_insert_with(T::default), &self.raw, start, tag)?;
         }
+        Ok(value)
     }
 
-    #[test]
-    fn test_bytes_roundtrip() {
-        for val in &[vec![], vec![1u8, 2, 3], vec![0xFF; 300]] {
-            let mut buf = Vec::new();
-            encode_bytes(&mut buf, val);
-            let (decoded, _) = decode_bytes(&buf, 0).unwrap();
-            assert_eq!(&decoded, val);
+    /// Replace any occurrence of `ext` with `value`.
+    pub fn set<M, T>(&mut self, ext: &Extension<M, T>, value: &T) {
+        self.remove(ext.info.number);
+        (ext.encode)(value, &mut self.raw);
+    }
+
+    /// Drop every occurrence of field `number`.
+    pub fn remove(&mut self, number: u32) {
+        let mut kept = Vec::with_capacity(self.raw.len());
+        for (tag, field_start, _, end) in self.spans() {
+            if tag >> 3 != number as u64 {
+                kept.extend_from_slice(&self.raw[field_start..end]);
+            }
         }
+        self.raw = kept;
     }
 
-    #[test]
-    fn test_skip_field() {
-        // Varint
-        let mut buf = Vec::new();
-        encode_varint(&mut buf, 300);
-        let new_pos = skip_field(&buf, 0, 0).unwrap();
-        assert_eq!(new_pos, buf.len());
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.raw
+    }
 
-        // Fixed64
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.raw);
+    }
+
+    pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        w.write_raw(&self.raw)?;
+        Ok(())
+    }
+
+    pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        w.write_raw(&self.raw);
+    }
+
+    /// `(tag, key start, value start, end)` of each field.
+    fn spans(&self) -> impl Iterator<Item = (u64, usize, usize, usize)> + '_ {
+        let mut pos = 0;
+        core::iter::from_fn(move || {
+            if pos >= self.raw.len() {
+                return None;
+            }
+            let field_start = pos;
+            let (tag, start) = decode_key(&self.raw, pos).ok()?;
+            pos = skip_field(&self.raw, start, tag).ok()?;
+            Some((tag, field_start, start, pos))
+        })
+    }
+}
+
+/// Whether field `number` is in one of a message's `[start, end)`
+/// extension ranges, for generated decoders.
+#[inline]
+pub fn in_extension_range(number: u64, ranges: &[(u32, u32)]) -> bool {
+    ranges.iter().any(|&(start, end)| number >= start as u64 && number < end as u64)
+}
+
+/// What an `ExtensionRegistry` knows of one extension field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExtensionInfo {
+    /// Fully qualified name, as `"package.name"` or `"package.Message.name"`
+    pub name: &'static str,
+    /// `FULL_NAME` of the extended message
+    pub extendee: &'static str,
+    pub number: u32,
+    /// Wire type of one value of the field.
+    pub wire_type: u64,
+    pub repeated: bool,
+    /// Set for packable repeated scalars, which also take a packed run.
+    pub packed: bool,
+}
+
+/// A generated extension of message `M`, with a value of type `T`:
+/// `Vec<_>` for a repeated extension. Read and written through
+/// `Extendable`.
+pub struct Extension<M, T> {
+    pub info: ExtensionInfo,
+    /// Merge the value at `pos`, after key `tag`, into the first argument;
+    /// returns the position after it.
+    merge: fn(&mut T, &[u8], usize, u64) -> Result<usize, DecodeError>,
+    /// Append the value with its key(s).
+    encode: fn(&T, &mut Vec<u8>),
+    extendee: core::marker::PhantomData<fn() -> M>,
+}
+
+impl<M, T> Extension<M, T> {
+    pub const fn new(
+        info: ExtensionInfo,
+        merge: fn(&mut T, &[u8], usize, u64) -> Result<usize, DecodeError>,
+        encode: fn(&T, &mut Vec<u8>),
+    ) -> Self {
+        Extension { info, merge, encode, extendee: core::marker::PhantomData }
+    }
+}
+
+/// Implemented by generated messages that declare extension ranges.
+pub trait Extendable: Message {
+    /// The `[start, end)` field number ranges declared for extensions.
+    const EXTENSION_RANGES: &'static [(u32, u32)];
+
+    fn extensions(&self) -> &ExtensionSet;
+
+    fn extensions_mut(&mut self) -> &mut ExtensionSet;
+
+    fn has_extension<T>(&self, ext: &Extension<Self, T>) -> bool {
+        self.extensions().contains(ext.info.number)
+    }
+
+    /// The value of `ext`, or `None` if it is not set; see `ExtensionSet::get`.
+    fn extension<T: Default>(&self, ext: &Extension<Self, T>) -> Result<Option<T>, DecodeError> {
+        self.extensions().get(ext)
+    }
+
+    fn set_extension<T>(&mut self, ext: &Extension<Self, T>, value: T) {
+        self.extensions_mut().set(ext, &value);
+    }
+
+    fn clear_extension<T>(&mut self, ext: &Extension<Self, T>) {
+        self.extensions_mut().remove(ext.info.number);
+    }
+}
+
+/// The extensions a program knows, gathered from the `EXTENSIONS` list
+/// each generated file with `extend` blocks declares, to look up the
+/// fields of an `ExtensionSet` by extendee and number or by name.
+#[derive(Clone, Debug, Default)]
+pub struct ExtensionRegistry {
+    infos: Vec<ExtensionInfo>,
+}
+
+impl ExtensionRegistry {
+    pub const fn new() -> Self {
+        ExtensionRegistry { infos: Vec::new() }
+    }
+
+    /// Add one file's `EXTENSIONS`.
+    pub fn register(&mut self, infos: &[ExtensionInfo]) -> &mut Self {
+        self.infos.extend_from_slice(infos);
+        self
+    }
+
+    /// Extension `number` of the message named `extendee`.
+    pub fn find(&self, extendee: &str, number: u32) -> Option<&ExtensionInfo> {
+        self.infos.iter().find(|info| info.extendee == extendee && info.number == number)
+    }
+
+    pub fn find_by_name(&self, name: &str) -> Option<&ExtensionInfo> {
+        self.infos.iter().find(|info| info.name == name)
+    }
+
+    /// Every registered extension of the message named `extendee`.
+    pub fn extensions_of<'a>(&'a self, extendee: &'a str) -> impl Iterator<Item = &'a ExtensionInfo> + 'a {
+        self.infos.iter().filter(move |info| info.extendee == extendee)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ExtensionInfo> + '_ {
+        self.infos.iter()
+    }
+}
+
+// ── Message trait ────────────────────────────────────────────────────
+
+/// Implemented by every generated message.
+pub trait Message: Default {
+    /// Append the encoded message to `buf`. Existing contents are kept, so
+    /// several messages can be written back to back; call `buf.clear()`
+    /// first to reuse one allocation across iterations.
+    fn encode_into(&self, buf: &mut Vec<u8>);
+
+    /// Exact number of bytes `encode_into` appends, for sizing a buffer
+    /// (or checking an account's space) up front.
+    fn encoded_len(&self) -> usize;
+
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.encoded_len());
+        self.encode_into(&mut buf);
+        buf
+    }
+
+    /// Byte-stable encoding for hashing and signature checks: fields in
+    /// ascending tag order, map entries sorted by key, scalar, enum,
+    /// string and bytes fields at their default omitted and every varint
+    /// in its shortest form. Generated encoders always write this form,
+    /// apart from preserved unknown fields, which it leaves out.
+    fn encode_canonical(&self) -> Vec<u8> {
+        self.encode()
+    }
+
+    /// Drop any preserved unknown fields, here and in every sub-message.
+    fn clear_unknown_fields(&mut self) {}
+
+    /// Write the encoded message through `w`, e.g. into account data.
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError>;
+
+    /// Write the encoded message in front of what `w` holds, last field
+    /// first; generated encoders are built on it. The default encodes
+    /// into a scratch buffer, for hand-written impls.
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        let mut scratch = Vec::with_capacity(self.encoded_len());
+        self.encode_into(&mut scratch);
+        w.write_raw(&scratch);
+    }
+
+    /// Encode into the start of `buf` and return the number of bytes
+    /// written. Fails with `BufferTooSmall`, leaving `buf` untouched,
+    /// if the message does not fit.
+    fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)?;
+        Ok(w.position())
+    }
+
+    /// `encoded_len`, recording the size of every sub-message in `sizes`
+    /// on the way, in the order `write_to_sized` reads them back. The
+    /// default records none, for messages without sub-messages.
+    fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+        self.encoded_len()
+    }
+
+    /// `write_to` with each sub-message length taken from `sizes` rather
+    /// than recomputed. Fails with `InvalidData` if a length does not
+    /// match what is written, i.e. the message changed since the cache
+    /// was made.
+    fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+        self.write_to(w)
+    }
+
+    /// `encode`, with the buffer sized from a `SizeCache` of this message.
+    fn encode_with_sizes(&self, sizes: &SizeCache) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, sizes.encoded_len()));
+        buf
+    }
+
+    /// `encode_to_slice` from a `SizeCache` of this message, with no
+    /// sizing pass. A cache whose lengths no longer match the message
+    /// fails with `InvalidData`, and may leave part of it written.
+    fn encode_to_slice_with_sizes(&self, buf: &mut [u8], sizes: &SizeCache) -> Result<usize, DecodeError> {
+        if sizes.encoded_len() > buf.len() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        let mut w = SliceWriter::new(buf);
+        self.write_to_sized(&mut w, &mut sizes.cursor())?;
+        if w.position() != sizes.encoded_len() {
+            return Err(STALE_SIZES);
+        }
+        Ok(w.position())
+    }
+
+    /// Merge an encoded message into `self` per protobuf semantics:
+    /// singular scalars take the last value, repeated fields append and
+    /// sub-messages merge recursively. Merging two buffers in turn is
+    /// equivalent to decoding their concatenation.
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError>;
+
+    fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+        self.merge_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        if data.len() > opts.max_message_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        let mut msg = Self::default();
+        msg.merge_with(data, opts)?;
+        Ok(msg)
+    }
+
+    fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(data, &DecodeOptions::DEFAULT)
+    }
+
+    /// Reset every field to its proto3 default, as if freshly decoded from
+    /// an empty buffer, so one value can be reused across decodes.
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// `decode` with the total input capped at `max_len` bytes, e.g. an
+    /// instruction handler's expected payload size.
+    fn decode_with_limit(data: &[u8], max_len: usize) -> Result<Self, DecodeError> {
+        let opts = DecodeOptions { max_message_len: max_len, ..DecodeOptions::DEFAULT };
+        Self::decode_with(data, &opts)
+    }
+}
+
+/// Whether `data` is exactly the canonical encoding of an `M`, i.e. it
+/// decodes and `encode_canonical` gives back the same bytes. Reordered or
+/// repeated singular fields, explicit defaults, overlong varints and
+/// unknown fields all make it non-canonical.
+pub fn verify_canonical<M: Message>(data: &[u8]) -> bool {
+    verify_canonical_with::<M>(data, &DecodeOptions::DEFAULT)
+}
+
+/// `verify_canonical` decoding with `opts`, e.g. `DecodeOptions::ACCOUNT`
+/// for account data.
+pub fn verify_canonical_with<M: Message>(data: &[u8], opts: &DecodeOptions) -> bool {
+    M::decode_with(data, opts).is_ok_and(|msg| msg.encode_canonical() == data)
+}
+
+/// Prefix of the type URLs written by `Any::pack`.
+pub const TYPE_URL_PREFIX: &str = "type.googleapis.com";
+
+/// Implemented by every generated message: its fully qualified protobuf
+/// name, used to address it from a `google.protobuf.Any`.
+pub trait MessageName: Message {
+    /// e.g. `"example.UserProfile"`
+    const FULL_NAME: &'static str;
+
+    fn type_url() -> String {
+        format!("{}/{}", TYPE_URL_PREFIX, Self::FULL_NAME)
+    }
+}
+
+// ── Key (tag) encode / decode ────────────────────────────────────────
+
+#[inline]
+pub fn encode_key(buf: &mut Vec<u8>, tag: u64) {
+    encode_varint(buf, tag);
+}
+
+/// Largest field number a schema can declare, `2^29 - 1`.
+pub const MAX_FIELD_NUMBER: u32 = (1 << 29) - 1;
+
+/// A field key, `field_number << 3 | wire_type`. A field number of 0 or
+/// above `MAX_FIELD_NUMBER` fails with `InvalidFieldNumber`, and wire
+/// types 6 and 7 with `UnknownWireType`, so a garbage key is never read
+/// as some other field.
+#[inline]
+pub fn decode_key(data: &[u8], pos: usize) -> Result<(u64, usize), DecodeError> {
+    let (tag, new_pos) = decode_varint(data, pos)?;
+    Ok((check_key(tag)?, new_pos))
+}
+
+/// The checks of `decode_key` on a key already read as a varint, which
+/// every `WireRead` applies as well.
+#[inline]
+fn check_key(tag: u64) -> Result<u64, DecodeError> {
+    let number = tag >> 3;
+    if number == 0 || number > MAX_FIELD_NUMBER as u64 {
+        return Err(DecodeError::InvalidFieldNumber(number));
+    }
+    if tag & 7 > 5 {
+        return Err(DecodeError::UnknownWireType(tag & 7));
+    }
+    Ok(tag)
+}
+
+/// End of the `len` bytes starting at `pos` in an input of `limit` bytes.
+/// A length that cannot be added to `pos` is `LengthOverflow`; one that
+/// merely runs past the input is `BufferOverflow`. Every length and
+/// fixed-width read goes through here, so no bounds check can wrap.
+#[inline]
+fn checked_end(pos: usize, len: u64, limit: usize) -> Result<usize, DecodeError> {
+    let end = usize::try_from(len)
+        .ok()
+        .and_then(|len| pos.checked_add(len))
+        .ok_or(DecodeError::LengthOverflow)?;
+    if end > limit {
+        return Err(DecodeError::BufferOverflow);
+    }
+    Ok(end)
+}
+
+// ── Wire Type 0: Varint ──────────────────────────────────────────────
+
+#[inline]
+pub fn encode_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        if value < 0x80 {
+            buf.push(value as u8);
+            return;
+        }
+        buf.push(((value & 0x7F) | 0x80) as u8);
+        value >>= 7;
+    }
+}
+
+/// Decode the varint at `pos`, returning it and the position just past it.
+///
+/// Single-byte values return straight away. With at least eight bytes left,
+/// longer values are read as one unaligned `u64` load: the first clear
+/// continuation bit gives the length, and three mask-and-shift steps pack
+/// the 7-bit groups together. Ten-byte values and varints near the end of
+/// `data` take the byte-at-a-time loop, which reports the errors.
+#[inline]
+pub fn decode_varint(data: &[u8], pos: usize) -> Result<(u64, usize), DecodeError> {
+    match data.get(pos) {
+        Some(&b) if b < 0x80 => return Ok((b as u64, pos + 1)),
+        None => return Err(DecodeError::BufferOverflow),
+        Some(_) => {}
+    }
+    if let Some(bytes) = data.get(pos..pos + 8) {
+        let word = u64::from_le_bytes(bytes.try_into().unwrap());
+        let stop = !word & 0x8080_8080_8080_8080;
+        if stop != 0 {
+            // The stop bit is bit 7 of the last byte, so this is 8 × length
+            let bits = stop.trailing_zeros() + 1;
+            let mut x = word & (u64::MAX >> (64 - bits)) & 0x7F7F_7F7F_7F7F_7F7F;
+            x = ((x & 0x7F00_7F00_7F00_7F00) >> 1) | (x & 0x007F_007F_007F_007F);
+            x = ((x & 0x3FFF_0000_3FFF_0000) >> 2) | (x & 0x0000_3FFF_0000_3FFF);
+            x = ((x & 0x0FFF_FFFF_0000_0000) >> 4) | (x & 0x0000_0000_0FFF_FFFF);
+            return Ok((x, pos + bits as usize / 8));
+        }
+    }
+    decode_varint_slow(data, pos)
+}
+
+/// Byte-at-a-time varint decode: the fallback for `decode_varint`.
+fn decode_varint_slow(data: &[u8], mut pos: usize) -> Result<(u64, usize), DecodeError> {
+    let mut result: u64 = 0;
+    let mut shift: u32 = 0;
+    loop {
+        if pos >= data.len() {
+            return Err(DecodeError::BufferOverflow);
+        }
+        let b = data[pos];
+        pos += 1;
+        result |= ((b & 0x7F) as u64) << shift;
+        if b & 0x80 == 0 {
+            return Ok((result, pos));
+        }
+        shift += 7;
+        if shift > 63 {
+            return Err(DecodeError::InvalidVarint);
+        }
+    }
+}
+
+/// Number of bytes `encode_varint` writes for `value` (1..=10).
+#[inline]
+pub fn varint_len(value: u64) -> usize {
+    ((64 - (value | 1).leading_zeros()) as usize).div_ceil(7)
+}
+
+/// Number of bytes the key of field number `field` takes, for any wire type.
+#[inline]
+pub fn key_len(field: u32) -> usize {
+    varint_len((field as u64) << 3)
+}
+
+/// Number of bytes a length-delimited payload of `len` bytes takes,
+/// including its length prefix.
+#[inline]
+pub fn bytes_len(len: usize) -> usize {
+    varint_len(len as u64) + len
+}
+
+// ── Bool ─────────────────────────────────────────────────────────────
+
+#[inline]
+pub fn encode_bool(buf: &mut Vec<u8>, value: bool) {
+    buf.push(if value { 1 } else { 0 });
+}
+
+#[inline]
+pub fn decode_bool(data: &[u8], pos: usize) -> Result<(bool, usize), DecodeError> {
+    let (v, new_pos) = decode_varint(data, pos)?;
+    Ok((v != 0, new_pos))
+}
+
+// ── Int32 / Int64 ────────────────────────────────────────────────────
+
+/// `int32` (and enum) value. A negative value is sign-extended to 64 bits
+/// first, so it always takes ten bytes, as every protobuf implementation
+/// writes it.
+#[inline]
+pub fn encode_int32(buf: &mut Vec<u8>, value: i32) {
+    encode_varint(buf, value as i64 as u64);
+}
+
+/// `int32` value, keeping the low 32 bits of the varint as other
+/// implementations do, so an `int64` on the wire reads back truncated.
+#[inline]
+pub fn decode_int32(data: &[u8], pos: usize) -> Result<(i32, usize), DecodeError> {
+    let (raw, new_pos) = decode_varint(data, pos)?;
+    Ok((raw as i32, new_pos))
+}
+
+#[inline]
+pub fn encode_int64(buf: &mut Vec<u8>, value: i64) {
+    encode_varint(buf, value as u64);
+}
+
+#[inline]
+pub fn decode_int64(data: &[u8], pos: usize) -> Result<(i64, usize), DecodeError> {
+    let (raw, new_pos) = decode_varint(data, pos)?;
+    Ok((raw as i64, new_pos))
+}
+
+#[inline]
+pub fn int32_len(value: i32) -> usize {
+    varint_len(value as i64 as u64)
+}
+
+#[inline]
+pub fn int64_len(value: i64) -> usize {
+    varint_len(value as u64)
+}
+
+// ── ZigZag (sint32/sint64) ───────────────────────────────────────────
+
+#[inline]
+pub fn encode_zigzag32(buf: &mut Vec<u8>, value: i32) {
+    let encoded = ((value << 1) ^ (value >> 31)) as u32;
+    encode_varint(buf, encoded as u64);
+}
+
+#[inline]
+pub fn decode_zigzag32(data: &[u8], pos: usize) -> Result<(i32, usize), DecodeError> {
+    let (raw, new_pos) = decode_varint(data, pos)?;
+    let n = raw as u32;
+    let value = ((n >> 1) as i32) ^ (-((n & 1) as i32));
+    Ok((value, new_pos))
+}
+
+#[inline]
+pub fn encode_zigzag64(buf: &mut Vec<u8>, value: i64) {
+    let encoded = ((value << 1) ^ (value >> 63)) as u64;
+    encode_varint(buf, encoded);
+}
+
+#[inline]
+pub fn decode_zigzag64(data: &[u8], pos: usize) -> Result<(i64, usize), DecodeError> {
+    let (raw, new_pos) = decode_varint(data, pos)?;
+    let value = ((raw >> 1) as i64) ^ (-((raw & 1) as i64));
+    Ok((value, new_pos))
+}
+
+#[inline]
+pub fn zigzag32_len(value: i32) -> usize {
+    varint_len(((value << 1) ^ (value >> 31)) as u32 as u64)
+}
+
+#[inline]
+pub fn zigzag64_len(value: i64) -> usize {
+    varint_len(((value << 1) ^ (value >> 63)) as u64)
+}
+
+/// Fail unless `tag` carries the `expected` wire type.
+#[inline]
+pub fn expect_wire_type(tag: u64, expected: u64) -> Result<(), DecodeError> {
+    let found = tag & 0x07;
+    if found != expected {
+        return Err(DecodeError::WireTypeMismatch { expected, found });
+    }
+    Ok(())
+}
+
+/// Key + zigzag value for an `sint32` field.
+#[inline]
+pub fn encode_sint32_field(buf: &mut Vec<u8>, field: u32, value: i32) {
+    encode_key(buf, (field as u64) << 3);
+    encode_zigzag32(buf, value);
+}
+
+/// Decode an `sint32` value whose key `tag` has already been read.
+#[inline]
+pub fn decode_sint32_field(data: &[u8], pos: usize, tag: u64) -> Result<(i32, usize), DecodeError> {
+    expect_wire_type(tag, 0)?;
+    decode_zigzag32(data, pos)
+}
+
+/// Key + zigzag value for an `sint64` field.
+#[inline]
+pub fn encode_sint64_field(buf: &mut Vec<u8>, field: u32, value: i64) {
+    encode_key(buf, (field as u64) << 3);
+    encode_zigzag64(buf, value);
+}
+
+/// Decode an `sint64` value whose key `tag` has already been read.
+#[inline]
+pub fn decode_sint64_field(data: &[u8], pos: usize, tag: u64) -> Result<(i64, usize), DecodeError> {
+    expect_wire_type(tag, 0)?;
+    decode_zigzag64(data, pos)
+}
+
+// ── Wire Type 1: 64-bit (little-endian) ─────────────────────────────
+
+#[inline]
+pub fn encode_fixed64(buf: &mut Vec<u8>, value: u64) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+#[inline]
+pub fn decode_fixed64(data: &[u8], pos: usize) -> Result<(u64, usize), DecodeError> {
+    let end = checked_end(pos, 8, data.len())?;
+    let value = u64::from_le_bytes(data[pos..end].try_into().unwrap());
+    Ok((value, end))
+}
+
+#[inline]
+pub fn encode_sfixed64(buf: &mut Vec<u8>, value: i64) {
+    encode_fixed64(buf, value as u64);
+}
+
+#[inline]
+pub fn decode_sfixed64(data: &[u8], pos: usize) -> Result<(i64, usize), DecodeError> {
+    let (raw, new_pos) = decode_fixed64(data, pos)?;
+    Ok((raw as i64, new_pos))
+}
+
+/// `double`: the IEEE 754 bits as fixed64, so NaN payloads and `-0.0`
+/// survive a round trip.
+#[inline]
+pub fn encode_double(buf: &mut Vec<u8>, value: f64) {
+    encode_fixed64(buf, value.to_bits());
+}
+
+#[inline]
+pub fn decode_double(data: &[u8], pos: usize) -> Result<(f64, usize), DecodeError> {
+    let (raw, new_pos) = decode_fixed64(data, pos)?;
+    Ok((f64::from_bits(raw), new_pos))
+}
+
+// ── Wire Type 5: 32-bit (little-endian) ─────────────────────────────
+
+#[inline]
+pub fn encode_fixed32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+#[inline]
+pub fn decode_fixed32(data: &[u8], pos: usize) -> Result<(u32, usize), DecodeError> {
+    let end = checked_end(pos, 4, data.len())?;
+    let value = u32::from_le_bytes(data[pos..end].try_into().unwrap());
+    Ok((value, end))
+}
+
+#[inline]
+pub fn encode_sfixed32(buf: &mut Vec<u8>, value: i32) {
+    encode_fixed32(buf, value as u32);
+}
+
+#[inline]
+pub fn decode_sfixed32(data: &[u8], pos: usize) -> Result<(i32, usize), DecodeError> {
+    let (raw, new_pos) = decode_fixed32(data, pos)?;
+    Ok((raw as i32, new_pos))
+}
+
+/// `float`: the IEEE 754 bits as fixed32.
+#[inline]
+pub fn encode_float(buf: &mut Vec<u8>, value: f32) {
+    encode_fixed32(buf, value.to_bits());
+}
+
+#[inline]
+pub fn decode_float(data: &[u8], pos: usize) -> Result<(f32, usize), DecodeError> {
+    let (raw, new_pos) = decode_fixed32(data, pos)?;
+    Ok((f32::from_bits(raw), new_pos))
+}
+
+// ── Wire Type 2: Length-delimited ────────────────────────────────────
+
+#[inline]
+pub fn encode_bytes(buf: &mut Vec<u8>, value: &[u8]) {
+    encode_varint(buf, value.len() as u64);
+    buf.extend_from_slice(value);
+}
+
+#[inline]
+pub fn decode_bytes(data: &[u8], pos: usize) -> Result<(Vec<u8>, usize), DecodeError> {
+    decode_bytes_with(data, pos, &DecodeOptions::DEFAULT)
+}
+
+/// `decode_bytes` that rejects payloads above `opts.max_alloc` before allocating.
+#[inline]
+pub fn decode_bytes_with(
+    data: &[u8],
+    pos: usize,
+    opts: &DecodeOptions,
+) -> Result<(Vec<u8>, usize), DecodeError> {
+    let (len, pos) = decode_varint(data, pos)?;
+    if len > opts.max_alloc as u64 {
+        return Err(DecodeError::SizeLimitExceeded);
+    }
+    let end = checked_end(pos, len, data.len())?;
+    Ok((data[pos..end].to_vec(), end))
+}
+
+/// Zero-copy variant of `decode_bytes`: borrows the payload from `data`.
+#[inline]
+pub fn decode_bytes_ref(data: &[u8], pos: usize) -> Result<(&[u8], usize), DecodeError> {
+    let (len, pos) = decode_varint(data, pos)?;
+    let end = checked_end(pos, len, data.len())?;
+    Ok((&data[pos..end], end))
+}
+
+#[inline]
+pub fn encode_string(buf: &mut Vec<u8>, value: &str) {
+    encode_bytes(buf, value.as_bytes());
+}
+
+#[inline]
+pub fn decode_string(data: &[u8], pos: usize) -> Result<(String, usize), DecodeError> {
+    decode_string_with(data, pos, &DecodeOptions::DEFAULT)
+}
+
+#[inline]
+pub fn decode_string_with(
+    data: &[u8],
+    pos: usize,
+    opts: &DecodeOptions,
+) -> Result<(String, usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_with(data, pos, opts)?;
+    String::from_utf8(raw)
+        .map(|s| (s, new_pos))
+        .map_err(|_| DecodeError::InvalidData("invalid UTF-8 in string field"))
+}
+
+/// `decode_bytes_with` for a `(solana.max_len)` field: a longer payload
+/// fails with `LimitExceeded` for `field` before anything is allocated.
+#[inline]
+pub fn decode_bytes_bounded(
+    data: &[u8],
+    pos: usize,
+    opts: &DecodeOptions,
+    max_len: usize,
+    field: u32,
+) -> Result<(Vec<u8>, usize), DecodeError> {
+    let (len, pos) = decode_varint(data, pos)?;
+    if len > max_len as u64 {
+        return Err(DecodeError::LimitExceeded { field });
+    }
+    if len > opts.max_alloc as u64 {
+        return Err(DecodeError::SizeLimitExceeded);
+    }
+    let end = checked_end(pos, len, data.len())?;
+    Ok((data[pos..end].to_vec(), end))
+}
+
+#[inline]
+pub fn decode_string_bounded(
+    data: &[u8],
+    pos: usize,
+    opts: &DecodeOptions,
+    max_len: usize,
+    field: u32,
+) -> Result<(String, usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_bounded(data, pos, opts, max_len, field)?;
+    String::from_utf8(raw)
+        .map(|s| (s, new_pos))
+        .map_err(|_| DecodeError::InvalidData("invalid UTF-8 in string field"))
+}
+
+/// `value` if it is at most `max_len` bytes, else `LimitExceeded` for
+/// `field`; used by views and after merging a bounded wrapper.
+#[inline]
+pub fn within_max_len<T: AsRef<[u8]>>(value: T, max_len: usize, field: u32) -> Result<T, DecodeError> {
+    if value.as_ref().len() > max_len {
+        return Err(DecodeError::LimitExceeded { field });
+    }
+    Ok(value)
+}
+
+/// Bounds-checked body of an embedded message: the `len` bytes after the
+/// length prefix, never running into sibling fields or past the buffer.
+#[inline]
+pub fn decode_message_slice(data: &[u8], pos: usize) -> Result<(&[u8], usize), DecodeError> {
+    decode_bytes_ref(data, pos)
+}
+
+/// Body of proto2 group `FIELD`: the fields from `pos`, just past its
+/// start-group key, up to the matching end-group key, and the position
+/// after that key. Groups nested in the body are matched up too.
+#[inline]
+pub fn decode_group_slice<const FIELD: u32>(data: &[u8], pos: usize) -> Result<(&[u8], usize), DecodeError> {
+    let (body_end, end) = skip_group(data, pos, FIELD as u64, DecodeOptions::DEFAULT.max_depth)?;
+    Ok((bytes_at(data, pos, body_end)?, end))
+}
+
+/// Bytes payload of exactly `N` bytes, for `(solana.fixed_len)` fields.
+#[inline]
+pub fn decode_fixed_bytes<const N: usize>(
+    data: &[u8],
+    pos: usize,
+) -> Result<([u8; N], usize), DecodeError> {
+    decode_fixed_bytes_ref(data, pos).map(|(raw, new_pos)| (*raw, new_pos))
+}
+
+/// Zero-copy variant of `decode_fixed_bytes`.
+#[inline]
+pub fn decode_fixed_bytes_ref<const N: usize>(
+    data: &[u8],
+    pos: usize,
+) -> Result<(&[u8; N], usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_ref(data, pos)?;
+    raw.try_into()
+        .map(|raw| (raw, new_pos))
+        .map_err(|_| DecodeError::InvalidData("bytes field has the wrong length"))
+}
+
+/// Zero-copy variant of `decode_string`: borrows the UTF-8 payload from `data`.
+#[inline]
+pub fn decode_str_ref(data: &[u8], pos: usize) -> Result<(&str, usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_ref(data, pos)?;
+    let value = core::str::from_utf8(raw)
+        .map_err(|_| DecodeError::InvalidData("invalid UTF-8 in string field"))?;
+    Ok((value, new_pos))
+}
+
+/// Like `decode_string`, but replaces invalid UTF-8 sequences with U+FFFD
+/// instead of failing. Valid input is not copied twice.
+#[inline]
+pub fn decode_string_lossy(data: &[u8], pos: usize) -> Result<(String, usize), DecodeError> {
+    decode_string_lossy_with(data, pos, &DecodeOptions::DEFAULT)
+}
+
+/// `decode_string_lossy` under `opts`, for `(solana.utf8) = "lossy"` fields.
+#[inline]
+pub fn decode_string_lossy_with(
+    data: &[u8],
+    pos: usize,
+    opts: &DecodeOptions,
+) -> Result<(String, usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_with(data, pos, opts)?;
+    Ok((utf8_lossy(raw), new_pos))
+}
+
+/// `decode_string_lossy_with` for a `(solana.max_len)` field. The payload
+/// is checked before anything is allocated, and the string again after
+/// any replacement, which may make it longer.
+#[inline]
+pub fn decode_string_lossy_bounded(
+    data: &[u8],
+    pos: usize,
+    opts: &DecodeOptions,
+    max_len: usize,
+    field: u32,
+) -> Result<(String, usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_bounded(data, pos, opts, max_len, field)?;
+    Ok((within_max_len(utf8_lossy(raw), max_len, field)?, new_pos))
+}
+
+#[inline]
+fn utf8_lossy(raw: Vec<u8>) -> String {
+    String::from_utf8(raw).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+}
+
+/// `decode_bytes_ref` as a `Cow`, for the bytes fields of `<Message>Cow` structs.
+#[inline]
+pub fn decode_bytes_cow(data: &[u8], pos: usize) -> Result<(Cow<'_, [u8]>, usize), DecodeError> {
+    decode_bytes_ref(data, pos).map(|(raw, new_pos)| (Cow::Borrowed(raw), new_pos))
+}
+
+/// `decode_str_ref` as a `Cow`, for the string fields of `<Message>Cow` structs.
+#[inline]
+pub fn decode_str_cow(data: &[u8], pos: usize) -> Result<(Cow<'_, str>, usize), DecodeError> {
+    decode_str_ref(data, pos).map(|(value, new_pos)| (Cow::Borrowed(value), new_pos))
+}
+
+/// `decode_str_cow` for a lossy string: borrowed if it is valid UTF-8,
+/// else an owned copy with each bad sequence replaced by U+FFFD.
+#[inline]
+pub fn decode_str_lossy_cow(data: &[u8], pos: usize) -> Result<(Cow<'_, str>, usize), DecodeError> {
+    decode_bytes_ref(data, pos).map(|(raw, new_pos)| (String::from_utf8_lossy(raw), new_pos))
+}
+
+/// `decode_bytes_cow` for a `(solana.max_len)` field.
+#[inline]
+pub fn decode_bytes_cow_bounded(
+    data: &[u8],
+    pos: usize,
+    max_len: usize,
+    field: u32,
+) -> Result<(Cow<'_, [u8]>, usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_ref(data, pos)?;
+    Ok((Cow::Borrowed(within_max_len(raw, max_len, field)?), new_pos))
+}
+
+#[inline]
+pub fn decode_str_cow_bounded(
+    data: &[u8],
+    pos: usize,
+    max_len: usize,
+    field: u32,
+) -> Result<(Cow<'_, str>, usize), DecodeError> {
+    let (value, new_pos) = decode_str_ref(data, pos)?;
+    Ok((Cow::Borrowed(within_max_len(value, max_len, field)?), new_pos))
+}
+
+/// `decode_str_lossy_cow` for a `(solana.max_len)` field, checked before
+/// and after any replacement, as in `decode_string_lossy_bounded`.
+#[inline]
+pub fn decode_str_lossy_cow_bounded(
+    data: &[u8],
+    pos: usize,
+    max_len: usize,
+    field: u32,
+) -> Result<(Cow<'_, str>, usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_ref(data, pos)?;
+    let value = String::from_utf8_lossy(within_max_len(raw, max_len, field)?);
+    if value.len() > max_len {
+        return Err(DecodeError::LimitExceeded { field });
+    }
+    Ok((value, new_pos))
+}
+
+/// Encode a length-delimited sub-message without a temporary buffer:
+/// writes the key and a one-byte length placeholder, lets `f` append the
+/// body, then backpatches the length. Bodies under 128 bytes are never
+/// moved; longer ones shift right by the extra length bytes.
+#[inline]
+pub fn encode_message_field<F: FnOnce(&mut Vec<u8>)>(buf: &mut Vec<u8>, field: u32, f: F) {
+    encode_key(buf, ((field as u64) << 3) | 2);
+    let len_pos = buf.len();
+    buf.push(0);
+    f(buf);
+
+    let body_start = len_pos + 1;
+    let body_len = buf.len() - body_start;
+    let width = varint_len(body_len as u64);
+    if width > 1 {
+        buf.resize(buf.len() + width - 1, 0);
+        buf.copy_within(body_start..body_start + body_len, len_pos + width);
+    }
+
+    let mut value = body_len as u64;
+    for b in &mut buf[len_pos..len_pos + width] {
+        *b = (value as u8 & 0x7F) | 0x80;
+        value >>= 7;
+    }
+    buf[len_pos + width - 1] &= 0x7F;
+}
+
+// ── Pubkey (`(solana.pubkey)` bytes fields) ──────────────────────────
+
+/// 32-byte account address: Solana's `Pubkey` with the `solana-program`
+/// feature, a bare `[u8; 32]` otherwise.
+#[cfg(feature = "solana-program")]
+pub use solana_program::pubkey::Pubkey;
+#[cfg(not(feature = "solana-program"))]
+pub type Pubkey = [u8; 32];
+
+pub const PUBKEY_LEN: usize = 32;
+
+/// The all-zero `Pubkey`, the default of pubkey fields, in `const` form.
+#[cfg(feature = "solana-program")]
+pub const ZERO_PUBKEY: Pubkey = Pubkey::new_from_array([0; PUBKEY_LEN]);
+#[cfg(not(feature = "solana-program"))]
+pub const ZERO_PUBKEY: Pubkey = [0; PUBKEY_LEN];
+
+#[cfg(feature = "solana-program")]
+#[inline]
+pub fn pubkey_from_array(bytes: [u8; PUBKEY_LEN]) -> Pubkey {
+    Pubkey::new_from_array(bytes)
+}
+
+#[cfg(not(feature = "solana-program"))]
+#[inline]
+pub fn pubkey_from_array(bytes: [u8; PUBKEY_LEN]) -> Pubkey {
+    bytes
+}
+
+#[cfg(feature = "solana-program")]
+#[inline]
+pub fn pubkey_to_array(value: &Pubkey) -> [u8; PUBKEY_LEN] {
+    value.to_bytes()
+}
+
+#[cfg(not(feature = "solana-program"))]
+#[inline]
+pub fn pubkey_to_array(value: &Pubkey) -> [u8; PUBKEY_LEN] {
+    *value
+}
+
+#[inline]
+pub fn encode_pubkey(buf: &mut Vec<u8>, value: &Pubkey) {
+    encode_bytes(buf, value.as_ref());
+}
+
+/// A pubkey is a bytes payload of exactly `PUBKEY_LEN` bytes.
+#[inline]
+pub fn decode_pubkey(data: &[u8], pos: usize) -> Result<(Pubkey, usize), DecodeError> {
+    let (raw, new_pos) = decode_bytes_ref(data, pos)?;
+    Ok((pubkey_from_slice(raw)?, new_pos))
+}
+
+#[inline]
+pub fn pubkey_from_slice(raw: &[u8]) -> Result<Pubkey, DecodeError> {
+    raw.try_into()
+        .map(pubkey_from_array)
+        .map_err(|_| DecodeError::InvalidData("pubkey must be 32 bytes"))
+}
+
+// ── Packed fixed-width (repeated fixed32/fixed64/float/double) ───────
+
+/// Write `values` as one packed length-delimited payload (key not included).
+fn encode_packed_fixed<T: Copy, const N: usize>(buf: &mut Vec<u8>, values: &[T], to_le: fn(T) -> [u8; N]) {
+    encode_varint(buf, (values.len() * N) as u64);
+    buf.reserve(values.len() * N);
+    for &v in values {
+        buf.extend_from_slice(&to_le(v));
+    }
+}
+
+/// Append every element of a packed payload to `out`. The payload length
+/// must be a whole number of elements, and `out` may not grow past
+/// `opts.max_repeated_len`; otherwise nothing is appended (or reserved).
+fn decode_packed_fixed<T, const N: usize>(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<T>,
+    opts: &DecodeOptions,
+    from_le: fn([u8; N]) -> T,
+) -> Result<usize, DecodeError> {
+    let (payload, end) = decode_bytes_ref(data, pos)?;
+    if payload.len() % N != 0 {
+        return Err(DecodeError::InvalidData("packed fixed field length not a multiple of element size"));
+    }
+    if payload.len() / N > opts.max_repeated_len.saturating_sub(out.len()) {
+        return Err(DecodeError::SizeLimitExceeded);
+    }
+    out.reserve(payload.len() / N);
+    for chunk in payload.chunks_exact(N) {
+        out.push(from_le(chunk.try_into().unwrap()));
+    }
+    Ok(end)
+}
+
+pub fn encode_packed_fixed32(buf: &mut Vec<u8>, values: &[u32]) {
+    encode_packed_fixed(buf, values, u32::to_le_bytes);
+}
+
+pub fn decode_packed_fixed32(data: &[u8], pos: usize, out: &mut Vec<u32>) -> Result<usize, DecodeError> {
+    decode_packed_fixed32_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_fixed32_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<u32>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_fixed(data, pos, out, opts, u32::from_le_bytes)
+}
+
+pub fn encode_packed_sfixed32(buf: &mut Vec<u8>, values: &[i32]) {
+    encode_packed_fixed(buf, values, i32::to_le_bytes);
+}
+
+pub fn decode_packed_sfixed32(data: &[u8], pos: usize, out: &mut Vec<i32>) -> Result<usize, DecodeError> {
+    decode_packed_sfixed32_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_sfixed32_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<i32>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_fixed(data, pos, out, opts, i32::from_le_bytes)
+}
+
+pub fn encode_packed_float(buf: &mut Vec<u8>, values: &[f32]) {
+    encode_packed_fixed(buf, values, f32::to_le_bytes);
+}
+
+pub fn decode_packed_float(data: &[u8], pos: usize, out: &mut Vec<f32>) -> Result<usize, DecodeError> {
+    decode_packed_float_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_float_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<f32>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_fixed(data, pos, out, opts, f32::from_le_bytes)
+}
+
+pub fn encode_packed_fixed64(buf: &mut Vec<u8>, values: &[u64]) {
+    encode_packed_fixed(buf, values, u64::to_le_bytes);
+}
+
+pub fn decode_packed_fixed64(data: &[u8], pos: usize, out: &mut Vec<u64>) -> Result<usize, DecodeError> {
+    decode_packed_fixed64_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_fixed64_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<u64>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_fixed(data, pos, out, opts, u64::from_le_bytes)
+}
+
+pub fn encode_packed_sfixed64(buf: &mut Vec<u8>, values: &[i64]) {
+    encode_packed_fixed(buf, values, i64::to_le_bytes);
+}
+
+pub fn decode_packed_sfixed64(data: &[u8], pos: usize, out: &mut Vec<i64>) -> Result<usize, DecodeError> {
+    decode_packed_sfixed64_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_sfixed64_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<i64>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_fixed(data, pos, out, opts, i64::from_le_bytes)
+}
+
+pub fn encode_packed_double(buf: &mut Vec<u8>, values: &[f64]) {
+    encode_packed_fixed(buf, values, f64::to_le_bytes);
+}
+
+pub fn decode_packed_double(data: &[u8], pos: usize, out: &mut Vec<f64>) -> Result<usize, DecodeError> {
+    decode_packed_double_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_double_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<f64>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_fixed(data, pos, out, opts, f64::from_le_bytes)
+}
+
+// ── Packed varint (repeated integer/bool/enum) ───────────────────────
+
+/// Write `values` as one packed payload of varints (key not included).
+fn encode_packed_varint_with<T: Copy>(buf: &mut Vec<u8>, values: &[T], to_u64: fn(T) -> u64) {
+    let len: usize = values.iter().map(|&v| varint_len(to_u64(v))).sum();
+    encode_varint(buf, len as u64);
+    buf.reserve(len);
+    for &v in values {
+        encode_varint(buf, to_u64(v));
+    }
+}
+
+/// Append every varint of a packed payload to `out`. A varint running
+/// past the end of the payload is an error, as is an element that would
+/// take `out` past `opts.max_repeated_len`.
+fn decode_packed_varints<T>(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<T>,
+    opts: &DecodeOptions,
+    from_u64: fn(u64) -> T,
+) -> Result<usize, DecodeError> {
+    let (payload, end) = decode_bytes_ref(data, pos)?;
+    let mut pos = 0;
+    while pos < payload.len() {
+        let (v, new_pos) = decode_varint(payload, pos)?;
+        if out.len() >= opts.max_repeated_len {
+            return Err(DecodeError::SizeLimitExceeded);
+        }
+        out.push(from_u64(v));
+        pos = new_pos;
+    }
+    Ok(end)
+}
+
+pub fn encode_packed_varint(buf: &mut Vec<u8>, values: &[u64]) {
+    encode_packed_varint_with(buf, values, |v| v);
+}
+
+pub fn decode_packed_varint(data: &[u8], pos: usize, out: &mut Vec<u64>) -> Result<usize, DecodeError> {
+    decode_packed_varint_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_varint_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<u64>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_varints(data, pos, out, opts, |v| v)
+}
+
+pub fn encode_packed_uint32(buf: &mut Vec<u8>, values: &[u32]) {
+    encode_packed_varint_with(buf, values, |v| v as u64);
+}
+
+pub fn decode_packed_uint32(data: &[u8], pos: usize, out: &mut Vec<u32>) -> Result<usize, DecodeError> {
+    decode_packed_uint32_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_uint32_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<u32>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_varints(data, pos, out, opts, |v| v as u32)
+}
+
+/// int32 and enum values; negatives are sign-extended to ten bytes.
+pub fn encode_packed_int32(buf: &mut Vec<u8>, values: &[i32]) {
+    encode_packed_varint_with(buf, values, |v| v as i64 as u64);
+}
+
+pub fn decode_packed_int32(data: &[u8], pos: usize, out: &mut Vec<i32>) -> Result<usize, DecodeError> {
+    decode_packed_int32_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_int32_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<i32>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_varints(data, pos, out, opts, |v| v as i32)
+}
+
+pub fn encode_packed_int64(buf: &mut Vec<u8>, values: &[i64]) {
+    encode_packed_varint_with(buf, values, |v| v as u64);
+}
+
+pub fn decode_packed_int64(data: &[u8], pos: usize, out: &mut Vec<i64>) -> Result<usize, DecodeError> {
+    decode_packed_int64_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_int64_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<i64>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_varints(data, pos, out, opts, |v| v as i64)
+}
+
+pub fn encode_packed_bool(buf: &mut Vec<u8>, values: &[bool]) {
+    encode_packed_varint_with(buf, values, |v| v as u64);
+}
+
+pub fn decode_packed_bool(data: &[u8], pos: usize, out: &mut Vec<bool>) -> Result<usize, DecodeError> {
+    decode_packed_bool_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_bool_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<bool>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_varints(data, pos, out, opts, |v| v != 0)
+}
+
+pub fn encode_packed_zigzag32(buf: &mut Vec<u8>, values: &[i32]) {
+    encode_packed_varint_with(buf, values, |v| ((v << 1) ^ (v >> 31)) as u32 as u64);
+}
+
+pub fn decode_packed_zigzag32(data: &[u8], pos: usize, out: &mut Vec<i32>) -> Result<usize, DecodeError> {
+    decode_packed_zigzag32_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_zigzag32_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<i32>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_varints(data, pos, out, opts, |n| {
+        let n = n as u32;
+        ((n >> 1) as i32) ^ (-((n & 1) as i32))
+    })
+}
+
+pub fn encode_packed_zigzag64(buf: &mut Vec<u8>, values: &[i64]) {
+    encode_packed_varint_with(buf, values, |v| ((v << 1) ^ (v >> 63)) as u64);
+}
+
+pub fn decode_packed_zigzag64(data: &[u8], pos: usize, out: &mut Vec<i64>) -> Result<usize, DecodeError> {
+    decode_packed_zigzag64_with(data, pos, out, &DecodeOptions::DEFAULT)
+}
+
+pub fn decode_packed_zigzag64_with(
+    data: &[u8],
+    pos: usize,
+    out: &mut Vec<i64>,
+    opts: &DecodeOptions,
+) -> Result<usize, DecodeError> {
+    decode_packed_varints(data, pos, out, opts, |n| ((n >> 1) as i64) ^ (-((n & 1) as i64)))
+}
+
+// ── Bounded repeated fields (`bounded_vec`) ──────────────────────────
+//
+// A `BoundedVec<T, N>` holds up to `N` elements inline, so a repeated
+// field with a `(solana.max_count)` takes no heap allocation of its own
+// and the struct's size is known at compile time. Elements that allocate
+// themselves (strings, bytes, sub-messages with those) still do.
+
+use core::mem::MaybeUninit;
+
+/// A `Vec` of at most `N` elements stored inline, for repeated fields
+/// generated with `bounded_vec`. Derefs to the slice of its elements.
+pub struct BoundedVec<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> BoundedVec<T, N> {
+    pub const fn new() -> Self {
+        // SAFETY: an array of `MaybeUninit` needs no initialization
+        let items = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
+        BoundedVec { items, len: 0 }
+    }
+
+    /// Most elements the vector can hold, `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Append `value`, or hand it back if the vector is full.
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        match self.items.get_mut(self.len) {
+            Some(slot) => {
+                slot.write(value);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Append `value`.
+    ///
+    /// # Panics
+    ///
+    /// If the vector is full; `try_push` returns the value instead.
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            panic!("BoundedVec is full");
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.len.checked_sub(1)?;
+        let slot = self.items.get(last)?;
+        self.len = last;
+        // SAFETY: the last element was initialized, and lowering `len`
+        // makes this the only read of it
+        Some(unsafe { slot.assume_init_read() })
+    }
+
+    /// Drop every element past the first `len`.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts(self.items.as_ptr().cast::<T>(), self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: as in `as_slice`
+        unsafe { core::slice::from_raw_parts_mut(self.items.as_mut_ptr().cast::<T>(), self.len) }
+    }
+}
+
+impl<T, const N: usize> Drop for BoundedVec<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: the elements are initialized and never used again
+        unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for BoundedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for BoundedVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut out = Self::new();
+        for elem in self.iter() {
+            // Never full: `out` has the capacity `self` fits in
+            let _ = out.try_push(elem.clone());
+        }
+        out
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for BoundedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for BoundedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T]> for BoundedVec<T, N> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<Vec<T>> for BoundedVec<T, N> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for BoundedVec<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for BoundedVec<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for BoundedVec<T, N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for BoundedVec<T, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a BoundedVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut BoundedVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for BoundedVec<T, N> {
+    type Item = T;
+    type IntoIter = BoundedVecIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BoundedVecIntoIter { vec: self, next: 0 }
+    }
+}
+
+/// Owning iterator over a `BoundedVec`.
+pub struct BoundedVecIntoIter<T, const N: usize> {
+    /// Elements before `next` have been moved out
+    vec: BoundedVec<T, N>,
+    next: usize,
+}
+
+impl<T, const N: usize> Iterator for BoundedVecIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next == self.vec.len {
+            return None;
+        }
+        let slot = self.vec.items.get(self.next)?;
+        self.next += 1;
+        // SAFETY: elements from `next` to `len` are initialized, and
+        // advancing `next` makes this the only read of this one
+        Some(unsafe { slot.assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.vec.len - self.next;
+        (left, Some(left))
+    }
+}
+
+impl<T, const N: usize> Drop for BoundedVecIntoIter<T, N> {
+    fn drop(&mut self) {
+        let len = self.vec.len;
+        // The vector now only owns, and drops, the elements not yet moved out
+        self.vec.len = 0;
+        for slot in self.vec.items.iter_mut().take(len).skip(self.next) {
+            // SAFETY: these elements are initialized and read nowhere else
+            unsafe { slot.assume_init_drop() }
+        }
+    }
+}
+
+/// # Panics
+///
+/// If the iterator yields more than `N` elements.
+impl<T, const N: usize> FromIterator<T> for BoundedVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut out = Self::new();
+        for elem in iter {
+            out.push(elem);
+        }
+        out
+    }
+}
+
+/// Fails with the vector, unchanged, if it has more than `N` elements.
+impl<T, const N: usize> TryFrom<Vec<T>> for BoundedVec<T, N> {
+    type Error = Vec<T>;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        if vec.len() > N {
+            return Err(vec);
+        }
+        Ok(vec.into_iter().collect())
+    }
+}
+
+impl<T, const N: usize> From<BoundedVec<T, N>> for Vec<T> {
+    fn from(vec: BoundedVec<T, N>) -> Vec<T> {
+        vec.into_iter().collect()
+    }
+}
+
+/// Append a decoded element of repeated field `field` to its
+/// `BoundedVec`; past its capacity, the field's `(solana.max_count)`, it
+/// fails with `LimitExceeded`.
+#[inline]
+pub fn push_bounded<T, const N: usize>(out: &mut BoundedVec<T, N>, value: T, field: u32) -> Result<(), DecodeError> {
+    out.try_push(value).map_err(|_| DecodeError::LimitExceeded { field })
+}
+
+/// Append every element of a packed payload to field `field`'s
+/// `BoundedVec`, each read with `decode`, as the `decode_packed_*`
+/// functions do for a `Vec`.
+pub fn decode_packed_bounded<'a, T, const N: usize>(
+    data: &'a [u8],
+    pos: usize,
+    out: &mut BoundedVec<T, N>,
+    field: u32,
+    decode: ElemDecoder<'a, T>,
+) -> Result<usize, DecodeError> {
+    let (payload, end) = decode_bytes_ref(data, pos)?;
+    let mut pos = 0;
+    while pos < payload.len() {
+        let (v, new_pos) = decode(payload, pos)?;
+        push_bounded(out, v, field)?;
+        pos = new_pos;
+    }
+    Ok(end)
+}
+
+// ── Partial reads ────────────────────────────────────────────────────
+
+/// A single undecoded field value, borrowed from the input buffer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    LengthDelimited(&'a [u8]),
+    Fixed32(u32),
+    /// Encoded fields between a group's start and end keys.
+    Group(&'a [u8]),
+}
+
+/// Read the value of a field whose key `tag` has already been consumed.
+#[inline]
+pub fn decode_field_value(
+    data: &[u8],
+    pos: usize,
+    tag: u64,
+) -> Result<(FieldValue<'_>, usize), DecodeError> {
+    match tag & 0x07 {
+        0 => {
+            let (v, new_pos) = decode_varint(data, pos)?;
+            Ok((FieldValue::Varint(v), new_pos))
+        }
+        1 => {
+            let (v, new_pos) = decode_fixed64(data, pos)?;
+            Ok((FieldValue::Fixed64(v), new_pos))
+        }
+        2 => {
+            let (len, new_pos) = decode_varint(data, pos)?;
+            let end = checked_end(new_pos, len, data.len())?;
+            Ok((FieldValue::LengthDelimited(&data[new_pos..end]), end))
+        }
+        5 => {
+            let (v, new_pos) = decode_fixed32(data, pos)?;
+            Ok((FieldValue::Fixed32(v), new_pos))
+        }
+        3 => {
+            let (body_end, end) = skip_group(data, pos, tag >> 3, DecodeOptions::DEFAULT.max_depth)?;
+            let body = data.get(pos..body_end).ok_or(DecodeError::BufferOverflow)?;
+            Ok((FieldValue::Group(body), end))
+        }
+        4 => Err(STRAY_END_GROUP),
+        wire_type => Err(DecodeError::UnknownWireType(wire_type)),
+    }
+}
+
+/// Whether a generated `decode_fields` selection includes `field`;
+/// `None` (a plain `merge`) selects every field.
+#[inline]
+pub fn is_selected(only: Option<&[u32]>, field: u64) -> bool {
+    match only {
+        Some(fields) => fields.iter().any(|&f| f as u64 == field),
+        None => true,
+    }
+}
+
+/// Extract a single field by a path of field numbers (e.g. `[3, 1, 2]`),
+/// descending into sub-messages and skipping everything else.
+///
+/// Follows merge semantics: if a field on the path appears more than
+/// once, the last occurrence that carries the leaf wins. Returns
+/// `Ok(None)` if the path is absent.
+pub fn extract_nested<'a>(
+    data: &'a [u8],
+    path: &[u32],
+) -> Result<Option<FieldValue<'a>>, DecodeError> {
+    let (&field, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return Ok(None),
+    };
+
+    let mut found = None;
+    let mut pos = 0usize;
+    while pos < data.len() {
+        let (tag, new_pos) = decode_key(data, pos)?;
+        if tag >> 3 != field as u64 {
+            pos = skip_field(data, new_pos, tag)?;
+            continue;
+        }
+
+        let (value, next) = decode_field_value(data, new_pos, tag)?;
+        pos = next;
+        if rest.is_empty() {
+            found = Some(value);
+        } else if let FieldValue::LengthDelimited(sub) = value {
+            if let Some(v) = extract_nested(sub, rest)? {
+                found = Some(v);
+            }
+        } else {
+            return Err(DecodeError::InvalidData("path descends into a non-message field"));
+        }
+    }
+    Ok(found)
+}
+
+// ── In-place updates ─────────────────────────────────────────────────
+
+const FIELD_NOT_PRESENT: DecodeError = DecodeError::InvalidData("field is not present in the encoding");
+const PATCH_WIDTH_MISMATCH: DecodeError = DecodeError::InvalidData("value does not fit the field's encoded width");
+
+/// The last occurrence of a field, as offsets into the buffer searched.
+#[derive(Debug, Clone, Copy)]
+struct FieldSpan {
+    /// First byte after the key: the length prefix of a length-delimited field.
+    after_key: usize,
+    /// The value, without any length prefix.
+    start: usize,
+    end: usize,
+}
+
+/// The last occurrence of `field` in `data`, failing if it does not have
+/// wire type `expected`. Every field is framed on the way.
+fn last_field_span(data: &[u8], field: u32, expected: u64) -> Result<Option<FieldSpan>, DecodeError> {
+    let mut found = None;
+    let mut pos = 0usize;
+    while pos < data.len() {
+        let (tag, after_key) = decode_key(data, pos)?;
+        let end = skip_field(data, after_key, tag)?;
+        if tag >> 3 == field as u64 {
+            if tag & 0x07 != expected {
+                return Err(DecodeError::WireTypeMismatch { expected, found: tag & 0x07 });
+            }
+            let start = if expected == 2 { decode_varint(data, after_key)?.1 } else { after_key };
+            found = Some(FieldSpan { after_key, start, end });
+        }
+        pos = end;
+    }
+    Ok(found)
+}
+
+/// The field at `path`, through the last occurrence of each sub-message
+/// on it, with offsets into `data`.
+fn field_span_at(data: &[u8], path: &[u32], expected: u64) -> Result<FieldSpan, DecodeError> {
+    let (&leaf, parents) = path.split_last().ok_or(FIELD_NOT_PRESENT)?;
+    let (mut base, mut end) = (0usize, data.len());
+    for &field in parents {
+        let span = last_field_span(bytes_at(data, base, end)?, field, 2)?.ok_or(FIELD_NOT_PRESENT)?;
+        (base, end) = (offset(base, span.start)?, offset(base, span.end)?);
+    }
+    let span = last_field_span(bytes_at(data, base, end)?, leaf, expected)?.ok_or(FIELD_NOT_PRESENT)?;
+    Ok(FieldSpan { after_key: offset(base, span.after_key)?, start: offset(base, span.start)?, end: offset(base, span.end)? })
+}
+
+/// `data[start..end]`, or `BufferOverflow` for a range outside it.
+#[inline]
+fn bytes_at(data: &[u8], start: usize, end: usize) -> Result<&[u8], DecodeError> {
+    data.get(start..end).ok_or(DecodeError::BufferOverflow)
+}
+
+/// Like `bytes_at`, for writing.
+#[inline]
+fn bytes_at_mut(data: &mut [u8], start: usize, end: usize) -> Result<&mut [u8], DecodeError> {
+    data.get_mut(start..end).ok_or(DecodeError::BufferOverflow)
+}
+
+/// `base + at`, for an offset `at` into the region starting at `base`.
+#[inline]
+fn offset(base: usize, at: usize) -> Result<usize, DecodeError> {
+    base.checked_add(at).ok_or(DecodeError::LengthOverflow)
+}
+
+/// Overwrite the varint field at `path` (e.g. `[3, 1]` for field 1 of
+/// sub-message 3) with `value`, keeping the width it is encoded in; a
+/// narrower value is padded with continuation bytes, which decodes the
+/// same but is no longer canonical. Pass `sint` values ZigZag-encoded
+/// and negative `int32` / `int64` values as `value as i64 as u64`.
+///
+/// Only the last occurrence is written, which is the one decoding keeps.
+/// A field at its default is not in the encoding and fails with
+/// `InvalidData`, as does a value wider than the one it replaces; `data`
+/// is then unchanged, and the message has to be re-encoded instead.
+pub fn patch_varint(data: &mut [u8], path: &[u32], value: u64) -> Result<(), DecodeError> {
+    let span = field_span_at(data, path, 0)?;
+    let bytes = bytes_at_mut(data, span.start, span.end)?;
+    if varint_len(value) > bytes.len() {
+        return Err(PATCH_WIDTH_MISMATCH);
+    }
+    let (last, head) = bytes.split_last_mut().ok_or(PATCH_WIDTH_MISMATCH)?;
+    let mut rest = value;
+    for byte in head {
+        *byte = (rest as u8 & 0x7F) | 0x80;
+        rest >>= 7;
+    }
+    *last = rest as u8;
+    Ok(())
+}
+
+/// Overwrite the `fixed32` / `sfixed32` / `float` (as `to_bits()`) field
+/// at `path`, as `patch_varint` does.
+pub fn patch_fixed32(data: &mut [u8], path: &[u32], value: u32) -> Result<(), DecodeError> {
+    let span = field_span_at(data, path, 5)?;
+    patch_exact(bytes_at_mut(data, span.start, span.end)?, &value.to_le_bytes())
+}
+
+/// Overwrite the `fixed64` / `sfixed64` / `double` (as `to_bits()`)
+/// field at `path`, as `patch_varint` does.
+pub fn patch_fixed64(data: &mut [u8], path: &[u32], value: u64) -> Result<(), DecodeError> {
+    let span = field_span_at(data, path, 1)?;
+    patch_exact(bytes_at_mut(data, span.start, span.end)?, &value.to_le_bytes())
+}
+
+/// Overwrite the `bytes` / `string` field at `path` with a value of the
+/// same length, as `patch_varint` does; use `splice_field` to change
+/// the length.
+pub fn patch_bytes(data: &mut [u8], path: &[u32], value: &[u8]) -> Result<(), DecodeError> {
+    let span = field_span_at(data, path, 2)?;
+    patch_exact(bytes_at_mut(data, span.start, span.end)?, value)
+}
+
+#[inline]
+fn patch_exact(bytes: &mut [u8], value: &[u8]) -> Result<(), DecodeError> {
+    if value.len() != bytes.len() {
+        return Err(PATCH_WIDTH_MISMATCH);
+    }
+    bytes.copy_from_slice(value);
+    Ok(())
+}
+
+/// Replace the length-delimited field at `path` (bytes, a string or a
+/// whole encoded sub-message) with `value` of any length, resizing `buf`.
+/// See `splice_field_in`.
+pub fn splice_field(buf: &mut Vec<u8>, path: &[u32], value: &[u8]) -> Result<(), DecodeError> {
+    let len = buf.len();
+    let new_len = spliced_len(buf, path, value.len())?;
+    buf.resize(len.max(new_len), 0);
+    let written = splice_region(buf, len, 0, len, path, value)?;
+    buf.truncate(written);
+    Ok(())
+}
+
+/// Replace the length-delimited field at `path` in the message held in
+/// the first `len` bytes of `data`, treating the rest of `data` as free
+/// space (e.g. an account allocated larger than its message), and return
+/// the message's new length. The bytes after the field move to make room
+/// and the length prefixes of the sub-messages on `path` are rewritten.
+///
+/// Only the last occurrence is replaced; a leaf field that is absent is
+/// appended to its sub-message instead, but every sub-message on `path`
+/// must be present. Fails with `BufferTooSmall` if `data` cannot hold
+/// the result, before anything is written.
+pub fn splice_field_in(data: &mut [u8], len: usize, path: &[u32], value: &[u8]) -> Result<usize, DecodeError> {
+    if spliced_len(bytes_at(data, 0, len)?, path, value.len())? > data.len() {
+        return Err(DecodeError::BufferTooSmall);
+    }
+    splice_region(data, len, 0, len, path, value)
+}
+
+/// Length of `region` once `splice_region` has put a `value_len`-byte
+/// value at `path` in it.
+fn spliced_len(region: &[u8], path: &[u32], value_len: usize) -> Result<usize, DecodeError> {
+    let (&field, rest) = path.split_first().ok_or(FIELD_NOT_PRESENT)?;
+    let span = match last_field_span(region, field, 2)? {
+        Some(span) => span,
+        None if rest.is_empty() => return grown_len(region.len(), 0, key_len(field), value_len),
+        None => return Err(FIELD_NOT_PRESENT),
+    };
+    let payload = match rest {
+        [] => value_len,
+        _ => spliced_len(bytes_at(region, span.start, span.end)?, rest, value_len)?,
+    };
+    let removed = span.end.checked_sub(span.after_key).ok_or(DecodeError::BufferOverflow)?;
+    grown_len(region.len(), removed, 0, payload)
+}
+
+/// `len` with `removed` bytes taken out and a `header`-byte key (or
+/// none) and a `payload` with its length prefix put in.
+#[inline]
+fn grown_len(len: usize, removed: usize, header: usize, payload: usize) -> Result<usize, DecodeError> {
+    len.checked_sub(removed)
+        .and_then(|len| len.checked_add(header))
+        .and_then(|len| len.checked_add(varint_len(payload as u64)))
+        .and_then(|len| len.checked_add(payload))
+        .ok_or(DecodeError::LengthOverflow)
+}
+
+/// Splice `value` in at `path` within `data[base..end]`, part of a
+/// message of `len` bytes, and return the message's new length. Edits
+/// run back to front, the leaf value first and then each enclosing
+/// length prefix, so the offsets still to be written stay valid; the
+/// caller has checked that the result fits.
+fn splice_region(
+    data: &mut [u8],
+    len: usize,
+    base: usize,
+    end: usize,
+    path: &[u32],
+    value: &[u8],
+) -> Result<usize, DecodeError> {
+    let (&field, rest) = path.split_first().ok_or(FIELD_NOT_PRESENT)?;
+    let span = match last_field_span(bytes_at(data, base, end)?, field, 2)? {
+        Some(span) => span,
+        None if rest.is_empty() => {
+            let mut header = [0u8; 20];
+            let n = put_varint(&mut header, 0, ((field as u64) << 3) | 2)?;
+            let n = put_varint(&mut header, n, value.len() as u64)?;
+            let len = replace_range(data, len, end, end, value)?;
+            return replace_range(data, len, end, end, bytes_at(&header, 0, n)?);
+        }
+        None => return Err(FIELD_NOT_PRESENT),
+    };
+    let (after_key, start, old_end) = (offset(base, span.after_key)?, offset(base, span.start)?, offset(base, span.end)?);
+    let (len, payload) = if rest.is_empty() {
+        (replace_range(data, len, start, old_end, value)?, value.len())
+    } else {
+        let spliced = splice_region(data, len, start, old_end, rest, value)?;
+        let payload = old_end.checked_sub(start).and_then(|n| n.checked_add(spliced)).and_then(|n| n.checked_sub(len));
+        (spliced, payload.ok_or(DecodeError::BufferOverflow)?)
+    };
+    let mut prefix = [0u8; 10];
+    let n = put_varint(&mut prefix, 0, payload as u64)?;
+    replace_range(data, len, after_key, start, bytes_at(&prefix, 0, n)?)
+}
+
+/// Replace `data[start..end]` with `bytes` in a message of `len` bytes,
+/// moving what follows, and return the new length.
+fn replace_range(data: &mut [u8], len: usize, start: usize, end: usize, bytes: &[u8]) -> Result<usize, DecodeError> {
+    let tail = bytes_at(data, end, len)?.len();
+    let moved_to = offset(start, bytes.len())?;
+    if moved_to > data.len() - tail {
+        return Err(DecodeError::BufferTooSmall);
+    }
+    data.copy_within(end..len, moved_to);
+    bytes_at_mut(data, start, moved_to)?.copy_from_slice(bytes);
+    Ok(moved_to + tail)
+}
+
+/// Write `value` as a varint at `buf[pos..]`, returning the end.
+fn put_varint(buf: &mut [u8], mut pos: usize, mut value: u64) -> Result<usize, DecodeError> {
+    loop {
+        let byte = buf.get_mut(pos).ok_or(DecodeError::BufferTooSmall)?;
+        pos = offset(pos, 1)?;
+        if value < 0x80 {
+            *byte = value as u8;
+            return Ok(pos);
+        }
+        *byte = (value as u8) | 0x80;
+        value >>= 7;
+    }
+}
+
+// ── Semantic comparison ──────────────────────────────────────────────
+
+/// Compare two encoded messages by content rather than by bytes.
+///
+/// Schema-light: top-level fields are stably sorted by number and compared
+/// by decoded value, so reordered fields and non-minimal varints compare
+/// equal. Occurrences of the same field number keep their relative order
+/// (repeated order is significant), and length-delimited payloads,
+/// including sub-messages, are compared byte-for-byte.
+pub fn messages_equal(a: &[u8], b: &[u8]) -> Result<bool, DecodeError> {
+    Ok(canonical_fields(a)? == canonical_fields(b)?)
+}
+
+fn canonical_fields(data: &[u8]) -> Result<Vec<(u64, FieldValue<'_>)>, DecodeError> {
+    let mut fields = Vec::new();
+    let mut pos = 0usize;
+    while pos < data.len() {
+        let (tag, new_pos) = decode_key(data, pos)?;
+        let (value, next) = decode_field_value(data, new_pos, tag)?;
+        fields.push((tag >> 3, value));
+        pos = next;
+    }
+    fields.sort_by_key(|(field, _)| *field);
+    Ok(fields)
+}
+
+// ── Length-delimited streams ─────────────────────────────────────────
+
+/// Append `msg` to `buf` prefixed with its encoded length as a varint:
+/// the framing of protobuf's `writeDelimitedTo` and prost's
+/// `encode_length_delimited`. Messages appended this way, e.g. to a log
+/// in account data, read back with `DelimitedMessages`.
+pub fn encode_length_delimited<M: Message>(buf: &mut Vec<u8>, msg: &M) {
+    let len = msg.encoded_len();
+    buf.reserve(bytes_len(len));
+    encode_varint(buf, len as u64);
+    msg.encode_into(buf);
+}
+
+/// Number of bytes `encode_length_delimited` appends for `msg`.
+pub fn length_delimited_len<M: Message>(msg: &M) -> usize {
+    bytes_len(msg.encoded_len())
+}
+
+/// Decode the length-prefixed message at `pos`, returning it and the
+/// position after it. Frames are usually read from account data, so each
+/// may be as large as `DecodeOptions::ACCOUNT` allows.
+pub fn decode_length_delimited<M: Message>(data: &[u8], pos: usize) -> Result<(M, usize), DecodeError> {
+    decode_length_delimited_with(data, pos, &DecodeOptions::ACCOUNT)
+}
+
+pub fn decode_length_delimited_with<M: Message>(
+    data: &[u8],
+    pos: usize,
+    opts: &DecodeOptions,
+) -> Result<(M, usize), DecodeError> {
+    let (body, end) = decode_bytes_ref(data, pos)?;
+    Ok((M::decode_with(body, opts)?, end))
+}
+
+/// Payloads of back-to-back length-prefixed messages, borrowed from the
+/// buffer. Stops after the first error, e.g. `BufferOverflow` for a last
+/// frame that was cut short, with `position()` left at that frame.
+#[derive(Debug, Clone)]
+pub struct DelimitedFrames<'a> {
+    data: &'a [u8],
+    pos: usize,
+    failed: bool,
+}
+
+impl<'a> DelimitedFrames<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        DelimitedFrames { data, pos: 0, failed: false }
+    }
+
+    /// Offset of the next frame: once every frame is read, the end of the
+    /// last one, where the next message would be appended.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> Iterator for DelimitedFrames<'a> {
+    type Item = Result<&'a [u8], DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.pos >= self.data.len() {
+            return None;
+        }
+        match decode_bytes_ref(self.data, self.pos) {
+            Ok((body, end)) => {
+                self.pos = end;
+                Some(Ok(body))
+            }
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// `DelimitedFrames` with each payload decoded as an `M`. A payload that
+/// does not decode ends the iteration as well, with `position()` at the
+/// start of its frame.
+#[derive(Debug, Clone)]
+pub struct DelimitedMessages<'a, M> {
+    frames: DelimitedFrames<'a>,
+    opts: DecodeOptions,
+    message: core::marker::PhantomData<fn() -> M>,
+}
+
+impl<'a, M: Message> DelimitedMessages<'a, M> {
+    /// Decodes each payload with `DecodeOptions::ACCOUNT`, as
+    /// `decode_length_delimited` does.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self::with_options(data, DecodeOptions::ACCOUNT)
+    }
+
+    pub fn with_options(data: &'a [u8], opts: DecodeOptions) -> Self {
+        DelimitedMessages { frames: DelimitedFrames::new(data), opts, message: core::marker::PhantomData }
+    }
+
+    /// See `DelimitedFrames::position`.
+    pub fn position(&self) -> usize {
+        self.frames.position()
+    }
+}
+
+impl<M: Message> Iterator for DelimitedMessages<'_, M> {
+    type Item = Result<M, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.frames.pos;
+        let decoded = self.frames.next()?.and_then(|body| M::decode_with(body, &self.opts));
+        if decoded.is_err() {
+            self.frames.pos = start;
+            self.frames.failed = true;
+        }
+        Some(decoded)
+    }
+}
+
+// ── Slice writer (no-alloc encode) ───────────────────────────────────
+
+/// Encodes directly into a caller-provided slice (e.g. account data),
+/// tracking the write offset. Every `write_*` returns the new offset, or
+/// `DecodeError::BufferTooSmall` without writing anything if the value
+/// does not fit.
+pub struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        SliceWriter { buf, pos: 0 }
+    }
+
+    /// Number of bytes written so far.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Bytes still available in the underlying slice.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    #[inline]
+    pub fn write_raw(&mut self, bytes: &[u8]) -> Result<usize, DecodeError> {
+        if bytes.len() > self.remaining() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        self.buf[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+        Ok(self.pos)
+    }
+
+    #[inline]
+    pub fn write_varint(&mut self, mut value: u64) -> Result<usize, DecodeError> {
+        let mut tmp = [0u8; 10];
+        let mut n = 0;
+        loop {
+            if value < 0x80 {
+                tmp[n] = value as u8;
+                n += 1;
+                break;
+            }
+            tmp[n] = ((value & 0x7F) | 0x80) as u8;
+            n += 1;
+            value >>= 7;
+        }
+        self.write_raw(&tmp[..n])
+    }
+
+    #[inline]
+    pub fn write_key(&mut self, tag: u64) -> Result<usize, DecodeError> {
+        self.write_varint(tag)
+    }
+
+    #[inline]
+    pub fn write_bool(&mut self, value: bool) -> Result<usize, DecodeError> {
+        self.write_raw(&[value as u8])
+    }
+
+    #[inline]
+    pub fn write_int32(&mut self, value: i32) -> Result<usize, DecodeError> {
+        self.write_varint(value as i64 as u64)
+    }
+
+    #[inline]
+    pub fn write_int64(&mut self, value: i64) -> Result<usize, DecodeError> {
+        self.write_varint(value as u64)
+    }
+
+    #[inline]
+    pub fn write_zigzag32(&mut self, value: i32) -> Result<usize, DecodeError> {
+        self.write_varint(((value << 1) ^ (value >> 31)) as u32 as u64)
+    }
+
+    #[inline]
+    pub fn write_zigzag64(&mut self, value: i64) -> Result<usize, DecodeError> {
+        self.write_varint(((value << 1) ^ (value >> 63)) as u64)
+    }
+
+    #[inline]
+    pub fn write_fixed64(&mut self, value: u64) -> Result<usize, DecodeError> {
+        self.write_raw(&value.to_le_bytes())
+    }
+
+    #[inline]
+    pub fn write_sfixed64(&mut self, value: i64) -> Result<usize, DecodeError> {
+        self.write_fixed64(value as u64)
+    }
+
+    #[inline]
+    pub fn write_double(&mut self, value: f64) -> Result<usize, DecodeError> {
+        self.write_fixed64(value.to_bits())
+    }
+
+    #[inline]
+    pub fn write_fixed32(&mut self, value: u32) -> Result<usize, DecodeError> {
+        self.write_raw(&value.to_le_bytes())
+    }
+
+    #[inline]
+    pub fn write_sfixed32(&mut self, value: i32) -> Result<usize, DecodeError> {
+        self.write_fixed32(value as u32)
+    }
+
+    #[inline]
+    pub fn write_float(&mut self, value: f32) -> Result<usize, DecodeError> {
+        self.write_fixed32(value.to_bits())
+    }
+
+    /// Length-prefixed bytes. Fails without writing the prefix if the
+    /// prefix and payload together do not fit.
+    #[inline]
+    pub fn write_bytes(&mut self, value: &[u8]) -> Result<usize, DecodeError> {
+        let start = self.pos;
+        self.write_varint(value.len() as u64)?;
+        if value.len() > self.remaining() {
+            self.pos = start;
+            return Err(DecodeError::BufferTooSmall);
+        }
+        self.write_raw(value)
+    }
+
+    #[inline]
+    pub fn write_string(&mut self, value: &str) -> Result<usize, DecodeError> {
+        self.write_bytes(value.as_bytes())
+    }
+
+    #[inline]
+    pub fn write_pubkey(&mut self, value: &Pubkey) -> Result<usize, DecodeError> {
+        self.write_bytes(value.as_ref())
+    }
+
+    /// Sub-message field: key, `encoded_len` prefix, then the body.
+    #[inline]
+    pub fn write_message<M: Message>(&mut self, field: u32, msg: &M) -> Result<usize, DecodeError> {
+        self.write_key(((field as u64) << 3) | 2)?;
+        self.write_varint(msg.encoded_len() as u64)?;
+        msg.write_to(self)?;
+        Ok(self.pos)
+    }
+
+    /// Proto2 group field: start-group key, the body, then the end-group
+    /// key; nothing on the wire gives the body's length.
+    #[inline]
+    pub fn write_group<M: Message>(&mut self, field: u32, msg: &M) -> Result<usize, DecodeError> {
+        self.write_key(((field as u64) << 3) | 3)?;
+        msg.write_to(self)?;
+        self.write_key(((field as u64) << 3) | 4)
+    }
+
+    /// Write `msg` prefixed with its length, as `encode_length_delimited`
+    /// does, e.g. to append to a log in account data.
+    #[inline]
+    pub fn write_length_delimited<M: Message>(&mut self, msg: &M) -> Result<usize, DecodeError> {
+        let len = msg.encoded_len();
+        if bytes_len(len) > self.remaining() {
+            return Err(DecodeError::BufferTooSmall);
+        }
+        self.write_varint(len as u64)?;
+        msg.write_to(self)?;
+        Ok(self.pos)
+    }
+
+    /// `write_message` with the length prefix taken from `sizes`.
+    #[inline]
+    pub fn write_message_sized<M: Message>(
+        &mut self,
+        field: u32,
+        msg: &M,
+        sizes: &mut SizeCursor<'_>,
+    ) -> Result<usize, DecodeError> {
+        let len = sizes.next_size()?;
+        self.write_key(((field as u64) << 3) | 2)?;
+        self.write_varint(len as u64)?;
+        let start = self.pos;
+        msg.write_to_sized(self, sizes)?;
+        if self.pos - start != len {
+            return Err(STALE_SIZES);
+        }
+        Ok(self.pos)
+    }
+
+    /// `write_group` for `write_to_sized`. The group's length is recorded
+    /// like a sub-message's, so it is read back and checked against the
+    /// body, though not written.
+    #[inline]
+    pub fn write_group_sized<M: Message>(
+        &mut self,
+        field: u32,
+        msg: &M,
+        sizes: &mut SizeCursor<'_>,
+    ) -> Result<usize, DecodeError> {
+        let len = sizes.next_size()?;
+        self.write_key(((field as u64) << 3) | 3)?;
+        let start = self.pos;
+        msg.write_to_sized(self, sizes)?;
+        if self.pos - start != len {
+            return Err(STALE_SIZES);
+        }
+        self.write_key(((field as u64) << 3) | 4)
+    }
+}
+
+// ── Size cache (repeated encodes of one value) ───────────────────────
+//
+// Writing front to back, every sub-message needs its length before its
+// body, and `write_to` recomputes it at each level of nesting. A program
+// that encodes the same value more than once (an event and an account
+// write, say) can size it once instead: `SizeCache::of` walks the message
+// a single time, and the `_with_sizes` encoders read the lengths back.
+
+const STALE_SIZES: DecodeError = DecodeError::InvalidData("SizeCache does not match the message");
+
+/// The encoded length of a message and of each sub-message inside it, in
+/// the order they are written. A cache describes the value as it was
+/// when made; after a change, make a new one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SizeCache {
+    sizes: Vec<usize>,
+}
+
+impl SizeCache {
+    pub fn of<M: Message>(msg: &M) -> Self {
+        let mut cache = SizeCache::default();
+        cache.record(msg);
+        cache
+    }
+
+    /// Encoded length of the message the cache was made for.
+    pub fn encoded_len(&self) -> usize {
+        self.sizes.first().copied().unwrap_or(0)
+    }
+
+    /// Record the size of `msg` and then of its sub-messages, returning
+    /// its encoded length; generated `encoded_len_cached` calls it for
+    /// each sub-message.
+    pub fn record<M: Message>(&mut self, msg: &M) -> usize {
+        let slot = self.sizes.len();
+        self.sizes.push(0);
+        let len = msg.encoded_len_cached(self);
+        self.sizes[slot] = len;
+        len
+    }
+
+    /// The sub-message lengths from the start, for `write_to_sized`.
+    pub fn cursor(&self) -> SizeCursor<'_> {
+        SizeCursor { sizes: &self.sizes, next: 1 }
+    }
+}
+
+/// Reads a `SizeCache`'s sub-message lengths back in order.
+#[derive(Clone, Debug)]
+pub struct SizeCursor<'a> {
+    sizes: &'a [usize],
+    next: usize,
+}
+
+impl SizeCursor<'_> {
+    /// The next length, without moving past it: a map entry's length
+    /// prefix counts the value message it is followed by.
+    pub fn peek(&self) -> Result<usize, DecodeError> {
+        self.sizes.get(self.next).copied().ok_or(STALE_SIZES)
+    }
+
+    pub fn next_size(&mut self) -> Result<usize, DecodeError> {
+        let len = self.peek()?;
+        self.next += 1;
+        Ok(len)
+    }
+}
+
+// ── Reverse writer (single-pass encode) ──────────────────────────────
+//
+// A length-delimited sub-message is prefixed with its length, which a
+// front-to-back encoder does not know until the body is written: it must
+// size every sub-message first, or reserve a byte and shift the body when
+// the length needs more. Writing from the end of the buffer backwards,
+// last field first, the body is already in place when its length is
+// written in front of it, so each byte is written once at any depth.
+
+/// Encodes into the tail of a `Vec<u8>` from the back: every `write_*`
+/// puts a complete value (or key) in front of everything written so far,
+/// so a message is written last field first. Dropping the writer moves
+/// the output down to where `buf` ended and truncates the spare room.
+pub struct ReverseWriter<'a> {
+    buf: &'a mut Vec<u8>,
+    /// `buf.len()` when the writer was made; output lands here
+    base: usize,
+    /// Start of the output so far, which runs to `buf.len()`
+    start: usize,
+}
+
+impl<'a> ReverseWriter<'a> {
+    /// A writer appending to `buf`, growing it as needed.
+    #[inline]
+    pub fn new(buf: &'a mut Vec<u8>) -> Self {
+        Self::with_capacity(buf, 0)
+    }
+
+    /// A writer with room for `capacity` bytes up front: given the exact
+    /// `encoded_len`, it never grows and never moves the output.
+    #[inline]
+    pub fn with_capacity(buf: &'a mut Vec<u8>, capacity: usize) -> Self {
+        let base = buf.len();
+        buf.resize(base + capacity, 0);
+        ReverseWriter { buf, base, start: base + capacity }
+    }
+
+    /// Number of bytes written so far.
+    #[inline]
+    pub fn written(&self) -> usize {
+        self.buf.len() - self.start
+    }
+
+    /// Make room for `n` more bytes in front of the output, at least
+    /// doubling it so that growing is amortised.
+    fn reserve(&mut self, n: usize) {
+        let room = self.start - self.base;
+        if room >= n {
+            return;
+        }
+        let grow = (n - room).max(self.written()).max(64);
+        let end = self.buf.len();
+        self.buf.resize(end + grow, 0);
+        self.buf.copy_within(self.start..end, self.start + grow);
+        self.start += grow;
+    }
+
+    #[inline]
+    pub fn write_raw(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        self.start -= bytes.len();
+        self.buf[self.start..self.start + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[inline]
+    pub fn write_varint(&mut self, mut value: u64) {
+        let n = varint_len(value);
+        self.reserve(n);
+        self.start -= n;
+        for b in &mut self.buf[self.start..self.start + n - 1] {
+            *b = (value as u8 & 0x7F) | 0x80;
+            value >>= 7;
+        }
+        self.buf[self.start + n - 1] = value as u8;
+    }
+
+    #[inline]
+    pub fn write_key(&mut self, tag: u64) {
+        self.write_varint(tag);
+    }
+
+    #[inline]
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_raw(&[value as u8]);
+    }
+
+    #[inline]
+    pub fn write_int32(&mut self, value: i32) {
+        self.write_varint(value as i64 as u64);
+    }
+
+    #[inline]
+    pub fn write_int64(&mut self, value: i64) {
+        self.write_varint(value as u64);
+    }
+
+    #[inline]
+    pub fn write_zigzag32(&mut self, value: i32) {
+        self.write_varint(((value << 1) ^ (value >> 31)) as u32 as u64);
+    }
+
+    #[inline]
+    pub fn write_zigzag64(&mut self, value: i64) {
+        self.write_varint(((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    #[inline]
+    pub fn write_fixed64(&mut self, value: u64) {
+        self.write_raw(&value.to_le_bytes());
+    }
+
+    #[inline]
+    pub fn write_sfixed64(&mut self, value: i64) {
+        self.write_fixed64(value as u64);
+    }
+
+    #[inline]
+    pub fn write_double(&mut self, value: f64) {
+        self.write_fixed64(value.to_bits());
+    }
+
+    #[inline]
+    pub fn write_fixed32(&mut self, value: u32) {
+        self.write_raw(&value.to_le_bytes());
+    }
+
+    #[inline]
+    pub fn write_sfixed32(&mut self, value: i32) {
+        self.write_fixed32(value as u32);
+    }
+
+    #[inline]
+    pub fn write_float(&mut self, value: f32) {
+        self.write_fixed32(value.to_bits());
+    }
+
+    /// Length-prefixed bytes: the payload, then its length in front.
+    #[inline]
+    pub fn write_bytes(&mut self, value: &[u8]) {
+        self.write_raw(value);
+        self.write_varint(value.len() as u64);
+    }
+
+    #[inline]
+    pub fn write_string(&mut self, value: &str) {
+        self.write_bytes(value.as_bytes());
+    }
+
+    #[inline]
+    pub fn write_pubkey(&mut self, value: &Pubkey) {
+        self.write_bytes(value.as_ref());
+    }
+
+    /// Length-delimited field `field` whose body `f` writes (in reverse,
+    /// like everything else): a map entry, packed payload or wrapper.
+    /// The body's length and the key are written in front of it.
+    #[inline]
+    pub fn write_nested<F: FnOnce(&mut Self)>(&mut self, field: u32, f: F) {
+        let end = self.written();
+        f(self);
+        self.write_varint((self.written() - end) as u64);
+        self.write_key(((field as u64) << 3) | 2);
+    }
+
+    /// Sub-message field: body, length and key, sized as they are written.
+    #[inline]
+    pub fn write_message<M: Message>(&mut self, field: u32, msg: &M) {
+        self.write_nested(field, |w| msg.write_reverse(w));
+    }
+
+    /// Proto2 group field: end-group key, body, then the start-group key
+    /// in front of them.
+    #[inline]
+    pub fn write_group<M: Message>(&mut self, field: u32, msg: &M) {
+        self.write_key(((field as u64) << 3) | 4);
+        msg.write_reverse(self);
+        self.write_key(((field as u64) << 3) | 3);
+    }
+}
+
+impl Drop for ReverseWriter<'_> {
+    fn drop(&mut self) {
+        let len = self.written();
+        if self.start > self.base {
+            self.buf.copy_within(self.start.., self.base);
+        }
+        self.buf.truncate(self.base + len);
+    }
+}
+
+// ── Skip unknown fields ──────────────────────────────────────────────
+
+/// Skip the value of a field whose key `tag` has already been read. Only
+/// the wire type matters, except for groups, which end at the end-group
+/// key of the same field number.
+#[inline]
+pub fn skip_field(data: &[u8], pos: usize, tag: u64) -> Result<usize, DecodeError> {
+    match tag & 0x07 {
+        0 => {
+            // Varint: skip until MSB is clear
+            let (_, new_pos) = decode_varint(data, pos)?;
+            Ok(new_pos)
+        }
+        // 64-bit: skip 8 bytes
+        1 => checked_end(pos, 8, data.len()),
+        2 => {
+            // Length-delimited: read length, skip that many bytes
+            let (len, new_pos) = decode_varint(data, pos)?;
+            checked_end(new_pos, len, data.len())
+        }
+        // 32-bit: skip 4 bytes
+        5 => checked_end(pos, 4, data.len()),
+        3 => skip_group(data, pos, tag >> 3, DecodeOptions::DEFAULT.max_depth).map(|(_, end)| end),
+        4 => Err(STRAY_END_GROUP),
+        wire_type => Err(DecodeError::UnknownWireType(wire_type)),
+    }
+}
+
+const STRAY_END_GROUP: DecodeError = DecodeError::InvalidData("end-group tag without a start group");
+const MISMATCHED_END_GROUP: DecodeError = DecodeError::InvalidData("end-group tag does not match its start group");
+
+/// Skip the fields of group `field` up to its end-group key, through at
+/// most `depth` levels of nested groups. Returns where the end key starts
+/// and where it ends.
+fn skip_group(data: &[u8], mut pos: usize, field: u64, depth: usize) -> Result<(usize, usize), DecodeError> {
+    if depth == 0 {
+        return Err(DecodeError::RecursionLimitExceeded);
+    }
+    loop {
+        let (tag, new_pos) = decode_key(data, pos)?;
+        pos = match tag & 0x07 {
+            4 if tag >> 3 == field => return Ok((pos, new_pos)),
+            4 => return Err(MISMATCHED_END_GROUP),
+            3 => skip_group(data, new_pos, tag >> 3, depth - 1)?.1,
+            _ => skip_field(data, new_pos, tag)?,
+        };
+    }
+}
+
+// ── Reader (cursor decode) ───────────────────────────────────────────
+
+/// Cursor over an encoded buffer. Wraps the free `decode_*` functions so
+/// callers don't thread `pos` by hand; each `read_*` advances past the
+/// value it returns, and leaves the cursor untouched on error.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    #[inline]
+    pub fn new(data: &'a [u8]) -> Self {
+        Reader { data, pos: 0 }
+    }
+
+    /// Offset of the next unread byte.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Bytes not yet consumed.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.pos)
+    }
+
+    #[inline]
+    pub fn is_eof(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    /// Decode the next tag without consuming it; `None` at end of buffer.
+    #[inline]
+    pub fn peek_key(&self) -> Result<Option<u64>, DecodeError> {
+        if self.is_eof() {
+            return Ok(None);
+        }
+        let (tag, _) = decode_key(self.data, self.pos)?;
+        Ok(Some(tag))
+    }
+
+    #[inline]
+    pub fn read_key(&mut self) -> Result<u64, DecodeError> {
+        let (tag, pos) = decode_key(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(tag)
+    }
+
+    #[inline]
+    pub fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        let (v, pos) = decode_varint(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    #[inline]
+    pub fn read_bool(&mut self) -> Result<bool, DecodeError> {
+        let (v, pos) = decode_bool(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    #[inline]
+    pub fn read_zigzag32(&mut self) -> Result<i32, DecodeError> {
+        let (v, pos) = decode_zigzag32(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    #[inline]
+    pub fn read_zigzag64(&mut self) -> Result<i64, DecodeError> {
+        let (v, pos) = decode_zigzag64(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    #[inline]
+    pub fn read_fixed64(&mut self) -> Result<u64, DecodeError> {
+        let (v, pos) = decode_fixed64(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    #[inline]
+    pub fn read_sfixed64(&mut self) -> Result<i64, DecodeError> {
+        let (v, pos) = decode_sfixed64(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    #[inline]
+    pub fn read_fixed32(&mut self) -> Result<u32, DecodeError> {
+        let (v, pos) = decode_fixed32(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    #[inline]
+    pub fn read_sfixed32(&mut self) -> Result<i32, DecodeError> {
+        let (v, pos) = decode_sfixed32(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    /// Length-delimited payload, borrowed from the input.
+    #[inline]
+    pub fn read_bytes(&mut self) -> Result<&'a [u8], DecodeError> {
+        let (v, pos) = decode_bytes_ref(self.data, self.pos)?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    #[inline]
+    pub fn read_str(&mut self) -> Result<&'a str, DecodeError> {
+        let (raw, pos) = decode_bytes_ref(self.data, self.pos)?;
+        let v = core::str::from_utf8(raw)
+            .map_err(|_| DecodeError::InvalidData("invalid UTF-8 in string field"))?;
+        self.pos = pos;
+        Ok(v)
+    }
+
+    /// Run `f` on a sub-reader limited to the next length-delimited
+    /// payload. Fails if `f` leaves any of the payload unread; on success
+    /// the cursor moves past the payload.
+    pub fn read_message<T, F>(&mut self, f: F) -> Result<T, DecodeError>
+    where
+        F: FnOnce(&mut Reader<'a>) -> Result<T, DecodeError>,
+    {
+        let (sub, end) = decode_message_slice(self.data, self.pos)?;
+        let mut inner = Reader::new(sub);
+        let value = f(&mut inner)?;
+        if !inner.is_eof() {
+            return Err(DecodeError::InvalidData("sub-message not fully consumed"));
+        }
+        self.pos = end;
+        Ok(value)
+    }
+
+    /// Skip the value of a field whose key `tag` has already been read.
+    #[inline]
+    pub fn skip_field(&mut self, tag: u64) -> Result<(), DecodeError> {
+        self.pos = skip_field(self.data, self.pos, tag)?;
+        Ok(())
+    }
+}
+
+// ── Chunked reader (scatter buffers) ─────────────────────────────────
+
+/// Read interface shared by `Reader` and `ChunkedReader`, so decode logic
+/// can be written once for contiguous and scattered input alike. Every
+/// read leaves the cursor untouched on error.
+pub trait WireRead<'a> {
+    /// Logical offset of the next unread byte.
+    fn position(&self) -> usize;
+    /// Bytes not yet consumed.
+    fn remaining(&self) -> usize;
+    /// Decode the next tag without consuming it; `None` at end of input.
+    fn peek_key(&self) -> Result<Option<u64>, DecodeError>;
+    fn read_varint(&mut self) -> Result<u64, DecodeError>;
+    fn read_fixed64(&mut self) -> Result<u64, DecodeError>;
+    fn read_fixed32(&mut self) -> Result<u32, DecodeError>;
+    /// Length-delimited payload, borrowed whenever it is contiguous.
+    fn read_bytes(&mut self) -> Result<Cow<'a, [u8]>, DecodeError>;
+    fn read_str(&mut self) -> Result<Cow<'a, str>, DecodeError>;
+    /// Skip the value of a field whose key `tag` has already been read;
+    /// a group is skipped through its matching end-group key.
+    fn skip_field(&mut self, tag: u64) -> Result<(), DecodeError>;
+
+    fn is_eof(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// The next tag, checked as `decode_key` checks it.
+    fn read_key(&mut self) -> Result<u64, DecodeError> {
+        check_key(self.read_varint()?)
+    }
+
+    fn read_bool(&mut self) -> Result<bool, DecodeError> {
+        Ok(self.read_varint()? != 0)
+    }
+
+    fn read_zigzag32(&mut self) -> Result<i32, DecodeError> {
+        let n = self.read_varint()? as u32;
+        Ok(((n >> 1) as i32) ^ (-((n & 1) as i32)))
+    }
+
+    fn read_zigzag64(&mut self) -> Result<i64, DecodeError> {
+        let n = self.read_varint()?;
+        Ok(((n >> 1) as i64) ^ (-((n & 1) as i64)))
+    }
+
+    fn read_sfixed64(&mut self) -> Result<i64, DecodeError> {
+        Ok(self.read_fixed64()? as i64)
+    }
+
+    fn read_sfixed32(&mut self) -> Result<i32, DecodeError> {
+        Ok(self.read_fixed32()? as i32)
+    }
+}
+
+impl<'a> WireRead<'a> for Reader<'a> {
+    fn position(&self) -> usize {
+        Reader::position(self)
+    }
+
+    fn remaining(&self) -> usize {
+        Reader::remaining(self)
+    }
+
+    fn peek_key(&self) -> Result<Option<u64>, DecodeError> {
+        Reader::peek_key(self)
+    }
+
+    fn read_key(&mut self) -> Result<u64, DecodeError> {
+        Reader::read_key(self)
+    }
+
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        Reader::read_varint(self)
+    }
+
+    fn read_fixed64(&mut self) -> Result<u64, DecodeError> {
+        Reader::read_fixed64(self)
+    }
+
+    fn read_fixed32(&mut self) -> Result<u32, DecodeError> {
+        Reader::read_fixed32(self)
+    }
+
+    fn read_bytes(&mut self) -> Result<Cow<'a, [u8]>, DecodeError> {
+        Reader::read_bytes(self).map(Cow::Borrowed)
+    }
+
+    fn read_str(&mut self) -> Result<Cow<'a, str>, DecodeError> {
+        Reader::read_str(self).map(Cow::Borrowed)
+    }
+
+    fn skip_field(&mut self, tag: u64) -> Result<(), DecodeError> {
+        Reader::skip_field(self, tag)
+    }
+}
+
+/// Cursor over the logical concatenation of several slices, e.g. an
+/// instruction payload split across account regions. Varints and
+/// length-delimited values may straddle chunk boundaries; bounds are
+/// checked against the total length, not the individual chunks.
+#[derive(Clone, Copy)]
+pub struct ChunkedReader<'a> {
+    chunks: &'a [&'a [u8]],
+    chunk: usize,
+    offset: usize,
+    pos: usize,
+    len: usize,
+}
+
+impl<'a> ChunkedReader<'a> {
+    pub fn new(chunks: &'a [&'a [u8]]) -> Self {
+        let len = chunks.iter().map(|c| c.len()).sum();
+        let mut reader = ChunkedReader { chunks, chunk: 0, offset: 0, pos: 0, len };
+        reader.settle();
+        reader
+    }
+
+    /// Move past exhausted (or empty) chunks so `chunk` points at the next byte.
+    #[inline]
+    fn settle(&mut self) {
+        while self.chunk < self.chunks.len() && self.offset >= self.chunks[self.chunk].len() {
+            self.chunk += 1;
+            self.offset = 0;
+        }
+    }
+
+    #[inline]
+    fn next_byte(&mut self) -> Result<u8, DecodeError> {
+        if self.pos >= self.len {
+            return Err(DecodeError::BufferOverflow);
+        }
+        let b = self.chunks[self.chunk][self.offset];
+        self.offset += 1;
+        self.pos += 1;
+        self.settle();
+        Ok(b)
+    }
+
+    fn advance(&mut self, mut n: usize) -> Result<(), DecodeError> {
+        if n > self.len - self.pos {
+            return Err(DecodeError::BufferOverflow);
+        }
+        self.pos += n;
+        while n > 0 {
+            let take = n.min(self.chunks[self.chunk].len() - self.offset);
+            self.offset += take;
+            n -= take;
+            self.settle();
+        }
+        Ok(())
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        if N > self.len - self.pos {
+            return Err(DecodeError::BufferOverflow);
+        }
+        let mut out = [0u8; N];
+        for b in &mut out {
+            *b = self.next_byte()?;
+        }
+        Ok(out)
+    }
+
+    /// Skip the fields of group `field` through its end-group key, at most
+    /// `depth` levels of nested groups deep.
+    fn skip_group(&mut self, field: u64, depth: usize) -> Result<(), DecodeError> {
+        if depth == 0 {
+            return Err(DecodeError::RecursionLimitExceeded);
+        }
+        loop {
+            let tag = self.read_key()?;
+            match tag & 0x07 {
+                4 if tag >> 3 == field => return Ok(()),
+                4 => return Err(MISMATCHED_END_GROUP),
+                3 => self.skip_group(tag >> 3, depth - 1)?,
+                _ => self.skip_field(tag)?,
+            }
+        }
+    }
+
+    /// Split off the next `len` bytes, borrowing when they sit in one chunk.
+    fn take(&mut self, len: usize) -> Result<Cow<'a, [u8]>, DecodeError> {
+        if len > self.len - self.pos {
+            return Err(DecodeError::BufferOverflow);
+        }
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+        let chunks = self.chunks;
+        if self.offset + len <= chunks[self.chunk].len() {
+            let (chunk, start) = (chunks[self.chunk], self.offset);
+            self.advance(len)?;
+            return Ok(Cow::Borrowed(&chunk[start..start + len]));
+        }
+        let mut out = Vec::with_capacity(len);
+        while out.len() < len {
+            let chunk = chunks[self.chunk];
+            let take = (len - out.len()).min(chunk.len() - self.offset);
+            out.extend_from_slice(&chunk[self.offset..self.offset + take]);
+            self.advance(take)?;
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl<'a> WireRead<'a> for ChunkedReader<'a> {
+    fn position(&self) -> usize {
+        self.pos
+    }
+
+    fn remaining(&self) -> usize {
+        self.len - self.pos
+    }
+
+    fn peek_key(&self) -> Result<Option<u64>, DecodeError> {
+        if self.is_eof() {
+            return Ok(None);
+        }
+        let mut probe = *self;
+        probe.read_key().map(Some)
+    }
+
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        let mut probe = *self;
+        let mut result: u64 = 0;
+        let mut shift: u32 = 0;
+        loop {
+            let b = probe.next_byte()?;
+            result |= ((b & 0x7F) as u64) << shift;
+            if b & 0x80 == 0 {
+                *self = probe;
+                return Ok(result);
+            }
+            shift += 7;
+            if shift > 63 {
+                return Err(DecodeError::InvalidVarint);
+            }
+        }
+    }
+
+    fn read_fixed64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_fixed32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_bytes(&mut self) -> Result<Cow<'a, [u8]>, DecodeError> {
+        let mut probe = *self;
+        let len = probe.read_varint()?;
+        let end = checked_end(probe.pos, len, probe.len)?;
+        let value = probe.take(end - probe.pos)?;
+        *self = probe;
+        Ok(value)
+    }
+
+    fn read_str(&mut self) -> Result<Cow<'a, str>, DecodeError> {
+        let mut probe = *self;
+        let value = match probe.read_bytes()? {
+            Cow::Borrowed(raw) => core::str::from_utf8(raw).map(Cow::Borrowed).ok(),
+            Cow::Owned(raw) => String::from_utf8(raw).map(Cow::Owned).ok(),
+        }
+        .ok_or(DecodeError::InvalidData("invalid UTF-8 in string field"))?;
+        *self = probe;
+        Ok(value)
+    }
+
+    fn skip_field(&mut self, tag: u64) -> Result<(), DecodeError> {
+        let mut probe = *self;
+        match tag & 0x07 {
+            0 => {
+                probe.read_varint()?;
+            }
+            1 => probe.advance(8)?,
+            2 => {
+                let len = probe.read_varint()?;
+                let end = checked_end(probe.pos, len, probe.len)?;
+                probe.advance(end - probe.pos)?;
+            }
+            5 => probe.advance(4)?,
+            3 => probe.skip_group(tag >> 3, DecodeOptions::DEFAULT.max_depth)?,
+            4 => return Err(STRAY_END_GROUP),
+            wire_type => return Err(DecodeError::UnknownWireType(wire_type)),
+        }
+        *self = probe;
+        Ok(())
+    }
+}
+
+// ── Field-at-a-time decoder ──────────────────────────────────────────
+
+/// One field read by `Decoder::next_field`: its key, its value borrowed
+/// from the input, and its complete encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Field<'a> {
+    pub tag: u64,
+    pub value: FieldValue<'a>,
+    /// Key and value bytes exactly as they appear in the input, e.g. to
+    /// forward the field unchanged.
+    pub raw: &'a [u8],
+}
+
+impl<'a> Field<'a> {
+    pub fn number(&self) -> u32 {
+        (self.tag >> 3) as u32
+    }
+
+    pub fn wire_type(&self) -> u64 {
+        self.tag & 0x07
+    }
+
+    fn mismatch(&self, expected: u64) -> DecodeError {
+        DecodeError::WireTypeMismatch { expected, found: self.wire_type() }
+    }
+
+    pub fn varint(&self) -> Result<u64, DecodeError> {
+        match self.value {
+            FieldValue::Varint(v) => Ok(v),
+            _ => Err(self.mismatch(0)),
+        }
+    }
+
+    pub fn fixed64(&self) -> Result<u64, DecodeError> {
+        match self.value {
+            FieldValue::Fixed64(v) => Ok(v),
+            _ => Err(self.mismatch(1)),
+        }
+    }
+
+    pub fn fixed32(&self) -> Result<u32, DecodeError> {
+        match self.value {
+            FieldValue::Fixed32(v) => Ok(v),
+            _ => Err(self.mismatch(5)),
+        }
+    }
+
+    /// Length-delimited payload: bytes, a string, a sub-message or a
+    /// packed run.
+    pub fn bytes(&self) -> Result<&'a [u8], DecodeError> {
+        match self.value {
+            FieldValue::LengthDelimited(v) => Ok(v),
+            _ => Err(self.mismatch(2)),
+        }
+    }
+
+    pub fn str(&self) -> Result<&'a str, DecodeError> {
+        core::str::from_utf8(self.bytes()?).map_err(|_| DecodeError::InvalidData("invalid UTF-8 in string field"))
+    }
+
+    /// Decode the payload as a sub-message. It is part of a buffer that is
+    /// already in memory, so only `DecodeOptions::ACCOUNT`'s size cap applies.
+    pub fn message<M: Message>(&self) -> Result<M, DecodeError> {
+        self.message_with(&DecodeOptions::ACCOUNT)
+    }
+
+    pub fn message_with<M: Message>(&self, opts: &DecodeOptions) -> Result<M, DecodeError> {
+        M::decode_with(self.bytes()?, opts)
+    }
+
+    /// A `Decoder` over the payload, to walk a sub-message lazily as well.
+    pub fn decoder(&self) -> Result<Decoder<'a>, DecodeError> {
+        Ok(Decoder::new(self.bytes()?))
+    }
+}
+
+/// Resumable, field-at-a-time decoder for messages too large to decode in
+/// one instruction's compute budget. Each `next_field` reads one top-level
+/// field and nothing more; `position()` can be saved (e.g. in a scratch
+/// account) and passed to `resume` by a later instruction to carry on with
+/// the next field of the same, unchanged, buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Decoder { data, pos: 0 }
+    }
+
+    /// Continue at a field boundary previously reported by `position()`.
+    pub fn resume(data: &'a [u8], position: usize) -> Result<Self, DecodeError> {
+        if position > data.len() {
+            return Err(DecodeError::BufferOverflow);
+        }
+        Ok(Decoder { data, pos: position })
+    }
+
+    /// Offset of the next field.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Bytes not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.pos)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    /// Read the next field; `None` once the buffer is exhausted. A
+    /// malformed field is an error and leaves the position at its start.
+    pub fn next_field(&mut self) -> Result<Option<Field<'a>>, DecodeError> {
+        if self.is_done() {
+            return Ok(None);
+        }
+        let start = self.pos;
+        let (tag, pos) = decode_key(self.data, start)?;
+        let (value, end) = decode_field_value(self.data, pos, tag)?;
+        let raw = self.data.get(start..end).ok_or(DecodeError::BufferOverflow)?;
+        self.pos = end;
+        Ok(Some(Field { tag, value, raw }))
+    }
+}
+
+// ── Borrowed views ───────────────────────────────────────────────────
+
+/// Reads one element starting just past its key, returning the value and
+/// the position after it.
+pub type ElemDecoder<'a, T> = fn(&'a [u8], usize) -> Result<(T, usize), DecodeError>;
+
+/// Repeated field of a generated `<Message>Ref` view. Holds the message
+/// buffer and decodes matching occurrences on iteration, so reading a
+/// view never allocates.
+pub struct RepeatedRef<'a, T> {
+    data: &'a [u8],
+    tag: u64,
+    packed: bool,
+    decode: ElemDecoder<'a, T>,
+}
+
+impl<'a, T> RepeatedRef<'a, T> {
+    /// Elements of `data` keyed by `tag`, each read with `decode` positioned
+    /// just past the key.
+    pub fn new(data: &'a [u8], tag: u64, decode: ElemDecoder<'a, T>) -> Self {
+        RepeatedRef { data, tag, packed: false, decode }
+    }
+
+    /// Like `new` for a packable scalar: elements may also appear in
+    /// packed runs under the field's length-delimited key.
+    pub fn packed(data: &'a [u8], tag: u64, decode: ElemDecoder<'a, T>) -> Self {
+        RepeatedRef { data, tag, packed: true, decode }
+    }
+
+    pub fn iter(&self) -> RepeatedRefIter<'a, T> {
+        RepeatedRefIter {
+            data: self.data,
+            pos: 0,
+            run_end: 0,
+            tag: self.tag,
+            packed: self.packed,
+            decode: self.decode,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+impl<T> Clone for RepeatedRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RepeatedRef<'_, T> {}
+
+impl<T: fmt::Debug> fmt::Debug for RepeatedRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T> IntoIterator for RepeatedRef<'a, T> {
+    type Item = Result<T, DecodeError>;
+    type IntoIter = RepeatedRefIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over a `RepeatedRef`; stops after the first error.
+pub struct RepeatedRefIter<'a, T> {
+    data: &'a [u8],
+    pos: usize,
+    /// End of the packed run being read; `pos < run_end` while inside one
+    run_end: usize,
+    tag: u64,
+    packed: bool,
+    decode: ElemDecoder<'a, T>,
+}
+
+impl<T> RepeatedRefIter<'_, T> {
+    fn fail(&mut self, err: DecodeError) -> Option<Result<T, DecodeError>> {
+        self.pos = self.data.len();
+        self.run_end = 0;
+        Some(Err(err))
+    }
+}
+
+impl<T> Iterator for RepeatedRefIter<'_, T> {
+    type Item = Result<T, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.run_end {
+            return match (self.decode)(self.data, self.pos) {
+                Ok((_, new_pos)) if new_pos > self.run_end => {
+                    self.fail(DecodeError::InvalidData("packed element overruns its payload"))
+                }
+                Ok((value, new_pos)) => {
+                    self.pos = new_pos;
+                    Some(Ok(value))
+                }
+                Err(e) => self.fail(e),
+            };
+        }
+        while self.pos < self.data.len() {
+            let (tag, pos) = match decode_key(self.data, self.pos) {
+                Ok(key) => key,
+                Err(e) => return self.fail(e),
+            };
+            if self.packed && tag == (self.tag & !0x07) | 2 {
+                let (start, end) = match decode_varint(self.data, pos)
+                    .and_then(|(len, start)| Ok((start, checked_end(start, len, self.data.len())?)))
+                {
+                    Ok(run) => run,
+                    Err(e) => return self.fail(e),
+                };
+                self.pos = start;
+                self.run_end = end;
+                if start < end {
+                    return self.next();
+                }
+                continue;
+            }
+            if tag == self.tag {
+                return match (self.decode)(self.data, pos) {
+                    Ok((value, new_pos)) => {
+                        self.pos = new_pos;
+                        Some(Ok(value))
+                    }
+                    Err(e) => self.fail(e),
+                };
+            }
+            match skip_field(self.data, pos, tag) {
+                Ok(new_pos) => self.pos = new_pos,
+                Err(e) => return self.fail(e),
+            }
+        }
+        None
+    }
+}
+
+/// Sub-message of a generated `<Message>Ref` view whose type contains the
+/// view's message again. Holds the sub-message's bytes and decodes them
+/// on `get`, so decoding a view reads one level of the recursion however
+/// deep the input nests.
+pub struct LazyRef<'a, T> {
+    data: &'a [u8],
+    decode: fn(&'a [u8]) -> Result<T, DecodeError>,
+}
+
+impl<'a, T> LazyRef<'a, T> {
+    /// The sub-message in `data`, read with `decode` when asked for.
+    pub fn new(data: &'a [u8], decode: fn(&'a [u8]) -> Result<T, DecodeError>) -> Self {
+        LazyRef { data, decode }
+    }
+
+    /// Decode the sub-message's view.
+    pub fn get(&self) -> Result<T, DecodeError> {
+        (self.decode)(self.data)
+    }
+
+    /// The sub-message's encoded bytes.
+    pub fn raw(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl<T> Clone for LazyRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for LazyRef<'_, T> {}
+
+// Not the decoded view: printing it would recurse as deep as the input
+impl<T> fmt::Debug for LazyRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyRef").field("len", &self.data.len()).finish()
+    }
+}
+
+// ── Text format ──────────────────────────────────────────────────────
+//
+// Protobuf text format writer behind the generated `write_text` methods
+// and `Display` impls, e.g. for `msg!` logging. `{}` renders one line
+// (`id: 7 owner { name: "a" }`), `{:#}` one field per line, indented.
+
+/// Text format writer with indentation and field separators.
+pub struct TextWriter {
+    out: String,
+    pretty: bool,
+    depth: usize,
+    /// No field written yet at the top level
+    first: bool,
+}
+
+impl TextWriter {
+    /// `pretty` puts every field on its own line.
+    pub fn new(pretty: bool) -> Self {
+        TextWriter { out: String::new(), pretty, depth: 0, first: true }
+    }
+
+    pub fn finish(self) -> String {
+        self.out
+    }
+
+    fn field(&mut self, name: &str) {
+        if self.pretty {
+            for _ in 0..self.depth {
+                self.out.push_str("  ");
+            }
+        } else if !self.first {
+            self.out.push(' ');
+        }
+        self.first = false;
+        self.out.push_str(name);
+    }
+
+    fn end_field(&mut self) {
+        if self.pretty {
+            self.out.push('\n');
+        }
+    }
+
+    pub fn begin_message(&mut self, name: &str) {
+        self.field(name);
+        self.out.push_str(" {");
+        self.end_field();
+        self.depth += 1;
+    }
+
+    pub fn end_message(&mut self) {
+        self.depth -= 1;
+        self.field("}");
+        self.end_field();
+    }
+
+    /// Integers and bools in their decimal / `true` form.
+    pub fn write_scalar(&mut self, name: &str, value: impl fmt::Display) {
+        self.field(name);
+        self.out.push_str(": ");
+        self.out.push_str(&value.to_string());
+        self.end_field();
+    }
+
+    pub fn write_f64(&mut self, name: &str, value: f64) {
+        if value.is_nan() {
+            self.write_scalar(name, "nan");
+        } else if value.is_infinite() {
+            self.write_scalar(name, if value > 0.0 { "inf" } else { "-inf" });
+        } else {
+            self.write_scalar(name, value);
+        }
+    }
+
+    pub fn write_f32(&mut self, name: &str, value: f32) {
+        self.write_f64(name, value as f64);
+    }
+
+    /// UTF-8 is kept; quotes, backslashes and control characters are
+    /// escaped.
+    pub fn write_str(&mut self, name: &str, value: &str) {
+        self.field(name);
+        self.out.push_str(": \"");
+        for c in value.chars() {
+            if c.is_ascii() {
+                push_text_byte(&mut self.out, c as u8);
+            } else {
+                self.out.push(c);
+            }
+        }
+        self.out.push('"');
+        self.end_field();
+    }
+
+    /// Printable ASCII as-is, every other byte as a `\ooo` octal escape.
+    pub fn write_bytes(&mut self, name: &str, value: &[u8]) {
+        self.field(name);
+        self.out.push_str(": \"");
+        for &b in value {
+            if b.is_ascii() {
+                push_text_byte(&mut self.out, b);
+            } else {
+                self.out.push_str(&format!("\\{:03o}", b));
+            }
+        }
+        self.out.push('"');
+        self.end_field();
+    }
+
+    /// Pubkeys are bytes fields, so escaped like any other.
+    pub fn write_pubkey(&mut self, name: &str, value: &Pubkey) {
+        self.write_bytes(name, value.as_ref());
+    }
+
+    /// Enums are written by name; values missing from `names` fall
+    /// back to their number.
+    pub fn write_enum(&mut self, name: &str, value: i32, names: &[(&str, i32)]) {
+        match names.iter().find(|(_, n)| *n == value) {
+            Some((variant, _)) => self.write_scalar(name, variant),
+            None => self.write_scalar(name, value),
+        }
+    }
+}
+
+/// One ASCII byte of a quoted text format string.
+fn push_text_byte(out: &mut String, b: u8) {
+    match b {
+        b'"' => out.push_str("\\\""),
+        b'\'' => out.push_str("\\'"),
+        b'\\' => out.push_str("\\\\"),
+        b'\n' => out.push_str("\\n"),
+        b'\r' => out.push_str("\\r"),
+        b'\t' => out.push_str("\\t"),
+        0x20..=0x7e => out.push(b as char),
+        _ => out.push_str(&format!("\\{:03o}", b)),
+    }
+}
+
+// ── Well-known types (google/protobuf/*.proto) ───────────────────────
+//
+// Hand-written stand-ins for the imported google.protobuf messages.
+// Fields of these types resolve here instead of to generated code;
+// singular wrapper fields (`UInt64Value` etc.) are generated as plain
+// `Option<T>` and use the `*Value` helpers below for their wire form.
+
+/// Seconds bounds of a valid `Timestamp`: 0001-01-01 to 9999-12-31 UTC.
+const TIMESTAMP_SECONDS_MIN: i64 = -62_135_596_800;
+const TIMESTAMP_SECONDS_MAX: i64 = 253_402_300_799;
+/// About 10,000 years, the range `Duration` allows either way.
+const DURATION_SECONDS_MAX: i64 = 315_576_000_000;
+
+/// Body of `Timestamp` / `Duration`: `seconds` = 1 (int64), `nanos` = 2 (int32).
+fn encode_seconds_nanos(buf: &mut Vec<u8>, seconds: i64, nanos: i32) {
+    if seconds != 0 {
+        encode_key(buf, 0x08);
+        encode_int64(buf, seconds);
+    }
+    if nanos != 0 {
+        encode_key(buf, 0x10);
+        encode_int32(buf, nanos);
+    }
+}
+
+fn seconds_nanos_len(seconds: i64, nanos: i32) -> usize {
+    let mut len = 0;
+    if seconds != 0 {
+        len += 1 + int64_len(seconds);
+    }
+    if nanos != 0 {
+        len += 1 + int32_len(nanos);
+    }
+    len
+}
+
+fn write_seconds_nanos(w: &mut SliceWriter<'_>, seconds: i64, nanos: i32) -> Result<(), DecodeError> {
+    if seconds != 0 {
+        w.write_key(0x08)?;
+        w.write_int64(seconds)?;
+    }
+    if nanos != 0 {
+        w.write_key(0x10)?;
+        w.write_int32(nanos)?;
+    }
+    Ok(())
+}
+
+fn write_seconds_nanos_reverse(w: &mut ReverseWriter<'_>, seconds: i64, nanos: i32) {
+    if nanos != 0 {
+        w.write_int32(nanos);
+        w.write_key(0x10);
+    }
+    if seconds != 0 {
+        w.write_int64(seconds);
+        w.write_key(0x08);
+    }
+}
+
+fn merge_seconds_nanos(seconds: &mut i64, nanos: &mut i32, data: &[u8]) -> Result<(), DecodeError> {
+    let mut pos = 0;
+    while pos < data.len() {
+        let (tag, new_pos) = decode_key(data, pos)?;
+        pos = match tag {
+            0x08 => {
+                let (v, new_pos) = decode_int64(data, new_pos)?;
+                *seconds = v;
+                new_pos
+            }
+            0x10 => {
+                let (v, new_pos) = decode_int32(data, new_pos)?;
+                *nanos = v;
+                new_pos
+            }
+            _ => skip_field(data, new_pos, tag)?,
+        };
+    }
+    Ok(())
+}
+
+/// `google.protobuf.Timestamp`: a point in time as seconds and
+/// non-negative nanoseconds since the Unix epoch (UTC).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timestamp {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+impl Timestamp {
+    /// Negative `seconds` and `nanos` take 10 bytes each.
+    pub const MAX_ENCODED_LEN: usize = 22;
+    /// The Unix epoch.
+    pub const DEFAULT: Self = Timestamp { seconds: 0, nanos: 0 };
+
+    pub fn new(seconds: i64, nanos: i32) -> Self {
+        Timestamp { seconds, nanos }
+    }
+
+    /// Whether the value is within the range protobuf allows
+    /// (years 1 to 9999, `nanos` in `0..1_000_000_000`).
+    pub fn is_valid(&self) -> bool {
+        (TIMESTAMP_SECONDS_MIN..=TIMESTAMP_SECONDS_MAX).contains(&self.seconds)
+            && (0..1_000_000_000).contains(&self.nanos)
+    }
+
+    pub fn write_text(&self, out: &mut TextWriter) {
+        write_seconds_nanos_text(out, self.seconds, self.nanos);
+    }
+}
+
+impl MessageName for Timestamp {
+    const FULL_NAME: &'static str = "google.protobuf.Timestamp";
+}
+
+impl Message for Timestamp {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        encode_seconds_nanos(buf, self.seconds, self.nanos);
+    }
+
+    fn encoded_len(&self) -> usize {
+        seconds_nanos_len(self.seconds, self.nanos)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        write_seconds_nanos(w, self.seconds, self.nanos)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        write_seconds_nanos_reverse(w, self.seconds, self.nanos);
+    }
+
+    fn merge_with(&mut self, data: &[u8], _opts: &DecodeOptions) -> Result<(), DecodeError> {
+        merge_seconds_nanos(&mut self.seconds, &mut self.nanos, data)
+    }
+}
+
+/// `google.protobuf.Duration`: a signed span of time; `seconds` and
+/// `nanos` carry the same sign.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Duration {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+impl Duration {
+    pub const MAX_ENCODED_LEN: usize = 22;
+    pub const DEFAULT: Self = Duration { seconds: 0, nanos: 0 };
+
+    pub fn new(seconds: i64, nanos: i32) -> Self {
+        Duration { seconds, nanos }
+    }
+
+    /// Whether the value is within the range protobuf allows
+    /// (±10,000 years, `nanos` below one second with the sign of `seconds`).
+    pub fn is_valid(&self) -> bool {
+        (-DURATION_SECONDS_MAX..=DURATION_SECONDS_MAX).contains(&self.seconds)
+            && (-999_999_999..=999_999_999).contains(&self.nanos)
+            && (self.seconds == 0 || self.nanos == 0 || (self.seconds < 0) == (self.nanos < 0))
+    }
+
+    pub fn write_text(&self, out: &mut TextWriter) {
+        write_seconds_nanos_text(out, self.seconds, self.nanos);
+    }
+}
+
+/// Text format body shared by `Timestamp` and `Duration`.
+fn write_seconds_nanos_text(out: &mut TextWriter, seconds: i64, nanos: i32) {
+    if seconds != 0 {
+        out.write_scalar("seconds", seconds);
+    }
+    if nanos != 0 {
+        out.write_scalar("nanos", nanos);
+    }
+}
+
+impl MessageName for Duration {
+    const FULL_NAME: &'static str = "google.protobuf.Duration";
+}
+
+impl Message for Duration {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        encode_seconds_nanos(buf, self.seconds, self.nanos);
+    }
+
+    fn encoded_len(&self) -> usize {
+        seconds_nanos_len(self.seconds, self.nanos)
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        write_seconds_nanos(w, self.seconds, self.nanos)
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        write_seconds_nanos_reverse(w, self.seconds, self.nanos);
+    }
+
+    fn merge_with(&mut self, data: &[u8], _opts: &DecodeOptions) -> Result<(), DecodeError> {
+        merge_seconds_nanos(&mut self.seconds, &mut self.nanos, data)
+    }
+}
+
+// Proto3 JSON forms: Timestamp as RFC 3339 in UTC ("2024-01-02T03:04:05.5Z"),
+// Duration as decimal seconds ("1.5s"). Fractions use 0, 3, 6 or 9 digits.
+
+#[cfg(feature = "json")]
+const INVALID_TIMESTAMP: DecodeError = DecodeError::InvalidData("invalid JSON timestamp");
+#[cfg(feature = "json")]
+const INVALID_DURATION: DecodeError = DecodeError::InvalidData("invalid JSON duration");
+
+/// Days since 1970-01-01 to (year, month, day) in the proleptic Gregorian calendar.
+#[cfg(feature = "json")]
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(feature = "json")]
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Append `.fff`, `.ffffff` or `.fffffffff` for non-zero `nanos`.
+#[cfg(feature = "json")]
+fn push_json_nanos(out: &mut String, nanos: u32) {
+    if nanos == 0 {
+        return;
+    }
+    let digits = format!("{:09}", nanos);
+    let keep = match digits.trim_end_matches('0').len() {
+        0..=3 => 3,
+        4..=6 => 6,
+        _ => 9,
+    };
+    out.push('.');
+    out.push_str(&digits[..keep]);
+}
+
+/// Parse fixed-width ASCII digits.
+#[cfg(feature = "json")]
+fn parse_digits(text: &[u8]) -> Option<i64> {
+    if text.is_empty() || !text.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some(text.iter().fold(0, |acc, d| acc * 10 + (d - b'0') as i64))
+}
+
+/// Parse 1 to 9 fraction digits as nanoseconds.
+#[cfg(feature = "json")]
+fn parse_json_nanos(text: &[u8]) -> Option<i32> {
+    if text.len() > 9 {
+        return None;
+    }
+    let value = parse_digits(text)?;
+    Some((value * 10i64.pow(9 - text.len() as u32)) as i32)
+}
+
+#[cfg(feature = "json")]
+impl Timestamp {
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        let days = self.seconds.div_euclid(86_400);
+        let secs = self.seconds.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        let mut text = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year,
+            month,
+            day,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        );
+        push_json_nanos(&mut text, self.nanos.clamp(0, 999_999_999) as u32);
+        text.push('Z');
+        out.write_str(&text);
+    }
+
+    /// Accepts `Z` or a `±hh:mm` offset, which is folded into UTC.
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let text = json_to_string(value)?;
+        let b = text.as_bytes();
+        let field = |range: core::ops::Range<usize>| {
+            b.get(range).and_then(parse_digits).ok_or(INVALID_TIMESTAMP)
+        };
+        let separators = [(4, b'-'), (7, b'-'), (10, b'T'), (13, b':'), (16, b':')];
+        if b.len() < 20 || separators.iter().any(|&(i, c)| b[i] != c) {
+            return Err(INVALID_TIMESTAMP);
+        }
+        let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+        let (hour, minute, second) = (field(11..13)?, field(14..16)?, field(17..19)?);
+        if !(1..=12).contains(&month) || hour > 23 || minute > 59 || second > 59 {
+            return Err(INVALID_TIMESTAMP);
+        }
+
+        let mut pos = 19;
+        let mut nanos = 0;
+        if b[pos] == b'.' {
+            let end = b[pos + 1..]
+                .iter()
+                .position(|c| !c.is_ascii_digit())
+                .map_or(b.len(), |n| pos + 1 + n);
+            nanos = parse_json_nanos(&b[pos + 1..end]).ok_or(INVALID_TIMESTAMP)?;
+            pos = end;
+        }
+        let offset = match b.get(pos) {
+            Some(b'Z') if pos + 1 == b.len() => 0,
+            Some(sign @ (b'+' | b'-')) if pos + 6 == b.len() && b[pos + 3] == b':' => {
+                let minutes = field(pos + 1..pos + 3)? * 60 + field(pos + 4..pos + 6)?;
+                if *sign == b'+' { minutes * 60 } else { -minutes * 60 }
+            }
+            _ => return Err(INVALID_TIMESTAMP),
+        };
+
+        // Day-of-month overflow (e.g. 02-30) fails the round trip
+        let days = days_from_civil(year, month, day);
+        if civil_from_days(days) != (year, month, day) {
+            return Err(INVALID_TIMESTAMP);
+        }
+        let seconds = days * 86_400 + hour * 3600 + minute * 60 + second - offset;
+        let ts = Timestamp { seconds, nanos };
+        if !ts.is_valid() {
+            return Err(INVALID_TIMESTAMP);
+        }
+        Ok(ts)
+    }
+}
+
+#[cfg(feature = "json")]
+impl Duration {
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        let mut text = String::new();
+        if self.seconds < 0 || self.nanos < 0 {
+            text.push('-');
+        }
+        text.push_str(&self.seconds.unsigned_abs().to_string());
+        push_json_nanos(&mut text, self.nanos.unsigned_abs().min(999_999_999));
+        text.push('s');
+        out.write_str(&text);
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let text = json_to_string(value)?;
+        let body = text.strip_suffix('s').ok_or(INVALID_DURATION)?;
+        let (negative, body) = match body.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, body),
+        };
+        let (whole, frac) = match body.split_once('.') {
+            Some((whole, frac)) => (whole, Some(frac)),
+            None => (body, None),
+        };
+        if whole.len() > 12 {
+            return Err(INVALID_DURATION);
+        }
+        let seconds = parse_digits(whole.as_bytes()).ok_or(INVALID_DURATION)?;
+        let nanos = match frac {
+            Some(frac) => parse_json_nanos(frac.as_bytes()).ok_or(INVALID_DURATION)?,
+            None => 0,
+        };
+        let d = if negative {
+            Duration { seconds: -seconds, nanos: -nanos }
+        } else {
+            Duration { seconds, nanos }
+        };
+        if !d.is_valid() {
+            return Err(INVALID_DURATION);
+        }
+        Ok(d)
+    }
+}
+
+/// `google.protobuf.Empty`, e.g. for rpcs without a meaningful response.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Empty {}
+
+impl Empty {
+    pub const MAX_ENCODED_LEN: usize = 0;
+    pub const DEFAULT: Self = Empty {};
+
+    pub fn write_text(&self, _out: &mut TextWriter) {}
+}
+
+#[cfg(feature = "json")]
+impl Empty {
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        out.end_object();
+    }
+
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        json_object(value)?;
+        Ok(Empty {})
+    }
+}
+
+impl MessageName for Empty {
+    const FULL_NAME: &'static str = "google.protobuf.Empty";
+}
+
+impl Message for Empty {
+    fn encode_into(&self, _buf: &mut Vec<u8>) {}
+
+    fn encoded_len(&self) -> usize {
+        0
+    }
+
+    fn write_to(&self, _w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        Ok(())
+    }
+
+    fn write_reverse(&self, _w: &mut ReverseWriter<'_>) {}
+
+    /// Every field is unknown and skipped.
+    fn merge_with(&mut self, data: &[u8], _opts: &DecodeOptions) -> Result<(), DecodeError> {
+        let mut pos = 0;
+        while pos < data.len() {
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = skip_field(data, new_pos, tag)?;
+        }
+        Ok(())
+    }
+}
+
+/// Wrapper messages hold a single `value` field (number 1). The
+/// associated `*_value` functions encode and merge a bare value so that
+/// generated `Option<T>` fields need no wrapper struct.
+macro_rules! wrapper_message {
+    (
+        $(#[$doc:meta])*
+        $name:ident($(#[$attr:meta])* $ty:ty),
+        tag: $tag:expr,
+        default: $default:expr,
+        is_set: $is_set:expr,
+        encode: $encode:expr,
+        len: $len:expr,
+        write: $write:expr,
+        write_reverse: $write_reverse:expr,
+        decode: $decode:expr,
+        to_text: $to_text:expr,
+        to_json: $to_json:expr,
+        from_json: $from_json:expr $(,)?
+    ) => {
+        $(#[$doc])*
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name {
+            $(#[$attr])*
+            pub value: $ty,
+        }
+
+        impl $name {
+            pub const DEFAULT: Self = $name { value: $default };
+
+            pub fn encode_value(value: &$ty, buf: &mut Vec<u8>) {
+                if ($is_set)(value) {
+                    encode_key(buf, $tag);
+                    ($encode)(buf, value);
+                }
+            }
+
+            pub fn value_len(value: &$ty) -> usize {
+                if ($is_set)(value) {
+                    1 + ($len)(value)
+                } else {
+                    0
+                }
+            }
+
+            pub fn write_value(value: &$ty, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if ($is_set)(value) {
+                    w.write_key($tag)?;
+                    ($write)(w, value)?;
+                }
+                Ok(())
+            }
+
+            pub fn write_value_reverse(value: &$ty, w: &mut ReverseWriter<'_>) {
+                if ($is_set)(value) {
+                    ($write_reverse)(w, value);
+                    w.write_key($tag);
+                }
+            }
+
+            /// Merge an encoded wrapper body into `value`: the last
+            /// `value` field wins, an empty body leaves it unchanged.
+            pub fn merge_value(value: &mut $ty, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                let mut pos = 0;
+                while pos < data.len() {
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = if tag == $tag {
+                        let (v, new_pos) = ($decode)(data, new_pos, opts)?;
+                        *value = v;
+                        new_pos
+                    } else {
+                        skip_field(data, new_pos, tag)?
+                    };
+                }
+                Ok(())
+            }
+
+            /// Text format body of a wrapper holding `value`.
+            pub fn write_text_value(value: &$ty, out: &mut TextWriter) {
+                if ($is_set)(value) {
+                    ($to_text)(out, value);
+                }
+            }
+
+            pub fn write_text(&self, out: &mut TextWriter) {
+                Self::write_text_value(&self.value, out);
+            }
+
+            /// Wrappers are written as their bare JSON value.
+            #[cfg(feature = "json")]
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                ($to_json)(out, &self.value)
+            }
+
+            #[cfg(feature = "json")]
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                Ok($name { value: ($from_json)(value)? })
+            }
+        }
+
+        impl MessageName for $name {
+            const FULL_NAME: &'static str = concat!("google.protobuf.", stringify!($name));
+        }
+
+        impl Message for $name {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Self::encode_value(&self.value, buf);
+            }
+
+            fn encoded_len(&self) -> usize {
+                Self::value_len(&self.value)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Self::write_value(&self.value, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Self::write_value_reverse(&self.value, w);
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Self::merge_value(&mut self.value, data, opts)
+            }
+        }
+    };
+}
+
+wrapper_message! {
+    /// `google.protobuf.DoubleValue`
+    DoubleValue(f64),
+    tag: 0x09,
+    default: 0.0,
+    is_set: |v: &f64| v.to_bits() != 0,
+    encode: |buf: &mut Vec<u8>, v: &f64| encode_double(buf, *v),
+    len: |_: &f64| 8,
+    write: |w: &mut SliceWriter<'_>, v: &f64| w.write_double(*v),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &f64| w.write_double(*v),
+    decode: |data, pos, _: &DecodeOptions| decode_double(data, pos),
+    to_text: |out: &mut TextWriter, v: &f64| out.write_f64("value", *v),
+    to_json: |out: &mut JsonWriter, v: &f64| out.write_f64(*v),
+    from_json: json_to_f64,
+}
+
+wrapper_message! {
+    /// `google.protobuf.FloatValue`
+    FloatValue(f32),
+    tag: 0x0d,
+    default: 0.0,
+    is_set: |v: &f32| v.to_bits() != 0,
+    encode: |buf: &mut Vec<u8>, v: &f32| encode_float(buf, *v),
+    len: |_: &f32| 4,
+    write: |w: &mut SliceWriter<'_>, v: &f32| w.write_float(*v),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &f32| w.write_float(*v),
+    decode: |data, pos, _: &DecodeOptions| decode_float(data, pos),
+    to_text: |out: &mut TextWriter, v: &f32| out.write_f32("value", *v),
+    to_json: |out: &mut JsonWriter, v: &f32| out.write_f32(*v),
+    from_json: json_to_f32,
+}
+
+wrapper_message! {
+    /// `google.protobuf.Int64Value`
+    Int64Value(i64),
+    tag: 0x08,
+    default: 0,
+    is_set: |v: &i64| *v != 0,
+    encode: |buf: &mut Vec<u8>, v: &i64| encode_int64(buf, *v),
+    len: |v: &i64| int64_len(*v),
+    write: |w: &mut SliceWriter<'_>, v: &i64| w.write_int64(*v),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &i64| w.write_int64(*v),
+    decode: |data, pos, _: &DecodeOptions| decode_int64(data, pos),
+    to_text: |out: &mut TextWriter, v: &i64| out.write_scalar("value", v),
+    to_json: |out: &mut JsonWriter, v: &i64| out.write_i64(*v),
+    from_json: json_to_i64,
+}
+
+wrapper_message! {
+    /// `google.protobuf.UInt64Value`
+    UInt64Value(u64),
+    tag: 0x08,
+    default: 0,
+    is_set: |v: &u64| *v != 0,
+    encode: |buf: &mut Vec<u8>, v: &u64| encode_varint(buf, *v),
+    len: |v: &u64| varint_len(*v),
+    write: |w: &mut SliceWriter<'_>, v: &u64| w.write_varint(*v),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &u64| w.write_varint(*v),
+    decode: |data, pos, _: &DecodeOptions| decode_varint(data, pos),
+    to_text: |out: &mut TextWriter, v: &u64| out.write_scalar("value", v),
+    to_json: |out: &mut JsonWriter, v: &u64| out.write_u64(*v),
+    from_json: json_to_u64,
+}
+
+wrapper_message! {
+    /// `google.protobuf.Int32Value`
+    Int32Value(i32),
+    tag: 0x08,
+    default: 0,
+    is_set: |v: &i32| *v != 0,
+    encode: |buf: &mut Vec<u8>, v: &i32| encode_int32(buf, *v),
+    len: |v: &i32| int32_len(*v),
+    write: |w: &mut SliceWriter<'_>, v: &i32| w.write_int32(*v),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &i32| w.write_int32(*v),
+    decode: |data, pos, _: &DecodeOptions| decode_int32(data, pos),
+    to_text: |out: &mut TextWriter, v: &i32| out.write_scalar("value", v),
+    to_json: |out: &mut JsonWriter, v: &i32| out.write_i32(*v),
+    from_json: json_to_i32,
+}
+
+wrapper_message! {
+    /// `google.protobuf.UInt32Value`
+    UInt32Value(u32),
+    tag: 0x08,
+    default: 0,
+    is_set: |v: &u32| *v != 0,
+    encode: |buf: &mut Vec<u8>, v: &u32| encode_varint(buf, *v as u64),
+    len: |v: &u32| varint_len(*v as u64),
+    write: |w: &mut SliceWriter<'_>, v: &u32| w.write_varint(*v as u64),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &u32| w.write_varint(*v as u64),
+    decode: |data, pos, _: &DecodeOptions| decode_varint(data, pos).map(|(v, pos)| (v as u32, pos)),
+    to_text: |out: &mut TextWriter, v: &u32| out.write_scalar("value", v),
+    to_json: |out: &mut JsonWriter, v: &u32| out.write_u32(*v),
+    from_json: json_to_u32,
+}
+
+wrapper_message! {
+    /// `google.protobuf.BoolValue`
+    BoolValue(bool),
+    tag: 0x08,
+    default: false,
+    is_set: |v: &bool| *v,
+    encode: |buf: &mut Vec<u8>, v: &bool| encode_bool(buf, *v),
+    len: |_: &bool| 1,
+    write: |w: &mut SliceWriter<'_>, v: &bool| w.write_bool(*v),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &bool| w.write_bool(*v),
+    decode: |data, pos, _: &DecodeOptions| decode_bool(data, pos),
+    to_text: |out: &mut TextWriter, v: &bool| out.write_scalar("value", v),
+    to_json: |out: &mut JsonWriter, v: &bool| out.write_bool(*v),
+    from_json: json_to_bool,
+}
+
+wrapper_message! {
+    /// `google.protobuf.StringValue`
+    StringValue(String),
+    tag: 0x0a,
+    default: String::new(),
+    is_set: |v: &String| !v.is_empty(),
+    encode: |buf: &mut Vec<u8>, v: &String| encode_string(buf, v),
+    len: |v: &String| bytes_len(v.len()),
+    write: |w: &mut SliceWriter<'_>, v: &String| w.write_string(v),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &String| w.write_string(v),
+    decode: decode_string_with,
+    to_text: |out: &mut TextWriter, v: &String| out.write_str("value", v),
+    to_json: |out: &mut JsonWriter, v: &String| out.write_str(v),
+    from_json: json_to_string,
+}
+
+wrapper_message! {
+    /// `google.protobuf.BytesValue`
+    BytesValue(#[cfg_attr(feature = "serde", serde(with = "serde_base64"))] Vec<u8>),
+    tag: 0x0a,
+    default: Vec::new(),
+    is_set: |v: &Vec<u8>| !v.is_empty(),
+    encode: |buf: &mut Vec<u8>, v: &Vec<u8>| encode_bytes(buf, v),
+    len: |v: &Vec<u8>| bytes_len(v.len()),
+    write: |w: &mut SliceWriter<'_>, v: &Vec<u8>| w.write_bytes(v),
+    write_reverse: |w: &mut ReverseWriter<'_>, v: &Vec<u8>| w.write_bytes(v),
+    decode: decode_bytes_with,
+    to_text: |out: &mut TextWriter, v: &Vec<u8>| out.write_bytes("value", v),
+    to_json: |out: &mut JsonWriter, v: &Vec<u8>| out.write_bytes(v),
+    from_json: json_to_bytes,
+}
+
+/// Value of an encoded wrapper message for borrowed views: the last
+/// field matching `tag` (the wrapper's `value` key), or the default.
+pub fn wrapper_value_ref<'a, T: Default>(
+    data: &'a [u8],
+    tag: u64,
+    decode: ElemDecoder<'a, T>,
+) -> Result<T, DecodeError> {
+    let mut value = T::default();
+    for v in RepeatedRef::new(data, tag, decode) {
+        value = v?;
+    }
+    Ok(value)
+}
+
+/// `google.protobuf.Any`: an encoded message of any type, named by its
+/// type URL (`type.googleapis.com/<full name>`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Any {
+    pub type_url: String,
+    #[cfg_attr(feature = "serde", serde(with = "serde_base64"))]
+    pub value: Vec<u8>,
+}
+
+/// Type-URL check shared by `Any` and `AnyRef`.
+const ANY_TYPE_MISMATCH: DecodeError = DecodeError::InvalidData("Any type URL does not match the message");
+
+/// Message name of a type URL: everything after its last `/`.
+fn any_type_name(type_url: &str) -> &str {
+    type_url.rsplit('/').next().unwrap_or(type_url)
+}
+
+impl Any {
+    pub const DEFAULT: Self = Any { type_url: String::new(), value: Vec::new() };
+
+    pub fn pack<M: MessageName>(msg: &M) -> Self {
+        Any { type_url: M::type_url(), value: msg.encode() }
+    }
+
+    /// Fully qualified name of the packed message, for dispatch.
+    pub fn type_name(&self) -> &str {
+        any_type_name(&self.type_url)
+    }
+
+    pub fn is<M: MessageName>(&self) -> bool {
+        self.type_name() == M::FULL_NAME
+    }
+
+    /// Decode the payload as `M`, failing if the type URL names another
+    /// message. The payload was decoded as part of another message, so
+    /// only `DecodeOptions::ACCOUNT`'s size cap applies.
+    pub fn unpack<M: MessageName>(&self) -> Result<M, DecodeError> {
+        self.unpack_with(&DecodeOptions::ACCOUNT)
+    }
+
+    pub fn unpack_with<M: MessageName>(&self, opts: &DecodeOptions) -> Result<M, DecodeError> {
+        if !self.is::<M>() {
+            return Err(ANY_TYPE_MISMATCH);
+        }
+        M::decode_with(&self.value, opts)
+    }
+
+    /// The payload stays bytes rather than the expanded
+    /// `[type.googleapis.com/...] { ... }` form.
+    pub fn write_text(&self, out: &mut TextWriter) {
+        if !self.type_url.is_empty() {
+            out.write_str("type_url", &self.type_url);
+        }
+        if !self.value.is_empty() {
+            out.write_bytes("value", &self.value);
+        }
+    }
+
+    /// The runtime has no type registry, so the payload is kept as
+    /// base64 bytes: `{"@type": "<type URL>", "value": "<base64>"}`.
+    #[cfg(feature = "json")]
+    pub fn write_json(&self, out: &mut JsonWriter) {
+        out.begin_object();
+        out.key("@type");
+        out.write_str(&self.type_url);
+        out.key("value");
+        out.write_bytes(&self.value);
+        out.end_object();
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+        let mut any = Any::default();
+        for (key, value) in json_object(value)? {
+            match key.as_str() {
+                "@type" => any.type_url = json_to_string(value)?,
+                "value" => any.value = json_to_bytes(value)?,
+                _ => return Err(DecodeError::InvalidData("unknown JSON field in Any")),
+            }
+        }
+        Ok(any)
+    }
+}
+
+impl MessageName for Any {
+    const FULL_NAME: &'static str = "google.protobuf.Any";
+}
+
+impl Message for Any {
+    fn encode_into(&self, buf: &mut Vec<u8>) {
+        if !self.type_url.is_empty() {
+            encode_key(buf, 0x0a);
+            encode_string(buf, &self.type_url);
+        }
+        if !self.value.is_empty() {
+            encode_key(buf, 0x12);
+            encode_bytes(buf, &self.value);
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if !self.type_url.is_empty() {
+            len += 1 + bytes_len(self.type_url.len());
+        }
+        if !self.value.is_empty() {
+            len += 1 + bytes_len(self.value.len());
+        }
+        len
+    }
+
+    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+        if !self.type_url.is_empty() {
+            w.write_key(0x0a)?;
+            w.write_string(&self.type_url)?;
+        }
+        if !self.value.is_empty() {
+            w.write_key(0x12)?;
+            w.write_bytes(&self.value)?;
+        }
+        Ok(())
+    }
+
+    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+        if !self.value.is_empty() {
+            w.write_bytes(&self.value);
+            w.write_key(0x12);
+        }
+        if !self.type_url.is_empty() {
+            w.write_string(&self.type_url);
+            w.write_key(0x0a);
+        }
+    }
+
+    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+        let mut pos = 0;
+        while pos < data.len() {
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = match tag {
+                0x0a => {
+                    let (v, new_pos) = decode_string_with(data, new_pos, opts)?;
+                    self.type_url = v;
+                    new_pos
+                }
+                0x12 => {
+                    let (v, new_pos) = decode_bytes_with(data, new_pos, opts)?;
+                    self.value = v;
+                    new_pos
+                }
+                _ => skip_field(data, new_pos, tag)?,
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Borrowed view of an `Any`, as used by generated `ref_views`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnyRef<'a> {
+    pub type_url: &'a str,
+    pub value: &'a [u8],
+}
+
+impl<'a> AnyRef<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut view = AnyRef::default();
+        let mut pos = 0;
+        while pos < data.len() {
+            let (tag, new_pos) = decode_key(data, pos)?;
+            pos = match tag {
+                0x0a => {
+                    let (v, new_pos) = decode_str_ref(data, new_pos)?;
+                    view.type_url = v;
+                    new_pos
+                }
+                0x12 => {
+                    let (v, new_pos) = decode_bytes_ref(data, new_pos)?;
+                    view.value = v;
+                    new_pos
+                }
+                _ => skip_field(data, new_pos, tag)?,
+            };
+        }
+        Ok(view)
+    }
+
+    pub fn type_name(&self) -> &'a str {
+        any_type_name(self.type_url)
+    }
+
+    pub fn is<M: MessageName>(&self) -> bool {
+        self.type_name() == M::FULL_NAME
+    }
+
+    /// See `Any::unpack`.
+    pub fn unpack<M: MessageName>(&self) -> Result<M, DecodeError> {
+        self.unpack_with(&DecodeOptions::ACCOUNT)
+    }
+
+    pub fn unpack_with<M: MessageName>(&self, opts: &DecodeOptions) -> Result<M, DecodeError> {
+        if !self.is::<M>() {
+            return Err(ANY_TYPE_MISMATCH);
+        }
+        M::decode_with(self.value, opts)
+    }
+
+    pub fn to_message(&self) -> Any {
+        Any { type_url: self.type_url.to_string(), value: self.value.to_vec() }
+    }
+}
+
+// ── Account data (discriminator + length-prefixed message) ──────────
+//
+// Layout used for messages stored in program accounts (e.g. the `anchor`
+// option): an 8-byte discriminator, the payload length as a u32 (LE),
+// then the protobuf payload. Accounts are allocated at a fixed size, so
+// the explicit length keeps zeroed trailing space out of the decode.
+
+pub const ACCOUNT_DISCRIMINATOR_LEN: usize = 8;
+/// The discriminator and the u32 payload length.
+pub const ACCOUNT_HEADER_LEN: usize = ACCOUNT_DISCRIMINATOR_LEN + 4;
+
+/// Bytes of account data needed to store `msg`.
+pub fn account_data_len<M: Message>(msg: &M) -> usize {
+    ACCOUNT_HEADER_LEN + msg.encoded_len()
+}
+
+pub fn encode_account_data<M: Message>(discriminator: &[u8; ACCOUNT_DISCRIMINATOR_LEN], msg: &M) -> Vec<u8> {
+    let len = msg.encoded_len();
+    let mut buf = Vec::with_capacity(ACCOUNT_HEADER_LEN + len);
+    buf.extend_from_slice(discriminator);
+    buf.extend_from_slice(&(len as u32).to_le_bytes());
+    msg.encode_into(&mut buf);
+    buf
+}
+
+/// Decode the message from account data, skipping (not checking) the
+/// discriminator. Bytes past the recorded payload length are ignored.
+pub fn decode_account_data<M: Message>(data: &[u8]) -> Result<M, DecodeError> {
+    M::decode_with(account_payload(data)?, &DecodeOptions::ACCOUNT)
+}
+
+/// The protobuf payload of account data: the recorded number of bytes
+/// after the header, e.g. to decode as a `DynamicMessage`.
+pub fn account_payload(data: &[u8]) -> Result<&[u8], DecodeError> {
+    if data.len() < ACCOUNT_HEADER_LEN {
+        return Err(DecodeError::BufferOverflow);
+    }
+    let mut len = [0u8; 4];
+    len.copy_from_slice(&data[ACCOUNT_DISCRIMINATOR_LEN..ACCOUNT_HEADER_LEN]);
+    let end = checked_end(ACCOUNT_HEADER_LEN, u32::from_le_bytes(len) as u64, data.len())?;
+    Ok(&data[ACCOUNT_HEADER_LEN..end])
+}
+
+// ── Type discriminators (`(solana.discriminator)` messages) ──────────
+//
+// A hash of the message's full name, then the protobuf payload. Protobuf
+// bytes carry no type, so data of one message often decodes as another;
+// the prefix makes passing the wrong account or buffer a decode error.
+
+/// `discriminator`, then the protobuf encoding of `msg`.
+pub fn encode_with_discriminator<M: Message>(discriminator: &[u8], msg: &M) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(discriminator.len() + msg.encoded_len());
+    buf.extend_from_slice(discriminator);
+    msg.encode_into(&mut buf);
+    buf
+}
+
+/// The payload after `discriminator`, or an error if `data` starts with
+/// anything else.
+pub fn strip_discriminator<'a>(discriminator: &[u8], data: &'a [u8]) -> Result<&'a [u8], DecodeError> {
+    if data.len() < discriminator.len() {
+        return Err(DecodeError::BufferOverflow);
+    }
+    data.strip_prefix(discriminator).ok_or(DISCRIMINATOR_MISMATCH)
+}
+
+const DISCRIMINATOR_MISMATCH: DecodeError = DecodeError::InvalidData("discriminator does not match the message");
+
+// ── Versioned data (`(solana.version)` messages) ─────────────────────
+//
+// One byte holding the schema version the payload was written at, then
+// the protobuf payload. Protobuf already tolerates added and removed
+// fields; the byte is for changes it cannot absorb, such as a field whose
+// meaning or unit changed, so old data is converted rather than misread.
+
+/// `version`, then the protobuf encoding of `msg`.
+pub fn encode_versioned<M: Message>(version: u8, msg: &M) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(1 + msg.encoded_len());
+    buf.push(version);
+    msg.encode_into(&mut buf);
+    buf
+}
+
+/// The version byte of versioned data and the payload after it.
+pub fn split_version(data: &[u8]) -> Result<(u8, &[u8]), DecodeError> {
+    data.split_first().map(|(&version, payload)| (version, payload)).ok_or(DecodeError::BufferOverflow)
+}
+
+// ── Events (`(solana.event)` messages) ──────────────────────────────
+//
+// An event is logged with `sol_log_data` as one chunk: an 8-byte
+// discriminator, then the protobuf payload. The validator prints it in
+// the transaction logs as `Program data: <base64>`, which is where
+// `decode_event_log` finds it again off-chain, and `EventParser` finds
+// the events of one program among all the logs of a transaction.
+
+pub const EVENT_DISCRIMINATOR_LEN: usize = 8;
+/// How the validator prefixes `sol_log_data` output in transaction logs.
+pub const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+/// The bytes `emit_event` logs: the discriminator and the payload.
+pub fn encode_event_data<M: Message>(discriminator: &[u8; EVENT_DISCRIMINATOR_LEN], msg: &M) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(EVENT_DISCRIMINATOR_LEN + msg.encoded_len());
+    buf.extend_from_slice(discriminator);
+    msg.encode_into(&mut buf);
+    buf
+}
+
+#[cfg(feature = "solana-program")]
+pub fn emit_event<M: Message>(discriminator: &[u8; EVENT_DISCRIMINATOR_LEN], msg: &M) {
+    let data = encode_event_data(discriminator, msg);
+    solana_program::log::sol_log_data(&[data.as_slice()]);
+}
+
+/// Decode the event in a `Program data:` log line. `None` if the line is
+/// some other log or its data starts with another discriminator; data
+/// logged in several chunks is joined first.
+pub fn decode_event_log<M: Message>(
+    discriminator: &[u8; EVENT_DISCRIMINATOR_LEN],
+    line: &str,
+) -> Option<Result<M, DecodeError>> {
+    match program_data_log(line)? {
+        Ok(data) => decode_event_data(discriminator, &data),
+        Err(e) => Some(Err(e)),
+    }
+}
+
+/// Decode the event in the bytes `encode_event_data` writes. `None` if
+/// they start with another discriminator.
+pub fn decode_event_data<M: Message>(
+    discriminator: &[u8; EVENT_DISCRIMINATOR_LEN],
+    data: &[u8],
+) -> Option<Result<M, DecodeError>> {
+    let payload = data.strip_prefix(discriminator.as_slice())?;
+    Some(M::decode(payload))
+}
+
+/// The data of a `Program data:` log line, its chunks joined, or `None`
+/// for any other line.
+fn program_data_log(line: &str) -> Option<Result<Vec<u8>, DecodeError>> {
+    let chunks = line.strip_prefix(PROGRAM_DATA_LOG_PREFIX)?;
+    let mut data = Vec::new();
+    for chunk in chunks.split_whitespace() {
+        match base64_decode(chunk) {
+            Ok(bytes) => data.extend_from_slice(&bytes),
+            Err(e) => return Some(Err(e)),
+        }
+    }
+    Some(Ok(data))
+}
+
+/// An event message, or a set of them such as a file's `AnyEvent`, that
+/// an `EventParser` reads back.
+pub trait FromEventData: Sized {
+    /// Decode the bytes `encode_event_data` writes, or `None` if they
+    /// start with none of this type's discriminators.
+    fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>>;
+}
+
+/// Prefix of the instruction data of an event logged through a self-CPI,
+/// as Anchor's `emit_cpi!` does so that truncated logs cannot drop it:
+/// Anchor's `EVENT_IX_TAG_LE`, then the event data.
+pub const EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// Reads the events one program logged back from a transaction. The
+/// validator logs `Program <id> invoke [<depth>]` when a program is
+/// called and `Program <id> success` or `failed` when it returns, and
+/// `parse_logs` follows those lines to keep only the `Program data:`
+/// lines the program wrote itself, including from inner instructions,
+/// and not those of the programs it calls or that call it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventParser {
+    program_id: String,
+}
+
+/// An event `EventParser::parse_logs` found.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedEvent<E> {
+    /// Index of the log line holding the event.
+    pub index: usize,
+    /// Invoke depth of the program when it logged the event: 1 for a
+    /// top-level instruction, more in a CPI.
+    pub depth: usize,
+    pub event: E,
+}
+
+impl EventParser {
+    /// A parser for the events of the program at `program_id`, as the
+    /// logs write it (base58, as `Pubkey`'s `Display`).
+    pub fn new(program_id: impl ToString) -> Self {
+        EventParser { program_id: program_id.to_string() }
+    }
+
+    /// The program ID, base58.
+    pub fn program_id(&self) -> &str {
+        &self.program_id
+    }
+
+    /// The program's events in a transaction's log messages, in log order.
+    /// A `Program data:` line of the program holding none of `E`'s
+    /// discriminators is skipped, and one that does not decode is an
+    /// `Err` item, after which parsing continues.
+    pub fn parse_logs<E: FromEventData, L: AsRef<str>, I: IntoIterator<Item = L>>(
+        &self,
+        logs: I,
+    ) -> EventLogs<'_, E, I::IntoIter> {
+        EventLogs {
+            parser: self,
+            lines: logs.into_iter().enumerate(),
+            stack: Vec::new(),
+            event: core::marker::PhantomData,
+        }
+    }
+
+    /// Decode the event in the data of an inner instruction calling
+    /// `program_id`, for a program that logs events through a self-CPI:
+    /// `EVENT_IX_TAG`, then the event data. `None` for an instruction of
+    /// another program or one that holds no `E`.
+    pub fn parse_instruction<E: FromEventData>(&self, program_id: &str, data: &[u8]) -> Option<Result<E, DecodeError>> {
+        if program_id != self.program_id {
+            return None;
+        }
+        E::from_event_data(data.strip_prefix(EVENT_IX_TAG.as_slice())?)
+    }
+}
+
+/// Iterator of `EventParser::parse_logs`.
+pub struct EventLogs<'p, E, I> {
+    parser: &'p EventParser,
+    lines: core::iter::Enumerate<I>,
+    /// Whether each program on the invoke stack is the parser's.
+    stack: Vec<bool>,
+    event: core::marker::PhantomData<fn() -> E>,
+}
+
+impl<E: FromEventData, L: AsRef<str>, I: Iterator<Item = L>> Iterator for EventLogs<'_, E, I> {
+    type Item = Result<ParsedEvent<E>, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (index, line) = self.lines.next()?;
+            let line = line.as_ref();
+            if let Some(data) = program_data_log(line) {
+                if self.stack.last() != Some(&true) {
+                    continue;
+                }
+                let depth = self.stack.len();
+                match data {
+                    Ok(data) => match E::from_event_data(&data) {
+                        Some(event) => return Some(event.map(|event| ParsedEvent { index, depth, event })),
+                        None => continue,
+                    },
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            // `Program log: ` and the other prefixed lines end the first word
+            // with a colon; a program ID never does
+            let Some((id, rest)) = line.strip_prefix("Program ").and_then(|l| l.split_once(' ')) else {
+                continue;
+            };
+            if id.ends_with(':') {
+                continue;
+            }
+            if rest.starts_with("invoke [") {
+                self.stack.push(id == self.parser.program_id);
+            } else if rest == "success" || rest.starts_with("failed") {
+                self.stack.pop();
+            }
+        }
+    }
+}
+
+// ── Bump arena (`#[global_allocator]` for decoding) ─────────────────
+//
+// Decoding a message makes one heap allocation per string, bytes and
+// repeated field, and a repeated field reallocates as it grows: with the
+// default SBF allocator every growth is a fresh allocation and a copy,
+// and nothing is ever reused. `BumpArena` makes every allocation a bounds
+// check and a pointer bump, grows or frees the most recent allocation
+// (the `Vec` being pushed to) in place, and `release` frees everything
+// allocated since a `mark` at once, so one instruction can decode, use
+// and drop several messages in the same region. Stable Rust's collections
+// take no allocator parameter, so the arena is installed as the program's
+// global allocator over the heap region, with the program's `custom-heap`
+// feature turning off the one `entrypoint!` defines:
+//
+//     #[global_allocator]
+//     static ALLOCATOR: BumpArena = unsafe { BumpArena::new(HEAP_START_ADDRESS as usize, HEAP_LENGTH) };
+
+/// `BumpArena` keeps its cursor in the region's first word, so zeroed
+/// memory (as the SBF heap starts) is an empty arena.
+const ARENA_HEADER: usize = core::mem::size_of::<usize>();
+
+/// A `BumpArena` position, to `release` back to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArenaMark(usize);
+
+/// A bump allocator over one fixed region; see the section comment.
+/// Allocations are never freed individually except the most recent one:
+/// memory comes back with `release` or `reset`. An exhausted arena
+/// returns null, which Rust turns into an allocation-failure abort, so
+/// bound untrusted input with `DecodeOptions` to fit the region.
+#[derive(Debug)]
+pub struct BumpArena {
+    start: usize,
+    len: usize,
+}
+
+impl BumpArena {
+    /// An arena over the `len` bytes at address `start`.
+    ///
+    /// # Safety
+    ///
+    /// The region must be valid for reads and writes, aligned to `usize`,
+    /// longer than one `usize`, and used by nothing but this arena, which
+    /// must only be used from one thread at a time (as on SBF).
+    pub const unsafe fn new(start: usize, len: usize) -> Self {
+        BumpArena { start, len }
+    }
+
+    /// Address of the next free byte.
+    fn cursor(&self) -> usize {
+        // SAFETY: `new`'s contract makes the first word ours to read
+        let stored = unsafe { *(self.start as *const usize) };
+        if stored == 0 { self.start + ARENA_HEADER } else { stored }
+    }
+
+    fn set_cursor(&self, at: usize) {
+        // SAFETY: as in `cursor`
+        unsafe { *(self.start as *mut usize) = at }
+    }
+
+    /// Bytes handed out and not yet given back, alignment padding included.
+    pub fn used(&self) -> usize {
+        self.cursor() - self.start - ARENA_HEADER
+    }
+
+    /// Bytes left for allocations, before alignment padding.
+    pub fn remaining(&self) -> usize {
+        self.start + self.len - self.cursor()
+    }
+
+    /// The current position, so that `release` can later free everything
+    /// allocated after it.
+    pub fn mark(&self) -> ArenaMark {
+        ArenaMark(self.cursor())
+    }
+
+    /// Free everything allocated since `mark`, in one step.
+    ///
+    /// # Safety
+    ///
+    /// `mark` must come from this arena, and no allocation made after it
+    /// (a decoded message, say) may be used, or dropped, again.
+    pub unsafe fn release(&self, mark: ArenaMark) {
+        self.set_cursor(mark.0);
+    }
+
+    /// Free every allocation in the arena.
+    ///
+    /// # Safety
+    ///
+    /// As for `release`: no allocation from the arena may be used again.
+    pub unsafe fn reset(&self) {
+        self.set_cursor(self.start + ARENA_HEADER);
+    }
+
+    /// Move the cursor to `end` if it is inside the region.
+    fn bump_to(&self, end: Option<usize>) -> bool {
+        match end {
+            Some(end) if end <= self.start + self.len => {
+                self.set_cursor(end);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+// SAFETY: allocations are carved out of the region without overlapping,
+// and only the most recent one (whose end is the cursor) is resized or
+// given back in place.
+unsafe impl core::alloc::GlobalAlloc for BumpArena {
+    unsafe fn alloc(&self, layout: core::alloc::Layout) -> *mut u8 {
+        let begin = match self.cursor().checked_add(layout.align() - 1) {
+            Some(at) => at & !(layout.align() - 1),
+            None => return core::ptr::null_mut(),
+        };
+        if !self.bump_to(begin.checked_add(layout.size())) {
+            return core::ptr::null_mut();
+        }
+        begin as *mut u8
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: core::alloc::Layout) {
+        if ptr as usize + layout.size() == self.cursor() {
+            self.set_cursor(ptr as usize);
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: core::alloc::Layout, new_size: usize) -> *mut u8 {
+        if ptr as usize + layout.size() == self.cursor() {
+            return if self.bump_to((ptr as usize).checked_add(new_size)) { ptr } else { core::ptr::null_mut() };
+        }
+        // SAFETY: `realloc`'s contract makes `new_size` valid for `layout.align()`
+        let new_layout = unsafe { core::alloc::Layout::from_size_align_unchecked(new_size, layout.align()) };
+        let new = unsafe { self.alloc(new_layout) };
+        if !new.is_null() {
+            // SAFETY: both blocks are live, distinct, and at least this long
+            unsafe { core::ptr::copy_nonoverlapping(ptr, new, layout.size().min(new_size)) };
+        }
+        new
+    }
+}
+
+// ── Builders (`(solana.required)` typestate) ─────────────────────────
+//
+// The builder of a message with `(solana.required)` fields takes one type
+// parameter per such field, `Unset` until its `with_<field>` setter is
+// called and `Set` after. `build()` is only implemented with every
+// parameter `Set`, so leaving a required field out is a compile error
+// rather than a message its own decoder rejects.
+
+/// Typestate of a required field whose builder setter has not run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Unset;
+
+/// Typestate of a required field whose builder setter has run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Set;
+
+// ── Validation (`validate()`) ────────────────────────────────────────
+//
+// Messages whose fields carry `(solana.min)`, `(solana.non_default)` or
+// the other rules of proto/solana/options.proto get a generated
+// `validate()`, which also runs the rules of their sub-messages. Decoding
+// checks none of them but `max_len` and `max_count`, so a program
+// validates an instruction's input after decoding it.
+
+/// A field rule from proto/solana/options.proto.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationRule {
+    /// `(solana.min)`: the value is smaller
+    Min(i64),
+    /// `(solana.max)`: the value is larger
+    Max(i64),
+    /// `(solana.min_len)`: the value is shorter, in bytes
+    MinLen(u32),
+    /// `(solana.max_len)`: the value is longer, in bytes
+    MaxLen(u32),
+    /// `(solana.max_count)`: the field has more elements
+    MaxCount(u32),
+    /// `(solana.prefix)`: the value does not start with it
+    Prefix(&'static str),
+    /// `(solana.suffix)`: the value does not end with it
+    Suffix(&'static str),
+    /// `(solana.non_default)`: the field is at its default or unset
+    NonDefault,
+}
+
+/// The first rule a message broke, returned by `validate()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// .proto field names from the validated message, with element
+    /// indices and map keys: `"config.fee_bps"`, `"history[2].salt"`
+    pub path: String,
+    pub rule: ValidationRule,
+}
+
+impl ValidationError {
+    pub fn new(field: &str, rule: ValidationRule) -> Self {
+        ValidationError { path: field.into(), rule }
+    }
+
+    /// An error in element `index` (or at map key `index`) of `field`.
+    pub fn element(field: &str, index: impl fmt::Debug, rule: ValidationRule) -> Self {
+        ValidationError { path: format!("{field}[{index:?}]"), rule }
+    }
+
+    /// This error of a sub-message, as seen from the message holding it
+    /// in `field`.
+    pub fn within(self, field: &str) -> Self {
+        ValidationError { path: format!("{field}.{}", self.path), ..self }
+    }
+
+    /// This error of the sub-message in element `index` (or at map key
+    /// `index`) of `field`.
+    pub fn within_element(self, field: &str, index: impl fmt::Debug) -> Self {
+        ValidationError { path: format!("{field}[{index:?}].{}", self.path), ..self }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match self.rule {
+            ValidationRule::Min(min) => write!(f, "less than {min}"),
+            ValidationRule::Max(max) => write!(f, "greater than {max}"),
+            ValidationRule::MinLen(len) => write!(f, "shorter than {len} bytes"),
+            ValidationRule::MaxLen(len) => write!(f, "longer than {len} bytes"),
+            ValidationRule::MaxCount(count) => write!(f, "more than {count} elements"),
+            ValidationRule::Prefix(prefix) => write!(f, "does not start with {prefix:?}"),
+            ValidationRule::Suffix(suffix) => write!(f, "does not end with {suffix:?}"),
+            ValidationRule::NonDefault => write!(f, "must be set"),
+        }
+    }
+}
+
+// ── Borsh interop (feature = "borsh") ────────────────────────────────
+
+/// Re-encode a Borsh-serialized value (e.g. an Anchor account) as protobuf.
+#[cfg(feature = "borsh")]
+pub fn borsh_to_protobuf<T>(data: &[u8]) -> Result<Vec<u8>, DecodeError>
+where
+    T: Message + borsh::BorshDeserialize,
+{
+    let msg: T = borsh::from_slice(data).map_err(|_| DecodeError::InvalidData("invalid Borsh data"))?;
+    Ok(msg.encode())
+}
+
+/// Re-encode a protobuf message as Borsh.
+#[cfg(feature = "borsh")]
+pub fn protobuf_to_borsh<T>(data: &[u8]) -> Result<Vec<u8>, DecodeError>
+where
+    T: Message + borsh::BorshSerialize,
+{
+    let msg = T::decode(data)?;
+    borsh::to_vec(&msg).map_err(|_| DecodeError::InvalidData("value not representable in Borsh"))
+}
+
+/// Written as Borsh writes a `Vec`: a `u32` length, then the elements.
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshSerialize, const N: usize> borsh::BorshSerialize for BoundedVec<T, N> {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        (self.len() as u32).serialize(writer)?;
+        for elem in self.iter() {
+            elem.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Read as a Borsh `Vec`; a length over `N` is `InvalidData`.
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshDeserialize, const N: usize> borsh::BorshDeserialize for BoundedVec<T, N> {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let len = u32::deserialize_reader(reader)? as usize;
+        if len > N {
+            return Err(borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, "BoundedVec length over its capacity"));
+        }
+        let mut out = Self::new();
+        for _ in 0..len {
+            out.push(T::deserialize_reader(reader)?);
+        }
+        Ok(out)
+    }
+}
+
+// ── Pod layouts (feature = "bytemuck") ───────────────────────────────
+//
+// The `pod` option's `<Message>Pod` structs are `#[repr(C, packed)]`,
+// so any byte offset is aligned for them and only the length can be
+// wrong. Like account data, input longer than the layout is fine: the
+// bytes past it are not looked at.
+
+/// View the first `size_of::<T>()` bytes of `data` as a `T`.
+#[cfg(feature = "bytemuck")]
+pub fn pod_from_bytes<T: bytemuck::Pod>(data: &[u8]) -> Result<&T, DecodeError> {
+    let bytes = data.get(..core::mem::size_of::<T>()).ok_or(DecodeError::BufferOverflow)?;
+    bytemuck::try_from_bytes(bytes).map_err(|_| POD_MISALIGNED)
+}
+
+/// Like `pod_from_bytes`, for writing the value in place.
+#[cfg(feature = "bytemuck")]
+pub fn pod_from_bytes_mut<T: bytemuck::Pod>(data: &mut [u8]) -> Result<&mut T, DecodeError> {
+    let bytes = data.get_mut(..core::mem::size_of::<T>()).ok_or(DecodeError::BufferOverflow)?;
+    bytemuck::try_from_bytes_mut(bytes).map_err(|_| POD_MISALIGNED)
+}
+
+#[cfg(feature = "bytemuck")]
+const POD_MISALIGNED: DecodeError = DecodeError::InvalidData("data is not aligned for the Pod type");
+
+// ── prost interop (feature = "prost") ────────────────────────────────
+//
+// Off-chain only: the generated `prost` option implements
+// `prost::Message` for every message behind this feature, so the same
+// types serve prost and tonic services.
+
+/// `prost::Message::merge_field` for a generated message: copy field `tag`
+/// out of `buf` and merge it into `msg` with the generated decoder.
+/// Merging field by field is equivalent to merging the whole buffer,
+/// groups and preserved unknown fields included.
+#[cfg(feature = "prost")]
+pub fn prost_merge_field<M: Message>(
+    msg: &mut M,
+    tag: u32,
+    wire_type: prost::encoding::WireType,
+    buf: &mut impl prost::bytes::Buf,
+) -> Result<(), prost::DecodeError> {
+    let mut field = Vec::new();
+    copy_prost_field(&mut field, ((tag as u64) << 3) | wire_type as u64, buf, DecodeOptions::DEFAULT.max_depth)?;
+    msg.merge(&field).map_err(prost_error)
+}
+
+/// Append key `key` and the value following it in `buf` to `out`, through
+/// at most `depth` levels of nested groups.
+#[cfg(feature = "prost")]
+fn copy_prost_field(
+    out: &mut Vec<u8>,
+    key: u64,
+    buf: &mut impl prost::bytes::Buf,
+    depth: usize,
+) -> Result<(), prost::DecodeError> {
+    use prost::encoding::decode_varint;
+
+    encode_key(out, key);
+    let len = match key & 0x07 {
+        0 => {
+            encode_varint(out, decode_varint(buf)?);
+            return Ok(());
+        }
+        1 => 8,
+        2 => {
+            let len = decode_varint(buf)?;
+            encode_varint(out, len);
+            usize::try_from(len).unwrap_or(usize::MAX)
+        }
+        5 => 4,
+        3 => {
+            if depth == 0 {
+                return Err(prost_error(DecodeError::RecursionLimitExceeded));
+            }
+            loop {
+                let inner = decode_varint(buf)?;
+                if inner & 0x07 == 4 {
+                    if inner >> 3 != key >> 3 {
+                        return Err(prost_error(MISMATCHED_END_GROUP));
+                    }
+                    encode_key(out, inner);
+                    return Ok(());
+                }
+                copy_prost_field(out, inner, buf, depth - 1)?;
+            }
+        }
+        4 => return Err(prost_error(STRAY_END_GROUP)),
+        wire_type => return Err(prost_error(DecodeError::UnknownWireType(wire_type))),
+    };
+    if buf.remaining() < len {
+        return Err(prost_error(DecodeError::BufferOverflow));
+    }
+    let start = out.len();
+    out.resize(start + len, 0);
+    buf.copy_to_slice(&mut out[start..]);
+    Ok(())
+}
+
+#[cfg(feature = "prost")]
+fn prost_error(err: DecodeError) -> prost::DecodeError {
+    prost::DecodeError::new(err.to_string())
+}
+
+// ── RPC client (feature = "client") ──────────────────────────────────
+//
+// Off-chain only: the generated `client` option's `fetch()` and
+// `fetch_multiple()` read accounts with solana-client's nonblocking
+// `RpcClient`, at the client's commitment, and decode their data with
+// the message's account layout; its filters select accounts for
+// `getProgramAccounts` by bytes at fixed offsets. The feature needs
+// `solana-program`, so `Pubkey` is the type the RPC client takes.
+
+#[cfg(all(feature = "client", not(feature = "solana-program")))]
+compile_error!("the `client` feature needs the `solana-program` feature");
+
+#[cfg(feature = "client")]
+pub use solana_client::nonblocking::rpc_client::RpcClient;
+#[cfg(feature = "client")]
+pub use solana_client::rpc_filter::RpcFilterType;
+
+/// Most addresses one `getMultipleAccounts` request may name.
+#[cfg(feature = "client")]
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Why fetching an account as a message failed.
+#[cfg(feature = "client")]
+#[derive(Debug)]
+pub enum FetchError {
+    /// The RPC request failed.
+    Rpc(alloc::boxed::Box<solana_client::client_error::ClientError>),
+    /// There is no account at the address.
+    AccountNotFound(Pubkey),
+    /// The data of the account at `address` is not the message, such as
+    /// one with another discriminator.
+    Decode { address: Pubkey, error: DecodeError },
+}
+
+#[cfg(feature = "client")]
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Rpc(err) => write!(f, "rpc: {}", err),
+            FetchError::AccountNotFound(address) => write!(f, "no account at {}", address),
+            FetchError::Decode { address, error } => write!(f, "account {}: {}", address, error),
+        }
+    }
+}
+
+#[cfg(feature = "client")]
+impl From<solana_client::client_error::ClientError> for FetchError {
+    fn from(err: solana_client::client_error::ClientError) -> Self {
+        FetchError::Rpc(alloc::boxed::Box::new(err))
+    }
+}
+
+/// Fetch the account at `address` and decode its data with `decode`.
+#[cfg(feature = "client")]
+pub async fn fetch_account<M>(
+    rpc: &RpcClient,
+    address: &Pubkey,
+    decode: impl FnOnce(&[u8]) -> Result<M, DecodeError>,
+) -> Result<M, FetchError> {
+    let account = rpc.get_account_with_commitment(address, rpc.commitment()).await?.value;
+    let account = account.ok_or(FetchError::AccountNotFound(*address))?;
+    decode(&account.data).map_err(|error| FetchError::Decode { address: *address, error })
+}
+
+/// Fetch the accounts at `addresses`, `MAX_MULTIPLE_ACCOUNTS` to a
+/// request, and decode each with `decode`: `None` for an address with no
+/// account, and the first account that does not decode fails them all.
+#[cfg(feature = "client")]
+pub async fn fetch_accounts<M>(
+    rpc: &RpcClient,
+    addresses: &[Pubkey],
+    decode: impl Fn(&[u8]) -> Result<M, DecodeError>,
+) -> Result<Vec<Option<M>>, FetchError> {
+    let mut out = Vec::with_capacity(addresses.len());
+    for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let accounts = rpc.get_multiple_accounts_with_commitment(chunk, rpc.commitment()).await?.value;
+        for (address, account) in chunk.iter().zip(accounts) {
+            let msg = match account {
+                Some(account) => {
+                    Some(decode(&account.data).map_err(|error| FetchError::Decode { address: *address, error })?)
+                }
+                None => None,
+            };
+            out.push(msg);
+        }
+    }
+    Ok(out)
+}
+
+/// A `getProgramAccounts` filter for accounts whose data holds `bytes` at
+/// byte `offset`, as the generated `discriminator_filter()` and the
+/// `<Message>Pod` `filter_by_<field>()` build them.
+#[cfg(feature = "client")]
+pub fn memcmp_filter(offset: usize, bytes: &[u8]) -> RpcFilterType {
+    RpcFilterType::Memcmp(solana_client::rpc_filter::Memcmp::new_raw_bytes(offset, bytes.to_vec()))
+}
+
+// ── WebAssembly (feature = "wasm") ───────────────────────────────────
+//
+// The runtime and generated code build for `wasm32-unknown-unknown` as
+// they are: only the `solana-program` feature calls into the validator,
+// and only `client` needs a native target. The `wasm` option's exports
+// take and return proto3 JSON, so the feature needs `json`.
+
+#[cfg(all(feature = "client", target_arch = "wasm32"))]
+compile_error!("the `client` feature needs a native target, not wasm32");
+
+#[cfg(all(feature = "wasm", not(feature = "json")))]
+compile_error!("the `wasm` feature needs the `json` feature");
+
+/// A `DecodeError` as the JavaScript `Error` a `wasm` export throws.
+#[cfg(feature = "wasm")]
+pub fn js_error(err: DecodeError) -> wasm_bindgen::JsError {
+    wasm_bindgen::JsError::new(&err.to_string())
+}
+
+// ── Reflection (feature = "reflect") ─────────────────────────────────
+//
+// Off-chain only: the generated `reflect` option describes every message
+// in a static table and implements `Reflect`, whose provided methods read
+// and write fields by number through the encoded message. A generic
+// indexer or debugger needs the descriptor, not the Rust type.
+
+/// A field's protobuf type; for a map field, the type of its values.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldType {
+    Double,
+    Float,
+    Int64,
+    Uint64,
+    Int32,
+    Fixed64,
+    Fixed32,
+    Bool,
+    String,
+    Message,
+    Bytes,
+    Uint32,
+    Enum,
+    Sfixed32,
+    Sfixed64,
+    Sint32,
+    Sint64,
+}
+
+#[cfg(feature = "reflect")]
+impl FieldType {
+    /// Wire type of one value.
+    pub fn wire_type(self) -> u64 {
+        match self {
+            FieldType::Double | FieldType::Fixed64 | FieldType::Sfixed64 => 1,
+            FieldType::Float | FieldType::Fixed32 | FieldType::Sfixed32 => 5,
+            FieldType::String | FieldType::Bytes | FieldType::Message => 2,
+            _ => 0,
+        }
+    }
+
+    /// The proto3 default value: what an absent implicit-presence field,
+    /// or a map entry without a value, reads as.
+    pub fn default_value(self) -> Value {
+        match self {
+            FieldType::Double => Value::F64(0.0),
+            FieldType::Float => Value::F32(0.0),
+            FieldType::Int64 | FieldType::Sfixed64 | FieldType::Sint64 => Value::I64(0),
+            FieldType::Uint64 | FieldType::Fixed64 => Value::U64(0),
+            FieldType::Int32 | FieldType::Sfixed32 | FieldType::Sint32 => Value::I32(0),
+            FieldType::Uint32 | FieldType::Fixed32 => Value::U32(0),
+            FieldType::Bool => Value::Bool(false),
+            FieldType::Enum => Value::Enum(0),
+            FieldType::String => Value::String(String::new()),
+            FieldType::Bytes => Value::Bytes(Vec::new()),
+            FieldType::Message => Value::Message(Vec::new()),
+        }
+    }
+}
+
+/// How many values a field holds.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldLabel {
+    /// One value; a scalar outside a oneof reads as its default when absent.
+    Singular,
+    /// proto3 `optional`: one value with explicit presence.
+    Optional,
+    Repeated,
+    /// `map<K, V>` with keys of this type; the field's type is `V`.
+    Map(FieldType),
+}
+
+#[cfg(feature = "reflect")]
+impl FieldLabel {
+    fn has_presence(self, field_type: FieldType, in_oneof: bool) -> bool {
+        match self {
+            FieldLabel::Optional => true,
+            FieldLabel::Singular => in_oneof || field_type == FieldType::Message,
+            FieldLabel::Repeated | FieldLabel::Map(_) => false,
+        }
+    }
+}
+
+/// One field of a `MessageDescriptor`.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Copy, Debug)]
+pub struct FieldDescriptor {
+    pub number: u32,
+    /// Name as declared in the .proto file
+    pub name: &'static str,
+    /// lowerCamelCase name of the proto3 JSON mapping
+    pub json_name: &'static str,
+    pub field_type: FieldType,
+    pub label: FieldLabel,
+    /// The oneof the field is a member of
+    pub oneof: Option<&'static str>,
+    /// Descriptor of a `Message` field's type
+    pub message: Option<fn() -> &'static MessageDescriptor>,
+    /// Descriptor of an `Enum` field's type
+    pub enum_type: Option<fn() -> &'static EnumDescriptor>,
+}
+
+#[cfg(feature = "reflect")]
+impl FieldDescriptor {
+    pub const fn new(
+        number: u32,
+        name: &'static str,
+        json_name: &'static str,
+        field_type: FieldType,
+        label: FieldLabel,
+    ) -> Self {
+        FieldDescriptor { number, name, json_name, field_type, label, oneof: None, message: None, enum_type: None }
+    }
+
+    pub const fn with_oneof(self, oneof: &'static str) -> Self {
+        FieldDescriptor { oneof: Some(oneof), ..self }
+    }
+
+    pub const fn with_message(self, message: fn() -> &'static MessageDescriptor) -> Self {
+        FieldDescriptor { message: Some(message), ..self }
+    }
+
+    pub const fn with_enum(self, enum_type: fn() -> &'static EnumDescriptor) -> Self {
+        FieldDescriptor { enum_type: Some(enum_type), ..self }
+    }
+
+    /// Whether an absent value reads as `None` rather than a default:
+    /// `optional` fields, sub-messages and oneof members.
+    pub fn has_presence(&self) -> bool {
+        self.label.has_presence(self.field_type, self.oneof.is_some())
+    }
+
+    fn message_descriptor(&self) -> Result<&'static MessageDescriptor, DecodeError> {
+        self.message.map(|descriptor| descriptor()).ok_or(NO_MESSAGE_DESCRIPTOR)
+    }
+}
+
+/// A message type: its full name and its fields in declaration order.
+#[cfg(feature = "reflect")]
+#[derive(Debug)]
+pub struct MessageDescriptor {
+    /// e.g. `"example.UserProfile"`
+    pub full_name: &'static str,
+    pub fields: &'static [FieldDescriptor],
+}
+
+#[cfg(feature = "reflect")]
+impl MessageDescriptor {
+    pub fn field(&self, number: u32) -> Option<&'static FieldDescriptor> {
+        self.fields.iter().find(|f| f.number == number)
+    }
+
+    /// The field with this .proto or JSON name.
+    pub fn field_by_name(&self, name: &str) -> Option<&'static FieldDescriptor> {
+        self.fields.iter().find(|f| f.name == name || f.json_name == name)
+    }
+}
+
+/// An enum type: its full name and its values as declared, aliases included.
+#[cfg(feature = "reflect")]
+#[derive(Debug)]
+pub struct EnumDescriptor {
+    pub full_name: &'static str,
+    pub values: &'static [(&'static str, i32)],
+}
+
+#[cfg(feature = "reflect")]
+impl EnumDescriptor {
+    /// The first name declared for `number`.
+    pub fn name_of(&self, number: i32) -> Option<&'static str> {
+        self.values.iter().find(|(_, n)| *n == number).map(|(name, _)| *name)
+    }
+
+    pub fn number_of(&self, name: &str) -> Option<i32> {
+        self.values.iter().find(|(n, _)| *n == name).map(|(_, number)| *number)
+    }
+}
+
+/// A field value read or written through `Reflect`. Each `FieldType` has
+/// one variant: `I32` for int32, sint32 and sfixed32, `U64` for uint64 and
+/// fixed64, and so on.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    I32(i32),
+    I64(i64),
+    U32(u32),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    /// An enum's number, declared or not
+    Enum(i32),
+    String(String),
+    Bytes(Vec<u8>),
+    /// A sub-message's present fields by number, in number order
+    Message(Vec<(u32, Value)>),
+    /// A repeated field's elements
+    List(Vec<Value>),
+    /// A map field's entries, in wire order
+    Map(Vec<(Value, Value)>),
+}
+
+#[cfg(feature = "reflect")]
+impl Value {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            Value::I32(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::I64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_u32(&self) -> Option<u32> {
+        match self {
+            Value::U32(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::U64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            Value::F32(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::F64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_enum(&self) -> Option<i32> {
+        match self {
+            Value::Enum(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_message(&self) -> Option<&[(u32, Value)]> {
+        match self {
+            Value::Message(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[Value]> {
+        match self {
+            Value::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_map(&self) -> Option<&[(Value, Value)]> {
+        match self {
+            Value::Map(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// Implemented by every generated message under the `reflect` option.
+/// Fields are read and written through the encoded message, so each call
+/// costs an encode and, for writes, a decode: a tool for off-chain
+/// inspection, not a fast path.
+#[cfg(feature = "reflect")]
+pub trait Reflect: Message {
+    fn descriptor() -> &'static MessageDescriptor;
+
+    /// Field `number`'s value. `None` if the message has no such field or
+    /// it has presence and is absent; repeated and map fields read as a
+    /// possibly empty `List` / `Map`.
+    fn get_field(&self, number: u32) -> Option<Value> {
+        let desc = Self::descriptor();
+        let field = desc.field(number)?;
+        let mut values = reflect_decode(desc, &self.encode(), Some(number), DecodeOptions::DEFAULT.max_depth).ok()?;
+        match values.pop() {
+            Some((_, value)) => Some(value),
+            None if field.has_presence() => None,
+            None => Some(match field.label {
+                FieldLabel::Repeated => Value::List(Vec::new()),
+                FieldLabel::Map(_) => Value::Map(Vec::new()),
+                _ => field.field_type.default_value(),
+            }),
+        }
+    }
+
+    /// The fields encoding would write, in number order, with their
+    /// values. Preserved unknown fields are left out.
+    fn fields(&self) -> Vec<(&'static FieldDescriptor, Value)> {
+        let desc = Self::descriptor();
+        reflect_decode(desc, &self.encode(), None, DecodeOptions::DEFAULT.max_depth)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(number, value)| Some((desc.field(number)?, value)))
+            .collect()
+    }
+
+    /// Replace field `number` with `value`; setting a oneof member clears
+    /// the others. Fails with `InvalidData`, leaving `self` unchanged, if
+    /// there is no such field or `value` does not match its type, and
+    /// with the decode error if the message rejects it (e.g. a
+    /// `(solana.fixed_len)` field of the wrong length).
+    fn set_field(&mut self, number: u32, value: &Value) -> Result<(), DecodeError> {
+        let field = Self::descriptor().field(number).ok_or(NO_SUCH_FIELD)?;
+        let mut data = without_field(&self.encode(), number)?;
+        reflect_encode_field(field, value, &mut data, DecodeOptions::DEFAULT.max_depth)?;
+        *self = Self::decode_with(&data, &DecodeOptions::ACCOUNT)?;
+        Ok(())
+    }
+
+    /// Reset field `number` to absent, or its default.
+    fn clear_field(&mut self, number: u32) -> Result<(), DecodeError> {
+        Self::descriptor().field(number).ok_or(NO_SUCH_FIELD)?;
+        *self = Self::decode_with(&without_field(&self.encode(), number)?, &DecodeOptions::ACCOUNT)?;
+        Ok(())
+    }
+}
+
+/// Implemented by every generated enum under the `reflect` option.
+#[cfg(feature = "reflect")]
+pub trait ReflectEnum {
+    fn descriptor() -> &'static EnumDescriptor;
+}
+
+#[cfg(feature = "reflect")]
+const NO_SUCH_FIELD: DecodeError = DecodeError::InvalidData("no field with that number");
+#[cfg(feature = "reflect")]
+const VALUE_MISMATCH: DecodeError = DecodeError::InvalidData("value does not match the field type");
+#[cfg(feature = "reflect")]
+const NO_MESSAGE_DESCRIPTOR: DecodeError = DecodeError::InvalidData("message field without a descriptor");
+
+/// `data` without the records of field `number`.
+#[cfg(feature = "reflect")]
+fn without_field(data: &[u8], number: u32) -> Result<Vec<u8>, DecodeError> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut pos = 0;
+    while pos < data.len() {
+        let (tag, value_pos) = decode_key(data, pos)?;
+        let end = skip_field(data, value_pos, tag)?;
+        if tag >> 3 != number as u64 {
+            out.extend_from_slice(&data[pos..end]);
+        }
+        pos = end;
+    }
+    Ok(out)
+}
+
+/// What `reflect_decode` needs to know of a field, from a static
+/// `MessageDescriptor` or a `DescriptorPool`.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Copy)]
+struct FieldInfo<'a, D> {
+    field_type: FieldType,
+    label: FieldLabel,
+    oneof: Option<&'a str>,
+    /// The type of a `Message` field, if known
+    message: Option<D>,
+}
+
+#[cfg(feature = "reflect")]
+impl<D> FieldInfo<'_, D> {
+    fn message(self) -> Result<D, DecodeError> {
+        self.message.ok_or(NO_MESSAGE_DESCRIPTOR)
+    }
+}
+
+/// A message type `reflect_decode` can decode: its fields by number.
+#[cfg(feature = "reflect")]
+trait FieldLookup<'a>: Copy {
+    fn lookup(self, number: u32) -> Option<FieldInfo<'a, Self>>;
+}
+
+#[cfg(feature = "reflect")]
+impl FieldLookup<'static> for &'static MessageDescriptor {
+    fn lookup(self, number: u32) -> Option<FieldInfo<'static, Self>> {
+        let field = self.field(number)?;
+        Some(FieldInfo {
+            field_type: field.field_type,
+            label: field.label,
+            oneof: field.oneof,
+            message: field.message.map(|descriptor| descriptor()),
+        })
+    }
+}
+
+/// Decode `data` as a `desc` message into its present fields, in number
+/// order, or only field `only`. Singular fields keep the last value, and
+/// sub-message records merge, as in generated decoders.
+#[cfg(feature = "reflect")]
+fn reflect_decode<'a, D: FieldLookup<'a>>(
+    desc: D,
+    data: &[u8],
+    only: Option<u32>,
+    depth: usize,
+) -> Result<Vec<(u32, Value)>, DecodeError> {
+    if depth == 0 {
+        return Err(DecodeError::RecursionLimitExceeded);
+    }
+    let mut values: Vec<(u32, Value)> = Vec::new();
+    // Payloads of singular sub-message fields; concatenating merges them
+    let mut messages: Vec<(u32, Vec<u8>)> = Vec::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        let (tag, value_pos) = decode_key(data, pos)?;
+        let number = (tag >> 3) as u32;
+        let field = match desc.lookup(number) {
+            Some(field) if only.unwrap_or(number) == number => field,
+            _ => {
+                pos = skip_field(data, value_pos, tag)?;
+                continue;
+            }
+        };
+        if let Some(oneof) = field.oneof {
+            let sibling = |n: u32| n != number && desc.lookup(n).is_some_and(|f| f.oneof == Some(oneof));
+            values.retain(|(n, _)| !sibling(*n));
+            messages.retain(|(n, _)| !sibling(*n));
+        }
+        pos = match field.label {
+            FieldLabel::Repeated => {
+                let Value::List(list) = entry_or(&mut values, number, || Value::List(Vec::new())) else {
+                    unreachable!()
+                };
+                if tag & 0x07 == 2 && field.field_type.wire_type() != 2 {
+                    // Packed scalars
+                    let (payload, end) = decode_message_slice(data, value_pos)?;
+                    let mut p = 0;
+                    while p < payload.len() {
+                        let (value, next) = reflect_decode_scalar(field.field_type, payload, p)?;
+                        list.push(value);
+                        p = next;
+                    }
+                    end
+                } else {
+                    expect_wire_type(tag, field.field_type.wire_type())?;
+                    let (value, end) = reflect_decode_value(field, data, value_pos, depth)?;
+                    list.push(value);
+                    end
+                }
+            }
+            FieldLabel::Map(key_type) => {
+                expect_wire_type(tag, 2)?;
+                let (entry, end) = decode_message_slice(data, value_pos)?;
+                let (key, value) = reflect_decode_entry(field, key_type, entry, depth)?;
+                let Value::Map(map) = entry_or(&mut values, number, || Value::Map(Vec::new())) else {
+                    unreachable!()
+                };
+                match map.iter_mut().find(|(k, _)| *k == key) {
+                    Some(existing) => existing.1 = value,
+                    None => map.push((key, value)),
+                }
+                end
+            }
+            _ if field.field_type == FieldType::Message => {
+                expect_wire_type(tag, 2)?;
+                let (payload, end) = decode_message_slice(data, value_pos)?;
+                match messages.iter_mut().find(|(n, _)| *n == number) {
+                    Some((_, merged)) => merged.extend_from_slice(payload),
+                    None => messages.push((number, payload.to_vec())),
+                }
+                end
+            }
+            _ => {
+                expect_wire_type(tag, field.field_type.wire_type())?;
+                let (value, end) = reflect_decode_scalar(field.field_type, data, value_pos)?;
+                values.retain(|(n, _)| *n != number);
+                values.push((number, value));
+                end
+            }
+        };
+    }
+    for (number, payload) in messages {
+        let nested = desc.lookup(number).ok_or(NO_SUCH_FIELD)?.message()?;
+        values.push((number, Value::Message(reflect_decode(nested, &payload, None, depth - 1)?)));
+    }
+    values.sort_by_key(|(number, _)| *number);
+    Ok(values)
+}
+
+/// The value of field `number` in `values`, inserted by `init` if absent.
+#[cfg(feature = "reflect")]
+fn entry_or(values: &mut Vec<(u32, Value)>, number: u32, init: impl FnOnce() -> Value) -> &mut Value {
+    let i = match values.iter().position(|(n, _)| *n == number) {
+        Some(i) => i,
+        None => {
+            values.push((number, init()));
+            values.len() - 1
+        }
+    };
+    &mut values[i].1
+}
+
+/// One value of `field`: a sub-message or a scalar.
+#[cfg(feature = "reflect")]
+fn reflect_decode_value<'a, D: FieldLookup<'a>>(
+    field: FieldInfo<'a, D>,
+    data: &[u8],
+    pos: usize,
+    depth: usize,
+) -> Result<(Value, usize), DecodeError> {
+    if field.field_type != FieldType::Message {
+        return reflect_decode_scalar(field.field_type, data, pos);
+    }
+    let (payload, end) = decode_message_slice(data, pos)?;
+    Ok((Value::Message(reflect_decode(field.message()?, payload, None, depth - 1)?), end))
+}
+
+/// A map entry's key and value, defaulted when absent.
+#[cfg(feature = "reflect")]
+fn reflect_decode_entry<'a, D: FieldLookup<'a>>(
+    field: FieldInfo<'a, D>,
+    key_type: FieldType,
+    entry: &[u8],
+    depth: usize,
+) -> Result<(Value, Value), DecodeError> {
+    let mut key = key_type.default_value();
+    let mut value = field.field_type.default_value();
+    let mut pos = 0;
+    while pos < entry.len() {
+        let (tag, value_pos) = decode_key(entry, pos)?;
+        pos = match tag >> 3 {
+            1 => {
+                expect_wire_type(tag, key_type.wire_type())?;
+                let (v, end) = reflect_decode_scalar(key_type, entry, value_pos)?;
+                key = v;
+                end
+            }
+            2 => {
+                expect_wire_type(tag, field.field_type.wire_type())?;
+                let (v, end) = reflect_decode_value(field, entry, value_pos, depth)?;
+                value = v;
+                end
+            }
+            _ => skip_field(entry, value_pos, tag)?,
+        };
+    }
+    Ok((key, value))
+}
+
+#[cfg(feature = "reflect")]
+fn reflect_decode_scalar(field_type: FieldType, data: &[u8], pos: usize) -> Result<(Value, usize), DecodeError> {
+    match field_type {
+        FieldType::Double => decode_double(data, pos).map(|(v, p)| (Value::F64(v), p)),
+        FieldType::Float => decode_float(data, pos).map(|(v, p)| (Value::F32(v), p)),
+        FieldType::Int64 => decode_int64(data, pos).map(|(v, p)| (Value::I64(v), p)),
+        FieldType::Uint64 => decode_varint(data, pos).map(|(v, p)| (Value::U64(v), p)),
+        FieldType::Int32 => decode_int32(data, pos).map(|(v, p)| (Value::I32(v), p)),
+        FieldType::Fixed64 => decode_fixed64(data, pos).map(|(v, p)| (Value::U64(v), p)),
+        FieldType::Fixed32 => decode_fixed32(data, pos).map(|(v, p)| (Value::U32(v), p)),
+        FieldType::Bool => decode_bool(data, pos).map(|(v, p)| (Value::Bool(v), p)),
+        FieldType::String => decode_string(data, pos).map(|(v, p)| (Value::String(v), p)),
+        FieldType::Bytes => decode_bytes(data, pos).map(|(v, p)| (Value::Bytes(v), p)),
+        FieldType::Uint32 => decode_varint(data, pos).map(|(v, p)| (Value::U32(v as u32), p)),
+        FieldType::Enum => decode_int32(data, pos).map(|(v, p)| (Value::Enum(v), p)),
+        FieldType::Sfixed32 => decode_sfixed32(data, pos).map(|(v, p)| (Value::I32(v), p)),
+        FieldType::Sfixed64 => decode_sfixed64(data, pos).map(|(v, p)| (Value::I64(v), p)),
+        FieldType::Sint32 => decode_zigzag32(data, pos).map(|(v, p)| (Value::I32(v), p)),
+        FieldType::Sint64 => decode_zigzag64(data, pos).map(|(v, p)| (Value::I64(v), p)),
+        FieldType::Message => Err(VALUE_MISMATCH),
+    }
+}
+
+/// Append the records of `value` as field `field`: one per element of
+/// a repeated or map field, each written even at its default.
+#[cfg(feature = "reflect")]
+fn reflect_encode_field(
+    field: &FieldDescriptor,
+    value: &Value,
+    buf: &mut Vec<u8>,
+    depth: usize,
+) -> Result<(), DecodeError> {
+    match (field.label, value) {
+        (FieldLabel::Repeated, Value::List(items)) => {
+            for item in items {
+                reflect_encode_value(field.number, field.field_type, field, item, buf, depth)?;
+            }
+            Ok(())
+        }
+        (FieldLabel::Map(key_type), Value::Map(entries)) => {
+            for (key, value) in entries {
+                let mut entry = Vec::new();
+                reflect_encode_value(1, key_type, field, key, &mut entry, depth)?;
+                reflect_encode_value(2, field.field_type, field, value, &mut entry, depth)?;
+                encode_key(buf, ((field.number as u64) << 3) | 2);
+                encode_bytes(buf, &entry);
+            }
+            Ok(())
+        }
+        (FieldLabel::Repeated | FieldLabel::Map(_), _) => Err(VALUE_MISMATCH),
+        _ => reflect_encode_value(field.number, field.field_type, field, value, buf, depth),
+    }
+}
+
+/// Append one `field_type` value as a record of field `number`; `field`
+/// supplies the descriptor of a sub-message.
+#[cfg(feature = "reflect")]
+fn reflect_encode_value(
+    number: u32,
+    field_type: FieldType,
+    field: &FieldDescriptor,
+    value: &Value,
+    buf: &mut Vec<u8>,
+    depth: usize,
+) -> Result<(), DecodeError> {
+    encode_key(buf, ((number as u64) << 3) | field_type.wire_type());
+    match (field_type, value) {
+        (FieldType::Double, Value::F64(v)) => encode_double(buf, *v),
+        (FieldType::Float, Value::F32(v)) => encode_float(buf, *v),
+        (FieldType::Int64, Value::I64(v)) => encode_int64(buf, *v),
+        (FieldType::Uint64, Value::U64(v)) => encode_varint(buf, *v),
+        (FieldType::Int32, Value::I32(v)) => encode_int32(buf, *v),
+        (FieldType::Fixed64, Value::U64(v)) => encode_fixed64(buf, *v),
+        (FieldType::Fixed32, Value::U32(v)) => encode_fixed32(buf, *v),
+        (FieldType::Bool, Value::Bool(v)) => encode_bool(buf, *v),
+        (FieldType::String, Value::String(v)) => encode_string(buf, v),
+        (FieldType::Bytes, Value::Bytes(v)) => encode_bytes(buf, v),
+        (FieldType::Uint32, Value::U32(v)) => encode_varint(buf, *v as u64),
+        (FieldType::Enum, Value::Enum(v)) => encode_int32(buf, *v),
+        (FieldType::Sfixed32, Value::I32(v)) => encode_sfixed32(buf, *v),
+        (FieldType::Sfixed64, Value::I64(v)) => encode_sfixed64(buf, *v),
+        (FieldType::Sint32, Value::I32(v)) => encode_zigzag32(buf, *v),
+        (FieldType::Sint64, Value::I64(v)) => encode_zigzag64(buf, *v),
+        (FieldType::Message, Value::Message(fields)) => {
+            if depth == 0 {
+                return Err(DecodeError::RecursionLimitExceeded);
+            }
+            let nested = field.message_descriptor()?;
+            let mut body = Vec::new();
+            for (n, v) in fields {
+                reflect_encode_field(nested.field(*n).ok_or(NO_SUCH_FIELD)?, v, &mut body, depth - 1)?;
+            }
+            encode_bytes(buf, &body);
+        }
+        _ => return Err(VALUE_MISMATCH),
+    }
+    Ok(())
+}
+
+/// `Reflect` for the runtime's well-known types.
+#[cfg(feature = "reflect")]
+macro_rules! reflect_well_known {
+    ($($name:ident { $($field:expr),* $(,)? })*) => {
+        $(
+            impl Reflect for $name {
+                fn descriptor() -> &'static MessageDescriptor {
+                    static DESCRIPTOR: MessageDescriptor = MessageDescriptor {
+                        full_name: concat!("google.protobuf.", stringify!($name)),
+                        fields: &[$($field),*],
+                    };
+                    &DESCRIPTOR
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "reflect")]
+reflect_well_known! {
+    Timestamp {
+        FieldDescriptor::new(1, "seconds", "seconds", FieldType::Int64, FieldLabel::Singular),
+        FieldDescriptor::new(2, "nanos", "nanos", FieldType::Int32, FieldLabel::Singular),
+    }
+    Duration {
+        FieldDescriptor::new(1, "seconds", "seconds", FieldType::Int64, FieldLabel::Singular),
+        FieldDescriptor::new(2, "nanos", "nanos", FieldType::Int32, FieldLabel::Singular),
+    }
+    Empty {}
+    Any {
+        FieldDescriptor::new(1, "type_url", "typeUrl", FieldType::String, FieldLabel::Singular),
+        FieldDescriptor::new(2, "value", "value", FieldType::Bytes, FieldLabel::Singular),
+    }
+    DoubleValue { FieldDescriptor::new(1, "value", "value", FieldType::Double, FieldLabel::Singular) }
+    FloatValue { FieldDescriptor::new(1, "value", "value", FieldType::Float, FieldLabel::Singular) }
+    Int64Value { FieldDescriptor::new(1, "value", "value", FieldType::Int64, FieldLabel::Singular) }
+    UInt64Value { FieldDescriptor::new(1, "value", "value", FieldType::Uint64, FieldLabel::Singular) }
+    Int32Value { FieldDescriptor::new(1, "value", "value", FieldType::Int32, FieldLabel::Singular) }
+    UInt32Value { FieldDescriptor::new(1, "value", "value", FieldType::Uint32, FieldLabel::Singular) }
+    BoolValue { FieldDescriptor::new(1, "value", "value", FieldType::Bool, FieldLabel::Singular) }
+    StringValue { FieldDescriptor::new(1, "value", "value", FieldType::String, FieldLabel::Singular) }
+    BytesValue { FieldDescriptor::new(1, "value", "value", FieldType::Bytes, FieldLabel::Singular) }
+}
+
+// ── Dynamic messages (feature = "reflect") ──────────────────────────
+//
+// Off-chain only: a `DescriptorPool` loads the message types of a
+// serialized `google.protobuf.FileDescriptorSet` at run time, and
+// `DynamicMessage` decodes bytes as any of them. A tool that cannot link
+// against a program's generated crate, such as an explorer rendering its
+// accounts, only needs the program's descriptor set.
+
+/// The message and enum types of a `FileDescriptorSet`, as written by
+/// `protoc --include_imports --descriptor_set_out=<file>`.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Debug, Default)]
+pub struct DescriptorPool {
+    messages: Vec<PoolMessage>,
+    enums: Vec<PoolEnum>,
+}
+
+/// A message type of a `DescriptorPool`; nested types are listed
+/// separately, under their full names.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Debug)]
+pub struct PoolMessage {
+    /// e.g. `"example.UserProfile"`
+    pub full_name: String,
+    /// Fields in declaration order
+    pub fields: Vec<PoolField>,
+    /// The entry type a `map<K, V>` field is encoded with
+    pub map_entry: bool,
+}
+
+/// One field of a `PoolMessage`.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Debug)]
+pub struct PoolField {
+    pub number: u32,
+    /// Name as declared in the .proto file
+    pub name: String,
+    /// lowerCamelCase name of the proto3 JSON mapping
+    pub json_name: String,
+    /// The field's type; for a map field, the type of its values
+    pub field_type: FieldType,
+    /// `Optional` for proto3 `optional` and for proto2 and editions
+    /// scalars, which have explicit presence.
+    pub label: FieldLabel,
+    /// The oneof the field is a member of
+    pub oneof: Option<String>,
+    /// Index in `DescriptorPool::messages` of a `Message` field's type
+    pub message: Option<usize>,
+    /// Index in `DescriptorPool::enums` of an `Enum` field's type
+    pub enum_type: Option<usize>,
+}
+
+/// An enum type of a `DescriptorPool`.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Debug)]
+pub struct PoolEnum {
+    pub full_name: String,
+    /// Values as declared, aliases included
+    pub values: Vec<(String, i32)>,
+}
+
+#[cfg(feature = "reflect")]
+const NO_SUCH_MESSAGE: DecodeError = DecodeError::InvalidData("no message type with that name");
+#[cfg(feature = "reflect")]
+const UNRESOLVED_TYPE: DecodeError =
+    DecodeError::InvalidData("field type not in the descriptor set (build it with --include_imports)");
+
+#[cfg(feature = "reflect")]
+impl DescriptorPool {
+    /// Load a serialized `FileDescriptorSet`. Every type a field refers
+    /// to must be in the set; group fields are rejected.
+    pub fn decode(file_descriptor_set: &[u8]) -> Result<Self, DecodeError> {
+        let mut messages = Vec::new();
+        let mut enums = Vec::new();
+        let mut set = Decoder::new(file_descriptor_set);
+        while let Some(file) = set.next_field()? {
+            if file.number() == 1 {
+                pool_parse_file(file.bytes()?, &mut messages, &mut enums)?;
+            }
+        }
+
+        let mut message_index = alloc::collections::BTreeMap::new();
+        for (i, message) in messages.iter().enumerate() {
+            if message_index.insert(message.full_name.clone(), i).is_some() {
+                return Err(DecodeError::InvalidData("message type defined twice in the descriptor set"));
+            }
+        }
+        let mut enum_index = alloc::collections::BTreeMap::new();
+        for (i, enum_type) in enums.iter().enumerate() {
+            if enum_index.insert(enum_type.full_name.clone(), i).is_some() {
+                return Err(DecodeError::InvalidData("enum type defined twice in the descriptor set"));
+            }
+        }
+        let resolve = |field: &RawField| -> Result<(FieldType, Option<usize>, Option<usize>), DecodeError> {
+            let field_type = pool_field_type(field.field_type)?;
+            let name = field.type_name.strip_prefix('.').unwrap_or(&field.type_name);
+            Ok(match field_type {
+                FieldType::Message => (field_type, Some(*message_index.get(name).ok_or(UNRESOLVED_TYPE)?), None),
+                FieldType::Enum => (field_type, None, Some(*enum_index.get(name).ok_or(UNRESOLVED_TYPE)?)),
+                _ => (field_type, None, None),
+            })
+        };
+
+        let mut pool = DescriptorPool { messages: Vec::with_capacity(messages.len()), enums };
+        for message in &messages {
+            let mut fields = Vec::with_capacity(message.fields.len());
+            for field in &message.fields {
+                let (mut field_type, mut message_type, mut enum_type) = resolve(field)?;
+                let entry = message_type.map(|i| &messages[i]).filter(|entry| entry.map_entry);
+                let label = match (field.label, entry) {
+                    (3, Some(entry)) => {
+                        let key = entry.fields.iter().find(|f| f.number == 1).ok_or(UNRESOLVED_TYPE)?;
+                        let value = entry.fields.iter().find(|f| f.number == 2).ok_or(UNRESOLVED_TYPE)?;
+                        (field_type, message_type, enum_type) = resolve(value)?;
+                        FieldLabel::Map(resolve(key)?.0)
+                    }
+                    (3, None) => FieldLabel::Repeated,
+                    _ if field.proto3_optional => FieldLabel::Optional,
+                    _ if !message.proto3 && field.oneof_index.is_none() && field_type != FieldType::Message => {
+                        FieldLabel::Optional
+                    }
+                    _ => FieldLabel::Singular,
+                };
+                let oneof = match field.oneof_index {
+                    Some(i) if !field.proto3_optional => {
+                        Some(message.oneofs.get(i).ok_or(DecodeError::InvalidData("oneof index out of range"))?.clone())
+                    }
+                    _ => None,
+                };
+                let json_name = match &field.json_name {
+                    Some(json_name) => json_name.clone(),
+                    None => pool_json_name(&field.name),
+                };
+                fields.push(PoolField {
+                    number: field.number,
+                    name: field.name.clone(),
+                    json_name,
+                    field_type,
+                    label,
+                    oneof,
+                    message: message_type,
+                    enum_type,
+                });
+            }
+            let full_name = message.full_name.clone();
+            pool.messages.push(PoolMessage { full_name, fields, map_entry: message.map_entry });
+        }
+        Ok(pool)
+    }
+
+    pub fn messages(&self) -> &[PoolMessage] {
+        &self.messages
+    }
+
+    pub fn enums(&self) -> &[PoolEnum] {
+        &self.enums
+    }
+
+    /// The message type `full_name`, e.g. `"example.UserProfile"`.
+    pub fn message(&self, full_name: &str) -> Option<&PoolMessage> {
+        self.messages.iter().find(|m| m.full_name == full_name)
+    }
+
+    pub fn enum_type(&self, full_name: &str) -> Option<&PoolEnum> {
+        self.enums.iter().find(|e| e.full_name == full_name)
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl PoolMessage {
+    pub fn field(&self, number: u32) -> Option<&PoolField> {
+        self.fields.iter().find(|f| f.number == number)
+    }
+
+    /// The field with this .proto or JSON name.
+    pub fn field_by_name(&self, name: &str) -> Option<&PoolField> {
+        self.fields.iter().find(|f| f.name == name || f.json_name == name)
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl PoolField {
+    /// Whether an absent value is `None` rather than a default.
+    pub fn has_presence(&self) -> bool {
+        self.label.has_presence(self.field_type, self.oneof.is_some())
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl PoolEnum {
+    /// The first name declared for `number`.
+    pub fn name_of(&self, number: i32) -> Option<&str> {
+        self.values.iter().find(|(_, n)| *n == number).map(|(name, _)| name.as_str())
+    }
+
+    pub fn number_of(&self, name: &str) -> Option<i32> {
+        self.values.iter().find(|(n, _)| n == name).map(|(_, number)| *number)
+    }
+}
+
+/// A `DescriptorProto` as read, before its field types are resolved.
+#[cfg(feature = "reflect")]
+struct RawMessage {
+    full_name: String,
+    fields: Vec<RawField>,
+    oneofs: Vec<String>,
+    map_entry: bool,
+    proto3: bool,
+}
+
+/// A `FieldDescriptorProto` as read.
+#[cfg(feature = "reflect")]
+#[derive(Default)]
+struct RawField {
+    name: String,
+    number: u32,
+    label: u64,
+    field_type: u64,
+    type_name: String,
+    json_name: Option<String>,
+    oneof_index: Option<usize>,
+    proto3_optional: bool,
+}
+
+/// Read a `FileDescriptorProto`'s message and enum types.
+#[cfg(feature = "reflect")]
+fn pool_parse_file(data: &[u8], messages: &mut Vec<RawMessage>, enums: &mut Vec<PoolEnum>) -> Result<(), DecodeError> {
+    let mut package = "";
+    let mut proto3 = false;
+    let mut fields = Decoder::new(data);
+    while let Some(field) = fields.next_field()? {
+        match field.number() {
+            2 => package = field.str()?,
+            12 => proto3 = field.str()? == "proto3",
+            _ => {}
+        }
+    }
+    let mut fields = Decoder::new(data);
+    while let Some(field) = fields.next_field()? {
+        match field.number() {
+            4 => {
+                let depth = DecodeOptions::DEFAULT.max_depth;
+                pool_parse_message(field.bytes()?, package, proto3, messages, enums, depth)?
+            }
+            5 => enums.push(pool_parse_enum(field.bytes()?, package)?),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// `scope.name`, or `name` at the top level of a file without a package.
+#[cfg(feature = "reflect")]
+fn pool_full_name(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.into()
+    } else {
+        format!("{scope}.{name}")
+    }
+}
+
+/// Read a `DescriptorProto` and, after it, its nested types.
+#[cfg(feature = "reflect")]
+fn pool_parse_message(
+    data: &[u8],
+    scope: &str,
+    proto3: bool,
+    messages: &mut Vec<RawMessage>,
+    enums: &mut Vec<PoolEnum>,
+    depth: usize,
+) -> Result<(), DecodeError> {
+    if depth == 0 {
+        return Err(DecodeError::RecursionLimitExceeded);
+    }
+    let mut message =
+        RawMessage { full_name: String::new(), fields: Vec::new(), oneofs: Vec::new(), map_entry: false, proto3 };
+    let mut fields = Decoder::new(data);
+    while let Some(field) = fields.next_field()? {
+        match field.number() {
+            1 => message.full_name = pool_full_name(scope, field.str()?),
+            2 => message.fields.push(pool_parse_field(field.bytes()?)?),
+            7 => {
+                let mut options = field.decoder()?;
+                while let Some(option) = options.next_field()? {
+                    if option.number() == 7 {
+                        message.map_entry = option.varint()? != 0;
+                    }
+                }
+            }
+            8 => {
+                let mut oneof = field.decoder()?;
+                let mut name = "";
+                while let Some(part) = oneof.next_field()? {
+                    if part.number() == 1 {
+                        name = part.str()?;
+                    }
+                }
+                message.oneofs.push(name.into());
+            }
+            _ => {}
+        }
+    }
+    let scope = message.full_name.clone();
+    messages.push(message);
+    let mut fields = Decoder::new(data);
+    while let Some(field) = fields.next_field()? {
+        match field.number() {
+            3 => pool_parse_message(field.bytes()?, &scope, proto3, messages, enums, depth - 1)?,
+            4 => enums.push(pool_parse_enum(field.bytes()?, &scope)?),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "reflect")]
+fn pool_parse_field(data: &[u8]) -> Result<RawField, DecodeError> {
+    let mut raw = RawField::default();
+    let mut fields = Decoder::new(data);
+    while let Some(field) = fields.next_field()? {
+        match field.number() {
+            1 => raw.name = field.str()?.into(),
+            3 => raw.number = field.varint()? as u32,
+            4 => raw.label = field.varint()?,
+            5 => raw.field_type = field.varint()?,
+            6 => raw.type_name = field.str()?.into(),
+            9 => raw.oneof_index = Some(field.varint()? as usize),
+            10 => raw.json_name = Some(field.str()?.into()),
+            17 => raw.proto3_optional = field.varint()? != 0,
+            _ => {}
+        }
+    }
+    Ok(raw)
+}
+
+#[cfg(feature = "reflect")]
+fn pool_parse_enum(data: &[u8], scope: &str) -> Result<PoolEnum, DecodeError> {
+    let mut enum_type = PoolEnum { full_name: String::new(), values: Vec::new() };
+    let mut fields = Decoder::new(data);
+    while let Some(field) = fields.next_field()? {
+        match field.number() {
+            1 => enum_type.full_name = pool_full_name(scope, field.str()?),
+            2 => {
+                let mut value = field.decoder()?;
+                let (mut name, mut number) = ("", 0);
+                while let Some(part) = value.next_field()? {
+                    match part.number() {
+                        1 => name = part.str()?,
+                        2 => number = part.varint()? as i32,
+                        _ => {}
+                    }
+                }
+                enum_type.values.push((name.into(), number));
+            }
+            _ => {}
+        }
+    }
+    Ok(enum_type)
+}
+
+/// A `FieldDescriptorProto.Type` number as a `FieldType`.
+#[cfg(feature = "reflect")]
+fn pool_field_type(number: u64) -> Result<FieldType, DecodeError> {
+    Ok(match number {
+        1 => FieldType::Double,
+        2 => FieldType::Float,
+        3 => FieldType::Int64,
+        4 => FieldType::Uint64,
+        5 => FieldType::Int32,
+        6 => FieldType::Fixed64,
+        7 => FieldType::Fixed32,
+        8 => FieldType::Bool,
+        9 => FieldType::String,
+        10 => return Err(DecodeError::InvalidData("group fields are not supported")),
+        11 => FieldType::Message,
+        12 => FieldType::Bytes,
+        13 => FieldType::Uint32,
+        14 => FieldType::Enum,
+        15 => FieldType::Sfixed32,
+        16 => FieldType::Sfixed64,
+        17 => FieldType::Sint32,
+        18 => FieldType::Sint64,
+        _ => return Err(DecodeError::InvalidData("unknown field type")),
+    })
+}
+
+/// protoc's JSON name for a field whose descriptor has none.
+#[cfg(feature = "reflect")]
+fn pool_json_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// A message type of a pool, for `reflect_decode`.
+#[cfg(feature = "reflect")]
+#[derive(Clone, Copy)]
+struct PoolRef<'p> {
+    pool: &'p DescriptorPool,
+    message: &'p PoolMessage,
+}
+
+#[cfg(feature = "reflect")]
+impl<'p> FieldLookup<'p> for PoolRef<'p> {
+    fn lookup(self, number: u32) -> Option<FieldInfo<'p, Self>> {
+        let field = self.message.field(number)?;
+        Some(FieldInfo {
+            field_type: field.field_type,
+            label: field.label,
+            oneof: field.oneof.as_deref(),
+            message: field.message.map(|i| PoolRef { pool: self.pool, message: &self.pool.messages[i] }),
+        })
+    }
+}
+
+/// A message decoded with a `DescriptorPool` type. Every field without
+/// presence has a value, its default if absent from the input; other
+/// fields only if present. Getters return `None` for a field the type
+/// does not have, an absent field, or a value of another type.
+#[cfg(feature = "reflect")]
+#[derive(Clone)]
+pub struct DynamicMessage<'p> {
+    message: PoolRef<'p>,
+    /// Values by field number, in number order
+    values: Vec<(u32, Value)>,
+}
+
+#[cfg(feature = "reflect")]
+impl<'p> DynamicMessage<'p> {
+    /// Decode `data` as the pool's message type `full_name`. Fields the
+    /// type does not have are skipped.
+    pub fn decode(pool: &'p DescriptorPool, full_name: &str, data: &[u8]) -> Result<Self, DecodeError> {
+        let message = PoolRef { pool, message: pool.message(full_name).ok_or(NO_SUCH_MESSAGE)? };
+        let values = reflect_decode(message, data, None, DecodeOptions::DEFAULT.max_depth)?;
+        Ok(DynamicMessage::new(message, values))
+    }
+
+    /// `values` of `message` with the absent fields without presence
+    /// filled in.
+    fn new(message: PoolRef<'p>, mut values: Vec<(u32, Value)>) -> Self {
+        for field in &message.message.fields {
+            if field.has_presence() || values.iter().any(|(n, _)| *n == field.number) {
+                continue;
+            }
+            let value = match field.label {
+                FieldLabel::Repeated => Value::List(Vec::new()),
+                FieldLabel::Map(_) => Value::Map(Vec::new()),
+                _ => field.field_type.default_value(),
+            };
+            values.push((field.number, value));
+        }
+        values.sort_by_key(|(number, _)| *number);
+        DynamicMessage { message, values }
+    }
+
+    pub fn descriptor(&self) -> &'p PoolMessage {
+        self.message.message
+    }
+
+    /// The fields with a value, in declaration order.
+    pub fn fields(&self) -> impl Iterator<Item = (&'p PoolField, &Value)> + '_ {
+        self.message.message.fields.iter().filter_map(move |field| Some((field, self.get_by_number(field.number)?)))
+    }
+
+    pub fn get_by_number(&self, number: u32) -> Option<&Value> {
+        self.values.iter().find(|(n, _)| *n == number).map(|(_, value)| value)
+    }
+
+    /// The value of the field with this .proto or JSON name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.get_by_number(self.message.message.field_by_name(name)?.number)
+    }
+
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        self.get(name)?.as_bool()
+    }
+
+    pub fn get_i32(&self, name: &str) -> Option<i32> {
+        self.get(name)?.as_i32()
+    }
+
+    pub fn get_i64(&self, name: &str) -> Option<i64> {
+        self.get(name)?.as_i64()
+    }
+
+    pub fn get_u32(&self, name: &str) -> Option<u32> {
+        self.get(name)?.as_u32()
+    }
+
+    pub fn get_u64(&self, name: &str) -> Option<u64> {
+        self.get(name)?.as_u64()
+    }
+
+    pub fn get_f32(&self, name: &str) -> Option<f32> {
+        self.get(name)?.as_f32()
+    }
+
+    pub fn get_f64(&self, name: &str) -> Option<f64> {
+        self.get(name)?.as_f64()
+    }
+
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.get(name)?.as_str()
+    }
+
+    pub fn get_bytes(&self, name: &str) -> Option<&[u8]> {
+        self.get(name)?.as_bytes()
+    }
+
+    /// An enum field's number, declared or not.
+    pub fn get_enum(&self, name: &str) -> Option<i32> {
+        self.get(name)?.as_enum()
+    }
+
+    /// The declared name of an enum field's value.
+    pub fn get_enum_name(&self, name: &str) -> Option<&'p str> {
+        let field = self.message.message.field_by_name(name)?;
+        let number = self.get_by_number(field.number)?.as_enum()?;
+        self.message.pool.enums[field.enum_type?].name_of(number)
+    }
+
+    pub fn get_list(&self, name: &str) -> Option<&[Value]> {
+        self.get(name)?.as_list()
+    }
+
+    pub fn get_map(&self, name: &str) -> Option<&[(Value, Value)]> {
+        self.get(name)?.as_map()
+    }
+
+    /// A singular sub-message field.
+    pub fn get_message(&self, name: &str) -> Option<DynamicMessage<'p>> {
+        let field = self.message.message.field_by_name(name)?;
+        let fields = self.get_by_number(field.number)?.as_message()?;
+        Some(DynamicMessage::new(self.sub_message(field)?, fields.to_vec()))
+    }
+
+    /// The elements of a repeated sub-message field.
+    pub fn get_messages(&self, name: &str) -> Option<Vec<DynamicMessage<'p>>> {
+        let field = self.message.message.field_by_name(name)?;
+        let message = self.sub_message(field)?;
+        self.get_by_number(field.number)?
+            .as_list()?
+            .iter()
+            .map(|item| Some(DynamicMessage::new(message, item.as_message()?.to_vec())))
+            .collect()
+    }
+
+    fn sub_message(&self, field: &PoolField) -> Option<PoolRef<'p>> {
+        let pool = self.message.pool;
+        Some(PoolRef { pool, message: &pool.messages[field.message?] })
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl fmt::Debug for DynamicMessage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = f.debug_struct(&self.message.message.full_name);
+        for (field, value) in self.fields() {
+            out.field(&field.name, value);
+        }
+        out.finish()
+    }
+}
+
+// ── base64 (RFC 4648) ────────────────────────────────────────────────
+
+const B64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        out.push(B64[(n >> 18) as usize & 63] as char);
+        out.push(B64[(n >> 12) as usize & 63] as char);
+        out.push(if chunk.len() > 1 { B64[(n >> 6) as usize & 63] as char } else { '=' });
+        out.push(if chunk.len() > 2 { B64[n as usize & 63] as char } else { '=' });
+    }
+    out
+}
+
+/// Accepts standard and URL-safe alphabets, with or without padding.
+pub fn base64_decode(text: &str) -> Result<Vec<u8>, DecodeError> {
+    let mut out = Vec::with_capacity(text.len() / 4 * 3);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for c in text.trim_end_matches('=').bytes() {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return Err(DecodeError::InvalidData("invalid base64")),
+        };
+        acc = (acc << 6) | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+// ── serde (feature = "serde") ────────────────────────────────────────
+//
+// Off-chain only: the generated `serde` option derives `Serialize` and
+// `Deserialize` behind this feature and routes bytes members through
+// these adapters, so they read as base64 strings rather than arrays of
+// numbers, as in the proto3 JSON mapping.
+
+/// A sequence, as serde writes a `Vec`.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for BoundedVec<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// A sequence of at most `N` elements.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for BoundedVec<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let items = Vec::<T>::deserialize(deserializer)?;
+        Self::try_from(items).map_err(|_| serde::de::Error::custom("more elements than the field's max_count"))
+    }
+}
+
+/// `#[serde(with = "serde_base64")]` adapters for `Vec<u8>`, `[u8; N]` and
+/// `Pubkey` members, plus `option`, `repeated`, `bounded` and `map` for
+/// `Option`, `Vec`, `BoundedVec` and map-value members holding them.
+#[cfg(feature = "serde")]
+pub mod serde_base64 {
+    use super::alloc::collections::BTreeMap;
+    use super::{base64_decode, base64_encode, String, Vec};
+    use serde::de::{Deserialize, Deserializer, Error as _};
+    use serde::ser::{Serialize, Serializer};
+
+    /// A bytes-like value that round-trips through its raw bytes.
+    pub trait Bytes: Sized {
+        fn as_bytes(&self) -> &[u8];
+        /// `None` if `raw` has the wrong length for `Self`.
+        fn from_vec(raw: Vec<u8>) -> Option<Self>;
+    }
+
+    impl Bytes for Vec<u8> {
+        fn as_bytes(&self) -> &[u8] {
+            self
+        }
+
+        fn from_vec(raw: Vec<u8>) -> Option<Self> {
+            Some(raw)
+        }
+    }
+
+    impl<const N: usize> Bytes for [u8; N] {
+        fn as_bytes(&self) -> &[u8] {
+            self
+        }
+
+        fn from_vec(raw: Vec<u8>) -> Option<Self> {
+            raw.try_into().ok()
+        }
+    }
+
+    // Without `solana-program`, `Pubkey` is `[u8; 32]` and covered above
+    #[cfg(feature = "solana-program")]
+    impl Bytes for super::Pubkey {
+        fn as_bytes(&self) -> &[u8] {
+            self.as_ref()
+        }
+
+        fn from_vec(raw: Vec<u8>) -> Option<Self> {
+            raw.try_into().ok().map(super::pubkey_from_array)
+        }
+    }
+
+    /// Borrowed value serialized as base64.
+    struct Encoded<'a, T>(&'a T);
+
+    impl<T: Bytes> Serialize for Encoded<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&base64_encode(self.0.as_bytes()))
+        }
+    }
+
+    /// Value deserialized from base64.
+    struct Decoded<T>(T);
+
+    impl<'de, T: Bytes> Deserialize<'de> for Decoded<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let text = String::deserialize(deserializer)?;
+            let raw = base64_decode(&text).map_err(D::Error::custom)?;
+            T::from_vec(raw).map(Decoded).ok_or_else(|| D::Error::custom("bytes field has the wrong length"))
+        }
+    }
+
+    pub fn serialize<T: Bytes, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Encoded(value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Bytes, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Decoded::deserialize(deserializer).map(|d| d.0)
+    }
+
+    /// `Option<T>`: `None` is `null`.
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<T: Bytes, S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+            value.as_ref().map(Encoded).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, T: Bytes, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+            Ok(Option::<Decoded<T>>::deserialize(deserializer)?.map(|d| d.0))
+        }
+    }
+
+    /// `Vec<T>`: an array of base64 strings.
+    pub mod repeated {
+        use super::*;
+
+        pub fn serialize<T: Bytes, S: Serializer>(value: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(value.iter().map(Encoded))
+        }
+
+        pub fn deserialize<'de, T: Bytes, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+            Ok(Vec::<Decoded<T>>::deserialize(deserializer)?.into_iter().map(|d| d.0).collect())
+        }
+    }
+
+    /// `BoundedVec<T, N>`: an array of at most `N` base64 strings.
+    pub mod bounded {
+        use super::super::BoundedVec;
+        use super::*;
+
+        pub use super::repeated::serialize;
+
+        pub fn deserialize<'de, T: Bytes, D: Deserializer<'de>, const N: usize>(
+            deserializer: D,
+        ) -> Result<BoundedVec<T, N>, D::Error> {
+            let items = super::repeated::deserialize(deserializer)?;
+            BoundedVec::try_from(items).map_err(|_| D::Error::custom("more elements than the field's max_count"))
+        }
+    }
+
+    /// `BTreeMap<K, T>`: keys as serde writes them, values as base64.
+    pub mod map {
+        use super::*;
+
+        pub fn serialize<K, T, S>(value: &BTreeMap<K, T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            K: Serialize,
+            T: Bytes,
+            S: Serializer,
+        {
+            serializer.collect_map(value.iter().map(|(k, v)| (k, Encoded(v))))
+        }
+
+        pub fn deserialize<'de, K, T, D>(deserializer: D) -> Result<BTreeMap<K, T>, D::Error>
+        where
+            K: Deserialize<'de> + Ord,
+            T: Bytes,
+            D: Deserializer<'de>,
+        {
+            let encoded = BTreeMap::<K, Decoded<T>>::deserialize(deserializer)?;
+            Ok(encoded.into_iter().map(|(k, v)| (k, v.0)).collect())
+        }
+    }
+}
+
+// ── Proto3 JSON (feature = "json") ───────────────────────────────────
+//
+// Off-chain only: a small dependency-free JSON writer/parser used by the
+// generated `to_json` / `from_json` methods. Follows the proto3 JSON
+// mapping: 64-bit integers as strings, bytes as base64, non-finite
+// floats as "NaN" / "Infinity" / "-Infinity".
+
+#[cfg(feature = "json")]
+pub use json::*;
+
+#[cfg(feature = "json")]
+mod json {
+    use super::{base64_decode, base64_encode, format, pubkey_from_slice, DecodeError, Pubkey, String, ToString, Vec};
+
+    const INVALID_JSON: DecodeError = DecodeError::InvalidData("invalid JSON");
+    const MAX_JSON_DEPTH: usize = 64;
+
+    /// Streaming JSON writer with automatic comma placement.
+    #[derive(Default)]
+    pub struct JsonWriter {
+        out: String,
+        /// One entry per open container: true until its first element
+        first: Vec<bool>,
+        after_key: bool,
+    }
+
+    impl JsonWriter {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn finish(self) -> String {
+            self.out
+        }
+
+        fn sep(&mut self) {
+            if self.after_key {
+                self.after_key = false;
+                return;
+            }
+            if let Some(first) = self.first.last_mut() {
+                if !*first {
+                    self.out.push(',');
+                }
+                *first = false;
+            }
+        }
+
+        pub fn begin_object(&mut self) {
+            self.sep();
+            self.out.push('{');
+            self.first.push(true);
+        }
+
+        pub fn end_object(&mut self) {
+            self.first.pop();
+            self.out.push('}');
+        }
+
+        pub fn begin_array(&mut self) {
+            self.sep();
+            self.out.push('[');
+            self.first.push(true);
+        }
+
+        pub fn end_array(&mut self) {
+            self.first.pop();
+            self.out.push(']');
+        }
+
+        pub fn key(&mut self, name: &str) {
+            self.sep();
+            push_json_string(&mut self.out, name);
+            self.out.push(':');
+            self.after_key = true;
+        }
+
+        pub fn write_bool(&mut self, value: bool) {
+            self.sep();
+            self.out.push_str(if value { "true" } else { "false" });
+        }
+
+        pub fn write_i32(&mut self, value: i32) {
+            self.sep();
+            self.out.push_str(&value.to_string());
+        }
+
+        pub fn write_u32(&mut self, value: u32) {
+            self.sep();
+            self.out.push_str(&value.to_string());
+        }
+
+        /// 64-bit integers are JSON strings to survive f64 parsers.
+        pub fn write_i64(&mut self, value: i64) {
+            self.sep();
+            self.out.push('"');
+            self.out.push_str(&value.to_string());
+            self.out.push('"');
+        }
+
+        pub fn write_u64(&mut self, value: u64) {
+            self.sep();
+            self.out.push('"');
+            self.out.push_str(&value.to_string());
+            self.out.push('"');
+        }
+
+        pub fn write_f64(&mut self, value: f64) {
+            self.sep();
+            if value.is_nan() {
+                self.out.push_str("\"NaN\"");
+            } else if value.is_infinite() {
+                self.out.push_str(if value > 0.0 { "\"Infinity\"" } else { "\"-Infinity\"" });
+            } else {
+                self.out.push_str(&value.to_string());
+            }
+        }
+
+        pub fn write_f32(&mut self, value: f32) {
+            self.sep();
+            if value.is_nan() {
+                self.out.push_str("\"NaN\"");
+            } else if value.is_infinite() {
+                self.out.push_str(if value > 0.0 { "\"Infinity\"" } else { "\"-Infinity\"" });
+            } else {
+                self.out.push_str(&value.to_string());
+            }
+        }
+
+        pub fn write_str(&mut self, value: &str) {
+            self.sep();
+            push_json_string(&mut self.out, value);
+        }
+
+        pub fn write_bytes(&mut self, value: &[u8]) {
+            self.sep();
+            self.out.push('"');
+            self.out.push_str(&base64_encode(value));
+            self.out.push('"');
+        }
+
+        /// Pubkeys are bytes fields, so base64 like any other.
+        pub fn write_pubkey(&mut self, value: Pubkey) {
+            self.write_bytes(value.as_ref());
+        }
+
+        /// Enums are written by name; values missing from `names` fall
+        /// back to their number.
+        pub fn write_enum(&mut self, value: i32, names: &[(&str, i32)]) {
+            match names.iter().find(|(_, n)| *n == value) {
+                Some((name, _)) => self.write_str(name),
+                None => self.write_i32(value),
+            }
+        }
+    }
+
+    fn push_json_string(out: &mut String, value: &str) {
+        out.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+
+    // ── Parser ──
+
+    /// Parsed JSON value. Numbers keep their source text so 64-bit
+    /// integers don't lose precision.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum JsonValue {
+        Null,
+        Bool(bool),
+        Number(String),
+        String(String),
+        Array(Vec<JsonValue>),
+        Object(Vec<(String, JsonValue)>),
+    }
+
+    impl JsonValue {
+        pub fn is_null(&self) -> bool {
+            matches!(self, JsonValue::Null)
+        }
+    }
+
+    pub fn parse_json(text: &str) -> Result<JsonValue, DecodeError> {
+        let bytes = text.as_bytes();
+        let (value, pos) = parse_value(bytes, skip_ws(bytes, 0), 0)?;
+        if skip_ws(bytes, pos) != bytes.len() {
+            return Err(INVALID_JSON);
+        }
+        Ok(value)
+    }
+
+    fn skip_ws(b: &[u8], mut pos: usize) -> usize {
+        while pos < b.len() && matches!(b[pos], b' ' | b'\t' | b'\n' | b'\r') {
+            pos += 1;
+        }
+        pos
+    }
+
+    fn parse_value(b: &[u8], pos: usize, depth: usize) -> Result<(JsonValue, usize), DecodeError> {
+        if depth > MAX_JSON_DEPTH {
+            return Err(DecodeError::InvalidData("JSON nested too deeply"));
+        }
+        match b.get(pos) {
+            Some(b'{') => {
+                let mut fields = Vec::new();
+                let mut pos = skip_ws(b, pos + 1);
+                if b.get(pos) == Some(&b'}') {
+                    return Ok((JsonValue::Object(fields), pos + 1));
+                }
+                loop {
+                    let (key, p) = parse_string(b, pos)?;
+                    pos = skip_ws(b, p);
+                    if b.get(pos) != Some(&b':') {
+                        return Err(INVALID_JSON);
+                    }
+                    let (value, p) = parse_value(b, skip_ws(b, pos + 1), depth + 1)?;
+                    fields.push((key, value));
+                    pos = skip_ws(b, p);
+                    match b.get(pos) {
+                        Some(b',') => pos = skip_ws(b, pos + 1),
+                        Some(b'}') => return Ok((JsonValue::Object(fields), pos + 1)),
+                        _ => return Err(INVALID_JSON),
+                    }
+                }
+            }
+            Some(b'[') => {
+                let mut items = Vec::new();
+                let mut pos = skip_ws(b, pos + 1);
+                if b.get(pos) == Some(&b']') {
+                    return Ok((JsonValue::Array(items), pos + 1));
+                }
+                loop {
+                    let (value, p) = parse_value(b, pos, depth + 1)?;
+                    items.push(value);
+                    pos = skip_ws(b, p);
+                    match b.get(pos) {
+                        Some(b',') => pos = skip_ws(b, pos + 1),
+                        Some(b']') => return Ok((JsonValue::Array(items), pos + 1)),
+                        _ => return Err(INVALID_JSON),
+                    }
+                }
+            }
+            Some(b'"') => {
+                let (s, p) = parse_string(b, pos)?;
+                Ok((JsonValue::String(s), p))
+            }
+            Some(b't') if b[pos..].starts_with(b"true") => Ok((JsonValue::Bool(true), pos + 4)),
+            Some(b'f') if b[pos..].starts_with(b"false") => Ok((JsonValue::Bool(false), pos + 5)),
+            Some(b'n') if b[pos..].starts_with(b"null") => Ok((JsonValue::Null, pos + 4)),
+            Some(c) if *c == b'-' || c.is_ascii_digit() => {
+                let mut end = pos + 1;
+                while end < b.len() && matches!(b[end], b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-') {
+                    end += 1;
+                }
+                let text = core::str::from_utf8(&b[pos..end]).map_err(|_| INVALID_JSON)?;
+                Ok((JsonValue::Number(text.to_string()), end))
+            }
+            _ => Err(INVALID_JSON),
+        }
+    }
+
+    fn parse_string(b: &[u8], pos: usize) -> Result<(String, usize), DecodeError> {
+        if b.get(pos) != Some(&b'"') {
+            return Err(INVALID_JSON);
+        }
+        let mut out = Vec::new();
+        let mut pos = pos + 1;
+        loop {
+            match b.get(pos) {
+                None => return Err(INVALID_JSON),
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    let esc = *b.get(pos + 1).ok_or(INVALID_JSON)?;
+                    pos += 2;
+                    let c = match esc {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let hi = parse_hex4(b, pos)?;
+                            pos += 4;
+                            let code = if (0xD800..0xDC00).contains(&hi) {
+                                if !b[pos..].starts_with(b"\\u") {
+                                    return Err(INVALID_JSON);
+                                }
+                                let lo = parse_hex4(b, pos + 2)?;
+                                pos += 6;
+                                0x10000 + ((hi - 0xD800) << 10) + (lo.wrapping_sub(0xDC00) & 0x3FF)
+                            } else {
+                                hi
+                            };
+                            char::from_u32(code).ok_or(INVALID_JSON)?
+                        }
+                        _ => return Err(INVALID_JSON),
+                    };
+                    let mut tmp = [0u8; 4];
+                    out.extend_from_slice(c.encode_utf8(&mut tmp).as_bytes());
+                }
+                Some(&c) => {
+                    out.push(c);
+                    pos += 1;
+                }
+            }
+        }
+        let s = String::from_utf8(out).map_err(|_| INVALID_JSON)?;
+        Ok((s, pos + 1))
+    }
+
+    fn parse_hex4(b: &[u8], pos: usize) -> Result<u32, DecodeError> {
+        let digits = b.get(pos..pos + 4).ok_or(INVALID_JSON)?;
+        let text = core::str::from_utf8(digits).map_err(|_| INVALID_JSON)?;
+        u32::from_str_radix(text, 16).map_err(|_| INVALID_JSON)
+    }
+
+    // ── Value conversions used by generated from_json ──
+
+    pub fn json_object(value: &JsonValue) -> Result<&[(String, JsonValue)], DecodeError> {
+        match value {
+            JsonValue::Object(fields) => Ok(fields),
+            _ => Err(DecodeError::InvalidData("expected JSON object")),
+        }
+    }
+
+    pub fn json_array(value: &JsonValue) -> Result<&[JsonValue], DecodeError> {
+        match value {
+            JsonValue::Array(items) => Ok(items),
+            _ => Err(DecodeError::InvalidData("expected JSON array")),
+        }
+    }
+
+    /// Integers may be given as JSON numbers or strings, and in exponent
+    /// or fraction form (`1e3`, `"7.0"`) as long as the value is integral.
+    fn json_to_int<T: TryFrom<i128>>(value: &JsonValue) -> Result<T, DecodeError> {
+        const INVALID_INT: DecodeError = DecodeError::InvalidData("invalid JSON integer");
+        let text = match value {
+            JsonValue::Number(s) | JsonValue::String(s) => s,
+            _ => return Err(DecodeError::InvalidData("expected JSON integer")),
+        };
+        let n = match text.parse::<i128>() {
+            Ok(n) => n,
+            // 2^64 bounds every target type and is exact in an f64
+            Err(_) => match text.parse::<f64>() {
+                Ok(v) if v.fract() == 0.0 && v.abs() <= 18_446_744_073_709_551_616.0 => v as i128,
+                _ => return Err(INVALID_INT),
+            },
+        };
+        T::try_from(n).map_err(|_| INVALID_INT)
+    }
+
+    pub fn json_to_i64(value: &JsonValue) -> Result<i64, DecodeError> {
+        json_to_int(value)
+    }
+
+    pub fn json_to_u64(value: &JsonValue) -> Result<u64, DecodeError> {
+        json_to_int(value)
+    }
+
+    pub fn json_to_i32(value: &JsonValue) -> Result<i32, DecodeError> {
+        json_to_int(value)
+    }
+
+    pub fn json_to_u32(value: &JsonValue) -> Result<u32, DecodeError> {
+        json_to_int(value)
+    }
+
+    pub fn json_to_f64(value: &JsonValue) -> Result<f64, DecodeError> {
+        match value {
+            JsonValue::String(s) if s == "NaN" => Ok(f64::NAN),
+            JsonValue::String(s) if s == "Infinity" => Ok(f64::INFINITY),
+            JsonValue::String(s) if s == "-Infinity" => Ok(f64::NEG_INFINITY),
+            JsonValue::Number(s) | JsonValue::String(s) => s
+                .parse()
+                .map_err(|_| DecodeError::InvalidData("invalid JSON number")),
+            _ => Err(DecodeError::InvalidData("expected JSON number")),
+        }
+    }
+
+    /// A finite value beyond `f32::MAX` is rejected rather than rounded
+    /// to infinity.
+    pub fn json_to_f32(value: &JsonValue) -> Result<f32, DecodeError> {
+        let v = json_to_f64(value)?;
+        if v.is_finite() && v.abs() > f32::MAX as f64 {
+            return Err(DecodeError::InvalidData("JSON number out of float range"));
+        }
+        Ok(v as f32)
+    }
+
+    pub fn json_to_bool(value: &JsonValue) -> Result<bool, DecodeError> {
+        match value {
+            JsonValue::Bool(v) => Ok(*v),
+            _ => Err(DecodeError::InvalidData("expected JSON bool")),
+        }
+    }
+
+    pub fn json_to_string(value: &JsonValue) -> Result<String, DecodeError> {
+        match value {
+            JsonValue::String(s) => Ok(s.clone()),
+            _ => Err(DecodeError::InvalidData("expected JSON string")),
+        }
+    }
+
+    /// Parse a JSON object key into a map key (string, integer or bool).
+    pub fn json_map_key<T: core::str::FromStr>(key: &str) -> Result<T, DecodeError> {
+        key.parse().map_err(|_| DecodeError::InvalidData("invalid JSON map key"))
+    }
+
+    /// Accepts an enum value name from `names` or its integer value.
+    pub fn json_to_enum(value: &JsonValue, names: &[(&str, i32)]) -> Result<i32, DecodeError> {
+        match value {
+            JsonValue::String(s) => names
+                .iter()
+                .find(|(name, _)| name == s)
+                .map(|(_, n)| *n)
+                .ok_or(DecodeError::InvalidData("unknown enum value name")),
+            _ => json_to_i32(value),
+        }
+    }
+
+    pub fn json_to_bytes(value: &JsonValue) -> Result<Vec<u8>, DecodeError> {
+        match value {
+            JsonValue::String(s) => base64_decode(s),
+            _ => Err(DecodeError::InvalidData("expected base64 JSON string")),
+        }
+    }
+
+    pub fn json_to_pubkey(value: &JsonValue) -> Result<Pubkey, DecodeError> {
+        pubkey_from_slice(&json_to_bytes(value)?)
+    }
+
+    pub fn json_to_fixed_bytes<const N: usize>(value: &JsonValue) -> Result<[u8; N], DecodeError> {
+        json_to_bytes(value)?
+            .try_into()
+            .map_err(|_| DecodeError::InvalidData("bytes field has the wrong length"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_classification() {
+        use DecodeErrorKind::*;
+        let cases = [
+            (DecodeError::BufferOverflow, Truncated, false),
+            (DecodeError::InvalidVarint, Malformed, false),
+            (DecodeError::UnknownWireType(6), Unsupported, true),
+            (DecodeError::InvalidData("bad"), Malformed, false),
+            (DecodeError::BufferTooSmall, Truncated, true),
+            (DecodeError::SizeLimitExceeded, Unsupported, false),
+            (DecodeError::WireTypeMismatch { expected: 0, found: 2 }, Malformed, false),
+            (DecodeError::LengthOverflow, Malformed, false),
+            (DecodeError::LimitExceeded { field: 3 }, Unsupported, false),
+            (DecodeError::MissingField { field: 1 }, Malformed, false),
+            (DecodeError::UnknownVersion { version: 3 }, Unsupported, false),
+            (DecodeError::InvalidFieldNumber(0), Malformed, false),
+            (
+                DecodeError::FieldWireTypeMismatch { name: "Vault.balance", field: 2, expected: 0, found: 2, offset: 9 },
+                Malformed,
+                false,
+            ),
+        ];
+        for (err, kind, recoverable) in cases {
+            assert_eq!(err.kind(), kind, "{err}");
+            assert_eq!(err.is_recoverable(), recoverable, "{err}");
+        }
+
+        // As surfaced by the decoders themselves
+        assert_eq!(decode_varint(&[0x80], 0).unwrap_err().kind(), Truncated);
+        assert!(skip_field(&[0x00], 0, 7).unwrap_err().is_recoverable());
+        assert!(!decode_string(&[0x01, 0xff], 0).unwrap_err().is_recoverable());
+    }
+
+    #[test]
+    fn test_varint_roundtrip() {
+        for &val in &[0u64, 1, 127, 128, 255, 300, 16384, u64::MAX] {
+            let mut buf = Vec::new();
+            encode_varint(&mut buf, val);
+            let (decoded, pos) = decode_varint(&buf, 0).unwrap();
+            assert_eq!(decoded, val);
+            assert_eq!(pos, buf.len());
+        }
+    }
+
+    #[test]
+    fn test_varint_fast_path_matches_loop() {
+        // DecodeError has no PartialEq, so outcomes compare by Debug text
+        let both = |data: &[u8], pos| {
+            (format!("{:?}", decode_varint(data, pos)), format!("{:?}", decode_varint_slow(data, pos)))
+        };
+
+        // Every encoded width, with the varint both well inside the buffer
+        // (the u64 load) and flush against its end (the fallback loop)
+        let mut values = vec![0u64, u64::MAX];
+        for bits in 1..64 {
+            values.extend([(1u64 << bits) - 1, 1u64 << bits, (1u64 << bits) | 0x55]);
+        }
+        for val in values {
+            let mut buf = vec![0xAA];
+            encode_varint(&mut buf, val);
+            let end = buf.len();
+            for padding in 0..10 {
+                buf.truncate(end);
+                buf.resize(end + padding, 0xFF);
+                assert_eq!(decode_varint(&buf, 1).unwrap(), (val, end), "{val:#x} + {padding}");
+                let (fast, slow) = both(&buf, 1);
+                assert_eq!(fast, slow);
+            }
+        }
+
+        // Malformed input fails the same way on both paths
+        let unterminated = [0x80u8; 16];
+        for len in 0..unterminated.len() {
+            let (fast, slow) = both(&unterminated[..len], 0);
+            assert_eq!(fast, slow, "{len} bytes");
+        }
+        assert!(matches!(decode_varint(&unterminated, 0), Err(DecodeError::InvalidVarint)));
+        assert!(matches!(decode_varint(&[0x80; 9], 0), Err(DecodeError::BufferOverflow)));
+        assert!(matches!(decode_varint(&[0x01], 1), Err(DecodeError::BufferOverflow)));
+        // Bits past the 64th are dropped, as the loop always did
+        let overlong = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0, 0];
+        assert_eq!(decode_varint(&overlong, 0).unwrap(), (u64::MAX, 10));
+    }
+
+    #[test]
+    fn test_bool_roundtrip() {
+        for &val in &[true, false] {
+            let mut buf = Vec::new();
+            encode_bool(&mut buf, val);
+            let (decoded, _) = decode_bool(&buf, 0).unwrap();
+            assert_eq!(decoded, val);
+        }
+    }
+
+    #[test]
+    fn test_int32_int64_match_prost() {
+        // Byte strings prost writes for the same values
+        let int32_cases: &[(i32, &[u8])] = &[
+            (0, &[0x00]),
+            (1, &[0x01]),
+            (300, &[0xac, 0x02]),
+            (-1, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]),
+            (i32::MIN, &[0x80, 0x80, 0x80, 0x80, 0xf8, 0xff, 0xff, 0xff, 0xff, 0x01]),
+            (i32::MAX, &[0xff, 0xff, 0xff, 0xff, 0x07]),
+        ];
+        for &(val, expected) in int32_cases {
+            let mut buf = Vec::new();
+            encode_int32(&mut buf, val);
+            assert_eq!(buf, expected, "int32 {}", val);
+            assert_eq!(int32_len(val), expected.len());
+            assert_eq!(decode_int32(&buf, 0).unwrap(), (val, expected.len()));
+            let mut out = [0u8; 10];
+            let mut w = SliceWriter::new(&mut out);
+            let written = w.write_int32(val).unwrap();
+            assert_eq!(&out[..written], expected);
+        }
+
+        let int64_cases: &[(i64, &[u8])] = &[
+            (-1, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]),
+            (i64::MIN, &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01]),
+            (i64::MAX, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]),
+        ];
+        for &(val, expected) in int64_cases {
+            let mut buf = Vec::new();
+            encode_int64(&mut buf, val);
+            assert_eq!(buf, expected, "int64 {}", val);
+            assert_eq!(int64_len(val), expected.len());
+            assert_eq!(decode_int64(&buf, 0).unwrap(), (val, expected.len()));
+        }
+
+        // An int64 read as int32 keeps its low 32 bits
+        for (wide, narrow) in [(1i64 << 32 | 1, 1i32), (i32::MIN as i64 - 1, i32::MAX), (-1, -1)] {
+            let mut buf = Vec::new();
+            encode_int64(&mut buf, wide);
+            assert_eq!(decode_int32(&buf, 0).unwrap().0, narrow);
+        }
+    }
+
+    #[test]
+    fn test_zigzag32_roundtrip() {
+        for &val in &[0i32, 1, -1, 2, -2, i32::MAX, i32::MIN] {
+            let mut buf = Vec::new();
+            encode_zigzag32(&mut buf, val);
+            let (decoded, _) = decode_zigzag32(&buf, 0).unwrap();
+            assert_eq!(decoded, val);
+        }
+    }
+
+    #[test]
+    fn test_zigzag64_roundtrip() {
+        for &val in &[0i64, 1, -1, 2, -2, i64::MAX, i64::MIN] {
+            let mut buf = Vec::new();
+            encode_zigzag64(&mut buf, val);
+            let (decoded, _) = decode_zigzag64(&buf, 0).unwrap();
+            assert_eq!(decoded, val);
+        }
+    }
+
+    #[test]
+    fn test_decode_key_range() {
+        for tag in [(1 << 3) | 5, ((MAX_FIELD_NUMBER as u64) << 3) | 2] {
+            let mut buf = Vec::new();
+            encode_key(&mut buf, tag);
+            assert_eq!(decode_key(&buf, 0).unwrap(), (tag, buf.len()));
+        }
+        let key = |tag: u64| {
+            let mut buf = Vec::new();
+            encode_key(&mut buf, tag);
+            decode_key(&buf, 0)
+        };
+        assert!(matches!(key(2), Err(DecodeError::InvalidFieldNumber(0))));
+        assert!(matches!(key((1 << 32) | 2), Err(DecodeError::InvalidFieldNumber(n)) if n == 1 << 29));
+        assert!(matches!(key(u64::MAX), Err(DecodeError::InvalidFieldNumber(_))));
+        assert!(matches!(key((3 << 3) | 6), Err(DecodeError::UnknownWireType(6))));
+        assert!(matches!(key((3 << 3) | 7), Err(DecodeError::UnknownWireType(7))));
+        // A zero byte where a key belongs fails the message, not skipped as field 0
+        assert!(matches!(Timestamp::decode(&[0x00, 0x00]), Err(DecodeError::InvalidFieldNumber(0))));
+    }
+
+    #[test]
+    fn test_chunked_reader_key_range() {
+        // Chunked input fails on the keys slice input fails on, wherever the
+        // key is split
+        for (tag, expected) in [
+            (2, DecodeError::InvalidFieldNumber(0)),
+            ((3 << 3) | 6, DecodeError::UnknownWireType(6)),
+            (((MAX_FIELD_NUMBER as u64) << 3) | 7, DecodeError::UnknownWireType(7)),
+        ] {
+            let mut buf = Vec::new();
+            encode_key(&mut buf, tag);
+            for cut in 0..=buf.len() {
+                let chunks = [&buf[..cut], &buf[cut..]];
+                let mut r = ChunkedReader::new(&chunks);
+                let peeked = r.peek_key().unwrap_err();
+                let read = r.read_key().unwrap_err();
+                let sliced = Reader::new(&buf).read_key().unwrap_err();
+                for err in [peeked, read, sliced] {
+                    assert_eq!(err.to_string(), expected.to_string(), "tag {tag:#x} cut at {cut}");
+                }
+            }
+        }
+
+        // As does a bad key inside a skipped group
+        let chunks: [&[u8]; 2] = [&[0x08, 0x01], &[0x00, 0x0c]];
+        let mut r = ChunkedReader::new(&chunks);
+        assert!(matches!(WireRead::skip_field(&mut r, 0x0b), Err(DecodeError::InvalidFieldNumber(0))));
+        let chunks: [&[u8]; 1] = [&[0x1e, 0x0c]];
+        let mut r = ChunkedReader::new(&chunks);
+        assert!(matches!(WireRead::skip_field(&mut r, 0x0b), Err(DecodeError::UnknownWireType(6))));
+    }
+
+    #[test]
+    fn test_sint_field_helpers() {
+        for &val in &[0i32, 1, -1, 63, -64, i32::MAX, i32::MIN] {
+            let mut buf = Vec::new();
+            encode_sint32_field(&mut buf, 5, val);
+            let (tag, pos) = decode_key(&buf, 0).unwrap();
+            assert_eq!(tag, 5 << 3);
+            let (decoded, end) = decode_sint32_field(&buf, pos, tag).unwrap();
+            assert_eq!(decoded, val);
+            assert_eq!(end, buf.len());
+        }
+        for &val in &[0i64, 1, -1, i64::MAX, i64::MIN] {
+            let mut buf = Vec::new();
+            encode_sint64_field(&mut buf, 300, val);
+            let (tag, pos) = decode_key(&buf, 0).unwrap();
+            assert_eq!(tag, 300 << 3);
+            let (decoded, _) = decode_sint64_field(&buf, pos, tag).unwrap();
+            assert_eq!(decoded, val);
+        }
+
+        // A fixed32-tagged value is rejected before it is read as a varint
+        let mut buf = Vec::new();
+        encode_key(&mut buf, (5 << 3) | 5);
+        encode_fixed32(&mut buf, 0xFFFF_FFFF);
+        let (tag, pos) = decode_key(&buf, 0).unwrap();
+        assert!(matches!(
+            decode_sint32_field(&buf, pos, tag),
+            Err(DecodeError::WireTypeMismatch { expected: 0, found: 5 })
+        ));
+        assert!(matches!(
+            decode_sint64_field(&buf, pos, tag),
+            Err(DecodeError::WireTypeMismatch { expected: 0, found: 5 })
+        ));
+    }
+
+    #[test]
+    fn test_fixed64_roundtrip() {
+        for &val in &[0u64, 1, 0xDEAD_BEEF, u64::MAX] {
+            let mut buf = Vec::new();
+            encode_fixed64(&mut buf, val);
+            let (decoded, _) = decode_fixed64(&buf, 0).unwrap();
+            assert_eq!(decoded, val);
+        }
+    }
+
+    #[test]
+    fn test_fixed32_roundtrip() {
+        for &val in &[0u32, 1, 0xDEAD, u32::MAX] {
+            let mut buf = Vec::new();
+            encode_fixed32(&mut buf, val);
+            let (decoded, _) = decode_fixed32(&buf, 0).unwrap();
+            assert_eq!(decoded, val);
+        }
+    }
+
+    #[test]
+    fn test_float_roundtrip() {
+        for &val in &[0.0f64, -0.0, 1.5, f64::MIN_POSITIVE, f64::INFINITY, f64::from_bits(0x7ff8_0000_0000_0001)] {
+            let mut buf = Vec::new();
+            encode_double(&mut buf, val);
+            assert_eq!(buf, val.to_bits().to_le_bytes());
+            let (decoded, pos) = decode_double(&buf, 0).unwrap();
+            assert_eq!((decoded.to_bits(), pos), (val.to_bits(), 8));
+        }
+        for &val in &[0.0f32, -0.0, -2.25, f32::NEG_INFINITY, f32::NAN] {
+            let mut buf = Vec::new();
+            encode_float(&mut buf, val);
+            let (decoded, pos) = decode_float(&buf, 0).unwrap();
+            assert_eq!((decoded.to_bits(), pos), (val.to_bits(), 4));
+
+            let mut out = [0u8; 4];
+            SliceWriter::new(&mut out).write_float(val).unwrap();
+            assert_eq!(out.to_vec(), buf);
+        }
+        let mut out = [0u8; 8];
+        SliceWriter::new(&mut out).write_double(0.1).unwrap();
+        assert_eq!(decode_double(&out, 0).unwrap().0, 0.1);
+        assert!(matches!(decode_double(&[0; 7], 0), Err(DecodeError::BufferOverflow)));
+        assert!(matches!(decode_float(&[0; 3], 0), Err(DecodeError::BufferOverflow)));
+    }
+
+    #[test]
+    fn test_packed_fixed_roundtrip() {
+        let mut buf = Vec::new();
+        encode_packed_fixed32(&mut buf, &[1, 0xdead_beef, u32::MAX]);
+        assert_eq!(buf.len(), 1 + 12);
+        let mut out = vec![7];
+        let pos = decode_packed_fixed32(&buf, 0, &mut out).unwrap();
+        assert_eq!(pos, buf.len());
+        assert_eq!(out, vec![7, 1, 0xdead_beef, u32::MAX]);
+
+        let mut buf = Vec::new();
+        encode_packed_double(&mut buf, &[1.5, -0.0, f64::MAX]);
+        let mut out = Vec::new();
+        decode_packed_double(&buf, 0, &mut out).unwrap();
+        assert_eq!(out, vec![1.5, -0.0, f64::MAX]);
+        assert!(out[1].is_sign_negative());
+
+        let mut buf = Vec::new();
+        encode_packed_sfixed64(&mut buf, &[-1, i64::MIN, 3]);
+        let mut out = Vec::new();
+        decode_packed_sfixed64(&buf, 0, &mut out).unwrap();
+        assert_eq!(out, vec![-1, i64::MIN, 3]);
+
+        // Empty payload
+        let mut out: Vec<f32> = Vec::new();
+        assert_eq!(decode_packed_float(&[0x00], 0, &mut out).unwrap(), 1);
+        assert!(out.is_empty());
+
+        // One byte short of three elements: rejected, nothing appended
+        let mut short = vec![11];
+        short.extend_from_slice(&[0u8; 11]);
+        let mut out = Vec::new();
+        assert!(matches!(
+            decode_packed_fixed32(&short, 0, &mut out),
+            Err(DecodeError::InvalidData("packed fixed field length not a multiple of element size"))
+        ));
+        assert!(out.is_empty());
+        assert!(decode_packed_fixed64(&short, 0, &mut Vec::new()).is_err());
+
+        // Length prefix past the end is still an overflow
+        assert!(matches!(
+            decode_packed_fixed32(&[12, 0, 0, 0, 0], 0, &mut Vec::new()),
+            Err(DecodeError::BufferOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_packed_varint_roundtrip() {
+        let mut buf = Vec::new();
+        encode_packed_varint(&mut buf, &[0, 300, u64::MAX]);
+        assert_eq!(buf, vec![13, 0x00, 0xAC, 0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+        let mut out = Vec::new();
+        assert_eq!(decode_packed_varint(&buf, 0, &mut out).unwrap(), buf.len());
+        assert_eq!(out, vec![0, 300, u64::MAX]);
+
+        let mut buf = Vec::new();
+        encode_packed_int32(&mut buf, &[-1, 1]);
+        assert_eq!(buf.len(), 1 + 10 + 1);
+        let mut out = Vec::new();
+        decode_packed_int32(&buf, 0, &mut out).unwrap();
+        assert_eq!(out, vec![-1, 1]);
+
+        let mut buf = Vec::new();
+        encode_packed_zigzag32(&mut buf, &[-1, i32::MIN, i32::MAX]);
+        let mut out = Vec::new();
+        decode_packed_zigzag32(&buf, 0, &mut out).unwrap();
+        assert_eq!(out, vec![-1, i32::MIN, i32::MAX]);
+
+        let mut buf = Vec::new();
+        encode_packed_zigzag64(&mut buf, &[i64::MIN, 0, 5]);
+        let mut out = Vec::new();
+        decode_packed_zigzag64(&buf, 0, &mut out).unwrap();
+        assert_eq!(out, vec![i64::MIN, 0, 5]);
+
+        let mut buf = Vec::new();
+        encode_packed_bool(&mut buf, &[true, false]);
+        assert_eq!(buf, vec![2, 1, 0]);
+        let mut out = Vec::new();
+        decode_packed_bool(&buf, 0, &mut out).unwrap();
+        assert_eq!(out, vec![true, false]);
+
+        // A varint continuing past the payload end is rejected
+        assert!(decode_packed_uint32(&[2, 0x01, 0x80, 0x01], 0, &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_packed_decode_limits() {
+        let opts = DecodeOptions { max_repeated_len: 3, ..DecodeOptions::DEFAULT };
+
+        // A fixed-width run over the budget is refused before reserving:
+        // the element count is known from the payload length
+        let mut buf = Vec::new();
+        encode_packed_fixed32(&mut buf, &[1, 2, 3, 4]);
+        let mut out = Vec::new();
+        assert!(matches!(decode_packed_fixed32_with(&buf, 0, &mut out, &opts), Err(DecodeError::SizeLimitExceeded)));
+        assert_eq!(out.capacity(), 0);
+        assert_eq!(decode_packed_fixed32(&buf, 0, &mut out).unwrap(), buf.len());
+
+        // The budget covers what earlier runs already appended
+        let mut buf = Vec::new();
+        encode_packed_double(&mut buf, &[1.0, 2.0]);
+        let mut out = Vec::new();
+        decode_packed_double_with(&buf, 0, &mut out, &opts).unwrap();
+        assert!(matches!(decode_packed_double_with(&buf, 0, &mut out, &opts), Err(DecodeError::SizeLimitExceeded)));
+        assert_eq!(out, vec![1.0, 2.0]);
+
+        // Varints are counted as they are read, failing on the first
+        // element past the budget
+        let mut buf = Vec::new();
+        encode_packed_uint32(&mut buf, &[1, 2, 3, 4, 5]);
+        let mut out = Vec::new();
+        assert!(matches!(decode_packed_uint32_with(&buf, 0, &mut out, &opts), Err(DecodeError::SizeLimitExceeded)));
+        assert_eq!(out, vec![1, 2, 3]);
+        let mut out = Vec::new();
+        assert_eq!(decode_packed_uint32_with(&[3, 1, 2, 3], 0, &mut out, &opts).unwrap(), 4);
+        assert_eq!(out, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_repeated_ref_reads_packed_and_unpacked() {
+        // field 2 (uint32): one unpacked element, a packed run, another element
+        let mut buf = Vec::new();
+        encode_key(&mut buf, 0x10);
+        encode_varint(&mut buf, 7);
+        encode_key(&mut buf, 0x12);
+        encode_packed_uint32(&mut buf, &[1, 300]);
+        encode_key(&mut buf, 0x12);
+        encode_packed_uint32(&mut buf, &[]);
+        encode_key(&mut buf, 0x10);
+        encode_varint(&mut buf, 9);
+
+        let decode: ElemDecoder<'_, u64> = decode_varint;
+        let items = RepeatedRef::packed(&buf, 0x10, decode);
+        let values: Vec<u64> = items.iter().map(Result::unwrap).collect();
+        assert_eq!(values, vec![7, 1, 300, 9]);
+
+        // Without packed support the runs are skipped as another field
+        let unpacked: Vec<u64> = RepeatedRef::new(&buf, 0x10, decode).iter().map(Result::unwrap).collect();
+        assert_eq!(unpacked, vec![7, 9]);
+
+        // A varint straddling the run end is an error, then the iterator stops
+        let bad = [0x12, 0x01, 0x80, 0x01];
+        let mut it = RepeatedRef::packed(&bad, 0x10, decode).iter();
+        assert!(matches!(it.next(), Some(Err(_))));
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn test_string_roundtrip() {
+        for val in &["", "hello", "hello world 🌍"] {
+            let mut buf = Vec::new();
+            encode_string(&mut buf, val);
+            let (decoded, _) = decode_string(&buf, 0).unwrap();
+            assert_eq!(&decoded, val);
+        }
+    }
+
+    #[test]
+    fn test_decode_string_lossy() {
+        for val in &["", "vault-🌍"] {
+            let mut buf = Vec::new();
+            encode_string(&mut buf, val);
+            assert_eq!(decode_string_lossy(&buf, 0).unwrap(), (val.to_string(), buf.len()));
+            assert_eq!(&decode_string(&buf, 0).unwrap().0, val);
+        }
+
+        let mut buf = Vec::new();
+        encode_bytes(&mut buf, b"id-\xFF\xFE-7");
+        assert!(matches!(decode_string(&buf, 0), Err(DecodeError::InvalidData(_))));
+        let (lossy, end) = decode_string_lossy(&buf, 0).unwrap();
+        assert_eq!(lossy, "id-\u{FFFD}\u{FFFD}-7");
+        assert_eq!(end, buf.len());
+
+        // Framing errors are still errors
+        assert!(decode_string_lossy(&[0x05, b'a'], 0).is_err());
+    }
+
+    #[test]
+    fn test_decode_string_lossy_bounded() {
+        let opts = DecodeOptions { max_alloc: 8, ..DecodeOptions::DEFAULT };
+        let mut buf = Vec::new();
+        encode_bytes(&mut buf, b"id-\xFF");
+        assert_eq!(decode_string_lossy_with(&buf, 0, &opts).unwrap(), ("id-\u{FFFD}".to_string(), buf.len()));
+        // Each replaced byte grows to three, past a bound the payload was within
+        assert_eq!(decode_string_lossy_bounded(&buf, 0, &opts, 6, 2).unwrap().0, "id-\u{FFFD}");
+        assert!(matches!(decode_string_lossy_bounded(&buf, 0, &opts, 5, 2), Err(DecodeError::LimitExceeded { field: 2 })));
+        assert!(matches!(decode_string_lossy_bounded(&buf, 0, &opts, 3, 2), Err(DecodeError::LimitExceeded { field: 2 })));
+
+        let mut long = Vec::new();
+        encode_bytes(&mut long, &[0xFF; 9]);
+        assert!(matches!(decode_string_lossy_with(&long, 0, &opts), Err(DecodeError::SizeLimitExceeded)));
+    }
+
+    #[test]
+    fn test_decode_cow_borrows_valid_input() {
+        let mut buf = Vec::new();
+        encode_string(&mut buf, "vault-🌍");
+        let (value, end) = decode_str_cow(&buf, 0).unwrap();
+        assert!(matches!(value, Cow::Borrowed("vault-🌍")));
+        assert_eq!(end, buf.len());
+        assert!(matches!(decode_str_lossy_cow(&buf, 0).unwrap().0, Cow::Borrowed(_)));
+        assert!(matches!(decode_bytes_cow(&buf, 0).unwrap().0, Cow::Borrowed(raw) if raw == "vault-🌍".as_bytes()));
+
+        let mut bad = Vec::new();
+        encode_bytes(&mut bad, b"id-\xFF");
+        assert!(matches!(decode_str_cow(&bad, 0), Err(DecodeError::InvalidData(_))));
+        let (lossy, _) = decode_str_lossy_cow(&bad, 0).unwrap();
+        assert!(matches!(&lossy, Cow::Owned(s) if s == "id-\u{FFFD}"));
+        assert_eq!(decode_str_lossy_cow_bounded(&bad, 0, 6, 2).unwrap().0, "id-\u{FFFD}");
+        assert!(matches!(decode_str_lossy_cow_bounded(&bad, 0, 5, 2), Err(DecodeError::LimitExceeded { field: 2 })));
+
+        assert_eq!(decode_str_cow_bounded(&buf, 0, 10, 1).unwrap().0, "vault-🌍");
+        assert!(matches!(decode_str_cow_bounded(&buf, 0, 9, 1), Err(DecodeError::LimitExceeded { field: 1 })));
+        assert!(matches!(decode_bytes_cow_bounded(&buf, 0, 9, 1), Err(DecodeError::LimitExceeded { field: 1 })));
+        assert!(decode_bytes_cow(&[0x05, b'a'], 0).is_err());
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        for val in &[vec![], vec![1u8, 2, 3], vec![0xFF; 300]] {
+            let mut buf = Vec::new();
+            encode_bytes(&mut buf, val);
+            let (decoded, _) = decode_bytes(&buf, 0).unwrap();
+            assert_eq!(&decoded, val);
+            let (borrowed, end) = decode_bytes_ref(&buf, 0).unwrap();
+            assert_eq!(borrowed, &val[..]);
+            assert_eq!(end, buf.len());
+        }
+        assert!(matches!(decode_bytes_ref(&[0x05, 1, 2], 0), Err(DecodeError::BufferOverflow)));
+    }
+
+    #[test]
+    fn test_pubkey_roundtrip() {
+        let key = pubkey_from_array([7; PUBKEY_LEN]);
+        let mut buf = Vec::new();
+        encode_pubkey(&mut buf, &key);
+        assert_eq!(buf.len(), 1 + PUBKEY_LEN);
+        assert_eq!(decode_pubkey(&buf, 0).unwrap(), (key, buf.len()));
+
+        let mut short = Vec::new();
+        encode_bytes(&mut short, &[7; 31]);
+        assert!(matches!(decode_pubkey(&short, 0), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(decode_pubkey(&[0x20, 1], 0), Err(DecodeError::BufferOverflow)));
+    }
+
+    #[test]
+    fn test_fixed_bytes_roundtrip() {
+        let mut buf = Vec::new();
+        encode_bytes(&mut buf, &[9u8; 64]);
+        let (sig, end) = decode_fixed_bytes::<64>(&buf, 0).unwrap();
+        assert_eq!((sig, end), ([9; 64], buf.len()));
+        assert_eq!(decode_fixed_bytes_ref::<64>(&buf, 0).unwrap().0, &[9; 64]);
+        assert!(matches!(decode_fixed_bytes::<32>(&buf, 0), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(decode_fixed_bytes::<65>(&buf, 0), Err(DecodeError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_skip_field() {
+        // Varint
+        let mut buf = Vec::new();
+        encode_varint(&mut buf, 300);
+        let new_pos = skip_field(&buf, 0, 0).unwrap();
+        assert_eq!(new_pos, buf.len());
+
+        // Fixed64
+        let mut buf = Vec::new();
+        encode_fixed64(&mut buf, 42);
+        let new_pos = skip_field(&buf, 0, 1).unwrap();
+        assert_eq!(new_pos, 8);
+
+        // Length-delimited
+        let mut buf = Vec::new();
+        encode_string(&mut buf, "hello");
+        let new_pos = skip_field(&buf, 0, 2).unwrap();
+        assert_eq!(new_pos, buf.len());
+
+        // Fixed32
+        let mut buf = Vec::new();
+        encode_fixed32(&mut buf, 42);
+        let new_pos = skip_field(&buf, 0, 5).unwrap();
+        assert_eq!(new_pos, 4);
+
+        // A length prefix near u64::MAX must not wrap past the end
+        let mut buf = Vec::new();
+        encode_varint(&mut buf, u64::MAX - 2);
+        buf.extend_from_slice(b"abc");
+        assert!(matches!(skip_field(&buf, 0, 2), Err(DecodeError::LengthOverflow)));
+        assert!(matches!(decode_field_value(&buf, 0, 2), Err(DecodeError::LengthOverflow)));
+        assert!(matches!(decode_bytes_ref(&buf, 0), Err(DecodeError::LengthOverflow)));
+        let unbounded = DecodeOptions { max_alloc: usize::MAX, ..DecodeOptions::DEFAULT };
+        assert!(matches!(decode_bytes_with(&buf, 0, &unbounded), Err(DecodeError::LengthOverflow)));
+        let chunks: [&[u8]; 2] = [&buf[..4], &buf[4..]];
+        assert!(matches!(ChunkedReader::new(&chunks).read_bytes(), Err(DecodeError::LengthOverflow)));
+        assert!(matches!(
+            WireRead::skip_field(&mut ChunkedReader::new(&chunks), 2),
+            Err(DecodeError::LengthOverflow)
+        ));
+
+        // So must a fixed-width read at a position near usize::MAX
+        assert!(matches!(skip_field(&buf, usize::MAX - 2, 1), Err(DecodeError::LengthOverflow)));
+        assert!(matches!(decode_fixed32(&buf, usize::MAX - 1), Err(DecodeError::LengthOverflow)));
+
+        // A length that fits but runs past the input is still truncation
+        assert!(matches!(skip_field(&[0x05, 0x00], 0, 2), Err(DecodeError::BufferOverflow)));
+    }
+
+    #[test]
+    fn test_skip_group() {
+        // Group 1 holding a varint and an empty nested group 3, then field 3
+        let buf = [0x0b, 0x10, 0x01, 0x1b, 0x1c, 0x0c, 0x18, 0x05];
+        assert_eq!(skip_field(&buf, 1, 0x0b).unwrap(), 6);
+        let (value, end) = decode_field_value(&buf, 1, 0x0b).unwrap();
+        assert_eq!((value, end), (FieldValue::Group(&[0x10, 0x01, 0x1b, 0x1c]), 6));
+
+        // Unknown groups are skipped like any other unknown field
+        let mut data = vec![0x08, 0xac, 0x02];
+        data.extend_from_slice(&buf);
+        assert_eq!(Timestamp::decode(&data).unwrap().seconds, 300);
+        let mut decoder = Decoder::new(&data);
+        let mut tags = Vec::new();
+        while let Some(field) = decoder.next_field().unwrap() {
+            tags.push(field.tag);
+        }
+        assert_eq!(tags, [0x08, 0x0b, 0x18]);
+        let mut r = Reader::new(&data[3..]);
+        r.read_key().unwrap();
+        r.skip_field(0x0b).unwrap();
+        assert_eq!(r.position(), 6);
+        let chunks: [&[u8]; 3] = [&buf[..2], &buf[2..4], &buf[4..]];
+        let mut r = ChunkedReader::new(&chunks);
+        r.read_key().unwrap();
+        WireRead::skip_field(&mut r, 0x0b).unwrap();
+        assert_eq!(r.position(), 6);
+
+        // The end key must close the innermost open group
+        assert!(matches!(skip_field(&[0x14], 0, 0x0b), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(skip_field(&[0x1b, 0x0c], 0, 0x0b), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(skip_field(&[], 0, 0x0c), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(decode_field_value(&[], 0, 0x0c), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(skip_field(&[0x10, 0x01], 0, 0x0b), Err(DecodeError::BufferOverflow)));
+
+        // Nesting is capped at the default recursion limit
+        let depth = DecodeOptions::DEFAULT.max_depth;
+        let nested = |levels: usize| [vec![0x0b; levels], vec![0x0c; levels]].concat();
+        assert_eq!(skip_field(&nested(depth), 1, 0x0b).unwrap(), 2 * depth);
+        assert!(matches!(
+            skip_field(&nested(depth + 1), 1, 0x0b),
+            Err(DecodeError::RecursionLimitExceeded)
+        ));
+        let deep = nested(depth + 1);
+        let chunks: [&[u8]; 1] = [&deep[1..]];
+        assert!(matches!(
+            WireRead::skip_field(&mut ChunkedReader::new(&chunks), 0x0b),
+            Err(DecodeError::RecursionLimitExceeded)
+        ));
+    }
+
+    #[test]
+    fn test_group_fields() {
+        // A `Timestamp` as group 2: start key, body, end key
+        let inner = Timestamp::new(3, 1);
+        let expected = [0x13, 0x08, 0x03, 0x10, 0x01, 0x14];
+        let mut buf = [0u8; 16];
+        let mut w = SliceWriter::new(&mut buf);
+        assert_eq!(w.write_group(2, &inner).unwrap(), expected.len());
+        assert_eq!(buf[..expected.len()], expected);
+        let mut reverse = Vec::new();
+        ReverseWriter::new(&mut reverse).write_group(2, &inner);
+        assert_eq!(reverse, expected);
+
+        // The sized writer checks the recorded length it does not write
+        let mut cache = SizeCache::default();
+        cache.record(&Empty {});
+        cache.record(&inner);
+        let mut w = SliceWriter::new(&mut buf);
+        assert_eq!(w.write_group_sized(2, &inner, &mut cache.cursor()).unwrap(), expected.len());
+        assert_eq!(buf[..expected.len()], expected);
+        let mut w = SliceWriter::new(&mut buf);
+        let err = w.write_group_sized(2, &Timestamp::default(), &mut cache.cursor()).unwrap_err();
+        assert!(matches!(err, DecodeError::InvalidData(_)));
+
+        let (body, end) = decode_group_slice::<2>(&expected, 1).unwrap();
+        assert_eq!((body, end), (&expected[1..5], 6));
+        assert_eq!(Timestamp::decode(body).unwrap(), inner);
+        assert!(matches!(decode_group_slice::<3>(&expected, 1), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(decode_group_slice::<2>(&expected[..5], 1), Err(DecodeError::BufferOverflow)));
+    }
+
+    #[test]
+    fn test_extract_nested() {
+        // inner { 1: 7, 2: "x" }
+        let mut inner = Vec::new();
+        encode_key(&mut inner, 0x08);
+        encode_varint(&mut inner, 7);
+        encode_key(&mut inner, 0x12);
+        encode_string(&mut inner, "x");
+
+        // outer { 1: 1, 2: <not a valid message>, 3: inner, 4: 0xBEEF }
+        let mut outer = Vec::new();
+        encode_key(&mut outer, 0x08);
+        encode_varint(&mut outer, 1);
+        encode_key(&mut outer, 0x12);
+        encode_bytes(&mut outer, &[0xFF, 0xFF, 0xFF]);
+        encode_key(&mut outer, 0x1a);
+        encode_bytes(&mut outer, &inner);
+        encode_key(&mut outer, 0x25);
+        encode_fixed32(&mut outer, 0xBEEF);
+
+        // The undecodable sibling at field 2 is skipped, never parsed
+        assert_eq!(extract_nested(&outer, &[3, 1]).unwrap(), Some(FieldValue::Varint(7)));
+        assert_eq!(
+            extract_nested(&outer, &[3, 2]).unwrap(),
+            Some(FieldValue::LengthDelimited(b"x"))
+        );
+        assert_eq!(extract_nested(&outer, &[4]).unwrap(), Some(FieldValue::Fixed32(0xBEEF)));
+        assert_eq!(extract_nested(&outer, &[3, 9]).unwrap(), None);
+        assert_eq!(extract_nested(&outer, &[5, 1]).unwrap(), None);
+        assert!(extract_nested(&outer, &[1, 1]).is_err());
+
+        // A later occurrence of the sub-message wins for the fields it sets
+        let mut patch = Vec::new();
+        encode_key(&mut patch, 0x08);
+        encode_varint(&mut patch, 8);
+        encode_key(&mut outer, 0x1a);
+        encode_bytes(&mut outer, &patch);
+        assert_eq!(extract_nested(&outer, &[3, 1]).unwrap(), Some(FieldValue::Varint(8)));
+        assert_eq!(
+            extract_nested(&outer, &[3, 2]).unwrap(),
+            Some(FieldValue::LengthDelimited(b"x"))
+        );
+    }
+
+    #[test]
+    fn test_is_selected() {
+        assert!(is_selected(None, 7));
+        assert!(is_selected(Some(&[2, 7]), 7));
+        assert!(!is_selected(Some(&[2, 7]), 3));
+        assert!(!is_selected(Some(&[]), 1));
+    }
+
+    #[test]
+    fn test_patch_in_place() {
+        // { 1: 300, 2: inner { 1: fixed64 5, 2: "ab" }, 3: fixed32 9 }
+        let mut inner = Vec::new();
+        encode_key(&mut inner, 0x09);
+        encode_fixed64(&mut inner, 5);
+        encode_key(&mut inner, 0x12);
+        encode_string(&mut inner, "ab");
+        let mut buf = Vec::new();
+        encode_key(&mut buf, 0x08);
+        encode_varint(&mut buf, 300);
+        encode_key(&mut buf, 0x12);
+        encode_bytes(&mut buf, &inner);
+        encode_key(&mut buf, 0x1d);
+        encode_fixed32(&mut buf, 9);
+        let len = buf.len();
+
+        patch_varint(&mut buf, &[1], 7).unwrap();
+        patch_fixed64(&mut buf, &[2, 1], u64::MAX).unwrap();
+        patch_bytes(&mut buf, &[2, 2], b"cd").unwrap();
+        patch_fixed32(&mut buf, &[3], 10).unwrap();
+        assert_eq!(buf.len(), len);
+        // The narrower varint keeps its two bytes, padded
+        assert_eq!(&buf[1..3], &[0x87, 0x00]);
+        assert_eq!(extract_nested(&buf, &[1]).unwrap(), Some(FieldValue::Varint(7)));
+        assert_eq!(extract_nested(&buf, &[2, 1]).unwrap(), Some(FieldValue::Fixed64(u64::MAX)));
+        assert_eq!(extract_nested(&buf, &[2, 2]).unwrap(), Some(FieldValue::LengthDelimited(b"cd")));
+        assert_eq!(extract_nested(&buf, &[3]).unwrap(), Some(FieldValue::Fixed32(10)));
+
+        // Refused without writing anything
+        let before = buf.clone();
+        assert!(matches!(patch_varint(&mut buf, &[1], 1 << 14), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(patch_bytes(&mut buf, &[2, 2], b"abc"), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(patch_varint(&mut buf, &[4], 1), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(
+            patch_fixed64(&mut buf, &[3], 1),
+            Err(DecodeError::WireTypeMismatch { expected: 1, found: 5 })
+        ));
+        assert!(matches!(patch_varint(&mut buf, &[], 1), Err(DecodeError::InvalidData(_))));
+        assert_eq!(buf, before);
+
+        // The last occurrence is the one decoding keeps
+        encode_key(&mut buf, 0x08);
+        encode_varint(&mut buf, 1);
+        patch_varint(&mut buf, &[1], 2).unwrap();
+        assert_eq!(extract_nested(&buf, &[1]).unwrap(), Some(FieldValue::Varint(2)));
+        assert_eq!(&buf[1..3], &[0x87, 0x00]);
+    }
+
+    #[test]
+    fn test_splice_field() {
+        // { 1: "x", 2: inner { 1: "ab", 2: 7 }, 3: 9 }
+        let encode = |name: &[u8], value: &[u8]| {
+            let mut inner = Vec::new();
+            encode_key(&mut inner, 0x0a);
+            encode_bytes(&mut inner, value);
+            encode_key(&mut inner, 0x10);
+            encode_varint(&mut inner, 7);
+            let mut buf = Vec::new();
+            encode_key(&mut buf, 0x0a);
+            encode_bytes(&mut buf, name);
+            encode_key(&mut buf, 0x12);
+            encode_bytes(&mut buf, &inner);
+            encode_key(&mut buf, 0x18);
+            encode_varint(&mut buf, 9);
+            buf
+        };
+
+        // Growing past a one-byte length prefix rewrites both prefixes
+        let long = [0x55u8; 200];
+        let mut buf = encode(b"x", b"ab");
+        splice_field(&mut buf, &[2, 1], &long).unwrap();
+        assert_eq!(buf, encode(b"x", &long));
+        splice_field(&mut buf, &[2, 1], b"").unwrap();
+        assert_eq!(buf, encode(b"x", b""));
+        splice_field(&mut buf, &[1], b"longer name").unwrap();
+        assert_eq!(buf, encode(b"longer name", b""));
+
+        // Within a fixed buffer: the tail is free space
+        let mut account = [0u8; 64];
+        let start = encode(b"x", b"ab");
+        account[..start.len()].copy_from_slice(&start);
+        let len = splice_field_in(&mut account, start.len(), &[2, 1], b"abcdef").unwrap();
+        assert_eq!(&account[..len], &encode(b"x", b"abcdef")[..]);
+        let before = account;
+        assert!(matches!(
+            splice_field_in(&mut account, len, &[2, 1], &[0; 64]),
+            Err(DecodeError::BufferTooSmall)
+        ));
+        assert_eq!(account, before);
+        assert!(matches!(splice_field_in(&mut account, 65, &[1], b""), Err(DecodeError::BufferOverflow)));
+
+        // An absent leaf is appended to its sub-message
+        let mut buf = encode(b"x", b"ab");
+        splice_field(&mut buf, &[2, 4], b"new").unwrap();
+        assert_eq!(extract_nested(&buf, &[2, 4]).unwrap(), Some(FieldValue::LengthDelimited(b"new")));
+        assert_eq!(extract_nested(&buf, &[2, 2]).unwrap(), Some(FieldValue::Varint(7)));
+        assert_eq!(extract_nested(&buf, &[3]).unwrap(), Some(FieldValue::Varint(9)));
+        assert!(matches!(splice_field(&mut buf, &[5, 1], b""), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(
+            splice_field(&mut buf, &[3], b""),
+            Err(DecodeError::WireTypeMismatch { expected: 2, found: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_length_delimited_stream() {
+        let entries = [
+            Timestamp { seconds: 1, nanos: 0 },
+            Timestamp::default(),
+            Timestamp { seconds: -5, nanos: 7 },
+        ];
+        let mut log = Vec::new();
+        for t in &entries {
+            encode_length_delimited(&mut log, t);
+        }
+        let len: usize = entries.iter().map(length_delimited_len).sum();
+        assert_eq!(log.len(), len);
+        // An empty message is a lone zero length
+        assert_eq!(log[length_delimited_len(&entries[0])], 0);
+
+        let (first, pos) = decode_length_delimited::<Timestamp>(&log, 0).unwrap();
+        assert_eq!(first, entries[0]);
+        assert_eq!(decode_length_delimited::<Timestamp>(&log, pos).unwrap().0, entries[1]);
+
+        let mut messages = DelimitedMessages::<Timestamp>::new(&log);
+        assert_eq!(messages.by_ref().collect::<Result<Vec<_>, _>>().unwrap(), entries);
+        assert_eq!(messages.position(), log.len());
+        assert_eq!(DelimitedFrames::new(&log).count(), 3);
+
+        // Appending through a SliceWriter writes the same frames
+        let mut account = [0u8; 64];
+        let mut w = SliceWriter::new(&mut account);
+        for t in &entries {
+            w.write_length_delimited(t).unwrap();
+        }
+        assert_eq!(&account[..len], &log[..]);
+        let mut small = [0u8; 3];
+        assert!(matches!(
+            SliceWriter::new(&mut small).write_length_delimited(&entries[2]),
+            Err(DecodeError::BufferTooSmall)
+        ));
+
+        // A cut-short frame ends the stream where the valid frames end
+        let mut frames = DelimitedFrames::new(&log[..log.len() - 1]);
+        assert!(frames.next().unwrap().is_ok());
+        assert!(frames.next().unwrap().is_ok());
+        assert!(matches!(frames.next(), Some(Err(DecodeError::BufferOverflow))));
+        assert!(frames.next().is_none());
+        assert_eq!(frames.position(), len - length_delimited_len(&entries[2]));
+
+        // As does a payload that is not an `M`
+        let mut bad = log.clone();
+        bad.extend_from_slice(&[2, 0x08, 0x80]);
+        let mut messages = DelimitedMessages::<Timestamp>::new(&bad);
+        assert_eq!(messages.by_ref().take(3).filter(Result::is_ok).count(), 3);
+        assert!(messages.next().unwrap().is_err());
+        assert!(messages.next().is_none());
+        assert_eq!(messages.position(), log.len());
+    }
+
+    #[test]
+    fn test_reader_peek_key() {
+        let mut buf = Vec::new();
+        for v in [7u64, 8] {
+            encode_key(&mut buf, 0x08);
+            encode_varint(&mut buf, v);
+        }
+        encode_key(&mut buf, 0x12);
+        encode_string(&mut buf, "hi");
+
+        let mut r = Reader::new(&buf);
+        assert_eq!(r.peek_key().unwrap(), Some(0x08));
+        assert_eq!(r.peek_key().unwrap(), Some(0x08));
+        assert_eq!(r.position(), 0);
+
+        // Keep reading while the next tag is still field 1
+        let mut values = Vec::new();
+        while r.peek_key().unwrap() == Some(0x08) {
+            assert_eq!(r.read_key().unwrap(), 0x08);
+            values.push(r.read_varint().unwrap());
+        }
+        assert_eq!(values, vec![7, 8]);
+
+        assert_eq!(r.read_key().unwrap(), 0x12);
+        assert_eq!(r.read_str().unwrap(), "hi");
+        assert!(r.is_eof());
+        assert_eq!(r.peek_key().unwrap(), None);
+        assert!(r.read_key().is_err());
+
+        // Truncated multi-byte tag is an error, not end of buffer
+        assert!(Reader::new(&[0x80]).peek_key().is_err());
+    }
+
+    #[test]
+    fn test_messages_equal() {
+        let mut a = Vec::new();
+        encode_key(&mut a, 0x08);
+        encode_varint(&mut a, 150);
+        encode_key(&mut a, 0x12);
+        encode_string(&mut a, "vault");
+        encode_key(&mut a, 0x1d);
+        encode_fixed32(&mut a, 7);
+
+        // Same fields, reverse order, with a non-minimal varint for 150
+        let mut b = Vec::new();
+        encode_key(&mut b, 0x1d);
+        encode_fixed32(&mut b, 7);
+        encode_key(&mut b, 0x12);
+        encode_string(&mut b, "vault");
+        b.extend_from_slice(&[0x08, 0x96, 0x81, 0x00]);
+        assert_ne!(a, b);
+        assert!(messages_equal(&a, &b).unwrap());
+        assert!(messages_equal(&[], &[]).unwrap());
+
+        // Differing value
+        let mut c = a.clone();
+        *c.last_mut().unwrap() = 8;
+        assert!(!messages_equal(&a, &c).unwrap());
+
+        // Missing field
+        assert!(!messages_equal(&a, &a[..3]).unwrap());
+
+        // Repeated order is significant, interleaving with other fields is not
+        let mut r1 = Vec::new();
+        for v in [1u64, 2] {
+            encode_key(&mut r1, 0x20);
+            encode_varint(&mut r1, v);
+        }
+        encode_key(&mut r1, 0x08);
+        encode_varint(&mut r1, 9);
+        let mut r2 = Vec::new();
+        encode_key(&mut r2, 0x20);
+        encode_varint(&mut r2, 1);
+        encode_key(&mut r2, 0x08);
+        encode_varint(&mut r2, 9);
+        encode_key(&mut r2, 0x20);
+        encode_varint(&mut r2, 2);
+        assert!(messages_equal(&r1, &r2).unwrap());
+        let mut r3 = Vec::new();
+        for v in [2u64, 1] {
+            encode_key(&mut r3, 0x20);
+            encode_varint(&mut r3, v);
+        }
+        encode_key(&mut r3, 0x08);
+        encode_varint(&mut r3, 9);
+        assert!(!messages_equal(&r1, &r3).unwrap());
+
+        // Malformed input is an error, not "unequal"
+        assert!(messages_equal(&a, &[0x12, 0x05]).is_err());
+    }
+
+    #[test]
+    fn test_reader_read_message_boundary() {
+        // field 1: { 1: 7 }, field 2: { 1: 8, 2: "z" }, field 3: 9
+        let mut first = Vec::new();
+        encode_key(&mut first, 0x08);
+        encode_varint(&mut first, 7);
+        let mut second = Vec::new();
+        encode_key(&mut second, 0x08);
+        encode_varint(&mut second, 8);
+        encode_key(&mut second, 0x12);
+        encode_string(&mut second, "z");
+        let mut buf = Vec::new();
+        encode_key(&mut buf, 0x0a);
+        encode_bytes(&mut buf, &first);
+        encode_key(&mut buf, 0x12);
+        encode_bytes(&mut buf, &second);
+        encode_key(&mut buf, 0x18);
+        encode_varint(&mut buf, 9);
+
+        // Decode a sub-message until its reader runs dry
+        fn fields(r: &mut Reader) -> Result<Vec<u64>, DecodeError> {
+            let mut out = Vec::new();
+            while let Some(tag) = r.peek_key()? {
+                r.read_key()?;
+                match tag & 0x07 {
+                    0 => out.push(r.read_varint()?),
+                    _ => r.skip_field(tag)?,
+                }
+            }
+            Ok(out)
+        }
+
+        let mut r = Reader::new(&buf);
+        assert_eq!(r.read_key().unwrap(), 0x0a);
+        assert_eq!(r.read_message(fields).unwrap(), vec![7]);
+        assert_eq!(r.position(), 2 + first.len());
+        assert_eq!(r.read_key().unwrap(), 0x12);
+        assert_eq!(r.read_message(fields).unwrap(), vec![8]);
+        assert_eq!(r.read_key().unwrap(), 0x18);
+        assert_eq!(r.read_varint().unwrap(), 9);
+        assert!(r.is_eof());
+
+        // Leaving part of the sub-message unread is rejected, cursor unmoved
+        let mut r = Reader::new(&buf);
+        r.read_key().unwrap();
+        let start = r.position();
+        assert!(matches!(
+            r.read_message(|_| Ok(())),
+            Err(DecodeError::InvalidData(_))
+        ));
+        assert_eq!(r.position(), start);
+
+        // Reading past the sub-message boundary fails inside the closure
+        let mut r = Reader::new(&buf);
+        r.read_key().unwrap();
+        let res = r.read_message(|sub| {
+            sub.read_key()?;
+            sub.read_varint()?;
+            sub.read_key()
+        });
+        assert!(matches!(res, Err(DecodeError::BufferOverflow)));
+
+        // Declared length beyond the buffer
+        assert!(Reader::new(&[0x05, 0x08]).read_message(fields).is_err());
+        assert!(matches!(Any::decode(&[0x12, 0x7f, 0x08]), Err(DecodeError::BufferOverflow)));
+    }
+
+    /// Drain a reader into (tag, value) pairs so contiguous and chunked
+    /// decoding can be compared directly.
+    fn read_all<'a, R: WireRead<'a>>(r: &mut R) -> Result<Vec<(u64, Vec<u8>)>, DecodeError> {
+        let mut out = Vec::new();
+        while let Some(tag) = r.peek_key()? {
+            r.read_key()?;
+            let value = match tag & 0x07 {
+                0 => r.read_varint()?.to_le_bytes().to_vec(),
+                1 => r.read_fixed64()?.to_le_bytes().to_vec(),
+                2 => r.read_bytes()?.into_owned(),
+                5 => r.read_fixed32()?.to_le_bytes().to_vec(),
+                wt => return Err(DecodeError::UnknownWireType(wt)),
+            };
+            out.push((tag, value));
+        }
+        Ok(out)
+    }
+
+    #[test]
+    fn test_chunked_reader_straddling_fields() {
+        let mut buf = Vec::new();
+        encode_key(&mut buf, 0x08);
+        encode_varint(&mut buf, u64::MAX);
+        encode_key(&mut buf, 0x12);
+        encode_string(&mut buf, "split across regions");
+        encode_key(&mut buf, 0x19);
+        encode_fixed64(&mut buf, 0x0102_0304_0506_0708);
+        encode_key(&mut buf, 0x25);
+        encode_fixed32(&mut buf, 42);
+        let expected = read_all(&mut Reader::new(&buf)).unwrap();
+
+        // Every two-way split: the 10-byte varint, the length prefix, the
+        // string payload and the fixed values each straddle some boundary.
+        for cut in 0..=buf.len() {
+            let chunks = [&buf[..cut], &buf[cut..]];
+            let mut r = ChunkedReader::new(&chunks);
+            assert_eq!(read_all(&mut r).unwrap(), expected, "cut at {cut}");
+            assert_eq!(r.position(), buf.len());
+        }
+
+        // Single-byte chunks with empty ones interleaved
+        let mut pieces: Vec<&[u8]> = Vec::new();
+        for b in buf.chunks(1) {
+            pieces.push(&[]);
+            pieces.push(b);
+        }
+        let mut r = ChunkedReader::new(&pieces);
+        assert_eq!(read_all(&mut r).unwrap(), expected);
+        assert!(r.is_eof());
+    }
+
+    #[test]
+    fn test_chunked_reader_bounds() {
+        let mut buf = Vec::new();
+        encode_key(&mut buf, 0x0a);
+        encode_bytes(&mut buf, b"payload");
+
+        // A payload inside one chunk is borrowed, a straddling one is copied
+        let whole = [&buf[..]];
+        let mut r = ChunkedReader::new(&whole);
+        r.read_key().unwrap();
+        assert!(matches!(r.read_bytes().unwrap(), Cow::Borrowed(b"payload")));
+        let split = [&buf[..4], &buf[4..]];
+        let mut r = ChunkedReader::new(&split);
+        r.read_key().unwrap();
+        assert!(matches!(r.read_bytes().unwrap(), Cow::Owned(ref v) if v == b"payload"));
+
+        // Truncated payload overflows the logical end; cursor stays put
+        let short = [&buf[..4], &buf[4..buf.len() - 1]];
+        let mut r = ChunkedReader::new(&short);
+        r.read_key().unwrap();
+        assert!(matches!(r.read_bytes(), Err(DecodeError::BufferOverflow)));
+        assert_eq!(r.position(), 1);
+        assert!(matches!(r.skip_field(2), Err(DecodeError::BufferOverflow)));
+        assert_eq!(r.position(), 1);
+
+        // Truncated varint across chunks
+        let chunks: [&[u8]; 2] = [&[0x80], &[0x80]];
+        let mut r = ChunkedReader::new(&chunks);
+        assert!(r.read_varint().is_err());
+        assert_eq!(r.position(), 0);
+        assert_eq!(r.remaining(), 2);
+
+        // Invalid UTF-8 split across chunks
+        let chunks: [&[u8]; 2] = [&[0x02, 0xc3], &[0x28]];
+        assert!(ChunkedReader::new(&chunks).read_str().is_err());
+
+        let empty: [&[u8]; 2] = [&[], &[]];
+        let r = ChunkedReader::new(&empty);
+        assert!(r.is_eof());
+        assert_eq!(r.peek_key().unwrap(), None);
+    }
+
+    #[test]
+    fn test_borrowed_decoders() {
+        let (s, pos) = decode_str_ref(&[0x02, b'o', b'k'], 0).unwrap();
+        assert_eq!((s, pos), ("ok", 3));
+        assert!(decode_str_ref(&[0x01, 0xff], 0).is_err());
+
+        // A bad element fails only its own iteration
+        let items = RepeatedRef::new(&[0x10, 0x01, 0x10][..], 16, decode_varint);
+        let mut iter = items.iter();
+        assert_eq!(iter.next().unwrap().unwrap(), 1);
+        assert!(matches!(iter.next(), Some(Err(DecodeError::BufferOverflow))));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_lazy_ref_decodes_on_get() {
+        fn first_varint(data: &[u8]) -> Result<u64, DecodeError> {
+            decode_varint(data, 1).map(|(v, _)| v)
+        }
+        let lazy = LazyRef::new(&[0x08, 0x96, 0x01][..], first_varint);
+        assert_eq!(lazy.get().unwrap(), 150);
+        assert_eq!(lazy.raw(), &[0x08, 0x96, 0x01]);
+        assert_eq!(format!("{:?}", lazy), "LazyRef { len: 3 }");
+
+        // Errors surface on `get`, not when the view holding it is decoded
+        let bad = LazyRef::new(&[0x08, 0x80][..], first_varint);
+        assert!(matches!(bad.get(), Err(DecodeError::BufferOverflow)));
+    }
+
+    #[test]
+    fn test_check_required() {
+        // Non-strict decoding still records each field it sees
+        let mut seen = [0u64; 2];
+        mark_singular(&mut seen, 0, &DecodeOptions::DEFAULT).unwrap();
+        mark_singular(&mut seen, 0, &DecodeOptions::DEFAULT).unwrap();
+        mark_singular(&mut seen, 70, &DecodeOptions::DEFAULT).unwrap();
+        assert!(check_required(&seen, 0, 1).is_ok());
+        assert!(check_required(&seen, 70, 9).is_ok());
+        assert!(matches!(check_required(&seen, 1, 2), Err(DecodeError::MissingField { field: 2 })));
+        assert!(matches!(check_required(&seen, 64, 5), Err(DecodeError::MissingField { field: 5 })));
+        // An index past the bitmap is an error, not a panic
+        assert!(matches!(mark_singular(&mut seen, 128, &DecodeOptions::DEFAULT), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(check_required(&seen, 128, 3), Err(DecodeError::InvalidData(_))));
+        assert_eq!(DecodeError::MissingField { field: 2 }.to_string(), "protobuf: required field 2 is missing");
+    }
+
+    #[test]
+    fn test_check_wire_type() {
+        const FIELDS: &[FieldWireType] = &[
+            FieldWireType { number: 1, wire_type: 0, packed: false, name: "Vault.owner_id" },
+            FieldWireType { number: 2, wire_type: 5, packed: true, name: "Vault.weights" },
+        ];
+        assert!(check_wire_type(1 << 3, 0, FIELDS).is_ok());
+        assert!(check_wire_type(2 << 3 | 2, 0, FIELDS).is_ok());
+        // Numbers the message doesn't declare are left to be skipped
+        assert!(check_wire_type(9 << 3 | 1, 0, FIELDS).is_ok());
+        let err = check_wire_type(1 << 3 | 2, 4, FIELDS).unwrap_err();
+        assert!(matches!(
+            err,
+            DecodeError::FieldWireTypeMismatch { name: "Vault.owner_id", field: 1, expected: 0, found: 2, offset: 4 }
+        ));
+        assert_eq!(err.to_string(), "protobuf: field Vault.owner_id (1) at byte 4 has wire type 2, expected 0");
+        assert!(check_wire_type(2 << 3 | 1, 0, FIELDS).is_err());
+
+        // Field accessors report the wire type they found
+        let data = [0x0a, 0x00];
+        let field = Decoder::new(&data).next_field().unwrap().unwrap();
+        assert!(matches!(field.varint(), Err(DecodeError::WireTypeMismatch { expected: 0, found: 2 })));
+    }
+
+    #[test]
+    fn test_field_error() {
+        const INNER: &[FieldWireType] = &[FieldWireType { number: 1, wire_type: 0, packed: true, name: "Order.price" }];
+        let err = field_error(DecodeError::InvalidVarint, &[], 3, 1 << 3, INNER, Some(1));
+        assert!(matches!(err.root(), DecodeError::InvalidVarint));
+        #[cfg(not(feature = "error_context"))]
+        assert!(matches!(err, DecodeError::InvalidVarint));
+
+        #[cfg(feature = "error_context")]
+        {
+            // Element indexes don't apply to a packed run
+            assert_eq!(err.context().unwrap().field_path(), "Order.price[1]");
+            let packed = field_error(DecodeError::InvalidVarint, &[], 3, 1 << 3 | 2, INNER, Some(1));
+            assert_eq!(packed.context().unwrap().field_path(), "Order.price");
+
+            // The outer field's key and length come before the inner offsets
+            const OUTER: &[FieldWireType] =
+                &[FieldWireType { number: 2, wire_type: 2, packed: false, name: "Book.orders" }];
+            let outer = [0x08, 0x01, 0x12, 0x06];
+            let err = field_error(err, &outer, 2, 2 << 3 | 2, OUTER, Some(4));
+            let ctx = err.context().unwrap();
+            assert_eq!((ctx.offset, ctx.field_path()), (7, "Book.orders[4].price[1]".to_string()));
+            assert_eq!(err.to_string(), "protobuf: invalid varint at byte 7 in Book.orders[4].price[1]");
+            assert_eq!(err.kind(), DecodeErrorKind::Malformed);
+        }
+    }
+
+    #[test]
+    fn test_bump_arena() {
+        use core::alloc::{GlobalAlloc, Layout};
+        let mut region = vec![0u64; 16];
+        let arena = unsafe { BumpArena::new(region.as_mut_ptr() as usize, 128) };
+        assert_eq!((arena.used(), arena.remaining()), (0, 120));
+
+        let byte = Layout::from_size_align(1, 1).unwrap();
+        let word = Layout::from_size_align(8, 8).unwrap();
+        unsafe {
+            let a = arena.alloc(byte);
+            let mark = arena.mark();
+            // Aligned past the single byte
+            let b = arena.alloc(word);
+            assert_eq!(b as usize % 8, 0);
+            assert_eq!(arena.used(), 16);
+
+            // The last allocation grows and shrinks in place; others move
+            assert_eq!(arena.realloc(b, word, 24), b);
+            assert_eq!(arena.used(), 32);
+            let moved = arena.realloc(a, byte, 2);
+            assert!(moved != a && !moved.is_null());
+            assert_eq!(arena.used(), 34);
+
+            // Freeing the last allocation rolls it back; others stay
+            arena.dealloc(moved, Layout::from_size_align(2, 1).unwrap());
+            assert_eq!(arena.used(), 32);
+            arena.dealloc(a, byte);
+            assert_eq!(arena.used(), 32);
+
+            assert!(arena.alloc(Layout::from_size_align(89, 1).unwrap()).is_null());
+            assert!(arena.realloc(b, Layout::from_size_align(24, 8).unwrap(), 200).is_null());
+            arena.release(mark);
+            assert_eq!(arena.used(), 1);
+            arena.reset();
+            assert_eq!(arena.used(), 0);
+        }
+    }
+
+    #[test]
+    fn test_decode_with_limit() {
+        let msg = Timestamp::new(300, 1);
+        let bytes = msg.encode();
+        assert_eq!(Timestamp::decode_with_limit(&bytes, bytes.len()).unwrap(), msg);
+        assert_eq!(Timestamp::decode(&bytes).unwrap(), msg);
+
+        // One byte over is rejected before parsing: the trailing 0x80 would
+        // otherwise fail as a truncated varint.
+        let mut over = bytes.clone();
+        over.push(0x80);
+        assert!(matches!(Timestamp::decode(&over), Err(DecodeError::BufferOverflow)));
+        assert!(matches!(Timestamp::decode_with_limit(&over, bytes.len()), Err(DecodeError::SizeLimitExceeded)));
+        assert!(Timestamp::decode_with_limit(&[], 0).is_ok());
+
+        // `decode` takes up to a transaction's worth of bytes; account data
+        // is decoded with the larger `ACCOUNT` cap
+        let fits = Any { type_url: String::new(), value: vec![7; 1229] };
+        assert_eq!(fits.encoded_len(), DecodeOptions::DEFAULT.max_message_len);
+        assert_eq!(Any::decode(&fits.encode()).unwrap(), fits);
+        let large = Any { type_url: String::new(), value: vec![7; 1230] };
+        assert!(matches!(Any::decode(&large.encode()), Err(DecodeError::SizeLimitExceeded)));
+        assert_eq!(Any::decode_with(&large.encode(), &DecodeOptions::ACCOUNT).unwrap(), large);
+        let data = encode_account_data(&[0; ACCOUNT_DISCRIMINATOR_LEN], &large);
+        assert_eq!(decode_account_data::<Any>(&data).unwrap(), large);
+    }
+
+    #[test]
+    fn test_nested_payloads_past_decode_cap() {
+        // A 2 KB message is over `decode`'s cap, but not when it is a
+        // sub-message, frame or `Any` payload of data already in memory
+        let big = BytesValue { value: vec![7; 2048] };
+        assert!(matches!(BytesValue::decode(&big.encode()), Err(DecodeError::SizeLimitExceeded)));
+        let mut data = Vec::new();
+        encode_key(&mut data, 0x0a);
+        encode_bytes(&mut data, &big.encode());
+        let field = Decoder::new(&data).next_field().unwrap().unwrap();
+        assert_eq!(field.message::<BytesValue>().unwrap(), big);
+        assert!(matches!(field.message_with::<BytesValue>(&DecodeOptions::DEFAULT), Err(DecodeError::SizeLimitExceeded)));
+
+        let mut log = Vec::new();
+        encode_length_delimited(&mut log, &big);
+        encode_length_delimited(&mut log, &big);
+        assert_eq!(decode_length_delimited::<BytesValue>(&log, 0).unwrap().0, big);
+        assert_eq!(DelimitedMessages::<BytesValue>::new(&log).filter(Result::is_ok).count(), 2);
+        let mut capped = DelimitedMessages::<BytesValue>::with_options(&log, DecodeOptions::DEFAULT);
+        assert!(matches!(capped.next(), Some(Err(DecodeError::SizeLimitExceeded))));
+
+        let any = Any::pack(&big);
+        assert_eq!(any.unpack::<BytesValue>().unwrap(), big);
+        let any_bytes = any.encode();
+        let view = AnyRef::decode(&any_bytes).unwrap();
+        assert_eq!(view.unpack::<BytesValue>().unwrap(), big);
+        assert!(matches!(view.unpack_with::<BytesValue>(&DecodeOptions::DEFAULT), Err(DecodeError::SizeLimitExceeded)));
+
+        assert!(!verify_canonical::<BytesValue>(&big.encode()));
+        assert!(verify_canonical_with::<BytesValue>(&big.encode(), &DecodeOptions::ACCOUNT));
+    }
+
+    #[test]
+    fn test_varint_len() {
+        for v in [0u64, 1, 127, 128, 16_383, 16_384, u32::MAX as u64, u64::MAX] {
+            let mut buf = Vec::new();
+            encode_varint(&mut buf, v);
+            assert_eq!(varint_len(v), buf.len(), "value {}", v);
+        }
+    }
+
+    #[test]
+    fn test_encoded_len_helpers() {
+        for field in [1u32, 15, 16, 2047, 2048, (1 << 29) - 1] {
+            let mut buf = Vec::new();
+            encode_key(&mut buf, ((field as u64) << 3) | 2);
+            assert_eq!(key_len(field), buf.len(), "field {}", field);
+        }
+        for len in [0usize, 127, 128, 20_000] {
+            let mut buf = Vec::new();
+            encode_bytes(&mut buf, &vec![0u8; len]);
+            assert_eq!(bytes_len(len), buf.len());
+        }
+        for v in [0i32, -1, 63, -64, 64, i32::MIN, i32::MAX] {
+            let mut buf = Vec::new();
+            encode_zigzag32(&mut buf, v);
+            assert_eq!(zigzag32_len(v), buf.len());
+        }
+        for v in [0i64, -1, i64::MIN, i64::MAX] {
+            let mut buf = Vec::new();
+            encode_zigzag64(&mut buf, v);
+            assert_eq!(zigzag64_len(v), buf.len());
+        }
+    }
+
+    #[test]
+    fn test_well_known_types_roundtrip() {
+        let ts = Timestamp::new(1_704_164_645, 500_000_000);
+        let bytes = ts.encode();
+        assert_eq!(bytes.len(), ts.encoded_len());
+        assert_eq!(Timestamp::decode(&bytes).unwrap(), ts);
+        assert!(Timestamp::default().encode().is_empty());
+        assert!(!Timestamp::new(0, -1).is_valid());
+
+        // Negative nanos are sign-extended like any int32
+        let d = Duration::new(-1, -500);
+        let mut buf = [0u8; 32];
+        let n = d.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(&buf[..n], &d.encode()[..]);
+        assert_eq!(Duration::decode(&buf[..n]).unwrap(), d);
+        assert!(!Duration::new(1, -1).is_valid());
+        assert!(Empty::decode(&[0x08, 0x01]).unwrap().encode().is_empty());
+
+        // Wrappers omit a default value but are still present as a field
+        assert!(UInt64Value::default().encode().is_empty());
+        let mut value = 5u64;
+        UInt64Value::merge_value(&mut value, &[], &DecodeOptions::DEFAULT).unwrap();
+        assert_eq!(value, 5);
+        UInt64Value::merge_value(&mut value, &[0x08, 0x01, 0x08, 0x02], &DecodeOptions::DEFAULT).unwrap();
+        assert_eq!(value, 2);
+        let s = StringValue { value: "hi".into() };
+        assert_eq!(StringValue::decode(&s.encode()).unwrap(), s);
+        assert_eq!(wrapper_value_ref(&s.encode(), 0x0a, decode_str_ref).unwrap(), "hi");
+        assert_eq!(wrapper_value_ref(&[], 0x0a, decode_str_ref).unwrap(), "");
+    }
+
+    #[test]
+    fn test_account_data() {
+        const DISC: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let msg = Duration::new(60, 0);
+        let mut data = encode_account_data(&DISC, &msg);
+        assert_eq!(data.len(), account_data_len(&msg));
+        assert_eq!(&data[..8], &DISC);
+        assert_eq!(&data[8..12], &(msg.encoded_len() as u32).to_le_bytes());
+
+        // Zeroed space after the payload is not part of the message
+        data.resize(64, 0);
+        assert_eq!(decode_account_data::<Duration>(&data).unwrap(), msg);
+        assert_eq!(account_payload(&data).unwrap(), msg.encode());
+        assert!(matches!(decode_account_data::<Duration>(&data[..11]), Err(DecodeError::BufferOverflow)));
+        assert!(matches!(decode_account_data::<Duration>(&data[..13]), Err(DecodeError::BufferOverflow)));
+
+        assert_eq!(Duration::new(-1, -1).encoded_len(), Duration::MAX_ENCODED_LEN);
+        assert_eq!(Timestamp::new(-1, -1).encoded_len(), Timestamp::MAX_ENCODED_LEN);
+    }
+
+    #[test]
+    fn test_bounded_vec() {
+        let mut v = BoundedVec::<u32, 3>::new();
+        assert!(v.is_empty());
+        v.push(1);
+        v.push(2);
+        assert_eq!(v.try_push(3), Ok(()));
+        assert!(v.is_full());
+        assert_eq!(v.try_push(4), Err(4));
+        assert_eq!(v, [1, 2, 3][..]);
+        assert_eq!(v.pop(), Some(3));
+        assert_eq!(v.iter().sum::<u32>(), 3);
+        assert_eq!(BoundedVec::<u32, 2>::try_from(vec![1, 2, 3]), Err(vec![1, 2, 3]));
+
+        // Every element is dropped once, whether it is popped, truncated, moved out or left in the iterator
+        #[derive(Clone)]
+        struct Counted<'a>(&'a core::cell::Cell<usize>);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+        let drops = core::cell::Cell::new(0);
+        let mut v = BoundedVec::<Counted, 4>::new();
+        (0..4).for_each(|_| v.push(Counted(&drops)));
+        drop(v.pop());
+        v.truncate(2);
+        assert_eq!(drops.get(), 2);
+        let mut iter = v.clone().into_iter();
+        drop(iter.next());
+        drop(iter);
+        assert_eq!(drops.get(), 4);
+        drop(v);
+        assert_eq!(drops.get(), 6);
+
+        // Decoding past the capacity is the field's limit, across packed records too
+        let mut out = BoundedVec::<u64, 3>::new();
+        assert_eq!(decode_packed_bounded(&[2, 1, 2], 0, &mut out, 5, decode_varint).unwrap(), 3);
+        assert!(matches!(
+            decode_packed_bounded(&[2, 3, 4], 0, &mut out, 5, decode_varint),
+            Err(DecodeError::LimitExceeded { field: 5 })
+        ));
+        assert!(matches!(push_bounded(&mut out, 9, 5), Err(DecodeError::LimitExceeded { field: 5 })));
+        assert_eq!(out, [1, 2, 3][..]);
+    }
+
+    #[test]
+    fn test_type_discriminator() {
+        const DISC: [u8; 4] = [0xAB, 0xCD, 0x01, 0x02];
+        let msg = Duration::new(60, 0);
+        let data = encode_with_discriminator(&DISC, &msg);
+        assert_eq!(data, [&DISC[..], &msg.encode()].concat());
+        assert_eq!(Duration::decode(strip_discriminator(&DISC, &data).unwrap()).unwrap(), msg);
+        assert!(matches!(strip_discriminator(&[0xAB, 0xCD, 0x01, 0x03], &data), Err(DecodeError::InvalidData(_))));
+        assert!(matches!(strip_discriminator(&DISC, &data[..3]), Err(DecodeError::BufferOverflow)));
+        // An empty message is the discriminator alone
+        assert_eq!(strip_discriminator(&DISC, &DISC).unwrap(), &[] as &[u8]);
+    }
+
+    #[test]
+    fn test_versioned_data() {
+        let msg = Duration::new(60, 0);
+        let data = encode_versioned(3, &msg);
+        assert_eq!(data, [&[3][..], &msg.encode()].concat());
+        let (version, payload) = split_version(&data).unwrap();
+        assert_eq!(version, 3);
+        assert_eq!(Duration::decode(payload).unwrap(), msg);
+        assert!(matches!(split_version(&[]), Err(DecodeError::BufferOverflow)));
+        assert_eq!(DecodeError::UnknownVersion { version: 7 }.to_string(), "protobuf: no migration from version 7");
+        assert_eq!(DecodeError::InvalidFieldNumber(0).to_string(), "protobuf: invalid field number 0");
+    }
+
+    #[test]
+    fn test_event_log() {
+        const DISC: [u8; 8] = [8, 7, 6, 5, 4, 3, 2, 1];
+        let msg = Duration::new(60, 5);
+        let data = encode_event_data(&DISC, &msg);
+        assert_eq!(&data[..8], &DISC);
+        assert_eq!(&data[8..], &msg.encode()[..]);
+
+        let line = format!("{PROGRAM_DATA_LOG_PREFIX}{}", base64_encode(&data));
+        assert_eq!(decode_event_log::<Duration>(&DISC, &line).unwrap().unwrap(), msg);
+        // Several chunks are joined
+        let split = format!("{PROGRAM_DATA_LOG_PREFIX}{} {}", base64_encode(&data[..8]), base64_encode(&data[8..]));
+        assert_eq!(decode_event_log::<Duration>(&DISC, &split).unwrap().unwrap(), msg);
+
+        assert!(decode_event_log::<Duration>(&[0; 8], &line).is_none());
+        assert!(decode_event_log::<Duration>(&DISC, "Program log: hello").is_none());
+        let bad = format!("{PROGRAM_DATA_LOG_PREFIX}***");
+        assert!(matches!(decode_event_log::<Duration>(&DISC, &bad), Some(Err(DecodeError::InvalidData(_)))));
+    }
+
+    #[test]
+    fn test_event_parser() {
+        const DISC: [u8; 8] = [8, 7, 6, 5, 4, 3, 2, 1];
+        #[derive(Debug, PartialEq)]
+        struct Tick(Duration);
+        impl FromEventData for Tick {
+            fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {
+                decode_event_data(&DISC, data).map(|r| r.map(Tick))
+            }
+        }
+        let line = |data: &[u8]| format!("{PROGRAM_DATA_LOG_PREFIX}{}", base64_encode(data));
+        let data = |secs| line(&encode_event_data(&DISC, &Duration::new(secs, 0)));
+        let logs = [
+            "Program Me111 invoke [1]".to_string(),
+            "Program log: success".to_string(),
+            data(1),
+            "Program Other invoke [2]".to_string(),
+            data(2),
+            "Program Me111 invoke [3]".to_string(),
+            data(3),
+            "Program Me111 consumed 100 of 200 compute units".to_string(),
+            "Program Me111 success".to_string(),
+            "Program Other success".to_string(),
+            line(&[0; 9]),
+            data(4),
+            "Program Me111 success".to_string(),
+            data(5),
+            "Program Me111 invoke [1]".to_string(),
+            line(&[&DISC[..], &[0xFF]].concat()),
+            "Program Me111 failed: custom program error: 0x1".to_string(),
+        ];
+        let parser = EventParser::new("Me111");
+        let events: Vec<_> = parser.parse_logs::<Tick, _, _>(&logs).map(|e| e.map_err(|e| e.to_string())).collect();
+        let at = |index, depth, secs| Ok(ParsedEvent { index, depth, event: Tick(Duration::new(secs, 0)) });
+        let bad = Err(DecodeError::BufferOverflow.to_string());
+        assert_eq!(events, [at(2, 1, 1), at(6, 3, 3), at(11, 1, 4), bad]);
+
+        let ix = [&EVENT_IX_TAG[..], &encode_event_data(&DISC, &Duration::new(7, 0))].concat();
+        assert_eq!(parser.parse_instruction::<Tick>("Me111", &ix).unwrap().unwrap(), Tick(Duration::new(7, 0)));
+        assert!(parser.parse_instruction::<Tick>("Other", &ix).is_none());
+        assert!(parser.parse_instruction::<Tick>("Me111", &ix[8..]).is_none());
+    }
+
+    #[test]
+    fn test_any_pack_unpack() {
+        let ts = Timestamp::new(5, 6);
+        let any = Any::pack(&ts);
+        assert_eq!(any.type_url, "type.googleapis.com/google.protobuf.Timestamp");
+        assert!(any.is::<Timestamp>());
+        assert_eq!(any.unpack::<Timestamp>().unwrap(), ts);
+        assert!(matches!(any.unpack::<Duration>(), Err(DecodeError::InvalidData(_))));
+
+        // Only the name after the last '/' is compared
+        let custom = Any { type_url: "example.com/x/google.protobuf.Timestamp".into(), ..any.clone() };
+        assert_eq!(custom.unpack::<Timestamp>().unwrap(), ts);
+
+        let bytes = any.encode();
+        assert_eq!(bytes.len(), any.encoded_len());
+        assert_eq!(Any::decode(&bytes).unwrap(), any);
+        let view = AnyRef::decode(&bytes).unwrap();
+        assert_eq!(view.type_name(), "google.protobuf.Timestamp");
+        assert_eq!(view.unpack::<Timestamp>().unwrap(), ts);
+        assert_eq!(view.to_message(), any);
+    }
+
+    #[test]
+    fn test_encode_message_field_matches_temp_vec() {
+        // Three levels deep; the innermost payload length crosses each
+        // varint width so the backpatch has to shift bytes
+        for payload_len in [0usize, 5, 120, 127, 128, 300, 16_383, 16_384, 20_000] {
+            let payload = vec![0x5Au8; payload_len];
+
+            let mut naive = Vec::new();
+            {
+                let mut level3 = Vec::new();
+                encode_key(&mut level3, 0x0a);
+                encode_bytes(&mut level3, &payload);
+                let mut level2 = Vec::new();
+                encode_key(&mut level2, 0x08);
+                encode_varint(&mut level2, 99);
+                encode_key(&mut level2, 0x12);
+                encode_bytes(&mut level2, &level3);
+                encode_key(&mut naive, 0x1a);
+                encode_bytes(&mut naive, &level2);
+                encode_key(&mut naive, 0x20);
+                encode_varint(&mut naive, 1);
+            }
+
+            let mut in_place = Vec::new();
+            encode_message_field(&mut in_place, 3, |buf| {
+                encode_key(buf, 0x08);
+                encode_varint(buf, 99);
+                encode_message_field(buf, 2, |buf| {
+                    encode_key(buf, 0x0a);
+                    encode_bytes(buf, &payload);
+                });
+            });
+            encode_key(&mut in_place, 0x20);
+            encode_varint(&mut in_place, 1);
+
+            assert_eq!(in_place, naive, "payload length {}", payload_len);
+        }
+    }
+
+    /// A hand-written `Message` over an `Any`, leaving every provided
+    /// method at its default.
+    #[derive(Debug, Default, PartialEq)]
+    struct Opaque(Any);
+
+    impl Message for Opaque {
+        fn encode_into(&self, buf: &mut Vec<u8>) {
+            self.0.encode_into(buf);
+        }
+
+        fn encoded_len(&self) -> usize {
+            self.0.encoded_len()
+        }
+
+        fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+            self.0.write_to(w)
+        }
+
+        fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+            self.0.merge_with(data, opts)
+        }
+    }
+
+    #[test]
+    fn test_reverse_writer_matches_forward() {
+        // The same three levels as above, written last field first after
+        // existing contents, growing from an empty writer
+        for payload_len in [0usize, 5, 127, 128, 300, 16_383, 16_384, 20_000] {
+            let payload = vec![0x5Au8; payload_len];
+            let mut forward = vec![0xEE];
+            encode_message_field(&mut forward, 3, |buf| {
+                encode_key(buf, 0x08);
+                encode_varint(buf, 99);
+                encode_message_field(buf, 2, |buf| {
+                    encode_key(buf, 0x0a);
+                    encode_bytes(buf, &payload);
+                });
+            });
+            encode_key(&mut forward, 0x20);
+            encode_int32(&mut forward, -1);
+
+            let mut reverse = vec![0xEE];
+            {
+                let mut w = ReverseWriter::new(&mut reverse);
+                w.write_int32(-1);
+                w.write_key(0x20);
+                w.write_nested(3, |w| {
+                    w.write_nested(2, |w| {
+                        w.write_bytes(&payload);
+                        w.write_key(0x0a);
+                    });
+                    w.write_varint(99);
+                    w.write_key(0x08);
+                });
+                assert_eq!(w.written(), forward.len() - 1);
+            }
+            assert_eq!(reverse, forward, "payload length {}", payload_len);
+        }
+
+        // Sized exactly, through the scratch-buffer default and the
+        // well-known types' own `write_reverse`
+        let opaque = Opaque(Any { type_url: "t".to_string(), value: vec![7; 200] });
+        let field_len = key_len(5) + bytes_len(opaque.encoded_len());
         let mut buf = Vec::new();
-        encode_fixed64(&mut buf, 42);
-        let new_pos = skip_field(&buf, 0, 1).unwrap();
-        assert_eq!(new_pos, 8);
+        ReverseWriter::with_capacity(&mut buf, field_len).write_message(5, &opaque);
+        assert_eq!(buf.len(), field_len);
+        assert_eq!(buf[field_len - opaque.encoded_len()..], opaque.encode()[..]);
+        let any = Any { type_url: "t".to_string(), value: vec![1, 2] };
+        let mut buf = Vec::new();
+        any.write_reverse(&mut ReverseWriter::new(&mut buf));
+        Timestamp::new(-1, 5).write_reverse(&mut ReverseWriter::new(&mut buf));
+        UInt64Value::write_value_reverse(&300, &mut ReverseWriter::new(&mut buf));
+        let mut expected = any.encode();
+        expected.extend(Timestamp::new(-1, 5).encode());
+        expected.extend(UInt64Value { value: 300 }.encode());
+        assert_eq!(buf, expected);
+    }
 
-        // Length-delimited
+    #[test]
+    fn test_decode_limits() {
+        let opts = DecodeOptions { max_alloc: 16, max_repeated_len: 2, ..DecodeOptions::DEFAULT };
+
+        // Payload fits in the buffer but claims more than the cap
         let mut buf = Vec::new();
-        encode_string(&mut buf, "hello");
-        let new_pos = skip_field(&buf, 0, 2).unwrap();
-        assert_eq!(new_pos, buf.len());
+        encode_bytes(&mut buf, &[0xAB; 17]);
+        assert!(matches!(decode_bytes_with(&buf, 0, &opts), Err(DecodeError::SizeLimitExceeded)));
+        assert!(matches!(decode_string_with(&buf, 0, &opts), Err(DecodeError::SizeLimitExceeded)));
+        assert_eq!(decode_bytes(&buf, 0).unwrap().0.len(), 17);
 
-        // Fixed32
+        // A huge claimed length is refused before the buffer is consulted
+        let mut huge = Vec::new();
+        encode_varint(&mut huge, u32::MAX as u64);
+        assert!(matches!(decode_bytes_with(&huge, 0, &opts), Err(DecodeError::SizeLimitExceeded)));
+
+        let mut at_cap = Vec::new();
+        encode_bytes(&mut at_cap, &[0xAB; 16]);
+        assert_eq!(decode_bytes_with(&at_cap, 0, &opts).unwrap().0.len(), 16);
+
+        // A string field over the cap, reached through merge_with
+        let mut any = Vec::new();
+        encode_key(&mut any, 0x0a);
+        encode_string(&mut any, "seventeen bytes!!");
+        assert!(matches!(Any::decode_with(&any, &opts), Err(DecodeError::SizeLimitExceeded)));
+        assert!(Any::decode(&any).is_ok());
+
+        // (solana.max_len) bounds, checked ahead of max_alloc and the buffer
+        assert_eq!(decode_bytes_bounded(&at_cap, 0, &opts, 16, 4).unwrap().0.len(), 16);
+        assert!(matches!(decode_bytes_bounded(&at_cap, 0, &opts, 15, 4), Err(DecodeError::LimitExceeded { field: 4 })));
+        assert!(matches!(decode_bytes_bounded(&huge, 0, &opts, 64, 4), Err(DecodeError::LimitExceeded { field: 4 })));
+        assert!(matches!(decode_bytes_bounded(&buf, 0, &opts, 64, 4), Err(DecodeError::SizeLimitExceeded)));
+        assert_eq!(within_max_len("abc", 3, 1).unwrap(), "abc");
+        assert!(matches!(within_max_len(&b"abcd"[..], 3, 1), Err(DecodeError::LimitExceeded { field: 1 })));
+    }
+
+    #[test]
+    fn test_recursion_limit() {
+        let flat = DecodeOptions { max_depth: 0, ..DecodeOptions::DEFAULT };
+        let one = DecodeOptions { max_depth: 1, ..DecodeOptions::DEFAULT };
+        // Messages without sub-messages are unaffected
+        assert!(Timestamp::decode_with(&[0x08, 0x01], &flat).is_ok());
+
+        assert_eq!(one.nested().unwrap().max_depth, 0);
+        assert!(matches!(flat.nested(), Err(DecodeError::RecursionLimitExceeded)));
+        assert_eq!(DecodeError::RecursionLimitExceeded.kind(), DecodeErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_verify_canonical() {
+        let msg = Timestamp::new(300, 1);
+        let canonical = msg.encode_canonical();
+        assert_eq!(canonical, msg.encode());
+        assert!(verify_canonical::<Timestamp>(&canonical));
+        assert!(verify_canonical::<Timestamp>(&[]));
+
+        // Same message, fields out of tag order
+        assert!(!verify_canonical::<Timestamp>(&[0x10, 0x01, 0x08, 0xac, 0x02]));
+        // Overlong varint, explicit default, repeated singular field
+        assert!(!verify_canonical::<Timestamp>(&[0x08, 0x81, 0x00]));
+        assert!(!verify_canonical::<Timestamp>(&[0x08, 0x00]));
+        assert!(!verify_canonical::<Timestamp>(&[0x08, 0x01, 0x08, 0x01]));
+        // Unknown field, malformed input
+        assert!(!verify_canonical::<Timestamp>(&[0x08, 0x01, 0x20, 0x01]));
+        assert!(!verify_canonical::<Timestamp>(&[0x08]));
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        let mut unknown = UnknownFields::default();
+        assert!(unknown.is_empty());
+        unknown.push(0x20, &[0x96, 0x01]);
+        unknown.push_raw(&[0x2a, 0x02, b'h', b'i']);
+        unknown.push(0x35, &1u32.to_le_bytes());
+
+        let fields: Vec<_> = unknown.iter().collect();
+        assert_eq!(
+            fields,
+            vec![(0x20, &[0x96, 0x01][..]), (0x2a, &[0x02, b'h', b'i'][..]), (0x35, &[1, 0, 0, 0][..])]
+        );
+
+        let mut buf = vec![0x08, 0x01];
+        unknown.encode_into(&mut buf);
+        assert_eq!(&buf[2..], unknown.as_bytes());
+        assert_eq!(unknown.encoded_len(), 12);
+
+        let mut out = [0u8; 12];
+        let mut w = SliceWriter::new(&mut out);
+        unknown.write_to(&mut w).unwrap();
+        assert_eq!(&out[..], unknown.as_bytes());
+        let mut short = [0u8; 4];
+        assert!(matches!(unknown.write_to(&mut SliceWriter::new(&mut short)), Err(DecodeError::BufferTooSmall)));
+
+        unknown.clear();
+        assert_eq!(unknown.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_extensions() {
+        const INFO: ExtensionInfo =
+            ExtensionInfo { name: "t.priority", extendee: "t.Base", number: 100, wire_type: 0, repeated: false, packed: false };
+        const PRIORITY: Extension<(), u32> = Extension::new(
+            INFO,
+            |value, data, pos, _| {
+                let (v, new_pos) = decode_varint(data, pos)?;
+                *value = v as u32;
+                Ok(new_pos)
+            },
+            |value, buf| {
+                encode_key(buf, 0x320);
+                encode_varint(buf, *value as u64);
+            },
+        );
+        const TAGS: Extension<(), Vec<u32>> = Extension::new(
+            ExtensionInfo { name: "t.tags", number: 101, repeated: true, packed: true, ..INFO },
+            |values, data, pos, tag| {
+                if tag & 0x07 == 2 {
+                    return decode_packed_uint32(data, pos, values);
+                }
+                let (v, new_pos) = decode_varint(data, pos)?;
+                values.push(v as u32);
+                Ok(new_pos)
+            },
+            |values, buf| {
+                for value in values {
+                    encode_key(buf, 0x328);
+                    encode_varint(buf, *value as u64);
+                }
+            },
+        );
+
+        let mut ext = ExtensionSet::new();
+        assert_eq!(ext.get(&PRIORITY).unwrap(), None);
+        // The last occurrence of a singular value wins; repeated values
+        // are collected across packed and unpacked runs
+        ext.push_raw(&[0xa0, 0x06, 0x01]);
+        ext.push_raw(&[0xa8, 0x06, 0x07, 0xaa, 0x06, 0x02, 0x08, 0x09]);
+        ext.push_raw(&[0xa0, 0x06, 0x05]);
+        assert!(ext.contains(100) && ext.contains(101) && !ext.contains(102));
+        assert_eq!(ext.get(&PRIORITY).unwrap(), Some(5));
+        assert_eq!(ext.get(&TAGS).unwrap(), Some(vec![7, 8, 9]));
+        assert_eq!(ext.iter().count(), 4);
+
+        ext.set(&PRIORITY, &300);
+        assert_eq!(ext.as_bytes(), &[0xa8, 0x06, 0x07, 0xaa, 0x06, 0x02, 0x08, 0x09, 0xa0, 0x06, 0xac, 0x02]);
+        assert_eq!(ext.get(&PRIORITY).unwrap(), Some(300));
+        ext.remove(101);
+        assert_eq!(ext.get(&TAGS).unwrap(), None);
+        assert_eq!(ext.encoded_len(), 4);
+
+        // A value with the wrong wire type is an error, not a default
+        ext.push_raw(&[0xa5, 0x06, 0, 0, 0, 0]);
+        assert!(matches!(
+            ext.get(&PRIORITY),
+            Err(DecodeError::FieldWireTypeMismatch { name: "t.priority", field: 100, expected: 0, found: 5, .. })
+        ));
+
+        assert!(in_extension_range(100, &[(10, 20), (100, 200)]));
+        assert!(!in_extension_range(200, &[(10, 20), (100, 200)]));
+
+        let mut registry = ExtensionRegistry::new();
+        registry.register(&[PRIORITY.info, TAGS.info]);
+        assert_eq!(registry.find("t.Base", 101).map(|info| info.name), Some("t.tags"));
+        assert_eq!(registry.find("t.Other", 101), None);
+        assert_eq!(registry.find_by_name("t.priority").map(|info| info.number), Some(100));
+        assert_eq!(registry.extensions_of("t.Base").count(), 2);
+    }
+
+    #[test]
+    fn test_decoder_resume() {
+        // scalar = 1, items = 2 and an `Any` as message field 3
+        let inner = Any { type_url: "x".to_string(), value: vec![7] };
+        let mut data = Vec::new();
+        encode_key(&mut data, 0x08);
+        encode_varint(&mut data, 300);
+        encode_key(&mut data, 0x10);
+        encode_varint(&mut data, 1);
+        encode_key(&mut data, 0x1a);
+        encode_bytes(&mut data, &inner.encode());
+
+        let mut decoder = Decoder::new(&data);
+        let first = decoder.next_field().unwrap().unwrap();
+        assert_eq!((first.number(), first.wire_type()), (1, 0));
+        assert_eq!(first.varint().unwrap(), 300);
+        assert_eq!(first.raw, &[0x08, 0xac, 0x02]);
+        assert!(matches!(first.bytes(), Err(DecodeError::WireTypeMismatch { expected: 2, found: 0 })));
+
+        // Pick up in a later "instruction" from the saved position
+        let saved = decoder.position();
+        let mut decoder = Decoder::resume(&data, saved).unwrap();
+        assert_eq!(decoder.next_field().unwrap().unwrap().varint().unwrap(), 1);
+        let field = decoder.next_field().unwrap().unwrap();
+        assert_eq!(field.number(), 3);
+        assert_eq!(field.message::<Any>().unwrap(), inner);
+        let mut sub = field.decoder().unwrap();
+        assert_eq!(sub.next_field().unwrap().unwrap().str().unwrap(), "x");
+        assert_eq!(sub.next_field().unwrap().unwrap().bytes().unwrap(), [7]);
+        assert!(sub.next_field().unwrap().is_none());
+        assert!(decoder.is_done() && decoder.next_field().unwrap().is_none());
+        assert_eq!(decoder.remaining(), 0);
+
+        // The raw fields reassemble the buffer
+        let mut decoder = Decoder::new(&data);
+        let mut raw = Vec::new();
+        while let Some(field) = decoder.next_field().unwrap() {
+            raw.extend_from_slice(field.raw);
+        }
+        assert_eq!(raw, data);
+
+        // A truncated field fails without moving past it
+        let mut truncated = Decoder::new(&data[..data.len() - 1]);
+        truncated.next_field().unwrap();
+        truncated.next_field().unwrap();
+        let at = truncated.position();
+        assert!(matches!(truncated.next_field(), Err(DecodeError::BufferOverflow)));
+        assert_eq!(truncated.position(), at);
+        assert!(matches!(Decoder::resume(&data, data.len() + 1), Err(DecodeError::BufferOverflow)));
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_borsh_layout_well_known_types() {
+        let ts = Timestamp::new(-2, 5);
+        assert_eq!(
+            borsh::to_vec(&ts).unwrap(),
+            [&(-2i64).to_le_bytes()[..], &5i32.to_le_bytes()].concat()
+        );
+        assert_eq!(borsh::to_vec(&UInt64Value { value: 1 }).unwrap(), 1u64.to_le_bytes());
+
+        let any = Any { type_url: "t/a.B".into(), value: vec![9] };
+        let bytes = borsh::to_vec(&any).unwrap();
+        assert_eq!(bytes, [&[5, 0, 0, 0][..], b"t/a.B", &[1, 0, 0, 0, 9]].concat());
+        assert_eq!(borsh_to_protobuf::<Any>(&bytes).unwrap(), any.encode());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_pod_from_bytes() {
+        #[repr(C, packed)]
+        #[derive(Clone, Copy)]
+        struct Pair {
+            amount: u64,
+            flag: u8,
+        }
+        unsafe impl bytemuck::Zeroable for Pair {}
+        unsafe impl bytemuck::Pod for Pair {}
+
+        // Packed, so an odd offset works, and trailing bytes are left alone
+        let mut data = [0xEE, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0xEE, 0xEE];
+        let pair: &Pair = pod_from_bytes(&data[1..]).unwrap();
+        assert_eq!({ pair.amount }, 1);
+        assert_eq!(pair.flag, 1);
+
+        let pair: &mut Pair = pod_from_bytes_mut(&mut data[1..]).unwrap();
+        pair.amount = 300;
+        assert_eq!(data, [0xEE, 0x2C, 1, 0, 0, 0, 0, 0, 0, 1, 0xEE, 0xEE]);
+
+        assert!(matches!(pod_from_bytes::<Pair>(&data[..8]), Err(DecodeError::BufferOverflow)));
+        // An aligned type at a misaligned offset is an error, not a panic
+        let words = [0u64; 2];
+        let bytes: &[u8] = bytemuck::bytes_of(&words);
+        assert!(matches!(pod_from_bytes::<u64>(&bytes[1..]), Err(DecodeError::InvalidData(_))));
+    }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn test_copy_prost_field() {
+        // The field's key, then the value prost left in the buffer
+        let copy = |key: u64, rest: &[u8]| {
+            let mut buf = rest;
+            let mut out = Vec::new();
+            copy_prost_field(&mut out, key, &mut buf, DecodeOptions::DEFAULT.max_depth).map(|_| (out, buf.len()))
+        };
+        assert_eq!(copy(0x08, &[0xAC, 0x02, 0x07]).unwrap(), (vec![0x08, 0xAC, 0x02], 1));
+        assert_eq!(copy(0x12, &[2, b'h', b'i']).unwrap(), (vec![0x12, 2, b'h', b'i'], 0));
+        assert_eq!(copy(0x1D, &[1, 2, 3, 4, 5]).unwrap(), (vec![0x1D, 1, 2, 3, 4], 1));
+
+        // A group runs to its own end key, past nested groups
+        let group = [0x08, 1, 0x13, 0x14, 0x0C, 0x08];
+        assert_eq!(copy(0x0B, &group).unwrap(), (vec![0x0B, 0x08, 1, 0x13, 0x14, 0x0C], 1));
+        assert!(copy(0x0B, &[0x14]).is_err());
+        assert!(copy(0x0C, &[]).is_err());
+
+        assert!(copy(0x12, &[5, b'a']).is_err());
+        assert!(copy(0x08, &[0x80]).is_err());
+    }
+
+    /// `message ReflectSample { repeated sint32 deltas = 1; map<uint32, string> names = 2;
+    ///  oneof pick { Timestamp at = 3; bool flag = 4; } }`
+    #[cfg(feature = "reflect")]
+    static REFLECT_SAMPLE: MessageDescriptor = MessageDescriptor {
+        full_name: "test.ReflectSample",
+        fields: &[
+            FieldDescriptor::new(1, "deltas", "deltas", FieldType::Sint32, FieldLabel::Repeated),
+            FieldDescriptor::new(2, "names", "names", FieldType::String, FieldLabel::Map(FieldType::Uint32)),
+            FieldDescriptor::new(3, "at", "at", FieldType::Message, FieldLabel::Singular)
+                .with_oneof("pick")
+                .with_message(Timestamp::descriptor),
+            FieldDescriptor::new(4, "flag", "flag", FieldType::Bool, FieldLabel::Singular).with_oneof("pick"),
+        ],
+    };
+
+    #[cfg(feature = "reflect")]
+    #[test]
+    fn test_reflect_decode() {
+        let depth = DecodeOptions::DEFAULT.max_depth;
+        let deltas = |values: &[i32]| Value::List(values.iter().map(|v| Value::I32(*v)).collect());
+
+        // Packed and unpacked records of one repeated field append
+        let mut data = Vec::new();
+        encode_key(&mut data, 0x0a);
+        encode_bytes(&mut data, &[0x01, 0x04]);
+        encode_sint32_field(&mut data, 1, -3);
+        let values = reflect_decode(&REFLECT_SAMPLE, &data, None, depth).unwrap();
+        assert_eq!(values, [(1, deltas(&[-1, 2, -3]))]);
+
+        // Map keys keep their last value; entries may omit key or value
+        let mut data = Vec::new();
+        encode_key(&mut data, 0x12);
+        encode_bytes(&mut data, &[0x08, 0x07, 0x12, 0x01, b'a']);
+        encode_key(&mut data, 0x12);
+        encode_bytes(&mut data, &[0x12, 0x01, b'z']);
+        encode_key(&mut data, 0x12);
+        encode_bytes(&mut data, &[0x08, 0x07, 0x12, 0x01, b'b']);
+        let values = reflect_decode(&REFLECT_SAMPLE, &data, None, depth).unwrap();
+        let entry = |k: u32, v: &str| (Value::U32(k), Value::String(v.into()));
+        assert_eq!(values, [(2, Value::Map(vec![entry(7, "b"), entry(0, "z")]))]);
+
+        // A oneof member replaces the others; sub-message records merge
+        let mut data = Vec::new();
+        encode_key(&mut data, 0x1a);
+        encode_bytes(&mut data, &Timestamp::new(5, 0).encode());
+        encode_key(&mut data, 0x20);
+        encode_bool(&mut data, true);
+        let values = reflect_decode(&REFLECT_SAMPLE, &data, None, depth).unwrap();
+        assert_eq!(values, [(4, Value::Bool(true))]);
+        encode_key(&mut data, 0x1a);
+        encode_bytes(&mut data, &[0x08, 0x05]);
+        encode_key(&mut data, 0x1a);
+        encode_bytes(&mut data, &[0x10, 0x09]);
+        let values = reflect_decode(&REFLECT_SAMPLE, &data, Some(3), depth).unwrap();
+        assert_eq!(values, [(3, Value::Message(vec![(1, Value::I64(5)), (2, Value::I32(9))]))]);
+
+        // Encoding the decoded values decodes back to them
+        let mut back = Vec::new();
+        for (number, value) in &values {
+            reflect_encode_field(REFLECT_SAMPLE.field(*number).unwrap(), value, &mut back, depth).unwrap();
+        }
+        assert_eq!(reflect_decode(&REFLECT_SAMPLE, &back, None, depth).unwrap(), values);
+
+        assert!(reflect_decode(&REFLECT_SAMPLE, &[0x20, 0x01], None, 0).is_err());
+        assert!(reflect_decode(&REFLECT_SAMPLE, &[0x25, 0, 0, 0, 0], None, depth).is_err());
+        let mut out = Vec::new();
+        assert!(reflect_encode_field(&REFLECT_SAMPLE.fields[0], &Value::I32(1), &mut out, depth).is_err());
+        assert!(reflect_encode_field(&REFLECT_SAMPLE.fields[3], &Value::U32(1), &mut out, depth).is_err());
+    }
+
+    #[test]
+    fn test_validation_error() {
+        let err = ValidationError::new("fee_bps", ValidationRule::Max(10_000));
+        assert_eq!(err.to_string(), "fee_bps: greater than 10000");
+        let err = err.within_element("history", 2).within("vault");
+        assert_eq!(err.path, "vault.history[2].fee_bps");
+        assert_eq!(err.rule, ValidationRule::Max(10_000));
+
+        let err = ValidationError::element("labels", "a\"b", ValidationRule::Prefix("x-"));
+        assert_eq!(err.to_string(), r#"labels["a\"b"]: does not start with "x-""#);
+        assert_eq!(ValidationError::new("owner", ValidationRule::NonDefault).to_string(), "owner: must be set");
+    }
+
+    /// A length-delimited record of field `number`.
+    #[cfg(feature = "reflect")]
+    fn delimited(buf: &mut Vec<u8>, number: u64, payload: &[u8]) {
+        encode_key(buf, (number << 3) | 2);
+        encode_bytes(buf, payload);
+    }
+
+    /// A `FieldDescriptorProto`.
+    #[cfg(feature = "reflect")]
+    fn field_proto(name: &str, number: u64, label: u64, field_type: u64, type_name: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        delimited(&mut out, 1, name.as_bytes());
+        for (n, v) in [(3, number), (4, label), (5, field_type)] {
+            encode_key(&mut out, n << 3);
+            encode_varint(&mut out, v);
+        }
+        if !type_name.is_empty() {
+            delimited(&mut out, 6, type_name.as_bytes());
+        }
+        out
+    }
+
+    /// The descriptor set of `syntax = "proto3"; package t;
+    /// enum Role { ROLE_UNSPECIFIED = 0; ROLE_ADMIN = 1; }
+    /// message Address { string city = 1; }
+    /// message Profile { uint64 id = 1; string display_name = 2; Role role = 3;
+    ///  map<string, uint32> scores = 4; repeated Address addresses = 5;
+    ///  optional int32 delta = 6; Address home = 7; oneof pick { bool flag = 8; } }`
+    #[cfg(feature = "reflect")]
+    fn sample_descriptor_set() -> Vec<u8> {
+        let mut role = Vec::new();
+        delimited(&mut role, 1, b"Role");
+        for (name, number) in [("ROLE_UNSPECIFIED", 0), ("ROLE_ADMIN", 1)] {
+            let mut value = Vec::new();
+            delimited(&mut value, 1, name.as_bytes());
+            encode_key(&mut value, 0x10);
+            encode_varint(&mut value, number);
+            delimited(&mut role, 2, &value);
+        }
+
+        let mut address = Vec::new();
+        delimited(&mut address, 1, b"Address");
+        delimited(&mut address, 2, &field_proto("city", 1, 1, 9, ""));
+
+        let mut entry = Vec::new();
+        delimited(&mut entry, 1, b"ScoresEntry");
+        delimited(&mut entry, 2, &field_proto("key", 1, 1, 9, ""));
+        delimited(&mut entry, 2, &field_proto("value", 2, 1, 13, ""));
+        delimited(&mut entry, 7, &[0x38, 0x01]);
+
+        let mut delta = field_proto("delta", 6, 1, 5, "");
+        delta.extend_from_slice(&[0x48, 0x01, 0x88, 0x01, 0x01]);
+        let mut flag = field_proto("flag", 8, 1, 8, "");
+        flag.extend_from_slice(&[0x48, 0x00]);
+        let mut profile = Vec::new();
+        delimited(&mut profile, 1, b"Profile");
+        delimited(&mut profile, 2, &field_proto("id", 1, 1, 4, ""));
+        delimited(&mut profile, 2, &field_proto("display_name", 2, 1, 9, ""));
+        delimited(&mut profile, 2, &field_proto("role", 3, 1, 14, ".t.Role"));
+        delimited(&mut profile, 2, &field_proto("scores", 4, 3, 11, ".t.Profile.ScoresEntry"));
+        delimited(&mut profile, 2, &field_proto("addresses", 5, 3, 11, ".t.Address"));
+        delimited(&mut profile, 2, &delta);
+        delimited(&mut profile, 2, &field_proto("home", 7, 1, 11, ".t.Address"));
+        delimited(&mut profile, 2, &flag);
+        delimited(&mut profile, 3, &entry);
+        delimited(&mut profile, 8, &[0x0a, 4, b'p', b'i', b'c', b'k']);
+        delimited(&mut profile, 8, &[0x0a, 6, b'_', b'd', b'e', b'l', b't', b'a']);
+
+        let mut file = Vec::new();
+        delimited(&mut file, 1, b"t.proto");
+        delimited(&mut file, 2, b"t");
+        delimited(&mut file, 4, &address);
+        delimited(&mut file, 4, &profile);
+        delimited(&mut file, 5, &role);
+        delimited(&mut file, 12, b"proto3");
+        let mut set = Vec::new();
+        delimited(&mut set, 1, &file);
+        set
+    }
+
+    #[cfg(feature = "reflect")]
+    #[test]
+    fn test_dynamic_message() {
+        let pool = DescriptorPool::decode(&sample_descriptor_set()).unwrap();
+        let names: Vec<_> = pool.messages().iter().map(|m| m.full_name.as_str()).collect();
+        assert_eq!(names, ["t.Address", "t.Profile", "t.Profile.ScoresEntry"]);
+        let profile = pool.message("t.Profile").unwrap();
+        assert_eq!(profile.field(4).unwrap().label, FieldLabel::Map(FieldType::String));
+        assert_eq!(profile.field(4).unwrap().field_type, FieldType::Uint32);
+        assert_eq!(profile.field(6).unwrap().label, FieldLabel::Optional);
+        assert_eq!(profile.field(6).unwrap().oneof, None);
+        assert_eq!(profile.field(8).unwrap().oneof.as_deref(), Some("pick"));
+        assert_eq!(profile.field_by_name("displayName").unwrap().number, 2);
+
+        let city = |name: &str| {
+            let mut out = Vec::new();
+            delimited(&mut out, 1, name.as_bytes());
+            out
+        };
+        let mut data = Vec::new();
+        encode_key(&mut data, 0x08);
+        encode_varint(&mut data, 7);
+        encode_key(&mut data, 0x18);
+        encode_varint(&mut data, 1);
+        delimited(&mut data, 4, &[0x0a, 1, b'a', 0x10, 3]);
+        delimited(&mut data, 5, &city("Lisbon"));
+        delimited(&mut data, 5, &city("Porto"));
+        delimited(&mut data, 7, &city("Faro"));
+        encode_key(&mut data, 0x40);
+        encode_bool(&mut data, true);
+        encode_key(&mut data, 0x9a);
+        encode_bytes(&mut data, b"skipped");
+
+        let msg = DynamicMessage::decode(&pool, "t.Profile", &data).unwrap();
+        assert_eq!(msg.get_u64("id"), Some(7));
+        assert_eq!(msg.get_str("display_name"), Some(""));
+        assert_eq!(msg.get_enum("role"), Some(1));
+        assert_eq!(msg.get_enum_name("role"), Some("ROLE_ADMIN"));
+        assert_eq!(msg.get_map("scores"), Some(&[(Value::String("a".into()), Value::U32(3))][..]));
+        let addresses = msg.get_messages("addresses").unwrap();
+        let cities: Vec<_> = addresses.iter().map(|a| a.get_str("city").unwrap()).collect();
+        assert_eq!(cities, ["Lisbon", "Porto"]);
+        assert_eq!(msg.get_i32("delta"), None);
+        assert_eq!(msg.get_message("home").unwrap().get_str("city"), Some("Faro"));
+        assert_eq!(msg.get_bool("flag"), Some(true));
+        assert_eq!(msg.get_u32("id"), None);
+        assert_eq!(msg.get_u64("missing"), None);
+        let order: Vec<_> = msg.fields().map(|(field, _)| field.number).collect();
+        assert_eq!(order, [1, 2, 3, 4, 5, 7, 8]);
+        assert!(format!("{msg:?}").starts_with("t.Profile { id: U64(7), display_name: String(\"\")"));
+
+        assert!(DynamicMessage::decode(&pool, "t.Missing", &data).is_err());
+        assert!(DynamicMessage::decode(&pool, "t.Profile", &[0x08]).is_err());
+
+        // Every referenced type must be in the set
+        let mut profile = Vec::new();
+        delimited(&mut profile, 1, b"Profile");
+        delimited(&mut profile, 2, &field_proto("home", 7, 1, 11, ".t.Address"));
+        let mut file = Vec::new();
+        delimited(&mut file, 4, &profile);
+        let mut set = Vec::new();
+        delimited(&mut set, 1, &file);
+        assert!(DescriptorPool::decode(&set).is_err());
+    }
+
+    /// Mirrors the generated JSON codec for
+    /// `message JsonSample { uint64 account_id = 1; string display_name = 2;
+    ///  double ratio = 3; optional int32 delta = 4; bytes raw_data = 5; }`
+    #[cfg(feature = "json")]
+    #[derive(Debug, Default, PartialEq)]
+    struct JsonSample {
+        account_id: u64,
+        display_name: String,
+        ratio: f64,
+        delta: Option<i32>,
+        raw_data: Vec<u8>,
+    }
+
+    #[cfg(feature = "json")]
+    impl JsonSample {
+        fn to_json(&self) -> String {
+            let mut out = JsonWriter::new();
+            out.begin_object();
+            if self.account_id != 0 {
+                out.key("accountId");
+                out.write_u64(self.account_id);
+            }
+            if !self.display_name.is_empty() {
+                out.key("displayName");
+                out.write_str(&self.display_name);
+            }
+            if self.ratio != 0.0 {
+                out.key("ratio");
+                out.write_f64(self.ratio);
+            }
+            if let Some(elem) = &self.delta {
+                out.key("delta");
+                out.write_i32(*elem);
+            }
+            if !self.raw_data.is_empty() {
+                out.key("rawData");
+                out.write_bytes(&self.raw_data);
+            }
+            out.end_object();
+            out.finish()
+        }
+
+        fn from_json(json: &str) -> Result<Self, DecodeError> {
+            let mut msg = Self::default();
+            for (key, value) in json_object(&parse_json(json)?)? {
+                if value.is_null() {
+                    continue;
+                }
+                match key.as_str() {
+                    "accountId" | "account_id" => msg.account_id = json_to_u64(value)?,
+                    "displayName" | "display_name" => msg.display_name = json_to_string(value)?,
+                    "ratio" => msg.ratio = json_to_f64(value)?,
+                    "delta" => msg.delta = Some(json_to_i32(value)?),
+                    "rawData" | "raw_data" => msg.raw_data = json_to_bytes(value)?,
+                    _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                }
+            }
+            Ok(msg)
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_camel_case_golden() {
+        let msg = JsonSample {
+            account_id: u64::MAX,
+            display_name: "a \"b\"".to_string(),
+            ratio: f64::INFINITY,
+            delta: None,
+            raw_data: vec![0xDE, 0xAD, 0xBE, 0xEF],
+        };
+        assert_eq!(
+            msg.to_json(),
+            r#"{"accountId":"18446744073709551615","displayName":"a \"b\"","ratio":"Infinity","rawData":"3q2+7w=="}"#
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_roundtrip() {
+        let msg = JsonSample {
+            account_id: 42,
+            display_name: "vault 🌍".to_string(),
+            ratio: 0.25,
+            delta: Some(0),
+            raw_data: vec![1, 2, 3],
+        };
+        let back = JsonSample::from_json(&msg.to_json()).unwrap();
+        assert_eq!(back, msg);
+
+        // Unset optional is omitted, set-to-zero optional is kept
+        assert!(!JsonSample::default().to_json().contains("delta"));
+        assert!(JsonSample { delta: Some(0), ..Default::default() }.to_json().contains("\"delta\":0"));
+
+        // Proto field names, numeric 64-bit values and NaN are accepted
+        let parsed = JsonSample::from_json(r#"{ "account_id": 7, "ratio": "NaN", "delta": null }"#).unwrap();
+        assert_eq!(parsed.account_id, 7);
+        assert!(parsed.ratio.is_nan());
+        assert_eq!(parsed.delta, None);
+
+        assert!(JsonSample::from_json(r#"{"unknown":1}"#).is_err());
+        assert!(JsonSample::from_json(r#"{"accountId":"-1"}"#).is_err());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_primitives() {
+        for data in [&b""[..], b"f", b"fo", b"foo", b"foob", &[0xFF, 0x00, 0xFE]] {
+            assert_eq!(base64_decode(&base64_encode(data)).unwrap(), data);
+        }
+        assert_eq!(base64_decode("-_8").unwrap(), vec![0xFB, 0xFF]);
+
+        let v = parse_json(r#"{"a":[1,"x\u00e9\ud83c\udf0d",true,null],"b":{}}"#).unwrap();
+        let fields = json_object(&v).unwrap();
+        let items = json_array(&fields[0].1).unwrap();
+        assert_eq!(json_to_i32(&items[0]).unwrap(), 1);
+        assert_eq!(json_to_string(&items[1]).unwrap(), "xé🌍");
+        assert!(json_to_bool(&items[2]).unwrap());
+        assert!(items[3].is_null());
+        assert!(parse_json(r#"{"a":1"#).is_err());
+        assert!(parse_json("[1] 2").is_err());
+
+        let names = [("ZERO", 0), ("ONE", 1)];
+        let mut out = JsonWriter::new();
+        out.begin_array();
+        out.write_enum(1, &names);
+        out.write_enum(7, &names);
+        out.end_array();
+        assert_eq!(out.finish(), r#"["ONE",7]"#);
+        assert_eq!(json_to_enum(&JsonValue::String("ONE".into()), &names).unwrap(), 1);
+        assert_eq!(json_to_enum(&JsonValue::Number("9".into()), &names).unwrap(), 9);
+
+        for (text, n) in [("1e3", 1000), ("7.0", 7), ("-2E1", -20)] {
+            assert_eq!(json_to_i32(&JsonValue::Number(text.into())).unwrap(), n);
+            assert_eq!(json_to_i64(&JsonValue::String(text.into())).unwrap(), n as i64);
+        }
+        assert_eq!(json_to_u64(&JsonValue::String("18446744073709551615".into())).unwrap(), u64::MAX);
+        for bad in ["1.5", "2147483648", "1e400", "0x10", ""] {
+            assert!(json_to_i32(&JsonValue::Number(bad.into())).is_err(), "{}", bad);
+        }
+        assert!(json_to_u32(&JsonValue::Number("-1".into())).is_err());
+        assert!(json_to_u64(&JsonValue::Number("18446744073709551616".into())).is_err());
+        assert!(json_to_f32(&JsonValue::Number("3.4e38".into())).is_ok());
+        assert!(json_to_f32(&JsonValue::Number("3.5e38".into())).is_err());
+        assert!(json_to_f32(&JsonValue::String("-Infinity".into())).unwrap().is_infinite());
+
+        assert_eq!(json_map_key::<i64>("-7").unwrap(), -7);
+        assert!(json_map_key::<bool>("true").unwrap());
+        assert_eq!(json_map_key::<String>("k").unwrap(), "k");
+        assert!(json_map_key::<u32>("x").is_err());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_well_known_types() {
+        fn ts_json(seconds: i64, nanos: i32) -> String {
+            let mut out = JsonWriter::new();
+            Timestamp::new(seconds, nanos).write_json(&mut out);
+            out.finish()
+        }
+        fn parse_ts(text: &str) -> Result<Timestamp, DecodeError> {
+            Timestamp::from_json_value(&JsonValue::String(text.into()))
+        }
+        assert_eq!(ts_json(0, 0), r#""1970-01-01T00:00:00Z""#);
+        assert_eq!(ts_json(1_704_164_645, 500_000_000), r#""2024-01-02T03:04:05.500Z""#);
+        assert_eq!(ts_json(-1, 1_000), r#""1969-12-31T23:59:59.000001Z""#);
+        assert_eq!(ts_json(951_782_400, 7), r#""2000-02-29T00:00:00.000000007Z""#);
+        assert_eq!(parse_ts("2024-01-02T03:04:05.5Z").unwrap(), Timestamp::new(1_704_164_645, 500_000_000));
+        assert_eq!(parse_ts("2024-01-02T05:04:05+02:00").unwrap(), Timestamp::new(1_704_164_645, 0));
+        assert_eq!(parse_ts("0001-01-01T00:00:00Z").unwrap().seconds, TIMESTAMP_SECONDS_MIN);
+        assert_eq!(parse_ts("9999-12-31T23:59:59Z").unwrap().seconds, TIMESTAMP_SECONDS_MAX);
+        for bad in ["2023-02-29T00:00:00Z", "2024-01-02 03:04:05Z", "2024-01-02T03:04:05", "2024-01-02T03:04:05.Z"] {
+            assert!(parse_ts(bad).is_err(), "{}", bad);
+        }
+
+        fn dur_json(seconds: i64, nanos: i32) -> String {
+            let mut out = JsonWriter::new();
+            Duration::new(seconds, nanos).write_json(&mut out);
+            out.finish()
+        }
+        fn parse_dur(text: &str) -> Result<Duration, DecodeError> {
+            Duration::from_json_value(&JsonValue::String(text.into()))
+        }
+        assert_eq!(dur_json(1, 500_000_000), r#""1.500s""#);
+        assert_eq!(dur_json(0, -1_000), r#""-0.000001s""#);
+        assert_eq!(dur_json(-3, 0), r#""-3s""#);
+        assert_eq!(parse_dur("1.5s").unwrap(), Duration::new(1, 500_000_000));
+        assert_eq!(parse_dur("-0.000001s").unwrap(), Duration::new(0, -1_000));
+        for bad in ["1.5", "s", "1.0000000001s", "-315576000001s", "+1s"] {
+            assert!(parse_dur(bad).is_err(), "{}", bad);
+        }
+
+        let mut out = JsonWriter::new();
+        UInt64Value { value: 7 }.write_json(&mut out);
+        assert_eq!(out.finish(), r#""7""#);
+        assert_eq!(StringValue::from_json_value(&JsonValue::String("hi".into())).unwrap().value, "hi");
+    }
+
+    #[test]
+    fn test_text_format() {
+        fn render(pretty: bool) -> String {
+            let mut out = TextWriter::new(pretty);
+            out.write_scalar("id", 7u64);
+            out.write_enum("role", 1, &[("ZERO", 0), ("ADMIN", 1)]);
+            out.write_enum("level", 9, &[("ZERO", 0)]);
+            out.begin_message("owner");
+            out.write_str("name", "é \"q\"\n");
+            out.write_bytes("raw", &[b'a', 0, 0xFF, b'\'']);
+            out.begin_message("empty");
+            out.end_message();
+            out.end_message();
+            out.write_f64("ratio", f64::NEG_INFINITY);
+            out.write_f32("half", 0.5);
+            out.finish()
+        }
+        assert_eq!(
+            render(false),
+            r#"id: 7 role: ADMIN level: 9 owner { name: "é \"q\"\n" raw: "a\000\377\'" empty { } } ratio: -inf half: 0.5"#
+        );
+        assert_eq!(
+            render(true),
+            "id: 7\nrole: ADMIN\nlevel: 9\nowner {\n  name: \"é \\\"q\\\"\\n\"\n  raw: \"a\\000\\377\\'\"\n  empty {\n  }\n}\nratio: -inf\nhalf: 0.5\n"
+        );
+
+        let mut out = TextWriter::new(false);
+        Timestamp::new(1_704_164_645, 0).write_text(&mut out);
+        UInt64Value { value: 0 }.write_text(&mut out);
+        BytesValue { value: vec![1] }.write_text(&mut out);
+        Any { type_url: "t/x.Y".into(), value: Vec::new() }.write_text(&mut out);
+        assert_eq!(out.finish(), r#"seconds: 1704164645 value: "\001" type_url: "t/x.Y""#);
+    }
+
+    /// Vec-based reference encoding of a small multi-field message.
+    fn sample_message_vec() -> Vec<u8> {
         let mut buf = Vec::new();
-        encode_fixed32(&mut buf, 42);
-        let new_pos = skip_field(&buf, 0, 5).unwrap();
-        assert_eq!(new_pos, 4);
+        encode_key(&mut buf, 0x08);
+        encode_varint(&mut buf, 300);
+        encode_key(&mut buf, 0x12);
+        encode_string(&mut buf, "hello");
+        encode_key(&mut buf, 0x18);
+        encode_zigzag64(&mut buf, -42);
+        encode_key(&mut buf, 0x25);
+        encode_fixed32(&mut buf, 0xDEAD);
+        encode_key(&mut buf, 0x28);
+        encode_bool(&mut buf, true);
+        buf
+    }
+
+    fn sample_message_slice(out: &mut [u8]) -> Result<usize, DecodeError> {
+        let mut w = SliceWriter::new(out);
+        w.write_key(0x08)?;
+        w.write_varint(300)?;
+        w.write_key(0x12)?;
+        w.write_string("hello")?;
+        w.write_key(0x18)?;
+        w.write_zigzag64(-42)?;
+        w.write_key(0x25)?;
+        w.write_fixed32(0xDEAD)?;
+        w.write_key(0x28)?;
+        w.write_bool(true)
+    }
+
+    #[test]
+    fn test_slice_writer_exact_size() {
+        let expected = sample_message_vec();
+        let mut out = vec![0u8; expected.len()];
+        let n = sample_message_slice(&mut out).unwrap();
+        assert_eq!(n, expected.len());
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_slice_writer_oversized() {
+        let expected = sample_message_vec();
+        let mut out = [0xAAu8; 64];
+        let n = sample_message_slice(&mut out).unwrap();
+        assert_eq!(n, expected.len());
+        assert_eq!(&out[..n], &expected[..]);
+        assert!(out[n..].iter().all(|&b| b == 0xAA));
+    }
+
+    #[test]
+    fn test_slice_writer_undersized() {
+        let expected = sample_message_vec();
+        let mut out = vec![0u8; expected.len() - 1];
+        assert!(matches!(
+            sample_message_slice(&mut out),
+            Err(DecodeError::BufferTooSmall)
+        ));
+
+        // A length-delimited write that doesn't fit leaves the offset untouched
+        let mut out = [0u8; 4];
+        let mut w = SliceWriter::new(&mut out);
+        assert!(matches!(w.write_string("hello"), Err(DecodeError::BufferTooSmall)));
+        assert_eq!(w.position(), 0);
     }
 }
@@ -0,0 +1,97 @@
+import Fs from "node:fs"
+import Path from "node:path"
+import { log } from "../util/logger.js"
+import { parseInstructionTag, parseParams, readDescriptorSet, writeDescriptorSet } from "../plugin.js"
+import { loadProtos } from "../compile/loader.js"
+import { breakingChanges } from "./rules.js"
+
+const USAGE = `Usage: protoc-gen-solana breaking [OPTION]... PROTO_FILE...
+
+Report the wire-breaking changes of .proto files against a baseline schema.
+
+  -I, --proto_path=DIR        Search DIR for imports; may be repeated (default: .)
+  --against=FILES             The baseline: FileDescriptorSets (separated by "${Path.delimiter}"),
+                              such as a --descriptor_set_out committed before
+  --descriptor_set_in=FILES   Read the current schema from FileDescriptorSets instead of
+                              parsing PROTO_FILEs
+  --descriptor_set_out=FILE   Write the current schema to FILE, as the next baseline
+  --solana_opt=OPTIONS        The plugin options the code is generated with, as for
+                              protoc; may be repeated
+  -h, --help                  Show this help
+
+Each change is printed as FILE: ELEMENT: PROBLEM; the exit status is 1 if
+there are any.`
+
+interface BreakingArgs {
+  includes: string[]
+  against: string[]
+  descriptorSets: string[]
+  descriptorSetOut?: string
+  options: string[]
+  protos: string[]
+}
+
+/**
+ * The `breaking` command: compare the schema of the given .proto files
+ * (or FileDescriptorSets) with a baseline FileDescriptorSet and print
+ * every change that breaks data or instructions already written with
+ * it, so the change is caught before it is deployed.
+ */
+export function runBreaking(argv: string[]): void {
+  if (argv.includes("-h") || argv.includes("--help")) {
+    process.stdout.write(`${USAGE}\n`)
+    return
+  }
+  const args = parseArgs(argv)
+  if (args.against.length === 0 && !args.descriptorSetOut) throw new Error(`Missing --against\n\n${USAGE}`)
+
+  let files: any[]
+  if (args.descriptorSets.length > 0) {
+    files = args.descriptorSets.flatMap(path => readDescriptorSet(Fs.readFileSync(path)))
+  } else {
+    if (args.protos.length === 0) throw new Error(`Missing input file\n\n${USAGE}`)
+    files = loadProtos(args.protos, args.includes).files
+  }
+  if (args.descriptorSetOut) {
+    Fs.writeFileSync(args.descriptorSetOut, writeDescriptorSet(files))
+    log.info("Wrote %d file(s) to %s", files.length, args.descriptorSetOut)
+  }
+  if (args.against.length === 0) return
+
+  const baseline = args.against.flatMap(path => readDescriptorSet(Fs.readFileSync(path)))
+  // Compared as the plugin reads them, so both sides have the same shape
+  const current = readDescriptorSet(writeDescriptorSet(files))
+  const instructionTag = parseInstructionTag(parseParams(args.options.join(",")).instruction_tag)
+  const findings = breakingChanges(baseline, current, instructionTag)
+  for (const { file, element, problem } of findings) process.stdout.write(`${file}: ${element}: ${problem}\n`)
+  if (findings.length > 0) process.exitCode = 1
+}
+
+/** The flags of `breaking`, written as `compile` takes them: `--flag=value` or `--flag value`, `-IDIR` or `-I DIR`. */
+function parseArgs(argv: string[]): BreakingArgs {
+  const args: BreakingArgs = { includes: [], against: [], descriptorSets: [], options: [], protos: [] }
+  for (let i = 0; i < argv.length; i++) {
+    const arg = argv[i]
+    if (!arg.startsWith("-")) {
+      args.protos.push(arg)
+      continue
+    }
+    const flag = /^(-I|--[a-z_]+)=?(.*)$/.exec(arg)
+    if (!flag) throw new Error(`Unknown option ${arg}\n\n${USAGE}`)
+    const [, name, inline] = flag
+    const hasValue = inline !== "" || arg.endsWith("=")
+    const value = () => {
+      if (hasValue) return inline
+      if (i + 1 >= argv.length) throw new Error(`Missing value for ${name}`)
+      return argv[++i]
+    }
+    const paths = () => value().split(Path.delimiter).filter(p => p)
+    if (name === "-I" || name === "--proto_path") args.includes.push(value())
+    else if (name === "--against") args.against.push(...paths())
+    else if (name === "--descriptor_set_in") args.descriptorSets.push(...paths())
+    else if (name === "--descriptor_set_out") args.descriptorSetOut = value()
+    else if (name === "--solana_opt") args.options.push(value())
+    else throw new Error(`Unknown option ${arg}\n\n${USAGE}`)
+  }
+  return args
+}
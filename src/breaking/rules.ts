@@ -0,0 +1,334 @@
+import { SCALAR_TYPES } from "../compile/parser.js"
+import { parseAccounts, unaryMethods } from "../generator/service.js"
+import type { InstructionTag } from "../generator/service.js"
+
+/** A change that stops data written under the baseline from reading back the same way. */
+export interface Finding {
+  /** The `.proto` file of the changed definition */
+  file: string
+  /** The definition's full name, e.g. "vault.Deposit.amount" */
+  element: string
+  problem: string
+}
+
+interface Definition {
+  file: string
+  node: any
+}
+
+/** Every message, enum and service of a schema, by full name (without the leading "."). */
+interface Index {
+  messages: Map<string, Definition>
+  enums: Map<string, Definition>
+  services: Map<string, Definition>
+}
+
+const SCALAR_NAMES: Record<number, string> = Object.fromEntries(
+  Object.entries(SCALAR_TYPES).map(([name, type]) => [type, name])
+)
+
+/**
+ * The types a field of each type can change to and still read every value
+ * written before: varints widening, a bool or an enum read as an integer,
+ * and a string read as its bytes.
+ */
+const WIDENINGS: Record<number, number[]> = {
+  5: [3],
+  13: [4, 3],
+  17: [18],
+  8: [5, 3, 13, 4],
+  14: [5, 3],
+  9: [12]
+}
+
+/**
+ * The wire-breaking changes from the schema `before` (the files of a
+ * FileDescriptorSet) to `after`: reused or unreserved field and enum
+ * numbers, incompatible type and cardinality changes, fields moved
+ * between oneofs, required fields added or removed, tightened
+ * `(solana.*)` field constraints, changed discriminators, versions, seeds
+ * and events, and instructions that no longer dispatch as before.
+ * `instructionTag` is the plugin's option, which decides whether an rpc's
+ * position is part of its instruction data. The google/protobuf files and
+ * solana/options.proto are not compared.
+ */
+export function breakingChanges(before: any[], after: any[], instructionTag: InstructionTag): Finding[] {
+  const old = indexSchema(before)
+  const now = indexSchema(after)
+  const findings: Finding[] = []
+  const report = (file: string, element: string, problem: string) => findings.push({ file, element, problem })
+
+  for (const [name, { file, node }] of old.messages) {
+    const current = now.messages.get(name)
+    if (node.options?.map_entry) continue
+    if (!current) {
+      const identity = onChainIdentity(node)
+      if (identity) report(file, name, `was removed; ${identity}`)
+      continue
+    }
+    compareMessage(name, node, current.node, old, now, (element, problem) => report(current.file, element, problem))
+  }
+  for (const [name, { node }] of old.enums) {
+    const current = now.enums.get(name)
+    if (current) compareEnum(name, node, current.node, (element, problem) => report(current.file, element, problem))
+  }
+  for (const [name, { file, node }] of old.services) {
+    const current = now.services.get(name)
+    if (!current) {
+      report(file, name, "was removed; its instructions no longer dispatch")
+      continue
+    }
+    compareService(name, node, current.node, instructionTag, (element, problem) =>
+      report(current.file, element, problem)
+    )
+  }
+  return findings
+}
+
+function indexSchema(files: any[]): Index {
+  const index: Index = { messages: new Map(), enums: new Map(), services: new Map() }
+  const addEnums = (file: string, enums: any[], scope: string) => {
+    for (const e of enums ?? []) index.enums.set(`${scope}${e.name}`, { file, node: e })
+  }
+  const addMessages = (file: string, messages: any[], scope: string) => {
+    for (const msg of messages ?? []) {
+      const name = `${scope}${msg.name}`
+      index.messages.set(name, { file, node: msg })
+      addMessages(file, msg.nested_type, `${name}.`)
+      addEnums(file, msg.enum_type, `${name}.`)
+    }
+  }
+  for (const file of files) {
+    if (file.name.startsWith("google/protobuf/") || file.name === "solana/options.proto") continue
+    const scope = file.package ? `${file.package}.` : ""
+    addMessages(file.name, file.message_type, scope)
+    addEnums(file.name, file.enum_type, scope)
+    for (const s of file.service ?? []) index.services.set(`${scope}${s.name}`, { file: file.name, node: s })
+  }
+  return index
+}
+
+/** What a removed message takes with it, if it has an identity on chain. */
+function onChainIdentity(msg: any): string | undefined {
+  const options = msg.options ?? {}
+  if (options.discriminator) return "accounts written with its discriminator no longer decode"
+  if (options.version !== undefined) return "accounts written with its version byte no longer decode"
+  if (options.seeds !== undefined) return "its PDAs are no longer derived"
+  if (options.event) return "its event logs no longer decode"
+  return undefined
+}
+
+function compareMessage(
+  name: string,
+  before: any,
+  after: any,
+  old: Index,
+  now: Index,
+  report: (element: string, problem: string) => void
+): void {
+  const was = before.options ?? {}
+  const is = after.options ?? {}
+  if (Boolean(was.discriminator) !== Boolean(is.discriminator)) {
+    const change = is.discriminator ? "added" : "removed"
+    report(name, `${change} (solana.discriminator); accounts written before no longer decode`)
+  }
+  if (was.version !== is.version && !(was.version !== undefined && is.version > was.version)) {
+    const [from, to] = [was.version ?? "none", is.version ?? "none"]
+    report(name, `(solana.version) changed from ${from} to ${to}; only increasing it migrates old data`)
+  }
+  if (was.seeds !== is.seeds) {
+    report(name, `(solana.seeds) changed from "${was.seeds ?? ""}" to "${is.seeds ?? ""}"; its PDAs move`)
+  }
+  if (was.event && !is.event) report(name, "is no longer an (solana.event); its logs no longer decode")
+
+  const fields: any[] = after.field ?? []
+  for (const f of before.field ?? []) {
+    const element = `${name}.${f.name}`
+    const moved = fields.find(g => g.name === f.name && g.number !== f.number)
+    const current = fields.find(g => g.number === f.number)
+    if (moved) report(element, `moved from number ${f.number} to ${moved.number}`)
+    if (!current) {
+      const reserved = (after.reserved_range ?? []).some((r: any) => f.number >= r.start && f.number < r.end)
+      if (!moved && !reserved) {
+        report(element, `was removed without reserving number ${f.number}, which a new field can reuse`)
+      }
+      continue
+    }
+    if (moved) report(`${name}.${current.name}`, `reuses number ${f.number} of ${f.name}`)
+    compareField(`${name}.${current.name}`, before, f, after, current, old, now, report)
+  }
+  for (const f of fields) {
+    if (isRequired(f) && !(before.field ?? []).some((g: any) => g.number === f.number)) {
+      report(`${name}.${f.name}`, "is a new required field, which data written before lacks")
+    }
+  }
+}
+
+function compareField(
+  element: string,
+  msgBefore: any,
+  before: any,
+  msgAfter: any,
+  after: any,
+  old: Index,
+  now: Index,
+  report: (element: string, problem: string) => void
+): void {
+  const typeBefore = fieldType(before, old)
+  const typeAfter = fieldType(after, now)
+  if (typeBefore !== typeAfter && !(WIDENINGS[before.type] ?? []).includes(after.type)) {
+    report(element, `changed type from ${typeBefore} to ${typeAfter}`)
+  }
+  if ((before.label === 3) !== (after.label === 3)) {
+    report(element, after.label === 3 ? "changed from singular to repeated" : "changed from repeated to singular")
+  }
+  const oneofBefore = oneofName(msgBefore, before)
+  const oneofAfter = oneofName(msgAfter, after)
+  if (oneofBefore !== oneofAfter) {
+    if (oneofBefore === undefined) report(element, `moved into oneof ${oneofAfter}`)
+    else if (oneofAfter === undefined) report(element, `moved out of oneof ${oneofBefore}`)
+    else report(element, `moved from oneof ${oneofBefore} to ${oneofAfter}`)
+  }
+  if (isRequired(before) !== isRequired(after)) {
+    report(
+      element,
+      isRequired(after)
+        ? "is now required; data written before without it no longer decodes"
+        : "is no longer required; programs built before reject data without it"
+    )
+  }
+
+  const was = before.options ?? {}
+  const is = after.options ?? {}
+  if (is.pubkey && !was.pubkey) {
+    report(element, "added (solana.pubkey); values written before of other lengths no longer decode")
+  }
+  if (is.fixed_len !== undefined && is.fixed_len !== was.fixed_len) {
+    report(
+      element,
+      was.fixed_len === undefined
+        ? `added (solana.fixed_len) = ${is.fixed_len}`
+        : `(solana.fixed_len) changed from ${was.fixed_len} to ${is.fixed_len}`
+    )
+  }
+  for (const option of ["max_len", "max_count"]) {
+    if (is[option] === undefined || (was[option] !== undefined && is[option] >= was[option])) continue
+    report(
+      element,
+      was[option] === undefined
+        ? `added (solana.${option}) = ${is[option]}`
+        : `(solana.${option}) lowered from ${was[option]} to ${is[option]}`
+    )
+  }
+}
+
+function compareEnum(name: string, before: any, after: any, report: (element: string, problem: string) => void): void {
+  const values: any[] = after.value ?? []
+  for (const v of before.value ?? []) {
+    if (values.some(w => w.name === v.name && w.number === v.number)) continue
+    const element = `${name}.${v.name}`
+    const moved = values.find(w => w.name === v.name)
+    const current = values.find(w => w.number === v.number)
+    if (moved) report(element, `moved from number ${v.number} to ${moved.number}`)
+    if (current) {
+      const reused = (before.value ?? []).some((w: any) => w.name === current.name)
+      if (reused) report(`${name}.${current.name}`, `reuses number ${v.number} of ${v.name}`)
+      continue
+    }
+    // An enum's reserved ranges include their end
+    const reserved = (after.reserved_range ?? []).some((r: any) => v.number >= r.start && v.number <= r.end)
+    if (!moved && !reserved) {
+      report(element, `was removed without reserving number ${v.number}, which a new value can reuse`)
+    }
+  }
+}
+
+function compareService(
+  name: string,
+  before: any,
+  after: any,
+  instructionTag: InstructionTag,
+  report: (element: string, problem: string) => void
+): void {
+  const unary = (service: any) =>
+    unaryMethods({
+      name: service.name,
+      fullName: name,
+      methods: (service.method ?? []).map((m: any) => ({
+        name: m.name,
+        inputType: m.input_type,
+        outputType: m.output_type,
+        clientStreaming: Boolean(m.client_streaming),
+        serverStreaming: Boolean(m.server_streaming)
+      }))
+    }).map(m => m.name)
+  const tagsBefore = unary(before)
+  const tagsAfter = unary(after)
+  const methods: any[] = after.method ?? []
+  for (const m of before.method ?? []) {
+    const element = `${name}.${m.name}`
+    const current = methods.find(n => n.name === m.name)
+    const tag = tagsBefore.indexOf(m.name)
+    if (tag < 0) continue
+    if (!current) {
+      report(element, "was removed; its instruction no longer dispatches")
+      continue
+    }
+    const newTag = tagsAfter.indexOf(m.name)
+    if (newTag < 0) {
+      report(element, "is now streaming and has no instruction")
+      continue
+    }
+    if (instructionTag === "u8" && newTag !== tag) report(element, `instruction tag changed from ${tag} to ${newTag}`)
+    if (m.input_type !== current.input_type) {
+      report(element, `request type changed from ${typeName(m.input_type)} to ${typeName(current.input_type)}`)
+    }
+    if (m.output_type !== current.output_type) {
+      report(element, `response type changed from ${typeName(m.output_type)} to ${typeName(current.output_type)}`)
+    }
+    const accountsBefore = accountList(m, element)
+    const accountsAfter = accountList(current, element)
+    if (accountsBefore !== accountsAfter) {
+      report(element, `(solana.accounts) changed from "${accountsBefore}" to "${accountsAfter}"`)
+    }
+  }
+}
+
+/** An rpc's `(solana.accounts)`, normalized so that only changes to the accounts compare unequal. */
+function accountList(method: any, rpc: string): string {
+  const spec = method.options?.accounts
+  if (spec === undefined) return ""
+  return parseAccounts(spec, rpc)
+    .map(a => {
+      const flags = [a.signer ? "signer" : "", a.writable ? "mut" : ""].filter(f => f !== "")
+      return flags.length > 0 ? `${a.name}: ${flags.join(", ")}` : a.name
+    })
+    .join("; ")
+}
+
+/** A field's type as .proto writes it, with a map's key and value types. */
+function fieldType(field: any, index: Index): string {
+  if (field.type === 11 || field.type === 14 || field.type === 10) {
+    const entry = index.messages.get(typeName(field.type_name))?.node
+    if (entry?.options?.map_entry) {
+      const [key, value] = [1, 2].map(n => entry.field.find((f: any) => f.number === n))
+      return `map<${fieldType(key, index)}, ${fieldType(value, index)}>`
+    }
+    return typeName(field.type_name)
+  }
+  return SCALAR_NAMES[field.type]
+}
+
+function typeName(name: string): string {
+  return name.replace(/^\./, "")
+}
+
+/** The real oneof holding a field; the synthetic one of a proto3 `optional` field does not count. */
+function oneofName(msg: any, field: any): string | undefined {
+  if (field.oneof_index === undefined || field.proto3_optional) return undefined
+  return msg.oneof_decl?.[field.oneof_index]?.name
+}
+
+function isRequired(field: any): boolean {
+  return field.label === 2 || field.options?.required === true || field.options?.features?.field_presence === 3
+}
@@ -0,0 +1,103 @@
+import Fs from "node:fs"
+import Path from "node:path"
+import { log } from "../util/logger.js"
+import { generate, readDescriptorSet } from "../plugin.js"
+import { loadProtos } from "./loader.js"
+
+const USAGE = `Usage: protoc-gen-solana compile [OPTION]... PROTO_FILE...
+
+Generate Rust from .proto files without protoc.
+
+  -I, --proto_path=DIR        Search DIR for imports; may be repeated (default: .)
+  --solana_out=DIR            Write the generated files under DIR
+  --solana_opt=OPTIONS        Plugin options, as for protoc; may be repeated
+  --descriptor_set_in=FILES   Read FileDescriptorSets (separated by "${Path.delimiter}") instead
+                              of parsing; with no PROTO_FILE, generate every file in them
+  --dependency_out=FILE       Write the files written and the files read to FILE, as a
+                              make rule
+  -h, --help                  Show this help`
+
+interface CompileArgs {
+  includes: string[]
+  out?: string
+  options: string[]
+  descriptorSets: string[]
+  dependencyOut?: string
+  protos: string[]
+}
+
+/**
+ * The `compile` command: what `protoc --solana_out` does, with the
+ * .proto files parsed here (or read from FileDescriptorSets) so protoc
+ * need not be installed.
+ */
+export function runCompile(argv: string[]): void {
+  if (argv.includes("-h") || argv.includes("--help")) {
+    process.stdout.write(`${USAGE}\n`)
+    return
+  }
+  const args = parseArgs(argv)
+  if (!args.out) throw new Error(`Missing --solana_out\n\n${USAGE}`)
+
+  let protoFiles: any[]
+  let toGenerate: string[]
+  let inputPaths: string[]
+  if (args.descriptorSets.length > 0) {
+    inputPaths = args.descriptorSets
+    protoFiles = args.descriptorSets.flatMap(path => readDescriptorSet(Fs.readFileSync(path)))
+    toGenerate = args.protos.length > 0 ? args.protos : protoFiles.map(f => f.name)
+    const known = new Set(protoFiles.map(f => f.name))
+    for (const name of toGenerate) {
+      if (!known.has(name)) throw new Error(`${name}: not in the descriptor set(s) given with --descriptor_set_in`)
+    }
+  } else {
+    if (args.protos.length === 0) throw new Error(`Missing input file\n\n${USAGE}`)
+    ;({ files: protoFiles, toGenerate, paths: inputPaths } = loadProtos(args.protos, args.includes))
+  }
+
+  const out = args.out
+  const result = generate({ file_to_generate: toGenerate, parameter: args.options.join(","), proto_file: protoFiles })
+  if (result.error) throw new Error(result.error)
+  const written = result.files.map(file => {
+    const path = Path.join(out, file.name)
+    Fs.mkdirSync(Path.dirname(path), { recursive: true })
+    Fs.writeFileSync(path, file.content)
+    return path
+  })
+  if (written.length > 0) log.info("Wrote %d file(s) to %s", written.length, out)
+  if (args.dependencyOut) Fs.writeFileSync(args.dependencyOut, makeRule(written, inputPaths))
+}
+
+/** `targets: prerequisites` as make reads it, as protoc's `--dependency_out` writes it. */
+function makeRule(targets: string[], prerequisites: string[]): string {
+  const escape = (path: string) => path.replace(/([ #\\])/g, "\\$1")
+  return `${targets.map(escape).join(" \\\n")}: ${prerequisites.map(escape).join(" \\\n  ")}\n`
+}
+
+/** protoc's flags that `compile` takes: `--flag=value` or `--flag value`, and `-IDIR` or `-I DIR`. */
+function parseArgs(argv: string[]): CompileArgs {
+  const args: CompileArgs = { includes: [], options: [], descriptorSets: [], protos: [] }
+  for (let i = 0; i < argv.length; i++) {
+    const arg = argv[i]
+    if (!arg.startsWith("-")) {
+      args.protos.push(arg)
+      continue
+    }
+    const flag = /^(-I|--[a-z_]+)=?(.*)$/.exec(arg)
+    if (!flag) throw new Error(`Unknown option ${arg}\n\n${USAGE}`)
+    const [, name, inline] = flag
+    const hasValue = inline !== "" || arg.endsWith("=")
+    const value = () => {
+      if (hasValue) return inline
+      if (i + 1 >= argv.length) throw new Error(`Missing value for ${name}`)
+      return argv[++i]
+    }
+    if (name === "-I" || name === "--proto_path") args.includes.push(value())
+    else if (name === "--solana_out") args.out = value()
+    else if (name === "--solana_opt") args.options.push(value())
+    else if (name === "--descriptor_set_in") args.descriptorSets.push(...value().split(Path.delimiter).filter(p => p))
+    else if (name === "--dependency_out") args.dependencyOut = value()
+    else throw new Error(`Unknown option ${arg}\n\n${USAGE}`)
+  }
+  return args
+}
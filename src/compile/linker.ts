@@ -0,0 +1,574 @@
+import { toJsonName } from "../util/names.js"
+import { customOptionName } from "../plugin.js"
+import { MAX_FIELD_NUMBER } from "./parser.js"
+import type { OptionValue, Position, RawOption } from "./parser.js"
+
+/** A named element, by full name without the leading dot. */
+interface Definition {
+  kind: "package" | "message" | "enum" | "enum value" | "service" | "method" | "field" | "extension" | "oneof"
+  file: string
+  node?: any
+}
+
+/** Kinds that names can be looked up inside of. */
+const AGGREGATES = new Set<Definition["kind"]>(["package", "message", "enum", "service"])
+
+/** Built-in options the parser accepts, by options message, with their value types. */
+const BUILTIN_OPTIONS: Record<string, Record<string, "bool" | "string" | "identifier" | "other">> = {
+  FileOptions: {
+    java_package: "string",
+    java_outer_classname: "string",
+    java_multiple_files: "bool",
+    java_generate_equals_and_hash: "bool",
+    java_string_check_utf8: "bool",
+    optimize_for: "identifier",
+    go_package: "string",
+    cc_generic_services: "bool",
+    java_generic_services: "bool",
+    py_generic_services: "bool",
+    deprecated: "bool",
+    cc_enable_arenas: "bool",
+    objc_class_prefix: "string",
+    csharp_namespace: "string",
+    swift_prefix: "string",
+    php_class_prefix: "string",
+    php_namespace: "string",
+    php_metadata_namespace: "string",
+    ruby_package: "string"
+  },
+  MessageOptions: {
+    message_set_wire_format: "bool",
+    no_standard_descriptor_accessor: "bool",
+    deprecated: "bool",
+    deprecated_legacy_json_field_conflicts: "bool"
+  },
+  FieldOptions: {
+    ctype: "identifier",
+    packed: "bool",
+    jstype: "identifier",
+    lazy: "bool",
+    unverified_lazy: "bool",
+    deprecated: "bool",
+    weak: "bool",
+    debug_redact: "bool",
+    retention: "identifier",
+    targets: "identifier",
+    edition_defaults: "other",
+    feature_support: "other"
+  },
+  OneofOptions: {},
+  EnumOptions: { allow_alias: "bool", deprecated: "bool", deprecated_legacy_json_field_conflicts: "bool" },
+  EnumValueOptions: { deprecated: "bool", debug_redact: "bool", feature_support: "other" },
+  ServiceOptions: { deprecated: "bool" },
+  MethodOptions: { deprecated: "bool", idempotency_level: "identifier" }
+}
+
+/** `features.<name>` values (FeatureSet enums) by feature. */
+const FEATURES: Record<string, Record<string, number>> = {
+  field_presence: { EXPLICIT: 1, IMPLICIT: 2, LEGACY_REQUIRED: 3 },
+  enum_type: { OPEN: 1, CLOSED: 2 },
+  repeated_field_encoding: { PACKED: 1, EXPANDED: 2 },
+  utf8_validation: { VERIFY: 2, NONE: 3 },
+  message_encoding: { LENGTH_PREFIXED: 1, DELIMITED: 2 },
+  json_format: { ALLOW: 1, LEGACY_BEST_EFFORT: 2 }
+}
+
+/** The range of each integer field type. */
+const INTEGER_RANGES: Record<number, [bigint, bigint]> = {
+  3: [-(2n ** 63n), 2n ** 63n - 1n],
+  4: [0n, 2n ** 64n - 1n],
+  5: [-(2n ** 31n), 2n ** 31n - 1n],
+  6: [0n, 2n ** 64n - 1n],
+  7: [0n, 2n ** 32n - 1n],
+  13: [0n, 2n ** 32n - 1n],
+  15: [-(2n ** 31n), 2n ** 31n - 1n],
+  16: [-(2n ** 63n), 2n ** 63n - 1n],
+  17: [-(2n ** 31n), 2n ** 31n - 1n],
+  18: [-(2n ** 63n), 2n ** 63n - 1n]
+}
+
+/** What `parseProto` adds to elements for linking, removed once linked. */
+const ANNOTATIONS = ["at", "typeAt", "typeRef", "extendeeAt", "extendeeRef", "inputAt", "inputRef", "outputAt", "outputRef"]
+
+/** Numbers protoc keeps for itself. */
+const RESERVED_NUMBERS: [number, number] = [19000, 19999]
+
+/**
+ * Link parsed files (`parseProto`), dependencies first, in place into the
+ * FileDescriptorProtos protoc would hand the plugin: type names resolved
+ * to full names as protoc scopes them, options interpreted (custom ones
+ * under the names the plugin's schema gives them), defaults in protoc's
+ * text form and `json_name` filled in. Fails on what protoc rejects when
+ * linking: undefined or unimported types, duplicate names and numbers,
+ * numbers out of range or reserved, and mistyped options.
+ */
+export function link(files: any[]): void {
+  const definitions = new Map<string, Definition>()
+  const byName = new Map<string, any>(files.map(f => [f.name, f]))
+
+  const fail = (file: any, at: Position | undefined, message: string): never => {
+    const position = at ? `${at.line + 1}:${at.column + 1}:` : ""
+    throw new Error(`${file.name}:${position} ${message}`)
+  }
+  const define = (file: any, scope: string, name: string, symbol: Definition, at?: Position) => {
+    const fullName = scope ? `${scope}.${name}` : name
+    const existing = definitions.get(fullName)
+    if (existing && !(existing.kind === "package" && symbol.kind === "package")) {
+      if (existing.kind === "package" || symbol.kind === "package") {
+        fail(file, at, `"${fullName}" is already defined (as something other than a package) in file "${existing.file}".`)
+      }
+      const where = scope ? ` in "${scope}"` : ""
+      const note =
+        symbol.kind === "enum value"
+          ? ` Note that enum values use C++ scoping rules, meaning that enum values are siblings of their type, ` +
+            `not children of it.`
+          : ""
+      fail(file, at, `"${name}" is already defined${where}.${note}`)
+    }
+    if (!existing) definitions.set(fullName, { ...symbol, file: file.name })
+  }
+
+  // Every name first, so references can point anywhere
+  for (const file of files) {
+    const parts = (file.package ?? "").split(".").filter((p: string) => p)
+    parts.forEach((part: string, i: number) => {
+      define(file, parts.slice(0, i).join("."), part, { kind: "package", file: "" })
+    })
+    walk(file, (kind, node, scope) => {
+      if (kind === "enum") {
+        for (const value of node.value) {
+          define(file, scope, value.name, { kind: "enum value", file: "", node: value }, value.at)
+        }
+      }
+      define(file, scope, node.name, { kind, file: "", node }, node.at)
+    })
+  }
+
+  // The files whose names `file` can see: itself, its imports, and theirs made public
+  const visibility = new Map<string, Set<string>>()
+  const visibleFiles = (file: any): Set<string> => {
+    let visible = visibility.get(file.name)
+    if (visible) return visible
+    visible = new Set([file.name])
+    const addPublic = (dep: any) => {
+      if (!dep || visible!.has(dep.name)) return
+      visible!.add(dep.name)
+      for (const i of dep.public_dependency ?? []) addPublic(byName.get(dep.dependency[i]))
+    }
+    for (const dep of file.dependency ?? []) addPublic(byName.get(dep))
+    visibility.set(file.name, visible)
+    return visible
+  }
+
+  // protoc's scoping: the innermost scope first; `A.B` is looked up wherever `A` is found first
+  type Kinds = Definition["kind"][]
+  const resolve = (file: any, scope: string, name: string, at: Position, wanted: Kinds): [string, Definition] => {
+    let fullName: string | undefined
+    if (name.startsWith(".")) {
+      fullName = name.slice(1)
+    } else {
+      const [first] = name.split(".")
+      const scopes = scope ? scope.split(".") : []
+      for (let n = scopes.length; n >= 0 && fullName === undefined; n--) {
+        const prefix = scopes.slice(0, n).join(".")
+        const candidate = definitions.get(prefix ? `${prefix}.${first}` : first)
+        if (!candidate) continue
+        if (first !== name && !AGGREGATES.has(candidate.kind)) continue
+        // Looking for a type, other names are skipped
+        const isType = candidate.kind === "message" || candidate.kind === "enum"
+        if (first === name && wanted.includes("message") && !isType) continue
+        fullName = prefix ? `${prefix}.${name}` : name
+        if (!definitions.has(fullName)) {
+          fail(
+            file,
+            at,
+            `"${name}" is resolved to "${fullName}", which is not defined. The innermost scope is searched first in name ` +
+              `resolution. Consider using a leading '.'(i.e., ".${name}") to start from the outermost scope.`
+          )
+        }
+      }
+    }
+    const symbol = fullName === undefined ? undefined : definitions.get(fullName)
+    if (!symbol) fail(file, at, `"${name}" is not defined.`)
+    if (!wanted.includes(symbol!.kind)) {
+      const what = wanted.includes("enum") ? "a type" : wanted.includes("extension") ? "an extension" : "a message type"
+      fail(file, at, `"${name}" is not ${what}.`)
+    }
+    if (!visibleFiles(file).has(symbol!.file)) {
+      fail(
+        file,
+        at,
+        `"${fullName}" seems to be defined in "${symbol!.file}", which is not imported by "${file.name}".  ` +
+          `To use it here, please add the necessary import.`
+      )
+    }
+    return [fullName!, symbol!]
+  }
+
+  // Field types, extendees and method types
+  for (const file of files) {
+    const proto3 = file.syntax === "proto3"
+    walk(file, (kind, node, scope) => {
+      if (kind === "field" || kind === "extension") {
+        if (node.typeRef !== undefined) {
+          const [fullName, symbol] = resolve(file, scope, node.typeRef, node.typeAt, ["message", "enum"])
+          node.type_name = `.${fullName}`
+          if (node.type !== 10) node.type = symbol.kind === "message" ? 11 : 14
+        }
+        if (node.json_name === undefined) node.json_name = toJsonName(node.name)
+      }
+      if (kind === "extension") {
+        const [fullName, symbol] = resolve(file, scope, node.extendeeRef, node.extendeeAt, ["message"])
+        node.extendee = `.${fullName}`
+        if (proto3 && !fullName.startsWith("google.protobuf.")) {
+          fail(file, node.extendeeAt, "Extensions in proto3 are only allowed for defining options.")
+        }
+        const ranges = symbol.node?.extension_range ?? []
+        if (!ranges.some((r: any) => node.number >= r.start && node.number < r.end)) {
+          fail(file, node.at, `"${fullName}" does not declare ${node.number} as an extension number.`)
+        }
+      }
+      if (kind === "method") {
+        node.input_type = `.${resolve(file, scope, node.inputRef, node.inputAt, ["message"])[0]}`
+        node.output_type = `.${resolve(file, scope, node.outputRef, node.outputAt, ["message"])[0]}`
+      }
+    })
+  }
+
+  // A raw option value as the JS value the plugin's schema takes for a field of `type`
+  const optionValue = (file: any, option: RawOption, field: any, name: string): any => {
+    const { value } = option
+    const wrong = (expected: string): never => fail(file, option.at, `Value must be ${expected} for ${name}.`)
+    if (field.type === 8) {
+      if (value.kind !== "identifier" || (value.text !== "true" && value.text !== "false")) wrong(`"true" or "false"`)
+      return value.text === "true"
+    }
+    if (field.type === 9 || field.type === 12) {
+      if (value.kind !== "string") wrong("quoted string")
+      return field.type === 9 ? value.text : Buffer.from(value.bytes!)
+    }
+    if (field.type === 1 || field.type === 2) {
+      if (value.kind === "identifier" && (value.text === "inf" || value.text === "nan")) {
+        return value.text === "nan" ? NaN : value.negative ? -Infinity : Infinity
+      }
+      if (value.kind !== "integer" && value.kind !== "float") wrong("number")
+      return (value.negative ? -1 : 1) * Number(value.text)
+    }
+    if (field.type === 14) {
+      const values: any[] = definitions.get(field.type_name.slice(1))?.node?.value ?? []
+      const match = value.kind === "identifier" ? values.find(v => v.name === value.text) : undefined
+      if (!match) wrong(`an identifier naming a value of ${field.type_name.slice(1)}`)
+      return match.number
+    }
+    if (field.type === 11 || field.type === 10) {
+      if (value.kind !== "aggregate") wrong("an aggregate value")
+      return undefined
+    }
+    const integer = integerValue(value, field.type)
+    if (integer === undefined) wrong(`an integer in the range of ${typeName(field.type)}`)
+    // int64 types go through as strings, which protobufjs turns into Long
+    return [3, 4, 6, 16, 18].includes(field.type) ? integer!.toString() : Number(integer)
+  }
+
+  // `rawOptions` of a node as its `options`, under the names the plugin's schema decodes
+  const interpret = (file: any, node: any, optionsType: string, scope: string) => {
+    const options: any = node.options ?? {}
+    const set = new Set<string>()
+    for (const option of node.rawOptions ?? []) {
+      const [part, ...rest] = option.name
+      const text = option.name.map((p: any) => (p.extension ? `(${p.name})` : p.name)).join(".")
+      if (!part.extension && part.name === "features") {
+        if (file.syntax !== "editions") fail(file, option.at, "Features are only valid under editions.")
+        const feature = rest.length === 1 && !rest[0].extension ? FEATURES[rest[0].name] : undefined
+        if (!feature) fail(file, option.at, `Option "${text}" unknown.`)
+        const number = option.value.kind === "identifier" ? feature![option.value.text] : undefined
+        if (number === undefined) {
+          fail(file, option.at, `Value must be one of ${Object.keys(feature!).join(", ")} for ${text}.`)
+        }
+        if (set.has(text)) fail(file, option.at, `Option "${text}" was already set.`)
+        set.add(text)
+        options.features = { ...options.features, [rest[0].name]: number }
+        continue
+      }
+      if (!part.extension) {
+        if (part.name === "map_entry" && optionsType === "MessageOptions") {
+          fail(file, option.at, "map_entry should not be set explicitly. Use map<KeyType, ValueType> instead.")
+        }
+        const type = BUILTIN_OPTIONS[optionsType][part.name]
+        if (type === undefined || (rest.length > 0 && type !== "other")) {
+          fail(
+            file,
+            option.at,
+            `Option "${text}" unknown. Ensure that your proto definition file imports the proto which defines the option.`
+          )
+        }
+        if (set.has(part.name)) fail(file, option.at, `Option "${text}" was already set.`)
+        set.add(part.name)
+        if (type === "bool") options[part.name] = optionValue(file, option, { type: 8 }, text)
+        else if (type === "string") optionValue(file, option, { type: 9 }, text)
+        continue
+      }
+
+      const [fullName, symbol] = resolve(file, scope, part.name, option.at, ["extension"])
+      const ext = symbol.node
+      if (ext.extendee !== `.google.protobuf.${optionsType}`) {
+        fail(file, option.at, `"${fullName}" is not a field or extension of message "google.protobuf.${optionsType}".`)
+      }
+      if (rest.length > 0 && ext.type !== 11 && ext.type !== 10) {
+        fail(file, option.at, `Option "${text}" is an atomic type, not a message.`)
+      }
+      const repeated = ext.label === 3
+      if (!repeated && set.has(text)) fail(file, option.at, `Option "${text}" was already set.`)
+      set.add(text)
+      const value = rest.length > 0 ? undefined : optionValue(file, option, ext, text)
+      const key = customOptionName(optionsType, ext.number)
+      if (key === undefined || value === undefined) continue
+      if (repeated) options[key] = [...(options[key] ?? []), value]
+      else options[key] = value
+    }
+    delete node.rawOptions
+    if (Object.keys(options).length > 0) node.options = options
+  }
+
+  for (const file of files) {
+    interpret(file, file, "FileOptions", file.package ?? "")
+    walk(file, (kind, node, scope) => {
+      // Option names are looked up from inside the element, as protoc does
+      const inner = fullNameOf(scope, node.name)
+      if (kind === "message") {
+        interpret(file, node, "MessageOptions", inner)
+        for (const oneof of node.oneof_decl) interpret(file, oneof, "OneofOptions", inner)
+      } else if (kind === "field" || kind === "extension") {
+        interpret(file, node, "FieldOptions", inner)
+      } else if (kind === "enum") {
+        interpret(file, node, "EnumOptions", inner)
+        for (const value of node.value) interpret(file, value, "EnumValueOptions", inner)
+      } else if (kind === "service") {
+        interpret(file, node, "ServiceOptions", inner)
+      } else if (kind === "method") {
+        interpret(file, node, "MethodOptions", inner)
+      }
+    })
+  }
+
+  for (const file of files) {
+    walk(file, (kind, node, scope) => {
+      if (kind === "message") checkMessage(file, node, fullNameOf(scope, node.name), fail)
+      else if (kind === "enum") checkEnum(file, node, fullNameOf(scope, node.name), fail)
+      else if (kind === "field" || kind === "extension") defaultValue(file, node, definitions, fail)
+    })
+    checkExtensionNumbers(file, files, fail)
+  }
+
+  for (const file of files) {
+    delete file.rawOptions
+    walk(file, (_, node) => {
+      for (const key of ANNOTATIONS) delete node[key]
+      for (const value of node.value ?? []) delete value.at
+    })
+  }
+}
+
+type Fail = (file: any, at: Position | undefined, message: string) => never
+
+function fullNameOf(scope: string, name: string): string {
+  return scope ? `${scope}.${name}` : name
+}
+
+/**
+ * Call `visit` on every message, field, extension, enum, service and
+ * method of a file, with the full name of the scope it is declared in.
+ */
+function walk(file: any, visit: (kind: Definition["kind"], node: any, scope: string) => void): void {
+  const walkMessages = (messages: any[], enums: any[], extensions: any[], scope: string) => {
+    for (const e of enums) visit("enum", e, scope)
+    for (const ext of extensions) visit("extension", ext, scope)
+    for (const msg of messages) {
+      visit("message", msg, scope)
+      const inner = fullNameOf(scope, msg.name)
+      for (const f of msg.field) visit("field", f, inner)
+      for (const oneof of msg.oneof_decl) visit("oneof", oneof, inner)
+      walkMessages(msg.nested_type, msg.enum_type, msg.extension, inner)
+    }
+  }
+  const pkg = file.package ?? ""
+  walkMessages(file.message_type, file.enum_type, file.extension, pkg)
+  for (const service of file.service) {
+    visit("service", service, pkg)
+    for (const method of service.method) visit("method", method, fullNameOf(pkg, service.name))
+  }
+}
+
+/** Field numbers in range, unique, and clear of reserved and extension ranges; names clear of reserved ones. */
+function checkMessage(file: any, msg: any, fullName: string, fail: Fail): void {
+  const numbers = new Map<number, string>()
+  if (file.syntax === "proto3" && msg.extension_range.length > 0) {
+    fail(file, msg.at, "Extension ranges are not allowed in proto3.")
+  }
+  for (const f of msg.field) {
+    checkFieldNumber(file, f, fail)
+    const other = numbers.get(f.number)
+    if (other !== undefined) {
+      fail(file, f.at, `Field number ${f.number} has already been used in "${fullName}" by field "${other}".`)
+    }
+    numbers.set(f.number, f.name)
+    if (msg.reserved_range.some((r: any) => f.number >= r.start && f.number < r.end)) {
+      fail(file, f.at, `Field "${f.name}" uses reserved number ${f.number}.`)
+    }
+    if (msg.reserved_name.includes(f.name)) fail(file, f.at, `Field name "${f.name}" is reserved.`)
+    const range = msg.extension_range.find((r: any) => f.number >= r.start && f.number < r.end)
+    if (range) {
+      fail(file, f.at, `Extension range ${range.start} to ${range.end - 1} includes field "${f.name}" (${f.number}).`)
+    }
+    if (f.options?.packed !== undefined && (f.label !== 3 || [9, 10, 11, 12].includes(f.type))) {
+      fail(file, f.at, "[packed = true] can only be specified for repeated primitive fields.")
+    }
+  }
+}
+
+function checkFieldNumber(file: any, f: any, fail: Fail): void {
+  if (f.number <= 0) fail(file, f.at, "Field numbers must be positive integers.")
+  if (f.number > MAX_FIELD_NUMBER) fail(file, f.at, `Field numbers cannot be greater than ${MAX_FIELD_NUMBER}.`)
+  if (f.number >= RESERVED_NUMBERS[0] && f.number <= RESERVED_NUMBERS[1]) {
+    fail(
+      file,
+      f.at,
+      `Field numbers ${RESERVED_NUMBERS[0]} through ${RESERVED_NUMBERS[1]} are reserved for the protocol buffer ` +
+        `library implementation.`
+    )
+  }
+}
+
+/** Extension numbers valid and not used twice for one message, across every file. */
+function checkExtensionNumbers(file: any, files: any[], fail: Fail): void {
+  const used = new Map<string, string>()
+  const all: Array<[any, any]> = []
+  for (const f of files) walk(f, (kind, node) => kind === "extension" && all.push([f, node]))
+  for (const [owner, ext] of all) {
+    const key = `${ext.extendee}:${ext.number}`
+    const other = used.get(key)
+    if (other !== undefined && owner === file) {
+      const extendee = ext.extendee.slice(1)
+      fail(file, ext.at, `Extension number ${ext.number} has already been used in "${extendee}" by extension "${other}".`)
+    }
+    used.set(key, ext.name)
+    if (owner === file) checkFieldNumber(file, ext, fail)
+  }
+}
+
+/** At least one value, zero first in proto3, numbers used once unless `allow_alias`, none reserved. */
+function checkEnum(file: any, e: any, fullName: string, fail: Fail): void {
+  if (e.value.length === 0) fail(file, e.at, "Enums must contain at least one value.")
+  if (file.syntax === "proto3" && e.value[0].number !== 0) {
+    fail(file, e.value[0].at, "The first enum value must be zero for open enums.")
+  }
+  const numbers = new Map<number, string>()
+  for (const value of e.value) {
+    const other = numbers.get(value.number)
+    if (other !== undefined && e.options?.allow_alias !== true) {
+      const scope = fullName.split(".").slice(0, -1).join(".")
+      fail(
+        file,
+        value.at,
+        `"${fullNameOf(scope, value.name)}" uses the same enum value as "${fullNameOf(scope, other)}". If this is ` +
+          `intended, set 'option allow_alias = true;' to the enum definition.`
+      )
+    }
+    if (other === undefined) numbers.set(value.number, value.name)
+    if (e.reserved_range.some((r: any) => value.number >= r.start && value.number <= r.end)) {
+      fail(file, value.at, `Enum value "${value.name}" uses reserved number ${value.number}.`)
+    }
+    if (e.reserved_name.includes(value.name)) fail(file, value.at, `Enum value "${value.name}" is reserved.`)
+  }
+  if (e.options?.allow_alias === true && numbers.size === e.value.length) {
+    fail(file, e.at, `"${fullName}" declares 'option allow_alias = true;', but does not have any aliased values.`)
+  }
+  // The plugin's schema has no allow_alias
+  if (e.options) delete e.options.allow_alias
+  if (e.options && Object.keys(e.options).length === 0) delete e.options
+}
+
+/** A field's `rawDefault` as `default_value`, in the text form protoc gives it. */
+function defaultValue(file: any, f: any, definitions: Map<string, Definition>, fail: Fail): void {
+  const value: (OptionValue & { at: Position }) | undefined = f.rawDefault
+  delete f.rawDefault
+  if (value === undefined) return
+  const wrong = (expected: string): never => fail(file, value.at, `Expected ${expected} for default value of "${f.name}".`)
+  if (f.label === 3) fail(file, value.at, "Repeated fields can't have default values.")
+  if (f.type === 10 || f.type === 11) fail(file, value.at, "Messages can't have default values.")
+  if (f.type === 8) {
+    if (value.kind !== "identifier" || (value.text !== "true" && value.text !== "false")) wrong(`"true" or "false"`)
+    f.default_value = value.text
+  } else if (f.type === 9) {
+    if (value.kind !== "string") wrong("string")
+    f.default_value = value.text
+  } else if (f.type === 12) {
+    if (value.kind !== "string") wrong("string")
+    f.default_value = cEscape(value.bytes!)
+  } else if (f.type === 14) {
+    const values: any[] = definitions.get(f.type_name.slice(1))?.node?.value ?? []
+    if (value.kind !== "identifier" || !values.some(v => v.name === value.text)) {
+      wrong(`a value of ${f.type_name.slice(1)}`)
+    }
+    f.default_value = value.text
+  } else if (f.type === 1 || f.type === 2) {
+    if (value.kind === "identifier" && (value.text === "inf" || value.text === "nan")) {
+      f.default_value = value.text === "nan" ? "nan" : value.negative ? "-inf" : "inf"
+    } else {
+      if (value.kind !== "integer" && value.kind !== "float") wrong("number")
+      f.default_value = formatFloat((value.negative ? -1 : 1) * Number(value.text), f.type === 2)
+    }
+  } else {
+    const integer = integerValue(value, f.type)
+    if (integer === undefined) wrong(`an integer in the range of ${typeName(f.type)}`)
+    f.default_value = integer!.toString()
+  }
+}
+
+/** An integer option or default of an integer `type`, or undefined if it is not one or out of range. */
+function integerValue(value: OptionValue, type: number): bigint | undefined {
+  if (value.kind !== "integer") return undefined
+  const text = /^0[0-7]+$/.test(value.text) ? `0o${value.text.slice(1)}` : value.text
+  const integer = value.negative ? -BigInt(text) : BigInt(text)
+  const [min, max] = INTEGER_RANGES[type]
+  return integer < min || integer > max ? undefined : integer
+}
+
+function typeName(type: number): string {
+  return { 3: "int64", 4: "uint64", 5: "int32", 6: "fixed64", 7: "fixed32", 13: "uint32" }[type] ?? `type ${type}`
+}
+
+/**
+ * A float as protoc prints defaults (`SimpleDtoa` / `SimpleFtoa`): the
+ * shortest of `%.15g` and `%.17g` (`%.6g` and `%.9g` for a float) that
+ * reads back as the same value.
+ */
+function formatFloat(value: number, isFloat: boolean): string {
+  const target = isFloat ? Math.fround(value) : value
+  const [short, long] = isFloat ? [6, 9] : [15, 17]
+  const text = formatG(target, short)
+  const same = isFloat ? Math.fround(Number(text)) === target : Number(text) === target
+  return same ? text : formatG(target, long)
+}
+
+/** `%.<precision>g`. */
+function formatG(value: number, precision: number): string {
+  if (value === 0) return Object.is(value, -0) ? "-0" : "0"
+  const exponent = Number(value.toExponential(precision - 1).split("e")[1])
+  if (exponent < -4 || exponent >= precision) {
+    const [mantissa, exp] = value.toExponential(precision - 1).split("e")
+    const digits = mantissa.includes(".") ? mantissa.replace(/\.?0+$/, "") : mantissa
+    const sign = exp.startsWith("-") ? "-" : "+"
+    return `${digits}e${sign}${exp.replace(/^[-+]/, "").padStart(2, "0")}`
+  }
+  const fixed = value.toFixed(Math.max(0, precision - 1 - exponent))
+  return fixed.includes(".") ? fixed.replace(/\.?0+$/, "") : fixed
+}
+
+/** Bytes C-escaped as protoc stores a bytes default: `\n`-style escapes, octal for other unprintable bytes. */
+function cEscape(bytes: number[]): string {
+  const named: Record<number, string> = { 0x0a: "\\n", 0x0d: "\\r", 0x09: "\\t", 0x22: '\\"', 0x27: "\\'", 0x5c: "\\\\" }
+  return bytes
+    .map(b => named[b] ?? (b >= 0x20 && b < 0x7f ? String.fromCharCode(b) : `\\${b.toString(8).padStart(3, "0")}`))
+    .join("")
+}
@@ -0,0 +1,88 @@
+import Fs from "node:fs"
+import Path from "node:path"
+import { parseProto } from "./parser.js"
+import { link } from "./linker.js"
+import { WELL_KNOWN_PROTOS } from "./well-known.js"
+
+/** proto/ of the package, holding solana/options.proto, for imports found on no include path. */
+const BUNDLED_PROTO_DIR = [Path.join(__dirname, "../../proto"), Path.join(__dirname, "../proto")].find(p =>
+  Fs.existsSync(p)
+)
+
+export interface LoadedFiles {
+  /** Every file, each after the files it imports, linked as protoc would send them */
+  files: any[]
+  /** The names (relative to their include path) of the files asked for */
+  toGenerate: string[]
+  /** The files read from disk, each once, for `--dependency_out` */
+  paths: string[]
+}
+
+/**
+ * Parse `inputs` and everything they import, searching `includes` (the
+ * `-I` directories, `.` if there are none) like protoc does, then the
+ * google/protobuf files protoc ships and the package's own proto/.
+ * Inputs are paths on disk under an include directory, or names
+ * relative to one.
+ */
+export function loadProtos(inputs: string[], includes: string[]): LoadedFiles {
+  const dirs = includes.length > 0 ? includes : ["."]
+  const toGenerate = inputs.map(input => virtualName(input, dirs))
+
+  const loaded = new Map<string, any>()
+  const files: any[] = []
+  const paths: string[] = []
+  const load = (name: string, chain: string[], importer?: string) => {
+    if (chain.includes(name)) {
+      throw new Error(`File recursively imports itself: ${[...chain.slice(chain.indexOf(name)), name].join(" -> ")}`)
+    }
+    if (loaded.has(name)) return
+    const found = readProto(name, dirs)
+    if (found === undefined) {
+      throw new Error(importer ? `${importer}: Import "${name}" was not found.` : `${name}: File not found.`)
+    }
+    if (found.path !== undefined) paths.push(found.path)
+    const file = parseProto(found.source, name)
+    for (const dep of file.dependency) load(dep, [...chain, name], name)
+    loaded.set(name, file)
+    files.push(file)
+  }
+  for (const name of toGenerate) load(name, [])
+
+  link(files)
+  return { files, toGenerate, paths }
+}
+
+/**
+ * The name protoc gives an input: a path on disk becomes relative to
+ * the include directory holding it; anything else is taken as a name
+ * relative to one.
+ */
+function virtualName(input: string, dirs: string[]): string {
+  if (!Fs.existsSync(input)) return input.split(Path.sep).join("/")
+  for (const dir of dirs) {
+    const relative = Path.relative(dir, input)
+    if (!relative.startsWith("..") && !Path.isAbsolute(relative)) return relative.split(Path.sep).join("/")
+  }
+  throw new Error(
+    `${input}: File does not reside within any path specified using --proto_path (or -I).  You must specify ` +
+      `a --proto_path which encompasses this file.`
+  )
+}
+
+/**
+ * The first `name` under an include directory, else a shipped
+ * google/protobuf file (which has no `path`), else one in proto/.
+ */
+function readProto(name: string, dirs: string[]): { source: string; path?: string } | undefined {
+  const onDisk = (dir: string) => {
+    const path = Path.join(dir, name)
+    return Fs.existsSync(path) && Fs.statSync(path).isFile() ? { source: Fs.readFileSync(path, "utf-8"), path } : undefined
+  }
+  for (const dir of dirs) {
+    const found = onDisk(dir)
+    if (found !== undefined) return found
+  }
+  if (WELL_KNOWN_PROTOS[name] !== undefined) return { source: WELL_KNOWN_PROTOS[name] }
+  return BUNDLED_PROTO_DIR ? onDisk(BUNDLED_PROTO_DIR) : undefined
+}
@@ -0,0 +1,674 @@
+import { log } from "../util/logger.js"
+import { tokenize } from "./tokenizer.js"
+import type { Token } from "./tokenizer.js"
+
+/** FieldDescriptorProto.Type of the scalar type keywords. */
+export const SCALAR_TYPES: Record<string, number> = {
+  double: 1,
+  float: 2,
+  int64: 3,
+  uint64: 4,
+  int32: 5,
+  fixed64: 6,
+  fixed32: 7,
+  bool: 8,
+  string: 9,
+  bytes: 12,
+  uint32: 13,
+  sfixed32: 15,
+  sfixed64: 16,
+  sint32: 17,
+  sint64: 18
+}
+
+/** The largest field number. */
+export const MAX_FIELD_NUMBER = 536870911
+
+const EDITIONS: Record<string, number> = { "2023": 1000, "2024": 1001 }
+
+/** Where an element was declared, to report errors at. */
+export interface Position {
+  line: number
+  column: number
+}
+
+/** An option's value as written; the linker converts it to the option's type. */
+export interface OptionValue {
+  kind: "identifier" | "integer" | "float" | "string" | "aggregate"
+  text: string
+  negative: boolean
+  /** A string's bytes, escapes resolved */
+  bytes?: number[]
+}
+
+/** An `option` statement or `[...]` entry: `(solana.max_len)` is one extension part, `features.x` two plain ones. */
+export interface RawOption {
+  name: Array<{ name: string; extension: boolean }>
+  value: OptionValue
+  at: Position
+}
+
+/**
+ * Parse a `.proto` file into a FileDescriptorProto-shaped object, as
+ * protoc's parser does before linking. In the result, message and enum
+ * types are still the names written (`typeRef`, `extendeeRef`,
+ * `inputRef`), options are `rawOptions` lists and field defaults
+ * `rawDefault` values, each element has its position (`at`), and
+ * `source_code_info` has the leading and trailing comments; `link`
+ * resolves the rest.
+ */
+export function parseProto(source: string, fileName: string): any {
+  const tokens = tokenize(source, fileName)
+  let index = 0
+  let syntax: "proto2" | "proto3" | "editions" = "proto2"
+  const locations: any[] = []
+
+  const peek = (offset = 0): Token | undefined => tokens[index + offset]
+  const lookingAt = (text: string) => peek()?.text === text
+  const fail = (message: string, at: Position | undefined = peek()): never => {
+    const where = at ?? tokens[tokens.length - 1]
+    const position = where ? `${where.line + 1}:${where.column + 1}` : "1:1"
+    throw new Error(`${fileName}:${position}: ${message}`)
+  }
+  const next = (): Token => {
+    const token = peek()
+    if (!token) fail("Unexpected end of file.")
+    index++
+    return token!
+  }
+  const tryConsume = (text: string) => {
+    if (!lookingAt(text)) return false
+    index++
+    return true
+  }
+  const expect = (text: string): Token => {
+    if (!lookingAt(text)) fail(`Expected "${text}".`)
+    return next()
+  }
+  const identifier = (message = "Expected identifier."): string => {
+    if (peek()?.kind !== "identifier") fail(message)
+    return next().text
+  }
+  const position = (token: Token): Position => ({ line: token.line, column: token.column })
+
+  // `a.b.c`, or with `absolute` a type name that may start with `.`
+  const fullName = (message?: string, absolute = false): string => {
+    let name = absolute && tryConsume(".") ? "." : ""
+    name += identifier(message)
+    while (tryConsume(".")) name += `.${identifier()}`
+    return name
+  }
+
+  const integer = (token: Token): bigint => {
+    if (token.kind !== "integer") fail("Expected integer.", token)
+    const text = /^0[0-7]+$/.test(token.text) ? `0o${token.text.slice(1)}` : token.text
+    return BigInt(text)
+  }
+  // A field or enum number: an int32, here optionally negative
+  const number = (negativeAllowed: boolean): number => {
+    const negative = negativeAllowed && tryConsume("-")
+    const token = next()
+    const value = negative ? -integer(token) : integer(token)
+    if (value > 2147483647n || value < -2147483648n) fail("Integer out of range.", token)
+    return Number(value)
+  }
+
+  const stringBytes = (): number[] => {
+    if (peek()?.kind !== "string") fail("Expected string.")
+    const bytes: number[] = []
+    // Adjacent strings are concatenated
+    while (peek()?.kind === "string") bytes.push(...unescape(next(), fail))
+    return bytes
+  }
+
+  const optionValue = (): OptionValue => {
+    if (lookingAt("{")) {
+      // An aggregate value, in text format: only its extent matters here
+      const start = index
+      let depth = 0
+      do {
+        const token = next()
+        if (token.text === "{") depth++
+        if (token.text === "}") depth--
+      } while (depth > 0)
+      return { kind: "aggregate", text: tokens.slice(start, index).map(t => t.text).join(" "), negative: false }
+    }
+    if (peek()?.kind === "string") {
+      const bytes = stringBytes()
+      return { kind: "string", text: new TextDecoder().decode(new Uint8Array(bytes)), negative: false, bytes }
+    }
+    const negative = tryConsume("-")
+    if (!negative) tryConsume("+")
+    const token = next()
+    const signed = token.kind === "integer" || token.kind === "float" || token.text === "inf" || token.text === "nan"
+    if (negative && !signed) fail("Expected number.", token)
+    if (token.kind === "symbol" || token.kind === "string") fail("Expected option value.", token)
+    return { kind: token.kind as OptionValue["kind"], text: token.text, negative }
+  }
+
+  const optionName = (): RawOption["name"] => {
+    const parts: RawOption["name"] = []
+    do {
+      if (tryConsume("(")) {
+        parts.push({ name: fullName(undefined, true), extension: true })
+        expect(")")
+      } else {
+        parts.push({ name: identifier(), extension: false })
+      }
+    } while (tryConsume("."))
+    return parts
+  }
+
+  // `option <name> = <value>;`
+  const optionStatement = (into: RawOption[]) => {
+    expect("option")
+    const at = position(peek()!)
+    const name = optionName()
+    expect("=")
+    into.push({ name, value: optionValue(), at })
+  }
+
+  // `[<name> = <value>, ...]`, if present
+  const optionList = (into: RawOption[]) => {
+    if (!tryConsume("[")) return
+    do {
+      const at = position(peek()!)
+      const name = optionName()
+      expect("=")
+      into.push({ name, value: optionValue(), at })
+    } while (tryConsume(","))
+    expect("]")
+  }
+
+  // Comments of the element whose first token is `first` and whose declaration ends with `end`
+  const recordDocs = (path: number[], first: Token, end: Token) => {
+    if (first.leading === undefined && end.trailing === undefined) return
+    locations.push({ path, leading_comments: first.leading, trailing_comments: end.trailing })
+  }
+
+  // `1, 5 to 9, 100 to max`: ranges with exclusive ends, or inclusive ones for enums
+  const ranges = (max: number, inclusive: boolean): Array<{ start: number; end: number }> => {
+    const result: Array<{ start: number; end: number }> = []
+    do {
+      const start = number(inclusive)
+      let end = start
+      if (tryConsume("to")) end = tryConsume("max") ? max : number(inclusive)
+      if (end < start) fail("Reserved range end number must be greater than start number.")
+      result.push({ start, end: inclusive ? end : end + 1 })
+    } while (tryConsume(","))
+    return result
+  }
+
+  // `reserved` ranges or names (quoted, or in editions bare identifiers)
+  const reserved = (into: any, max: number, inclusive: boolean) => {
+    expect("reserved")
+    if (peek()?.kind === "integer" || (inclusive && lookingAt("-"))) {
+      into.reserved_range.push(...ranges(max, inclusive))
+    } else {
+      do {
+        if (syntax === "editions") {
+          if (peek()?.kind === "string") fail("Reserved names must be identifiers in editions, not string literals.")
+          into.reserved_name.push(identifier("Expected field name or number range."))
+        } else {
+          if (peek()?.kind === "identifier") {
+            fail("Reserved names must be string literals. (Only editions supports identifiers.)")
+          }
+          into.reserved_name.push(new TextDecoder().decode(new Uint8Array(stringBytes())))
+        }
+      } while (tryConsume(","))
+    }
+    expect(";")
+  }
+
+  // A field, group or map field of a message, oneof or `extend` block
+  interface FieldScope {
+    fields: any[]
+    nested: any[]
+    fieldPath: number[]
+    nestedPath: number[]
+    oneofIndex?: number
+    extendee?: { name: string; at: Position }
+  }
+  const field = (scope: FieldScope) => {
+    const first = peek()!
+    const fieldPath = [...scope.fieldPath, scope.fields.length]
+    let label: string | undefined
+    if (lookingAt("optional") || lookingAt("required") || lookingAt("repeated")) {
+      label = next().text
+      if (scope.oneofIndex !== undefined) {
+        fail("Fields in oneofs must not have labels (required / optional / repeated).", first)
+      }
+      if (syntax === "proto3" && label === "required") fail("Required fields are not allowed in proto3.", first)
+      if (syntax === "editions" && label === "optional") {
+        fail(
+          `Label "optional" is not supported in editions. Singular fields have explicit presence, see ` +
+            `features.field_presence.`,
+          first
+        )
+      }
+      if (syntax === "editions" && label === "required") {
+        fail(`Label "required" is not supported in editions, use features.field_presence = LEGACY_REQUIRED.`, first)
+      }
+    }
+    const isMap = lookingAt("map") && peek(1)?.text === "<"
+    if (isMap && label) fail("Field labels (required/optional/repeated) are not allowed on map fields.", first)
+    const isGroup = lookingAt("group")
+    if (!label && !isMap && syntax === "proto2" && scope.oneofIndex === undefined) {
+      fail(`Expected "required", "optional", or "repeated".`)
+    }
+
+    const descriptor: any = { label: { required: 2, repeated: 3 }[label ?? ""] ?? 1, rawOptions: [] }
+    let entry: any
+    if (isMap) {
+      next()
+      expect("<")
+      const keyAt = position(peek()!)
+      const key = fieldType()
+      expect(",")
+      const value = fieldType()
+      expect(">")
+      if (key.typeRef !== undefined || [1, 2, 12].includes(key.type)) {
+        fail("Key in map fields cannot be float/double, bytes or message types.", tokens[index - 1])
+      }
+      entry = {
+        field: [
+          { name: "key", number: 1, label: 1, json_name: "key", ...key, at: keyAt, rawOptions: [] },
+          { name: "value", number: 2, label: 1, json_name: "value", ...value, at: keyAt, rawOptions: [] }
+        ],
+        options: { map_entry: true }
+      }
+      descriptor.label = 3
+    } else if (isGroup) {
+      if (syntax === "proto3") fail("Groups are not supported in proto3 syntax.")
+      if (syntax === "editions") {
+        fail("Group syntax is no longer supported in editions. Use a message with features.message_encoding = DELIMITED.")
+      }
+      next()
+      descriptor.type = 10
+    } else {
+      descriptor.typeAt = position(peek()!)
+      Object.assign(descriptor, fieldType())
+    }
+
+    const nameToken = peek()!
+    descriptor.name = identifier(isGroup ? "Expected group name." : "Expected field name.")
+    descriptor.at = position(nameToken)
+    expect("=")
+    descriptor.number = number(false)
+    optionList(descriptor.rawOptions)
+    pseudoOptions(descriptor)
+    if (scope.oneofIndex !== undefined) descriptor.oneof_index = scope.oneofIndex
+    if (label === "optional" && syntax === "proto3") descriptor.proto3_optional = true
+    if (scope.extendee) {
+      descriptor.extendeeRef = scope.extendee.name
+      descriptor.extendeeAt = scope.extendee.at
+    }
+    scope.fields.push(descriptor)
+
+    if (isMap) {
+      entry.name = mapEntryName(descriptor.name)
+      entry.at = descriptor.at
+      descriptor.typeRef = entry.name
+      descriptor.typeAt = descriptor.at
+      scope.nested.push(newMessage(entry))
+      recordDocs(fieldPath, first, expect(";"))
+    } else if (isGroup) {
+      // FieldDescriptorProto names a group after its type, in lower case
+      if (!/^[A-Z]/.test(descriptor.name)) fail("Group names must start with a capital letter.", nameToken)
+      const group = newMessage({ name: descriptor.name, at: descriptor.at })
+      descriptor.name = descriptor.name.toLowerCase()
+      descriptor.typeRef = group.name
+      descriptor.typeAt = descriptor.at
+      const nestedPath = [...scope.nestedPath, scope.nested.length]
+      scope.nested.push(group)
+      recordDocs(fieldPath, first, expect("{"))
+      messageBody(group, nestedPath)
+    } else {
+      recordDocs(fieldPath, first, expect(";"))
+    }
+  }
+
+  // A scalar type keyword, or the name of a message or enum
+  const fieldType = (): { type?: number; typeRef?: string } => {
+    const token = peek()
+    if (token?.kind === "identifier" && SCALAR_TYPES[token.text] !== undefined && peek(1)?.text !== ".") {
+      next()
+      return { type: SCALAR_TYPES[token.text] }
+    }
+    return { typeRef: fullName("Expected type name.", true) }
+  }
+
+  // `default` and `json_name` are not options: they set descriptor fields
+  const pseudoOptions = (descriptor: any) => {
+    descriptor.rawOptions = descriptor.rawOptions.filter((option: RawOption) => {
+      const [part, ...rest] = option.name
+      if (part.extension || rest.length > 0 || (part.name !== "default" && part.name !== "json_name")) return true
+      const key = part.name === "default" ? "rawDefault" : "json_name"
+      if (descriptor[key] !== undefined) fail(`Already set option "${part.name}".`, option.at)
+      if (part.name === "default") {
+        if (syntax === "proto3") fail("Explicit default values are not allowed in proto3.", option.at)
+        descriptor.rawDefault = { ...option.value, at: option.at }
+      } else {
+        if (option.value.kind !== "string") fail("Expected string for JSON name.", option.at)
+        descriptor.json_name = option.value.text
+      }
+      return false
+    })
+  }
+
+  const newMessage = (fields: any) => ({
+    field: [],
+    nested_type: [],
+    enum_type: [],
+    extension_range: [],
+    extension: [],
+    oneof_decl: [],
+    reserved_range: [],
+    reserved_name: [],
+    rawOptions: [],
+    ...fields
+  })
+
+  const message = (into: any[], path: number[]) => {
+    const first = expect("message")
+    const nameToken = peek()!
+    const msg = newMessage({ name: identifier("Expected message name."), at: position(nameToken) })
+    into.push(msg)
+    recordDocs(path, first, expect("{"))
+    messageBody(msg, path)
+  }
+
+  // Everything between a message's (or group's) braces, and the closing brace
+  const messageBody = (msg: any, path: number[]) => {
+    const scope: FieldScope = {
+      fields: msg.field,
+      nested: msg.nested_type,
+      fieldPath: [...path, 2],
+      nestedPath: [...path, 3]
+    }
+    while (!tryConsume("}")) {
+      if (!peek()) fail("Reached end of input in message definition (missing '}').")
+      if (tryConsume(";")) continue
+      if (lookingAt("message")) {
+        message(msg.nested_type, [...path, 3, msg.nested_type.length])
+      } else if (lookingAt("enum")) {
+        enumType(msg.enum_type, [...path, 4, msg.enum_type.length])
+      } else if (lookingAt("extend")) {
+        extend(msg.extension, msg.nested_type, [...path, 6], [...path, 3])
+      } else if (lookingAt("option")) {
+        optionStatement(msg.rawOptions)
+        expect(";")
+      } else if (lookingAt("oneof")) {
+        oneof(msg, path, scope)
+      } else if (lookingAt("reserved")) {
+        reserved(msg, MAX_FIELD_NUMBER, false)
+      } else if (lookingAt("extensions")) {
+        next()
+        msg.extension_range.push(...ranges(MAX_FIELD_NUMBER, false))
+        // Extension declarations and verification are not checked
+        optionList([])
+        expect(";")
+      } else {
+        field(scope)
+      }
+    }
+    syntheticOneofs(msg)
+  }
+
+  const oneof = (msg: any, path: number[], scope: FieldScope) => {
+    const first = expect("oneof")
+    const oneofIndex = msg.oneof_decl.length
+    const decl: any = { name: identifier("Expected oneof name."), rawOptions: [], at: position(tokens[index - 1]) }
+    msg.oneof_decl.push(decl)
+    recordDocs([...path, 8, oneofIndex], first, expect("{"))
+    const start = msg.field.length
+    while (!tryConsume("}")) {
+      if (!peek()) fail("Reached end of input in oneof definition (missing '}').")
+      if (tryConsume(";")) continue
+      if (lookingAt("option")) {
+        optionStatement(decl.rawOptions)
+        expect(";")
+        continue
+      }
+      if (lookingAt("map") && peek(1)?.text === "<") fail("Map fields are not allowed in oneofs.")
+      field({ ...scope, oneofIndex })
+    }
+    if (msg.field.length === start) fail("Oneof must have at least one field.", tokens[index - 1])
+  }
+
+  // proto3 `optional` fields each get a oneof of their own, after the declared ones
+  const syntheticOneofs = (msg: any) => {
+    const taken = new Set<string>([
+      ...msg.field.map((f: any) => f.name),
+      ...msg.nested_type.map((m: any) => m.name),
+      ...msg.enum_type.map((e: any) => e.name),
+      ...msg.oneof_decl.map((o: any) => o.name)
+    ])
+    for (const f of msg.field) {
+      if (!f.proto3_optional) continue
+      let name = `_${f.name}`
+      while (taken.has(name)) name = `X${name}`
+      taken.add(name)
+      f.oneof_index = msg.oneof_decl.length
+      msg.oneof_decl.push({ name, rawOptions: [], at: f.at })
+    }
+  }
+
+  // `extend <Type> { ... }`: the fields go to `into`, groups' types to `nested`
+  const extend = (into: any[], nested: any[], fieldPath: number[], nestedPath: number[]) => {
+    expect("extend")
+    const at = position(peek()!)
+    const name = fullName("Expected type name.", true)
+    expect("{")
+    while (!tryConsume("}")) {
+      if (!peek()) fail("Reached end of input in extend definition (missing '}').")
+      if (tryConsume(";")) continue
+      if (lookingAt("map") && peek(1)?.text === "<") fail("Map fields are not allowed to be extensions.")
+      field({ fields: into, nested, fieldPath, nestedPath, extendee: { name, at } })
+    }
+  }
+
+  const enumType = (into: any[], path: number[]) => {
+    const first = expect("enum")
+    const e: any = { name: identifier("Expected enum name."), at: position(tokens[index - 1]) }
+    Object.assign(e, { value: [], reserved_range: [], reserved_name: [], rawOptions: [] })
+    into.push(e)
+    recordDocs(path, first, expect("{"))
+    while (!tryConsume("}")) {
+      if (!peek()) fail("Reached end of input in enum definition (missing '}').")
+      if (tryConsume(";")) continue
+      if (lookingAt("option")) {
+        optionStatement(e.rawOptions)
+        expect(";")
+      } else if (lookingAt("reserved")) {
+        reserved(e, 2147483647, true)
+      } else {
+        const valueFirst = peek()!
+        const value: any = { name: identifier("Expected enum constant name."), at: position(valueFirst), rawOptions: [] }
+        expect("=")
+        value.number = number(true)
+        optionList(value.rawOptions)
+        recordDocs([...path, 2, e.value.length], valueFirst, expect(";"))
+        e.value.push(value)
+      }
+    }
+  }
+
+  const service = (into: any[], path: number[]) => {
+    const first = expect("service")
+    const s: any = { name: identifier("Expected service name."), at: position(tokens[index - 1]) }
+    Object.assign(s, { method: [], rawOptions: [] })
+    into.push(s)
+    recordDocs(path, first, expect("{"))
+    while (!tryConsume("}")) {
+      if (!peek()) fail("Reached end of input in service definition (missing '}').")
+      if (tryConsume(";")) continue
+      if (lookingAt("option")) {
+        optionStatement(s.rawOptions)
+        expect(";")
+        continue
+      }
+      const methodFirst = expect("rpc")
+      const method: any = { name: identifier("Expected method name."), at: position(tokens[index - 1]), rawOptions: [] }
+      expect("(")
+      if (lookingAt("stream") && peek(1)?.text !== ")") {
+        next()
+        method.client_streaming = true
+      }
+      method.inputAt = position(peek()!)
+      method.inputRef = fullName("Expected message type.", true)
+      expect(")")
+      expect("returns")
+      expect("(")
+      if (lookingAt("stream") && peek(1)?.text !== ")") {
+        next()
+        method.server_streaming = true
+      }
+      method.outputAt = position(peek()!)
+      method.outputRef = fullName("Expected message type.", true)
+      expect(")")
+      const methodPath = [...path, 2, s.method.length]
+      if (lookingAt("{")) {
+        recordDocs(methodPath, methodFirst, next())
+        while (!tryConsume("}")) {
+          if (!peek()) fail("Reached end of input in method options (missing '}').")
+          if (tryConsume(";")) continue
+          optionStatement(method.rawOptions)
+          expect(";")
+        }
+      } else {
+        recordDocs(methodPath, methodFirst, expect(";"))
+      }
+      s.method.push(method)
+    }
+  }
+
+  const file: any = {
+    name: fileName,
+    dependency: [],
+    public_dependency: [],
+    weak_dependency: [],
+    message_type: [],
+    enum_type: [],
+    service: [],
+    extension: [],
+    rawOptions: [],
+    source_code_info: { location: locations }
+  }
+
+  if (lookingAt("syntax")) {
+    next()
+    expect("=")
+    const token = peek()!
+    const value = new TextDecoder().decode(new Uint8Array(stringBytes()))
+    if (value !== "proto2" && value !== "proto3") {
+      fail(`Unrecognized syntax identifier "${value}".  This parser only recognizes "proto2" and "proto3".`, token)
+    }
+    syntax = value
+    if (syntax === "proto3") file.syntax = "proto3"
+    expect(";")
+  } else if (lookingAt("edition")) {
+    next()
+    expect("=")
+    const token = peek()!
+    const value = new TextDecoder().decode(new Uint8Array(stringBytes()))
+    if (EDITIONS[value] === undefined) fail(`Unknown edition "${value}".`, token)
+    syntax = "editions"
+    file.syntax = "editions"
+    file.edition = EDITIONS[value]
+    expect(";")
+  } else {
+    log.warn(`No syntax specified for ${fileName}; it is read as proto2. Add 'syntax = "proto2";' to be explicit.`)
+  }
+
+  while (peek()) {
+    if (tryConsume(";")) continue
+    if (lookingAt("package")) {
+      next()
+      if (file.package !== undefined) fail("Multiple package definitions.", tokens[index - 1])
+      file.package = fullName("Expected package name.")
+      expect(";")
+    } else if (lookingAt("import")) {
+      next()
+      if (tryConsume("public")) file.public_dependency.push(file.dependency.length)
+      else if (tryConsume("weak")) file.weak_dependency.push(file.dependency.length)
+      file.dependency.push(new TextDecoder().decode(new Uint8Array(stringBytes())))
+      expect(";")
+    } else if (lookingAt("option")) {
+      optionStatement(file.rawOptions)
+      expect(";")
+    } else if (lookingAt("message")) {
+      message(file.message_type, [4, file.message_type.length])
+    } else if (lookingAt("enum")) {
+      enumType(file.enum_type, [5, file.enum_type.length])
+    } else if (lookingAt("service")) {
+      service(file.service, [6, file.service.length])
+    } else if (lookingAt("extend")) {
+      extend(file.extension, file.message_type, [7], [4])
+    } else if (lookingAt("syntax") || lookingAt("edition")) {
+      fail(`"${peek()!.text}" must be the first statement of the file.`)
+    } else {
+      fail(`Expected top-level statement (e.g. "message").`)
+    }
+  }
+  return file
+}
+
+/** protoc's name for a map field's entry type: `token_balances` → `TokenBalancesEntry`. */
+function mapEntryName(fieldName: string): string {
+  return fieldName.replace(/(?:^|_)([a-z]?)/g, (_, c: string) => c.toUpperCase()) + "Entry"
+}
+
+const ESCAPES: Record<string, number> = {
+  a: 0x07,
+  b: 0x08,
+  f: 0x0c,
+  n: 0x0a,
+  r: 0x0d,
+  t: 0x09,
+  v: 0x0b,
+  "\\": 0x5c,
+  "'": 0x27,
+  '"': 0x22,
+  "?": 0x3f
+}
+
+/** The bytes of a string token: `\n`, octal, `\x` hex and `\u` / `\U` escapes resolved, the rest UTF-8. */
+function unescape(token: Token, fail: (message: string, at: Position) => never): number[] {
+  const text = token.text.slice(1, -1)
+  const bytes: number[] = []
+  const utf8 = (s: string) => bytes.push(...new TextEncoder().encode(s))
+  for (let i = 0; i < text.length; i++) {
+    if (text[i] !== "\\") {
+      const c = String.fromCodePoint(text.codePointAt(i)!)
+      utf8(c)
+      i += c.length - 1
+      continue
+    }
+    const c = text[++i]
+    if (ESCAPES[c] !== undefined) {
+      bytes.push(ESCAPES[c])
+    } else if (/[0-7]/.test(c)) {
+      const digits = /^[0-7]{1,3}/.exec(text.slice(i))![0]
+      bytes.push(parseInt(digits, 8) & 0xff)
+      i += digits.length - 1
+    } else if (c === "x" || c === "X") {
+      const digits = /^[0-9a-fA-F]{1,2}/.exec(text.slice(i + 1))?.[0]
+      if (!digits) fail("Expected hex digits for escape sequence.", token)
+      bytes.push(parseInt(digits!, 16))
+      i += digits!.length
+    } else if (c === "u" || c === "U") {
+      const length = c === "u" ? 4 : 8
+      const digits = text.slice(i + 1, i + 1 + length)
+      const code = parseInt(digits, 16)
+      if (!/^[0-9a-fA-F]+$/.test(digits) || digits.length !== length || code > 0x10ffff) {
+        fail("Expected four hex digits for \\u escape sequence.", token)
+      }
+      utf8(String.fromCodePoint(code))
+      i += length
+    } else {
+      fail("Invalid escape sequence in string literal.", token)
+    }
+  }
+  return bytes
+}
@@ -0,0 +1,245 @@
+/** A token of a `.proto` file, with the comments protoc attaches to it. */
+export interface Token {
+  kind: "identifier" | "integer" | "float" | "string" | "symbol"
+  /** The source text; a string keeps its quotes and escapes */
+  text: string
+  /** 0-based position, as in protoc (which prints it 1-based) */
+  line: number
+  column: number
+  /** The comment block just before the token, not separated from it by a blank line */
+  leading?: string
+  /** The comment after the token: on its line, or the block on the next line if a blank line follows */
+  trailing?: string
+}
+
+/**
+ * Split a `.proto` file into tokens, attaching comments as protoc's
+ * tokenizer does (`Tokenizer::NextWithComments`), so `SourceCodeInfo`
+ * carries the same leading and trailing comments.
+ */
+export function tokenize(source: string, fileName: string): Token[] {
+  let pos = 0
+  let line = 0
+  let column = 0
+
+  const fail = (message: string): never => {
+    throw new Error(`${fileName}:${line + 1}:${column + 1}: ${message}`)
+  }
+  const at = (offset = 0) => source[pos + offset] ?? ""
+  const advance = () => {
+    if (source[pos] === "\n") {
+      line++
+      column = 0
+    } else {
+      // protoc counts a tab to the next multiple of 8
+      column += source[pos] === "\t" ? 8 - (column % 8) : 1
+    }
+    pos++
+  }
+  const tryConsume = (c: string) => {
+    if (at() !== c) return false
+    advance()
+    return true
+  }
+  const skipSpaceNoNewline = () => {
+    while (/[ \t\r\v\f]/.test(at())) advance()
+  }
+  const tryCommentStart = (): "line" | "block" | undefined => {
+    if (at() !== "/" || (at(1) !== "/" && at(1) !== "*")) return undefined
+    const kind = at(1) === "/" ? "line" : "block"
+    advance()
+    advance()
+    return kind
+  }
+  // The text after `//`, up to and including the newline
+  const lineComment = () => {
+    const start = pos
+    while (at() !== "" && at() !== "\n") advance()
+    tryConsume("\n")
+    return source.slice(start, pos)
+  }
+  // The text between `/*` and `*/`, without each further line's indent and leading `*`
+  const blockComment = () => {
+    let content = ""
+    let start = pos
+    for (;;) {
+      while (at() !== "" && at() !== "*" && at() !== "/" && at() !== "\n") advance()
+      if (at() === "\n") {
+        advance()
+        content += source.slice(start, pos)
+        skipSpaceNoNewline()
+        if (tryConsume("*") && tryConsume("/")) return content
+        start = pos
+      } else if (at() === "*" && at(1) === "/") {
+        content += source.slice(start, pos)
+        advance()
+        advance()
+        return content
+      } else if (at() === "/" && at(1) === "*") {
+        fail(`"/*" inside block comment. Block comments cannot be nested.`)
+      } else if (at() === "") {
+        fail("End-of-file inside block comment.")
+      } else {
+        advance()
+      }
+    }
+  }
+
+  // The next token, skipping whitespace and comments; undefined at the end of the file
+  const next = (): Token | undefined => {
+    for (;;) {
+      while (/\s/.test(at())) advance()
+      const comment = tryCommentStart()
+      if (comment === "line") lineComment()
+      else if (comment === "block") blockComment()
+      else break
+    }
+    if (at() === "") return undefined
+    const token = { line, column }
+    const start = pos
+    const c = at()
+    let kind: Token["kind"]
+    if (/[A-Za-z_]/.test(c)) {
+      kind = "identifier"
+      while (/[A-Za-z0-9_]/.test(at())) advance()
+    } else if (/[0-9]/.test(c) || (c === "." && /[0-9]/.test(at(1)))) {
+      kind = number()
+    } else if (c === '"' || c === "'") {
+      kind = "string"
+      advance()
+      while (!tryConsume(c)) {
+        if (at() === "" || at() === "\n") fail(`Multiline strings are not allowed. Did you miss a ${c}?`)
+        if (at() === "\\") advance()
+        advance()
+      }
+    } else {
+      kind = "symbol"
+      advance()
+    }
+    return { kind, text: source.slice(start, pos), ...token }
+  }
+  const number = (): Token["kind"] => {
+    let kind: Token["kind"] = "integer"
+    if (at() === "0" && /[xX]/.test(at(1))) {
+      advance()
+      advance()
+      if (!/[0-9a-fA-F]/.test(at())) fail(`"0x" must be followed by hex digits.`)
+      while (/[0-9a-fA-F]/.test(at())) advance()
+    } else {
+      while (/[0-9]/.test(at())) advance()
+      if (at() === ".") {
+        kind = "float"
+        advance()
+        while (/[0-9]/.test(at())) advance()
+      }
+      if (/[eE]/.test(at())) {
+        kind = "float"
+        advance()
+        if (at() === "+" || at() === "-") advance()
+        if (!/[0-9]/.test(at())) fail(`"e" must be followed by exponent.`)
+        while (/[0-9]/.test(at())) advance()
+      }
+    }
+    if (/[A-Za-z0-9_.]/.test(at())) fail("Need space between number and identifier.")
+    return kind
+  }
+
+  // protoc's CommentCollector: comments between the previous token and the
+  // next one go to the previous token's trailing comment, are detached, or
+  // become the next token's leading comment
+  interface Collector {
+    buffer: string | undefined
+    isLine: boolean
+    canAttachToPrev: boolean
+    trailing: string | undefined
+    comments: number
+  }
+  const flush = (c: Collector) => {
+    if (c.buffer === undefined) return
+    if (c.canAttachToPrev) {
+      c.trailing = c.buffer
+      c.canAttachToPrev = false
+    }
+    c.buffer = undefined
+    c.comments++
+  }
+  const readComment = (c: Collector, kind: "line" | "block") => {
+    // Consecutive line comments form one block; a block comment stands alone
+    if (c.buffer !== undefined && (kind === "block" || !c.isLine)) flush(c)
+    const text = kind === "line" ? lineComment() : blockComment()
+    c.buffer = (kind === "line" && c.buffer !== undefined ? c.buffer : "") + text
+    c.isLine = kind === "line"
+  }
+  const maybeDetach = (c: Collector) => {
+    // A lone comment between two tokens on the same line belongs to neither
+    if (c.comments + (c.buffer !== undefined ? 1 : 0) !== 1) return
+    c.trailing = undefined
+    c.canAttachToPrev = false
+    c.buffer = undefined
+  }
+
+  const tokens: Token[] = []
+  for (;;) {
+    const prev = tokens[tokens.length - 1]
+    const c: Collector = { buffer: undefined, isLine: false, canAttachToPrev: true, trailing: undefined, comments: 0 }
+    const prevLine = line
+    let trailingEndLine = -1
+    let token: Token | undefined
+    let sameLine = false
+
+    if (prev === undefined) {
+      c.canAttachToPrev = false
+    } else {
+      // A comment on the previous token's line is its trailing comment
+      skipSpaceNoNewline()
+      const comment = tryCommentStart()
+      if (comment === "line") {
+        trailingEndLine = line
+        readComment(c, comment)
+        flush(c)
+      } else if (comment === "block") {
+        readComment(c, comment)
+        trailingEndLine = line
+        skipSpaceNoNewline()
+        if (!tryConsume("\n")) {
+          // The next token is on the same line too, so the comment could be either's
+          c.buffer = undefined
+          token = next()
+          sameLine = true
+        } else {
+          flush(c)
+        }
+      } else if (!tryConsume("\n")) {
+        token = next()
+        sameLine = true
+      }
+    }
+
+    while (!sameLine) {
+      skipSpaceNoNewline()
+      const comment = tryCommentStart()
+      if (comment === "line") {
+        readComment(c, comment)
+      } else if (comment === "block") {
+        readComment(c, comment)
+        skipSpaceNoNewline()
+        tryConsume("\n")
+      } else if (tryConsume("\n")) {
+        // A blank line ends the block, which can only be the previous token's trailing comment
+        flush(c)
+        c.canAttachToPrev = false
+      } else {
+        token = next()
+        // Nothing follows the end of a scope, so a comment before it trails the previous token
+        if (token === undefined || token.text === "}" || token.text === "]" || token.text === ")") flush(c)
+        if (token !== undefined && (prevLine === line || trailingEndLine === line)) maybeDetach(c)
+        break
+      }
+    }
+
+    if (prev !== undefined && c.trailing !== undefined) prev.trailing = c.trailing
+    if (token === undefined) return tokens
+    if (!sameLine && c.buffer !== undefined) token.leading = c.buffer
+    tokens.push(token)
+  }
+}
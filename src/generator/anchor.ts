@@ -0,0 +1,58 @@
+import Crypto from "node:crypto"
+
+/**
+ * 8-byte account discriminator for a message: the first bytes of
+ * `sha256("account:<full name>")`, as Anchor derives it from a struct name.
+ */
+export function accountDiscriminator(fullName: string): number[] {
+  const name = fullName.replace(/^\./, "")
+  return [...Crypto.createHash("sha256").update(`account:${name}`).digest().subarray(0, 8)]
+}
+
+/**
+ * Generate the `anchor` option's `AccountSerialize`, `AccountDeserialize`
+ * and `Owner` impls, so the message can be used as `Account<'info, T>`.
+ * Account data is the discriminator, a u32 payload length and the
+ * protobuf payload (see `encode_account_data`); the owner is `crate::ID`.
+ */
+export function genAnchorImpl(structName: string, fullName: string): string {
+  const discriminator = accountDiscriminator(fullName)
+  const name = fullName.replace(/^\./, "")
+  return [
+    `impl ${structName} {`,
+    `    /// \`sha256("account:${name}")[..8]\``,
+    `    pub const DISCRIMINATOR: [u8; 8] = [${discriminator.join(", ")}];`,
+    `}`,
+    ``,
+    `impl anchor_lang::AccountSerialize for ${structName} {`,
+    `    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {`,
+    `        writer`,
+    `            .write_all(&encode_account_data(&Self::DISCRIMINATOR, self))`,
+    `            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize.into())`,
+    `    }`,
+    `}`,
+    ``,
+    `impl anchor_lang::AccountDeserialize for ${structName} {`,
+    `    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {`,
+    `        if buf.len() < Self::DISCRIMINATOR.len() {`,
+    `            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into());`,
+    `        }`,
+    `        if buf[..Self::DISCRIMINATOR.len()] != Self::DISCRIMINATOR {`,
+    `            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());`,
+    `        }`,
+    `        Self::try_deserialize_unchecked(buf)`,
+    `    }`,
+    ``,
+    `    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {`,
+    `        decode_account_data(buf)`,
+    `            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())`,
+    `    }`,
+    `}`,
+    ``,
+    `impl anchor_lang::Owner for ${structName} {`,
+    `    fn owner() -> anchor_lang::prelude::Pubkey {`,
+    `        crate::ID`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
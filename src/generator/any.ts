@@ -0,0 +1,46 @@
+/**
+ * Generate the `MessageName` impl that lets `Any::pack` / `Any::unpack`
+ * address the message by type URL.
+ */
+export function genMessageName(structName: string, fullName: string): string {
+  return [
+    `impl MessageName for ${structName} {`,
+    `    const FULL_NAME: &'static str = "${fullName.replace(/^\./, "")}";`,
+    `}`
+  ].join("\n")
+}
+
+/**
+ * Generate the file's `AnyMessage` registry: one variant per (public)
+ * message and `unpack`, which dispatches an `Any` on its type URL.
+ */
+export function genAnyRegistry(structNames: string[]): string {
+  return [
+    `/// Every message in this file, for dispatching \`Any\` payloads by type URL.`,
+    `#[derive(Clone, Debug, PartialEq)]`,
+    `#[allow(clippy::large_enum_variant)]`,
+    `pub enum AnyMessage {`,
+    ...structNames.map(name => `    ${name}(${name}),`),
+    `}`,
+    ``,
+    `impl AnyMessage {`,
+    `    /// Decode \`any\` as the message its type URL names, or \`None\` if that`,
+    `    /// message is not in this file.`,
+    `    pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {`,
+    `        Ok(Some(match any.type_name() {`,
+    ...structNames.map(name => {
+      const decode = `${name}::decode_with(&any.value, &DecodeOptions::ACCOUNT)?`
+      return `            ${name}::FULL_NAME => AnyMessage::${name}(${decode}),`
+    }),
+    `            _ => return Ok(None),`,
+    `        }))`,
+    `    }`,
+    ``,
+    `    pub fn pack(&self) -> Any {`,
+    `        match self {`,
+    ...structNames.map(name => `            AnyMessage::${name}(msg) => Any::pack(msg),`),
+    `        }`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
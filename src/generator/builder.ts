@@ -0,0 +1,158 @@
+import {
+  FieldInfo,
+  fieldRustName,
+  fieldVisibility,
+  isMap,
+  isRepeated,
+  elemRustType,
+  isOptional,
+  mapKeyField,
+  mapValueField,
+  mapRustType,
+  repeatedRustType
+} from "./field.js"
+import { resolveRustType } from "./type-map.js"
+import { deprecatedLines } from "./deprecated.js"
+
+/**
+ * Generate `<Struct>Builder` with chainable `with_<field>` setters,
+ * `add_<field>` accumulators for repeated fields (which panic on a full
+ * `BoundedVec`, as its `push` does), `insert_<field>` for maps, and
+ * `build()`.
+ * Unset fields keep their proto3 default (`None` for optionals).
+ * With `(solana.required)` fields the builder is generic over one
+ * `Unset` / `Set` typestate per such field, and `build()` needs them all
+ * `Set`.
+ */
+export function genBuilder(structName: string, fields: FieldInfo[], visibility: string): string {
+  const builderName = `${structName}Builder`
+  // Named after field numbers rather than names, so they do not shadow field types
+  const required = fields.filter(f => f.required)
+  const params = required.map(f => `F${f.number}`)
+  const generics = params.length > 0 ? `<${params.join(", ")}>` : ""
+  const self = `${builderName}${generics}`
+
+  const lines: string[] =
+    params.length > 0
+      ? [
+          `#[derive(Clone, Debug, Default, PartialEq)]`,
+          `${visibility} struct ${builderName}<${params.map(p => `${p} = Unset`).join(", ")}> {`,
+          `    inner: ${structName},`,
+          `    required: core::marker::PhantomData<${params.length > 1 ? `(${params.join(", ")})` : params[0]}>,`,
+          `}`
+        ]
+      : [
+          `#[derive(Clone, Debug, Default, PartialEq)]`,
+          `${visibility} struct ${builderName} {`,
+          `    inner: ${structName},`,
+          `}`
+        ]
+  lines.push(
+    ``,
+    `impl ${structName} {`,
+    `    pub fn builder() -> ${builderName} {`,
+    `        ${builderName}::default()`,
+    `    }`,
+    `}`,
+    ``,
+    `impl${generics} ${self} {`
+  )
+
+  for (const field of fields) {
+    const rustName = fieldRustName(field)
+    const elemType = elemRustType(field)
+    const vis = fieldVisibility(field)
+    const deprecated = deprecatedLines(field, "    ")
+    // A boxed value goes into its `box_type`
+    const held = field.box ? "value.into()" : "value"
+
+    if (field.required) {
+      // Moves the message into the builder type with this field `Set`
+      const next = params.map(p => (p === `F${field.number}` ? "Set" : p)).join(", ")
+      const value = isOptional(field) ? `Some(${held})` : held
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn with_${rustName}(self, value: ${elemType}) -> ${builderName}<${next}> {`,
+        `        let mut inner = self.inner;`,
+        `        inner.${rustName} = ${value};`,
+        `        ${builderName} { inner, required: core::marker::PhantomData }`,
+        `    }`,
+        ``
+      )
+    } else if (isMap(field)) {
+      const key = mapKeyField(field)
+      const value = mapValueField(field)
+      const keyType = resolveRustType(key.type, key.typeName)
+      const valType = resolveRustType(value.type, value.typeName)
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn with_${rustName}(mut self, value: ${mapRustType(field)}) -> Self {`,
+        `        self.inner.${rustName} = value;`,
+        `        self`,
+        `    }`,
+        ``,
+        ...deprecated,
+        `    ${vis} fn insert_${rustName}(mut self, key: ${keyType}, value: ${valType}) -> Self {`,
+        `        self.inner.${rustName}.insert(key, value);`,
+        `        self`,
+        `    }`,
+        ``
+      )
+    } else if (isRepeated(field)) {
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn with_${rustName}(mut self, value: ${repeatedRustType(field, elemType)}) -> Self {`,
+        `        self.inner.${rustName} = value;`,
+        `        self`,
+        `    }`,
+        ``,
+        ...deprecated,
+        `    ${vis} fn add_${rustName}(mut self, value: ${elemType}) -> Self {`,
+        `        self.inner.${rustName}.push(value);`,
+        `        self`,
+        `    }`,
+        ``
+      )
+    } else if (field.oneof) {
+      // Selecting a member replaces any other member of the oneof
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn with_${rustName}(mut self, value: ${elemType}) -> Self {`,
+        `        self.inner.${field.oneof.field} = ${field.oneof.enumName}::${field.oneof.variant}(${held});`,
+        `        self`,
+        `    }`,
+        ``
+      )
+    } else if (isOptional(field)) {
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn with_${rustName}(mut self, value: ${elemType}) -> Self {`,
+        `        self.inner.${rustName} = Some(${held});`,
+        `        self`,
+        `    }`,
+        ``
+      )
+    } else {
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn with_${rustName}(mut self, value: ${elemType}) -> Self {`,
+        `        self.inner.${rustName} = value;`,
+        `        self`,
+        `    }`,
+        ``
+      )
+    }
+  }
+
+  if (params.length > 0) {
+    lines.pop()
+    lines.push(`}`, ``, `impl ${builderName}<${params.map(() => "Set").join(", ")}> {`)
+  }
+  lines.push(
+    `    pub fn build(self) -> ${structName} {`,
+    `        self.inner`,
+    `    }`,
+    `}`
+  )
+  return lines.join("\n")
+}
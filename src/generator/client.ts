@@ -0,0 +1,128 @@
+import { FieldInfo, elemRustType, fieldRustName, isFixedBytes } from "./field.js"
+import { podFieldLen } from "./pod.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+import type { GenContext } from "./context.js"
+import type { MessageDescriptor } from "./message.js"
+
+/**
+ * Generate the `client` option's `fetch()` and `fetch_multiple()`,
+ * behind `feature = "client"`: read accounts over RPC and decode them
+ * with the layout the message's accounts are written in. That is the
+ * `anchor` account data with its `DISCRIMINATOR` checked, else
+ * `decode_with_discriminator` for a `(solana.discriminator)` message,
+ * else `decode_versioned` for a `(solana.version)` one, taking the
+ * `<Message>Migrations` from version 2 on, else the bare protobuf. Each
+ * layout decodes with `DecodeOptions::ACCOUNT`, since an account can be
+ * larger than `decode`'s instruction-sized cap. The
+ * layouts that start with a discriminator also get
+ * `discriminator_filter()`, selecting the accounts `fetch` would read.
+ */
+export function genClientImpl(structName: string, msg: MessageDescriptor, ctx: GenContext): string {
+  let decode: string
+  let layout: string
+  let generic = ""
+  let discriminator: string | undefined
+  if (ctx.anchor) {
+    decode = [
+      `|data: &[u8]| {`,
+      `            strip_discriminator(&Self::DISCRIMINATOR, data)?;`,
+      `            decode_account_data(data)`,
+      `        }`
+    ].join("\n")
+    layout = "`encode_account_data`, checking its `DISCRIMINATOR`"
+    discriminator = "DISCRIMINATOR"
+  } else if (msg.hasDiscriminator) {
+    decode = `Self::decode_with_discriminator`
+    layout = "`encode_with_discriminator`"
+    discriminator = "TYPE_DISCRIMINATOR"
+  } else if (msg.version && msg.version <= 255) {
+    generic = msg.version > 1 ? `<M: ${structName}Migrations>` : ""
+    decode = msg.version > 1 ? `Self::decode_versioned::<M>` : `Self::decode_versioned`
+    layout = msg.version > 1 ? "`encode_versioned`, migrating older data with `M`" : "`encode_versioned`"
+  } else {
+    decode = `|data: &[u8]| Self::decode_with(data, &DecodeOptions::ACCOUNT)`
+    layout = "the bare protobuf encoding"
+  }
+  return [
+    `#[cfg(feature = "client")]`,
+    `impl ${structName} {`,
+    `    /// Fetch the account at \`address\` and decode its data, written as`,
+    `    /// ${layout}.`,
+    `    pub async fn fetch${generic}(rpc: &RpcClient, address: &Pubkey) -> Result<Self, FetchError> {`,
+    `        fetch_account(rpc, address, ${decode}).await`,
+    `    }`,
+    ``,
+    `    /// \`fetch\` for each of \`addresses\`, in as few \`getMultipleAccounts\``,
+    `    /// requests as it takes: \`None\` for an address with no account.`,
+    `    pub async fn fetch_multiple${generic}(`,
+    `        rpc: &RpcClient,`,
+    `        addresses: &[Pubkey],`,
+    `    ) -> Result<Vec<Option<Self>>, FetchError> {`,
+    `        fetch_accounts(rpc, addresses, ${decode}).await`,
+    `    }`,
+    ...(discriminator
+      ? [
+          ``,
+          `    /// A \`getProgramAccounts\` filter for the accounts starting with`,
+          `    /// \`${discriminator}\`, which \`fetch\` reads.`,
+          `    pub fn discriminator_filter() -> RpcFilterType {`,
+          `        memcmp_filter(0, &Self::${discriminator})`,
+          `    }`
+        ]
+      : []),
+    `}`
+  ].join("\n")
+}
+
+/**
+ * Generate the `client` option's `getProgramAccounts` filters of a
+ * `<Message>Pod` layout, behind its `bytemuck` and the `client`
+ * features: the byte offset of every field, computed from the field
+ * widths since the layout has no padding, a `filter_by_<field>()`
+ * matching the field's bytes there, and a `size_filter()` on `LEN`.
+ * Integers are matched little-endian, as Solana stores them.
+ */
+export function genPodFilters(structName: string, fields: FieldInfo[]): string {
+  const podName = `${structName}Pod`
+  let offset = 0
+  const consts = fields.flatMap(f => {
+    const name = fieldRustName(f)
+    const lines = [
+      `    /// Byte offset of \`${name}\` in the layout.`,
+      `    pub const ${name.toUpperCase()}_OFFSET: usize = ${offset};`
+    ]
+    offset += podFieldLen(f)
+    return lines
+  })
+  const filters = fields.flatMap(f => {
+    const name = fieldRustName(f)
+    const { param, bytes } = filterValue(f)
+    return [
+      ``,
+      `    /// A \`getProgramAccounts\` filter for the accounts whose \`${name}\` is \`value\`.`,
+      `    pub fn filter_by_${name}(value: ${param}) -> RpcFilterType {`,
+      `        memcmp_filter(Self::${name.toUpperCase()}_OFFSET, ${bytes})`,
+      `    }`
+    ]
+  })
+  return [
+    `#[cfg(all(feature = "bytemuck", feature = "client"))]`,
+    `impl ${podName} {`,
+    ...consts,
+    ``,
+    `    /// A \`getProgramAccounts\` filter for the accounts of exactly \`LEN\` bytes.`,
+    `    pub fn size_filter() -> RpcFilterType {`,
+    `        RpcFilterType::DataSize(Self::LEN as u64)`,
+    `    }`,
+    ...filters,
+    `}`
+  ].join("\n")
+}
+
+/** The parameter type of a field's Pod filter and the expression of its bytes. */
+function filterValue(field: FieldInfo): { param: string; bytes: string } {
+  if (field.type === 8) return { param: "bool", bytes: "&[value as u8]" }
+  if (field.type === TYPE_PUBKEY) return { param: "&Pubkey", bytes: "&pubkey_to_array(value)" }
+  if (isFixedBytes(field)) return { param: `&[u8; ${field.fixedLen}]`, bytes: "value" }
+  return { param: elemRustType(field), bytes: "&value.to_le_bytes()" }
+}
@@ -0,0 +1,86 @@
+import type { EnumDescriptor } from "./enum.js"
+import type { MessageDescriptor } from "./message.js"
+import type { InstructionTag } from "./service.js"
+import type { ModuleMapping } from "./module-tree.js"
+import type { Utf8Policy } from "./solana-options.js"
+
+/**
+ * Cross-file state shared by the generators for one plugin run.
+ */
+export interface GenContext {
+  /** Every enum in the request, keyed by type name (".my_package.Role") */
+  enums: Map<string, EnumDescriptor>
+  /** Every top-level message in the request, keyed by type name (".my_package.Transfer") */
+  messages: Map<string, MessageDescriptor>
+  /** Emit borrowed `<Message>Ref<'a>` views (`ref_views` option) */
+  refViews: boolean
+  /**
+   * Messages that get a borrowing `<Message>Cow<'a>` struct, keyed by type
+   * name (`cow_structs` option); empty without it
+   */
+  cowStructs: Set<string>
+  /** Implement Anchor's account traits on every message (`anchor` option) */
+  anchor: boolean
+  /** Keep unrecognised fields in an `unknown_fields` member and re-emit them (`unknown_fields` option) */
+  unknownFields: boolean
+  /** Fail on float and double fields, for deterministic programs (`reject_floats` option) */
+  rejectFloats: boolean
+  /** Import `Vec`, `String` and `BTreeMap` from `alloc`, for `#![no_std]` crates (`no_std` option) */
+  noStd: boolean
+  /** Derive serde traits behind the output's `serde` feature, bytes as base64 (`serde` option) */
+  serde: boolean
+  /** Implement `prost::Message` behind the output's `prost` feature (`prost` option) */
+  prost: boolean
+  /** Describe messages and enums for `Reflect` behind the output's `reflect` feature (`reflect` option) */
+  reflect: boolean
+  /**
+   * Emit async account `fetch()` / `fetch_multiple()` and `getProgramAccounts` filters behind the
+   * output's `client` feature (`client` option)
+   */
+  client: boolean
+  /**
+   * Emit wasm-bindgen exports encoding messages and instruction data from proto3 JSON behind the
+   * output's `wasm` feature (`wasm` option)
+   */
+  wasm: boolean
+  /** Leave deprecated fields out of the wire encoding (`omit_deprecated` option) */
+  omitDeprecated: boolean
+  /** Traits every message struct and oneof enum derives besides the generated ones (`derive` option) */
+  derives: string[]
+  /** Emit `<Message>Pod` layouts of fixed-width messages behind the output's `bytemuck` feature (`pod` option) */
+  pod: boolean
+  /** Service instruction tag: a `u8` index or an 8-byte discriminator (`instruction_tag` option) */
+  instructionTag: InstructionTag
+  /** What decoding a string field without `(solana.utf8)` does with invalid UTF-8 (`utf8` option) */
+  utf8: Utf8Policy
+  /** Hash of `(solana.discriminator)` type discriminators (`discriminator_hash` option) */
+  discriminatorHash: string
+  /** Bytes of `(solana.discriminator)` type discriminators (`discriminator_len` option) */
+  discriminatorLen: number
+  /** Smart pointer holding the message fields that close a cycle of messages (`box_type` option) */
+  boxType: string
+  /** Hold every repeated field with a `(solana.max_count)` in a `BoundedVec` (`bounded_vec` option) */
+  boundedVec: boolean
+  /** Emit a `<file>_tests.rs` of round-trip, canonical-encoding and size tests per file (`tests` option) */
+  tests: boolean
+  /** Emit an Anchor IDL JSON file per service (`idl` option) */
+  idl: boolean
+  /** Program address written to the IDL's `address` (`idl_address` option) */
+  idlAddress?: string
+  /** Proto package prefixes placed under other Rust module paths (`module_map` option) */
+  moduleMap: ModuleMapping[]
+  /** Emit a `mod.rs` per package directory (`module_tree` option) */
+  moduleTree: boolean
+  /** Emit the runtime and all codecs as one `.rs` file of that name (`bundle` option) */
+  bundle?: string
+  /** Path generated files import the runtime from instead of an emitted `protobuf_runtime` (`runtime_path` option) */
+  runtimePath?: string
+  /** Rust module path of every proto file's `.rs` file, keyed by proto file name */
+  fileModules: Map<string, string[]>
+  /** Rust module path of the file declaring every message and enum, keyed by type name */
+  typeModules: Map<string, string[]>
+  /** Directory under the output root for TypeScript codecs (`ts_out` option) */
+  tsOut?: string
+  /** Output `.ts` path under `ts_out` of every message and enum, for imports between files */
+  tsFiles: Map<string, string>
+}
@@ -0,0 +1,156 @@
+import {
+  FieldInfo,
+  defaultValueExpr,
+  fieldRustName,
+  genStructMember,
+  isMap,
+  isMessage,
+  isOptional,
+  isRepeated,
+  withMapEntry
+} from "./field.js"
+import { resolveRustType } from "./type-map.js"
+import { DEPRECATED_ATTR } from "./deprecated.js"
+import type { MessageDescriptor } from "./message.js"
+
+/**
+ * The messages that get a `<Message>Cow<'a>` struct under `cow_structs`:
+ * those with a string or bytes field to borrow, directly or through a
+ * sub-message's `<Type>Cow`. A message with nothing to borrow would have
+ * no use for the lifetime, so fields of its type hold the owned message.
+ */
+export function cowMessages(messages: Map<string, MessageDescriptor>): Set<string> {
+  const cow = new Set<string>()
+  // A cycle of messages borrows if any message on it does, so go round until nothing is added
+  let added = true
+  while (added) {
+    added = false
+    for (const [typeName, msg] of messages) {
+      if (cow.has(typeName) || msg.isMapEntry || typeName.startsWith(".google.protobuf.")) continue
+      if (msg.fields.some(f => isCowField(withMapEntry(f, msg), cow))) {
+        cow.add(typeName)
+        added = true
+      }
+    }
+  }
+  return cow
+}
+
+/** A message's fields with `cow` set on the ones its `<Message>Cow` struct borrows. */
+export function withCowFields(fields: FieldInfo[], cow: Set<string>): FieldInfo[] {
+  return fields.map(f => (isCowField(f, cow) ? { ...f, cow: true } : f))
+}
+
+/**
+ * String and bytes fields, and sub-messages of a type with a `<Type>Cow`,
+ * outside oneofs and maps, which are held as the message holds them.
+ * `Pubkey`, `(solana.fixed_len)` and wrapper fields are copied too.
+ */
+function isCowField(field: FieldInfo, cow: Set<string>): boolean {
+  if (isMap(field) || field.wrapper || (field.oneofIndex !== undefined && !field.proto3Optional)) return false
+  if (isMessage(field)) return cow.has(field.typeName ?? "")
+  return field.type === 9 || (field.type === 12 && !field.pubkey && field.fixedLen === undefined)
+}
+
+/**
+ * Generate the `cow_structs` option's `<Struct>Cow<'a>`: the message with
+ * its string and bytes fields as `Cow<'a, str>` / `Cow<'a, [u8]>` and its
+ * sub-messages as their own `Cow` structs, so a decode borrows from the
+ * input and the value can still be changed and encoded again. `const
+ * DEFAULT` and `Default` match the message's, `From` converts both ways,
+ * moving owned values rather than copying them, and `into_owned()`
+ * detaches the value from its input. `hasRaw` marks a message with
+ * `extensions` or `unknown_fields`, which the `Cow` struct does not keep.
+ */
+export function genCowStruct(
+  structName: string,
+  fields: FieldInfo[],
+  hasRaw: boolean,
+  visibility: string,
+  deprecated: boolean
+): string {
+  const cowName = `${structName}Cow`
+  // A oneof group is one member, placed where its first field appears
+  const seen = new Set<string>()
+  const groupFields = fields.filter(f => {
+    if (!f.oneof) return true
+    if (seen.has(f.oneof.field)) return false
+    seen.add(f.oneof.field)
+    return true
+  })
+  const members = groupFields.map(f =>
+    f.oneof ? `    ${f.oneof.visibility} ${f.oneof.field}: ${f.oneof.enumName},` : genStructMember(f)
+  )
+  const defaults = groupFields.map(f =>
+    f.oneof
+      ? `        ${f.oneof.field}: ${f.oneof.enumName}::Unset,`
+      : `        ${fieldRustName(f)}: ${defaultValueExpr(f)},`
+  )
+  const converted = (toCow: boolean) =>
+    groupFields.map(f => {
+      const name = f.oneof?.field ?? fieldRustName(f)
+      return `            ${name}: ${f.cow ? convert(f, `msg.${name}`, toCow) : `msg.${name}`},`
+    })
+
+  return [
+    `#[derive(Clone, Debug, PartialEq)]`,
+    ...(deprecated ? [DEPRECATED_ATTR] : []),
+    `${visibility} struct ${cowName}<'a> {`,
+    ...members,
+    `}`,
+    ``,
+    `impl ${cowName}<'_> {`,
+    `    /// Every field at its proto3 default, for \`const\` and \`static\` items.`,
+    `    pub const DEFAULT: Self = ${cowName} {`,
+    ...defaults,
+    `    };`,
+    ``,
+    `    /// This value with every borrowed field copied, so it outlives its input.`,
+    `    pub fn into_owned(self) -> ${cowName}<'static> {`,
+    `        ${structName}::from(self).into()`,
+    `    }`,
+    `}`,
+    ``,
+    `impl Default for ${cowName}<'_> {`,
+    `    fn default() -> Self {`,
+    `        Self::DEFAULT`,
+    `    }`,
+    `}`,
+    ``,
+    `impl From<${cowName}<'_>> for ${structName} {`,
+    `    fn from(msg: ${cowName}<'_>) -> Self {`,
+    `        ${structName} {`,
+    ...converted(false),
+    ...(hasRaw ? [`            ..Default::default()`] : []),
+    `        }`,
+    `    }`,
+    `}`,
+    ``,
+    `impl From<${structName}> for ${cowName}<'_> {`,
+    `    fn from(msg: ${structName}) -> Self {`,
+    `        ${cowName} {`,
+    ...converted(true),
+    `        }`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
+
+/**
+ * Expression moving `value`, a `cow` field of the owned struct or of its
+ * `Cow` struct, into the other one: `toCow` wraps owned values in
+ * `Cow::Owned`, else borrowed ones are copied. A boxed sub-message is
+ * taken out of its box, which only promises `DerefMut` and `Default`.
+ */
+function convert(field: FieldInfo, value: string, toCow: boolean): string {
+  let one: string
+  if (isMessage(field)) {
+    const target = `${resolveRustType(field.type, field.typeName)}${toCow ? "Cow" : ""}`
+    if (!isRepeated(field) && !isOptional(field)) return `${value}.into()`
+    one = field.box ? `|mut v| ${target}::from(core::mem::take(&mut *v)).into()` : `${target}::from`
+  } else {
+    if (!isRepeated(field) && !isOptional(field)) return toCow ? `Cow::Owned(${value})` : `${value}.into_owned()`
+    one = toCow ? `Cow::Owned` : `Cow::into_owned`
+  }
+  return isRepeated(field) ? `${value}.into_iter().map(${one}).collect()` : `${value}.map(${one})`
+}
@@ -0,0 +1,32 @@
+import type { FieldInfo } from "./field.js"
+import type { GenContext } from "./context.js"
+
+/** The attribute on items generated from a `deprecated = true` message, field, enum or value. */
+export const DEPRECATED_ATTR = `#[deprecated(note = "marked deprecated in the .proto definition")]`
+
+/** `DEPRECATED_ATTR` at `indent` for a deprecated field, nothing otherwise. */
+export function deprecatedLines(field: FieldInfo, indent: string): string[] {
+  return field.deprecated ? [`${indent}${DEPRECATED_ATTR}`] : []
+}
+
+/**
+ * True if anything in the request is deprecated. Generated code uses the
+ * deprecated items itself, and may name a deprecated type from another
+ * file, so every file then allows `deprecated`.
+ */
+export function hasDeprecations(ctx: GenContext): boolean {
+  return (
+    [...ctx.messages.values()].some(m => m.deprecated || m.fields.some(f => f.deprecated)) ||
+    [...ctx.enums.values()].some(e => e.deprecated || e.values.some(v => v.deprecated))
+  )
+}
+
+/**
+ * The fields an encoder writes: all of them, or under the `omit_deprecated`
+ * option all but the deprecated ones. A required field is still written,
+ * since decoding fails without it.
+ */
+export function encodedFields(fields: FieldInfo[], ctx: GenContext): FieldInfo[] {
+  if (!ctx.omitDeprecated) return fields
+  return fields.filter(f => !f.deprecated || f.required)
+}
@@ -0,0 +1,11 @@
+/**
+ * The `#[derive(...)]` line of a generated struct or enum: the traits it
+ * always derives, then the `extra` ones (from the `derive` option and the
+ * message's `(solana.derive)`) it does not. `Default` is left out, since
+ * the generated types implement it by hand.
+ */
+export function deriveAttr(traits: string[], extra: string[]): string {
+  const implemented = new Set([...traits, "Default"])
+  const added = extra.filter((t, i) => !implemented.has(t) && extra.indexOf(t) === i)
+  return `#[derive(${[...traits, ...added].join(", ")})]`
+}
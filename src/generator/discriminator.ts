@@ -0,0 +1,48 @@
+import Crypto from "node:crypto"
+import type { GenContext } from "./context.js"
+
+/** Hash functions the `discriminator_hash` option accepts. */
+export const DISCRIMINATOR_HASHES = ["sha256", "sha512", "sha3-256", "blake2s256"]
+
+/** Shortest digest of `DISCRIMINATOR_HASHES`, and so the longest `discriminator_len`. */
+export const MAX_DISCRIMINATOR_LEN = 32
+
+/**
+ * Type discriminator of a `(solana.discriminator)` message: the first
+ * `discriminator_len` bytes of `<discriminator_hash>("<full name>")`.
+ */
+export function typeDiscriminator(fullName: string, ctx: GenContext): number[] {
+  const name = fullName.replace(/^\./, "")
+  const digest = Crypto.createHash(ctx.discriminatorHash).update(name).digest()
+  return [...digest.subarray(0, ctx.discriminatorLen)]
+}
+
+/**
+ * Generate `TYPE_DISCRIMINATOR`, `encode_with_discriminator()` and
+ * `decode_with_discriminator()` for a `(solana.discriminator)` message,
+ * so data of one message type is rejected where another is expected.
+ * `encode` and `decode` keep the bare protobuf form, which is also what
+ * the message is as a sub-message. The data is usually an account's, so
+ * it decodes with `DecodeOptions::ACCOUNT`, as `decode_account_data` does.
+ */
+export function genDiscriminatorImpl(structName: string, fullName: string, ctx: GenContext): string {
+  const discriminator = typeDiscriminator(fullName, ctx)
+  const name = fullName.replace(/^\./, "")
+  return [
+    `impl ${structName} {`,
+    `    /// \`${ctx.discriminatorHash}("${name}")[..${ctx.discriminatorLen}]\``,
+    `    pub const TYPE_DISCRIMINATOR: [u8; ${ctx.discriminatorLen}] = [${discriminator.join(", ")}];`,
+    ``,
+    `    /// \`TYPE_DISCRIMINATOR\`, then the protobuf encoding.`,
+    `    pub fn encode_with_discriminator(&self) -> Vec<u8> {`,
+    `        encode_with_discriminator(&Self::TYPE_DISCRIMINATOR, self)`,
+    `    }`,
+    ``,
+    `    /// Decode \`encode_with_discriminator\` data, failing if it starts with`,
+    `    /// another discriminator.`,
+    `    pub fn decode_with_discriminator(data: &[u8]) -> Result<Self, DecodeError> {`,
+    `        Self::decode_with(strip_discriminator(&Self::TYPE_DISCRIMINATOR, data)?, &DecodeOptions::ACCOUNT)`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
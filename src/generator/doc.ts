@@ -0,0 +1,77 @@
+/**
+ * `///` lines at `indent` of a `.proto` comment (its leading and trailing
+ * comments from `SourceCodeInfo`), nothing if there is none. protoc keeps
+ * the space after `//`, so one leading space is dropped. Rustdoc would run
+ * code blocks as doctests, so fences without a language and indented
+ * blocks become `text` fences, and the prose is escaped (`escapeText`).
+ */
+export function docLines(doc: string | undefined, indent: string): string[] {
+  if (!doc) return []
+  const source = doc.split("\n").map(line => line.replace(/^ /, "").replace(/\t/g, "    ").trimEnd())
+  while (source.length > 0 && source[0] === "") source.shift()
+  while (source.length > 0 && source[source.length - 1] === "") source.pop()
+
+  const lines: string[] = []
+  let fence: string | undefined
+  let indented = false
+  for (const line of source) {
+    if (fence !== undefined) {
+      if (line.trimStart().startsWith(fence)) fence = undefined
+      lines.push(line)
+      continue
+    }
+    const isIndented = line.startsWith("    ")
+    if (indented && !isIndented && line !== "") {
+      closeBlock(lines)
+      lines.push("")
+      indented = false
+    }
+    if (indented) {
+      lines.push(line.slice(4))
+      continue
+    }
+    const opening = /^\s*(```|~~~)(.*)$/.exec(line)
+    if (opening) {
+      fence = opening[1]
+      lines.push(opening[2].trim() === "" ? `${line}text` : line)
+    } else if (isIndented && (lines.length === 0 || lines[lines.length - 1] === "")) {
+      lines.push("```text", line.slice(4))
+      indented = true
+    } else {
+      lines.push(escapeText(line))
+    }
+  }
+  if (indented) closeBlock(lines)
+  if (fence !== undefined) lines.push(fence)
+  return lines.map(line => (line === "" ? `${indent}///` : `${indent}/// ${line}`))
+}
+
+/**
+ * A line of prose with the Markdown rustdoc misreads escaped, outside
+ * inline code: `[x]` not followed by a link target, and a `<` other than
+ * an autolink's, which would open an HTML tag (`Option<T>`).
+ */
+function escapeText(line: string): string {
+  return line
+    .split(/(`[^`]*`)/)
+    .map((part, i) => {
+      if (i % 2 === 1) return part
+      return part
+        .replace(/\[([^\]]+)\](?=[^[(]|$)/g, "\\[$1\\]")
+        .replace(/<(?!https?:\/\/[^>\s]+>)/g, "\\<")
+    })
+    .join("")
+}
+
+/** Close an indented code block, before the blank lines that ended it. */
+function closeBlock(lines: string[]): void {
+  while (lines[lines.length - 1] === "") lines.pop()
+  lines.push("```")
+}
+
+/** A `.proto` comment's `docLines` before generated doc lines, separated by a blank one. */
+export function withDoc(doc: string | undefined, generated: string[], indent: string): string[] {
+  const lines = docLines(doc, indent)
+  if (lines.length === 0) return generated
+  return generated.length === 0 ? lines : [...lines, `${indent}///`, ...generated]
+}
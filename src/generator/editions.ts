@@ -0,0 +1,90 @@
+import { FieldInfo, isMessage } from "./field.js"
+
+/** `Edition` enum values of the editions the generator resolves features for. */
+export const EDITION_PROTO2 = 998
+export const EDITION_2023 = 1000
+
+// FeatureSet enum values
+const FIELD_PRESENCE_EXPLICIT = 1
+const FIELD_PRESENCE_LEGACY_REQUIRED = 3
+const REPEATED_FIELD_ENCODING_PACKED = 1
+const UTF8_VALIDATION_VERIFY = 2
+export const UTF8_VALIDATION_NONE = 3
+const MESSAGE_ENCODING_LENGTH_PREFIXED = 1
+const MESSAGE_ENCODING_DELIMITED = 2
+
+/**
+ * The `FeatureSet` features codegen depends on, as their enum numbers.
+ * `enum_type` is left out: enums are open either way, as for proto2.
+ */
+export interface Features {
+  fieldPresence: number
+  repeatedFieldEncoding: number
+  utf8Validation: number
+  messageEncoding: number
+}
+
+/** Edition 2023 defaults: explicit presence, packed, verified UTF-8, length-prefixed messages. */
+const EDITION_2023_DEFAULTS: Features = {
+  fieldPresence: FIELD_PRESENCE_EXPLICIT,
+  repeatedFieldEncoding: REPEATED_FIELD_ENCODING_PACKED,
+  utf8Validation: UTF8_VALIDATION_VERIFY,
+  messageEncoding: MESSAGE_ENCODING_LENGTH_PREFIXED
+}
+
+/**
+ * The features of an editions file: the edition's defaults overridden by
+ * the file's `option features.*`. Fails for an edition other than 2023.
+ */
+export function fileFeatures(edition: number | undefined, features: any, fileName: string): Features {
+  if (edition !== EDITION_2023) {
+    throw new Error(`${fileName} uses edition ${editionName(edition)}, which is not supported (expected 2023)`)
+  }
+  return mergeFeatures(EDITION_2023_DEFAULTS, features)
+}
+
+function editionName(edition: number | undefined): string {
+  if (edition === 1001) return "2024"
+  return edition === undefined ? "(unset)" : String(edition)
+}
+
+/**
+ * The features in scope inside an element: its parent's, overridden by
+ * the ones it sets (a `FeatureSet` from its options; unset features are 0).
+ */
+export function mergeFeatures(parent: Features, features: any): Features {
+  if (!features) return parent
+  return {
+    fieldPresence: features.field_presence || parent.fieldPresence,
+    repeatedFieldEncoding: features.repeated_field_encoding || parent.repeatedFieldEncoding,
+    utf8Validation: features.utf8_validation || parent.utf8Validation,
+    messageEncoding: features.message_encoding || parent.messageEncoding
+  }
+}
+
+/**
+ * Editions field semantics on the proto2 / proto3 field model. Explicit
+ * presence is an `Option`, as for proto2 `optional`, and `LEGACY_REQUIRED`
+ * makes the field proto2 `required`. Repeated scalars are packed unless
+ * `EXPANDED`, and a `DELIMITED` message field is coded as a group, except
+ * in a map (`inMap`: a map field or its entry's value), which is always
+ * length-prefixed. `utf8_validation` has no effect: strings are checked
+ * unless `(solana.utf8)` says otherwise.
+ */
+export function withEditionFeatures(field: FieldInfo, features: Features, inMap: boolean): FieldInfo {
+  let resolved = field
+  if (features.messageEncoding === MESSAGE_ENCODING_DELIMITED && field.type === 11 && !inMap) {
+    resolved = { ...resolved, type: 10 }
+  }
+  if (field.label === 3) {
+    return { ...resolved, packed: features.repeatedFieldEncoding === REPEATED_FIELD_ENCODING_PACKED }
+  }
+  // protoc describes a LEGACY_REQUIRED field as LABEL_OPTIONAL
+  if (field.label === 2 || features.fieldPresence === FIELD_PRESENCE_LEGACY_REQUIRED) {
+    return { ...resolved, label: 2, required: true }
+  }
+  if (features.fieldPresence !== FIELD_PRESENCE_EXPLICIT || isMessage(resolved) || field.oneofIndex !== undefined) {
+    return resolved
+  }
+  return { ...resolved, proto3Optional: true }
+}
@@ -0,0 +1,189 @@
+import { protoNameToRust, toPascalCase } from "../util/names.js"
+import { FieldInfo, fieldRustName, fieldVisibility, isMap, isOptional, isRepeated } from "./field.js"
+import { DEPRECATED_ATTR, deprecatedLines } from "./deprecated.js"
+import { docLines, withDoc } from "./doc.js"
+
+/**
+ * Descriptor subset for a protobuf enum needed by the codegen.
+ */
+export interface EnumDescriptor {
+  /** Simple name (e.g. "Role") */
+  name: string
+  /** Fully qualified name without leading dot (e.g. "my_package.Role") */
+  fullName: string
+  /** Declared values in source order */
+  values: Array<{ name: string; number: number; deprecated?: boolean; doc?: string }>
+  /** True if the enum sets `deprecated = true` */
+  deprecated?: boolean
+  /** The enum's `.proto` comments */
+  doc?: string
+  /** Visibility of the Rust enum from the `overrides` file (`pub(crate)`), `pub` if unset */
+  visibility?: string
+}
+
+/**
+ * Rust variant names for an enum's values: PascalCase, with the
+ * SCREAMING_SNAKE enum name prefix dropped when every value carries it
+ * (`ROLE_ADMIN` in `Role` → `Admin`).
+ */
+function variantNames(desc: EnumDescriptor): string[] {
+  const prefix = `${desc.name.replace(/([a-z0-9])([A-Z])/g, "$1_$2").toUpperCase()}_`
+  const strip = desc.values.every(
+    v => v.name.startsWith(prefix) && /^[A-Za-z]/.test(v.name.slice(prefix.length))
+  )
+  return desc.values.map(v => {
+    const variant = toPascalCase((strip ? v.name.slice(prefix.length) : v.name).toLowerCase())
+    return variant === "Self" ? "Self_" : variant
+  })
+}
+
+/** Rust variant for the value named `valueName`: the first declared with its number. */
+export function variantFor(desc: EnumDescriptor, valueName: string): string | undefined {
+  const number = desc.values.find(v => v.name === valueName)?.number
+  const index = desc.values.findIndex(v => v.number === number)
+  return index < 0 ? undefined : variantNames(desc)[index]
+}
+
+/**
+ * Generate the Rust enum for a protobuf enum. Message fields keep the
+ * raw `i32`, so proto3's open semantics hold: a value this build does not
+ * know survives decode and re-encode, and only converting it with
+ * `TryFrom<i32>` reports it (handing the number back). Aliases
+ * (`allow_alias`) become associated consts of the first variant with
+ * their number.
+ */
+export function genEnum(desc: EnumDescriptor): string {
+  if (desc.values.length === 0) return ""
+  const enumName = protoNameToRust(desc.fullName)
+  const names = variantNames(desc)
+  type Value = { name: string; protoName: string; deprecated?: boolean; doc?: string }
+  const variants: Array<Value & { number: number }> = []
+  const aliases: Array<Value & { target: string }> = []
+  desc.values.forEach((v, i) => {
+    const value = { name: names[i], protoName: v.name, deprecated: v.deprecated, doc: v.doc }
+    const first = variants.find(x => x.number === v.number)
+    if (first) aliases.push({ ...value, target: first.name })
+    else variants.push({ ...value, number: v.number })
+  })
+  const deprecated = (item: Value) => (item.deprecated ? [`    ${DEPRECATED_ATTR}`] : [])
+  // Aliases differing only in case (`MOO`, `moo`) map to one Rust name;
+  // the first gets the const, and `from_str_name` still accepts them all
+  const constNames = new Set(variants.map(v => v.name))
+  const aliasConsts = aliases.filter(a => !constNames.has(a.name) && constNames.add(a.name))
+  const defaultVariant = (variants.find(v => v.number === 0) ?? variants[0]).name
+
+  const lines: string[] = [
+    ...withDoc(desc.doc, [`/// \`${desc.fullName}\``], ""),
+    `#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]`,
+    `#[repr(i32)]`,
+    ...(desc.deprecated ? [DEPRECATED_ATTR] : []),
+    `${desc.visibility ?? "pub"} enum ${enumName} {`,
+    ...variants.flatMap(v => [...docLines(v.doc, "    "), ...deprecated(v), `    ${v.name} = ${v.number},`]),
+    `}`,
+    ``,
+    `impl ${enumName} {`,
+    `    /// The proto3 default, numbered 0, for \`const\` and \`static\` items.`,
+    `    pub const DEFAULT: ${enumName} = ${enumName}::${defaultVariant};`,
+    ``
+  ]
+  for (const alias of aliasConsts) {
+    lines.push(
+      ...withDoc(alias.doc, [`    /// \`${alias.protoName}\`, an alias of \`${alias.target}\``], "    "),
+      `    #[allow(non_upper_case_globals)]`,
+      ...deprecated(alias),
+      `    pub const ${alias.name}: ${enumName} = ${enumName}::${alias.target};`,
+      ``
+    )
+  }
+  lines.push(
+    `    /// The value's name as declared in the .proto file.`,
+    `    pub fn as_str_name(&self) -> &'static str {`,
+    `        match self {`,
+    ...variants.map(v => `            ${enumName}::${v.name} => "${v.protoName}",`),
+    `        }`,
+    `    }`,
+    ``,
+    `    pub fn from_str_name(name: &str) -> Option<Self> {`,
+    `        match name {`,
+    ...variants.map(v => `            "${v.protoName}" => Some(${enumName}::${v.name}),`),
+    ...aliases.map(a => `            "${a.protoName}" => Some(${enumName}::${a.target}),`),
+    `            _ => None,`,
+    `        }`,
+    `    }`,
+    `}`,
+    ``,
+    `impl Default for ${enumName} {`,
+    `    fn default() -> Self {`,
+    `        Self::DEFAULT`,
+    `    }`,
+    `}`,
+    ``,
+    `impl From<${enumName}> for i32 {`,
+    `    fn from(value: ${enumName}) -> i32 {`,
+    `        value as i32`,
+    `    }`,
+    `}`,
+    ``,
+    `/// A number this build does not know is handed back as the error.`,
+    `impl TryFrom<i32> for ${enumName} {`,
+    `    type Error = i32;`,
+    ``,
+    `    fn try_from(value: i32) -> Result<Self, i32> {`,
+    `        match value {`,
+    ...variants.map(v => `            ${v.number} => Ok(${enumName}::${v.name}),`),
+    `            _ => Err(value),`,
+    `        }`,
+    `    }`,
+    `}`
+  )
+  return lines.join("\n")
+}
+
+/**
+ * Generate typed accessors for a message's singular enum fields: `<field>()`
+ * converts the stored `i32` (`Err` with the raw number if it is not a
+ * known value) and `set_<field>()` stores a variant. Repeated, map and
+ * oneof members keep only their `i32` form.
+ */
+export function genEnumAccessors(structName: string, fields: FieldInfo[]): string {
+  const enumFields = fields.filter(
+    f => f.type === 14 && f.typeName && !isRepeated(f) && !isMap(f) && !f.oneof
+  )
+  if (enumFields.length === 0) return ""
+
+  const lines: string[] = [`impl ${structName} {`]
+  enumFields.forEach((f, i) => {
+    const rustName = fieldRustName(f)
+    const enumName = protoNameToRust(f.typeName!)
+    const vis = fieldVisibility(f)
+    const deprecated = deprecatedLines(f, "    ")
+    if (i > 0) lines.push(``)
+    if (isOptional(f)) {
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn ${rustName}(&self) -> Option<Result<${enumName}, i32>> {`,
+        `        self.${rustName}.map(${enumName}::try_from)`,
+        `    }`,
+        ``,
+        ...deprecated,
+        `    ${vis} fn set_${rustName}(&mut self, value: ${enumName}) {`,
+        `        self.${rustName} = Some(value.into());`,
+        `    }`
+      )
+    } else {
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn ${rustName}(&self) -> Result<${enumName}, i32> {`,
+        `        ${enumName}::try_from(self.${rustName})`,
+        `    }`,
+        ``,
+        ...deprecated,
+        `    ${vis} fn set_${rustName}(&mut self, value: ${enumName}) {`,
+        `        self.${rustName} = value.into();`,
+        `    }`
+      )
+    }
+  })
+  lines.push(`}`)
+  return lines.join("\n")
+}
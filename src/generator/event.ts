@@ -0,0 +1,88 @@
+import Crypto from "node:crypto"
+
+/**
+ * 8-byte event discriminator for a message: the first bytes of
+ * `sha256("event:<full name>")`, as Anchor derives it for `emit!`.
+ */
+export function eventDiscriminator(fullName: string): number[] {
+  const name = fullName.replace(/^\./, "")
+  return [...Crypto.createHash("sha256").update(`event:${name}`).digest().subarray(0, 8)]
+}
+
+/**
+ * Generate `EVENT_DISCRIMINATOR`, `emit()` and `from_log()` for a
+ * `(solana.event)` message. `emit` logs the discriminator and the
+ * protobuf payload with `sol_log_data`, so it needs the `solana-program`
+ * feature; `from_log` reads the event back from the `Program data:` line
+ * the runtime prints for it, and the runtime's `FromEventData` lets an
+ * `EventParser` read it from a whole transaction's logs.
+ */
+export function genEventImpl(structName: string, fullName: string): string {
+  const discriminator = eventDiscriminator(fullName)
+  const name = fullName.replace(/^\./, "")
+  return [
+    `impl ${structName} {`,
+    `    /// \`sha256("event:${name}")[..8]\``,
+    `    pub const EVENT_DISCRIMINATOR: [u8; 8] = [${discriminator.join(", ")}];`,
+    ``,
+    `    /// Log this event to the transaction with \`sol_log_data\`.`,
+    `    #[cfg(feature = "solana-program")]`,
+    `    pub fn emit(&self) {`,
+    `        emit_event(&Self::EVENT_DISCRIMINATOR, self);`,
+    `    }`,
+    ``,
+    `    /// Decode the event from a transaction log line, or \`None\` if the`,
+    `    /// line holds no \`${structName}\`.`,
+    `    pub fn from_log(line: &str) -> Option<Result<Self, DecodeError>> {`,
+    `        decode_event_log(&Self::EVENT_DISCRIMINATOR, line)`,
+    `    }`,
+    `}`,
+    ``,
+    `impl FromEventData for ${structName} {`,
+    `    fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {`,
+    `        decode_event_data(&Self::EVENT_DISCRIMINATOR, data)`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
+
+/**
+ * Generate the file's `AnyEvent` enum: one variant per event message,
+ * `from_log`, which decodes whichever of them a log line holds, and its
+ * `FromEventData`, so an `EventParser` reads every event of the file at once.
+ */
+export function genEventRegistry(structNames: string[]): string {
+  return [
+    `/// Every \`(solana.event)\` message in this file, for reading events back`,
+    `/// from transaction logs.`,
+    `#[derive(Clone, Debug, PartialEq)]`,
+    `#[allow(clippy::large_enum_variant)]`,
+    `pub enum AnyEvent {`,
+    ...structNames.map(name => `    ${name}(${name}),`),
+    `}`,
+    ``,
+    `impl AnyEvent {`,
+    `    /// Decode the event a log line holds, or \`None\` if it is not a`,
+    `    /// \`Program data:\` line carrying one of this file's events.`,
+    `    pub fn from_log(line: &str) -> Option<Result<Self, DecodeError>> {`,
+    ...structNames.flatMap(name => [
+      `        if let Some(event) = ${name}::from_log(line) {`,
+      `            return Some(event.map(AnyEvent::${name}));`,
+      `        }`
+    ]),
+    `        None`,
+    `    }`,
+    `}`,
+    ``,
+    `impl FromEventData for AnyEvent {`,
+    `    fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {`,
+    ...structNames.flatMap(name => [
+      `        if let Some(event) = ${name}::from_event_data(data) {`,
+      `            return Some(event.map(AnyEvent::${name}));`,
+      `        }`
+    ]),
+    `        None`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
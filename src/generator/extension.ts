@@ -0,0 +1,167 @@
+import { protoNameToRust, toSnakeCase } from "../util/names.js"
+import { FieldInfo, elemRustType, isGroup, isMessage, isPackable, isPacked, isRepeated, messageSlice } from "./field.js"
+import { PROTO_TYPE_MAP, WireType, fieldTag, needsVarintCast, varintDecodeCast } from "./type-map.js"
+
+/**
+ * A proto2 extension field, from an `extend` block at file level or
+ * nested in a message.
+ */
+export interface ExtensionDescriptor {
+  /** Fully qualified name (e.g. "vendor.priority", "vendor.Order.note") */
+  fullName: string
+  /** Type name of the extended message (".vendor.Order") */
+  extendee: string
+  /** Full name of the message the `extend` block is nested in, if any */
+  scope?: string
+  field: FieldInfo
+}
+
+/**
+ * Generate the `Extendable` impl for a message with extension ranges;
+ * the decoder keeps fields in those ranges in its `extensions` member.
+ */
+export function genExtendableImpl(structName: string, ranges: Array<[number, number]>): string {
+  return [
+    `impl Extendable for ${structName} {`,
+    `    const EXTENSION_RANGES: &'static [(u32, u32)] = &[${ranges.map(([start, end]) => `(${start}, ${end})`).join(", ")}];`,
+    ``,
+    `    fn extensions(&self) -> &ExtensionSet {`,
+    `        &self.extensions`,
+    `    }`,
+    ``,
+    `    fn extensions_mut(&mut self) -> &mut ExtensionSet {`,
+    `        &mut self.extensions`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
+
+/**
+ * Generate an `Extension` const per extension, named after the field
+ * (prefixed with the enclosing message's name for a nested `extend`),
+ * and the file's `EXTENSIONS` list for an `ExtensionRegistry`.
+ */
+export function genExtensions(extensions: ExtensionDescriptor[]): string {
+  const names = extensions.map(constName)
+  return [
+    ...extensions.flatMap((ext, i) => [...genExtension(ext, names[i]), ``]),
+    `/// Every extension declared in this file, for an \`ExtensionRegistry\`.`,
+    `pub const EXTENSIONS: &[ExtensionInfo] = &[`,
+    ...names.map(name => `    ${name}.info,`),
+    `];`
+  ].join("\n")
+}
+
+function constName(ext: ExtensionDescriptor): string {
+  const name = toSnakeCase(ext.field.name).toUpperCase()
+  return ext.scope ? `${toSnakeCase(protoNameToRust(ext.scope)).toUpperCase()}_${name}` : name
+}
+
+function genExtension(ext: ExtensionDescriptor, name: string): string[] {
+  const field = ext.field
+  const extendee = protoNameToRust(ext.extendee)
+  const elemType = elemRustType(field)
+  const valueType = isRepeated(field) ? `Vec<${elemType}>` : elemType
+  const wireType = PROTO_TYPE_MAP[field.type].wireType
+  return [
+    `/// Extension \`${ext.fullName}\` of \`${extendee}\`, field ${field.number}.`,
+    `pub const ${name}: Extension<${extendee}, ${valueType}> = Extension::new(`,
+    `    ExtensionInfo {`,
+    `        name: "${ext.fullName}",`,
+    `        extendee: "${ext.extendee.replace(/^\./, "")}",`,
+    `        number: ${field.number},`,
+    `        wire_type: ${wireType},`,
+    `        repeated: ${isRepeated(field)},`,
+    `        packed: ${isPackable(field)},`,
+    `    },`,
+    ...indent(isRepeated(field) ? repeatedMerge(field) : singularMerge(field)),
+    ...indent(isRepeated(field) ? repeatedEncode(field) : singularEncode(field)),
+    `);`
+  ]
+}
+
+function indent(lines: string[]): string[] {
+  return lines.map(line => `    ${line}`)
+}
+
+/**
+ * Statements reading one value at `pos`, leaving `new_pos` after it, and
+ * the expression for the value.
+ */
+function readValue(field: FieldInfo): [string[], string] {
+  if (isMessage(field)) {
+    return [[`let (sub, new_pos) = ${messageSlice(field)}(data, pos)?;`], `${elemRustType(field)}::decode_with(sub, &DecodeOptions::ACCOUNT)?`]
+  }
+  const decode = PROTO_TYPE_MAP[field.type].decodeFunc
+  return [[`let (v, new_pos) = ${decode}(data, pos)?;`], `v${varintDecodeCast(field.type)}`]
+}
+
+/** A singular value merges like a field: a sub-message merges, anything else is replaced. */
+function singularMerge(field: FieldInfo): string[] {
+  if (isMessage(field)) {
+    return [
+      `|value, data, pos, _| {`,
+      `    let (sub, new_pos) = ${messageSlice(field)}(data, pos)?;`,
+      `    value.merge(sub)?;`,
+      `    Ok(new_pos)`,
+      `},`
+    ]
+  }
+  const [read, v] = readValue(field)
+  return [`|value, data, pos, _| {`, ...indent(read), `    *value = ${v};`, `    Ok(new_pos)`, `},`]
+}
+
+/** Repeated values append; a packable scalar also takes a packed run. */
+function repeatedMerge(field: FieldInfo): string[] {
+  const [read, v] = readValue(field)
+  const packed = isPackable(field)
+    ? [
+        `    if tag & 0x07 == ${WireType.LengthDelimited} {`,
+        `        return decode_packed_${PROTO_TYPE_MAP[field.type].packed}(data, pos, values);`,
+        `    }`
+      ]
+    : []
+  return [
+    `|values, data, pos, ${packed.length > 0 ? "tag" : "_"}| {`,
+    ...packed,
+    ...indent(read),
+    `    values.push(${v});`,
+    `    Ok(new_pos)`,
+    `},`
+  ]
+}
+
+/** Statements appending `value` (a `&T`) with its key to `buf`. */
+function writeValue(field: FieldInfo, value: string): string[] {
+  if (isGroup(field)) {
+    // The start-group key, the body and the end-group key (wire type 4)
+    return [
+      `encode_key(buf, 0x${fieldTag(field.number, WireType.StartGroup).toString(16)});`,
+      `${value}.encode_into(buf);`,
+      `encode_key(buf, 0x${fieldTag(field.number, WireType.EndGroup).toString(16)});`
+    ]
+  }
+  if (isMessage(field)) return [`encode_message_field(buf, ${field.number}, |buf| ${value}.encode_into(buf));`]
+  const typeInfo = PROTO_TYPE_MAP[field.type]
+  const key = `encode_key(buf, 0x${fieldTag(field.number, typeInfo.wireType).toString(16)});`
+  if (typeInfo.encodeRef) return [key, `${typeInfo.encodeFunc}(buf, ${value});`]
+  return [key, `${typeInfo.encodeFunc}(buf, *${value}${needsVarintCast(field.type)});`]
+}
+
+function singularEncode(field: FieldInfo): string[] {
+  return [`|value, buf| {`, ...indent(writeValue(field, "value")), `},`]
+}
+
+function repeatedEncode(field: FieldInfo): string[] {
+  if (isPacked(field)) {
+    return [
+      `|values, buf| {`,
+      `    if !values.is_empty() {`,
+      `        encode_key(buf, 0x${fieldTag(field.number, WireType.LengthDelimited).toString(16)});`,
+      `        encode_packed_${PROTO_TYPE_MAP[field.type].packed}(buf, values);`,
+      `    }`,
+      `},`
+    ]
+  }
+  return [`|values, buf| {`, `    for value in values {`, ...indent(indent(writeValue(field, "value"))), `    }`, `},`]
+}
@@ -1,13 +1,18 @@
 import { toSnakeCase } from "../util/names.js"
+import { deprecatedLines } from "./deprecated.js"
 import {
   PROTO_TYPE_MAP,
+  TYPE_PUBKEY,
   WireType,
   fieldTag,
   resolveRustType,
   needsVarintCast,
-  varintDecodeCast
+  varintDecodeCast,
+  decodeCall
 } from "./type-map.js"
 import { log } from "../util/logger.js"
+import type { MessageDescriptor } from "./message.js"
+import type { Utf8Policy } from "./solana-options.js"
 
 /** Parsed field descriptor subset needed for codegen. */
 export interface FieldInfo {
@@ -15,9 +20,114 @@ export interface FieldInfo {
   number: number
   type: number
   typeName?: string
-  label: number // 1=optional, 2=required, 3=repeated
+  label: number // 1=optional, 2=required (proto2), 3=repeated
+  /** lowerCamelCase name used by the proto3 JSON mapping */
+  jsonName?: string
   oneofIndex?: number
+  /**
+   * Explicit presence, held as an `Option`: proto3 `optional` (a
+   * synthetic oneof), or a proto2 `optional` field that is not a message
+   */
+  proto3Optional?: boolean
+  /** True if the field sets `deprecated = true` */
+  deprecated?: boolean
+  /** The field's `.proto` comments */
+  doc?: string
+  /** Rust member name from the `overrides` file, in place of the snake_case proto name */
+  rustName?: string
+  /** Rust visibility of the member from the `overrides` file (`pub(crate)`), `pub` if unset */
+  visibility?: string
+  /** proto2 `[default = ...]` option, as the descriptor spells it */
+  defaultValue?: string
   mapEntry?: { keyType: number; valueType: number; valueTypeName?: string }
+  /** `[packed = ...]` option; proto3 packs repeated scalars unless it is false */
+  packed?: boolean
+  /** Set for members of a real (non-synthetic) oneof */
+  oneof?: OneofMember
+  /**
+   * Runtime wrapper type (`UInt64Value` etc.) carrying this `Option<T>`
+   * field on the wire as a `google.protobuf.*Value` sub-message
+   */
+  wrapper?: string
+  /**
+   * Smart pointer holding the value (the `box_type` option, `Box` by
+   * default): set on a singular message field whose type contains its
+   * message again, so the struct has a finite size
+   */
+  box?: string
+  /**
+   * Set in `<Message>Cow<'a>` structs (`cow_structs` option) on string
+   * and bytes fields held as a `Cow` borrowing from the input, and on
+   * sub-message fields held as their type's `<Type>Cow<'a>`
+   */
+  cow?: boolean
+  /** `[(solana.pubkey) = true]` option */
+  pubkey?: boolean
+  /** `[(solana.fixed_len) = N]` option: a bytes field held as `[u8; N]` */
+  fixedLen?: number
+  /** `[(solana.max_len) = N]` option: longest string / bytes value, for `MAX_ENCODED_LEN` */
+  maxLen?: number
+  /** `[(solana.max_count) = N]` option: most repeated / map elements, for `MAX_ENCODED_LEN` */
+  maxCount?: number
+  /**
+   * `[(solana.bounded_vec) = true]` option, or the `bounded_vec` plugin
+   * option: a repeated field held as a `BoundedVec<T, max_count>`, with
+   * its elements inline, rather than a `Vec`
+   */
+  boundedVec?: boolean
+  /** `[(solana.min) = N]` option: smallest integer value `validate()` accepts */
+  min?: bigint
+  /** `[(solana.max) = N]` option: largest integer value `validate()` accepts */
+  max?: bigint
+  /** `[(solana.min_len) = N]` option: shortest string / bytes value `validate()` accepts */
+  minLen?: number
+  /** `[(solana.prefix) = "..."]` option: text a string value must start with */
+  prefix?: string
+  /** `[(solana.suffix) = "..."]` option: text a string value must end with */
+  suffix?: string
+  /** `[(solana.non_default) = true]` option: `validate()` rejects the default / unset value */
+  nonDefault?: boolean
+  /** `[(solana.required) = true]` option: decoding fails if the field is absent from the input */
+  required?: boolean
+  /**
+   * `[(solana.utf8) = "..."]` option, then the policy in effect: `lossy`
+   * for a string field (or a map's string keys and values) decoded with
+   * U+FFFD replacements, `bytes` for a string field generated as bytes
+   */
+  utf8?: Utf8Policy
+}
+
+/** Where a oneof member lives: `self.<field>` holding `<enumName>::<variant>`. */
+export interface OneofMember {
+  field: string
+  enumName: string
+  /** Visibility of `<field>` */
+  visibility: string
+  variant: string
+}
+
+/** Check if field is a map (a repeated synthetic MapEntry). */
+export function isMap(field: FieldInfo): boolean {
+  return field.mapEntry !== undefined
+}
+
+/** The entry's `key` (field 1) as a standalone field. */
+export function mapKeyField(field: FieldInfo): FieldInfo {
+  return { name: "key", number: 1, type: field.mapEntry!.keyType, label: 1 }
+}
+
+/** The entry's `value` (field 2) as a standalone field. */
+export function mapValueField(field: FieldInfo): FieldInfo {
+  const me = field.mapEntry!
+  return { name: "value", number: 2, type: me.valueType, typeName: me.valueTypeName, label: 1 }
+}
+
+/** Rust type of a map field: `BTreeMap<K, V>`, ordered for deterministic encoding. */
+export function mapRustType(field: FieldInfo): string {
+  const me = field.mapEntry!
+  const keyType = resolveRustType(me.keyType, undefined)
+  const valType = resolveRustType(me.valueType, me.valueTypeName)
+  return `BTreeMap<${keyType}, ${valType}>`
 }
 
 /** Check if field is repeated (label == 3). */
@@ -25,70 +135,186 @@ export function isRepeated(field: FieldInfo): boolean {
   return field.label === 3
 }
 
-/** Check if field is a message type (type == 11). */
+/** Check if a repeated scalar may be packed (decode accepts both forms). */
+export function isPackable(field: FieldInfo): boolean {
+  return isRepeated(field) && !isMap(field) && PROTO_TYPE_MAP[field.type]?.packed !== undefined
+}
+
+/** Check if a repeated scalar is encoded packed. */
+export function isPacked(field: FieldInfo): boolean {
+  return isPackable(field) && field.packed !== false
+}
+
+/** Check if field has explicit presence (proto3 `optional`, proto2 `optional` scalars). */
+export function isOptional(field: FieldInfo): boolean {
+  return field.proto3Optional === true
+}
+
+/** Rust member name of a field: its `overrides` rename, else its snake_case proto name. */
+export function fieldRustName(field: FieldInfo): string {
+  return field.rustName ?? toSnakeCase(field.name)
+}
+
+/** Visibility of a field's member and accessors: its `overrides` one, else its oneof's, else `pub`. */
+export function fieldVisibility(field: FieldInfo): string {
+  return field.visibility ?? field.oneof?.visibility ?? "pub"
+}
+
+/** Check if field is a message type (type == 11), proto2 groups (type == 10) included. */
 export function isMessage(field: FieldInfo): boolean {
-  return field.type === 11
+  return field.type === 11 || field.type === 10
+}
+
+/** Check if field is a proto2 group: a sub-message framed by start- and end-group keys. */
+export function isGroup(field: FieldInfo): boolean {
+  return field.type === 10
+}
+
+/** Check if field is proto2 `required`, which is encoded even at its default. */
+export function isRequiredLabel(field: FieldInfo): boolean {
+  return field.label === 2
 }
 
 /**
- * Generate the Rust struct member declaration for a field.
+ * Whether a singular field is encoded even at its default: a proto2
+ * `required` field, or a message, whose struct cannot tell an unset
+ * sub-message from one with every field at its default.
  */
-export function genStructMember(field: FieldInfo): string {
-  const rustName = toSnakeCase(field.name)
-  let rustType = resolveRustType(field.type, field.typeName)
+export function alwaysWritten(field: FieldInfo): boolean {
+  return isRequiredLabel(field) || isMessage(field)
+}
 
-  if (field.mapEntry) {
-    const keyType = resolveRustType(field.mapEntry.keyType, undefined)
-    const valType = resolveRustType(
-      field.mapEntry.valueType,
-      field.mapEntry.valueTypeName
-    )
-    // Maps become parallel Vecs: keys + values
-    return [
-      `    pub ${rustName}_keys: Vec<${keyType}>,`,
-      `    pub ${rustName}_values: Vec<${valType}>,`
-    ].join("\n")
-  }
+/** Check if field is a `(solana.fixed_len)` bytes field. */
+export function isFixedBytes(field: FieldInfo): boolean {
+  return field.fixedLen !== undefined
+}
 
-  if (isRepeated(field)) {
-    rustType = `Vec<${rustType}>`
+/** Check if the field's decode arm consults `DecodeOptions`. */
+export function decodeUsesOptions(field: FieldInfo): boolean {
+  return (
+    isRepeated(field) ||
+    field.mapEntry !== undefined ||
+    field.wrapper !== undefined ||
+    isGroup(field) ||
+    (PROTO_TYPE_MAP[field.type]?.wireType === WireType.LengthDelimited &&
+      field.type !== TYPE_PUBKEY &&
+      !isFixedBytes(field))
+  )
+}
+
+/** Rust type of one value of the field (the element type if repeated). */
+export function elemRustType(field: FieldInfo): string {
+  if (isFixedBytes(field)) return `[u8; ${field.fixedLen}]`
+  if (field.cow) {
+    if (field.type === 9) return `Cow<'a, str>`
+    if (field.type === 12) return `Cow<'a, [u8]>`
+    return `${messageRustType(field)}<'a>`
   }
+  return resolveRustType(field.type, field.typeName)
+}
 
-  return `    pub ${rustName}: ${rustType},`
+/** Name of a sub-message field's struct: its type, or the type's `<Type>Cow` for a `cow` field. */
+function messageRustType(field: FieldInfo): string {
+  const structType = resolveRustType(field.type, field.typeName)
+  return field.cow ? `${structType}Cow` : structType
+}
+
+/** Rust type of a repeated field holding `elemType`s: a `Vec`, or a `BoundedVec` under `bounded_vec`. */
+export function repeatedRustType(field: FieldInfo, elemType: string): string {
+  return field.boundedVec ? `BoundedVec<${elemType}, ${field.maxCount}>` : `Vec<${elemType}>`
+}
+
+/** Rust type of one value as the struct holds it: `elemRustType`, in its `box` if it has one. */
+export function heldRustType(field: FieldInfo): string {
+  const rustType = elemRustType(field)
+  return field.box ? `${field.box}<${rustType}>` : rustType
 }
 
 /**
- * Generate encode logic for a single field.
- * Returns Rust statements that append encoded bytes to a `Vec<u8> buf`.
+ * Runtime decode call for one non-message value; fixed-size bytes are
+ * read straight into their array.
  */
-export function genFieldEncode(field: FieldInfo): string {
-  const rustName = toSnakeCase(field.name)
-  const typeInfo = PROTO_TYPE_MAP[field.type]
+function fieldDecodeCall(field: FieldInfo): string {
+  if (isFixedBytes(field)) return `decode_fixed_bytes::<${field.fixedLen}>(data, pos)`
+  return valueDecodeCall(field, field.number)
+}
 
-  if (!typeInfo) {
-    log.warn(`Skipping unsupported field type ${field.type} for ${field.name}`)
-    return `        // TODO: unsupported field type ${field.type} for ${field.name}`
+/**
+ * Decode call for one string, bytes or scalar value. A `(solana.max_len)`
+ * string or bytes value fails with `LimitExceeded` for field
+ * `fieldNumber` before allocating; a lossy string replaces invalid UTF-8.
+ */
+function valueDecodeCall(field: FieldInfo, fieldNumber: number, data = "data", pos = "pos"): string {
+  const lossy = field.type === 9 && field.utf8 === "lossy"
+  if (field.cow) {
+    // Borrowed, so there is no allocation for `max_alloc` to bound
+    const kind = field.type === 12 ? "bytes_cow" : lossy ? "str_lossy_cow" : "str_cow"
+    if (field.maxLen === undefined) return `decode_${kind}(${data}, ${pos})`
+    return `decode_${kind}_bounded(${data}, ${pos}, ${field.maxLen}, ${fieldNumber})`
   }
-
-  const tag = fieldTag(
-    field.number,
-    field.mapEntry ? WireType.LengthDelimited : typeInfo.wireType
-  )
-  const tagHex = `0x${tag.toString(16).padStart(2, "0")}`
-
-  if (field.mapEntry) {
-    return genMapEncode(field, tagHex)
+  if (field.maxLen !== undefined && (field.type === 9 || field.type === 12)) {
+    const kind = lossy ? "string_lossy" : field.type === 9 ? "string" : "bytes"
+    return `decode_${kind}_bounded(${data}, ${pos}, opts, ${field.maxLen}, ${fieldNumber})`
   }
+  if (lossy) return `decode_string_lossy_with(${data}, ${pos}, opts)`
+  return decodeCall(field.type, data, pos)
+}
 
-  if (isRepeated(field)) {
-    return genRepeatedEncode(field, rustName, typeInfo, tagHex)
+/**
+ * Rust condition that is true when an implicit-presence field differs
+ * from its proto3 default. Floats compare bits so `-0.0` is kept.
+ */
+export function nonDefaultCond(field: FieldInfo, rustName: string): string {
+  if (isMessage(field)) return `self.${rustName} != ${messageRustType(field)}::default()`
+  switch (field.type) {
+    case 1:
+    case 2:
+      return `self.${rustName}.to_bits() != 0`
+    case 8:
+      return `self.${rustName}`
+    case 9:
+      return `!self.${rustName}.is_empty()`
+    case 12:
+      return isFixedBytes(field)
+        ? `self.${rustName} != [0; ${field.fixedLen}]`
+        : `!self.${rustName}.is_empty()`
+    case TYPE_PUBKEY:
+      return `self.${rustName} != Pubkey::default()`
+    default:
+      return `self.${rustName} != 0`
   }
+}
 
-  if (isMessage(field)) {
-    return genMessageEncode(field, rustName, tagHex)
+/**
+ * Rust expression for a field's proto3 default value, as it appears in
+ * the generated `const DEFAULT`: a const expression for every field.
+ */
+export function defaultValueExpr(field: FieldInfo): string {
+  if (isMap(field)) return "BTreeMap::new()"
+  if (isRepeated(field)) return field.boundedVec ? "BoundedVec::new()" : "Vec::new()"
+  if (isOptional(field)) return "None"
+  if (isMessage(field)) return `${messageRustType(field)}::DEFAULT`
+  if (isFixedBytes(field)) return `[0; ${field.fixedLen}]`
+  if (field.cow) return field.type === 9 ? `Cow::Borrowed("")` : `Cow::Borrowed(&[])`
+  return PROTO_TYPE_MAP[field.type].defaultValue
+}
+
+/**
+ * Generate the Rust struct member declaration for a field.
+ */
+export function genStructMember(field: FieldInfo): string {
+  const rustName = fieldRustName(field)
+  let rustType = heldRustType(field)
+
+  if (isMap(field)) {
+    rustType = mapRustType(field)
+  } else if (isRepeated(field)) {
+    rustType = repeatedRustType(field, rustType)
+  } else if (isOptional(field)) {
+    rustType = `Option<${rustType}>`
   }
 
-  return genScalarEncode(field, rustName, typeInfo, tagHex)
+  return [...deprecatedLines(field, "    "), `    ${fieldVisibility(field)} ${rustName}: ${rustType},`].join("\n")
 }
 
 /**
@@ -96,7 +322,7 @@ export function genFieldEncode(field: FieldInfo): string {
  * Returns a `TAG => { ... }` arm.
  */
 export function genFieldDecode(field: FieldInfo): string {
-  const rustName = toSnakeCase(field.name)
+  const rustName = fieldRustName(field)
   const typeInfo = PROTO_TYPE_MAP[field.type]
 
   if (!typeInfo) {
@@ -112,10 +338,30 @@ export function genFieldDecode(field: FieldInfo): string {
     return genMapDecode(field, rustName, tag)
   }
 
+  if (field.wrapper) {
+    return genWrapperDecode(field, rustName)
+  }
+
+  if (isPackable(field)) {
+    // Parsers must accept both forms, whichever one the field encodes with
+    return [
+      genRepeatedDecode(field, rustName, typeInfo, tag),
+      genPackedDecode(field, rustName, typeInfo)
+    ].join("\n")
+  }
+
   if (isRepeated(field)) {
     return genRepeatedDecode(field, rustName, typeInfo, tag)
   }
 
+  if (isOptional(field)) {
+    return genOptionalDecode(field, rustName, typeInfo, tag)
+  }
+
+  if (field.oneof) {
+    return genOneofDecode(field, field.oneof, typeInfo, tag)
+  }
+
   if (isMessage(field)) {
     return genMessageDecode(field, rustName, tag)
   }
@@ -123,137 +369,370 @@ export function genFieldDecode(field: FieldInfo): string {
   return genScalarDecode(field, rustName, typeInfo, tag)
 }
 
-// ── Internal codegen helpers ──────────────────────────────────────────
+/**
+ * How sub-message lengths are found: computed with `encoded_len()`,
+ * recorded into a `sizes: &mut SizeCache` as they are computed, or read
+ * back from a `sizes: &mut SizeCursor`.
+ */
+export type SizeSource = "compute" | "record" | "read"
 
-function genScalarEncode(
-  field: FieldInfo,
-  rustName: string,
-  typeInfo: (typeof PROTO_TYPE_MAP)[number],
-  tagHex: string
-): string {
-  const cast = needsVarintCast(field.type)
-  // For float types, need to pass bits
-  if (field.type === 1) {
-    // double → f64.to_bits() → u64
+/**
+ * Generate statements adding the field's encoded size (keys included) to
+ * `len`, mirroring the conditions in `genFieldWriteReverse`.
+ */
+export function genFieldEncodedLen(field: FieldInfo, sizes: SizeSource = "compute"): string {
+  const rustName = fieldRustName(field)
+
+  if (!PROTO_TYPE_MAP[field.type]) {
+    return `        // TODO: unsupported field type ${field.type} for ${field.name}`
+  }
+
+  if (isMap(field)) {
+    const key = mapKeyField(field)
+    const value = mapValueField(field)
+    const entryLen = `key_len(${field.number}) + bytes_len(${mapEntryLenExpr(field, sizes)})`
+    // Only bind the halves whose size depends on the value
+    const keyFixed = fixedWidth(key.type) !== undefined
+    const valueFixed = fixedWidth(value.type) !== undefined
+    if (keyFixed && valueFixed) {
+      return `        len += self.${rustName}.len() * (${entryLen});`
+    }
+    const loop = keyFixed
+      ? `for value in self.${rustName}.values()`
+      : valueFixed
+        ? `for key in self.${rustName}.keys()`
+        : `for (key, value) in &self.${rustName}`
+    return [`        ${loop} {`, `            len += ${entryLen};`, `        }`].join("\n")
+  }
+
+  if (field.wrapper) {
     return [
-      `        encode_key(&mut buf, ${tagHex});`,
-      `        encode_fixed64(&mut buf, self.${rustName}.to_bits());`
+      `        if let Some(elem) = &self.${rustName} {`,
+      `            len += key_len(${field.number}) + bytes_len(${field.wrapper}::value_len(elem));`,
+      `        }`
     ].join("\n")
   }
-  if (field.type === 2) {
-    // float → f32.to_bits() → u32
+
+  const fieldLen = (value: string, byRef: boolean) =>
+    `key_len(${field.number}) + ${valueLenExpr(field, value, byRef, sizes)}`
+
+  if (isPacked(field)) {
     return [
-      `        encode_key(&mut buf, ${tagHex});`,
-      `        encode_fixed32(&mut buf, self.${rustName}.to_bits());`
+      `        if !self.${rustName}.is_empty() {`,
+      `            len += key_len(${field.number}) + bytes_len(${packedPayloadLenExpr(field, rustName)});`,
+      `        }`
     ].join("\n")
   }
-  if (typeInfo.encodeRef) {
+
+  if (isRepeated(field)) {
+    const width = fixedWidth(field.type)
+    if (width !== undefined) {
+      return `        len += self.${rustName}.len() * (key_len(${field.number}) + ${width});`
+    }
+    return [
+      `        for elem in &self.${rustName} {`,
+      `            len += ${fieldLen("elem", true)};`,
+      `        }`
+    ].join("\n")
+  }
+
+  if (isOptional(field) || field.oneof) {
+    // Fixed-width values only need to know the field is present
+    const fixed = fixedWidth(field.type) !== undefined
+    if (isOptional(field) && fixed) {
+      return [
+        `        if self.${rustName}.is_some() {`,
+        `            len += ${fieldLen("", true)};`,
+        `        }`
+      ].join("\n")
+    }
+    const binding = fixed ? "_" : "elem"
+    const pattern = field.oneof ? oneofPattern(field.oneof, binding) : "Some(elem)"
+    const target = field.oneof ? field.oneof.field : rustName
     return [
-      `        encode_key(&mut buf, ${tagHex});`,
-      `        ${typeInfo.encodeFunc}(&mut buf, &self.${rustName});`
+      `        if let ${pattern} = &self.${target} {`,
+      `            len += ${fieldLen("elem", true)};`,
+      `        }`
     ].join("\n")
   }
+
+  if (alwaysWritten(field)) return `        len += ${fieldLen(`self.${rustName}`, false)};`
   return [
-    `        encode_key(&mut buf, ${tagHex});`,
-    `        ${typeInfo.encodeFunc}(&mut buf, self.${rustName}${cast});`
+    `        if ${nonDefaultCond(field, rustName)} {`,
+    `            len += ${fieldLen(`self.${rustName}`, false)};`,
+    `        }`
   ].join("\n")
 }
 
-function genMessageEncode(
-  field: FieldInfo,
-  rustName: string,
-  tagHex: string
-): string {
+/** Size of a packed field's payload, without its key and length prefix. */
+function packedPayloadLenExpr(field: FieldInfo, rustName: string): string {
+  const width = fixedWidth(field.type)
+  if (width === 1) return `self.${rustName}.len()`
+  if (width !== undefined) return `self.${rustName}.len() * ${width}`
+  return `self.${rustName}.iter().map(|elem| ${valueLenExpr(field, "elem", true)}).sum::<usize>()`
+}
+
+/** Size of one MapEntry body for the `key` / `value` bindings. */
+function mapEntryLenExpr(field: FieldInfo, sizes: SizeSource = "compute"): string {
   return [
-    `        encode_key(&mut buf, ${tagHex});`,
-    `        let ${rustName}_encoded = self.${rustName}.encode();`,
-    `        encode_varint(&mut buf, ${rustName}_encoded.len() as u64);`,
-    `        buf.extend_from_slice(&${rustName}_encoded);`
-  ].join("\n")
+    `key_len(1) + ${valueLenExpr(mapKeyField(field), "key", true)}`,
+    `key_len(2) + ${valueLenExpr(mapValueField(field), "value", true, sizes)}`
+  ].join(" + ")
 }
 
-function genRepeatedEncode(
-  field: FieldInfo,
-  rustName: string,
-  typeInfo: (typeof PROTO_TYPE_MAP)[number],
-  tagHex: string
-): string {
-  const lines: string[] = []
-  lines.push(`        for elem in &self.${rustName} {`)
-  lines.push(`            encode_key(&mut buf, ${tagHex});`)
+/**
+ * Generate `SliceWriter` statements writing the field through `w`, the
+ * fallible counterpart of `genFieldWriteReverse` for caller-provided
+ * slices. With `sized`, sub-message lengths come from a `SizeCursor`.
+ */
+export function genFieldWrite(field: FieldInfo, sized = false): string {
+  const rustName = fieldRustName(field)
 
-  if (isMessage(field)) {
-    lines.push(
-      `            let elem_encoded = elem.encode();`,
-      `            encode_varint(&mut buf, elem_encoded.len() as u64);`,
-      `            buf.extend_from_slice(&elem_encoded);`
-    )
-  } else if (field.type === 1) {
-    // repeated double
-    lines.push(`            encode_fixed64(&mut buf, elem.to_bits());`)
-  } else if (field.type === 2) {
-    // repeated float
-    lines.push(`            encode_fixed32(&mut buf, elem.to_bits());`)
-  } else if (typeInfo.encodeRef) {
-    lines.push(`            ${typeInfo.encodeFunc}(&mut buf, elem);`)
-  } else {
-    const cast = needsVarintCast(field.type)
-    lines.push(`            ${typeInfo.encodeFunc}(&mut buf, *elem${cast});`)
+  if (!PROTO_TYPE_MAP[field.type]) {
+    return `        // TODO: unsupported field type ${field.type} for ${field.name}`
+  }
+
+  const indent = (lines: string[]) => lines.map(line => `            ${line}`)
+
+  if (isMap(field)) {
+    const tag = fieldTag(field.number, WireType.LengthDelimited)
+    return [
+      `        for (key, value) in &self.${rustName} {`,
+      `            w.write_key(0x${tag.toString(16).padStart(2, "0")})?;`,
+      `            w.write_varint((${mapEntryLenExpr(field, sized ? "read" : "compute")}) as u64)?;`,
+      ...indent(elemWrite(mapKeyField(field), "key", true)),
+      ...indent(elemWrite(mapValueField(field), "value", true, sized)),
+      `        }`
+    ].join("\n")
+  }
+
+  if (field.wrapper) {
+    const tag = fieldTag(field.number, WireType.LengthDelimited)
+    return [
+      `        if let Some(elem) = &self.${rustName} {`,
+      `            w.write_key(0x${tag.toString(16).padStart(2, "0")})?;`,
+      `            w.write_varint(${field.wrapper}::value_len(elem) as u64)?;`,
+      `            ${field.wrapper}::write_value(elem, w)?;`,
+      `        }`
+    ].join("\n")
   }
 
-  lines.push(`        }`)
-  return lines.join("\n")
+  if (isPacked(field)) {
+    const tag = fieldTag(field.number, WireType.LengthDelimited)
+    const payloadLen = packedPayloadLenExpr(field, rustName)
+    // Packed elements are written without their keys
+    const [, write] = elemWrite(field, "elem", true)
+    return [
+      `        if !self.${rustName}.is_empty() {`,
+      `            w.write_key(0x${tag.toString(16).padStart(2, "0")})?;`,
+      `            w.write_varint(${payloadLen.includes(" * ") ? `(${payloadLen})` : payloadLen} as u64)?;`,
+      `            for elem in &self.${rustName} {`,
+      `                ${write}`,
+      `            }`,
+      `        }`
+    ].join("\n")
+  }
+
+  if (isRepeated(field)) {
+    return [
+      `        for elem in &self.${rustName} {`,
+      ...indent(elemWrite(field, "elem", true, sized)),
+      `        }`
+    ].join("\n")
+  }
+
+  if (isOptional(field) || field.oneof) {
+    const pattern = field.oneof ? oneofPattern(field.oneof) : "Some(elem)"
+    const target = field.oneof ? field.oneof.field : rustName
+    return [
+      `        if let ${pattern} = &self.${target} {`,
+      ...indent(elemWrite(field, "elem", true, sized)),
+      `        }`
+    ].join("\n")
+  }
+
+  if (alwaysWritten(field)) {
+    return elemWrite(field, `self.${rustName}`, false, sized).map(line => `        ${line}`).join("\n")
+  }
+  return [
+    `        if ${nonDefaultCond(field, rustName)} {`,
+    ...indent(elemWrite(field, `self.${rustName}`, false, sized)),
+    `        }`
+  ].join("\n")
 }
 
-function genMapEncode(field: FieldInfo, tagHex: string): string {
-  const rustName = toSnakeCase(field.name)
-  const me = field.mapEntry!
-  const keyInfo = PROTO_TYPE_MAP[me.keyType]
-  const valInfo = PROTO_TYPE_MAP[me.valueType]
+/**
+ * Generate `ReverseWriter` statements for `write_reverse`: the field's
+ * encoding written back to front, so elements and map entries go last
+ * first and each value precedes its key. Scalar, enum, string and bytes
+ * fields equal to their default are not emitted; a present optional, the
+ * selected oneof member, a singular message or a proto2 `required` field
+ * always is.
+ */
+export function genFieldWriteReverse(field: FieldInfo): string {
+  const rustName = fieldRustName(field)
 
-  const keyTag = `0x${fieldTag(1, keyInfo.wireType).toString(16).padStart(2, "0")}`
-  const keyCast = needsVarintCast(me.keyType)
+  if (!PROTO_TYPE_MAP[field.type]) {
+    log.warn(`Skipping unsupported field type ${field.type} for ${field.name}`)
+    return `        // TODO: unsupported field type ${field.type} for ${field.name}`
+  }
 
-  const lines = [
-    `        for i in 0..self.${rustName}_keys.len() {`,
-    `            let mut entry = Vec::new();`,
-    `            encode_key(&mut entry, ${keyTag});`
-  ]
+  const indent = (lines: string[], depth = 12) => lines.map(line => `${" ".repeat(depth)}${line}`)
 
-  if (keyInfo.encodeRef) {
-    lines.push(`            ${keyInfo.encodeFunc}(&mut entry, &self.${rustName}_keys[i]);`)
-  } else {
-    lines.push(`            ${keyInfo.encodeFunc}(&mut entry, self.${rustName}_keys[i]${keyCast});`)
+  if (isMap(field)) {
+    // BTreeMap iteration is sorted by key, so the output is too
+    return [
+      `        for (key, value) in self.${rustName}.iter().rev() {`,
+      `            w.write_nested(${field.number}, |w| {`,
+      ...indent(elemWriteReverse(mapValueField(field), "value", true), 16),
+      ...indent(elemWriteReverse(mapKeyField(field), "key", true), 16),
+      `            });`,
+      `        }`
+    ].join("\n")
   }
 
-  if (me.valueType === 11) {
-    const valTag = `0x${fieldTag(2, WireType.LengthDelimited).toString(16).padStart(2, "0")}`
-    lines.push(
-      `            encode_key(&mut entry, ${valTag});`,
-      `            let val_encoded = self.${rustName}_values[i].encode();`,
-      `            encode_varint(&mut entry, val_encoded.len() as u64);`,
-      `            entry.extend_from_slice(&val_encoded);`
-    )
-  } else {
-    const valTag = `0x${fieldTag(2, valInfo.wireType).toString(16).padStart(2, "0")}`
-    const valCast = needsVarintCast(me.valueType)
-    lines.push(`            encode_key(&mut entry, ${valTag});`)
-    if (valInfo.encodeRef) {
-      lines.push(`            ${valInfo.encodeFunc}(&mut entry, &self.${rustName}_values[i]);`)
-    } else {
-      lines.push(`            ${valInfo.encodeFunc}(&mut entry, self.${rustName}_values[i]${valCast});`)
-    }
+  if (field.wrapper) {
+    return [
+      `        if let Some(elem) = &self.${rustName} {`,
+      `            w.write_nested(${field.number}, |w| ${field.wrapper}::write_value_reverse(elem, w));`,
+      `        }`
+    ].join("\n")
+  }
+
+  if (isPacked(field)) {
+    // Packed elements are written without their keys
+    const [write] = elemWriteReverse(field, "elem", true)
+    return [
+      `        if !self.${rustName}.is_empty() {`,
+      `            w.write_nested(${field.number}, |w| {`,
+      `                for elem in self.${rustName}.iter().rev() {`,
+      `                    ${write}`,
+      `                }`,
+      `            });`,
+      `        }`
+    ].join("\n")
+  }
+
+  if (isRepeated(field)) {
+    return [
+      `        for elem in self.${rustName}.iter().rev() {`,
+      ...indent(elemWriteReverse(field, "elem", true)),
+      `        }`
+    ].join("\n")
+  }
+
+  if (isOptional(field) || field.oneof) {
+    const pattern = field.oneof ? oneofPattern(field.oneof) : "Some(elem)"
+    const target = field.oneof ? field.oneof.field : rustName
+    return [
+      `        if let ${pattern} = &self.${target} {`,
+      ...indent(elemWriteReverse(field, "elem", true)),
+      `        }`
+    ].join("\n")
   }
 
-  lines.push(
-    `            encode_key(&mut buf, ${tagHex});`,
-    `            encode_varint(&mut buf, entry.len() as u64);`,
-    `            buf.extend_from_slice(&entry);`,
+  if (alwaysWritten(field)) return indent(elemWriteReverse(field, `self.${rustName}`, false), 8).join("\n")
+  return [
+    `        if ${nonDefaultCond(field, rustName)} {`,
+    ...indent(elemWriteReverse(field, `self.${rustName}`, false)),
     `        }`
-  )
-  return lines.join("\n")
+  ].join("\n")
 }
 
+/** `elemWrite` for a `ReverseWriter`: the value first, then its key. */
+function elemWriteReverse(field: FieldInfo, value: string, byRef: boolean): string[] {
+  if (field.cow && isMessage(field)) {
+    // A `<Type>Cow` is not a `Message`, so it is framed here
+    if (!isGroup(field)) return [`w.write_nested(${field.number}, |w| ${value}.write_reverse(w));`]
+    return [
+      `w.write_key(0x${fieldTag(field.number, WireType.EndGroup).toString(16).padStart(2, "0")});`,
+      `${value}.write_reverse(w);`,
+      `w.write_key(0x${fieldTag(field.number, WireType.StartGroup).toString(16).padStart(2, "0")});`
+    ]
+  }
+  return elemWrite(field, value, byRef)
+    .map(line => line.replace(/\?;$/, ";"))
+    .reverse()
+}
+
+/**
+ * `SliceWriter` calls writing one value with its key. `byRef` marks
+ * `value` as a `&T` binding rather than a place of type `T`.
+ */
+function elemWrite(field: FieldInfo, value: string, byRef: boolean, sized = false): string[] {
+  if (field.cow && isMessage(field)) {
+    const start = fieldTag(field.number, isGroup(field) ? WireType.StartGroup : WireType.LengthDelimited)
+    const key = (tag: number) => `w.write_key(0x${tag.toString(16).padStart(2, "0")})?;`
+    if (isGroup(field)) return [key(start), `${value}.write_to(w)?;`, key(fieldTag(field.number, WireType.EndGroup))]
+    return [key(start), `w.write_varint(${value}.encoded_len() as u64)?;`, `${value}.write_to(w)?;`]
+  }
+  if (isMessage(field)) {
+    const msg = messageRef(field, value, byRef)
+    const write = isGroup(field) ? "write_group" : "write_message"
+    return [sized ? `w.${write}_sized(${field.number}, ${msg}, sizes)?;` : `w.${write}(${field.number}, ${msg})?;`]
+  }
+
+  const typeInfo = PROTO_TYPE_MAP[field.type]
+  const tag = fieldTag(field.number, typeInfo.wireType)
+  const key = `w.write_key(0x${tag.toString(16).padStart(2, "0")})?;`
+  const write = typeInfo.encodeFunc.replace(/^encode_/, "write_")
+
+  if (typeInfo.encodeRef) {
+    return [key, `w.${write}(${byRef ? value : `&${value}`})?;`]
+  }
+  const v = byRef ? `*${value}` : value
+  return [key, `w.${write}(${v}${needsVarintCast(field.type)})?;`]
+}
+
+/** `value` as the `&T` the runtime's generic message writers take, through the field's `box`. */
+function messageRef(field: FieldInfo, value: string, byRef: boolean): string {
+  if (field.box) return byRef ? `&**${value}` : `&*${value}`
+  return byRef ? value : `&${value}`
+}
+
+/** Encoded size of a fixed-width field type's value, if it has one. */
+function fixedWidth(fieldType: number): number | undefined {
+  switch (PROTO_TYPE_MAP[fieldType].wireType) {
+    case WireType.Fixed64:
+      return 8
+    case WireType.Fixed32:
+      return 4
+    default:
+      // A pubkey is a one-byte length prefix and 32 bytes
+      if (fieldType === TYPE_PUBKEY) return 33
+      return fieldType === 8 ? 1 : undefined
+  }
+}
+
+/**
+ * Rust expression for the encoded size of one value, without its key.
+ * `byRef` marks `value` as a `&T` binding rather than a place of type `T`.
+ */
+function valueLenExpr(field: FieldInfo, value: string, byRef: boolean, sizes: SizeSource = "compute"): string {
+  const width = fixedWidth(field.type)
+  if (width !== undefined) return `${width}`
+  if (isGroup(field)) {
+    // The body and the end-group key, as long as the start key
+    if (sizes === "record") return `sizes.record(${messageRef(field, value, byRef)}) + key_len(${field.number})`
+    return `${value}.encoded_len() + key_len(${field.number})`
+  }
+  if (isMessage(field)) {
+    if (sizes === "record") return `bytes_len(sizes.record(${messageRef(field, value, byRef)}))`
+    if (sizes === "read") return `bytes_len(sizes.peek()?)`
+    return `bytes_len(${value}.encoded_len())`
+  }
+  if (field.type === 9 || field.type === 12) return `bytes_len(${value}.len())`
+
+  const v = byRef ? `*${value}` : value
+  if (field.type === 3) return `int64_len(${v})`
+  if (field.type === 5 || field.type === 14) return `int32_len(${v})`
+  if (field.type === 17) return `zigzag32_len(${v})`
+  if (field.type === 18) return `zigzag64_len(${v})`
+  return `varint_len(${v}${needsVarintCast(field.type)})`
+}
+
+// ── Internal codegen helpers ──────────────────────────────────────────
+
 function genScalarDecode(
   field: FieldInfo,
   rustName: string,
@@ -262,37 +741,20 @@ function genScalarDecode(
 ): string {
   const cast = varintDecodeCast(field.type)
 
-  // Float types need from_bits
-  if (field.type === 1) {
-    // double → f64::from_bits
-    return [
-      `            ${tag} => {`,
-      `                let (v, new_pos) = decode_fixed64(data, pos)?;`,
-      `                msg.${rustName} = f64::from_bits(v);`,
-      `                pos = new_pos;`,
-      `            }`
-    ].join("\n")
-  }
-  if (field.type === 2) {
-    // float → f32::from_bits
-    return [
-      `            ${tag} => {`,
-      `                let (v, new_pos) = decode_fixed32(data, pos)?;`,
-      `                msg.${rustName} = f32::from_bits(v);`,
-      `                pos = new_pos;`,
-      `            }`
-    ].join("\n")
-  }
-
   return [
     `            ${tag} => {`,
-    `                let (v, new_pos) = ${typeInfo.decodeFunc}(data, pos)?;`,
-    `                msg.${rustName} = v${cast};`,
+    `                let (v, new_pos) = ${fieldDecodeCall(field)}?;`,
+    `                self.${rustName} = v${cast};`,
     `                pos = new_pos;`,
     `            }`
   ].join("\n")
 }
 
+/** Runtime function reading a sub-message's body as `(sub, new_pos)`. */
+export function messageSlice(field: FieldInfo): string {
+  return isGroup(field) ? `decode_group_slice::<${field.number}>` : "decode_message_slice"
+}
+
 function genMessageDecode(
   field: FieldInfo,
   rustName: string,
@@ -301,10 +763,9 @@ function genMessageDecode(
   const structType = resolveRustType(field.type, field.typeName)
   return [
     `            ${tag} => {`,
-    `                let (len, new_pos) = decode_varint(data, pos)?;`,
-    `                let end = new_pos + len as usize;`,
-    `                msg.${rustName} = ${structType}::decode(&data[new_pos..end])?;`,
-    `                pos = end;`,
+    `                let (sub, new_pos) = ${messageSlice(field)}(data, pos)?;`,
+    `                self.${rustName}.merge_with(sub, &opts.nested()?)?;`,
+    `                pos = new_pos;`,
     `            }`
   ].join("\n")
 }
@@ -314,108 +775,264 @@ function genRepeatedDecode(
   rustName: string,
   typeInfo: (typeof PROTO_TYPE_MAP)[number],
   tag: number
+): string {
+  return genElemDecode(field, typeInfo, tag, v =>
+    [
+      `if self.${rustName}.len() >= opts.max_repeated_len {`,
+      `    return Err(DecodeError::SizeLimitExceeded);`,
+      `}`,
+      // A `BoundedVec` is as long as `max_count` allows, so pushing checks it
+      ...(field.boundedVec
+        ? [`push_bounded(&mut self.${rustName}, ${v}, ${field.number})?;`]
+        : field.maxCount === undefined
+          ? [`self.${rustName}.push(${v});`]
+          : [
+              `if self.${rustName}.len() >= ${field.maxCount} {`,
+              `    return Err(DecodeError::LimitExceeded { field: ${field.number} });`,
+              `}`,
+              `self.${rustName}.push(${v});`
+            ])
+    ].join("\n                ")
+  )
+}
+
+/**
+ * Packed run of the field under its length-delimited key, appended in one
+ * call. A `Vec` is grown by the `_with` decoder, which refuses a run past
+ * `max_repeated_len` before it reserves or pushes; a `BoundedVec` cannot
+ * grow past its capacity, so the budget is checked after the run.
+ */
+function genPackedDecode(
+  field: FieldInfo,
+  rustName: string,
+  typeInfo: (typeof PROTO_TYPE_MAP)[number]
+): string {
+  const cast = varintDecodeCast(field.type)
+  const decoder = cast
+    ? `|data, pos| ${typeInfo.decodeFunc}(data, pos).map(|(v, pos)| (v${cast}, pos))`
+    : typeInfo.decodeFunc
+  const decode = field.boundedVec
+    ? `decode_packed_bounded(data, pos, &mut self.${rustName}, ${field.number}, ${decoder})`
+    : `decode_packed_${typeInfo.packed}_with(data, pos, &mut self.${rustName}, opts)`
+  return [
+    `            ${fieldTag(field.number, WireType.LengthDelimited)} => {`,
+    `                let new_pos = ${decode}?;`,
+    ...(field.boundedVec
+      ? [
+          `                if self.${rustName}.len() > opts.max_repeated_len {`,
+          `                    return Err(DecodeError::SizeLimitExceeded);`,
+          `                }`
+        ]
+      : []),
+    ...(field.maxCount === undefined || field.boundedVec
+      ? []
+      : [
+          `                if self.${rustName}.len() > ${field.maxCount} {`,
+          `                    return Err(DecodeError::LimitExceeded { field: ${field.number} });`,
+          `                }`
+        ]),
+    `                pos = new_pos;`,
+    `            }`
+  ].join("\n")
+}
+
+/** A present wrapper merges into the current value, like any sub-message. */
+function genWrapperDecode(field: FieldInfo, rustName: string): string {
+  return [
+    `            ${fieldTag(field.number, WireType.LengthDelimited)} => {`,
+    `                let (sub, new_pos) = decode_message_slice(data, pos)?;`,
+    `                let value = self.${rustName}.get_or_insert_with(Default::default);`,
+    `                ${field.wrapper}::merge_value(value, sub, opts)?;`,
+    `                pos = new_pos;`,
+    `            }`
+  ].join("\n")
+}
+
+function genOptionalDecode(
+  field: FieldInfo,
+  rustName: string,
+  typeInfo: (typeof PROTO_TYPE_MAP)[number],
+  tag: number
 ): string {
   if (isMessage(field)) {
-    const structType = resolveRustType(field.type, field.typeName)
+    // A present sub-message merges into any earlier occurrence
     return [
       `            ${tag} => {`,
-      `                let (len, new_pos) = decode_varint(data, pos)?;`,
-      `                let end = new_pos + len as usize;`,
-      `                msg.${rustName}.push(${structType}::decode(&data[new_pos..end])?);`,
-      `                pos = end;`,
+      `                let (sub, new_pos) = ${messageSlice(field)}(data, pos)?;`,
+      `                self.${rustName}.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;`,
+      `                pos = new_pos;`,
       `            }`
     ].join("\n")
   }
+  // Presence is set whenever the field is on the wire, even if zero
+  return genElemDecode(field, typeInfo, tag, v => `self.${rustName} = Some(${v});`)
+}
 
-  const cast = varintDecodeCast(field.type)
-
-  if (field.type === 1) {
+function genOneofDecode(
+  field: FieldInfo,
+  oneof: OneofMember,
+  typeInfo: (typeof PROTO_TYPE_MAP)[number],
+  tag: number
+): string {
+  const target = `self.${oneof.field}`
+  const variant = `${oneof.enumName}::${oneof.variant}`
+  if (isMessage(field)) {
+    // Merges into the same member if it is already selected
+    const structType = resolveRustType(field.type, field.typeName)
     return [
       `            ${tag} => {`,
-      `                let (v, new_pos) = decode_fixed64(data, pos)?;`,
-      `                msg.${rustName}.push(f64::from_bits(v));`,
+      `                let (sub, new_pos) = ${messageSlice(field)}(data, pos)?;`,
+      `                if let ${oneofPattern(oneof, "existing")} = &mut ${target} {`,
+      `                    existing.merge_with(sub, &opts.nested()?)?;`,
+      `                } else {`,
+      `                    ${target} = ${variant}(${structType}::decode_with(sub, &opts.nested()?)?${field.box ? ".into()" : ""});`,
+      `                }`,
       `                pos = new_pos;`,
       `            }`
     ].join("\n")
   }
-  if (field.type === 2) {
+  // Any member on the wire replaces the current selection
+  return genElemDecode(field, typeInfo, tag, v => `${target} = ${variant}(${v});`)
+}
+
+/** `Enum::Variant(binding)` pattern for a oneof member. */
+export function oneofPattern(oneof: OneofMember, binding = "elem"): string {
+  return `${oneof.enumName}::${oneof.variant}(${binding})`
+}
+
+/**
+ * Decode arm for a single value, handing the decoded expression to
+ * `store` (bounded push for repeated elements, `Some(..)` for optionals).
+ */
+function genElemDecode(
+  field: FieldInfo,
+  typeInfo: (typeof PROTO_TYPE_MAP)[number],
+  tag: number,
+  store: (value: string) => string
+): string {
+  if (isMessage(field)) {
     return [
       `            ${tag} => {`,
-      `                let (v, new_pos) = decode_fixed32(data, pos)?;`,
-      `                msg.${rustName}.push(f32::from_bits(v));`,
+      `                let (sub, new_pos) = ${messageSlice(field)}(data, pos)?;`,
+      `                ${store(`${messageRustType(field)}::decode_with(sub, &opts.nested()?)?`)}`,
       `                pos = new_pos;`,
       `            }`
     ].join("\n")
   }
 
+  const cast = varintDecodeCast(field.type)
+
   return [
     `            ${tag} => {`,
-    `                let (v, new_pos) = ${typeInfo.decodeFunc}(data, pos)?;`,
-    `                msg.${rustName}.push(v${cast});`,
+    `                let (v, new_pos) = ${fieldDecodeCall(field)}?;`,
+    `                ${store(`v${cast}`)}`,
     `                pos = new_pos;`,
     `            }`
   ].join("\n")
 }
 
+/**
+ * Decode one MapEntry and insert it, replacing any earlier entry with the
+ * same key. A missing key or value takes its proto3 default; a message
+ * value repeated within one entry is merged.
+ */
 function genMapDecode(field: FieldInfo, rustName: string, tag: number): string {
-  const me = field.mapEntry!
-  const keyInfo = PROTO_TYPE_MAP[me.keyType]
-  const valInfo = PROTO_TYPE_MAP[me.valueType]
-  const keySol = resolveRustType(me.keyType, undefined)
-  const valSol = resolveRustType(me.valueType, me.valueTypeName)
-
-  const keyTag = fieldTag(1, keyInfo.wireType)
-  const keyCast = varintDecodeCast(me.keyType)
+  const key = mapKeyField(field)
+  const value = mapValueField(field)
 
-  const lines = [
+  return [
     `            ${tag} => {`,
-    `                let (entry_len, new_pos) = decode_varint(data, pos)?;`,
-    `                pos = new_pos;`,
-    `                let entry_end = pos + entry_len as usize;`,
-    `                let mut key: ${keySol} = Default::default();`,
-    `                let mut val: ${valSol} = Default::default();`,
-    `                while pos < entry_end {`,
-    `                    let (entry_tag, new_pos) = decode_key(data, pos)?;`,
-    `                    pos = new_pos;`,
+    `                let (entry, new_pos) = decode_message_slice(data, pos)?;`,
+    `                let mut key = ${defaultValueExpr(key)};`,
+    `                let mut value = ${defaultValueExpr(value)};`,
+    `                let mut entry_pos = 0usize;`,
+    `                while entry_pos < entry.len() {`,
+    `                    let (entry_tag, next) = decode_key(entry, entry_pos)?;`,
+    `                    entry_pos = next;`,
     `                    match entry_tag {`,
-    `                        ${keyTag} => {`,
-    `                            let (v, new_pos) = ${keyInfo.decodeFunc}(data, pos)?;`,
-    `                            key = v${keyCast};`,
-    `                            pos = new_pos;`,
-    `                        }`
-  ]
-
-  if (me.valueType === 11) {
-    const valTag = fieldTag(2, WireType.LengthDelimited)
-    lines.push(
-      `                        ${valTag} => {`,
-      `                            let (v_len, new_pos) = decode_varint(data, pos)?;`,
-      `                            let v_end = new_pos + v_len as usize;`,
-      `                            val = ${valSol}::decode(&data[new_pos..v_end])?;`,
-      `                            pos = v_end;`,
-      `                        }`
-    )
-  } else {
-    const valTag = fieldTag(2, valInfo.wireType)
-    const valCast = varintDecodeCast(me.valueType)
-    lines.push(
-      `                        ${valTag} => {`,
-      `                            let (v, new_pos) = ${valInfo.decodeFunc}(data, pos)?;`,
-      `                            val = v${valCast};`,
-      `                            pos = new_pos;`,
-      `                        }`
-    )
-  }
-
-  lines.push(
+    ...genEntryDecode({ ...key, maxLen: field.maxLen, utf8: field.utf8 }, field.number),
+    ...genEntryDecode({ ...value, maxLen: field.maxLen, utf8: field.utf8 }, field.number),
     `                        _ => {`,
-    `                            pos = skip_field(data, pos, entry_tag & 0x07)?;`,
+    `                            entry_pos = skip_field(entry, entry_pos, entry_tag)?;`,
     `                        }`,
     `                    }`,
     `                }`,
-    `                msg.${rustName}_keys.push(key);`,
-    `                msg.${rustName}_values.push(val);`,
+    `                if self.${rustName}.len() >= opts.max_repeated_len && !self.${rustName}.contains_key(&key) {`,
+    `                    return Err(DecodeError::SizeLimitExceeded);`,
+    `                }`,
+    ...(field.maxCount === undefined
+      ? []
+      : [
+          `                if self.${rustName}.len() >= ${field.maxCount} && !self.${rustName}.contains_key(&key) {`,
+          `                    return Err(DecodeError::LimitExceeded { field: ${field.number} });`,
+          `                }`
+        ]),
+    `                self.${rustName}.insert(key, value);`,
+    `                pos = new_pos;`,
     `            }`
+  ].join("\n")
+}
+
+/**
+ * Entry-loop arm reading the entry's `key` or `value` into the local of
+ * that name; `mapNumber` is the map field's number, for bound errors.
+ */
+function genEntryDecode(field: FieldInfo, mapNumber: number): string[] {
+  const target = field.name
+  const tag = fieldTag(field.number, PROTO_TYPE_MAP[field.type].wireType)
+  let read: string[]
+  if (isMessage(field)) {
+    read = [
+      `let (sub, next) = decode_message_slice(entry, entry_pos)?;`,
+      `${target}.merge_with(sub, &opts.nested()?)?;`
+    ]
+  } else {
+    read = [
+      `let (v, next) = ${valueDecodeCall(field, mapNumber, "entry", "entry_pos")}?;`,
+      `${target} = v${varintDecodeCast(field.type)};`
+    ]
+  }
+  return [
+    `                        ${tag} => {`,
+    ...[...read, `entry_pos = next;`].map(line => `                            ${line}`),
+    `                        }`
+  ]
+}
+
+// ── Map entry resolution ──────────────────────────────────────────────
+
+/** Attach resolved map entry key/value types to a field, if it is a map. */
+export function withMapEntry(field: FieldInfo, parentMsg: MessageDescriptor): FieldInfo {
+  const mapEntry = resolveMapEntry(field, parentMsg)
+  return mapEntry ? { ...field, mapEntry } : field
+}
+
+interface MapEntryInfo {
+  keyType: number
+  valueType: number
+  valueTypeName?: string
+}
+
+/**
+ * Resolve a map entry's key/value types from the synthetic nested message.
+ */
+function resolveMapEntry(
+  field: FieldInfo,
+  parentMsg: MessageDescriptor
+): MapEntryInfo | undefined {
+  if (field.type !== 11 || field.label !== 3) return undefined
+  const nested = parentMsg.nestedMessages.find(
+    m => field.typeName?.endsWith(`.${m.name}`)
   )
-  return lines.join("\n")
+  if (!nested?.isMapEntry) return undefined
+
+  const keyField = nested.fields.find(f => f.number === 1)
+  const valField = nested.fields.find(f => f.number === 2)
+  if (!keyField || !valField) return undefined
+
+  return {
+    keyType: keyField.type,
+    valueType: valField.type,
+    valueTypeName: valField.typeName
+  }
 }
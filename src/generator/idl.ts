@@ -0,0 +1,251 @@
+import { protoNameToRust, toSnakeCase } from "../util/names.js"
+import {
+  FieldInfo,
+  fieldRustName,
+  isMap,
+  isMessage,
+  isOptional,
+  isRepeated,
+  mapKeyField,
+  mapValueField,
+  withMapEntry
+} from "./field.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+import { unwrapWrapper, withWellKnownType } from "./well-known.js"
+import { withSolanaOptions } from "./solana-options.js"
+import { resolveOneofs } from "./oneof.js"
+import { accountDiscriminator } from "./anchor.js"
+import { eventDiscriminator } from "./event.js"
+import { instructionDiscriminator, parseAccounts, unaryMethods } from "./service.js"
+import type { ServiceDescriptor } from "./service.js"
+import type { MessageDescriptor } from "./message.js"
+import type { GenContext } from "./context.js"
+
+/** An Anchor IDL type reference (`IdlType`). */
+type IdlType = string | { vec: IdlType } | { option: IdlType } | { array: [IdlType, number] } | { defined: { name: string } }
+
+interface IdlTypeDef {
+  name: string
+  type:
+    | { kind: "struct"; fields?: Array<{ name: string; type: IdlType }> | IdlType[] }
+    | { kind: "enum"; variants: Array<{ name: string; fields?: IdlType[] }> }
+}
+
+/** Borsh shapes of the runtime's well-known types, as IDL type defs. */
+const RUNTIME_TYPES: Record<string, IdlTypeDef["type"]> = {
+  Timestamp: { kind: "struct", fields: [{ name: "seconds", type: "i64" }, { name: "nanos", type: "i32" }] },
+  Duration: { kind: "struct", fields: [{ name: "seconds", type: "i64" }, { name: "nanos", type: "i32" }] },
+  Empty: { kind: "struct" },
+  Any: { kind: "struct", fields: [{ name: "type_url", type: "string" }, { name: "value", type: "bytes" }] },
+  UnknownFields: { kind: "struct", fields: [{ name: "raw", type: "bytes" }] },
+  ExtensionSet: { kind: "struct", fields: [{ name: "raw", type: "bytes" }] },
+  DoubleValue: { kind: "struct", fields: ["f64"] },
+  FloatValue: { kind: "struct", fields: ["f32"] },
+  Int64Value: { kind: "struct", fields: ["i64"] },
+  UInt64Value: { kind: "struct", fields: ["u64"] },
+  Int32Value: { kind: "struct", fields: ["i32"] },
+  UInt32Value: { kind: "struct", fields: ["u32"] },
+  BoolValue: { kind: "struct", fields: ["bool"] },
+  StringValue: { kind: "struct", fields: ["string"] },
+  BytesValue: { kind: "struct", fields: ["bytes"] }
+}
+
+const SCALAR_IDL_TYPES: Record<number, string> = {
+  1: "f64",
+  2: "f32",
+  3: "i64",
+  4: "u64",
+  5: "i32",
+  6: "u64",
+  7: "u32",
+  8: "bool",
+  9: "string",
+  12: "bytes",
+  13: "u32",
+  14: "i32",
+  15: "i32",
+  16: "i64",
+  17: "i32",
+  18: "i64",
+  [TYPE_PUBKEY]: "pubkey"
+}
+
+/**
+ * Generate an Anchor IDL (spec 0.1.0, as Anchor 0.30 emits) for a service
+ * (`idl` option). Each rpc is an instruction with its tag as the
+ * discriminator, its `(solana.accounts)` and the request as its one `request`
+ * arg; the file's messages are the IDL types, in the Borsh layout their
+ * `feature = "borsh"` derives use, together with every type they reach.
+ * Messages are accounts under the `anchor` option and `(solana.event)`
+ * messages are events. Instruction, account and event data stay protobuf;
+ * the IDL describes names, discriminators, accounts and shapes.
+ * `undefined` for a service with only streaming rpcs.
+ */
+export function genIdl(
+  service: ServiceDescriptor,
+  messages: MessageDescriptor[],
+  protoFileName: string,
+  ctx: GenContext
+): string | undefined {
+  const methods = unaryMethods(service)
+  if (methods.length === 0) return undefined
+  const discriminated = ctx.instructionTag === "discriminator"
+  const fileMessages = messages.filter(m => !m.isMapEntry)
+
+  const instructions = methods.map((m, i) => {
+    const accounts = m.accounts ? parseAccounts(m.accounts, `${service.fullName}.${m.name}`) : []
+    return {
+      name: toSnakeCase(m.name),
+      discriminator: discriminated ? instructionDiscriminator(service.fullName, m.name) : [i],
+      accounts: accounts.map(a => ({
+        name: a.name,
+        ...(a.writable ? { writable: true } : {}),
+        ...(a.signer ? { signer: true } : {})
+      })),
+      args: [{ name: "request", type: definedType(m.inputType) }],
+      returns: definedType(m.outputType)
+    }
+  })
+
+  const types: TypeSet = { ctx, defs: [], seen: new Set() }
+  for (const msg of fileMessages) addMessage(types, `.${msg.fullName}`)
+  for (const m of methods) {
+    addMessage(types, m.inputType)
+    addMessage(types, m.outputType)
+  }
+
+  const idl = {
+    address: ctx.idlAddress ?? "",
+    metadata: {
+      name: toSnakeCase(service.name),
+      version: "0.1.0",
+      spec: "0.1.0",
+      description: `Generated by protoc-gen-solana from ${protoFileName}`
+    },
+    instructions,
+    ...(ctx.anchor
+      ? {
+          accounts: fileMessages.map(msg => ({
+            name: protoNameToRust(msg.fullName),
+            discriminator: accountDiscriminator(msg.fullName)
+          }))
+        }
+      : {}),
+    events: fileMessages
+      .filter(msg => msg.isEvent)
+      .map(msg => ({ name: protoNameToRust(msg.fullName), discriminator: eventDiscriminator(msg.fullName) })),
+    types: types.defs
+  }
+  return JSON.stringify(idl, null, 2) + "\n"
+}
+
+function definedType(typeName: string): IdlType {
+  return { defined: { name: protoNameToRust(typeName) } }
+}
+
+/** Type defs for messages, their oneof enums and map entries, each once. */
+interface TypeSet {
+  ctx: GenContext
+  defs: IdlTypeDef[]
+  seen: Set<string>
+}
+
+function addMessage(types: TypeSet, typeName: string): void {
+  const name = protoNameToRust(typeName)
+  if (types.seen.has(name)) return
+  types.seen.add(name)
+
+  const msg = types.ctx.messages.get(typeName)
+  if (typeName.startsWith(".google.protobuf.") || !msg) {
+    addRuntime(types, name)
+    return
+  }
+
+  const { fields, groups } = resolveOneofs(
+    name,
+    msg.fields.map(f => withWellKnownType(withSolanaOptions(withMapEntry(f, msg), types.ctx.utf8))),
+    msg.oneofs,
+    msg.oneofOverrides
+  )
+  const def: IdlTypeDef = { name, type: { kind: "struct" } }
+  types.defs.push(def)
+
+  const members: Array<{ name: string; type: IdlType }> = []
+  for (const field of fields) {
+    if (!field.oneof) {
+      members.push({ name: fieldRustName(field), type: fieldType(types, name, field) })
+      continue
+    }
+    // The oneof enum, placed where its first member appears, `Unset` first as in Rust
+    const group = groups.find(g => g.field === field.oneof!.field)!
+    if (group.members[0] !== field) continue
+    members.push({ name: group.field, type: { defined: { name: group.enumName } } })
+    types.seen.add(group.enumName)
+    types.defs.push({
+      name: group.enumName,
+      type: {
+        kind: "enum",
+        variants: [
+          { name: "Unset" },
+          ...group.members.map(m => ({ name: m.oneof!.variant, fields: [valueType(types, m)] }))
+        ]
+      }
+    })
+  }
+  if (msg.extensionRanges.length > 0) {
+    members.push({ name: "extensions", type: { defined: { name: "ExtensionSet" } } })
+    addRuntime(types, "ExtensionSet")
+  }
+  if (types.ctx.unknownFields) {
+    members.push({ name: "unknown_fields", type: { defined: { name: "UnknownFields" } } })
+    addRuntime(types, "UnknownFields")
+  }
+  if (members.length > 0) def.type = { kind: "struct", fields: members }
+}
+
+function addRuntime(types: TypeSet, name: string): void {
+  types.seen.add(name)
+  if (name in RUNTIME_TYPES && !types.defs.some(d => d.name === name)) {
+    types.defs.push({ name, type: RUNTIME_TYPES[name] })
+  }
+}
+
+/**
+ * A map is a `BTreeMap`, which Borsh writes like a `Vec` of key-value
+ * pairs, so it is described as a vec of a synthesized entry struct.
+ */
+function fieldType(types: TypeSet, structName: string, field: FieldInfo): IdlType {
+  if (isMap(field)) {
+    const entryName = `${structName}${protoNameToRust(field.typeName!)}`
+    if (!types.seen.has(entryName)) {
+      types.seen.add(entryName)
+      types.defs.push({
+        name: entryName,
+        type: {
+          kind: "struct",
+          fields: [
+            { name: "key", type: valueType(types, mapKeyField(field)) },
+            { name: "value", type: valueType(types, mapValueField(field)) }
+          ]
+        }
+      })
+    }
+    return { vec: { defined: { name: entryName } } }
+  }
+  const value = valueType(types, field)
+  if (isRepeated(field)) return { vec: value }
+  if (isOptional(field)) return { option: value }
+  return value
+}
+
+function valueType(types: TypeSet, field: FieldInfo): IdlType {
+  if (field.fixedLen !== undefined) return { array: ["u8", field.fixedLen] }
+  // Unwrapped singular wrappers are their scalar; other wrappers keep the struct
+  if (isMessage(field) && field.typeName) {
+    addMessage(types, field.typeName)
+    return { defined: { name: protoNameToRust(field.typeName) } }
+  }
+  const scalar = SCALAR_IDL_TYPES[unwrapWrapper(field).type]
+  if (!scalar) throw new Error(`No IDL type for field ${field.name} (type ${field.type})`)
+  return scalar
+}
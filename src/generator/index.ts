@@ -1,5 +1,31 @@
 export { generateRsFile } from "./message.js"
-export { generateRuntime } from "./runtime.js"
+export { generateRuntime, generateTsRuntime } from "./runtime.js"
+export { genIdl } from "./idl.js"
+export { genTests } from "./tests.js"
+export { DEFAULT_BOX_TYPE, withRecursionBoxes } from "./recursion.js"
+export { cowMessages } from "./cow.js"
+export { withBoundedVecs } from "./solana-options.js"
+export { generateTsFile } from "./typescript.js"
+export { genBundle, genModFiles, isRustKeyword, packageModule } from "./module-tree.js"
+export type { ModuleMapping } from "./module-tree.js"
+export { isWellKnownFile } from "./well-known.js"
+export { withProto2Semantics } from "./proto2.js"
+export {
+  EDITION_2023,
+  EDITION_PROTO2,
+  UTF8_VALIDATION_NONE,
+  fileFeatures,
+  mergeFeatures,
+  withEditionFeatures
+} from "./editions.js"
+export { DISCRIMINATOR_HASHES, MAX_DISCRIMINATOR_LEN } from "./discriminator.js"
 export type { MessageDescriptor } from "./message.js"
+export type { OneofOverride } from "./oneof.js"
+export type { ServiceDescriptor, InstructionTag } from "./service.js"
 export type { FieldInfo } from "./field.js"
+export type { Utf8Policy } from "./solana-options.js"
+export type { EnumDescriptor } from "./enum.js"
+export type { ExtensionDescriptor } from "./extension.js"
+export type { Features } from "./editions.js"
+export type { GenContext } from "./context.js"
 export { PROTO_TYPE_MAP, WireType, resolveRustType, fieldTag } from "./type-map.js"
@@ -0,0 +1,268 @@
+import { toJsonName } from "../util/names.js"
+import {
+  FieldInfo,
+  fieldRustName,
+  isMap,
+  isRepeated,
+  isOptional,
+  isMessage,
+  nonDefaultCond,
+  oneofPattern,
+  isFixedBytes,
+  mapKeyField,
+  mapValueField
+} from "./field.js"
+import { TYPE_PUBKEY, resolveRustType } from "./type-map.js"
+import type { GenContext } from "./context.js"
+
+/**
+ * JsonWriter method / from_json conversion per protobuf field type.
+ * 64-bit integers map to the string-encoding writers per proto3 JSON.
+ */
+const JSON_CODEC: Record<number, { write: string; read: string }> = {
+  1: { write: "write_f64", read: "json_to_f64" },
+  2: { write: "write_f32", read: "json_to_f32" },
+  3: { write: "write_i64", read: "json_to_i64" },
+  4: { write: "write_u64", read: "json_to_u64" },
+  5: { write: "write_i32", read: "json_to_i32" },
+  6: { write: "write_u64", read: "json_to_u64" },
+  7: { write: "write_u32", read: "json_to_u32" },
+  8: { write: "write_bool", read: "json_to_bool" },
+  9: { write: "write_str", read: "json_to_string" },
+  12: { write: "write_bytes", read: "json_to_bytes" },
+  13: { write: "write_u32", read: "json_to_u32" },
+  14: { write: "write_enum", read: "json_to_enum" },
+  15: { write: "write_i32", read: "json_to_i32" },
+  16: { write: "write_i64", read: "json_to_i64" },
+  17: { write: "write_i32", read: "json_to_i32" },
+  18: { write: "write_i64", read: "json_to_i64" },
+  [TYPE_PUBKEY]: { write: "write_pubkey", read: "json_to_pubkey" }
+}
+
+/**
+ * Generate the `feature = "json"` impl block with to_json() / from_json()
+ * following the proto3 JSON mapping. Keys use the field's json_name;
+ * decoding accepts both the json_name and the original proto name.
+ * Fields at their default value (and unset optionals) are omitted.
+ * Maps are JSON objects keyed by the map key's string form.
+ */
+export function genJsonImpl(
+  structName: string,
+  fields: FieldInfo[],
+  ctx: GenContext
+): string {
+  const supported = fields.filter(f => isMessage(f) || JSON_CODEC[f.type])
+
+  const lines: string[] = [
+    `#[cfg(feature = "json")]`,
+    `impl ${structName} {`,
+    `    pub fn to_json(&self) -> String {`,
+    `        let mut out = JsonWriter::new();`,
+    `        self.write_json(&mut out);`,
+    `        out.finish()`,
+    `    }`,
+    ``,
+    `    pub fn write_json(&self, out: &mut JsonWriter) {`,
+    `        out.begin_object();`
+  ]
+
+  for (const field of supported) {
+    lines.push(genJsonWrite(field, ctx))
+  }
+
+  lines.push(
+    `        out.end_object();`,
+    `    }`,
+    ``,
+    `    pub fn from_json(json: &str) -> Result<Self, DecodeError> {`,
+    `        Self::from_json_value(&parse_json(json)?)`,
+    `    }`,
+    ``,
+    `    pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {`
+  )
+
+  if (supported.length === 0) {
+    lines.push(
+      `        if !json_object(value)?.is_empty() {`,
+      `            return Err(DecodeError::InvalidData("unknown JSON field"));`,
+      `        }`,
+      `        Ok(Self::default())`
+    )
+  } else {
+    lines.push(
+      `        let mut msg = Self::default();`,
+      `        for (key, value) in json_object(value)? {`,
+      `            if value.is_null() {`,
+      `                continue;`,
+      `            }`,
+      `            match key.as_str() {`
+    )
+    for (const field of supported) {
+      lines.push(genJsonRead(field, ctx))
+    }
+    lines.push(
+      `                _ => return Err(DecodeError::InvalidData("unknown JSON field")),`,
+      `            }`,
+      `        }`,
+      `        Ok(msg)`
+    )
+  }
+
+  lines.push(`    }`, `}`)
+  return lines.join("\n")
+}
+
+function jsonKey(field: FieldInfo): string {
+  return field.jsonName || toJsonName(field.name)
+}
+
+/**
+ * Statement writing one value. `value` is the Rust expression for the
+ * value itself for Copy types, or a reference for String/bytes/messages.
+ */
+function jsonValueWrite(field: FieldInfo, value: string, ctx: GenContext): string {
+  if (isMessage(field)) {
+    return `${value}.write_json(out);`
+  }
+  if (field.type === 14) {
+    return `out.write_enum(${value}, ${enumTable(field, ctx)});`
+  }
+  // A string held as bytes is still a JSON string, invalid UTF-8 replaced
+  if (field.utf8 === "bytes") {
+    return `out.write_str(&String::from_utf8_lossy(${value}));`
+  }
+  return `out.${JSON_CODEC[field.type].write}(${value});`
+}
+
+function jsonValueRead(field: FieldInfo, value: string, ctx: GenContext): string {
+  if (isMessage(field)) {
+    const read = `${resolveRustType(field.type, field.typeName)}::from_json_value(${value})?`
+    return field.box ? `${read}.into()` : read
+  }
+  if (field.type === 14) {
+    return `json_to_enum(${value}, ${enumTable(field, ctx)})?`
+  }
+  if (isFixedBytes(field)) {
+    return `json_to_fixed_bytes::<${field.fixedLen}>(${value})?`
+  }
+  if (field.utf8 === "bytes") {
+    return `json_to_string(${value})?.into_bytes()`
+  }
+  return `${JSON_CODEC[field.type].read}(${value})?`
+}
+
+/** `&[("NAME", n), ...]` name table for an enum field (empty if unknown). */
+export function enumTable(field: FieldInfo, ctx: GenContext): string {
+  const desc = field.typeName ? ctx.enums.get(field.typeName) : undefined
+  const seen = new Set<number>()
+  const entries = (desc?.values ?? [])
+    .filter(v => !seen.has(v.number) && seen.add(v.number))
+    .map(v => `("${v.name}", ${v.number})`)
+  return `&[${entries.join(", ")}]`
+}
+
+function isCopyType(field: FieldInfo): boolean {
+  return !isMessage(field) && field.type !== 9 && field.type !== 12
+}
+
+function genJsonWrite(field: FieldInfo, ctx: GenContext): string {
+  const rustName = fieldRustName(field)
+  const key = jsonKey(field)
+  const elem = isCopyType(field) ? "*elem" : "elem"
+
+  if (isMap(field)) {
+    const value = mapValueField(field)
+    // String keys are written as-is; integer and bool keys in decimal/`true` form
+    const keyText = mapKeyField(field).type === 9 ? "key" : "&key.to_string()"
+    return [
+      `        if !self.${rustName}.is_empty() {`,
+      `            out.key("${key}");`,
+      `            out.begin_object();`,
+      `            for (key, value) in &self.${rustName} {`,
+      `                out.key(${keyText});`,
+      `                ${jsonValueWrite(value, isCopyType(value) ? "*value" : "value", ctx)}`,
+      `            }`,
+      `            out.end_object();`,
+      `        }`
+    ].join("\n")
+  }
+
+  if (isRepeated(field)) {
+    return [
+      `        if !self.${rustName}.is_empty() {`,
+      `            out.key("${key}");`,
+      `            out.begin_array();`,
+      `            for elem in &self.${rustName} {`,
+      `                ${jsonValueWrite(field, elem, ctx)}`,
+      `            }`,
+      `            out.end_array();`,
+      `        }`
+    ].join("\n")
+  }
+
+  if (field.oneof) {
+    return [
+      `        if let ${oneofPattern(field.oneof)} = &self.${field.oneof.field} {`,
+      `            out.key("${key}");`,
+      `            ${jsonValueWrite(field, elem, ctx)}`,
+      `        }`
+    ].join("\n")
+  }
+
+  if (isOptional(field)) {
+    return [
+      `        if let Some(elem) = &self.${rustName} {`,
+      `            out.key("${key}");`,
+      `            ${jsonValueWrite(field, elem, ctx)}`,
+      `        }`
+    ].join("\n")
+  }
+
+  const value = isCopyType(field) ? `self.${rustName}` : `&self.${rustName}`
+  return [
+    `        if ${nonDefaultCond(field, rustName)} {`,
+    `            out.key("${key}");`,
+    `            ${jsonValueWrite(field, isMessage(field) ? `self.${rustName}` : value, ctx)}`,
+    `        }`
+  ].join("\n")
+}
+
+function genJsonRead(field: FieldInfo, ctx: GenContext): string {
+  const rustName = fieldRustName(field)
+  const key = jsonKey(field)
+  const pattern = key === field.name ? `"${key}"` : `"${key}" | "${field.name}"`
+
+  if (isMap(field)) {
+    return [
+      `                ${pattern} => {`,
+      `                    for (key, value) in json_object(value)? {`,
+      `                        msg.${rustName}.insert(json_map_key(key)?, ${jsonValueRead(mapValueField(field), "value", ctx)});`,
+      `                    }`,
+      `                }`
+    ].join("\n")
+  }
+
+  if (isRepeated(field)) {
+    const elem = jsonValueRead(field, "elem", ctx)
+    return [
+      `                ${pattern} => {`,
+      `                    for elem in json_array(value)? {`,
+      field.boundedVec
+        ? `                        push_bounded(&mut msg.${rustName}, ${elem}, ${field.number})?;`
+        : `                        msg.${rustName}.push(${elem});`,
+      `                    }`,
+      `                }`
+    ].join("\n")
+  }
+
+  if (isOptional(field)) {
+    return `                ${pattern} => msg.${rustName} = Some(${jsonValueRead(field, "value", ctx)}),`
+  }
+
+  if (field.oneof) {
+    const variant = `${field.oneof.enumName}::${field.oneof.variant}`
+    return `                ${pattern} => msg.${field.oneof.field} = ${variant}(${jsonValueRead(field, "value", ctx)}),`
+  }
+
+  return `                ${pattern} => msg.${rustName} = ${jsonValueRead(field, "value", ctx)},`
+}
@@ -2,11 +2,60 @@ import { protoNameToRust } from "../util/names.js"
 import { log } from "../util/logger.js"
 import {
   FieldInfo,
+  fieldRustName,
   genStructMember,
-  genFieldEncode,
-  genFieldDecode
+  genFieldWriteReverse,
+  genFieldDecode,
+  genFieldEncodedLen,
+  genFieldWrite,
+  decodeUsesOptions,
+  isMap,
+  isMessage,
+  isPackable,
+  isOptional,
+  isRepeated,
+  mapValueField,
+  oneofPattern,
+  defaultValueExpr,
+  withMapEntry
 } from "./field.js"
 import { PROTO_TYPE_MAP, WireType } from "./type-map.js"
+import { genJsonImpl } from "./json.js"
+import { genTextImpl } from "./text.js"
+import { genBuilder } from "./builder.js"
+import { genView } from "./view.js"
+import { genCowStruct, withCowFields } from "./cow.js"
+import { DEFAULT_BOX_TYPE } from "./recursion.js"
+import { resolveOneofs, genOneofEnum, genOneofAccessors } from "./oneof.js"
+import type { OneofOverride } from "./oneof.js"
+import { withWellKnownType } from "./well-known.js"
+import { withSolanaOptions } from "./solana-options.js"
+import { SERDE_DERIVE, serdeWithAttr } from "./serde.js"
+import { deriveAttr } from "./derive.js"
+import { DEPRECATED_ATTR, encodedFields, hasDeprecations } from "./deprecated.js"
+import { docLines } from "./doc.js"
+import { genProstImpl } from "./prost.js"
+import { genReflectEnumImpl, genReflectImpl } from "./reflect.js"
+import { genPodImpl } from "./pod.js"
+import { genMessageName, genAnyRegistry } from "./any.js"
+import { genMaxEncodedLen } from "./size.js"
+import { genValidate } from "./validate.js"
+import { genAnchorImpl } from "./anchor.js"
+import { genEventImpl, genEventRegistry } from "./event.js"
+import { genPdaImpl } from "./pda.js"
+import { genVersionImpl } from "./version.js"
+import { genDiscriminatorImpl } from "./discriminator.js"
+import { genClientImpl, genPodFilters } from "./client.js"
+import { genWasmModule } from "./wasm.js"
+import { genService, unaryMethods } from "./service.js"
+import { genCrossFileUses, rootPath } from "./module-tree.js"
+import type { ServiceDescriptor } from "./service.js"
+import { genEnum, genEnumAccessors } from "./enum.js"
+import { genDefaultAccessors } from "./proto2.js"
+import { genExtendableImpl, genExtensions } from "./extension.js"
+import type { ExtensionDescriptor } from "./extension.js"
+import type { EnumDescriptor } from "./enum.js"
+import type { GenContext } from "./context.js"
 
 /**
  * Descriptor subset for a protobuf message needed by the codegen.
@@ -22,33 +71,256 @@ export interface MessageDescriptor {
   nestedMessages: MessageDescriptor[]
   /** True if this message is a synthetic map entry */
   isMapEntry: boolean
+  /** True if the message sets `option (solana.event) = true` */
+  isEvent: boolean
+  /** The message's `(solana.seeds)` PDA seed list, unparsed */
+  seeds?: string
+  /** The message's `(solana.version)` schema version */
+  version?: number
+  /** True if the message sets `option (solana.discriminator) = true` */
+  hasDiscriminator: boolean
+  /** `oneof_decl` names, indexed by a field's `oneofIndex` */
+  oneofs: string[]
+  /** `overrides` file entries of the oneofs, indexed like `oneofs` */
+  oneofOverrides: OneofOverride[]
+  /** Proto2 `extensions` ranges as `[start, end)` field numbers */
+  extensionRanges: Array<[number, number]>
+  /** Traits from the message's `(solana.derive)`, derived on its struct and oneof enums */
+  derives: string[]
+  /** The message's `(solana.attr)` attributes, placed on its struct */
+  attributes: string[]
+  /** True if the message sets `deprecated = true` */
+  deprecated: boolean
+  /** The message's `.proto` comments */
+  doc?: string
+  /** Visibility of its struct and companion types from the `overrides` file (`pub(crate)`), `pub` if unset */
+  visibility?: string
 }
 
 /**
- * Generate a complete .rs file containing struct definitions and
- * encode/decode impl blocks for all non-map-entry messages
- * in a given proto file.
+ * A member holding raw encoded fields, written after the declared
+ * fields: `extensions` for messages with extension ranges, then
+ * `unknown_fields` under the `unknown_fields` option.
+ */
+interface RawMember {
+  name: string
+  type: string
+}
+
+function rawMembers(msg: MessageDescriptor, ctx: GenContext): RawMember[] {
+  return [
+    ...(msg.extensionRanges.length > 0 ? [{ name: "extensions", type: "ExtensionSet" }] : []),
+    ...(ctx.unknownFields ? [{ name: "unknown_fields", type: "UnknownFields" }] : [])
+  ]
+}
+
+/**
+ * Generate a complete .rs file containing the file's enums, struct
+ * definitions and encode/decode impl blocks for all non-map-entry messages
+ * in a given proto file, followed by the instruction dispatchers of its
+ * services.
  */
 export function generateRsFile(
   messages: MessageDescriptor[],
-  protoFileName: string
+  protoFileName: string,
+  ctx: GenContext,
+  services: ServiceDescriptor[] = [],
+  enums: EnumDescriptor[] = [],
+  extensions: ExtensionDescriptor[] = []
 ): string {
   const lines: string[] = []
 
   lines.push(`// Auto-generated by protoc-gen-solana from ${protoFileName}`)
   lines.push(`// DO NOT EDIT`)
   lines.push(``)
-  lines.push(`#![allow(unused_imports, non_camel_case_types, dead_code)]`)
+  const allowed = ["unused_imports", "non_camel_case_types", "dead_code", "clippy::derivable_impls"]
+  if (hasDeprecations(ctx)) allowed.push("deprecated")
+  lines.push(`#![allow(${allowed.join(", ")})]`)
   lines.push(``)
-  lines.push(`use crate::protobuf_runtime::*;`)
+  const fileModule = ctx.fileModules.get(protoFileName) ?? []
+  lines.push(`use ${ctx.runtimePath ?? `${rootPath(fileModule, ctx)}::protobuf_runtime`}::*;`)
+  if (ctx.noStd) {
+    // A `#![no_std]` crate has no prelude `Vec` / `String`
+    lines.push(`use alloc::collections::BTreeMap;`)
+    lines.push(`use alloc::string::{String, ToString};`)
+    lines.push(`use alloc::vec::Vec;`)
+    if (messages.some(m => m.fields.some(f => f.box === DEFAULT_BOX_TYPE))) lines.push(`use alloc::boxed::Box;`)
+  } else {
+    lines.push(`use std::collections::BTreeMap;`)
+  }
+  if (messages.some(m => ctx.cowStructs.has(`.${m.fullName}`))) {
+    lines.push(ctx.noStd ? `use alloc::borrow::Cow;` : `use std::borrow::Cow;`)
+  }
+  const localTypes = [...enums, ...messages].map(t => t.fullName)
+  lines.push(...genCrossFileUses(messages, services, localTypes, fileModule, ctx, extensions))
   lines.push(``)
 
+  for (const desc of enums) {
+    const generated = genEnum(desc)
+    if (!generated) continue
+    lines.push(generated)
+    lines.push(``)
+    if (ctx.reflect) {
+      lines.push(genReflectEnumImpl(desc))
+      lines.push(``)
+    }
+  }
+
   // Generate structs with encode/decode impls
   for (const msg of messages) {
     if (msg.isMapEntry) continue
-    lines.push(genStruct(msg))
+    const structName = protoNameToRust(msg.fullName)
+    const { fields, groups } = resolveOneofs(
+      structName,
+      msg.fields.map(f => withWellKnownType(withSolanaOptions(withMapEntry(f, msg), ctx.utf8))),
+      msg.oneofs,
+      msg.oneofOverrides
+    )
+    if (ctx.rejectFloats) rejectFloatFields(msg.fullName, fields)
+    if (ctx.omitDeprecated) {
+      for (const f of fields.filter(f => f.deprecated && f.required)) {
+        log.warn(`${msg.fullName}.${f.name} is deprecated but required, so it is still encoded`)
+      }
+    }
+
+    const derives = [...ctx.derives, ...msg.derives]
+    const visibility = msg.visibility ?? "pub"
+    for (const group of groups) {
+      lines.push(genOneofEnum(group, ctx.serde, derives, visibility))
+      lines.push(``)
+    }
+    const raw = rawMembers(msg, ctx)
+    const attributes = [...(msg.deprecated ? [DEPRECATED_ATTR] : []), ...msg.attributes]
+    lines.push(...docLines(msg.doc, ""))
+    lines.push(genStruct(structName, fields, raw, ctx.serde, derives, attributes, visibility))
+    lines.push(``)
+    lines.push(genDefaultImpl(structName, fields, raw))
+    lines.push(``)
+    lines.push(genImpl(structName, fields, encodedFields(fields, ctx), raw))
+    lines.push(``)
+    if (msg.extensionRanges.length > 0) {
+      lines.push(genExtendableImpl(structName, msg.extensionRanges))
+      lines.push(``)
+    }
+    lines.push(genMessageName(structName, msg.fullName))
     lines.push(``)
-    lines.push(genImpl(msg))
+    const maxEncodedLen = genMaxEncodedLen(structName, fields, ctx)
+    if (maxEncodedLen) {
+      lines.push(maxEncodedLen)
+      lines.push(``)
+    }
+    const validate = genValidate(structName, msg.fullName, fields, ctx)
+    if (validate) {
+      lines.push(validate)
+      lines.push(``)
+    }
+    if (ctx.prost) {
+      lines.push(genProstImpl(structName))
+      lines.push(``)
+    }
+    if (ctx.reflect) {
+      lines.push(genReflectImpl(structName, msg, ctx))
+      lines.push(``)
+    }
+    const pod =
+      ctx.pod && msg.extensionRanges.length === 0
+        ? genPodImpl(structName, fields, ctx.unknownFields, visibility, msg.deprecated)
+        : undefined
+    if (pod) {
+      lines.push(pod)
+      lines.push(``)
+      if (ctx.client) {
+        lines.push(genPodFilters(structName, fields))
+        lines.push(``)
+      }
+    }
+    if (ctx.anchor) {
+      lines.push(genAnchorImpl(structName, msg.fullName))
+      lines.push(``)
+    }
+    if (msg.isEvent) {
+      lines.push(genEventImpl(structName, msg.fullName))
+      lines.push(``)
+    }
+    if (msg.seeds) {
+      lines.push(genPdaImpl(structName, fields, msg.seeds))
+      lines.push(``)
+    }
+    if (msg.hasDiscriminator) {
+      lines.push(genDiscriminatorImpl(structName, msg.fullName, ctx))
+      lines.push(``)
+    }
+    const versioned = msg.version ? genVersionImpl(structName, msg.version) : undefined
+    if (versioned) {
+      lines.push(versioned)
+      lines.push(``)
+    }
+    if (ctx.client) {
+      lines.push(genClientImpl(structName, msg, ctx))
+      lines.push(``)
+    }
+    if (groups.length > 0) {
+      lines.push(genOneofAccessors(structName, groups))
+      lines.push(``)
+    }
+    const enumAccessors = genEnumAccessors(structName, fields)
+    if (enumAccessors) {
+      lines.push(enumAccessors)
+      lines.push(``)
+    }
+    const defaultAccessors = genDefaultAccessors(structName, fields, ctx)
+    if (defaultAccessors) {
+      lines.push(defaultAccessors)
+      lines.push(``)
+    }
+    lines.push(genBuilder(structName, fields, visibility))
+    lines.push(``)
+    lines.push(genTextImpl(structName, fields, ctx))
+    lines.push(``)
+    lines.push(genJsonImpl(structName, fields, ctx))
+    lines.push(``)
+    if (ctx.refViews) {
+      lines.push(genView(structName, fields, visibility, msg.deprecated))
+      lines.push(``)
+    }
+    if (ctx.cowStructs.has(`.${msg.fullName}`)) {
+      const cowFields = withCowFields(fields, ctx.cowStructs)
+      lines.push(genCowStruct(structName, cowFields, raw.length > 0, visibility, msg.deprecated))
+      lines.push(``)
+      lines.push(genCowImpl(structName, cowFields, encodedFields(cowFields, ctx)))
+      lines.push(``)
+    }
+  }
+
+  if (extensions.length > 0) {
+    lines.push(genExtensions(extensions))
+    lines.push(``)
+  }
+
+  // The registries are public, so they leave out messages the overrides file narrows
+  const registered = messages.filter(m => !m.isMapEntry && (m.visibility ?? "pub") === "pub")
+  const structNames = registered.map(m => protoNameToRust(m.fullName))
+  if (structNames.length > 0) {
+    lines.push(genAnyRegistry(structNames))
+    lines.push(``)
+  }
+
+  const eventNames = registered.filter(m => m.isEvent).map(m => protoNameToRust(m.fullName))
+  if (eventNames.length > 0) {
+    lines.push(genEventRegistry(eventNames))
+    lines.push(``)
+  }
+
+  for (const service of services) {
+    const dispatcher = genService(service, ctx)
+    if (!dispatcher) continue
+    lines.push(dispatcher)
+    lines.push(``)
+  }
+
+  if (ctx.wasm && (registered.length > 0 || services.some(s => unaryMethods(s).length > 0))) {
+    const exported = registered.map(m => ({ structName: protoNameToRust(m.fullName), fullName: m.fullName }))
+    lines.push(genWasmModule(exported, services))
     lines.push(``)
   }
 
@@ -56,163 +328,572 @@ export function generateRsFile(
 }
 
 /**
- * Generate Rust struct definition for a message.
+ * Generate Rust struct definition for a message, with serde derives and
+ * base64 bytes members under the `serde` option, the extra `derives`
+ * and `attributes` the options ask for, and its `visibility`.
  */
-function genStruct(msg: MessageDescriptor): string {
-  const name = protoNameToRust(msg.fullName)
-  log.debug(`Generating struct ${name} (${msg.fields.length} fields)`)
+function genStruct(
+  name: string,
+  fields: FieldInfo[],
+  raw: RawMember[],
+  serde: boolean,
+  derives: string[],
+  attributes: string[],
+  visibility: string
+): string {
+  log.debug(`Generating struct ${name} (${fields.length} fields)`)
 
-  const members = msg.fields
-    .filter(f => !isMapEntryField(f, msg))
-    .map(f => {
-      const mapEntry = resolveMapEntry(f, msg)
-      if (mapEntry) {
-        return genStructMember({ ...f, mapEntry })
-      }
-      return genStructMember(f)
-    })
+  // A oneof group is one member, placed where its first field appears
+  const members = fields.flatMap(f => {
+    if (!f.oneof) {
+      const attr = serde ? serdeWithAttr(f) : undefined
+      return [...docLines(f.doc, "    "), ...(attr ? [`    ${attr}`] : []), genStructMember(f)]
+    }
+    if (!isFirstOneofMember(f, fields)) return []
+    return [`    ${f.oneof.visibility} ${f.oneof.field}: ${f.oneof.enumName},`]
+  })
+  members.push(...raw.map(m => `    pub ${m.name}: ${m.type},`))
 
   return [
-    `#[derive(Clone, Debug, Default, PartialEq)]`,
+    deriveAttr(["Clone", "Debug", "PartialEq"], derives),
     `#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]`,
-    `pub struct ${name} {`,
+    ...(serde ? [SERDE_DERIVE] : []),
+    ...attributes,
+    `${visibility} struct ${name} {`,
     ...members,
     `}`
   ].join("\n")
 }
 
 /**
- * Generate the impl block with encode() and decode() for a message.
+ * Generate `const DEFAULT` spelling out each field's proto3 default, and
+ * `impl Default` returning it.
  */
-function genImpl(msg: MessageDescriptor): string {
-  const structName = protoNameToRust(msg.fullName)
+function genDefaultImpl(structName: string, fields: FieldInfo[], raw: RawMember[]): string {
+  const members = fields.flatMap(f => {
+    const rustName = fieldRustName(f)
+    if (f.oneof) {
+      return isFirstOneofMember(f, fields)
+        ? [`            ${f.oneof.field}: ${f.oneof.enumName}::Unset,`]
+        : []
+    }
+    return [`            ${rustName}: ${defaultValueExpr(f)},`]
+  })
+  members.push(...raw.map(m => `            ${m.name}: ${m.type}::new(),`))
+
+  const body =
+    members.length > 0
+      ? [`    pub const DEFAULT: Self = ${structName} {`, ...members.map(m => m.slice(4)), `    };`]
+      : [`    pub const DEFAULT: Self = ${structName} {};`]
+
+  return [
+    `impl ${structName} {`,
+    `    /// Every field at its proto3 default, for \`const\` and \`static\` items.`,
+    ...body,
+    `}`,
+    ``,
+    `impl Default for ${structName} {`,
+    `    fn default() -> Self {`,
+    `        Self::DEFAULT`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
 
+/**
+ * Generate the impl block with encode(), decode(), merge() and clear() for a
+ * message, plus the runtime `Message` trait impl and the `TryFrom<&[u8]>`
+ * / `Into<Vec<u8>>` conversions delegating to them, the former with
+ * `DecodeOptions::ACCOUNT`, as a slice is often account data. The
+ * encoders write the `encoded` fields, which leave out deprecated ones
+ * under `omit_deprecated`.
+ */
+function genImpl(structName: string, fields: FieldInfo[], encoded: FieldInfo[], raw: RawMember[]): string {
   log.debug(`Generating impl ${structName}`)
+  const keepUnknown = raw.some(m => m.name === "unknown_fields")
+  const extensible = raw.some(m => m.name === "extensions")
 
-  const encodeBody = genEncodeFunction(msg, structName)
-  const decodeBody = genDecodeFunction(msg, structName)
+  // Fields go on the wire in ascending tag order, so `encode` is canonical
+  // regardless of declaration order
+  const encodeBody = genEncodeFunction(
+    [...encoded].sort((a, b) => a.number - b.number),
+    raw
+  )
+  const decodeBody = genDecodeFunction()
+  const mergeBody = genMergeFunction(fields, keepUnknown, extensible)
+  const wireTypes = genFieldWireTypes(structName, fields)
 
   return [
     `impl ${structName} {`,
+    ...fields.map(f => `    pub const ${fieldRustName(f).toUpperCase()}_FIELD_NUMBER: u32 = ${f.number};`),
     ``,
+    ...(wireTypes.length > 0 ? [...wireTypes, ``] : []),
     encodeBody,
     ``,
     decodeBody,
+    ``,
+    mergeBody,
+    `}`,
+    ``,
+    `impl Message for ${structName} {`,
+    `    fn encode_into(&self, buf: &mut Vec<u8>) {`,
+    `        ${structName}::encode_into(self, buf)`,
+    `    }`,
+    ``,
+    `    fn encoded_len(&self) -> usize {`,
+    `        ${structName}::encoded_len(self)`,
+    `    }`,
+    ``,
+    `    fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {`,
+    `        ${structName}::write_to(self, w)`,
+    `    }`,
+    ``,
+    `    fn write_reverse(&self, w: &mut ReverseWriter<'_>) {`,
+    `        ${structName}::write_reverse(self, w)`,
+    `    }`,
+    ``,
+    `    fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {`,
+    `        ${structName}::encoded_len_cached(self, sizes)`,
+    `    }`,
+    ``,
+    `    fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {`,
+    `        ${structName}::write_to_sized(self, w, sizes)`,
+    `    }`,
+    ``,
+    `    fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {`,
+    `        ${structName}::merge_with(self, data, opts)`,
+    `    }`,
+    ...(keepUnknown ? genUnknownFieldsOverrides(fields) : []),
+    `}`,
+    ``,
+    `impl TryFrom<&[u8]> for ${structName} {`,
+    `    type Error = DecodeError;`,
+    ``,
+    `    fn try_from(data: &[u8]) -> Result<Self, DecodeError> {`,
+    `        ${structName}::decode_with(data, &DecodeOptions::ACCOUNT)`,
+    `    }`,
+    `}`,
+    ``,
+    `impl From<${structName}> for Vec<u8> {`,
+    `    fn from(msg: ${structName}) -> Vec<u8> {`,
+    `        msg.encode()`,
+    `    }`,
     `}`
   ].join("\n")
 }
 
 /**
- * Generate the encode function body.
+ * Generate the impl block of the `<Struct>Cow<'a>` struct: decode() and
+ * merge() borrowing from their input, with the checks and semantics of
+ * the message's own, skipping unknown and extension fields, and the
+ * `Vec` and slice encoders. Sub-messages are not `Message`s here, so
+ * there are no `SizeCache` variants.
  */
-function genEncodeFunction(
-  msg: MessageDescriptor,
-  structName: string
-): string {
+function genCowImpl(structName: string, fields: FieldInfo[], encoded: FieldInfo[]): string {
+  const input = "&'a [u8]"
+  return [
+    `impl<'a> ${structName}Cow<'a> {`,
+    genDecodeFunction(input),
+    ``,
+    genMergeFunction(fields, false, false, structName, input),
+    ``,
+    genEncodeFunction([...encoded].sort((a, b) => a.number - b.number), [], false),
+    `}`
+  ].join("\n")
+}
+
+/**
+ * `Message` overrides for messages that keep unknown fields: canonical
+ * encoding leaves them out, so it encodes a copy with them cleared.
+ */
+function genUnknownFieldsOverrides(fields: FieldInfo[]): string[] {
+  const lines = [
+    ``,
+    `    fn encode_canonical(&self) -> Vec<u8> {`,
+    `        let mut msg = self.clone();`,
+    `        msg.clear_unknown_fields();`,
+    `        msg.encode()`,
+    `    }`,
+    ``,
+    `    fn clear_unknown_fields(&mut self) {`,
+    `        self.unknown_fields.clear();`
+  ]
+  for (const field of fields) {
+    const rustName = fieldRustName(field)
+    if (isMap(field)) {
+      if (!isMessage(mapValueField(field))) continue
+      lines.push(
+        `        for v in self.${rustName}.values_mut() {`,
+        `            v.clear_unknown_fields();`,
+        `        }`
+      )
+    } else if (!isMessage(field)) {
+      continue
+    } else if (isRepeated(field)) {
+      lines.push(
+        `        for v in &mut self.${rustName} {`,
+        `            v.clear_unknown_fields();`,
+        `        }`
+      )
+    } else if (field.oneof) {
+      lines.push(
+        `        if let ${oneofPattern(field.oneof, "v")} = &mut self.${field.oneof.field} {`,
+        `            v.clear_unknown_fields();`,
+        `        }`
+      )
+    } else if (isOptional(field)) {
+      lines.push(
+        `        if let Some(v) = &mut self.${rustName} {`,
+        `            v.clear_unknown_fields();`,
+        `        }`
+      )
+    } else {
+      lines.push(`        self.${rustName}.clear_unknown_fields();`)
+    }
+  }
+  lines.push(`    }`)
+  return lines
+}
+
+/**
+ * Generate encode(), encode_into(), write_reverse(), encoded_len(),
+ * encode_to_slice(), write_to() and their `SizeCache` variants. Both `Vec` encoders go through
+ * `write_reverse`, which writes the fields last first so each
+ * sub-message's length is known when its prefix is written; both size
+ * their space from `encoded_len` up front, so the writer never
+ * reallocates or moves what it has written. Extension fields, then
+ * preserved unknown fields, follow the declared ones. `cached` adds the `SizeCache` variants.
+ */
+function genEncodeFunction(fields: FieldInfo[], raw: RawMember[], cached = true): string {
+  const w = fields.length > 0 || raw.length > 0 ? "w" : "_w"
+
   const lines: string[] = []
   lines.push(`    pub fn encode(&self) -> Vec<u8> {`)
   lines.push(`        let mut buf = Vec::new();`)
+  lines.push(`        self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));`)
+  lines.push(`        buf`)
+  lines.push(`    }`)
+  lines.push(``)
+  lines.push(`    pub fn encode_into(&self, buf: &mut Vec<u8>) {`)
+  lines.push(`        self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));`)
+  lines.push(`    }`)
+  lines.push(``)
+  lines.push(`    pub fn write_reverse(&self, ${w}: &mut ReverseWriter<'_>) {`)
 
-  for (const field of msg.fields) {
-    if (isMapEntryField(field, msg)) continue
-
-    const mapEntry = resolveMapEntry(field, msg)
-    const fieldInfo = mapEntry ? { ...field, mapEntry } : field
-
-    lines.push(``)
-    lines.push(`        // field ${field.number}: ${field.name}`)
-    lines.push(genFieldEncode(fieldInfo))
+  for (const m of [...raw].reverse()) {
+    lines.push(`        self.${m.name}.write_reverse(w);`)
   }
+  const lastFirst = [...fields].reverse()
+  lastFirst.forEach((field, i) => {
+    if (i > 0 || raw.length > 0) lines.push(``)
+    lines.push(`        // field ${field.number}: ${field.name}`)
+    lines.push(genFieldWriteReverse(field))
+  })
 
-  lines.push(``)
-  lines.push(`        buf`)
   lines.push(`    }`)
+  lines.push(``)
+  lines.push(...genEncodedLenFunction(fields, raw))
+  lines.push(``)
+  lines.push(...genWriteFunction(fields, raw))
+  if (cached) lines.push(``, ...genSizedFunctions(fields, raw))
   return lines.join("\n")
 }
 
 /**
- * Generate the decode function body with tag-dispatch loop.
+ * Slice encoding checks the total size first, so a too-small buffer is
+ * rejected before any byte is written.
+ */
+function genWriteFunction(fields: FieldInfo[], raw: RawMember[]): string[] {
+  const w = fields.length > 0 || raw.length > 0 ? "w" : "_w"
+  return [
+    `    pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {`,
+    `        if self.encoded_len() > buf.len() {`,
+    `            return Err(DecodeError::BufferTooSmall);`,
+    `        }`,
+    `        let mut w = SliceWriter::new(buf);`,
+    `        self.write_to(&mut w)?;`,
+    `        Ok(w.position())`,
+    `    }`,
+    ``,
+    `    pub fn write_to(&self, ${w}: &mut SliceWriter<'_>) -> Result<(), DecodeError> {`,
+    ...fields.map(field => genFieldWrite(field)),
+    ...raw.map(m => `        self.${m.name}.write_to(w)?;`),
+    `        Ok(())`,
+    `    }`
+  ]
+}
+
+/**
+ * Generate encoded_len_cached() and write_to_sized(), the `SizeCache`
+ * variants of encoded_len() and write_to(): sub-message lengths are
+ * recorded into the cache in write order, then read back instead of
+ * recomputed. A message without sub-messages has nothing to cache.
  */
-function genDecodeFunction(
-  msg: MessageDescriptor,
-  structName: string
+function genSizedFunctions(fields: FieldInfo[], raw: RawMember[]): string[] {
+  const sized = fields.filter(f => (isMap(f) ? isMessage(mapValueField(f)) : isMessage(f) && !f.wrapper))
+  if (sized.length === 0) {
+    return [
+      `    pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {`,
+      `        self.encoded_len()`,
+      `    }`,
+      ``,
+      `    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {`,
+      `        self.write_to(w)`,
+      `    }`
+    ]
+  }
+  return [
+    `    pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {`,
+    `        let mut len = 0;`,
+    ...fields.map(field => genFieldEncodedLen(field, "record")),
+    ...raw.map(m => `        len += self.${m.name}.encoded_len();`),
+    `        len`,
+    `    }`,
+    ``,
+    `    pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {`,
+    ...fields.map(field => genFieldWrite(field, true)),
+    ...raw.map(m => `        self.${m.name}.write_to(w)?;`),
+    `        Ok(())`,
+    `    }`
+  ]
+}
+
+function genEncodedLenFunction(fields: FieldInfo[], raw: RawMember[]): string[] {
+  if (fields.length === 0) {
+    const len = raw.length > 0 ? raw.map(m => `self.${m.name}.encoded_len()`).join(" + ") : `0`
+    return [`    pub fn encoded_len(&self) -> usize {`, `        ${len}`, `    }`]
+  }
+
+  const lines = [`    pub fn encoded_len(&self) -> usize {`, `        let mut len = 0;`]
+  for (const field of fields) {
+    lines.push(genFieldEncodedLen(field))
+  }
+  lines.push(...raw.map(m => `        len += self.${m.name}.encoded_len();`))
+  lines.push(`        len`, `    }`)
+  return lines
+}
+
+/**
+ * Generate decode()/decode_with(): a fresh message merged from the buffer,
+ * after rejecting input longer than `max_message_len`, and clear().
+ * `input` is the type of the buffer.
+ */
+function genDecodeFunction(input = "&[u8]"): string {
+  return [
+    `    pub fn decode(data: ${input}) -> Result<Self, DecodeError> {`,
+    `        Self::decode_with(data, &DecodeOptions::DEFAULT)`,
+    `    }`,
+    ``,
+    `    pub fn decode_with(data: ${input}, opts: &DecodeOptions) -> Result<Self, DecodeError> {`,
+    `        if data.len() > opts.max_message_len {`,
+    `            return Err(DecodeError::SizeLimitExceeded);`,
+    `        }`,
+    `        let mut msg = Self::default();`,
+    `        msg.merge_with(data, opts)?;`,
+    `        Ok(msg)`,
+    `    }`,
+    ``,
+    `    /// Decode only the fields numbered in \`fields\` (see the \`*_FIELD_NUMBER\``,
+    `    /// constants), skipping the rest of \`data\` without allocating for it.`,
+    `    /// Every other field keeps its default.`,
+    `    pub fn decode_fields(data: ${input}, fields: &[u32]) -> Result<Self, DecodeError> {`,
+    `        Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)`,
+    `    }`,
+    ``,
+    `    pub fn decode_fields_with(data: ${input}, fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {`,
+    `        if data.len() > opts.max_message_len {`,
+    `            return Err(DecodeError::SizeLimitExceeded);`,
+    `        }`,
+    `        let mut msg = Self::default();`,
+    `        msg.merge_selected(data, opts, Some(fields))?;`,
+    `        Ok(msg)`,
+    `    }`,
+    ``,
+    `    /// Reset every field to its proto3 default.`,
+    `    pub fn clear(&mut self) {`,
+    `        *self = Self::default();`,
+    `    }`
+  ].join("\n")
+}
+
+/**
+ * Generate the merge function body with tag-dispatch loop.
+ * Merge follows protobuf semantics: singular scalars take the last
+ * value, repeated fields append, and sub-messages merge recursively.
+ * Fields in an extension range are appended to `extensions`;
+ * unrecognised ones are skipped, or appended to `unknown_fields`.
+ * `(solana.required)` fields missing from the buffer fail the merge.
+ * `decode_fields` shares the loop, skipping fields outside its selection.
+ * Every other field is passed to `trace_field` with its result. `owner`
+ * is the message struct, whose name and wire types the errors use, and
+ * `input` the type of the buffer.
+ */
+function genMergeFunction(
+  fields: FieldInfo[],
+  keepUnknown: boolean,
+  extensible: boolean,
+  owner = "Self",
+  input = "&[u8]"
 ): string {
+  // One bit per singular field for `reject_duplicate_fields`; a oneof
+  // shares one bit, since any two of its members conflict
+  const singular = [
+    ...new Set(fields.filter(f => !isRepeated(f)).map(f => f.oneof?.field ?? f.name))
+  ]
+  // Messages with only repeated fixed-size scalars never consult the
+  // options, short of `reject_wire_type_mismatch`
+  const checksWireTypes = fields.some(f => PROTO_TYPE_MAP[f.type])
+  const usesOpts = singular.length > 0 || checksWireTypes || fields.some(decodeUsesOptions)
+  const opts = usesOpts ? "opts" : "_opts"
+
   const lines: string[] = []
   lines.push(
-    `    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {`
+    `    pub fn merge(&mut self, data: ${input}) -> Result<(), DecodeError> {`,
+    `        self.merge_with(data, &DecodeOptions::DEFAULT)`,
+    `    }`,
+    ``,
+    `    pub fn merge_with(&mut self, data: ${input}, opts: &DecodeOptions) -> Result<(), DecodeError> {`,
+    `        self.merge_selected(data, opts, None)`,
+    `    }`,
+    ``,
+    `    fn merge_selected(&mut self, data: ${input}, ${opts}: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {`
   )
-  lines.push(`        let mut msg = Self::default();`)
   lines.push(`        let mut pos = 0usize;`)
   lines.push(`        let end = data.len();`)
+  if (singular.length > 0) {
+    lines.push(`        let mut seen = [0u64; ${Math.ceil(singular.length / 64)}];`)
+  }
   lines.push(``)
   lines.push(`        while pos < end {`)
+  lines.push(`            let field_start = pos;`)
   lines.push(`            let (tag, new_pos) = decode_key(data, pos)?;`)
   lines.push(`            pos = new_pos;`)
+  lines.push(`            if !is_selected(only, tag >> 3) {`)
+  lines.push(`                pos = skip_field(data, pos, tag)?;`)
+  lines.push(`                continue;`)
+  lines.push(`            }`)
   lines.push(``)
-  lines.push(`            match tag {`)
-
-  for (const field of msg.fields) {
-    if (isMapEntryField(field, msg)) continue
-
-    const mapEntry = resolveMapEntry(field, msg)
-    const fieldInfo = mapEntry ? { ...field, mapEntry } : field
+  // The arms run in a closure so any error they raise can be given the
+  // field's position on the way out
+  const arms: string[] = []
+  // Every well-formed key of a declared field has an arm, so only
+  // unknown numbers and mismatched wire types get to the fallback
+  const fallback: string[] = []
+  if (checksWireTypes) {
+    fallback.push(`                if opts.reject_wire_type_mismatch {`)
+    fallback.push(`                    check_wire_type(tag, field_start, ${owner}::FIELD_WIRE_TYPES)?;`)
+    fallback.push(`                }`)
+  }
+  fallback.push(`                pos = skip_field(data, pos, tag)?;`)
+  if (keepUnknown) fallback.push(`                self.unknown_fields.push_raw(&data[field_start..pos]);`)
 
-    lines.push(genFieldDecode(fieldInfo))
+  if (fields.length === 0 && !extensible) {
+    // A `match` of the fallback alone is clippy's `match_single_binding`
+    arms.push(...fallback.map(l => l.slice(4)))
+  } else {
+    arms.push(`            match tag {`)
+    for (const field of fields) {
+      const arm = genFieldDecode(field)
+      const index = isRepeated(field) ? -1 : singular.indexOf(field.oneof?.field ?? field.name)
+      arms.push(index >= 0 ? withSingularCheck(arm, index) : arm)
+    }
+    if (extensible) {
+      arms.push(`            _ if in_extension_range(tag >> 3, Self::EXTENSION_RANGES) => {`)
+      arms.push(`                pos = skip_field(data, pos, tag)?;`)
+      arms.push(`                self.extensions.push_raw(&data[field_start..pos]);`)
+      arms.push(`            }`)
+    }
+    arms.push(`            _ => {`, ...fallback, `            }`, `            }`)
   }
+  arms.push(`            Ok(())`)
 
-  lines.push(`            _ => {`)
-  lines.push(`                pos = skip_field(data, pos, tag & 0x07)?;`)
-  lines.push(`            }`)
+  const fieldTable = checksWireTypes ? `${owner}::FIELD_WIRE_TYPES` : "&[]"
+  const elemIndex = repeatedElems(fields).length > 0 ? "self.next_index(tag >> 3)" : "None"
+  lines.push(`            let mut field = || -> Result<(), DecodeError> {`)
+  lines.push(...arms.join("\n").split("\n").map(l => (l ? `    ${l}` : l)))
+  lines.push(`            };`)
+  lines.push(`            let result = field();`)
+  lines.push(`            trace_field(${owner}::FULL_NAME, tag, field_start, pos, result.as_ref().err());`)
+  lines.push(`            if let Err(e) = result {`)
+  lines.push(`                return Err(field_error(e, data, field_start, tag, ${fieldTable}, ${elemIndex}));`)
   lines.push(`            }`)
   lines.push(`        }`)
   lines.push(``)
-  lines.push(`        Ok(msg)`)
+  // `(solana.required)` fields must have been marked by their arm
+  for (const field of fields.filter(f => f.required)) {
+    lines.push(`        if is_selected(only, ${field.number}) {`)
+    lines.push(`            check_required(&seen, ${singular.indexOf(field.name)}, ${field.number})?;`)
+    lines.push(`        }`)
+  }
+  lines.push(`        Ok(())`)
   lines.push(`    }`)
+
+  const repeated = repeatedElems(fields)
+  if (repeated.length > 0) {
+    lines.push(
+      ``,
+      `    /// The element of repeated field \`number\` being decoded, for \`field_error\`.`,
+      `    fn next_index(&self, number: u64) -> Option<usize> {`,
+      `        match number {`,
+      ...repeated.map(f => `            ${f.number} => Some(self.${fieldRustName(f)}.len()),`),
+      `            _ => None,`,
+      `        }`,
+      `    }`
+    )
+  }
   return lines.join("\n")
 }
 
-// ── Map entry resolution ──────────────────────────────────────────────
-
-interface MapEntryInfo {
-  keyType: number
-  valueType: number
-  valueTypeName?: string
+/** Repeated fields decoded one element at a time, so not maps. */
+function repeatedElems(fields: FieldInfo[]): FieldInfo[] {
+  return fields.filter(f => isRepeated(f) && !isMap(f) && PROTO_TYPE_MAP[f.type])
 }
 
 /**
- * Check if a field references a map entry message type.
+ * The `FIELD_WIRE_TYPES` table `check_wire_type` looks declared fields
+ * up in, with the wire type each one is decoded from. Empty when no
+ * field has a supported type.
  */
-function isMapEntryField(
-  field: FieldInfo,
-  parentMsg: MessageDescriptor
-): boolean {
-  if (field.type !== 11 || field.label !== 3) return false
-  const nested = parentMsg.nestedMessages.find(
-    m => field.typeName?.endsWith(`.${m.name}`)
-  )
-  return nested?.isMapEntry ?? false
+function genFieldWireTypes(structName: string, fields: FieldInfo[]): string[] {
+  const entries = fields.flatMap(f => {
+    const typeInfo = PROTO_TYPE_MAP[f.type]
+    if (!typeInfo) return []
+    const wireType = f.mapEntry ? WireType.LengthDelimited : typeInfo.wireType
+    return [
+      `        FieldWireType { number: ${f.number}, wire_type: ${wireType}, packed: ${isPackable(f)}, name: "${structName}.${f.name}" },`
+    ]
+  })
+  if (entries.length === 0) return []
+  return [`    const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[`, ...entries, `    ];`]
 }
 
 /**
- * Resolve a map entry's key/value types from the synthetic nested message.
+ * Open a singular field's decode arm with `mark_singular`, so strict
+ * decoding rejects a second occurrence before the value is stored.
  */
-function resolveMapEntry(
-  field: FieldInfo,
-  parentMsg: MessageDescriptor
-): MapEntryInfo | undefined {
-  if (field.type !== 11 || field.label !== 3) return undefined
-  const nested = parentMsg.nestedMessages.find(
-    m => field.typeName?.endsWith(`.${m.name}`)
-  )
-  if (!nested?.isMapEntry) return undefined
-
-  const keyField = nested.fields.find(f => f.number === 1)
-  const valField = nested.fields.find(f => f.number === 2)
-  if (!keyField || !valField) return undefined
+function withSingularCheck(arm: string, index: number): string {
+  const [head, ...body] = arm.split("\n")
+  if (!head.endsWith("=> {")) return arm
+  return [head, `                mark_singular(&mut seen, ${index}, opts)?;`, ...body].join("\n")
+}
 
-  return {
-    keyType: keyField.type,
-    valueType: valField.type,
-    valueTypeName: valField.typeName
+/**
+ * The `reject_floats` check: any `float` or `double` value, including
+ * wrapper types and map values, fails generation.
+ */
+function rejectFloatFields(fullName: string, fields: FieldInfo[]): void {
+  for (const field of fields) {
+    const value = isMap(field) ? mapValueField(field) : field
+    const wrapper =
+      value.typeName === ".google.protobuf.DoubleValue" ||
+      value.typeName === ".google.protobuf.FloatValue"
+    if (value.type === 1 || value.type === 2 || wrapper) {
+      throw new Error(`${fullName}.${field.name} is a floating-point field, which reject_floats forbids`)
+    }
   }
 }
+
+/** True for the member a oneof group's struct field is emitted with. */
+function isFirstOneofMember(field: FieldInfo, fields: FieldInfo[]): boolean {
+  return fields.find(f => f.oneof?.field === field.oneof?.field) === field
+}
@@ -0,0 +1,232 @@
+import { protoNameToRust } from "../util/names.js"
+import { withMapEntry } from "./field.js"
+import type { ServiceDescriptor } from "./service.js"
+import type { MessageDescriptor } from "./message.js"
+import type { ExtensionDescriptor } from "./extension.js"
+import type { GenContext } from "./context.js"
+
+/** A `module_map` entry: proto packages under `package` go to Rust module `module`. */
+export interface ModuleMapping {
+  package: string
+  module: string[]
+}
+
+const RUST_KEYWORDS = new Set([
+  "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false", "fn", "for",
+  "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static", "struct",
+  "trait", "true", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "gen",
+  "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield"
+])
+
+/** Module names that a raw identifier cannot spell. */
+const RESERVED_MODULES = new Set(["crate", "self", "super", "Self"])
+
+/** True for a name Rust reserves: a keyword, or `self`, `super`, `crate` and `Self`. */
+export function isRustKeyword(name: string): boolean {
+  return RUST_KEYWORDS.has(name) || RESERVED_MODULES.has(name)
+}
+
+/**
+ * The Rust module path of a proto package: the longest `module_map`
+ * entry covering it, with the rest of the package appended, or the
+ * package's own components.
+ */
+export function packageModule(packageName: string, mappings: ModuleMapping[]): string[] {
+  const covering = mappings
+    .filter(m => packageName === m.package || packageName.startsWith(`${m.package}.`))
+    .sort((a, b) => b.package.length - a.package.length)[0]
+  if (!covering) return packageName ? packageName.split(".") : []
+  const rest = packageName.slice(covering.package.length).split(".").filter(Boolean)
+  return [...covering.module, ...rest]
+}
+
+/** A module name as Rust spells it, raw if it is a keyword. */
+export function moduleIdent(name: string): string {
+  if (RESERVED_MODULES.has(name)) throw new Error(`"${name}" cannot be used as a Rust module name`)
+  return RUST_KEYWORDS.has(name) ? `r#${name}` : name
+}
+
+/**
+ * Generate `use` declarations for the types a file takes from other
+ * generated files: `super::<file>::` for another file of the same
+ * package, crate-relative otherwise. Views are imported alongside their
+ * messages under `ref_views`, and `Cow` structs under `cow_structs`.
+ * Types are used by their last name component, so two of them with the
+ * same name in one file are an error.
+ */
+export function genCrossFileUses(
+  messages: MessageDescriptor[],
+  services: ServiceDescriptor[],
+  localTypes: string[],
+  self: string[],
+  ctx: GenContext,
+  extensions: ExtensionDescriptor[] = []
+): string[] {
+  const referenced = new Set<string>()
+  for (const msg of messages) {
+    if (msg.isMapEntry) continue
+    for (const field of msg.fields.map(f => withMapEntry(f, msg))) {
+      const typeName = field.mapEntry ? field.mapEntry.valueTypeName : field.typeName
+      if (typeName) referenced.add(typeName)
+    }
+  }
+  for (const service of services) {
+    for (const m of service.methods) referenced.add(m.inputType).add(m.outputType)
+  }
+  // Views and `Cow` structs have no extensions, so these types need no `Ref` or `Cow` import
+  const viewed = new Set(referenced)
+  for (const ext of extensions) {
+    referenced.add(ext.extendee)
+    if (ext.field.typeName) referenced.add(ext.field.typeName)
+  }
+
+  const byPath = new Map<string, Set<string>>()
+  // Full name of the type each Rust name refers to in this file
+  const owners = new Map<string, string>(localTypes.map(fullName => [protoNameToRust(fullName), fullName]))
+  for (const typeName of [...referenced].sort()) {
+    const target = ctx.typeModules.get(typeName)
+    if (typeName.startsWith(".google.protobuf.") || !target || samePath(target, self)) continue
+    const name = protoNameToRust(typeName)
+    const owner = owners.get(name)
+    if (owner !== undefined && owner !== typeName.slice(1)) {
+      throw new Error(`${typeName.slice(1)} and ${owner} are both named ${name} in module ${self.join("::")}`)
+    }
+    owners.set(name, typeName.slice(1))
+
+    const path = modulePath(target, self, ctx)
+    if (!byPath.has(path)) byPath.set(path, new Set())
+    byPath.get(path)!.add(name)
+    if (ctx.refViews && viewed.has(typeName) && ctx.messages.has(typeName)) byPath.get(path)!.add(`${name}Ref`)
+    if (viewed.has(typeName) && ctx.cowStructs.has(typeName)) byPath.get(path)!.add(`${name}Cow`)
+  }
+
+  return [...byPath].sort(([a], [b]) => compareNames(a, b)).map(([path, names]) => {
+    const sorted = [...names].sort()
+    return sorted.length === 1 ? `use ${path}::${sorted[0]};` : `use ${path}::{${sorted.join(", ")}};`
+  })
+}
+
+/**
+ * Order by UTF-16 code units, as `sort()` does, rather than `localeCompare`,
+ * whose order depends on the machine's locale.
+ */
+function compareNames(a: string, b: string): number {
+  return a < b ? -1 : a > b ? 1 : 0
+}
+
+function samePath(a: string[], b: string[]): boolean {
+  return a.length === b.length && a.every((part, i) => part === b[i])
+}
+
+/**
+ * Path of the output root from module `self`: `crate`, or under `bundle`,
+ * where the tree is one module anywhere in the crate, `super::` up to it.
+ */
+export function rootPath(self: string[], ctx: GenContext): string {
+  return ctx.bundle ? self.map(() => "super").join("::") : "crate"
+}
+
+/** Path of module `target` as seen from module `self`. */
+function modulePath(target: string[], self: string[], ctx: GenContext): string {
+  const siblings =
+    target.length === self.length && target.slice(0, -1).every((part, i) => part === self[i])
+  if (siblings) return `super::${moduleIdent(target[target.length - 1])}`
+  return [rootPath(self, ctx), ...target.map(moduleIdent)].join("::")
+}
+
+/** Output `.rs` files as a module tree: directories and files by name. */
+interface ModuleNode {
+  content?: string
+  children: Map<string, ModuleNode>
+}
+
+/**
+ * Build the module tree of the output files, failing when a file and a
+ * package directory would be the same module.
+ */
+function moduleTree(files: Array<{ name: string; content?: string }>): ModuleNode {
+  const root: ModuleNode = { children: new Map() }
+  for (const file of files) {
+    const parts = file.name.replace(/\.rs$/, "").split("/")
+    let node = root
+    parts.forEach((part, i) => {
+      let child = node.children.get(part)
+      if (!child) node.children.set(part, (child = { children: new Map() }))
+      const isFile = i === parts.length - 1
+      if (isFile ? child.children.size > 0 : child.content !== undefined) {
+        const path = parts.slice(0, i + 1).join("/")
+        throw new Error(`${path}.rs and ${path}/ are both module ${part}`)
+      }
+      if (isFile) child.content = file.content ?? ""
+      node = child
+    })
+  }
+  return root
+}
+
+/**
+ * Generate the `bundle` option's single file: the runtime and every
+ * generated file as inline modules, laid out as their output paths
+ * would be, so the file can be declared as one module anywhere in a
+ * crate.
+ */
+export function genBundle(files: Array<{ name: string; content: string }>): string {
+  const render = (node: ModuleNode, depth: number): string[] =>
+    [...node.children].sort(([a], [b]) => compareNames(a, b)).flatMap(([name, child], i) => {
+      const indent = "    ".repeat(depth)
+      const body =
+        child.content !== undefined
+          ? child.content.replace(/\n+$/, "").split("\n").map(line => (line ? `${indent}    ${line}` : ""))
+          : render(child, depth + 1)
+      return [...(i > 0 ? [""] : []), `${indent}pub mod ${moduleIdent(name)} {`, ...body, `${indent}}`]
+    })
+  // With `runtime_path` the runtime is not among the files, and the bundle imports it
+  const about = files.some(f => f.name === "protobuf_runtime.rs")
+    ? [
+        `// The protobuf runtime and every generated file as one self-contained`,
+        `// module: declare it with \`mod <file name>;\` anywhere in the crate.`
+      ]
+    : [
+        `// Every generated file as one module, using the runtime at \`runtime_path\`:`,
+        `// declare it with \`mod <file name>;\` anywhere in the crate.`
+      ]
+  return [
+    `// Auto-generated by protoc-gen-solana`,
+    `// DO NOT EDIT`,
+    `//`,
+    ...about,
+    ``,
+    ...render(moduleTree(files), 0),
+    ``
+  ].join("\n")
+}
+
+/**
+ * Generate a `mod.rs` for every directory of generated `.rs` files
+ * (`module_tree` option), declaring the package's file modules and its
+ * sub-package modules. The output root gets none: the crate root
+ * declares `protobuf_runtime` and the top-level packages.
+ */
+export function genModFiles(rsFileNames: string[]): Array<{ name: string; content: string }> {
+  const files: Array<{ name: string; content: string }> = []
+  const walk = (node: ModuleNode, dir: string) => {
+    for (const [name, child] of node.children) {
+      if (child.content !== undefined) continue
+      const path = dir ? `${dir}/${name}` : name
+      if (child.children.has("mod")) throw new Error(`${path}/mod.rs would replace the generated ${path}/mod.rs`)
+      files.push({
+        name: `${path}/mod.rs`,
+        content:
+          [
+            `// Auto-generated by protoc-gen-solana`,
+            `// DO NOT EDIT`,
+            ``,
+            ...[...child.children.keys()].sort().map(child => `pub mod ${moduleIdent(child)};`)
+          ].join("\n") + "\n"
+      })
+      walk(child, path)
+    }
+  }
+  walk(moduleTree(rsFileNames.map(name => ({ name }))), "")
+  return files
+}
@@ -0,0 +1,134 @@
+import { toSnakeCase, toPascalCase } from "../util/names.js"
+import { FieldInfo, elemRustType, fieldRustName, fieldVisibility, heldRustType } from "./field.js"
+import { SERDE_DERIVE, serdeWithAttr } from "./serde.js"
+import { deriveAttr } from "./derive.js"
+import { deprecatedLines } from "./deprecated.js"
+import { docLines } from "./doc.js"
+
+/** A real oneof group and the fields that belong to it. */
+export interface OneofGroup {
+  /** Struct member holding the selection (snake_case oneof name) */
+  field: string
+  /** Generated enum, e.g. `InstructionKind` */
+  enumName: string
+  /** Visibility of the struct member, `pub` unless the `overrides` file sets one */
+  visibility: string
+  members: FieldInfo[]
+}
+
+/** A oneof's Rust member name and visibility from the `overrides` file. */
+export interface OneofOverride {
+  rustName?: string
+  visibility?: string
+}
+
+/**
+ * Tag each member of a real oneof with its group. Synthetic oneofs
+ * backing proto3 `optional` fields are left as plain `Option<T>` fields.
+ * A oneof's `overrides` rename names its member and enum.
+ */
+export function resolveOneofs(
+  structName: string,
+  fields: FieldInfo[],
+  oneofNames: string[],
+  overrides: OneofOverride[] = []
+): { fields: FieldInfo[]; groups: OneofGroup[] } {
+  const groups = new Map<number, OneofGroup>()
+
+  const resolved = fields.map(f => {
+    if (f.oneofIndex === undefined || f.proto3Optional) return f
+    let group = groups.get(f.oneofIndex)
+    if (!group) {
+      const override = overrides[f.oneofIndex]
+      const name = override?.rustName ?? oneofNames[f.oneofIndex] ?? `oneof_${f.oneofIndex}`
+      group = {
+        field: toSnakeCase(name),
+        enumName: `${structName}${toPascalCase(name)}`,
+        visibility: override?.visibility ?? "pub",
+        members: []
+      }
+      groups.set(f.oneofIndex, group)
+    }
+    const member = {
+      ...f,
+      oneof: { field: group.field, enumName: group.enumName, visibility: group.visibility, variant: toPascalCase(f.name) }
+    }
+    group.members.push(member)
+    return member
+  })
+
+  return { fields: resolved, groups: [...groups.values()] }
+}
+
+/**
+ * Generate the enum for a oneof group: one variant per member plus
+ * `Unset`, the default when no member is on the wire. It derives the
+ * message's extra `derives` too, which its struct needs of it, and has
+ * the message's visibility.
+ */
+export function genOneofEnum(group: OneofGroup, serde: boolean, derives: string[], visibility: string): string {
+  const variant = (m: FieldInfo) => {
+    const attr = serde ? serdeWithAttr(m) : undefined
+    return [
+      ...docLines(m.doc, "    "),
+      ...deprecatedLines(m, "    "),
+      `    ${m.oneof!.variant}(${attr ? `${attr} ` : ""}${heldRustType(m)}),`
+    ].join("\n")
+  }
+  return [
+    deriveAttr(["Clone", "Debug", "PartialEq"], derives),
+    `#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]`,
+    ...(serde ? [SERDE_DERIVE] : []),
+    `${visibility} enum ${group.enumName} {`,
+    `    Unset,`,
+    ...group.members.map(variant),
+    `}`,
+    ``,
+    `impl ${group.enumName} {`,
+    `    pub const DEFAULT: Self = ${group.enumName}::Unset;`,
+    `}`,
+    ``,
+    `impl Default for ${group.enumName} {`,
+    `    fn default() -> Self {`,
+    `        Self::DEFAULT`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
+
+/**
+ * Generate `<member>()` getters and `set_<member>()` setters on the
+ * message for every oneof member. Setting one member replaces any other.
+ */
+export function genOneofAccessors(structName: string, groups: OneofGroup[]): string {
+  const lines: string[] = [`impl ${structName} {`]
+
+  for (const group of groups) {
+    for (const member of group.members) {
+      const rustName = fieldRustName(member)
+      const rustType = elemRustType(member)
+      const vis = fieldVisibility(member)
+      const variant = `${group.enumName}::${member.oneof!.variant}`
+      const deprecated = deprecatedLines(member, "    ")
+      lines.push(
+        ...deprecated,
+        `    ${vis} fn ${rustName}(&self) -> Option<&${rustType}> {`,
+        `        match &self.${group.field} {`,
+        `            ${variant}(value) => Some(value),`,
+        `            _ => None,`,
+        `        }`,
+        `    }`,
+        ``,
+        ...deprecated,
+        `    ${vis} fn set_${rustName}(&mut self, value: ${rustType}) {`,
+        `        self.${group.field} = ${variant}(${member.box ? "value.into()" : "value"});`,
+        `    }`,
+        ``
+      )
+    }
+  }
+
+  if (lines[lines.length - 1] === "") lines.pop()
+  lines.push(`}`)
+  return lines.join("\n")
+}
@@ -0,0 +1,121 @@
+import { FieldInfo, fieldRustName, isMessage, isOptional, isRepeated } from "./field.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+
+/** Longest seed `create_program_address` accepts. */
+const MAX_SEED_LEN = 32
+/** Most seeds a PDA may have, not counting the bump. */
+const MAX_SEEDS = 15
+
+/**
+ * Parse `(solana.seeds)`: `;`-separated entries, each a literal seed or
+ * `field:<name>` for one of the message's fields.
+ */
+function parseSeeds(spec: string, messageName: string): Array<{ literal: string } | { field: string }> {
+  const seeds = spec
+    .split(";")
+    .map(e => e.trim())
+    .filter(e => e !== "")
+    .map(e => (e.startsWith("field:") ? { field: e.slice("field:".length).trim() } : { literal: e }))
+  if (seeds.length === 0) throw new Error(`(solana.seeds) of ${messageName} declares no seeds`)
+  if (seeds.length > MAX_SEEDS) {
+    throw new Error(`(solana.seeds) of ${messageName} has ${seeds.length} seeds, more than the ${MAX_SEEDS} a PDA allows`)
+  }
+  return seeds
+}
+
+/** A Rust byte string literal for `text`'s UTF-8 bytes. */
+function byteStringLiteral(text: string): string {
+  const escaped = [...Buffer.from(text, "utf8")]
+    .map(b => {
+      if (b === 0x22 || b === 0x5c) return `\\${String.fromCharCode(b)}`
+      if (b >= 0x20 && b < 0x7f) return String.fromCharCode(b)
+      return `\\x${b.toString(16).padStart(2, "0")}`
+    })
+    .join("")
+  return `b"${escaped}"`
+}
+
+/**
+ * The seed bytes of a field: pubkeys, bytes and strings as they are,
+ * integers and enums little-endian, bools as one byte. Integers and bools
+ * are first bound to a local, returned as `binding`.
+ */
+function fieldSeed(field: FieldInfo, messageName: string): { binding?: string; expr: string } {
+  const where = `field ${field.name} of ${messageName}`
+  if (isRepeated(field) || isOptional(field) || field.oneof || isMessage(field)) {
+    throw new Error(`(solana.seeds) ${where} must be a singular scalar`)
+  }
+  const rustName = fieldRustName(field)
+  switch (field.type) {
+    case TYPE_PUBKEY:
+      return { expr: `self.${rustName}.as_ref()` }
+    case 9:
+      return { expr: `self.${rustName}.as_bytes()` }
+    case 12:
+      if (field.fixedLen !== undefined && field.fixedLen > MAX_SEED_LEN) {
+        throw new Error(`(solana.seeds) ${where} is ${field.fixedLen} bytes, longer than a ${MAX_SEED_LEN}-byte seed`)
+      }
+      return { expr: `self.${rustName}.as_slice()` }
+    case 8:
+      return { binding: `let ${rustName}_seed = [self.${rustName} as u8];`, expr: `${rustName}_seed.as_slice()` }
+    case 1:
+    case 2:
+      throw new Error(`(solana.seeds) ${where} is a float, which has no stable seed bytes`)
+    default:
+      return { binding: `let ${rustName}_seed = self.${rustName}.to_le_bytes();`, expr: `${rustName}_seed.as_slice()` }
+  }
+}
+
+/**
+ * Generate `derive_address` and `verify_pda` for a message with
+ * `(solana.seeds)`, behind the `solana-program` feature. The seeds are
+ * literals and the message's own field values, in the declared order;
+ * `verify_pda` takes the bump so a program checks an address with one
+ * `create_program_address` instead of the bump search.
+ */
+export function genPdaImpl(structName: string, fields: FieldInfo[], spec: string): string {
+  const bindings: string[] = []
+  const exprs: string[] = []
+  for (const seed of parseSeeds(spec, structName)) {
+    if ("literal" in seed) {
+      if (Buffer.byteLength(seed.literal, "utf8") > MAX_SEED_LEN) {
+        throw new Error(`(solana.seeds) literal "${seed.literal}" of ${structName} is longer than ${MAX_SEED_LEN} bytes`)
+      }
+      exprs.push(`${byteStringLiteral(seed.literal)}.as_slice()`)
+      continue
+    }
+    const field = fields.find(f => f.name === seed.field)
+    if (!field) throw new Error(`(solana.seeds) of ${structName} names unknown field ${seed.field}`)
+    const { binding, expr } = fieldSeed(field, structName)
+    if (binding && !bindings.includes(binding)) bindings.push(binding)
+    exprs.push(expr)
+  }
+
+  const body = (seeds: string[]) => [...bindings.map(b => `        ${b}`), `        let seeds = [${seeds.join(", ")}];`]
+  return [
+    `#[cfg(feature = "solana-program")]`,
+    `impl ${structName} {`,
+    `    /// The program address for this message's seeds under \`program_id\`,`,
+    `    /// with its bump seed.`,
+    `    pub fn derive_address(&self, program_id: &Pubkey) -> (Pubkey, u8) {`,
+    ...body(exprs),
+    `        Pubkey::find_program_address(&seeds, program_id)`,
+    `    }`,
+    ``,
+    `    /// Check that \`address\` is the program address for this message's`,
+    `    /// seeds and \`bump\` under \`program_id\`.`,
+    `    pub fn verify_pda(`,
+    `        &self,`,
+    `        program_id: &Pubkey,`,
+    `        address: &Pubkey,`,
+    `        bump: u8,`,
+    `    ) -> Result<(), solana_program::program_error::ProgramError> {`,
+    ...body([...exprs, "core::slice::from_ref(&bump)"]),
+    `        match Pubkey::create_program_address(&seeds, program_id) {`,
+    `            Ok(expected) if expected == *address => Ok(()),`,
+    `            _ => Err(solana_program::program_error::ProgramError::InvalidSeeds),`,
+    `        }`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
@@ -0,0 +1,135 @@
+import { log } from "../util/logger.js"
+import {
+  FieldInfo,
+  elemRustType,
+  fieldRustName,
+  fieldVisibility,
+  isFixedBytes,
+  isOptional,
+  isRepeated
+} from "./field.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+import { DEPRECATED_ATTR, deprecatedLines } from "./deprecated.js"
+
+/** Scalar types stored in a Pod layout as their own Rust type. */
+const POD_SCALARS = new Set([1, 2, 3, 4, 5, 6, 7, 13, 14, 15, 16, 17, 18])
+
+/**
+ * Generate the `pod` option's `<Message>Pod` layout, behind the output
+ * crate's `bytemuck` feature, for a message whose fields are all
+ * singular and fixed-width: numbers, enums (as `i32`), bools (as `u8`),
+ * pubkeys and `(solana.fixed_len)` bytes (as byte arrays). The layout
+ * is `#[repr(C, packed)]` in declaration order, so it has no padding
+ * and can be read in place from unaligned account data. Any other
+ * message gets none; `undefined` then.
+ */
+export function genPodImpl(
+  structName: string,
+  fields: FieldInfo[],
+  keepUnknown: boolean,
+  visibility: string,
+  deprecated: boolean
+): string | undefined {
+  if (fields.length === 0 || !fields.every(isPodField)) {
+    log.debug(`${structName} is not fixed-width, no Pod layout`)
+    return undefined
+  }
+  const podName = `${structName}Pod`
+  const members = fields.flatMap(f => [
+    ...deprecatedLines(f, "    "),
+    `    ${fieldVisibility(f)} ${fieldRustName(f)}: ${podRustType(f)},`
+  ])
+  const toPod = fields.map(f => `            ${fieldRustName(f)}: ${toPodExpr(f, `msg.${fieldRustName(f)}`)},`)
+  const fromPod = fields.map(f => `            ${fieldRustName(f)}: ${fromPodExpr(f, `pod.${fieldRustName(f)}`)},`)
+  return [
+    `/// \`${structName}\` as a fixed, unpadded layout that account data can be`,
+    `/// read as in place. Bools are \`0\` / \`1\` bytes and pubkeys byte arrays.`,
+    `#[cfg(feature = "bytemuck")]`,
+    `#[repr(C, packed)]`,
+    `#[derive(Clone, Copy, Debug, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]`,
+    ...(deprecated ? [DEPRECATED_ATTR] : []),
+    `${visibility} struct ${podName} {`,
+    ...members,
+    `}`,
+    ``,
+    `#[cfg(feature = "bytemuck")]`,
+    `impl ${podName} {`,
+    `    /// Size of the layout in bytes.`,
+    `    pub const LEN: usize = core::mem::size_of::<Self>();`,
+    ``,
+    `    /// View the first \`LEN\` bytes of \`data\` as a \`${podName}\`, without copying.`,
+    `    pub fn from_bytes(data: &[u8]) -> Result<&Self, DecodeError> {`,
+    `        pod_from_bytes(data)`,
+    `    }`,
+    ``,
+    `    /// Like \`from_bytes\`, for updating the fields in place.`,
+    `    pub fn from_bytes_mut(data: &mut [u8]) -> Result<&mut Self, DecodeError> {`,
+    `        pod_from_bytes_mut(data)`,
+    `    }`,
+    ``,
+    `    pub fn as_bytes(&self) -> &[u8] {`,
+    `        bytemuck::bytes_of(self)`,
+    `    }`,
+    ``,
+    `    /// Decode the protobuf encoding of a \`${structName}\` into this layout.`,
+    `    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {`,
+    `        ${structName}::decode_with(data, &DecodeOptions::ACCOUNT).map(|msg| Self::from(&msg))`,
+    `    }`,
+    ``,
+    `    /// The protobuf encoding of the equivalent \`${structName}\`.`,
+    `    pub fn encode(&self) -> Vec<u8> {`,
+    `        ${structName}::from(*self).encode()`,
+    `    }`,
+    `}`,
+    ``,
+    `#[cfg(feature = "bytemuck")]`,
+    `impl From<&${structName}> for ${podName} {`,
+    `    fn from(msg: &${structName}) -> Self {`,
+    `        ${podName} {`,
+    ...toPod,
+    `        }`,
+    `    }`,
+    `}`,
+    ``,
+    `#[cfg(feature = "bytemuck")]`,
+    `impl From<${podName}> for ${structName} {`,
+    `    fn from(pod: ${podName}) -> Self {`,
+    `        ${structName} {`,
+    ...fromPod,
+    ...(keepUnknown ? [`            unknown_fields: UnknownFields::new(),`] : []),
+    `        }`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
+
+function isPodField(field: FieldInfo): boolean {
+  if (isRepeated(field) || isOptional(field) || field.oneof || field.wrapper) return false
+  return POD_SCALARS.has(field.type) || field.type === 8 || field.type === TYPE_PUBKEY || isFixedBytes(field)
+}
+
+/** Bytes a field of a `<Message>Pod` layout takes up. */
+export function podFieldLen(field: FieldInfo): number {
+  if (field.type === 8) return 1
+  if (field.type === TYPE_PUBKEY) return 32
+  if (isFixedBytes(field)) return field.fixedLen!
+  return [1, 3, 4, 6, 16, 18].includes(field.type) ? 8 : 4
+}
+
+function podRustType(field: FieldInfo): string {
+  if (field.type === 8) return "u8"
+  if (field.type === TYPE_PUBKEY) return "[u8; PUBKEY_LEN]"
+  return elemRustType(field)
+}
+
+function toPodExpr(field: FieldInfo, value: string): string {
+  if (field.type === 8) return `${value} as u8`
+  if (field.type === TYPE_PUBKEY) return `pubkey_to_array(&${value})`
+  return value
+}
+
+function fromPodExpr(field: FieldInfo, value: string): string {
+  if (field.type === 8) return `${value} != 0`
+  if (field.type === TYPE_PUBKEY) return `pubkey_from_array(${value})`
+  return value
+}
@@ -0,0 +1,34 @@
+/**
+ * Generate the `prost` option's `prost::Message` impl, behind the output
+ * crate's `prost` feature: encoding reuses the generated encoder, and
+ * each field prost reads is merged through the runtime's
+ * `prost_merge_field`, so the message works with prost and tonic as is.
+ */
+export function genProstImpl(structName: string): string {
+  return [
+    `#[cfg(feature = "prost")]`,
+    `impl prost::Message for ${structName} {`,
+    `    fn encode_raw(&self, buf: &mut impl prost::bytes::BufMut) {`,
+    `        buf.put_slice(&Message::encode(self));`,
+    `    }`,
+    ``,
+    `    fn merge_field(`,
+    `        &mut self,`,
+    `        tag: u32,`,
+    `        wire_type: prost::encoding::WireType,`,
+    `        buf: &mut impl prost::bytes::Buf,`,
+    `        _ctx: prost::encoding::DecodeContext,`,
+    `    ) -> Result<(), prost::DecodeError> {`,
+    `        prost_merge_field(self, tag, wire_type, buf)`,
+    `    }`,
+    ``,
+    `    fn encoded_len(&self) -> usize {`,
+    `        Message::encoded_len(self)`,
+    `    }`,
+    ``,
+    `    fn clear(&mut self) {`,
+    `        Message::clear(self)`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
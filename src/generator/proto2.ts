@@ -0,0 +1,155 @@
+import { log } from "../util/logger.js"
+import { protoNameToRust } from "../util/names.js"
+import {
+  FieldInfo,
+  elemRustType,
+  fieldRustName,
+  fieldVisibility,
+  isFixedBytes,
+  isMessage,
+  isOptional
+} from "./field.js"
+import { variantFor } from "./enum.js"
+import { deprecatedLines } from "./deprecated.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+import type { GenContext } from "./context.js"
+
+/**
+ * Proto2 field semantics on the proto3 field model: an `optional` field
+ * has explicit presence, an `Option` as for proto3 `optional` unless it
+ * is a message or oneof member (which have presence already). `required`
+ * fails decoding when absent, like `(solana.required)`, and is always
+ * encoded. Repeated scalars are packed only with `[packed = true]`.
+ */
+export function withProto2Semantics(field: FieldInfo): FieldInfo {
+  switch (field.label) {
+    case 2:
+      return { ...field, required: true }
+    case 3:
+      return { ...field, packed: field.packed === true }
+    default:
+      if (isMessage(field) || field.oneofIndex !== undefined) return field
+      return { ...field, proto3Optional: true }
+  }
+}
+
+/**
+ * Generate `<field>_or_default()` for the optional fields declaring a
+ * proto2 `[default = ...]`: the value if present, else that default. A
+ * `required` field is always present, so its default never applies.
+ */
+export function genDefaultAccessors(structName: string, fields: FieldInfo[], ctx: GenContext): string {
+  const accessors: string[][] = []
+  for (const field of fields) {
+    if (field.defaultValue === undefined || field.label === 2) continue
+    const where = `${structName}.${field.name}`
+    const accessor = isOptional(field) ? defaultAccessor(field, ctx) : undefined
+    if (!accessor) {
+      log.warn(`Ignoring [default = ${field.defaultValue}] on ${where}, which has no default accessor`)
+      continue
+    }
+    accessors.push(accessor)
+  }
+  if (accessors.length === 0) return ""
+  return [`impl ${structName} {`, ...accessors.flatMap((a, i) => (i > 0 ? [``, ...a] : a)), `}`].join("\n")
+}
+
+function defaultAccessor(field: FieldInfo, ctx: GenContext): string[] | undefined {
+  const rustName = fieldRustName(field)
+  const value = field.defaultValue!
+  const accessor = (returnType: string, body: string) => [
+    ...deprecatedLines(field, "    "),
+    `    ${fieldVisibility(field)} fn ${rustName}_or_default(&self) -> ${returnType} {`,
+    `        ${body}`,
+    `    }`
+  ]
+
+  if (field.type === TYPE_PUBKEY || isFixedBytes(field)) return undefined
+  if (field.type === 9) return accessor("&str", `self.${rustName}.as_deref().unwrap_or(${rustStr(value)})`)
+  if (field.type === 12) return accessor("&[u8]", `self.${rustName}.as_deref().unwrap_or(${rustBytes(value)})`)
+  if (field.type === 14) {
+    const desc = field.typeName ? ctx.enums.get(field.typeName) : undefined
+    const variant = desc && variantFor(desc, value)
+    if (!variant) return undefined
+    return accessor("i32", `self.${rustName}.unwrap_or(${protoNameToRust(field.typeName!)}::${variant} as i32)`)
+  }
+  const rustType = elemRustType(field)
+  const literal = field.type === 1 || field.type === 2 ? floatLiteral(value, rustType) : value
+  return accessor(rustType, `self.${rustName}.unwrap_or(${literal})`)
+}
+
+/** A float default as protoc prints it (`1.5`, `1e+10`, `inf`, `nan`) as a Rust expression. */
+function floatLiteral(value: string, rustType: string): string {
+  if (value === "inf") return `${rustType}::INFINITY`
+  if (value === "-inf") return `${rustType}::NEG_INFINITY`
+  if (value === "nan") return `${rustType}::NAN`
+  // `1` is an integer literal in Rust
+  return /^-?\d+$/.test(value) ? `${value}.0` : value
+}
+
+/** A string default (descriptors store it unescaped) as a Rust string literal. */
+function rustStr(value: string): string {
+  const escaped = [...value]
+    .map(c => {
+      if (c === "\\" || c === '"') return `\\${c}`
+      if (c === "\n") return "\\n"
+      if (c === "\r") return "\\r"
+      if (c === "\t") return "\\t"
+      const code = c.codePointAt(0)!
+      return code < 0x20 || code === 0x7f ? `\\u{${code.toString(16)}}` : c
+    })
+    .join("")
+  return `"${escaped}"`
+}
+
+/** A bytes default, which descriptors store C-escaped, as a Rust byte string literal. */
+function rustBytes(value: string): string {
+  const escaped = cUnescape(value)
+    .map(b => {
+      if (b === 0x5c || b === 0x22) return `\\${String.fromCharCode(b)}`
+      return b >= 0x20 && b < 0x7f ? String.fromCharCode(b) : `\\x${b.toString(16).padStart(2, "0")}`
+    })
+    .join("")
+  return `b"${escaped}"`
+}
+
+const C_ESCAPES: Record<string, number> = {
+  n: 0x0a,
+  r: 0x0d,
+  t: 0x09,
+  a: 0x07,
+  b: 0x08,
+  f: 0x0c,
+  v: 0x0b,
+  "\\": 0x5c,
+  "'": 0x27,
+  '"': 0x22,
+  "?": 0x3f
+}
+
+/**
+ * Bytes of a C-escaped string: `\n`-style, octal `\NNN` and hex `\xHH`
+ * escapes. protoc escapes every byte above 0x7f, so the rest is ASCII.
+ */
+function cUnescape(value: string): number[] {
+  const bytes: number[] = []
+  for (let i = 0; i < value.length; i++) {
+    if (value[i] !== "\\" || i + 1 === value.length) {
+      bytes.push(value.charCodeAt(i))
+      continue
+    }
+    const next = value[++i]
+    const octal = /^[0-7]{1,3}/.exec(value.slice(i))
+    const hex = /^x([0-9a-fA-F]{1,2})/.exec(value.slice(i))
+    if (octal) {
+      bytes.push(parseInt(octal[0], 8) & 0xff)
+      i += octal[0].length - 1
+    } else if (hex) {
+      bytes.push(parseInt(hex[1], 16))
+      i += hex[0].length - 1
+    } else {
+      bytes.push(C_ESCAPES[next] ?? next.charCodeAt(0))
+    }
+  }
+  return bytes
+}
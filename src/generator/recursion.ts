@@ -0,0 +1,99 @@
+import { FieldInfo, isMessage, isRepeated } from "./field.js"
+import type { MessageDescriptor } from "./message.js"
+
+/** The `box_type` option's default: the std / alloc prelude's `Box`. */
+export const DEFAULT_BOX_TYPE = "Box"
+
+/**
+ * Hold the singular message fields that close a cycle, such as a
+ * `message Expr { Expr operand = 1; }`, in `boxType`: a struct holding
+ * its own type by value would have infinite size. A field closes a
+ * cycle when its type can reach its message again through singular
+ * message fields (oneof members included); repeated and map fields
+ * already allocate, so they break cycles and are left alone. Outside a
+ * oneof such a field also gets presence, `Option<Box<T>>`, so its
+ * default is `None` rather than an endless chain of defaults. A
+ * `BoundedVec` holds its elements inline, so it is on the path too, and
+ * one that closes a cycle stays a `Vec`. Imports cannot be circular, so
+ * every cycle is within one file's `messages`.
+ */
+export function withRecursionBoxes(messages: MessageDescriptor[], boxType: string): MessageDescriptor[] {
+  const components = cycleComponents(messages)
+  return messages.map(msg => {
+    const component = components.get(`.${msg.fullName}`)
+    if (!msg.fields.some(f => closesCycle(f, component, components))) return msg
+    const fields = msg.fields.map((f): FieldInfo => {
+      if (!closesCycle(f, component, components)) return f
+      if (isRepeated(f)) return { ...f, boundedVec: undefined }
+      const inOneof = f.oneofIndex !== undefined && !f.proto3Optional
+      return inOneof ? { ...f, box: boxType } : { ...f, box: boxType, proto3Optional: true }
+    })
+    return { ...msg, fields }
+  })
+}
+
+function closesCycle(field: FieldInfo, component: number | undefined, components: Map<string, number>): boolean {
+  return isInlineMessage(field) && component !== undefined && components.get(field.typeName!) === component
+}
+
+/** A message field whose value is part of its struct: a singular one, or a `BoundedVec` of them. */
+function isInlineMessage(field: FieldInfo): boolean {
+  return isMessage(field) && (!isRepeated(field) || field.boundedVec === true) && field.typeName !== undefined
+}
+
+/**
+ * The strongly connected component of every message in the graph of
+ * inline message fields, numbered by Tarjan's algorithm, keyed by type
+ * name. Only messages on a cycle are listed, a self-referential one
+ * included.
+ */
+function cycleComponents(messages: MessageDescriptor[]): Map<string, number> {
+  const edges = new Map<string, string[]>()
+  for (const msg of messages) {
+    const targets = msg.fields.filter(isInlineMessage).map(f => f.typeName!)
+    edges.set(`.${msg.fullName}`, targets)
+  }
+
+  const index = new Map<string, number>()
+  const low = new Map<string, number>()
+  const stack: string[] = []
+  const onStack = new Set<string>()
+  const components = new Map<string, number>()
+  let next = 0
+  let component = 0
+
+  const visit = (node: string): void => {
+    index.set(node, next)
+    low.set(node, next)
+    next++
+    stack.push(node)
+    onStack.add(node)
+    for (const target of edges.get(node) ?? []) {
+      if (!edges.has(target)) continue
+      if (!index.has(target)) {
+        visit(target)
+        low.set(node, Math.min(low.get(node)!, low.get(target)!))
+      } else if (onStack.has(target)) {
+        low.set(node, Math.min(low.get(node)!, index.get(target)!))
+      }
+    }
+    if (low.get(node) !== index.get(node)) return
+    const members: string[] = []
+    let member: string
+    do {
+      member = stack.pop()!
+      onStack.delete(member)
+      members.push(member)
+    } while (member !== node)
+    // A component of one message is a cycle only through a field of its own type
+    if (members.length > 1 || edges.get(node)!.includes(node)) {
+      for (const m of members) components.set(m, component)
+      component++
+    }
+  }
+
+  for (const node of edges.keys()) {
+    if (!index.has(node)) visit(node)
+  }
+  return components
+}
@@ -0,0 +1,102 @@
+import { protoNameToRust, toJsonName } from "../util/names.js"
+import { FieldInfo, isMap, isOptional, isRepeated, withMapEntry } from "./field.js"
+import type { EnumDescriptor } from "./enum.js"
+import type { MessageDescriptor } from "./message.js"
+import type { GenContext } from "./context.js"
+
+/** Runtime `FieldType` variant per FieldDescriptorProto.Type. */
+const FIELD_TYPES: Record<number, string> = {
+  1: "Double",
+  2: "Float",
+  3: "Int64",
+  4: "Uint64",
+  5: "Int32",
+  6: "Fixed64",
+  7: "Fixed32",
+  8: "Bool",
+  9: "String",
+  11: "Message",
+  12: "Bytes",
+  13: "Uint32",
+  14: "Enum",
+  15: "Sfixed32",
+  16: "Sfixed64",
+  17: "Sint32",
+  18: "Sint64"
+}
+
+function fieldType(type: number): string {
+  const variant = FIELD_TYPES[type]
+  if (!variant) throw new Error(`Unsupported protobuf field type for reflection: ${type}`)
+  return `FieldType::${variant}`
+}
+
+/** One `FieldDescriptor` entry of a message's table. */
+function fieldDescriptor(field: FieldInfo, oneofs: string[], ctx: GenContext): string {
+  const valueType = field.mapEntry ? field.mapEntry.valueType : field.type
+  const valueTypeName = field.mapEntry ? field.mapEntry.valueTypeName : field.typeName
+  if (valueType === 10) throw new Error(`Group field ${field.name} is not supported by the reflect option`)
+  const label = isMap(field)
+    ? `FieldLabel::Map(${fieldType(field.mapEntry!.keyType)})`
+    : isRepeated(field)
+      ? "FieldLabel::Repeated"
+      : isOptional(field)
+        ? "FieldLabel::Optional"
+        : "FieldLabel::Singular"
+  const jsonName = field.jsonName ?? toJsonName(field.name)
+  const calls: string[] = []
+  if (field.oneofIndex !== undefined && !field.proto3Optional) {
+    calls.push(`.with_oneof("${oneofs[field.oneofIndex] ?? `oneof_${field.oneofIndex}`}")`)
+  }
+  if (valueType === 11 && valueTypeName) {
+    calls.push(`.with_message(${protoNameToRust(valueTypeName)}::descriptor)`)
+  }
+  // An enum without values generates no Rust type to describe it
+  if (valueType === 14 && valueTypeName && (ctx.enums.get(valueTypeName)?.values.length ?? 0) > 0) {
+    calls.push(`.with_enum(${protoNameToRust(valueTypeName)}::descriptor)`)
+  }
+  const entry = `FieldDescriptor::new(${field.number}, "${field.name}", "${jsonName}", ${fieldType(valueType)}, ${label})`
+  return [`                ${entry}`, ...calls.map(call => `                    ${call}`)].join("\n") + ","
+}
+
+/**
+ * Generate the `reflect` option's `Reflect` impl, behind the output
+ * crate's `reflect` feature: a static table of the message's fields in
+ * declaration order. Field access goes through the runtime's provided
+ * methods on the encoded message.
+ */
+export function genReflectImpl(structName: string, msg: MessageDescriptor, ctx: GenContext): string {
+  const fields = msg.fields.map(f => withMapEntry(f, msg))
+  return [
+    `#[cfg(feature = "reflect")]`,
+    `impl Reflect for ${structName} {`,
+    `    fn descriptor() -> &'static MessageDescriptor {`,
+    `        static DESCRIPTOR: MessageDescriptor = MessageDescriptor {`,
+    `            full_name: "${msg.fullName.replace(/^\./, "")}",`,
+    ...(fields.length === 0
+      ? [`            fields: &[],`]
+      : [`            fields: &[`, ...fields.map(f => fieldDescriptor(f, msg.oneofs, ctx)), `            ],`]),
+    `        };`,
+    `        &DESCRIPTOR`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
+
+/** Generate the `reflect` option's `ReflectEnum` impl: the enum's values by name. */
+export function genReflectEnumImpl(desc: EnumDescriptor): string {
+  return [
+    `#[cfg(feature = "reflect")]`,
+    `impl ReflectEnum for ${protoNameToRust(desc.fullName)} {`,
+    `    fn descriptor() -> &'static EnumDescriptor {`,
+    `        static DESCRIPTOR: EnumDescriptor = EnumDescriptor {`,
+    `            full_name: "${desc.fullName}",`,
+    `            values: &[`,
+    ...desc.values.map(v => `                ("${v.name}", ${v.number}),`),
+    `            ],`,
+    `        };`,
+    `        &DESCRIPTOR`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
@@ -12,6 +12,16 @@ export function generateRuntime(): string {
   return RUNTIME_RS
 }
 
+/**
+ * Returns the complete protobuf_runtime.ts source: the wire format
+ * primitives the `ts_out` codecs import, emitted at the root of `ts_out`.
+ */
+export function generateTsRuntime(): string {
+  return RUNTIME_TS
+}
+
 const
   RUNTIME_RS_PATH = [Path.join(__dirname, "../../rs/protobuf_runtime.rs"), Path.join(__dirname, "../rs/protobuf_runtime.rs")].find(p => Fs.existsSync(p)),
-  RUNTIME_RS = Fs.readFileSync(RUNTIME_RS_PATH, "utf-8")
+  RUNTIME_RS = Fs.readFileSync(RUNTIME_RS_PATH, "utf-8"),
+  RUNTIME_TS_PATH = [Path.join(__dirname, "../../ts/protobuf_runtime.ts"), Path.join(__dirname, "../ts/protobuf_runtime.ts")].find(p => Fs.existsSync(p)),
+  RUNTIME_TS = Fs.readFileSync(RUNTIME_TS_PATH, "utf-8")
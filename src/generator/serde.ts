@@ -0,0 +1,38 @@
+import { FieldInfo, isMap, isOptional, isRepeated, isFixedBytes, mapValueField } from "./field.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+
+/**
+ * serde derives for generated structs and oneof enums (`serde` option),
+ * behind the output crate's `serde` feature so on-chain builds skip them.
+ */
+export const SERDE_DERIVE = `#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]`
+
+function holdsBytes(field: FieldInfo): boolean {
+  return field.type === 12 || field.type === TYPE_PUBKEY || isFixedBytes(field)
+}
+
+/**
+ * The `serde(with = ...)` attribute writing a bytes, fixed-length bytes
+ * or pubkey member as a base64 string, using the runtime's
+ * `serde_base64` adapter for its shape (singular, `Option`, `Vec`,
+ * `BoundedVec` or map value); `undefined` for every other member. Oneof variants hold the
+ * bare value.
+ */
+export function serdeWithAttr(field: FieldInfo): string | undefined {
+  let adapter: string
+  if (isMap(field)) {
+    if (!holdsBytes(mapValueField(field))) return undefined
+    adapter = "serde_base64::map"
+  } else if (!holdsBytes(field)) {
+    return undefined
+  } else if (field.oneof) {
+    adapter = "serde_base64"
+  } else if (isRepeated(field)) {
+    adapter = field.boundedVec ? "serde_base64::bounded" : "serde_base64::repeated"
+  } else if (isOptional(field)) {
+    adapter = "serde_base64::option"
+  } else {
+    adapter = "serde_base64"
+  }
+  return `#[cfg_attr(feature = "serde", serde(with = "${adapter}"))]`
+}
@@ -0,0 +1,341 @@
+import Crypto from "node:crypto"
+import { toSnakeCase } from "../util/names.js"
+import { log } from "../util/logger.js"
+import { resolveRustType } from "./type-map.js"
+import { docLines, withDoc } from "./doc.js"
+import type { GenContext } from "./context.js"
+
+/**
+ * Descriptor subset for a protobuf service needed by the codegen.
+ */
+export interface ServiceDescriptor {
+  /** Simple name (e.g. "Vault") */
+  name: string
+  /** Fully qualified name (e.g. "my_package.Vault") */
+  fullName: string
+  methods: MethodDescriptor[]
+  /** The service's `.proto` comments */
+  doc?: string
+}
+
+export interface MethodDescriptor {
+  name: string
+  /** Request type name (e.g. ".my_package.DepositRequest") */
+  inputType: string
+  /** Response type name */
+  outputType: string
+  clientStreaming: boolean
+  serverStreaming: boolean
+  /** The rpc's `(solana.accounts)` list, unparsed */
+  accounts?: string
+  /** The rpc's `.proto` comments */
+  doc?: string
+}
+
+/** How an instruction names its rpc (`instruction_tag` option). */
+export type InstructionTag = "u8" | "discriminator"
+
+/**
+ * 8-byte instruction discriminator for an rpc: the first bytes of
+ * `sha256("instruction:<service full name>/<rpc>")`.
+ */
+export function instructionDiscriminator(serviceFullName: string, methodName: string): number[] {
+  const path = `${serviceFullName.replace(/^\./, "")}/${methodName}`
+  return [...Crypto.createHash("sha256").update(`instruction:${path}`).digest().subarray(0, 8)]
+}
+
+/** The rpcs that have an instruction form: all but the streaming ones. */
+export function unaryMethods(service: ServiceDescriptor): MethodDescriptor[] {
+  return service.methods.filter(m => !m.clientStreaming && !m.serverStreaming)
+}
+
+/**
+ * Generate the Solana program dispatcher for a service: the
+ * `<Service>Instruction` enum with one variant per rpc, decoded from
+ * instruction data as a tag followed by the protobuf request, and the
+ * `<Service>Handler` trait the program implements. `dispatch` runs the
+ * matching handler and returns the encoded response. Streaming rpcs have
+ * no instruction form and are skipped.
+ */
+export function genService(service: ServiceDescriptor, ctx: GenContext): string {
+  for (const m of service.methods) {
+    if (m.clientStreaming || m.serverStreaming) log.warn(`Skipping streaming rpc ${service.fullName}.${m.name}`)
+  }
+  const methods = unaryMethods(service)
+  if (methods.length === 0) return ""
+  const streaming = service.methods.filter(m => !methods.includes(m)).map(m => `\`${m.name}\``)
+  const verb = streaming.length > 1 ? "have" : "has"
+  if (ctx.instructionTag === "u8" && methods.length > 256) {
+    throw new Error(
+      `Service ${service.fullName} has ${methods.length} rpcs, more than a u8 instruction tag can address`
+    )
+  }
+
+  const enumName = `${service.name}Instruction`
+  const handlerName = `${service.name}Handler`
+  const serviceName = service.fullName.replace(/^\./, "")
+  const discriminated = ctx.instructionTag === "discriminator"
+  const tagType = discriminated ? "[u8; 8]" : "u8"
+  const tagConst = (m: MethodDescriptor) => `${toSnakeCase(m.name).toUpperCase()}_TAG`
+  const tagValue = (m: MethodDescriptor, i: number) =>
+    discriminated ? `[${instructionDiscriminator(service.fullName, m.name).join(", ")}]` : `${i}`
+  const request = (m: MethodDescriptor) => resolveRustType(11, m.inputType)
+  const response = (m: MethodDescriptor) => resolveRustType(11, m.outputType)
+
+  const lines: string[] = [
+    discriminated
+      ? `/// Instructions of the \`${serviceName}\` service, tagged by an 8-byte discriminator.`
+      : `/// Instructions of the \`${serviceName}\` service, tagged by a leading \`u8\`.`,
+    ...(streaming.length > 0
+      ? [`///`, `/// Streaming rpcs have no instruction form, so ${streaming.join(", ")} ${verb} no variant.`]
+      : []),
+    `#[derive(Clone, Debug, PartialEq)]`,
+    `#[allow(clippy::large_enum_variant)]`,
+    `pub enum ${enumName} {`,
+    ...methods.flatMap(m => [...docLines(m.doc, "    "), `    ${m.name}(${request(m)}),`]),
+    `}`,
+    ``,
+    `impl ${enumName} {`
+  ]
+
+  methods.forEach((m, i) => {
+    if (discriminated) {
+      lines.push(`    /// \`sha256("instruction:${serviceName}/${m.name}")[..8]\``)
+    }
+    lines.push(`    pub const ${tagConst(m)}: ${tagType} = ${tagValue(m, i)};`)
+  })
+
+  lines.push(
+    ``,
+    `    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {`,
+    `        Self::decode_with(data, &DecodeOptions::DEFAULT)`,
+    `    }`,
+    ``,
+    `    pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {`
+  )
+  if (discriminated) {
+    lines.push(
+      `        if data.len() < 8 {`,
+      `            return Err(DecodeError::BufferOverflow);`,
+      `        }`,
+      `        let (head, payload) = data.split_at(8);`,
+      `        let mut tag = [0u8; 8];`,
+      `        tag.copy_from_slice(head);`
+    )
+  } else {
+    lines.push(`        let (&tag, payload) = data.split_first().ok_or(DecodeError::BufferOverflow)?;`)
+  }
+  lines.push(`        match tag {`)
+  for (const m of methods) {
+    lines.push(
+      `            Self::${tagConst(m)} => Ok(${enumName}::${m.name}(${request(m)}::decode_with(payload, opts)?)),`
+    )
+  }
+  lines.push(
+    `            _ => Err(DecodeError::InvalidData("unknown instruction tag")),`,
+    `        }`,
+    `    }`,
+    ``,
+    `    pub fn tag(&self) -> ${tagType} {`,
+    `        match self {`,
+    ...methods.map(m => `            ${enumName}::${m.name}(_) => Self::${tagConst(m)},`),
+    `        }`,
+    `    }`,
+    ``,
+    `    /// Instruction data: the tag followed by the encoded request.`,
+    `    pub fn encode(&self) -> Vec<u8> {`,
+    `        let mut buf = Vec::new();`,
+    discriminated ? `        buf.extend_from_slice(&self.tag());` : `        buf.push(self.tag());`,
+    `        match self {`,
+    ...methods.map(m => `            ${enumName}::${m.name}(request) => request.encode_into(&mut buf),`),
+    `        }`,
+    `        buf`,
+    `    }`,
+    ``,
+    `    /// Run the handler for this instruction and encode its response.`,
+    `    pub fn dispatch<C, H: ${handlerName}<C>>(self, handler: &mut H, ctx: C) -> Result<Vec<u8>, H::Error> {`,
+    `        match self {`,
+    ...methods.map(
+      m =>
+        `            ${enumName}::${m.name}(request) => Ok(handler.${toSnakeCase(m.name)}(ctx, request)?.encode()),`
+    ),
+    `        }`,
+    `    }`,
+    ``,
+    `    /// Decode instruction data and dispatch it, e.g. from the program's`,
+    `    /// \`process_instruction\` entrypoint.`,
+    `    pub fn process<C, H: ${handlerName}<C>>(handler: &mut H, ctx: C, data: &[u8]) -> Result<Vec<u8>, H::Error> {`,
+    `        Self::decode(data)?.dispatch(handler, ctx)`,
+    `    }`,
+    `}`,
+    ``,
+    ...withDoc(
+      service.doc,
+      [
+        `/// Program-side implementation of the \`${serviceName}\` service; \`C\` is the`,
+        `/// per-instruction context, e.g. the program id and accounts.`
+      ],
+      ""
+    ),
+    `pub trait ${handlerName}<C> {`,
+    `    type Error: From<DecodeError>;`,
+    ``
+  )
+  methods.forEach((m, i) => {
+    if (i > 0) lines.push(``)
+    lines.push(
+      ...docLines(m.doc, "    "),
+      `    fn ${toSnakeCase(m.name)}(&mut self, ctx: C, request: ${request(m)}) -> Result<${response(m)}, Self::Error>;`
+    )
+  })
+  lines.push(`}`, ``, genServiceModule(service, methods, enumName))
+  return lines.join("\n")
+}
+
+/** One entry of an rpc's `(solana.accounts)` list. */
+export interface AccountDecl {
+  name: string
+  signer: boolean
+  writable: boolean
+}
+
+/** Names the generated builders and account structs use themselves. */
+const RESERVED_ACCOUNT_NAMES = new Set(["program_id", "request", "remaining"])
+
+/**
+ * Parse `(solana.accounts)`: `;`-separated `name[:flag,...]` entries,
+ * where the flags are `signer` and `mut`.
+ */
+export function parseAccounts(spec: string, rpc: string): AccountDecl[] {
+  const accounts: AccountDecl[] = []
+  for (const entry of spec.split(";").map(e => e.trim()).filter(e => e !== "")) {
+    const [name, flags = ""] = entry.split(":").map(part => part.trim())
+    if (!/^[a-z_][a-z0-9_]*$/.test(name) || RESERVED_ACCOUNT_NAMES.has(name)) {
+      throw new Error(`Invalid account name "${name}" in (solana.accounts) of rpc ${rpc}`)
+    }
+    if (accounts.some(a => a.name === name)) {
+      throw new Error(`Duplicate account "${name}" in (solana.accounts) of rpc ${rpc}`)
+    }
+    const account: AccountDecl = { name, signer: false, writable: false }
+    for (const flag of flags.split(",").map(f => f.trim()).filter(f => f !== "")) {
+      if (flag === "signer") account.signer = true
+      else if (flag === "mut") account.writable = true
+      else throw new Error(`Unknown account flag "${flag}" for ${name} in (solana.accounts) of rpc ${rpc}`)
+    }
+    accounts.push(account)
+  }
+  if (accounts.length === 0) throw new Error(`(solana.accounts) of rpc ${rpc} declares no accounts`)
+  return accounts
+}
+
+function describeAccount(account: AccountDecl): string {
+  const flags = [account.signer ? "signer" : "", account.writable ? "writable" : ""].filter(f => f !== "")
+  return flags.length > 0 ? `\`${account.name}\` (${flags.join(", ")})` : `\`${account.name}\``
+}
+
+/**
+ * The `<service>` module, behind the `solana-program` feature like the
+ * runtime's `Pubkey`: a checked `<Rpc>Accounts` struct for each rpc that
+ * declares `(solana.accounts)`, and in `<service>::instruction` a
+ * client-side builder per rpc returning the `Instruction` for a CPI or a
+ * transaction. A builder takes the declared accounts as keys and lays out
+ * their `AccountMeta`s, or else the whole `AccountMeta` list.
+ */
+function genServiceModule(service: ServiceDescriptor, methods: MethodDescriptor[], enumName: string): string {
+  const serviceName = service.fullName.replace(/^\./, "")
+  const declared = new Map(
+    methods.filter(m => m.accounts).map(m => [m.name, parseAccounts(m.accounts!, `${serviceName}.${m.name}`)])
+  )
+
+  const lines: string[] = [
+    `/// Account validation and instruction builders for the \`${serviceName}\` service.`,
+    `#[cfg(feature = "solana-program")]`,
+    `pub mod ${toSnakeCase(service.name)} {`
+  ]
+  if (declared.size > 0) {
+    lines.push(
+      `    use solana_program::account_info::AccountInfo;`,
+      `    use solana_program::program_error::ProgramError;`
+    )
+  }
+  for (const [rpc, accounts] of declared) {
+    lines.push(``, ...genAccountsStruct(rpc, accounts).map(line => (line ? `    ${line}` : "")))
+  }
+
+  lines.push(``, `    pub mod instruction {`, `        use super::super::*;`)
+  for (const m of methods) {
+    const request = resolveRustType(11, m.inputType)
+    const accounts = declared.get(m.name)
+    const generated = `        /// \`${m.name}\`, with \`request\` encoded as \`${enumName}\` data.`
+    lines.push(``, ...withDoc(m.doc, [generated], "        "))
+    if (accounts && accounts.length + 2 > 7) lines.push(`        #[allow(clippy::too_many_arguments)]`)
+    lines.push(`        pub fn ${toSnakeCase(m.name)}(`, `            program_id: Pubkey,`)
+    if (accounts) {
+      lines.push(...accounts.map(a => `            ${a.name}: Pubkey,`))
+    } else {
+      lines.push(`            accounts: Vec<solana_program::instruction::AccountMeta>,`)
+    }
+    lines.push(
+      `            request: ${request},`,
+      `        ) -> solana_program::instruction::Instruction {`,
+      `            solana_program::instruction::Instruction {`,
+      `                program_id,`
+    )
+    if (accounts) {
+      lines.push(
+        `                accounts: Vec::from([`,
+        ...accounts.map(a => {
+          const ctor = a.writable ? "new" : "new_readonly"
+          return `                    solana_program::instruction::AccountMeta::${ctor}(${a.name}, ${a.signer}),`
+        }),
+        `                ]),`
+      )
+    } else {
+      lines.push(`                accounts,`)
+    }
+    lines.push(
+      `                data: ${enumName}::${m.name}(request).encode(),`,
+      `            }`,
+      `        }`
+    )
+  }
+  lines.push(`    }`, `}`)
+  return lines.join("\n")
+}
+
+/** `<Rpc>Accounts`, borrowing the declared accounts in order. */
+function genAccountsStruct(rpc: string, accounts: AccountDecl[]): string[] {
+  const structName = `${rpc}Accounts`
+  const lines: string[] = [
+    `/// Accounts of \`${rpc}\`, in order: ${accounts.map(describeAccount).join(", ")}.`,
+    `pub struct ${structName}<'a, 'info> {`,
+    ...accounts.map(a => `    pub ${a.name}: &'a AccountInfo<'info>,`),
+    `    /// Accounts after the declared ones.`,
+    `    pub remaining: &'a [AccountInfo<'info>],`,
+    `}`,
+    ``,
+    `impl<'a, 'info> ${structName}<'a, 'info> {`,
+    `    pub const LEN: usize = ${accounts.length};`,
+    ``,
+    `    /// Take the declared accounts from the front of \`accounts\`, checking`,
+    `    /// that each is a signer or writable where declared so.`,
+    `    pub fn from_accounts(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {`,
+    `        let [${[...accounts.map(a => a.name), "remaining @ .."].join(", ")}] = accounts else {`,
+    `            return Err(ProgramError::NotEnoughAccountKeys);`,
+    `        };`
+  ]
+  for (const a of accounts) {
+    if (a.signer) {
+      lines.push(`        if !${a.name}.is_signer {`, `            return Err(ProgramError::MissingRequiredSignature);`, `        }`)
+    }
+    if (a.writable) {
+      lines.push(`        if !${a.name}.is_writable {`, `            return Err(ProgramError::Immutable);`, `        }`)
+    }
+  }
+  lines.push(
+    `        Ok(Self { ${[...accounts.map(a => a.name), "remaining"].join(", ")} })`,
+    `    }`,
+    `}`
+  )
+  return lines
+}
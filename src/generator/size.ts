@@ -0,0 +1,168 @@
+import { log } from "../util/logger.js"
+import {
+  FieldInfo,
+  isGroup,
+  isMap,
+  isMessage,
+  isPacked,
+  isRepeated,
+  mapKeyField,
+  mapValueField,
+  withMapEntry
+} from "./field.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+import { unwrapWrapper, withWellKnownType } from "./well-known.js"
+import { withSolanaOptions } from "./solana-options.js"
+import type { GenContext } from "./context.js"
+
+/**
+ * Runtime messages with a bounded encoding: `seconds` and `nanos` as
+ * 10-byte negative varints, each behind a one-byte key. Wrappers are
+ * sized from their value; `Any` is unbounded.
+ */
+const RUNTIME_MAX_LEN: Record<string, number> = {
+  ".google.protobuf.Timestamp": 22,
+  ".google.protobuf.Duration": 22,
+  ".google.protobuf.Empty": 0
+}
+
+/**
+ * Generate `MAX_ENCODED_LEN` and `MAX_ACCOUNT_DATA_LEN` for a message
+ * whose every field has a bounded encoding: scalars by their widest
+ * form, strings and bytes up to `(solana.max_len)`, repeated and map
+ * fields up to `(solana.max_count)` elements, and sub-messages
+ * likewise. Messages with an unbounded field (or a recursive one) get
+ * neither; `undefined` then.
+ */
+export function genMaxEncodedLen(structName: string, fields: FieldInfo[], ctx: GenContext): string | undefined {
+  const max = maxEncodedLen(fields, ctx)
+  if (max === undefined) {
+    log.debug(`${structName} has no encoded size bound`)
+    return undefined
+  }
+  return [
+    `impl ${structName} {`,
+    `    /// Longest encoding of a \`${structName}\` whose fields stay within`,
+    `    /// their \`(solana.max_len)\` / \`(solana.max_count)\` bounds, not`,
+    `    /// counting unknown fields.`,
+    `    pub const MAX_ENCODED_LEN: usize = ${max};`,
+    ``,
+    `    /// Account space for any such value in the \`encode_account_data\``,
+    `    /// layout, e.g. for \`Rent::minimum_balance\`.`,
+    `    pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;`,
+    `}`
+  ].join("\n")
+}
+
+/** The message's `MAX_ENCODED_LEN`, `undefined` if it has none. */
+export function maxEncodedLen(fields: FieldInfo[], ctx: GenContext): number | undefined {
+  return bodyMaxLen(fields, ctx, new Set())
+}
+
+function varintLen(value: number): number {
+  let len = 1
+  while (value >= 0x80) {
+    value = Math.floor(value / 0x80)
+    len++
+  }
+  return len
+}
+
+function keyLen(field: FieldInfo): number {
+  return varintLen(field.number * 8)
+}
+
+/** A length-delimited value's prefix plus `len`. */
+function delimited(len: number): number {
+  return varintLen(len) + len
+}
+
+/** Widest encoding of a message body; oneof members share one slot. */
+function bodyMaxLen(fields: FieldInfo[], ctx: GenContext, visiting: Set<string>): number | undefined {
+  let total = 0
+  const oneofs = new Map<number, number>()
+  for (const field of fields) {
+    const len = fieldMaxLen(field, ctx, visiting)
+    if (len === undefined) return undefined
+    if (field.oneofIndex !== undefined && !field.proto3Optional) {
+      oneofs.set(field.oneofIndex, Math.max(oneofs.get(field.oneofIndex) ?? 0, len))
+    } else {
+      total += len
+    }
+  }
+  for (const len of oneofs.values()) total += len
+  return total
+}
+
+/** Widest encoding of a field, keys included. */
+function fieldMaxLen(field: FieldInfo, ctx: GenContext, visiting: Set<string>): number | undefined {
+  if (isMap(field)) {
+    const key = valueMaxLen({ ...mapKeyField(field), maxLen: field.maxLen }, ctx, visiting)
+    const value = valueMaxLen({ ...mapValueField(field), maxLen: field.maxLen }, ctx, visiting)
+    if (field.maxCount === undefined || key === undefined || value === undefined) return undefined
+    return field.maxCount * (keyLen(field) + delimited(2 + key + value))
+  }
+
+  const value = valueMaxLen(field, ctx, visiting)
+  if (value === undefined) return undefined
+  if (!isRepeated(field)) return keyLen(field) + value
+
+  if (field.maxCount === undefined) return undefined
+  if (isPacked(field)) return keyLen(field) + delimited(field.maxCount * value)
+  return field.maxCount * (keyLen(field) + value)
+}
+
+/** Widest encoding of one value, without its key. */
+function valueMaxLen(field: FieldInfo, ctx: GenContext, visiting: Set<string>): number | undefined {
+  // Singular wrappers arrive unwrapped already; repeated, map and oneof ones do not
+  const unwrapped = isMessage(field) ? unwrapWrapper(field) : field
+  if (unwrapped.wrapper) {
+    const inner = scalarMaxLen(unwrapped)
+    return inner === undefined ? undefined : delimited(1 + inner)
+  }
+  if (isMessage(field)) {
+    const inner = messageMaxLen(field.typeName!, ctx, visiting)
+    if (inner === undefined) return undefined
+    // A group ends with an end-group key instead of starting with a length
+    return isGroup(field) ? inner + keyLen(field) : delimited(inner)
+  }
+  return scalarMaxLen(field)
+}
+
+function scalarMaxLen(field: FieldInfo): number | undefined {
+  switch (field.type) {
+    case 1: // double
+    case 6: // fixed64
+    case 16: // sfixed64
+      return 8
+    case 2: // float
+    case 7: // fixed32
+    case 15: // sfixed32
+      return 4
+    case 8: // bool
+      return 1
+    case 13: // uint32
+    case 17: // sint32
+      return 5
+    case 9: // string
+    case 12: // bytes
+      if (field.fixedLen !== undefined) return delimited(field.fixedLen)
+      return field.maxLen === undefined ? undefined : delimited(field.maxLen)
+    case TYPE_PUBKEY:
+      return 33
+    default:
+      // int32 and enums sign-extend negatives to 10 bytes, like the 64-bit types
+      return 10
+  }
+}
+
+function messageMaxLen(typeName: string, ctx: GenContext, visiting: Set<string>): number | undefined {
+  if (typeName in RUNTIME_MAX_LEN) return RUNTIME_MAX_LEN[typeName]
+  const msg = ctx.messages.get(typeName)
+  if (!msg || visiting.has(typeName)) return undefined
+  visiting.add(typeName)
+  const fields = msg.fields.map(f => withWellKnownType(withSolanaOptions(withMapEntry(f, msg), ctx.utf8)))
+  const max = bodyMaxLen(fields, ctx, visiting)
+  visiting.delete(typeName)
+  return max
+}
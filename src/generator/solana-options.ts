@@ -0,0 +1,102 @@
+import { log } from "../util/logger.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+import { FieldInfo, withMapEntry } from "./field.js"
+import type { MessageDescriptor } from "./message.js"
+
+/** What decoding a string field does with invalid UTF-8 (`utf8` option, `(solana.utf8)`). */
+export type Utf8Policy = "strict" | "lossy" | "bytes"
+
+/**
+ * Apply the field's `(solana.*)` options from `proto/solana/options.proto`:
+ * `pubkey` turns a bytes field into a 32-byte `Pubkey` and `fixed_len`
+ * into a `[u8; N]`. `max_len` and `max_count` are kept for sizing,
+ * `bounded_vec` for a repeated field's type and `required` for decoding,
+ * and dropped where they cannot apply. `utf8`
+ * (else the plugin-wide `utf8` policy) may make a string field bytes.
+ */
+export function withSolanaOptions(field: FieldInfo, utf8: Utf8Policy): FieldInfo {
+  return withUtf8(withBytesOptions(withRequired(withBounds(field))), utf8)
+}
+
+function withBytesOptions(field: FieldInfo): FieldInfo {
+  if (!field.pubkey && field.fixedLen === undefined) return field
+  if (field.type !== 12) {
+    log.warn(`Ignoring (solana.*) bytes options on non-bytes field ${field.name}`)
+    return { ...field, pubkey: undefined, fixedLen: undefined }
+  }
+  if (field.pubkey) {
+    if (field.fixedLen !== undefined && field.fixedLen !== 32) {
+      log.warn(`Ignoring (solana.fixed_len) = ${field.fixedLen} on pubkey field ${field.name}`)
+    }
+    return { ...field, type: TYPE_PUBKEY, fixedLen: undefined }
+  }
+  return field
+}
+
+function withBounds(field: FieldInfo): FieldInfo {
+  let bounded = field
+  if (field.maxLen !== undefined && field.type !== 9 && field.type !== 12 && !field.mapEntry) {
+    log.warn(`Ignoring (solana.max_len) on field ${field.name}, which is not a string, bytes or map field`)
+    bounded = { ...bounded, maxLen: undefined }
+  }
+  if (field.maxCount !== undefined && field.label !== 3) {
+    log.warn(`Ignoring (solana.max_count) on field ${field.name}, which is not repeated`)
+    bounded = { ...bounded, maxCount: undefined }
+  }
+  if (bounded.boundedVec && (bounded.label !== 3 || bounded.mapEntry || bounded.maxCount === undefined)) {
+    log.warn(
+      `Ignoring (solana.bounded_vec) on field ${field.name}, which is not a repeated field with a (solana.max_count)`
+    )
+    bounded = { ...bounded, boundedVec: undefined }
+  }
+  return bounded
+}
+
+/**
+ * The `bounded_vec` option: `boundedVec` on every repeated field of
+ * `msg` with a `(solana.max_count)`, maps aside, as if each set
+ * `(solana.bounded_vec)`.
+ */
+export function withBoundedVecs(msg: MessageDescriptor): MessageDescriptor {
+  const fields = msg.fields.map(f =>
+    f.label === 3 && f.maxCount !== undefined && !withMapEntry(f, msg).mapEntry ? { ...f, boundedVec: true } : f
+  )
+  return { ...msg, fields }
+}
+
+function withRequired(field: FieldInfo): FieldInfo {
+  if (!field.required) return field
+  // A oneof member's presence belongs to its group; proto3 `optional` is a synthetic oneof
+  if (field.label === 3 || (field.oneofIndex !== undefined && !field.proto3Optional)) {
+    log.warn(`Ignoring (solana.required) on field ${field.name}, which is not a singular field outside a oneof`)
+    return { ...field, required: undefined }
+  }
+  return field
+}
+
+/**
+ * Resolve the field's UTF-8 policy, `(solana.utf8)` over the plugin-wide
+ * `utf8`. `bytes` retypes a string field as bytes, with a proto2 default
+ * respelled as bytes defaults are; map keys and values stay `String`s, so
+ * a map takes it as `lossy`. A strict field keeps no `utf8`.
+ */
+function withUtf8(field: FieldInfo, utf8: Utf8Policy): FieldInfo {
+  const me = field.mapEntry
+  const isString = me ? me.keyType === 9 || me.valueType === 9 : field.type === 9
+  if (!isString) {
+    if (field.utf8 !== undefined) log.warn(`Ignoring (solana.utf8) on field ${field.name}, which is not a string field`)
+    return field.utf8 === undefined ? field : { ...field, utf8: undefined }
+  }
+  const policy = field.utf8 ?? utf8
+  if (policy === "strict") return { ...field, utf8: undefined }
+  if (policy === "lossy" || me) return { ...field, utf8: "lossy" }
+  const defaultValue = field.defaultValue === undefined ? undefined : cEscape(field.defaultValue)
+  return { ...field, type: 12, defaultValue, utf8: "bytes" }
+}
+
+/** `text`'s UTF-8 bytes C-escaped, as a descriptor spells a bytes default. */
+function cEscape(text: string): string {
+  return [...Buffer.from(text, "utf8")]
+    .map(b => (b === 0x5c ? "\\\\" : b < 0x80 ? String.fromCharCode(b) : `\\${b.toString(8).padStart(3, "0")}`))
+    .join("")
+}
@@ -0,0 +1,307 @@
+import { protoNameToRust, toSnakeCase } from "../util/names.js"
+import {
+  FieldInfo,
+  fieldRustName,
+  isFixedBytes,
+  isMap,
+  isMessage,
+  isOptional,
+  isRepeated,
+  mapKeyField,
+  mapValueField,
+  withMapEntry
+} from "./field.js"
+import { TYPE_PUBKEY, resolveRustType } from "./type-map.js"
+import { unwrapWrapper, withWellKnownType } from "./well-known.js"
+import { withSolanaOptions } from "./solana-options.js"
+import { resolveOneofs } from "./oneof.js"
+import { maxEncodedLen } from "./size.js"
+import type { MessageDescriptor } from "./message.js"
+import type { GenContext } from "./context.js"
+
+/** Elements of an unbounded repeated or map field, and bytes of an unbounded string. */
+const UNBOUNDED_COUNT = 2
+const UNBOUNDED_LEN = 8
+
+/** Runtime well-known messages with a sample other than their default. */
+const RUNTIME_SAMPLES: Record<string, string> = {
+  ".google.protobuf.Timestamp": "Timestamp { seconds: 1_700_000_000, nanos: 500_000_000 }",
+  ".google.protobuf.Duration": "Duration { seconds: 3_600, nanos: 500_000_000 }"
+}
+
+/** Widest value of each scalar type; enums are sampled from their declared values. */
+const SCALAR_SAMPLES: Record<number, string> = {
+  1: "1.5",
+  2: "1.5",
+  3: "i64::MIN",
+  4: "u64::MAX",
+  5: "i32::MIN",
+  6: "u64::MAX",
+  7: "u32::MAX",
+  8: "true",
+  13: "u32::MAX",
+  15: "i32::MIN",
+  16: "i64::MIN",
+  17: "i32::MIN",
+  18: "i64::MIN"
+}
+
+/** The companion tests module of one generated file. */
+export interface TestModule {
+  /** Declaration appended to the generated file */
+  declaration: string
+  /** `<file>_tests.rs` beside it; `undefined` under `bundle`, where the declaration holds the tests inline */
+  file?: { name: string; content: string }
+}
+
+/**
+ * Generate the `tests` option's companion `<file>_tests.rs` for the
+ * generated file `rsFileName`, declared from it as a `#[cfg(test)]`
+ * module. Every message gets a sample with each field set, at the
+ * widest value its `(solana.max_len)` / `(solana.max_count)` bounds
+ * allow, and tests that the sample round-trips through `decode` (and its
+ * view under `ref_views`, its `Cow` struct under `cow_structs`), encodes
+ * canonically and, for messages with a `MAX_ENCODED_LEN`, fits it. A
+ * oneof holds its first member. A field whose type contains the message
+ * again holds that type's default, as do messages from other files,
+ * which their own file's tests cover.
+ * `undefined` for a file without messages.
+ */
+export function genTests(
+  messages: MessageDescriptor[],
+  protoFileName: string,
+  rsFileName: string,
+  ctx: GenContext
+): TestModule | undefined {
+  const local = messages.filter(m => !m.isMapEntry)
+  if (local.length === 0) return undefined
+  const localNames = new Set(local.map(m => `.${m.fullName}`))
+  const helpers = new Set<string>()
+  const sampler: Sampler = { ctx, localNames, helpers }
+
+  const body: string[] = []
+  for (const msg of local) {
+    const structName = protoNameToRust(msg.fullName)
+    const fields = messageFields(msg, ctx)
+    body.push(``, ...genSample(structName, msg, fields, sampler))
+    body.push(``, ...genMessageTests(structName, fields, ctx.cowStructs.has(`.${msg.fullName}`), ctx))
+  }
+
+  const lines = [
+    `use super::*;`,
+    ...(helpers.has("repeated")
+      ? [
+          ``,
+          `/// \`n\` copies of \`value\`.`,
+          `fn repeated<T: Clone>(value: T, n: usize) -> Vec<T> {`,
+          `    (0..n).map(|_| value.clone()).collect()`,
+          `}`
+        ]
+      : []),
+    ...(helpers.has("key")
+      ? [
+          ``,
+          `/// Map key \`i\`, zero-padded to \`width\` digits.`,
+          `fn key(i: usize, width: usize) -> String {`,
+          `    let digits = i.to_string();`,
+          `    "0".repeat(width.saturating_sub(digits.len())) + &digits`,
+          `}`
+        ]
+      : []),
+    ...body
+  ]
+
+  const base = rsFileName.replace(/^.*\//, "").replace(/\.rs$/, "")
+  if (ctx.bundle) {
+    return {
+      declaration: [
+        ``,
+        `#[cfg(test)]`,
+        `mod tests {`,
+        ...lines.map(line => (line ? `    ${line}` : line)),
+        `}`,
+        ``
+      ].join("\n")
+    }
+  }
+  return {
+    declaration: [``, `#[cfg(test)]`, `#[path = "${base}_tests.rs"]`, `mod tests;`, ``].join("\n"),
+    file: {
+      name: rsFileName.replace(/\.rs$/, "_tests.rs"),
+      content: [
+        `// Auto-generated by protoc-gen-solana from ${protoFileName}`,
+        `// DO NOT EDIT`,
+        `//`,
+        `// Tests of the messages in \`${base}.rs\`, which declares this file as`,
+        `// its \`tests\` module.`,
+        ``,
+        ...lines,
+        ``
+      ].join("\n")
+    }
+  }
+}
+
+/** What a sample may call on: the file's own messages' samples, and the helpers it uses. */
+interface Sampler {
+  ctx: GenContext
+  localNames: Set<string>
+  helpers: Set<string>
+}
+
+/** A message's fields as its struct declares them. */
+function messageFields(msg: MessageDescriptor, ctx: GenContext): FieldInfo[] {
+  const fields = msg.fields.map(f => withWellKnownType(withSolanaOptions(withMapEntry(f, msg), ctx.utf8)))
+  return resolveOneofs(protoNameToRust(msg.fullName), fields, msg.oneofs, msg.oneofOverrides).fields
+}
+
+function sampleFn(structName: string): string {
+  return `sample_${toSnakeCase(structName)}`
+}
+
+/** The `sample_<message>()` function building the message's sample. */
+function genSample(structName: string, msg: MessageDescriptor, fields: FieldInfo[], sampler: Sampler): string[] {
+  const self = `.${msg.fullName}`
+  const members: string[] = []
+  let complete = msg.extensionRanges.length === 0 && !sampler.ctx.unknownFields
+  const oneofs = new Set<string>()
+  for (const field of fields) {
+    // Left out of the encoding, so they would not come back
+    if (sampler.ctx.omitDeprecated && field.deprecated && !field.required) {
+      complete = false
+      continue
+    }
+    if (field.oneof) {
+      if (oneofs.has(field.oneof.field)) continue
+      oneofs.add(field.oneof.field)
+      const value = elemSample(field, self, sampler)
+      const held = field.box ? `${value}.into()` : value
+      members.push(`${field.oneof.field}: ${field.oneof.enumName}::${field.oneof.variant}(${held}),`)
+      continue
+    }
+    members.push(`${fieldRustName(field)}: ${fieldSample(field, self, sampler)},`)
+  }
+  if (!complete) members.push(`..Default::default()`)
+  return [
+    `fn ${sampleFn(structName)}() -> ${structName} {`,
+    `    ${structName} {`,
+    ...members.map(m => `        ${m}`),
+    `    }`,
+    `}`
+  ]
+}
+
+function genMessageTests(structName: string, fields: FieldInfo[], cow: boolean, ctx: GenContext): string[] {
+  const name = toSnakeCase(structName)
+  const sample = `${sampleFn(structName)}()`
+  const lines = [
+    `#[test]`,
+    `fn ${name}_round_trips() {`,
+    `    let msg = ${sample};`,
+    `    let bytes = msg.encode();`,
+    `    assert_eq!(bytes.len(), msg.encoded_len());`,
+    `    assert_eq!(${structName}::decode(&bytes).unwrap(), msg);`,
+    ...(ctx.refViews ? [`    assert_eq!(${structName}Ref::decode(&bytes).unwrap().to_message().unwrap(), msg);`] : []),
+    ...(cow
+      ? [
+          `    let cow = ${structName}Cow::decode(&bytes).unwrap();`,
+          `    assert_eq!(cow.encode(), bytes);`,
+          `    assert_eq!(${structName}::from(cow), msg);`
+        ]
+      : []),
+    `}`,
+    ``,
+    `#[test]`,
+    `fn ${name}_encodes_canonically() {`,
+    `    assert!(verify_canonical::<${structName}>(&${sample}.encode()));`,
+    `}`
+  ]
+  const max = maxEncodedLen(fields, ctx)
+  if (max !== undefined) {
+    const len = `${sample}.encoded_len()`
+    lines.push(
+      ``,
+      `#[test]`,
+      `fn ${name}_fits_max_encoded_len() {`,
+      // Clippy holds `<=` against a zero bound to be always false
+      max === 0
+        ? `    assert_eq!(${len}, ${structName}::MAX_ENCODED_LEN);`
+        : `    assert!(${len} <= ${structName}::MAX_ENCODED_LEN);`,
+      `}`
+    )
+  }
+  return lines
+}
+
+/** Sample of a whole field: `Some` of one value, or `max_count` of them. */
+function fieldSample(field: FieldInfo, self: string, sampler: Sampler): string {
+  // Its type contains the message, so it stays unset
+  if (field.box) return "None"
+  const count = field.maxCount ?? UNBOUNDED_COUNT
+  if (isMap(field)) {
+    const key = { ...mapKeyField(field), maxLen: field.maxLen }
+    const value = elemSample({ ...mapValueField(field), maxLen: field.maxLen }, self, sampler)
+    const { expr, distinct } = keySample(key, sampler)
+    const binding = /\bi\b/.test(expr) ? "i" : "_"
+    return `(0..${Math.min(count, distinct)}usize).map(|${binding}| (${expr}, ${value})).collect()`
+  }
+  const value = elemSample(field, self, sampler)
+  if (isRepeated(field)) {
+    sampler.helpers.add("repeated")
+    return field.boundedVec ? `repeated(${value}, ${count}).into_iter().collect()` : `repeated(${value}, ${count})`
+  }
+  return isOptional(field) ? `Some(${value})` : value
+}
+
+/** Map key `i` of `field`'s key type, and how many distinct keys it can spell. */
+function keySample(field: FieldInfo, sampler: Sampler): { expr: string; distinct: number } {
+  if (field.type === 8) return { expr: "i == 1", distinct: 2 }
+  if (field.type === 9) {
+    if (field.maxLen === 0) return { expr: "String::new()", distinct: 1 }
+    sampler.helpers.add("key")
+    const width = field.maxLen ?? 1
+    return { expr: `key(i, ${width})`, distinct: width >= 9 ? Infinity : 10 ** width }
+  }
+  return { expr: `i as ${resolveRustType(field.type, undefined)}`, distinct: Infinity }
+}
+
+/** Sample of one value of the field. */
+function elemSample(field: FieldInfo, self: string, sampler: Sampler): string {
+  if (isMessage(field)) {
+    // Singular wrappers arrive unwrapped already; repeated, map and oneof ones do not
+    const unwrapped = unwrapWrapper(field)
+    if (unwrapped.wrapper) return `${unwrapped.wrapper} { value: ${elemSample(unwrapped, self, sampler)} }`
+    const typeName = field.typeName!
+    if (typeName in RUNTIME_SAMPLES) return RUNTIME_SAMPLES[typeName]
+    const structType = resolveRustType(field.type, typeName)
+    const sampled = sampler.localNames.has(typeName) && !contains(typeName, self, sampler.ctx, new Set())
+    return sampled ? `${sampleFn(structType)}()` : `${structType}::default()`
+  }
+  const len = field.maxLen ?? UNBOUNDED_LEN
+  if (field.type === 9) return `"x".repeat(${len})`
+  if (isFixedBytes(field)) return `[7; ${field.fixedLen}]`
+  if (field.type === 12) return `[7u8].repeat(${len})`
+  if (field.type === TYPE_PUBKEY) return `pubkey_from_array([7; 32])`
+  if (field.type === 14) return enumSample(field, sampler.ctx)
+  return SCALAR_SAMPLES[field.type]
+}
+
+/** The enum's widest declared value: a negative one if any, else the largest. */
+function enumSample(field: FieldInfo, ctx: GenContext): string {
+  const numbers = ctx.enums.get(field.typeName ?? "")?.values.map(v => v.number) ?? [1]
+  const negative = numbers.filter(n => n < 0)
+  return String(negative.length > 0 ? Math.min(...negative) : Math.max(...numbers))
+}
+
+/** True if a value of message `typeName` can hold a `target`, itself included. */
+function contains(typeName: string, target: string, ctx: GenContext, visited: Set<string>): boolean {
+  if (typeName === target) return true
+  const msg = ctx.messages.get(typeName)
+  if (!msg || visited.has(typeName)) return false
+  visited.add(typeName)
+  return msg.fields.some(f => {
+    const inner = withMapEntry(f, msg)
+    const child = inner.mapEntry ? inner.mapEntry.valueTypeName : inner.typeName
+    return child !== undefined && contains(child, target, ctx, visited)
+  })
+}
@@ -0,0 +1,140 @@
+import {
+  FieldInfo,
+  fieldRustName,
+  isMap,
+  isRepeated,
+  isOptional,
+  isMessage,
+  nonDefaultCond,
+  oneofPattern,
+  mapKeyField,
+  mapValueField
+} from "./field.js"
+import { TYPE_PUBKEY } from "./type-map.js"
+import type { GenContext } from "./context.js"
+import { enumTable } from "./json.js"
+
+/**
+ * Generate `write_text()` rendering the message in protobuf text format
+ * and a `Display` impl over it: `{}` for one line, `{:#}` indented.
+ * Fields are written in field-number order under their .proto names,
+ * skipping those at their default (and unset optionals and oneofs);
+ * unknown fields are not shown.
+ */
+export function genTextImpl(structName: string, fields: FieldInfo[], ctx: GenContext): string {
+  const ordered = [...fields].sort((a, b) => a.number - b.number)
+  const body = ordered.map(f => genTextWrite(f, ctx))
+  const out = body.length > 0 ? "out" : "_out"
+
+  return [
+    `impl ${structName} {`,
+    `    pub fn write_text(&self, ${out}: &mut TextWriter) {`,
+    ...body,
+    `    }`,
+    `}`,
+    ``,
+    `impl core::fmt::Display for ${structName} {`,
+    `    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {`,
+    `        let mut out = TextWriter::new(f.alternate());`,
+    `        self.write_text(&mut out);`,
+    `        f.write_str(&out.finish())`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
+
+/** `elem` for the place `*elem`, `&self.x` for `self.x`. */
+function borrow(place: string): string {
+  return place.startsWith("*") ? place.slice(1) : `&${place}`
+}
+
+/** Receiver for a method call on the value at `place`. */
+function receiver(place: string): string {
+  return place.startsWith("*") ? place.slice(1) : place
+}
+
+/**
+ * Statements writing the value at `place` as field `name`, each indented
+ * by `indent`.
+ */
+function textValueWrite(field: FieldInfo, name: string, place: string, ctx: GenContext, indent: string): string {
+  let stmts: string[]
+  if (field.wrapper) {
+    stmts = [
+      `out.begin_message("${name}");`,
+      `${field.wrapper}::write_text_value(${borrow(place)}, out);`,
+      `out.end_message();`
+    ]
+  } else if (isMessage(field)) {
+    stmts = [`out.begin_message("${name}");`, `${receiver(place)}.write_text(out);`, `out.end_message();`]
+  } else {
+    stmts = [textScalarWrite(field, name, place, ctx)]
+  }
+  return stmts.map(stmt => indent + stmt).join("\n")
+}
+
+function textScalarWrite(field: FieldInfo, name: string, place: string, ctx: GenContext): string {
+  switch (field.type) {
+    case 1:
+      return `out.write_f64("${name}", ${place});`
+    case 2:
+      return `out.write_f32("${name}", ${place});`
+    case 9:
+      return `out.write_str("${name}", ${borrow(place)});`
+    case 12:
+      return `out.write_bytes("${name}", ${borrow(place)});`
+    case 14:
+      return `out.write_enum("${name}", ${place}, ${enumTable(field, ctx)});`
+    case TYPE_PUBKEY:
+      return `out.write_pubkey("${name}", ${borrow(place)});`
+    default:
+      return `out.write_scalar("${name}", ${place});`
+  }
+}
+
+function genTextWrite(field: FieldInfo, ctx: GenContext): string {
+  const rustName = fieldRustName(field)
+  const name = field.name
+
+  if (isMap(field)) {
+    // Each entry is a `{ key: .. value: .. }` message, both always written
+    return [
+      `        for (key, value) in &self.${rustName} {`,
+      `            out.begin_message("${name}");`,
+      textValueWrite(mapKeyField(field), "key", "*key", ctx, "            "),
+      textValueWrite(mapValueField(field), "value", "*value", ctx, "            "),
+      `            out.end_message();`,
+      `        }`
+    ].join("\n")
+  }
+
+  if (isRepeated(field)) {
+    return [
+      `        for elem in &self.${rustName} {`,
+      textValueWrite(field, name, "*elem", ctx, "            "),
+      `        }`
+    ].join("\n")
+  }
+
+  if (field.oneof) {
+    return [
+      `        if let ${oneofPattern(field.oneof)} = &self.${field.oneof.field} {`,
+      textValueWrite(field, name, "*elem", ctx, "            "),
+      `        }`
+    ].join("\n")
+  }
+
+  if (isOptional(field)) {
+    return [
+      `        if let Some(elem) = &self.${rustName} {`,
+      textValueWrite(field, name, "*elem", ctx, "            "),
+      `        }`
+    ].join("\n")
+  }
+
+  return [
+    `        if ${nonDefaultCond(field, rustName)} {`,
+    textValueWrite(field, name, `self.${rustName}`, ctx, "            "),
+    `        }`
+  ].join("\n")
+}
@@ -1,13 +1,22 @@
+import { protoNameToRust } from "../util/names.js"
 /**
- * Protobuf wire types (proto3 encoding).
+ * Protobuf wire types; `StartGroup` only for proto2 group fields.
  */
 export const enum WireType {
   Varint = 0,
   Fixed64 = 1,
   LengthDelimited = 2,
+  StartGroup = 3,
+  EndGroup = 4,
   Fixed32 = 5
 }
 
+/**
+ * Field type of a `bytes` field marked `[(solana.pubkey) = true]`. Not a
+ * FieldDescriptorProto.Type; it is out of that enum's range.
+ */
+export const TYPE_PUBKEY = 100
+
 /**
  * Mapping entry: protobuf field type → Rust type + wire metadata.
  */
@@ -24,6 +33,8 @@ export interface RustTypeInfo {
   defaultValue: string
   /** Whether the encode func takes a reference (&self.field) */
   encodeRef: boolean
+  /** Suffix of the runtime `encode_packed_*` / `decode_packed_*` pair, for packable scalars */
+  packed?: string
 }
 
 /**
@@ -33,34 +44,38 @@ export interface RustTypeInfo {
  *   1=double, 2=float, 3=int64, 4=uint64, 5=int32, 6=fixed64,
  *   7=fixed32, 8=bool, 9=string, 10=group, 11=message, 12=bytes,
  *   13=uint32, 14=enum, 15=sfixed32, 16=sfixed64, 17=sint32, 18=sint64
+ * plus TYPE_PUBKEY for `(solana.pubkey)` bytes fields.
  */
 export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
   // TYPE_DOUBLE = 1
   1: {
     rustType: "f64",
     wireType: WireType.Fixed64,
-    encodeFunc: "encode_fixed64",
-    decodeFunc: "decode_fixed64",
+    encodeFunc: "encode_double",
+    decodeFunc: "decode_double",
     defaultValue: "0.0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "double"
   },
   // TYPE_FLOAT = 2
   2: {
     rustType: "f32",
     wireType: WireType.Fixed32,
-    encodeFunc: "encode_fixed32",
-    decodeFunc: "decode_fixed32",
+    encodeFunc: "encode_float",
+    decodeFunc: "decode_float",
     defaultValue: "0.0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "float"
   },
   // TYPE_INT64 = 3
   3: {
     rustType: "i64",
     wireType: WireType.Varint,
-    encodeFunc: "encode_varint",
-    decodeFunc: "decode_varint",
+    encodeFunc: "encode_int64",
+    decodeFunc: "decode_int64",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "int64"
   },
   // TYPE_UINT64 = 4
   4: {
@@ -69,16 +84,18 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_varint",
     decodeFunc: "decode_varint",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "varint"
   },
   // TYPE_INT32 = 5
   5: {
     rustType: "i32",
     wireType: WireType.Varint,
-    encodeFunc: "encode_varint",
-    decodeFunc: "decode_varint",
+    encodeFunc: "encode_int32",
+    decodeFunc: "decode_int32",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "int32"
   },
   // TYPE_FIXED64 = 6
   6: {
@@ -87,7 +104,8 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_fixed64",
     decodeFunc: "decode_fixed64",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "fixed64"
   },
   // TYPE_FIXED32 = 7
   7: {
@@ -96,7 +114,8 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_fixed32",
     decodeFunc: "decode_fixed32",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "fixed32"
   },
   // TYPE_BOOL = 8
   8: {
@@ -105,7 +124,8 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_bool",
     decodeFunc: "decode_bool",
     defaultValue: "false",
-    encodeRef: false
+    encodeRef: false,
+    packed: "bool"
   },
   // TYPE_STRING = 9
   9: {
@@ -116,6 +136,15 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     defaultValue: "String::new()",
     encodeRef: true
   },
+  // TYPE_GROUP = 10: a proto2 sub-message between start- and end-group keys
+  10: {
+    rustType: "", // resolved per-field from typeName
+    wireType: WireType.StartGroup,
+    encodeFunc: "", // delegated to nested codec
+    decodeFunc: "",
+    defaultValue: "", // Default::default()
+    encodeRef: true
+  },
   // TYPE_MESSAGE = 11
   11: {
     rustType: "", // resolved per-field from typeName
@@ -141,16 +170,18 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_varint",
     decodeFunc: "decode_varint",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "uint32"
   },
   // TYPE_ENUM = 14
   14: {
     rustType: "i32",
     wireType: WireType.Varint,
-    encodeFunc: "encode_varint",
-    decodeFunc: "decode_varint",
+    encodeFunc: "encode_int32",
+    decodeFunc: "decode_int32",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "int32"
   },
   // TYPE_SFIXED32 = 15
   15: {
@@ -159,7 +190,8 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_sfixed32",
     decodeFunc: "decode_sfixed32",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "sfixed32"
   },
   // TYPE_SFIXED64 = 16
   16: {
@@ -168,7 +200,8 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_sfixed64",
     decodeFunc: "decode_sfixed64",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "sfixed64"
   },
   // TYPE_SINT32 = 17
   17: {
@@ -177,7 +210,8 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_zigzag32",
     decodeFunc: "decode_zigzag32",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "zigzag32"
   },
   // TYPE_SINT64 = 18
   18: {
@@ -186,22 +220,31 @@ export const PROTO_TYPE_MAP: Record<number, RustTypeInfo> = {
     encodeFunc: "encode_zigzag64",
     decodeFunc: "decode_zigzag64",
     defaultValue: "0",
-    encodeRef: false
+    encodeRef: false,
+    packed: "zigzag64"
+  },
+  // TYPE_PUBKEY: 32-byte bytes payload
+  [TYPE_PUBKEY]: {
+    rustType: "Pubkey",
+    wireType: WireType.LengthDelimited,
+    encodeFunc: "encode_pubkey",
+    decodeFunc: "decode_pubkey",
+    defaultValue: "ZERO_PUBKEY",
+    encodeRef: true
   }
 }
 
 /**
  * Resolve the Rust type for a field descriptor.
- * For TYPE_MESSAGE, resolves from the nested message name.
+ * For TYPE_MESSAGE and TYPE_GROUP, resolves from the nested message name.
  */
 export function resolveRustType(
   fieldType: number,
   typeName: string | undefined
 ): string {
-  if (fieldType === 11 && typeName) {
-    // Nested message → struct name (strip leading dot and package prefix)
-    const parts = typeName.replace(/^\./, "").split(".")
-    return parts[parts.length - 1]
+  if ((fieldType === 11 || fieldType === 10) && typeName) {
+    // Nested message or group → struct name
+    return protoNameToRust(typeName)
   }
   const info = PROTO_TYPE_MAP[fieldType]
   if (!info) {
@@ -214,7 +257,8 @@ export function resolveRustType(
  * Build a protobuf field tag (field_number << 3 | wire_type).
  */
 export function fieldTag(fieldNumber: number, wireType: WireType): number {
-  return (fieldNumber << 3) | wireType
+  // Not `<<`, which wraps to a negative int32 from field 2^28 on
+  return fieldNumber * 8 + wireType
 }
 
 /**
@@ -230,6 +274,20 @@ export function needsVarintCast(fieldType: number): string {
   return ""
 }
 
+/**
+ * Runtime decode call for a non-message field type. Length-delimited
+ * payloads go through the `_with` variants so `DecodeOptions` caps
+ * their allocation. `data` / `pos` name the buffer and offset in scope.
+ */
+export function decodeCall(fieldType: number, data = "data", pos = "pos"): string {
+  const info = PROTO_TYPE_MAP[fieldType]
+  // Pubkeys have a fixed size, so there is no allocation to cap
+  if (info.wireType === WireType.LengthDelimited && fieldType !== TYPE_PUBKEY) {
+    return `${info.decodeFunc}_with(${data}, ${pos}, opts)`
+  }
+  return `${info.decodeFunc}(${data}, ${pos})`
+}
+
 /**
  * Get the cast needed when decoding a varint into a non-u64 type.
  */
@@ -0,0 +1,605 @@
+import Path from "node:path"
+import { protoNameToRust, toJsonName, toSnakeCase } from "../util/names.js"
+import { log } from "../util/logger.js"
+import {
+  FieldInfo,
+  alwaysWritten,
+  isGroup,
+  isMap,
+  isMessage,
+  isOptional,
+  isPackable,
+  isPacked,
+  isRepeated,
+  mapKeyField,
+  mapValueField,
+  withMapEntry
+} from "./field.js"
+import { PROTO_TYPE_MAP, TYPE_PUBKEY, WireType, fieldTag } from "./type-map.js"
+import { withWellKnownType } from "./well-known.js"
+import { withSolanaOptions } from "./solana-options.js"
+import { encodedFields } from "./deprecated.js"
+import { resolveOneofs } from "./oneof.js"
+import { accountDiscriminator } from "./anchor.js"
+import { typeDiscriminator } from "./discriminator.js"
+import { eventDiscriminator } from "./event.js"
+import { instructionDiscriminator, parseAccounts, unaryMethods } from "./service.js"
+import type { ServiceDescriptor } from "./service.js"
+import type { MessageDescriptor } from "./message.js"
+import type { EnumDescriptor } from "./enum.js"
+import type { GenContext } from "./context.js"
+
+/** TypeScript side of a scalar type: its type, default and runtime codec functions. */
+interface TsScalar {
+  tsType: string
+  zero: string
+  write: string
+  read: string
+  /** Condition under which an implicit-presence value is written */
+  nonDefault: (value: string) => string
+  /** Element width of a packed fixed-size run */
+  width?: number
+}
+
+const isNonZeroNumber = (v: string) => `${v} !== 0`
+const isNonZeroBigint = (v: string) => `${v} !== 0n`
+
+const TS_SCALARS: Record<number, TsScalar> = {
+  // Floats compare as bits, so `-0` is written like in Rust
+  1: { tsType: "number", zero: "0", write: "writeDouble", read: "readDouble", nonDefault: v => `!Object.is(${v}, 0)`, width: 8 },
+  2: {
+    tsType: "number",
+    zero: "0",
+    write: "writeFloat",
+    read: "readFloat",
+    nonDefault: v => `!Object.is(Math.fround(${v}), 0)`,
+    width: 4
+  },
+  3: { tsType: "bigint", zero: "0n", write: "writeInt64", read: "readInt64", nonDefault: isNonZeroBigint },
+  4: { tsType: "bigint", zero: "0n", write: "writeUint64", read: "readUint64", nonDefault: isNonZeroBigint },
+  5: { tsType: "number", zero: "0", write: "writeInt32", read: "readInt32", nonDefault: isNonZeroNumber },
+  6: { tsType: "bigint", zero: "0n", write: "writeFixed64", read: "readFixed64", nonDefault: isNonZeroBigint, width: 8 },
+  7: { tsType: "number", zero: "0", write: "writeFixed32", read: "readFixed32", nonDefault: isNonZeroNumber, width: 4 },
+  8: { tsType: "boolean", zero: "false", write: "writeBool", read: "readBool", nonDefault: v => v },
+  9: { tsType: "string", zero: `""`, write: "writeString", read: "readString", nonDefault: v => `${v}.length > 0` },
+  12: {
+    tsType: "Uint8Array",
+    zero: "new Uint8Array(0)",
+    write: "writeBytes",
+    read: "readBytes",
+    nonDefault: v => `${v}.length > 0`
+  },
+  13: { tsType: "number", zero: "0", write: "writeUint32", read: "readUint32", nonDefault: isNonZeroNumber },
+  14: { tsType: "number", zero: "0", write: "writeInt32", read: "readInt32", nonDefault: isNonZeroNumber },
+  15: { tsType: "number", zero: "0", write: "writeSfixed32", read: "readSfixed32", nonDefault: isNonZeroNumber, width: 4 },
+  16: { tsType: "bigint", zero: "0n", write: "writeSfixed64", read: "readSfixed64", nonDefault: isNonZeroBigint, width: 8 },
+  17: { tsType: "number", zero: "0", write: "writeSint32", read: "readSint32", nonDefault: isNonZeroNumber },
+  18: { tsType: "bigint", zero: "0n", write: "writeSint64", read: "readSint64", nonDefault: isNonZeroBigint },
+  [TYPE_PUBKEY]: {
+    tsType: "Uint8Array",
+    zero: "new Uint8Array(32)",
+    write: "writePubkey",
+    read: "readPubkey",
+    nonDefault: v => `pb.isNonZero(${v})`
+  }
+}
+
+/** Types named by one generated file, with what it must import for them. */
+interface TsModule {
+  ctx: GenContext
+  /** This file's path under `ts_out` */
+  fileName: string
+  /** Imported names by module specifier */
+  imports: Map<string, Set<string>>
+}
+
+/**
+ * Generate the `ts_out` TypeScript module for a proto file: for each
+ * enum a TS `enum`, for each message an interface of its fields and a
+ * same-named `pb.MessageCodec` writing the bytes the Rust `encode` writes
+ * and decoding as Rust `decode` does; events and (under `anchor`)
+ * accounts get their discriminators, and each service an
+ * `<Service>Instruction` union with the instruction dispatcher's tags.
+ * Field names are lowerCamelCase; unknown fields are skipped.
+ */
+export function generateTsFile(
+  messages: MessageDescriptor[],
+  protoFileName: string,
+  fileName: string,
+  ctx: GenContext,
+  services: ServiceDescriptor[] = [],
+  enums: EnumDescriptor[] = []
+): string {
+  const mod: TsModule = { ctx, fileName, imports: new Map() }
+  const body: string[] = []
+
+  for (const desc of enums) {
+    if (desc.values.length === 0) continue
+    body.push(genTsEnum(desc), ``)
+  }
+  for (const msg of messages) {
+    if (msg.isMapEntry) continue
+    body.push(genTsMessage(mod, msg), ``)
+  }
+  const events = messages.filter(m => m.isEvent).map(m => protoNameToRust(m.fullName))
+  if (events.length > 0) body.push(genTsEventRegistry(events), ``)
+  for (const service of services) {
+    const instruction = genTsInstruction(mod, service)
+    if (instruction) body.push(instruction, ``)
+  }
+
+  const runtime = importSpecifier(fileName, "protobuf_runtime.ts")
+  const lines: string[] = [
+    `// Auto-generated by protoc-gen-solana from ${protoFileName}`,
+    `// DO NOT EDIT`,
+    ``,
+    `import * as pb from "${runtime}"`,
+    ...[...mod.imports]
+      .sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0))
+      .map(([spec, names]) => `import { ${[...names].sort().join(", ")} } from "${spec}"`),
+    ``,
+    ...body
+  ]
+  return lines.join("\n")
+}
+
+/** `./`-relative `.js` specifier of `target` from `from`, both under `ts_out`. */
+function importSpecifier(from: string, target: string): string {
+  const rel = Path.posix.relative(Path.posix.dirname(from), target).replace(/\.ts$/, ".js")
+  return rel.startsWith(".") ? rel : `./${rel}`
+}
+
+/**
+ * TypeScript name of a message or enum type, imported from its own file
+ * when it is declared elsewhere. Well-known types come from the runtime.
+ */
+function typeRef(mod: TsModule, typeName: string): string {
+  const name = protoNameToRust(typeName)
+  if (typeName.startsWith(".google.protobuf.")) return `pb.${name}`
+  const file = mod.ctx.tsFiles.get(typeName)
+  if (file && file !== mod.fileName) {
+    const spec = importSpecifier(mod.fileName, file)
+    if (!mod.imports.has(spec)) mod.imports.set(spec, new Set())
+    mod.imports.get(spec)!.add(name)
+  }
+  return name
+}
+
+function genTsEnum(desc: EnumDescriptor): string {
+  return [
+    ...typeDoc(desc.fullName, desc.deprecated),
+    `export enum ${protoNameToRust(desc.fullName)} {`,
+    ...desc.values.flatMap((v, i) => [
+      ...(v.deprecated ? [`  /** @deprecated */`] : []),
+      `  ${v.name} = ${v.number}${i < desc.values.length - 1 ? "," : ""}`
+    ]),
+    `}`
+  ].join("\n")
+}
+
+/** The doc comment of an enum or interface, tagged `@deprecated` if it is. */
+function typeDoc(fullName: string, deprecated: boolean | undefined): string[] {
+  return deprecated ? [`/**`, ` * \`${fullName}\``, ` * @deprecated`, ` */`] : [`/** \`${fullName}\` */`]
+}
+
+function propName(field: FieldInfo): string {
+  return toJsonName(field.name)
+}
+
+function scalarOf(field: FieldInfo): TsScalar | undefined {
+  return TS_SCALARS[field.type]
+}
+
+/** TypeScript type of one value of the field (the element type if repeated). */
+function valueType(mod: TsModule, field: FieldInfo): string {
+  if (isMessage(field)) return typeRef(mod, field.typeName!)
+  if (field.type === 14 && field.typeName && mod.ctx.enums.has(field.typeName)) return typeRef(mod, field.typeName)
+  return scalarOf(field)!.tsType
+}
+
+/** Interface member for a field; a oneof group is one member, `undefined` when unset. */
+function memberDecl(mod: TsModule, field: FieldInfo): string {
+  const name = propName(field)
+  if (isMap(field)) {
+    return `  ${name}: Map<${valueType(mod, mapKeyField(field))}, ${valueType(mod, mapValueField(field))}>`
+  }
+  if (isRepeated(field)) return `  ${name}: ${valueType(mod, field)}[]`
+  if (isOptional(field)) return `  ${name}?: ${valueType(mod, field)}`
+  return `  ${name}: ${valueType(mod, field)}`
+}
+
+/** `create()` default of an implicit-presence, repeated or map field. */
+function defaultValue(mod: TsModule, field: FieldInfo): string {
+  if (isMap(field)) return "new Map()"
+  if (isRepeated(field)) return "[]"
+  if (isMessage(field)) return `${typeRef(mod, field.typeName!)}.create()`
+  if (field.fixedLen !== undefined) return `new Uint8Array(${field.fixedLen})`
+  return scalarOf(field)!.zero
+}
+
+function genTsMessage(mod: TsModule, msg: MessageDescriptor): string {
+  const name = protoNameToRust(msg.fullName)
+  const resolved = resolveOneofs(
+    name,
+    msg.fields.map(f => withWellKnownType(withSolanaOptions(withMapEntry(f, msg), mod.ctx.utf8))),
+    msg.oneofs
+  )
+  const fields = resolved.fields.filter(f => {
+    if (PROTO_TYPE_MAP[f.type]) return true
+    log.warn(`Skipping unsupported field type ${f.type} for ${f.name} in TypeScript output`)
+    return false
+  })
+  const groups = resolved.groups.filter(g => g.members.some(m => fields.includes(m)))
+
+  const lines: string[] = []
+  for (const group of groups) {
+    lines.push(
+      `export type ${group.enumName} =`,
+      ...group.members.map(m => `  | { case: "${propName(m)}"; value: ${valueType(mod, m)} }`),
+      ``
+    )
+  }
+
+  // Members in declaration order, a oneof where its first member appears
+  const members = fields.flatMap(f => {
+    if (!f.oneof) return [...(f.deprecated ? [`  /** @deprecated */`] : []), memberDecl(mod, f)]
+    const group = groups.find(g => g.field === f.oneof!.field)!
+    return group.members[0] === f ? [`  ${toJsonName(group.field)}?: ${group.enumName}`] : []
+  })
+  lines.push(...typeDoc(msg.fullName, msg.deprecated))
+  lines.push(members.length > 0 ? `export interface ${name} {` : `export interface ${name} {}`)
+  if (members.length > 0) lines.push(...members, `}`)
+  lines.push(``)
+
+  const codecTypes = [`pb.MessageCodec<${name}>`]
+  if (msg.isEvent) codecTypes.push(`pb.EventCodec<${name}>`)
+  if (mod.ctx.anchor) codecTypes.push(`pb.AccountCodec<${name}>`)
+  if (msg.hasDiscriminator) codecTypes.push(`pb.DiscriminatedCodec<${name}>`)
+  const versioned = msg.version !== undefined && msg.version <= 255
+  if (versioned) codecTypes.push(`pb.VersionedCodec<${name}>`)
+  lines.push(`export const ${name}: ${codecTypes.join(" & ")} = {`)
+
+  if (msg.isEvent) {
+    const discriminator = eventDiscriminator(msg.fullName)
+    lines.push(
+      `  /** \`sha256("event:${msg.fullName}")[..8]\` */`,
+      `  EVENT_DISCRIMINATOR: Uint8Array.of(${discriminator.join(", ")}),`,
+      `  fromLog: line => pb.decodeEventLog(${name}.EVENT_DISCRIMINATOR, ${name}, line),`
+    )
+  }
+  if (mod.ctx.anchor) {
+    const discriminator = accountDiscriminator(msg.fullName)
+    lines.push(
+      `  /** \`sha256("account:${msg.fullName}")[..8]\` */`,
+      `  DISCRIMINATOR: Uint8Array.of(${discriminator.join(", ")}),`,
+      `  encodeAccount: msg => pb.encodeAccountData(${name}.DISCRIMINATOR, ${name}, msg),`,
+      `  decodeAccount: data => pb.decodeAccountData(${name}.DISCRIMINATOR, ${name}, data),`
+    )
+  }
+  if (msg.hasDiscriminator) {
+    const discriminator = typeDiscriminator(msg.fullName, mod.ctx)
+    lines.push(
+      `  /** \`${mod.ctx.discriminatorHash}("${msg.fullName}")[..${mod.ctx.discriminatorLen}]\` */`,
+      `  TYPE_DISCRIMINATOR: Uint8Array.of(${discriminator.join(", ")}),`,
+      `  encodeWithDiscriminator: msg => pb.encodeWithDiscriminator(${name}.TYPE_DISCRIMINATOR, ${name}, msg),`,
+      `  decodeWithDiscriminator: data => pb.decodeWithDiscriminator(${name}.TYPE_DISCRIMINATOR, ${name}, data),`
+    )
+  }
+  if (versioned) {
+    lines.push(
+      `  VERSION: ${msg.version},`,
+      `  encodeVersioned: msg => pb.encodeVersioned(${name}.VERSION, ${name}, msg),`,
+      `  decodeVersioned: (data, migrations) => pb.decodeVersioned(${name}.VERSION, ${name}, data, migrations),`
+    )
+  }
+
+  const defaults = fields
+    .filter(f => !f.oneof && (!isOptional(f) || isRepeated(f)))
+    .map(f => `${propName(f)}: ${defaultValue(mod, f)}`)
+  const create = `  create: (init = {}) => ({ ${[...defaults, "...init"].join(", ")} }),`
+  lines.push(
+    ...(create.length <= 100
+      ? [create]
+      : [`  create: (init = {}) => ({`, ...defaults.map(d => `    ${d},`), `    ...init`, `  }),`]),
+    `  encode: msg => pb.encode(${name}, msg),`,
+    `  decode: data => pb.decode(${name}, data),`,
+    ...genWrite(mod, [...encodedFields(fields, mod.ctx)].sort((a, b) => a.number - b.number)),
+    ...genMerge(mod, fields),
+    `}`
+  )
+  return lines.join("\n")
+}
+
+// ── Encode ───────────────────────────────────────────────────────────
+
+function genWrite(mod: TsModule, fields: FieldInfo[]): string[] {
+  if (fields.length === 0) return [`  write() {},`]
+  return [`  write(w, msg) {`, ...fields.flatMap(f => fieldWrite(mod, f)).map(line => `    ${line}`), `  },`]
+}
+
+function key(field: FieldInfo): number {
+  return fieldTag(field.number, PROTO_TYPE_MAP[field.type].wireType)
+}
+
+/** Statements writing one value `value` of the field with its key. */
+function elemWrite(mod: TsModule, field: FieldInfo, value: string, w = "w"): string[] {
+  if (isMessage(field)) return [`pb.${messageWrite(field)}(${w}, ${field.number}, ${typeRef(mod, field.typeName!)}, ${value})`]
+  const write =
+    field.fixedLen !== undefined
+      ? `pb.writeFixedBytes(${w}, ${value}, ${field.fixedLen})`
+      : `pb.${scalarOf(field)!.write}(${w}, ${value})`
+  return [`pb.writeKey(${w}, ${key(field)})`, write]
+}
+
+function messageWrite(field: FieldInfo): string {
+  return isGroup(field) ? "writeGroup" : "writeMessage"
+}
+
+function block(head: string, body: string[]): string[] {
+  return [`${head} {`, ...body.map(line => `  ${line}`), `}`]
+}
+
+/** Statements writing a field, under the same presence rules as `genFieldWriteReverse`. */
+function fieldWrite(mod: TsModule, field: FieldInfo): string[] {
+  const value = `msg.${propName(field)}`
+
+  if (isMap(field)) {
+    return block(`for (const [key, value] of pb.sortedEntries(${value}))`, [
+      `pb.writeDelimited(w, ${field.number}, entry => {`,
+      ...[
+        ...elemWrite(mod, mapKeyField(field), "key", "entry"),
+        ...elemWrite(mod, mapValueField(field), "value", "entry")
+      ].map(line => `  ${line}`),
+      `})`
+    ])
+  }
+  if (field.wrapper) {
+    return block(`if (${value} !== undefined)`, [
+      `pb.writeMessage(w, ${field.number}, pb.${field.wrapper}, { value: ${value} })`
+    ])
+  }
+  if (isPacked(field)) return [`pb.writePacked(w, ${field.number}, ${value}, pb.${scalarOf(field)!.write})`]
+  if (isRepeated(field)) return block(`for (const elem of ${value})`, elemWrite(mod, field, "elem"))
+  if (isOptional(field)) return block(`if (${value} !== undefined)`, elemWrite(mod, field, value))
+  if (field.oneof) {
+    // The selected member is always written, even at its default value
+    const group = `msg.${toJsonName(field.oneof.field)}`
+    return block(`if (${group}?.case === "${propName(field)}")`, elemWrite(mod, field, `${group}.value`))
+  }
+  // A proto2 `required` field or a singular message is always written
+  if (alwaysWritten(field)) return elemWrite(mod, field, value)
+  // Other implicit-presence fields equal to their default are left out
+  const nonDefault =
+    field.fixedLen !== undefined ? `pb.isNonZero(${value})` : scalarOf(field)!.nonDefault(value)
+  return block(`if (${nonDefault})`, elemWrite(mod, field, value))
+}
+
+// ── Decode ───────────────────────────────────────────────────────────
+
+function genMerge(mod: TsModule, fields: FieldInfo[]): string[] {
+  const required = fields.filter(f => f.required).map(f => f.number)
+  const cases = fields.flatMap(f => (f.required ? markSeen(fieldCases(mod, f), f) : fieldCases(mod, f)))
+  const nests = cases.some(line => line.includes("depth"))
+  const params = [fields.length > 0 ? "msg" : "_msg", "data", ...(nests ? ["depth = pb.MAX_DEPTH"] : [])]
+  const loop =
+    cases.length > 0
+      ? [
+          `const tag = pb.readKey(r)`,
+          `switch (tag) {`,
+          ...cases,
+          `  default:`,
+          `    pb.skipField(r, tag)`,
+          `}`
+        ]
+      : [`pb.skipField(r, pb.readKey(r))`]
+  return [
+    `  merge(${params.join(", ")}) {`,
+    `    const r = pb.reader(data)`,
+    ...(required.length > 0 ? [`    const seen = new Set<number>()`] : []),
+    `    while (!pb.done(r)) {`,
+    ...loop.map(line => `      ${line}`),
+    `    }`,
+    ...(required.length > 0 ? [`    pb.checkRequired(seen, [${required.join(", ")}])`] : []),
+    `  }`
+  ]
+}
+
+/** Open each of a `(solana.required)` field's arms by recording it in `seen`. */
+function markSeen(cases: string[], field: FieldInfo): string[] {
+  return cases.flatMap(line => (/^  case \d+:/.test(line) ? [line, `    seen.add(${field.number})`] : [line]))
+}
+
+/** Read expression for one non-message value at reader `r`. */
+function readExpr(field: FieldInfo, r: string, boundField = field.number): string {
+  if (field.fixedLen !== undefined) return `pb.readFixedBytes(${r}, ${field.fixedLen})`
+  const read = field.type === 9 && field.utf8 === "lossy" ? "readStringLossy" : scalarOf(field)!.read
+  if (field.maxLen !== undefined && (field.type === 9 || field.type === 12)) {
+    return `pb.${read}(${r}, ${field.maxLen}, ${boundField})`
+  }
+  return `pb.${read}(${r})`
+}
+
+/** One value of the field decoded at `r`, merged into `into` if a message. */
+function valueExpr(mod: TsModule, field: FieldInfo, r = "r", into?: string): string {
+  if (!isMessage(field)) return readExpr(field, r)
+  const codec = typeRef(mod, field.typeName!)
+  // A group's body runs to the end key with its field number
+  const args = [codec, r, ...(isGroup(field) ? [`${field.number}`] : []), "depth", ...(into ? [into] : [])]
+  return `pb.${isGroup(field) ? "mergeGroup" : "mergeMessage"}(${args.join(", ")})`
+}
+
+function countArgs(field: FieldInfo): string {
+  return field.maxCount === undefined ? "" : `, ${field.maxCount}, ${field.number}`
+}
+
+function arm(tag: number, body: string[]): string[] {
+  return [`  case ${tag}:`, ...body.map(line => `    ${line}`), `    break`]
+}
+
+/** `switch` arms decoding a field, mirroring the Rust `merge_with` arms. */
+function fieldCases(mod: TsModule, field: FieldInfo): string[] {
+  const target = `msg.${propName(field)}`
+
+  if (isMap(field)) return mapCase(mod, field, target)
+  if (field.wrapper) {
+    // A present wrapper merges into the current value, like any sub-message
+    return arm(fieldTag(field.number, WireType.LengthDelimited), [
+      `${target} = pb.${field.wrapper}.mergeValue(${target}, pb.readMessage(r))`
+    ])
+  }
+  if (isRepeated(field)) {
+    const cases = arm(key(field), [`pb.push(${target}, ${valueExpr(mod, field)}${countArgs(field)})`])
+    if (!isPackable(field)) return cases
+    // Parsers must accept both forms, whichever one the field encodes with
+    const scalar = scalarOf(field)!
+    return [
+      ...cases,
+      ...arm(fieldTag(field.number, WireType.LengthDelimited), [
+        `pb.readPacked(r, ${target}, pb.${scalar.read}, ${scalar.width ?? 0}${countArgs(field)})`
+      ])
+    ]
+  }
+  if (field.oneof) {
+    const group = `msg.${toJsonName(field.oneof.field)}`
+    const caseName = propName(field)
+    // A message member merges into itself if it is already selected
+    if (isMessage(field)) {
+      const into = `${group}?.case === "${caseName}" ? ${group}.value : undefined`
+      return arm(key(field), [
+        `${group} = {`,
+        `  case: "${caseName}",`,
+        `  value: ${valueExpr(mod, field, "r", into)}`,
+        `}`
+      ])
+    }
+    return arm(key(field), [`${group} = { case: "${caseName}", value: ${readExpr(field, "r")} }`])
+  }
+  if (isOptional(field)) {
+    return arm(key(field), [`${target} = ${valueExpr(mod, field, "r", isMessage(field) ? target : undefined)}`])
+  }
+  if (isMessage(field)) return arm(key(field), [`${valueExpr(mod, field, "r", target)}`])
+  return arm(key(field), [`${target} = ${readExpr(field, "r")}`])
+}
+
+/**
+ * Decode one map entry and insert it; a missing key or value takes its
+ * default, and bounds apply to both with the map's field number.
+ */
+function mapCase(mod: TsModule, field: FieldInfo, target: string): string[] {
+  const entryField = (f: FieldInfo) => ({ ...f, maxLen: field.maxLen, utf8: field.utf8 })
+  const keyField = entryField(mapKeyField(field))
+  const valueField = entryField(mapValueField(field))
+  const local = (f: FieldInfo) => {
+    if (isMessage(f)) return `const ${f.name} = ${defaultValue(mod, f)}`
+    return `let ${f.name}: ${valueType(mod, f)} = ${defaultValue(mod, f)}`
+  }
+  const read = (f: FieldInfo) =>
+    isMessage(f) ? `${valueExpr(mod, f, "entry", f.name)}` : `${f.name} = ${readExpr(f, "entry", field.number)}`
+  return [
+    `  case ${fieldTag(field.number, WireType.LengthDelimited)}: {`,
+    `    const entry = pb.reader(pb.readMessage(r))`,
+    `    ${local(keyField)}`,
+    `    ${local(valueField)}`,
+    `    while (!pb.done(entry)) {`,
+    `      const entryTag = pb.readKey(entry)`,
+    `      if (entryTag === ${key(keyField)}) ${read(keyField)}`,
+    `      else if (entryTag === ${key(valueField)}) ${read(valueField)}`,
+    `      else pb.skipField(entry, entryTag)`,
+    `    }`,
+    `    pb.setEntry(${target}, key, value${countArgs(field)})`,
+    `    break`,
+    `  }`
+  ]
+}
+
+// ── Events and instructions ──────────────────────────────────────────
+
+function genTsEventRegistry(names: string[]): string {
+  return [
+    `/** Every \`(solana.event)\` message in this file. */`,
+    `export type AnyEvent =`,
+    ...names.map(name => `  | { case: "${name}"; value: ${name} }`),
+    ``,
+    `export const AnyEvent = {`,
+    `  /** The event a log line holds, or \`undefined\` if it carries none of this file's events. */`,
+    `  fromLog: (line: string): AnyEvent | undefined => {`,
+    ...names.flatMap(name => [
+      `    const ${lowerFirst(name)} = ${name}.fromLog(line)`,
+      `    if (${lowerFirst(name)} !== undefined) return { case: "${name}", value: ${lowerFirst(name)} }`
+    ]),
+    `    return undefined`,
+    `  }`,
+    `}`
+  ].join("\n")
+}
+
+function lowerFirst(name: string): string {
+  return name[0].toLowerCase() + name.slice(1)
+}
+
+/**
+ * `<Service>Instruction`: a union of the service's rpcs with their
+ * requests, and a const holding each rpc's tag (and `(solana.accounts)`)
+ * with `encode` / `decode` of instruction data in the dispatcher's
+ * layout, the tag followed by the protobuf request.
+ */
+function genTsInstruction(mod: TsModule, service: ServiceDescriptor): string | undefined {
+  const methods = unaryMethods(service)
+  if (methods.length === 0) return undefined
+  const name = `${service.name}Instruction`
+  const serviceName = service.fullName.replace(/^\./, "")
+  const discriminated = mod.ctx.instructionTag === "discriminator"
+  const constName = (rpc: string) => toSnakeCase(rpc).toUpperCase()
+
+  const lines: string[] = [
+    discriminated
+      ? `/** Instructions of the \`${serviceName}\` service, tagged by an 8-byte discriminator. */`
+      : `/** Instructions of the \`${serviceName}\` service, tagged by a leading \`u8\`. */`,
+    `export type ${name} =`,
+    ...methods.map(m => `  | { case: "${m.name}"; value: ${typeRef(mod, m.inputType)} }`),
+    ``,
+    `export const ${name} = {`
+  ]
+  methods.forEach((m, i) => {
+    if (discriminated) {
+      lines.push(
+        `  /** \`sha256("instruction:${serviceName}/${m.name}")[..8]\` */`,
+        `  ${constName(m.name)}_TAG: Uint8Array.of(${instructionDiscriminator(service.fullName, m.name).join(", ")}),`
+      )
+    } else {
+      lines.push(`  ${constName(m.name)}_TAG: ${i},`)
+    }
+    if (m.accounts) {
+      const accounts = parseAccounts(m.accounts, `${serviceName}.${m.name}`)
+      lines.push(
+        `  ${constName(m.name)}_ACCOUNTS: [`,
+        ...accounts.map(a => `    { name: "${a.name}", isSigner: ${a.signer}, isWritable: ${a.writable} },`),
+        `  ] as readonly pb.AccountSpec[],`
+      )
+    }
+  })
+  lines.push(
+    ``,
+    `  /** Instruction data: the tag followed by the encoded request. */`,
+    `  encode: (ix: ${name}): Uint8Array => {`,
+    `    switch (ix.case) {`,
+    ...methods.flatMap(m => [
+      `      case "${m.name}":`,
+      `        return pb.instructionData(${name}.${constName(m.name)}_TAG, ${typeRef(mod, m.inputType)}, ix.value)`
+    ]),
+    `    }`,
+    `  },`,
+    ``,
+    `  decode: (data: Uint8Array): ${name} => {`,
+    `    const payload = pb.instructionPayload(data, ${discriminated ? 8 : 1})`,
+    ...methods.flatMap(m => [
+      `    if (pb.hasTag(data, ${name}.${constName(m.name)}_TAG)) {`,
+      `      return { case: "${m.name}", value: ${typeRef(mod, m.inputType)}.decode(payload) }`,
+      `    }`
+    ]),
+    `    throw pb.decodeError("InvalidData", "unknown instruction tag")`,
+    `  }`,
+    `}`
+  )
+  return lines.join("\n")
+}
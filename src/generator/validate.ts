@@ -0,0 +1,246 @@
+import { log } from "../util/logger.js"
+import {
+  FieldInfo,
+  defaultValueExpr,
+  fieldRustName,
+  isFixedBytes,
+  isMap,
+  isMessage,
+  isOptional,
+  isRepeated,
+  mapKeyField,
+  mapValueField,
+  oneofPattern
+} from "./field.js"
+import type { GenContext } from "./context.js"
+
+/** Inclusive range of each integer type, for `(solana.min)` / `(solana.max)`. */
+const INTEGER_RANGES: Record<number, [bigint, bigint]> = {
+  3: [-(2n ** 63n), 2n ** 63n - 1n], // int64
+  4: [0n, 2n ** 64n - 1n], // uint64
+  5: [-(2n ** 31n), 2n ** 31n - 1n], // int32
+  6: [0n, 2n ** 64n - 1n], // fixed64
+  7: [0n, 2n ** 32n - 1n], // fixed32
+  13: [0n, 2n ** 32n - 1n], // uint32
+  15: [-(2n ** 31n), 2n ** 31n - 1n], // sfixed32
+  16: [-(2n ** 63n), 2n ** 63n - 1n], // sfixed64
+  17: [-(2n ** 31n), 2n ** 31n - 1n], // sint32
+  18: [-(2n ** 63n), 2n ** 63n - 1n] // sint64
+}
+
+/** True if the field declares any rule `validate()` checks. */
+function hasRules(field: FieldInfo): boolean {
+  return (
+    field.min !== undefined ||
+    field.max !== undefined ||
+    field.minLen !== undefined ||
+    field.maxLen !== undefined ||
+    field.maxCount !== undefined ||
+    field.prefix !== undefined ||
+    field.suffix !== undefined ||
+    field.nonDefault === true
+  )
+}
+
+/**
+ * True if `validate()` is generated for message `typeName`: it, or a
+ * message reachable through its fields, declares a rule. Runtime types
+ * never have rules.
+ */
+export function hasValidate(typeName: string, ctx: GenContext, seen = new Set<string>()): boolean {
+  const msg = ctx.messages.get(typeName)
+  if (!msg || seen.has(typeName)) return false
+  seen.add(typeName)
+  const entries = new Map(msg.nestedMessages.filter(m => m.isMapEntry).map(m => [`.${m.fullName}`, m]))
+  return msg.fields.some(field => {
+    if (hasRules(field)) return true
+    if (!isMessage(field) || !field.typeName) return false
+    const entry = entries.get(field.typeName)
+    const valueType = entry ? entry.fields.find(f => f.number === 2)?.typeName : field.typeName
+    return valueType !== undefined && hasValidate(valueType, ctx, seen)
+  })
+}
+
+/** The rules `validate()` checks on each value of a field. */
+interface ValueRules {
+  min?: bigint
+  max?: bigint
+  minLen?: number
+  maxLen?: number
+  prefix?: string
+  suffix?: string
+  /** The prefix and suffix are matched against bytes, for a `(solana.utf8) = "bytes"` string */
+  bytes?: boolean
+}
+
+/**
+ * The field's value rules that can apply to it, warning about and
+ * dropping the others.
+ */
+function valueRules(field: FieldInfo, where: string): ValueRules {
+  const value = isMap(field) ? undefined : field
+  const range = value ? INTEGER_RANGES[value.type] : undefined
+  const rules: ValueRules = {}
+  if (field.min !== undefined || field.max !== undefined) {
+    if (!range) {
+      log.warn(`Ignoring (solana.min) / (solana.max) on ${where}, which is not an integer field`)
+    } else {
+      if (field.min !== undefined && field.max !== undefined && field.min > field.max) {
+        throw new Error(`(solana.min) = ${field.min} is greater than (solana.max) = ${field.max} on ${where}`)
+      }
+      // A bound at or past the end of the type's range always holds
+      if (field.min !== undefined && field.min > range[0]) rules.min = field.min
+      if (field.max !== undefined && field.max < range[1]) rules.max = field.max
+      if ((rules.min ?? range[0]) > range[1] || (rules.max ?? range[1]) < range[0]) {
+        throw new Error(`The (solana.min) / (solana.max) bounds on ${where} exclude every value of its type`)
+      }
+    }
+  }
+  const sized = value !== undefined && (value.type === 9 || value.type === 12) && !isFixedBytes(value)
+  if (field.minLen !== undefined) {
+    if (sized) rules.minLen = field.minLen
+    else log.warn(`Ignoring (solana.min_len) on ${where}, which is not a string or bytes field`)
+  }
+  if (sized && field.maxLen !== undefined) rules.maxLen = field.maxLen
+  for (const option of ["prefix", "suffix"] as const) {
+    if (field[option] === undefined) continue
+    if (value?.type === 9 || value?.utf8 === "bytes") rules[option] = field[option]
+    else log.warn(`Ignoring (solana.${option}) on ${where}, which is not a string field`)
+  }
+  if (value?.utf8 === "bytes" && (rules.prefix !== undefined || rules.suffix !== undefined)) rules.bytes = true
+  return rules
+}
+
+/** A Rust string literal for `text`. */
+function stringLiteral(text: string): string {
+  const escaped = [...text]
+    .map(c => {
+      if (c === '"' || c === "\\") return `\\${c}`
+      const code = c.codePointAt(0)!
+      return code >= 0x20 && code < 0x7f ? c : `\\u{${code.toString(16)}}`
+    })
+    .join("")
+  return `"${escaped}"`
+}
+
+/**
+ * `if` statements returning `error(rule)` for each broken rule of one
+ * value: `value` is the `T` for integer comparisons and the receiver of
+ * `len()` / `starts_with()`.
+ */
+function genValueChecks(rules: ValueRules, value: string, error: (rule: string) => string, indent: string): string[] {
+  const receiver = value.replace(/^\*/, "")
+  const checks: Array<[string, string]> = []
+  if (rules.min !== undefined) checks.push([`${value} < ${rules.min}`, `Min(${rules.min})`])
+  if (rules.max !== undefined) checks.push([`${value} > ${rules.max}`, `Max(${rules.max})`])
+  if (rules.minLen !== undefined) checks.push([`${receiver}.len() < ${rules.minLen}`, `MinLen(${rules.minLen})`])
+  if (rules.maxLen !== undefined) checks.push([`${receiver}.len() > ${rules.maxLen}`, `MaxLen(${rules.maxLen})`])
+  const asBytes = rules.bytes ? ".as_bytes()" : ""
+  if (rules.prefix !== undefined) {
+    const prefix = stringLiteral(rules.prefix)
+    checks.push([`!${receiver}.starts_with(${prefix}${asBytes})`, `Prefix(${prefix})`])
+  }
+  if (rules.suffix !== undefined) {
+    const suffix = stringLiteral(rules.suffix)
+    checks.push([`!${receiver}.ends_with(${suffix}${asBytes})`, `Suffix(${suffix})`])
+  }
+  return checks.flatMap(([cond, rule]) => [
+    `${indent}if ${cond} {`,
+    `${indent}    return Err(${error(`ValidationRule::${rule}`)});`,
+    `${indent}}`
+  ])
+}
+
+/** The checks of one field of a message. */
+function genFieldChecks(field: FieldInfo, messageName: string, ctx: GenContext): string[] {
+  const where = `field ${field.name} of ${messageName}`
+  const rustName = fieldRustName(field)
+  const name = JSON.stringify(field.name)
+  const here = (rule: string) => `ValidationError::new(${name}, ${rule})`
+  const lines: string[] = []
+
+  if (isMap(field)) {
+    // `max_len` bounds string and bytes keys and values alike
+    const sized = (f: FieldInfo) => (f.type === 9 || f.type === 12) && field.maxLen !== undefined
+    const key = sized(mapKeyField(field))
+    const value = mapValueField(field)
+    const validated = isMessage(value) && hasValidate(value.typeName!, ctx)
+    if (key || sized(value) || validated) {
+      const at = (rule: string) => `ValidationError::element(${name}, k, ${rule})`
+      const useValue = sized(value) || validated
+      lines.push(useValue ? `        for (k, v) in &self.${rustName} {` : `        for k in self.${rustName}.keys() {`)
+      if (key) lines.push(...genValueChecks({ maxLen: field.maxLen }, "k", at, "            "))
+      if (sized(value)) lines.push(...genValueChecks({ maxLen: field.maxLen }, "v", at, "            "))
+      if (validated) lines.push(`            v.validate().map_err(|e| e.within_element(${name}, k))?;`)
+      lines.push(`        }`)
+    }
+    if ([field.min, field.max, field.minLen, field.prefix, field.suffix].some(rule => rule !== undefined)) {
+      log.warn(`Ignoring (solana.min), (solana.max), (solana.min_len), (solana.prefix) and (solana.suffix) on map ${where}`)
+    }
+  } else {
+    const rules = valueRules(field, where)
+    const validated = isMessage(field) && hasValidate(field.typeName!, ctx)
+    if (isRepeated(field)) {
+      if (Object.keys(rules).length > 0 || validated) {
+        const at = (rule: string) => `ValidationError::element(${name}, i, ${rule})`
+        lines.push(`        for (i, v) in self.${rustName}.iter().enumerate() {`)
+        lines.push(...genValueChecks(rules, "*v", at, "            "))
+        if (validated) lines.push(`            v.validate().map_err(|e| e.within_element(${name}, i))?;`)
+        lines.push(`        }`)
+      }
+    } else if (field.oneof || isOptional(field)) {
+      if (Object.keys(rules).length > 0 || validated) {
+        const pattern = field.oneof ? oneofPattern(field.oneof, "v") : "Some(v)"
+        const target = field.oneof ? field.oneof.field : rustName
+        lines.push(`        if let ${pattern} = &self.${target} {`)
+        lines.push(...genValueChecks(rules, "*v", here, "            "))
+        if (validated) lines.push(`            v.validate().map_err(|e| e.within(${name}))?;`)
+        lines.push(`        }`)
+      }
+    } else {
+      lines.push(...genValueChecks(rules, `self.${rustName}`, here, "        "))
+      if (validated) lines.push(`        self.${rustName}.validate().map_err(|e| e.within(${name}))?;`)
+    }
+  }
+
+  if (field.maxCount !== undefined) {
+    lines.push(
+      `        if self.${rustName}.len() > ${field.maxCount} {`,
+      `            return Err(${here(`ValidationRule::MaxCount(${field.maxCount})`)});`,
+      `        }`
+    )
+  }
+  if (field.nonDefault) {
+    let unset: string | undefined
+    if (isMap(field) || isRepeated(field)) unset = `self.${rustName}.is_empty()`
+    else if (isOptional(field)) unset = `self.${rustName}.is_none()`
+    else if (!field.oneof) unset = `self.${rustName} == ${defaultValueExpr(field)}`
+    if (unset === undefined) {
+      log.warn(`Ignoring (solana.non_default) on oneof member ${where}`)
+    } else {
+      lines.push(`        if ${unset} {`, `            return Err(${here("ValidationRule::NonDefault")});`, `        }`)
+    }
+  }
+  return lines
+}
+
+/**
+ * Generate `validate()` for a message with field rules of its own or in
+ * its sub-messages: each field's checks in declaration order, returning
+ * the first broken rule. `undefined` for a message without any.
+ */
+export function genValidate(structName: string, fullName: string, fields: FieldInfo[], ctx: GenContext): string | undefined {
+  if (!hasValidate(`.${fullName}`, ctx)) return undefined
+  const checks = fields.map(field => genFieldChecks(field, fullName, ctx)).filter(lines => lines.length > 0)
+  return [
+    `impl ${structName} {`,
+    `    /// Check the \`(solana.*)\` field rules, those of sub-messages`,
+    `    /// included; decoding does not. Returns the first rule broken.`,
+    `    pub fn validate(&self) -> Result<(), ValidationError> {`,
+    ...checks.flatMap((lines, i) => (i > 0 ? ["", ...lines] : lines)),
+    ...(checks.length > 0 ? [""] : []),
+    `        Ok(())`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
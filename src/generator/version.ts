@@ -0,0 +1,59 @@
+import { log } from "../util/logger.js"
+
+/**
+ * Generate `VERSION`, `encode_versioned()` and `decode_versioned()` for
+ * a `(solana.version)` message, plus, from version 2 on, the
+ * `<Message>Migrations` trait with one `migrate_from_v<N>` per earlier
+ * version. `decode_versioned` decodes data at the current version and
+ * routes older data through the trait, which the program implements,
+ * usually by decoding a message kept for that version and converting it.
+ * Like `decode_account_data`, it decodes with `DecodeOptions::ACCOUNT`,
+ * since versioned data is usually an account's. `undefined` for a
+ * version outside 1..=255.
+ */
+export function genVersionImpl(structName: string, version: number): string | undefined {
+  if (version > 255) {
+    log.warn(`Ignoring (solana.version) = ${version} on ${structName}, which does not fit the version byte`)
+    return undefined
+  }
+  const traitName = `${structName}Migrations`
+  const earlier = Array.from({ length: version - 1 }, (_, i) => i + 1)
+  const trait = earlier.length > 0
+    ? [
+        `/// Conversions of \`${structName}\` data written at earlier \`(solana.version)\`s,`,
+        `/// implemented by the program for \`${structName}::decode_versioned\`.`,
+        `pub trait ${traitName} {`,
+        ...earlier.flatMap((v, i) => [
+          ...(i > 0 ? [``] : []),
+          `    /// A \`${structName}\` from the payload of data written at version ${v}.`,
+          `    fn migrate_from_v${v}(payload: &[u8]) -> Result<${structName}, DecodeError>;`
+        ]),
+        `}`,
+        ``
+      ]
+    : []
+  const generic = earlier.length > 0 ? `<M: ${traitName}>` : ""
+  return [
+    ...trait,
+    `impl ${structName} {`,
+    `    /// The schema version \`encode_versioned\` writes.`,
+    `    pub const VERSION: u8 = ${version};`,
+    ``,
+    `    /// The version byte, then the protobuf encoding.`,
+    `    pub fn encode_versioned(&self) -> Vec<u8> {`,
+    `        encode_versioned(Self::VERSION, self)`,
+    `    }`,
+    ``,
+    earlier.length > 0
+      ? `    /// Decode \`encode_versioned\` data, migrating data from earlier versions with \`M\`.`
+      : `    /// Decode \`encode_versioned\` data; other versions fail with \`UnknownVersion\`.`,
+    `    pub fn decode_versioned${generic}(data: &[u8]) -> Result<Self, DecodeError> {`,
+    `        match split_version(data)? {`,
+    `            (Self::VERSION, payload) => Self::decode_with(payload, &DecodeOptions::ACCOUNT),`,
+    ...earlier.map(v => `            (${v}, payload) => M::migrate_from_v${v}(payload),`),
+    `            (version, _) => Err(DecodeError::UnknownVersion { version }),`,
+    `        }`,
+    `    }`,
+    `}`
+  ].join("\n")
+}
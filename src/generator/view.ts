@@ -0,0 +1,227 @@
+import {
+  FieldInfo,
+  fieldRustName,
+  fieldVisibility,
+  isMap,
+  isPackable,
+  isRepeated,
+  isOptional,
+  isMessage,
+  isGroup,
+  isFixedBytes,
+  messageSlice
+} from "./field.js"
+import {
+  PROTO_TYPE_MAP,
+  WireType,
+  fieldTag,
+  resolveRustType,
+  varintDecodeCast
+} from "./type-map.js"
+import { isViewedByValue, unwrapWrapper } from "./well-known.js"
+import { DEPRECATED_ATTR, deprecatedLines } from "./deprecated.js"
+
+/**
+ * Generate the borrowed `<Struct>Ref<'a>` view: `string`/`bytes` fields
+ * are `&'a str`/`&'a [u8]` (or `&'a [u8; N]`) into the input, sub-messages are nested views
+ * (`None` when absent; the last occurrence wins, where `decode` merges
+ * them all, since a view cannot merge without allocating), or `LazyRef`s decoded
+ * on `get()` for fields closing a cycle of messages, oneof members are
+ * `Option`s of which at most one is set, and repeated fields are
+ * `RepeatedRef`s decoded on iteration, so `decode` never allocates.
+ * Map fields are not exposed; use `to_message()` for them. Wrapper
+ * types read as their bare value; Timestamp, Duration and Empty are `Copy`
+ * runtime structs. `(solana.required)` fields are checked as in `decode`.
+ * A lossy string is its raw `&'a [u8]`, since a replaced one is not in
+ * the input to borrow. Each field read is passed to `trace_field`; one
+ * that fails to decode is not.
+ */
+export function genView(structName: string, fields: FieldInfo[], visibility: string, deprecated: boolean): string {
+  const viewName = `${structName}Ref`
+  const supported = fields
+    .filter(f => PROTO_TYPE_MAP[f.type] && !isMap(f))
+    .map(f => unwrapWrapper(f.utf8 === "lossy" ? { ...f, type: 12 } : f))
+  // Repeated fields are read lazily, so only singular ones assign to the view
+  const mutView = supported.some(f => !isRepeated(f)) ? "mut " : ""
+  const required = supported.filter(f => f.required)
+
+  const lines: string[] = [
+    `#[derive(Clone, Copy, Debug)]`,
+    ...(deprecated ? [DEPRECATED_ATTR] : []),
+    `${visibility} struct ${viewName}<'a> {`,
+    `    raw: &'a [u8],`,
+    ...supported.flatMap(f => [...deprecatedLines(f, "    "), `    ${fieldVisibility(f)} ${fieldRustName(f)}: ${viewType(f)},`]),
+    `}`,
+    ``,
+    `impl<'a> ${viewName}<'a> {`,
+    `    pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {`,
+    `        let ${mutView}view = ${viewName} {`,
+    `            raw: data,`,
+    ...supported.map(f => `            ${fieldRustName(f)}: ${viewInit(f)},`),
+    `        };`,
+    ``,
+    ...required.map(f => `        let mut has_${fieldRustName(f)} = false;`),
+    `        let mut pos = 0usize;`,
+    `        while pos < data.len() {`,
+    `            let field_start = pos;`,
+    `            let (tag, new_pos) = decode_key(data, pos)?;`,
+    `            pos = new_pos;`,
+    ``
+  ]
+
+  // With no fields to match, the skip stands alone, as clippy's `match_single_binding` wants
+  if (supported.length === 0) {
+    lines.push(`            pos = skip_field(data, pos, tag)?;`)
+  } else {
+    lines.push(
+      `            match tag {`,
+      ...supported.map(field => genViewArm(field, supported)),
+      `            _ => {`,
+      `                pos = skip_field(data, pos, tag)?;`,
+      `            }`,
+      `            }`
+    )
+  }
+  lines.push(
+    `            trace_field(${structName}::FULL_NAME, tag, field_start, pos, None);`,
+    `        }`,
+    ``,
+    ...required.flatMap(f => [
+      `        if !has_${fieldRustName(f)} {`,
+      `            return Err(DecodeError::MissingField { field: ${f.number} });`,
+      `        }`
+    ]),
+    `        Ok(view)`,
+    `    }`,
+    ``,
+    `    /// The encoded message this view borrows from.`,
+    `    pub fn raw(&self) -> &'a [u8] {`,
+    `        self.raw`,
+    `    }`,
+    ``,
+    `    /// Decode the borrowed bytes into the owned message.`,
+    // Clippy holds `to_*` on a `Copy` type to taking `self`, unless it is exported
+    ...(visibility === "pub" ? [] : [`    #[allow(clippy::wrong_self_convention)]`]),
+    `    pub fn to_message(&self) -> Result<${structName}, DecodeError> {`,
+    `        ${structName}::decode_with(self.raw, &DecodeOptions::ACCOUNT)`,
+    `    }`,
+    `}`
+  )
+  return lines.join("\n")
+}
+
+/** Rust type of a single element as seen through a view. */
+function elemViewType(field: FieldInfo): string {
+  if (field.box) return `LazyRef<'a, ${resolveRustType(field.type, field.typeName)}Ref<'a>>`
+  if (isMessage(field) && isViewedByValue(field.typeName)) {
+    return resolveRustType(field.type, field.typeName)
+  }
+  if (isMessage(field)) return `${resolveRustType(field.type, field.typeName)}Ref<'a>`
+  if (field.type === 9) return `&'a str`
+  if (isFixedBytes(field)) return `&'a [u8; ${field.fixedLen}]`
+  if (field.type === 12) return `&'a [u8]`
+  return resolveRustType(field.type, field.typeName)
+}
+
+function viewType(field: FieldInfo): string {
+  const elem = elemViewType(field)
+  if (isRepeated(field)) return `RepeatedRef<'a, ${elem}>`
+  if (hasPresence(field)) return `Option<${elem}>`
+  return elem
+}
+
+function viewInit(field: FieldInfo): string {
+  if (isRepeated(field)) {
+    const tag = fieldTag(field.number, wireType(field))
+    const { call, value } = elemRead(field)
+    const decoder =
+      value === "v"
+        ? call
+        : `|data, pos| {\n                let (v, new_pos) = ${call}(data, pos)?;\n                Ok((${value}, new_pos))\n            }`
+    // Packable scalars also yield the elements of packed runs
+    const ctor = isPackable(field) && !field.wrapper ? "packed" : "new"
+    return `RepeatedRef::${ctor}(data, ${tag}, ${decoder})`
+  }
+  if (hasPresence(field)) return `None`
+  if (field.type === 9) return `""`
+  if (isFixedBytes(field)) return `&[0; ${field.fixedLen}]`
+  if (field.type === 12) return `&[]`
+  return PROTO_TYPE_MAP[field.type].defaultValue
+}
+
+/**
+ * Runtime function reading one element as `(v, new_pos)`, and the
+ * expression turning `v` into the view's value.
+ */
+function elemRead(field: FieldInfo): { call: string; value: string } {
+  if (field.wrapper) {
+    // The wrapper's own `value` field, read with the scalar's decoder
+    const { call, value } = elemRead({ ...field, wrapper: undefined })
+    const tag = fieldTag(1, PROTO_TYPE_MAP[field.type].wireType)
+    const decoder =
+      value === "v" ? call : `|data, pos| ${call}(data, pos).map(|(v, pos)| (${value}, pos))`
+    return { call: "decode_message_slice", value: `wrapper_value_ref(v, ${tag}, ${decoder})?` }
+  }
+  if (field.box) {
+    const viewType = `${resolveRustType(field.type, field.typeName)}Ref`
+    return { call: messageSlice(field), value: `LazyRef::new(v, ${viewType}::decode)` }
+  }
+  if (isMessage(field) && isViewedByValue(field.typeName)) {
+    const structType = resolveRustType(field.type, field.typeName)
+    return { call: messageSlice(field), value: `${structType}::decode(v)?` }
+  }
+  if (isMessage(field)) {
+    const viewType = `${resolveRustType(field.type, field.typeName)}Ref`
+    return { call: messageSlice(field), value: `${viewType}::decode(v)?` }
+  }
+  // `(solana.max_len)` is checked here, per element for repeated fields
+  const bounded = field.maxLen === undefined ? "v" : `within_max_len(v, ${field.maxLen}, ${field.number})?`
+  if (field.type === 9) return { call: "decode_str_ref", value: bounded }
+  if (isFixedBytes(field)) return { call: `decode_fixed_bytes_ref::<${field.fixedLen}>`, value: "v" }
+  if (field.type === 12) return { call: "decode_bytes_ref", value: bounded }
+  return {
+    call: PROTO_TYPE_MAP[field.type].decodeFunc,
+    value: `v${varintDecodeCast(field.type)}`
+  }
+}
+
+/** Wire type of one element; wrappers are sub-messages. */
+function wireType(field: FieldInfo): number {
+  return field.wrapper ? WireType.LengthDelimited : PROTO_TYPE_MAP[field.type].wireType
+}
+
+/** Fields whose view member is an `Option`. */
+function hasPresence(field: FieldInfo): boolean {
+  return isOptional(field) || isMessage(field) || field.oneof !== undefined
+}
+
+function genViewArm(field: FieldInfo, fields: FieldInfo[]): string {
+  const rustName = fieldRustName(field)
+  const tag = fieldTag(field.number, wireType(field))
+
+  if (isRepeated(field)) {
+    // Framing is checked here; element values are decoded on iteration.
+    // A group's end key is found by its field number, so skip by tag
+    return [
+      `            ${tag} => {`,
+      `                pos = skip_field(data, pos, ${isGroup(field) ? tag : wireType(field)})?;`,
+      `            }`
+    ].join("\n")
+  }
+
+  const { call, value } = elemRead(field)
+  const store = hasPresence(field) ? `Some(${value})` : value
+  // A oneof member on the wire deselects the rest of its group
+  const siblings = fields.filter(
+    f => f !== field && field.oneof && f.oneof?.field === field.oneof.field
+  )
+  return [
+    `            ${tag} => {`,
+    ...(field.required ? [`                has_${rustName} = true;`] : []),
+    `                let (v, new_pos) = ${call}(data, pos)?;`,
+    `                view.${rustName} = ${store};`,
+    ...siblings.map(f => `                view.${fieldRustName(f)} = None;`),
+    `                pos = new_pos;`,
+    `            }`
+  ].join("\n")
+}
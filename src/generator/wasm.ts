@@ -0,0 +1,79 @@
+import { toPascalCase, toSnakeCase } from "../util/names.js"
+import { resolveRustType } from "./type-map.js"
+import { unaryMethods } from "./service.js"
+import type { ServiceDescriptor } from "./service.js"
+
+/** A message the `wasm` module exports: its struct and full proto name. */
+export interface WasmMessage {
+  structName: string
+  fullName: string
+}
+
+/**
+ * JavaScript name part for a full proto name: every segment in
+ * PascalCase, so that "my_package.Transfer" is "MyPackageTransfer".
+ * wasm-bindgen exports share one namespace per crate, so the names carry
+ * the package.
+ */
+function jsName(fullName: string): string {
+  return fullName
+    .replace(/^\./, "")
+    .split(".")
+    .map(segment => toPascalCase(segment))
+    .join("")
+}
+
+/**
+ * Generate the `wasm` option's `wasm` module of a file, behind the output
+ * crate's `wasm` feature: wasm-bindgen exports that encode each public
+ * message from its proto3 JSON and decode it back, and encode the
+ * instruction data of each service rpc from the JSON of its request, so
+ * a browser dApp writes the exact bytes the program reads. Errors are
+ * thrown as JavaScript `Error`s through the runtime's `js_error`.
+ */
+export function genWasmModule(messages: WasmMessage[], services: ServiceDescriptor[]): string {
+  const lines: string[] = [
+    `/// wasm-bindgen exports encoding this file's messages and instructions from proto3 JSON.`,
+    `#[cfg(feature = "wasm")]`,
+    `pub mod wasm {`,
+    `    use super::*;`,
+    `    use wasm_bindgen::prelude::*;`
+  ]
+  for (const { structName, fullName } of messages) {
+    const name = toSnakeCase(structName)
+    const js = jsName(fullName)
+    lines.push(
+      ``,
+      `    /// The protobuf encoding of the \`${fullName}\` in \`json\`.`,
+      `    #[wasm_bindgen(js_name = "encode${js}")]`,
+      `    pub fn encode_${name}(json: &str) -> Result<Vec<u8>, JsError> {`,
+      `        Ok(${structName}::from_json(json).map_err(js_error)?.encode())`,
+      `    }`,
+      ``,
+      `    /// The \`${fullName}\` in \`data\`, as proto3 JSON.`,
+      `    #[wasm_bindgen(js_name = "decode${js}")]`,
+      `    pub fn decode_${name}(data: &[u8]) -> Result<String, JsError> {`,
+      `        Ok(${structName}::decode(data).map_err(js_error)?.to_json())`,
+      `    }`
+    )
+  }
+  for (const service of services) {
+    const enumName = `${service.name}Instruction`
+    for (const m of unaryMethods(service)) {
+      // Well-known request types, such as `Empty`, only have `from_json_value`
+      const request = resolveRustType(11, m.inputType)
+      const fnName = `encode_${toSnakeCase(service.name)}_${toSnakeCase(m.name)}_instruction`
+      lines.push(
+        ``,
+        `    /// \`${enumName}::${m.name}\` data for the request in \`json\`.`,
+        `    #[wasm_bindgen(js_name = "encode${jsName(service.fullName)}${m.name}Instruction")]`,
+        `    pub fn ${fnName}(json: &str) -> Result<Vec<u8>, JsError> {`,
+        `        let request = ${request}::from_json_value(&parse_json(json).map_err(js_error)?).map_err(js_error)?;`,
+        `        Ok(${enumName}::${m.name}(request).encode())`,
+        `    }`
+      )
+    }
+  }
+  lines.push(`}`)
+  return lines.join("\n")
+}
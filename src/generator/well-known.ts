@@ -0,0 +1,84 @@
+import { log } from "../util/logger.js"
+import type { FieldInfo } from "./field.js"
+
+/**
+ * google.protobuf wrapper messages and the scalar type of their `value`
+ * field (FieldDescriptorProto.Type).
+ */
+const WRAPPER_VALUE_TYPES: Record<string, number> = {
+  ".google.protobuf.DoubleValue": 1,
+  ".google.protobuf.FloatValue": 2,
+  ".google.protobuf.Int64Value": 3,
+  ".google.protobuf.UInt64Value": 4,
+  ".google.protobuf.Int32Value": 5,
+  ".google.protobuf.BoolValue": 8,
+  ".google.protobuf.StringValue": 9,
+  ".google.protobuf.BytesValue": 12,
+  ".google.protobuf.UInt32Value": 13
+}
+
+/** Small `Copy` well-known messages that views hold by value. */
+const VIEWED_BY_VALUE = new Set<string>([
+  ".google.protobuf.Timestamp",
+  ".google.protobuf.Duration",
+  ".google.protobuf.Empty"
+])
+
+/** Well-known messages implemented by hand in `protobuf_runtime.rs`. */
+const RUNTIME_MESSAGES = new Set<string>([
+  ...VIEWED_BY_VALUE,
+  ".google.protobuf.Any",
+  ...Object.keys(WRAPPER_VALUE_TYPES)
+])
+
+/**
+ * Files under google/protobuf/ are served by the runtime rather than
+ * generated.
+ */
+export function isWellKnownFile(protoFileName: string): boolean {
+  return protoFileName.startsWith("google/protobuf/")
+}
+
+/**
+ * Check if views decode this message type into its owned runtime struct
+ * (Timestamp, Duration, Empty) rather than a `<Type>Ref<'a>`.
+ */
+export function isViewedByValue(typeName?: string): boolean {
+  return typeName !== undefined && VIEWED_BY_VALUE.has(typeName)
+}
+
+/**
+ * Map a singular wrapper-typed field (`google.protobuf.UInt64Value` etc.)
+ * to a proto3 `optional` field of the wrapped scalar, i.e. `Option<u64>`,
+ * remembering the wrapper for its wire form. Repeated, map and oneof
+ * uses keep the runtime wrapper struct.
+ */
+export function withWellKnownType(field: FieldInfo): FieldInfo {
+  if (field.type !== 11 || !field.typeName?.startsWith(".google.protobuf.")) {
+    return field
+  }
+  if (!RUNTIME_MESSAGES.has(field.typeName)) {
+    log.warn(`Unsupported well-known type ${field.typeName} for field ${field.name}`)
+    return field
+  }
+
+  const singular = field.label !== 3 && (field.oneofIndex === undefined || field.proto3Optional)
+  if (!singular) return field
+  const unwrapped = unwrapWrapper(field)
+  return unwrapped === field ? field : { ...unwrapped, proto3Optional: true }
+}
+
+/**
+ * The wrapped scalar of a wrapper-typed field, wherever it appears;
+ * other fields are returned unchanged.
+ */
+export function unwrapWrapper(field: FieldInfo): FieldInfo {
+  const valueType = field.typeName ? WRAPPER_VALUE_TYPES[field.typeName] : undefined
+  if (field.type !== 11 || valueType === undefined) return field
+  return {
+    ...field,
+    type: valueType,
+    typeName: undefined,
+    wrapper: field.typeName!.slice(".google.protobuf.".length)
+  }
+}
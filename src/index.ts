@@ -1,14 +1,28 @@
 import { log } from "./util/logger.js"
 import { runPlugin } from "./plugin.js"
+import { runCompile } from "./compile/index.js"
+import { runBreaking } from "./breaking/index.js"
 
 /**
  * protoc-gen-solana entry point.
  *
  * protoc invokes this binary, writes a serialized CodeGeneratorRequest
  * to stdin, and reads a serialized CodeGeneratorResponse from stdout.
- * All diagnostic output goes to stderr via tracer.
+ * All diagnostic output goes to stderr via tracer. Run as
+ * `protoc-gen-solana compile ...`, it parses the .proto files itself
+ * instead (`runCompile`); as `protoc-gen-solana breaking ...`, it checks
+ * the .proto files against a baseline schema (`runBreaking`).
  */
 async function main(): Promise<void> {
+  if (process.argv[2] === "compile") {
+    runCompile(process.argv.slice(3))
+    return
+  }
+  if (process.argv[2] === "breaking") {
+    runBreaking(process.argv.slice(3))
+    return
+  }
+
   log.info("protoc-gen-solana starting")
 
   const stdin = await readStdin()
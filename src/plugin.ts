@@ -1,8 +1,47 @@
+import Fs from "node:fs"
+import Path from "node:path"
 import * as protobuf from "protobufjs"
 import { log, setLogLevel } from "./util/logger.js"
-import { protoFileToRsFile } from "./util/names.js"
-import { generateRsFile, generateRuntime } from "./generator/index.js"
-import type { MessageDescriptor, FieldInfo } from "./generator/index.js"
+import { protoFileToRsFile, setTypeRenames, toSnakeCase } from "./util/names.js"
+import {
+  generateRsFile,
+  generateRuntime,
+  generateTsFile,
+  generateTsRuntime,
+  genBundle,
+  genIdl,
+  genModFiles,
+  genTests,
+  cowMessages,
+  withBoundedVecs,
+  withRecursionBoxes,
+  DEFAULT_BOX_TYPE,
+  isRustKeyword,
+  isWellKnownFile,
+  packageModule,
+  withProto2Semantics,
+  withEditionFeatures,
+  fileFeatures,
+  mergeFeatures,
+  EDITION_2023,
+  EDITION_PROTO2,
+  UTF8_VALIDATION_NONE,
+  DISCRIMINATOR_HASHES,
+  MAX_DISCRIMINATOR_LEN
+} from "./generator/index.js"
+import type {
+  MessageDescriptor,
+  FieldInfo,
+  EnumDescriptor,
+  ExtensionDescriptor,
+  Features,
+  ServiceDescriptor,
+  InstructionTag,
+  Utf8Policy,
+  GenContext,
+  ModuleMapping,
+  OneofOverride
+} from "./generator/index.js"
 
 // ── Protobuf schema for the plugin protocol ───────────────────────────
 // Defined programmatically so the plugin is fully self-contained
@@ -27,12 +66,24 @@ const ResponseFile = new protobuf.Type("File")
 const CodeGeneratorResponse = new protobuf.Type("CodeGeneratorResponse")
   .add(new protobuf.Field("error", 1, "string", "optional"))
   .add(new protobuf.Field("supported_features", 2, "uint64", "optional"))
+  .add(new protobuf.Field("minimum_edition", 3, "int32", "optional"))
+  .add(new protobuf.Field("maximum_edition", 4, "int32", "optional"))
   .add(new protobuf.Field("file", 15, "File", "repeated"))
   .add(ResponseFile)
 
 // FileDescriptorProto and its nested types
+
+// Editions features, resolved by the plugin (enum values as int32)
+const FeatureSet = new protobuf.Type("FeatureSet")
+  .add(new protobuf.Field("field_presence", 1, "int32", "optional"))
+  .add(new protobuf.Field("enum_type", 2, "int32", "optional"))
+  .add(new protobuf.Field("repeated_field_encoding", 3, "int32", "optional"))
+  .add(new protobuf.Field("utf8_validation", 4, "int32", "optional"))
+  .add(new protobuf.Field("message_encoding", 5, "int32", "optional"))
+
 const FieldDescriptorProto = new protobuf.Type("FieldDescriptorProto")
   .add(new protobuf.Field("name", 1, "string", "optional"))
+  .add(new protobuf.Field("extendee", 2, "string", "optional"))
   .add(new protobuf.Field("number", 3, "int32", "optional"))
   .add(new protobuf.Field("label", 4, "int32", "optional"))
   .add(new protobuf.Field("type", 5, "int32", "optional"))
@@ -40,32 +91,162 @@ const FieldDescriptorProto = new protobuf.Type("FieldDescriptorProto")
   .add(new protobuf.Field("default_value", 7, "string", "optional"))
   .add(new protobuf.Field("oneof_index", 9, "int32", "optional"))
   .add(new protobuf.Field("json_name", 10, "string", "optional"))
+  .add(new protobuf.Field("options", 8, "FieldOptions", "optional"))
+  .add(new protobuf.Field("proto3_optional", 17, "bool", "optional"))
+
+const FieldOptions = new protobuf.Type("FieldOptions")
+  .add(new protobuf.Field("packed", 2, "bool", "optional"))
+  .add(new protobuf.Field("deprecated", 3, "bool", "optional"))
+  .add(new protobuf.Field("features", 21, "FeatureSet", "optional"))
+  // Extensions from proto/solana/options.proto
+  .add(new protobuf.Field("pubkey", 50001, "bool", "optional"))
+  .add(new protobuf.Field("fixed_len", 50002, "uint32", "optional"))
+  .add(new protobuf.Field("max_len", 50003, "uint32", "optional"))
+  .add(new protobuf.Field("max_count", 50004, "uint32", "optional"))
+  .add(new protobuf.Field("min", 50005, "int64", "optional"))
+  .add(new protobuf.Field("max", 50006, "int64", "optional"))
+  .add(new protobuf.Field("min_len", 50007, "uint32", "optional"))
+  .add(new protobuf.Field("prefix", 50008, "string", "optional"))
+  .add(new protobuf.Field("suffix", 50009, "string", "optional"))
+  .add(new protobuf.Field("non_default", 50010, "bool", "optional"))
+  .add(new protobuf.Field("required", 50011, "bool", "optional"))
+  .add(new protobuf.Field("utf8", 50012, "string", "optional"))
+  .add(new protobuf.Field("bounded_vec", 50013, "bool", "optional"))
+
+const OneofOptions = new protobuf.Type("OneofOptions")
+  .add(new protobuf.Field("features", 1, "FeatureSet", "optional"))
+
+const OneofDescriptorProto = new protobuf.Type("OneofDescriptorProto")
+  .add(new protobuf.Field("name", 1, "string", "optional"))
+  .add(new protobuf.Field("options", 2, "OneofOptions", "optional"))
+  .add(OneofOptions)
 
 const MessageOptions = new protobuf.Type("MessageOptions")
+  .add(new protobuf.Field("message_set_wire_format", 1, "bool", "optional"))
+  .add(new protobuf.Field("deprecated", 3, "bool", "optional"))
   .add(new protobuf.Field("map_entry", 7, "bool", "optional"))
+  .add(new protobuf.Field("features", 12, "FeatureSet", "optional"))
+  // Extensions from proto/solana/options.proto
+  .add(new protobuf.Field("event", 51001, "bool", "optional"))
+  .add(new protobuf.Field("seeds", 51002, "string", "optional"))
+  .add(new protobuf.Field("version", 51003, "uint32", "optional"))
+  .add(new protobuf.Field("discriminator", 51004, "bool", "optional"))
+  .add(new protobuf.Field("derive", 51005, "string", "optional"))
+  .add(new protobuf.Field("attr", 51006, "string", "repeated"))
+
+const EnumValueOptions = new protobuf.Type("EnumValueOptions")
+  .add(new protobuf.Field("deprecated", 1, "bool", "optional"))
+
+const EnumValueDescriptorProto = new protobuf.Type("EnumValueDescriptorProto")
+  .add(new protobuf.Field("name", 1, "string", "optional"))
+  .add(new protobuf.Field("number", 2, "int32", "optional"))
+  .add(new protobuf.Field("options", 3, "EnumValueOptions", "optional"))
+  .add(EnumValueOptions)
+
+const EnumOptions = new protobuf.Type("EnumOptions")
+  .add(new protobuf.Field("deprecated", 3, "bool", "optional"))
+
+// Inclusive of `end`, unlike a message's ReservedRange
+const EnumReservedRange = new protobuf.Type("EnumReservedRange")
+  .add(new protobuf.Field("start", 1, "int32", "optional"))
+  .add(new protobuf.Field("end", 2, "int32", "optional"))
+
+const EnumDescriptorProto = new protobuf.Type("EnumDescriptorProto")
+  .add(new protobuf.Field("name", 1, "string", "optional"))
+  .add(new protobuf.Field("value", 2, "EnumValueDescriptorProto", "repeated"))
+  .add(new protobuf.Field("options", 3, "EnumOptions", "optional"))
+  .add(new protobuf.Field("reserved_range", 4, "EnumReservedRange", "repeated"))
+  .add(new protobuf.Field("reserved_name", 5, "string", "repeated"))
+  .add(EnumValueDescriptorProto)
+  .add(EnumOptions)
+  .add(EnumReservedRange)
+
+const ExtensionRange = new protobuf.Type("ExtensionRange")
+  .add(new protobuf.Field("start", 1, "int32", "optional"))
+  .add(new protobuf.Field("end", 2, "int32", "optional"))
+
+const ReservedRange = new protobuf.Type("ReservedRange")
+  .add(new protobuf.Field("start", 1, "int32", "optional"))
+  .add(new protobuf.Field("end", 2, "int32", "optional"))
 
 const DescriptorProto = new protobuf.Type("DescriptorProto")
   .add(new protobuf.Field("name", 1, "string", "optional"))
   .add(new protobuf.Field("field", 2, "FieldDescriptorProto", "repeated"))
   .add(new protobuf.Field("nested_type", 3, "DescriptorProto", "repeated"))
+  .add(new protobuf.Field("enum_type", 4, "EnumDescriptorProto", "repeated"))
+  .add(new protobuf.Field("extension_range", 5, "ExtensionRange", "repeated"))
+  .add(new protobuf.Field("extension", 6, "FieldDescriptorProto", "repeated"))
   .add(new protobuf.Field("options", 7, "MessageOptions", "optional"))
+  .add(new protobuf.Field("oneof_decl", 8, "OneofDescriptorProto", "repeated"))
+  .add(new protobuf.Field("reserved_range", 9, "ReservedRange", "repeated"))
+  .add(new protobuf.Field("reserved_name", 10, "string", "repeated"))
   .add(FieldDescriptorProto)
+  .add(FieldOptions)
   .add(MessageOptions)
+  .add(OneofDescriptorProto)
+  .add(ExtensionRange)
+  .add(ReservedRange)
+
+const MethodOptions = new protobuf.Type("MethodOptions")
+  // Extension from proto/solana/options.proto
+  .add(new protobuf.Field("accounts", 52001, "string", "optional"))
+
+const MethodDescriptorProto = new protobuf.Type("MethodDescriptorProto")
+  .add(new protobuf.Field("name", 1, "string", "optional"))
+  .add(new protobuf.Field("input_type", 2, "string", "optional"))
+  .add(new protobuf.Field("output_type", 3, "string", "optional"))
+  .add(new protobuf.Field("options", 4, "MethodOptions", "optional"))
+  .add(new protobuf.Field("client_streaming", 5, "bool", "optional"))
+  .add(new protobuf.Field("server_streaming", 6, "bool", "optional"))
+  .add(MethodOptions)
+
+const ServiceDescriptorProto = new protobuf.Type("ServiceDescriptorProto")
+  .add(new protobuf.Field("name", 1, "string", "optional"))
+  .add(new protobuf.Field("method", 2, "MethodDescriptorProto", "repeated"))
+  .add(MethodDescriptorProto)
+
+const Location = new protobuf.Type("Location")
+  .add(new protobuf.Field("path", 1, "int32", "repeated"))
+  .add(new protobuf.Field("leading_comments", 3, "string", "optional"))
+  .add(new protobuf.Field("trailing_comments", 4, "string", "optional"))
+
+const SourceCodeInfo = new protobuf.Type("SourceCodeInfo")
+  .add(new protobuf.Field("location", 1, "Location", "repeated"))
+  .add(Location)
+
+const FileOptions = new protobuf.Type("FileOptions")
+  .add(new protobuf.Field("features", 50, "FeatureSet", "optional"))
 
 const FileDescriptorProto = new protobuf.Type("FileDescriptorProto")
   .add(new protobuf.Field("name", 1, "string", "optional"))
   .add(new protobuf.Field("package", 2, "string", "optional"))
   .add(new protobuf.Field("dependency", 3, "string", "repeated"))
   .add(new protobuf.Field("message_type", 4, "DescriptorProto", "repeated"))
+  .add(new protobuf.Field("enum_type", 5, "EnumDescriptorProto", "repeated"))
+  .add(new protobuf.Field("service", 6, "ServiceDescriptorProto", "repeated"))
+  .add(new protobuf.Field("extension", 7, "DescriptorProto.FieldDescriptorProto", "repeated"))
+  .add(new protobuf.Field("options", 8, "FileOptions", "optional"))
+  .add(new protobuf.Field("source_code_info", 9, "SourceCodeInfo", "optional"))
   .add(new protobuf.Field("syntax", 12, "string", "optional"))
+  .add(new protobuf.Field("edition", 14, "int32", "optional"))
+  .add(FileOptions)
+  .add(SourceCodeInfo)
   .add(DescriptorProto)
+  .add(EnumDescriptorProto)
+  .add(ServiceDescriptorProto)
+
+// google.protobuf.FileDescriptorSet, for `compile --descriptor_set_in` and `breaking`
+const FileDescriptorSet = new protobuf.Type("FileDescriptorSet")
+  .add(new protobuf.Field("file", 1, "FileDescriptorProto", "repeated"))
 
 // Wire types into namespaces
 const googlePb = new protobuf.Namespace("google")
 const protobufNs = new protobuf.Namespace("protobuf")
 const compilerNs = new protobuf.Namespace("compiler")
 
+protobufNs.add(FeatureSet)
 protobufNs.add(FileDescriptorProto)
+protobufNs.add(FileDescriptorSet)
 compilerNs.add(CodeGeneratorRequest)
 compilerNs.add(CodeGeneratorResponse)
 protobufNs.add(compilerNs)
@@ -75,6 +256,39 @@ pluginRoot.add(googlePb)
 // Resolve all type references
 pluginRoot.resolveAll()
 
+/** The options messages, by name, that custom options extend. */
+const OPTIONS_TYPES: Record<string, protobuf.Type> = {
+  FileOptions,
+  MessageOptions,
+  FieldOptions,
+  OneofOptions,
+  EnumOptions,
+  EnumValueOptions,
+  MethodOptions
+}
+
+/**
+ * The field the schema above decodes a custom option (an extension of
+ * `google.protobuf.<optionsType>` numbered `number`) into, or `undefined`
+ * if the plugin does not read it.
+ */
+export function customOptionName(optionsType: string, number: number): string | undefined {
+  if (number < 1000) return undefined
+  return OPTIONS_TYPES[optionsType]?.fieldsById[number]?.name
+}
+
+/** The files of a serialized FileDescriptorSet (`protoc --descriptor_set_out`). */
+export function readDescriptorSet(data: Buffer): any[] {
+  const SetType = pluginRoot.lookupType("google.protobuf.FileDescriptorSet")
+  return (SetType.decode(data) as any).file ?? []
+}
+
+/** `files` as a serialized FileDescriptorSet, which `readDescriptorSet` reads back. */
+export function writeDescriptorSet(files: any[]): Buffer {
+  const SetType = pluginRoot.lookupType("google.protobuf.FileDescriptorSet")
+  return Buffer.from(SetType.encode(SetType.fromObject({ file: files })).finish())
+}
+
 // ── Plugin entry ──────────────────────────────────────────────────────
 
 export interface PluginResult {
@@ -98,6 +312,15 @@ export function runPlugin(stdin: Buffer): Buffer {
   return encodeResponse(result)
 }
 
+/**
+ * Generate from a CodeGeneratorRequest built without protoc (the
+ * `compile` command), the same way as from one protoc sends.
+ */
+export function generate(request: { file_to_generate: string[]; parameter?: string; proto_file: any[] }): PluginResult {
+  const ReqType = pluginRoot.lookupType("google.protobuf.compiler.CodeGeneratorRequest")
+  return processRequest(Buffer.from(ReqType.encode(ReqType.fromObject(request)).finish()))
+}
+
 /**
  * Decode CodeGeneratorRequest, walk descriptors, produce output files.
  */
@@ -115,6 +338,9 @@ function processRequest(stdin: Buffer): PluginResult {
 
   const filesToGenerate = new Set<string>(request.file_to_generate ?? [])
   const protoFiles: any[] = request.proto_file ?? []
+  const overrides = parseOverrides(params.overrides)
+  const checkDir = parseCheck(params.check)
+  setTypeRenames(typeRenames(overrides, protoFiles))
 
   log.info(
     "Processing %d proto file(s), generating for %d",
@@ -122,35 +348,189 @@ function processRequest(stdin: Buffer): PluginResult {
     filesToGenerate.size
   )
 
+  // Enums and messages from every file (including imports) so field
+  // types resolve
+  const ctx: GenContext = {
+    enums: new Map(),
+    messages: new Map(),
+    refViews: isFlagSet(params.ref_views),
+    cowStructs: new Set(),
+    anchor: isFlagSet(params.anchor),
+    unknownFields: isFlagSet(params.unknown_fields),
+    rejectFloats: isFlagSet(params.reject_floats),
+    noStd: isFlagSet(params.no_std),
+    serde: isFlagSet(params.serde),
+    prost: isFlagSet(params.prost),
+    reflect: isFlagSet(params.reflect),
+    client: isFlagSet(params.client),
+    wasm: isFlagSet(params.wasm),
+    pod: isFlagSet(params.pod),
+    omitDeprecated: isFlagSet(params.omit_deprecated),
+    derives: parseDerives(params.derive, "the derive option"),
+    instructionTag: parseInstructionTag(params.instruction_tag),
+    utf8: parseUtf8Policy(params.utf8 ?? "strict", "utf8"),
+    discriminatorHash: parseDiscriminatorHash(params.discriminator_hash),
+    discriminatorLen: parseDiscriminatorLen(params.discriminator_len),
+    boxType: parseBoxType(params.box_type),
+    boundedVec: isFlagSet(params.bounded_vec),
+    tests: isFlagSet(params.tests),
+    idl: isFlagSet(params.idl),
+    idlAddress: params.idl_address,
+    moduleMap: parseModuleMap(params.module_map),
+    moduleTree: isFlagSet(params.module_tree),
+    bundle: parseBundle(params.bundle),
+    runtimePath: parseRuntimePath(params.runtime_path),
+    fileModules: new Map(),
+    typeModules: new Map(),
+    tsOut: params.ts_out?.replace(/\/+$/, "") || undefined,
+    tsFiles: new Map()
+  }
+  for (const protoFile of protoFiles) {
+    // Where each type lands, for paths and imports between files
+    const rsFileName = rsFileFor(protoFile, ctx)
+    const fileModule = rsFileName.replace(/\.rs$/, "").split("/")
+    const tsFileName = rsFileName.replace(/\.rs$/, ".ts")
+    ctx.fileModules.set(protoFile.name ?? "", fileModule)
+    for (const e of extractEnums(protoFile, protoFile.package ?? "", overrides)) {
+      ctx.enums.set(`.${e.fullName}`, e)
+      ctx.typeModules.set(`.${e.fullName}`, fileModule)
+      ctx.tsFiles.set(`.${e.fullName}`, tsFileName)
+    }
+    for (const m of extractMessages(protoFile, protoFile.package ?? "", overrides, ctx)) {
+      ctx.messages.set(`.${m.fullName}`, m)
+      ctx.typeModules.set(`.${m.fullName}`, fileModule)
+      ctx.tsFiles.set(`.${m.fullName}`, tsFileName)
+    }
+  }
+  if (isFlagSet(params.cow_structs)) ctx.cowStructs = cowMessages(ctx.messages)
+
   const files: Array<{ name: string; content: string }> = []
+  // Declared by their file's `#[path]` test module, so kept out of the module tree
+  const testFiles: Array<{ name: string; content: string }> = []
 
-  // Always emit the runtime library
-  files.push({
-    name: "protobuf_runtime.rs",
-    content: generateRuntime()
-  })
+  // Emit the runtime library, unless the generated code imports it from elsewhere
+  if (!ctx.runtimePath) {
+    files.push({
+      name: "protobuf_runtime.rs",
+      content: generateRuntime()
+    })
+  }
+  if (ctx.tsOut) {
+    files.push({ name: `${ctx.tsOut}/protobuf_runtime.ts`, content: generateTsRuntime() })
+  }
 
   // Process each requested proto file
   for (const protoFile of protoFiles) {
     const fileName: string = protoFile.name ?? ""
     if (!filesToGenerate.has(fileName)) continue
+    if (isWellKnownFile(fileName)) {
+      log.info("%s is provided by the runtime, skipping", fileName)
+      continue
+    }
 
     log.info("Generating for %s", fileName)
+    checkFieldNumbers(protoFile)
+    warnUnsupported(protoFile, overrides, ctx.utf8)
 
-    const messages = extractMessages(protoFile, protoFile.package ?? "")
-    if (messages.length === 0) {
-      log.info("No messages in %s, skipping", fileName)
+    const messages = extractMessages(protoFile, protoFile.package ?? "", overrides, ctx)
+    const services = extractServices(protoFile, protoFile.package ?? "")
+    const enums = extractEnums(protoFile, protoFile.package ?? "", overrides)
+    const extensions = extractExtensions(protoFile, protoFile.package ?? "", ctx)
+    if (messages.length === 0 && services.length === 0 && enums.length === 0 && extensions.length === 0) {
+      log.info("No messages, enums, services or extensions in %s, skipping", fileName)
       continue
     }
 
-    const rsFileName = protoFileToRsFile(fileName, protoFile.package ?? "")
-    const rsContent = generateRsFile(messages, fileName)
+    const rsFileName = rsFileFor(protoFile, ctx)
+    const tests = ctx.tests ? genTests(messages, fileName, rsFileName, ctx) : undefined
+    const rsContent = generateRsFile(messages, fileName, ctx, services, enums, extensions)
+
+    files.push({ name: rsFileName, content: tests ? rsContent + tests.declaration : rsContent })
+    if (tests?.file) testFiles.push(tests.file)
+    if (ctx.idl) {
+      for (const service of services) {
+        const idl = genIdl(service, messages, fileName, ctx)
+        if (!idl) continue
+        const idlFileName = rsFileName.replace(/[^/]*\.rs$/, `${toSnakeCase(service.name)}.idl.json`)
+        files.push({ name: idlFileName, content: idl })
+        log.info("Generated %s", idlFileName)
+      }
+    }
+    if (ctx.tsOut) {
+      const tsFileName = rsFileName.replace(/\.rs$/, ".ts")
+      const tsContent = generateTsFile(messages, fileName, tsFileName, ctx, services, enums)
+      files.push({ name: `${ctx.tsOut}/${tsFileName}`, content: tsContent })
+      log.info("Generated %s/%s", ctx.tsOut, tsFileName)
+    }
+    log.info(
+      "Generated %s (%d messages, %d enums, %d services)",
+      rsFileName,
+      messages.length,
+      enums.length,
+      services.length
+    )
+  }
+
+  let output = files
+  if (ctx.bundle) {
+    // The runtime and codecs become one file; IDLs and TypeScript stay as they are
+    const rsFiles = files.filter(f => f.name.endsWith(".rs"))
+    const others = files.filter(f => !f.name.endsWith(".rs"))
+    output = [{ name: ctx.bundle, content: genBundle(rsFiles) }, ...others]
+  } else if (ctx.moduleTree) {
+    output.push(...genModFiles(files.map(f => f.name).filter(name => name.endsWith(".rs"))))
+  }
+  for (const file of testFiles) {
+    if (output.some(f => f.name === file.name)) {
+      throw new Error(`The tests of ${file.name.replace(/_tests\.rs$/, ".rs")} would replace the generated ${file.name}`)
+    }
+  }
+  output = [...output, ...testFiles]
+
+  return checkDir ? checkOutput(output, checkDir) : { files: output }
+}
 
-    files.push({ name: rsFileName, content: rsContent })
-    log.info("Generated %s (%d messages)", rsFileName, messages.length)
+/**
+ * The `check` option: compare the output with the files under `dir` (the
+ * `--solana_out` directory, relative to protoc's working directory) and
+ * fail, writing nothing, if any would change.
+ */
+function checkOutput(files: Array<{ name: string; content: string }>, dir: string): PluginResult {
+  const changed = files
+    .filter(f => {
+      const path = Path.join(dir, f.name)
+      return !Fs.existsSync(path) || Fs.readFileSync(path, "utf-8") !== f.content
+    })
+    .map(f => f.name)
+    .sort()
+  if (changed.length > 0) {
+    throw new Error(`Generated code in ${dir} is out of date; regenerating would change ${changed.join(", ")}`)
   }
+  log.info("%d generated files in %s are up to date", files.length, dir)
+  return { files: [] }
+}
+
+/** Output `.rs` path of a proto file: its package's module directory and snake_case name. */
+function rsFileFor(protoFile: any, ctx: GenContext): string {
+  const module = packageModule(protoFile.package ?? "", ctx.moduleMap)
+  return protoFileToRsFile(protoFile.name ?? "", module.join("."))
+}
+
+/**
+ * A file's comments by `SourceCodeInfo` path, joined as in `path`:
+ * `4.0.2.1` is the second field of the first message.
+ */
+type Docs = Map<string, string>
 
-  return { files }
+/** The leading and trailing comments of each commented element, separated by a blank line. */
+function sourceDocs(protoFile: any): Docs {
+  const docs: Docs = new Map()
+  for (const location of protoFile.source_code_info?.location ?? []) {
+    const comments = [location.leading_comments, location.trailing_comments].filter(c => c?.trim())
+    if (comments.length === 0) continue
+    docs.set((location.path ?? []).join("."), comments.map(c => c.replace(/\n$/, "")).join("\n\n"))
+  }
+  return docs
 }
 
 /**
@@ -158,37 +538,330 @@ function processRequest(stdin: Buffer): PluginResult {
  */
 function extractMessages(
   protoFile: any,
-  packageName: string
+  packageName: string,
+  overrides: Overrides,
+  ctx: GenContext
 ): MessageDescriptor[] {
   const result: MessageDescriptor[] = []
   const messageTypes: any[] = protoFile.message_type ?? []
+  const syntax = fileSyntax(protoFile)
+  const docs = sourceDocs(protoFile)
+
+  messageTypes.forEach((msg, i) => {
+    const converted = convertDescriptor(msg, packageName, syntax, overrides, docs, [4, i])
+    result.push(converted, ...groupBodies(converted))
+  })
+
+  return withRecursionBoxes(ctx.boundedVec ? result.map(withBoundedVecs) : result, ctx.boxType)
+}
+
+/**
+ * The message types of `msg`'s group fields, and of theirs in turn.
+ * Nested types are otherwise generated only as map entries, but a group
+ * always declares its type in place, so these are lifted to the top.
+ */
+function groupBodies(msg: MessageDescriptor): MessageDescriptor[] {
+  return msg.nestedMessages
+    .filter(nested => msg.fields.some(f => f.type === 10 && f.typeName === `.${nested.fullName}`))
+    .flatMap(nested => [nested, ...groupBodies(nested)])
+}
+
+/** Largest field number a key can carry, `2^29 - 1`, as the runtime's `MAX_FIELD_NUMBER`. */
+const MAX_FIELD_NUMBER = 536870911
+
+/** Field numbers protobuf keeps for its implementation. */
+const IMPLEMENTATION_RESERVED: [number, number] = [19000, 19999]
+
+/**
+ * Fail on field and extension numbers protoc rejects: outside 1 to
+ * `MAX_FIELD_NUMBER`, in 19000 to 19999, or in one of the message's
+ * `reserved` ranges. A descriptor set from another tool reaches the
+ * plugin unchecked, and its keys would not decode.
+ */
+function checkFieldNumbers(protoFile: any): void {
+  const check = (f: any, where: string, reserved: any[]) => {
+    const number: number = f.number ?? 0
+    let problem: string | undefined
+    if (number < 1 || number > MAX_FIELD_NUMBER) problem = `is outside 1 to ${MAX_FIELD_NUMBER}`
+    else if (number >= IMPLEMENTATION_RESERVED[0] && number <= IMPLEMENTATION_RESERVED[1]) {
+      problem = `is in ${IMPLEMENTATION_RESERVED[0]} to ${IMPLEMENTATION_RESERVED[1]}, reserved for the protobuf implementation`
+    } else if (reserved.some((r: any) => number >= (r.start ?? 0) && number < (r.end ?? 0))) {
+      problem = "is reserved by its message"
+    }
+    if (problem) throw new Error(`Field number ${number} of ${where} ${problem}`)
+  }
+  const walk = (messages: any[], parentFqn: string) => {
+    for (const msg of messages ?? []) {
+      const fqn = parentFqn ? `${parentFqn}.${msg.name}` : msg.name
+      for (const f of msg.field ?? []) check(f, `${fqn}.${f.name}`, msg.reserved_range ?? [])
+      for (const ext of msg.extension ?? []) check(ext, `extension ${fqn}.${ext.name}`, [])
+      walk(msg.nested_type, fqn)
+    }
+  }
+  const pkg = protoFile.package ?? ""
+  for (const ext of protoFile.extension ?? []) check(ext, `extension ${pkg ? `${pkg}.` : ""}${ext.name}`, [])
+  walk(protoFile.message_type, pkg)
+}
+
+/**
+ * Warn about messages using the MessageSet wire format, which generates
+ * nothing special, about editions string fields with
+ * `utf8_validation = NONE` that `utf8` and `(solana.utf8)` leave strict,
+ * and about fields and oneofs named after a Rust keyword with no
+ * `overrides` rename.
+ */
+function warnUnsupported(protoFile: any, overrides: Overrides, utf8: Utf8Policy): void {
+  const walk = (messages: any[], parentFqn: string, parentSyntax: Syntax) => {
+    for (const msg of messages ?? []) {
+      const fqn = parentFqn ? `${parentFqn}.${msg.name}` : msg.name
+      const syntax = scopeSyntax(parentSyntax, msg.options)
+      if (msg.options?.message_set_wire_format === true) {
+        log.warn(`${fqn} uses the MessageSet wire format, which is not supported; it is coded as a plain message`)
+      }
+      for (const f of msg.field ?? []) {
+        const features = scopeSyntax(fieldScope(f, msg, syntax), f.options)
+        const strict = (f.options?.utf8 || utf8) === "strict"
+        if (typeof features === "object" && features.utf8Validation === UTF8_VALIDATION_NONE && f.type === 9 && strict) {
+          log.warn(`${fqn}.${f.name} has utf8_validation = NONE; set (solana.utf8) to skip the UTF-8 check on decode`)
+        }
+      }
+      const names: string[] = [...(msg.field ?? []), ...(msg.oneof_decl ?? [])].map((d: any) => d.name ?? "")
+      for (const name of names) {
+        if (isRustKeyword(toSnakeCase(name)) && !overrides.get(`${fqn}.${name}`)?.rename) {
+          log.warn(`The Rust name of ${fqn}.${name} is a keyword and will not compile; rename it in the overrides file`)
+        }
+      }
+      walk(msg.nested_type, fqn, syntax)
+    }
+  }
+  walk(protoFile.message_type, protoFile.package ?? "", fileSyntax(protoFile))
+}
+
+/**
+ * Collect the extensions a proto file declares, at file level and nested
+ * in messages. Extensions of `google.protobuf` descriptor options are
+ * custom options, which protoc applies, so they are left out, as are
+ * those of messages outside the request, whose type cannot be named.
+ */
+function extractExtensions(protoFile: any, packageName: string, ctx: GenContext): ExtensionDescriptor[] {
+  const result: ExtensionDescriptor[] = []
+
+  const walk = (extensions: any[], messages: any[], parentFqn: string, syntax: Syntax, scope?: string) => {
+    for (const ext of extensions ?? []) {
+      const fullName = parentFqn ? `${parentFqn}.${ext.name}` : ext.name
+      const extendee: string = ext.extendee ?? ""
+      if (extendee.startsWith(".google.protobuf.")) {
+        log.debug(`${fullName} extends ${extendee}, a custom option; skipping`)
+        continue
+      }
+      if (!ctx.messages.has(extendee)) {
+        log.warn(`Skipping extension ${fullName}: ${extendee.slice(1)} is not in the request`)
+        continue
+      }
+      result.push({ fullName, extendee, scope, field: convertField(ext, syntax) })
+    }
+    for (const msg of messages ?? []) {
+      const fqn = parentFqn ? `${parentFqn}.${msg.name}` : msg.name
+      walk(msg.extension, msg.nested_type, fqn, scopeSyntax(syntax, msg.options), fqn)
+    }
+  }
+
+  walk(protoFile.extension, protoFile.message_type, packageName, fileSyntax(protoFile))
+  return result
+}
+
+/**
+ * Collect the service descriptors of a proto file.
+ */
+function extractServices(protoFile: any, packageName: string): ServiceDescriptor[] {
+  const docs = sourceDocs(protoFile)
+  return (protoFile.service ?? []).map((s: any, i: number) => {
+    const name: string = s.name ?? ""
+    return {
+      name,
+      fullName: packageName ? `${packageName}.${name}` : name,
+      doc: docs.get(`6.${i}`),
+      methods: (s.method ?? []).map((m: any, j: number) => ({
+        name: m.name ?? "",
+        doc: docs.get(`6.${i}.2.${j}`),
+        inputType: m.input_type ?? "",
+        outputType: m.output_type ?? "",
+        clientStreaming: m.client_streaming === true,
+        serverStreaming: m.server_streaming === true,
+        accounts: m.options?.accounts || undefined
+      }))
+    }
+  })
+}
+
+/**
+ * Collect top-level and nested enum descriptors from a proto file.
+ */
+function extractEnums(protoFile: any, packageName: string, overrides: Overrides): EnumDescriptor[] {
+  const result: EnumDescriptor[] = []
+  const docs = sourceDocs(protoFile)
 
-  for (const msg of messageTypes) {
-    result.push(convertDescriptor(msg, packageName))
+  // `enumPath` / `messagePath`: the SourceCodeInfo paths of the enums and nested messages
+  const walk = (enums: any[], messages: any[], parentFqn: string, enumPath: number[], messagePath: number[]) => {
+    for (const [i, e] of (enums ?? []).entries()) {
+      const name: string = e.name ?? ""
+      const fullName = parentFqn ? `${parentFqn}.${name}` : name
+      const path = [...enumPath, i].join(".")
+      result.push({
+        name,
+        fullName,
+        values: (e.value ?? []).map((v: any, j: number) => ({
+          name: v.name ?? "",
+          number: v.number ?? 0,
+          deprecated: v.options?.deprecated === true,
+          doc: docs.get(`${path}.2.${j}`)
+        })),
+        deprecated: e.options?.deprecated === true,
+        doc: docs.get(path),
+        visibility: overrides.get(fullName)?.visibility
+      })
+    }
+    for (const [i, msg] of (messages ?? []).entries()) {
+      const fqn = parentFqn ? `${parentFqn}.${msg.name}` : msg.name
+      const path = [...messagePath, i]
+      walk(msg.enum_type, msg.nested_type, fqn, [...path, 4], [...path, 3])
+    }
   }
 
+  walk(protoFile.enum_type, protoFile.message_type, packageName, [5], [4])
   return result
 }
 
-function convertDescriptor(desc: any, parentFqn: string): MessageDescriptor {
+/**
+ * Where a field's semantics come from: its file's syntax, or in an
+ * editions file the features in scope.
+ */
+type Syntax = "proto2" | "proto3" | Features
+
+/** protoc leaves `syntax` empty for proto2 files. */
+function fileSyntax(protoFile: any): Syntax {
+  const syntax: string = protoFile.syntax || "proto2"
+  if (syntax === "proto2" || syntax === "proto3") return syntax
+  if (syntax === "editions") return fileFeatures(protoFile.edition, protoFile.options?.features, protoFile.name ?? "")
+  throw new Error(`Unknown syntax "${syntax}" in ${protoFile.name}`)
+}
+
+/** The syntax inside an element with `options`: in an editions file, its features override its parent's. */
+function scopeSyntax(syntax: Syntax, options: any): Syntax {
+  return typeof syntax === "string" ? syntax : mergeFeatures(syntax, options?.features)
+}
+
+function convertDescriptor(
+  desc: any,
+  parentFqn: string,
+  parentSyntax: Syntax,
+  overrides: Overrides,
+  docs: Docs,
+  path: number[]
+): MessageDescriptor {
   const name: string = desc.name ?? ""
   const fullName = parentFqn ? `${parentFqn}.${name}` : name
   const isMapEntry: boolean = desc.options?.map_entry === true
+  const isEvent: boolean = desc.options?.event === true
+  const seeds: string | undefined = desc.options?.seeds || undefined
+  const version: number | undefined = desc.options?.version || undefined
+  const hasDiscriminator: boolean = desc.options?.discriminator === true
+  const derives = parseDerives(desc.options?.derive, fullName)
+  const attributes = parseAttributes(desc.options?.attr ?? [], fullName)
+  const syntax = scopeSyntax(parentSyntax, desc.options)
+
+  const mapEntries = new Set<string>(
+    (desc.nested_type ?? []).filter((n: any) => n.options?.map_entry === true).map((n: any) => `.${fullName}.${n.name}`)
+  )
+  const fields: FieldInfo[] = (desc.field ?? []).map((f: any, i: number) => {
+    const inMap = isMapEntry || (f.label === 3 && mapEntries.has(f.type_name))
+    const field = { ...convertField(f, fieldScope(f, desc, syntax), inMap), doc: docs.get([...path, 2, i].join(".")) }
+    const override = overrides.get(`${fullName}.${field.name}`)
+    return override ? { ...field, rustName: override.rename, visibility: override.visibility } : field
+  })
+
+  const nestedMessages: MessageDescriptor[] = (desc.nested_type ?? []).map(
+    (nested: any, i: number) => convertDescriptor(nested, fullName, syntax, overrides, docs, [...path, 3, i])
+  )
+
+  const oneofs: string[] = (desc.oneof_decl ?? []).map((o: any) => o.name ?? "")
+  const oneofOverrides = oneofs.map((oneof): OneofOverride => {
+    const override = overrides.get(`${fullName}.${oneof}`)
+    return { rustName: override?.rename, visibility: override?.visibility }
+  })
+  const extensionRanges: Array<[number, number]> = (desc.extension_range ?? []).map(
+    (r: any): [number, number] => [r.start ?? 0, r.end ?? 0]
+  )
+
+  return {
+    name,
+    fullName,
+    fields,
+    nestedMessages,
+    isMapEntry,
+    isEvent,
+    seeds,
+    version,
+    hasDiscriminator,
+    oneofs,
+    oneofOverrides,
+    extensionRanges,
+    derives,
+    attributes,
+    deprecated: desc.options?.deprecated === true,
+    doc: docs.get(path.join(".")),
+    visibility: overrides.get(fullName)?.visibility
+  }
+}
+
+/** The syntax a field of `msg` (whose own is `syntax`) is in: a oneof member is in its oneof's scope. */
+function fieldScope(f: any, msg: any, syntax: Syntax): Syntax {
+  if (f.oneof_index === undefined) return syntax
+  return scopeSyntax(syntax, msg.oneof_decl?.[f.oneof_index]?.options)
+}
 
-  const fields: FieldInfo[] = (desc.field ?? []).map((f: any) => ({
+function convertField(f: any, parentSyntax: Syntax, inMap = false): FieldInfo {
+  const field: FieldInfo = {
     name: f.name ?? "",
     number: f.number ?? 0,
     type: f.type ?? 0,
     typeName: f.type_name,
     label: f.label ?? 1,
-    oneofIndex: f.oneof_index
-  }))
-
-  const nestedMessages: MessageDescriptor[] = (desc.nested_type ?? []).map(
-    (nested: any) => convertDescriptor(nested, fullName)
-  )
+    jsonName: f.json_name,
+    oneofIndex: f.oneof_index,
+    proto3Optional: f.proto3_optional === true,
+    packed: f.options?.packed,
+    pubkey: f.options?.pubkey === true,
+    boundedVec: f.options?.bounded_vec === true,
+    // 0 is not a usable length or count, so it also stands for "unset"
+    fixedLen: f.options?.fixed_len || undefined,
+    maxLen: f.options?.max_len || undefined,
+    maxCount: f.options?.max_count || undefined,
+    min: int64Option(f.options, "min"),
+    max: int64Option(f.options, "max"),
+    minLen: f.options?.min_len || undefined,
+    prefix: f.options?.prefix || undefined,
+    suffix: f.options?.suffix || undefined,
+    nonDefault: f.options?.non_default === true,
+    required: f.options?.required === true,
+    utf8: f.options?.utf8 ? parseUtf8Policy(f.options.utf8, `(solana.utf8) on field ${f.name}`) : undefined,
+    deprecated: f.options?.deprecated === true,
+    defaultValue: f.default_value || undefined
+  }
+  const syntax = scopeSyntax(parentSyntax, f.options)
+  if (syntax === "proto2") return withProto2Semantics(field)
+  if (syntax === "proto3") return field
+  return withEditionFeatures(field, syntax, inMap)
+}
 
-  return { name, fullName, fields, nestedMessages, isMapEntry }
+/**
+ * An int64 option as a bigint, or `undefined` if unset: unlike the
+ * other options, 0 is a meaningful value. protobufjs decodes int64 as a
+ * `Long` (or a number), and leaves unset fields on the prototype.
+ */
+function int64Option(options: any, name: string): bigint | undefined {
+  if (!options || !Object.prototype.hasOwnProperty.call(options, name)) return undefined
+  return BigInt(options[name].toString())
 }
 
 /**
@@ -200,7 +873,9 @@ function encodeResponse(result: PluginResult): Buffer {
   )
 
   const payload: any = {
-    supported_features: 1, // FEATURE_PROTO3_OPTIONAL
+    supported_features: 3, // FEATURE_PROTO3_OPTIONAL | FEATURE_SUPPORTS_EDITIONS
+    minimum_edition: EDITION_PROTO2,
+    maximum_edition: EDITION_2023,
     file: result.files.map(f => ({
       name: f.name,
       content: f.content
@@ -216,9 +891,10 @@ function encodeResponse(result: PluginResult): Buffer {
 }
 
 /**
- * Parse "key=value,key2=value2" parameter string.
+ * Parse "key=value,key2=value2" parameter string. A bare key is shorthand
+ * for `key=true`.
  */
-function parseParams(param: string): Record<string, string> {
+export function parseParams(param: string): Record<string, string> {
   const result: Record<string, string> = {}
   if (!param) return result
 
@@ -226,7 +902,217 @@ function parseParams(param: string): Record<string, string> {
     const eq = pair.indexOf("=")
     if (eq > 0) {
       result[pair.slice(0, eq).trim()] = pair.slice(eq + 1).trim()
+    } else if (pair.trim()) {
+      result[pair.trim()] = "true"
+    }
+  }
+  return result
+}
+
+/** The `instruction_tag` parameter: "u8" (the default) or "discriminator". */
+export function parseInstructionTag(value: string | undefined): InstructionTag {
+  if (value === undefined || value === "u8") return "u8"
+  if (value === "discriminator") return value
+  throw new Error(`Unknown instruction_tag "${value}" (expected "u8" or "discriminator")`)
+}
+
+/** The `utf8` parameter or a field's `(solana.utf8)` option: "strict", "lossy" or "bytes". */
+function parseUtf8Policy(value: string, what: string): Utf8Policy {
+  if (value === "strict" || value === "lossy" || value === "bytes") return value
+  throw new Error(`Unknown ${what} "${value}" (expected "strict", "lossy" or "bytes")`)
+}
+
+/** The `discriminator_hash` parameter: a hash from `DISCRIMINATOR_HASHES`, `sha256` by default. */
+function parseDiscriminatorHash(value: string | undefined): string {
+  if (value === undefined) return "sha256"
+  if (DISCRIMINATOR_HASHES.includes(value)) return value
+  throw new Error(`Unknown discriminator_hash "${value}" (expected one of ${DISCRIMINATOR_HASHES.join(", ")})`)
+}
+
+/** The `discriminator_len` parameter: 1 to `MAX_DISCRIMINATOR_LEN` bytes, 8 by default. */
+function parseDiscriminatorLen(value: string | undefined): number {
+  if (value === undefined) return 8
+  const len = Number(value)
+  if (!Number.isInteger(len) || len < 1 || len > MAX_DISCRIMINATOR_LEN) {
+    throw new Error(`Invalid discriminator_len "${value}" (expected 1 to ${MAX_DISCRIMINATOR_LEN})`)
+  }
+  return len
+}
+
+/**
+ * The `module_map` parameter: `;`-separated `<proto package>=<rust::path>`
+ * entries. An entry covers the package and its sub-packages; an empty
+ * path places them at the output root.
+ */
+function parseModuleMap(value: string | undefined): ModuleMapping[] {
+  if (!value) return []
+  return value
+    .split(";")
+    .map(entry => entry.trim())
+    .filter(entry => entry !== "")
+    .map(entry => {
+      const eq = entry.indexOf("=")
+      const pkg = eq > 0 ? entry.slice(0, eq).trim() : ""
+      const path = entry.slice(eq + 1).trim()
+      const module = path === "" ? [] : path.split("::")
+      if (!pkg || !module.every(part => /^[A-Za-z_][A-Za-z0-9_]*$/.test(part))) {
+        throw new Error(`Invalid module_map entry "${entry}" (expected <proto package>=<rust::module::path>)`)
+      }
+      return { package: pkg, module }
+    })
+}
+
+/** The `check` parameter: the directory holding the committed output, which it must name. */
+function parseCheck(value: string | undefined): string | undefined {
+  if (value === undefined || value === "false" || value === "0") return undefined
+  if (isFlagSet(value) || value === "") {
+    throw new Error(`The check option needs the directory to compare with, e.g. check=src/generated`)
+  }
+  return value
+}
+
+/** The `bundle` parameter: a bare `bundle` writes `bundle.rs`, otherwise the given `.rs` file. */
+function parseBundle(value: string | undefined): string | undefined {
+  if (value === undefined || value === "false" || value === "0") return undefined
+  if (isFlagSet(value)) return "bundle.rs"
+  if (!value.endsWith(".rs")) throw new Error(`Invalid bundle file "${value}" (expected a .rs file name)`)
+  return value
+}
+
+/**
+ * The `runtime_path` parameter: the Rust path of a module or crate
+ * providing the runtime, such as `crate::proto_rt` or `wire_proto_runtime`.
+ */
+function parseRuntimePath(value: string | undefined): string | undefined {
+  if (!value) return undefined
+  const parts = value.replace(/^::/, "").split("::")
+  if (!parts.every(part => /^[A-Za-z_][A-Za-z0-9_]*$/.test(part))) {
+    throw new Error(`Invalid runtime_path "${value}" (expected a Rust path such as crate::proto_rt)`)
+  }
+  return value
+}
+
+/**
+ * The `box_type` parameter: the Rust path of the smart pointer holding
+ * fields that close a cycle of messages, `Box` by default.
+ */
+function parseBoxType(value: string | undefined): string {
+  if (!value) return DEFAULT_BOX_TYPE
+  if (!/^(::)?[A-Za-z_][A-Za-z0-9_]*(::[A-Za-z_][A-Za-z0-9_]*)*$/.test(value)) {
+    throw new Error(`Invalid box_type "${value}" (expected a Rust path such as Box or alloc::boxed::Box)`)
+  }
+  return value
+}
+
+/**
+ * A list of traits to derive, separated by `+` (in the `derive` parameter,
+ * where `,` separates parameters) or `,`: `Eq+Hash`, `"Eq, arbitrary::Arbitrary"`.
+ */
+function parseDerives(value: string | undefined, where: string): string[] {
+  if (!value) return []
+  const traits = value.split(/[+,]/).map(t => t.trim()).filter(t => t)
+  for (const trait of traits) {
+    if (!/^(::)?[A-Za-z_][A-Za-z0-9_]*(::[A-Za-z_][A-Za-z0-9_]*)*$/.test(trait)) {
+      throw new Error(`Invalid derive "${trait}" in ${where} (expected a trait path such as Hash or arbitrary::Arbitrary)`)
+    }
+  }
+  return traits
+}
+
+/** `(solana.attr)` values: each a whole outer attribute, `#[...]`. */
+function parseAttributes(values: string[], where: string): string[] {
+  return values.map(value => {
+    const attr = value.trim()
+    if (!/^#\[[\s\S]*\]$/.test(attr)) {
+      throw new Error(`Invalid attribute "${value}" on ${where} (expected an attribute such as #[repr(C)])`)
+    }
+    return attr
+  })
+}
+
+/** An `overrides` file entry: the Rust name and visibility of a message, enum, field or oneof. */
+interface Override {
+  rename?: string
+  visibility?: string
+}
+
+/** `overrides` file entries, keyed by full proto name without the leading dot. */
+type Overrides = Map<string, Override>
+
+const VISIBILITIES = ["pub", "pub(crate)", "pub(super)"]
+
+/**
+ * The `overrides` parameter: a JSON file, relative to protoc's working
+ * directory, mapping full proto names of messages, enums, fields
+ * (`pkg.Message.field`) and oneofs to a Rust `rename` and/or
+ * `visibility`, for .proto files that cannot be edited.
+ */
+function parseOverrides(path: string | undefined): Overrides {
+  const result: Overrides = new Map()
+  if (!path) return result
+  let json: any
+  try {
+    json = JSON.parse(Fs.readFileSync(path, "utf-8"))
+  } catch (err: any) {
+    throw new Error(`Invalid overrides file ${path}: ${err.message}`)
+  }
+  if (typeof json !== "object" || json === null || Array.isArray(json)) {
+    throw new Error(`Invalid overrides file ${path}: expected an object keyed by proto name`)
+  }
+  for (const [name, entry] of Object.entries<any>(json)) {
+    const where = `${name} in ${path}`
+    if (typeof entry !== "object" || entry === null || Array.isArray(entry)) {
+      throw new Error(`Invalid override for ${where} (expected an object with rename and/or visibility)`)
+    }
+    for (const key of Object.keys(entry)) {
+      if (key !== "rename" && key !== "visibility") {
+        throw new Error(`Unknown key "${key}" in the override for ${where} (expected rename or visibility)`)
+      }
     }
+    const { rename, visibility } = entry
+    const validName = typeof rename === "string" && /^[A-Za-z_][A-Za-z0-9_]*$/.test(rename) && !isRustKeyword(rename)
+    if (rename !== undefined && !validName) {
+      throw new Error(`Invalid rename "${rename}" for ${where} (expected a Rust identifier that is not a keyword)`)
+    }
+    if (visibility !== undefined && !VISIBILITIES.includes(visibility)) {
+      throw new Error(`Invalid visibility "${visibility}" for ${where} (expected ${VISIBILITIES.join(", ")})`)
+    }
+    result.set(name.replace(/^\./, ""), { rename, visibility })
   }
   return result
 }
+
+/**
+ * The message and enum renames among `overrides`, for `protoNameToRust`.
+ * Warns about entries naming nothing in the request.
+ */
+function typeRenames(overrides: Overrides, protoFiles: any[]): Map<string, string> {
+  const types = new Set<string>()
+  const members = new Set<string>()
+  const walk = (messages: any[], enums: any[], parentFqn: string) => {
+    for (const e of enums ?? []) types.add(parentFqn ? `${parentFqn}.${e.name}` : e.name)
+    for (const msg of messages ?? []) {
+      const fqn = parentFqn ? `${parentFqn}.${msg.name}` : msg.name
+      types.add(fqn)
+      for (const f of msg.field ?? []) members.add(`${fqn}.${f.name}`)
+      for (const o of msg.oneof_decl ?? []) members.add(`${fqn}.${o.name}`)
+      walk(msg.nested_type, msg.enum_type, fqn)
+    }
+  }
+  for (const protoFile of protoFiles) walk(protoFile.message_type, protoFile.enum_type, protoFile.package ?? "")
+
+  const renames = new Map<string, string>()
+  for (const [name, override] of overrides) {
+    if (types.has(name)) {
+      if (override.rename) renames.set(name, override.rename)
+    } else if (!members.has(name)) {
+      log.warn(`The override for ${name} matches no message, enum, field or oneof`)
+    }
+  }
+  return renames
+}
+
+/** True for an enabled boolean parameter ("true" or "1"). */
+function isFlagSet(value: string | undefined): boolean {
+  return value === "true" || value === "1"
+}
@@ -1,8 +1,19 @@
+/** Type renames from the `overrides` file, keyed by full name without the leading dot. */
+let typeRenames = new Map<string, string>()
+
+/** Set the message and enum renames `protoNameToRust` applies for this run. */
+export function setTypeRenames(renames: Map<string, string>): void {
+  typeRenames = renames
+}
+
 /**
- * Convert a protobuf fully-qualified name to a Rust-safe identifier (PascalCase struct name).
+ * Convert a protobuf fully-qualified name to a Rust-safe identifier (PascalCase struct name),
+ * or the type's rename from the `overrides` file.
  * e.g. "my_package.MyMessage" → "MyMessage"
  */
 export function protoNameToRust(fqn: string): string {
+  const renamed = typeRenames.get(fqn.replace(/^\./, ""))
+  if (renamed) return renamed
   const parts = fqn.split(".")
   return parts[parts.length - 1]
 }
@@ -36,3 +47,23 @@ export function protoFileToRsFile(protoFile: string, packageName?: string): stri
   const dir = packageName.split(".").join("/")
   return `${dir}/${rsBasename}`
 }
+
+/**
+ * proto3 JSON field name, matching protoc's default `json_name`.
+ * e.g. "avatar_hash" → "avatarHash"
+ */
+export function toJsonName(name: string): string {
+  return name.replace(/_+([a-zA-Z0-9])/g, (_, c: string) => c.toUpperCase())
+}
+
+/**
+ * PascalCase identifier for a snake_case proto name, used for enum variants.
+ * e.g. "transfer_sol" → "TransferSol"
+ */
+export function toPascalCase(name: string): string {
+  return name
+    .split("_")
+    .filter(Boolean)
+    .map(part => part[0].toUpperCase() + part.slice(1))
+    .join("")
+}
@@ -0,0 +1,47 @@
+//! Snapshot tests of the generated code, in `tests/golden.rs`: each
+//! fixture in `tests/protos/` is generated with `protoc-gen-solana
+//! compile` and compared with its snapshot in `snapshots/`, and every
+//! snapshot is compiled against the runtime crate with trybuild.
+//! `tests/behavior.rs` compiles the snapshots in as modules and tests
+//! the generated code's behaviour.
+//!
+//! ```text
+//! cargo test --manifest-path tests/golden/Cargo.toml
+//! UPDATE_GOLDEN=1 cargo test --manifest-path tests/golden/Cargo.toml
+//! ```
+//!
+//! The second form rewrites the snapshots that changed; review the diff
+//! before committing it.
+//!
+//! A typestate builder's `build()` exists only once every required
+//! field is set. `behavior.rs` tests what it builds; not being able to
+//! call it earlier is a compile error, so it is checked here instead:
+//!
+//! ```
+//! # mod generated {
+//! #     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots/required.rs"));
+//! # }
+//! # use generated::example::required::{Withdraw, WithdrawTarget};
+//! let target = WithdrawTarget::builder().with_vault([2; 32]).build();
+//! let msg = Withdraw::builder().with_authority([1; 32]).with_amount(5).with_slot(0).with_fee(0).with_target(target);
+//! assert_eq!(msg.build().amount, 5);
+//! ```
+//!
+//! ```compile_fail,E0599
+//! # mod generated {
+//! #     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots/required.rs"));
+//! # }
+//! # use generated::example::required::{Withdraw, WithdrawTarget};
+//! // No target
+//! let msg = Withdraw::builder().with_authority([1; 32]).with_amount(5).with_slot(0).with_fee(0);
+//! msg.build();
+//! ```
+//!
+//! ```compile_fail,E0599
+//! # mod generated {
+//! #     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots/required.rs"));
+//! # }
+//! # use generated::example::required::WithdrawTarget;
+//! // No vault
+//! WithdrawTarget::builder().with_index(1).build();
+//! ```
@@ -0,0 +1,1284 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod any {
+        // Auto-generated by protoc-gen-solana from any.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: google.protobuf.Any payloads dispatched through AnyMessage
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Envelope {
+            pub source_chain: u64,
+            pub payload: Any,
+            pub attachments: Vec<Any>,
+        }
+
+        impl Envelope {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Envelope {
+                source_chain: 0,
+                payload: Any::DEFAULT,
+                attachments: Vec::new(),
+            };
+        }
+
+        impl Default for Envelope {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Envelope {
+            pub const SOURCE_CHAIN_FIELD_NUMBER: u32 = 1;
+            pub const PAYLOAD_FIELD_NUMBER: u32 = 2;
+            pub const ATTACHMENTS_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Envelope.source_chain" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Envelope.payload" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Envelope.attachments" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: attachments
+                for elem in self.attachments.iter().rev() {
+                    w.write_message(3, elem);
+                }
+
+                // field 2: payload
+                w.write_message(2, &self.payload);
+
+                // field 1: source_chain
+                if self.source_chain != 0 {
+                    w.write_varint(self.source_chain);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.source_chain != 0 {
+                    len += key_len(1) + varint_len(self.source_chain);
+                }
+                len += key_len(2) + bytes_len(self.payload.encoded_len());
+                for elem in &self.attachments {
+                    len += key_len(3) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.source_chain != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.source_chain)?;
+                }
+                w.write_message(2, &self.payload)?;
+                for elem in &self.attachments {
+                    w.write_message(3, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.source_chain != 0 {
+                    len += key_len(1) + varint_len(self.source_chain);
+                }
+                len += key_len(2) + bytes_len(sizes.record(&self.payload));
+                for elem in &self.attachments {
+                    len += key_len(3) + bytes_len(sizes.record(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.source_chain != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.source_chain)?;
+                }
+                w.write_message_sized(2, &self.payload, sizes)?;
+                for elem in &self.attachments {
+                    w.write_message_sized(3, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.source_chain = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.payload.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.attachments.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.attachments.push(Any::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    3 => Some(self.attachments.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Envelope {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Envelope::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Envelope::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Envelope::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Envelope::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Envelope::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Envelope::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Envelope::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Envelope {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Envelope::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Envelope> for Vec<u8> {
+            fn from(msg: Envelope) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Envelope {
+            const FULL_NAME: &'static str = "example.Envelope";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct EnvelopeBuilder {
+            inner: Envelope,
+        }
+
+        impl Envelope {
+            pub fn builder() -> EnvelopeBuilder {
+                EnvelopeBuilder::default()
+            }
+        }
+
+        impl EnvelopeBuilder {
+            pub fn with_source_chain(mut self, value: u64) -> Self {
+                self.inner.source_chain = value;
+                self
+            }
+
+            pub fn with_payload(mut self, value: Any) -> Self {
+                self.inner.payload = value;
+                self
+            }
+
+            pub fn with_attachments(mut self, value: Vec<Any>) -> Self {
+                self.inner.attachments = value;
+                self
+            }
+
+            pub fn add_attachments(mut self, value: Any) -> Self {
+                self.inner.attachments.push(value);
+                self
+            }
+
+            pub fn build(self) -> Envelope {
+                self.inner
+            }
+        }
+
+        impl Envelope {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.source_chain != 0 {
+                    out.write_scalar("source_chain", self.source_chain);
+                }
+                if self.payload != Any::default() {
+                    out.begin_message("payload");
+                    self.payload.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.attachments {
+                    out.begin_message("attachments");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Envelope {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Envelope {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.source_chain != 0 {
+                    out.key("sourceChain");
+                    out.write_u64(self.source_chain);
+                }
+                if self.payload != Any::default() {
+                    out.key("payload");
+                    self.payload.write_json(out);
+                }
+                if !self.attachments.is_empty() {
+                    out.key("attachments");
+                    out.begin_array();
+                    for elem in &self.attachments {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "sourceChain" | "source_chain" => msg.source_chain = json_to_u64(value)?,
+                        "payload" => msg.payload = Any::from_json_value(value)?,
+                        "attachments" => {
+                            for elem in json_array(value)? {
+                                msg.attachments.push(Any::from_json_value(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct EnvelopeRef<'a> {
+            raw: &'a [u8],
+            pub source_chain: u64,
+            pub payload: Option<AnyRef<'a>>,
+            pub attachments: RepeatedRef<'a, AnyRef<'a>>,
+        }
+
+        impl<'a> EnvelopeRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = EnvelopeRef {
+                    raw: data,
+                    source_chain: 0,
+                    payload: None,
+                    attachments: RepeatedRef::new(data, 26, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((AnyRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.source_chain = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.payload = Some(AnyRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    26 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Envelope::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Envelope, DecodeError> {
+                Envelope::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Deposit {
+            pub account: Vec<u8>,
+            pub amount: u64,
+        }
+
+        impl Deposit {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Deposit {
+                account: Vec::new(),
+                amount: 0,
+            };
+        }
+
+        impl Default for Deposit {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Deposit {
+            pub const ACCOUNT_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Deposit.account" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Deposit.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: account
+                if !self.account.is_empty() {
+                    w.write_bytes(&self.account);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.account.is_empty() {
+                    len += key_len(1) + bytes_len(self.account.len());
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.account.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_bytes(&self.account)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.account = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Deposit {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Deposit::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Deposit::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Deposit::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Deposit::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Deposit::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Deposit::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Deposit::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Deposit {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Deposit::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Deposit> for Vec<u8> {
+            fn from(msg: Deposit) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Deposit {
+            const FULL_NAME: &'static str = "example.Deposit";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct DepositBuilder {
+            inner: Deposit,
+        }
+
+        impl Deposit {
+            pub fn builder() -> DepositBuilder {
+                DepositBuilder::default()
+            }
+        }
+
+        impl DepositBuilder {
+            pub fn with_account(mut self, value: Vec<u8>) -> Self {
+                self.inner.account = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> Deposit {
+                self.inner
+            }
+        }
+
+        impl Deposit {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.account.is_empty() {
+                    out.write_bytes("account", &self.account);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Deposit {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Deposit {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.account.is_empty() {
+                    out.key("account");
+                    out.write_bytes(&self.account);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "account" => msg.account = json_to_bytes(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct DepositRef<'a> {
+            raw: &'a [u8],
+            pub account: &'a [u8],
+            pub amount: u64,
+        }
+
+        impl<'a> DepositRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = DepositRef {
+                    raw: data,
+                    account: &[],
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.account = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Deposit::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Deposit, DecodeError> {
+                Deposit::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Withdrawal {
+            pub account: Vec<u8>,
+            pub amount: u64,
+            pub memo: String,
+        }
+
+        impl Withdrawal {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Withdrawal {
+                account: Vec::new(),
+                amount: 0,
+                memo: String::new(),
+            };
+        }
+
+        impl Default for Withdrawal {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Withdrawal {
+            pub const ACCOUNT_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+            pub const MEMO_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Withdrawal.account" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Withdrawal.amount" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Withdrawal.memo" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: memo
+                if !self.memo.is_empty() {
+                    w.write_string(&self.memo);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: account
+                if !self.account.is_empty() {
+                    w.write_bytes(&self.account);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.account.is_empty() {
+                    len += key_len(1) + bytes_len(self.account.len());
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    len += key_len(3) + bytes_len(self.memo.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.account.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_bytes(&self.account)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                if !self.memo.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.memo)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.account = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.memo = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Withdrawal {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Withdrawal::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Withdrawal::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Withdrawal::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Withdrawal::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Withdrawal::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Withdrawal::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Withdrawal::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Withdrawal {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Withdrawal::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Withdrawal> for Vec<u8> {
+            fn from(msg: Withdrawal) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Withdrawal {
+            const FULL_NAME: &'static str = "example.Withdrawal";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawalBuilder {
+            inner: Withdrawal,
+        }
+
+        impl Withdrawal {
+            pub fn builder() -> WithdrawalBuilder {
+                WithdrawalBuilder::default()
+            }
+        }
+
+        impl WithdrawalBuilder {
+            pub fn with_account(mut self, value: Vec<u8>) -> Self {
+                self.inner.account = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn with_memo(mut self, value: String) -> Self {
+                self.inner.memo = value;
+                self
+            }
+
+            pub fn build(self) -> Withdrawal {
+                self.inner
+            }
+        }
+
+        impl Withdrawal {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.account.is_empty() {
+                    out.write_bytes("account", &self.account);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+                if !self.memo.is_empty() {
+                    out.write_str("memo", &self.memo);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Withdrawal {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Withdrawal {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.account.is_empty() {
+                    out.key("account");
+                    out.write_bytes(&self.account);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    out.key("memo");
+                    out.write_str(&self.memo);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "account" => msg.account = json_to_bytes(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        "memo" => msg.memo = json_to_string(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawalRef<'a> {
+            raw: &'a [u8],
+            pub account: &'a [u8],
+            pub amount: u64,
+            pub memo: &'a str,
+        }
+
+        impl<'a> WithdrawalRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawalRef {
+                    raw: data,
+                    account: &[],
+                    amount: 0,
+                    memo: "",
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.account = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.memo = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Withdrawal::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Withdrawal, DecodeError> {
+                Withdrawal::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Envelope(Envelope),
+            Deposit(Deposit),
+            Withdrawal(Withdrawal),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Envelope::FULL_NAME => AnyMessage::Envelope(Envelope::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Deposit::FULL_NAME => AnyMessage::Deposit(Deposit::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Withdrawal::FULL_NAME => AnyMessage::Withdrawal(Withdrawal::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Envelope(msg) => Any::pack(msg),
+                    AnyMessage::Deposit(msg) => Any::pack(msg),
+                    AnyMessage::Withdrawal(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,2240 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod bounded_vec {
+        // Auto-generated by protoc-gen-solana from bounded_vec.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// `example.RosterRole`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum RosterRole {
+            Member = 0,
+            Admin = 1,
+        }
+
+        impl RosterRole {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: RosterRole = RosterRole::Member;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    RosterRole::Member => "ROSTER_ROLE_MEMBER",
+                    RosterRole::Admin => "ROSTER_ROLE_ADMIN",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "ROSTER_ROLE_MEMBER" => Some(RosterRole::Member),
+                    "ROSTER_ROLE_ADMIN" => Some(RosterRole::Admin),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for RosterRole {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<RosterRole> for i32 {
+            fn from(value: RosterRole) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for RosterRole {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(RosterRole::Member),
+                    1 => Ok(RosterRole::Admin),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// Exercises: (solana.bounded_vec) repeated fields of every kind, held
+        /// inline in a BoundedVec of their (solana.max_count)
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Roster {
+            pub slots: BoundedVec<u32, 4>,
+            pub deltas: BoundedVec<i64, 4>,
+            pub stamps: BoundedVec<u64, 2>,
+            pub unpacked: BoundedVec<i32, 3>,
+            pub roles: BoundedVec<i32, 2>,
+            pub members: BoundedVec<Pubkey, 2>,
+            pub blobs: BoundedVec<Vec<u8>, 2>,
+            pub names: BoundedVec<String, 2>,
+            pub seats: BoundedVec<Seat, 3>,
+            pub caps: BoundedVec<UInt64Value, 2>,
+            /// Ignored with a warning: there is no capacity to give it
+            pub history: Vec<u64>,
+            /// Without the option it stays a Vec
+            pub scores: Vec<u64>,
+        }
+
+        impl Roster {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Roster {
+                slots: BoundedVec::new(),
+                deltas: BoundedVec::new(),
+                stamps: BoundedVec::new(),
+                unpacked: BoundedVec::new(),
+                roles: BoundedVec::new(),
+                members: BoundedVec::new(),
+                blobs: BoundedVec::new(),
+                names: BoundedVec::new(),
+                seats: BoundedVec::new(),
+                caps: BoundedVec::new(),
+                history: Vec::new(),
+                scores: Vec::new(),
+            };
+        }
+
+        impl Default for Roster {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Roster {
+            pub const SLOTS_FIELD_NUMBER: u32 = 1;
+            pub const DELTAS_FIELD_NUMBER: u32 = 2;
+            pub const STAMPS_FIELD_NUMBER: u32 = 3;
+            pub const UNPACKED_FIELD_NUMBER: u32 = 4;
+            pub const ROLES_FIELD_NUMBER: u32 = 5;
+            pub const MEMBERS_FIELD_NUMBER: u32 = 6;
+            pub const BLOBS_FIELD_NUMBER: u32 = 7;
+            pub const NAMES_FIELD_NUMBER: u32 = 8;
+            pub const SEATS_FIELD_NUMBER: u32 = 9;
+            pub const CAPS_FIELD_NUMBER: u32 = 10;
+            pub const HISTORY_FIELD_NUMBER: u32 = 11;
+            pub const SCORES_FIELD_NUMBER: u32 = 12;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: true, name: "Roster.slots" },
+                FieldWireType { number: 2, wire_type: 0, packed: true, name: "Roster.deltas" },
+                FieldWireType { number: 3, wire_type: 1, packed: true, name: "Roster.stamps" },
+                FieldWireType { number: 4, wire_type: 0, packed: true, name: "Roster.unpacked" },
+                FieldWireType { number: 5, wire_type: 0, packed: true, name: "Roster.roles" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Roster.members" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "Roster.blobs" },
+                FieldWireType { number: 8, wire_type: 2, packed: false, name: "Roster.names" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Roster.seats" },
+                FieldWireType { number: 10, wire_type: 2, packed: false, name: "Roster.caps" },
+                FieldWireType { number: 11, wire_type: 0, packed: true, name: "Roster.history" },
+                FieldWireType { number: 12, wire_type: 0, packed: true, name: "Roster.scores" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 12: scores
+                if !self.scores.is_empty() {
+                    w.write_nested(12, |w| {
+                        for elem in self.scores.iter().rev() {
+                            w.write_varint(*elem);
+                        }
+                    });
+                }
+
+                // field 11: history
+                if !self.history.is_empty() {
+                    w.write_nested(11, |w| {
+                        for elem in self.history.iter().rev() {
+                            w.write_varint(*elem);
+                        }
+                    });
+                }
+
+                // field 10: caps
+                for elem in self.caps.iter().rev() {
+                    w.write_message(10, elem);
+                }
+
+                // field 9: seats
+                for elem in self.seats.iter().rev() {
+                    w.write_message(9, elem);
+                }
+
+                // field 8: names
+                for elem in self.names.iter().rev() {
+                    w.write_string(elem);
+                    w.write_key(0x42);
+                }
+
+                // field 7: blobs
+                for elem in self.blobs.iter().rev() {
+                    w.write_bytes(elem);
+                    w.write_key(0x3a);
+                }
+
+                // field 6: members
+                for elem in self.members.iter().rev() {
+                    w.write_pubkey(elem);
+                    w.write_key(0x32);
+                }
+
+                // field 5: roles
+                if !self.roles.is_empty() {
+                    w.write_nested(5, |w| {
+                        for elem in self.roles.iter().rev() {
+                            w.write_int32(*elem);
+                        }
+                    });
+                }
+
+                // field 4: unpacked
+                for elem in self.unpacked.iter().rev() {
+                    w.write_int32(*elem);
+                    w.write_key(0x20);
+                }
+
+                // field 3: stamps
+                if !self.stamps.is_empty() {
+                    w.write_nested(3, |w| {
+                        for elem in self.stamps.iter().rev() {
+                            w.write_fixed64(*elem);
+                        }
+                    });
+                }
+
+                // field 2: deltas
+                if !self.deltas.is_empty() {
+                    w.write_nested(2, |w| {
+                        for elem in self.deltas.iter().rev() {
+                            w.write_zigzag64(*elem);
+                        }
+                    });
+                }
+
+                // field 1: slots
+                if !self.slots.is_empty() {
+                    w.write_nested(1, |w| {
+                        for elem in self.slots.iter().rev() {
+                            w.write_varint(*elem as u64);
+                        }
+                    });
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.slots.is_empty() {
+                    len += key_len(1) + bytes_len(self.slots.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                if !self.deltas.is_empty() {
+                    len += key_len(2) + bytes_len(self.deltas.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+                }
+                if !self.stamps.is_empty() {
+                    len += key_len(3) + bytes_len(self.stamps.len() * 8);
+                }
+                for elem in &self.unpacked {
+                    len += key_len(4) + int32_len(*elem);
+                }
+                if !self.roles.is_empty() {
+                    len += key_len(5) + bytes_len(self.roles.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+                }
+                len += self.members.len() * (key_len(6) + 33);
+                for elem in &self.blobs {
+                    len += key_len(7) + bytes_len(elem.len());
+                }
+                for elem in &self.names {
+                    len += key_len(8) + bytes_len(elem.len());
+                }
+                for elem in &self.seats {
+                    len += key_len(9) + bytes_len(elem.encoded_len());
+                }
+                for elem in &self.caps {
+                    len += key_len(10) + bytes_len(elem.encoded_len());
+                }
+                if !self.history.is_empty() {
+                    len += key_len(11) + bytes_len(self.history.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                if !self.scores.is_empty() {
+                    len += key_len(12) + bytes_len(self.scores.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.slots.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_varint(self.slots.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.slots {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                if !self.deltas.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_varint(self.deltas.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.deltas {
+                        w.write_zigzag64(*elem)?;
+                    }
+                }
+                if !self.stamps.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_varint((self.stamps.len() * 8) as u64)?;
+                    for elem in &self.stamps {
+                        w.write_fixed64(*elem)?;
+                    }
+                }
+                for elem in &self.unpacked {
+                    w.write_key(0x20)?;
+                    w.write_int32(*elem)?;
+                }
+                if !self.roles.is_empty() {
+                    w.write_key(0x2a)?;
+                    w.write_varint(self.roles.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.roles {
+                        w.write_int32(*elem)?;
+                    }
+                }
+                for elem in &self.members {
+                    w.write_key(0x32)?;
+                    w.write_pubkey(elem)?;
+                }
+                for elem in &self.blobs {
+                    w.write_key(0x3a)?;
+                    w.write_bytes(elem)?;
+                }
+                for elem in &self.names {
+                    w.write_key(0x42)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.seats {
+                    w.write_message(9, elem)?;
+                }
+                for elem in &self.caps {
+                    w.write_message(10, elem)?;
+                }
+                if !self.history.is_empty() {
+                    w.write_key(0x5a)?;
+                    w.write_varint(self.history.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.history {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                if !self.scores.is_empty() {
+                    w.write_key(0x62)?;
+                    w.write_varint(self.scores.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.scores {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if !self.slots.is_empty() {
+                    len += key_len(1) + bytes_len(self.slots.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                if !self.deltas.is_empty() {
+                    len += key_len(2) + bytes_len(self.deltas.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+                }
+                if !self.stamps.is_empty() {
+                    len += key_len(3) + bytes_len(self.stamps.len() * 8);
+                }
+                for elem in &self.unpacked {
+                    len += key_len(4) + int32_len(*elem);
+                }
+                if !self.roles.is_empty() {
+                    len += key_len(5) + bytes_len(self.roles.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+                }
+                len += self.members.len() * (key_len(6) + 33);
+                for elem in &self.blobs {
+                    len += key_len(7) + bytes_len(elem.len());
+                }
+                for elem in &self.names {
+                    len += key_len(8) + bytes_len(elem.len());
+                }
+                for elem in &self.seats {
+                    len += key_len(9) + bytes_len(sizes.record(elem));
+                }
+                for elem in &self.caps {
+                    len += key_len(10) + bytes_len(sizes.record(elem));
+                }
+                if !self.history.is_empty() {
+                    len += key_len(11) + bytes_len(self.history.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                if !self.scores.is_empty() {
+                    len += key_len(12) + bytes_len(self.scores.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if !self.slots.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_varint(self.slots.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.slots {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                if !self.deltas.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_varint(self.deltas.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.deltas {
+                        w.write_zigzag64(*elem)?;
+                    }
+                }
+                if !self.stamps.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_varint((self.stamps.len() * 8) as u64)?;
+                    for elem in &self.stamps {
+                        w.write_fixed64(*elem)?;
+                    }
+                }
+                for elem in &self.unpacked {
+                    w.write_key(0x20)?;
+                    w.write_int32(*elem)?;
+                }
+                if !self.roles.is_empty() {
+                    w.write_key(0x2a)?;
+                    w.write_varint(self.roles.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.roles {
+                        w.write_int32(*elem)?;
+                    }
+                }
+                for elem in &self.members {
+                    w.write_key(0x32)?;
+                    w.write_pubkey(elem)?;
+                }
+                for elem in &self.blobs {
+                    w.write_key(0x3a)?;
+                    w.write_bytes(elem)?;
+                }
+                for elem in &self.names {
+                    w.write_key(0x42)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.seats {
+                    w.write_message_sized(9, elem, sizes)?;
+                }
+                for elem in &self.caps {
+                    w.write_message_sized(10, elem, sizes)?;
+                }
+                if !self.history.is_empty() {
+                    w.write_key(0x5a)?;
+                    w.write_varint(self.history.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.history {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                if !self.scores.is_empty() {
+                    w.write_key(0x62)?;
+                    w.write_varint(self.scores.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.scores {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.slots.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.slots, v as u32, 1)?;
+                            pos = new_pos;
+                        }
+                        10 => {
+                            let new_pos = decode_packed_bounded(data, pos, &mut self.slots, 1, |data, pos| decode_varint(data, pos).map(|(v, pos)| (v as u32, pos)))?;
+                            if self.slots.len() > opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            pos = new_pos;
+                        }
+                        16 => {
+                            let (v, new_pos) = decode_zigzag64(data, pos)?;
+                            if self.deltas.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.deltas, v, 2)?;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let new_pos = decode_packed_bounded(data, pos, &mut self.deltas, 2, decode_zigzag64)?;
+                            if self.deltas.len() > opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            pos = new_pos;
+                        }
+                        25 => {
+                            let (v, new_pos) = decode_fixed64(data, pos)?;
+                            if self.stamps.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.stamps, v, 3)?;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let new_pos = decode_packed_bounded(data, pos, &mut self.stamps, 3, decode_fixed64)?;
+                            if self.stamps.len() > opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            pos = new_pos;
+                        }
+                        32 => {
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            if self.unpacked.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.unpacked, v, 4)?;
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let new_pos = decode_packed_bounded(data, pos, &mut self.unpacked, 4, decode_int32)?;
+                            if self.unpacked.len() > opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            pos = new_pos;
+                        }
+                        40 => {
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            if self.roles.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.roles, v, 5)?;
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let new_pos = decode_packed_bounded(data, pos, &mut self.roles, 5, decode_int32)?;
+                            if self.roles.len() > opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            pos = new_pos;
+                        }
+                        50 => {
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            if self.members.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.members, v, 6)?;
+                            pos = new_pos;
+                        }
+                        58 => {
+                            let (v, new_pos) = decode_bytes_bounded(data, pos, opts, 8, 7)?;
+                            if self.blobs.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.blobs, v, 7)?;
+                            pos = new_pos;
+                        }
+                        66 => {
+                            let (v, new_pos) = decode_string_bounded(data, pos, opts, 8, 8)?;
+                            if self.names.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.names, v, 8)?;
+                            pos = new_pos;
+                        }
+                        74 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.seats.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.seats, Seat::decode_with(sub, &opts.nested()?)?, 9)?;
+                            pos = new_pos;
+                        }
+                        82 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.caps.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.caps, UInt64Value::decode_with(sub, &opts.nested()?)?, 10)?;
+                            pos = new_pos;
+                        }
+                        88 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.history.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.history.push(v);
+                            pos = new_pos;
+                        }
+                        90 => {
+                            let new_pos = decode_packed_varint_with(data, pos, &mut self.history, opts)?;
+                            pos = new_pos;
+                        }
+                        96 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.scores.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            if self.scores.len() >= 4 {
+                                return Err(DecodeError::LimitExceeded { field: 12 });
+                            }
+                            self.scores.push(v);
+                            pos = new_pos;
+                        }
+                        98 => {
+                            let new_pos = decode_packed_varint_with(data, pos, &mut self.scores, opts)?;
+                            if self.scores.len() > 4 {
+                                return Err(DecodeError::LimitExceeded { field: 12 });
+                            }
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    1 => Some(self.slots.len()),
+                    2 => Some(self.deltas.len()),
+                    3 => Some(self.stamps.len()),
+                    4 => Some(self.unpacked.len()),
+                    5 => Some(self.roles.len()),
+                    6 => Some(self.members.len()),
+                    7 => Some(self.blobs.len()),
+                    8 => Some(self.names.len()),
+                    9 => Some(self.seats.len()),
+                    10 => Some(self.caps.len()),
+                    11 => Some(self.history.len()),
+                    12 => Some(self.scores.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Roster {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Roster::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Roster::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Roster::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Roster::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Roster::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Roster::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Roster::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Roster {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Roster::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Roster> for Vec<u8> {
+            fn from(msg: Roster) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Roster {
+            const FULL_NAME: &'static str = "example.Roster";
+        }
+
+        impl Roster {
+            /// Check the `(solana.*)` field rules, those of sub-messages
+            /// included; decoding does not. Returns the first rule broken.
+            pub fn validate(&self) -> Result<(), ValidationError> {
+                if self.slots.len() > 4 {
+                    return Err(ValidationError::new("slots", ValidationRule::MaxCount(4)));
+                }
+
+                if self.deltas.len() > 4 {
+                    return Err(ValidationError::new("deltas", ValidationRule::MaxCount(4)));
+                }
+
+                if self.stamps.len() > 2 {
+                    return Err(ValidationError::new("stamps", ValidationRule::MaxCount(2)));
+                }
+
+                if self.unpacked.len() > 3 {
+                    return Err(ValidationError::new("unpacked", ValidationRule::MaxCount(3)));
+                }
+
+                if self.roles.len() > 2 {
+                    return Err(ValidationError::new("roles", ValidationRule::MaxCount(2)));
+                }
+
+                if self.members.len() > 2 {
+                    return Err(ValidationError::new("members", ValidationRule::MaxCount(2)));
+                }
+
+                for (i, v) in self.blobs.iter().enumerate() {
+                    if v.len() > 8 {
+                        return Err(ValidationError::element("blobs", i, ValidationRule::MaxLen(8)));
+                    }
+                }
+                if self.blobs.len() > 2 {
+                    return Err(ValidationError::new("blobs", ValidationRule::MaxCount(2)));
+                }
+
+                for (i, v) in self.names.iter().enumerate() {
+                    if v.len() > 8 {
+                        return Err(ValidationError::element("names", i, ValidationRule::MaxLen(8)));
+                    }
+                }
+                if self.names.len() > 2 {
+                    return Err(ValidationError::new("names", ValidationRule::MaxCount(2)));
+                }
+
+                if self.seats.len() > 3 {
+                    return Err(ValidationError::new("seats", ValidationRule::MaxCount(3)));
+                }
+
+                if self.caps.len() > 2 {
+                    return Err(ValidationError::new("caps", ValidationRule::MaxCount(2)));
+                }
+
+                if self.scores.len() > 4 {
+                    return Err(ValidationError::new("scores", ValidationRule::MaxCount(4)));
+                }
+
+                Ok(())
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct RosterBuilder {
+            inner: Roster,
+        }
+
+        impl Roster {
+            pub fn builder() -> RosterBuilder {
+                RosterBuilder::default()
+            }
+        }
+
+        impl RosterBuilder {
+            pub fn with_slots(mut self, value: BoundedVec<u32, 4>) -> Self {
+                self.inner.slots = value;
+                self
+            }
+
+            pub fn add_slots(mut self, value: u32) -> Self {
+                self.inner.slots.push(value);
+                self
+            }
+
+            pub fn with_deltas(mut self, value: BoundedVec<i64, 4>) -> Self {
+                self.inner.deltas = value;
+                self
+            }
+
+            pub fn add_deltas(mut self, value: i64) -> Self {
+                self.inner.deltas.push(value);
+                self
+            }
+
+            pub fn with_stamps(mut self, value: BoundedVec<u64, 2>) -> Self {
+                self.inner.stamps = value;
+                self
+            }
+
+            pub fn add_stamps(mut self, value: u64) -> Self {
+                self.inner.stamps.push(value);
+                self
+            }
+
+            pub fn with_unpacked(mut self, value: BoundedVec<i32, 3>) -> Self {
+                self.inner.unpacked = value;
+                self
+            }
+
+            pub fn add_unpacked(mut self, value: i32) -> Self {
+                self.inner.unpacked.push(value);
+                self
+            }
+
+            pub fn with_roles(mut self, value: BoundedVec<i32, 2>) -> Self {
+                self.inner.roles = value;
+                self
+            }
+
+            pub fn add_roles(mut self, value: i32) -> Self {
+                self.inner.roles.push(value);
+                self
+            }
+
+            pub fn with_members(mut self, value: BoundedVec<Pubkey, 2>) -> Self {
+                self.inner.members = value;
+                self
+            }
+
+            pub fn add_members(mut self, value: Pubkey) -> Self {
+                self.inner.members.push(value);
+                self
+            }
+
+            pub fn with_blobs(mut self, value: BoundedVec<Vec<u8>, 2>) -> Self {
+                self.inner.blobs = value;
+                self
+            }
+
+            pub fn add_blobs(mut self, value: Vec<u8>) -> Self {
+                self.inner.blobs.push(value);
+                self
+            }
+
+            pub fn with_names(mut self, value: BoundedVec<String, 2>) -> Self {
+                self.inner.names = value;
+                self
+            }
+
+            pub fn add_names(mut self, value: String) -> Self {
+                self.inner.names.push(value);
+                self
+            }
+
+            pub fn with_seats(mut self, value: BoundedVec<Seat, 3>) -> Self {
+                self.inner.seats = value;
+                self
+            }
+
+            pub fn add_seats(mut self, value: Seat) -> Self {
+                self.inner.seats.push(value);
+                self
+            }
+
+            pub fn with_caps(mut self, value: BoundedVec<UInt64Value, 2>) -> Self {
+                self.inner.caps = value;
+                self
+            }
+
+            pub fn add_caps(mut self, value: UInt64Value) -> Self {
+                self.inner.caps.push(value);
+                self
+            }
+
+            pub fn with_history(mut self, value: Vec<u64>) -> Self {
+                self.inner.history = value;
+                self
+            }
+
+            pub fn add_history(mut self, value: u64) -> Self {
+                self.inner.history.push(value);
+                self
+            }
+
+            pub fn with_scores(mut self, value: Vec<u64>) -> Self {
+                self.inner.scores = value;
+                self
+            }
+
+            pub fn add_scores(mut self, value: u64) -> Self {
+                self.inner.scores.push(value);
+                self
+            }
+
+            pub fn build(self) -> Roster {
+                self.inner
+            }
+        }
+
+        impl Roster {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                for elem in &self.slots {
+                    out.write_scalar("slots", *elem);
+                }
+                for elem in &self.deltas {
+                    out.write_scalar("deltas", *elem);
+                }
+                for elem in &self.stamps {
+                    out.write_scalar("stamps", *elem);
+                }
+                for elem in &self.unpacked {
+                    out.write_scalar("unpacked", *elem);
+                }
+                for elem in &self.roles {
+                    out.write_enum("roles", *elem, &[("ROSTER_ROLE_MEMBER", 0), ("ROSTER_ROLE_ADMIN", 1)]);
+                }
+                for elem in &self.members {
+                    out.write_pubkey("members", elem);
+                }
+                for elem in &self.blobs {
+                    out.write_bytes("blobs", elem);
+                }
+                for elem in &self.names {
+                    out.write_str("names", elem);
+                }
+                for elem in &self.seats {
+                    out.begin_message("seats");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.caps {
+                    out.begin_message("caps");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.history {
+                    out.write_scalar("history", *elem);
+                }
+                for elem in &self.scores {
+                    out.write_scalar("scores", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Roster {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Roster {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.slots.is_empty() {
+                    out.key("slots");
+                    out.begin_array();
+                    for elem in &self.slots {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.deltas.is_empty() {
+                    out.key("deltas");
+                    out.begin_array();
+                    for elem in &self.deltas {
+                        out.write_i64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.stamps.is_empty() {
+                    out.key("stamps");
+                    out.begin_array();
+                    for elem in &self.stamps {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.unpacked.is_empty() {
+                    out.key("unpacked");
+                    out.begin_array();
+                    for elem in &self.unpacked {
+                        out.write_i32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.roles.is_empty() {
+                    out.key("roles");
+                    out.begin_array();
+                    for elem in &self.roles {
+                        out.write_enum(*elem, &[("ROSTER_ROLE_MEMBER", 0), ("ROSTER_ROLE_ADMIN", 1)]);
+                    }
+                    out.end_array();
+                }
+                if !self.members.is_empty() {
+                    out.key("members");
+                    out.begin_array();
+                    for elem in &self.members {
+                        out.write_pubkey(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.blobs.is_empty() {
+                    out.key("blobs");
+                    out.begin_array();
+                    for elem in &self.blobs {
+                        out.write_bytes(elem);
+                    }
+                    out.end_array();
+                }
+                if !self.names.is_empty() {
+                    out.key("names");
+                    out.begin_array();
+                    for elem in &self.names {
+                        out.write_str(elem);
+                    }
+                    out.end_array();
+                }
+                if !self.seats.is_empty() {
+                    out.key("seats");
+                    out.begin_array();
+                    for elem in &self.seats {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if !self.caps.is_empty() {
+                    out.key("caps");
+                    out.begin_array();
+                    for elem in &self.caps {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if !self.history.is_empty() {
+                    out.key("history");
+                    out.begin_array();
+                    for elem in &self.history {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.scores.is_empty() {
+                    out.key("scores");
+                    out.begin_array();
+                    for elem in &self.scores {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "slots" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.slots, json_to_u32(elem)?, 1)?;
+                            }
+                        }
+                        "deltas" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.deltas, json_to_i64(elem)?, 2)?;
+                            }
+                        }
+                        "stamps" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.stamps, json_to_u64(elem)?, 3)?;
+                            }
+                        }
+                        "unpacked" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.unpacked, json_to_i32(elem)?, 4)?;
+                            }
+                        }
+                        "roles" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.roles, json_to_enum(elem, &[("ROSTER_ROLE_MEMBER", 0), ("ROSTER_ROLE_ADMIN", 1)])?, 5)?;
+                            }
+                        }
+                        "members" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.members, json_to_pubkey(elem)?, 6)?;
+                            }
+                        }
+                        "blobs" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.blobs, json_to_bytes(elem)?, 7)?;
+                            }
+                        }
+                        "names" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.names, json_to_string(elem)?, 8)?;
+                            }
+                        }
+                        "seats" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.seats, Seat::from_json_value(elem)?, 9)?;
+                            }
+                        }
+                        "caps" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.caps, UInt64Value::from_json_value(elem)?, 10)?;
+                            }
+                        }
+                        "history" => {
+                            for elem in json_array(value)? {
+                                msg.history.push(json_to_u64(elem)?);
+                            }
+                        }
+                        "scores" => {
+                            for elem in json_array(value)? {
+                                msg.scores.push(json_to_u64(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct RosterRef<'a> {
+            raw: &'a [u8],
+            pub slots: RepeatedRef<'a, u32>,
+            pub deltas: RepeatedRef<'a, i64>,
+            pub stamps: RepeatedRef<'a, u64>,
+            pub unpacked: RepeatedRef<'a, i32>,
+            pub roles: RepeatedRef<'a, i32>,
+            pub members: RepeatedRef<'a, Pubkey>,
+            pub blobs: RepeatedRef<'a, &'a [u8]>,
+            pub names: RepeatedRef<'a, &'a str>,
+            pub seats: RepeatedRef<'a, SeatRef<'a>>,
+            pub caps: RepeatedRef<'a, u64>,
+            pub history: RepeatedRef<'a, u64>,
+            pub scores: RepeatedRef<'a, u64>,
+        }
+
+        impl<'a> RosterRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let view = RosterRef {
+                    raw: data,
+                    slots: RepeatedRef::packed(data, 8, |data, pos| {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        Ok((v as u32, new_pos))
+                    }),
+                    deltas: RepeatedRef::packed(data, 16, decode_zigzag64),
+                    stamps: RepeatedRef::packed(data, 25, decode_fixed64),
+                    unpacked: RepeatedRef::packed(data, 32, decode_int32),
+                    roles: RepeatedRef::packed(data, 40, decode_int32),
+                    members: RepeatedRef::new(data, 50, decode_pubkey),
+                    blobs: RepeatedRef::new(data, 58, |data, pos| {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        Ok((within_max_len(v, 8, 7)?, new_pos))
+                    }),
+                    names: RepeatedRef::new(data, 66, |data, pos| {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        Ok((within_max_len(v, 8, 8)?, new_pos))
+                    }),
+                    seats: RepeatedRef::new(data, 74, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((SeatRef::decode(v)?, new_pos))
+                    }),
+                    caps: RepeatedRef::new(data, 82, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((wrapper_value_ref(v, 8, decode_varint)?, new_pos))
+                    }),
+                    history: RepeatedRef::packed(data, 88, decode_varint),
+                    scores: RepeatedRef::packed(data, 96, decode_varint),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    16 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    25 => {
+                        pos = skip_field(data, pos, 1)?;
+                    }
+                    32 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    40 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    50 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    58 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    66 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    74 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    82 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    88 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    96 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Roster::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Roster, DecodeError> {
+                Roster::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Seat {
+            pub row: u32,
+            pub taken: bool,
+        }
+
+        impl Seat {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Seat {
+                row: 0,
+                taken: false,
+            };
+        }
+
+        impl Default for Seat {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Seat {
+            pub const ROW_FIELD_NUMBER: u32 = 1;
+            pub const TAKEN_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Seat.row" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Seat.taken" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: taken
+                if self.taken {
+                    w.write_bool(self.taken);
+                    w.write_key(0x10);
+                }
+
+                // field 1: row
+                if self.row != 0 {
+                    w.write_varint(self.row as u64);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.row != 0 {
+                    len += key_len(1) + varint_len(self.row as u64);
+                }
+                if self.taken {
+                    len += key_len(2) + 1;
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.row != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.row as u64)?;
+                }
+                if self.taken {
+                    w.write_key(0x10)?;
+                    w.write_bool(self.taken)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.row = v as u32;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_bool(data, pos)?;
+                            self.taken = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Seat {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Seat::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Seat::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Seat::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Seat::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Seat::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Seat::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Seat::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Seat {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Seat::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Seat> for Vec<u8> {
+            fn from(msg: Seat) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Seat {
+            const FULL_NAME: &'static str = "example.Seat";
+        }
+
+        impl Seat {
+            /// Longest encoding of a `Seat` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 8;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct SeatBuilder {
+            inner: Seat,
+        }
+
+        impl Seat {
+            pub fn builder() -> SeatBuilder {
+                SeatBuilder::default()
+            }
+        }
+
+        impl SeatBuilder {
+            pub fn with_row(mut self, value: u32) -> Self {
+                self.inner.row = value;
+                self
+            }
+
+            pub fn with_taken(mut self, value: bool) -> Self {
+                self.inner.taken = value;
+                self
+            }
+
+            pub fn build(self) -> Seat {
+                self.inner
+            }
+        }
+
+        impl Seat {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.row != 0 {
+                    out.write_scalar("row", self.row);
+                }
+                if self.taken {
+                    out.write_scalar("taken", self.taken);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Seat {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Seat {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.row != 0 {
+                    out.key("row");
+                    out.write_u32(self.row);
+                }
+                if self.taken {
+                    out.key("taken");
+                    out.write_bool(self.taken);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "row" => msg.row = json_to_u32(value)?,
+                        "taken" => msg.taken = json_to_bool(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct SeatRef<'a> {
+            raw: &'a [u8],
+            pub row: u32,
+            pub taken: bool,
+        }
+
+        impl<'a> SeatRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = SeatRef {
+                    raw: data,
+                    row: 0,
+                    taken: false,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.row = v as u32;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_bool(data, pos)?;
+                        view.taken = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Seat::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Seat, DecodeError> {
+                Seat::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// `children` closes a cycle, so it stays a Vec
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Squad {
+            pub size: u32,
+            pub children: Vec<Squad>,
+        }
+
+        impl Squad {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Squad {
+                size: 0,
+                children: Vec::new(),
+            };
+        }
+
+        impl Default for Squad {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Squad {
+            pub const SIZE_FIELD_NUMBER: u32 = 1;
+            pub const CHILDREN_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Squad.size" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Squad.children" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: children
+                for elem in self.children.iter().rev() {
+                    w.write_message(2, elem);
+                }
+
+                // field 1: size
+                if self.size != 0 {
+                    w.write_varint(self.size as u64);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.size != 0 {
+                    len += key_len(1) + varint_len(self.size as u64);
+                }
+                for elem in &self.children {
+                    len += key_len(2) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.size != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.size as u64)?;
+                }
+                for elem in &self.children {
+                    w.write_message(2, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.size != 0 {
+                    len += key_len(1) + varint_len(self.size as u64);
+                }
+                for elem in &self.children {
+                    len += key_len(2) + bytes_len(sizes.record(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.size != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.size as u64)?;
+                }
+                for elem in &self.children {
+                    w.write_message_sized(2, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.size = v as u32;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.children.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            if self.children.len() >= 2 {
+                                return Err(DecodeError::LimitExceeded { field: 2 });
+                            }
+                            self.children.push(Squad::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    2 => Some(self.children.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Squad {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Squad::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Squad::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Squad::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Squad::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Squad::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Squad::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Squad::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Squad {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Squad::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Squad> for Vec<u8> {
+            fn from(msg: Squad) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Squad {
+            const FULL_NAME: &'static str = "example.Squad";
+        }
+
+        impl Squad {
+            /// Check the `(solana.*)` field rules, those of sub-messages
+            /// included; decoding does not. Returns the first rule broken.
+            pub fn validate(&self) -> Result<(), ValidationError> {
+                for (i, v) in self.children.iter().enumerate() {
+                    v.validate().map_err(|e| e.within_element("children", i))?;
+                }
+                if self.children.len() > 2 {
+                    return Err(ValidationError::new("children", ValidationRule::MaxCount(2)));
+                }
+
+                Ok(())
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct SquadBuilder {
+            inner: Squad,
+        }
+
+        impl Squad {
+            pub fn builder() -> SquadBuilder {
+                SquadBuilder::default()
+            }
+        }
+
+        impl SquadBuilder {
+            pub fn with_size(mut self, value: u32) -> Self {
+                self.inner.size = value;
+                self
+            }
+
+            pub fn with_children(mut self, value: Vec<Squad>) -> Self {
+                self.inner.children = value;
+                self
+            }
+
+            pub fn add_children(mut self, value: Squad) -> Self {
+                self.inner.children.push(value);
+                self
+            }
+
+            pub fn build(self) -> Squad {
+                self.inner
+            }
+        }
+
+        impl Squad {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.size != 0 {
+                    out.write_scalar("size", self.size);
+                }
+                for elem in &self.children {
+                    out.begin_message("children");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Squad {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Squad {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.size != 0 {
+                    out.key("size");
+                    out.write_u32(self.size);
+                }
+                if !self.children.is_empty() {
+                    out.key("children");
+                    out.begin_array();
+                    for elem in &self.children {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "size" => msg.size = json_to_u32(value)?,
+                        "children" => {
+                            for elem in json_array(value)? {
+                                msg.children.push(Squad::from_json_value(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct SquadRef<'a> {
+            raw: &'a [u8],
+            pub size: u32,
+            pub children: RepeatedRef<'a, SquadRef<'a>>,
+        }
+
+        impl<'a> SquadRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = SquadRef {
+                    raw: data,
+                    size: 0,
+                    children: RepeatedRef::new(data, 18, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((SquadRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.size = v as u32;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Squad::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Squad, DecodeError> {
+                Squad::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Roster(Roster),
+            Seat(Seat),
+            Squad(Squad),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Roster::FULL_NAME => AnyMessage::Roster(Roster::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Seat::FULL_NAME => AnyMessage::Seat(Seat::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Squad::FULL_NAME => AnyMessage::Squad(Squad::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Roster(msg) => Any::pack(msg),
+                    AnyMessage::Seat(msg) => Any::pack(msg),
+                    AnyMessage::Squad(msg) => Any::pack(msg),
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            /// `n` copies of `value`.
+            fn repeated<T: Clone>(value: T, n: usize) -> Vec<T> {
+                (0..n).map(|_| value.clone()).collect()
+            }
+
+            fn sample_roster() -> Roster {
+                Roster {
+                    slots: repeated(u32::MAX, 4).into_iter().collect(),
+                    deltas: repeated(i64::MIN, 4).into_iter().collect(),
+                    stamps: repeated(u64::MAX, 2).into_iter().collect(),
+                    unpacked: repeated(i32::MIN, 3).into_iter().collect(),
+                    roles: repeated(1, 2).into_iter().collect(),
+                    members: repeated(pubkey_from_array([7; 32]), 2).into_iter().collect(),
+                    blobs: repeated([7u8].repeat(8), 2).into_iter().collect(),
+                    names: repeated("x".repeat(8), 2).into_iter().collect(),
+                    seats: repeated(sample_seat(), 3).into_iter().collect(),
+                    caps: repeated(UInt64Value { value: u64::MAX }, 2).into_iter().collect(),
+                    history: repeated(u64::MAX, 2),
+                    scores: repeated(u64::MAX, 4),
+                }
+            }
+
+            #[test]
+            fn roster_round_trips() {
+                let msg = sample_roster();
+                let bytes = msg.encode();
+                assert_eq!(bytes.len(), msg.encoded_len());
+                assert_eq!(Roster::decode(&bytes).unwrap(), msg);
+                assert_eq!(RosterRef::decode(&bytes).unwrap().to_message().unwrap(), msg);
+            }
+
+            #[test]
+            fn roster_encodes_canonically() {
+                assert!(verify_canonical::<Roster>(&sample_roster().encode()));
+            }
+
+            fn sample_seat() -> Seat {
+                Seat {
+                    row: u32::MAX,
+                    taken: true,
+                }
+            }
+
+            #[test]
+            fn seat_round_trips() {
+                let msg = sample_seat();
+                let bytes = msg.encode();
+                assert_eq!(bytes.len(), msg.encoded_len());
+                assert_eq!(Seat::decode(&bytes).unwrap(), msg);
+                assert_eq!(SeatRef::decode(&bytes).unwrap().to_message().unwrap(), msg);
+            }
+
+            #[test]
+            fn seat_encodes_canonically() {
+                assert!(verify_canonical::<Seat>(&sample_seat().encode()));
+            }
+
+            #[test]
+            fn seat_fits_max_encoded_len() {
+                assert!(sample_seat().encoded_len() <= Seat::MAX_ENCODED_LEN);
+            }
+
+            fn sample_squad() -> Squad {
+                Squad {
+                    size: u32::MAX,
+                    children: repeated(Squad::default(), 2),
+                }
+            }
+
+            #[test]
+            fn squad_round_trips() {
+                let msg = sample_squad();
+                let bytes = msg.encode();
+                assert_eq!(bytes.len(), msg.encoded_len());
+                assert_eq!(Squad::decode(&bytes).unwrap(), msg);
+                assert_eq!(SquadRef::decode(&bytes).unwrap().to_message().unwrap(), msg);
+            }
+
+            #[test]
+            fn squad_encodes_canonically() {
+                assert!(verify_canonical::<Squad>(&sample_squad().encode()));
+            }
+        }
+    }
+}
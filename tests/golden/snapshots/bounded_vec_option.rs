@@ -0,0 +1,1990 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod bounded {
+        // Auto-generated by protoc-gen-solana from bounded.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// `example.VaultState`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum VaultState {
+            Open = 0,
+            Closed = 1,
+        }
+
+        impl VaultState {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: VaultState = VaultState::Open;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    VaultState::Open => "VAULT_STATE_OPEN",
+                    VaultState::Closed => "VAULT_STATE_CLOSED",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "VAULT_STATE_OPEN" => Some(VaultState::Open),
+                    "VAULT_STATE_CLOSED" => Some(VaultState::Closed),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for VaultState {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<VaultState> for i32 {
+            fn from(value: VaultState) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for VaultState {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(VaultState::Open),
+                    1 => Ok(VaultState::Closed),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum VaultLock {
+            Unset,
+            UntilSlot(i32),
+            Memo(Vec<u8>),
+        }
+
+        impl VaultLock {
+            pub const DEFAULT: Self = VaultLock::Unset;
+        }
+
+        impl Default for VaultLock {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: MAX_ENCODED_LEN from (solana.max_len) / (solana.max_count) bounds
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Vault {
+            pub authority: Pubkey,
+            pub name: String,
+            pub balances: BoundedVec<u64, 16>,
+            pub tags: BoundedVec<String, 4>,
+            pub blobs: BTreeMap<u32, Vec<u8>>,
+            pub config: VaultConfig,
+            pub created: Timestamp,
+            pub cap: Option<u64>,
+            pub lock: VaultLock,
+            pub state: i32,
+            pub history: BoundedVec<VaultConfig, 3>,
+        }
+
+        impl Vault {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Vault {
+                authority: ZERO_PUBKEY,
+                name: String::new(),
+                balances: BoundedVec::new(),
+                tags: BoundedVec::new(),
+                blobs: BTreeMap::new(),
+                config: VaultConfig::DEFAULT,
+                created: Timestamp::DEFAULT,
+                cap: None,
+                lock: VaultLock::Unset,
+                state: 0,
+                history: BoundedVec::new(),
+            };
+        }
+
+        impl Default for Vault {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Vault {
+            pub const AUTHORITY_FIELD_NUMBER: u32 = 1;
+            pub const NAME_FIELD_NUMBER: u32 = 2;
+            pub const BALANCES_FIELD_NUMBER: u32 = 3;
+            pub const TAGS_FIELD_NUMBER: u32 = 4;
+            pub const BLOBS_FIELD_NUMBER: u32 = 5;
+            pub const CONFIG_FIELD_NUMBER: u32 = 6;
+            pub const CREATED_FIELD_NUMBER: u32 = 7;
+            pub const CAP_FIELD_NUMBER: u32 = 8;
+            pub const UNTIL_SLOT_FIELD_NUMBER: u32 = 9;
+            pub const MEMO_FIELD_NUMBER: u32 = 10;
+            pub const STATE_FIELD_NUMBER: u32 = 11;
+            pub const HISTORY_FIELD_NUMBER: u32 = 12;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Vault.authority" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Vault.name" },
+                FieldWireType { number: 3, wire_type: 0, packed: true, name: "Vault.balances" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Vault.tags" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "Vault.blobs" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Vault.config" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "Vault.created" },
+                FieldWireType { number: 8, wire_type: 0, packed: false, name: "Vault.cap" },
+                FieldWireType { number: 9, wire_type: 0, packed: false, name: "Vault.until_slot" },
+                FieldWireType { number: 10, wire_type: 2, packed: false, name: "Vault.memo" },
+                FieldWireType { number: 11, wire_type: 0, packed: false, name: "Vault.state" },
+                FieldWireType { number: 12, wire_type: 2, packed: false, name: "Vault.history" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 12: history
+                for elem in self.history.iter().rev() {
+                    w.write_message(12, elem);
+                }
+
+                // field 11: state
+                if self.state != 0 {
+                    w.write_int32(self.state);
+                    w.write_key(0x58);
+                }
+
+                // field 10: memo
+                if let VaultLock::Memo(elem) = &self.lock {
+                    w.write_bytes(elem);
+                    w.write_key(0x52);
+                }
+
+                // field 9: until_slot
+                if let VaultLock::UntilSlot(elem) = &self.lock {
+                    w.write_int32(*elem);
+                    w.write_key(0x48);
+                }
+
+                // field 8: cap
+                if let Some(elem) = &self.cap {
+                    w.write_nested(8, |w| UInt64Value::write_value_reverse(elem, w));
+                }
+
+                // field 7: created
+                w.write_message(7, &self.created);
+
+                // field 6: config
+                w.write_message(6, &self.config);
+
+                // field 5: blobs
+                for (key, value) in self.blobs.iter().rev() {
+                    w.write_nested(5, |w| {
+                        w.write_bytes(value);
+                        w.write_key(0x12);
+                        w.write_varint(*key as u64);
+                        w.write_key(0x08);
+                    });
+                }
+
+                // field 4: tags
+                for elem in self.tags.iter().rev() {
+                    w.write_string(elem);
+                    w.write_key(0x22);
+                }
+
+                // field 3: balances
+                if !self.balances.is_empty() {
+                    w.write_nested(3, |w| {
+                        for elem in self.balances.iter().rev() {
+                            w.write_varint(*elem);
+                        }
+                    });
+                }
+
+                // field 2: name
+                if !self.name.is_empty() {
+                    w.write_string(&self.name);
+                    w.write_key(0x12);
+                }
+
+                // field 1: authority
+                if self.authority != Pubkey::default() {
+                    w.write_pubkey(&self.authority);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if !self.name.is_empty() {
+                    len += key_len(2) + bytes_len(self.name.len());
+                }
+                if !self.balances.is_empty() {
+                    len += key_len(3) + bytes_len(self.balances.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                for elem in &self.tags {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                for (key, value) in &self.blobs {
+                    len += key_len(5) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len()));
+                }
+                len += key_len(6) + bytes_len(self.config.encoded_len());
+                len += key_len(7) + bytes_len(self.created.encoded_len());
+                if let Some(elem) = &self.cap {
+                    len += key_len(8) + bytes_len(UInt64Value::value_len(elem));
+                }
+                if let VaultLock::UntilSlot(elem) = &self.lock {
+                    len += key_len(9) + int32_len(*elem);
+                }
+                if let VaultLock::Memo(elem) = &self.lock {
+                    len += key_len(10) + bytes_len(elem.len());
+                }
+                if self.state != 0 {
+                    len += key_len(11) + int32_len(self.state);
+                }
+                for elem in &self.history {
+                    len += key_len(12) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                if !self.name.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.name)?;
+                }
+                if !self.balances.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_varint(self.balances.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.balances {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                for elem in &self.tags {
+                    w.write_key(0x22)?;
+                    w.write_string(elem)?;
+                }
+                for (key, value) in &self.blobs {
+                    w.write_key(0x2a)?;
+                    w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key as u64)?;
+                    w.write_key(0x12)?;
+                    w.write_bytes(value)?;
+                }
+                w.write_message(6, &self.config)?;
+                w.write_message(7, &self.created)?;
+                if let Some(elem) = &self.cap {
+                    w.write_key(0x42)?;
+                    w.write_varint(UInt64Value::value_len(elem) as u64)?;
+                    UInt64Value::write_value(elem, w)?;
+                }
+                if let VaultLock::UntilSlot(elem) = &self.lock {
+                    w.write_key(0x48)?;
+                    w.write_int32(*elem)?;
+                }
+                if let VaultLock::Memo(elem) = &self.lock {
+                    w.write_key(0x52)?;
+                    w.write_bytes(elem)?;
+                }
+                if self.state != 0 {
+                    w.write_key(0x58)?;
+                    w.write_int32(self.state)?;
+                }
+                for elem in &self.history {
+                    w.write_message(12, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if !self.name.is_empty() {
+                    len += key_len(2) + bytes_len(self.name.len());
+                }
+                if !self.balances.is_empty() {
+                    len += key_len(3) + bytes_len(self.balances.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                for elem in &self.tags {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                for (key, value) in &self.blobs {
+                    len += key_len(5) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len()));
+                }
+                len += key_len(6) + bytes_len(sizes.record(&self.config));
+                len += key_len(7) + bytes_len(sizes.record(&self.created));
+                if let Some(elem) = &self.cap {
+                    len += key_len(8) + bytes_len(UInt64Value::value_len(elem));
+                }
+                if let VaultLock::UntilSlot(elem) = &self.lock {
+                    len += key_len(9) + int32_len(*elem);
+                }
+                if let VaultLock::Memo(elem) = &self.lock {
+                    len += key_len(10) + bytes_len(elem.len());
+                }
+                if self.state != 0 {
+                    len += key_len(11) + int32_len(self.state);
+                }
+                for elem in &self.history {
+                    len += key_len(12) + bytes_len(sizes.record(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                if !self.name.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.name)?;
+                }
+                if !self.balances.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_varint(self.balances.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.balances {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                for elem in &self.tags {
+                    w.write_key(0x22)?;
+                    w.write_string(elem)?;
+                }
+                for (key, value) in &self.blobs {
+                    w.write_key(0x2a)?;
+                    w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key as u64)?;
+                    w.write_key(0x12)?;
+                    w.write_bytes(value)?;
+                }
+                w.write_message_sized(6, &self.config, sizes)?;
+                w.write_message_sized(7, &self.created, sizes)?;
+                if let Some(elem) = &self.cap {
+                    w.write_key(0x42)?;
+                    w.write_varint(UInt64Value::value_len(elem) as u64)?;
+                    UInt64Value::write_value(elem, w)?;
+                }
+                if let VaultLock::UntilSlot(elem) = &self.lock {
+                    w.write_key(0x48)?;
+                    w.write_int32(*elem)?;
+                }
+                if let VaultLock::Memo(elem) = &self.lock {
+                    w.write_key(0x52)?;
+                    w.write_bytes(elem)?;
+                }
+                if self.state != 0 {
+                    w.write_key(0x58)?;
+                    w.write_int32(self.state)?;
+                }
+                for elem in &self.history {
+                    w.write_message_sized(12, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.authority = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_bounded(data, pos, opts, 32, 2)?;
+                            self.name = v;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.balances.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.balances, v, 3)?;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let new_pos = decode_packed_bounded(data, pos, &mut self.balances, 3, decode_varint)?;
+                            if self.balances.len() > opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let (v, new_pos) = decode_string_bounded(data, pos, opts, 8, 4)?;
+                            if self.tags.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.tags, v, 4)?;
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = Vec::new();
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    8 => {
+                                        let (v, next) = decode_varint(entry, entry_pos)?;
+                                        key = v as u32;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (v, next) = decode_bytes_bounded(entry, entry_pos, opts, 64, 5)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.blobs.len() >= opts.max_repeated_len && !self.blobs.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            if self.blobs.len() >= 2 && !self.blobs.contains_key(&key) {
+                                return Err(DecodeError::LimitExceeded { field: 5 });
+                            }
+                            self.blobs.insert(key, value);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.config.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.created.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        66 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.cap.get_or_insert_with(Default::default);
+                            UInt64Value::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        72 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.lock = VaultLock::UntilSlot(v);
+                            pos = new_pos;
+                        }
+                        82 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_bytes_bounded(data, pos, opts, 16, 10)?;
+                            self.lock = VaultLock::Memo(v);
+                            pos = new_pos;
+                        }
+                        88 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.state = v;
+                            pos = new_pos;
+                        }
+                        98 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.history.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            push_bounded(&mut self.history, VaultConfig::decode_with(sub, &opts.nested()?)?, 12)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    3 => Some(self.balances.len()),
+                    4 => Some(self.tags.len()),
+                    12 => Some(self.history.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Vault {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Vault::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Vault::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Vault::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Vault::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Vault::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Vault::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Vault::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Vault {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Vault::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Vault> for Vec<u8> {
+            fn from(msg: Vault) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Vault {
+            const FULL_NAME: &'static str = "example.Vault";
+        }
+
+        impl Vault {
+            /// Longest encoding of a `Vault` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 565;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        impl Vault {
+            /// Check the `(solana.*)` field rules, those of sub-messages
+            /// included; decoding does not. Returns the first rule broken.
+            pub fn validate(&self) -> Result<(), ValidationError> {
+                if self.name.len() > 32 {
+                    return Err(ValidationError::new("name", ValidationRule::MaxLen(32)));
+                }
+
+                if self.balances.len() > 16 {
+                    return Err(ValidationError::new("balances", ValidationRule::MaxCount(16)));
+                }
+
+                for (i, v) in self.tags.iter().enumerate() {
+                    if v.len() > 8 {
+                        return Err(ValidationError::element("tags", i, ValidationRule::MaxLen(8)));
+                    }
+                }
+                if self.tags.len() > 4 {
+                    return Err(ValidationError::new("tags", ValidationRule::MaxCount(4)));
+                }
+
+                for (k, v) in &self.blobs {
+                    if v.len() > 64 {
+                        return Err(ValidationError::element("blobs", k, ValidationRule::MaxLen(64)));
+                    }
+                }
+                if self.blobs.len() > 2 {
+                    return Err(ValidationError::new("blobs", ValidationRule::MaxCount(2)));
+                }
+
+                if let VaultLock::Memo(v) = &self.lock {
+                    if v.len() > 16 {
+                        return Err(ValidationError::new("memo", ValidationRule::MaxLen(16)));
+                    }
+                }
+
+                if self.history.len() > 3 {
+                    return Err(ValidationError::new("history", ValidationRule::MaxCount(3)));
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Vault {
+            pub fn until_slot(&self) -> Option<&i32> {
+                match &self.lock {
+                    VaultLock::UntilSlot(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_until_slot(&mut self, value: i32) {
+                self.lock = VaultLock::UntilSlot(value);
+            }
+
+            pub fn memo(&self) -> Option<&Vec<u8>> {
+                match &self.lock {
+                    VaultLock::Memo(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_memo(&mut self, value: Vec<u8>) {
+                self.lock = VaultLock::Memo(value);
+            }
+        }
+
+        impl Vault {
+            pub fn state(&self) -> Result<VaultState, i32> {
+                VaultState::try_from(self.state)
+            }
+
+            pub fn set_state(&mut self, value: VaultState) {
+                self.state = value.into();
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct VaultBuilder {
+            inner: Vault,
+        }
+
+        impl Vault {
+            pub fn builder() -> VaultBuilder {
+                VaultBuilder::default()
+            }
+        }
+
+        impl VaultBuilder {
+            pub fn with_authority(mut self, value: Pubkey) -> Self {
+                self.inner.authority = value;
+                self
+            }
+
+            pub fn with_name(mut self, value: String) -> Self {
+                self.inner.name = value;
+                self
+            }
+
+            pub fn with_balances(mut self, value: BoundedVec<u64, 16>) -> Self {
+                self.inner.balances = value;
+                self
+            }
+
+            pub fn add_balances(mut self, value: u64) -> Self {
+                self.inner.balances.push(value);
+                self
+            }
+
+            pub fn with_tags(mut self, value: BoundedVec<String, 4>) -> Self {
+                self.inner.tags = value;
+                self
+            }
+
+            pub fn add_tags(mut self, value: String) -> Self {
+                self.inner.tags.push(value);
+                self
+            }
+
+            pub fn with_blobs(mut self, value: BTreeMap<u32, Vec<u8>>) -> Self {
+                self.inner.blobs = value;
+                self
+            }
+
+            pub fn insert_blobs(mut self, key: u32, value: Vec<u8>) -> Self {
+                self.inner.blobs.insert(key, value);
+                self
+            }
+
+            pub fn with_config(mut self, value: VaultConfig) -> Self {
+                self.inner.config = value;
+                self
+            }
+
+            pub fn with_created(mut self, value: Timestamp) -> Self {
+                self.inner.created = value;
+                self
+            }
+
+            pub fn with_cap(mut self, value: u64) -> Self {
+                self.inner.cap = Some(value);
+                self
+            }
+
+            pub fn with_until_slot(mut self, value: i32) -> Self {
+                self.inner.lock = VaultLock::UntilSlot(value);
+                self
+            }
+
+            pub fn with_memo(mut self, value: Vec<u8>) -> Self {
+                self.inner.lock = VaultLock::Memo(value);
+                self
+            }
+
+            pub fn with_state(mut self, value: i32) -> Self {
+                self.inner.state = value;
+                self
+            }
+
+            pub fn with_history(mut self, value: BoundedVec<VaultConfig, 3>) -> Self {
+                self.inner.history = value;
+                self
+            }
+
+            pub fn add_history(mut self, value: VaultConfig) -> Self {
+                self.inner.history.push(value);
+                self
+            }
+
+            pub fn build(self) -> Vault {
+                self.inner
+            }
+        }
+
+        impl Vault {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.authority != Pubkey::default() {
+                    out.write_pubkey("authority", &self.authority);
+                }
+                if !self.name.is_empty() {
+                    out.write_str("name", &self.name);
+                }
+                for elem in &self.balances {
+                    out.write_scalar("balances", *elem);
+                }
+                for elem in &self.tags {
+                    out.write_str("tags", elem);
+                }
+                for (key, value) in &self.blobs {
+                    out.begin_message("blobs");
+                    out.write_scalar("key", *key);
+                    out.write_bytes("value", value);
+                    out.end_message();
+                }
+                if self.config != VaultConfig::default() {
+                    out.begin_message("config");
+                    self.config.write_text(out);
+                    out.end_message();
+                }
+                if self.created != Timestamp::default() {
+                    out.begin_message("created");
+                    self.created.write_text(out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.cap {
+                    out.begin_message("cap");
+                    UInt64Value::write_text_value(elem, out);
+                    out.end_message();
+                }
+                if let VaultLock::UntilSlot(elem) = &self.lock {
+                    out.write_scalar("until_slot", *elem);
+                }
+                if let VaultLock::Memo(elem) = &self.lock {
+                    out.write_bytes("memo", elem);
+                }
+                if self.state != 0 {
+                    out.write_enum("state", self.state, &[("VAULT_STATE_OPEN", 0), ("VAULT_STATE_CLOSED", 1)]);
+                }
+                for elem in &self.history {
+                    out.begin_message("history");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Vault {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Vault {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.authority != Pubkey::default() {
+                    out.key("authority");
+                    out.write_pubkey(self.authority);
+                }
+                if !self.name.is_empty() {
+                    out.key("name");
+                    out.write_str(&self.name);
+                }
+                if !self.balances.is_empty() {
+                    out.key("balances");
+                    out.begin_array();
+                    for elem in &self.balances {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.tags.is_empty() {
+                    out.key("tags");
+                    out.begin_array();
+                    for elem in &self.tags {
+                        out.write_str(elem);
+                    }
+                    out.end_array();
+                }
+                if !self.blobs.is_empty() {
+                    out.key("blobs");
+                    out.begin_object();
+                    for (key, value) in &self.blobs {
+                        out.key(&key.to_string());
+                        out.write_bytes(value);
+                    }
+                    out.end_object();
+                }
+                if self.config != VaultConfig::default() {
+                    out.key("config");
+                    self.config.write_json(out);
+                }
+                if self.created != Timestamp::default() {
+                    out.key("created");
+                    self.created.write_json(out);
+                }
+                if let Some(elem) = &self.cap {
+                    out.key("cap");
+                    out.write_u64(*elem);
+                }
+                if let VaultLock::UntilSlot(elem) = &self.lock {
+                    out.key("untilSlot");
+                    out.write_i32(*elem);
+                }
+                if let VaultLock::Memo(elem) = &self.lock {
+                    out.key("memo");
+                    out.write_bytes(elem);
+                }
+                if self.state != 0 {
+                    out.key("state");
+                    out.write_enum(self.state, &[("VAULT_STATE_OPEN", 0), ("VAULT_STATE_CLOSED", 1)]);
+                }
+                if !self.history.is_empty() {
+                    out.key("history");
+                    out.begin_array();
+                    for elem in &self.history {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "authority" => msg.authority = json_to_pubkey(value)?,
+                        "name" => msg.name = json_to_string(value)?,
+                        "balances" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.balances, json_to_u64(elem)?, 3)?;
+                            }
+                        }
+                        "tags" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.tags, json_to_string(elem)?, 4)?;
+                            }
+                        }
+                        "blobs" => {
+                            for (key, value) in json_object(value)? {
+                                msg.blobs.insert(json_map_key(key)?, json_to_bytes(value)?);
+                            }
+                        }
+                        "config" => msg.config = VaultConfig::from_json_value(value)?,
+                        "created" => msg.created = Timestamp::from_json_value(value)?,
+                        "cap" => msg.cap = Some(json_to_u64(value)?),
+                        "untilSlot" | "until_slot" => msg.lock = VaultLock::UntilSlot(json_to_i32(value)?),
+                        "memo" => msg.lock = VaultLock::Memo(json_to_bytes(value)?),
+                        "state" => msg.state = json_to_enum(value, &[("VAULT_STATE_OPEN", 0), ("VAULT_STATE_CLOSED", 1)])?,
+                        "history" => {
+                            for elem in json_array(value)? {
+                                push_bounded(&mut msg.history, VaultConfig::from_json_value(elem)?, 12)?;
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct VaultRef<'a> {
+            raw: &'a [u8],
+            pub authority: Pubkey,
+            pub name: &'a str,
+            pub balances: RepeatedRef<'a, u64>,
+            pub tags: RepeatedRef<'a, &'a str>,
+            pub config: Option<VaultConfigRef<'a>>,
+            pub created: Option<Timestamp>,
+            pub cap: Option<u64>,
+            pub until_slot: Option<i32>,
+            pub memo: Option<&'a [u8]>,
+            pub state: i32,
+            pub history: RepeatedRef<'a, VaultConfigRef<'a>>,
+        }
+
+        impl<'a> VaultRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = VaultRef {
+                    raw: data,
+                    authority: ZERO_PUBKEY,
+                    name: "",
+                    balances: RepeatedRef::packed(data, 24, decode_varint),
+                    tags: RepeatedRef::new(data, 34, |data, pos| {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        Ok((within_max_len(v, 8, 4)?, new_pos))
+                    }),
+                    config: None,
+                    created: None,
+                    cap: None,
+                    until_slot: None,
+                    memo: None,
+                    state: 0,
+                    history: RepeatedRef::new(data, 98, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((VaultConfigRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.authority = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.name = within_max_len(v, 32, 2)?;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    34 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    50 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.config = Some(VaultConfigRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    58 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.created = Some(Timestamp::decode(v)?);
+                        pos = new_pos;
+                    }
+                    66 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.cap = Some(wrapper_value_ref(v, 8, decode_varint)?);
+                        pos = new_pos;
+                    }
+                    72 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.until_slot = Some(v);
+                        view.memo = None;
+                        pos = new_pos;
+                    }
+                    82 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.memo = Some(within_max_len(v, 16, 10)?);
+                        view.until_slot = None;
+                        pos = new_pos;
+                    }
+                    88 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.state = v;
+                        pos = new_pos;
+                    }
+                    98 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Vault::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Vault, DecodeError> {
+                Vault::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct VaultConfig {
+            pub fee_bps: u32,
+            pub frozen: bool,
+            pub salt: [u8; 8],
+        }
+
+        impl VaultConfig {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = VaultConfig {
+                fee_bps: 0,
+                frozen: false,
+                salt: [0; 8],
+            };
+        }
+
+        impl Default for VaultConfig {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl VaultConfig {
+            pub const FEE_BPS_FIELD_NUMBER: u32 = 1;
+            pub const FROZEN_FIELD_NUMBER: u32 = 2;
+            pub const SALT_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "VaultConfig.fee_bps" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "VaultConfig.frozen" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "VaultConfig.salt" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: salt
+                if self.salt != [0; 8] {
+                    w.write_bytes(&self.salt);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: frozen
+                if self.frozen {
+                    w.write_bool(self.frozen);
+                    w.write_key(0x10);
+                }
+
+                // field 1: fee_bps
+                if self.fee_bps != 0 {
+                    w.write_varint(self.fee_bps as u64);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.fee_bps != 0 {
+                    len += key_len(1) + varint_len(self.fee_bps as u64);
+                }
+                if self.frozen {
+                    len += key_len(2) + 1;
+                }
+                if self.salt != [0; 8] {
+                    len += key_len(3) + bytes_len(self.salt.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.fee_bps != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.fee_bps as u64)?;
+                }
+                if self.frozen {
+                    w.write_key(0x10)?;
+                    w.write_bool(self.frozen)?;
+                }
+                if self.salt != [0; 8] {
+                    w.write_key(0x1a)?;
+                    w.write_bytes(&self.salt)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.fee_bps = v as u32;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_bool(data, pos)?;
+                            self.frozen = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<8>(data, pos)?;
+                            self.salt = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for VaultConfig {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                VaultConfig::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                VaultConfig::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                VaultConfig::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                VaultConfig::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                VaultConfig::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                VaultConfig::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                VaultConfig::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for VaultConfig {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                VaultConfig::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<VaultConfig> for Vec<u8> {
+            fn from(msg: VaultConfig) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for VaultConfig {
+            const FULL_NAME: &'static str = "example.VaultConfig";
+        }
+
+        impl VaultConfig {
+            /// Longest encoding of a `VaultConfig` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 18;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct VaultConfigBuilder {
+            inner: VaultConfig,
+        }
+
+        impl VaultConfig {
+            pub fn builder() -> VaultConfigBuilder {
+                VaultConfigBuilder::default()
+            }
+        }
+
+        impl VaultConfigBuilder {
+            pub fn with_fee_bps(mut self, value: u32) -> Self {
+                self.inner.fee_bps = value;
+                self
+            }
+
+            pub fn with_frozen(mut self, value: bool) -> Self {
+                self.inner.frozen = value;
+                self
+            }
+
+            pub fn with_salt(mut self, value: [u8; 8]) -> Self {
+                self.inner.salt = value;
+                self
+            }
+
+            pub fn build(self) -> VaultConfig {
+                self.inner
+            }
+        }
+
+        impl VaultConfig {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.fee_bps != 0 {
+                    out.write_scalar("fee_bps", self.fee_bps);
+                }
+                if self.frozen {
+                    out.write_scalar("frozen", self.frozen);
+                }
+                if self.salt != [0; 8] {
+                    out.write_bytes("salt", &self.salt);
+                }
+            }
+        }
+
+        impl core::fmt::Display for VaultConfig {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl VaultConfig {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.fee_bps != 0 {
+                    out.key("feeBps");
+                    out.write_u32(self.fee_bps);
+                }
+                if self.frozen {
+                    out.key("frozen");
+                    out.write_bool(self.frozen);
+                }
+                if self.salt != [0; 8] {
+                    out.key("salt");
+                    out.write_bytes(&self.salt);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "feeBps" | "fee_bps" => msg.fee_bps = json_to_u32(value)?,
+                        "frozen" => msg.frozen = json_to_bool(value)?,
+                        "salt" => msg.salt = json_to_fixed_bytes::<8>(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct VaultConfigRef<'a> {
+            raw: &'a [u8],
+            pub fee_bps: u32,
+            pub frozen: bool,
+            pub salt: &'a [u8; 8],
+        }
+
+        impl<'a> VaultConfigRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = VaultConfigRef {
+                    raw: data,
+                    fee_bps: 0,
+                    frozen: false,
+                    salt: &[0; 8],
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.fee_bps = v as u32;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_bool(data, pos)?;
+                        view.frozen = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_fixed_bytes_ref::<8>(data, pos)?;
+                        view.salt = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(VaultConfig::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<VaultConfig, DecodeError> {
+                VaultConfig::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// No MAX_ENCODED_LEN: `notes` has no (solana.max_count)
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct VaultLog {
+            pub notes: Vec<String>,
+        }
+
+        impl VaultLog {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = VaultLog {
+                notes: Vec::new(),
+            };
+        }
+
+        impl Default for VaultLog {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl VaultLog {
+            pub const NOTES_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "VaultLog.notes" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: notes
+                for elem in self.notes.iter().rev() {
+                    w.write_string(elem);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                for elem in &self.notes {
+                    len += key_len(1) + bytes_len(elem.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                for elem in &self.notes {
+                    w.write_key(0x0a)?;
+                    w.write_string(elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            let (v, new_pos) = decode_string_bounded(data, pos, opts, 8, 1)?;
+                            if self.notes.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.notes.push(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    1 => Some(self.notes.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for VaultLog {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                VaultLog::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                VaultLog::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                VaultLog::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                VaultLog::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                VaultLog::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                VaultLog::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                VaultLog::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for VaultLog {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                VaultLog::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<VaultLog> for Vec<u8> {
+            fn from(msg: VaultLog) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for VaultLog {
+            const FULL_NAME: &'static str = "example.VaultLog";
+        }
+
+        impl VaultLog {
+            /// Check the `(solana.*)` field rules, those of sub-messages
+            /// included; decoding does not. Returns the first rule broken.
+            pub fn validate(&self) -> Result<(), ValidationError> {
+                for (i, v) in self.notes.iter().enumerate() {
+                    if v.len() > 8 {
+                        return Err(ValidationError::element("notes", i, ValidationRule::MaxLen(8)));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct VaultLogBuilder {
+            inner: VaultLog,
+        }
+
+        impl VaultLog {
+            pub fn builder() -> VaultLogBuilder {
+                VaultLogBuilder::default()
+            }
+        }
+
+        impl VaultLogBuilder {
+            pub fn with_notes(mut self, value: Vec<String>) -> Self {
+                self.inner.notes = value;
+                self
+            }
+
+            pub fn add_notes(mut self, value: String) -> Self {
+                self.inner.notes.push(value);
+                self
+            }
+
+            pub fn build(self) -> VaultLog {
+                self.inner
+            }
+        }
+
+        impl VaultLog {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                for elem in &self.notes {
+                    out.write_str("notes", elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for VaultLog {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl VaultLog {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.notes.is_empty() {
+                    out.key("notes");
+                    out.begin_array();
+                    for elem in &self.notes {
+                        out.write_str(elem);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "notes" => {
+                            for elem in json_array(value)? {
+                                msg.notes.push(json_to_string(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct VaultLogRef<'a> {
+            raw: &'a [u8],
+            pub notes: RepeatedRef<'a, &'a str>,
+        }
+
+        impl<'a> VaultLogRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let view = VaultLogRef {
+                    raw: data,
+                    notes: RepeatedRef::new(data, 10, |data, pos| {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        Ok((within_max_len(v, 8, 1)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(VaultLog::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<VaultLog, DecodeError> {
+                VaultLog::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Vault(Vault),
+            VaultConfig(VaultConfig),
+            VaultLog(VaultLog),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Vault::FULL_NAME => AnyMessage::Vault(Vault::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    VaultConfig::FULL_NAME => AnyMessage::VaultConfig(VaultConfig::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    VaultLog::FULL_NAME => AnyMessage::VaultLog(VaultLog::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Vault(msg) => Any::pack(msg),
+                    AnyMessage::VaultConfig(msg) => Any::pack(msg),
+                    AnyMessage::VaultLog(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,701 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod canonical {
+        // Auto-generated by protoc-gen-solana from canonical.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum SignedPayloadAction {
+            Unset,
+            Memo(String),
+            Code(u32),
+        }
+
+        impl SignedPayloadAction {
+            pub const DEFAULT: Self = SignedPayloadAction::Unset;
+        }
+
+        impl Default for SignedPayloadAction {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: canonical encoding of fields declared out of tag order
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct SignedPayload {
+            pub signature: Vec<u8>,
+            pub balances: BTreeMap<String, u64>,
+            pub action: SignedPayloadAction,
+            pub nonce: u64,
+            pub deltas: Vec<i32>,
+        }
+
+        impl SignedPayload {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = SignedPayload {
+                signature: Vec::new(),
+                balances: BTreeMap::new(),
+                action: SignedPayloadAction::Unset,
+                nonce: 0,
+                deltas: Vec::new(),
+            };
+        }
+
+        impl Default for SignedPayload {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl SignedPayload {
+            pub const SIGNATURE_FIELD_NUMBER: u32 = 9;
+            pub const BALANCES_FIELD_NUMBER: u32 = 4;
+            pub const MEMO_FIELD_NUMBER: u32 = 7;
+            pub const CODE_FIELD_NUMBER: u32 = 2;
+            pub const NONCE_FIELD_NUMBER: u32 = 1;
+            pub const DELTAS_FIELD_NUMBER: u32 = 5;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "SignedPayload.signature" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "SignedPayload.balances" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "SignedPayload.memo" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "SignedPayload.code" },
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "SignedPayload.nonce" },
+                FieldWireType { number: 5, wire_type: 0, packed: true, name: "SignedPayload.deltas" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 9: signature
+                if !self.signature.is_empty() {
+                    w.write_bytes(&self.signature);
+                    w.write_key(0x4a);
+                }
+
+                // field 7: memo
+                if let SignedPayloadAction::Memo(elem) = &self.action {
+                    w.write_string(elem);
+                    w.write_key(0x3a);
+                }
+
+                // field 5: deltas
+                if !self.deltas.is_empty() {
+                    w.write_nested(5, |w| {
+                        for elem in self.deltas.iter().rev() {
+                            w.write_zigzag32(*elem);
+                        }
+                    });
+                }
+
+                // field 4: balances
+                for (key, value) in self.balances.iter().rev() {
+                    w.write_nested(4, |w| {
+                        w.write_varint(*value);
+                        w.write_key(0x10);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 2: code
+                if let SignedPayloadAction::Code(elem) = &self.action {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x10);
+                }
+
+                // field 1: nonce
+                if self.nonce != 0 {
+                    w.write_varint(self.nonce);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.nonce != 0 {
+                    len += key_len(1) + varint_len(self.nonce);
+                }
+                if let SignedPayloadAction::Code(elem) = &self.action {
+                    len += key_len(2) + varint_len(*elem as u64);
+                }
+                for (key, value) in &self.balances {
+                    len += key_len(4) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value));
+                }
+                if !self.deltas.is_empty() {
+                    len += key_len(5) + bytes_len(self.deltas.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>());
+                }
+                if let SignedPayloadAction::Memo(elem) = &self.action {
+                    len += key_len(7) + bytes_len(elem.len());
+                }
+                if !self.signature.is_empty() {
+                    len += key_len(9) + bytes_len(self.signature.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.nonce != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.nonce)?;
+                }
+                if let SignedPayloadAction::Code(elem) = &self.action {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                for (key, value) in &self.balances {
+                    w.write_key(0x22)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x10)?;
+                    w.write_varint(*value)?;
+                }
+                if !self.deltas.is_empty() {
+                    w.write_key(0x2a)?;
+                    w.write_varint(self.deltas.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.deltas {
+                        w.write_zigzag32(*elem)?;
+                    }
+                }
+                if let SignedPayloadAction::Memo(elem) = &self.action {
+                    w.write_key(0x3a)?;
+                    w.write_string(elem)?;
+                }
+                if !self.signature.is_empty() {
+                    w.write_key(0x4a)?;
+                    w.write_bytes(&self.signature)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        74 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.signature = v;
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = 0;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    16 => {
+                                        let (v, next) = decode_varint(entry, entry_pos)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.balances.len() >= opts.max_repeated_len && !self.balances.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.balances.insert(key, value);
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.action = SignedPayloadAction::Memo(v);
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.action = SignedPayloadAction::Code(v as u32);
+                            pos = new_pos;
+                        }
+                        8 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.nonce = v;
+                            pos = new_pos;
+                        }
+                        40 => {
+                            let (v, new_pos) = decode_zigzag32(data, pos)?;
+                            if self.deltas.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.deltas.push(v);
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let new_pos = decode_packed_zigzag32_with(data, pos, &mut self.deltas, opts)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    5 => Some(self.deltas.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for SignedPayload {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                SignedPayload::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                SignedPayload::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                SignedPayload::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                SignedPayload::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                SignedPayload::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                SignedPayload::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                SignedPayload::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for SignedPayload {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                SignedPayload::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<SignedPayload> for Vec<u8> {
+            fn from(msg: SignedPayload) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for SignedPayload {
+            const FULL_NAME: &'static str = "example.SignedPayload";
+        }
+
+        impl SignedPayload {
+            pub fn memo(&self) -> Option<&String> {
+                match &self.action {
+                    SignedPayloadAction::Memo(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_memo(&mut self, value: String) {
+                self.action = SignedPayloadAction::Memo(value);
+            }
+
+            pub fn code(&self) -> Option<&u32> {
+                match &self.action {
+                    SignedPayloadAction::Code(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_code(&mut self, value: u32) {
+                self.action = SignedPayloadAction::Code(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct SignedPayloadBuilder {
+            inner: SignedPayload,
+        }
+
+        impl SignedPayload {
+            pub fn builder() -> SignedPayloadBuilder {
+                SignedPayloadBuilder::default()
+            }
+        }
+
+        impl SignedPayloadBuilder {
+            pub fn with_signature(mut self, value: Vec<u8>) -> Self {
+                self.inner.signature = value;
+                self
+            }
+
+            pub fn with_balances(mut self, value: BTreeMap<String, u64>) -> Self {
+                self.inner.balances = value;
+                self
+            }
+
+            pub fn insert_balances(mut self, key: String, value: u64) -> Self {
+                self.inner.balances.insert(key, value);
+                self
+            }
+
+            pub fn with_memo(mut self, value: String) -> Self {
+                self.inner.action = SignedPayloadAction::Memo(value);
+                self
+            }
+
+            pub fn with_code(mut self, value: u32) -> Self {
+                self.inner.action = SignedPayloadAction::Code(value);
+                self
+            }
+
+            pub fn with_nonce(mut self, value: u64) -> Self {
+                self.inner.nonce = value;
+                self
+            }
+
+            pub fn with_deltas(mut self, value: Vec<i32>) -> Self {
+                self.inner.deltas = value;
+                self
+            }
+
+            pub fn add_deltas(mut self, value: i32) -> Self {
+                self.inner.deltas.push(value);
+                self
+            }
+
+            pub fn build(self) -> SignedPayload {
+                self.inner
+            }
+        }
+
+        impl SignedPayload {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.nonce != 0 {
+                    out.write_scalar("nonce", self.nonce);
+                }
+                if let SignedPayloadAction::Code(elem) = &self.action {
+                    out.write_scalar("code", *elem);
+                }
+                for (key, value) in &self.balances {
+                    out.begin_message("balances");
+                    out.write_str("key", key);
+                    out.write_scalar("value", *value);
+                    out.end_message();
+                }
+                for elem in &self.deltas {
+                    out.write_scalar("deltas", *elem);
+                }
+                if let SignedPayloadAction::Memo(elem) = &self.action {
+                    out.write_str("memo", elem);
+                }
+                if !self.signature.is_empty() {
+                    out.write_bytes("signature", &self.signature);
+                }
+            }
+        }
+
+        impl core::fmt::Display for SignedPayload {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl SignedPayload {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.signature.is_empty() {
+                    out.key("signature");
+                    out.write_bytes(&self.signature);
+                }
+                if !self.balances.is_empty() {
+                    out.key("balances");
+                    out.begin_object();
+                    for (key, value) in &self.balances {
+                        out.key(key);
+                        out.write_u64(*value);
+                    }
+                    out.end_object();
+                }
+                if let SignedPayloadAction::Memo(elem) = &self.action {
+                    out.key("memo");
+                    out.write_str(elem);
+                }
+                if let SignedPayloadAction::Code(elem) = &self.action {
+                    out.key("code");
+                    out.write_u32(*elem);
+                }
+                if self.nonce != 0 {
+                    out.key("nonce");
+                    out.write_u64(self.nonce);
+                }
+                if !self.deltas.is_empty() {
+                    out.key("deltas");
+                    out.begin_array();
+                    for elem in &self.deltas {
+                        out.write_i32(*elem);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "signature" => msg.signature = json_to_bytes(value)?,
+                        "balances" => {
+                            for (key, value) in json_object(value)? {
+                                msg.balances.insert(json_map_key(key)?, json_to_u64(value)?);
+                            }
+                        }
+                        "memo" => msg.action = SignedPayloadAction::Memo(json_to_string(value)?),
+                        "code" => msg.action = SignedPayloadAction::Code(json_to_u32(value)?),
+                        "nonce" => msg.nonce = json_to_u64(value)?,
+                        "deltas" => {
+                            for elem in json_array(value)? {
+                                msg.deltas.push(json_to_i32(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct SignedPayloadRef<'a> {
+            raw: &'a [u8],
+            pub signature: &'a [u8],
+            pub memo: Option<&'a str>,
+            pub code: Option<u32>,
+            pub nonce: u64,
+            pub deltas: RepeatedRef<'a, i32>,
+        }
+
+        impl<'a> SignedPayloadRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = SignedPayloadRef {
+                    raw: data,
+                    signature: &[],
+                    memo: None,
+                    code: None,
+                    nonce: 0,
+                    deltas: RepeatedRef::packed(data, 40, decode_zigzag32),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    74 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.signature = v;
+                        pos = new_pos;
+                    }
+                    58 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.memo = Some(v);
+                        view.code = None;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.code = Some(v as u32);
+                        view.memo = None;
+                        pos = new_pos;
+                    }
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.nonce = v;
+                        pos = new_pos;
+                    }
+                    40 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(SignedPayload::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<SignedPayload, DecodeError> {
+                SignedPayload::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            SignedPayload(SignedPayload),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    SignedPayload::FULL_NAME => AnyMessage::SignedPayload(SignedPayload::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::SignedPayload(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1583 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod docs {
+    pub mod comments {
+        // Auto-generated by protoc-gen-solana from comments.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// The side of an order.
+        ///
+        /// `docs.Side`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum Side {
+            /// Not set.
+            Unspecified = 0,
+            /// Buy.
+            Bid = 1,
+            Ask = 2,
+        }
+
+        impl Side {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: Side = Side::Unspecified;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Side::Unspecified => "SIDE_UNSPECIFIED",
+                    Side::Bid => "SIDE_BID",
+                    Side::Ask => "SIDE_ASK",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "SIDE_UNSPECIFIED" => Some(Side::Unspecified),
+                    "SIDE_BID" => Some(Side::Bid),
+                    "SIDE_ASK" => Some(Side::Ask),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for Side {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<Side> for i32 {
+            fn from(value: Side) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for Side {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(Side::Unspecified),
+                    1 => Ok(Side::Bid),
+                    2 => Ok(Side::Ask),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum OrderAmount {
+            Unset,
+            /// Base units.
+            Size(u64),
+            /// Quote units.
+            Notional(u64),
+        }
+
+        impl OrderAmount {
+            pub const DEFAULT: Self = OrderAmount::Unset;
+        }
+
+        impl Default for OrderAmount {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// A limit order on the book.
+        ///
+        /// Prices are in \[lamports\], see [the docs](https://docs.solana.com).
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Order {
+            /// Client-assigned id.
+            pub id: u64,
+            /// Limit price; zero for a market order.
+            pub price: u64,
+            /// As an enum:
+            ///
+            /// ```text
+            /// let side = order.side();
+            /// ```
+            ///
+            /// or its number.
+            pub side: i32,
+            pub amount: OrderAmount,
+            /// Fenced:
+            /// ```text
+            /// assert!(false);
+            /// ```
+            pub fills: Vec<Fill>,
+        }
+
+        impl Order {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Order {
+                id: 0,
+                price: 0,
+                side: 0,
+                amount: OrderAmount::Unset,
+                fills: Vec::new(),
+            };
+        }
+
+        impl Default for Order {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Order {
+            pub const ID_FIELD_NUMBER: u32 = 1;
+            pub const PRICE_FIELD_NUMBER: u32 = 2;
+            pub const SIDE_FIELD_NUMBER: u32 = 3;
+            pub const SIZE_FIELD_NUMBER: u32 = 4;
+            pub const NOTIONAL_FIELD_NUMBER: u32 = 5;
+            pub const FILLS_FIELD_NUMBER: u32 = 6;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Order.id" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Order.price" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "Order.side" },
+                FieldWireType { number: 4, wire_type: 0, packed: false, name: "Order.size" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "Order.notional" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Order.fills" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 6: fills
+                for elem in self.fills.iter().rev() {
+                    w.write_message(6, elem);
+                }
+
+                // field 5: notional
+                if let OrderAmount::Notional(elem) = &self.amount {
+                    w.write_varint(*elem);
+                    w.write_key(0x28);
+                }
+
+                // field 4: size
+                if let OrderAmount::Size(elem) = &self.amount {
+                    w.write_varint(*elem);
+                    w.write_key(0x20);
+                }
+
+                // field 3: side
+                if self.side != 0 {
+                    w.write_int32(self.side);
+                    w.write_key(0x18);
+                }
+
+                // field 2: price
+                if self.price != 0 {
+                    w.write_varint(self.price);
+                    w.write_key(0x10);
+                }
+
+                // field 1: id
+                if self.id != 0 {
+                    w.write_varint(self.id);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                if self.price != 0 {
+                    len += key_len(2) + varint_len(self.price);
+                }
+                if self.side != 0 {
+                    len += key_len(3) + int32_len(self.side);
+                }
+                if let OrderAmount::Size(elem) = &self.amount {
+                    len += key_len(4) + varint_len(*elem);
+                }
+                if let OrderAmount::Notional(elem) = &self.amount {
+                    len += key_len(5) + varint_len(*elem);
+                }
+                for elem in &self.fills {
+                    len += key_len(6) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                if self.price != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.price)?;
+                }
+                if self.side != 0 {
+                    w.write_key(0x18)?;
+                    w.write_int32(self.side)?;
+                }
+                if let OrderAmount::Size(elem) = &self.amount {
+                    w.write_key(0x20)?;
+                    w.write_varint(*elem)?;
+                }
+                if let OrderAmount::Notional(elem) = &self.amount {
+                    w.write_key(0x28)?;
+                    w.write_varint(*elem)?;
+                }
+                for elem in &self.fills {
+                    w.write_message(6, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                if self.price != 0 {
+                    len += key_len(2) + varint_len(self.price);
+                }
+                if self.side != 0 {
+                    len += key_len(3) + int32_len(self.side);
+                }
+                if let OrderAmount::Size(elem) = &self.amount {
+                    len += key_len(4) + varint_len(*elem);
+                }
+                if let OrderAmount::Notional(elem) = &self.amount {
+                    len += key_len(5) + varint_len(*elem);
+                }
+                for elem in &self.fills {
+                    len += key_len(6) + bytes_len(sizes.record(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                if self.price != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.price)?;
+                }
+                if self.side != 0 {
+                    w.write_key(0x18)?;
+                    w.write_int32(self.side)?;
+                }
+                if let OrderAmount::Size(elem) = &self.amount {
+                    w.write_key(0x20)?;
+                    w.write_varint(*elem)?;
+                }
+                if let OrderAmount::Notional(elem) = &self.amount {
+                    w.write_key(0x28)?;
+                    w.write_varint(*elem)?;
+                }
+                for elem in &self.fills {
+                    w.write_message_sized(6, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.id = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.price = v;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.side = v;
+                            pos = new_pos;
+                        }
+                        32 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = OrderAmount::Size(v);
+                            pos = new_pos;
+                        }
+                        40 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = OrderAmount::Notional(v);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.fills.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.fills.push(Fill::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    6 => Some(self.fills.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Order {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Order::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Order::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Order::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Order::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Order::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Order::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Order::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Order {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Order::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Order> for Vec<u8> {
+            fn from(msg: Order) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Order {
+            const FULL_NAME: &'static str = "docs.Order";
+        }
+
+        impl Order {
+            pub fn size(&self) -> Option<&u64> {
+                match &self.amount {
+                    OrderAmount::Size(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_size(&mut self, value: u64) {
+                self.amount = OrderAmount::Size(value);
+            }
+
+            pub fn notional(&self) -> Option<&u64> {
+                match &self.amount {
+                    OrderAmount::Notional(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_notional(&mut self, value: u64) {
+                self.amount = OrderAmount::Notional(value);
+            }
+        }
+
+        impl Order {
+            pub fn side(&self) -> Result<Side, i32> {
+                Side::try_from(self.side)
+            }
+
+            pub fn set_side(&mut self, value: Side) {
+                self.side = value.into();
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct OrderBuilder {
+            inner: Order,
+        }
+
+        impl Order {
+            pub fn builder() -> OrderBuilder {
+                OrderBuilder::default()
+            }
+        }
+
+        impl OrderBuilder {
+            pub fn with_id(mut self, value: u64) -> Self {
+                self.inner.id = value;
+                self
+            }
+
+            pub fn with_price(mut self, value: u64) -> Self {
+                self.inner.price = value;
+                self
+            }
+
+            pub fn with_side(mut self, value: i32) -> Self {
+                self.inner.side = value;
+                self
+            }
+
+            pub fn with_size(mut self, value: u64) -> Self {
+                self.inner.amount = OrderAmount::Size(value);
+                self
+            }
+
+            pub fn with_notional(mut self, value: u64) -> Self {
+                self.inner.amount = OrderAmount::Notional(value);
+                self
+            }
+
+            pub fn with_fills(mut self, value: Vec<Fill>) -> Self {
+                self.inner.fills = value;
+                self
+            }
+
+            pub fn add_fills(mut self, value: Fill) -> Self {
+                self.inner.fills.push(value);
+                self
+            }
+
+            pub fn build(self) -> Order {
+                self.inner
+            }
+        }
+
+        impl Order {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.id != 0 {
+                    out.write_scalar("id", self.id);
+                }
+                if self.price != 0 {
+                    out.write_scalar("price", self.price);
+                }
+                if self.side != 0 {
+                    out.write_enum("side", self.side, &[("SIDE_UNSPECIFIED", 0), ("SIDE_BID", 1), ("SIDE_ASK", 2)]);
+                }
+                if let OrderAmount::Size(elem) = &self.amount {
+                    out.write_scalar("size", *elem);
+                }
+                if let OrderAmount::Notional(elem) = &self.amount {
+                    out.write_scalar("notional", *elem);
+                }
+                for elem in &self.fills {
+                    out.begin_message("fills");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Order {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Order {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.id != 0 {
+                    out.key("id");
+                    out.write_u64(self.id);
+                }
+                if self.price != 0 {
+                    out.key("price");
+                    out.write_u64(self.price);
+                }
+                if self.side != 0 {
+                    out.key("side");
+                    out.write_enum(self.side, &[("SIDE_UNSPECIFIED", 0), ("SIDE_BID", 1), ("SIDE_ASK", 2)]);
+                }
+                if let OrderAmount::Size(elem) = &self.amount {
+                    out.key("size");
+                    out.write_u64(*elem);
+                }
+                if let OrderAmount::Notional(elem) = &self.amount {
+                    out.key("notional");
+                    out.write_u64(*elem);
+                }
+                if !self.fills.is_empty() {
+                    out.key("fills");
+                    out.begin_array();
+                    for elem in &self.fills {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "id" => msg.id = json_to_u64(value)?,
+                        "price" => msg.price = json_to_u64(value)?,
+                        "side" => msg.side = json_to_enum(value, &[("SIDE_UNSPECIFIED", 0), ("SIDE_BID", 1), ("SIDE_ASK", 2)])?,
+                        "size" => msg.amount = OrderAmount::Size(json_to_u64(value)?),
+                        "notional" => msg.amount = OrderAmount::Notional(json_to_u64(value)?),
+                        "fills" => {
+                            for elem in json_array(value)? {
+                                msg.fills.push(Fill::from_json_value(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct OrderRef<'a> {
+            raw: &'a [u8],
+            pub id: u64,
+            pub price: u64,
+            pub side: i32,
+            pub size: Option<u64>,
+            pub notional: Option<u64>,
+            pub fills: RepeatedRef<'a, FillRef<'a>>,
+        }
+
+        impl<'a> OrderRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = OrderRef {
+                    raw: data,
+                    id: 0,
+                    price: 0,
+                    side: 0,
+                    size: None,
+                    notional: None,
+                    fills: RepeatedRef::new(data, 50, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((FillRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.id = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.price = v;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.side = v;
+                        pos = new_pos;
+                    }
+                    32 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.size = Some(v);
+                        view.notional = None;
+                        pos = new_pos;
+                    }
+                    40 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.notional = Some(v);
+                        view.size = None;
+                        pos = new_pos;
+                    }
+                    50 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Order::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Order, DecodeError> {
+                Order::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// One execution against an \[Order\].
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Fill {
+            pub qty: u64,
+        }
+
+        impl Fill {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Fill {
+                qty: 0,
+            };
+        }
+
+        impl Default for Fill {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Fill {
+            pub const QTY_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Fill.qty" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: qty
+                if self.qty != 0 {
+                    w.write_varint(self.qty);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.qty != 0 {
+                    len += key_len(1) + varint_len(self.qty);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.qty != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.qty)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.qty = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Fill {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Fill::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Fill::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Fill::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Fill::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Fill::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Fill::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Fill::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Fill {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Fill::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Fill> for Vec<u8> {
+            fn from(msg: Fill) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Fill {
+            const FULL_NAME: &'static str = "docs.Fill";
+        }
+
+        impl Fill {
+            /// Longest encoding of a `Fill` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 11;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct FillBuilder {
+            inner: Fill,
+        }
+
+        impl Fill {
+            pub fn builder() -> FillBuilder {
+                FillBuilder::default()
+            }
+        }
+
+        impl FillBuilder {
+            pub fn with_qty(mut self, value: u64) -> Self {
+                self.inner.qty = value;
+                self
+            }
+
+            pub fn build(self) -> Fill {
+                self.inner
+            }
+        }
+
+        impl Fill {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.qty != 0 {
+                    out.write_scalar("qty", self.qty);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Fill {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Fill {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.qty != 0 {
+                    out.key("qty");
+                    out.write_u64(self.qty);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "qty" => msg.qty = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct FillRef<'a> {
+            raw: &'a [u8],
+            pub qty: u64,
+        }
+
+        impl<'a> FillRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = FillRef {
+                    raw: data,
+                    qty: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.qty = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Fill::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Fill, DecodeError> {
+                Fill::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Ack {
+            pub id: u64,
+        }
+
+        impl Ack {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Ack {
+                id: 0,
+            };
+        }
+
+        impl Default for Ack {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Ack {
+            pub const ID_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Ack.id" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: id
+                if self.id != 0 {
+                    w.write_varint(self.id);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.id = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Ack {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Ack::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Ack::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Ack::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Ack::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Ack::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Ack::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Ack::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Ack {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Ack::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Ack> for Vec<u8> {
+            fn from(msg: Ack) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Ack {
+            const FULL_NAME: &'static str = "docs.Ack";
+        }
+
+        impl Ack {
+            /// Longest encoding of a `Ack` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 11;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct AckBuilder {
+            inner: Ack,
+        }
+
+        impl Ack {
+            pub fn builder() -> AckBuilder {
+                AckBuilder::default()
+            }
+        }
+
+        impl AckBuilder {
+            pub fn with_id(mut self, value: u64) -> Self {
+                self.inner.id = value;
+                self
+            }
+
+            pub fn build(self) -> Ack {
+                self.inner
+            }
+        }
+
+        impl Ack {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.id != 0 {
+                    out.write_scalar("id", self.id);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Ack {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Ack {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.id != 0 {
+                    out.key("id");
+                    out.write_u64(self.id);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "id" => msg.id = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct AckRef<'a> {
+            raw: &'a [u8],
+            pub id: u64,
+        }
+
+        impl<'a> AckRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = AckRef {
+                    raw: data,
+                    id: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.id = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Ack::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Ack, DecodeError> {
+                Ack::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Order(Order),
+            Fill(Fill),
+            Ack(Ack),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Order::FULL_NAME => AnyMessage::Order(Order::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Fill::FULL_NAME => AnyMessage::Fill(Fill::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Ack::FULL_NAME => AnyMessage::Ack(Ack::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Order(msg) => Any::pack(msg),
+                    AnyMessage::Fill(msg) => Any::pack(msg),
+                    AnyMessage::Ack(msg) => Any::pack(msg),
+                }
+            }
+        }
+
+        /// Instructions of the `docs.Book` service, tagged by a leading `u8`.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum BookInstruction {
+            /// Place an order.
+            Place(Order),
+            /// Cancel by id.
+            Cancel(Ack),
+        }
+
+        impl BookInstruction {
+            pub const PLACE_TAG: u8 = 0;
+            pub const CANCEL_TAG: u8 = 1;
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                let (&tag, payload) = data.split_first().ok_or(DecodeError::BufferOverflow)?;
+                match tag {
+                    Self::PLACE_TAG => Ok(BookInstruction::Place(Order::decode_with(payload, opts)?)),
+                    Self::CANCEL_TAG => Ok(BookInstruction::Cancel(Ack::decode_with(payload, opts)?)),
+                    _ => Err(DecodeError::InvalidData("unknown instruction tag")),
+                }
+            }
+
+            pub fn tag(&self) -> u8 {
+                match self {
+                    BookInstruction::Place(_) => Self::PLACE_TAG,
+                    BookInstruction::Cancel(_) => Self::CANCEL_TAG,
+                }
+            }
+
+            /// Instruction data: the tag followed by the encoded request.
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                buf.push(self.tag());
+                match self {
+                    BookInstruction::Place(request) => request.encode_into(&mut buf),
+                    BookInstruction::Cancel(request) => request.encode_into(&mut buf),
+                }
+                buf
+            }
+
+            /// Run the handler for this instruction and encode its response.
+            pub fn dispatch<C, H: BookHandler<C>>(self, handler: &mut H, ctx: C) -> Result<Vec<u8>, H::Error> {
+                match self {
+                    BookInstruction::Place(request) => Ok(handler.place(ctx, request)?.encode()),
+                    BookInstruction::Cancel(request) => Ok(handler.cancel(ctx, request)?.encode()),
+                }
+            }
+
+            /// Decode instruction data and dispatch it, e.g. from the program's
+            /// `process_instruction` entrypoint.
+            pub fn process<C, H: BookHandler<C>>(handler: &mut H, ctx: C, data: &[u8]) -> Result<Vec<u8>, H::Error> {
+                Self::decode(data)?.dispatch(handler, ctx)
+            }
+        }
+
+        /// Order entry.
+        ///
+        /// Program-side implementation of the `docs.Book` service; `C` is the
+        /// per-instruction context, e.g. the program id and accounts.
+        pub trait BookHandler<C> {
+            type Error: From<DecodeError>;
+
+            /// Place an order.
+            fn place(&mut self, ctx: C, request: Order) -> Result<Ack, Self::Error>;
+
+            /// Cancel by id.
+            fn cancel(&mut self, ctx: C, request: Ack) -> Result<Ack, Self::Error>;
+        }
+
+        /// Account validation and instruction builders for the `docs.Book` service.
+        #[cfg(feature = "solana-program")]
+        pub mod book {
+
+            pub mod instruction {
+                use super::super::*;
+
+                /// Place an order.
+                ///
+                /// `Place`, with `request` encoded as `BookInstruction` data.
+                pub fn place(
+                    program_id: Pubkey,
+                    accounts: Vec<solana_program::instruction::AccountMeta>,
+                    request: Order,
+                ) -> solana_program::instruction::Instruction {
+                    solana_program::instruction::Instruction {
+                        program_id,
+                        accounts,
+                        data: BookInstruction::Place(request).encode(),
+                    }
+                }
+
+                /// Cancel by id.
+                ///
+                /// `Cancel`, with `request` encoded as `BookInstruction` data.
+                pub fn cancel(
+                    program_id: Pubkey,
+                    accounts: Vec<solana_program::instruction::AccountMeta>,
+                    request: Ack,
+                ) -> solana_program::instruction::Instruction {
+                    solana_program::instruction::Instruction {
+                        program_id,
+                        accounts,
+                        data: BookInstruction::Cancel(request).encode(),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,3534 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod cow {
+        // Auto-generated by protoc-gen-solana from cow.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+        use std::borrow::Cow;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum ListingStatus {
+            Unset,
+            Reason(String),
+            Code(u32),
+        }
+
+        impl ListingStatus {
+            pub const DEFAULT: Self = ListingStatus::Unset;
+        }
+
+        impl Default for ListingStatus {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: cow_structs, with string and bytes fields borrowed from the
+        /// input and the rest held as the owned message holds them
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Listing {
+            pub title: String,
+            pub image: Vec<u8>,
+            pub subtitle: Option<String>,
+            pub keywords: Vec<String>,
+            pub chunks: Vec<Vec<u8>>,
+            pub label: String,
+            pub note: String,
+            pub seller: Pubkey,
+            pub digest: [u8; 4],
+            pub price: u64,
+            /// A sub-message with strings is its own Cow struct, one without is owned
+            pub owner: Seller,
+            pub backup: Option<Seller>,
+            pub history: Vec<Seller>,
+            pub quote: Price,
+            pub status: ListingStatus,
+            pub attributes: BTreeMap<String, String>,
+        }
+
+        impl Listing {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Listing {
+                title: String::new(),
+                image: Vec::new(),
+                subtitle: None,
+                keywords: Vec::new(),
+                chunks: Vec::new(),
+                label: String::new(),
+                note: String::new(),
+                seller: ZERO_PUBKEY,
+                digest: [0; 4],
+                price: 0,
+                owner: Seller::DEFAULT,
+                backup: None,
+                history: Vec::new(),
+                quote: Price::DEFAULT,
+                status: ListingStatus::Unset,
+                attributes: BTreeMap::new(),
+            };
+        }
+
+        impl Default for Listing {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Listing {
+            pub const TITLE_FIELD_NUMBER: u32 = 1;
+            pub const IMAGE_FIELD_NUMBER: u32 = 2;
+            pub const SUBTITLE_FIELD_NUMBER: u32 = 3;
+            pub const KEYWORDS_FIELD_NUMBER: u32 = 4;
+            pub const CHUNKS_FIELD_NUMBER: u32 = 5;
+            pub const LABEL_FIELD_NUMBER: u32 = 6;
+            pub const NOTE_FIELD_NUMBER: u32 = 7;
+            pub const SELLER_FIELD_NUMBER: u32 = 8;
+            pub const DIGEST_FIELD_NUMBER: u32 = 9;
+            pub const PRICE_FIELD_NUMBER: u32 = 10;
+            pub const OWNER_FIELD_NUMBER: u32 = 11;
+            pub const BACKUP_FIELD_NUMBER: u32 = 12;
+            pub const HISTORY_FIELD_NUMBER: u32 = 13;
+            pub const QUOTE_FIELD_NUMBER: u32 = 14;
+            pub const REASON_FIELD_NUMBER: u32 = 15;
+            pub const CODE_FIELD_NUMBER: u32 = 16;
+            pub const ATTRIBUTES_FIELD_NUMBER: u32 = 17;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Listing.title" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Listing.image" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Listing.subtitle" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Listing.keywords" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "Listing.chunks" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Listing.label" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "Listing.note" },
+                FieldWireType { number: 8, wire_type: 2, packed: false, name: "Listing.seller" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Listing.digest" },
+                FieldWireType { number: 10, wire_type: 0, packed: false, name: "Listing.price" },
+                FieldWireType { number: 11, wire_type: 2, packed: false, name: "Listing.owner" },
+                FieldWireType { number: 12, wire_type: 2, packed: false, name: "Listing.backup" },
+                FieldWireType { number: 13, wire_type: 2, packed: false, name: "Listing.history" },
+                FieldWireType { number: 14, wire_type: 2, packed: false, name: "Listing.quote" },
+                FieldWireType { number: 15, wire_type: 2, packed: false, name: "Listing.reason" },
+                FieldWireType { number: 16, wire_type: 0, packed: false, name: "Listing.code" },
+                FieldWireType { number: 17, wire_type: 2, packed: false, name: "Listing.attributes" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 17: attributes
+                for (key, value) in self.attributes.iter().rev() {
+                    w.write_nested(17, |w| {
+                        w.write_string(value);
+                        w.write_key(0x12);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 16: code
+                if let ListingStatus::Code(elem) = &self.status {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x80);
+                }
+
+                // field 15: reason
+                if let ListingStatus::Reason(elem) = &self.status {
+                    w.write_string(elem);
+                    w.write_key(0x7a);
+                }
+
+                // field 14: quote
+                w.write_message(14, &self.quote);
+
+                // field 13: history
+                for elem in self.history.iter().rev() {
+                    w.write_message(13, elem);
+                }
+
+                // field 12: backup
+                if let Some(elem) = &self.backup {
+                    w.write_message(12, elem);
+                }
+
+                // field 11: owner
+                w.write_message(11, &self.owner);
+
+                // field 10: price
+                if self.price != 0 {
+                    w.write_varint(self.price);
+                    w.write_key(0x50);
+                }
+
+                // field 9: digest
+                if self.digest != [0; 4] {
+                    w.write_bytes(&self.digest);
+                    w.write_key(0x4a);
+                }
+
+                // field 8: seller
+                if self.seller != Pubkey::default() {
+                    w.write_pubkey(&self.seller);
+                    w.write_key(0x42);
+                }
+
+                // field 7: note
+                if !self.note.is_empty() {
+                    w.write_string(&self.note);
+                    w.write_key(0x3a);
+                }
+
+                // field 6: label
+                if !self.label.is_empty() {
+                    w.write_string(&self.label);
+                    w.write_key(0x32);
+                }
+
+                // field 5: chunks
+                for elem in self.chunks.iter().rev() {
+                    w.write_bytes(elem);
+                    w.write_key(0x2a);
+                }
+
+                // field 4: keywords
+                for elem in self.keywords.iter().rev() {
+                    w.write_string(elem);
+                    w.write_key(0x22);
+                }
+
+                // field 3: subtitle
+                if let Some(elem) = &self.subtitle {
+                    w.write_string(elem);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: image
+                if !self.image.is_empty() {
+                    w.write_bytes(&self.image);
+                    w.write_key(0x12);
+                }
+
+                // field 1: title
+                if !self.title.is_empty() {
+                    w.write_string(&self.title);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.title.is_empty() {
+                    len += key_len(1) + bytes_len(self.title.len());
+                }
+                if !self.image.is_empty() {
+                    len += key_len(2) + bytes_len(self.image.len());
+                }
+                if let Some(elem) = &self.subtitle {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                for elem in &self.keywords {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                for elem in &self.chunks {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                if !self.label.is_empty() {
+                    len += key_len(6) + bytes_len(self.label.len());
+                }
+                if !self.note.is_empty() {
+                    len += key_len(7) + bytes_len(self.note.len());
+                }
+                if self.seller != Pubkey::default() {
+                    len += key_len(8) + 33;
+                }
+                if self.digest != [0; 4] {
+                    len += key_len(9) + bytes_len(self.digest.len());
+                }
+                if self.price != 0 {
+                    len += key_len(10) + varint_len(self.price);
+                }
+                len += key_len(11) + bytes_len(self.owner.encoded_len());
+                if let Some(elem) = &self.backup {
+                    len += key_len(12) + bytes_len(elem.encoded_len());
+                }
+                for elem in &self.history {
+                    len += key_len(13) + bytes_len(elem.encoded_len());
+                }
+                len += key_len(14) + bytes_len(self.quote.encoded_len());
+                if let ListingStatus::Reason(elem) = &self.status {
+                    len += key_len(15) + bytes_len(elem.len());
+                }
+                if let ListingStatus::Code(elem) = &self.status {
+                    len += key_len(16) + varint_len(*elem as u64);
+                }
+                for (key, value) in &self.attributes {
+                    len += key_len(17) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.title.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.title)?;
+                }
+                if !self.image.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_bytes(&self.image)?;
+                }
+                if let Some(elem) = &self.subtitle {
+                    w.write_key(0x1a)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.keywords {
+                    w.write_key(0x22)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.chunks {
+                    w.write_key(0x2a)?;
+                    w.write_bytes(elem)?;
+                }
+                if !self.label.is_empty() {
+                    w.write_key(0x32)?;
+                    w.write_string(&self.label)?;
+                }
+                if !self.note.is_empty() {
+                    w.write_key(0x3a)?;
+                    w.write_string(&self.note)?;
+                }
+                if self.seller != Pubkey::default() {
+                    w.write_key(0x42)?;
+                    w.write_pubkey(&self.seller)?;
+                }
+                if self.digest != [0; 4] {
+                    w.write_key(0x4a)?;
+                    w.write_bytes(&self.digest)?;
+                }
+                if self.price != 0 {
+                    w.write_key(0x50)?;
+                    w.write_varint(self.price)?;
+                }
+                w.write_message(11, &self.owner)?;
+                if let Some(elem) = &self.backup {
+                    w.write_message(12, elem)?;
+                }
+                for elem in &self.history {
+                    w.write_message(13, elem)?;
+                }
+                w.write_message(14, &self.quote)?;
+                if let ListingStatus::Reason(elem) = &self.status {
+                    w.write_key(0x7a)?;
+                    w.write_string(elem)?;
+                }
+                if let ListingStatus::Code(elem) = &self.status {
+                    w.write_key(0x80)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                for (key, value) in &self.attributes {
+                    w.write_key(0x8a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x12)?;
+                    w.write_string(value)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if !self.title.is_empty() {
+                    len += key_len(1) + bytes_len(self.title.len());
+                }
+                if !self.image.is_empty() {
+                    len += key_len(2) + bytes_len(self.image.len());
+                }
+                if let Some(elem) = &self.subtitle {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                for elem in &self.keywords {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                for elem in &self.chunks {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                if !self.label.is_empty() {
+                    len += key_len(6) + bytes_len(self.label.len());
+                }
+                if !self.note.is_empty() {
+                    len += key_len(7) + bytes_len(self.note.len());
+                }
+                if self.seller != Pubkey::default() {
+                    len += key_len(8) + 33;
+                }
+                if self.digest != [0; 4] {
+                    len += key_len(9) + bytes_len(self.digest.len());
+                }
+                if self.price != 0 {
+                    len += key_len(10) + varint_len(self.price);
+                }
+                len += key_len(11) + bytes_len(sizes.record(&self.owner));
+                if let Some(elem) = &self.backup {
+                    len += key_len(12) + bytes_len(sizes.record(elem));
+                }
+                for elem in &self.history {
+                    len += key_len(13) + bytes_len(sizes.record(elem));
+                }
+                len += key_len(14) + bytes_len(sizes.record(&self.quote));
+                if let ListingStatus::Reason(elem) = &self.status {
+                    len += key_len(15) + bytes_len(elem.len());
+                }
+                if let ListingStatus::Code(elem) = &self.status {
+                    len += key_len(16) + varint_len(*elem as u64);
+                }
+                for (key, value) in &self.attributes {
+                    len += key_len(17) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if !self.title.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.title)?;
+                }
+                if !self.image.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_bytes(&self.image)?;
+                }
+                if let Some(elem) = &self.subtitle {
+                    w.write_key(0x1a)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.keywords {
+                    w.write_key(0x22)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.chunks {
+                    w.write_key(0x2a)?;
+                    w.write_bytes(elem)?;
+                }
+                if !self.label.is_empty() {
+                    w.write_key(0x32)?;
+                    w.write_string(&self.label)?;
+                }
+                if !self.note.is_empty() {
+                    w.write_key(0x3a)?;
+                    w.write_string(&self.note)?;
+                }
+                if self.seller != Pubkey::default() {
+                    w.write_key(0x42)?;
+                    w.write_pubkey(&self.seller)?;
+                }
+                if self.digest != [0; 4] {
+                    w.write_key(0x4a)?;
+                    w.write_bytes(&self.digest)?;
+                }
+                if self.price != 0 {
+                    w.write_key(0x50)?;
+                    w.write_varint(self.price)?;
+                }
+                w.write_message_sized(11, &self.owner, sizes)?;
+                if let Some(elem) = &self.backup {
+                    w.write_message_sized(12, elem, sizes)?;
+                }
+                for elem in &self.history {
+                    w.write_message_sized(13, elem, sizes)?;
+                }
+                w.write_message_sized(14, &self.quote, sizes)?;
+                if let ListingStatus::Reason(elem) = &self.status {
+                    w.write_key(0x7a)?;
+                    w.write_string(elem)?;
+                }
+                if let ListingStatus::Code(elem) = &self.status {
+                    w.write_key(0x80)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                for (key, value) in &self.attributes {
+                    w.write_key(0x8a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x12)?;
+                    w.write_string(value)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.title = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.image = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.subtitle = Some(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            if self.keywords.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.keywords.push(v);
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            if self.chunks.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.chunks.push(v);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_string_bounded(data, pos, opts, 8, 6)?;
+                            self.label = v;
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_string_lossy_with(data, pos, opts)?;
+                            self.note = v;
+                            pos = new_pos;
+                        }
+                        66 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.seller = v;
+                            pos = new_pos;
+                        }
+                        74 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<4>(data, pos)?;
+                            self.digest = v;
+                            pos = new_pos;
+                        }
+                        80 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.price = v;
+                            pos = new_pos;
+                        }
+                        90 => {
+                            mark_singular(&mut seen, 8, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.owner.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        98 => {
+                            mark_singular(&mut seen, 9, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.backup.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        106 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.history.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.history.push(Seller::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        114 => {
+                            mark_singular(&mut seen, 10, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.quote.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        122 => {
+                            mark_singular(&mut seen, 11, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.status = ListingStatus::Reason(v);
+                            pos = new_pos;
+                        }
+                        128 => {
+                            mark_singular(&mut seen, 11, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.status = ListingStatus::Code(v as u32);
+                            pos = new_pos;
+                        }
+                        138 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = String::new();
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.attributes.len() >= opts.max_repeated_len && !self.attributes.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.attributes.insert(key, value);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    4 => Some(self.keywords.len()),
+                    5 => Some(self.chunks.len()),
+                    13 => Some(self.history.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Listing {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Listing::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Listing::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Listing::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Listing::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Listing::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Listing::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Listing::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Listing {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Listing::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Listing> for Vec<u8> {
+            fn from(msg: Listing) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Listing {
+            const FULL_NAME: &'static str = "example.Listing";
+        }
+
+        impl Listing {
+            /// Check the `(solana.*)` field rules, those of sub-messages
+            /// included; decoding does not. Returns the first rule broken.
+            pub fn validate(&self) -> Result<(), ValidationError> {
+                if self.label.len() > 8 {
+                    return Err(ValidationError::new("label", ValidationRule::MaxLen(8)));
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Listing {
+            pub fn reason(&self) -> Option<&String> {
+                match &self.status {
+                    ListingStatus::Reason(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_reason(&mut self, value: String) {
+                self.status = ListingStatus::Reason(value);
+            }
+
+            pub fn code(&self) -> Option<&u32> {
+                match &self.status {
+                    ListingStatus::Code(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_code(&mut self, value: u32) {
+                self.status = ListingStatus::Code(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct ListingBuilder {
+            inner: Listing,
+        }
+
+        impl Listing {
+            pub fn builder() -> ListingBuilder {
+                ListingBuilder::default()
+            }
+        }
+
+        impl ListingBuilder {
+            pub fn with_title(mut self, value: String) -> Self {
+                self.inner.title = value;
+                self
+            }
+
+            pub fn with_image(mut self, value: Vec<u8>) -> Self {
+                self.inner.image = value;
+                self
+            }
+
+            pub fn with_subtitle(mut self, value: String) -> Self {
+                self.inner.subtitle = Some(value);
+                self
+            }
+
+            pub fn with_keywords(mut self, value: Vec<String>) -> Self {
+                self.inner.keywords = value;
+                self
+            }
+
+            pub fn add_keywords(mut self, value: String) -> Self {
+                self.inner.keywords.push(value);
+                self
+            }
+
+            pub fn with_chunks(mut self, value: Vec<Vec<u8>>) -> Self {
+                self.inner.chunks = value;
+                self
+            }
+
+            pub fn add_chunks(mut self, value: Vec<u8>) -> Self {
+                self.inner.chunks.push(value);
+                self
+            }
+
+            pub fn with_label(mut self, value: String) -> Self {
+                self.inner.label = value;
+                self
+            }
+
+            pub fn with_note(mut self, value: String) -> Self {
+                self.inner.note = value;
+                self
+            }
+
+            pub fn with_seller(mut self, value: Pubkey) -> Self {
+                self.inner.seller = value;
+                self
+            }
+
+            pub fn with_digest(mut self, value: [u8; 4]) -> Self {
+                self.inner.digest = value;
+                self
+            }
+
+            pub fn with_price(mut self, value: u64) -> Self {
+                self.inner.price = value;
+                self
+            }
+
+            pub fn with_owner(mut self, value: Seller) -> Self {
+                self.inner.owner = value;
+                self
+            }
+
+            pub fn with_backup(mut self, value: Seller) -> Self {
+                self.inner.backup = Some(value);
+                self
+            }
+
+            pub fn with_history(mut self, value: Vec<Seller>) -> Self {
+                self.inner.history = value;
+                self
+            }
+
+            pub fn add_history(mut self, value: Seller) -> Self {
+                self.inner.history.push(value);
+                self
+            }
+
+            pub fn with_quote(mut self, value: Price) -> Self {
+                self.inner.quote = value;
+                self
+            }
+
+            pub fn with_reason(mut self, value: String) -> Self {
+                self.inner.status = ListingStatus::Reason(value);
+                self
+            }
+
+            pub fn with_code(mut self, value: u32) -> Self {
+                self.inner.status = ListingStatus::Code(value);
+                self
+            }
+
+            pub fn with_attributes(mut self, value: BTreeMap<String, String>) -> Self {
+                self.inner.attributes = value;
+                self
+            }
+
+            pub fn insert_attributes(mut self, key: String, value: String) -> Self {
+                self.inner.attributes.insert(key, value);
+                self
+            }
+
+            pub fn build(self) -> Listing {
+                self.inner
+            }
+        }
+
+        impl Listing {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.title.is_empty() {
+                    out.write_str("title", &self.title);
+                }
+                if !self.image.is_empty() {
+                    out.write_bytes("image", &self.image);
+                }
+                if let Some(elem) = &self.subtitle {
+                    out.write_str("subtitle", elem);
+                }
+                for elem in &self.keywords {
+                    out.write_str("keywords", elem);
+                }
+                for elem in &self.chunks {
+                    out.write_bytes("chunks", elem);
+                }
+                if !self.label.is_empty() {
+                    out.write_str("label", &self.label);
+                }
+                if !self.note.is_empty() {
+                    out.write_str("note", &self.note);
+                }
+                if self.seller != Pubkey::default() {
+                    out.write_pubkey("seller", &self.seller);
+                }
+                if self.digest != [0; 4] {
+                    out.write_bytes("digest", &self.digest);
+                }
+                if self.price != 0 {
+                    out.write_scalar("price", self.price);
+                }
+                if self.owner != Seller::default() {
+                    out.begin_message("owner");
+                    self.owner.write_text(out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.backup {
+                    out.begin_message("backup");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.history {
+                    out.begin_message("history");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if self.quote != Price::default() {
+                    out.begin_message("quote");
+                    self.quote.write_text(out);
+                    out.end_message();
+                }
+                if let ListingStatus::Reason(elem) = &self.status {
+                    out.write_str("reason", elem);
+                }
+                if let ListingStatus::Code(elem) = &self.status {
+                    out.write_scalar("code", *elem);
+                }
+                for (key, value) in &self.attributes {
+                    out.begin_message("attributes");
+                    out.write_str("key", key);
+                    out.write_str("value", value);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Listing {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Listing {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.title.is_empty() {
+                    out.key("title");
+                    out.write_str(&self.title);
+                }
+                if !self.image.is_empty() {
+                    out.key("image");
+                    out.write_bytes(&self.image);
+                }
+                if let Some(elem) = &self.subtitle {
+                    out.key("subtitle");
+                    out.write_str(elem);
+                }
+                if !self.keywords.is_empty() {
+                    out.key("keywords");
+                    out.begin_array();
+                    for elem in &self.keywords {
+                        out.write_str(elem);
+                    }
+                    out.end_array();
+                }
+                if !self.chunks.is_empty() {
+                    out.key("chunks");
+                    out.begin_array();
+                    for elem in &self.chunks {
+                        out.write_bytes(elem);
+                    }
+                    out.end_array();
+                }
+                if !self.label.is_empty() {
+                    out.key("label");
+                    out.write_str(&self.label);
+                }
+                if !self.note.is_empty() {
+                    out.key("note");
+                    out.write_str(&self.note);
+                }
+                if self.seller != Pubkey::default() {
+                    out.key("seller");
+                    out.write_pubkey(self.seller);
+                }
+                if self.digest != [0; 4] {
+                    out.key("digest");
+                    out.write_bytes(&self.digest);
+                }
+                if self.price != 0 {
+                    out.key("price");
+                    out.write_u64(self.price);
+                }
+                if self.owner != Seller::default() {
+                    out.key("owner");
+                    self.owner.write_json(out);
+                }
+                if let Some(elem) = &self.backup {
+                    out.key("backup");
+                    elem.write_json(out);
+                }
+                if !self.history.is_empty() {
+                    out.key("history");
+                    out.begin_array();
+                    for elem in &self.history {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if self.quote != Price::default() {
+                    out.key("quote");
+                    self.quote.write_json(out);
+                }
+                if let ListingStatus::Reason(elem) = &self.status {
+                    out.key("reason");
+                    out.write_str(elem);
+                }
+                if let ListingStatus::Code(elem) = &self.status {
+                    out.key("code");
+                    out.write_u32(*elem);
+                }
+                if !self.attributes.is_empty() {
+                    out.key("attributes");
+                    out.begin_object();
+                    for (key, value) in &self.attributes {
+                        out.key(key);
+                        out.write_str(value);
+                    }
+                    out.end_object();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "title" => msg.title = json_to_string(value)?,
+                        "image" => msg.image = json_to_bytes(value)?,
+                        "subtitle" => msg.subtitle = Some(json_to_string(value)?),
+                        "keywords" => {
+                            for elem in json_array(value)? {
+                                msg.keywords.push(json_to_string(elem)?);
+                            }
+                        }
+                        "chunks" => {
+                            for elem in json_array(value)? {
+                                msg.chunks.push(json_to_bytes(elem)?);
+                            }
+                        }
+                        "label" => msg.label = json_to_string(value)?,
+                        "note" => msg.note = json_to_string(value)?,
+                        "seller" => msg.seller = json_to_pubkey(value)?,
+                        "digest" => msg.digest = json_to_fixed_bytes::<4>(value)?,
+                        "price" => msg.price = json_to_u64(value)?,
+                        "owner" => msg.owner = Seller::from_json_value(value)?,
+                        "backup" => msg.backup = Some(Seller::from_json_value(value)?),
+                        "history" => {
+                            for elem in json_array(value)? {
+                                msg.history.push(Seller::from_json_value(elem)?);
+                            }
+                        }
+                        "quote" => msg.quote = Price::from_json_value(value)?,
+                        "reason" => msg.status = ListingStatus::Reason(json_to_string(value)?),
+                        "code" => msg.status = ListingStatus::Code(json_to_u32(value)?),
+                        "attributes" => {
+                            for (key, value) in json_object(value)? {
+                                msg.attributes.insert(json_map_key(key)?, json_to_string(value)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct ListingRef<'a> {
+            raw: &'a [u8],
+            pub title: &'a str,
+            pub image: &'a [u8],
+            pub subtitle: Option<&'a str>,
+            pub keywords: RepeatedRef<'a, &'a str>,
+            pub chunks: RepeatedRef<'a, &'a [u8]>,
+            pub label: &'a str,
+            pub note: &'a [u8],
+            pub seller: Pubkey,
+            pub digest: &'a [u8; 4],
+            pub price: u64,
+            pub owner: Option<SellerRef<'a>>,
+            pub backup: Option<SellerRef<'a>>,
+            pub history: RepeatedRef<'a, SellerRef<'a>>,
+            pub quote: Option<PriceRef<'a>>,
+            pub reason: Option<&'a str>,
+            pub code: Option<u32>,
+        }
+
+        impl<'a> ListingRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = ListingRef {
+                    raw: data,
+                    title: "",
+                    image: &[],
+                    subtitle: None,
+                    keywords: RepeatedRef::new(data, 34, decode_str_ref),
+                    chunks: RepeatedRef::new(data, 42, decode_bytes_ref),
+                    label: "",
+                    note: &[],
+                    seller: ZERO_PUBKEY,
+                    digest: &[0; 4],
+                    price: 0,
+                    owner: None,
+                    backup: None,
+                    history: RepeatedRef::new(data, 106, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((SellerRef::decode(v)?, new_pos))
+                    }),
+                    quote: None,
+                    reason: None,
+                    code: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.title = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.image = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.subtitle = Some(v);
+                        pos = new_pos;
+                    }
+                    34 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    42 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    50 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.label = within_max_len(v, 8, 6)?;
+                        pos = new_pos;
+                    }
+                    58 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.note = v;
+                        pos = new_pos;
+                    }
+                    66 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.seller = v;
+                        pos = new_pos;
+                    }
+                    74 => {
+                        let (v, new_pos) = decode_fixed_bytes_ref::<4>(data, pos)?;
+                        view.digest = v;
+                        pos = new_pos;
+                    }
+                    80 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.price = v;
+                        pos = new_pos;
+                    }
+                    90 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.owner = Some(SellerRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    98 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.backup = Some(SellerRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    106 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    114 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.quote = Some(PriceRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    122 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.reason = Some(v);
+                        view.code = None;
+                        pos = new_pos;
+                    }
+                    128 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.code = Some(v as u32);
+                        view.reason = None;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Listing::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Listing, DecodeError> {
+                Listing::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct ListingCow<'a> {
+            pub title: Cow<'a, str>,
+            pub image: Cow<'a, [u8]>,
+            pub subtitle: Option<Cow<'a, str>>,
+            pub keywords: Vec<Cow<'a, str>>,
+            pub chunks: Vec<Cow<'a, [u8]>>,
+            pub label: Cow<'a, str>,
+            pub note: Cow<'a, str>,
+            pub seller: Pubkey,
+            pub digest: [u8; 4],
+            pub price: u64,
+            pub owner: SellerCow<'a>,
+            pub backup: Option<SellerCow<'a>>,
+            pub history: Vec<SellerCow<'a>>,
+            pub quote: Price,
+            pub status: ListingStatus,
+            pub attributes: BTreeMap<String, String>,
+        }
+
+        impl ListingCow<'_> {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = ListingCow {
+                title: Cow::Borrowed(""),
+                image: Cow::Borrowed(&[]),
+                subtitle: None,
+                keywords: Vec::new(),
+                chunks: Vec::new(),
+                label: Cow::Borrowed(""),
+                note: Cow::Borrowed(""),
+                seller: ZERO_PUBKEY,
+                digest: [0; 4],
+                price: 0,
+                owner: SellerCow::DEFAULT,
+                backup: None,
+                history: Vec::new(),
+                quote: Price::DEFAULT,
+                status: ListingStatus::Unset,
+                attributes: BTreeMap::new(),
+            };
+
+            /// This value with every borrowed field copied, so it outlives its input.
+            pub fn into_owned(self) -> ListingCow<'static> {
+                Listing::from(self).into()
+            }
+        }
+
+        impl Default for ListingCow<'_> {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<ListingCow<'_>> for Listing {
+            fn from(msg: ListingCow<'_>) -> Self {
+                Listing {
+                    title: msg.title.into_owned(),
+                    image: msg.image.into_owned(),
+                    subtitle: msg.subtitle.map(Cow::into_owned),
+                    keywords: msg.keywords.into_iter().map(Cow::into_owned).collect(),
+                    chunks: msg.chunks.into_iter().map(Cow::into_owned).collect(),
+                    label: msg.label.into_owned(),
+                    note: msg.note.into_owned(),
+                    seller: msg.seller,
+                    digest: msg.digest,
+                    price: msg.price,
+                    owner: msg.owner.into(),
+                    backup: msg.backup.map(Seller::from),
+                    history: msg.history.into_iter().map(Seller::from).collect(),
+                    quote: msg.quote,
+                    status: msg.status,
+                    attributes: msg.attributes,
+                }
+            }
+        }
+
+        impl From<Listing> for ListingCow<'_> {
+            fn from(msg: Listing) -> Self {
+                ListingCow {
+                    title: Cow::Owned(msg.title),
+                    image: Cow::Owned(msg.image),
+                    subtitle: msg.subtitle.map(Cow::Owned),
+                    keywords: msg.keywords.into_iter().map(Cow::Owned).collect(),
+                    chunks: msg.chunks.into_iter().map(Cow::Owned).collect(),
+                    label: Cow::Owned(msg.label),
+                    note: Cow::Owned(msg.note),
+                    seller: msg.seller,
+                    digest: msg.digest,
+                    price: msg.price,
+                    owner: msg.owner.into(),
+                    backup: msg.backup.map(SellerCow::from),
+                    history: msg.history.into_iter().map(SellerCow::from).collect(),
+                    quote: msg.quote,
+                    status: msg.status,
+                    attributes: msg.attributes,
+                }
+            }
+        }
+
+        impl<'a> ListingCow<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &'a [u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &'a [u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &'a [u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &'a [u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &'a [u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &'a [u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_str_cow(data, pos)?;
+                            self.title = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_bytes_cow(data, pos)?;
+                            self.image = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_str_cow(data, pos)?;
+                            self.subtitle = Some(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let (v, new_pos) = decode_str_cow(data, pos)?;
+                            if self.keywords.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.keywords.push(v);
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let (v, new_pos) = decode_bytes_cow(data, pos)?;
+                            if self.chunks.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.chunks.push(v);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_str_cow_bounded(data, pos, 8, 6)?;
+                            self.label = v;
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_str_lossy_cow(data, pos)?;
+                            self.note = v;
+                            pos = new_pos;
+                        }
+                        66 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.seller = v;
+                            pos = new_pos;
+                        }
+                        74 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<4>(data, pos)?;
+                            self.digest = v;
+                            pos = new_pos;
+                        }
+                        80 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.price = v;
+                            pos = new_pos;
+                        }
+                        90 => {
+                            mark_singular(&mut seen, 8, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.owner.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        98 => {
+                            mark_singular(&mut seen, 9, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.backup.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        106 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.history.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.history.push(SellerCow::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        114 => {
+                            mark_singular(&mut seen, 10, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.quote.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        122 => {
+                            mark_singular(&mut seen, 11, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.status = ListingStatus::Reason(v);
+                            pos = new_pos;
+                        }
+                        128 => {
+                            mark_singular(&mut seen, 11, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.status = ListingStatus::Code(v as u32);
+                            pos = new_pos;
+                        }
+                        138 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = String::new();
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.attributes.len() >= opts.max_repeated_len && !self.attributes.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.attributes.insert(key, value);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Listing::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Listing::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Listing::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    4 => Some(self.keywords.len()),
+                    5 => Some(self.chunks.len()),
+                    13 => Some(self.history.len()),
+                    _ => None,
+                }
+            }
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 17: attributes
+                for (key, value) in self.attributes.iter().rev() {
+                    w.write_nested(17, |w| {
+                        w.write_string(value);
+                        w.write_key(0x12);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 16: code
+                if let ListingStatus::Code(elem) = &self.status {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x80);
+                }
+
+                // field 15: reason
+                if let ListingStatus::Reason(elem) = &self.status {
+                    w.write_string(elem);
+                    w.write_key(0x7a);
+                }
+
+                // field 14: quote
+                w.write_message(14, &self.quote);
+
+                // field 13: history
+                for elem in self.history.iter().rev() {
+                    w.write_nested(13, |w| elem.write_reverse(w));
+                }
+
+                // field 12: backup
+                if let Some(elem) = &self.backup {
+                    w.write_nested(12, |w| elem.write_reverse(w));
+                }
+
+                // field 11: owner
+                w.write_nested(11, |w| self.owner.write_reverse(w));
+
+                // field 10: price
+                if self.price != 0 {
+                    w.write_varint(self.price);
+                    w.write_key(0x50);
+                }
+
+                // field 9: digest
+                if self.digest != [0; 4] {
+                    w.write_bytes(&self.digest);
+                    w.write_key(0x4a);
+                }
+
+                // field 8: seller
+                if self.seller != Pubkey::default() {
+                    w.write_pubkey(&self.seller);
+                    w.write_key(0x42);
+                }
+
+                // field 7: note
+                if !self.note.is_empty() {
+                    w.write_string(&self.note);
+                    w.write_key(0x3a);
+                }
+
+                // field 6: label
+                if !self.label.is_empty() {
+                    w.write_string(&self.label);
+                    w.write_key(0x32);
+                }
+
+                // field 5: chunks
+                for elem in self.chunks.iter().rev() {
+                    w.write_bytes(elem);
+                    w.write_key(0x2a);
+                }
+
+                // field 4: keywords
+                for elem in self.keywords.iter().rev() {
+                    w.write_string(elem);
+                    w.write_key(0x22);
+                }
+
+                // field 3: subtitle
+                if let Some(elem) = &self.subtitle {
+                    w.write_string(elem);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: image
+                if !self.image.is_empty() {
+                    w.write_bytes(&self.image);
+                    w.write_key(0x12);
+                }
+
+                // field 1: title
+                if !self.title.is_empty() {
+                    w.write_string(&self.title);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.title.is_empty() {
+                    len += key_len(1) + bytes_len(self.title.len());
+                }
+                if !self.image.is_empty() {
+                    len += key_len(2) + bytes_len(self.image.len());
+                }
+                if let Some(elem) = &self.subtitle {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                for elem in &self.keywords {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                for elem in &self.chunks {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                if !self.label.is_empty() {
+                    len += key_len(6) + bytes_len(self.label.len());
+                }
+                if !self.note.is_empty() {
+                    len += key_len(7) + bytes_len(self.note.len());
+                }
+                if self.seller != Pubkey::default() {
+                    len += key_len(8) + 33;
+                }
+                if self.digest != [0; 4] {
+                    len += key_len(9) + bytes_len(self.digest.len());
+                }
+                if self.price != 0 {
+                    len += key_len(10) + varint_len(self.price);
+                }
+                len += key_len(11) + bytes_len(self.owner.encoded_len());
+                if let Some(elem) = &self.backup {
+                    len += key_len(12) + bytes_len(elem.encoded_len());
+                }
+                for elem in &self.history {
+                    len += key_len(13) + bytes_len(elem.encoded_len());
+                }
+                len += key_len(14) + bytes_len(self.quote.encoded_len());
+                if let ListingStatus::Reason(elem) = &self.status {
+                    len += key_len(15) + bytes_len(elem.len());
+                }
+                if let ListingStatus::Code(elem) = &self.status {
+                    len += key_len(16) + varint_len(*elem as u64);
+                }
+                for (key, value) in &self.attributes {
+                    len += key_len(17) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.title.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.title)?;
+                }
+                if !self.image.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_bytes(&self.image)?;
+                }
+                if let Some(elem) = &self.subtitle {
+                    w.write_key(0x1a)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.keywords {
+                    w.write_key(0x22)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.chunks {
+                    w.write_key(0x2a)?;
+                    w.write_bytes(elem)?;
+                }
+                if !self.label.is_empty() {
+                    w.write_key(0x32)?;
+                    w.write_string(&self.label)?;
+                }
+                if !self.note.is_empty() {
+                    w.write_key(0x3a)?;
+                    w.write_string(&self.note)?;
+                }
+                if self.seller != Pubkey::default() {
+                    w.write_key(0x42)?;
+                    w.write_pubkey(&self.seller)?;
+                }
+                if self.digest != [0; 4] {
+                    w.write_key(0x4a)?;
+                    w.write_bytes(&self.digest)?;
+                }
+                if self.price != 0 {
+                    w.write_key(0x50)?;
+                    w.write_varint(self.price)?;
+                }
+                w.write_key(0x5a)?;
+                w.write_varint(self.owner.encoded_len() as u64)?;
+                self.owner.write_to(w)?;
+                if let Some(elem) = &self.backup {
+                    w.write_key(0x62)?;
+                    w.write_varint(elem.encoded_len() as u64)?;
+                    elem.write_to(w)?;
+                }
+                for elem in &self.history {
+                    w.write_key(0x6a)?;
+                    w.write_varint(elem.encoded_len() as u64)?;
+                    elem.write_to(w)?;
+                }
+                w.write_message(14, &self.quote)?;
+                if let ListingStatus::Reason(elem) = &self.status {
+                    w.write_key(0x7a)?;
+                    w.write_string(elem)?;
+                }
+                if let ListingStatus::Code(elem) = &self.status {
+                    w.write_key(0x80)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                for (key, value) in &self.attributes {
+                    w.write_key(0x8a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x12)?;
+                    w.write_string(value)?;
+                }
+                Ok(())
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Seller {
+            pub name: String,
+            pub rating: u32,
+        }
+
+        impl Seller {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Seller {
+                name: String::new(),
+                rating: 0,
+            };
+        }
+
+        impl Default for Seller {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Seller {
+            pub const NAME_FIELD_NUMBER: u32 = 1;
+            pub const RATING_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Seller.name" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Seller.rating" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: rating
+                if self.rating != 0 {
+                    w.write_varint(self.rating as u64);
+                    w.write_key(0x10);
+                }
+
+                // field 1: name
+                if !self.name.is_empty() {
+                    w.write_string(&self.name);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.name.is_empty() {
+                    len += key_len(1) + bytes_len(self.name.len());
+                }
+                if self.rating != 0 {
+                    len += key_len(2) + varint_len(self.rating as u64);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.name.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.name)?;
+                }
+                if self.rating != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.rating as u64)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.name = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.rating = v as u32;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Seller {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Seller::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Seller::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Seller::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Seller::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Seller::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Seller::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Seller::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Seller {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Seller::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Seller> for Vec<u8> {
+            fn from(msg: Seller) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Seller {
+            const FULL_NAME: &'static str = "example.Seller";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct SellerBuilder {
+            inner: Seller,
+        }
+
+        impl Seller {
+            pub fn builder() -> SellerBuilder {
+                SellerBuilder::default()
+            }
+        }
+
+        impl SellerBuilder {
+            pub fn with_name(mut self, value: String) -> Self {
+                self.inner.name = value;
+                self
+            }
+
+            pub fn with_rating(mut self, value: u32) -> Self {
+                self.inner.rating = value;
+                self
+            }
+
+            pub fn build(self) -> Seller {
+                self.inner
+            }
+        }
+
+        impl Seller {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.name.is_empty() {
+                    out.write_str("name", &self.name);
+                }
+                if self.rating != 0 {
+                    out.write_scalar("rating", self.rating);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Seller {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Seller {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.name.is_empty() {
+                    out.key("name");
+                    out.write_str(&self.name);
+                }
+                if self.rating != 0 {
+                    out.key("rating");
+                    out.write_u32(self.rating);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "name" => msg.name = json_to_string(value)?,
+                        "rating" => msg.rating = json_to_u32(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct SellerRef<'a> {
+            raw: &'a [u8],
+            pub name: &'a str,
+            pub rating: u32,
+        }
+
+        impl<'a> SellerRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = SellerRef {
+                    raw: data,
+                    name: "",
+                    rating: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.name = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.rating = v as u32;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Seller::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Seller, DecodeError> {
+                Seller::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct SellerCow<'a> {
+            pub name: Cow<'a, str>,
+            pub rating: u32,
+        }
+
+        impl SellerCow<'_> {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = SellerCow {
+                name: Cow::Borrowed(""),
+                rating: 0,
+            };
+
+            /// This value with every borrowed field copied, so it outlives its input.
+            pub fn into_owned(self) -> SellerCow<'static> {
+                Seller::from(self).into()
+            }
+        }
+
+        impl Default for SellerCow<'_> {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<SellerCow<'_>> for Seller {
+            fn from(msg: SellerCow<'_>) -> Self {
+                Seller {
+                    name: msg.name.into_owned(),
+                    rating: msg.rating,
+                }
+            }
+        }
+
+        impl From<Seller> for SellerCow<'_> {
+            fn from(msg: Seller) -> Self {
+                SellerCow {
+                    name: Cow::Owned(msg.name),
+                    rating: msg.rating,
+                }
+            }
+        }
+
+        impl<'a> SellerCow<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &'a [u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &'a [u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &'a [u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &'a [u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &'a [u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &'a [u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_str_cow(data, pos)?;
+                            self.name = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.rating = v as u32;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Seller::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Seller::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Seller::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: rating
+                if self.rating != 0 {
+                    w.write_varint(self.rating as u64);
+                    w.write_key(0x10);
+                }
+
+                // field 1: name
+                if !self.name.is_empty() {
+                    w.write_string(&self.name);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.name.is_empty() {
+                    len += key_len(1) + bytes_len(self.name.len());
+                }
+                if self.rating != 0 {
+                    len += key_len(2) + varint_len(self.rating as u64);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.name.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.name)?;
+                }
+                if self.rating != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.rating as u64)?;
+                }
+                Ok(())
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Price {
+            pub amount: u64,
+            pub decimals: u32,
+        }
+
+        impl Price {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Price {
+                amount: 0,
+                decimals: 0,
+            };
+        }
+
+        impl Default for Price {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Price {
+            pub const AMOUNT_FIELD_NUMBER: u32 = 1;
+            pub const DECIMALS_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Price.amount" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Price.decimals" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: decimals
+                if self.decimals != 0 {
+                    w.write_varint(self.decimals as u64);
+                    w.write_key(0x10);
+                }
+
+                // field 1: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.amount != 0 {
+                    len += key_len(1) + varint_len(self.amount);
+                }
+                if self.decimals != 0 {
+                    len += key_len(2) + varint_len(self.decimals as u64);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.amount != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.amount)?;
+                }
+                if self.decimals != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.decimals as u64)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.decimals = v as u32;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Price {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Price::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Price::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Price::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Price::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Price::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Price::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Price::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Price {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Price::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Price> for Vec<u8> {
+            fn from(msg: Price) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Price {
+            const FULL_NAME: &'static str = "example.Price";
+        }
+
+        impl Price {
+            /// Longest encoding of a `Price` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 17;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct PriceBuilder {
+            inner: Price,
+        }
+
+        impl Price {
+            pub fn builder() -> PriceBuilder {
+                PriceBuilder::default()
+            }
+        }
+
+        impl PriceBuilder {
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn with_decimals(mut self, value: u32) -> Self {
+                self.inner.decimals = value;
+                self
+            }
+
+            pub fn build(self) -> Price {
+                self.inner
+            }
+        }
+
+        impl Price {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+                if self.decimals != 0 {
+                    out.write_scalar("decimals", self.decimals);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Price {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Price {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                if self.decimals != 0 {
+                    out.key("decimals");
+                    out.write_u32(self.decimals);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        "decimals" => msg.decimals = json_to_u32(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct PriceRef<'a> {
+            raw: &'a [u8],
+            pub amount: u64,
+            pub decimals: u32,
+        }
+
+        impl<'a> PriceRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = PriceRef {
+                    raw: data,
+                    amount: 0,
+                    decimals: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.decimals = v as u32;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Price::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Price, DecodeError> {
+                Price::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Exercises: a recursive message's boxed Cow struct
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Thread {
+            pub text: String,
+            pub reply: Option<Box<Thread>>,
+        }
+
+        impl Thread {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Thread {
+                text: String::new(),
+                reply: None,
+            };
+        }
+
+        impl Default for Thread {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Thread {
+            pub const TEXT_FIELD_NUMBER: u32 = 1;
+            pub const REPLY_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Thread.text" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Thread.reply" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: reply
+                if let Some(elem) = &self.reply {
+                    w.write_message(2, &**elem);
+                }
+
+                // field 1: text
+                if !self.text.is_empty() {
+                    w.write_string(&self.text);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.text.is_empty() {
+                    len += key_len(1) + bytes_len(self.text.len());
+                }
+                if let Some(elem) = &self.reply {
+                    len += key_len(2) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.text.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.text)?;
+                }
+                if let Some(elem) = &self.reply {
+                    w.write_message(2, &**elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if !self.text.is_empty() {
+                    len += key_len(1) + bytes_len(self.text.len());
+                }
+                if let Some(elem) = &self.reply {
+                    len += key_len(2) + bytes_len(sizes.record(&**elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if !self.text.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.text)?;
+                }
+                if let Some(elem) = &self.reply {
+                    w.write_message_sized(2, &**elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.text = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.reply.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Thread {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Thread::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Thread::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Thread::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Thread::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Thread::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Thread::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Thread::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Thread {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Thread::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Thread> for Vec<u8> {
+            fn from(msg: Thread) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Thread {
+            const FULL_NAME: &'static str = "example.Thread";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct ThreadBuilder {
+            inner: Thread,
+        }
+
+        impl Thread {
+            pub fn builder() -> ThreadBuilder {
+                ThreadBuilder::default()
+            }
+        }
+
+        impl ThreadBuilder {
+            pub fn with_text(mut self, value: String) -> Self {
+                self.inner.text = value;
+                self
+            }
+
+            pub fn with_reply(mut self, value: Thread) -> Self {
+                self.inner.reply = Some(value.into());
+                self
+            }
+
+            pub fn build(self) -> Thread {
+                self.inner
+            }
+        }
+
+        impl Thread {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.text.is_empty() {
+                    out.write_str("text", &self.text);
+                }
+                if let Some(elem) = &self.reply {
+                    out.begin_message("reply");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Thread {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Thread {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.text.is_empty() {
+                    out.key("text");
+                    out.write_str(&self.text);
+                }
+                if let Some(elem) = &self.reply {
+                    out.key("reply");
+                    elem.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "text" => msg.text = json_to_string(value)?,
+                        "reply" => msg.reply = Some(Thread::from_json_value(value)?.into()),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct ThreadRef<'a> {
+            raw: &'a [u8],
+            pub text: &'a str,
+            pub reply: Option<LazyRef<'a, ThreadRef<'a>>>,
+        }
+
+        impl<'a> ThreadRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = ThreadRef {
+                    raw: data,
+                    text: "",
+                    reply: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.text = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.reply = Some(LazyRef::new(v, ThreadRef::decode));
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Thread::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Thread, DecodeError> {
+                Thread::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct ThreadCow<'a> {
+            pub text: Cow<'a, str>,
+            pub reply: Option<Box<ThreadCow<'a>>>,
+        }
+
+        impl ThreadCow<'_> {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = ThreadCow {
+                text: Cow::Borrowed(""),
+                reply: None,
+            };
+
+            /// This value with every borrowed field copied, so it outlives its input.
+            pub fn into_owned(self) -> ThreadCow<'static> {
+                Thread::from(self).into()
+            }
+        }
+
+        impl Default for ThreadCow<'_> {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<ThreadCow<'_>> for Thread {
+            fn from(msg: ThreadCow<'_>) -> Self {
+                Thread {
+                    text: msg.text.into_owned(),
+                    reply: msg.reply.map(|mut v| Thread::from(core::mem::take(&mut *v)).into()),
+                }
+            }
+        }
+
+        impl From<Thread> for ThreadCow<'_> {
+            fn from(msg: Thread) -> Self {
+                ThreadCow {
+                    text: Cow::Owned(msg.text),
+                    reply: msg.reply.map(|mut v| ThreadCow::from(core::mem::take(&mut *v)).into()),
+                }
+            }
+        }
+
+        impl<'a> ThreadCow<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &'a [u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &'a [u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &'a [u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &'a [u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &'a [u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &'a [u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_str_cow(data, pos)?;
+                            self.text = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.reply.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Thread::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Thread::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Thread::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: reply
+                if let Some(elem) = &self.reply {
+                    w.write_nested(2, |w| elem.write_reverse(w));
+                }
+
+                // field 1: text
+                if !self.text.is_empty() {
+                    w.write_string(&self.text);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.text.is_empty() {
+                    len += key_len(1) + bytes_len(self.text.len());
+                }
+                if let Some(elem) = &self.reply {
+                    len += key_len(2) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.text.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.text)?;
+                }
+                if let Some(elem) = &self.reply {
+                    w.write_key(0x12)?;
+                    w.write_varint(elem.encoded_len() as u64)?;
+                    elem.write_to(w)?;
+                }
+                Ok(())
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Listing(Listing),
+            Seller(Seller),
+            Price(Price),
+            Thread(Thread),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Listing::FULL_NAME => AnyMessage::Listing(Listing::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Seller::FULL_NAME => AnyMessage::Seller(Seller::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Price::FULL_NAME => AnyMessage::Price(Price::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Thread::FULL_NAME => AnyMessage::Thread(Thread::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Listing(msg) => Any::pack(msg),
+                    AnyMessage::Seller(msg) => Any::pack(msg),
+                    AnyMessage::Price(msg) => Any::pack(msg),
+                    AnyMessage::Thread(msg) => Any::pack(msg),
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            /// `n` copies of `value`.
+            fn repeated<T: Clone>(value: T, n: usize) -> Vec<T> {
+                (0..n).map(|_| value.clone()).collect()
+            }
+
+            /// Map key `i`, zero-padded to `width` digits.
+            fn key(i: usize, width: usize) -> String {
+                let digits = i.to_string();
+                "0".repeat(width.saturating_sub(digits.len())) + &digits
+            }
+
+            fn sample_listing() -> Listing {
+                Listing {
+                    title: "x".repeat(8),
+                    image: [7u8].repeat(8),
+                    subtitle: Some("x".repeat(8)),
+                    keywords: repeated("x".repeat(8), 2),
+                    chunks: repeated([7u8].repeat(8), 2),
+                    label: "x".repeat(8),
+                    note: "x".repeat(8),
+                    seller: pubkey_from_array([7; 32]),
+                    digest: [7; 4],
+                    price: u64::MAX,
+                    owner: sample_seller(),
+                    backup: Some(sample_seller()),
+                    history: repeated(sample_seller(), 2),
+                    quote: sample_price(),
+                    status: ListingStatus::Reason("x".repeat(8)),
+                    attributes: (0..2usize).map(|i| (key(i, 1), "x".repeat(8))).collect(),
+                }
+            }
+
+            #[test]
+            fn listing_round_trips() {
+                let msg = sample_listing();
+                let bytes = msg.encode();
+                assert_eq!(bytes.len(), msg.encoded_len());
+                assert_eq!(Listing::decode(&bytes).unwrap(), msg);
+                assert_eq!(ListingRef::decode(&bytes).unwrap().to_message().unwrap(), msg);
+                let cow = ListingCow::decode(&bytes).unwrap();
+                assert_eq!(cow.encode(), bytes);
+                assert_eq!(Listing::from(cow), msg);
+            }
+
+            #[test]
+            fn listing_encodes_canonically() {
+                assert!(verify_canonical::<Listing>(&sample_listing().encode()));
+            }
+
+            fn sample_seller() -> Seller {
+                Seller {
+                    name: "x".repeat(8),
+                    rating: u32::MAX,
+                }
+            }
+
+            #[test]
+            fn seller_round_trips() {
+                let msg = sample_seller();
+                let bytes = msg.encode();
+                assert_eq!(bytes.len(), msg.encoded_len());
+                assert_eq!(Seller::decode(&bytes).unwrap(), msg);
+                assert_eq!(SellerRef::decode(&bytes).unwrap().to_message().unwrap(), msg);
+                let cow = SellerCow::decode(&bytes).unwrap();
+                assert_eq!(cow.encode(), bytes);
+                assert_eq!(Seller::from(cow), msg);
+            }
+
+            #[test]
+            fn seller_encodes_canonically() {
+                assert!(verify_canonical::<Seller>(&sample_seller().encode()));
+            }
+
+            fn sample_price() -> Price {
+                Price {
+                    amount: u64::MAX,
+                    decimals: u32::MAX,
+                }
+            }
+
+            #[test]
+            fn price_round_trips() {
+                let msg = sample_price();
+                let bytes = msg.encode();
+                assert_eq!(bytes.len(), msg.encoded_len());
+                assert_eq!(Price::decode(&bytes).unwrap(), msg);
+                assert_eq!(PriceRef::decode(&bytes).unwrap().to_message().unwrap(), msg);
+            }
+
+            #[test]
+            fn price_encodes_canonically() {
+                assert!(verify_canonical::<Price>(&sample_price().encode()));
+            }
+
+            #[test]
+            fn price_fits_max_encoded_len() {
+                assert!(sample_price().encoded_len() <= Price::MAX_ENCODED_LEN);
+            }
+
+            fn sample_thread() -> Thread {
+                Thread {
+                    text: "x".repeat(8),
+                    reply: None,
+                }
+            }
+
+            #[test]
+            fn thread_round_trips() {
+                let msg = sample_thread();
+                let bytes = msg.encode();
+                assert_eq!(bytes.len(), msg.encoded_len());
+                assert_eq!(Thread::decode(&bytes).unwrap(), msg);
+                assert_eq!(ThreadRef::decode(&bytes).unwrap().to_message().unwrap(), msg);
+                let cow = ThreadCow::decode(&bytes).unwrap();
+                assert_eq!(cow.encode(), bytes);
+                assert_eq!(Thread::from(cow), msg);
+            }
+
+            #[test]
+            fn thread_encodes_canonically() {
+                assert!(verify_canonical::<Thread>(&sample_thread().encode()));
+            }
+        }
+    }
+}
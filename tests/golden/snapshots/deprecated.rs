@@ -0,0 +1,1374 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod legacy {
+    pub mod deprecated {
+        // Auto-generated by protoc-gen-solana from deprecated.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls, deprecated)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: deprecated messages, fields, oneof members, enums and enum
+        /// values getting #\[deprecated\], and the omit_deprecated option leaving
+        /// deprecated fields out of the encoding (except required ones)
+        ///
+        /// `legacy.Side`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum Side {
+            Unspecified = 0,
+            Bid = 1,
+            Ask = 2,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            Cross = 3,
+        }
+
+        impl Side {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: Side = Side::Unspecified;
+
+            /// `SIDE_SELL`, an alias of `Ask`
+            #[allow(non_upper_case_globals)]
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub const Sell: Side = Side::Ask;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Side::Unspecified => "SIDE_UNSPECIFIED",
+                    Side::Bid => "SIDE_BID",
+                    Side::Ask => "SIDE_ASK",
+                    Side::Cross => "SIDE_CROSS",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "SIDE_UNSPECIFIED" => Some(Side::Unspecified),
+                    "SIDE_BID" => Some(Side::Bid),
+                    "SIDE_ASK" => Some(Side::Ask),
+                    "SIDE_CROSS" => Some(Side::Cross),
+                    "SIDE_SELL" => Some(Side::Ask),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for Side {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<Side> for i32 {
+            fn from(value: Side) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for Side {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(Side::Unspecified),
+                    1 => Ok(Side::Bid),
+                    2 => Ok(Side::Ask),
+                    3 => Ok(Side::Cross),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// `legacy.FeeTier`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        #[deprecated(note = "marked deprecated in the .proto definition")]
+        pub enum FeeTier {
+            Unspecified = 0,
+            Maker = 1,
+        }
+
+        impl FeeTier {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: FeeTier = FeeTier::Unspecified;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    FeeTier::Unspecified => "FEE_TIER_UNSPECIFIED",
+                    FeeTier::Maker => "FEE_TIER_MAKER",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "FEE_TIER_UNSPECIFIED" => Some(FeeTier::Unspecified),
+                    "FEE_TIER_MAKER" => Some(FeeTier::Maker),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for FeeTier {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<FeeTier> for i32 {
+            fn from(value: FeeTier) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for FeeTier {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(FeeTier::Unspecified),
+                    1 => Ok(FeeTier::Maker),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum TradeSettle {
+            Unset,
+            Amount(u64),
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            LegacyReceipt(Vec<u8>),
+        }
+
+        impl TradeSettle {
+            pub const DEFAULT: Self = TradeSettle::Unset;
+        }
+
+        impl Default for TradeSettle {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Trade {
+            pub id: u64,
+            pub side: i32,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub old_price: u64,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub venue: String,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub tier: i32,
+            pub settle: TradeSettle,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub version: u32,
+            pub quote: Quote,
+        }
+
+        impl Trade {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Trade {
+                id: 0,
+                side: 0,
+                old_price: 0,
+                venue: String::new(),
+                tier: 0,
+                settle: TradeSettle::Unset,
+                version: 0,
+                quote: Quote::DEFAULT,
+            };
+        }
+
+        impl Default for Trade {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Trade {
+            pub const ID_FIELD_NUMBER: u32 = 1;
+            pub const SIDE_FIELD_NUMBER: u32 = 2;
+            pub const OLD_PRICE_FIELD_NUMBER: u32 = 3;
+            pub const VENUE_FIELD_NUMBER: u32 = 4;
+            pub const TIER_FIELD_NUMBER: u32 = 5;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 6;
+            pub const LEGACY_RECEIPT_FIELD_NUMBER: u32 = 7;
+            pub const VERSION_FIELD_NUMBER: u32 = 8;
+            pub const QUOTE_FIELD_NUMBER: u32 = 9;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Trade.id" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Trade.side" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "Trade.old_price" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Trade.venue" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "Trade.tier" },
+                FieldWireType { number: 6, wire_type: 0, packed: false, name: "Trade.amount" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "Trade.legacy_receipt" },
+                FieldWireType { number: 8, wire_type: 0, packed: false, name: "Trade.version" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Trade.quote" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 9: quote
+                w.write_message(9, &self.quote);
+
+                // field 8: version
+                if self.version != 0 {
+                    w.write_varint(self.version as u64);
+                    w.write_key(0x40);
+                }
+
+                // field 7: legacy_receipt
+                if let TradeSettle::LegacyReceipt(elem) = &self.settle {
+                    w.write_bytes(elem);
+                    w.write_key(0x3a);
+                }
+
+                // field 6: amount
+                if let TradeSettle::Amount(elem) = &self.settle {
+                    w.write_varint(*elem);
+                    w.write_key(0x30);
+                }
+
+                // field 5: tier
+                if self.tier != 0 {
+                    w.write_int32(self.tier);
+                    w.write_key(0x28);
+                }
+
+                // field 4: venue
+                if !self.venue.is_empty() {
+                    w.write_string(&self.venue);
+                    w.write_key(0x22);
+                }
+
+                // field 3: old_price
+                if self.old_price != 0 {
+                    w.write_varint(self.old_price);
+                    w.write_key(0x18);
+                }
+
+                // field 2: side
+                if self.side != 0 {
+                    w.write_int32(self.side);
+                    w.write_key(0x10);
+                }
+
+                // field 1: id
+                if self.id != 0 {
+                    w.write_varint(self.id);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                if self.side != 0 {
+                    len += key_len(2) + int32_len(self.side);
+                }
+                if self.old_price != 0 {
+                    len += key_len(3) + varint_len(self.old_price);
+                }
+                if !self.venue.is_empty() {
+                    len += key_len(4) + bytes_len(self.venue.len());
+                }
+                if self.tier != 0 {
+                    len += key_len(5) + int32_len(self.tier);
+                }
+                if let TradeSettle::Amount(elem) = &self.settle {
+                    len += key_len(6) + varint_len(*elem);
+                }
+                if let TradeSettle::LegacyReceipt(elem) = &self.settle {
+                    len += key_len(7) + bytes_len(elem.len());
+                }
+                if self.version != 0 {
+                    len += key_len(8) + varint_len(self.version as u64);
+                }
+                len += key_len(9) + bytes_len(self.quote.encoded_len());
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                if self.side != 0 {
+                    w.write_key(0x10)?;
+                    w.write_int32(self.side)?;
+                }
+                if self.old_price != 0 {
+                    w.write_key(0x18)?;
+                    w.write_varint(self.old_price)?;
+                }
+                if !self.venue.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_string(&self.venue)?;
+                }
+                if self.tier != 0 {
+                    w.write_key(0x28)?;
+                    w.write_int32(self.tier)?;
+                }
+                if let TradeSettle::Amount(elem) = &self.settle {
+                    w.write_key(0x30)?;
+                    w.write_varint(*elem)?;
+                }
+                if let TradeSettle::LegacyReceipt(elem) = &self.settle {
+                    w.write_key(0x3a)?;
+                    w.write_bytes(elem)?;
+                }
+                if self.version != 0 {
+                    w.write_key(0x40)?;
+                    w.write_varint(self.version as u64)?;
+                }
+                w.write_message(9, &self.quote)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                if self.side != 0 {
+                    len += key_len(2) + int32_len(self.side);
+                }
+                if self.old_price != 0 {
+                    len += key_len(3) + varint_len(self.old_price);
+                }
+                if !self.venue.is_empty() {
+                    len += key_len(4) + bytes_len(self.venue.len());
+                }
+                if self.tier != 0 {
+                    len += key_len(5) + int32_len(self.tier);
+                }
+                if let TradeSettle::Amount(elem) = &self.settle {
+                    len += key_len(6) + varint_len(*elem);
+                }
+                if let TradeSettle::LegacyReceipt(elem) = &self.settle {
+                    len += key_len(7) + bytes_len(elem.len());
+                }
+                if self.version != 0 {
+                    len += key_len(8) + varint_len(self.version as u64);
+                }
+                len += key_len(9) + bytes_len(sizes.record(&self.quote));
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                if self.side != 0 {
+                    w.write_key(0x10)?;
+                    w.write_int32(self.side)?;
+                }
+                if self.old_price != 0 {
+                    w.write_key(0x18)?;
+                    w.write_varint(self.old_price)?;
+                }
+                if !self.venue.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_string(&self.venue)?;
+                }
+                if self.tier != 0 {
+                    w.write_key(0x28)?;
+                    w.write_int32(self.tier)?;
+                }
+                if let TradeSettle::Amount(elem) = &self.settle {
+                    w.write_key(0x30)?;
+                    w.write_varint(*elem)?;
+                }
+                if let TradeSettle::LegacyReceipt(elem) = &self.settle {
+                    w.write_key(0x3a)?;
+                    w.write_bytes(elem)?;
+                }
+                if self.version != 0 {
+                    w.write_key(0x40)?;
+                    w.write_varint(self.version as u64)?;
+                }
+                w.write_message_sized(9, &self.quote, sizes)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.id = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.side = v;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.old_price = v;
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.venue = v;
+                            pos = new_pos;
+                        }
+                        40 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.tier = v;
+                            pos = new_pos;
+                        }
+                        48 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.settle = TradeSettle::Amount(v);
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.settle = TradeSettle::LegacyReceipt(v);
+                            pos = new_pos;
+                        }
+                        64 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.version = v as u32;
+                            pos = new_pos;
+                        }
+                        74 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.quote.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                if is_selected(only, 8) {
+                    check_required(&seen, 6, 8)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl Message for Trade {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Trade::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Trade::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Trade::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Trade::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Trade::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Trade::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Trade::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Trade {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Trade::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Trade> for Vec<u8> {
+            fn from(msg: Trade) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Trade {
+            const FULL_NAME: &'static str = "legacy.Trade";
+        }
+
+        impl Trade {
+            pub fn amount(&self) -> Option<&u64> {
+                match &self.settle {
+                    TradeSettle::Amount(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_amount(&mut self, value: u64) {
+                self.settle = TradeSettle::Amount(value);
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn legacy_receipt(&self) -> Option<&Vec<u8>> {
+                match &self.settle {
+                    TradeSettle::LegacyReceipt(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn set_legacy_receipt(&mut self, value: Vec<u8>) {
+                self.settle = TradeSettle::LegacyReceipt(value);
+            }
+        }
+
+        impl Trade {
+            pub fn side(&self) -> Result<Side, i32> {
+                Side::try_from(self.side)
+            }
+
+            pub fn set_side(&mut self, value: Side) {
+                self.side = value.into();
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn tier(&self) -> Result<FeeTier, i32> {
+                FeeTier::try_from(self.tier)
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn set_tier(&mut self, value: FeeTier) {
+                self.tier = value.into();
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct TradeBuilder<F8 = Unset> {
+            inner: Trade,
+            required: core::marker::PhantomData<F8>,
+        }
+
+        impl Trade {
+            pub fn builder() -> TradeBuilder {
+                TradeBuilder::default()
+            }
+        }
+
+        impl<F8> TradeBuilder<F8> {
+            pub fn with_id(mut self, value: u64) -> Self {
+                self.inner.id = value;
+                self
+            }
+
+            pub fn with_side(mut self, value: i32) -> Self {
+                self.inner.side = value;
+                self
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn with_old_price(mut self, value: u64) -> Self {
+                self.inner.old_price = value;
+                self
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn with_venue(mut self, value: String) -> Self {
+                self.inner.venue = value;
+                self
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn with_tier(mut self, value: i32) -> Self {
+                self.inner.tier = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.settle = TradeSettle::Amount(value);
+                self
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn with_legacy_receipt(mut self, value: Vec<u8>) -> Self {
+                self.inner.settle = TradeSettle::LegacyReceipt(value);
+                self
+            }
+
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub fn with_version(self, value: u32) -> TradeBuilder<Set> {
+                let mut inner = self.inner;
+                inner.version = value;
+                TradeBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_quote(mut self, value: Quote) -> Self {
+                self.inner.quote = value;
+                self
+            }
+        }
+
+        impl TradeBuilder<Set> {
+            pub fn build(self) -> Trade {
+                self.inner
+            }
+        }
+
+        impl Trade {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.id != 0 {
+                    out.write_scalar("id", self.id);
+                }
+                if self.side != 0 {
+                    out.write_enum("side", self.side, &[("SIDE_UNSPECIFIED", 0), ("SIDE_BID", 1), ("SIDE_ASK", 2), ("SIDE_CROSS", 3)]);
+                }
+                if self.old_price != 0 {
+                    out.write_scalar("old_price", self.old_price);
+                }
+                if !self.venue.is_empty() {
+                    out.write_str("venue", &self.venue);
+                }
+                if self.tier != 0 {
+                    out.write_enum("tier", self.tier, &[("FEE_TIER_UNSPECIFIED", 0), ("FEE_TIER_MAKER", 1)]);
+                }
+                if let TradeSettle::Amount(elem) = &self.settle {
+                    out.write_scalar("amount", *elem);
+                }
+                if let TradeSettle::LegacyReceipt(elem) = &self.settle {
+                    out.write_bytes("legacy_receipt", elem);
+                }
+                if self.version != 0 {
+                    out.write_scalar("version", self.version);
+                }
+                if self.quote != Quote::default() {
+                    out.begin_message("quote");
+                    self.quote.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Trade {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Trade {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.id != 0 {
+                    out.key("id");
+                    out.write_u64(self.id);
+                }
+                if self.side != 0 {
+                    out.key("side");
+                    out.write_enum(self.side, &[("SIDE_UNSPECIFIED", 0), ("SIDE_BID", 1), ("SIDE_ASK", 2), ("SIDE_CROSS", 3)]);
+                }
+                if self.old_price != 0 {
+                    out.key("oldPrice");
+                    out.write_u64(self.old_price);
+                }
+                if !self.venue.is_empty() {
+                    out.key("venue");
+                    out.write_str(&self.venue);
+                }
+                if self.tier != 0 {
+                    out.key("tier");
+                    out.write_enum(self.tier, &[("FEE_TIER_UNSPECIFIED", 0), ("FEE_TIER_MAKER", 1)]);
+                }
+                if let TradeSettle::Amount(elem) = &self.settle {
+                    out.key("amount");
+                    out.write_u64(*elem);
+                }
+                if let TradeSettle::LegacyReceipt(elem) = &self.settle {
+                    out.key("legacyReceipt");
+                    out.write_bytes(elem);
+                }
+                if self.version != 0 {
+                    out.key("version");
+                    out.write_u32(self.version);
+                }
+                if self.quote != Quote::default() {
+                    out.key("quote");
+                    self.quote.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "id" => msg.id = json_to_u64(value)?,
+                        "side" => msg.side = json_to_enum(value, &[("SIDE_UNSPECIFIED", 0), ("SIDE_BID", 1), ("SIDE_ASK", 2), ("SIDE_CROSS", 3)])?,
+                        "oldPrice" | "old_price" => msg.old_price = json_to_u64(value)?,
+                        "venue" => msg.venue = json_to_string(value)?,
+                        "tier" => msg.tier = json_to_enum(value, &[("FEE_TIER_UNSPECIFIED", 0), ("FEE_TIER_MAKER", 1)])?,
+                        "amount" => msg.settle = TradeSettle::Amount(json_to_u64(value)?),
+                        "legacyReceipt" | "legacy_receipt" => msg.settle = TradeSettle::LegacyReceipt(json_to_bytes(value)?),
+                        "version" => msg.version = json_to_u32(value)?,
+                        "quote" => msg.quote = Quote::from_json_value(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct TradeRef<'a> {
+            raw: &'a [u8],
+            pub id: u64,
+            pub side: i32,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub old_price: u64,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub venue: &'a str,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub tier: i32,
+            pub amount: Option<u64>,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub legacy_receipt: Option<&'a [u8]>,
+            #[deprecated(note = "marked deprecated in the .proto definition")]
+            pub version: u32,
+            pub quote: Option<QuoteRef<'a>>,
+        }
+
+        impl<'a> TradeRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = TradeRef {
+                    raw: data,
+                    id: 0,
+                    side: 0,
+                    old_price: 0,
+                    venue: "",
+                    tier: 0,
+                    amount: None,
+                    legacy_receipt: None,
+                    version: 0,
+                    quote: None,
+                };
+
+                let mut has_version = false;
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.id = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.side = v;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.old_price = v;
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.venue = v;
+                        pos = new_pos;
+                    }
+                    40 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.tier = v;
+                        pos = new_pos;
+                    }
+                    48 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = Some(v);
+                        view.legacy_receipt = None;
+                        pos = new_pos;
+                    }
+                    58 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.legacy_receipt = Some(v);
+                        view.amount = None;
+                        pos = new_pos;
+                    }
+                    64 => {
+                        has_version = true;
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.version = v as u32;
+                        pos = new_pos;
+                    }
+                    74 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.quote = Some(QuoteRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Trade::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                if !has_version {
+                    return Err(DecodeError::MissingField { field: 8 });
+                }
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Trade, DecodeError> {
+                Trade::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        #[deprecated(note = "marked deprecated in the .proto definition")]
+        pub struct Quote {
+            pub bid: u64,
+            pub ask: u64,
+        }
+
+        impl Quote {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Quote {
+                bid: 0,
+                ask: 0,
+            };
+        }
+
+        impl Default for Quote {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Quote {
+            pub const BID_FIELD_NUMBER: u32 = 1;
+            pub const ASK_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Quote.bid" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Quote.ask" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: ask
+                if self.ask != 0 {
+                    w.write_varint(self.ask);
+                    w.write_key(0x10);
+                }
+
+                // field 1: bid
+                if self.bid != 0 {
+                    w.write_varint(self.bid);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.bid != 0 {
+                    len += key_len(1) + varint_len(self.bid);
+                }
+                if self.ask != 0 {
+                    len += key_len(2) + varint_len(self.ask);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.bid != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.bid)?;
+                }
+                if self.ask != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.ask)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.bid = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.ask = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Quote {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Quote::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Quote::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Quote::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Quote::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Quote::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Quote::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Quote::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Quote {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Quote::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Quote> for Vec<u8> {
+            fn from(msg: Quote) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Quote {
+            const FULL_NAME: &'static str = "legacy.Quote";
+        }
+
+        impl Quote {
+            /// Longest encoding of a `Quote` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 22;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct QuoteBuilder {
+            inner: Quote,
+        }
+
+        impl Quote {
+            pub fn builder() -> QuoteBuilder {
+                QuoteBuilder::default()
+            }
+        }
+
+        impl QuoteBuilder {
+            pub fn with_bid(mut self, value: u64) -> Self {
+                self.inner.bid = value;
+                self
+            }
+
+            pub fn with_ask(mut self, value: u64) -> Self {
+                self.inner.ask = value;
+                self
+            }
+
+            pub fn build(self) -> Quote {
+                self.inner
+            }
+        }
+
+        impl Quote {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.bid != 0 {
+                    out.write_scalar("bid", self.bid);
+                }
+                if self.ask != 0 {
+                    out.write_scalar("ask", self.ask);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Quote {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Quote {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.bid != 0 {
+                    out.key("bid");
+                    out.write_u64(self.bid);
+                }
+                if self.ask != 0 {
+                    out.key("ask");
+                    out.write_u64(self.ask);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "bid" => msg.bid = json_to_u64(value)?,
+                        "ask" => msg.ask = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        #[deprecated(note = "marked deprecated in the .proto definition")]
+        pub struct QuoteRef<'a> {
+            raw: &'a [u8],
+            pub bid: u64,
+            pub ask: u64,
+        }
+
+        impl<'a> QuoteRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = QuoteRef {
+                    raw: data,
+                    bid: 0,
+                    ask: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.bid = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.ask = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Quote::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Quote, DecodeError> {
+                Quote::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Trade(Trade),
+            Quote(Quote),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Trade::FULL_NAME => AnyMessage::Trade(Trade::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Quote::FULL_NAME => AnyMessage::Quote(Quote::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Trade(msg) => Any::pack(msg),
+                    AnyMessage::Quote(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1055 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod derive {
+        // Auto-generated by protoc-gen-solana from derive.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum MarketKeyVenue {
+            Unset,
+            SpotId(u64),
+            PerpSymbol(String),
+        }
+
+        impl MarketKeyVenue {
+            pub const DEFAULT: Self = MarketKeyVenue::Unset;
+        }
+
+        impl Default for MarketKeyVenue {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: (solana.derive) traits on a struct and its oneof enum, and
+        /// (solana.attr) attributes; with the derive=Eq+Hash plugin option every
+        /// message derives those two as well
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        #[must_use]
+        #[allow(missing_docs)]
+        pub struct MarketKey {
+            pub mint: Pubkey,
+            pub bucket: u32,
+            pub venue: MarketKeyVenue,
+        }
+
+        impl MarketKey {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = MarketKey {
+                mint: ZERO_PUBKEY,
+                bucket: 0,
+                venue: MarketKeyVenue::Unset,
+            };
+        }
+
+        impl Default for MarketKey {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl MarketKey {
+            pub const MINT_FIELD_NUMBER: u32 = 1;
+            pub const BUCKET_FIELD_NUMBER: u32 = 2;
+            pub const SPOT_ID_FIELD_NUMBER: u32 = 3;
+            pub const PERP_SYMBOL_FIELD_NUMBER: u32 = 4;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "MarketKey.mint" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "MarketKey.bucket" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "MarketKey.spot_id" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "MarketKey.perp_symbol" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 4: perp_symbol
+                if let MarketKeyVenue::PerpSymbol(elem) = &self.venue {
+                    w.write_string(elem);
+                    w.write_key(0x22);
+                }
+
+                // field 3: spot_id
+                if let MarketKeyVenue::SpotId(elem) = &self.venue {
+                    w.write_varint(*elem);
+                    w.write_key(0x18);
+                }
+
+                // field 2: bucket
+                if self.bucket != 0 {
+                    w.write_varint(self.bucket as u64);
+                    w.write_key(0x10);
+                }
+
+                // field 1: mint
+                if self.mint != Pubkey::default() {
+                    w.write_pubkey(&self.mint);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.mint != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.bucket != 0 {
+                    len += key_len(2) + varint_len(self.bucket as u64);
+                }
+                if let MarketKeyVenue::SpotId(elem) = &self.venue {
+                    len += key_len(3) + varint_len(*elem);
+                }
+                if let MarketKeyVenue::PerpSymbol(elem) = &self.venue {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.mint != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.mint)?;
+                }
+                if self.bucket != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.bucket as u64)?;
+                }
+                if let MarketKeyVenue::SpotId(elem) = &self.venue {
+                    w.write_key(0x18)?;
+                    w.write_varint(*elem)?;
+                }
+                if let MarketKeyVenue::PerpSymbol(elem) = &self.venue {
+                    w.write_key(0x22)?;
+                    w.write_string(elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.mint = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.bucket = v as u32;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.venue = MarketKeyVenue::SpotId(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.venue = MarketKeyVenue::PerpSymbol(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for MarketKey {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                MarketKey::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                MarketKey::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                MarketKey::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                MarketKey::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                MarketKey::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                MarketKey::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                MarketKey::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for MarketKey {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                MarketKey::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<MarketKey> for Vec<u8> {
+            fn from(msg: MarketKey) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for MarketKey {
+            const FULL_NAME: &'static str = "example.MarketKey";
+        }
+
+        impl MarketKey {
+            pub fn spot_id(&self) -> Option<&u64> {
+                match &self.venue {
+                    MarketKeyVenue::SpotId(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_spot_id(&mut self, value: u64) {
+                self.venue = MarketKeyVenue::SpotId(value);
+            }
+
+            pub fn perp_symbol(&self) -> Option<&String> {
+                match &self.venue {
+                    MarketKeyVenue::PerpSymbol(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_perp_symbol(&mut self, value: String) {
+                self.venue = MarketKeyVenue::PerpSymbol(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct MarketKeyBuilder {
+            inner: MarketKey,
+        }
+
+        impl MarketKey {
+            pub fn builder() -> MarketKeyBuilder {
+                MarketKeyBuilder::default()
+            }
+        }
+
+        impl MarketKeyBuilder {
+            pub fn with_mint(mut self, value: Pubkey) -> Self {
+                self.inner.mint = value;
+                self
+            }
+
+            pub fn with_bucket(mut self, value: u32) -> Self {
+                self.inner.bucket = value;
+                self
+            }
+
+            pub fn with_spot_id(mut self, value: u64) -> Self {
+                self.inner.venue = MarketKeyVenue::SpotId(value);
+                self
+            }
+
+            pub fn with_perp_symbol(mut self, value: String) -> Self {
+                self.inner.venue = MarketKeyVenue::PerpSymbol(value);
+                self
+            }
+
+            pub fn build(self) -> MarketKey {
+                self.inner
+            }
+        }
+
+        impl MarketKey {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.mint != Pubkey::default() {
+                    out.write_pubkey("mint", &self.mint);
+                }
+                if self.bucket != 0 {
+                    out.write_scalar("bucket", self.bucket);
+                }
+                if let MarketKeyVenue::SpotId(elem) = &self.venue {
+                    out.write_scalar("spot_id", *elem);
+                }
+                if let MarketKeyVenue::PerpSymbol(elem) = &self.venue {
+                    out.write_str("perp_symbol", elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for MarketKey {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl MarketKey {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.mint != Pubkey::default() {
+                    out.key("mint");
+                    out.write_pubkey(self.mint);
+                }
+                if self.bucket != 0 {
+                    out.key("bucket");
+                    out.write_u32(self.bucket);
+                }
+                if let MarketKeyVenue::SpotId(elem) = &self.venue {
+                    out.key("spotId");
+                    out.write_u64(*elem);
+                }
+                if let MarketKeyVenue::PerpSymbol(elem) = &self.venue {
+                    out.key("perpSymbol");
+                    out.write_str(elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "mint" => msg.mint = json_to_pubkey(value)?,
+                        "bucket" => msg.bucket = json_to_u32(value)?,
+                        "spotId" | "spot_id" => msg.venue = MarketKeyVenue::SpotId(json_to_u64(value)?),
+                        "perpSymbol" | "perp_symbol" => msg.venue = MarketKeyVenue::PerpSymbol(json_to_string(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct MarketKeyRef<'a> {
+            raw: &'a [u8],
+            pub mint: Pubkey,
+            pub bucket: u32,
+            pub spot_id: Option<u64>,
+            pub perp_symbol: Option<&'a str>,
+        }
+
+        impl<'a> MarketKeyRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = MarketKeyRef {
+                    raw: data,
+                    mint: ZERO_PUBKEY,
+                    bucket: 0,
+                    spot_id: None,
+                    perp_symbol: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.mint = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.bucket = v as u32;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.spot_id = Some(v);
+                        view.perp_symbol = None;
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.perp_symbol = Some(v);
+                        view.spot_id = None;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(MarketKey::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<MarketKey, DecodeError> {
+                MarketKey::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct KeyedBook {
+            pub key: MarketKey,
+            pub levels: Vec<u64>,
+            pub labels: BTreeMap<u32, String>,
+        }
+
+        impl KeyedBook {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = KeyedBook {
+                key: MarketKey::DEFAULT,
+                levels: Vec::new(),
+                labels: BTreeMap::new(),
+            };
+        }
+
+        impl Default for KeyedBook {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl KeyedBook {
+            pub const KEY_FIELD_NUMBER: u32 = 1;
+            pub const LEVELS_FIELD_NUMBER: u32 = 2;
+            pub const LABELS_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "KeyedBook.key" },
+                FieldWireType { number: 2, wire_type: 0, packed: true, name: "KeyedBook.levels" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "KeyedBook.labels" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: labels
+                for (key, value) in self.labels.iter().rev() {
+                    w.write_nested(3, |w| {
+                        w.write_string(value);
+                        w.write_key(0x12);
+                        w.write_varint(*key as u64);
+                        w.write_key(0x08);
+                    });
+                }
+
+                // field 2: levels
+                if !self.levels.is_empty() {
+                    w.write_nested(2, |w| {
+                        for elem in self.levels.iter().rev() {
+                            w.write_varint(*elem);
+                        }
+                    });
+                }
+
+                // field 1: key
+                w.write_message(1, &self.key);
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                len += key_len(1) + bytes_len(self.key.encoded_len());
+                if !self.levels.is_empty() {
+                    len += key_len(2) + bytes_len(self.levels.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                for (key, value) in &self.labels {
+                    len += key_len(3) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len()));
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                w.write_message(1, &self.key)?;
+                if !self.levels.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_varint(self.levels.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.levels {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                for (key, value) in &self.labels {
+                    w.write_key(0x1a)?;
+                    w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key as u64)?;
+                    w.write_key(0x12)?;
+                    w.write_string(value)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                len += key_len(1) + bytes_len(sizes.record(&self.key));
+                if !self.levels.is_empty() {
+                    len += key_len(2) + bytes_len(self.levels.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                for (key, value) in &self.labels {
+                    len += key_len(3) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len()));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                w.write_message_sized(1, &self.key, sizes)?;
+                if !self.levels.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_varint(self.levels.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.levels {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                for (key, value) in &self.labels {
+                    w.write_key(0x1a)?;
+                    w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key as u64)?;
+                    w.write_key(0x12)?;
+                    w.write_string(value)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.key.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.levels.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.levels.push(v);
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let new_pos = decode_packed_varint_with(data, pos, &mut self.levels, opts)?;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = String::new();
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    8 => {
+                                        let (v, next) = decode_varint(entry, entry_pos)?;
+                                        key = v as u32;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.labels.len() >= opts.max_repeated_len && !self.labels.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.labels.insert(key, value);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    2 => Some(self.levels.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for KeyedBook {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                KeyedBook::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                KeyedBook::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                KeyedBook::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                KeyedBook::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                KeyedBook::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                KeyedBook::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                KeyedBook::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for KeyedBook {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                KeyedBook::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<KeyedBook> for Vec<u8> {
+            fn from(msg: KeyedBook) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for KeyedBook {
+            const FULL_NAME: &'static str = "example.KeyedBook";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct KeyedBookBuilder {
+            inner: KeyedBook,
+        }
+
+        impl KeyedBook {
+            pub fn builder() -> KeyedBookBuilder {
+                KeyedBookBuilder::default()
+            }
+        }
+
+        impl KeyedBookBuilder {
+            pub fn with_key(mut self, value: MarketKey) -> Self {
+                self.inner.key = value;
+                self
+            }
+
+            pub fn with_levels(mut self, value: Vec<u64>) -> Self {
+                self.inner.levels = value;
+                self
+            }
+
+            pub fn add_levels(mut self, value: u64) -> Self {
+                self.inner.levels.push(value);
+                self
+            }
+
+            pub fn with_labels(mut self, value: BTreeMap<u32, String>) -> Self {
+                self.inner.labels = value;
+                self
+            }
+
+            pub fn insert_labels(mut self, key: u32, value: String) -> Self {
+                self.inner.labels.insert(key, value);
+                self
+            }
+
+            pub fn build(self) -> KeyedBook {
+                self.inner
+            }
+        }
+
+        impl KeyedBook {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.key != MarketKey::default() {
+                    out.begin_message("key");
+                    self.key.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.levels {
+                    out.write_scalar("levels", *elem);
+                }
+                for (key, value) in &self.labels {
+                    out.begin_message("labels");
+                    out.write_scalar("key", *key);
+                    out.write_str("value", value);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for KeyedBook {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl KeyedBook {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.key != MarketKey::default() {
+                    out.key("key");
+                    self.key.write_json(out);
+                }
+                if !self.levels.is_empty() {
+                    out.key("levels");
+                    out.begin_array();
+                    for elem in &self.levels {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.labels.is_empty() {
+                    out.key("labels");
+                    out.begin_object();
+                    for (key, value) in &self.labels {
+                        out.key(&key.to_string());
+                        out.write_str(value);
+                    }
+                    out.end_object();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "key" => msg.key = MarketKey::from_json_value(value)?,
+                        "levels" => {
+                            for elem in json_array(value)? {
+                                msg.levels.push(json_to_u64(elem)?);
+                            }
+                        }
+                        "labels" => {
+                            for (key, value) in json_object(value)? {
+                                msg.labels.insert(json_map_key(key)?, json_to_string(value)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct KeyedBookRef<'a> {
+            raw: &'a [u8],
+            pub key: Option<MarketKeyRef<'a>>,
+            pub levels: RepeatedRef<'a, u64>,
+        }
+
+        impl<'a> KeyedBookRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = KeyedBookRef {
+                    raw: data,
+                    key: None,
+                    levels: RepeatedRef::packed(data, 16, decode_varint),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.key = Some(MarketKeyRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    16 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(KeyedBook::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<KeyedBook, DecodeError> {
+                KeyedBook::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            MarketKey(MarketKey),
+            KeyedBook(KeyedBook),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    MarketKey::FULL_NAME => AnyMessage::MarketKey(MarketKey::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    KeyedBook::FULL_NAME => AnyMessage::KeyedBook(KeyedBook::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::MarketKey(msg) => Any::pack(msg),
+                    AnyMessage::KeyedBook(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
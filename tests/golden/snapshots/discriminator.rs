@@ -0,0 +1,844 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod discriminator {
+        // Auto-generated by protoc-gen-solana from discriminator.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: (solana.discriminator) on two messages with the same wire
+        /// shape, which only the discriminator tells apart
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct MintAuthority {
+            pub authority: Pubkey,
+            pub supply_cap: u64,
+        }
+
+        impl MintAuthority {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = MintAuthority {
+                authority: ZERO_PUBKEY,
+                supply_cap: 0,
+            };
+        }
+
+        impl Default for MintAuthority {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl MintAuthority {
+            pub const AUTHORITY_FIELD_NUMBER: u32 = 1;
+            pub const SUPPLY_CAP_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "MintAuthority.authority" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "MintAuthority.supply_cap" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: supply_cap
+                if self.supply_cap != 0 {
+                    w.write_varint(self.supply_cap);
+                    w.write_key(0x10);
+                }
+
+                // field 1: authority
+                if self.authority != Pubkey::default() {
+                    w.write_pubkey(&self.authority);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.supply_cap != 0 {
+                    len += key_len(2) + varint_len(self.supply_cap);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                if self.supply_cap != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.supply_cap)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.authority = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.supply_cap = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for MintAuthority {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                MintAuthority::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                MintAuthority::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                MintAuthority::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                MintAuthority::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                MintAuthority::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                MintAuthority::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                MintAuthority::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for MintAuthority {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                MintAuthority::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<MintAuthority> for Vec<u8> {
+            fn from(msg: MintAuthority) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for MintAuthority {
+            const FULL_NAME: &'static str = "example.MintAuthority";
+        }
+
+        impl MintAuthority {
+            /// Longest encoding of a `MintAuthority` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 45;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        impl MintAuthority {
+            /// `sha256("example.MintAuthority")[..8]`
+            pub const TYPE_DISCRIMINATOR: [u8; 8] = [70, 76, 56, 170, 22, 72, 183, 157];
+
+            /// `TYPE_DISCRIMINATOR`, then the protobuf encoding.
+            pub fn encode_with_discriminator(&self) -> Vec<u8> {
+                encode_with_discriminator(&Self::TYPE_DISCRIMINATOR, self)
+            }
+
+            /// Decode `encode_with_discriminator` data, failing if it starts with
+            /// another discriminator.
+            pub fn decode_with_discriminator(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(strip_discriminator(&Self::TYPE_DISCRIMINATOR, data)?, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct MintAuthorityBuilder {
+            inner: MintAuthority,
+        }
+
+        impl MintAuthority {
+            pub fn builder() -> MintAuthorityBuilder {
+                MintAuthorityBuilder::default()
+            }
+        }
+
+        impl MintAuthorityBuilder {
+            pub fn with_authority(mut self, value: Pubkey) -> Self {
+                self.inner.authority = value;
+                self
+            }
+
+            pub fn with_supply_cap(mut self, value: u64) -> Self {
+                self.inner.supply_cap = value;
+                self
+            }
+
+            pub fn build(self) -> MintAuthority {
+                self.inner
+            }
+        }
+
+        impl MintAuthority {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.authority != Pubkey::default() {
+                    out.write_pubkey("authority", &self.authority);
+                }
+                if self.supply_cap != 0 {
+                    out.write_scalar("supply_cap", self.supply_cap);
+                }
+            }
+        }
+
+        impl core::fmt::Display for MintAuthority {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl MintAuthority {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.authority != Pubkey::default() {
+                    out.key("authority");
+                    out.write_pubkey(self.authority);
+                }
+                if self.supply_cap != 0 {
+                    out.key("supplyCap");
+                    out.write_u64(self.supply_cap);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "authority" => msg.authority = json_to_pubkey(value)?,
+                        "supplyCap" | "supply_cap" => msg.supply_cap = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct MintAuthorityRef<'a> {
+            raw: &'a [u8],
+            pub authority: Pubkey,
+            pub supply_cap: u64,
+        }
+
+        impl<'a> MintAuthorityRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = MintAuthorityRef {
+                    raw: data,
+                    authority: ZERO_PUBKEY,
+                    supply_cap: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.authority = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.supply_cap = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(MintAuthority::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<MintAuthority, DecodeError> {
+                MintAuthority::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct FreezeAuthority {
+            pub authority: Pubkey,
+            pub frozen_until: u64,
+        }
+
+        impl FreezeAuthority {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = FreezeAuthority {
+                authority: ZERO_PUBKEY,
+                frozen_until: 0,
+            };
+        }
+
+        impl Default for FreezeAuthority {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl FreezeAuthority {
+            pub const AUTHORITY_FIELD_NUMBER: u32 = 1;
+            pub const FROZEN_UNTIL_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "FreezeAuthority.authority" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "FreezeAuthority.frozen_until" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: frozen_until
+                if self.frozen_until != 0 {
+                    w.write_varint(self.frozen_until);
+                    w.write_key(0x10);
+                }
+
+                // field 1: authority
+                if self.authority != Pubkey::default() {
+                    w.write_pubkey(&self.authority);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.frozen_until != 0 {
+                    len += key_len(2) + varint_len(self.frozen_until);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                if self.frozen_until != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.frozen_until)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.authority = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.frozen_until = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for FreezeAuthority {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                FreezeAuthority::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                FreezeAuthority::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                FreezeAuthority::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                FreezeAuthority::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                FreezeAuthority::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                FreezeAuthority::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                FreezeAuthority::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for FreezeAuthority {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                FreezeAuthority::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<FreezeAuthority> for Vec<u8> {
+            fn from(msg: FreezeAuthority) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for FreezeAuthority {
+            const FULL_NAME: &'static str = "example.FreezeAuthority";
+        }
+
+        impl FreezeAuthority {
+            /// Longest encoding of a `FreezeAuthority` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 45;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        impl FreezeAuthority {
+            /// `sha256("example.FreezeAuthority")[..8]`
+            pub const TYPE_DISCRIMINATOR: [u8; 8] = [123, 195, 9, 74, 57, 240, 253, 66];
+
+            /// `TYPE_DISCRIMINATOR`, then the protobuf encoding.
+            pub fn encode_with_discriminator(&self) -> Vec<u8> {
+                encode_with_discriminator(&Self::TYPE_DISCRIMINATOR, self)
+            }
+
+            /// Decode `encode_with_discriminator` data, failing if it starts with
+            /// another discriminator.
+            pub fn decode_with_discriminator(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(strip_discriminator(&Self::TYPE_DISCRIMINATOR, data)?, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct FreezeAuthorityBuilder {
+            inner: FreezeAuthority,
+        }
+
+        impl FreezeAuthority {
+            pub fn builder() -> FreezeAuthorityBuilder {
+                FreezeAuthorityBuilder::default()
+            }
+        }
+
+        impl FreezeAuthorityBuilder {
+            pub fn with_authority(mut self, value: Pubkey) -> Self {
+                self.inner.authority = value;
+                self
+            }
+
+            pub fn with_frozen_until(mut self, value: u64) -> Self {
+                self.inner.frozen_until = value;
+                self
+            }
+
+            pub fn build(self) -> FreezeAuthority {
+                self.inner
+            }
+        }
+
+        impl FreezeAuthority {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.authority != Pubkey::default() {
+                    out.write_pubkey("authority", &self.authority);
+                }
+                if self.frozen_until != 0 {
+                    out.write_scalar("frozen_until", self.frozen_until);
+                }
+            }
+        }
+
+        impl core::fmt::Display for FreezeAuthority {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl FreezeAuthority {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.authority != Pubkey::default() {
+                    out.key("authority");
+                    out.write_pubkey(self.authority);
+                }
+                if self.frozen_until != 0 {
+                    out.key("frozenUntil");
+                    out.write_u64(self.frozen_until);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "authority" => msg.authority = json_to_pubkey(value)?,
+                        "frozenUntil" | "frozen_until" => msg.frozen_until = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct FreezeAuthorityRef<'a> {
+            raw: &'a [u8],
+            pub authority: Pubkey,
+            pub frozen_until: u64,
+        }
+
+        impl<'a> FreezeAuthorityRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = FreezeAuthorityRef {
+                    raw: data,
+                    authority: ZERO_PUBKEY,
+                    frozen_until: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.authority = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.frozen_until = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(FreezeAuthority::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<FreezeAuthority, DecodeError> {
+                FreezeAuthority::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            MintAuthority(MintAuthority),
+            FreezeAuthority(FreezeAuthority),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    MintAuthority::FULL_NAME => AnyMessage::MintAuthority(MintAuthority::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    FreezeAuthority::FULL_NAME => AnyMessage::FreezeAuthority(FreezeAuthority::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::MintAuthority(msg) => Any::pack(msg),
+                    AnyMessage::FreezeAuthority(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
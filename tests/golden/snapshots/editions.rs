@@ -0,0 +1,2102 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod editions {
+    pub mod editions {
+        // Auto-generated by protoc-gen-solana from editions.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum AccountKind {
+            Unset,
+            UserId(u64),
+            Program(String),
+        }
+
+        impl AccountKind {
+            pub const DEFAULT: Self = AccountKind::Unset;
+        }
+
+        impl Default for AccountKind {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Account {
+            pub id: u64,
+            pub balance: Option<u64>,
+            pub owner: String,
+            pub fee_bps: Option<u32>,
+            pub levels: Vec<u32>,
+            pub history: Vec<u32>,
+            pub limits: Limits,
+            pub tiers: Vec<Limits>,
+            pub plain: Limits,
+            pub balances: BTreeMap<String, u64>,
+            pub kind: AccountKind,
+        }
+
+        impl Account {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Account {
+                id: 0,
+                balance: None,
+                owner: String::new(),
+                fee_bps: None,
+                levels: Vec::new(),
+                history: Vec::new(),
+                limits: Limits::DEFAULT,
+                tiers: Vec::new(),
+                plain: Limits::DEFAULT,
+                balances: BTreeMap::new(),
+                kind: AccountKind::Unset,
+            };
+        }
+
+        impl Default for Account {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Account {
+            pub const ID_FIELD_NUMBER: u32 = 1;
+            pub const BALANCE_FIELD_NUMBER: u32 = 2;
+            pub const OWNER_FIELD_NUMBER: u32 = 3;
+            pub const FEE_BPS_FIELD_NUMBER: u32 = 4;
+            pub const LEVELS_FIELD_NUMBER: u32 = 5;
+            pub const HISTORY_FIELD_NUMBER: u32 = 6;
+            pub const LIMITS_FIELD_NUMBER: u32 = 7;
+            pub const TIERS_FIELD_NUMBER: u32 = 8;
+            pub const PLAIN_FIELD_NUMBER: u32 = 9;
+            pub const BALANCES_FIELD_NUMBER: u32 = 10;
+            pub const USER_ID_FIELD_NUMBER: u32 = 11;
+            pub const PROGRAM_FIELD_NUMBER: u32 = 12;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Account.id" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Account.balance" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Account.owner" },
+                FieldWireType { number: 4, wire_type: 0, packed: false, name: "Account.fee_bps" },
+                FieldWireType { number: 5, wire_type: 0, packed: true, name: "Account.levels" },
+                FieldWireType { number: 6, wire_type: 0, packed: true, name: "Account.history" },
+                FieldWireType { number: 7, wire_type: 3, packed: false, name: "Account.limits" },
+                FieldWireType { number: 8, wire_type: 3, packed: false, name: "Account.tiers" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Account.plain" },
+                FieldWireType { number: 10, wire_type: 2, packed: false, name: "Account.balances" },
+                FieldWireType { number: 11, wire_type: 0, packed: false, name: "Account.user_id" },
+                FieldWireType { number: 12, wire_type: 2, packed: false, name: "Account.program" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 12: program
+                if let AccountKind::Program(elem) = &self.kind {
+                    w.write_string(elem);
+                    w.write_key(0x62);
+                }
+
+                // field 11: user_id
+                if let AccountKind::UserId(elem) = &self.kind {
+                    w.write_varint(*elem);
+                    w.write_key(0x58);
+                }
+
+                // field 10: balances
+                for (key, value) in self.balances.iter().rev() {
+                    w.write_nested(10, |w| {
+                        w.write_varint(*value);
+                        w.write_key(0x10);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 9: plain
+                w.write_message(9, &self.plain);
+
+                // field 8: tiers
+                for elem in self.tiers.iter().rev() {
+                    w.write_group(8, elem);
+                }
+
+                // field 7: limits
+                w.write_group(7, &self.limits);
+
+                // field 6: history
+                for elem in self.history.iter().rev() {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x30);
+                }
+
+                // field 5: levels
+                if !self.levels.is_empty() {
+                    w.write_nested(5, |w| {
+                        for elem in self.levels.iter().rev() {
+                            w.write_varint(*elem as u64);
+                        }
+                    });
+                }
+
+                // field 4: fee_bps
+                if let Some(elem) = &self.fee_bps {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x20);
+                }
+
+                // field 3: owner
+                if !self.owner.is_empty() {
+                    w.write_string(&self.owner);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: balance
+                if let Some(elem) = &self.balance {
+                    w.write_varint(*elem);
+                    w.write_key(0x10);
+                }
+
+                // field 1: id
+                w.write_varint(self.id);
+                w.write_key(0x08);
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                len += key_len(1) + varint_len(self.id);
+                if let Some(elem) = &self.balance {
+                    len += key_len(2) + varint_len(*elem);
+                }
+                if !self.owner.is_empty() {
+                    len += key_len(3) + bytes_len(self.owner.len());
+                }
+                if let Some(elem) = &self.fee_bps {
+                    len += key_len(4) + varint_len(*elem as u64);
+                }
+                if !self.levels.is_empty() {
+                    len += key_len(5) + bytes_len(self.levels.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                for elem in &self.history {
+                    len += key_len(6) + varint_len(*elem as u64);
+                }
+                len += key_len(7) + self.limits.encoded_len() + key_len(7);
+                for elem in &self.tiers {
+                    len += key_len(8) + elem.encoded_len() + key_len(8);
+                }
+                len += key_len(9) + bytes_len(self.plain.encoded_len());
+                for (key, value) in &self.balances {
+                    len += key_len(10) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value));
+                }
+                if let AccountKind::UserId(elem) = &self.kind {
+                    len += key_len(11) + varint_len(*elem);
+                }
+                if let AccountKind::Program(elem) = &self.kind {
+                    len += key_len(12) + bytes_len(elem.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                w.write_key(0x08)?;
+                w.write_varint(self.id)?;
+                if let Some(elem) = &self.balance {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem)?;
+                }
+                if !self.owner.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.owner)?;
+                }
+                if let Some(elem) = &self.fee_bps {
+                    w.write_key(0x20)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if !self.levels.is_empty() {
+                    w.write_key(0x2a)?;
+                    w.write_varint(self.levels.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.levels {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                for elem in &self.history {
+                    w.write_key(0x30)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                w.write_group(7, &self.limits)?;
+                for elem in &self.tiers {
+                    w.write_group(8, elem)?;
+                }
+                w.write_message(9, &self.plain)?;
+                for (key, value) in &self.balances {
+                    w.write_key(0x52)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x10)?;
+                    w.write_varint(*value)?;
+                }
+                if let AccountKind::UserId(elem) = &self.kind {
+                    w.write_key(0x58)?;
+                    w.write_varint(*elem)?;
+                }
+                if let AccountKind::Program(elem) = &self.kind {
+                    w.write_key(0x62)?;
+                    w.write_string(elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                len += key_len(1) + varint_len(self.id);
+                if let Some(elem) = &self.balance {
+                    len += key_len(2) + varint_len(*elem);
+                }
+                if !self.owner.is_empty() {
+                    len += key_len(3) + bytes_len(self.owner.len());
+                }
+                if let Some(elem) = &self.fee_bps {
+                    len += key_len(4) + varint_len(*elem as u64);
+                }
+                if !self.levels.is_empty() {
+                    len += key_len(5) + bytes_len(self.levels.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                for elem in &self.history {
+                    len += key_len(6) + varint_len(*elem as u64);
+                }
+                len += key_len(7) + sizes.record(&self.limits) + key_len(7);
+                for elem in &self.tiers {
+                    len += key_len(8) + sizes.record(elem) + key_len(8);
+                }
+                len += key_len(9) + bytes_len(sizes.record(&self.plain));
+                for (key, value) in &self.balances {
+                    len += key_len(10) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value));
+                }
+                if let AccountKind::UserId(elem) = &self.kind {
+                    len += key_len(11) + varint_len(*elem);
+                }
+                if let AccountKind::Program(elem) = &self.kind {
+                    len += key_len(12) + bytes_len(elem.len());
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                w.write_key(0x08)?;
+                w.write_varint(self.id)?;
+                if let Some(elem) = &self.balance {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem)?;
+                }
+                if !self.owner.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.owner)?;
+                }
+                if let Some(elem) = &self.fee_bps {
+                    w.write_key(0x20)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if !self.levels.is_empty() {
+                    w.write_key(0x2a)?;
+                    w.write_varint(self.levels.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.levels {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                for elem in &self.history {
+                    w.write_key(0x30)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                w.write_group_sized(7, &self.limits, sizes)?;
+                for elem in &self.tiers {
+                    w.write_group_sized(8, elem, sizes)?;
+                }
+                w.write_message_sized(9, &self.plain, sizes)?;
+                for (key, value) in &self.balances {
+                    w.write_key(0x52)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x10)?;
+                    w.write_varint(*value)?;
+                }
+                if let AccountKind::UserId(elem) = &self.kind {
+                    w.write_key(0x58)?;
+                    w.write_varint(*elem)?;
+                }
+                if let AccountKind::Program(elem) = &self.kind {
+                    w.write_key(0x62)?;
+                    w.write_string(elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.id = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.balance = Some(v);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.owner = v;
+                            pos = new_pos;
+                        }
+                        32 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.fee_bps = Some(v as u32);
+                            pos = new_pos;
+                        }
+                        40 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.levels.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.levels.push(v as u32);
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let new_pos = decode_packed_uint32_with(data, pos, &mut self.levels, opts)?;
+                            pos = new_pos;
+                        }
+                        48 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.history.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.history.push(v as u32);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            let new_pos = decode_packed_uint32_with(data, pos, &mut self.history, opts)?;
+                            pos = new_pos;
+                        }
+                        59 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (sub, new_pos) = decode_group_slice::<7>(data, pos)?;
+                            self.limits.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        67 => {
+                            let (sub, new_pos) = decode_group_slice::<8>(data, pos)?;
+                            if self.tiers.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.tiers.push(Limits::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        74 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.plain.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        82 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = 0;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    16 => {
+                                        let (v, next) = decode_varint(entry, entry_pos)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.balances.len() >= opts.max_repeated_len && !self.balances.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.balances.insert(key, value);
+                            pos = new_pos;
+                        }
+                        88 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.kind = AccountKind::UserId(v);
+                            pos = new_pos;
+                        }
+                        98 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.kind = AccountKind::Program(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                if is_selected(only, 1) {
+                    check_required(&seen, 0, 1)?;
+                }
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    5 => Some(self.levels.len()),
+                    6 => Some(self.history.len()),
+                    8 => Some(self.tiers.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Account {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Account::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Account::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Account::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Account::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Account::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Account::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Account::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Account {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Account::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Account> for Vec<u8> {
+            fn from(msg: Account) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Account {
+            const FULL_NAME: &'static str = "editions.Account";
+        }
+
+        impl Account {
+            pub fn user_id(&self) -> Option<&u64> {
+                match &self.kind {
+                    AccountKind::UserId(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_user_id(&mut self, value: u64) {
+                self.kind = AccountKind::UserId(value);
+            }
+
+            pub fn program(&self) -> Option<&String> {
+                match &self.kind {
+                    AccountKind::Program(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_program(&mut self, value: String) {
+                self.kind = AccountKind::Program(value);
+            }
+        }
+
+        impl Account {
+            pub fn fee_bps_or_default(&self) -> u32 {
+                self.fee_bps.unwrap_or(30)
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct AccountBuilder<F1 = Unset> {
+            inner: Account,
+            required: core::marker::PhantomData<F1>,
+        }
+
+        impl Account {
+            pub fn builder() -> AccountBuilder {
+                AccountBuilder::default()
+            }
+        }
+
+        impl<F1> AccountBuilder<F1> {
+            pub fn with_id(self, value: u64) -> AccountBuilder<Set> {
+                let mut inner = self.inner;
+                inner.id = value;
+                AccountBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_balance(mut self, value: u64) -> Self {
+                self.inner.balance = Some(value);
+                self
+            }
+
+            pub fn with_owner(mut self, value: String) -> Self {
+                self.inner.owner = value;
+                self
+            }
+
+            pub fn with_fee_bps(mut self, value: u32) -> Self {
+                self.inner.fee_bps = Some(value);
+                self
+            }
+
+            pub fn with_levels(mut self, value: Vec<u32>) -> Self {
+                self.inner.levels = value;
+                self
+            }
+
+            pub fn add_levels(mut self, value: u32) -> Self {
+                self.inner.levels.push(value);
+                self
+            }
+
+            pub fn with_history(mut self, value: Vec<u32>) -> Self {
+                self.inner.history = value;
+                self
+            }
+
+            pub fn add_history(mut self, value: u32) -> Self {
+                self.inner.history.push(value);
+                self
+            }
+
+            pub fn with_limits(mut self, value: Limits) -> Self {
+                self.inner.limits = value;
+                self
+            }
+
+            pub fn with_tiers(mut self, value: Vec<Limits>) -> Self {
+                self.inner.tiers = value;
+                self
+            }
+
+            pub fn add_tiers(mut self, value: Limits) -> Self {
+                self.inner.tiers.push(value);
+                self
+            }
+
+            pub fn with_plain(mut self, value: Limits) -> Self {
+                self.inner.plain = value;
+                self
+            }
+
+            pub fn with_balances(mut self, value: BTreeMap<String, u64>) -> Self {
+                self.inner.balances = value;
+                self
+            }
+
+            pub fn insert_balances(mut self, key: String, value: u64) -> Self {
+                self.inner.balances.insert(key, value);
+                self
+            }
+
+            pub fn with_user_id(mut self, value: u64) -> Self {
+                self.inner.kind = AccountKind::UserId(value);
+                self
+            }
+
+            pub fn with_program(mut self, value: String) -> Self {
+                self.inner.kind = AccountKind::Program(value);
+                self
+            }
+        }
+
+        impl AccountBuilder<Set> {
+            pub fn build(self) -> Account {
+                self.inner
+            }
+        }
+
+        impl Account {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.id != 0 {
+                    out.write_scalar("id", self.id);
+                }
+                if let Some(elem) = &self.balance {
+                    out.write_scalar("balance", *elem);
+                }
+                if !self.owner.is_empty() {
+                    out.write_str("owner", &self.owner);
+                }
+                if let Some(elem) = &self.fee_bps {
+                    out.write_scalar("fee_bps", *elem);
+                }
+                for elem in &self.levels {
+                    out.write_scalar("levels", *elem);
+                }
+                for elem in &self.history {
+                    out.write_scalar("history", *elem);
+                }
+                if self.limits != Limits::default() {
+                    out.begin_message("limits");
+                    self.limits.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.tiers {
+                    out.begin_message("tiers");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if self.plain != Limits::default() {
+                    out.begin_message("plain");
+                    self.plain.write_text(out);
+                    out.end_message();
+                }
+                for (key, value) in &self.balances {
+                    out.begin_message("balances");
+                    out.write_str("key", key);
+                    out.write_scalar("value", *value);
+                    out.end_message();
+                }
+                if let AccountKind::UserId(elem) = &self.kind {
+                    out.write_scalar("user_id", *elem);
+                }
+                if let AccountKind::Program(elem) = &self.kind {
+                    out.write_str("program", elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Account {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Account {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.id != 0 {
+                    out.key("id");
+                    out.write_u64(self.id);
+                }
+                if let Some(elem) = &self.balance {
+                    out.key("balance");
+                    out.write_u64(*elem);
+                }
+                if !self.owner.is_empty() {
+                    out.key("owner");
+                    out.write_str(&self.owner);
+                }
+                if let Some(elem) = &self.fee_bps {
+                    out.key("feeBps");
+                    out.write_u32(*elem);
+                }
+                if !self.levels.is_empty() {
+                    out.key("levels");
+                    out.begin_array();
+                    for elem in &self.levels {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.history.is_empty() {
+                    out.key("history");
+                    out.begin_array();
+                    for elem in &self.history {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if self.limits != Limits::default() {
+                    out.key("limits");
+                    self.limits.write_json(out);
+                }
+                if !self.tiers.is_empty() {
+                    out.key("tiers");
+                    out.begin_array();
+                    for elem in &self.tiers {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if self.plain != Limits::default() {
+                    out.key("plain");
+                    self.plain.write_json(out);
+                }
+                if !self.balances.is_empty() {
+                    out.key("balances");
+                    out.begin_object();
+                    for (key, value) in &self.balances {
+                        out.key(key);
+                        out.write_u64(*value);
+                    }
+                    out.end_object();
+                }
+                if let AccountKind::UserId(elem) = &self.kind {
+                    out.key("userId");
+                    out.write_u64(*elem);
+                }
+                if let AccountKind::Program(elem) = &self.kind {
+                    out.key("program");
+                    out.write_str(elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "id" => msg.id = json_to_u64(value)?,
+                        "balance" => msg.balance = Some(json_to_u64(value)?),
+                        "owner" => msg.owner = json_to_string(value)?,
+                        "feeBps" | "fee_bps" => msg.fee_bps = Some(json_to_u32(value)?),
+                        "levels" => {
+                            for elem in json_array(value)? {
+                                msg.levels.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "history" => {
+                            for elem in json_array(value)? {
+                                msg.history.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "limits" => msg.limits = Limits::from_json_value(value)?,
+                        "tiers" => {
+                            for elem in json_array(value)? {
+                                msg.tiers.push(Limits::from_json_value(elem)?);
+                            }
+                        }
+                        "plain" => msg.plain = Limits::from_json_value(value)?,
+                        "balances" => {
+                            for (key, value) in json_object(value)? {
+                                msg.balances.insert(json_map_key(key)?, json_to_u64(value)?);
+                            }
+                        }
+                        "userId" | "user_id" => msg.kind = AccountKind::UserId(json_to_u64(value)?),
+                        "program" => msg.kind = AccountKind::Program(json_to_string(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct AccountRef<'a> {
+            raw: &'a [u8],
+            pub id: u64,
+            pub balance: Option<u64>,
+            pub owner: &'a str,
+            pub fee_bps: Option<u32>,
+            pub levels: RepeatedRef<'a, u32>,
+            pub history: RepeatedRef<'a, u32>,
+            pub limits: Option<LimitsRef<'a>>,
+            pub tiers: RepeatedRef<'a, LimitsRef<'a>>,
+            pub plain: Option<LimitsRef<'a>>,
+            pub user_id: Option<u64>,
+            pub program: Option<&'a str>,
+        }
+
+        impl<'a> AccountRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = AccountRef {
+                    raw: data,
+                    id: 0,
+                    balance: None,
+                    owner: "",
+                    fee_bps: None,
+                    levels: RepeatedRef::packed(data, 40, |data, pos| {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        Ok((v as u32, new_pos))
+                    }),
+                    history: RepeatedRef::packed(data, 48, |data, pos| {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        Ok((v as u32, new_pos))
+                    }),
+                    limits: None,
+                    tiers: RepeatedRef::new(data, 67, |data, pos| {
+                        let (v, new_pos) = decode_group_slice::<8>(data, pos)?;
+                        Ok((LimitsRef::decode(v)?, new_pos))
+                    }),
+                    plain: None,
+                    user_id: None,
+                    program: None,
+                };
+
+                let mut has_id = false;
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        has_id = true;
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.id = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.balance = Some(v);
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.owner = v;
+                        pos = new_pos;
+                    }
+                    32 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.fee_bps = Some(v as u32);
+                        pos = new_pos;
+                    }
+                    40 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    48 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    59 => {
+                        let (v, new_pos) = decode_group_slice::<7>(data, pos)?;
+                        view.limits = Some(LimitsRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    67 => {
+                        pos = skip_field(data, pos, 67)?;
+                    }
+                    74 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.plain = Some(LimitsRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    88 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.user_id = Some(v);
+                        view.program = None;
+                        pos = new_pos;
+                    }
+                    98 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.program = Some(v);
+                        view.user_id = None;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Account::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                if !has_id {
+                    return Err(DecodeError::MissingField { field: 1 });
+                }
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Account, DecodeError> {
+                Account::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Limits {
+            pub daily: u64,
+            pub weekly: Option<u64>,
+            pub caps: Vec<i64>,
+        }
+
+        impl Limits {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Limits {
+                daily: 0,
+                weekly: None,
+                caps: Vec::new(),
+            };
+        }
+
+        impl Default for Limits {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Limits {
+            pub const DAILY_FIELD_NUMBER: u32 = 1;
+            pub const WEEKLY_FIELD_NUMBER: u32 = 2;
+            pub const CAPS_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Limits.daily" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Limits.weekly" },
+                FieldWireType { number: 3, wire_type: 0, packed: true, name: "Limits.caps" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: caps
+                if !self.caps.is_empty() {
+                    w.write_nested(3, |w| {
+                        for elem in self.caps.iter().rev() {
+                            w.write_int64(*elem);
+                        }
+                    });
+                }
+
+                // field 2: weekly
+                if let Some(elem) = &self.weekly {
+                    w.write_varint(*elem);
+                    w.write_key(0x10);
+                }
+
+                // field 1: daily
+                if self.daily != 0 {
+                    w.write_varint(self.daily);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.daily != 0 {
+                    len += key_len(1) + varint_len(self.daily);
+                }
+                if let Some(elem) = &self.weekly {
+                    len += key_len(2) + varint_len(*elem);
+                }
+                if !self.caps.is_empty() {
+                    len += key_len(3) + bytes_len(self.caps.iter().map(|elem| int64_len(*elem)).sum::<usize>());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.daily != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.daily)?;
+                }
+                if let Some(elem) = &self.weekly {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem)?;
+                }
+                if !self.caps.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_varint(self.caps.iter().map(|elem| int64_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.caps {
+                        w.write_int64(*elem)?;
+                    }
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.daily = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.weekly = Some(v);
+                            pos = new_pos;
+                        }
+                        24 => {
+                            let (v, new_pos) = decode_int64(data, pos)?;
+                            if self.caps.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.caps.push(v);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let new_pos = decode_packed_int64_with(data, pos, &mut self.caps, opts)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    3 => Some(self.caps.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Limits {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Limits::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Limits::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Limits::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Limits::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Limits::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Limits::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Limits::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Limits {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Limits::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Limits> for Vec<u8> {
+            fn from(msg: Limits) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Limits {
+            const FULL_NAME: &'static str = "editions.Limits";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LimitsBuilder {
+            inner: Limits,
+        }
+
+        impl Limits {
+            pub fn builder() -> LimitsBuilder {
+                LimitsBuilder::default()
+            }
+        }
+
+        impl LimitsBuilder {
+            pub fn with_daily(mut self, value: u64) -> Self {
+                self.inner.daily = value;
+                self
+            }
+
+            pub fn with_weekly(mut self, value: u64) -> Self {
+                self.inner.weekly = Some(value);
+                self
+            }
+
+            pub fn with_caps(mut self, value: Vec<i64>) -> Self {
+                self.inner.caps = value;
+                self
+            }
+
+            pub fn add_caps(mut self, value: i64) -> Self {
+                self.inner.caps.push(value);
+                self
+            }
+
+            pub fn build(self) -> Limits {
+                self.inner
+            }
+        }
+
+        impl Limits {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.daily != 0 {
+                    out.write_scalar("daily", self.daily);
+                }
+                if let Some(elem) = &self.weekly {
+                    out.write_scalar("weekly", *elem);
+                }
+                for elem in &self.caps {
+                    out.write_scalar("caps", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Limits {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Limits {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.daily != 0 {
+                    out.key("daily");
+                    out.write_u64(self.daily);
+                }
+                if let Some(elem) = &self.weekly {
+                    out.key("weekly");
+                    out.write_u64(*elem);
+                }
+                if !self.caps.is_empty() {
+                    out.key("caps");
+                    out.begin_array();
+                    for elem in &self.caps {
+                        out.write_i64(*elem);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "daily" => msg.daily = json_to_u64(value)?,
+                        "weekly" => msg.weekly = Some(json_to_u64(value)?),
+                        "caps" => {
+                            for elem in json_array(value)? {
+                                msg.caps.push(json_to_i64(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LimitsRef<'a> {
+            raw: &'a [u8],
+            pub daily: u64,
+            pub weekly: Option<u64>,
+            pub caps: RepeatedRef<'a, i64>,
+        }
+
+        impl<'a> LimitsRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = LimitsRef {
+                    raw: data,
+                    daily: 0,
+                    weekly: None,
+                    caps: RepeatedRef::packed(data, 24, decode_int64),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.daily = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.weekly = Some(v);
+                        pos = new_pos;
+                    }
+                    24 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Limits::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Limits, DecodeError> {
+                Limits::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Message-level features apply to every field, map entries aside
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Ledger {
+            pub entries: Vec<i32>,
+            pub limits: Limits,
+            pub by_day: BTreeMap<u32, Limits>,
+            pub deltas: Vec<i64>,
+        }
+
+        impl Ledger {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Ledger {
+                entries: Vec::new(),
+                limits: Limits::DEFAULT,
+                by_day: BTreeMap::new(),
+                deltas: Vec::new(),
+            };
+        }
+
+        impl Default for Ledger {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Ledger {
+            pub const ENTRIES_FIELD_NUMBER: u32 = 1;
+            pub const LIMITS_FIELD_NUMBER: u32 = 2;
+            pub const BY_DAY_FIELD_NUMBER: u32 = 3;
+            pub const DELTAS_FIELD_NUMBER: u32 = 4;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: true, name: "Ledger.entries" },
+                FieldWireType { number: 2, wire_type: 3, packed: false, name: "Ledger.limits" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Ledger.by_day" },
+                FieldWireType { number: 4, wire_type: 0, packed: true, name: "Ledger.deltas" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 4: deltas
+                if !self.deltas.is_empty() {
+                    w.write_nested(4, |w| {
+                        for elem in self.deltas.iter().rev() {
+                            w.write_zigzag64(*elem);
+                        }
+                    });
+                }
+
+                // field 3: by_day
+                for (key, value) in self.by_day.iter().rev() {
+                    w.write_nested(3, |w| {
+                        w.write_message(2, value);
+                        w.write_varint(*key as u64);
+                        w.write_key(0x08);
+                    });
+                }
+
+                // field 2: limits
+                w.write_group(2, &self.limits);
+
+                // field 1: entries
+                for elem in self.entries.iter().rev() {
+                    w.write_int32(*elem);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                for elem in &self.entries {
+                    len += key_len(1) + int32_len(*elem);
+                }
+                len += key_len(2) + self.limits.encoded_len() + key_len(2);
+                for (key, value) in &self.by_day {
+                    len += key_len(3) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.encoded_len()));
+                }
+                if !self.deltas.is_empty() {
+                    len += key_len(4) + bytes_len(self.deltas.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                for elem in &self.entries {
+                    w.write_key(0x08)?;
+                    w.write_int32(*elem)?;
+                }
+                w.write_group(2, &self.limits)?;
+                for (key, value) in &self.by_day {
+                    w.write_key(0x1a)?;
+                    w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key as u64)?;
+                    w.write_message(2, value)?;
+                }
+                if !self.deltas.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_varint(self.deltas.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.deltas {
+                        w.write_zigzag64(*elem)?;
+                    }
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                for elem in &self.entries {
+                    len += key_len(1) + int32_len(*elem);
+                }
+                len += key_len(2) + sizes.record(&self.limits) + key_len(2);
+                for (key, value) in &self.by_day {
+                    len += key_len(3) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(sizes.record(value)));
+                }
+                if !self.deltas.is_empty() {
+                    len += key_len(4) + bytes_len(self.deltas.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                for elem in &self.entries {
+                    w.write_key(0x08)?;
+                    w.write_int32(*elem)?;
+                }
+                w.write_group_sized(2, &self.limits, sizes)?;
+                for (key, value) in &self.by_day {
+                    w.write_key(0x1a)?;
+                    w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key as u64)?;
+                    w.write_message_sized(2, value, sizes)?;
+                }
+                if !self.deltas.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_varint(self.deltas.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.deltas {
+                        w.write_zigzag64(*elem)?;
+                    }
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            if self.entries.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.entries.push(v);
+                            pos = new_pos;
+                        }
+                        10 => {
+                            let new_pos = decode_packed_int32_with(data, pos, &mut self.entries, opts)?;
+                            pos = new_pos;
+                        }
+                        19 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (sub, new_pos) = decode_group_slice::<2>(data, pos)?;
+                            self.limits.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = Limits::DEFAULT;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    8 => {
+                                        let (v, next) = decode_varint(entry, entry_pos)?;
+                                        key = v as u32;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                        value.merge_with(sub, &opts.nested()?)?;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.by_day.len() >= opts.max_repeated_len && !self.by_day.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.by_day.insert(key, value);
+                            pos = new_pos;
+                        }
+                        32 => {
+                            let (v, new_pos) = decode_zigzag64(data, pos)?;
+                            if self.deltas.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.deltas.push(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let new_pos = decode_packed_zigzag64_with(data, pos, &mut self.deltas, opts)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    1 => Some(self.entries.len()),
+                    4 => Some(self.deltas.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Ledger {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Ledger::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Ledger::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Ledger::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Ledger::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Ledger::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Ledger::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Ledger::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Ledger {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Ledger::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Ledger> for Vec<u8> {
+            fn from(msg: Ledger) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Ledger {
+            const FULL_NAME: &'static str = "editions.Ledger";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LedgerBuilder {
+            inner: Ledger,
+        }
+
+        impl Ledger {
+            pub fn builder() -> LedgerBuilder {
+                LedgerBuilder::default()
+            }
+        }
+
+        impl LedgerBuilder {
+            pub fn with_entries(mut self, value: Vec<i32>) -> Self {
+                self.inner.entries = value;
+                self
+            }
+
+            pub fn add_entries(mut self, value: i32) -> Self {
+                self.inner.entries.push(value);
+                self
+            }
+
+            pub fn with_limits(mut self, value: Limits) -> Self {
+                self.inner.limits = value;
+                self
+            }
+
+            pub fn with_by_day(mut self, value: BTreeMap<u32, Limits>) -> Self {
+                self.inner.by_day = value;
+                self
+            }
+
+            pub fn insert_by_day(mut self, key: u32, value: Limits) -> Self {
+                self.inner.by_day.insert(key, value);
+                self
+            }
+
+            pub fn with_deltas(mut self, value: Vec<i64>) -> Self {
+                self.inner.deltas = value;
+                self
+            }
+
+            pub fn add_deltas(mut self, value: i64) -> Self {
+                self.inner.deltas.push(value);
+                self
+            }
+
+            pub fn build(self) -> Ledger {
+                self.inner
+            }
+        }
+
+        impl Ledger {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                for elem in &self.entries {
+                    out.write_scalar("entries", *elem);
+                }
+                if self.limits != Limits::default() {
+                    out.begin_message("limits");
+                    self.limits.write_text(out);
+                    out.end_message();
+                }
+                for (key, value) in &self.by_day {
+                    out.begin_message("by_day");
+                    out.write_scalar("key", *key);
+                    out.begin_message("value");
+                    value.write_text(out);
+                    out.end_message();
+                    out.end_message();
+                }
+                for elem in &self.deltas {
+                    out.write_scalar("deltas", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Ledger {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Ledger {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.entries.is_empty() {
+                    out.key("entries");
+                    out.begin_array();
+                    for elem in &self.entries {
+                        out.write_i32(*elem);
+                    }
+                    out.end_array();
+                }
+                if self.limits != Limits::default() {
+                    out.key("limits");
+                    self.limits.write_json(out);
+                }
+                if !self.by_day.is_empty() {
+                    out.key("byDay");
+                    out.begin_object();
+                    for (key, value) in &self.by_day {
+                        out.key(&key.to_string());
+                        value.write_json(out);
+                    }
+                    out.end_object();
+                }
+                if !self.deltas.is_empty() {
+                    out.key("deltas");
+                    out.begin_array();
+                    for elem in &self.deltas {
+                        out.write_i64(*elem);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "entries" => {
+                            for elem in json_array(value)? {
+                                msg.entries.push(json_to_i32(elem)?);
+                            }
+                        }
+                        "limits" => msg.limits = Limits::from_json_value(value)?,
+                        "byDay" | "by_day" => {
+                            for (key, value) in json_object(value)? {
+                                msg.by_day.insert(json_map_key(key)?, Limits::from_json_value(value)?);
+                            }
+                        }
+                        "deltas" => {
+                            for elem in json_array(value)? {
+                                msg.deltas.push(json_to_i64(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LedgerRef<'a> {
+            raw: &'a [u8],
+            pub entries: RepeatedRef<'a, i32>,
+            pub limits: Option<LimitsRef<'a>>,
+            pub deltas: RepeatedRef<'a, i64>,
+        }
+
+        impl<'a> LedgerRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = LedgerRef {
+                    raw: data,
+                    entries: RepeatedRef::packed(data, 8, decode_int32),
+                    limits: None,
+                    deltas: RepeatedRef::packed(data, 32, decode_zigzag64),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    19 => {
+                        let (v, new_pos) = decode_group_slice::<2>(data, pos)?;
+                        view.limits = Some(LimitsRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    32 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Ledger::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Ledger, DecodeError> {
+                Ledger::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Account(Account),
+            Limits(Limits),
+            Ledger(Ledger),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Account::FULL_NAME => AnyMessage::Account(Account::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Limits::FULL_NAME => AnyMessage::Limits(Limits::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Ledger::FULL_NAME => AnyMessage::Ledger(Ledger::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Account(msg) => Any::pack(msg),
+                    AnyMessage::Limits(msg) => Any::pack(msg),
+                    AnyMessage::Ledger(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,756 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod enums {
+        // Auto-generated by protoc-gen-solana from enums.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: open enums; values unknown to the schema survive a round trip
+        ///
+        /// `example.OrderSide`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum OrderSide {
+            Unspecified = 0,
+            Bid = 1,
+            Ask = 2,
+        }
+
+        impl OrderSide {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: OrderSide = OrderSide::Unspecified;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    OrderSide::Unspecified => "ORDER_SIDE_UNSPECIFIED",
+                    OrderSide::Bid => "ORDER_SIDE_BID",
+                    OrderSide::Ask => "ORDER_SIDE_ASK",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "ORDER_SIDE_UNSPECIFIED" => Some(OrderSide::Unspecified),
+                    "ORDER_SIDE_BID" => Some(OrderSide::Bid),
+                    "ORDER_SIDE_ASK" => Some(OrderSide::Ask),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for OrderSide {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<OrderSide> for i32 {
+            fn from(value: OrderSide) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for OrderSide {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(OrderSide::Unspecified),
+                    1 => Ok(OrderSide::Bid),
+                    2 => Ok(OrderSide::Ask),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// `example.Venue`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum Venue {
+            Serum = 0,
+            Phoenix = 1,
+            Legacy = -1,
+        }
+
+        impl Venue {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: Venue = Venue::Serum;
+
+            /// `OPENBOOK`, an alias of `Serum`
+            #[allow(non_upper_case_globals)]
+            pub const Openbook: Venue = Venue::Serum;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Venue::Serum => "SERUM",
+                    Venue::Phoenix => "PHOENIX",
+                    Venue::Legacy => "LEGACY",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "SERUM" => Some(Venue::Serum),
+                    "PHOENIX" => Some(Venue::Phoenix),
+                    "LEGACY" => Some(Venue::Legacy),
+                    "OPENBOOK" => Some(Venue::Serum),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for Venue {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<Venue> for i32 {
+            fn from(value: Venue) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for Venue {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(Venue::Serum),
+                    1 => Ok(Venue::Phoenix),
+                    -1 => Ok(Venue::Legacy),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum QuoteRouting {
+            Unset,
+            Only(i32),
+            Pool(u32),
+        }
+
+        impl QuoteRouting {
+            pub const DEFAULT: Self = QuoteRouting::Unset;
+        }
+
+        impl Default for QuoteRouting {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Quote {
+            pub side: i32,
+            pub venue: Option<i32>,
+            pub history: Vec<i32>,
+            pub routing: QuoteRouting,
+        }
+
+        impl Quote {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Quote {
+                side: 0,
+                venue: None,
+                history: Vec::new(),
+                routing: QuoteRouting::Unset,
+            };
+        }
+
+        impl Default for Quote {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Quote {
+            pub const SIDE_FIELD_NUMBER: u32 = 1;
+            pub const VENUE_FIELD_NUMBER: u32 = 2;
+            pub const HISTORY_FIELD_NUMBER: u32 = 3;
+            pub const ONLY_FIELD_NUMBER: u32 = 4;
+            pub const POOL_FIELD_NUMBER: u32 = 5;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Quote.side" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Quote.venue" },
+                FieldWireType { number: 3, wire_type: 0, packed: true, name: "Quote.history" },
+                FieldWireType { number: 4, wire_type: 0, packed: false, name: "Quote.only" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "Quote.pool" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 5: pool
+                if let QuoteRouting::Pool(elem) = &self.routing {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x28);
+                }
+
+                // field 4: only
+                if let QuoteRouting::Only(elem) = &self.routing {
+                    w.write_int32(*elem);
+                    w.write_key(0x20);
+                }
+
+                // field 3: history
+                if !self.history.is_empty() {
+                    w.write_nested(3, |w| {
+                        for elem in self.history.iter().rev() {
+                            w.write_int32(*elem);
+                        }
+                    });
+                }
+
+                // field 2: venue
+                if let Some(elem) = &self.venue {
+                    w.write_int32(*elem);
+                    w.write_key(0x10);
+                }
+
+                // field 1: side
+                if self.side != 0 {
+                    w.write_int32(self.side);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.side != 0 {
+                    len += key_len(1) + int32_len(self.side);
+                }
+                if let Some(elem) = &self.venue {
+                    len += key_len(2) + int32_len(*elem);
+                }
+                if !self.history.is_empty() {
+                    len += key_len(3) + bytes_len(self.history.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+                }
+                if let QuoteRouting::Only(elem) = &self.routing {
+                    len += key_len(4) + int32_len(*elem);
+                }
+                if let QuoteRouting::Pool(elem) = &self.routing {
+                    len += key_len(5) + varint_len(*elem as u64);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.side != 0 {
+                    w.write_key(0x08)?;
+                    w.write_int32(self.side)?;
+                }
+                if let Some(elem) = &self.venue {
+                    w.write_key(0x10)?;
+                    w.write_int32(*elem)?;
+                }
+                if !self.history.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_varint(self.history.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.history {
+                        w.write_int32(*elem)?;
+                    }
+                }
+                if let QuoteRouting::Only(elem) = &self.routing {
+                    w.write_key(0x20)?;
+                    w.write_int32(*elem)?;
+                }
+                if let QuoteRouting::Pool(elem) = &self.routing {
+                    w.write_key(0x28)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.side = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.venue = Some(v);
+                            pos = new_pos;
+                        }
+                        24 => {
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            if self.history.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.history.push(v);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let new_pos = decode_packed_int32_with(data, pos, &mut self.history, opts)?;
+                            pos = new_pos;
+                        }
+                        32 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.routing = QuoteRouting::Only(v);
+                            pos = new_pos;
+                        }
+                        40 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.routing = QuoteRouting::Pool(v as u32);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    3 => Some(self.history.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Quote {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Quote::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Quote::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Quote::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Quote::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Quote::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Quote::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Quote::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Quote {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Quote::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Quote> for Vec<u8> {
+            fn from(msg: Quote) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Quote {
+            const FULL_NAME: &'static str = "example.Quote";
+        }
+
+        impl Quote {
+            pub fn only(&self) -> Option<&i32> {
+                match &self.routing {
+                    QuoteRouting::Only(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_only(&mut self, value: i32) {
+                self.routing = QuoteRouting::Only(value);
+            }
+
+            pub fn pool(&self) -> Option<&u32> {
+                match &self.routing {
+                    QuoteRouting::Pool(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_pool(&mut self, value: u32) {
+                self.routing = QuoteRouting::Pool(value);
+            }
+        }
+
+        impl Quote {
+            pub fn side(&self) -> Result<OrderSide, i32> {
+                OrderSide::try_from(self.side)
+            }
+
+            pub fn set_side(&mut self, value: OrderSide) {
+                self.side = value.into();
+            }
+
+            pub fn venue(&self) -> Option<Result<Venue, i32>> {
+                self.venue.map(Venue::try_from)
+            }
+
+            pub fn set_venue(&mut self, value: Venue) {
+                self.venue = Some(value.into());
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct QuoteBuilder {
+            inner: Quote,
+        }
+
+        impl Quote {
+            pub fn builder() -> QuoteBuilder {
+                QuoteBuilder::default()
+            }
+        }
+
+        impl QuoteBuilder {
+            pub fn with_side(mut self, value: i32) -> Self {
+                self.inner.side = value;
+                self
+            }
+
+            pub fn with_venue(mut self, value: i32) -> Self {
+                self.inner.venue = Some(value);
+                self
+            }
+
+            pub fn with_history(mut self, value: Vec<i32>) -> Self {
+                self.inner.history = value;
+                self
+            }
+
+            pub fn add_history(mut self, value: i32) -> Self {
+                self.inner.history.push(value);
+                self
+            }
+
+            pub fn with_only(mut self, value: i32) -> Self {
+                self.inner.routing = QuoteRouting::Only(value);
+                self
+            }
+
+            pub fn with_pool(mut self, value: u32) -> Self {
+                self.inner.routing = QuoteRouting::Pool(value);
+                self
+            }
+
+            pub fn build(self) -> Quote {
+                self.inner
+            }
+        }
+
+        impl Quote {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.side != 0 {
+                    out.write_enum("side", self.side, &[("ORDER_SIDE_UNSPECIFIED", 0), ("ORDER_SIDE_BID", 1), ("ORDER_SIDE_ASK", 2)]);
+                }
+                if let Some(elem) = &self.venue {
+                    out.write_enum("venue", *elem, &[("SERUM", 0), ("PHOENIX", 1), ("LEGACY", -1)]);
+                }
+                for elem in &self.history {
+                    out.write_enum("history", *elem, &[("ORDER_SIDE_UNSPECIFIED", 0), ("ORDER_SIDE_BID", 1), ("ORDER_SIDE_ASK", 2)]);
+                }
+                if let QuoteRouting::Only(elem) = &self.routing {
+                    out.write_enum("only", *elem, &[("SERUM", 0), ("PHOENIX", 1), ("LEGACY", -1)]);
+                }
+                if let QuoteRouting::Pool(elem) = &self.routing {
+                    out.write_scalar("pool", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Quote {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Quote {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.side != 0 {
+                    out.key("side");
+                    out.write_enum(self.side, &[("ORDER_SIDE_UNSPECIFIED", 0), ("ORDER_SIDE_BID", 1), ("ORDER_SIDE_ASK", 2)]);
+                }
+                if let Some(elem) = &self.venue {
+                    out.key("venue");
+                    out.write_enum(*elem, &[("SERUM", 0), ("PHOENIX", 1), ("LEGACY", -1)]);
+                }
+                if !self.history.is_empty() {
+                    out.key("history");
+                    out.begin_array();
+                    for elem in &self.history {
+                        out.write_enum(*elem, &[("ORDER_SIDE_UNSPECIFIED", 0), ("ORDER_SIDE_BID", 1), ("ORDER_SIDE_ASK", 2)]);
+                    }
+                    out.end_array();
+                }
+                if let QuoteRouting::Only(elem) = &self.routing {
+                    out.key("only");
+                    out.write_enum(*elem, &[("SERUM", 0), ("PHOENIX", 1), ("LEGACY", -1)]);
+                }
+                if let QuoteRouting::Pool(elem) = &self.routing {
+                    out.key("pool");
+                    out.write_u32(*elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "side" => msg.side = json_to_enum(value, &[("ORDER_SIDE_UNSPECIFIED", 0), ("ORDER_SIDE_BID", 1), ("ORDER_SIDE_ASK", 2)])?,
+                        "venue" => msg.venue = Some(json_to_enum(value, &[("SERUM", 0), ("PHOENIX", 1), ("LEGACY", -1)])?),
+                        "history" => {
+                            for elem in json_array(value)? {
+                                msg.history.push(json_to_enum(elem, &[("ORDER_SIDE_UNSPECIFIED", 0), ("ORDER_SIDE_BID", 1), ("ORDER_SIDE_ASK", 2)])?);
+                            }
+                        }
+                        "only" => msg.routing = QuoteRouting::Only(json_to_enum(value, &[("SERUM", 0), ("PHOENIX", 1), ("LEGACY", -1)])?),
+                        "pool" => msg.routing = QuoteRouting::Pool(json_to_u32(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct QuoteRef<'a> {
+            raw: &'a [u8],
+            pub side: i32,
+            pub venue: Option<i32>,
+            pub history: RepeatedRef<'a, i32>,
+            pub only: Option<i32>,
+            pub pool: Option<u32>,
+        }
+
+        impl<'a> QuoteRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = QuoteRef {
+                    raw: data,
+                    side: 0,
+                    venue: None,
+                    history: RepeatedRef::packed(data, 24, decode_int32),
+                    only: None,
+                    pool: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.side = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.venue = Some(v);
+                        pos = new_pos;
+                    }
+                    24 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    32 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.only = Some(v);
+                        view.pool = None;
+                        pos = new_pos;
+                    }
+                    40 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.pool = Some(v as u32);
+                        view.only = None;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Quote::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Quote, DecodeError> {
+                Quote::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Quote(Quote),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Quote::FULL_NAME => AnyMessage::Quote(Quote::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Quote(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
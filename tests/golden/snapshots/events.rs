@@ -0,0 +1,1288 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod events {
+        // Auto-generated by protoc-gen-solana from events.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: (solana.event) messages with emit() / from_log() and the
+        /// file's AnyEvent registry
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Deposited {
+            pub owner: Pubkey,
+            pub amount: u64,
+        }
+
+        impl Deposited {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Deposited {
+                owner: ZERO_PUBKEY,
+                amount: 0,
+            };
+        }
+
+        impl Default for Deposited {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Deposited {
+            pub const OWNER_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Deposited.owner" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Deposited.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: owner
+                if self.owner != Pubkey::default() {
+                    w.write_pubkey(&self.owner);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.owner != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.owner != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.owner)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.owner = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Deposited {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Deposited::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Deposited::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Deposited::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Deposited::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Deposited::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Deposited::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Deposited::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Deposited {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Deposited::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Deposited> for Vec<u8> {
+            fn from(msg: Deposited) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Deposited {
+            const FULL_NAME: &'static str = "example.Deposited";
+        }
+
+        impl Deposited {
+            /// Longest encoding of a `Deposited` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 45;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        impl Deposited {
+            /// `sha256("event:example.Deposited")[..8]`
+            pub const EVENT_DISCRIMINATOR: [u8; 8] = [206, 72, 159, 107, 223, 115, 65, 67];
+
+            /// Log this event to the transaction with `sol_log_data`.
+            #[cfg(feature = "solana-program")]
+            pub fn emit(&self) {
+                emit_event(&Self::EVENT_DISCRIMINATOR, self);
+            }
+
+            /// Decode the event from a transaction log line, or `None` if the
+            /// line holds no `Deposited`.
+            pub fn from_log(line: &str) -> Option<Result<Self, DecodeError>> {
+                decode_event_log(&Self::EVENT_DISCRIMINATOR, line)
+            }
+        }
+
+        impl FromEventData for Deposited {
+            fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {
+                decode_event_data(&Self::EVENT_DISCRIMINATOR, data)
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct DepositedBuilder {
+            inner: Deposited,
+        }
+
+        impl Deposited {
+            pub fn builder() -> DepositedBuilder {
+                DepositedBuilder::default()
+            }
+        }
+
+        impl DepositedBuilder {
+            pub fn with_owner(mut self, value: Pubkey) -> Self {
+                self.inner.owner = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> Deposited {
+                self.inner
+            }
+        }
+
+        impl Deposited {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.owner != Pubkey::default() {
+                    out.write_pubkey("owner", &self.owner);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Deposited {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Deposited {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.owner != Pubkey::default() {
+                    out.key("owner");
+                    out.write_pubkey(self.owner);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "owner" => msg.owner = json_to_pubkey(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct DepositedRef<'a> {
+            raw: &'a [u8],
+            pub owner: Pubkey,
+            pub amount: u64,
+        }
+
+        impl<'a> DepositedRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = DepositedRef {
+                    raw: data,
+                    owner: ZERO_PUBKEY,
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.owner = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Deposited::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Deposited, DecodeError> {
+                Deposited::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Withdrawn {
+            pub owner: Pubkey,
+            pub amount: u64,
+            pub memo: String,
+        }
+
+        impl Withdrawn {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Withdrawn {
+                owner: ZERO_PUBKEY,
+                amount: 0,
+                memo: String::new(),
+            };
+        }
+
+        impl Default for Withdrawn {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Withdrawn {
+            pub const OWNER_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+            pub const MEMO_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Withdrawn.owner" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Withdrawn.amount" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Withdrawn.memo" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: memo
+                if !self.memo.is_empty() {
+                    w.write_string(&self.memo);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: owner
+                if self.owner != Pubkey::default() {
+                    w.write_pubkey(&self.owner);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.owner != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    len += key_len(3) + bytes_len(self.memo.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.owner != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.owner)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                if !self.memo.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.memo)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.owner = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.memo = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Withdrawn {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Withdrawn::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Withdrawn::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Withdrawn::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Withdrawn::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Withdrawn::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Withdrawn::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Withdrawn::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Withdrawn {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Withdrawn::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Withdrawn> for Vec<u8> {
+            fn from(msg: Withdrawn) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Withdrawn {
+            const FULL_NAME: &'static str = "example.Withdrawn";
+        }
+
+        impl Withdrawn {
+            /// `sha256("event:example.Withdrawn")[..8]`
+            pub const EVENT_DISCRIMINATOR: [u8; 8] = [15, 156, 113, 46, 115, 183, 8, 227];
+
+            /// Log this event to the transaction with `sol_log_data`.
+            #[cfg(feature = "solana-program")]
+            pub fn emit(&self) {
+                emit_event(&Self::EVENT_DISCRIMINATOR, self);
+            }
+
+            /// Decode the event from a transaction log line, or `None` if the
+            /// line holds no `Withdrawn`.
+            pub fn from_log(line: &str) -> Option<Result<Self, DecodeError>> {
+                decode_event_log(&Self::EVENT_DISCRIMINATOR, line)
+            }
+        }
+
+        impl FromEventData for Withdrawn {
+            fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {
+                decode_event_data(&Self::EVENT_DISCRIMINATOR, data)
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawnBuilder {
+            inner: Withdrawn,
+        }
+
+        impl Withdrawn {
+            pub fn builder() -> WithdrawnBuilder {
+                WithdrawnBuilder::default()
+            }
+        }
+
+        impl WithdrawnBuilder {
+            pub fn with_owner(mut self, value: Pubkey) -> Self {
+                self.inner.owner = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn with_memo(mut self, value: String) -> Self {
+                self.inner.memo = value;
+                self
+            }
+
+            pub fn build(self) -> Withdrawn {
+                self.inner
+            }
+        }
+
+        impl Withdrawn {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.owner != Pubkey::default() {
+                    out.write_pubkey("owner", &self.owner);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+                if !self.memo.is_empty() {
+                    out.write_str("memo", &self.memo);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Withdrawn {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Withdrawn {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.owner != Pubkey::default() {
+                    out.key("owner");
+                    out.write_pubkey(self.owner);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    out.key("memo");
+                    out.write_str(&self.memo);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "owner" => msg.owner = json_to_pubkey(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        "memo" => msg.memo = json_to_string(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawnRef<'a> {
+            raw: &'a [u8],
+            pub owner: Pubkey,
+            pub amount: u64,
+            pub memo: &'a str,
+        }
+
+        impl<'a> WithdrawnRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawnRef {
+                    raw: data,
+                    owner: ZERO_PUBKEY,
+                    amount: 0,
+                    memo: "",
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.owner = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.memo = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Withdrawn::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Withdrawn, DecodeError> {
+                Withdrawn::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Not an event: no emit() and not in AnyEvent
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Ledger {
+            pub deposits: Vec<Deposited>,
+        }
+
+        impl Ledger {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Ledger {
+                deposits: Vec::new(),
+            };
+        }
+
+        impl Default for Ledger {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Ledger {
+            pub const DEPOSITS_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Ledger.deposits" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: deposits
+                for elem in self.deposits.iter().rev() {
+                    w.write_message(1, elem);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                for elem in &self.deposits {
+                    len += key_len(1) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                for elem in &self.deposits {
+                    w.write_message(1, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                for elem in &self.deposits {
+                    len += key_len(1) + bytes_len(sizes.record(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                for elem in &self.deposits {
+                    w.write_message_sized(1, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.deposits.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.deposits.push(Deposited::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    1 => Some(self.deposits.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Ledger {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Ledger::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Ledger::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Ledger::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Ledger::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Ledger::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Ledger::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Ledger::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Ledger {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Ledger::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Ledger> for Vec<u8> {
+            fn from(msg: Ledger) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Ledger {
+            const FULL_NAME: &'static str = "example.Ledger";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LedgerBuilder {
+            inner: Ledger,
+        }
+
+        impl Ledger {
+            pub fn builder() -> LedgerBuilder {
+                LedgerBuilder::default()
+            }
+        }
+
+        impl LedgerBuilder {
+            pub fn with_deposits(mut self, value: Vec<Deposited>) -> Self {
+                self.inner.deposits = value;
+                self
+            }
+
+            pub fn add_deposits(mut self, value: Deposited) -> Self {
+                self.inner.deposits.push(value);
+                self
+            }
+
+            pub fn build(self) -> Ledger {
+                self.inner
+            }
+        }
+
+        impl Ledger {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                for elem in &self.deposits {
+                    out.begin_message("deposits");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Ledger {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Ledger {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.deposits.is_empty() {
+                    out.key("deposits");
+                    out.begin_array();
+                    for elem in &self.deposits {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "deposits" => {
+                            for elem in json_array(value)? {
+                                msg.deposits.push(Deposited::from_json_value(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LedgerRef<'a> {
+            raw: &'a [u8],
+            pub deposits: RepeatedRef<'a, DepositedRef<'a>>,
+        }
+
+        impl<'a> LedgerRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let view = LedgerRef {
+                    raw: data,
+                    deposits: RepeatedRef::new(data, 10, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((DepositedRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Ledger::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Ledger, DecodeError> {
+                Ledger::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Deposited(Deposited),
+            Withdrawn(Withdrawn),
+            Ledger(Ledger),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Deposited::FULL_NAME => AnyMessage::Deposited(Deposited::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Withdrawn::FULL_NAME => AnyMessage::Withdrawn(Withdrawn::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Ledger::FULL_NAME => AnyMessage::Ledger(Ledger::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Deposited(msg) => Any::pack(msg),
+                    AnyMessage::Withdrawn(msg) => Any::pack(msg),
+                    AnyMessage::Ledger(msg) => Any::pack(msg),
+                }
+            }
+        }
+
+        /// Every `(solana.event)` message in this file, for reading events back
+        /// from transaction logs.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyEvent {
+            Deposited(Deposited),
+            Withdrawn(Withdrawn),
+        }
+
+        impl AnyEvent {
+            /// Decode the event a log line holds, or `None` if it is not a
+            /// `Program data:` line carrying one of this file's events.
+            pub fn from_log(line: &str) -> Option<Result<Self, DecodeError>> {
+                if let Some(event) = Deposited::from_log(line) {
+                    return Some(event.map(AnyEvent::Deposited));
+                }
+                if let Some(event) = Withdrawn::from_log(line) {
+                    return Some(event.map(AnyEvent::Withdrawn));
+                }
+                None
+            }
+        }
+
+        impl FromEventData for AnyEvent {
+            fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {
+                if let Some(event) = Deposited::from_event_data(data) {
+                    return Some(event.map(AnyEvent::Deposited));
+                }
+                if let Some(event) = Withdrawn::from_event_data(data) {
+                    return Some(event.map(AnyEvent::Withdrawn));
+                }
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,1205 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod field_numbers {
+        // Auto-generated by protoc-gen-solana from field_numbers.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum SparsePick {
+            Unset,
+            NearMax(u32),
+            Max(Leaf),
+        }
+
+        impl SparsePick {
+            pub const DEFAULT: Self = SparsePick::Unset;
+        }
+
+        impl Default for SparsePick {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: keys at every varint length up to the largest field number,
+        /// 2^29 - 1, whose keys no longer fit an int32 once shifted
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Sparse {
+            pub one: u64,
+            pub fifteen: u64,
+            pub sixteen: Vec<u8>,
+            pub two_byte_max: u32,
+            pub three_byte: String,
+            pub four_byte: i64,
+            pub five_byte: Vec<u32>,
+            pub pick: SparsePick,
+        }
+
+        impl Sparse {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Sparse {
+                one: 0,
+                fifteen: 0,
+                sixteen: Vec::new(),
+                two_byte_max: 0,
+                three_byte: String::new(),
+                four_byte: 0,
+                five_byte: Vec::new(),
+                pick: SparsePick::Unset,
+            };
+        }
+
+        impl Default for Sparse {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Sparse {
+            pub const ONE_FIELD_NUMBER: u32 = 1;
+            pub const FIFTEEN_FIELD_NUMBER: u32 = 15;
+            pub const SIXTEEN_FIELD_NUMBER: u32 = 16;
+            pub const TWO_BYTE_MAX_FIELD_NUMBER: u32 = 2047;
+            pub const THREE_BYTE_FIELD_NUMBER: u32 = 2048;
+            pub const FOUR_BYTE_FIELD_NUMBER: u32 = 262144;
+            pub const FIVE_BYTE_FIELD_NUMBER: u32 = 268435456;
+            pub const NEAR_MAX_FIELD_NUMBER: u32 = 536870910;
+            pub const MAX_FIELD_NUMBER: u32 = 536870911;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Sparse.one" },
+                FieldWireType { number: 15, wire_type: 0, packed: false, name: "Sparse.fifteen" },
+                FieldWireType { number: 16, wire_type: 2, packed: false, name: "Sparse.sixteen" },
+                FieldWireType { number: 2047, wire_type: 0, packed: false, name: "Sparse.two_byte_max" },
+                FieldWireType { number: 2048, wire_type: 2, packed: false, name: "Sparse.three_byte" },
+                FieldWireType { number: 262144, wire_type: 0, packed: false, name: "Sparse.four_byte" },
+                FieldWireType { number: 268435456, wire_type: 0, packed: true, name: "Sparse.five_byte" },
+                FieldWireType { number: 536870910, wire_type: 5, packed: false, name: "Sparse.near_max" },
+                FieldWireType { number: 536870911, wire_type: 2, packed: false, name: "Sparse.max" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 536870911: max
+                if let SparsePick::Max(elem) = &self.pick {
+                    w.write_message(536870911, elem);
+                }
+
+                // field 536870910: near_max
+                if let SparsePick::NearMax(elem) = &self.pick {
+                    w.write_fixed32(*elem);
+                    w.write_key(0xfffffff5);
+                }
+
+                // field 268435456: five_byte
+                if !self.five_byte.is_empty() {
+                    w.write_nested(268435456, |w| {
+                        for elem in self.five_byte.iter().rev() {
+                            w.write_varint(*elem as u64);
+                        }
+                    });
+                }
+
+                // field 262144: four_byte
+                if self.four_byte != 0 {
+                    w.write_zigzag64(self.four_byte);
+                    w.write_key(0x200000);
+                }
+
+                // field 2048: three_byte
+                if !self.three_byte.is_empty() {
+                    w.write_string(&self.three_byte);
+                    w.write_key(0x4002);
+                }
+
+                // field 2047: two_byte_max
+                if self.two_byte_max != 0 {
+                    w.write_varint(self.two_byte_max as u64);
+                    w.write_key(0x3ff8);
+                }
+
+                // field 16: sixteen
+                if !self.sixteen.is_empty() {
+                    w.write_bytes(&self.sixteen);
+                    w.write_key(0x82);
+                }
+
+                // field 15: fifteen
+                if self.fifteen != 0 {
+                    w.write_varint(self.fifteen);
+                    w.write_key(0x78);
+                }
+
+                // field 1: one
+                if self.one != 0 {
+                    w.write_varint(self.one);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.one != 0 {
+                    len += key_len(1) + varint_len(self.one);
+                }
+                if self.fifteen != 0 {
+                    len += key_len(15) + varint_len(self.fifteen);
+                }
+                if !self.sixteen.is_empty() {
+                    len += key_len(16) + bytes_len(self.sixteen.len());
+                }
+                if self.two_byte_max != 0 {
+                    len += key_len(2047) + varint_len(self.two_byte_max as u64);
+                }
+                if !self.three_byte.is_empty() {
+                    len += key_len(2048) + bytes_len(self.three_byte.len());
+                }
+                if self.four_byte != 0 {
+                    len += key_len(262144) + zigzag64_len(self.four_byte);
+                }
+                if !self.five_byte.is_empty() {
+                    len += key_len(268435456) + bytes_len(self.five_byte.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                if let SparsePick::NearMax(_) = &self.pick {
+                    len += key_len(536870910) + 4;
+                }
+                if let SparsePick::Max(elem) = &self.pick {
+                    len += key_len(536870911) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.one != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.one)?;
+                }
+                if self.fifteen != 0 {
+                    w.write_key(0x78)?;
+                    w.write_varint(self.fifteen)?;
+                }
+                if !self.sixteen.is_empty() {
+                    w.write_key(0x82)?;
+                    w.write_bytes(&self.sixteen)?;
+                }
+                if self.two_byte_max != 0 {
+                    w.write_key(0x3ff8)?;
+                    w.write_varint(self.two_byte_max as u64)?;
+                }
+                if !self.three_byte.is_empty() {
+                    w.write_key(0x4002)?;
+                    w.write_string(&self.three_byte)?;
+                }
+                if self.four_byte != 0 {
+                    w.write_key(0x200000)?;
+                    w.write_zigzag64(self.four_byte)?;
+                }
+                if !self.five_byte.is_empty() {
+                    w.write_key(0x80000002)?;
+                    w.write_varint(self.five_byte.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.five_byte {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                if let SparsePick::NearMax(elem) = &self.pick {
+                    w.write_key(0xfffffff5)?;
+                    w.write_fixed32(*elem)?;
+                }
+                if let SparsePick::Max(elem) = &self.pick {
+                    w.write_message(536870911, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.one != 0 {
+                    len += key_len(1) + varint_len(self.one);
+                }
+                if self.fifteen != 0 {
+                    len += key_len(15) + varint_len(self.fifteen);
+                }
+                if !self.sixteen.is_empty() {
+                    len += key_len(16) + bytes_len(self.sixteen.len());
+                }
+                if self.two_byte_max != 0 {
+                    len += key_len(2047) + varint_len(self.two_byte_max as u64);
+                }
+                if !self.three_byte.is_empty() {
+                    len += key_len(2048) + bytes_len(self.three_byte.len());
+                }
+                if self.four_byte != 0 {
+                    len += key_len(262144) + zigzag64_len(self.four_byte);
+                }
+                if !self.five_byte.is_empty() {
+                    len += key_len(268435456) + bytes_len(self.five_byte.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                if let SparsePick::NearMax(_) = &self.pick {
+                    len += key_len(536870910) + 4;
+                }
+                if let SparsePick::Max(elem) = &self.pick {
+                    len += key_len(536870911) + bytes_len(sizes.record(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.one != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.one)?;
+                }
+                if self.fifteen != 0 {
+                    w.write_key(0x78)?;
+                    w.write_varint(self.fifteen)?;
+                }
+                if !self.sixteen.is_empty() {
+                    w.write_key(0x82)?;
+                    w.write_bytes(&self.sixteen)?;
+                }
+                if self.two_byte_max != 0 {
+                    w.write_key(0x3ff8)?;
+                    w.write_varint(self.two_byte_max as u64)?;
+                }
+                if !self.three_byte.is_empty() {
+                    w.write_key(0x4002)?;
+                    w.write_string(&self.three_byte)?;
+                }
+                if self.four_byte != 0 {
+                    w.write_key(0x200000)?;
+                    w.write_zigzag64(self.four_byte)?;
+                }
+                if !self.five_byte.is_empty() {
+                    w.write_key(0x80000002)?;
+                    w.write_varint(self.five_byte.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.five_byte {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                if let SparsePick::NearMax(elem) = &self.pick {
+                    w.write_key(0xfffffff5)?;
+                    w.write_fixed32(*elem)?;
+                }
+                if let SparsePick::Max(elem) = &self.pick {
+                    w.write_message_sized(536870911, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.one = v;
+                            pos = new_pos;
+                        }
+                        120 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.fifteen = v;
+                            pos = new_pos;
+                        }
+                        130 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.sixteen = v;
+                            pos = new_pos;
+                        }
+                        16376 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.two_byte_max = v as u32;
+                            pos = new_pos;
+                        }
+                        16386 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.three_byte = v;
+                            pos = new_pos;
+                        }
+                        2097152 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_zigzag64(data, pos)?;
+                            self.four_byte = v;
+                            pos = new_pos;
+                        }
+                        2147483648 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.five_byte.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.five_byte.push(v as u32);
+                            pos = new_pos;
+                        }
+                        2147483650 => {
+                            let new_pos = decode_packed_uint32_with(data, pos, &mut self.five_byte, opts)?;
+                            pos = new_pos;
+                        }
+                        4294967285 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_fixed32(data, pos)?;
+                            self.pick = SparsePick::NearMax(v);
+                            pos = new_pos;
+                        }
+                        4294967290 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if let SparsePick::Max(existing) = &mut self.pick {
+                                existing.merge_with(sub, &opts.nested()?)?;
+                            } else {
+                                self.pick = SparsePick::Max(Leaf::decode_with(sub, &opts.nested()?)?);
+                            }
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    268435456 => Some(self.five_byte.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Sparse {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Sparse::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Sparse::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Sparse::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Sparse::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Sparse::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Sparse::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Sparse::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Sparse {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Sparse::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Sparse> for Vec<u8> {
+            fn from(msg: Sparse) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Sparse {
+            const FULL_NAME: &'static str = "example.Sparse";
+        }
+
+        impl Sparse {
+            pub fn near_max(&self) -> Option<&u32> {
+                match &self.pick {
+                    SparsePick::NearMax(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_near_max(&mut self, value: u32) {
+                self.pick = SparsePick::NearMax(value);
+            }
+
+            pub fn max(&self) -> Option<&Leaf> {
+                match &self.pick {
+                    SparsePick::Max(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_max(&mut self, value: Leaf) {
+                self.pick = SparsePick::Max(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct SparseBuilder {
+            inner: Sparse,
+        }
+
+        impl Sparse {
+            pub fn builder() -> SparseBuilder {
+                SparseBuilder::default()
+            }
+        }
+
+        impl SparseBuilder {
+            pub fn with_one(mut self, value: u64) -> Self {
+                self.inner.one = value;
+                self
+            }
+
+            pub fn with_fifteen(mut self, value: u64) -> Self {
+                self.inner.fifteen = value;
+                self
+            }
+
+            pub fn with_sixteen(mut self, value: Vec<u8>) -> Self {
+                self.inner.sixteen = value;
+                self
+            }
+
+            pub fn with_two_byte_max(mut self, value: u32) -> Self {
+                self.inner.two_byte_max = value;
+                self
+            }
+
+            pub fn with_three_byte(mut self, value: String) -> Self {
+                self.inner.three_byte = value;
+                self
+            }
+
+            pub fn with_four_byte(mut self, value: i64) -> Self {
+                self.inner.four_byte = value;
+                self
+            }
+
+            pub fn with_five_byte(mut self, value: Vec<u32>) -> Self {
+                self.inner.five_byte = value;
+                self
+            }
+
+            pub fn add_five_byte(mut self, value: u32) -> Self {
+                self.inner.five_byte.push(value);
+                self
+            }
+
+            pub fn with_near_max(mut self, value: u32) -> Self {
+                self.inner.pick = SparsePick::NearMax(value);
+                self
+            }
+
+            pub fn with_max(mut self, value: Leaf) -> Self {
+                self.inner.pick = SparsePick::Max(value);
+                self
+            }
+
+            pub fn build(self) -> Sparse {
+                self.inner
+            }
+        }
+
+        impl Sparse {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.one != 0 {
+                    out.write_scalar("one", self.one);
+                }
+                if self.fifteen != 0 {
+                    out.write_scalar("fifteen", self.fifteen);
+                }
+                if !self.sixteen.is_empty() {
+                    out.write_bytes("sixteen", &self.sixteen);
+                }
+                if self.two_byte_max != 0 {
+                    out.write_scalar("two_byte_max", self.two_byte_max);
+                }
+                if !self.three_byte.is_empty() {
+                    out.write_str("three_byte", &self.three_byte);
+                }
+                if self.four_byte != 0 {
+                    out.write_scalar("four_byte", self.four_byte);
+                }
+                for elem in &self.five_byte {
+                    out.write_scalar("five_byte", *elem);
+                }
+                if let SparsePick::NearMax(elem) = &self.pick {
+                    out.write_scalar("near_max", *elem);
+                }
+                if let SparsePick::Max(elem) = &self.pick {
+                    out.begin_message("max");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Sparse {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Sparse {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.one != 0 {
+                    out.key("one");
+                    out.write_u64(self.one);
+                }
+                if self.fifteen != 0 {
+                    out.key("fifteen");
+                    out.write_u64(self.fifteen);
+                }
+                if !self.sixteen.is_empty() {
+                    out.key("sixteen");
+                    out.write_bytes(&self.sixteen);
+                }
+                if self.two_byte_max != 0 {
+                    out.key("twoByteMax");
+                    out.write_u32(self.two_byte_max);
+                }
+                if !self.three_byte.is_empty() {
+                    out.key("threeByte");
+                    out.write_str(&self.three_byte);
+                }
+                if self.four_byte != 0 {
+                    out.key("fourByte");
+                    out.write_i64(self.four_byte);
+                }
+                if !self.five_byte.is_empty() {
+                    out.key("fiveByte");
+                    out.begin_array();
+                    for elem in &self.five_byte {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if let SparsePick::NearMax(elem) = &self.pick {
+                    out.key("nearMax");
+                    out.write_u32(*elem);
+                }
+                if let SparsePick::Max(elem) = &self.pick {
+                    out.key("max");
+                    elem.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "one" => msg.one = json_to_u64(value)?,
+                        "fifteen" => msg.fifteen = json_to_u64(value)?,
+                        "sixteen" => msg.sixteen = json_to_bytes(value)?,
+                        "twoByteMax" | "two_byte_max" => msg.two_byte_max = json_to_u32(value)?,
+                        "threeByte" | "three_byte" => msg.three_byte = json_to_string(value)?,
+                        "fourByte" | "four_byte" => msg.four_byte = json_to_i64(value)?,
+                        "fiveByte" | "five_byte" => {
+                            for elem in json_array(value)? {
+                                msg.five_byte.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "nearMax" | "near_max" => msg.pick = SparsePick::NearMax(json_to_u32(value)?),
+                        "max" => msg.pick = SparsePick::Max(Leaf::from_json_value(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct SparseRef<'a> {
+            raw: &'a [u8],
+            pub one: u64,
+            pub fifteen: u64,
+            pub sixteen: &'a [u8],
+            pub two_byte_max: u32,
+            pub three_byte: &'a str,
+            pub four_byte: i64,
+            pub five_byte: RepeatedRef<'a, u32>,
+            pub near_max: Option<u32>,
+            pub max: Option<LeafRef<'a>>,
+        }
+
+        impl<'a> SparseRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = SparseRef {
+                    raw: data,
+                    one: 0,
+                    fifteen: 0,
+                    sixteen: &[],
+                    two_byte_max: 0,
+                    three_byte: "",
+                    four_byte: 0,
+                    five_byte: RepeatedRef::packed(data, 2147483648, |data, pos| {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        Ok((v as u32, new_pos))
+                    }),
+                    near_max: None,
+                    max: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.one = v;
+                        pos = new_pos;
+                    }
+                    120 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.fifteen = v;
+                        pos = new_pos;
+                    }
+                    130 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.sixteen = v;
+                        pos = new_pos;
+                    }
+                    16376 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.two_byte_max = v as u32;
+                        pos = new_pos;
+                    }
+                    16386 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.three_byte = v;
+                        pos = new_pos;
+                    }
+                    2097152 => {
+                        let (v, new_pos) = decode_zigzag64(data, pos)?;
+                        view.four_byte = v;
+                        pos = new_pos;
+                    }
+                    2147483648 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    4294967285 => {
+                        let (v, new_pos) = decode_fixed32(data, pos)?;
+                        view.near_max = Some(v);
+                        view.max = None;
+                        pos = new_pos;
+                    }
+                    4294967290 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.max = Some(LeafRef::decode(v)?);
+                        view.near_max = None;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Sparse::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Sparse, DecodeError> {
+                Sparse::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Leaf {
+            pub value: u64,
+        }
+
+        impl Leaf {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Leaf {
+                value: 0,
+            };
+        }
+
+        impl Default for Leaf {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Leaf {
+            pub const VALUE_FIELD_NUMBER: u32 = 536870911;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 536870911, wire_type: 0, packed: false, name: "Leaf.value" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 536870911: value
+                if self.value != 0 {
+                    w.write_varint(self.value);
+                    w.write_key(0xfffffff8);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.value != 0 {
+                    len += key_len(536870911) + varint_len(self.value);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.value != 0 {
+                    w.write_key(0xfffffff8)?;
+                    w.write_varint(self.value)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        4294967288 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.value = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Leaf {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Leaf::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Leaf::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Leaf::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Leaf::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Leaf::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Leaf::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Leaf::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Leaf {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Leaf::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Leaf> for Vec<u8> {
+            fn from(msg: Leaf) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Leaf {
+            const FULL_NAME: &'static str = "example.Leaf";
+        }
+
+        impl Leaf {
+            /// Longest encoding of a `Leaf` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 15;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LeafBuilder {
+            inner: Leaf,
+        }
+
+        impl Leaf {
+            pub fn builder() -> LeafBuilder {
+                LeafBuilder::default()
+            }
+        }
+
+        impl LeafBuilder {
+            pub fn with_value(mut self, value: u64) -> Self {
+                self.inner.value = value;
+                self
+            }
+
+            pub fn build(self) -> Leaf {
+                self.inner
+            }
+        }
+
+        impl Leaf {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.value != 0 {
+                    out.write_scalar("value", self.value);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Leaf {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Leaf {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.value != 0 {
+                    out.key("value");
+                    out.write_u64(self.value);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "value" => msg.value = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LeafRef<'a> {
+            raw: &'a [u8],
+            pub value: u64,
+        }
+
+        impl<'a> LeafRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = LeafRef {
+                    raw: data,
+                    value: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    4294967288 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.value = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Leaf::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Leaf, DecodeError> {
+                Leaf::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Sparse(Sparse),
+            Leaf(Leaf),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Sparse::FULL_NAME => AnyMessage::Sparse(Sparse::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Leaf::FULL_NAME => AnyMessage::Leaf(Leaf::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Sparse(msg) => Any::pack(msg),
+                    AnyMessage::Leaf(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,649 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod fixed_bytes {
+        // Auto-generated by protoc-gen-solana from fixed_bytes.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum SignedTransferProof {
+            Unset,
+            MerkleRoot([u8; 32]),
+            Note(String),
+        }
+
+        impl SignedTransferProof {
+            pub const DEFAULT: Self = SignedTransferProof::Unset;
+        }
+
+        impl Default for SignedTransferProof {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: (solana.fixed_len) bytes fields as \[u8; N\] arrays
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct SignedTransfer {
+            pub signature: [u8; 64],
+            pub recent_blockhash: [u8; 32],
+            pub seeds: Vec<[u8; 8]>,
+            pub nonce: Option<[u8; 16]>,
+            pub proof: SignedTransferProof,
+        }
+
+        impl SignedTransfer {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = SignedTransfer {
+                signature: [0; 64],
+                recent_blockhash: [0; 32],
+                seeds: Vec::new(),
+                nonce: None,
+                proof: SignedTransferProof::Unset,
+            };
+        }
+
+        impl Default for SignedTransfer {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl SignedTransfer {
+            pub const SIGNATURE_FIELD_NUMBER: u32 = 1;
+            pub const RECENT_BLOCKHASH_FIELD_NUMBER: u32 = 2;
+            pub const SEEDS_FIELD_NUMBER: u32 = 3;
+            pub const NONCE_FIELD_NUMBER: u32 = 4;
+            pub const MERKLE_ROOT_FIELD_NUMBER: u32 = 5;
+            pub const NOTE_FIELD_NUMBER: u32 = 6;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "SignedTransfer.signature" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "SignedTransfer.recent_blockhash" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "SignedTransfer.seeds" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "SignedTransfer.nonce" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "SignedTransfer.merkle_root" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "SignedTransfer.note" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 6: note
+                if let SignedTransferProof::Note(elem) = &self.proof {
+                    w.write_string(elem);
+                    w.write_key(0x32);
+                }
+
+                // field 5: merkle_root
+                if let SignedTransferProof::MerkleRoot(elem) = &self.proof {
+                    w.write_bytes(elem);
+                    w.write_key(0x2a);
+                }
+
+                // field 4: nonce
+                if let Some(elem) = &self.nonce {
+                    w.write_bytes(elem);
+                    w.write_key(0x22);
+                }
+
+                // field 3: seeds
+                for elem in self.seeds.iter().rev() {
+                    w.write_bytes(elem);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: recent_blockhash
+                if self.recent_blockhash != [0; 32] {
+                    w.write_bytes(&self.recent_blockhash);
+                    w.write_key(0x12);
+                }
+
+                // field 1: signature
+                if self.signature != [0; 64] {
+                    w.write_bytes(&self.signature);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.signature != [0; 64] {
+                    len += key_len(1) + bytes_len(self.signature.len());
+                }
+                if self.recent_blockhash != [0; 32] {
+                    len += key_len(2) + bytes_len(self.recent_blockhash.len());
+                }
+                for elem in &self.seeds {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.nonce {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                if let SignedTransferProof::MerkleRoot(elem) = &self.proof {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                if let SignedTransferProof::Note(elem) = &self.proof {
+                    len += key_len(6) + bytes_len(elem.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.signature != [0; 64] {
+                    w.write_key(0x0a)?;
+                    w.write_bytes(&self.signature)?;
+                }
+                if self.recent_blockhash != [0; 32] {
+                    w.write_key(0x12)?;
+                    w.write_bytes(&self.recent_blockhash)?;
+                }
+                for elem in &self.seeds {
+                    w.write_key(0x1a)?;
+                    w.write_bytes(elem)?;
+                }
+                if let Some(elem) = &self.nonce {
+                    w.write_key(0x22)?;
+                    w.write_bytes(elem)?;
+                }
+                if let SignedTransferProof::MerkleRoot(elem) = &self.proof {
+                    w.write_key(0x2a)?;
+                    w.write_bytes(elem)?;
+                }
+                if let SignedTransferProof::Note(elem) = &self.proof {
+                    w.write_key(0x32)?;
+                    w.write_string(elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<64>(data, pos)?;
+                            self.signature = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<32>(data, pos)?;
+                            self.recent_blockhash = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let (v, new_pos) = decode_fixed_bytes::<8>(data, pos)?;
+                            if self.seeds.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.seeds.push(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<16>(data, pos)?;
+                            self.nonce = Some(v);
+                            pos = new_pos;
+                        }
+                        42 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<32>(data, pos)?;
+                            self.proof = SignedTransferProof::MerkleRoot(v);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.proof = SignedTransferProof::Note(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    3 => Some(self.seeds.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for SignedTransfer {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                SignedTransfer::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                SignedTransfer::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                SignedTransfer::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                SignedTransfer::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                SignedTransfer::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                SignedTransfer::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                SignedTransfer::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for SignedTransfer {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                SignedTransfer::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<SignedTransfer> for Vec<u8> {
+            fn from(msg: SignedTransfer) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for SignedTransfer {
+            const FULL_NAME: &'static str = "example.SignedTransfer";
+        }
+
+        impl SignedTransfer {
+            pub fn merkle_root(&self) -> Option<&[u8; 32]> {
+                match &self.proof {
+                    SignedTransferProof::MerkleRoot(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_merkle_root(&mut self, value: [u8; 32]) {
+                self.proof = SignedTransferProof::MerkleRoot(value);
+            }
+
+            pub fn note(&self) -> Option<&String> {
+                match &self.proof {
+                    SignedTransferProof::Note(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_note(&mut self, value: String) {
+                self.proof = SignedTransferProof::Note(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct SignedTransferBuilder {
+            inner: SignedTransfer,
+        }
+
+        impl SignedTransfer {
+            pub fn builder() -> SignedTransferBuilder {
+                SignedTransferBuilder::default()
+            }
+        }
+
+        impl SignedTransferBuilder {
+            pub fn with_signature(mut self, value: [u8; 64]) -> Self {
+                self.inner.signature = value;
+                self
+            }
+
+            pub fn with_recent_blockhash(mut self, value: [u8; 32]) -> Self {
+                self.inner.recent_blockhash = value;
+                self
+            }
+
+            pub fn with_seeds(mut self, value: Vec<[u8; 8]>) -> Self {
+                self.inner.seeds = value;
+                self
+            }
+
+            pub fn add_seeds(mut self, value: [u8; 8]) -> Self {
+                self.inner.seeds.push(value);
+                self
+            }
+
+            pub fn with_nonce(mut self, value: [u8; 16]) -> Self {
+                self.inner.nonce = Some(value);
+                self
+            }
+
+            pub fn with_merkle_root(mut self, value: [u8; 32]) -> Self {
+                self.inner.proof = SignedTransferProof::MerkleRoot(value);
+                self
+            }
+
+            pub fn with_note(mut self, value: String) -> Self {
+                self.inner.proof = SignedTransferProof::Note(value);
+                self
+            }
+
+            pub fn build(self) -> SignedTransfer {
+                self.inner
+            }
+        }
+
+        impl SignedTransfer {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.signature != [0; 64] {
+                    out.write_bytes("signature", &self.signature);
+                }
+                if self.recent_blockhash != [0; 32] {
+                    out.write_bytes("recent_blockhash", &self.recent_blockhash);
+                }
+                for elem in &self.seeds {
+                    out.write_bytes("seeds", elem);
+                }
+                if let Some(elem) = &self.nonce {
+                    out.write_bytes("nonce", elem);
+                }
+                if let SignedTransferProof::MerkleRoot(elem) = &self.proof {
+                    out.write_bytes("merkle_root", elem);
+                }
+                if let SignedTransferProof::Note(elem) = &self.proof {
+                    out.write_str("note", elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for SignedTransfer {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl SignedTransfer {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.signature != [0; 64] {
+                    out.key("signature");
+                    out.write_bytes(&self.signature);
+                }
+                if self.recent_blockhash != [0; 32] {
+                    out.key("recentBlockhash");
+                    out.write_bytes(&self.recent_blockhash);
+                }
+                if !self.seeds.is_empty() {
+                    out.key("seeds");
+                    out.begin_array();
+                    for elem in &self.seeds {
+                        out.write_bytes(elem);
+                    }
+                    out.end_array();
+                }
+                if let Some(elem) = &self.nonce {
+                    out.key("nonce");
+                    out.write_bytes(elem);
+                }
+                if let SignedTransferProof::MerkleRoot(elem) = &self.proof {
+                    out.key("merkleRoot");
+                    out.write_bytes(elem);
+                }
+                if let SignedTransferProof::Note(elem) = &self.proof {
+                    out.key("note");
+                    out.write_str(elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "signature" => msg.signature = json_to_fixed_bytes::<64>(value)?,
+                        "recentBlockhash" | "recent_blockhash" => msg.recent_blockhash = json_to_fixed_bytes::<32>(value)?,
+                        "seeds" => {
+                            for elem in json_array(value)? {
+                                msg.seeds.push(json_to_fixed_bytes::<8>(elem)?);
+                            }
+                        }
+                        "nonce" => msg.nonce = Some(json_to_fixed_bytes::<16>(value)?),
+                        "merkleRoot" | "merkle_root" => msg.proof = SignedTransferProof::MerkleRoot(json_to_fixed_bytes::<32>(value)?),
+                        "note" => msg.proof = SignedTransferProof::Note(json_to_string(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct SignedTransferRef<'a> {
+            raw: &'a [u8],
+            pub signature: &'a [u8; 64],
+            pub recent_blockhash: &'a [u8; 32],
+            pub seeds: RepeatedRef<'a, &'a [u8; 8]>,
+            pub nonce: Option<&'a [u8; 16]>,
+            pub merkle_root: Option<&'a [u8; 32]>,
+            pub note: Option<&'a str>,
+        }
+
+        impl<'a> SignedTransferRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = SignedTransferRef {
+                    raw: data,
+                    signature: &[0; 64],
+                    recent_blockhash: &[0; 32],
+                    seeds: RepeatedRef::new(data, 26, decode_fixed_bytes_ref::<8>),
+                    nonce: None,
+                    merkle_root: None,
+                    note: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_fixed_bytes_ref::<64>(data, pos)?;
+                        view.signature = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_fixed_bytes_ref::<32>(data, pos)?;
+                        view.recent_blockhash = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_fixed_bytes_ref::<16>(data, pos)?;
+                        view.nonce = Some(v);
+                        pos = new_pos;
+                    }
+                    42 => {
+                        let (v, new_pos) = decode_fixed_bytes_ref::<32>(data, pos)?;
+                        view.merkle_root = Some(v);
+                        view.note = None;
+                        pos = new_pos;
+                    }
+                    50 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.note = Some(v);
+                        view.merkle_root = None;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(SignedTransfer::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<SignedTransfer, DecodeError> {
+                SignedTransfer::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            SignedTransfer(SignedTransfer),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    SignedTransfer::FULL_NAME => AnyMessage::SignedTransfer(SignedTransfer::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::SignedTransfer(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1663 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod map {
+        // Auto-generated by protoc-gen-solana from map.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// `example.Status`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum Status {
+            Unknown = 0,
+            Active = 1,
+        }
+
+        impl Status {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: Status = Status::Unknown;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Status::Unknown => "STATUS_UNKNOWN",
+                    Status::Active => "STATUS_ACTIVE",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "STATUS_UNKNOWN" => Some(Status::Unknown),
+                    "STATUS_ACTIVE" => Some(Status::Active),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for Status {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<Status> for i32 {
+            fn from(value: Status) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for Status {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(Status::Unknown),
+                    1 => Ok(Status::Active),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// Exercises: map\<K, V> across key types and scalar/enum/message values
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Registry {
+            pub balances: BTreeMap<String, u64>,
+            pub labels: BTreeMap<u32, String>,
+            pub blobs: BTreeMap<i64, Vec<u8>>,
+            pub rates: BTreeMap<i32, f64>,
+            pub flags: BTreeMap<u64, bool>,
+            pub weights: BTreeMap<i32, f32>,
+            pub counts: BTreeMap<bool, i32>,
+            pub slots: BTreeMap<u64, Slot>,
+            pub statuses: BTreeMap<String, i32>,
+            pub epoch: u64,
+        }
+
+        impl Registry {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Registry {
+                balances: BTreeMap::new(),
+                labels: BTreeMap::new(),
+                blobs: BTreeMap::new(),
+                rates: BTreeMap::new(),
+                flags: BTreeMap::new(),
+                weights: BTreeMap::new(),
+                counts: BTreeMap::new(),
+                slots: BTreeMap::new(),
+                statuses: BTreeMap::new(),
+                epoch: 0,
+            };
+        }
+
+        impl Default for Registry {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Registry {
+            pub const BALANCES_FIELD_NUMBER: u32 = 1;
+            pub const LABELS_FIELD_NUMBER: u32 = 2;
+            pub const BLOBS_FIELD_NUMBER: u32 = 3;
+            pub const RATES_FIELD_NUMBER: u32 = 4;
+            pub const FLAGS_FIELD_NUMBER: u32 = 5;
+            pub const WEIGHTS_FIELD_NUMBER: u32 = 6;
+            pub const COUNTS_FIELD_NUMBER: u32 = 7;
+            pub const SLOTS_FIELD_NUMBER: u32 = 8;
+            pub const STATUSES_FIELD_NUMBER: u32 = 9;
+            pub const EPOCH_FIELD_NUMBER: u32 = 10;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Registry.balances" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Registry.labels" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Registry.blobs" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Registry.rates" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "Registry.flags" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Registry.weights" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "Registry.counts" },
+                FieldWireType { number: 8, wire_type: 2, packed: false, name: "Registry.slots" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Registry.statuses" },
+                FieldWireType { number: 10, wire_type: 0, packed: false, name: "Registry.epoch" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 10: epoch
+                if self.epoch != 0 {
+                    w.write_varint(self.epoch);
+                    w.write_key(0x50);
+                }
+
+                // field 9: statuses
+                for (key, value) in self.statuses.iter().rev() {
+                    w.write_nested(9, |w| {
+                        w.write_int32(*value);
+                        w.write_key(0x10);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 8: slots
+                for (key, value) in self.slots.iter().rev() {
+                    w.write_nested(8, |w| {
+                        w.write_message(2, value);
+                        w.write_varint(*key);
+                        w.write_key(0x08);
+                    });
+                }
+
+                // field 7: counts
+                for (key, value) in self.counts.iter().rev() {
+                    w.write_nested(7, |w| {
+                        w.write_int32(*value);
+                        w.write_key(0x10);
+                        w.write_bool(*key);
+                        w.write_key(0x08);
+                    });
+                }
+
+                // field 6: weights
+                for (key, value) in self.weights.iter().rev() {
+                    w.write_nested(6, |w| {
+                        w.write_float(*value);
+                        w.write_key(0x15);
+                        w.write_sfixed32(*key);
+                        w.write_key(0x0d);
+                    });
+                }
+
+                // field 5: flags
+                for (key, value) in self.flags.iter().rev() {
+                    w.write_nested(5, |w| {
+                        w.write_bool(*value);
+                        w.write_key(0x10);
+                        w.write_fixed64(*key);
+                        w.write_key(0x09);
+                    });
+                }
+
+                // field 4: rates
+                for (key, value) in self.rates.iter().rev() {
+                    w.write_nested(4, |w| {
+                        w.write_double(*value);
+                        w.write_key(0x11);
+                        w.write_zigzag32(*key);
+                        w.write_key(0x08);
+                    });
+                }
+
+                // field 3: blobs
+                for (key, value) in self.blobs.iter().rev() {
+                    w.write_nested(3, |w| {
+                        w.write_bytes(value);
+                        w.write_key(0x12);
+                        w.write_int64(*key);
+                        w.write_key(0x08);
+                    });
+                }
+
+                // field 2: labels
+                for (key, value) in self.labels.iter().rev() {
+                    w.write_nested(2, |w| {
+                        w.write_string(value);
+                        w.write_key(0x12);
+                        w.write_varint(*key as u64);
+                        w.write_key(0x08);
+                    });
+                }
+
+                // field 1: balances
+                for (key, value) in self.balances.iter().rev() {
+                    w.write_nested(1, |w| {
+                        w.write_varint(*value);
+                        w.write_key(0x10);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                for (key, value) in &self.balances {
+                    len += key_len(1) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value));
+                }
+                for (key, value) in &self.labels {
+                    len += key_len(2) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len()));
+                }
+                for (key, value) in &self.blobs {
+                    len += key_len(3) + bytes_len(key_len(1) + int64_len(*key) + key_len(2) + bytes_len(value.len()));
+                }
+                for key in self.rates.keys() {
+                    len += key_len(4) + bytes_len(key_len(1) + zigzag32_len(*key) + key_len(2) + 8);
+                }
+                len += self.flags.len() * (key_len(5) + bytes_len(key_len(1) + 8 + key_len(2) + 1));
+                len += self.weights.len() * (key_len(6) + bytes_len(key_len(1) + 4 + key_len(2) + 4));
+                for value in self.counts.values() {
+                    len += key_len(7) + bytes_len(key_len(1) + 1 + key_len(2) + int32_len(*value));
+                }
+                for (key, value) in &self.slots {
+                    len += key_len(8) + bytes_len(key_len(1) + varint_len(*key) + key_len(2) + bytes_len(value.encoded_len()));
+                }
+                for (key, value) in &self.statuses {
+                    len += key_len(9) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value));
+                }
+                if self.epoch != 0 {
+                    len += key_len(10) + varint_len(self.epoch);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                for (key, value) in &self.balances {
+                    w.write_key(0x0a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x10)?;
+                    w.write_varint(*value)?;
+                }
+                for (key, value) in &self.labels {
+                    w.write_key(0x12)?;
+                    w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key as u64)?;
+                    w.write_key(0x12)?;
+                    w.write_string(value)?;
+                }
+                for (key, value) in &self.blobs {
+                    w.write_key(0x1a)?;
+                    w.write_varint((key_len(1) + int64_len(*key) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_int64(*key)?;
+                    w.write_key(0x12)?;
+                    w.write_bytes(value)?;
+                }
+                for (key, value) in &self.rates {
+                    w.write_key(0x22)?;
+                    w.write_varint((key_len(1) + zigzag32_len(*key) + key_len(2) + 8) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_zigzag32(*key)?;
+                    w.write_key(0x11)?;
+                    w.write_double(*value)?;
+                }
+                for (key, value) in &self.flags {
+                    w.write_key(0x2a)?;
+                    w.write_varint((key_len(1) + 8 + key_len(2) + 1) as u64)?;
+                    w.write_key(0x09)?;
+                    w.write_fixed64(*key)?;
+                    w.write_key(0x10)?;
+                    w.write_bool(*value)?;
+                }
+                for (key, value) in &self.weights {
+                    w.write_key(0x32)?;
+                    w.write_varint((key_len(1) + 4 + key_len(2) + 4) as u64)?;
+                    w.write_key(0x0d)?;
+                    w.write_sfixed32(*key)?;
+                    w.write_key(0x15)?;
+                    w.write_float(*value)?;
+                }
+                for (key, value) in &self.counts {
+                    w.write_key(0x3a)?;
+                    w.write_varint((key_len(1) + 1 + key_len(2) + int32_len(*value)) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_bool(*key)?;
+                    w.write_key(0x10)?;
+                    w.write_int32(*value)?;
+                }
+                for (key, value) in &self.slots {
+                    w.write_key(0x42)?;
+                    w.write_varint((key_len(1) + varint_len(*key) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key)?;
+                    w.write_message(2, value)?;
+                }
+                for (key, value) in &self.statuses {
+                    w.write_key(0x4a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x10)?;
+                    w.write_int32(*value)?;
+                }
+                if self.epoch != 0 {
+                    w.write_key(0x50)?;
+                    w.write_varint(self.epoch)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                for (key, value) in &self.balances {
+                    len += key_len(1) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value));
+                }
+                for (key, value) in &self.labels {
+                    len += key_len(2) + bytes_len(key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len()));
+                }
+                for (key, value) in &self.blobs {
+                    len += key_len(3) + bytes_len(key_len(1) + int64_len(*key) + key_len(2) + bytes_len(value.len()));
+                }
+                for key in self.rates.keys() {
+                    len += key_len(4) + bytes_len(key_len(1) + zigzag32_len(*key) + key_len(2) + 8);
+                }
+                len += self.flags.len() * (key_len(5) + bytes_len(key_len(1) + 8 + key_len(2) + 1));
+                len += self.weights.len() * (key_len(6) + bytes_len(key_len(1) + 4 + key_len(2) + 4));
+                for value in self.counts.values() {
+                    len += key_len(7) + bytes_len(key_len(1) + 1 + key_len(2) + int32_len(*value));
+                }
+                for (key, value) in &self.slots {
+                    len += key_len(8) + bytes_len(key_len(1) + varint_len(*key) + key_len(2) + bytes_len(sizes.record(value)));
+                }
+                for (key, value) in &self.statuses {
+                    len += key_len(9) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value));
+                }
+                if self.epoch != 0 {
+                    len += key_len(10) + varint_len(self.epoch);
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                for (key, value) in &self.balances {
+                    w.write_key(0x0a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + varint_len(*value)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x10)?;
+                    w.write_varint(*value)?;
+                }
+                for (key, value) in &self.labels {
+                    w.write_key(0x12)?;
+                    w.write_varint((key_len(1) + varint_len(*key as u64) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key as u64)?;
+                    w.write_key(0x12)?;
+                    w.write_string(value)?;
+                }
+                for (key, value) in &self.blobs {
+                    w.write_key(0x1a)?;
+                    w.write_varint((key_len(1) + int64_len(*key) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_int64(*key)?;
+                    w.write_key(0x12)?;
+                    w.write_bytes(value)?;
+                }
+                for (key, value) in &self.rates {
+                    w.write_key(0x22)?;
+                    w.write_varint((key_len(1) + zigzag32_len(*key) + key_len(2) + 8) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_zigzag32(*key)?;
+                    w.write_key(0x11)?;
+                    w.write_double(*value)?;
+                }
+                for (key, value) in &self.flags {
+                    w.write_key(0x2a)?;
+                    w.write_varint((key_len(1) + 8 + key_len(2) + 1) as u64)?;
+                    w.write_key(0x09)?;
+                    w.write_fixed64(*key)?;
+                    w.write_key(0x10)?;
+                    w.write_bool(*value)?;
+                }
+                for (key, value) in &self.weights {
+                    w.write_key(0x32)?;
+                    w.write_varint((key_len(1) + 4 + key_len(2) + 4) as u64)?;
+                    w.write_key(0x0d)?;
+                    w.write_sfixed32(*key)?;
+                    w.write_key(0x15)?;
+                    w.write_float(*value)?;
+                }
+                for (key, value) in &self.counts {
+                    w.write_key(0x3a)?;
+                    w.write_varint((key_len(1) + 1 + key_len(2) + int32_len(*value)) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_bool(*key)?;
+                    w.write_key(0x10)?;
+                    w.write_int32(*value)?;
+                }
+                for (key, value) in &self.slots {
+                    w.write_key(0x42)?;
+                    w.write_varint((key_len(1) + varint_len(*key) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+                    w.write_key(0x08)?;
+                    w.write_varint(*key)?;
+                    w.write_message_sized(2, value, sizes)?;
+                }
+                for (key, value) in &self.statuses {
+                    w.write_key(0x4a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + int32_len(*value)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x10)?;
+                    w.write_int32(*value)?;
+                }
+                if self.epoch != 0 {
+                    w.write_key(0x50)?;
+                    w.write_varint(self.epoch)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = 0;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    16 => {
+                                        let (v, next) = decode_varint(entry, entry_pos)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.balances.len() >= opts.max_repeated_len && !self.balances.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.balances.insert(key, value);
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = String::new();
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    8 => {
+                                        let (v, next) = decode_varint(entry, entry_pos)?;
+                                        key = v as u32;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.labels.len() >= opts.max_repeated_len && !self.labels.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.labels.insert(key, value);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = Vec::new();
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    8 => {
+                                        let (v, next) = decode_int64(entry, entry_pos)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (v, next) = decode_bytes_with(entry, entry_pos, opts)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.blobs.len() >= opts.max_repeated_len && !self.blobs.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.blobs.insert(key, value);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = 0.0;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    8 => {
+                                        let (v, next) = decode_zigzag32(entry, entry_pos)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    17 => {
+                                        let (v, next) = decode_double(entry, entry_pos)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.rates.len() >= opts.max_repeated_len && !self.rates.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.rates.insert(key, value);
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = false;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    9 => {
+                                        let (v, next) = decode_fixed64(entry, entry_pos)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    16 => {
+                                        let (v, next) = decode_bool(entry, entry_pos)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.flags.len() >= opts.max_repeated_len && !self.flags.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.flags.insert(key, value);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = 0.0;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    13 => {
+                                        let (v, next) = decode_sfixed32(entry, entry_pos)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    21 => {
+                                        let (v, next) = decode_float(entry, entry_pos)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.weights.len() >= opts.max_repeated_len && !self.weights.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.weights.insert(key, value);
+                            pos = new_pos;
+                        }
+                        58 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = false;
+                            let mut value = 0;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    8 => {
+                                        let (v, next) = decode_bool(entry, entry_pos)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    16 => {
+                                        let (v, next) = decode_int32(entry, entry_pos)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.counts.len() >= opts.max_repeated_len && !self.counts.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.counts.insert(key, value);
+                            pos = new_pos;
+                        }
+                        66 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = 0;
+                            let mut value = Slot::DEFAULT;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    8 => {
+                                        let (v, next) = decode_varint(entry, entry_pos)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                        value.merge_with(sub, &opts.nested()?)?;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.slots.len() >= opts.max_repeated_len && !self.slots.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.slots.insert(key, value);
+                            pos = new_pos;
+                        }
+                        74 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = 0;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    16 => {
+                                        let (v, next) = decode_int32(entry, entry_pos)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.statuses.len() >= opts.max_repeated_len && !self.statuses.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.statuses.insert(key, value);
+                            pos = new_pos;
+                        }
+                        80 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.epoch = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Registry {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Registry::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Registry::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Registry::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Registry::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Registry::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Registry::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Registry::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Registry {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Registry::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Registry> for Vec<u8> {
+            fn from(msg: Registry) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Registry {
+            const FULL_NAME: &'static str = "example.Registry";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct RegistryBuilder {
+            inner: Registry,
+        }
+
+        impl Registry {
+            pub fn builder() -> RegistryBuilder {
+                RegistryBuilder::default()
+            }
+        }
+
+        impl RegistryBuilder {
+            pub fn with_balances(mut self, value: BTreeMap<String, u64>) -> Self {
+                self.inner.balances = value;
+                self
+            }
+
+            pub fn insert_balances(mut self, key: String, value: u64) -> Self {
+                self.inner.balances.insert(key, value);
+                self
+            }
+
+            pub fn with_labels(mut self, value: BTreeMap<u32, String>) -> Self {
+                self.inner.labels = value;
+                self
+            }
+
+            pub fn insert_labels(mut self, key: u32, value: String) -> Self {
+                self.inner.labels.insert(key, value);
+                self
+            }
+
+            pub fn with_blobs(mut self, value: BTreeMap<i64, Vec<u8>>) -> Self {
+                self.inner.blobs = value;
+                self
+            }
+
+            pub fn insert_blobs(mut self, key: i64, value: Vec<u8>) -> Self {
+                self.inner.blobs.insert(key, value);
+                self
+            }
+
+            pub fn with_rates(mut self, value: BTreeMap<i32, f64>) -> Self {
+                self.inner.rates = value;
+                self
+            }
+
+            pub fn insert_rates(mut self, key: i32, value: f64) -> Self {
+                self.inner.rates.insert(key, value);
+                self
+            }
+
+            pub fn with_flags(mut self, value: BTreeMap<u64, bool>) -> Self {
+                self.inner.flags = value;
+                self
+            }
+
+            pub fn insert_flags(mut self, key: u64, value: bool) -> Self {
+                self.inner.flags.insert(key, value);
+                self
+            }
+
+            pub fn with_weights(mut self, value: BTreeMap<i32, f32>) -> Self {
+                self.inner.weights = value;
+                self
+            }
+
+            pub fn insert_weights(mut self, key: i32, value: f32) -> Self {
+                self.inner.weights.insert(key, value);
+                self
+            }
+
+            pub fn with_counts(mut self, value: BTreeMap<bool, i32>) -> Self {
+                self.inner.counts = value;
+                self
+            }
+
+            pub fn insert_counts(mut self, key: bool, value: i32) -> Self {
+                self.inner.counts.insert(key, value);
+                self
+            }
+
+            pub fn with_slots(mut self, value: BTreeMap<u64, Slot>) -> Self {
+                self.inner.slots = value;
+                self
+            }
+
+            pub fn insert_slots(mut self, key: u64, value: Slot) -> Self {
+                self.inner.slots.insert(key, value);
+                self
+            }
+
+            pub fn with_statuses(mut self, value: BTreeMap<String, i32>) -> Self {
+                self.inner.statuses = value;
+                self
+            }
+
+            pub fn insert_statuses(mut self, key: String, value: i32) -> Self {
+                self.inner.statuses.insert(key, value);
+                self
+            }
+
+            pub fn with_epoch(mut self, value: u64) -> Self {
+                self.inner.epoch = value;
+                self
+            }
+
+            pub fn build(self) -> Registry {
+                self.inner
+            }
+        }
+
+        impl Registry {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                for (key, value) in &self.balances {
+                    out.begin_message("balances");
+                    out.write_str("key", key);
+                    out.write_scalar("value", *value);
+                    out.end_message();
+                }
+                for (key, value) in &self.labels {
+                    out.begin_message("labels");
+                    out.write_scalar("key", *key);
+                    out.write_str("value", value);
+                    out.end_message();
+                }
+                for (key, value) in &self.blobs {
+                    out.begin_message("blobs");
+                    out.write_scalar("key", *key);
+                    out.write_bytes("value", value);
+                    out.end_message();
+                }
+                for (key, value) in &self.rates {
+                    out.begin_message("rates");
+                    out.write_scalar("key", *key);
+                    out.write_f64("value", *value);
+                    out.end_message();
+                }
+                for (key, value) in &self.flags {
+                    out.begin_message("flags");
+                    out.write_scalar("key", *key);
+                    out.write_scalar("value", *value);
+                    out.end_message();
+                }
+                for (key, value) in &self.weights {
+                    out.begin_message("weights");
+                    out.write_scalar("key", *key);
+                    out.write_f32("value", *value);
+                    out.end_message();
+                }
+                for (key, value) in &self.counts {
+                    out.begin_message("counts");
+                    out.write_scalar("key", *key);
+                    out.write_scalar("value", *value);
+                    out.end_message();
+                }
+                for (key, value) in &self.slots {
+                    out.begin_message("slots");
+                    out.write_scalar("key", *key);
+                    out.begin_message("value");
+                    value.write_text(out);
+                    out.end_message();
+                    out.end_message();
+                }
+                for (key, value) in &self.statuses {
+                    out.begin_message("statuses");
+                    out.write_str("key", key);
+                    out.write_enum("value", *value, &[("STATUS_UNKNOWN", 0), ("STATUS_ACTIVE", 1)]);
+                    out.end_message();
+                }
+                if self.epoch != 0 {
+                    out.write_scalar("epoch", self.epoch);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Registry {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Registry {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.balances.is_empty() {
+                    out.key("balances");
+                    out.begin_object();
+                    for (key, value) in &self.balances {
+                        out.key(key);
+                        out.write_u64(*value);
+                    }
+                    out.end_object();
+                }
+                if !self.labels.is_empty() {
+                    out.key("labels");
+                    out.begin_object();
+                    for (key, value) in &self.labels {
+                        out.key(&key.to_string());
+                        out.write_str(value);
+                    }
+                    out.end_object();
+                }
+                if !self.blobs.is_empty() {
+                    out.key("blobs");
+                    out.begin_object();
+                    for (key, value) in &self.blobs {
+                        out.key(&key.to_string());
+                        out.write_bytes(value);
+                    }
+                    out.end_object();
+                }
+                if !self.rates.is_empty() {
+                    out.key("rates");
+                    out.begin_object();
+                    for (key, value) in &self.rates {
+                        out.key(&key.to_string());
+                        out.write_f64(*value);
+                    }
+                    out.end_object();
+                }
+                if !self.flags.is_empty() {
+                    out.key("flags");
+                    out.begin_object();
+                    for (key, value) in &self.flags {
+                        out.key(&key.to_string());
+                        out.write_bool(*value);
+                    }
+                    out.end_object();
+                }
+                if !self.weights.is_empty() {
+                    out.key("weights");
+                    out.begin_object();
+                    for (key, value) in &self.weights {
+                        out.key(&key.to_string());
+                        out.write_f32(*value);
+                    }
+                    out.end_object();
+                }
+                if !self.counts.is_empty() {
+                    out.key("counts");
+                    out.begin_object();
+                    for (key, value) in &self.counts {
+                        out.key(&key.to_string());
+                        out.write_i32(*value);
+                    }
+                    out.end_object();
+                }
+                if !self.slots.is_empty() {
+                    out.key("slots");
+                    out.begin_object();
+                    for (key, value) in &self.slots {
+                        out.key(&key.to_string());
+                        value.write_json(out);
+                    }
+                    out.end_object();
+                }
+                if !self.statuses.is_empty() {
+                    out.key("statuses");
+                    out.begin_object();
+                    for (key, value) in &self.statuses {
+                        out.key(key);
+                        out.write_enum(*value, &[("STATUS_UNKNOWN", 0), ("STATUS_ACTIVE", 1)]);
+                    }
+                    out.end_object();
+                }
+                if self.epoch != 0 {
+                    out.key("epoch");
+                    out.write_u64(self.epoch);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "balances" => {
+                            for (key, value) in json_object(value)? {
+                                msg.balances.insert(json_map_key(key)?, json_to_u64(value)?);
+                            }
+                        }
+                        "labels" => {
+                            for (key, value) in json_object(value)? {
+                                msg.labels.insert(json_map_key(key)?, json_to_string(value)?);
+                            }
+                        }
+                        "blobs" => {
+                            for (key, value) in json_object(value)? {
+                                msg.blobs.insert(json_map_key(key)?, json_to_bytes(value)?);
+                            }
+                        }
+                        "rates" => {
+                            for (key, value) in json_object(value)? {
+                                msg.rates.insert(json_map_key(key)?, json_to_f64(value)?);
+                            }
+                        }
+                        "flags" => {
+                            for (key, value) in json_object(value)? {
+                                msg.flags.insert(json_map_key(key)?, json_to_bool(value)?);
+                            }
+                        }
+                        "weights" => {
+                            for (key, value) in json_object(value)? {
+                                msg.weights.insert(json_map_key(key)?, json_to_f32(value)?);
+                            }
+                        }
+                        "counts" => {
+                            for (key, value) in json_object(value)? {
+                                msg.counts.insert(json_map_key(key)?, json_to_i32(value)?);
+                            }
+                        }
+                        "slots" => {
+                            for (key, value) in json_object(value)? {
+                                msg.slots.insert(json_map_key(key)?, Slot::from_json_value(value)?);
+                            }
+                        }
+                        "statuses" => {
+                            for (key, value) in json_object(value)? {
+                                msg.statuses.insert(json_map_key(key)?, json_to_enum(value, &[("STATUS_UNKNOWN", 0), ("STATUS_ACTIVE", 1)])?);
+                            }
+                        }
+                        "epoch" => msg.epoch = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct RegistryRef<'a> {
+            raw: &'a [u8],
+            pub epoch: u64,
+        }
+
+        impl<'a> RegistryRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = RegistryRef {
+                    raw: data,
+                    epoch: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    80 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.epoch = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Registry::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Registry, DecodeError> {
+                Registry::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Slot {
+            pub height: u64,
+            pub leader: String,
+        }
+
+        impl Slot {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Slot {
+                height: 0,
+                leader: String::new(),
+            };
+        }
+
+        impl Default for Slot {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Slot {
+            pub const HEIGHT_FIELD_NUMBER: u32 = 1;
+            pub const LEADER_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Slot.height" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Slot.leader" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: leader
+                if !self.leader.is_empty() {
+                    w.write_string(&self.leader);
+                    w.write_key(0x12);
+                }
+
+                // field 1: height
+                if self.height != 0 {
+                    w.write_varint(self.height);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.height != 0 {
+                    len += key_len(1) + varint_len(self.height);
+                }
+                if !self.leader.is_empty() {
+                    len += key_len(2) + bytes_len(self.leader.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.height != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.height)?;
+                }
+                if !self.leader.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.leader)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.height = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.leader = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Slot {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Slot::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Slot::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Slot::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Slot::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Slot::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Slot::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Slot::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Slot {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Slot::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Slot> for Vec<u8> {
+            fn from(msg: Slot) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Slot {
+            const FULL_NAME: &'static str = "example.Slot";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct SlotBuilder {
+            inner: Slot,
+        }
+
+        impl Slot {
+            pub fn builder() -> SlotBuilder {
+                SlotBuilder::default()
+            }
+        }
+
+        impl SlotBuilder {
+            pub fn with_height(mut self, value: u64) -> Self {
+                self.inner.height = value;
+                self
+            }
+
+            pub fn with_leader(mut self, value: String) -> Self {
+                self.inner.leader = value;
+                self
+            }
+
+            pub fn build(self) -> Slot {
+                self.inner
+            }
+        }
+
+        impl Slot {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.height != 0 {
+                    out.write_scalar("height", self.height);
+                }
+                if !self.leader.is_empty() {
+                    out.write_str("leader", &self.leader);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Slot {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Slot {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.height != 0 {
+                    out.key("height");
+                    out.write_u64(self.height);
+                }
+                if !self.leader.is_empty() {
+                    out.key("leader");
+                    out.write_str(&self.leader);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "height" => msg.height = json_to_u64(value)?,
+                        "leader" => msg.leader = json_to_string(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct SlotRef<'a> {
+            raw: &'a [u8],
+            pub height: u64,
+            pub leader: &'a str,
+        }
+
+        impl<'a> SlotRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = SlotRef {
+                    raw: data,
+                    height: 0,
+                    leader: "",
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.height = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.leader = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Slot::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Slot, DecodeError> {
+                Slot::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Registry(Registry),
+            Slot(Slot),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Registry::FULL_NAME => AnyMessage::Registry(Registry::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Slot::FULL_NAME => AnyMessage::Slot(Slot::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Registry(msg) => Any::pack(msg),
+                    AnyMessage::Slot(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
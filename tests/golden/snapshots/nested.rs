@@ -0,0 +1,1447 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod nested {
+        // Auto-generated by protoc-gen-solana from nested.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// `example.Phase`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum Phase {
+            Unspecified = 0,
+            Active = 1,
+        }
+
+        impl Phase {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: Phase = Phase::Unspecified;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Phase::Unspecified => "PHASE_UNSPECIFIED",
+                    Phase::Active => "PHASE_ACTIVE",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "PHASE_UNSPECIFIED" => Some(Phase::Unspecified),
+                    "PHASE_ACTIVE" => Some(Phase::Active),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for Phase {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<Phase> for i32 {
+            fn from(value: Phase) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for Phase {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(Phase::Unspecified),
+                    1 => Ok(Phase::Active),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// Exercises: a scalar, a packed repeated field and a sub-message together,
+        /// for merge, strict and conversion behaviour
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Outer {
+            pub scalar: u64,
+            pub items: Vec<u32>,
+            pub inner: Inner,
+        }
+
+        impl Outer {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Outer {
+                scalar: 0,
+                items: Vec::new(),
+                inner: Inner::DEFAULT,
+            };
+        }
+
+        impl Default for Outer {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Outer {
+            pub const SCALAR_FIELD_NUMBER: u32 = 1;
+            pub const ITEMS_FIELD_NUMBER: u32 = 2;
+            pub const INNER_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Outer.scalar" },
+                FieldWireType { number: 2, wire_type: 0, packed: true, name: "Outer.items" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Outer.inner" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: inner
+                w.write_message(3, &self.inner);
+
+                // field 2: items
+                if !self.items.is_empty() {
+                    w.write_nested(2, |w| {
+                        for elem in self.items.iter().rev() {
+                            w.write_varint(*elem as u64);
+                        }
+                    });
+                }
+
+                // field 1: scalar
+                if self.scalar != 0 {
+                    w.write_varint(self.scalar);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.scalar != 0 {
+                    len += key_len(1) + varint_len(self.scalar);
+                }
+                if !self.items.is_empty() {
+                    len += key_len(2) + bytes_len(self.items.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                len += key_len(3) + bytes_len(self.inner.encoded_len());
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.scalar != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.scalar)?;
+                }
+                if !self.items.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_varint(self.items.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.items {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                w.write_message(3, &self.inner)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.scalar != 0 {
+                    len += key_len(1) + varint_len(self.scalar);
+                }
+                if !self.items.is_empty() {
+                    len += key_len(2) + bytes_len(self.items.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                len += key_len(3) + bytes_len(sizes.record(&self.inner));
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.scalar != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.scalar)?;
+                }
+                if !self.items.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_varint(self.items.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.items {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                w.write_message_sized(3, &self.inner, sizes)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.scalar = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.items.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.items.push(v as u32);
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let new_pos = decode_packed_uint32_with(data, pos, &mut self.items, opts)?;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.inner.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    2 => Some(self.items.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Outer {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Outer::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Outer::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Outer::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Outer::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Outer::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Outer::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Outer::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Outer {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Outer::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Outer> for Vec<u8> {
+            fn from(msg: Outer) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Outer {
+            const FULL_NAME: &'static str = "example.Outer";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct OuterBuilder {
+            inner: Outer,
+        }
+
+        impl Outer {
+            pub fn builder() -> OuterBuilder {
+                OuterBuilder::default()
+            }
+        }
+
+        impl OuterBuilder {
+            pub fn with_scalar(mut self, value: u64) -> Self {
+                self.inner.scalar = value;
+                self
+            }
+
+            pub fn with_items(mut self, value: Vec<u32>) -> Self {
+                self.inner.items = value;
+                self
+            }
+
+            pub fn add_items(mut self, value: u32) -> Self {
+                self.inner.items.push(value);
+                self
+            }
+
+            pub fn with_inner(mut self, value: Inner) -> Self {
+                self.inner.inner = value;
+                self
+            }
+
+            pub fn build(self) -> Outer {
+                self.inner
+            }
+        }
+
+        impl Outer {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.scalar != 0 {
+                    out.write_scalar("scalar", self.scalar);
+                }
+                for elem in &self.items {
+                    out.write_scalar("items", *elem);
+                }
+                if self.inner != Inner::default() {
+                    out.begin_message("inner");
+                    self.inner.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Outer {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Outer {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.scalar != 0 {
+                    out.key("scalar");
+                    out.write_u64(self.scalar);
+                }
+                if !self.items.is_empty() {
+                    out.key("items");
+                    out.begin_array();
+                    for elem in &self.items {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if self.inner != Inner::default() {
+                    out.key("inner");
+                    self.inner.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "scalar" => msg.scalar = json_to_u64(value)?,
+                        "items" => {
+                            for elem in json_array(value)? {
+                                msg.items.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "inner" => msg.inner = Inner::from_json_value(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct OuterRef<'a> {
+            raw: &'a [u8],
+            pub scalar: u64,
+            pub items: RepeatedRef<'a, u32>,
+            pub inner: Option<InnerRef<'a>>,
+        }
+
+        impl<'a> OuterRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = OuterRef {
+                    raw: data,
+                    scalar: 0,
+                    items: RepeatedRef::packed(data, 16, |data, pos| {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        Ok((v as u32, new_pos))
+                    }),
+                    inner: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.scalar = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.inner = Some(InnerRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Outer::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Outer, DecodeError> {
+                Outer::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Inner {
+            pub a: u64,
+            pub b: String,
+        }
+
+        impl Inner {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Inner {
+                a: 0,
+                b: String::new(),
+            };
+        }
+
+        impl Default for Inner {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Inner {
+            pub const A_FIELD_NUMBER: u32 = 1;
+            pub const B_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Inner.a" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Inner.b" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: b
+                if !self.b.is_empty() {
+                    w.write_string(&self.b);
+                    w.write_key(0x12);
+                }
+
+                // field 1: a
+                if self.a != 0 {
+                    w.write_varint(self.a);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.a != 0 {
+                    len += key_len(1) + varint_len(self.a);
+                }
+                if !self.b.is_empty() {
+                    len += key_len(2) + bytes_len(self.b.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.a != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.a)?;
+                }
+                if !self.b.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.b)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.a = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.b = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Inner {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Inner::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Inner::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Inner::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Inner::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Inner::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Inner::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Inner::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Inner {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Inner::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Inner> for Vec<u8> {
+            fn from(msg: Inner) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Inner {
+            const FULL_NAME: &'static str = "example.Inner";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct InnerBuilder {
+            inner: Inner,
+        }
+
+        impl Inner {
+            pub fn builder() -> InnerBuilder {
+                InnerBuilder::default()
+            }
+        }
+
+        impl InnerBuilder {
+            pub fn with_a(mut self, value: u64) -> Self {
+                self.inner.a = value;
+                self
+            }
+
+            pub fn with_b(mut self, value: String) -> Self {
+                self.inner.b = value;
+                self
+            }
+
+            pub fn build(self) -> Inner {
+                self.inner
+            }
+        }
+
+        impl Inner {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.a != 0 {
+                    out.write_scalar("a", self.a);
+                }
+                if !self.b.is_empty() {
+                    out.write_str("b", &self.b);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Inner {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Inner {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.a != 0 {
+                    out.key("a");
+                    out.write_u64(self.a);
+                }
+                if !self.b.is_empty() {
+                    out.key("b");
+                    out.write_str(&self.b);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "a" => msg.a = json_to_u64(value)?,
+                        "b" => msg.b = json_to_string(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct InnerRef<'a> {
+            raw: &'a [u8],
+            pub a: u64,
+            pub b: &'a str,
+        }
+
+        impl<'a> InnerRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = InnerRef {
+                    raw: data,
+                    a: 0,
+                    b: "",
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.a = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.b = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Inner::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Inner, DecodeError> {
+                Inner::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Exercises: the Borsh layout of an enum, an `optional`, a repeated and a
+        /// sub-message field, in declaration order
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Tagged {
+            pub phase: i32,
+            pub limit: Option<u32>,
+            pub tags: Vec<String>,
+            pub inner: Inner,
+        }
+
+        impl Tagged {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Tagged {
+                phase: 0,
+                limit: None,
+                tags: Vec::new(),
+                inner: Inner::DEFAULT,
+            };
+        }
+
+        impl Default for Tagged {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Tagged {
+            pub const PHASE_FIELD_NUMBER: u32 = 1;
+            pub const LIMIT_FIELD_NUMBER: u32 = 2;
+            pub const TAGS_FIELD_NUMBER: u32 = 3;
+            pub const INNER_FIELD_NUMBER: u32 = 4;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Tagged.phase" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Tagged.limit" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Tagged.tags" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Tagged.inner" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 4: inner
+                w.write_message(4, &self.inner);
+
+                // field 3: tags
+                for elem in self.tags.iter().rev() {
+                    w.write_string(elem);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: limit
+                if let Some(elem) = &self.limit {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x10);
+                }
+
+                // field 1: phase
+                if self.phase != 0 {
+                    w.write_int32(self.phase);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.phase != 0 {
+                    len += key_len(1) + int32_len(self.phase);
+                }
+                if let Some(elem) = &self.limit {
+                    len += key_len(2) + varint_len(*elem as u64);
+                }
+                for elem in &self.tags {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                len += key_len(4) + bytes_len(self.inner.encoded_len());
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.phase != 0 {
+                    w.write_key(0x08)?;
+                    w.write_int32(self.phase)?;
+                }
+                if let Some(elem) = &self.limit {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                for elem in &self.tags {
+                    w.write_key(0x1a)?;
+                    w.write_string(elem)?;
+                }
+                w.write_message(4, &self.inner)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.phase != 0 {
+                    len += key_len(1) + int32_len(self.phase);
+                }
+                if let Some(elem) = &self.limit {
+                    len += key_len(2) + varint_len(*elem as u64);
+                }
+                for elem in &self.tags {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                len += key_len(4) + bytes_len(sizes.record(&self.inner));
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.phase != 0 {
+                    w.write_key(0x08)?;
+                    w.write_int32(self.phase)?;
+                }
+                if let Some(elem) = &self.limit {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                for elem in &self.tags {
+                    w.write_key(0x1a)?;
+                    w.write_string(elem)?;
+                }
+                w.write_message_sized(4, &self.inner, sizes)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.phase = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.limit = Some(v as u32);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            if self.tags.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.tags.push(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.inner.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    3 => Some(self.tags.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Tagged {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Tagged::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Tagged::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Tagged::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Tagged::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Tagged::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Tagged::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Tagged::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Tagged {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Tagged::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Tagged> for Vec<u8> {
+            fn from(msg: Tagged) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Tagged {
+            const FULL_NAME: &'static str = "example.Tagged";
+        }
+
+        impl Tagged {
+            pub fn phase(&self) -> Result<Phase, i32> {
+                Phase::try_from(self.phase)
+            }
+
+            pub fn set_phase(&mut self, value: Phase) {
+                self.phase = value.into();
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct TaggedBuilder {
+            inner: Tagged,
+        }
+
+        impl Tagged {
+            pub fn builder() -> TaggedBuilder {
+                TaggedBuilder::default()
+            }
+        }
+
+        impl TaggedBuilder {
+            pub fn with_phase(mut self, value: i32) -> Self {
+                self.inner.phase = value;
+                self
+            }
+
+            pub fn with_limit(mut self, value: u32) -> Self {
+                self.inner.limit = Some(value);
+                self
+            }
+
+            pub fn with_tags(mut self, value: Vec<String>) -> Self {
+                self.inner.tags = value;
+                self
+            }
+
+            pub fn add_tags(mut self, value: String) -> Self {
+                self.inner.tags.push(value);
+                self
+            }
+
+            pub fn with_inner(mut self, value: Inner) -> Self {
+                self.inner.inner = value;
+                self
+            }
+
+            pub fn build(self) -> Tagged {
+                self.inner
+            }
+        }
+
+        impl Tagged {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.phase != 0 {
+                    out.write_enum("phase", self.phase, &[("PHASE_UNSPECIFIED", 0), ("PHASE_ACTIVE", 1)]);
+                }
+                if let Some(elem) = &self.limit {
+                    out.write_scalar("limit", *elem);
+                }
+                for elem in &self.tags {
+                    out.write_str("tags", elem);
+                }
+                if self.inner != Inner::default() {
+                    out.begin_message("inner");
+                    self.inner.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Tagged {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Tagged {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.phase != 0 {
+                    out.key("phase");
+                    out.write_enum(self.phase, &[("PHASE_UNSPECIFIED", 0), ("PHASE_ACTIVE", 1)]);
+                }
+                if let Some(elem) = &self.limit {
+                    out.key("limit");
+                    out.write_u32(*elem);
+                }
+                if !self.tags.is_empty() {
+                    out.key("tags");
+                    out.begin_array();
+                    for elem in &self.tags {
+                        out.write_str(elem);
+                    }
+                    out.end_array();
+                }
+                if self.inner != Inner::default() {
+                    out.key("inner");
+                    self.inner.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "phase" => msg.phase = json_to_enum(value, &[("PHASE_UNSPECIFIED", 0), ("PHASE_ACTIVE", 1)])?,
+                        "limit" => msg.limit = Some(json_to_u32(value)?),
+                        "tags" => {
+                            for elem in json_array(value)? {
+                                msg.tags.push(json_to_string(elem)?);
+                            }
+                        }
+                        "inner" => msg.inner = Inner::from_json_value(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct TaggedRef<'a> {
+            raw: &'a [u8],
+            pub phase: i32,
+            pub limit: Option<u32>,
+            pub tags: RepeatedRef<'a, &'a str>,
+            pub inner: Option<InnerRef<'a>>,
+        }
+
+        impl<'a> TaggedRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = TaggedRef {
+                    raw: data,
+                    phase: 0,
+                    limit: None,
+                    tags: RepeatedRef::new(data, 26, decode_str_ref),
+                    inner: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.phase = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.limit = Some(v as u32);
+                        pos = new_pos;
+                    }
+                    26 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.inner = Some(InnerRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Tagged::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Tagged, DecodeError> {
+                Tagged::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Outer(Outer),
+            Inner(Inner),
+            Tagged(Tagged),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Outer::FULL_NAME => AnyMessage::Outer(Outer::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Inner::FULL_NAME => AnyMessage::Inner(Inner::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Tagged::FULL_NAME => AnyMessage::Tagged(Tagged::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Outer(msg) => Any::pack(msg),
+                    AnyMessage::Inner(msg) => Any::pack(msg),
+                    AnyMessage::Tagged(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1764 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod optional {
+        // Auto-generated by protoc-gen-solana from optional.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// `example.PatchMode`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum PatchMode {
+            Unspecified = 0,
+            Replace = 1,
+        }
+
+        impl PatchMode {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: PatchMode = PatchMode::Unspecified;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    PatchMode::Unspecified => "PATCH_MODE_UNSPECIFIED",
+                    PatchMode::Replace => "PATCH_MODE_REPLACE",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "PATCH_MODE_UNSPECIFIED" => Some(PatchMode::Unspecified),
+                    "PATCH_MODE_REPLACE" => Some(PatchMode::Replace),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for PatchMode {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<PatchMode> for i32 {
+            fn from(value: PatchMode) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for PatchMode {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(PatchMode::Unspecified),
+                    1 => Ok(PatchMode::Replace),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// Exercises: proto3 `optional` (explicit presence) scalars and messages
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct AccountPatch {
+            pub delta: Option<i32>,
+            pub lamports: Option<u64>,
+            pub label: Option<String>,
+            pub owner: Option<Vec<u8>>,
+            pub frozen: Option<bool>,
+            pub ratio: Option<f64>,
+            pub offset: Option<i64>,
+            pub meta: Option<PatchMeta>,
+            pub slot: u64,
+        }
+
+        impl AccountPatch {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = AccountPatch {
+                delta: None,
+                lamports: None,
+                label: None,
+                owner: None,
+                frozen: None,
+                ratio: None,
+                offset: None,
+                meta: None,
+                slot: 0,
+            };
+        }
+
+        impl Default for AccountPatch {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl AccountPatch {
+            pub const DELTA_FIELD_NUMBER: u32 = 1;
+            pub const LAMPORTS_FIELD_NUMBER: u32 = 2;
+            pub const LABEL_FIELD_NUMBER: u32 = 3;
+            pub const OWNER_FIELD_NUMBER: u32 = 4;
+            pub const FROZEN_FIELD_NUMBER: u32 = 5;
+            pub const RATIO_FIELD_NUMBER: u32 = 6;
+            pub const OFFSET_FIELD_NUMBER: u32 = 7;
+            pub const META_FIELD_NUMBER: u32 = 8;
+            pub const SLOT_FIELD_NUMBER: u32 = 9;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "AccountPatch.delta" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "AccountPatch.lamports" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "AccountPatch.label" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "AccountPatch.owner" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "AccountPatch.frozen" },
+                FieldWireType { number: 6, wire_type: 1, packed: false, name: "AccountPatch.ratio" },
+                FieldWireType { number: 7, wire_type: 0, packed: false, name: "AccountPatch.offset" },
+                FieldWireType { number: 8, wire_type: 2, packed: false, name: "AccountPatch.meta" },
+                FieldWireType { number: 9, wire_type: 0, packed: false, name: "AccountPatch.slot" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 9: slot
+                if self.slot != 0 {
+                    w.write_varint(self.slot);
+                    w.write_key(0x48);
+                }
+
+                // field 8: meta
+                if let Some(elem) = &self.meta {
+                    w.write_message(8, elem);
+                }
+
+                // field 7: offset
+                if let Some(elem) = &self.offset {
+                    w.write_zigzag64(*elem);
+                    w.write_key(0x38);
+                }
+
+                // field 6: ratio
+                if let Some(elem) = &self.ratio {
+                    w.write_double(*elem);
+                    w.write_key(0x31);
+                }
+
+                // field 5: frozen
+                if let Some(elem) = &self.frozen {
+                    w.write_bool(*elem);
+                    w.write_key(0x28);
+                }
+
+                // field 4: owner
+                if let Some(elem) = &self.owner {
+                    w.write_bytes(elem);
+                    w.write_key(0x22);
+                }
+
+                // field 3: label
+                if let Some(elem) = &self.label {
+                    w.write_string(elem);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: lamports
+                if let Some(elem) = &self.lamports {
+                    w.write_varint(*elem);
+                    w.write_key(0x10);
+                }
+
+                // field 1: delta
+                if let Some(elem) = &self.delta {
+                    w.write_int32(*elem);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.delta {
+                    len += key_len(1) + int32_len(*elem);
+                }
+                if let Some(elem) = &self.lamports {
+                    len += key_len(2) + varint_len(*elem);
+                }
+                if let Some(elem) = &self.label {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.owner {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                if self.frozen.is_some() {
+                    len += key_len(5) + 1;
+                }
+                if self.ratio.is_some() {
+                    len += key_len(6) + 8;
+                }
+                if let Some(elem) = &self.offset {
+                    len += key_len(7) + zigzag64_len(*elem);
+                }
+                if let Some(elem) = &self.meta {
+                    len += key_len(8) + bytes_len(elem.encoded_len());
+                }
+                if self.slot != 0 {
+                    len += key_len(9) + varint_len(self.slot);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.delta {
+                    w.write_key(0x08)?;
+                    w.write_int32(*elem)?;
+                }
+                if let Some(elem) = &self.lamports {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem)?;
+                }
+                if let Some(elem) = &self.label {
+                    w.write_key(0x1a)?;
+                    w.write_string(elem)?;
+                }
+                if let Some(elem) = &self.owner {
+                    w.write_key(0x22)?;
+                    w.write_bytes(elem)?;
+                }
+                if let Some(elem) = &self.frozen {
+                    w.write_key(0x28)?;
+                    w.write_bool(*elem)?;
+                }
+                if let Some(elem) = &self.ratio {
+                    w.write_key(0x31)?;
+                    w.write_double(*elem)?;
+                }
+                if let Some(elem) = &self.offset {
+                    w.write_key(0x38)?;
+                    w.write_zigzag64(*elem)?;
+                }
+                if let Some(elem) = &self.meta {
+                    w.write_message(8, elem)?;
+                }
+                if self.slot != 0 {
+                    w.write_key(0x48)?;
+                    w.write_varint(self.slot)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.delta {
+                    len += key_len(1) + int32_len(*elem);
+                }
+                if let Some(elem) = &self.lamports {
+                    len += key_len(2) + varint_len(*elem);
+                }
+                if let Some(elem) = &self.label {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.owner {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                if self.frozen.is_some() {
+                    len += key_len(5) + 1;
+                }
+                if self.ratio.is_some() {
+                    len += key_len(6) + 8;
+                }
+                if let Some(elem) = &self.offset {
+                    len += key_len(7) + zigzag64_len(*elem);
+                }
+                if let Some(elem) = &self.meta {
+                    len += key_len(8) + bytes_len(sizes.record(elem));
+                }
+                if self.slot != 0 {
+                    len += key_len(9) + varint_len(self.slot);
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.delta {
+                    w.write_key(0x08)?;
+                    w.write_int32(*elem)?;
+                }
+                if let Some(elem) = &self.lamports {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem)?;
+                }
+                if let Some(elem) = &self.label {
+                    w.write_key(0x1a)?;
+                    w.write_string(elem)?;
+                }
+                if let Some(elem) = &self.owner {
+                    w.write_key(0x22)?;
+                    w.write_bytes(elem)?;
+                }
+                if let Some(elem) = &self.frozen {
+                    w.write_key(0x28)?;
+                    w.write_bool(*elem)?;
+                }
+                if let Some(elem) = &self.ratio {
+                    w.write_key(0x31)?;
+                    w.write_double(*elem)?;
+                }
+                if let Some(elem) = &self.offset {
+                    w.write_key(0x38)?;
+                    w.write_zigzag64(*elem)?;
+                }
+                if let Some(elem) = &self.meta {
+                    w.write_message_sized(8, elem, sizes)?;
+                }
+                if self.slot != 0 {
+                    w.write_key(0x48)?;
+                    w.write_varint(self.slot)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.delta = Some(v);
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.lamports = Some(v);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.label = Some(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.owner = Some(v);
+                            pos = new_pos;
+                        }
+                        40 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_bool(data, pos)?;
+                            self.frozen = Some(v);
+                            pos = new_pos;
+                        }
+                        49 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_double(data, pos)?;
+                            self.ratio = Some(v);
+                            pos = new_pos;
+                        }
+                        56 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_zigzag64(data, pos)?;
+                            self.offset = Some(v);
+                            pos = new_pos;
+                        }
+                        66 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.meta.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        72 => {
+                            mark_singular(&mut seen, 8, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.slot = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for AccountPatch {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                AccountPatch::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                AccountPatch::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                AccountPatch::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                AccountPatch::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                AccountPatch::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                AccountPatch::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                AccountPatch::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for AccountPatch {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                AccountPatch::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<AccountPatch> for Vec<u8> {
+            fn from(msg: AccountPatch) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for AccountPatch {
+            const FULL_NAME: &'static str = "example.AccountPatch";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct AccountPatchBuilder {
+            inner: AccountPatch,
+        }
+
+        impl AccountPatch {
+            pub fn builder() -> AccountPatchBuilder {
+                AccountPatchBuilder::default()
+            }
+        }
+
+        impl AccountPatchBuilder {
+            pub fn with_delta(mut self, value: i32) -> Self {
+                self.inner.delta = Some(value);
+                self
+            }
+
+            pub fn with_lamports(mut self, value: u64) -> Self {
+                self.inner.lamports = Some(value);
+                self
+            }
+
+            pub fn with_label(mut self, value: String) -> Self {
+                self.inner.label = Some(value);
+                self
+            }
+
+            pub fn with_owner(mut self, value: Vec<u8>) -> Self {
+                self.inner.owner = Some(value);
+                self
+            }
+
+            pub fn with_frozen(mut self, value: bool) -> Self {
+                self.inner.frozen = Some(value);
+                self
+            }
+
+            pub fn with_ratio(mut self, value: f64) -> Self {
+                self.inner.ratio = Some(value);
+                self
+            }
+
+            pub fn with_offset(mut self, value: i64) -> Self {
+                self.inner.offset = Some(value);
+                self
+            }
+
+            pub fn with_meta(mut self, value: PatchMeta) -> Self {
+                self.inner.meta = Some(value);
+                self
+            }
+
+            pub fn with_slot(mut self, value: u64) -> Self {
+                self.inner.slot = value;
+                self
+            }
+
+            pub fn build(self) -> AccountPatch {
+                self.inner
+            }
+        }
+
+        impl AccountPatch {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if let Some(elem) = &self.delta {
+                    out.write_scalar("delta", *elem);
+                }
+                if let Some(elem) = &self.lamports {
+                    out.write_scalar("lamports", *elem);
+                }
+                if let Some(elem) = &self.label {
+                    out.write_str("label", elem);
+                }
+                if let Some(elem) = &self.owner {
+                    out.write_bytes("owner", elem);
+                }
+                if let Some(elem) = &self.frozen {
+                    out.write_scalar("frozen", *elem);
+                }
+                if let Some(elem) = &self.ratio {
+                    out.write_f64("ratio", *elem);
+                }
+                if let Some(elem) = &self.offset {
+                    out.write_scalar("offset", *elem);
+                }
+                if let Some(elem) = &self.meta {
+                    out.begin_message("meta");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if self.slot != 0 {
+                    out.write_scalar("slot", self.slot);
+                }
+            }
+        }
+
+        impl core::fmt::Display for AccountPatch {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl AccountPatch {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if let Some(elem) = &self.delta {
+                    out.key("delta");
+                    out.write_i32(*elem);
+                }
+                if let Some(elem) = &self.lamports {
+                    out.key("lamports");
+                    out.write_u64(*elem);
+                }
+                if let Some(elem) = &self.label {
+                    out.key("label");
+                    out.write_str(elem);
+                }
+                if let Some(elem) = &self.owner {
+                    out.key("owner");
+                    out.write_bytes(elem);
+                }
+                if let Some(elem) = &self.frozen {
+                    out.key("frozen");
+                    out.write_bool(*elem);
+                }
+                if let Some(elem) = &self.ratio {
+                    out.key("ratio");
+                    out.write_f64(*elem);
+                }
+                if let Some(elem) = &self.offset {
+                    out.key("offset");
+                    out.write_i64(*elem);
+                }
+                if let Some(elem) = &self.meta {
+                    out.key("meta");
+                    elem.write_json(out);
+                }
+                if self.slot != 0 {
+                    out.key("slot");
+                    out.write_u64(self.slot);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "delta" => msg.delta = Some(json_to_i32(value)?),
+                        "lamports" => msg.lamports = Some(json_to_u64(value)?),
+                        "label" => msg.label = Some(json_to_string(value)?),
+                        "owner" => msg.owner = Some(json_to_bytes(value)?),
+                        "frozen" => msg.frozen = Some(json_to_bool(value)?),
+                        "ratio" => msg.ratio = Some(json_to_f64(value)?),
+                        "offset" => msg.offset = Some(json_to_i64(value)?),
+                        "meta" => msg.meta = Some(PatchMeta::from_json_value(value)?),
+                        "slot" => msg.slot = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct AccountPatchRef<'a> {
+            raw: &'a [u8],
+            pub delta: Option<i32>,
+            pub lamports: Option<u64>,
+            pub label: Option<&'a str>,
+            pub owner: Option<&'a [u8]>,
+            pub frozen: Option<bool>,
+            pub ratio: Option<f64>,
+            pub offset: Option<i64>,
+            pub meta: Option<PatchMetaRef<'a>>,
+            pub slot: u64,
+        }
+
+        impl<'a> AccountPatchRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = AccountPatchRef {
+                    raw: data,
+                    delta: None,
+                    lamports: None,
+                    label: None,
+                    owner: None,
+                    frozen: None,
+                    ratio: None,
+                    offset: None,
+                    meta: None,
+                    slot: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.delta = Some(v);
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.lamports = Some(v);
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.label = Some(v);
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.owner = Some(v);
+                        pos = new_pos;
+                    }
+                    40 => {
+                        let (v, new_pos) = decode_bool(data, pos)?;
+                        view.frozen = Some(v);
+                        pos = new_pos;
+                    }
+                    49 => {
+                        let (v, new_pos) = decode_double(data, pos)?;
+                        view.ratio = Some(v);
+                        pos = new_pos;
+                    }
+                    56 => {
+                        let (v, new_pos) = decode_zigzag64(data, pos)?;
+                        view.offset = Some(v);
+                        pos = new_pos;
+                    }
+                    66 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.meta = Some(PatchMetaRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    72 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.slot = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(AccountPatch::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<AccountPatch, DecodeError> {
+                AccountPatch::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct PatchMeta {
+            pub author: String,
+        }
+
+        impl PatchMeta {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = PatchMeta {
+                author: String::new(),
+            };
+        }
+
+        impl Default for PatchMeta {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl PatchMeta {
+            pub const AUTHOR_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "PatchMeta.author" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: author
+                if !self.author.is_empty() {
+                    w.write_string(&self.author);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.author.is_empty() {
+                    len += key_len(1) + bytes_len(self.author.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.author.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.author)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.author = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for PatchMeta {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                PatchMeta::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                PatchMeta::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                PatchMeta::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                PatchMeta::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                PatchMeta::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                PatchMeta::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                PatchMeta::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for PatchMeta {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                PatchMeta::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<PatchMeta> for Vec<u8> {
+            fn from(msg: PatchMeta) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for PatchMeta {
+            const FULL_NAME: &'static str = "example.PatchMeta";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct PatchMetaBuilder {
+            inner: PatchMeta,
+        }
+
+        impl PatchMeta {
+            pub fn builder() -> PatchMetaBuilder {
+                PatchMetaBuilder::default()
+            }
+        }
+
+        impl PatchMetaBuilder {
+            pub fn with_author(mut self, value: String) -> Self {
+                self.inner.author = value;
+                self
+            }
+
+            pub fn build(self) -> PatchMeta {
+                self.inner
+            }
+        }
+
+        impl PatchMeta {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.author.is_empty() {
+                    out.write_str("author", &self.author);
+                }
+            }
+        }
+
+        impl core::fmt::Display for PatchMeta {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl PatchMeta {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.author.is_empty() {
+                    out.key("author");
+                    out.write_str(&self.author);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "author" => msg.author = json_to_string(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct PatchMetaRef<'a> {
+            raw: &'a [u8],
+            pub author: &'a str,
+        }
+
+        impl<'a> PatchMetaRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = PatchMetaRef {
+                    raw: data,
+                    author: "",
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.author = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(PatchMeta::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<PatchMeta, DecodeError> {
+                PatchMeta::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum PatchOptionsTarget {
+            Unset,
+            AccountIndex(u64),
+            Seed(String),
+        }
+
+        impl PatchOptionsTarget {
+            pub const DEFAULT: Self = PatchOptionsTarget::Unset;
+        }
+
+        impl Default for PatchOptionsTarget {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Synthetic oneofs of `optional` fields alongside a real oneof
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct PatchOptions {
+            pub mode: Option<i32>,
+            pub target: PatchOptionsTarget,
+            pub version: Option<u32>,
+            pub weight: Option<f32>,
+            pub epoch: Option<i64>,
+        }
+
+        impl PatchOptions {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = PatchOptions {
+                mode: None,
+                target: PatchOptionsTarget::Unset,
+                version: None,
+                weight: None,
+                epoch: None,
+            };
+        }
+
+        impl Default for PatchOptions {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl PatchOptions {
+            pub const MODE_FIELD_NUMBER: u32 = 1;
+            pub const ACCOUNT_INDEX_FIELD_NUMBER: u32 = 2;
+            pub const SEED_FIELD_NUMBER: u32 = 3;
+            pub const VERSION_FIELD_NUMBER: u32 = 4;
+            pub const WEIGHT_FIELD_NUMBER: u32 = 5;
+            pub const EPOCH_FIELD_NUMBER: u32 = 6;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "PatchOptions.mode" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "PatchOptions.account_index" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "PatchOptions.seed" },
+                FieldWireType { number: 4, wire_type: 5, packed: false, name: "PatchOptions.version" },
+                FieldWireType { number: 5, wire_type: 5, packed: false, name: "PatchOptions.weight" },
+                FieldWireType { number: 6, wire_type: 1, packed: false, name: "PatchOptions.epoch" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 6: epoch
+                if let Some(elem) = &self.epoch {
+                    w.write_sfixed64(*elem);
+                    w.write_key(0x31);
+                }
+
+                // field 5: weight
+                if let Some(elem) = &self.weight {
+                    w.write_float(*elem);
+                    w.write_key(0x2d);
+                }
+
+                // field 4: version
+                if let Some(elem) = &self.version {
+                    w.write_fixed32(*elem);
+                    w.write_key(0x25);
+                }
+
+                // field 3: seed
+                if let PatchOptionsTarget::Seed(elem) = &self.target {
+                    w.write_string(elem);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: account_index
+                if let PatchOptionsTarget::AccountIndex(elem) = &self.target {
+                    w.write_varint(*elem);
+                    w.write_key(0x10);
+                }
+
+                // field 1: mode
+                if let Some(elem) = &self.mode {
+                    w.write_int32(*elem);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.mode {
+                    len += key_len(1) + int32_len(*elem);
+                }
+                if let PatchOptionsTarget::AccountIndex(elem) = &self.target {
+                    len += key_len(2) + varint_len(*elem);
+                }
+                if let PatchOptionsTarget::Seed(elem) = &self.target {
+                    len += key_len(3) + bytes_len(elem.len());
+                }
+                if self.version.is_some() {
+                    len += key_len(4) + 4;
+                }
+                if self.weight.is_some() {
+                    len += key_len(5) + 4;
+                }
+                if self.epoch.is_some() {
+                    len += key_len(6) + 8;
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.mode {
+                    w.write_key(0x08)?;
+                    w.write_int32(*elem)?;
+                }
+                if let PatchOptionsTarget::AccountIndex(elem) = &self.target {
+                    w.write_key(0x10)?;
+                    w.write_varint(*elem)?;
+                }
+                if let PatchOptionsTarget::Seed(elem) = &self.target {
+                    w.write_key(0x1a)?;
+                    w.write_string(elem)?;
+                }
+                if let Some(elem) = &self.version {
+                    w.write_key(0x25)?;
+                    w.write_fixed32(*elem)?;
+                }
+                if let Some(elem) = &self.weight {
+                    w.write_key(0x2d)?;
+                    w.write_float(*elem)?;
+                }
+                if let Some(elem) = &self.epoch {
+                    w.write_key(0x31)?;
+                    w.write_sfixed64(*elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.mode = Some(v);
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.target = PatchOptionsTarget::AccountIndex(v);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.target = PatchOptionsTarget::Seed(v);
+                            pos = new_pos;
+                        }
+                        37 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_fixed32(data, pos)?;
+                            self.version = Some(v);
+                            pos = new_pos;
+                        }
+                        45 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_float(data, pos)?;
+                            self.weight = Some(v);
+                            pos = new_pos;
+                        }
+                        49 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_sfixed64(data, pos)?;
+                            self.epoch = Some(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for PatchOptions {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                PatchOptions::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                PatchOptions::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                PatchOptions::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                PatchOptions::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                PatchOptions::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                PatchOptions::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                PatchOptions::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for PatchOptions {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                PatchOptions::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<PatchOptions> for Vec<u8> {
+            fn from(msg: PatchOptions) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for PatchOptions {
+            const FULL_NAME: &'static str = "example.PatchOptions";
+        }
+
+        impl PatchOptions {
+            pub fn account_index(&self) -> Option<&u64> {
+                match &self.target {
+                    PatchOptionsTarget::AccountIndex(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_account_index(&mut self, value: u64) {
+                self.target = PatchOptionsTarget::AccountIndex(value);
+            }
+
+            pub fn seed(&self) -> Option<&String> {
+                match &self.target {
+                    PatchOptionsTarget::Seed(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_seed(&mut self, value: String) {
+                self.target = PatchOptionsTarget::Seed(value);
+            }
+        }
+
+        impl PatchOptions {
+            pub fn mode(&self) -> Option<Result<PatchMode, i32>> {
+                self.mode.map(PatchMode::try_from)
+            }
+
+            pub fn set_mode(&mut self, value: PatchMode) {
+                self.mode = Some(value.into());
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct PatchOptionsBuilder {
+            inner: PatchOptions,
+        }
+
+        impl PatchOptions {
+            pub fn builder() -> PatchOptionsBuilder {
+                PatchOptionsBuilder::default()
+            }
+        }
+
+        impl PatchOptionsBuilder {
+            pub fn with_mode(mut self, value: i32) -> Self {
+                self.inner.mode = Some(value);
+                self
+            }
+
+            pub fn with_account_index(mut self, value: u64) -> Self {
+                self.inner.target = PatchOptionsTarget::AccountIndex(value);
+                self
+            }
+
+            pub fn with_seed(mut self, value: String) -> Self {
+                self.inner.target = PatchOptionsTarget::Seed(value);
+                self
+            }
+
+            pub fn with_version(mut self, value: u32) -> Self {
+                self.inner.version = Some(value);
+                self
+            }
+
+            pub fn with_weight(mut self, value: f32) -> Self {
+                self.inner.weight = Some(value);
+                self
+            }
+
+            pub fn with_epoch(mut self, value: i64) -> Self {
+                self.inner.epoch = Some(value);
+                self
+            }
+
+            pub fn build(self) -> PatchOptions {
+                self.inner
+            }
+        }
+
+        impl PatchOptions {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if let Some(elem) = &self.mode {
+                    out.write_enum("mode", *elem, &[("PATCH_MODE_UNSPECIFIED", 0), ("PATCH_MODE_REPLACE", 1)]);
+                }
+                if let PatchOptionsTarget::AccountIndex(elem) = &self.target {
+                    out.write_scalar("account_index", *elem);
+                }
+                if let PatchOptionsTarget::Seed(elem) = &self.target {
+                    out.write_str("seed", elem);
+                }
+                if let Some(elem) = &self.version {
+                    out.write_scalar("version", *elem);
+                }
+                if let Some(elem) = &self.weight {
+                    out.write_f32("weight", *elem);
+                }
+                if let Some(elem) = &self.epoch {
+                    out.write_scalar("epoch", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for PatchOptions {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl PatchOptions {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if let Some(elem) = &self.mode {
+                    out.key("mode");
+                    out.write_enum(*elem, &[("PATCH_MODE_UNSPECIFIED", 0), ("PATCH_MODE_REPLACE", 1)]);
+                }
+                if let PatchOptionsTarget::AccountIndex(elem) = &self.target {
+                    out.key("accountIndex");
+                    out.write_u64(*elem);
+                }
+                if let PatchOptionsTarget::Seed(elem) = &self.target {
+                    out.key("seed");
+                    out.write_str(elem);
+                }
+                if let Some(elem) = &self.version {
+                    out.key("version");
+                    out.write_u32(*elem);
+                }
+                if let Some(elem) = &self.weight {
+                    out.key("weight");
+                    out.write_f32(*elem);
+                }
+                if let Some(elem) = &self.epoch {
+                    out.key("epoch");
+                    out.write_i64(*elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "mode" => msg.mode = Some(json_to_enum(value, &[("PATCH_MODE_UNSPECIFIED", 0), ("PATCH_MODE_REPLACE", 1)])?),
+                        "accountIndex" | "account_index" => msg.target = PatchOptionsTarget::AccountIndex(json_to_u64(value)?),
+                        "seed" => msg.target = PatchOptionsTarget::Seed(json_to_string(value)?),
+                        "version" => msg.version = Some(json_to_u32(value)?),
+                        "weight" => msg.weight = Some(json_to_f32(value)?),
+                        "epoch" => msg.epoch = Some(json_to_i64(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct PatchOptionsRef<'a> {
+            raw: &'a [u8],
+            pub mode: Option<i32>,
+            pub account_index: Option<u64>,
+            pub seed: Option<&'a str>,
+            pub version: Option<u32>,
+            pub weight: Option<f32>,
+            pub epoch: Option<i64>,
+        }
+
+        impl<'a> PatchOptionsRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = PatchOptionsRef {
+                    raw: data,
+                    mode: None,
+                    account_index: None,
+                    seed: None,
+                    version: None,
+                    weight: None,
+                    epoch: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.mode = Some(v);
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.account_index = Some(v);
+                        view.seed = None;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.seed = Some(v);
+                        view.account_index = None;
+                        pos = new_pos;
+                    }
+                    37 => {
+                        let (v, new_pos) = decode_fixed32(data, pos)?;
+                        view.version = Some(v);
+                        pos = new_pos;
+                    }
+                    45 => {
+                        let (v, new_pos) = decode_float(data, pos)?;
+                        view.weight = Some(v);
+                        pos = new_pos;
+                    }
+                    49 => {
+                        let (v, new_pos) = decode_sfixed64(data, pos)?;
+                        view.epoch = Some(v);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(PatchOptions::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<PatchOptions, DecodeError> {
+                PatchOptions::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            AccountPatch(AccountPatch),
+            PatchMeta(PatchMeta),
+            PatchOptions(PatchOptions),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    AccountPatch::FULL_NAME => AnyMessage::AccountPatch(AccountPatch::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    PatchMeta::FULL_NAME => AnyMessage::PatchMeta(PatchMeta::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    PatchOptions::FULL_NAME => AnyMessage::PatchOptions(PatchOptions::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::AccountPatch(msg) => Any::pack(msg),
+                    AnyMessage::PatchMeta(msg) => Any::pack(msg),
+                    AnyMessage::PatchOptions(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
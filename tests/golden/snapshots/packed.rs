@@ -0,0 +1,1391 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod packed {
+        // Auto-generated by protoc-gen-solana from packed.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// `example.Level`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum Level {
+            Low = 0,
+            High = 1,
+        }
+
+        impl Level {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: Level = Level::Low;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Level::Low => "LEVEL_LOW",
+                    Level::High => "LEVEL_HIGH",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "LEVEL_LOW" => Some(Level::Low),
+                    "LEVEL_HIGH" => Some(Level::High),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for Level {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<Level> for i32 {
+            fn from(value: Level) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for Level {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(Level::Low),
+                    1 => Ok(Level::High),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// Exercises: packed repeated scalars of every packable type, plus an
+        /// explicitly unpacked field
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Samples {
+            pub doubles: Vec<f64>,
+            pub floats: Vec<f32>,
+            pub int64s: Vec<i64>,
+            pub uint64s: Vec<u64>,
+            pub int32s: Vec<i32>,
+            pub fixed64s: Vec<u64>,
+            pub fixed32s: Vec<u32>,
+            pub bools: Vec<bool>,
+            pub uint32s: Vec<u32>,
+            pub levels: Vec<i32>,
+            pub sfixed32s: Vec<i32>,
+            pub sfixed64s: Vec<i64>,
+            pub sint32s: Vec<i32>,
+            pub sint64s: Vec<i64>,
+            pub legacy: Vec<u64>,
+        }
+
+        impl Samples {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Samples {
+                doubles: Vec::new(),
+                floats: Vec::new(),
+                int64s: Vec::new(),
+                uint64s: Vec::new(),
+                int32s: Vec::new(),
+                fixed64s: Vec::new(),
+                fixed32s: Vec::new(),
+                bools: Vec::new(),
+                uint32s: Vec::new(),
+                levels: Vec::new(),
+                sfixed32s: Vec::new(),
+                sfixed64s: Vec::new(),
+                sint32s: Vec::new(),
+                sint64s: Vec::new(),
+                legacy: Vec::new(),
+            };
+        }
+
+        impl Default for Samples {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Samples {
+            pub const DOUBLES_FIELD_NUMBER: u32 = 1;
+            pub const FLOATS_FIELD_NUMBER: u32 = 2;
+            pub const INT64S_FIELD_NUMBER: u32 = 3;
+            pub const UINT64S_FIELD_NUMBER: u32 = 4;
+            pub const INT32S_FIELD_NUMBER: u32 = 5;
+            pub const FIXED64S_FIELD_NUMBER: u32 = 6;
+            pub const FIXED32S_FIELD_NUMBER: u32 = 7;
+            pub const BOOLS_FIELD_NUMBER: u32 = 8;
+            pub const UINT32S_FIELD_NUMBER: u32 = 9;
+            pub const LEVELS_FIELD_NUMBER: u32 = 10;
+            pub const SFIXED32S_FIELD_NUMBER: u32 = 11;
+            pub const SFIXED64S_FIELD_NUMBER: u32 = 12;
+            pub const SINT32S_FIELD_NUMBER: u32 = 13;
+            pub const SINT64S_FIELD_NUMBER: u32 = 14;
+            pub const LEGACY_FIELD_NUMBER: u32 = 15;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 1, packed: true, name: "Samples.doubles" },
+                FieldWireType { number: 2, wire_type: 5, packed: true, name: "Samples.floats" },
+                FieldWireType { number: 3, wire_type: 0, packed: true, name: "Samples.int64s" },
+                FieldWireType { number: 4, wire_type: 0, packed: true, name: "Samples.uint64s" },
+                FieldWireType { number: 5, wire_type: 0, packed: true, name: "Samples.int32s" },
+                FieldWireType { number: 6, wire_type: 1, packed: true, name: "Samples.fixed64s" },
+                FieldWireType { number: 7, wire_type: 5, packed: true, name: "Samples.fixed32s" },
+                FieldWireType { number: 8, wire_type: 0, packed: true, name: "Samples.bools" },
+                FieldWireType { number: 9, wire_type: 0, packed: true, name: "Samples.uint32s" },
+                FieldWireType { number: 10, wire_type: 0, packed: true, name: "Samples.levels" },
+                FieldWireType { number: 11, wire_type: 5, packed: true, name: "Samples.sfixed32s" },
+                FieldWireType { number: 12, wire_type: 1, packed: true, name: "Samples.sfixed64s" },
+                FieldWireType { number: 13, wire_type: 0, packed: true, name: "Samples.sint32s" },
+                FieldWireType { number: 14, wire_type: 0, packed: true, name: "Samples.sint64s" },
+                FieldWireType { number: 15, wire_type: 0, packed: true, name: "Samples.legacy" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 15: legacy
+                for elem in self.legacy.iter().rev() {
+                    w.write_varint(*elem);
+                    w.write_key(0x78);
+                }
+
+                // field 14: sint64s
+                if !self.sint64s.is_empty() {
+                    w.write_nested(14, |w| {
+                        for elem in self.sint64s.iter().rev() {
+                            w.write_zigzag64(*elem);
+                        }
+                    });
+                }
+
+                // field 13: sint32s
+                if !self.sint32s.is_empty() {
+                    w.write_nested(13, |w| {
+                        for elem in self.sint32s.iter().rev() {
+                            w.write_zigzag32(*elem);
+                        }
+                    });
+                }
+
+                // field 12: sfixed64s
+                if !self.sfixed64s.is_empty() {
+                    w.write_nested(12, |w| {
+                        for elem in self.sfixed64s.iter().rev() {
+                            w.write_sfixed64(*elem);
+                        }
+                    });
+                }
+
+                // field 11: sfixed32s
+                if !self.sfixed32s.is_empty() {
+                    w.write_nested(11, |w| {
+                        for elem in self.sfixed32s.iter().rev() {
+                            w.write_sfixed32(*elem);
+                        }
+                    });
+                }
+
+                // field 10: levels
+                if !self.levels.is_empty() {
+                    w.write_nested(10, |w| {
+                        for elem in self.levels.iter().rev() {
+                            w.write_int32(*elem);
+                        }
+                    });
+                }
+
+                // field 9: uint32s
+                if !self.uint32s.is_empty() {
+                    w.write_nested(9, |w| {
+                        for elem in self.uint32s.iter().rev() {
+                            w.write_varint(*elem as u64);
+                        }
+                    });
+                }
+
+                // field 8: bools
+                if !self.bools.is_empty() {
+                    w.write_nested(8, |w| {
+                        for elem in self.bools.iter().rev() {
+                            w.write_bool(*elem);
+                        }
+                    });
+                }
+
+                // field 7: fixed32s
+                if !self.fixed32s.is_empty() {
+                    w.write_nested(7, |w| {
+                        for elem in self.fixed32s.iter().rev() {
+                            w.write_fixed32(*elem);
+                        }
+                    });
+                }
+
+                // field 6: fixed64s
+                if !self.fixed64s.is_empty() {
+                    w.write_nested(6, |w| {
+                        for elem in self.fixed64s.iter().rev() {
+                            w.write_fixed64(*elem);
+                        }
+                    });
+                }
+
+                // field 5: int32s
+                if !self.int32s.is_empty() {
+                    w.write_nested(5, |w| {
+                        for elem in self.int32s.iter().rev() {
+                            w.write_int32(*elem);
+                        }
+                    });
+                }
+
+                // field 4: uint64s
+                if !self.uint64s.is_empty() {
+                    w.write_nested(4, |w| {
+                        for elem in self.uint64s.iter().rev() {
+                            w.write_varint(*elem);
+                        }
+                    });
+                }
+
+                // field 3: int64s
+                if !self.int64s.is_empty() {
+                    w.write_nested(3, |w| {
+                        for elem in self.int64s.iter().rev() {
+                            w.write_int64(*elem);
+                        }
+                    });
+                }
+
+                // field 2: floats
+                if !self.floats.is_empty() {
+                    w.write_nested(2, |w| {
+                        for elem in self.floats.iter().rev() {
+                            w.write_float(*elem);
+                        }
+                    });
+                }
+
+                // field 1: doubles
+                if !self.doubles.is_empty() {
+                    w.write_nested(1, |w| {
+                        for elem in self.doubles.iter().rev() {
+                            w.write_double(*elem);
+                        }
+                    });
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.doubles.is_empty() {
+                    len += key_len(1) + bytes_len(self.doubles.len() * 8);
+                }
+                if !self.floats.is_empty() {
+                    len += key_len(2) + bytes_len(self.floats.len() * 4);
+                }
+                if !self.int64s.is_empty() {
+                    len += key_len(3) + bytes_len(self.int64s.iter().map(|elem| int64_len(*elem)).sum::<usize>());
+                }
+                if !self.uint64s.is_empty() {
+                    len += key_len(4) + bytes_len(self.uint64s.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                if !self.int32s.is_empty() {
+                    len += key_len(5) + bytes_len(self.int32s.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+                }
+                if !self.fixed64s.is_empty() {
+                    len += key_len(6) + bytes_len(self.fixed64s.len() * 8);
+                }
+                if !self.fixed32s.is_empty() {
+                    len += key_len(7) + bytes_len(self.fixed32s.len() * 4);
+                }
+                if !self.bools.is_empty() {
+                    len += key_len(8) + bytes_len(self.bools.len());
+                }
+                if !self.uint32s.is_empty() {
+                    len += key_len(9) + bytes_len(self.uint32s.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                if !self.levels.is_empty() {
+                    len += key_len(10) + bytes_len(self.levels.iter().map(|elem| int32_len(*elem)).sum::<usize>());
+                }
+                if !self.sfixed32s.is_empty() {
+                    len += key_len(11) + bytes_len(self.sfixed32s.len() * 4);
+                }
+                if !self.sfixed64s.is_empty() {
+                    len += key_len(12) + bytes_len(self.sfixed64s.len() * 8);
+                }
+                if !self.sint32s.is_empty() {
+                    len += key_len(13) + bytes_len(self.sint32s.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>());
+                }
+                if !self.sint64s.is_empty() {
+                    len += key_len(14) + bytes_len(self.sint64s.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>());
+                }
+                for elem in &self.legacy {
+                    len += key_len(15) + varint_len(*elem);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.doubles.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_varint((self.doubles.len() * 8) as u64)?;
+                    for elem in &self.doubles {
+                        w.write_double(*elem)?;
+                    }
+                }
+                if !self.floats.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_varint((self.floats.len() * 4) as u64)?;
+                    for elem in &self.floats {
+                        w.write_float(*elem)?;
+                    }
+                }
+                if !self.int64s.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_varint(self.int64s.iter().map(|elem| int64_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.int64s {
+                        w.write_int64(*elem)?;
+                    }
+                }
+                if !self.uint64s.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_varint(self.uint64s.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.uint64s {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                if !self.int32s.is_empty() {
+                    w.write_key(0x2a)?;
+                    w.write_varint(self.int32s.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.int32s {
+                        w.write_int32(*elem)?;
+                    }
+                }
+                if !self.fixed64s.is_empty() {
+                    w.write_key(0x32)?;
+                    w.write_varint((self.fixed64s.len() * 8) as u64)?;
+                    for elem in &self.fixed64s {
+                        w.write_fixed64(*elem)?;
+                    }
+                }
+                if !self.fixed32s.is_empty() {
+                    w.write_key(0x3a)?;
+                    w.write_varint((self.fixed32s.len() * 4) as u64)?;
+                    for elem in &self.fixed32s {
+                        w.write_fixed32(*elem)?;
+                    }
+                }
+                if !self.bools.is_empty() {
+                    w.write_key(0x42)?;
+                    w.write_varint(self.bools.len() as u64)?;
+                    for elem in &self.bools {
+                        w.write_bool(*elem)?;
+                    }
+                }
+                if !self.uint32s.is_empty() {
+                    w.write_key(0x4a)?;
+                    w.write_varint(self.uint32s.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.uint32s {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                if !self.levels.is_empty() {
+                    w.write_key(0x52)?;
+                    w.write_varint(self.levels.iter().map(|elem| int32_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.levels {
+                        w.write_int32(*elem)?;
+                    }
+                }
+                if !self.sfixed32s.is_empty() {
+                    w.write_key(0x5a)?;
+                    w.write_varint((self.sfixed32s.len() * 4) as u64)?;
+                    for elem in &self.sfixed32s {
+                        w.write_sfixed32(*elem)?;
+                    }
+                }
+                if !self.sfixed64s.is_empty() {
+                    w.write_key(0x62)?;
+                    w.write_varint((self.sfixed64s.len() * 8) as u64)?;
+                    for elem in &self.sfixed64s {
+                        w.write_sfixed64(*elem)?;
+                    }
+                }
+                if !self.sint32s.is_empty() {
+                    w.write_key(0x6a)?;
+                    w.write_varint(self.sint32s.iter().map(|elem| zigzag32_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.sint32s {
+                        w.write_zigzag32(*elem)?;
+                    }
+                }
+                if !self.sint64s.is_empty() {
+                    w.write_key(0x72)?;
+                    w.write_varint(self.sint64s.iter().map(|elem| zigzag64_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.sint64s {
+                        w.write_zigzag64(*elem)?;
+                    }
+                }
+                for elem in &self.legacy {
+                    w.write_key(0x78)?;
+                    w.write_varint(*elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        9 => {
+                            let (v, new_pos) = decode_double(data, pos)?;
+                            if self.doubles.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.doubles.push(v);
+                            pos = new_pos;
+                        }
+                        10 => {
+                            let new_pos = decode_packed_double_with(data, pos, &mut self.doubles, opts)?;
+                            pos = new_pos;
+                        }
+                        21 => {
+                            let (v, new_pos) = decode_float(data, pos)?;
+                            if self.floats.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.floats.push(v);
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let new_pos = decode_packed_float_with(data, pos, &mut self.floats, opts)?;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            let (v, new_pos) = decode_int64(data, pos)?;
+                            if self.int64s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.int64s.push(v);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let new_pos = decode_packed_int64_with(data, pos, &mut self.int64s, opts)?;
+                            pos = new_pos;
+                        }
+                        32 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.uint64s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.uint64s.push(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let new_pos = decode_packed_varint_with(data, pos, &mut self.uint64s, opts)?;
+                            pos = new_pos;
+                        }
+                        40 => {
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            if self.int32s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.int32s.push(v);
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let new_pos = decode_packed_int32_with(data, pos, &mut self.int32s, opts)?;
+                            pos = new_pos;
+                        }
+                        49 => {
+                            let (v, new_pos) = decode_fixed64(data, pos)?;
+                            if self.fixed64s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.fixed64s.push(v);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            let new_pos = decode_packed_fixed64_with(data, pos, &mut self.fixed64s, opts)?;
+                            pos = new_pos;
+                        }
+                        61 => {
+                            let (v, new_pos) = decode_fixed32(data, pos)?;
+                            if self.fixed32s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.fixed32s.push(v);
+                            pos = new_pos;
+                        }
+                        58 => {
+                            let new_pos = decode_packed_fixed32_with(data, pos, &mut self.fixed32s, opts)?;
+                            pos = new_pos;
+                        }
+                        64 => {
+                            let (v, new_pos) = decode_bool(data, pos)?;
+                            if self.bools.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.bools.push(v);
+                            pos = new_pos;
+                        }
+                        66 => {
+                            let new_pos = decode_packed_bool_with(data, pos, &mut self.bools, opts)?;
+                            pos = new_pos;
+                        }
+                        72 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.uint32s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.uint32s.push(v as u32);
+                            pos = new_pos;
+                        }
+                        74 => {
+                            let new_pos = decode_packed_uint32_with(data, pos, &mut self.uint32s, opts)?;
+                            pos = new_pos;
+                        }
+                        80 => {
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            if self.levels.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.levels.push(v);
+                            pos = new_pos;
+                        }
+                        82 => {
+                            let new_pos = decode_packed_int32_with(data, pos, &mut self.levels, opts)?;
+                            pos = new_pos;
+                        }
+                        93 => {
+                            let (v, new_pos) = decode_sfixed32(data, pos)?;
+                            if self.sfixed32s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.sfixed32s.push(v);
+                            pos = new_pos;
+                        }
+                        90 => {
+                            let new_pos = decode_packed_sfixed32_with(data, pos, &mut self.sfixed32s, opts)?;
+                            pos = new_pos;
+                        }
+                        97 => {
+                            let (v, new_pos) = decode_sfixed64(data, pos)?;
+                            if self.sfixed64s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.sfixed64s.push(v);
+                            pos = new_pos;
+                        }
+                        98 => {
+                            let new_pos = decode_packed_sfixed64_with(data, pos, &mut self.sfixed64s, opts)?;
+                            pos = new_pos;
+                        }
+                        104 => {
+                            let (v, new_pos) = decode_zigzag32(data, pos)?;
+                            if self.sint32s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.sint32s.push(v);
+                            pos = new_pos;
+                        }
+                        106 => {
+                            let new_pos = decode_packed_zigzag32_with(data, pos, &mut self.sint32s, opts)?;
+                            pos = new_pos;
+                        }
+                        112 => {
+                            let (v, new_pos) = decode_zigzag64(data, pos)?;
+                            if self.sint64s.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.sint64s.push(v);
+                            pos = new_pos;
+                        }
+                        114 => {
+                            let new_pos = decode_packed_zigzag64_with(data, pos, &mut self.sint64s, opts)?;
+                            pos = new_pos;
+                        }
+                        120 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.legacy.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.legacy.push(v);
+                            pos = new_pos;
+                        }
+                        122 => {
+                            let new_pos = decode_packed_varint_with(data, pos, &mut self.legacy, opts)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    1 => Some(self.doubles.len()),
+                    2 => Some(self.floats.len()),
+                    3 => Some(self.int64s.len()),
+                    4 => Some(self.uint64s.len()),
+                    5 => Some(self.int32s.len()),
+                    6 => Some(self.fixed64s.len()),
+                    7 => Some(self.fixed32s.len()),
+                    8 => Some(self.bools.len()),
+                    9 => Some(self.uint32s.len()),
+                    10 => Some(self.levels.len()),
+                    11 => Some(self.sfixed32s.len()),
+                    12 => Some(self.sfixed64s.len()),
+                    13 => Some(self.sint32s.len()),
+                    14 => Some(self.sint64s.len()),
+                    15 => Some(self.legacy.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Samples {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Samples::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Samples::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Samples::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Samples::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Samples::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Samples::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Samples::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Samples {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Samples::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Samples> for Vec<u8> {
+            fn from(msg: Samples) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Samples {
+            const FULL_NAME: &'static str = "example.Samples";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct SamplesBuilder {
+            inner: Samples,
+        }
+
+        impl Samples {
+            pub fn builder() -> SamplesBuilder {
+                SamplesBuilder::default()
+            }
+        }
+
+        impl SamplesBuilder {
+            pub fn with_doubles(mut self, value: Vec<f64>) -> Self {
+                self.inner.doubles = value;
+                self
+            }
+
+            pub fn add_doubles(mut self, value: f64) -> Self {
+                self.inner.doubles.push(value);
+                self
+            }
+
+            pub fn with_floats(mut self, value: Vec<f32>) -> Self {
+                self.inner.floats = value;
+                self
+            }
+
+            pub fn add_floats(mut self, value: f32) -> Self {
+                self.inner.floats.push(value);
+                self
+            }
+
+            pub fn with_int64s(mut self, value: Vec<i64>) -> Self {
+                self.inner.int64s = value;
+                self
+            }
+
+            pub fn add_int64s(mut self, value: i64) -> Self {
+                self.inner.int64s.push(value);
+                self
+            }
+
+            pub fn with_uint64s(mut self, value: Vec<u64>) -> Self {
+                self.inner.uint64s = value;
+                self
+            }
+
+            pub fn add_uint64s(mut self, value: u64) -> Self {
+                self.inner.uint64s.push(value);
+                self
+            }
+
+            pub fn with_int32s(mut self, value: Vec<i32>) -> Self {
+                self.inner.int32s = value;
+                self
+            }
+
+            pub fn add_int32s(mut self, value: i32) -> Self {
+                self.inner.int32s.push(value);
+                self
+            }
+
+            pub fn with_fixed64s(mut self, value: Vec<u64>) -> Self {
+                self.inner.fixed64s = value;
+                self
+            }
+
+            pub fn add_fixed64s(mut self, value: u64) -> Self {
+                self.inner.fixed64s.push(value);
+                self
+            }
+
+            pub fn with_fixed32s(mut self, value: Vec<u32>) -> Self {
+                self.inner.fixed32s = value;
+                self
+            }
+
+            pub fn add_fixed32s(mut self, value: u32) -> Self {
+                self.inner.fixed32s.push(value);
+                self
+            }
+
+            pub fn with_bools(mut self, value: Vec<bool>) -> Self {
+                self.inner.bools = value;
+                self
+            }
+
+            pub fn add_bools(mut self, value: bool) -> Self {
+                self.inner.bools.push(value);
+                self
+            }
+
+            pub fn with_uint32s(mut self, value: Vec<u32>) -> Self {
+                self.inner.uint32s = value;
+                self
+            }
+
+            pub fn add_uint32s(mut self, value: u32) -> Self {
+                self.inner.uint32s.push(value);
+                self
+            }
+
+            pub fn with_levels(mut self, value: Vec<i32>) -> Self {
+                self.inner.levels = value;
+                self
+            }
+
+            pub fn add_levels(mut self, value: i32) -> Self {
+                self.inner.levels.push(value);
+                self
+            }
+
+            pub fn with_sfixed32s(mut self, value: Vec<i32>) -> Self {
+                self.inner.sfixed32s = value;
+                self
+            }
+
+            pub fn add_sfixed32s(mut self, value: i32) -> Self {
+                self.inner.sfixed32s.push(value);
+                self
+            }
+
+            pub fn with_sfixed64s(mut self, value: Vec<i64>) -> Self {
+                self.inner.sfixed64s = value;
+                self
+            }
+
+            pub fn add_sfixed64s(mut self, value: i64) -> Self {
+                self.inner.sfixed64s.push(value);
+                self
+            }
+
+            pub fn with_sint32s(mut self, value: Vec<i32>) -> Self {
+                self.inner.sint32s = value;
+                self
+            }
+
+            pub fn add_sint32s(mut self, value: i32) -> Self {
+                self.inner.sint32s.push(value);
+                self
+            }
+
+            pub fn with_sint64s(mut self, value: Vec<i64>) -> Self {
+                self.inner.sint64s = value;
+                self
+            }
+
+            pub fn add_sint64s(mut self, value: i64) -> Self {
+                self.inner.sint64s.push(value);
+                self
+            }
+
+            pub fn with_legacy(mut self, value: Vec<u64>) -> Self {
+                self.inner.legacy = value;
+                self
+            }
+
+            pub fn add_legacy(mut self, value: u64) -> Self {
+                self.inner.legacy.push(value);
+                self
+            }
+
+            pub fn build(self) -> Samples {
+                self.inner
+            }
+        }
+
+        impl Samples {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                for elem in &self.doubles {
+                    out.write_f64("doubles", *elem);
+                }
+                for elem in &self.floats {
+                    out.write_f32("floats", *elem);
+                }
+                for elem in &self.int64s {
+                    out.write_scalar("int64s", *elem);
+                }
+                for elem in &self.uint64s {
+                    out.write_scalar("uint64s", *elem);
+                }
+                for elem in &self.int32s {
+                    out.write_scalar("int32s", *elem);
+                }
+                for elem in &self.fixed64s {
+                    out.write_scalar("fixed64s", *elem);
+                }
+                for elem in &self.fixed32s {
+                    out.write_scalar("fixed32s", *elem);
+                }
+                for elem in &self.bools {
+                    out.write_scalar("bools", *elem);
+                }
+                for elem in &self.uint32s {
+                    out.write_scalar("uint32s", *elem);
+                }
+                for elem in &self.levels {
+                    out.write_enum("levels", *elem, &[("LEVEL_LOW", 0), ("LEVEL_HIGH", 1)]);
+                }
+                for elem in &self.sfixed32s {
+                    out.write_scalar("sfixed32s", *elem);
+                }
+                for elem in &self.sfixed64s {
+                    out.write_scalar("sfixed64s", *elem);
+                }
+                for elem in &self.sint32s {
+                    out.write_scalar("sint32s", *elem);
+                }
+                for elem in &self.sint64s {
+                    out.write_scalar("sint64s", *elem);
+                }
+                for elem in &self.legacy {
+                    out.write_scalar("legacy", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Samples {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Samples {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.doubles.is_empty() {
+                    out.key("doubles");
+                    out.begin_array();
+                    for elem in &self.doubles {
+                        out.write_f64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.floats.is_empty() {
+                    out.key("floats");
+                    out.begin_array();
+                    for elem in &self.floats {
+                        out.write_f32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.int64s.is_empty() {
+                    out.key("int64s");
+                    out.begin_array();
+                    for elem in &self.int64s {
+                        out.write_i64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.uint64s.is_empty() {
+                    out.key("uint64s");
+                    out.begin_array();
+                    for elem in &self.uint64s {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.int32s.is_empty() {
+                    out.key("int32s");
+                    out.begin_array();
+                    for elem in &self.int32s {
+                        out.write_i32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.fixed64s.is_empty() {
+                    out.key("fixed64s");
+                    out.begin_array();
+                    for elem in &self.fixed64s {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.fixed32s.is_empty() {
+                    out.key("fixed32s");
+                    out.begin_array();
+                    for elem in &self.fixed32s {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.bools.is_empty() {
+                    out.key("bools");
+                    out.begin_array();
+                    for elem in &self.bools {
+                        out.write_bool(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.uint32s.is_empty() {
+                    out.key("uint32s");
+                    out.begin_array();
+                    for elem in &self.uint32s {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.levels.is_empty() {
+                    out.key("levels");
+                    out.begin_array();
+                    for elem in &self.levels {
+                        out.write_enum(*elem, &[("LEVEL_LOW", 0), ("LEVEL_HIGH", 1)]);
+                    }
+                    out.end_array();
+                }
+                if !self.sfixed32s.is_empty() {
+                    out.key("sfixed32s");
+                    out.begin_array();
+                    for elem in &self.sfixed32s {
+                        out.write_i32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.sfixed64s.is_empty() {
+                    out.key("sfixed64s");
+                    out.begin_array();
+                    for elem in &self.sfixed64s {
+                        out.write_i64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.sint32s.is_empty() {
+                    out.key("sint32s");
+                    out.begin_array();
+                    for elem in &self.sint32s {
+                        out.write_i32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.sint64s.is_empty() {
+                    out.key("sint64s");
+                    out.begin_array();
+                    for elem in &self.sint64s {
+                        out.write_i64(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.legacy.is_empty() {
+                    out.key("legacy");
+                    out.begin_array();
+                    for elem in &self.legacy {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "doubles" => {
+                            for elem in json_array(value)? {
+                                msg.doubles.push(json_to_f64(elem)?);
+                            }
+                        }
+                        "floats" => {
+                            for elem in json_array(value)? {
+                                msg.floats.push(json_to_f32(elem)?);
+                            }
+                        }
+                        "int64s" => {
+                            for elem in json_array(value)? {
+                                msg.int64s.push(json_to_i64(elem)?);
+                            }
+                        }
+                        "uint64s" => {
+                            for elem in json_array(value)? {
+                                msg.uint64s.push(json_to_u64(elem)?);
+                            }
+                        }
+                        "int32s" => {
+                            for elem in json_array(value)? {
+                                msg.int32s.push(json_to_i32(elem)?);
+                            }
+                        }
+                        "fixed64s" => {
+                            for elem in json_array(value)? {
+                                msg.fixed64s.push(json_to_u64(elem)?);
+                            }
+                        }
+                        "fixed32s" => {
+                            for elem in json_array(value)? {
+                                msg.fixed32s.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "bools" => {
+                            for elem in json_array(value)? {
+                                msg.bools.push(json_to_bool(elem)?);
+                            }
+                        }
+                        "uint32s" => {
+                            for elem in json_array(value)? {
+                                msg.uint32s.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "levels" => {
+                            for elem in json_array(value)? {
+                                msg.levels.push(json_to_enum(elem, &[("LEVEL_LOW", 0), ("LEVEL_HIGH", 1)])?);
+                            }
+                        }
+                        "sfixed32s" => {
+                            for elem in json_array(value)? {
+                                msg.sfixed32s.push(json_to_i32(elem)?);
+                            }
+                        }
+                        "sfixed64s" => {
+                            for elem in json_array(value)? {
+                                msg.sfixed64s.push(json_to_i64(elem)?);
+                            }
+                        }
+                        "sint32s" => {
+                            for elem in json_array(value)? {
+                                msg.sint32s.push(json_to_i32(elem)?);
+                            }
+                        }
+                        "sint64s" => {
+                            for elem in json_array(value)? {
+                                msg.sint64s.push(json_to_i64(elem)?);
+                            }
+                        }
+                        "legacy" => {
+                            for elem in json_array(value)? {
+                                msg.legacy.push(json_to_u64(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct SamplesRef<'a> {
+            raw: &'a [u8],
+            pub doubles: RepeatedRef<'a, f64>,
+            pub floats: RepeatedRef<'a, f32>,
+            pub int64s: RepeatedRef<'a, i64>,
+            pub uint64s: RepeatedRef<'a, u64>,
+            pub int32s: RepeatedRef<'a, i32>,
+            pub fixed64s: RepeatedRef<'a, u64>,
+            pub fixed32s: RepeatedRef<'a, u32>,
+            pub bools: RepeatedRef<'a, bool>,
+            pub uint32s: RepeatedRef<'a, u32>,
+            pub levels: RepeatedRef<'a, i32>,
+            pub sfixed32s: RepeatedRef<'a, i32>,
+            pub sfixed64s: RepeatedRef<'a, i64>,
+            pub sint32s: RepeatedRef<'a, i32>,
+            pub sint64s: RepeatedRef<'a, i64>,
+            pub legacy: RepeatedRef<'a, u64>,
+        }
+
+        impl<'a> SamplesRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let view = SamplesRef {
+                    raw: data,
+                    doubles: RepeatedRef::packed(data, 9, decode_double),
+                    floats: RepeatedRef::packed(data, 21, decode_float),
+                    int64s: RepeatedRef::packed(data, 24, decode_int64),
+                    uint64s: RepeatedRef::packed(data, 32, decode_varint),
+                    int32s: RepeatedRef::packed(data, 40, decode_int32),
+                    fixed64s: RepeatedRef::packed(data, 49, decode_fixed64),
+                    fixed32s: RepeatedRef::packed(data, 61, decode_fixed32),
+                    bools: RepeatedRef::packed(data, 64, decode_bool),
+                    uint32s: RepeatedRef::packed(data, 72, |data, pos| {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        Ok((v as u32, new_pos))
+                    }),
+                    levels: RepeatedRef::packed(data, 80, decode_int32),
+                    sfixed32s: RepeatedRef::packed(data, 93, decode_sfixed32),
+                    sfixed64s: RepeatedRef::packed(data, 97, decode_sfixed64),
+                    sint32s: RepeatedRef::packed(data, 104, decode_zigzag32),
+                    sint64s: RepeatedRef::packed(data, 112, decode_zigzag64),
+                    legacy: RepeatedRef::packed(data, 120, decode_varint),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    9 => {
+                        pos = skip_field(data, pos, 1)?;
+                    }
+                    21 => {
+                        pos = skip_field(data, pos, 5)?;
+                    }
+                    24 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    32 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    40 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    49 => {
+                        pos = skip_field(data, pos, 1)?;
+                    }
+                    61 => {
+                        pos = skip_field(data, pos, 5)?;
+                    }
+                    64 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    72 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    80 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    93 => {
+                        pos = skip_field(data, pos, 5)?;
+                    }
+                    97 => {
+                        pos = skip_field(data, pos, 1)?;
+                    }
+                    104 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    112 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    120 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Samples::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Samples, DecodeError> {
+                Samples::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Samples(Samples),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Samples::FULL_NAME => AnyMessage::Samples(Samples::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Samples(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,941 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod pda {
+        // Auto-generated by protoc-gen-solana from pda.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: (solana.seeds) PDA derivation from literals and pubkey,
+        /// integer, bool and string fields
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct VaultAddress {
+            pub owner: Pubkey,
+            pub mint: Pubkey,
+            pub index: u32,
+        }
+
+        impl VaultAddress {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = VaultAddress {
+                owner: ZERO_PUBKEY,
+                mint: ZERO_PUBKEY,
+                index: 0,
+            };
+        }
+
+        impl Default for VaultAddress {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl VaultAddress {
+            pub const OWNER_FIELD_NUMBER: u32 = 1;
+            pub const MINT_FIELD_NUMBER: u32 = 2;
+            pub const INDEX_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "VaultAddress.owner" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "VaultAddress.mint" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "VaultAddress.index" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: index
+                if self.index != 0 {
+                    w.write_varint(self.index as u64);
+                    w.write_key(0x18);
+                }
+
+                // field 2: mint
+                if self.mint != Pubkey::default() {
+                    w.write_pubkey(&self.mint);
+                    w.write_key(0x12);
+                }
+
+                // field 1: owner
+                if self.owner != Pubkey::default() {
+                    w.write_pubkey(&self.owner);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.owner != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.mint != Pubkey::default() {
+                    len += key_len(2) + 33;
+                }
+                if self.index != 0 {
+                    len += key_len(3) + varint_len(self.index as u64);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.owner != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.owner)?;
+                }
+                if self.mint != Pubkey::default() {
+                    w.write_key(0x12)?;
+                    w.write_pubkey(&self.mint)?;
+                }
+                if self.index != 0 {
+                    w.write_key(0x18)?;
+                    w.write_varint(self.index as u64)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.owner = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.mint = v;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.index = v as u32;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for VaultAddress {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                VaultAddress::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                VaultAddress::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                VaultAddress::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                VaultAddress::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                VaultAddress::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                VaultAddress::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                VaultAddress::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for VaultAddress {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                VaultAddress::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<VaultAddress> for Vec<u8> {
+            fn from(msg: VaultAddress) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for VaultAddress {
+            const FULL_NAME: &'static str = "example.VaultAddress";
+        }
+
+        impl VaultAddress {
+            /// Longest encoding of a `VaultAddress` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 74;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[cfg(feature = "solana-program")]
+        impl VaultAddress {
+            /// The program address for this message's seeds under `program_id`,
+            /// with its bump seed.
+            pub fn derive_address(&self, program_id: &Pubkey) -> (Pubkey, u8) {
+                let index_seed = self.index.to_le_bytes();
+                let seeds = [b"vault".as_slice(), self.owner.as_ref(), self.mint.as_ref(), index_seed.as_slice()];
+                Pubkey::find_program_address(&seeds, program_id)
+            }
+
+            /// Check that `address` is the program address for this message's
+            /// seeds and `bump` under `program_id`.
+            pub fn verify_pda(
+                &self,
+                program_id: &Pubkey,
+                address: &Pubkey,
+                bump: u8,
+            ) -> Result<(), solana_program::program_error::ProgramError> {
+                let index_seed = self.index.to_le_bytes();
+                let seeds = [b"vault".as_slice(), self.owner.as_ref(), self.mint.as_ref(), index_seed.as_slice(), core::slice::from_ref(&bump)];
+                match Pubkey::create_program_address(&seeds, program_id) {
+                    Ok(expected) if expected == *address => Ok(()),
+                    _ => Err(solana_program::program_error::ProgramError::InvalidSeeds),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct VaultAddressBuilder {
+            inner: VaultAddress,
+        }
+
+        impl VaultAddress {
+            pub fn builder() -> VaultAddressBuilder {
+                VaultAddressBuilder::default()
+            }
+        }
+
+        impl VaultAddressBuilder {
+            pub fn with_owner(mut self, value: Pubkey) -> Self {
+                self.inner.owner = value;
+                self
+            }
+
+            pub fn with_mint(mut self, value: Pubkey) -> Self {
+                self.inner.mint = value;
+                self
+            }
+
+            pub fn with_index(mut self, value: u32) -> Self {
+                self.inner.index = value;
+                self
+            }
+
+            pub fn build(self) -> VaultAddress {
+                self.inner
+            }
+        }
+
+        impl VaultAddress {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.owner != Pubkey::default() {
+                    out.write_pubkey("owner", &self.owner);
+                }
+                if self.mint != Pubkey::default() {
+                    out.write_pubkey("mint", &self.mint);
+                }
+                if self.index != 0 {
+                    out.write_scalar("index", self.index);
+                }
+            }
+        }
+
+        impl core::fmt::Display for VaultAddress {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl VaultAddress {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.owner != Pubkey::default() {
+                    out.key("owner");
+                    out.write_pubkey(self.owner);
+                }
+                if self.mint != Pubkey::default() {
+                    out.key("mint");
+                    out.write_pubkey(self.mint);
+                }
+                if self.index != 0 {
+                    out.key("index");
+                    out.write_u32(self.index);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "owner" => msg.owner = json_to_pubkey(value)?,
+                        "mint" => msg.mint = json_to_pubkey(value)?,
+                        "index" => msg.index = json_to_u32(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct VaultAddressRef<'a> {
+            raw: &'a [u8],
+            pub owner: Pubkey,
+            pub mint: Pubkey,
+            pub index: u32,
+        }
+
+        impl<'a> VaultAddressRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = VaultAddressRef {
+                    raw: data,
+                    owner: ZERO_PUBKEY,
+                    mint: ZERO_PUBKEY,
+                    index: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.owner = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.mint = v;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.index = v as u32;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(VaultAddress::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<VaultAddress, DecodeError> {
+                VaultAddress::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Escrow {
+            pub label: String,
+            pub open: bool,
+            pub amount: u64,
+        }
+
+        impl Escrow {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Escrow {
+                label: String::new(),
+                open: false,
+                amount: 0,
+            };
+        }
+
+        impl Default for Escrow {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Escrow {
+            pub const LABEL_FIELD_NUMBER: u32 = 1;
+            pub const OPEN_FIELD_NUMBER: u32 = 2;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Escrow.label" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Escrow.open" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "Escrow.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x18);
+                }
+
+                // field 2: open
+                if self.open {
+                    w.write_bool(self.open);
+                    w.write_key(0x10);
+                }
+
+                // field 1: label
+                if !self.label.is_empty() {
+                    w.write_string(&self.label);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.label.is_empty() {
+                    len += key_len(1) + bytes_len(self.label.len());
+                }
+                if self.open {
+                    len += key_len(2) + 1;
+                }
+                if self.amount != 0 {
+                    len += key_len(3) + varint_len(self.amount);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.label.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.label)?;
+                }
+                if self.open {
+                    w.write_key(0x10)?;
+                    w.write_bool(self.open)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x18)?;
+                    w.write_varint(self.amount)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.label = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_bool(data, pos)?;
+                            self.open = v;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Escrow {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Escrow::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Escrow::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Escrow::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Escrow::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Escrow::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Escrow::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Escrow::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Escrow {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Escrow::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Escrow> for Vec<u8> {
+            fn from(msg: Escrow) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Escrow {
+            const FULL_NAME: &'static str = "example.Escrow";
+        }
+
+        #[cfg(feature = "solana-program")]
+        impl Escrow {
+            /// The program address for this message's seeds under `program_id`,
+            /// with its bump seed.
+            pub fn derive_address(&self, program_id: &Pubkey) -> (Pubkey, u8) {
+                let open_seed = [self.open as u8];
+                let seeds = [b"escrow".as_slice(), self.label.as_bytes(), open_seed.as_slice()];
+                Pubkey::find_program_address(&seeds, program_id)
+            }
+
+            /// Check that `address` is the program address for this message's
+            /// seeds and `bump` under `program_id`.
+            pub fn verify_pda(
+                &self,
+                program_id: &Pubkey,
+                address: &Pubkey,
+                bump: u8,
+            ) -> Result<(), solana_program::program_error::ProgramError> {
+                let open_seed = [self.open as u8];
+                let seeds = [b"escrow".as_slice(), self.label.as_bytes(), open_seed.as_slice(), core::slice::from_ref(&bump)];
+                match Pubkey::create_program_address(&seeds, program_id) {
+                    Ok(expected) if expected == *address => Ok(()),
+                    _ => Err(solana_program::program_error::ProgramError::InvalidSeeds),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct EscrowBuilder {
+            inner: Escrow,
+        }
+
+        impl Escrow {
+            pub fn builder() -> EscrowBuilder {
+                EscrowBuilder::default()
+            }
+        }
+
+        impl EscrowBuilder {
+            pub fn with_label(mut self, value: String) -> Self {
+                self.inner.label = value;
+                self
+            }
+
+            pub fn with_open(mut self, value: bool) -> Self {
+                self.inner.open = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> Escrow {
+                self.inner
+            }
+        }
+
+        impl Escrow {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.label.is_empty() {
+                    out.write_str("label", &self.label);
+                }
+                if self.open {
+                    out.write_scalar("open", self.open);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Escrow {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Escrow {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.label.is_empty() {
+                    out.key("label");
+                    out.write_str(&self.label);
+                }
+                if self.open {
+                    out.key("open");
+                    out.write_bool(self.open);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "label" => msg.label = json_to_string(value)?,
+                        "open" => msg.open = json_to_bool(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct EscrowRef<'a> {
+            raw: &'a [u8],
+            pub label: &'a str,
+            pub open: bool,
+            pub amount: u64,
+        }
+
+        impl<'a> EscrowRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = EscrowRef {
+                    raw: data,
+                    label: "",
+                    open: false,
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.label = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_bool(data, pos)?;
+                        view.open = v;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Escrow::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Escrow, DecodeError> {
+                Escrow::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            VaultAddress(VaultAddress),
+            Escrow(Escrow),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    VaultAddress::FULL_NAME => AnyMessage::VaultAddress(VaultAddress::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Escrow::FULL_NAME => AnyMessage::Escrow(Escrow::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::VaultAddress(msg) => Any::pack(msg),
+                    AnyMessage::Escrow(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
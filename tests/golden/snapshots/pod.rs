@@ -0,0 +1,1236 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod pod {
+        // Auto-generated by protoc-gen-solana from pod.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// `example.ReserveStatus`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum ReserveStatus {
+            Unspecified = 0,
+            Open = 1,
+            Frozen = 2,
+        }
+
+        impl ReserveStatus {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: ReserveStatus = ReserveStatus::Unspecified;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    ReserveStatus::Unspecified => "RESERVE_STATUS_UNSPECIFIED",
+                    ReserveStatus::Open => "RESERVE_STATUS_OPEN",
+                    ReserveStatus::Frozen => "RESERVE_STATUS_FROZEN",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "RESERVE_STATUS_UNSPECIFIED" => Some(ReserveStatus::Unspecified),
+                    "RESERVE_STATUS_OPEN" => Some(ReserveStatus::Open),
+                    "RESERVE_STATUS_FROZEN" => Some(ReserveStatus::Frozen),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for ReserveStatus {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<ReserveStatus> for i32 {
+            fn from(value: ReserveStatus) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for ReserveStatus {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(ReserveStatus::Unspecified),
+                    1 => Ok(ReserveStatus::Open),
+                    2 => Ok(ReserveStatus::Frozen),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// Exercises: the `pod` option's ReserveStatePod layout (every field fixed-width)
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct ReserveState {
+            pub owner: Pubkey,
+            pub balance: u64,
+            pub delta: i32,
+            pub frozen: bool,
+            pub status: i32,
+            pub rate: f64,
+            pub seed: [u8; 8],
+            pub bump: u32,
+        }
+
+        impl ReserveState {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = ReserveState {
+                owner: ZERO_PUBKEY,
+                balance: 0,
+                delta: 0,
+                frozen: false,
+                status: 0,
+                rate: 0.0,
+                seed: [0; 8],
+                bump: 0,
+            };
+        }
+
+        impl Default for ReserveState {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl ReserveState {
+            pub const OWNER_FIELD_NUMBER: u32 = 1;
+            pub const BALANCE_FIELD_NUMBER: u32 = 2;
+            pub const DELTA_FIELD_NUMBER: u32 = 3;
+            pub const FROZEN_FIELD_NUMBER: u32 = 4;
+            pub const STATUS_FIELD_NUMBER: u32 = 5;
+            pub const RATE_FIELD_NUMBER: u32 = 6;
+            pub const SEED_FIELD_NUMBER: u32 = 7;
+            pub const BUMP_FIELD_NUMBER: u32 = 8;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "ReserveState.owner" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "ReserveState.balance" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "ReserveState.delta" },
+                FieldWireType { number: 4, wire_type: 0, packed: false, name: "ReserveState.frozen" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "ReserveState.status" },
+                FieldWireType { number: 6, wire_type: 1, packed: false, name: "ReserveState.rate" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "ReserveState.seed" },
+                FieldWireType { number: 8, wire_type: 5, packed: false, name: "ReserveState.bump" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 8: bump
+                if self.bump != 0 {
+                    w.write_fixed32(self.bump);
+                    w.write_key(0x45);
+                }
+
+                // field 7: seed
+                if self.seed != [0; 8] {
+                    w.write_bytes(&self.seed);
+                    w.write_key(0x3a);
+                }
+
+                // field 6: rate
+                if self.rate.to_bits() != 0 {
+                    w.write_double(self.rate);
+                    w.write_key(0x31);
+                }
+
+                // field 5: status
+                if self.status != 0 {
+                    w.write_int32(self.status);
+                    w.write_key(0x28);
+                }
+
+                // field 4: frozen
+                if self.frozen {
+                    w.write_bool(self.frozen);
+                    w.write_key(0x20);
+                }
+
+                // field 3: delta
+                if self.delta != 0 {
+                    w.write_zigzag32(self.delta);
+                    w.write_key(0x18);
+                }
+
+                // field 2: balance
+                if self.balance != 0 {
+                    w.write_varint(self.balance);
+                    w.write_key(0x10);
+                }
+
+                // field 1: owner
+                if self.owner != Pubkey::default() {
+                    w.write_pubkey(&self.owner);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.owner != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.balance != 0 {
+                    len += key_len(2) + varint_len(self.balance);
+                }
+                if self.delta != 0 {
+                    len += key_len(3) + zigzag32_len(self.delta);
+                }
+                if self.frozen {
+                    len += key_len(4) + 1;
+                }
+                if self.status != 0 {
+                    len += key_len(5) + int32_len(self.status);
+                }
+                if self.rate.to_bits() != 0 {
+                    len += key_len(6) + 8;
+                }
+                if self.seed != [0; 8] {
+                    len += key_len(7) + bytes_len(self.seed.len());
+                }
+                if self.bump != 0 {
+                    len += key_len(8) + 4;
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.owner != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.owner)?;
+                }
+                if self.balance != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.balance)?;
+                }
+                if self.delta != 0 {
+                    w.write_key(0x18)?;
+                    w.write_zigzag32(self.delta)?;
+                }
+                if self.frozen {
+                    w.write_key(0x20)?;
+                    w.write_bool(self.frozen)?;
+                }
+                if self.status != 0 {
+                    w.write_key(0x28)?;
+                    w.write_int32(self.status)?;
+                }
+                if self.rate.to_bits() != 0 {
+                    w.write_key(0x31)?;
+                    w.write_double(self.rate)?;
+                }
+                if self.seed != [0; 8] {
+                    w.write_key(0x3a)?;
+                    w.write_bytes(&self.seed)?;
+                }
+                if self.bump != 0 {
+                    w.write_key(0x45)?;
+                    w.write_fixed32(self.bump)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.owner = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.balance = v;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_zigzag32(data, pos)?;
+                            self.delta = v;
+                            pos = new_pos;
+                        }
+                        32 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_bool(data, pos)?;
+                            self.frozen = v;
+                            pos = new_pos;
+                        }
+                        40 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.status = v;
+                            pos = new_pos;
+                        }
+                        49 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_double(data, pos)?;
+                            self.rate = v;
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<8>(data, pos)?;
+                            self.seed = v;
+                            pos = new_pos;
+                        }
+                        69 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (v, new_pos) = decode_fixed32(data, pos)?;
+                            self.bump = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for ReserveState {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                ReserveState::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                ReserveState::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                ReserveState::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                ReserveState::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                ReserveState::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                ReserveState::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                ReserveState::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for ReserveState {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                ReserveState::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<ReserveState> for Vec<u8> {
+            fn from(msg: ReserveState) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for ReserveState {
+            const FULL_NAME: &'static str = "example.ReserveState";
+        }
+
+        impl ReserveState {
+            /// Longest encoding of a `ReserveState` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 88;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        /// `ReserveState` as a fixed, unpadded layout that account data can be
+        /// read as in place. Bools are `0` / `1` bytes and pubkeys byte arrays.
+        #[cfg(feature = "bytemuck")]
+        #[repr(C, packed)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+        pub struct ReserveStatePod {
+            pub owner: [u8; PUBKEY_LEN],
+            pub balance: u64,
+            pub delta: i32,
+            pub frozen: u8,
+            pub status: i32,
+            pub rate: f64,
+            pub seed: [u8; 8],
+            pub bump: u32,
+        }
+
+        #[cfg(feature = "bytemuck")]
+        impl ReserveStatePod {
+            /// Size of the layout in bytes.
+            pub const LEN: usize = core::mem::size_of::<Self>();
+
+            /// View the first `LEN` bytes of `data` as a `ReserveStatePod`, without copying.
+            pub fn from_bytes(data: &[u8]) -> Result<&Self, DecodeError> {
+                pod_from_bytes(data)
+            }
+
+            /// Like `from_bytes`, for updating the fields in place.
+            pub fn from_bytes_mut(data: &mut [u8]) -> Result<&mut Self, DecodeError> {
+                pod_from_bytes_mut(data)
+            }
+
+            pub fn as_bytes(&self) -> &[u8] {
+                bytemuck::bytes_of(self)
+            }
+
+            /// Decode the protobuf encoding of a `ReserveState` into this layout.
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                ReserveState::decode_with(data, &DecodeOptions::ACCOUNT).map(|msg| Self::from(&msg))
+            }
+
+            /// The protobuf encoding of the equivalent `ReserveState`.
+            pub fn encode(&self) -> Vec<u8> {
+                ReserveState::from(*self).encode()
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        impl From<&ReserveState> for ReserveStatePod {
+            fn from(msg: &ReserveState) -> Self {
+                ReserveStatePod {
+                    owner: pubkey_to_array(&msg.owner),
+                    balance: msg.balance,
+                    delta: msg.delta,
+                    frozen: msg.frozen as u8,
+                    status: msg.status,
+                    rate: msg.rate,
+                    seed: msg.seed,
+                    bump: msg.bump,
+                }
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        impl From<ReserveStatePod> for ReserveState {
+            fn from(pod: ReserveStatePod) -> Self {
+                ReserveState {
+                    owner: pubkey_from_array(pod.owner),
+                    balance: pod.balance,
+                    delta: pod.delta,
+                    frozen: pod.frozen != 0,
+                    status: pod.status,
+                    rate: pod.rate,
+                    seed: pod.seed,
+                    bump: pod.bump,
+                }
+            }
+        }
+
+        impl ReserveState {
+            pub fn status(&self) -> Result<ReserveStatus, i32> {
+                ReserveStatus::try_from(self.status)
+            }
+
+            pub fn set_status(&mut self, value: ReserveStatus) {
+                self.status = value.into();
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct ReserveStateBuilder {
+            inner: ReserveState,
+        }
+
+        impl ReserveState {
+            pub fn builder() -> ReserveStateBuilder {
+                ReserveStateBuilder::default()
+            }
+        }
+
+        impl ReserveStateBuilder {
+            pub fn with_owner(mut self, value: Pubkey) -> Self {
+                self.inner.owner = value;
+                self
+            }
+
+            pub fn with_balance(mut self, value: u64) -> Self {
+                self.inner.balance = value;
+                self
+            }
+
+            pub fn with_delta(mut self, value: i32) -> Self {
+                self.inner.delta = value;
+                self
+            }
+
+            pub fn with_frozen(mut self, value: bool) -> Self {
+                self.inner.frozen = value;
+                self
+            }
+
+            pub fn with_status(mut self, value: i32) -> Self {
+                self.inner.status = value;
+                self
+            }
+
+            pub fn with_rate(mut self, value: f64) -> Self {
+                self.inner.rate = value;
+                self
+            }
+
+            pub fn with_seed(mut self, value: [u8; 8]) -> Self {
+                self.inner.seed = value;
+                self
+            }
+
+            pub fn with_bump(mut self, value: u32) -> Self {
+                self.inner.bump = value;
+                self
+            }
+
+            pub fn build(self) -> ReserveState {
+                self.inner
+            }
+        }
+
+        impl ReserveState {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.owner != Pubkey::default() {
+                    out.write_pubkey("owner", &self.owner);
+                }
+                if self.balance != 0 {
+                    out.write_scalar("balance", self.balance);
+                }
+                if self.delta != 0 {
+                    out.write_scalar("delta", self.delta);
+                }
+                if self.frozen {
+                    out.write_scalar("frozen", self.frozen);
+                }
+                if self.status != 0 {
+                    out.write_enum("status", self.status, &[("RESERVE_STATUS_UNSPECIFIED", 0), ("RESERVE_STATUS_OPEN", 1), ("RESERVE_STATUS_FROZEN", 2)]);
+                }
+                if self.rate.to_bits() != 0 {
+                    out.write_f64("rate", self.rate);
+                }
+                if self.seed != [0; 8] {
+                    out.write_bytes("seed", &self.seed);
+                }
+                if self.bump != 0 {
+                    out.write_scalar("bump", self.bump);
+                }
+            }
+        }
+
+        impl core::fmt::Display for ReserveState {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl ReserveState {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.owner != Pubkey::default() {
+                    out.key("owner");
+                    out.write_pubkey(self.owner);
+                }
+                if self.balance != 0 {
+                    out.key("balance");
+                    out.write_u64(self.balance);
+                }
+                if self.delta != 0 {
+                    out.key("delta");
+                    out.write_i32(self.delta);
+                }
+                if self.frozen {
+                    out.key("frozen");
+                    out.write_bool(self.frozen);
+                }
+                if self.status != 0 {
+                    out.key("status");
+                    out.write_enum(self.status, &[("RESERVE_STATUS_UNSPECIFIED", 0), ("RESERVE_STATUS_OPEN", 1), ("RESERVE_STATUS_FROZEN", 2)]);
+                }
+                if self.rate.to_bits() != 0 {
+                    out.key("rate");
+                    out.write_f64(self.rate);
+                }
+                if self.seed != [0; 8] {
+                    out.key("seed");
+                    out.write_bytes(&self.seed);
+                }
+                if self.bump != 0 {
+                    out.key("bump");
+                    out.write_u32(self.bump);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "owner" => msg.owner = json_to_pubkey(value)?,
+                        "balance" => msg.balance = json_to_u64(value)?,
+                        "delta" => msg.delta = json_to_i32(value)?,
+                        "frozen" => msg.frozen = json_to_bool(value)?,
+                        "status" => msg.status = json_to_enum(value, &[("RESERVE_STATUS_UNSPECIFIED", 0), ("RESERVE_STATUS_OPEN", 1), ("RESERVE_STATUS_FROZEN", 2)])?,
+                        "rate" => msg.rate = json_to_f64(value)?,
+                        "seed" => msg.seed = json_to_fixed_bytes::<8>(value)?,
+                        "bump" => msg.bump = json_to_u32(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct ReserveStateRef<'a> {
+            raw: &'a [u8],
+            pub owner: Pubkey,
+            pub balance: u64,
+            pub delta: i32,
+            pub frozen: bool,
+            pub status: i32,
+            pub rate: f64,
+            pub seed: &'a [u8; 8],
+            pub bump: u32,
+        }
+
+        impl<'a> ReserveStateRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = ReserveStateRef {
+                    raw: data,
+                    owner: ZERO_PUBKEY,
+                    balance: 0,
+                    delta: 0,
+                    frozen: false,
+                    status: 0,
+                    rate: 0.0,
+                    seed: &[0; 8],
+                    bump: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.owner = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.balance = v;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        let (v, new_pos) = decode_zigzag32(data, pos)?;
+                        view.delta = v;
+                        pos = new_pos;
+                    }
+                    32 => {
+                        let (v, new_pos) = decode_bool(data, pos)?;
+                        view.frozen = v;
+                        pos = new_pos;
+                    }
+                    40 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.status = v;
+                        pos = new_pos;
+                    }
+                    49 => {
+                        let (v, new_pos) = decode_double(data, pos)?;
+                        view.rate = v;
+                        pos = new_pos;
+                    }
+                    58 => {
+                        let (v, new_pos) = decode_fixed_bytes_ref::<8>(data, pos)?;
+                        view.seed = v;
+                        pos = new_pos;
+                    }
+                    69 => {
+                        let (v, new_pos) = decode_fixed32(data, pos)?;
+                        view.bump = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(ReserveState::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<ReserveState, DecodeError> {
+                ReserveState::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// No Pod layout: a string and a repeated field are not fixed-width
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct ReserveLabel {
+            pub name: String,
+            pub history: Vec<u64>,
+        }
+
+        impl ReserveLabel {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = ReserveLabel {
+                name: String::new(),
+                history: Vec::new(),
+            };
+        }
+
+        impl Default for ReserveLabel {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl ReserveLabel {
+            pub const NAME_FIELD_NUMBER: u32 = 1;
+            pub const HISTORY_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "ReserveLabel.name" },
+                FieldWireType { number: 2, wire_type: 0, packed: true, name: "ReserveLabel.history" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: history
+                if !self.history.is_empty() {
+                    w.write_nested(2, |w| {
+                        for elem in self.history.iter().rev() {
+                            w.write_varint(*elem);
+                        }
+                    });
+                }
+
+                // field 1: name
+                if !self.name.is_empty() {
+                    w.write_string(&self.name);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.name.is_empty() {
+                    len += key_len(1) + bytes_len(self.name.len());
+                }
+                if !self.history.is_empty() {
+                    len += key_len(2) + bytes_len(self.history.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.name.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.name)?;
+                }
+                if !self.history.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_varint(self.history.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.history {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.name = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.history.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.history.push(v);
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let new_pos = decode_packed_varint_with(data, pos, &mut self.history, opts)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    2 => Some(self.history.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for ReserveLabel {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                ReserveLabel::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                ReserveLabel::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                ReserveLabel::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                ReserveLabel::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                ReserveLabel::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                ReserveLabel::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                ReserveLabel::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for ReserveLabel {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                ReserveLabel::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<ReserveLabel> for Vec<u8> {
+            fn from(msg: ReserveLabel) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for ReserveLabel {
+            const FULL_NAME: &'static str = "example.ReserveLabel";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct ReserveLabelBuilder {
+            inner: ReserveLabel,
+        }
+
+        impl ReserveLabel {
+            pub fn builder() -> ReserveLabelBuilder {
+                ReserveLabelBuilder::default()
+            }
+        }
+
+        impl ReserveLabelBuilder {
+            pub fn with_name(mut self, value: String) -> Self {
+                self.inner.name = value;
+                self
+            }
+
+            pub fn with_history(mut self, value: Vec<u64>) -> Self {
+                self.inner.history = value;
+                self
+            }
+
+            pub fn add_history(mut self, value: u64) -> Self {
+                self.inner.history.push(value);
+                self
+            }
+
+            pub fn build(self) -> ReserveLabel {
+                self.inner
+            }
+        }
+
+        impl ReserveLabel {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.name.is_empty() {
+                    out.write_str("name", &self.name);
+                }
+                for elem in &self.history {
+                    out.write_scalar("history", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for ReserveLabel {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl ReserveLabel {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.name.is_empty() {
+                    out.key("name");
+                    out.write_str(&self.name);
+                }
+                if !self.history.is_empty() {
+                    out.key("history");
+                    out.begin_array();
+                    for elem in &self.history {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "name" => msg.name = json_to_string(value)?,
+                        "history" => {
+                            for elem in json_array(value)? {
+                                msg.history.push(json_to_u64(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct ReserveLabelRef<'a> {
+            raw: &'a [u8],
+            pub name: &'a str,
+            pub history: RepeatedRef<'a, u64>,
+        }
+
+        impl<'a> ReserveLabelRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = ReserveLabelRef {
+                    raw: data,
+                    name: "",
+                    history: RepeatedRef::packed(data, 16, decode_varint),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.name = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(ReserveLabel::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<ReserveLabel, DecodeError> {
+                ReserveLabel::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            ReserveState(ReserveState),
+            ReserveLabel(ReserveLabel),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    ReserveState::FULL_NAME => AnyMessage::ReserveState(ReserveState::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    ReserveLabel::FULL_NAME => AnyMessage::ReserveLabel(ReserveLabel::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::ReserveState(msg) => Any::pack(msg),
+                    AnyMessage::ReserveLabel(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
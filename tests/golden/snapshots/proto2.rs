@@ -0,0 +1,2823 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod vendor {
+    pub mod proto2 {
+        // Auto-generated by protoc-gen-solana from proto2.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// `vendor.Venue`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum Venue {
+            Unknown = 0,
+            Spot = 1,
+            Perp = 2,
+        }
+
+        impl Venue {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: Venue = Venue::Unknown;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Venue::Unknown => "VENUE_UNKNOWN",
+                    Venue::Spot => "VENUE_SPOT",
+                    Venue::Perp => "VENUE_PERP",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "VENUE_UNKNOWN" => Some(Venue::Unknown),
+                    "VENUE_SPOT" => Some(Venue::Spot),
+                    "VENUE_PERP" => Some(Venue::Perp),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for Venue {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<Venue> for i32 {
+            fn from(value: Venue) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for Venue {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(Venue::Unknown),
+                    1 => Ok(Venue::Spot),
+                    2 => Ok(Venue::Perp),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum QuoteAction {
+            Unset,
+            CancelId(u64),
+            Amend(Amend),
+        }
+
+        impl QuoteAction {
+            pub const DEFAULT: Self = QuoteAction::Unset;
+        }
+
+        impl Default for QuoteAction {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: proto2 presence (required fields always encoded and failing
+        /// decode when absent, optional scalars as Option), \[default = ...\]
+        /// accessors, unpacked repeated scalars and groups
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Quote {
+            pub price: u64,
+            pub market: String,
+            pub size: Option<u32>,
+            pub memo: Option<String>,
+            pub venue: Option<i32>,
+            pub post_only: Option<bool>,
+            pub fee: Option<f64>,
+            pub limit: Option<f32>,
+            pub tag: Option<Vec<u8>>,
+            pub offset: Option<i64>,
+            pub levels: Vec<u32>,
+            pub packed_levels: Vec<u32>,
+            pub fill: Fill,
+            pub leg: Vec<Leg>,
+            pub note: QuoteNote,
+            pub action: QuoteAction,
+        }
+
+        impl Quote {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Quote {
+                price: 0,
+                market: String::new(),
+                size: None,
+                memo: None,
+                venue: None,
+                post_only: None,
+                fee: None,
+                limit: None,
+                tag: None,
+                offset: None,
+                levels: Vec::new(),
+                packed_levels: Vec::new(),
+                fill: Fill::DEFAULT,
+                leg: Vec::new(),
+                note: QuoteNote::DEFAULT,
+                action: QuoteAction::Unset,
+            };
+        }
+
+        impl Default for Quote {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Quote {
+            pub const PRICE_FIELD_NUMBER: u32 = 1;
+            pub const MARKET_FIELD_NUMBER: u32 = 2;
+            pub const SIZE_FIELD_NUMBER: u32 = 3;
+            pub const MEMO_FIELD_NUMBER: u32 = 4;
+            pub const VENUE_FIELD_NUMBER: u32 = 5;
+            pub const POST_ONLY_FIELD_NUMBER: u32 = 6;
+            pub const FEE_FIELD_NUMBER: u32 = 7;
+            pub const LIMIT_FIELD_NUMBER: u32 = 8;
+            pub const TAG_FIELD_NUMBER: u32 = 9;
+            pub const OFFSET_FIELD_NUMBER: u32 = 10;
+            pub const LEVELS_FIELD_NUMBER: u32 = 11;
+            pub const PACKED_LEVELS_FIELD_NUMBER: u32 = 12;
+            pub const FILL_FIELD_NUMBER: u32 = 13;
+            pub const LEG_FIELD_NUMBER: u32 = 16;
+            pub const NOTE_FIELD_NUMBER: u32 = 19;
+            pub const CANCEL_ID_FIELD_NUMBER: u32 = 20;
+            pub const AMEND_FIELD_NUMBER: u32 = 21;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Quote.price" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Quote.market" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "Quote.size" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Quote.memo" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "Quote.venue" },
+                FieldWireType { number: 6, wire_type: 0, packed: false, name: "Quote.post_only" },
+                FieldWireType { number: 7, wire_type: 1, packed: false, name: "Quote.fee" },
+                FieldWireType { number: 8, wire_type: 5, packed: false, name: "Quote.limit" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Quote.tag" },
+                FieldWireType { number: 10, wire_type: 0, packed: false, name: "Quote.offset" },
+                FieldWireType { number: 11, wire_type: 0, packed: true, name: "Quote.levels" },
+                FieldWireType { number: 12, wire_type: 0, packed: true, name: "Quote.packed_levels" },
+                FieldWireType { number: 13, wire_type: 3, packed: false, name: "Quote.fill" },
+                FieldWireType { number: 16, wire_type: 3, packed: false, name: "Quote.leg" },
+                FieldWireType { number: 19, wire_type: 2, packed: false, name: "Quote.note" },
+                FieldWireType { number: 20, wire_type: 0, packed: false, name: "Quote.cancel_id" },
+                FieldWireType { number: 21, wire_type: 3, packed: false, name: "Quote.amend" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 21: amend
+                if let QuoteAction::Amend(elem) = &self.action {
+                    w.write_group(21, elem);
+                }
+
+                // field 20: cancel_id
+                if let QuoteAction::CancelId(elem) = &self.action {
+                    w.write_varint(*elem);
+                    w.write_key(0xa0);
+                }
+
+                // field 19: note
+                w.write_message(19, &self.note);
+
+                // field 16: leg
+                for elem in self.leg.iter().rev() {
+                    w.write_group(16, elem);
+                }
+
+                // field 13: fill
+                w.write_group(13, &self.fill);
+
+                // field 12: packed_levels
+                if !self.packed_levels.is_empty() {
+                    w.write_nested(12, |w| {
+                        for elem in self.packed_levels.iter().rev() {
+                            w.write_varint(*elem as u64);
+                        }
+                    });
+                }
+
+                // field 11: levels
+                for elem in self.levels.iter().rev() {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x58);
+                }
+
+                // field 10: offset
+                if let Some(elem) = &self.offset {
+                    w.write_zigzag64(*elem);
+                    w.write_key(0x50);
+                }
+
+                // field 9: tag
+                if let Some(elem) = &self.tag {
+                    w.write_bytes(elem);
+                    w.write_key(0x4a);
+                }
+
+                // field 8: limit
+                if let Some(elem) = &self.limit {
+                    w.write_float(*elem);
+                    w.write_key(0x45);
+                }
+
+                // field 7: fee
+                if let Some(elem) = &self.fee {
+                    w.write_double(*elem);
+                    w.write_key(0x39);
+                }
+
+                // field 6: post_only
+                if let Some(elem) = &self.post_only {
+                    w.write_bool(*elem);
+                    w.write_key(0x30);
+                }
+
+                // field 5: venue
+                if let Some(elem) = &self.venue {
+                    w.write_int32(*elem);
+                    w.write_key(0x28);
+                }
+
+                // field 4: memo
+                if let Some(elem) = &self.memo {
+                    w.write_string(elem);
+                    w.write_key(0x22);
+                }
+
+                // field 3: size
+                if let Some(elem) = &self.size {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x18);
+                }
+
+                // field 2: market
+                w.write_string(&self.market);
+                w.write_key(0x12);
+
+                // field 1: price
+                w.write_varint(self.price);
+                w.write_key(0x08);
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                len += key_len(1) + varint_len(self.price);
+                len += key_len(2) + bytes_len(self.market.len());
+                if let Some(elem) = &self.size {
+                    len += key_len(3) + varint_len(*elem as u64);
+                }
+                if let Some(elem) = &self.memo {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.venue {
+                    len += key_len(5) + int32_len(*elem);
+                }
+                if self.post_only.is_some() {
+                    len += key_len(6) + 1;
+                }
+                if self.fee.is_some() {
+                    len += key_len(7) + 8;
+                }
+                if self.limit.is_some() {
+                    len += key_len(8) + 4;
+                }
+                if let Some(elem) = &self.tag {
+                    len += key_len(9) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.offset {
+                    len += key_len(10) + zigzag64_len(*elem);
+                }
+                for elem in &self.levels {
+                    len += key_len(11) + varint_len(*elem as u64);
+                }
+                if !self.packed_levels.is_empty() {
+                    len += key_len(12) + bytes_len(self.packed_levels.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                len += key_len(13) + self.fill.encoded_len() + key_len(13);
+                for elem in &self.leg {
+                    len += key_len(16) + elem.encoded_len() + key_len(16);
+                }
+                len += key_len(19) + bytes_len(self.note.encoded_len());
+                if let QuoteAction::CancelId(elem) = &self.action {
+                    len += key_len(20) + varint_len(*elem);
+                }
+                if let QuoteAction::Amend(elem) = &self.action {
+                    len += key_len(21) + elem.encoded_len() + key_len(21);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                w.write_key(0x08)?;
+                w.write_varint(self.price)?;
+                w.write_key(0x12)?;
+                w.write_string(&self.market)?;
+                if let Some(elem) = &self.size {
+                    w.write_key(0x18)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if let Some(elem) = &self.memo {
+                    w.write_key(0x22)?;
+                    w.write_string(elem)?;
+                }
+                if let Some(elem) = &self.venue {
+                    w.write_key(0x28)?;
+                    w.write_int32(*elem)?;
+                }
+                if let Some(elem) = &self.post_only {
+                    w.write_key(0x30)?;
+                    w.write_bool(*elem)?;
+                }
+                if let Some(elem) = &self.fee {
+                    w.write_key(0x39)?;
+                    w.write_double(*elem)?;
+                }
+                if let Some(elem) = &self.limit {
+                    w.write_key(0x45)?;
+                    w.write_float(*elem)?;
+                }
+                if let Some(elem) = &self.tag {
+                    w.write_key(0x4a)?;
+                    w.write_bytes(elem)?;
+                }
+                if let Some(elem) = &self.offset {
+                    w.write_key(0x50)?;
+                    w.write_zigzag64(*elem)?;
+                }
+                for elem in &self.levels {
+                    w.write_key(0x58)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if !self.packed_levels.is_empty() {
+                    w.write_key(0x62)?;
+                    w.write_varint(self.packed_levels.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.packed_levels {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                w.write_group(13, &self.fill)?;
+                for elem in &self.leg {
+                    w.write_group(16, elem)?;
+                }
+                w.write_message(19, &self.note)?;
+                if let QuoteAction::CancelId(elem) = &self.action {
+                    w.write_key(0xa0)?;
+                    w.write_varint(*elem)?;
+                }
+                if let QuoteAction::Amend(elem) = &self.action {
+                    w.write_group(21, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                len += key_len(1) + varint_len(self.price);
+                len += key_len(2) + bytes_len(self.market.len());
+                if let Some(elem) = &self.size {
+                    len += key_len(3) + varint_len(*elem as u64);
+                }
+                if let Some(elem) = &self.memo {
+                    len += key_len(4) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.venue {
+                    len += key_len(5) + int32_len(*elem);
+                }
+                if self.post_only.is_some() {
+                    len += key_len(6) + 1;
+                }
+                if self.fee.is_some() {
+                    len += key_len(7) + 8;
+                }
+                if self.limit.is_some() {
+                    len += key_len(8) + 4;
+                }
+                if let Some(elem) = &self.tag {
+                    len += key_len(9) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.offset {
+                    len += key_len(10) + zigzag64_len(*elem);
+                }
+                for elem in &self.levels {
+                    len += key_len(11) + varint_len(*elem as u64);
+                }
+                if !self.packed_levels.is_empty() {
+                    len += key_len(12) + bytes_len(self.packed_levels.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>());
+                }
+                len += key_len(13) + sizes.record(&self.fill) + key_len(13);
+                for elem in &self.leg {
+                    len += key_len(16) + sizes.record(elem) + key_len(16);
+                }
+                len += key_len(19) + bytes_len(sizes.record(&self.note));
+                if let QuoteAction::CancelId(elem) = &self.action {
+                    len += key_len(20) + varint_len(*elem);
+                }
+                if let QuoteAction::Amend(elem) = &self.action {
+                    len += key_len(21) + sizes.record(elem) + key_len(21);
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                w.write_key(0x08)?;
+                w.write_varint(self.price)?;
+                w.write_key(0x12)?;
+                w.write_string(&self.market)?;
+                if let Some(elem) = &self.size {
+                    w.write_key(0x18)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if let Some(elem) = &self.memo {
+                    w.write_key(0x22)?;
+                    w.write_string(elem)?;
+                }
+                if let Some(elem) = &self.venue {
+                    w.write_key(0x28)?;
+                    w.write_int32(*elem)?;
+                }
+                if let Some(elem) = &self.post_only {
+                    w.write_key(0x30)?;
+                    w.write_bool(*elem)?;
+                }
+                if let Some(elem) = &self.fee {
+                    w.write_key(0x39)?;
+                    w.write_double(*elem)?;
+                }
+                if let Some(elem) = &self.limit {
+                    w.write_key(0x45)?;
+                    w.write_float(*elem)?;
+                }
+                if let Some(elem) = &self.tag {
+                    w.write_key(0x4a)?;
+                    w.write_bytes(elem)?;
+                }
+                if let Some(elem) = &self.offset {
+                    w.write_key(0x50)?;
+                    w.write_zigzag64(*elem)?;
+                }
+                for elem in &self.levels {
+                    w.write_key(0x58)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if !self.packed_levels.is_empty() {
+                    w.write_key(0x62)?;
+                    w.write_varint(self.packed_levels.iter().map(|elem| varint_len(*elem as u64)).sum::<usize>() as u64)?;
+                    for elem in &self.packed_levels {
+                        w.write_varint(*elem as u64)?;
+                    }
+                }
+                w.write_group_sized(13, &self.fill, sizes)?;
+                for elem in &self.leg {
+                    w.write_group_sized(16, elem, sizes)?;
+                }
+                w.write_message_sized(19, &self.note, sizes)?;
+                if let QuoteAction::CancelId(elem) = &self.action {
+                    w.write_key(0xa0)?;
+                    w.write_varint(*elem)?;
+                }
+                if let QuoteAction::Amend(elem) = &self.action {
+                    w.write_group_sized(21, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.price = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.market = v;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.size = Some(v as u32);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.memo = Some(v);
+                            pos = new_pos;
+                        }
+                        40 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.venue = Some(v);
+                            pos = new_pos;
+                        }
+                        48 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_bool(data, pos)?;
+                            self.post_only = Some(v);
+                            pos = new_pos;
+                        }
+                        57 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_double(data, pos)?;
+                            self.fee = Some(v);
+                            pos = new_pos;
+                        }
+                        69 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (v, new_pos) = decode_float(data, pos)?;
+                            self.limit = Some(v);
+                            pos = new_pos;
+                        }
+                        74 => {
+                            mark_singular(&mut seen, 8, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.tag = Some(v);
+                            pos = new_pos;
+                        }
+                        80 => {
+                            mark_singular(&mut seen, 9, opts)?;
+                            let (v, new_pos) = decode_zigzag64(data, pos)?;
+                            self.offset = Some(v);
+                            pos = new_pos;
+                        }
+                        88 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.levels.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.levels.push(v as u32);
+                            pos = new_pos;
+                        }
+                        90 => {
+                            let new_pos = decode_packed_uint32_with(data, pos, &mut self.levels, opts)?;
+                            pos = new_pos;
+                        }
+                        96 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.packed_levels.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.packed_levels.push(v as u32);
+                            pos = new_pos;
+                        }
+                        98 => {
+                            let new_pos = decode_packed_uint32_with(data, pos, &mut self.packed_levels, opts)?;
+                            pos = new_pos;
+                        }
+                        107 => {
+                            mark_singular(&mut seen, 10, opts)?;
+                            let (sub, new_pos) = decode_group_slice::<13>(data, pos)?;
+                            self.fill.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        131 => {
+                            let (sub, new_pos) = decode_group_slice::<16>(data, pos)?;
+                            if self.leg.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.leg.push(Leg::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        154 => {
+                            mark_singular(&mut seen, 11, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.note.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        160 => {
+                            mark_singular(&mut seen, 12, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.action = QuoteAction::CancelId(v);
+                            pos = new_pos;
+                        }
+                        171 => {
+                            mark_singular(&mut seen, 12, opts)?;
+                            let (sub, new_pos) = decode_group_slice::<21>(data, pos)?;
+                            if let QuoteAction::Amend(existing) = &mut self.action {
+                                existing.merge_with(sub, &opts.nested()?)?;
+                            } else {
+                                self.action = QuoteAction::Amend(Amend::decode_with(sub, &opts.nested()?)?);
+                            }
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                if is_selected(only, 1) {
+                    check_required(&seen, 0, 1)?;
+                }
+                if is_selected(only, 2) {
+                    check_required(&seen, 1, 2)?;
+                }
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    11 => Some(self.levels.len()),
+                    12 => Some(self.packed_levels.len()),
+                    16 => Some(self.leg.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Quote {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Quote::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Quote::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Quote::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Quote::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Quote::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Quote::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Quote::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Quote {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Quote::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Quote> for Vec<u8> {
+            fn from(msg: Quote) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Quote {
+            const FULL_NAME: &'static str = "vendor.Quote";
+        }
+
+        impl Quote {
+            pub fn cancel_id(&self) -> Option<&u64> {
+                match &self.action {
+                    QuoteAction::CancelId(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_cancel_id(&mut self, value: u64) {
+                self.action = QuoteAction::CancelId(value);
+            }
+
+            pub fn amend(&self) -> Option<&Amend> {
+                match &self.action {
+                    QuoteAction::Amend(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_amend(&mut self, value: Amend) {
+                self.action = QuoteAction::Amend(value);
+            }
+        }
+
+        impl Quote {
+            pub fn venue(&self) -> Option<Result<Venue, i32>> {
+                self.venue.map(Venue::try_from)
+            }
+
+            pub fn set_venue(&mut self, value: Venue) {
+                self.venue = Some(value.into());
+            }
+        }
+
+        impl Quote {
+            pub fn size_or_default(&self) -> u32 {
+                self.size.unwrap_or(100)
+            }
+
+            pub fn memo_or_default(&self) -> &str {
+                self.memo.as_deref().unwrap_or("n/a")
+            }
+
+            pub fn venue_or_default(&self) -> i32 {
+                self.venue.unwrap_or(Venue::Spot as i32)
+            }
+
+            pub fn post_only_or_default(&self) -> bool {
+                self.post_only.unwrap_or(true)
+            }
+
+            pub fn fee_or_default(&self) -> f64 {
+                self.fee.unwrap_or(0.25)
+            }
+
+            pub fn limit_or_default(&self) -> f32 {
+                self.limit.unwrap_or(f32::INFINITY)
+            }
+
+            pub fn tag_or_default(&self) -> &[u8] {
+                self.tag.as_deref().unwrap_or(b"q\x01")
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct QuoteBuilder<F1 = Unset, F2 = Unset> {
+            inner: Quote,
+            required: core::marker::PhantomData<(F1, F2)>,
+        }
+
+        impl Quote {
+            pub fn builder() -> QuoteBuilder {
+                QuoteBuilder::default()
+            }
+        }
+
+        impl<F1, F2> QuoteBuilder<F1, F2> {
+            pub fn with_price(self, value: u64) -> QuoteBuilder<Set, F2> {
+                let mut inner = self.inner;
+                inner.price = value;
+                QuoteBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_market(self, value: String) -> QuoteBuilder<F1, Set> {
+                let mut inner = self.inner;
+                inner.market = value;
+                QuoteBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_size(mut self, value: u32) -> Self {
+                self.inner.size = Some(value);
+                self
+            }
+
+            pub fn with_memo(mut self, value: String) -> Self {
+                self.inner.memo = Some(value);
+                self
+            }
+
+            pub fn with_venue(mut self, value: i32) -> Self {
+                self.inner.venue = Some(value);
+                self
+            }
+
+            pub fn with_post_only(mut self, value: bool) -> Self {
+                self.inner.post_only = Some(value);
+                self
+            }
+
+            pub fn with_fee(mut self, value: f64) -> Self {
+                self.inner.fee = Some(value);
+                self
+            }
+
+            pub fn with_limit(mut self, value: f32) -> Self {
+                self.inner.limit = Some(value);
+                self
+            }
+
+            pub fn with_tag(mut self, value: Vec<u8>) -> Self {
+                self.inner.tag = Some(value);
+                self
+            }
+
+            pub fn with_offset(mut self, value: i64) -> Self {
+                self.inner.offset = Some(value);
+                self
+            }
+
+            pub fn with_levels(mut self, value: Vec<u32>) -> Self {
+                self.inner.levels = value;
+                self
+            }
+
+            pub fn add_levels(mut self, value: u32) -> Self {
+                self.inner.levels.push(value);
+                self
+            }
+
+            pub fn with_packed_levels(mut self, value: Vec<u32>) -> Self {
+                self.inner.packed_levels = value;
+                self
+            }
+
+            pub fn add_packed_levels(mut self, value: u32) -> Self {
+                self.inner.packed_levels.push(value);
+                self
+            }
+
+            pub fn with_fill(mut self, value: Fill) -> Self {
+                self.inner.fill = value;
+                self
+            }
+
+            pub fn with_leg(mut self, value: Vec<Leg>) -> Self {
+                self.inner.leg = value;
+                self
+            }
+
+            pub fn add_leg(mut self, value: Leg) -> Self {
+                self.inner.leg.push(value);
+                self
+            }
+
+            pub fn with_note(mut self, value: QuoteNote) -> Self {
+                self.inner.note = value;
+                self
+            }
+
+            pub fn with_cancel_id(mut self, value: u64) -> Self {
+                self.inner.action = QuoteAction::CancelId(value);
+                self
+            }
+
+            pub fn with_amend(mut self, value: Amend) -> Self {
+                self.inner.action = QuoteAction::Amend(value);
+                self
+            }
+        }
+
+        impl QuoteBuilder<Set, Set> {
+            pub fn build(self) -> Quote {
+                self.inner
+            }
+        }
+
+        impl Quote {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.price != 0 {
+                    out.write_scalar("price", self.price);
+                }
+                if !self.market.is_empty() {
+                    out.write_str("market", &self.market);
+                }
+                if let Some(elem) = &self.size {
+                    out.write_scalar("size", *elem);
+                }
+                if let Some(elem) = &self.memo {
+                    out.write_str("memo", elem);
+                }
+                if let Some(elem) = &self.venue {
+                    out.write_enum("venue", *elem, &[("VENUE_UNKNOWN", 0), ("VENUE_SPOT", 1), ("VENUE_PERP", 2)]);
+                }
+                if let Some(elem) = &self.post_only {
+                    out.write_scalar("post_only", *elem);
+                }
+                if let Some(elem) = &self.fee {
+                    out.write_f64("fee", *elem);
+                }
+                if let Some(elem) = &self.limit {
+                    out.write_f32("limit", *elem);
+                }
+                if let Some(elem) = &self.tag {
+                    out.write_bytes("tag", elem);
+                }
+                if let Some(elem) = &self.offset {
+                    out.write_scalar("offset", *elem);
+                }
+                for elem in &self.levels {
+                    out.write_scalar("levels", *elem);
+                }
+                for elem in &self.packed_levels {
+                    out.write_scalar("packed_levels", *elem);
+                }
+                if self.fill != Fill::default() {
+                    out.begin_message("fill");
+                    self.fill.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.leg {
+                    out.begin_message("leg");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if self.note != QuoteNote::default() {
+                    out.begin_message("note");
+                    self.note.write_text(out);
+                    out.end_message();
+                }
+                if let QuoteAction::CancelId(elem) = &self.action {
+                    out.write_scalar("cancel_id", *elem);
+                }
+                if let QuoteAction::Amend(elem) = &self.action {
+                    out.begin_message("amend");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Quote {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Quote {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.price != 0 {
+                    out.key("price");
+                    out.write_u64(self.price);
+                }
+                if !self.market.is_empty() {
+                    out.key("market");
+                    out.write_str(&self.market);
+                }
+                if let Some(elem) = &self.size {
+                    out.key("size");
+                    out.write_u32(*elem);
+                }
+                if let Some(elem) = &self.memo {
+                    out.key("memo");
+                    out.write_str(elem);
+                }
+                if let Some(elem) = &self.venue {
+                    out.key("venue");
+                    out.write_enum(*elem, &[("VENUE_UNKNOWN", 0), ("VENUE_SPOT", 1), ("VENUE_PERP", 2)]);
+                }
+                if let Some(elem) = &self.post_only {
+                    out.key("postOnly");
+                    out.write_bool(*elem);
+                }
+                if let Some(elem) = &self.fee {
+                    out.key("fee");
+                    out.write_f64(*elem);
+                }
+                if let Some(elem) = &self.limit {
+                    out.key("limit");
+                    out.write_f32(*elem);
+                }
+                if let Some(elem) = &self.tag {
+                    out.key("tag");
+                    out.write_bytes(elem);
+                }
+                if let Some(elem) = &self.offset {
+                    out.key("offset");
+                    out.write_i64(*elem);
+                }
+                if !self.levels.is_empty() {
+                    out.key("levels");
+                    out.begin_array();
+                    for elem in &self.levels {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.packed_levels.is_empty() {
+                    out.key("packedLevels");
+                    out.begin_array();
+                    for elem in &self.packed_levels {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if self.fill != Fill::default() {
+                    out.key("fill");
+                    self.fill.write_json(out);
+                }
+                if !self.leg.is_empty() {
+                    out.key("leg");
+                    out.begin_array();
+                    for elem in &self.leg {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if self.note != QuoteNote::default() {
+                    out.key("note");
+                    self.note.write_json(out);
+                }
+                if let QuoteAction::CancelId(elem) = &self.action {
+                    out.key("cancelId");
+                    out.write_u64(*elem);
+                }
+                if let QuoteAction::Amend(elem) = &self.action {
+                    out.key("amend");
+                    elem.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "price" => msg.price = json_to_u64(value)?,
+                        "market" => msg.market = json_to_string(value)?,
+                        "size" => msg.size = Some(json_to_u32(value)?),
+                        "memo" => msg.memo = Some(json_to_string(value)?),
+                        "venue" => msg.venue = Some(json_to_enum(value, &[("VENUE_UNKNOWN", 0), ("VENUE_SPOT", 1), ("VENUE_PERP", 2)])?),
+                        "postOnly" | "post_only" => msg.post_only = Some(json_to_bool(value)?),
+                        "fee" => msg.fee = Some(json_to_f64(value)?),
+                        "limit" => msg.limit = Some(json_to_f32(value)?),
+                        "tag" => msg.tag = Some(json_to_bytes(value)?),
+                        "offset" => msg.offset = Some(json_to_i64(value)?),
+                        "levels" => {
+                            for elem in json_array(value)? {
+                                msg.levels.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "packedLevels" | "packed_levels" => {
+                            for elem in json_array(value)? {
+                                msg.packed_levels.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "fill" => msg.fill = Fill::from_json_value(value)?,
+                        "leg" => {
+                            for elem in json_array(value)? {
+                                msg.leg.push(Leg::from_json_value(elem)?);
+                            }
+                        }
+                        "note" => msg.note = QuoteNote::from_json_value(value)?,
+                        "cancelId" | "cancel_id" => msg.action = QuoteAction::CancelId(json_to_u64(value)?),
+                        "amend" => msg.action = QuoteAction::Amend(Amend::from_json_value(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct QuoteRef<'a> {
+            raw: &'a [u8],
+            pub price: u64,
+            pub market: &'a str,
+            pub size: Option<u32>,
+            pub memo: Option<&'a str>,
+            pub venue: Option<i32>,
+            pub post_only: Option<bool>,
+            pub fee: Option<f64>,
+            pub limit: Option<f32>,
+            pub tag: Option<&'a [u8]>,
+            pub offset: Option<i64>,
+            pub levels: RepeatedRef<'a, u32>,
+            pub packed_levels: RepeatedRef<'a, u32>,
+            pub fill: Option<FillRef<'a>>,
+            pub leg: RepeatedRef<'a, LegRef<'a>>,
+            pub note: Option<QuoteNoteRef<'a>>,
+            pub cancel_id: Option<u64>,
+            pub amend: Option<AmendRef<'a>>,
+        }
+
+        impl<'a> QuoteRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = QuoteRef {
+                    raw: data,
+                    price: 0,
+                    market: "",
+                    size: None,
+                    memo: None,
+                    venue: None,
+                    post_only: None,
+                    fee: None,
+                    limit: None,
+                    tag: None,
+                    offset: None,
+                    levels: RepeatedRef::packed(data, 88, |data, pos| {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        Ok((v as u32, new_pos))
+                    }),
+                    packed_levels: RepeatedRef::packed(data, 96, |data, pos| {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        Ok((v as u32, new_pos))
+                    }),
+                    fill: None,
+                    leg: RepeatedRef::new(data, 131, |data, pos| {
+                        let (v, new_pos) = decode_group_slice::<16>(data, pos)?;
+                        Ok((LegRef::decode(v)?, new_pos))
+                    }),
+                    note: None,
+                    cancel_id: None,
+                    amend: None,
+                };
+
+                let mut has_price = false;
+                let mut has_market = false;
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        has_price = true;
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.price = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        has_market = true;
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.market = v;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.size = Some(v as u32);
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.memo = Some(v);
+                        pos = new_pos;
+                    }
+                    40 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.venue = Some(v);
+                        pos = new_pos;
+                    }
+                    48 => {
+                        let (v, new_pos) = decode_bool(data, pos)?;
+                        view.post_only = Some(v);
+                        pos = new_pos;
+                    }
+                    57 => {
+                        let (v, new_pos) = decode_double(data, pos)?;
+                        view.fee = Some(v);
+                        pos = new_pos;
+                    }
+                    69 => {
+                        let (v, new_pos) = decode_float(data, pos)?;
+                        view.limit = Some(v);
+                        pos = new_pos;
+                    }
+                    74 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.tag = Some(v);
+                        pos = new_pos;
+                    }
+                    80 => {
+                        let (v, new_pos) = decode_zigzag64(data, pos)?;
+                        view.offset = Some(v);
+                        pos = new_pos;
+                    }
+                    88 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    96 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    107 => {
+                        let (v, new_pos) = decode_group_slice::<13>(data, pos)?;
+                        view.fill = Some(FillRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    131 => {
+                        pos = skip_field(data, pos, 131)?;
+                    }
+                    154 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.note = Some(QuoteNoteRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    160 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.cancel_id = Some(v);
+                        view.amend = None;
+                        pos = new_pos;
+                    }
+                    171 => {
+                        let (v, new_pos) = decode_group_slice::<21>(data, pos)?;
+                        view.amend = Some(AmendRef::decode(v)?);
+                        view.cancel_id = None;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Quote::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                if !has_price {
+                    return Err(DecodeError::MissingField { field: 1 });
+                }
+                if !has_market {
+                    return Err(DecodeError::MissingField { field: 2 });
+                }
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Quote, DecodeError> {
+                Quote::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Fill {
+            pub qty: u64,
+            pub px: Option<u64>,
+        }
+
+        impl Fill {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Fill {
+                qty: 0,
+                px: None,
+            };
+        }
+
+        impl Default for Fill {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Fill {
+            pub const QTY_FIELD_NUMBER: u32 = 14;
+            pub const PX_FIELD_NUMBER: u32 = 15;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 14, wire_type: 0, packed: false, name: "Fill.qty" },
+                FieldWireType { number: 15, wire_type: 0, packed: false, name: "Fill.px" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 15: px
+                if let Some(elem) = &self.px {
+                    w.write_varint(*elem);
+                    w.write_key(0x78);
+                }
+
+                // field 14: qty
+                w.write_varint(self.qty);
+                w.write_key(0x70);
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                len += key_len(14) + varint_len(self.qty);
+                if let Some(elem) = &self.px {
+                    len += key_len(15) + varint_len(*elem);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                w.write_key(0x70)?;
+                w.write_varint(self.qty)?;
+                if let Some(elem) = &self.px {
+                    w.write_key(0x78)?;
+                    w.write_varint(*elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        112 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.qty = v;
+                            pos = new_pos;
+                        }
+                        120 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.px = Some(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                if is_selected(only, 14) {
+                    check_required(&seen, 0, 14)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl Message for Fill {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Fill::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Fill::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Fill::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Fill::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Fill::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Fill::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Fill::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Fill {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Fill::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Fill> for Vec<u8> {
+            fn from(msg: Fill) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Fill {
+            const FULL_NAME: &'static str = "vendor.Quote.Fill";
+        }
+
+        impl Fill {
+            /// Longest encoding of a `Fill` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 22;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct FillBuilder<F14 = Unset> {
+            inner: Fill,
+            required: core::marker::PhantomData<F14>,
+        }
+
+        impl Fill {
+            pub fn builder() -> FillBuilder {
+                FillBuilder::default()
+            }
+        }
+
+        impl<F14> FillBuilder<F14> {
+            pub fn with_qty(self, value: u64) -> FillBuilder<Set> {
+                let mut inner = self.inner;
+                inner.qty = value;
+                FillBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_px(mut self, value: u64) -> Self {
+                self.inner.px = Some(value);
+                self
+            }
+        }
+
+        impl FillBuilder<Set> {
+            pub fn build(self) -> Fill {
+                self.inner
+            }
+        }
+
+        impl Fill {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.qty != 0 {
+                    out.write_scalar("qty", self.qty);
+                }
+                if let Some(elem) = &self.px {
+                    out.write_scalar("px", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Fill {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Fill {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.qty != 0 {
+                    out.key("qty");
+                    out.write_u64(self.qty);
+                }
+                if let Some(elem) = &self.px {
+                    out.key("px");
+                    out.write_u64(*elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "qty" => msg.qty = json_to_u64(value)?,
+                        "px" => msg.px = Some(json_to_u64(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct FillRef<'a> {
+            raw: &'a [u8],
+            pub qty: u64,
+            pub px: Option<u64>,
+        }
+
+        impl<'a> FillRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = FillRef {
+                    raw: data,
+                    qty: 0,
+                    px: None,
+                };
+
+                let mut has_qty = false;
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    112 => {
+                        has_qty = true;
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.qty = v;
+                        pos = new_pos;
+                    }
+                    120 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.px = Some(v);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Fill::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                if !has_qty {
+                    return Err(DecodeError::MissingField { field: 14 });
+                }
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Fill, DecodeError> {
+                Fill::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Leg {
+            pub symbol: Option<String>,
+            pub note: QuoteNote,
+        }
+
+        impl Leg {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Leg {
+                symbol: None,
+                note: QuoteNote::DEFAULT,
+            };
+        }
+
+        impl Default for Leg {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Leg {
+            pub const SYMBOL_FIELD_NUMBER: u32 = 17;
+            pub const NOTE_FIELD_NUMBER: u32 = 18;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 17, wire_type: 2, packed: false, name: "Leg.symbol" },
+                FieldWireType { number: 18, wire_type: 2, packed: false, name: "Leg.note" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 18: note
+                w.write_message(18, &self.note);
+
+                // field 17: symbol
+                if let Some(elem) = &self.symbol {
+                    w.write_string(elem);
+                    w.write_key(0x8a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.symbol {
+                    len += key_len(17) + bytes_len(elem.len());
+                }
+                len += key_len(18) + bytes_len(self.note.encoded_len());
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.symbol {
+                    w.write_key(0x8a)?;
+                    w.write_string(elem)?;
+                }
+                w.write_message(18, &self.note)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.symbol {
+                    len += key_len(17) + bytes_len(elem.len());
+                }
+                len += key_len(18) + bytes_len(sizes.record(&self.note));
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.symbol {
+                    w.write_key(0x8a)?;
+                    w.write_string(elem)?;
+                }
+                w.write_message_sized(18, &self.note, sizes)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        138 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.symbol = Some(v);
+                            pos = new_pos;
+                        }
+                        146 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.note.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Leg {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Leg::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Leg::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Leg::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Leg::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Leg::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Leg::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Leg::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Leg {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Leg::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Leg> for Vec<u8> {
+            fn from(msg: Leg) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Leg {
+            const FULL_NAME: &'static str = "vendor.Quote.Leg";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LegBuilder {
+            inner: Leg,
+        }
+
+        impl Leg {
+            pub fn builder() -> LegBuilder {
+                LegBuilder::default()
+            }
+        }
+
+        impl LegBuilder {
+            pub fn with_symbol(mut self, value: String) -> Self {
+                self.inner.symbol = Some(value);
+                self
+            }
+
+            pub fn with_note(mut self, value: QuoteNote) -> Self {
+                self.inner.note = value;
+                self
+            }
+
+            pub fn build(self) -> Leg {
+                self.inner
+            }
+        }
+
+        impl Leg {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if let Some(elem) = &self.symbol {
+                    out.write_str("symbol", elem);
+                }
+                if self.note != QuoteNote::default() {
+                    out.begin_message("note");
+                    self.note.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Leg {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Leg {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if let Some(elem) = &self.symbol {
+                    out.key("symbol");
+                    out.write_str(elem);
+                }
+                if self.note != QuoteNote::default() {
+                    out.key("note");
+                    self.note.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "symbol" => msg.symbol = Some(json_to_string(value)?),
+                        "note" => msg.note = QuoteNote::from_json_value(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LegRef<'a> {
+            raw: &'a [u8],
+            pub symbol: Option<&'a str>,
+            pub note: Option<QuoteNoteRef<'a>>,
+        }
+
+        impl<'a> LegRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = LegRef {
+                    raw: data,
+                    symbol: None,
+                    note: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    138 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.symbol = Some(v);
+                        pos = new_pos;
+                    }
+                    146 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.note = Some(QuoteNoteRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Leg::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Leg, DecodeError> {
+                Leg::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Amend {
+            pub new_price: Option<u64>,
+        }
+
+        impl Amend {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Amend {
+                new_price: None,
+            };
+        }
+
+        impl Default for Amend {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Amend {
+            pub const NEW_PRICE_FIELD_NUMBER: u32 = 22;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 22, wire_type: 0, packed: false, name: "Amend.new_price" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 22: new_price
+                if let Some(elem) = &self.new_price {
+                    w.write_varint(*elem);
+                    w.write_key(0xb0);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.new_price {
+                    len += key_len(22) + varint_len(*elem);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.new_price {
+                    w.write_key(0xb0)?;
+                    w.write_varint(*elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        176 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.new_price = Some(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Amend {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Amend::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Amend::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Amend::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Amend::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Amend::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Amend::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Amend::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Amend {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Amend::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Amend> for Vec<u8> {
+            fn from(msg: Amend) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Amend {
+            const FULL_NAME: &'static str = "vendor.Quote.Amend";
+        }
+
+        impl Amend {
+            /// Longest encoding of a `Amend` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 12;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct AmendBuilder {
+            inner: Amend,
+        }
+
+        impl Amend {
+            pub fn builder() -> AmendBuilder {
+                AmendBuilder::default()
+            }
+        }
+
+        impl AmendBuilder {
+            pub fn with_new_price(mut self, value: u64) -> Self {
+                self.inner.new_price = Some(value);
+                self
+            }
+
+            pub fn build(self) -> Amend {
+                self.inner
+            }
+        }
+
+        impl Amend {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if let Some(elem) = &self.new_price {
+                    out.write_scalar("new_price", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Amend {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Amend {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if let Some(elem) = &self.new_price {
+                    out.key("newPrice");
+                    out.write_u64(*elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "newPrice" | "new_price" => msg.new_price = Some(json_to_u64(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct AmendRef<'a> {
+            raw: &'a [u8],
+            pub new_price: Option<u64>,
+        }
+
+        impl<'a> AmendRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = AmendRef {
+                    raw: data,
+                    new_price: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    176 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.new_price = Some(v);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Amend::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Amend, DecodeError> {
+                Amend::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct QuoteNote {
+            pub text: Option<String>,
+        }
+
+        impl QuoteNote {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = QuoteNote {
+                text: None,
+            };
+        }
+
+        impl Default for QuoteNote {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl QuoteNote {
+            pub const TEXT_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "QuoteNote.text" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: text
+                if let Some(elem) = &self.text {
+                    w.write_string(elem);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.text {
+                    len += key_len(1) + bytes_len(elem.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.text {
+                    w.write_key(0x0a)?;
+                    w.write_string(elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.text = Some(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for QuoteNote {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                QuoteNote::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                QuoteNote::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                QuoteNote::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                QuoteNote::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                QuoteNote::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                QuoteNote::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                QuoteNote::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for QuoteNote {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                QuoteNote::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<QuoteNote> for Vec<u8> {
+            fn from(msg: QuoteNote) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for QuoteNote {
+            const FULL_NAME: &'static str = "vendor.QuoteNote";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct QuoteNoteBuilder {
+            inner: QuoteNote,
+        }
+
+        impl QuoteNote {
+            pub fn builder() -> QuoteNoteBuilder {
+                QuoteNoteBuilder::default()
+            }
+        }
+
+        impl QuoteNoteBuilder {
+            pub fn with_text(mut self, value: String) -> Self {
+                self.inner.text = Some(value);
+                self
+            }
+
+            pub fn build(self) -> QuoteNote {
+                self.inner
+            }
+        }
+
+        impl QuoteNote {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if let Some(elem) = &self.text {
+                    out.write_str("text", elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for QuoteNote {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl QuoteNote {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if let Some(elem) = &self.text {
+                    out.key("text");
+                    out.write_str(elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "text" => msg.text = Some(json_to_string(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct QuoteNoteRef<'a> {
+            raw: &'a [u8],
+            pub text: Option<&'a str>,
+        }
+
+        impl<'a> QuoteNoteRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = QuoteNoteRef {
+                    raw: data,
+                    text: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.text = Some(v);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(QuoteNote::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<QuoteNote, DecodeError> {
+                QuoteNote::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Quote(Quote),
+            Fill(Fill),
+            Leg(Leg),
+            Amend(Amend),
+            QuoteNote(QuoteNote),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Quote::FULL_NAME => AnyMessage::Quote(Quote::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Fill::FULL_NAME => AnyMessage::Fill(Fill::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Leg::FULL_NAME => AnyMessage::Leg(Leg::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Amend::FULL_NAME => AnyMessage::Amend(Amend::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    QuoteNote::FULL_NAME => AnyMessage::QuoteNote(QuoteNote::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Quote(msg) => Any::pack(msg),
+                    AnyMessage::Fill(msg) => Any::pack(msg),
+                    AnyMessage::Leg(msg) => Any::pack(msg),
+                    AnyMessage::Amend(msg) => Any::pack(msg),
+                    AnyMessage::QuoteNote(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,647 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod pubkey {
+        // Auto-generated by protoc-gen-solana from pubkey.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum StakeAccountTarget {
+            Unset,
+            VoteAccount(Pubkey),
+            PoolId(u64),
+        }
+
+        impl StakeAccountTarget {
+            pub const DEFAULT: Self = StakeAccountTarget::Unset;
+        }
+
+        impl Default for StakeAccountTarget {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: (solana.pubkey) bytes fields as fixed 32-byte keys
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct StakeAccount {
+            pub authority: Pubkey,
+            pub delegates: Vec<Pubkey>,
+            pub withdrawer: Option<Pubkey>,
+            pub target: StakeAccountTarget,
+            pub memo: Vec<u8>,
+        }
+
+        impl StakeAccount {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = StakeAccount {
+                authority: ZERO_PUBKEY,
+                delegates: Vec::new(),
+                withdrawer: None,
+                target: StakeAccountTarget::Unset,
+                memo: Vec::new(),
+            };
+        }
+
+        impl Default for StakeAccount {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl StakeAccount {
+            pub const AUTHORITY_FIELD_NUMBER: u32 = 1;
+            pub const DELEGATES_FIELD_NUMBER: u32 = 2;
+            pub const WITHDRAWER_FIELD_NUMBER: u32 = 3;
+            pub const VOTE_ACCOUNT_FIELD_NUMBER: u32 = 4;
+            pub const POOL_ID_FIELD_NUMBER: u32 = 5;
+            pub const MEMO_FIELD_NUMBER: u32 = 6;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "StakeAccount.authority" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "StakeAccount.delegates" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "StakeAccount.withdrawer" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "StakeAccount.vote_account" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "StakeAccount.pool_id" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "StakeAccount.memo" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 6: memo
+                if !self.memo.is_empty() {
+                    w.write_bytes(&self.memo);
+                    w.write_key(0x32);
+                }
+
+                // field 5: pool_id
+                if let StakeAccountTarget::PoolId(elem) = &self.target {
+                    w.write_varint(*elem);
+                    w.write_key(0x28);
+                }
+
+                // field 4: vote_account
+                if let StakeAccountTarget::VoteAccount(elem) = &self.target {
+                    w.write_pubkey(elem);
+                    w.write_key(0x22);
+                }
+
+                // field 3: withdrawer
+                if let Some(elem) = &self.withdrawer {
+                    w.write_pubkey(elem);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: delegates
+                for elem in self.delegates.iter().rev() {
+                    w.write_pubkey(elem);
+                    w.write_key(0x12);
+                }
+
+                // field 1: authority
+                if self.authority != Pubkey::default() {
+                    w.write_pubkey(&self.authority);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                len += self.delegates.len() * (key_len(2) + 33);
+                if self.withdrawer.is_some() {
+                    len += key_len(3) + 33;
+                }
+                if let StakeAccountTarget::VoteAccount(_) = &self.target {
+                    len += key_len(4) + 33;
+                }
+                if let StakeAccountTarget::PoolId(elem) = &self.target {
+                    len += key_len(5) + varint_len(*elem);
+                }
+                if !self.memo.is_empty() {
+                    len += key_len(6) + bytes_len(self.memo.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                for elem in &self.delegates {
+                    w.write_key(0x12)?;
+                    w.write_pubkey(elem)?;
+                }
+                if let Some(elem) = &self.withdrawer {
+                    w.write_key(0x1a)?;
+                    w.write_pubkey(elem)?;
+                }
+                if let StakeAccountTarget::VoteAccount(elem) = &self.target {
+                    w.write_key(0x22)?;
+                    w.write_pubkey(elem)?;
+                }
+                if let StakeAccountTarget::PoolId(elem) = &self.target {
+                    w.write_key(0x28)?;
+                    w.write_varint(*elem)?;
+                }
+                if !self.memo.is_empty() {
+                    w.write_key(0x32)?;
+                    w.write_bytes(&self.memo)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.authority = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            if self.delegates.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.delegates.push(v);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.withdrawer = Some(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.target = StakeAccountTarget::VoteAccount(v);
+                            pos = new_pos;
+                        }
+                        40 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.target = StakeAccountTarget::PoolId(v);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.memo = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    2 => Some(self.delegates.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for StakeAccount {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                StakeAccount::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                StakeAccount::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                StakeAccount::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                StakeAccount::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                StakeAccount::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                StakeAccount::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                StakeAccount::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for StakeAccount {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                StakeAccount::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<StakeAccount> for Vec<u8> {
+            fn from(msg: StakeAccount) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for StakeAccount {
+            const FULL_NAME: &'static str = "example.StakeAccount";
+        }
+
+        impl StakeAccount {
+            pub fn vote_account(&self) -> Option<&Pubkey> {
+                match &self.target {
+                    StakeAccountTarget::VoteAccount(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_vote_account(&mut self, value: Pubkey) {
+                self.target = StakeAccountTarget::VoteAccount(value);
+            }
+
+            pub fn pool_id(&self) -> Option<&u64> {
+                match &self.target {
+                    StakeAccountTarget::PoolId(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_pool_id(&mut self, value: u64) {
+                self.target = StakeAccountTarget::PoolId(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct StakeAccountBuilder {
+            inner: StakeAccount,
+        }
+
+        impl StakeAccount {
+            pub fn builder() -> StakeAccountBuilder {
+                StakeAccountBuilder::default()
+            }
+        }
+
+        impl StakeAccountBuilder {
+            pub fn with_authority(mut self, value: Pubkey) -> Self {
+                self.inner.authority = value;
+                self
+            }
+
+            pub fn with_delegates(mut self, value: Vec<Pubkey>) -> Self {
+                self.inner.delegates = value;
+                self
+            }
+
+            pub fn add_delegates(mut self, value: Pubkey) -> Self {
+                self.inner.delegates.push(value);
+                self
+            }
+
+            pub fn with_withdrawer(mut self, value: Pubkey) -> Self {
+                self.inner.withdrawer = Some(value);
+                self
+            }
+
+            pub fn with_vote_account(mut self, value: Pubkey) -> Self {
+                self.inner.target = StakeAccountTarget::VoteAccount(value);
+                self
+            }
+
+            pub fn with_pool_id(mut self, value: u64) -> Self {
+                self.inner.target = StakeAccountTarget::PoolId(value);
+                self
+            }
+
+            pub fn with_memo(mut self, value: Vec<u8>) -> Self {
+                self.inner.memo = value;
+                self
+            }
+
+            pub fn build(self) -> StakeAccount {
+                self.inner
+            }
+        }
+
+        impl StakeAccount {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.authority != Pubkey::default() {
+                    out.write_pubkey("authority", &self.authority);
+                }
+                for elem in &self.delegates {
+                    out.write_pubkey("delegates", elem);
+                }
+                if let Some(elem) = &self.withdrawer {
+                    out.write_pubkey("withdrawer", elem);
+                }
+                if let StakeAccountTarget::VoteAccount(elem) = &self.target {
+                    out.write_pubkey("vote_account", elem);
+                }
+                if let StakeAccountTarget::PoolId(elem) = &self.target {
+                    out.write_scalar("pool_id", *elem);
+                }
+                if !self.memo.is_empty() {
+                    out.write_bytes("memo", &self.memo);
+                }
+            }
+        }
+
+        impl core::fmt::Display for StakeAccount {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl StakeAccount {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.authority != Pubkey::default() {
+                    out.key("authority");
+                    out.write_pubkey(self.authority);
+                }
+                if !self.delegates.is_empty() {
+                    out.key("delegates");
+                    out.begin_array();
+                    for elem in &self.delegates {
+                        out.write_pubkey(*elem);
+                    }
+                    out.end_array();
+                }
+                if let Some(elem) = &self.withdrawer {
+                    out.key("withdrawer");
+                    out.write_pubkey(*elem);
+                }
+                if let StakeAccountTarget::VoteAccount(elem) = &self.target {
+                    out.key("voteAccount");
+                    out.write_pubkey(*elem);
+                }
+                if let StakeAccountTarget::PoolId(elem) = &self.target {
+                    out.key("poolId");
+                    out.write_u64(*elem);
+                }
+                if !self.memo.is_empty() {
+                    out.key("memo");
+                    out.write_bytes(&self.memo);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "authority" => msg.authority = json_to_pubkey(value)?,
+                        "delegates" => {
+                            for elem in json_array(value)? {
+                                msg.delegates.push(json_to_pubkey(elem)?);
+                            }
+                        }
+                        "withdrawer" => msg.withdrawer = Some(json_to_pubkey(value)?),
+                        "voteAccount" | "vote_account" => msg.target = StakeAccountTarget::VoteAccount(json_to_pubkey(value)?),
+                        "poolId" | "pool_id" => msg.target = StakeAccountTarget::PoolId(json_to_u64(value)?),
+                        "memo" => msg.memo = json_to_bytes(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct StakeAccountRef<'a> {
+            raw: &'a [u8],
+            pub authority: Pubkey,
+            pub delegates: RepeatedRef<'a, Pubkey>,
+            pub withdrawer: Option<Pubkey>,
+            pub vote_account: Option<Pubkey>,
+            pub pool_id: Option<u64>,
+            pub memo: &'a [u8],
+        }
+
+        impl<'a> StakeAccountRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = StakeAccountRef {
+                    raw: data,
+                    authority: ZERO_PUBKEY,
+                    delegates: RepeatedRef::new(data, 18, decode_pubkey),
+                    withdrawer: None,
+                    vote_account: None,
+                    pool_id: None,
+                    memo: &[],
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.authority = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.withdrawer = Some(v);
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.vote_account = Some(v);
+                        view.pool_id = None;
+                        pos = new_pos;
+                    }
+                    40 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.pool_id = Some(v);
+                        view.vote_account = None;
+                        pos = new_pos;
+                    }
+                    50 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.memo = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(StakeAccount::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<StakeAccount, DecodeError> {
+                StakeAccount::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            StakeAccount(StakeAccount),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    StakeAccount::FULL_NAME => AnyMessage::StakeAccount(StakeAccount::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::StakeAccount(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,2301 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod recursive {
+        // Auto-generated by protoc-gen-solana from recursive.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: self-referential messages under the decode recursion limit
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct TreeNode {
+            pub value: u64,
+            pub children: Vec<TreeNode>,
+            pub named: BTreeMap<String, TreeNode>,
+        }
+
+        impl TreeNode {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = TreeNode {
+                value: 0,
+                children: Vec::new(),
+                named: BTreeMap::new(),
+            };
+        }
+
+        impl Default for TreeNode {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl TreeNode {
+            pub const VALUE_FIELD_NUMBER: u32 = 1;
+            pub const CHILDREN_FIELD_NUMBER: u32 = 2;
+            pub const NAMED_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "TreeNode.value" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "TreeNode.children" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "TreeNode.named" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: named
+                for (key, value) in self.named.iter().rev() {
+                    w.write_nested(3, |w| {
+                        w.write_message(2, value);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 2: children
+                for elem in self.children.iter().rev() {
+                    w.write_message(2, elem);
+                }
+
+                // field 1: value
+                if self.value != 0 {
+                    w.write_varint(self.value);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.value != 0 {
+                    len += key_len(1) + varint_len(self.value);
+                }
+                for elem in &self.children {
+                    len += key_len(2) + bytes_len(elem.encoded_len());
+                }
+                for (key, value) in &self.named {
+                    len += key_len(3) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len()));
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.value != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.value)?;
+                }
+                for elem in &self.children {
+                    w.write_message(2, elem)?;
+                }
+                for (key, value) in &self.named {
+                    w.write_key(0x1a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message(2, value)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.value != 0 {
+                    len += key_len(1) + varint_len(self.value);
+                }
+                for elem in &self.children {
+                    len += key_len(2) + bytes_len(sizes.record(elem));
+                }
+                for (key, value) in &self.named {
+                    len += key_len(3) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.record(value)));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.value != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.value)?;
+                }
+                for elem in &self.children {
+                    w.write_message_sized(2, elem, sizes)?;
+                }
+                for (key, value) in &self.named {
+                    w.write_key(0x1a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message_sized(2, value, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.value = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.children.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.children.push(TreeNode::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        26 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = TreeNode::DEFAULT;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                        value.merge_with(sub, &opts.nested()?)?;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.named.len() >= opts.max_repeated_len && !self.named.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.named.insert(key, value);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    2 => Some(self.children.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for TreeNode {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                TreeNode::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                TreeNode::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                TreeNode::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                TreeNode::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                TreeNode::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                TreeNode::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                TreeNode::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for TreeNode {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                TreeNode::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<TreeNode> for Vec<u8> {
+            fn from(msg: TreeNode) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for TreeNode {
+            const FULL_NAME: &'static str = "example.TreeNode";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct TreeNodeBuilder {
+            inner: TreeNode,
+        }
+
+        impl TreeNode {
+            pub fn builder() -> TreeNodeBuilder {
+                TreeNodeBuilder::default()
+            }
+        }
+
+        impl TreeNodeBuilder {
+            pub fn with_value(mut self, value: u64) -> Self {
+                self.inner.value = value;
+                self
+            }
+
+            pub fn with_children(mut self, value: Vec<TreeNode>) -> Self {
+                self.inner.children = value;
+                self
+            }
+
+            pub fn add_children(mut self, value: TreeNode) -> Self {
+                self.inner.children.push(value);
+                self
+            }
+
+            pub fn with_named(mut self, value: BTreeMap<String, TreeNode>) -> Self {
+                self.inner.named = value;
+                self
+            }
+
+            pub fn insert_named(mut self, key: String, value: TreeNode) -> Self {
+                self.inner.named.insert(key, value);
+                self
+            }
+
+            pub fn build(self) -> TreeNode {
+                self.inner
+            }
+        }
+
+        impl TreeNode {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.value != 0 {
+                    out.write_scalar("value", self.value);
+                }
+                for elem in &self.children {
+                    out.begin_message("children");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for (key, value) in &self.named {
+                    out.begin_message("named");
+                    out.write_str("key", key);
+                    out.begin_message("value");
+                    value.write_text(out);
+                    out.end_message();
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for TreeNode {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl TreeNode {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.value != 0 {
+                    out.key("value");
+                    out.write_u64(self.value);
+                }
+                if !self.children.is_empty() {
+                    out.key("children");
+                    out.begin_array();
+                    for elem in &self.children {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if !self.named.is_empty() {
+                    out.key("named");
+                    out.begin_object();
+                    for (key, value) in &self.named {
+                        out.key(key);
+                        value.write_json(out);
+                    }
+                    out.end_object();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "value" => msg.value = json_to_u64(value)?,
+                        "children" => {
+                            for elem in json_array(value)? {
+                                msg.children.push(TreeNode::from_json_value(elem)?);
+                            }
+                        }
+                        "named" => {
+                            for (key, value) in json_object(value)? {
+                                msg.named.insert(json_map_key(key)?, TreeNode::from_json_value(value)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct TreeNodeRef<'a> {
+            raw: &'a [u8],
+            pub value: u64,
+            pub children: RepeatedRef<'a, TreeNodeRef<'a>>,
+        }
+
+        impl<'a> TreeNodeRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = TreeNodeRef {
+                    raw: data,
+                    value: 0,
+                    children: RepeatedRef::new(data, 18, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((TreeNodeRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.value = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(TreeNode::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<TreeNode, DecodeError> {
+                TreeNode::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum ExprUnary {
+            Unset,
+            Negate(Box<Expr>),
+            Variable(String),
+        }
+
+        impl ExprUnary {
+            pub const DEFAULT: Self = ExprUnary::Unset;
+        }
+
+        impl Default for ExprUnary {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: singular, optional and oneof fields of the message's own type, boxed
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Expr {
+            pub literal: i64,
+            pub left: Option<Box<Expr>>,
+            pub right: Option<Box<Expr>>,
+            pub unary: ExprUnary,
+            pub args: Vec<Expr>,
+        }
+
+        impl Expr {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Expr {
+                literal: 0,
+                left: None,
+                right: None,
+                unary: ExprUnary::Unset,
+                args: Vec::new(),
+            };
+        }
+
+        impl Default for Expr {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Expr {
+            pub const LITERAL_FIELD_NUMBER: u32 = 1;
+            pub const LEFT_FIELD_NUMBER: u32 = 2;
+            pub const RIGHT_FIELD_NUMBER: u32 = 3;
+            pub const NEGATE_FIELD_NUMBER: u32 = 4;
+            pub const VARIABLE_FIELD_NUMBER: u32 = 5;
+            pub const ARGS_FIELD_NUMBER: u32 = 6;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Expr.literal" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Expr.left" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Expr.right" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Expr.negate" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "Expr.variable" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Expr.args" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 6: args
+                for elem in self.args.iter().rev() {
+                    w.write_message(6, elem);
+                }
+
+                // field 5: variable
+                if let ExprUnary::Variable(elem) = &self.unary {
+                    w.write_string(elem);
+                    w.write_key(0x2a);
+                }
+
+                // field 4: negate
+                if let ExprUnary::Negate(elem) = &self.unary {
+                    w.write_message(4, &**elem);
+                }
+
+                // field 3: right
+                if let Some(elem) = &self.right {
+                    w.write_message(3, &**elem);
+                }
+
+                // field 2: left
+                if let Some(elem) = &self.left {
+                    w.write_message(2, &**elem);
+                }
+
+                // field 1: literal
+                if self.literal != 0 {
+                    w.write_int64(self.literal);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.literal != 0 {
+                    len += key_len(1) + int64_len(self.literal);
+                }
+                if let Some(elem) = &self.left {
+                    len += key_len(2) + bytes_len(elem.encoded_len());
+                }
+                if let Some(elem) = &self.right {
+                    len += key_len(3) + bytes_len(elem.encoded_len());
+                }
+                if let ExprUnary::Negate(elem) = &self.unary {
+                    len += key_len(4) + bytes_len(elem.encoded_len());
+                }
+                if let ExprUnary::Variable(elem) = &self.unary {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                for elem in &self.args {
+                    len += key_len(6) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.literal != 0 {
+                    w.write_key(0x08)?;
+                    w.write_int64(self.literal)?;
+                }
+                if let Some(elem) = &self.left {
+                    w.write_message(2, &**elem)?;
+                }
+                if let Some(elem) = &self.right {
+                    w.write_message(3, &**elem)?;
+                }
+                if let ExprUnary::Negate(elem) = &self.unary {
+                    w.write_message(4, &**elem)?;
+                }
+                if let ExprUnary::Variable(elem) = &self.unary {
+                    w.write_key(0x2a)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.args {
+                    w.write_message(6, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.literal != 0 {
+                    len += key_len(1) + int64_len(self.literal);
+                }
+                if let Some(elem) = &self.left {
+                    len += key_len(2) + bytes_len(sizes.record(&**elem));
+                }
+                if let Some(elem) = &self.right {
+                    len += key_len(3) + bytes_len(sizes.record(&**elem));
+                }
+                if let ExprUnary::Negate(elem) = &self.unary {
+                    len += key_len(4) + bytes_len(sizes.record(&**elem));
+                }
+                if let ExprUnary::Variable(elem) = &self.unary {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                for elem in &self.args {
+                    len += key_len(6) + bytes_len(sizes.record(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.literal != 0 {
+                    w.write_key(0x08)?;
+                    w.write_int64(self.literal)?;
+                }
+                if let Some(elem) = &self.left {
+                    w.write_message_sized(2, &**elem, sizes)?;
+                }
+                if let Some(elem) = &self.right {
+                    w.write_message_sized(3, &**elem, sizes)?;
+                }
+                if let ExprUnary::Negate(elem) = &self.unary {
+                    w.write_message_sized(4, &**elem, sizes)?;
+                }
+                if let ExprUnary::Variable(elem) = &self.unary {
+                    w.write_key(0x2a)?;
+                    w.write_string(elem)?;
+                }
+                for elem in &self.args {
+                    w.write_message_sized(6, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_int64(data, pos)?;
+                            self.literal = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.left.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.right.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if let ExprUnary::Negate(existing) = &mut self.unary {
+                                existing.merge_with(sub, &opts.nested()?)?;
+                            } else {
+                                self.unary = ExprUnary::Negate(Expr::decode_with(sub, &opts.nested()?)?.into());
+                            }
+                            pos = new_pos;
+                        }
+                        42 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.unary = ExprUnary::Variable(v);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.args.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.args.push(Expr::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    6 => Some(self.args.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Expr {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Expr::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Expr::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Expr::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Expr::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Expr::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Expr::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Expr::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Expr {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Expr::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Expr> for Vec<u8> {
+            fn from(msg: Expr) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Expr {
+            const FULL_NAME: &'static str = "example.Expr";
+        }
+
+        impl Expr {
+            pub fn negate(&self) -> Option<&Expr> {
+                match &self.unary {
+                    ExprUnary::Negate(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_negate(&mut self, value: Expr) {
+                self.unary = ExprUnary::Negate(value.into());
+            }
+
+            pub fn variable(&self) -> Option<&String> {
+                match &self.unary {
+                    ExprUnary::Variable(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_variable(&mut self, value: String) {
+                self.unary = ExprUnary::Variable(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct ExprBuilder {
+            inner: Expr,
+        }
+
+        impl Expr {
+            pub fn builder() -> ExprBuilder {
+                ExprBuilder::default()
+            }
+        }
+
+        impl ExprBuilder {
+            pub fn with_literal(mut self, value: i64) -> Self {
+                self.inner.literal = value;
+                self
+            }
+
+            pub fn with_left(mut self, value: Expr) -> Self {
+                self.inner.left = Some(value.into());
+                self
+            }
+
+            pub fn with_right(mut self, value: Expr) -> Self {
+                self.inner.right = Some(value.into());
+                self
+            }
+
+            pub fn with_negate(mut self, value: Expr) -> Self {
+                self.inner.unary = ExprUnary::Negate(value.into());
+                self
+            }
+
+            pub fn with_variable(mut self, value: String) -> Self {
+                self.inner.unary = ExprUnary::Variable(value);
+                self
+            }
+
+            pub fn with_args(mut self, value: Vec<Expr>) -> Self {
+                self.inner.args = value;
+                self
+            }
+
+            pub fn add_args(mut self, value: Expr) -> Self {
+                self.inner.args.push(value);
+                self
+            }
+
+            pub fn build(self) -> Expr {
+                self.inner
+            }
+        }
+
+        impl Expr {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.literal != 0 {
+                    out.write_scalar("literal", self.literal);
+                }
+                if let Some(elem) = &self.left {
+                    out.begin_message("left");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.right {
+                    out.begin_message("right");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if let ExprUnary::Negate(elem) = &self.unary {
+                    out.begin_message("negate");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if let ExprUnary::Variable(elem) = &self.unary {
+                    out.write_str("variable", elem);
+                }
+                for elem in &self.args {
+                    out.begin_message("args");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Expr {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Expr {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.literal != 0 {
+                    out.key("literal");
+                    out.write_i64(self.literal);
+                }
+                if let Some(elem) = &self.left {
+                    out.key("left");
+                    elem.write_json(out);
+                }
+                if let Some(elem) = &self.right {
+                    out.key("right");
+                    elem.write_json(out);
+                }
+                if let ExprUnary::Negate(elem) = &self.unary {
+                    out.key("negate");
+                    elem.write_json(out);
+                }
+                if let ExprUnary::Variable(elem) = &self.unary {
+                    out.key("variable");
+                    out.write_str(elem);
+                }
+                if !self.args.is_empty() {
+                    out.key("args");
+                    out.begin_array();
+                    for elem in &self.args {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "literal" => msg.literal = json_to_i64(value)?,
+                        "left" => msg.left = Some(Expr::from_json_value(value)?.into()),
+                        "right" => msg.right = Some(Expr::from_json_value(value)?.into()),
+                        "negate" => msg.unary = ExprUnary::Negate(Expr::from_json_value(value)?.into()),
+                        "variable" => msg.unary = ExprUnary::Variable(json_to_string(value)?),
+                        "args" => {
+                            for elem in json_array(value)? {
+                                msg.args.push(Expr::from_json_value(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct ExprRef<'a> {
+            raw: &'a [u8],
+            pub literal: i64,
+            pub left: Option<LazyRef<'a, ExprRef<'a>>>,
+            pub right: Option<LazyRef<'a, ExprRef<'a>>>,
+            pub negate: Option<LazyRef<'a, ExprRef<'a>>>,
+            pub variable: Option<&'a str>,
+            pub args: RepeatedRef<'a, ExprRef<'a>>,
+        }
+
+        impl<'a> ExprRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = ExprRef {
+                    raw: data,
+                    literal: 0,
+                    left: None,
+                    right: None,
+                    negate: None,
+                    variable: None,
+                    args: RepeatedRef::new(data, 50, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((ExprRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_int64(data, pos)?;
+                        view.literal = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.left = Some(LazyRef::new(v, ExprRef::decode));
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.right = Some(LazyRef::new(v, ExprRef::decode));
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.negate = Some(LazyRef::new(v, ExprRef::decode));
+                        view.variable = None;
+                        pos = new_pos;
+                    }
+                    42 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.variable = Some(v);
+                        view.negate = None;
+                        pos = new_pos;
+                    }
+                    50 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Expr::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Expr, DecodeError> {
+                Expr::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Exercises: a cycle through two messages, and a message outside it holding one
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Ping {
+            pub pong: Option<Box<Pong>>,
+            pub hops: u32,
+        }
+
+        impl Ping {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Ping {
+                pong: None,
+                hops: 0,
+            };
+        }
+
+        impl Default for Ping {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Ping {
+            pub const PONG_FIELD_NUMBER: u32 = 1;
+            pub const HOPS_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Ping.pong" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Ping.hops" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: hops
+                if self.hops != 0 {
+                    w.write_varint(self.hops as u64);
+                    w.write_key(0x10);
+                }
+
+                // field 1: pong
+                if let Some(elem) = &self.pong {
+                    w.write_message(1, &**elem);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.pong {
+                    len += key_len(1) + bytes_len(elem.encoded_len());
+                }
+                if self.hops != 0 {
+                    len += key_len(2) + varint_len(self.hops as u64);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.pong {
+                    w.write_message(1, &**elem)?;
+                }
+                if self.hops != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.hops as u64)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.pong {
+                    len += key_len(1) + bytes_len(sizes.record(&**elem));
+                }
+                if self.hops != 0 {
+                    len += key_len(2) + varint_len(self.hops as u64);
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.pong {
+                    w.write_message_sized(1, &**elem, sizes)?;
+                }
+                if self.hops != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.hops as u64)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.pong.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.hops = v as u32;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Ping {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Ping::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Ping::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Ping::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Ping::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Ping::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Ping::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Ping::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Ping {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Ping::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Ping> for Vec<u8> {
+            fn from(msg: Ping) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Ping {
+            const FULL_NAME: &'static str = "example.Ping";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct PingBuilder {
+            inner: Ping,
+        }
+
+        impl Ping {
+            pub fn builder() -> PingBuilder {
+                PingBuilder::default()
+            }
+        }
+
+        impl PingBuilder {
+            pub fn with_pong(mut self, value: Pong) -> Self {
+                self.inner.pong = Some(value.into());
+                self
+            }
+
+            pub fn with_hops(mut self, value: u32) -> Self {
+                self.inner.hops = value;
+                self
+            }
+
+            pub fn build(self) -> Ping {
+                self.inner
+            }
+        }
+
+        impl Ping {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if let Some(elem) = &self.pong {
+                    out.begin_message("pong");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if self.hops != 0 {
+                    out.write_scalar("hops", self.hops);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Ping {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Ping {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if let Some(elem) = &self.pong {
+                    out.key("pong");
+                    elem.write_json(out);
+                }
+                if self.hops != 0 {
+                    out.key("hops");
+                    out.write_u32(self.hops);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "pong" => msg.pong = Some(Pong::from_json_value(value)?.into()),
+                        "hops" => msg.hops = json_to_u32(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct PingRef<'a> {
+            raw: &'a [u8],
+            pub pong: Option<LazyRef<'a, PongRef<'a>>>,
+            pub hops: u32,
+        }
+
+        impl<'a> PingRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = PingRef {
+                    raw: data,
+                    pong: None,
+                    hops: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.pong = Some(LazyRef::new(v, PongRef::decode));
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.hops = v as u32;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Ping::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Ping, DecodeError> {
+                Ping::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Pong {
+            pub ping: Option<Box<Ping>>,
+        }
+
+        impl Pong {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Pong {
+                ping: None,
+            };
+        }
+
+        impl Default for Pong {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Pong {
+            pub const PING_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Pong.ping" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: ping
+                if let Some(elem) = &self.ping {
+                    w.write_message(1, &**elem);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.ping {
+                    len += key_len(1) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.ping {
+                    w.write_message(1, &**elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if let Some(elem) = &self.ping {
+                    len += key_len(1) + bytes_len(sizes.record(&**elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if let Some(elem) = &self.ping {
+                    w.write_message_sized(1, &**elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.ping.get_or_insert_with(Default::default).merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Pong {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Pong::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Pong::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Pong::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Pong::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Pong::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Pong::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Pong::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Pong {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Pong::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Pong> for Vec<u8> {
+            fn from(msg: Pong) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Pong {
+            const FULL_NAME: &'static str = "example.Pong";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct PongBuilder {
+            inner: Pong,
+        }
+
+        impl Pong {
+            pub fn builder() -> PongBuilder {
+                PongBuilder::default()
+            }
+        }
+
+        impl PongBuilder {
+            pub fn with_ping(mut self, value: Ping) -> Self {
+                self.inner.ping = Some(value.into());
+                self
+            }
+
+            pub fn build(self) -> Pong {
+                self.inner
+            }
+        }
+
+        impl Pong {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if let Some(elem) = &self.ping {
+                    out.begin_message("ping");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Pong {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Pong {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if let Some(elem) = &self.ping {
+                    out.key("ping");
+                    elem.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "ping" => msg.ping = Some(Ping::from_json_value(value)?.into()),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct PongRef<'a> {
+            raw: &'a [u8],
+            pub ping: Option<LazyRef<'a, PingRef<'a>>>,
+        }
+
+        impl<'a> PongRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = PongRef {
+                    raw: data,
+                    ping: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.ping = Some(LazyRef::new(v, PingRef::decode));
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Pong::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Pong, DecodeError> {
+                Pong::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Rally {
+            pub serve: Ping,
+            pub score: Expr,
+        }
+
+        impl Rally {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Rally {
+                serve: Ping::DEFAULT,
+                score: Expr::DEFAULT,
+            };
+        }
+
+        impl Default for Rally {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Rally {
+            pub const SERVE_FIELD_NUMBER: u32 = 1;
+            pub const SCORE_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Rally.serve" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Rally.score" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: score
+                w.write_message(2, &self.score);
+
+                // field 1: serve
+                w.write_message(1, &self.serve);
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                len += key_len(1) + bytes_len(self.serve.encoded_len());
+                len += key_len(2) + bytes_len(self.score.encoded_len());
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                w.write_message(1, &self.serve)?;
+                w.write_message(2, &self.score)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                len += key_len(1) + bytes_len(sizes.record(&self.serve));
+                len += key_len(2) + bytes_len(sizes.record(&self.score));
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                w.write_message_sized(1, &self.serve, sizes)?;
+                w.write_message_sized(2, &self.score, sizes)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.serve.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.score.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Rally {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Rally::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Rally::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Rally::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Rally::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Rally::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Rally::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Rally::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Rally {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Rally::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Rally> for Vec<u8> {
+            fn from(msg: Rally) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Rally {
+            const FULL_NAME: &'static str = "example.Rally";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct RallyBuilder {
+            inner: Rally,
+        }
+
+        impl Rally {
+            pub fn builder() -> RallyBuilder {
+                RallyBuilder::default()
+            }
+        }
+
+        impl RallyBuilder {
+            pub fn with_serve(mut self, value: Ping) -> Self {
+                self.inner.serve = value;
+                self
+            }
+
+            pub fn with_score(mut self, value: Expr) -> Self {
+                self.inner.score = value;
+                self
+            }
+
+            pub fn build(self) -> Rally {
+                self.inner
+            }
+        }
+
+        impl Rally {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.serve != Ping::default() {
+                    out.begin_message("serve");
+                    self.serve.write_text(out);
+                    out.end_message();
+                }
+                if self.score != Expr::default() {
+                    out.begin_message("score");
+                    self.score.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Rally {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Rally {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.serve != Ping::default() {
+                    out.key("serve");
+                    self.serve.write_json(out);
+                }
+                if self.score != Expr::default() {
+                    out.key("score");
+                    self.score.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "serve" => msg.serve = Ping::from_json_value(value)?,
+                        "score" => msg.score = Expr::from_json_value(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct RallyRef<'a> {
+            raw: &'a [u8],
+            pub serve: Option<PingRef<'a>>,
+            pub score: Option<ExprRef<'a>>,
+        }
+
+        impl<'a> RallyRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = RallyRef {
+                    raw: data,
+                    serve: None,
+                    score: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.serve = Some(PingRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.score = Some(ExprRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Rally::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Rally, DecodeError> {
+                Rally::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            TreeNode(TreeNode),
+            Expr(Expr),
+            Ping(Ping),
+            Pong(Pong),
+            Rally(Rally),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    TreeNode::FULL_NAME => AnyMessage::TreeNode(TreeNode::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Expr::FULL_NAME => AnyMessage::Expr(Expr::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Ping::FULL_NAME => AnyMessage::Ping(Ping::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Pong::FULL_NAME => AnyMessage::Pong(Pong::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Rally::FULL_NAME => AnyMessage::Rally(Rally::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::TreeNode(msg) => Any::pack(msg),
+                    AnyMessage::Expr(msg) => Any::pack(msg),
+                    AnyMessage::Ping(msg) => Any::pack(msg),
+                    AnyMessage::Pong(msg) => Any::pack(msg),
+                    AnyMessage::Rally(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
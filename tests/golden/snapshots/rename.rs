@@ -0,0 +1,1229 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod upstream {
+    pub mod rename {
+        // Auto-generated by protoc-gen-solana from rename.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: the overrides file (rename.overrides.json) renaming an enum,
+        /// a message, fields named after Rust keywords and a oneof, and making a
+        /// message, a field and an enum pub(crate), without touching this file
+        ///
+        /// `upstream.Type`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum OrderType {
+            Unspecified = 0,
+            Limit = 1,
+            Market = 2,
+        }
+
+        impl OrderType {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: OrderType = OrderType::Unspecified;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    OrderType::Unspecified => "TYPE_UNSPECIFIED",
+                    OrderType::Limit => "TYPE_LIMIT",
+                    OrderType::Market => "TYPE_MARKET",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "TYPE_UNSPECIFIED" => Some(OrderType::Unspecified),
+                    "TYPE_LIMIT" => Some(OrderType::Limit),
+                    "TYPE_MARKET" => Some(OrderType::Market),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for OrderType {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<OrderType> for i32 {
+            fn from(value: OrderType) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for OrderType {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(OrderType::Unspecified),
+                    1 => Ok(OrderType::Limit),
+                    2 => Ok(OrderType::Market),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        /// `upstream.Scope`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub(crate) enum Scope {
+            Unspecified = 0,
+            Local = 1,
+        }
+
+        impl Scope {
+            /// The proto3 default, numbered 0, for `const` and `static` items.
+            pub const DEFAULT: Scope = Scope::Unspecified;
+
+            /// The value's name as declared in the .proto file.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Scope::Unspecified => "SCOPE_UNSPECIFIED",
+                    Scope::Local => "SCOPE_LOCAL",
+                }
+            }
+
+            pub fn from_str_name(name: &str) -> Option<Self> {
+                match name {
+                    "SCOPE_UNSPECIFIED" => Some(Scope::Unspecified),
+                    "SCOPE_LOCAL" => Some(Scope::Local),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Default for Scope {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl From<Scope> for i32 {
+            fn from(value: Scope) -> i32 {
+                value as i32
+            }
+        }
+
+        /// A number this build does not know is handed back as the error.
+        impl TryFrom<i32> for Scope {
+            type Error = i32;
+
+            fn try_from(value: i32) -> Result<Self, i32> {
+                match value {
+                    0 => Ok(Scope::Unspecified),
+                    1 => Ok(Scope::Local),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum OrderExecution {
+            Unset,
+            Price(u64),
+            Best(bool),
+        }
+
+        impl OrderExecution {
+            pub const DEFAULT: Self = OrderExecution::Unset;
+        }
+
+        impl Default for OrderExecution {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Order {
+            pub id: u64,
+            pub kind: i32,
+            pub reference: String,
+            pub(crate) internal_note: Vec<u8>,
+            pub execution: OrderExecution,
+            pub(crate) cache: OrderCache,
+        }
+
+        impl Order {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Order {
+                id: 0,
+                kind: 0,
+                reference: String::new(),
+                internal_note: Vec::new(),
+                execution: OrderExecution::Unset,
+                cache: OrderCache::DEFAULT,
+            };
+        }
+
+        impl Default for Order {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Order {
+            pub const ID_FIELD_NUMBER: u32 = 1;
+            pub const KIND_FIELD_NUMBER: u32 = 2;
+            pub const REFERENCE_FIELD_NUMBER: u32 = 3;
+            pub const INTERNAL_NOTE_FIELD_NUMBER: u32 = 4;
+            pub const PRICE_FIELD_NUMBER: u32 = 5;
+            pub const BEST_FIELD_NUMBER: u32 = 6;
+            pub const CACHE_FIELD_NUMBER: u32 = 7;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Order.id" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Order.type" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Order.ref" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Order.internal_note" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "Order.price" },
+                FieldWireType { number: 6, wire_type: 0, packed: false, name: "Order.best" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "Order.cache" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 7: cache
+                w.write_message(7, &self.cache);
+
+                // field 6: best
+                if let OrderExecution::Best(elem) = &self.execution {
+                    w.write_bool(*elem);
+                    w.write_key(0x30);
+                }
+
+                // field 5: price
+                if let OrderExecution::Price(elem) = &self.execution {
+                    w.write_varint(*elem);
+                    w.write_key(0x28);
+                }
+
+                // field 4: internal_note
+                if !self.internal_note.is_empty() {
+                    w.write_bytes(&self.internal_note);
+                    w.write_key(0x22);
+                }
+
+                // field 3: ref
+                if !self.reference.is_empty() {
+                    w.write_string(&self.reference);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: type
+                if self.kind != 0 {
+                    w.write_int32(self.kind);
+                    w.write_key(0x10);
+                }
+
+                // field 1: id
+                if self.id != 0 {
+                    w.write_varint(self.id);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                if self.kind != 0 {
+                    len += key_len(2) + int32_len(self.kind);
+                }
+                if !self.reference.is_empty() {
+                    len += key_len(3) + bytes_len(self.reference.len());
+                }
+                if !self.internal_note.is_empty() {
+                    len += key_len(4) + bytes_len(self.internal_note.len());
+                }
+                if let OrderExecution::Price(elem) = &self.execution {
+                    len += key_len(5) + varint_len(*elem);
+                }
+                if let OrderExecution::Best(_) = &self.execution {
+                    len += key_len(6) + 1;
+                }
+                len += key_len(7) + bytes_len(self.cache.encoded_len());
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                if self.kind != 0 {
+                    w.write_key(0x10)?;
+                    w.write_int32(self.kind)?;
+                }
+                if !self.reference.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.reference)?;
+                }
+                if !self.internal_note.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_bytes(&self.internal_note)?;
+                }
+                if let OrderExecution::Price(elem) = &self.execution {
+                    w.write_key(0x28)?;
+                    w.write_varint(*elem)?;
+                }
+                if let OrderExecution::Best(elem) = &self.execution {
+                    w.write_key(0x30)?;
+                    w.write_bool(*elem)?;
+                }
+                w.write_message(7, &self.cache)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                if self.kind != 0 {
+                    len += key_len(2) + int32_len(self.kind);
+                }
+                if !self.reference.is_empty() {
+                    len += key_len(3) + bytes_len(self.reference.len());
+                }
+                if !self.internal_note.is_empty() {
+                    len += key_len(4) + bytes_len(self.internal_note.len());
+                }
+                if let OrderExecution::Price(elem) = &self.execution {
+                    len += key_len(5) + varint_len(*elem);
+                }
+                if let OrderExecution::Best(_) = &self.execution {
+                    len += key_len(6) + 1;
+                }
+                len += key_len(7) + bytes_len(sizes.record(&self.cache));
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                if self.kind != 0 {
+                    w.write_key(0x10)?;
+                    w.write_int32(self.kind)?;
+                }
+                if !self.reference.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.reference)?;
+                }
+                if !self.internal_note.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_bytes(&self.internal_note)?;
+                }
+                if let OrderExecution::Price(elem) = &self.execution {
+                    w.write_key(0x28)?;
+                    w.write_varint(*elem)?;
+                }
+                if let OrderExecution::Best(elem) = &self.execution {
+                    w.write_key(0x30)?;
+                    w.write_bool(*elem)?;
+                }
+                w.write_message_sized(7, &self.cache, sizes)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.id = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.kind = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.reference = v;
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.internal_note = v;
+                            pos = new_pos;
+                        }
+                        40 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.execution = OrderExecution::Price(v);
+                            pos = new_pos;
+                        }
+                        48 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_bool(data, pos)?;
+                            self.execution = OrderExecution::Best(v);
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.cache.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Order {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Order::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Order::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Order::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Order::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Order::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Order::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Order::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Order {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Order::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Order> for Vec<u8> {
+            fn from(msg: Order) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Order {
+            const FULL_NAME: &'static str = "upstream.Order";
+        }
+
+        impl Order {
+            pub fn price(&self) -> Option<&u64> {
+                match &self.execution {
+                    OrderExecution::Price(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_price(&mut self, value: u64) {
+                self.execution = OrderExecution::Price(value);
+            }
+
+            pub fn best(&self) -> Option<&bool> {
+                match &self.execution {
+                    OrderExecution::Best(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_best(&mut self, value: bool) {
+                self.execution = OrderExecution::Best(value);
+            }
+        }
+
+        impl Order {
+            pub fn kind(&self) -> Result<OrderType, i32> {
+                OrderType::try_from(self.kind)
+            }
+
+            pub fn set_kind(&mut self, value: OrderType) {
+                self.kind = value.into();
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct OrderBuilder {
+            inner: Order,
+        }
+
+        impl Order {
+            pub fn builder() -> OrderBuilder {
+                OrderBuilder::default()
+            }
+        }
+
+        impl OrderBuilder {
+            pub fn with_id(mut self, value: u64) -> Self {
+                self.inner.id = value;
+                self
+            }
+
+            pub fn with_kind(mut self, value: i32) -> Self {
+                self.inner.kind = value;
+                self
+            }
+
+            pub fn with_reference(mut self, value: String) -> Self {
+                self.inner.reference = value;
+                self
+            }
+
+            pub(crate) fn with_internal_note(mut self, value: Vec<u8>) -> Self {
+                self.inner.internal_note = value;
+                self
+            }
+
+            pub fn with_price(mut self, value: u64) -> Self {
+                self.inner.execution = OrderExecution::Price(value);
+                self
+            }
+
+            pub fn with_best(mut self, value: bool) -> Self {
+                self.inner.execution = OrderExecution::Best(value);
+                self
+            }
+
+            pub(crate) fn with_cache(mut self, value: OrderCache) -> Self {
+                self.inner.cache = value;
+                self
+            }
+
+            pub fn build(self) -> Order {
+                self.inner
+            }
+        }
+
+        impl Order {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.id != 0 {
+                    out.write_scalar("id", self.id);
+                }
+                if self.kind != 0 {
+                    out.write_enum("type", self.kind, &[("TYPE_UNSPECIFIED", 0), ("TYPE_LIMIT", 1), ("TYPE_MARKET", 2)]);
+                }
+                if !self.reference.is_empty() {
+                    out.write_str("ref", &self.reference);
+                }
+                if !self.internal_note.is_empty() {
+                    out.write_bytes("internal_note", &self.internal_note);
+                }
+                if let OrderExecution::Price(elem) = &self.execution {
+                    out.write_scalar("price", *elem);
+                }
+                if let OrderExecution::Best(elem) = &self.execution {
+                    out.write_scalar("best", *elem);
+                }
+                if self.cache != OrderCache::default() {
+                    out.begin_message("cache");
+                    self.cache.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Order {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Order {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.id != 0 {
+                    out.key("id");
+                    out.write_u64(self.id);
+                }
+                if self.kind != 0 {
+                    out.key("type");
+                    out.write_enum(self.kind, &[("TYPE_UNSPECIFIED", 0), ("TYPE_LIMIT", 1), ("TYPE_MARKET", 2)]);
+                }
+                if !self.reference.is_empty() {
+                    out.key("ref");
+                    out.write_str(&self.reference);
+                }
+                if !self.internal_note.is_empty() {
+                    out.key("internalNote");
+                    out.write_bytes(&self.internal_note);
+                }
+                if let OrderExecution::Price(elem) = &self.execution {
+                    out.key("price");
+                    out.write_u64(*elem);
+                }
+                if let OrderExecution::Best(elem) = &self.execution {
+                    out.key("best");
+                    out.write_bool(*elem);
+                }
+                if self.cache != OrderCache::default() {
+                    out.key("cache");
+                    self.cache.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "id" => msg.id = json_to_u64(value)?,
+                        "type" => msg.kind = json_to_enum(value, &[("TYPE_UNSPECIFIED", 0), ("TYPE_LIMIT", 1), ("TYPE_MARKET", 2)])?,
+                        "ref" => msg.reference = json_to_string(value)?,
+                        "internalNote" | "internal_note" => msg.internal_note = json_to_bytes(value)?,
+                        "price" => msg.execution = OrderExecution::Price(json_to_u64(value)?),
+                        "best" => msg.execution = OrderExecution::Best(json_to_bool(value)?),
+                        "cache" => msg.cache = OrderCache::from_json_value(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct OrderRef<'a> {
+            raw: &'a [u8],
+            pub id: u64,
+            pub kind: i32,
+            pub reference: &'a str,
+            pub(crate) internal_note: &'a [u8],
+            pub price: Option<u64>,
+            pub best: Option<bool>,
+            pub(crate) cache: Option<OrderCacheRef<'a>>,
+        }
+
+        impl<'a> OrderRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = OrderRef {
+                    raw: data,
+                    id: 0,
+                    kind: 0,
+                    reference: "",
+                    internal_note: &[],
+                    price: None,
+                    best: None,
+                    cache: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.id = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.kind = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.reference = v;
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.internal_note = v;
+                        pos = new_pos;
+                    }
+                    40 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.price = Some(v);
+                        view.best = None;
+                        pos = new_pos;
+                    }
+                    48 => {
+                        let (v, new_pos) = decode_bool(data, pos)?;
+                        view.best = Some(v);
+                        view.price = None;
+                        pos = new_pos;
+                    }
+                    58 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.cache = Some(OrderCacheRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Order::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Order, DecodeError> {
+                Order::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub(crate) struct OrderCache {
+            pub slot: u64,
+            pub scope: i32,
+        }
+
+        impl OrderCache {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = OrderCache {
+                slot: 0,
+                scope: 0,
+            };
+        }
+
+        impl Default for OrderCache {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl OrderCache {
+            pub const SLOT_FIELD_NUMBER: u32 = 1;
+            pub const SCOPE_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "OrderCache.slot" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "OrderCache.scope" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: scope
+                if self.scope != 0 {
+                    w.write_int32(self.scope);
+                    w.write_key(0x10);
+                }
+
+                // field 1: slot
+                if self.slot != 0 {
+                    w.write_varint(self.slot);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.slot != 0 {
+                    len += key_len(1) + varint_len(self.slot);
+                }
+                if self.scope != 0 {
+                    len += key_len(2) + int32_len(self.scope);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.slot != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.slot)?;
+                }
+                if self.scope != 0 {
+                    w.write_key(0x10)?;
+                    w.write_int32(self.scope)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.slot = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_int32(data, pos)?;
+                            self.scope = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for OrderCache {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                OrderCache::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                OrderCache::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                OrderCache::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                OrderCache::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                OrderCache::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                OrderCache::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                OrderCache::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for OrderCache {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                OrderCache::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<OrderCache> for Vec<u8> {
+            fn from(msg: OrderCache) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for OrderCache {
+            const FULL_NAME: &'static str = "upstream.Cache";
+        }
+
+        impl OrderCache {
+            /// Longest encoding of a `OrderCache` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 22;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        impl OrderCache {
+            pub fn scope(&self) -> Result<Scope, i32> {
+                Scope::try_from(self.scope)
+            }
+
+            pub fn set_scope(&mut self, value: Scope) {
+                self.scope = value.into();
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub(crate) struct OrderCacheBuilder {
+            inner: OrderCache,
+        }
+
+        impl OrderCache {
+            pub fn builder() -> OrderCacheBuilder {
+                OrderCacheBuilder::default()
+            }
+        }
+
+        impl OrderCacheBuilder {
+            pub fn with_slot(mut self, value: u64) -> Self {
+                self.inner.slot = value;
+                self
+            }
+
+            pub fn with_scope(mut self, value: i32) -> Self {
+                self.inner.scope = value;
+                self
+            }
+
+            pub fn build(self) -> OrderCache {
+                self.inner
+            }
+        }
+
+        impl OrderCache {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.slot != 0 {
+                    out.write_scalar("slot", self.slot);
+                }
+                if self.scope != 0 {
+                    out.write_enum("scope", self.scope, &[("SCOPE_UNSPECIFIED", 0), ("SCOPE_LOCAL", 1)]);
+                }
+            }
+        }
+
+        impl core::fmt::Display for OrderCache {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl OrderCache {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.slot != 0 {
+                    out.key("slot");
+                    out.write_u64(self.slot);
+                }
+                if self.scope != 0 {
+                    out.key("scope");
+                    out.write_enum(self.scope, &[("SCOPE_UNSPECIFIED", 0), ("SCOPE_LOCAL", 1)]);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "slot" => msg.slot = json_to_u64(value)?,
+                        "scope" => msg.scope = json_to_enum(value, &[("SCOPE_UNSPECIFIED", 0), ("SCOPE_LOCAL", 1)])?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub(crate) struct OrderCacheRef<'a> {
+            raw: &'a [u8],
+            pub slot: u64,
+            pub scope: i32,
+        }
+
+        impl<'a> OrderCacheRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = OrderCacheRef {
+                    raw: data,
+                    slot: 0,
+                    scope: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.slot = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_int32(data, pos)?;
+                        view.scope = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(OrderCache::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            #[allow(clippy::wrong_self_convention)]
+            pub fn to_message(&self) -> Result<OrderCache, DecodeError> {
+                OrderCache::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Order(Order),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Order::FULL_NAME => AnyMessage::Order(Order::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Order(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
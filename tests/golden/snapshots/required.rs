@@ -0,0 +1,1297 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod required {
+        // Auto-generated by protoc-gen-solana from required.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum WithdrawRoute {
+            Unset,
+            Hops(u32),
+            Relay(String),
+        }
+
+        impl WithdrawRoute {
+            pub const DEFAULT: Self = WithdrawRoute::Unset;
+        }
+
+        impl Default for WithdrawRoute {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: (solana.required) fields failing decode with
+        /// DecodeError::MissingField when absent, even at their default value
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Withdraw {
+            pub authority: Pubkey,
+            pub amount: u64,
+            pub memo: String,
+            pub slot: Option<u32>,
+            pub fee: Option<u64>,
+            pub target: WithdrawTarget,
+            /// Ignored with a warning: repeated fields and oneof members
+            pub tags: Vec<u64>,
+            pub route: WithdrawRoute,
+        }
+
+        impl Withdraw {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Withdraw {
+                authority: ZERO_PUBKEY,
+                amount: 0,
+                memo: String::new(),
+                slot: None,
+                fee: None,
+                target: WithdrawTarget::DEFAULT,
+                tags: Vec::new(),
+                route: WithdrawRoute::Unset,
+            };
+        }
+
+        impl Default for Withdraw {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Withdraw {
+            pub const AUTHORITY_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+            pub const MEMO_FIELD_NUMBER: u32 = 3;
+            pub const SLOT_FIELD_NUMBER: u32 = 4;
+            pub const FEE_FIELD_NUMBER: u32 = 5;
+            pub const TARGET_FIELD_NUMBER: u32 = 6;
+            pub const TAGS_FIELD_NUMBER: u32 = 7;
+            pub const HOPS_FIELD_NUMBER: u32 = 8;
+            pub const RELAY_FIELD_NUMBER: u32 = 9;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Withdraw.authority" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Withdraw.amount" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Withdraw.memo" },
+                FieldWireType { number: 4, wire_type: 0, packed: false, name: "Withdraw.slot" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "Withdraw.fee" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Withdraw.target" },
+                FieldWireType { number: 7, wire_type: 0, packed: true, name: "Withdraw.tags" },
+                FieldWireType { number: 8, wire_type: 0, packed: false, name: "Withdraw.hops" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Withdraw.relay" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 9: relay
+                if let WithdrawRoute::Relay(elem) = &self.route {
+                    w.write_string(elem);
+                    w.write_key(0x4a);
+                }
+
+                // field 8: hops
+                if let WithdrawRoute::Hops(elem) = &self.route {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x40);
+                }
+
+                // field 7: tags
+                if !self.tags.is_empty() {
+                    w.write_nested(7, |w| {
+                        for elem in self.tags.iter().rev() {
+                            w.write_varint(*elem);
+                        }
+                    });
+                }
+
+                // field 6: target
+                w.write_message(6, &self.target);
+
+                // field 5: fee
+                if let Some(elem) = &self.fee {
+                    w.write_nested(5, |w| UInt64Value::write_value_reverse(elem, w));
+                }
+
+                // field 4: slot
+                if let Some(elem) = &self.slot {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x20);
+                }
+
+                // field 3: memo
+                if !self.memo.is_empty() {
+                    w.write_string(&self.memo);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: authority
+                if self.authority != Pubkey::default() {
+                    w.write_pubkey(&self.authority);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    len += key_len(3) + bytes_len(self.memo.len());
+                }
+                if let Some(elem) = &self.slot {
+                    len += key_len(4) + varint_len(*elem as u64);
+                }
+                if let Some(elem) = &self.fee {
+                    len += key_len(5) + bytes_len(UInt64Value::value_len(elem));
+                }
+                len += key_len(6) + bytes_len(self.target.encoded_len());
+                if !self.tags.is_empty() {
+                    len += key_len(7) + bytes_len(self.tags.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                if let WithdrawRoute::Hops(elem) = &self.route {
+                    len += key_len(8) + varint_len(*elem as u64);
+                }
+                if let WithdrawRoute::Relay(elem) = &self.route {
+                    len += key_len(9) + bytes_len(elem.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                if !self.memo.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.memo)?;
+                }
+                if let Some(elem) = &self.slot {
+                    w.write_key(0x20)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if let Some(elem) = &self.fee {
+                    w.write_key(0x2a)?;
+                    w.write_varint(UInt64Value::value_len(elem) as u64)?;
+                    UInt64Value::write_value(elem, w)?;
+                }
+                w.write_message(6, &self.target)?;
+                if !self.tags.is_empty() {
+                    w.write_key(0x3a)?;
+                    w.write_varint(self.tags.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.tags {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                if let WithdrawRoute::Hops(elem) = &self.route {
+                    w.write_key(0x40)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if let WithdrawRoute::Relay(elem) = &self.route {
+                    w.write_key(0x4a)?;
+                    w.write_string(elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    len += key_len(3) + bytes_len(self.memo.len());
+                }
+                if let Some(elem) = &self.slot {
+                    len += key_len(4) + varint_len(*elem as u64);
+                }
+                if let Some(elem) = &self.fee {
+                    len += key_len(5) + bytes_len(UInt64Value::value_len(elem));
+                }
+                len += key_len(6) + bytes_len(sizes.record(&self.target));
+                if !self.tags.is_empty() {
+                    len += key_len(7) + bytes_len(self.tags.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                if let WithdrawRoute::Hops(elem) = &self.route {
+                    len += key_len(8) + varint_len(*elem as u64);
+                }
+                if let WithdrawRoute::Relay(elem) = &self.route {
+                    len += key_len(9) + bytes_len(elem.len());
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                if !self.memo.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.memo)?;
+                }
+                if let Some(elem) = &self.slot {
+                    w.write_key(0x20)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if let Some(elem) = &self.fee {
+                    w.write_key(0x2a)?;
+                    w.write_varint(UInt64Value::value_len(elem) as u64)?;
+                    UInt64Value::write_value(elem, w)?;
+                }
+                w.write_message_sized(6, &self.target, sizes)?;
+                if !self.tags.is_empty() {
+                    w.write_key(0x3a)?;
+                    w.write_varint(self.tags.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.tags {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                if let WithdrawRoute::Hops(elem) = &self.route {
+                    w.write_key(0x40)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if let WithdrawRoute::Relay(elem) = &self.route {
+                    w.write_key(0x4a)?;
+                    w.write_string(elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.authority = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.memo = v;
+                            pos = new_pos;
+                        }
+                        32 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.slot = Some(v as u32);
+                            pos = new_pos;
+                        }
+                        42 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.fee.get_or_insert_with(Default::default);
+                            UInt64Value::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        50 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.target.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        56 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.tags.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.tags.push(v);
+                            pos = new_pos;
+                        }
+                        58 => {
+                            let new_pos = decode_packed_varint_with(data, pos, &mut self.tags, opts)?;
+                            pos = new_pos;
+                        }
+                        64 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.route = WithdrawRoute::Hops(v as u32);
+                            pos = new_pos;
+                        }
+                        74 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.route = WithdrawRoute::Relay(v);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                if is_selected(only, 1) {
+                    check_required(&seen, 0, 1)?;
+                }
+                if is_selected(only, 2) {
+                    check_required(&seen, 1, 2)?;
+                }
+                if is_selected(only, 4) {
+                    check_required(&seen, 3, 4)?;
+                }
+                if is_selected(only, 5) {
+                    check_required(&seen, 4, 5)?;
+                }
+                if is_selected(only, 6) {
+                    check_required(&seen, 5, 6)?;
+                }
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    7 => Some(self.tags.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Withdraw {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Withdraw::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Withdraw::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Withdraw::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Withdraw::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Withdraw::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Withdraw::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Withdraw::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Withdraw {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Withdraw::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Withdraw> for Vec<u8> {
+            fn from(msg: Withdraw) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Withdraw {
+            const FULL_NAME: &'static str = "example.Withdraw";
+        }
+
+        impl Withdraw {
+            pub fn hops(&self) -> Option<&u32> {
+                match &self.route {
+                    WithdrawRoute::Hops(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_hops(&mut self, value: u32) {
+                self.route = WithdrawRoute::Hops(value);
+            }
+
+            pub fn relay(&self) -> Option<&String> {
+                match &self.route {
+                    WithdrawRoute::Relay(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_relay(&mut self, value: String) {
+                self.route = WithdrawRoute::Relay(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawBuilder<F1 = Unset, F2 = Unset, F4 = Unset, F5 = Unset, F6 = Unset> {
+            inner: Withdraw,
+            required: core::marker::PhantomData<(F1, F2, F4, F5, F6)>,
+        }
+
+        impl Withdraw {
+            pub fn builder() -> WithdrawBuilder {
+                WithdrawBuilder::default()
+            }
+        }
+
+        impl<F1, F2, F4, F5, F6> WithdrawBuilder<F1, F2, F4, F5, F6> {
+            pub fn with_authority(self, value: Pubkey) -> WithdrawBuilder<Set, F2, F4, F5, F6> {
+                let mut inner = self.inner;
+                inner.authority = value;
+                WithdrawBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_amount(self, value: u64) -> WithdrawBuilder<F1, Set, F4, F5, F6> {
+                let mut inner = self.inner;
+                inner.amount = value;
+                WithdrawBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_memo(mut self, value: String) -> Self {
+                self.inner.memo = value;
+                self
+            }
+
+            pub fn with_slot(self, value: u32) -> WithdrawBuilder<F1, F2, Set, F5, F6> {
+                let mut inner = self.inner;
+                inner.slot = Some(value);
+                WithdrawBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_fee(self, value: u64) -> WithdrawBuilder<F1, F2, F4, Set, F6> {
+                let mut inner = self.inner;
+                inner.fee = Some(value);
+                WithdrawBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_target(self, value: WithdrawTarget) -> WithdrawBuilder<F1, F2, F4, F5, Set> {
+                let mut inner = self.inner;
+                inner.target = value;
+                WithdrawBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_tags(mut self, value: Vec<u64>) -> Self {
+                self.inner.tags = value;
+                self
+            }
+
+            pub fn add_tags(mut self, value: u64) -> Self {
+                self.inner.tags.push(value);
+                self
+            }
+
+            pub fn with_hops(mut self, value: u32) -> Self {
+                self.inner.route = WithdrawRoute::Hops(value);
+                self
+            }
+
+            pub fn with_relay(mut self, value: String) -> Self {
+                self.inner.route = WithdrawRoute::Relay(value);
+                self
+            }
+        }
+
+        impl WithdrawBuilder<Set, Set, Set, Set, Set> {
+            pub fn build(self) -> Withdraw {
+                self.inner
+            }
+        }
+
+        impl Withdraw {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.authority != Pubkey::default() {
+                    out.write_pubkey("authority", &self.authority);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+                if !self.memo.is_empty() {
+                    out.write_str("memo", &self.memo);
+                }
+                if let Some(elem) = &self.slot {
+                    out.write_scalar("slot", *elem);
+                }
+                if let Some(elem) = &self.fee {
+                    out.begin_message("fee");
+                    UInt64Value::write_text_value(elem, out);
+                    out.end_message();
+                }
+                if self.target != WithdrawTarget::default() {
+                    out.begin_message("target");
+                    self.target.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.tags {
+                    out.write_scalar("tags", *elem);
+                }
+                if let WithdrawRoute::Hops(elem) = &self.route {
+                    out.write_scalar("hops", *elem);
+                }
+                if let WithdrawRoute::Relay(elem) = &self.route {
+                    out.write_str("relay", elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Withdraw {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Withdraw {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.authority != Pubkey::default() {
+                    out.key("authority");
+                    out.write_pubkey(self.authority);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    out.key("memo");
+                    out.write_str(&self.memo);
+                }
+                if let Some(elem) = &self.slot {
+                    out.key("slot");
+                    out.write_u32(*elem);
+                }
+                if let Some(elem) = &self.fee {
+                    out.key("fee");
+                    out.write_u64(*elem);
+                }
+                if self.target != WithdrawTarget::default() {
+                    out.key("target");
+                    self.target.write_json(out);
+                }
+                if !self.tags.is_empty() {
+                    out.key("tags");
+                    out.begin_array();
+                    for elem in &self.tags {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                if let WithdrawRoute::Hops(elem) = &self.route {
+                    out.key("hops");
+                    out.write_u32(*elem);
+                }
+                if let WithdrawRoute::Relay(elem) = &self.route {
+                    out.key("relay");
+                    out.write_str(elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "authority" => msg.authority = json_to_pubkey(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        "memo" => msg.memo = json_to_string(value)?,
+                        "slot" => msg.slot = Some(json_to_u32(value)?),
+                        "fee" => msg.fee = Some(json_to_u64(value)?),
+                        "target" => msg.target = WithdrawTarget::from_json_value(value)?,
+                        "tags" => {
+                            for elem in json_array(value)? {
+                                msg.tags.push(json_to_u64(elem)?);
+                            }
+                        }
+                        "hops" => msg.route = WithdrawRoute::Hops(json_to_u32(value)?),
+                        "relay" => msg.route = WithdrawRoute::Relay(json_to_string(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawRef<'a> {
+            raw: &'a [u8],
+            pub authority: Pubkey,
+            pub amount: u64,
+            pub memo: &'a str,
+            pub slot: Option<u32>,
+            pub fee: Option<u64>,
+            pub target: Option<WithdrawTargetRef<'a>>,
+            pub tags: RepeatedRef<'a, u64>,
+            pub hops: Option<u32>,
+            pub relay: Option<&'a str>,
+        }
+
+        impl<'a> WithdrawRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawRef {
+                    raw: data,
+                    authority: ZERO_PUBKEY,
+                    amount: 0,
+                    memo: "",
+                    slot: None,
+                    fee: None,
+                    target: None,
+                    tags: RepeatedRef::packed(data, 56, decode_varint),
+                    hops: None,
+                    relay: None,
+                };
+
+                let mut has_authority = false;
+                let mut has_amount = false;
+                let mut has_slot = false;
+                let mut has_fee = false;
+                let mut has_target = false;
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        has_authority = true;
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.authority = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        has_amount = true;
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.memo = v;
+                        pos = new_pos;
+                    }
+                    32 => {
+                        has_slot = true;
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.slot = Some(v as u32);
+                        pos = new_pos;
+                    }
+                    42 => {
+                        has_fee = true;
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.fee = Some(wrapper_value_ref(v, 8, decode_varint)?);
+                        pos = new_pos;
+                    }
+                    50 => {
+                        has_target = true;
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.target = Some(WithdrawTargetRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    56 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    64 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.hops = Some(v as u32);
+                        view.relay = None;
+                        pos = new_pos;
+                    }
+                    74 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.relay = Some(v);
+                        view.hops = None;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Withdraw::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                if !has_authority {
+                    return Err(DecodeError::MissingField { field: 1 });
+                }
+                if !has_amount {
+                    return Err(DecodeError::MissingField { field: 2 });
+                }
+                if !has_slot {
+                    return Err(DecodeError::MissingField { field: 4 });
+                }
+                if !has_fee {
+                    return Err(DecodeError::MissingField { field: 5 });
+                }
+                if !has_target {
+                    return Err(DecodeError::MissingField { field: 6 });
+                }
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Withdraw, DecodeError> {
+                Withdraw::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Checked when decoded as a sub-message too
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct WithdrawTarget {
+            pub vault: [u8; 32],
+            pub index: u32,
+        }
+
+        impl WithdrawTarget {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = WithdrawTarget {
+                vault: [0; 32],
+                index: 0,
+            };
+        }
+
+        impl Default for WithdrawTarget {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl WithdrawTarget {
+            pub const VAULT_FIELD_NUMBER: u32 = 1;
+            pub const INDEX_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "WithdrawTarget.vault" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "WithdrawTarget.index" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: index
+                if self.index != 0 {
+                    w.write_varint(self.index as u64);
+                    w.write_key(0x10);
+                }
+
+                // field 1: vault
+                if self.vault != [0; 32] {
+                    w.write_bytes(&self.vault);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.vault != [0; 32] {
+                    len += key_len(1) + bytes_len(self.vault.len());
+                }
+                if self.index != 0 {
+                    len += key_len(2) + varint_len(self.index as u64);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.vault != [0; 32] {
+                    w.write_key(0x0a)?;
+                    w.write_bytes(&self.vault)?;
+                }
+                if self.index != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.index as u64)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_fixed_bytes::<32>(data, pos)?;
+                            self.vault = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.index = v as u32;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                if is_selected(only, 1) {
+                    check_required(&seen, 0, 1)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl Message for WithdrawTarget {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                WithdrawTarget::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                WithdrawTarget::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                WithdrawTarget::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                WithdrawTarget::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                WithdrawTarget::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                WithdrawTarget::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                WithdrawTarget::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for WithdrawTarget {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                WithdrawTarget::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<WithdrawTarget> for Vec<u8> {
+            fn from(msg: WithdrawTarget) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for WithdrawTarget {
+            const FULL_NAME: &'static str = "example.WithdrawTarget";
+        }
+
+        impl WithdrawTarget {
+            /// Longest encoding of a `WithdrawTarget` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 40;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawTargetBuilder<F1 = Unset> {
+            inner: WithdrawTarget,
+            required: core::marker::PhantomData<F1>,
+        }
+
+        impl WithdrawTarget {
+            pub fn builder() -> WithdrawTargetBuilder {
+                WithdrawTargetBuilder::default()
+            }
+        }
+
+        impl<F1> WithdrawTargetBuilder<F1> {
+            pub fn with_vault(self, value: [u8; 32]) -> WithdrawTargetBuilder<Set> {
+                let mut inner = self.inner;
+                inner.vault = value;
+                WithdrawTargetBuilder { inner, required: core::marker::PhantomData }
+            }
+
+            pub fn with_index(mut self, value: u32) -> Self {
+                self.inner.index = value;
+                self
+            }
+        }
+
+        impl WithdrawTargetBuilder<Set> {
+            pub fn build(self) -> WithdrawTarget {
+                self.inner
+            }
+        }
+
+        impl WithdrawTarget {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.vault != [0; 32] {
+                    out.write_bytes("vault", &self.vault);
+                }
+                if self.index != 0 {
+                    out.write_scalar("index", self.index);
+                }
+            }
+        }
+
+        impl core::fmt::Display for WithdrawTarget {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl WithdrawTarget {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.vault != [0; 32] {
+                    out.key("vault");
+                    out.write_bytes(&self.vault);
+                }
+                if self.index != 0 {
+                    out.key("index");
+                    out.write_u32(self.index);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "vault" => msg.vault = json_to_fixed_bytes::<32>(value)?,
+                        "index" => msg.index = json_to_u32(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawTargetRef<'a> {
+            raw: &'a [u8],
+            pub vault: &'a [u8; 32],
+            pub index: u32,
+        }
+
+        impl<'a> WithdrawTargetRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawTargetRef {
+                    raw: data,
+                    vault: &[0; 32],
+                    index: 0,
+                };
+
+                let mut has_vault = false;
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        has_vault = true;
+                        let (v, new_pos) = decode_fixed_bytes_ref::<32>(data, pos)?;
+                        view.vault = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.index = v as u32;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(WithdrawTarget::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                if !has_vault {
+                    return Err(DecodeError::MissingField { field: 1 });
+                }
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<WithdrawTarget, DecodeError> {
+                WithdrawTarget::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Withdraw(Withdraw),
+            WithdrawTarget(WithdrawTarget),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Withdraw::FULL_NAME => AnyMessage::Withdraw(Withdraw::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    WithdrawTarget::FULL_NAME => AnyMessage::WithdrawTarget(WithdrawTarget::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Withdraw(msg) => Any::pack(msg),
+                    AnyMessage::WithdrawTarget(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1275 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod service {
+        // Auto-generated by protoc-gen-solana from service.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct DepositRequest {
+            pub account: Vec<u8>,
+            pub amount: u64,
+        }
+
+        impl DepositRequest {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = DepositRequest {
+                account: Vec::new(),
+                amount: 0,
+            };
+        }
+
+        impl Default for DepositRequest {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl DepositRequest {
+            pub const ACCOUNT_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "DepositRequest.account" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "DepositRequest.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: account
+                if !self.account.is_empty() {
+                    w.write_bytes(&self.account);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.account.is_empty() {
+                    len += key_len(1) + bytes_len(self.account.len());
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.account.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_bytes(&self.account)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.account = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for DepositRequest {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                DepositRequest::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                DepositRequest::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                DepositRequest::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                DepositRequest::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                DepositRequest::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                DepositRequest::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                DepositRequest::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for DepositRequest {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                DepositRequest::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<DepositRequest> for Vec<u8> {
+            fn from(msg: DepositRequest) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for DepositRequest {
+            const FULL_NAME: &'static str = "example.DepositRequest";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct DepositRequestBuilder {
+            inner: DepositRequest,
+        }
+
+        impl DepositRequest {
+            pub fn builder() -> DepositRequestBuilder {
+                DepositRequestBuilder::default()
+            }
+        }
+
+        impl DepositRequestBuilder {
+            pub fn with_account(mut self, value: Vec<u8>) -> Self {
+                self.inner.account = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> DepositRequest {
+                self.inner
+            }
+        }
+
+        impl DepositRequest {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.account.is_empty() {
+                    out.write_bytes("account", &self.account);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for DepositRequest {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl DepositRequest {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.account.is_empty() {
+                    out.key("account");
+                    out.write_bytes(&self.account);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "account" => msg.account = json_to_bytes(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct DepositRequestRef<'a> {
+            raw: &'a [u8],
+            pub account: &'a [u8],
+            pub amount: u64,
+        }
+
+        impl<'a> DepositRequestRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = DepositRequestRef {
+                    raw: data,
+                    account: &[],
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.account = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(DepositRequest::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<DepositRequest, DecodeError> {
+                DepositRequest::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct WithdrawRequest {
+            pub account: Vec<u8>,
+            pub amount: u64,
+        }
+
+        impl WithdrawRequest {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = WithdrawRequest {
+                account: Vec::new(),
+                amount: 0,
+            };
+        }
+
+        impl Default for WithdrawRequest {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl WithdrawRequest {
+            pub const ACCOUNT_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "WithdrawRequest.account" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "WithdrawRequest.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: account
+                if !self.account.is_empty() {
+                    w.write_bytes(&self.account);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.account.is_empty() {
+                    len += key_len(1) + bytes_len(self.account.len());
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.account.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_bytes(&self.account)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.account = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for WithdrawRequest {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                WithdrawRequest::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                WithdrawRequest::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                WithdrawRequest::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                WithdrawRequest::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                WithdrawRequest::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                WithdrawRequest::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                WithdrawRequest::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for WithdrawRequest {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                WithdrawRequest::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<WithdrawRequest> for Vec<u8> {
+            fn from(msg: WithdrawRequest) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for WithdrawRequest {
+            const FULL_NAME: &'static str = "example.WithdrawRequest";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawRequestBuilder {
+            inner: WithdrawRequest,
+        }
+
+        impl WithdrawRequest {
+            pub fn builder() -> WithdrawRequestBuilder {
+                WithdrawRequestBuilder::default()
+            }
+        }
+
+        impl WithdrawRequestBuilder {
+            pub fn with_account(mut self, value: Vec<u8>) -> Self {
+                self.inner.account = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> WithdrawRequest {
+                self.inner
+            }
+        }
+
+        impl WithdrawRequest {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.account.is_empty() {
+                    out.write_bytes("account", &self.account);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for WithdrawRequest {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl WithdrawRequest {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.account.is_empty() {
+                    out.key("account");
+                    out.write_bytes(&self.account);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "account" => msg.account = json_to_bytes(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawRequestRef<'a> {
+            raw: &'a [u8],
+            pub account: &'a [u8],
+            pub amount: u64,
+        }
+
+        impl<'a> WithdrawRequestRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawRequestRef {
+                    raw: data,
+                    account: &[],
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.account = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(WithdrawRequest::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<WithdrawRequest, DecodeError> {
+                WithdrawRequest::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct WithdrawResponse {
+            pub remaining: u64,
+        }
+
+        impl WithdrawResponse {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = WithdrawResponse {
+                remaining: 0,
+            };
+        }
+
+        impl Default for WithdrawResponse {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl WithdrawResponse {
+            pub const REMAINING_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "WithdrawResponse.remaining" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: remaining
+                if self.remaining != 0 {
+                    w.write_varint(self.remaining);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.remaining != 0 {
+                    len += key_len(1) + varint_len(self.remaining);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.remaining != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.remaining)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.remaining = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for WithdrawResponse {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                WithdrawResponse::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                WithdrawResponse::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                WithdrawResponse::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                WithdrawResponse::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                WithdrawResponse::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                WithdrawResponse::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                WithdrawResponse::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for WithdrawResponse {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                WithdrawResponse::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<WithdrawResponse> for Vec<u8> {
+            fn from(msg: WithdrawResponse) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for WithdrawResponse {
+            const FULL_NAME: &'static str = "example.WithdrawResponse";
+        }
+
+        impl WithdrawResponse {
+            /// Longest encoding of a `WithdrawResponse` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 11;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawResponseBuilder {
+            inner: WithdrawResponse,
+        }
+
+        impl WithdrawResponse {
+            pub fn builder() -> WithdrawResponseBuilder {
+                WithdrawResponseBuilder::default()
+            }
+        }
+
+        impl WithdrawResponseBuilder {
+            pub fn with_remaining(mut self, value: u64) -> Self {
+                self.inner.remaining = value;
+                self
+            }
+
+            pub fn build(self) -> WithdrawResponse {
+                self.inner
+            }
+        }
+
+        impl WithdrawResponse {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.remaining != 0 {
+                    out.write_scalar("remaining", self.remaining);
+                }
+            }
+        }
+
+        impl core::fmt::Display for WithdrawResponse {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl WithdrawResponse {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.remaining != 0 {
+                    out.key("remaining");
+                    out.write_u64(self.remaining);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "remaining" => msg.remaining = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawResponseRef<'a> {
+            raw: &'a [u8],
+            pub remaining: u64,
+        }
+
+        impl<'a> WithdrawResponseRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawResponseRef {
+                    raw: data,
+                    remaining: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.remaining = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(WithdrawResponse::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<WithdrawResponse, DecodeError> {
+                WithdrawResponse::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            DepositRequest(DepositRequest),
+            WithdrawRequest(WithdrawRequest),
+            WithdrawResponse(WithdrawResponse),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    DepositRequest::FULL_NAME => AnyMessage::DepositRequest(DepositRequest::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    WithdrawRequest::FULL_NAME => AnyMessage::WithdrawRequest(WithdrawRequest::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    WithdrawResponse::FULL_NAME => AnyMessage::WithdrawResponse(WithdrawResponse::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::DepositRequest(msg) => Any::pack(msg),
+                    AnyMessage::WithdrawRequest(msg) => Any::pack(msg),
+                    AnyMessage::WithdrawResponse(msg) => Any::pack(msg),
+                }
+            }
+        }
+
+        /// Instructions of the `example.Vault` service, tagged by a leading `u8`.
+        ///
+        /// Streaming rpcs have no instruction form, so `WatchBalance` has no variant.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum VaultInstruction {
+            Deposit(DepositRequest),
+            Withdraw(WithdrawRequest),
+        }
+
+        impl VaultInstruction {
+            pub const DEPOSIT_TAG: u8 = 0;
+            pub const WITHDRAW_TAG: u8 = 1;
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                let (&tag, payload) = data.split_first().ok_or(DecodeError::BufferOverflow)?;
+                match tag {
+                    Self::DEPOSIT_TAG => Ok(VaultInstruction::Deposit(DepositRequest::decode_with(payload, opts)?)),
+                    Self::WITHDRAW_TAG => Ok(VaultInstruction::Withdraw(WithdrawRequest::decode_with(payload, opts)?)),
+                    _ => Err(DecodeError::InvalidData("unknown instruction tag")),
+                }
+            }
+
+            pub fn tag(&self) -> u8 {
+                match self {
+                    VaultInstruction::Deposit(_) => Self::DEPOSIT_TAG,
+                    VaultInstruction::Withdraw(_) => Self::WITHDRAW_TAG,
+                }
+            }
+
+            /// Instruction data: the tag followed by the encoded request.
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                buf.push(self.tag());
+                match self {
+                    VaultInstruction::Deposit(request) => request.encode_into(&mut buf),
+                    VaultInstruction::Withdraw(request) => request.encode_into(&mut buf),
+                }
+                buf
+            }
+
+            /// Run the handler for this instruction and encode its response.
+            pub fn dispatch<C, H: VaultHandler<C>>(self, handler: &mut H, ctx: C) -> Result<Vec<u8>, H::Error> {
+                match self {
+                    VaultInstruction::Deposit(request) => Ok(handler.deposit(ctx, request)?.encode()),
+                    VaultInstruction::Withdraw(request) => Ok(handler.withdraw(ctx, request)?.encode()),
+                }
+            }
+
+            /// Decode instruction data and dispatch it, e.g. from the program's
+            /// `process_instruction` entrypoint.
+            pub fn process<C, H: VaultHandler<C>>(handler: &mut H, ctx: C, data: &[u8]) -> Result<Vec<u8>, H::Error> {
+                Self::decode(data)?.dispatch(handler, ctx)
+            }
+        }
+
+        /// Exercises: service rpcs generated as program instructions and a handler
+        /// trait; (solana.accounts) on Withdraw for WithdrawAccounts and a keyed builder
+        ///
+        /// Program-side implementation of the `example.Vault` service; `C` is the
+        /// per-instruction context, e.g. the program id and accounts.
+        pub trait VaultHandler<C> {
+            type Error: From<DecodeError>;
+
+            fn deposit(&mut self, ctx: C, request: DepositRequest) -> Result<Empty, Self::Error>;
+
+            fn withdraw(&mut self, ctx: C, request: WithdrawRequest) -> Result<WithdrawResponse, Self::Error>;
+        }
+
+        /// Account validation and instruction builders for the `example.Vault` service.
+        #[cfg(feature = "solana-program")]
+        pub mod vault {
+            use solana_program::account_info::AccountInfo;
+            use solana_program::program_error::ProgramError;
+
+            /// Accounts of `Withdraw`, in order: `owner` (signer), `vault` (writable), `system_program`.
+            pub struct WithdrawAccounts<'a, 'info> {
+                pub owner: &'a AccountInfo<'info>,
+                pub vault: &'a AccountInfo<'info>,
+                pub system_program: &'a AccountInfo<'info>,
+                /// Accounts after the declared ones.
+                pub remaining: &'a [AccountInfo<'info>],
+            }
+
+            impl<'a, 'info> WithdrawAccounts<'a, 'info> {
+                pub const LEN: usize = 3;
+
+                /// Take the declared accounts from the front of `accounts`, checking
+                /// that each is a signer or writable where declared so.
+                pub fn from_accounts(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+                    let [owner, vault, system_program, remaining @ ..] = accounts else {
+                        return Err(ProgramError::NotEnoughAccountKeys);
+                    };
+                    if !owner.is_signer {
+                        return Err(ProgramError::MissingRequiredSignature);
+                    }
+                    if !vault.is_writable {
+                        return Err(ProgramError::Immutable);
+                    }
+                    Ok(Self { owner, vault, system_program, remaining })
+                }
+            }
+
+            pub mod instruction {
+                use super::super::*;
+
+                /// `Deposit`, with `request` encoded as `VaultInstruction` data.
+                pub fn deposit(
+                    program_id: Pubkey,
+                    accounts: Vec<solana_program::instruction::AccountMeta>,
+                    request: DepositRequest,
+                ) -> solana_program::instruction::Instruction {
+                    solana_program::instruction::Instruction {
+                        program_id,
+                        accounts,
+                        data: VaultInstruction::Deposit(request).encode(),
+                    }
+                }
+
+                /// `Withdraw`, with `request` encoded as `VaultInstruction` data.
+                pub fn withdraw(
+                    program_id: Pubkey,
+                    owner: Pubkey,
+                    vault: Pubkey,
+                    system_program: Pubkey,
+                    request: WithdrawRequest,
+                ) -> solana_program::instruction::Instruction {
+                    solana_program::instruction::Instruction {
+                        program_id,
+                        accounts: Vec::from([
+                            solana_program::instruction::AccountMeta::new_readonly(owner, true),
+                            solana_program::instruction::AccountMeta::new(vault, false),
+                            solana_program::instruction::AccountMeta::new_readonly(system_program, false),
+                        ]),
+                        data: VaultInstruction::Withdraw(request).encode(),
+                    }
+                }
+            }
+        }
+    }
+}
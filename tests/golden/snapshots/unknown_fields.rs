@@ -0,0 +1,2409 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod unknown_fields {
+        // Auto-generated by protoc-gen-solana from unknown_fields.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: unknown field preservation (`unknown_fields` option). The V1
+        /// messages are older revisions of the V2 ones.
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct LegacyLeg {
+            pub amount: u64,
+            pub unknown_fields: UnknownFields,
+        }
+
+        impl LegacyLeg {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = LegacyLeg {
+                amount: 0,
+                unknown_fields: UnknownFields::new(),
+            };
+        }
+
+        impl Default for LegacyLeg {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl LegacyLeg {
+            pub const AMOUNT_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "LegacyLeg.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                self.unknown_fields.write_reverse(w);
+
+                // field 1: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.amount != 0 {
+                    len += key_len(1) + varint_len(self.amount);
+                }
+                len += self.unknown_fields.encoded_len();
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.amount != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.amount)?;
+                }
+                self.unknown_fields.write_to(w)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                            self.unknown_fields.push_raw(&data[field_start..pos]);
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for LegacyLeg {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                LegacyLeg::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                LegacyLeg::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                LegacyLeg::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                LegacyLeg::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                LegacyLeg::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                LegacyLeg::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                LegacyLeg::merge_with(self, data, opts)
+            }
+
+            fn encode_canonical(&self) -> Vec<u8> {
+                let mut msg = self.clone();
+                msg.clear_unknown_fields();
+                msg.encode()
+            }
+
+            fn clear_unknown_fields(&mut self) {
+                self.unknown_fields.clear();
+            }
+        }
+
+        impl TryFrom<&[u8]> for LegacyLeg {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                LegacyLeg::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<LegacyLeg> for Vec<u8> {
+            fn from(msg: LegacyLeg) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for LegacyLeg {
+            const FULL_NAME: &'static str = "example.LegacyLeg";
+        }
+
+        impl LegacyLeg {
+            /// Longest encoding of a `LegacyLeg` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 11;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LegacyLegBuilder {
+            inner: LegacyLeg,
+        }
+
+        impl LegacyLeg {
+            pub fn builder() -> LegacyLegBuilder {
+                LegacyLegBuilder::default()
+            }
+        }
+
+        impl LegacyLegBuilder {
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> LegacyLeg {
+                self.inner
+            }
+        }
+
+        impl LegacyLeg {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for LegacyLeg {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl LegacyLeg {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LegacyLegRef<'a> {
+            raw: &'a [u8],
+            pub amount: u64,
+        }
+
+        impl<'a> LegacyLegRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = LegacyLegRef {
+                    raw: data,
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(LegacyLeg::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<LegacyLeg, DecodeError> {
+                LegacyLeg::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct LegacyOrder {
+            pub id: u64,
+            pub leg: LegacyLeg,
+            pub fills: Vec<LegacyLeg>,
+            pub by_venue: BTreeMap<String, LegacyLeg>,
+            pub unknown_fields: UnknownFields,
+        }
+
+        impl LegacyOrder {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = LegacyOrder {
+                id: 0,
+                leg: LegacyLeg::DEFAULT,
+                fills: Vec::new(),
+                by_venue: BTreeMap::new(),
+                unknown_fields: UnknownFields::new(),
+            };
+        }
+
+        impl Default for LegacyOrder {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl LegacyOrder {
+            pub const ID_FIELD_NUMBER: u32 = 1;
+            pub const LEG_FIELD_NUMBER: u32 = 3;
+            pub const FILLS_FIELD_NUMBER: u32 = 5;
+            pub const BY_VENUE_FIELD_NUMBER: u32 = 6;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "LegacyOrder.id" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "LegacyOrder.leg" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "LegacyOrder.fills" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "LegacyOrder.by_venue" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                self.unknown_fields.write_reverse(w);
+
+                // field 6: by_venue
+                for (key, value) in self.by_venue.iter().rev() {
+                    w.write_nested(6, |w| {
+                        w.write_message(2, value);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 5: fills
+                for elem in self.fills.iter().rev() {
+                    w.write_message(5, elem);
+                }
+
+                // field 3: leg
+                w.write_message(3, &self.leg);
+
+                // field 1: id
+                if self.id != 0 {
+                    w.write_varint(self.id);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                len += key_len(3) + bytes_len(self.leg.encoded_len());
+                for elem in &self.fills {
+                    len += key_len(5) + bytes_len(elem.encoded_len());
+                }
+                for (key, value) in &self.by_venue {
+                    len += key_len(6) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len()));
+                }
+                len += self.unknown_fields.encoded_len();
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                w.write_message(3, &self.leg)?;
+                for elem in &self.fills {
+                    w.write_message(5, elem)?;
+                }
+                for (key, value) in &self.by_venue {
+                    w.write_key(0x32)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message(2, value)?;
+                }
+                self.unknown_fields.write_to(w)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                len += key_len(3) + bytes_len(sizes.record(&self.leg));
+                for elem in &self.fills {
+                    len += key_len(5) + bytes_len(sizes.record(elem));
+                }
+                for (key, value) in &self.by_venue {
+                    len += key_len(6) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.record(value)));
+                }
+                len += self.unknown_fields.encoded_len();
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                w.write_message_sized(3, &self.leg, sizes)?;
+                for elem in &self.fills {
+                    w.write_message_sized(5, elem, sizes)?;
+                }
+                for (key, value) in &self.by_venue {
+                    w.write_key(0x32)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message_sized(2, value, sizes)?;
+                }
+                self.unknown_fields.write_to(w)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.id = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.leg.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.fills.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.fills.push(LegacyLeg::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = LegacyLeg::DEFAULT;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                        value.merge_with(sub, &opts.nested()?)?;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.by_venue.len() >= opts.max_repeated_len && !self.by_venue.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.by_venue.insert(key, value);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                            self.unknown_fields.push_raw(&data[field_start..pos]);
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    5 => Some(self.fills.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for LegacyOrder {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                LegacyOrder::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                LegacyOrder::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                LegacyOrder::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                LegacyOrder::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                LegacyOrder::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                LegacyOrder::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                LegacyOrder::merge_with(self, data, opts)
+            }
+
+            fn encode_canonical(&self) -> Vec<u8> {
+                let mut msg = self.clone();
+                msg.clear_unknown_fields();
+                msg.encode()
+            }
+
+            fn clear_unknown_fields(&mut self) {
+                self.unknown_fields.clear();
+                self.leg.clear_unknown_fields();
+                for v in &mut self.fills {
+                    v.clear_unknown_fields();
+                }
+                for v in self.by_venue.values_mut() {
+                    v.clear_unknown_fields();
+                }
+            }
+        }
+
+        impl TryFrom<&[u8]> for LegacyOrder {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                LegacyOrder::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<LegacyOrder> for Vec<u8> {
+            fn from(msg: LegacyOrder) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for LegacyOrder {
+            const FULL_NAME: &'static str = "example.LegacyOrder";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LegacyOrderBuilder {
+            inner: LegacyOrder,
+        }
+
+        impl LegacyOrder {
+            pub fn builder() -> LegacyOrderBuilder {
+                LegacyOrderBuilder::default()
+            }
+        }
+
+        impl LegacyOrderBuilder {
+            pub fn with_id(mut self, value: u64) -> Self {
+                self.inner.id = value;
+                self
+            }
+
+            pub fn with_leg(mut self, value: LegacyLeg) -> Self {
+                self.inner.leg = value;
+                self
+            }
+
+            pub fn with_fills(mut self, value: Vec<LegacyLeg>) -> Self {
+                self.inner.fills = value;
+                self
+            }
+
+            pub fn add_fills(mut self, value: LegacyLeg) -> Self {
+                self.inner.fills.push(value);
+                self
+            }
+
+            pub fn with_by_venue(mut self, value: BTreeMap<String, LegacyLeg>) -> Self {
+                self.inner.by_venue = value;
+                self
+            }
+
+            pub fn insert_by_venue(mut self, key: String, value: LegacyLeg) -> Self {
+                self.inner.by_venue.insert(key, value);
+                self
+            }
+
+            pub fn build(self) -> LegacyOrder {
+                self.inner
+            }
+        }
+
+        impl LegacyOrder {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.id != 0 {
+                    out.write_scalar("id", self.id);
+                }
+                if self.leg != LegacyLeg::default() {
+                    out.begin_message("leg");
+                    self.leg.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.fills {
+                    out.begin_message("fills");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for (key, value) in &self.by_venue {
+                    out.begin_message("by_venue");
+                    out.write_str("key", key);
+                    out.begin_message("value");
+                    value.write_text(out);
+                    out.end_message();
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for LegacyOrder {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl LegacyOrder {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.id != 0 {
+                    out.key("id");
+                    out.write_u64(self.id);
+                }
+                if self.leg != LegacyLeg::default() {
+                    out.key("leg");
+                    self.leg.write_json(out);
+                }
+                if !self.fills.is_empty() {
+                    out.key("fills");
+                    out.begin_array();
+                    for elem in &self.fills {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if !self.by_venue.is_empty() {
+                    out.key("byVenue");
+                    out.begin_object();
+                    for (key, value) in &self.by_venue {
+                        out.key(key);
+                        value.write_json(out);
+                    }
+                    out.end_object();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "id" => msg.id = json_to_u64(value)?,
+                        "leg" => msg.leg = LegacyLeg::from_json_value(value)?,
+                        "fills" => {
+                            for elem in json_array(value)? {
+                                msg.fills.push(LegacyLeg::from_json_value(elem)?);
+                            }
+                        }
+                        "byVenue" | "by_venue" => {
+                            for (key, value) in json_object(value)? {
+                                msg.by_venue.insert(json_map_key(key)?, LegacyLeg::from_json_value(value)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LegacyOrderRef<'a> {
+            raw: &'a [u8],
+            pub id: u64,
+            pub leg: Option<LegacyLegRef<'a>>,
+            pub fills: RepeatedRef<'a, LegacyLegRef<'a>>,
+        }
+
+        impl<'a> LegacyOrderRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = LegacyOrderRef {
+                    raw: data,
+                    id: 0,
+                    leg: None,
+                    fills: RepeatedRef::new(data, 42, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((LegacyLegRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.id = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.leg = Some(LegacyLegRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    42 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(LegacyOrder::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<LegacyOrder, DecodeError> {
+                LegacyOrder::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct OrderLeg {
+            pub amount: u64,
+            pub asset: String,
+            pub unknown_fields: UnknownFields,
+        }
+
+        impl OrderLeg {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = OrderLeg {
+                amount: 0,
+                asset: String::new(),
+                unknown_fields: UnknownFields::new(),
+            };
+        }
+
+        impl Default for OrderLeg {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl OrderLeg {
+            pub const AMOUNT_FIELD_NUMBER: u32 = 1;
+            pub const ASSET_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "OrderLeg.amount" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "OrderLeg.asset" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                self.unknown_fields.write_reverse(w);
+
+                // field 2: asset
+                if !self.asset.is_empty() {
+                    w.write_string(&self.asset);
+                    w.write_key(0x12);
+                }
+
+                // field 1: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.amount != 0 {
+                    len += key_len(1) + varint_len(self.amount);
+                }
+                if !self.asset.is_empty() {
+                    len += key_len(2) + bytes_len(self.asset.len());
+                }
+                len += self.unknown_fields.encoded_len();
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.amount != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.amount)?;
+                }
+                if !self.asset.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.asset)?;
+                }
+                self.unknown_fields.write_to(w)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.asset = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                            self.unknown_fields.push_raw(&data[field_start..pos]);
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for OrderLeg {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                OrderLeg::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                OrderLeg::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                OrderLeg::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                OrderLeg::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                OrderLeg::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                OrderLeg::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                OrderLeg::merge_with(self, data, opts)
+            }
+
+            fn encode_canonical(&self) -> Vec<u8> {
+                let mut msg = self.clone();
+                msg.clear_unknown_fields();
+                msg.encode()
+            }
+
+            fn clear_unknown_fields(&mut self) {
+                self.unknown_fields.clear();
+            }
+        }
+
+        impl TryFrom<&[u8]> for OrderLeg {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                OrderLeg::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<OrderLeg> for Vec<u8> {
+            fn from(msg: OrderLeg) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for OrderLeg {
+            const FULL_NAME: &'static str = "example.OrderLeg";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct OrderLegBuilder {
+            inner: OrderLeg,
+        }
+
+        impl OrderLeg {
+            pub fn builder() -> OrderLegBuilder {
+                OrderLegBuilder::default()
+            }
+        }
+
+        impl OrderLegBuilder {
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn with_asset(mut self, value: String) -> Self {
+                self.inner.asset = value;
+                self
+            }
+
+            pub fn build(self) -> OrderLeg {
+                self.inner
+            }
+        }
+
+        impl OrderLeg {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+                if !self.asset.is_empty() {
+                    out.write_str("asset", &self.asset);
+                }
+            }
+        }
+
+        impl core::fmt::Display for OrderLeg {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl OrderLeg {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                if !self.asset.is_empty() {
+                    out.key("asset");
+                    out.write_str(&self.asset);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        "asset" => msg.asset = json_to_string(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct OrderLegRef<'a> {
+            raw: &'a [u8],
+            pub amount: u64,
+            pub asset: &'a str,
+        }
+
+        impl<'a> OrderLegRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = OrderLegRef {
+                    raw: data,
+                    amount: 0,
+                    asset: "",
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.asset = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(OrderLeg::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<OrderLeg, DecodeError> {
+                OrderLeg::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Order {
+            pub id: u64,
+            pub memo: String,
+            pub leg: OrderLeg,
+            pub flags: Vec<u32>,
+            pub fills: Vec<OrderLeg>,
+            pub by_venue: BTreeMap<String, OrderLeg>,
+            pub fee: i64,
+            pub unknown_fields: UnknownFields,
+        }
+
+        impl Order {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Order {
+                id: 0,
+                memo: String::new(),
+                leg: OrderLeg::DEFAULT,
+                flags: Vec::new(),
+                fills: Vec::new(),
+                by_venue: BTreeMap::new(),
+                fee: 0,
+                unknown_fields: UnknownFields::new(),
+            };
+        }
+
+        impl Default for Order {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Order {
+            pub const ID_FIELD_NUMBER: u32 = 1;
+            pub const MEMO_FIELD_NUMBER: u32 = 2;
+            pub const LEG_FIELD_NUMBER: u32 = 3;
+            pub const FLAGS_FIELD_NUMBER: u32 = 4;
+            pub const FILLS_FIELD_NUMBER: u32 = 5;
+            pub const BY_VENUE_FIELD_NUMBER: u32 = 6;
+            pub const FEE_FIELD_NUMBER: u32 = 7;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Order.id" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Order.memo" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Order.leg" },
+                FieldWireType { number: 4, wire_type: 5, packed: true, name: "Order.flags" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "Order.fills" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Order.by_venue" },
+                FieldWireType { number: 7, wire_type: 0, packed: false, name: "Order.fee" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                self.unknown_fields.write_reverse(w);
+
+                // field 7: fee
+                if self.fee != 0 {
+                    w.write_zigzag64(self.fee);
+                    w.write_key(0x38);
+                }
+
+                // field 6: by_venue
+                for (key, value) in self.by_venue.iter().rev() {
+                    w.write_nested(6, |w| {
+                        w.write_message(2, value);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 5: fills
+                for elem in self.fills.iter().rev() {
+                    w.write_message(5, elem);
+                }
+
+                // field 4: flags
+                if !self.flags.is_empty() {
+                    w.write_nested(4, |w| {
+                        for elem in self.flags.iter().rev() {
+                            w.write_fixed32(*elem);
+                        }
+                    });
+                }
+
+                // field 3: leg
+                w.write_message(3, &self.leg);
+
+                // field 2: memo
+                if !self.memo.is_empty() {
+                    w.write_string(&self.memo);
+                    w.write_key(0x12);
+                }
+
+                // field 1: id
+                if self.id != 0 {
+                    w.write_varint(self.id);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                if !self.memo.is_empty() {
+                    len += key_len(2) + bytes_len(self.memo.len());
+                }
+                len += key_len(3) + bytes_len(self.leg.encoded_len());
+                if !self.flags.is_empty() {
+                    len += key_len(4) + bytes_len(self.flags.len() * 4);
+                }
+                for elem in &self.fills {
+                    len += key_len(5) + bytes_len(elem.encoded_len());
+                }
+                for (key, value) in &self.by_venue {
+                    len += key_len(6) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len()));
+                }
+                if self.fee != 0 {
+                    len += key_len(7) + zigzag64_len(self.fee);
+                }
+                len += self.unknown_fields.encoded_len();
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                if !self.memo.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.memo)?;
+                }
+                w.write_message(3, &self.leg)?;
+                if !self.flags.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_varint((self.flags.len() * 4) as u64)?;
+                    for elem in &self.flags {
+                        w.write_fixed32(*elem)?;
+                    }
+                }
+                for elem in &self.fills {
+                    w.write_message(5, elem)?;
+                }
+                for (key, value) in &self.by_venue {
+                    w.write_key(0x32)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message(2, value)?;
+                }
+                if self.fee != 0 {
+                    w.write_key(0x38)?;
+                    w.write_zigzag64(self.fee)?;
+                }
+                self.unknown_fields.write_to(w)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.id != 0 {
+                    len += key_len(1) + varint_len(self.id);
+                }
+                if !self.memo.is_empty() {
+                    len += key_len(2) + bytes_len(self.memo.len());
+                }
+                len += key_len(3) + bytes_len(sizes.record(&self.leg));
+                if !self.flags.is_empty() {
+                    len += key_len(4) + bytes_len(self.flags.len() * 4);
+                }
+                for elem in &self.fills {
+                    len += key_len(5) + bytes_len(sizes.record(elem));
+                }
+                for (key, value) in &self.by_venue {
+                    len += key_len(6) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.record(value)));
+                }
+                if self.fee != 0 {
+                    len += key_len(7) + zigzag64_len(self.fee);
+                }
+                len += self.unknown_fields.encoded_len();
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.id != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.id)?;
+                }
+                if !self.memo.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.memo)?;
+                }
+                w.write_message_sized(3, &self.leg, sizes)?;
+                if !self.flags.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_varint((self.flags.len() * 4) as u64)?;
+                    for elem in &self.flags {
+                        w.write_fixed32(*elem)?;
+                    }
+                }
+                for elem in &self.fills {
+                    w.write_message_sized(5, elem, sizes)?;
+                }
+                for (key, value) in &self.by_venue {
+                    w.write_key(0x32)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message_sized(2, value, sizes)?;
+                }
+                if self.fee != 0 {
+                    w.write_key(0x38)?;
+                    w.write_zigzag64(self.fee)?;
+                }
+                self.unknown_fields.write_to(w)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.id = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.memo = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.leg.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        37 => {
+                            let (v, new_pos) = decode_fixed32(data, pos)?;
+                            if self.flags.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.flags.push(v);
+                            pos = new_pos;
+                        }
+                        34 => {
+                            let new_pos = decode_packed_fixed32_with(data, pos, &mut self.flags, opts)?;
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.fills.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.fills.push(OrderLeg::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = OrderLeg::DEFAULT;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                        value.merge_with(sub, &opts.nested()?)?;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.by_venue.len() >= opts.max_repeated_len && !self.by_venue.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.by_venue.insert(key, value);
+                            pos = new_pos;
+                        }
+                        56 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_zigzag64(data, pos)?;
+                            self.fee = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                            self.unknown_fields.push_raw(&data[field_start..pos]);
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    4 => Some(self.flags.len()),
+                    5 => Some(self.fills.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Order {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Order::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Order::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Order::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Order::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Order::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Order::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Order::merge_with(self, data, opts)
+            }
+
+            fn encode_canonical(&self) -> Vec<u8> {
+                let mut msg = self.clone();
+                msg.clear_unknown_fields();
+                msg.encode()
+            }
+
+            fn clear_unknown_fields(&mut self) {
+                self.unknown_fields.clear();
+                self.leg.clear_unknown_fields();
+                for v in &mut self.fills {
+                    v.clear_unknown_fields();
+                }
+                for v in self.by_venue.values_mut() {
+                    v.clear_unknown_fields();
+                }
+            }
+        }
+
+        impl TryFrom<&[u8]> for Order {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Order::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Order> for Vec<u8> {
+            fn from(msg: Order) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Order {
+            const FULL_NAME: &'static str = "example.Order";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct OrderBuilder {
+            inner: Order,
+        }
+
+        impl Order {
+            pub fn builder() -> OrderBuilder {
+                OrderBuilder::default()
+            }
+        }
+
+        impl OrderBuilder {
+            pub fn with_id(mut self, value: u64) -> Self {
+                self.inner.id = value;
+                self
+            }
+
+            pub fn with_memo(mut self, value: String) -> Self {
+                self.inner.memo = value;
+                self
+            }
+
+            pub fn with_leg(mut self, value: OrderLeg) -> Self {
+                self.inner.leg = value;
+                self
+            }
+
+            pub fn with_flags(mut self, value: Vec<u32>) -> Self {
+                self.inner.flags = value;
+                self
+            }
+
+            pub fn add_flags(mut self, value: u32) -> Self {
+                self.inner.flags.push(value);
+                self
+            }
+
+            pub fn with_fills(mut self, value: Vec<OrderLeg>) -> Self {
+                self.inner.fills = value;
+                self
+            }
+
+            pub fn add_fills(mut self, value: OrderLeg) -> Self {
+                self.inner.fills.push(value);
+                self
+            }
+
+            pub fn with_by_venue(mut self, value: BTreeMap<String, OrderLeg>) -> Self {
+                self.inner.by_venue = value;
+                self
+            }
+
+            pub fn insert_by_venue(mut self, key: String, value: OrderLeg) -> Self {
+                self.inner.by_venue.insert(key, value);
+                self
+            }
+
+            pub fn with_fee(mut self, value: i64) -> Self {
+                self.inner.fee = value;
+                self
+            }
+
+            pub fn build(self) -> Order {
+                self.inner
+            }
+        }
+
+        impl Order {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.id != 0 {
+                    out.write_scalar("id", self.id);
+                }
+                if !self.memo.is_empty() {
+                    out.write_str("memo", &self.memo);
+                }
+                if self.leg != OrderLeg::default() {
+                    out.begin_message("leg");
+                    self.leg.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.flags {
+                    out.write_scalar("flags", *elem);
+                }
+                for elem in &self.fills {
+                    out.begin_message("fills");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for (key, value) in &self.by_venue {
+                    out.begin_message("by_venue");
+                    out.write_str("key", key);
+                    out.begin_message("value");
+                    value.write_text(out);
+                    out.end_message();
+                    out.end_message();
+                }
+                if self.fee != 0 {
+                    out.write_scalar("fee", self.fee);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Order {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Order {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.id != 0 {
+                    out.key("id");
+                    out.write_u64(self.id);
+                }
+                if !self.memo.is_empty() {
+                    out.key("memo");
+                    out.write_str(&self.memo);
+                }
+                if self.leg != OrderLeg::default() {
+                    out.key("leg");
+                    self.leg.write_json(out);
+                }
+                if !self.flags.is_empty() {
+                    out.key("flags");
+                    out.begin_array();
+                    for elem in &self.flags {
+                        out.write_u32(*elem);
+                    }
+                    out.end_array();
+                }
+                if !self.fills.is_empty() {
+                    out.key("fills");
+                    out.begin_array();
+                    for elem in &self.fills {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if !self.by_venue.is_empty() {
+                    out.key("byVenue");
+                    out.begin_object();
+                    for (key, value) in &self.by_venue {
+                        out.key(key);
+                        value.write_json(out);
+                    }
+                    out.end_object();
+                }
+                if self.fee != 0 {
+                    out.key("fee");
+                    out.write_i64(self.fee);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "id" => msg.id = json_to_u64(value)?,
+                        "memo" => msg.memo = json_to_string(value)?,
+                        "leg" => msg.leg = OrderLeg::from_json_value(value)?,
+                        "flags" => {
+                            for elem in json_array(value)? {
+                                msg.flags.push(json_to_u32(elem)?);
+                            }
+                        }
+                        "fills" => {
+                            for elem in json_array(value)? {
+                                msg.fills.push(OrderLeg::from_json_value(elem)?);
+                            }
+                        }
+                        "byVenue" | "by_venue" => {
+                            for (key, value) in json_object(value)? {
+                                msg.by_venue.insert(json_map_key(key)?, OrderLeg::from_json_value(value)?);
+                            }
+                        }
+                        "fee" => msg.fee = json_to_i64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct OrderRef<'a> {
+            raw: &'a [u8],
+            pub id: u64,
+            pub memo: &'a str,
+            pub leg: Option<OrderLegRef<'a>>,
+            pub flags: RepeatedRef<'a, u32>,
+            pub fills: RepeatedRef<'a, OrderLegRef<'a>>,
+            pub fee: i64,
+        }
+
+        impl<'a> OrderRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = OrderRef {
+                    raw: data,
+                    id: 0,
+                    memo: "",
+                    leg: None,
+                    flags: RepeatedRef::packed(data, 37, decode_fixed32),
+                    fills: RepeatedRef::new(data, 42, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((OrderLegRef::decode(v)?, new_pos))
+                    }),
+                    fee: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.id = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.memo = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.leg = Some(OrderLegRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    37 => {
+                        pos = skip_field(data, pos, 5)?;
+                    }
+                    42 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    56 => {
+                        let (v, new_pos) = decode_zigzag64(data, pos)?;
+                        view.fee = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Order::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Order, DecodeError> {
+                Order::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Blank {
+            pub unknown_fields: UnknownFields,
+        }
+
+        impl Blank {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Blank {
+                unknown_fields: UnknownFields::new(),
+            };
+        }
+
+        impl Default for Blank {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Blank {
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                self.unknown_fields.write_reverse(w);
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                self.unknown_fields.encoded_len()
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                self.unknown_fields.write_to(w)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], _opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        pos = skip_field(data, pos, tag)?;
+                        self.unknown_fields.push_raw(&data[field_start..pos]);
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, &[], None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Blank {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Blank::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Blank::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Blank::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Blank::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Blank::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Blank::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Blank::merge_with(self, data, opts)
+            }
+
+            fn encode_canonical(&self) -> Vec<u8> {
+                let mut msg = self.clone();
+                msg.clear_unknown_fields();
+                msg.encode()
+            }
+
+            fn clear_unknown_fields(&mut self) {
+                self.unknown_fields.clear();
+            }
+        }
+
+        impl TryFrom<&[u8]> for Blank {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Blank::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Blank> for Vec<u8> {
+            fn from(msg: Blank) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Blank {
+            const FULL_NAME: &'static str = "example.Blank";
+        }
+
+        impl Blank {
+            /// Longest encoding of a `Blank` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 0;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct BlankBuilder {
+            inner: Blank,
+        }
+
+        impl Blank {
+            pub fn builder() -> BlankBuilder {
+                BlankBuilder::default()
+            }
+        }
+
+        impl BlankBuilder {
+            pub fn build(self) -> Blank {
+                self.inner
+            }
+        }
+
+        impl Blank {
+            pub fn write_text(&self, _out: &mut TextWriter) {
+            }
+        }
+
+        impl core::fmt::Display for Blank {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Blank {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                if !json_object(value)?.is_empty() {
+                    return Err(DecodeError::InvalidData("unknown JSON field"));
+                }
+                Ok(Self::default())
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct BlankRef<'a> {
+            raw: &'a [u8],
+        }
+
+        impl<'a> BlankRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let view = BlankRef {
+                    raw: data,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    pos = skip_field(data, pos, tag)?;
+                    trace_field(Blank::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Blank, DecodeError> {
+                Blank::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            LegacyLeg(LegacyLeg),
+            LegacyOrder(LegacyOrder),
+            OrderLeg(OrderLeg),
+            Order(Order),
+            Blank(Blank),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    LegacyLeg::FULL_NAME => AnyMessage::LegacyLeg(LegacyLeg::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    LegacyOrder::FULL_NAME => AnyMessage::LegacyOrder(LegacyOrder::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    OrderLeg::FULL_NAME => AnyMessage::OrderLeg(OrderLeg::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Order::FULL_NAME => AnyMessage::Order(Order::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Blank::FULL_NAME => AnyMessage::Blank(Blank::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::LegacyLeg(msg) => Any::pack(msg),
+                    AnyMessage::LegacyOrder(msg) => Any::pack(msg),
+                    AnyMessage::OrderLeg(msg) => Any::pack(msg),
+                    AnyMessage::Order(msg) => Any::pack(msg),
+                    AnyMessage::Blank(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
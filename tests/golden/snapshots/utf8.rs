@@ -0,0 +1,879 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod utf8 {
+        // Auto-generated by protoc-gen-solana from utf8.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum MemoBody {
+            Unset,
+            Plain(String),
+            Blob(Vec<u8>),
+        }
+
+        impl MemoBody {
+            pub const DEFAULT: Self = MemoBody::Unset;
+        }
+
+        impl Default for MemoBody {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: (solana.utf8) policies for string fields with invalid UTF-8
+        /// on the wire: rejected, replaced with U+FFFD, or kept as bytes
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Memo {
+            pub text: String,
+            pub payload: Vec<u8>,
+            pub name: String,
+            /// Checked against the bound again after replacement
+            pub label: String,
+            pub lines: Vec<String>,
+            pub note: Option<Vec<u8>>,
+            /// Map keys and values stay strings, so "bytes" reads them lossily
+            pub tags: BTreeMap<String, String>,
+            pub body: MemoBody,
+            /// Ignored with a warning: not a string field
+            pub seq: u64,
+        }
+
+        impl Memo {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Memo {
+                text: String::new(),
+                payload: Vec::new(),
+                name: String::new(),
+                label: String::new(),
+                lines: Vec::new(),
+                note: None,
+                tags: BTreeMap::new(),
+                body: MemoBody::Unset,
+                seq: 0,
+            };
+        }
+
+        impl Default for Memo {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Memo {
+            pub const TEXT_FIELD_NUMBER: u32 = 1;
+            pub const PAYLOAD_FIELD_NUMBER: u32 = 2;
+            pub const NAME_FIELD_NUMBER: u32 = 3;
+            pub const LABEL_FIELD_NUMBER: u32 = 4;
+            pub const LINES_FIELD_NUMBER: u32 = 5;
+            pub const NOTE_FIELD_NUMBER: u32 = 6;
+            pub const TAGS_FIELD_NUMBER: u32 = 7;
+            pub const PLAIN_FIELD_NUMBER: u32 = 8;
+            pub const BLOB_FIELD_NUMBER: u32 = 9;
+            pub const SEQ_FIELD_NUMBER: u32 = 10;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Memo.text" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Memo.payload" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Memo.name" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Memo.label" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "Memo.lines" },
+                FieldWireType { number: 6, wire_type: 2, packed: false, name: "Memo.note" },
+                FieldWireType { number: 7, wire_type: 2, packed: false, name: "Memo.tags" },
+                FieldWireType { number: 8, wire_type: 2, packed: false, name: "Memo.plain" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Memo.blob" },
+                FieldWireType { number: 10, wire_type: 0, packed: false, name: "Memo.seq" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 10: seq
+                if self.seq != 0 {
+                    w.write_varint(self.seq);
+                    w.write_key(0x50);
+                }
+
+                // field 9: blob
+                if let MemoBody::Blob(elem) = &self.body {
+                    w.write_bytes(elem);
+                    w.write_key(0x4a);
+                }
+
+                // field 8: plain
+                if let MemoBody::Plain(elem) = &self.body {
+                    w.write_string(elem);
+                    w.write_key(0x42);
+                }
+
+                // field 7: tags
+                for (key, value) in self.tags.iter().rev() {
+                    w.write_nested(7, |w| {
+                        w.write_string(value);
+                        w.write_key(0x12);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 6: note
+                if let Some(elem) = &self.note {
+                    w.write_bytes(elem);
+                    w.write_key(0x32);
+                }
+
+                // field 5: lines
+                for elem in self.lines.iter().rev() {
+                    w.write_string(elem);
+                    w.write_key(0x2a);
+                }
+
+                // field 4: label
+                if !self.label.is_empty() {
+                    w.write_string(&self.label);
+                    w.write_key(0x22);
+                }
+
+                // field 3: name
+                if !self.name.is_empty() {
+                    w.write_string(&self.name);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: payload
+                if !self.payload.is_empty() {
+                    w.write_bytes(&self.payload);
+                    w.write_key(0x12);
+                }
+
+                // field 1: text
+                if !self.text.is_empty() {
+                    w.write_string(&self.text);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.text.is_empty() {
+                    len += key_len(1) + bytes_len(self.text.len());
+                }
+                if !self.payload.is_empty() {
+                    len += key_len(2) + bytes_len(self.payload.len());
+                }
+                if !self.name.is_empty() {
+                    len += key_len(3) + bytes_len(self.name.len());
+                }
+                if !self.label.is_empty() {
+                    len += key_len(4) + bytes_len(self.label.len());
+                }
+                for elem in &self.lines {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.note {
+                    len += key_len(6) + bytes_len(elem.len());
+                }
+                for (key, value) in &self.tags {
+                    len += key_len(7) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len()));
+                }
+                if let MemoBody::Plain(elem) = &self.body {
+                    len += key_len(8) + bytes_len(elem.len());
+                }
+                if let MemoBody::Blob(elem) = &self.body {
+                    len += key_len(9) + bytes_len(elem.len());
+                }
+                if self.seq != 0 {
+                    len += key_len(10) + varint_len(self.seq);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.text.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_string(&self.text)?;
+                }
+                if !self.payload.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_bytes(&self.payload)?;
+                }
+                if !self.name.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.name)?;
+                }
+                if !self.label.is_empty() {
+                    w.write_key(0x22)?;
+                    w.write_string(&self.label)?;
+                }
+                for elem in &self.lines {
+                    w.write_key(0x2a)?;
+                    w.write_string(elem)?;
+                }
+                if let Some(elem) = &self.note {
+                    w.write_key(0x32)?;
+                    w.write_bytes(elem)?;
+                }
+                for (key, value) in &self.tags {
+                    w.write_key(0x3a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_key(0x12)?;
+                    w.write_string(value)?;
+                }
+                if let MemoBody::Plain(elem) = &self.body {
+                    w.write_key(0x42)?;
+                    w.write_string(elem)?;
+                }
+                if let MemoBody::Blob(elem) = &self.body {
+                    w.write_key(0x4a)?;
+                    w.write_bytes(elem)?;
+                }
+                if self.seq != 0 {
+                    w.write_key(0x50)?;
+                    w.write_varint(self.seq)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_string_lossy_with(data, pos, opts)?;
+                            self.text = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.payload = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.name = v;
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_string_lossy_bounded(data, pos, opts, 6, 4)?;
+                            self.label = v;
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let (v, new_pos) = decode_string_lossy_with(data, pos, opts)?;
+                            if self.lines.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.lines.push(v);
+                            pos = new_pos;
+                        }
+                        50 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.note = Some(v);
+                            pos = new_pos;
+                        }
+                        58 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = String::new();
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_lossy_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (v, next) = decode_string_lossy_with(entry, entry_pos, opts)?;
+                                        value = v;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.tags.len() >= opts.max_repeated_len && !self.tags.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.tags.insert(key, value);
+                            pos = new_pos;
+                        }
+                        66 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_string_lossy_with(data, pos, opts)?;
+                            self.body = MemoBody::Plain(v);
+                            pos = new_pos;
+                        }
+                        74 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.body = MemoBody::Blob(v);
+                            pos = new_pos;
+                        }
+                        80 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.seq = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    5 => Some(self.lines.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Memo {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Memo::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Memo::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Memo::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Memo::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Memo::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Memo::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Memo::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Memo {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Memo::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Memo> for Vec<u8> {
+            fn from(msg: Memo) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Memo {
+            const FULL_NAME: &'static str = "example.Memo";
+        }
+
+        impl Memo {
+            /// Check the `(solana.*)` field rules, those of sub-messages
+            /// included; decoding does not. Returns the first rule broken.
+            pub fn validate(&self) -> Result<(), ValidationError> {
+                if self.label.len() > 6 {
+                    return Err(ValidationError::new("label", ValidationRule::MaxLen(6)));
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Memo {
+            pub fn plain(&self) -> Option<&String> {
+                match &self.body {
+                    MemoBody::Plain(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_plain(&mut self, value: String) {
+                self.body = MemoBody::Plain(value);
+            }
+
+            pub fn blob(&self) -> Option<&Vec<u8>> {
+                match &self.body {
+                    MemoBody::Blob(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_blob(&mut self, value: Vec<u8>) {
+                self.body = MemoBody::Blob(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct MemoBuilder {
+            inner: Memo,
+        }
+
+        impl Memo {
+            pub fn builder() -> MemoBuilder {
+                MemoBuilder::default()
+            }
+        }
+
+        impl MemoBuilder {
+            pub fn with_text(mut self, value: String) -> Self {
+                self.inner.text = value;
+                self
+            }
+
+            pub fn with_payload(mut self, value: Vec<u8>) -> Self {
+                self.inner.payload = value;
+                self
+            }
+
+            pub fn with_name(mut self, value: String) -> Self {
+                self.inner.name = value;
+                self
+            }
+
+            pub fn with_label(mut self, value: String) -> Self {
+                self.inner.label = value;
+                self
+            }
+
+            pub fn with_lines(mut self, value: Vec<String>) -> Self {
+                self.inner.lines = value;
+                self
+            }
+
+            pub fn add_lines(mut self, value: String) -> Self {
+                self.inner.lines.push(value);
+                self
+            }
+
+            pub fn with_note(mut self, value: Vec<u8>) -> Self {
+                self.inner.note = Some(value);
+                self
+            }
+
+            pub fn with_tags(mut self, value: BTreeMap<String, String>) -> Self {
+                self.inner.tags = value;
+                self
+            }
+
+            pub fn insert_tags(mut self, key: String, value: String) -> Self {
+                self.inner.tags.insert(key, value);
+                self
+            }
+
+            pub fn with_plain(mut self, value: String) -> Self {
+                self.inner.body = MemoBody::Plain(value);
+                self
+            }
+
+            pub fn with_blob(mut self, value: Vec<u8>) -> Self {
+                self.inner.body = MemoBody::Blob(value);
+                self
+            }
+
+            pub fn with_seq(mut self, value: u64) -> Self {
+                self.inner.seq = value;
+                self
+            }
+
+            pub fn build(self) -> Memo {
+                self.inner
+            }
+        }
+
+        impl Memo {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.text.is_empty() {
+                    out.write_str("text", &self.text);
+                }
+                if !self.payload.is_empty() {
+                    out.write_bytes("payload", &self.payload);
+                }
+                if !self.name.is_empty() {
+                    out.write_str("name", &self.name);
+                }
+                if !self.label.is_empty() {
+                    out.write_str("label", &self.label);
+                }
+                for elem in &self.lines {
+                    out.write_str("lines", elem);
+                }
+                if let Some(elem) = &self.note {
+                    out.write_bytes("note", elem);
+                }
+                for (key, value) in &self.tags {
+                    out.begin_message("tags");
+                    out.write_str("key", key);
+                    out.write_str("value", value);
+                    out.end_message();
+                }
+                if let MemoBody::Plain(elem) = &self.body {
+                    out.write_str("plain", elem);
+                }
+                if let MemoBody::Blob(elem) = &self.body {
+                    out.write_bytes("blob", elem);
+                }
+                if self.seq != 0 {
+                    out.write_scalar("seq", self.seq);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Memo {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Memo {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.text.is_empty() {
+                    out.key("text");
+                    out.write_str(&self.text);
+                }
+                if !self.payload.is_empty() {
+                    out.key("payload");
+                    out.write_str(&String::from_utf8_lossy(&self.payload));
+                }
+                if !self.name.is_empty() {
+                    out.key("name");
+                    out.write_str(&self.name);
+                }
+                if !self.label.is_empty() {
+                    out.key("label");
+                    out.write_str(&self.label);
+                }
+                if !self.lines.is_empty() {
+                    out.key("lines");
+                    out.begin_array();
+                    for elem in &self.lines {
+                        out.write_str(elem);
+                    }
+                    out.end_array();
+                }
+                if let Some(elem) = &self.note {
+                    out.key("note");
+                    out.write_str(&String::from_utf8_lossy(elem));
+                }
+                if !self.tags.is_empty() {
+                    out.key("tags");
+                    out.begin_object();
+                    for (key, value) in &self.tags {
+                        out.key(key);
+                        out.write_str(value);
+                    }
+                    out.end_object();
+                }
+                if let MemoBody::Plain(elem) = &self.body {
+                    out.key("plain");
+                    out.write_str(elem);
+                }
+                if let MemoBody::Blob(elem) = &self.body {
+                    out.key("blob");
+                    out.write_str(&String::from_utf8_lossy(elem));
+                }
+                if self.seq != 0 {
+                    out.key("seq");
+                    out.write_u64(self.seq);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "text" => msg.text = json_to_string(value)?,
+                        "payload" => msg.payload = json_to_string(value)?.into_bytes(),
+                        "name" => msg.name = json_to_string(value)?,
+                        "label" => msg.label = json_to_string(value)?,
+                        "lines" => {
+                            for elem in json_array(value)? {
+                                msg.lines.push(json_to_string(elem)?);
+                            }
+                        }
+                        "note" => msg.note = Some(json_to_string(value)?.into_bytes()),
+                        "tags" => {
+                            for (key, value) in json_object(value)? {
+                                msg.tags.insert(json_map_key(key)?, json_to_string(value)?);
+                            }
+                        }
+                        "plain" => msg.body = MemoBody::Plain(json_to_string(value)?),
+                        "blob" => msg.body = MemoBody::Blob(json_to_string(value)?.into_bytes()),
+                        "seq" => msg.seq = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct MemoRef<'a> {
+            raw: &'a [u8],
+            pub text: &'a [u8],
+            pub payload: &'a [u8],
+            pub name: &'a str,
+            pub label: &'a [u8],
+            pub lines: RepeatedRef<'a, &'a [u8]>,
+            pub note: Option<&'a [u8]>,
+            pub plain: Option<&'a [u8]>,
+            pub blob: Option<&'a [u8]>,
+            pub seq: u64,
+        }
+
+        impl<'a> MemoRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = MemoRef {
+                    raw: data,
+                    text: &[],
+                    payload: &[],
+                    name: "",
+                    label: &[],
+                    lines: RepeatedRef::new(data, 42, decode_bytes_ref),
+                    note: None,
+                    plain: None,
+                    blob: None,
+                    seq: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.text = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.payload = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.name = v;
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.label = within_max_len(v, 6, 4)?;
+                        pos = new_pos;
+                    }
+                    42 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    50 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.note = Some(v);
+                        pos = new_pos;
+                    }
+                    66 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.plain = Some(v);
+                        view.blob = None;
+                        pos = new_pos;
+                    }
+                    74 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.blob = Some(v);
+                        view.plain = None;
+                        pos = new_pos;
+                    }
+                    80 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.seq = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Memo::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Memo, DecodeError> {
+                Memo::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Memo(Memo),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Memo::FULL_NAME => AnyMessage::Memo(Memo::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Memo(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
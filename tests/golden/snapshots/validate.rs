@@ -0,0 +1,1960 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod validate {
+        // Auto-generated by protoc-gen-solana from validate.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum CreateMarketOracle {
+            Unset,
+            Feed(String),
+            FixedPrice(u32),
+        }
+
+        impl CreateMarketOracle {
+            pub const DEFAULT: Self = CreateMarketOracle::Unset;
+        }
+
+        impl Default for CreateMarketOracle {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: validate() from (solana.min) / (solana.max) / (solana.min_len) /
+        /// (solana.prefix) / (solana.suffix) / (solana.non_default) rules
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct CreateMarket {
+            pub authority: Pubkey,
+            pub symbol: String,
+            pub fee_bps: u32,
+            pub min_price: i64,
+            pub tags: Vec<String>,
+            pub cap: Option<u64>,
+            pub leverage: Option<u32>,
+            pub config: MarketConfig,
+            pub history: Vec<MarketConfig>,
+            pub by_region: BTreeMap<String, MarketConfig>,
+            pub oracle: CreateMarketOracle,
+            pub tiers: Vec<u64>,
+        }
+
+        impl CreateMarket {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = CreateMarket {
+                authority: ZERO_PUBKEY,
+                symbol: String::new(),
+                fee_bps: 0,
+                min_price: 0,
+                tags: Vec::new(),
+                cap: None,
+                leverage: None,
+                config: MarketConfig::DEFAULT,
+                history: Vec::new(),
+                by_region: BTreeMap::new(),
+                oracle: CreateMarketOracle::Unset,
+                tiers: Vec::new(),
+            };
+        }
+
+        impl Default for CreateMarket {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl CreateMarket {
+            pub const AUTHORITY_FIELD_NUMBER: u32 = 1;
+            pub const SYMBOL_FIELD_NUMBER: u32 = 2;
+            pub const FEE_BPS_FIELD_NUMBER: u32 = 3;
+            pub const MIN_PRICE_FIELD_NUMBER: u32 = 4;
+            pub const TAGS_FIELD_NUMBER: u32 = 5;
+            pub const CAP_FIELD_NUMBER: u32 = 6;
+            pub const LEVERAGE_FIELD_NUMBER: u32 = 7;
+            pub const CONFIG_FIELD_NUMBER: u32 = 8;
+            pub const HISTORY_FIELD_NUMBER: u32 = 9;
+            pub const BY_REGION_FIELD_NUMBER: u32 = 10;
+            pub const FEED_FIELD_NUMBER: u32 = 11;
+            pub const FIXED_PRICE_FIELD_NUMBER: u32 = 12;
+            pub const TIERS_FIELD_NUMBER: u32 = 13;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "CreateMarket.authority" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "CreateMarket.symbol" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "CreateMarket.fee_bps" },
+                FieldWireType { number: 4, wire_type: 0, packed: false, name: "CreateMarket.min_price" },
+                FieldWireType { number: 5, wire_type: 2, packed: false, name: "CreateMarket.tags" },
+                FieldWireType { number: 6, wire_type: 0, packed: false, name: "CreateMarket.cap" },
+                FieldWireType { number: 7, wire_type: 0, packed: false, name: "CreateMarket.leverage" },
+                FieldWireType { number: 8, wire_type: 2, packed: false, name: "CreateMarket.config" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "CreateMarket.history" },
+                FieldWireType { number: 10, wire_type: 2, packed: false, name: "CreateMarket.by_region" },
+                FieldWireType { number: 11, wire_type: 2, packed: false, name: "CreateMarket.feed" },
+                FieldWireType { number: 12, wire_type: 0, packed: false, name: "CreateMarket.fixed_price" },
+                FieldWireType { number: 13, wire_type: 0, packed: true, name: "CreateMarket.tiers" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 13: tiers
+                if !self.tiers.is_empty() {
+                    w.write_nested(13, |w| {
+                        for elem in self.tiers.iter().rev() {
+                            w.write_varint(*elem);
+                        }
+                    });
+                }
+
+                // field 12: fixed_price
+                if let CreateMarketOracle::FixedPrice(elem) = &self.oracle {
+                    w.write_varint(*elem as u64);
+                    w.write_key(0x60);
+                }
+
+                // field 11: feed
+                if let CreateMarketOracle::Feed(elem) = &self.oracle {
+                    w.write_string(elem);
+                    w.write_key(0x5a);
+                }
+
+                // field 10: by_region
+                for (key, value) in self.by_region.iter().rev() {
+                    w.write_nested(10, |w| {
+                        w.write_message(2, value);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 9: history
+                for elem in self.history.iter().rev() {
+                    w.write_message(9, elem);
+                }
+
+                // field 8: config
+                w.write_message(8, &self.config);
+
+                // field 7: leverage
+                if let Some(elem) = &self.leverage {
+                    w.write_nested(7, |w| UInt32Value::write_value_reverse(elem, w));
+                }
+
+                // field 6: cap
+                if let Some(elem) = &self.cap {
+                    w.write_varint(*elem);
+                    w.write_key(0x30);
+                }
+
+                // field 5: tags
+                for elem in self.tags.iter().rev() {
+                    w.write_string(elem);
+                    w.write_key(0x2a);
+                }
+
+                // field 4: min_price
+                if self.min_price != 0 {
+                    w.write_int64(self.min_price);
+                    w.write_key(0x20);
+                }
+
+                // field 3: fee_bps
+                if self.fee_bps != 0 {
+                    w.write_varint(self.fee_bps as u64);
+                    w.write_key(0x18);
+                }
+
+                // field 2: symbol
+                if !self.symbol.is_empty() {
+                    w.write_string(&self.symbol);
+                    w.write_key(0x12);
+                }
+
+                // field 1: authority
+                if self.authority != Pubkey::default() {
+                    w.write_pubkey(&self.authority);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if !self.symbol.is_empty() {
+                    len += key_len(2) + bytes_len(self.symbol.len());
+                }
+                if self.fee_bps != 0 {
+                    len += key_len(3) + varint_len(self.fee_bps as u64);
+                }
+                if self.min_price != 0 {
+                    len += key_len(4) + int64_len(self.min_price);
+                }
+                for elem in &self.tags {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.cap {
+                    len += key_len(6) + varint_len(*elem);
+                }
+                if let Some(elem) = &self.leverage {
+                    len += key_len(7) + bytes_len(UInt32Value::value_len(elem));
+                }
+                len += key_len(8) + bytes_len(self.config.encoded_len());
+                for elem in &self.history {
+                    len += key_len(9) + bytes_len(elem.encoded_len());
+                }
+                for (key, value) in &self.by_region {
+                    len += key_len(10) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len()));
+                }
+                if let CreateMarketOracle::Feed(elem) = &self.oracle {
+                    len += key_len(11) + bytes_len(elem.len());
+                }
+                if let CreateMarketOracle::FixedPrice(elem) = &self.oracle {
+                    len += key_len(12) + varint_len(*elem as u64);
+                }
+                if !self.tiers.is_empty() {
+                    len += key_len(13) + bytes_len(self.tiers.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                if !self.symbol.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.symbol)?;
+                }
+                if self.fee_bps != 0 {
+                    w.write_key(0x18)?;
+                    w.write_varint(self.fee_bps as u64)?;
+                }
+                if self.min_price != 0 {
+                    w.write_key(0x20)?;
+                    w.write_int64(self.min_price)?;
+                }
+                for elem in &self.tags {
+                    w.write_key(0x2a)?;
+                    w.write_string(elem)?;
+                }
+                if let Some(elem) = &self.cap {
+                    w.write_key(0x30)?;
+                    w.write_varint(*elem)?;
+                }
+                if let Some(elem) = &self.leverage {
+                    w.write_key(0x3a)?;
+                    w.write_varint(UInt32Value::value_len(elem) as u64)?;
+                    UInt32Value::write_value(elem, w)?;
+                }
+                w.write_message(8, &self.config)?;
+                for elem in &self.history {
+                    w.write_message(9, elem)?;
+                }
+                for (key, value) in &self.by_region {
+                    w.write_key(0x52)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message(2, value)?;
+                }
+                if let CreateMarketOracle::Feed(elem) = &self.oracle {
+                    w.write_key(0x5a)?;
+                    w.write_string(elem)?;
+                }
+                if let CreateMarketOracle::FixedPrice(elem) = &self.oracle {
+                    w.write_key(0x60)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if !self.tiers.is_empty() {
+                    w.write_key(0x6a)?;
+                    w.write_varint(self.tiers.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.tiers {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.authority != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if !self.symbol.is_empty() {
+                    len += key_len(2) + bytes_len(self.symbol.len());
+                }
+                if self.fee_bps != 0 {
+                    len += key_len(3) + varint_len(self.fee_bps as u64);
+                }
+                if self.min_price != 0 {
+                    len += key_len(4) + int64_len(self.min_price);
+                }
+                for elem in &self.tags {
+                    len += key_len(5) + bytes_len(elem.len());
+                }
+                if let Some(elem) = &self.cap {
+                    len += key_len(6) + varint_len(*elem);
+                }
+                if let Some(elem) = &self.leverage {
+                    len += key_len(7) + bytes_len(UInt32Value::value_len(elem));
+                }
+                len += key_len(8) + bytes_len(sizes.record(&self.config));
+                for elem in &self.history {
+                    len += key_len(9) + bytes_len(sizes.record(elem));
+                }
+                for (key, value) in &self.by_region {
+                    len += key_len(10) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.record(value)));
+                }
+                if let CreateMarketOracle::Feed(elem) = &self.oracle {
+                    len += key_len(11) + bytes_len(elem.len());
+                }
+                if let CreateMarketOracle::FixedPrice(elem) = &self.oracle {
+                    len += key_len(12) + varint_len(*elem as u64);
+                }
+                if !self.tiers.is_empty() {
+                    len += key_len(13) + bytes_len(self.tiers.iter().map(|elem| varint_len(*elem)).sum::<usize>());
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.authority != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.authority)?;
+                }
+                if !self.symbol.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.symbol)?;
+                }
+                if self.fee_bps != 0 {
+                    w.write_key(0x18)?;
+                    w.write_varint(self.fee_bps as u64)?;
+                }
+                if self.min_price != 0 {
+                    w.write_key(0x20)?;
+                    w.write_int64(self.min_price)?;
+                }
+                for elem in &self.tags {
+                    w.write_key(0x2a)?;
+                    w.write_string(elem)?;
+                }
+                if let Some(elem) = &self.cap {
+                    w.write_key(0x30)?;
+                    w.write_varint(*elem)?;
+                }
+                if let Some(elem) = &self.leverage {
+                    w.write_key(0x3a)?;
+                    w.write_varint(UInt32Value::value_len(elem) as u64)?;
+                    UInt32Value::write_value(elem, w)?;
+                }
+                w.write_message_sized(8, &self.config, sizes)?;
+                for elem in &self.history {
+                    w.write_message_sized(9, elem, sizes)?;
+                }
+                for (key, value) in &self.by_region {
+                    w.write_key(0x52)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message_sized(2, value, sizes)?;
+                }
+                if let CreateMarketOracle::Feed(elem) = &self.oracle {
+                    w.write_key(0x5a)?;
+                    w.write_string(elem)?;
+                }
+                if let CreateMarketOracle::FixedPrice(elem) = &self.oracle {
+                    w.write_key(0x60)?;
+                    w.write_varint(*elem as u64)?;
+                }
+                if !self.tiers.is_empty() {
+                    w.write_key(0x6a)?;
+                    w.write_varint(self.tiers.iter().map(|elem| varint_len(*elem)).sum::<usize>() as u64)?;
+                    for elem in &self.tiers {
+                        w.write_varint(*elem)?;
+                    }
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.authority = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_bounded(data, pos, opts, 10, 2)?;
+                            self.symbol = v;
+                            pos = new_pos;
+                        }
+                        24 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.fee_bps = v as u32;
+                            pos = new_pos;
+                        }
+                        32 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (v, new_pos) = decode_int64(data, pos)?;
+                            self.min_price = v;
+                            pos = new_pos;
+                        }
+                        42 => {
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            if self.tags.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            if self.tags.len() >= 4 {
+                                return Err(DecodeError::LimitExceeded { field: 5 });
+                            }
+                            self.tags.push(v);
+                            pos = new_pos;
+                        }
+                        48 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.cap = Some(v);
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.leverage.get_or_insert_with(Default::default);
+                            UInt32Value::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        66 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.config.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        74 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.history.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.history.push(MarketConfig::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        82 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = MarketConfig::DEFAULT;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_bounded(entry, entry_pos, opts, 4, 10)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                        value.merge_with(sub, &opts.nested()?)?;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.by_region.len() >= opts.max_repeated_len && !self.by_region.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.by_region.insert(key, value);
+                            pos = new_pos;
+                        }
+                        90 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.oracle = CreateMarketOracle::Feed(v);
+                            pos = new_pos;
+                        }
+                        96 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.oracle = CreateMarketOracle::FixedPrice(v as u32);
+                            pos = new_pos;
+                        }
+                        104 => {
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            if self.tiers.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.tiers.push(v);
+                            pos = new_pos;
+                        }
+                        106 => {
+                            let new_pos = decode_packed_varint_with(data, pos, &mut self.tiers, opts)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    5 => Some(self.tags.len()),
+                    9 => Some(self.history.len()),
+                    13 => Some(self.tiers.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for CreateMarket {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                CreateMarket::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                CreateMarket::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                CreateMarket::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                CreateMarket::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                CreateMarket::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                CreateMarket::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                CreateMarket::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for CreateMarket {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                CreateMarket::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<CreateMarket> for Vec<u8> {
+            fn from(msg: CreateMarket) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for CreateMarket {
+            const FULL_NAME: &'static str = "example.CreateMarket";
+        }
+
+        impl CreateMarket {
+            /// Check the `(solana.*)` field rules, those of sub-messages
+            /// included; decoding does not. Returns the first rule broken.
+            pub fn validate(&self) -> Result<(), ValidationError> {
+                if self.authority == ZERO_PUBKEY {
+                    return Err(ValidationError::new("authority", ValidationRule::NonDefault));
+                }
+
+                if self.symbol.len() < 3 {
+                    return Err(ValidationError::new("symbol", ValidationRule::MinLen(3)));
+                }
+                if self.symbol.len() > 10 {
+                    return Err(ValidationError::new("symbol", ValidationRule::MaxLen(10)));
+                }
+                if !self.symbol.ends_with("-PERP") {
+                    return Err(ValidationError::new("symbol", ValidationRule::Suffix("-PERP")));
+                }
+
+                if self.fee_bps > 10000 {
+                    return Err(ValidationError::new("fee_bps", ValidationRule::Max(10000)));
+                }
+
+                if self.min_price < -1000 {
+                    return Err(ValidationError::new("min_price", ValidationRule::Min(-1000)));
+                }
+                if self.min_price > 1000 {
+                    return Err(ValidationError::new("min_price", ValidationRule::Max(1000)));
+                }
+
+                for (i, v) in self.tags.iter().enumerate() {
+                    if !v.starts_with("t:") {
+                        return Err(ValidationError::element("tags", i, ValidationRule::Prefix("t:")));
+                    }
+                }
+                if self.tags.len() > 4 {
+                    return Err(ValidationError::new("tags", ValidationRule::MaxCount(4)));
+                }
+
+                if let Some(v) = &self.cap {
+                    if *v < 1 {
+                        return Err(ValidationError::new("cap", ValidationRule::Min(1)));
+                    }
+                }
+
+                if let Some(v) = &self.leverage {
+                    if *v < 1 {
+                        return Err(ValidationError::new("leverage", ValidationRule::Min(1)));
+                    }
+                    if *v > 20 {
+                        return Err(ValidationError::new("leverage", ValidationRule::Max(20)));
+                    }
+                }
+
+                self.config.validate().map_err(|e| e.within("config"))?;
+
+                for (i, v) in self.history.iter().enumerate() {
+                    v.validate().map_err(|e| e.within_element("history", i))?;
+                }
+
+                for (k, v) in &self.by_region {
+                    if k.len() > 4 {
+                        return Err(ValidationError::element("by_region", k, ValidationRule::MaxLen(4)));
+                    }
+                    v.validate().map_err(|e| e.within_element("by_region", k))?;
+                }
+
+                if let CreateMarketOracle::Feed(v) = &self.oracle {
+                    if !v.starts_with("pyth:") {
+                        return Err(ValidationError::new("feed", ValidationRule::Prefix("pyth:")));
+                    }
+                }
+
+                if let CreateMarketOracle::FixedPrice(v) = &self.oracle {
+                    if *v < 1 {
+                        return Err(ValidationError::new("fixed_price", ValidationRule::Min(1)));
+                    }
+                }
+
+                if self.tiers.is_empty() {
+                    return Err(ValidationError::new("tiers", ValidationRule::NonDefault));
+                }
+
+                Ok(())
+            }
+        }
+
+        impl CreateMarket {
+            pub fn feed(&self) -> Option<&String> {
+                match &self.oracle {
+                    CreateMarketOracle::Feed(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_feed(&mut self, value: String) {
+                self.oracle = CreateMarketOracle::Feed(value);
+            }
+
+            pub fn fixed_price(&self) -> Option<&u32> {
+                match &self.oracle {
+                    CreateMarketOracle::FixedPrice(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_fixed_price(&mut self, value: u32) {
+                self.oracle = CreateMarketOracle::FixedPrice(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct CreateMarketBuilder {
+            inner: CreateMarket,
+        }
+
+        impl CreateMarket {
+            pub fn builder() -> CreateMarketBuilder {
+                CreateMarketBuilder::default()
+            }
+        }
+
+        impl CreateMarketBuilder {
+            pub fn with_authority(mut self, value: Pubkey) -> Self {
+                self.inner.authority = value;
+                self
+            }
+
+            pub fn with_symbol(mut self, value: String) -> Self {
+                self.inner.symbol = value;
+                self
+            }
+
+            pub fn with_fee_bps(mut self, value: u32) -> Self {
+                self.inner.fee_bps = value;
+                self
+            }
+
+            pub fn with_min_price(mut self, value: i64) -> Self {
+                self.inner.min_price = value;
+                self
+            }
+
+            pub fn with_tags(mut self, value: Vec<String>) -> Self {
+                self.inner.tags = value;
+                self
+            }
+
+            pub fn add_tags(mut self, value: String) -> Self {
+                self.inner.tags.push(value);
+                self
+            }
+
+            pub fn with_cap(mut self, value: u64) -> Self {
+                self.inner.cap = Some(value);
+                self
+            }
+
+            pub fn with_leverage(mut self, value: u32) -> Self {
+                self.inner.leverage = Some(value);
+                self
+            }
+
+            pub fn with_config(mut self, value: MarketConfig) -> Self {
+                self.inner.config = value;
+                self
+            }
+
+            pub fn with_history(mut self, value: Vec<MarketConfig>) -> Self {
+                self.inner.history = value;
+                self
+            }
+
+            pub fn add_history(mut self, value: MarketConfig) -> Self {
+                self.inner.history.push(value);
+                self
+            }
+
+            pub fn with_by_region(mut self, value: BTreeMap<String, MarketConfig>) -> Self {
+                self.inner.by_region = value;
+                self
+            }
+
+            pub fn insert_by_region(mut self, key: String, value: MarketConfig) -> Self {
+                self.inner.by_region.insert(key, value);
+                self
+            }
+
+            pub fn with_feed(mut self, value: String) -> Self {
+                self.inner.oracle = CreateMarketOracle::Feed(value);
+                self
+            }
+
+            pub fn with_fixed_price(mut self, value: u32) -> Self {
+                self.inner.oracle = CreateMarketOracle::FixedPrice(value);
+                self
+            }
+
+            pub fn with_tiers(mut self, value: Vec<u64>) -> Self {
+                self.inner.tiers = value;
+                self
+            }
+
+            pub fn add_tiers(mut self, value: u64) -> Self {
+                self.inner.tiers.push(value);
+                self
+            }
+
+            pub fn build(self) -> CreateMarket {
+                self.inner
+            }
+        }
+
+        impl CreateMarket {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.authority != Pubkey::default() {
+                    out.write_pubkey("authority", &self.authority);
+                }
+                if !self.symbol.is_empty() {
+                    out.write_str("symbol", &self.symbol);
+                }
+                if self.fee_bps != 0 {
+                    out.write_scalar("fee_bps", self.fee_bps);
+                }
+                if self.min_price != 0 {
+                    out.write_scalar("min_price", self.min_price);
+                }
+                for elem in &self.tags {
+                    out.write_str("tags", elem);
+                }
+                if let Some(elem) = &self.cap {
+                    out.write_scalar("cap", *elem);
+                }
+                if let Some(elem) = &self.leverage {
+                    out.begin_message("leverage");
+                    UInt32Value::write_text_value(elem, out);
+                    out.end_message();
+                }
+                if self.config != MarketConfig::default() {
+                    out.begin_message("config");
+                    self.config.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.history {
+                    out.begin_message("history");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for (key, value) in &self.by_region {
+                    out.begin_message("by_region");
+                    out.write_str("key", key);
+                    out.begin_message("value");
+                    value.write_text(out);
+                    out.end_message();
+                    out.end_message();
+                }
+                if let CreateMarketOracle::Feed(elem) = &self.oracle {
+                    out.write_str("feed", elem);
+                }
+                if let CreateMarketOracle::FixedPrice(elem) = &self.oracle {
+                    out.write_scalar("fixed_price", *elem);
+                }
+                for elem in &self.tiers {
+                    out.write_scalar("tiers", *elem);
+                }
+            }
+        }
+
+        impl core::fmt::Display for CreateMarket {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl CreateMarket {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.authority != Pubkey::default() {
+                    out.key("authority");
+                    out.write_pubkey(self.authority);
+                }
+                if !self.symbol.is_empty() {
+                    out.key("symbol");
+                    out.write_str(&self.symbol);
+                }
+                if self.fee_bps != 0 {
+                    out.key("feeBps");
+                    out.write_u32(self.fee_bps);
+                }
+                if self.min_price != 0 {
+                    out.key("minPrice");
+                    out.write_i64(self.min_price);
+                }
+                if !self.tags.is_empty() {
+                    out.key("tags");
+                    out.begin_array();
+                    for elem in &self.tags {
+                        out.write_str(elem);
+                    }
+                    out.end_array();
+                }
+                if let Some(elem) = &self.cap {
+                    out.key("cap");
+                    out.write_u64(*elem);
+                }
+                if let Some(elem) = &self.leverage {
+                    out.key("leverage");
+                    out.write_u32(*elem);
+                }
+                if self.config != MarketConfig::default() {
+                    out.key("config");
+                    self.config.write_json(out);
+                }
+                if !self.history.is_empty() {
+                    out.key("history");
+                    out.begin_array();
+                    for elem in &self.history {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if !self.by_region.is_empty() {
+                    out.key("byRegion");
+                    out.begin_object();
+                    for (key, value) in &self.by_region {
+                        out.key(key);
+                        value.write_json(out);
+                    }
+                    out.end_object();
+                }
+                if let CreateMarketOracle::Feed(elem) = &self.oracle {
+                    out.key("feed");
+                    out.write_str(elem);
+                }
+                if let CreateMarketOracle::FixedPrice(elem) = &self.oracle {
+                    out.key("fixedPrice");
+                    out.write_u32(*elem);
+                }
+                if !self.tiers.is_empty() {
+                    out.key("tiers");
+                    out.begin_array();
+                    for elem in &self.tiers {
+                        out.write_u64(*elem);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "authority" => msg.authority = json_to_pubkey(value)?,
+                        "symbol" => msg.symbol = json_to_string(value)?,
+                        "feeBps" | "fee_bps" => msg.fee_bps = json_to_u32(value)?,
+                        "minPrice" | "min_price" => msg.min_price = json_to_i64(value)?,
+                        "tags" => {
+                            for elem in json_array(value)? {
+                                msg.tags.push(json_to_string(elem)?);
+                            }
+                        }
+                        "cap" => msg.cap = Some(json_to_u64(value)?),
+                        "leverage" => msg.leverage = Some(json_to_u32(value)?),
+                        "config" => msg.config = MarketConfig::from_json_value(value)?,
+                        "history" => {
+                            for elem in json_array(value)? {
+                                msg.history.push(MarketConfig::from_json_value(elem)?);
+                            }
+                        }
+                        "byRegion" | "by_region" => {
+                            for (key, value) in json_object(value)? {
+                                msg.by_region.insert(json_map_key(key)?, MarketConfig::from_json_value(value)?);
+                            }
+                        }
+                        "feed" => msg.oracle = CreateMarketOracle::Feed(json_to_string(value)?),
+                        "fixedPrice" | "fixed_price" => msg.oracle = CreateMarketOracle::FixedPrice(json_to_u32(value)?),
+                        "tiers" => {
+                            for elem in json_array(value)? {
+                                msg.tiers.push(json_to_u64(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct CreateMarketRef<'a> {
+            raw: &'a [u8],
+            pub authority: Pubkey,
+            pub symbol: &'a str,
+            pub fee_bps: u32,
+            pub min_price: i64,
+            pub tags: RepeatedRef<'a, &'a str>,
+            pub cap: Option<u64>,
+            pub leverage: Option<u32>,
+            pub config: Option<MarketConfigRef<'a>>,
+            pub history: RepeatedRef<'a, MarketConfigRef<'a>>,
+            pub feed: Option<&'a str>,
+            pub fixed_price: Option<u32>,
+            pub tiers: RepeatedRef<'a, u64>,
+        }
+
+        impl<'a> CreateMarketRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = CreateMarketRef {
+                    raw: data,
+                    authority: ZERO_PUBKEY,
+                    symbol: "",
+                    fee_bps: 0,
+                    min_price: 0,
+                    tags: RepeatedRef::new(data, 42, decode_str_ref),
+                    cap: None,
+                    leverage: None,
+                    config: None,
+                    history: RepeatedRef::new(data, 74, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((MarketConfigRef::decode(v)?, new_pos))
+                    }),
+                    feed: None,
+                    fixed_price: None,
+                    tiers: RepeatedRef::packed(data, 104, decode_varint),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.authority = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.symbol = within_max_len(v, 10, 2)?;
+                        pos = new_pos;
+                    }
+                    24 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.fee_bps = v as u32;
+                        pos = new_pos;
+                    }
+                    32 => {
+                        let (v, new_pos) = decode_int64(data, pos)?;
+                        view.min_price = v;
+                        pos = new_pos;
+                    }
+                    42 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    48 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.cap = Some(v);
+                        pos = new_pos;
+                    }
+                    58 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.leverage = Some(wrapper_value_ref(v, 8, |data, pos| decode_varint(data, pos).map(|(v, pos)| (v as u32, pos)))?);
+                        pos = new_pos;
+                    }
+                    66 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.config = Some(MarketConfigRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    74 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    90 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.feed = Some(v);
+                        view.fixed_price = None;
+                        pos = new_pos;
+                    }
+                    96 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.fixed_price = Some(v as u32);
+                        view.feed = None;
+                        pos = new_pos;
+                    }
+                    104 => {
+                        pos = skip_field(data, pos, 0)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(CreateMarket::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<CreateMarket, DecodeError> {
+                CreateMarket::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Only validated through CreateMarket
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct MarketConfig {
+            pub tick_size: u32,
+            pub limits: MarketLimits,
+        }
+
+        impl MarketConfig {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = MarketConfig {
+                tick_size: 0,
+                limits: MarketLimits::DEFAULT,
+            };
+        }
+
+        impl Default for MarketConfig {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl MarketConfig {
+            pub const TICK_SIZE_FIELD_NUMBER: u32 = 1;
+            pub const LIMITS_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "MarketConfig.tick_size" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "MarketConfig.limits" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: limits
+                w.write_message(2, &self.limits);
+
+                // field 1: tick_size
+                if self.tick_size != 0 {
+                    w.write_varint(self.tick_size as u64);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.tick_size != 0 {
+                    len += key_len(1) + varint_len(self.tick_size as u64);
+                }
+                len += key_len(2) + bytes_len(self.limits.encoded_len());
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.tick_size != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.tick_size as u64)?;
+                }
+                w.write_message(2, &self.limits)?;
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                if self.tick_size != 0 {
+                    len += key_len(1) + varint_len(self.tick_size as u64);
+                }
+                len += key_len(2) + bytes_len(sizes.record(&self.limits));
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                if self.tick_size != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.tick_size as u64)?;
+                }
+                w.write_message_sized(2, &self.limits, sizes)?;
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.tick_size = v as u32;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.limits.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for MarketConfig {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                MarketConfig::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                MarketConfig::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                MarketConfig::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                MarketConfig::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                MarketConfig::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                MarketConfig::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                MarketConfig::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for MarketConfig {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                MarketConfig::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<MarketConfig> for Vec<u8> {
+            fn from(msg: MarketConfig) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for MarketConfig {
+            const FULL_NAME: &'static str = "example.MarketConfig";
+        }
+
+        impl MarketConfig {
+            /// Longest encoding of a `MarketConfig` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 19;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        impl MarketConfig {
+            /// Check the `(solana.*)` field rules, those of sub-messages
+            /// included; decoding does not. Returns the first rule broken.
+            pub fn validate(&self) -> Result<(), ValidationError> {
+                if self.tick_size < 1 {
+                    return Err(ValidationError::new("tick_size", ValidationRule::Min(1)));
+                }
+
+                Ok(())
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct MarketConfigBuilder {
+            inner: MarketConfig,
+        }
+
+        impl MarketConfig {
+            pub fn builder() -> MarketConfigBuilder {
+                MarketConfigBuilder::default()
+            }
+        }
+
+        impl MarketConfigBuilder {
+            pub fn with_tick_size(mut self, value: u32) -> Self {
+                self.inner.tick_size = value;
+                self
+            }
+
+            pub fn with_limits(mut self, value: MarketLimits) -> Self {
+                self.inner.limits = value;
+                self
+            }
+
+            pub fn build(self) -> MarketConfig {
+                self.inner
+            }
+        }
+
+        impl MarketConfig {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.tick_size != 0 {
+                    out.write_scalar("tick_size", self.tick_size);
+                }
+                if self.limits != MarketLimits::default() {
+                    out.begin_message("limits");
+                    self.limits.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for MarketConfig {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl MarketConfig {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.tick_size != 0 {
+                    out.key("tickSize");
+                    out.write_u32(self.tick_size);
+                }
+                if self.limits != MarketLimits::default() {
+                    out.key("limits");
+                    self.limits.write_json(out);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "tickSize" | "tick_size" => msg.tick_size = json_to_u32(value)?,
+                        "limits" => msg.limits = MarketLimits::from_json_value(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct MarketConfigRef<'a> {
+            raw: &'a [u8],
+            pub tick_size: u32,
+            pub limits: Option<MarketLimitsRef<'a>>,
+        }
+
+        impl<'a> MarketConfigRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = MarketConfigRef {
+                    raw: data,
+                    tick_size: 0,
+                    limits: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.tick_size = v as u32;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.limits = Some(MarketLimitsRef::decode(v)?);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(MarketConfig::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<MarketConfig, DecodeError> {
+                MarketConfig::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// No rules: no validate(); MarketConfig does not call it
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct MarketLimits {
+            pub max_open: u64,
+        }
+
+        impl MarketLimits {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = MarketLimits {
+                max_open: 0,
+            };
+        }
+
+        impl Default for MarketLimits {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl MarketLimits {
+            pub const MAX_OPEN_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "MarketLimits.max_open" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: max_open
+                if self.max_open != 0 {
+                    w.write_varint(self.max_open);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.max_open != 0 {
+                    len += key_len(1) + varint_len(self.max_open);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.max_open != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.max_open)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.max_open = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for MarketLimits {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                MarketLimits::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                MarketLimits::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                MarketLimits::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                MarketLimits::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                MarketLimits::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                MarketLimits::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                MarketLimits::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for MarketLimits {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                MarketLimits::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<MarketLimits> for Vec<u8> {
+            fn from(msg: MarketLimits) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for MarketLimits {
+            const FULL_NAME: &'static str = "example.MarketLimits";
+        }
+
+        impl MarketLimits {
+            /// Longest encoding of a `MarketLimits` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 11;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct MarketLimitsBuilder {
+            inner: MarketLimits,
+        }
+
+        impl MarketLimits {
+            pub fn builder() -> MarketLimitsBuilder {
+                MarketLimitsBuilder::default()
+            }
+        }
+
+        impl MarketLimitsBuilder {
+            pub fn with_max_open(mut self, value: u64) -> Self {
+                self.inner.max_open = value;
+                self
+            }
+
+            pub fn build(self) -> MarketLimits {
+                self.inner
+            }
+        }
+
+        impl MarketLimits {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.max_open != 0 {
+                    out.write_scalar("max_open", self.max_open);
+                }
+            }
+        }
+
+        impl core::fmt::Display for MarketLimits {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl MarketLimits {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.max_open != 0 {
+                    out.key("maxOpen");
+                    out.write_u64(self.max_open);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "maxOpen" | "max_open" => msg.max_open = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct MarketLimitsRef<'a> {
+            raw: &'a [u8],
+            pub max_open: u64,
+        }
+
+        impl<'a> MarketLimitsRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = MarketLimitsRef {
+                    raw: data,
+                    max_open: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.max_open = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(MarketLimits::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<MarketLimits, DecodeError> {
+                MarketLimits::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            CreateMarket(CreateMarket),
+            MarketConfig(MarketConfig),
+            MarketLimits(MarketLimits),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    CreateMarket::FULL_NAME => AnyMessage::CreateMarket(CreateMarket::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    MarketConfig::FULL_NAME => AnyMessage::MarketConfig(MarketConfig::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    MarketLimits::FULL_NAME => AnyMessage::MarketLimits(MarketLimits::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::CreateMarket(msg) => Any::pack(msg),
+                    AnyMessage::MarketConfig(msg) => Any::pack(msg),
+                    AnyMessage::MarketLimits(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
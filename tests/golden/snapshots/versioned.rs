@@ -0,0 +1,803 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod versioned {
+        // Auto-generated by protoc-gen-solana from versioned.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: (solana.version) at 1, with no migrations
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct GaugeV1 {
+            pub reading: u64,
+        }
+
+        impl GaugeV1 {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = GaugeV1 {
+                reading: 0,
+            };
+        }
+
+        impl Default for GaugeV1 {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl GaugeV1 {
+            pub const READING_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "GaugeV1.reading" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: reading
+                if self.reading != 0 {
+                    w.write_varint(self.reading);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.reading != 0 {
+                    len += key_len(1) + varint_len(self.reading);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.reading != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.reading)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.reading = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for GaugeV1 {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                GaugeV1::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                GaugeV1::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                GaugeV1::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                GaugeV1::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                GaugeV1::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                GaugeV1::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                GaugeV1::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for GaugeV1 {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                GaugeV1::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<GaugeV1> for Vec<u8> {
+            fn from(msg: GaugeV1) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for GaugeV1 {
+            const FULL_NAME: &'static str = "example.GaugeV1";
+        }
+
+        impl GaugeV1 {
+            /// Longest encoding of a `GaugeV1` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 11;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        impl GaugeV1 {
+            /// The schema version `encode_versioned` writes.
+            pub const VERSION: u8 = 1;
+
+            /// The version byte, then the protobuf encoding.
+            pub fn encode_versioned(&self) -> Vec<u8> {
+                encode_versioned(Self::VERSION, self)
+            }
+
+            /// Decode `encode_versioned` data; other versions fail with `UnknownVersion`.
+            pub fn decode_versioned(data: &[u8]) -> Result<Self, DecodeError> {
+                match split_version(data)? {
+                    (Self::VERSION, payload) => Self::decode_with(payload, &DecodeOptions::ACCOUNT),
+                    (version, _) => Err(DecodeError::UnknownVersion { version }),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct GaugeV1Builder {
+            inner: GaugeV1,
+        }
+
+        impl GaugeV1 {
+            pub fn builder() -> GaugeV1Builder {
+                GaugeV1Builder::default()
+            }
+        }
+
+        impl GaugeV1Builder {
+            pub fn with_reading(mut self, value: u64) -> Self {
+                self.inner.reading = value;
+                self
+            }
+
+            pub fn build(self) -> GaugeV1 {
+                self.inner
+            }
+        }
+
+        impl GaugeV1 {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.reading != 0 {
+                    out.write_scalar("reading", self.reading);
+                }
+            }
+        }
+
+        impl core::fmt::Display for GaugeV1 {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl GaugeV1 {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.reading != 0 {
+                    out.key("reading");
+                    out.write_u64(self.reading);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "reading" => msg.reading = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct GaugeV1Ref<'a> {
+            raw: &'a [u8],
+            pub reading: u64,
+        }
+
+        impl<'a> GaugeV1Ref<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = GaugeV1Ref {
+                    raw: data,
+                    reading: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.reading = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(GaugeV1::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<GaugeV1, DecodeError> {
+                GaugeV1::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Exercises: (solana.version) at 2, migrating version 1 data whose
+        /// reading was in whole units
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Gauge {
+            pub reading_milli: u64,
+            pub label: String,
+        }
+
+        impl Gauge {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Gauge {
+                reading_milli: 0,
+                label: String::new(),
+            };
+        }
+
+        impl Default for Gauge {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Gauge {
+            pub const READING_MILLI_FIELD_NUMBER: u32 = 1;
+            pub const LABEL_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "Gauge.reading_milli" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Gauge.label" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: label
+                if !self.label.is_empty() {
+                    w.write_string(&self.label);
+                    w.write_key(0x12);
+                }
+
+                // field 1: reading_milli
+                if self.reading_milli != 0 {
+                    w.write_varint(self.reading_milli);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.reading_milli != 0 {
+                    len += key_len(1) + varint_len(self.reading_milli);
+                }
+                if !self.label.is_empty() {
+                    len += key_len(2) + bytes_len(self.label.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.reading_milli != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.reading_milli)?;
+                }
+                if !self.label.is_empty() {
+                    w.write_key(0x12)?;
+                    w.write_string(&self.label)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.reading_milli = v;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.label = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Gauge {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Gauge::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Gauge::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Gauge::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Gauge::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Gauge::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Gauge::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Gauge::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Gauge {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Gauge::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Gauge> for Vec<u8> {
+            fn from(msg: Gauge) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Gauge {
+            const FULL_NAME: &'static str = "example.Gauge";
+        }
+
+        /// Conversions of `Gauge` data written at earlier `(solana.version)`s,
+        /// implemented by the program for `Gauge::decode_versioned`.
+        pub trait GaugeMigrations {
+            /// A `Gauge` from the payload of data written at version 1.
+            fn migrate_from_v1(payload: &[u8]) -> Result<Gauge, DecodeError>;
+        }
+
+        impl Gauge {
+            /// The schema version `encode_versioned` writes.
+            pub const VERSION: u8 = 2;
+
+            /// The version byte, then the protobuf encoding.
+            pub fn encode_versioned(&self) -> Vec<u8> {
+                encode_versioned(Self::VERSION, self)
+            }
+
+            /// Decode `encode_versioned` data, migrating data from earlier versions with `M`.
+            pub fn decode_versioned<M: GaugeMigrations>(data: &[u8]) -> Result<Self, DecodeError> {
+                match split_version(data)? {
+                    (Self::VERSION, payload) => Self::decode_with(payload, &DecodeOptions::ACCOUNT),
+                    (1, payload) => M::migrate_from_v1(payload),
+                    (version, _) => Err(DecodeError::UnknownVersion { version }),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct GaugeBuilder {
+            inner: Gauge,
+        }
+
+        impl Gauge {
+            pub fn builder() -> GaugeBuilder {
+                GaugeBuilder::default()
+            }
+        }
+
+        impl GaugeBuilder {
+            pub fn with_reading_milli(mut self, value: u64) -> Self {
+                self.inner.reading_milli = value;
+                self
+            }
+
+            pub fn with_label(mut self, value: String) -> Self {
+                self.inner.label = value;
+                self
+            }
+
+            pub fn build(self) -> Gauge {
+                self.inner
+            }
+        }
+
+        impl Gauge {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.reading_milli != 0 {
+                    out.write_scalar("reading_milli", self.reading_milli);
+                }
+                if !self.label.is_empty() {
+                    out.write_str("label", &self.label);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Gauge {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Gauge {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.reading_milli != 0 {
+                    out.key("readingMilli");
+                    out.write_u64(self.reading_milli);
+                }
+                if !self.label.is_empty() {
+                    out.key("label");
+                    out.write_str(&self.label);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "readingMilli" | "reading_milli" => msg.reading_milli = json_to_u64(value)?,
+                        "label" => msg.label = json_to_string(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct GaugeRef<'a> {
+            raw: &'a [u8],
+            pub reading_milli: u64,
+            pub label: &'a str,
+        }
+
+        impl<'a> GaugeRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = GaugeRef {
+                    raw: data,
+                    reading_milli: 0,
+                    label: "",
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.reading_milli = v;
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.label = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Gauge::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Gauge, DecodeError> {
+                Gauge::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            GaugeV1(GaugeV1),
+            Gauge(Gauge),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    GaugeV1::FULL_NAME => AnyMessage::GaugeV1(GaugeV1::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Gauge::FULL_NAME => AnyMessage::Gauge(Gauge::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::GaugeV1(msg) => Any::pack(msg),
+                    AnyMessage::Gauge(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,2656 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod events {
+        // Auto-generated by protoc-gen-solana from events.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        /// Exercises: (solana.event) messages with emit() / from_log() and the
+        /// file's AnyEvent registry
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Deposited {
+            pub owner: Pubkey,
+            pub amount: u64,
+        }
+
+        impl Deposited {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Deposited {
+                owner: ZERO_PUBKEY,
+                amount: 0,
+            };
+        }
+
+        impl Default for Deposited {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Deposited {
+            pub const OWNER_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Deposited.owner" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Deposited.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: owner
+                if self.owner != Pubkey::default() {
+                    w.write_pubkey(&self.owner);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.owner != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.owner != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.owner)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.owner = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Deposited {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Deposited::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Deposited::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Deposited::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Deposited::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Deposited::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Deposited::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Deposited::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Deposited {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Deposited::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Deposited> for Vec<u8> {
+            fn from(msg: Deposited) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Deposited {
+            const FULL_NAME: &'static str = "example.Deposited";
+        }
+
+        impl Deposited {
+            /// Longest encoding of a `Deposited` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 45;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        impl Deposited {
+            /// `sha256("event:example.Deposited")[..8]`
+            pub const EVENT_DISCRIMINATOR: [u8; 8] = [206, 72, 159, 107, 223, 115, 65, 67];
+
+            /// Log this event to the transaction with `sol_log_data`.
+            #[cfg(feature = "solana-program")]
+            pub fn emit(&self) {
+                emit_event(&Self::EVENT_DISCRIMINATOR, self);
+            }
+
+            /// Decode the event from a transaction log line, or `None` if the
+            /// line holds no `Deposited`.
+            pub fn from_log(line: &str) -> Option<Result<Self, DecodeError>> {
+                decode_event_log(&Self::EVENT_DISCRIMINATOR, line)
+            }
+        }
+
+        impl FromEventData for Deposited {
+            fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {
+                decode_event_data(&Self::EVENT_DISCRIMINATOR, data)
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct DepositedBuilder {
+            inner: Deposited,
+        }
+
+        impl Deposited {
+            pub fn builder() -> DepositedBuilder {
+                DepositedBuilder::default()
+            }
+        }
+
+        impl DepositedBuilder {
+            pub fn with_owner(mut self, value: Pubkey) -> Self {
+                self.inner.owner = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> Deposited {
+                self.inner
+            }
+        }
+
+        impl Deposited {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.owner != Pubkey::default() {
+                    out.write_pubkey("owner", &self.owner);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Deposited {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Deposited {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.owner != Pubkey::default() {
+                    out.key("owner");
+                    out.write_pubkey(self.owner);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "owner" => msg.owner = json_to_pubkey(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct DepositedRef<'a> {
+            raw: &'a [u8],
+            pub owner: Pubkey,
+            pub amount: u64,
+        }
+
+        impl<'a> DepositedRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = DepositedRef {
+                    raw: data,
+                    owner: ZERO_PUBKEY,
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.owner = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Deposited::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Deposited, DecodeError> {
+                Deposited::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Withdrawn {
+            pub owner: Pubkey,
+            pub amount: u64,
+            pub memo: String,
+        }
+
+        impl Withdrawn {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Withdrawn {
+                owner: ZERO_PUBKEY,
+                amount: 0,
+                memo: String::new(),
+            };
+        }
+
+        impl Default for Withdrawn {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Withdrawn {
+            pub const OWNER_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+            pub const MEMO_FIELD_NUMBER: u32 = 3;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Withdrawn.owner" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "Withdrawn.amount" },
+                FieldWireType { number: 3, wire_type: 2, packed: false, name: "Withdrawn.memo" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 3: memo
+                if !self.memo.is_empty() {
+                    w.write_string(&self.memo);
+                    w.write_key(0x1a);
+                }
+
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: owner
+                if self.owner != Pubkey::default() {
+                    w.write_pubkey(&self.owner);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.owner != Pubkey::default() {
+                    len += key_len(1) + 33;
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    len += key_len(3) + bytes_len(self.memo.len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.owner != Pubkey::default() {
+                    w.write_key(0x0a)?;
+                    w.write_pubkey(&self.owner)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                if !self.memo.is_empty() {
+                    w.write_key(0x1a)?;
+                    w.write_string(&self.memo)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_pubkey(data, pos)?;
+                            self.owner = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (v, new_pos) = decode_string_with(data, pos, opts)?;
+                            self.memo = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for Withdrawn {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Withdrawn::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Withdrawn::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Withdrawn::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Withdrawn::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Withdrawn::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Withdrawn::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Withdrawn::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Withdrawn {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Withdrawn::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Withdrawn> for Vec<u8> {
+            fn from(msg: Withdrawn) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Withdrawn {
+            const FULL_NAME: &'static str = "example.Withdrawn";
+        }
+
+        impl Withdrawn {
+            /// `sha256("event:example.Withdrawn")[..8]`
+            pub const EVENT_DISCRIMINATOR: [u8; 8] = [15, 156, 113, 46, 115, 183, 8, 227];
+
+            /// Log this event to the transaction with `sol_log_data`.
+            #[cfg(feature = "solana-program")]
+            pub fn emit(&self) {
+                emit_event(&Self::EVENT_DISCRIMINATOR, self);
+            }
+
+            /// Decode the event from a transaction log line, or `None` if the
+            /// line holds no `Withdrawn`.
+            pub fn from_log(line: &str) -> Option<Result<Self, DecodeError>> {
+                decode_event_log(&Self::EVENT_DISCRIMINATOR, line)
+            }
+        }
+
+        impl FromEventData for Withdrawn {
+            fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {
+                decode_event_data(&Self::EVENT_DISCRIMINATOR, data)
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawnBuilder {
+            inner: Withdrawn,
+        }
+
+        impl Withdrawn {
+            pub fn builder() -> WithdrawnBuilder {
+                WithdrawnBuilder::default()
+            }
+        }
+
+        impl WithdrawnBuilder {
+            pub fn with_owner(mut self, value: Pubkey) -> Self {
+                self.inner.owner = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn with_memo(mut self, value: String) -> Self {
+                self.inner.memo = value;
+                self
+            }
+
+            pub fn build(self) -> Withdrawn {
+                self.inner
+            }
+        }
+
+        impl Withdrawn {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.owner != Pubkey::default() {
+                    out.write_pubkey("owner", &self.owner);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+                if !self.memo.is_empty() {
+                    out.write_str("memo", &self.memo);
+                }
+            }
+        }
+
+        impl core::fmt::Display for Withdrawn {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Withdrawn {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.owner != Pubkey::default() {
+                    out.key("owner");
+                    out.write_pubkey(self.owner);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                if !self.memo.is_empty() {
+                    out.key("memo");
+                    out.write_str(&self.memo);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "owner" => msg.owner = json_to_pubkey(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        "memo" => msg.memo = json_to_string(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawnRef<'a> {
+            raw: &'a [u8],
+            pub owner: Pubkey,
+            pub amount: u64,
+            pub memo: &'a str,
+        }
+
+        impl<'a> WithdrawnRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawnRef {
+                    raw: data,
+                    owner: ZERO_PUBKEY,
+                    amount: 0,
+                    memo: "",
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_pubkey(data, pos)?;
+                        view.owner = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_str_ref(data, pos)?;
+                        view.memo = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Withdrawn::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Withdrawn, DecodeError> {
+                Withdrawn::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Not an event: no emit() and not in AnyEvent
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Ledger {
+            pub deposits: Vec<Deposited>,
+        }
+
+        impl Ledger {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Ledger {
+                deposits: Vec::new(),
+            };
+        }
+
+        impl Default for Ledger {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Ledger {
+            pub const DEPOSITS_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Ledger.deposits" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: deposits
+                for elem in self.deposits.iter().rev() {
+                    w.write_message(1, elem);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                for elem in &self.deposits {
+                    len += key_len(1) + bytes_len(elem.encoded_len());
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                for elem in &self.deposits {
+                    w.write_message(1, elem)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                for elem in &self.deposits {
+                    len += key_len(1) + bytes_len(sizes.record(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                for elem in &self.deposits {
+                    w.write_message_sized(1, elem, sizes)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.deposits.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.deposits.push(Deposited::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    1 => Some(self.deposits.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Ledger {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Ledger::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Ledger::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Ledger::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Ledger::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Ledger::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Ledger::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Ledger::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Ledger {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Ledger::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Ledger> for Vec<u8> {
+            fn from(msg: Ledger) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Ledger {
+            const FULL_NAME: &'static str = "example.Ledger";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LedgerBuilder {
+            inner: Ledger,
+        }
+
+        impl Ledger {
+            pub fn builder() -> LedgerBuilder {
+                LedgerBuilder::default()
+            }
+        }
+
+        impl LedgerBuilder {
+            pub fn with_deposits(mut self, value: Vec<Deposited>) -> Self {
+                self.inner.deposits = value;
+                self
+            }
+
+            pub fn add_deposits(mut self, value: Deposited) -> Self {
+                self.inner.deposits.push(value);
+                self
+            }
+
+            pub fn build(self) -> Ledger {
+                self.inner
+            }
+        }
+
+        impl Ledger {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                for elem in &self.deposits {
+                    out.begin_message("deposits");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Ledger {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Ledger {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.deposits.is_empty() {
+                    out.key("deposits");
+                    out.begin_array();
+                    for elem in &self.deposits {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "deposits" => {
+                            for elem in json_array(value)? {
+                                msg.deposits.push(Deposited::from_json_value(elem)?);
+                            }
+                        }
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LedgerRef<'a> {
+            raw: &'a [u8],
+            pub deposits: RepeatedRef<'a, DepositedRef<'a>>,
+        }
+
+        impl<'a> LedgerRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let view = LedgerRef {
+                    raw: data,
+                    deposits: RepeatedRef::new(data, 10, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((DepositedRef::decode(v)?, new_pos))
+                    }),
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Ledger::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Ledger, DecodeError> {
+                Ledger::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Deposited(Deposited),
+            Withdrawn(Withdrawn),
+            Ledger(Ledger),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Deposited::FULL_NAME => AnyMessage::Deposited(Deposited::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Withdrawn::FULL_NAME => AnyMessage::Withdrawn(Withdrawn::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    Ledger::FULL_NAME => AnyMessage::Ledger(Ledger::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Deposited(msg) => Any::pack(msg),
+                    AnyMessage::Withdrawn(msg) => Any::pack(msg),
+                    AnyMessage::Ledger(msg) => Any::pack(msg),
+                }
+            }
+        }
+
+        /// Every `(solana.event)` message in this file, for reading events back
+        /// from transaction logs.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyEvent {
+            Deposited(Deposited),
+            Withdrawn(Withdrawn),
+        }
+
+        impl AnyEvent {
+            /// Decode the event a log line holds, or `None` if it is not a
+            /// `Program data:` line carrying one of this file's events.
+            pub fn from_log(line: &str) -> Option<Result<Self, DecodeError>> {
+                if let Some(event) = Deposited::from_log(line) {
+                    return Some(event.map(AnyEvent::Deposited));
+                }
+                if let Some(event) = Withdrawn::from_log(line) {
+                    return Some(event.map(AnyEvent::Withdrawn));
+                }
+                None
+            }
+        }
+
+        impl FromEventData for AnyEvent {
+            fn from_event_data(data: &[u8]) -> Option<Result<Self, DecodeError>> {
+                if let Some(event) = Deposited::from_event_data(data) {
+                    return Some(event.map(AnyEvent::Deposited));
+                }
+                if let Some(event) = Withdrawn::from_event_data(data) {
+                    return Some(event.map(AnyEvent::Withdrawn));
+                }
+                None
+            }
+        }
+
+        /// wasm-bindgen exports encoding this file's messages and instructions from proto3 JSON.
+        #[cfg(feature = "wasm")]
+        pub mod wasm {
+            use super::*;
+            use wasm_bindgen::prelude::*;
+
+            /// The protobuf encoding of the `example.Deposited` in `json`.
+            #[wasm_bindgen(js_name = "encodeExampleDeposited")]
+            pub fn encode_deposited(json: &str) -> Result<Vec<u8>, JsError> {
+                Ok(Deposited::from_json(json).map_err(js_error)?.encode())
+            }
+
+            /// The `example.Deposited` in `data`, as proto3 JSON.
+            #[wasm_bindgen(js_name = "decodeExampleDeposited")]
+            pub fn decode_deposited(data: &[u8]) -> Result<String, JsError> {
+                Ok(Deposited::decode(data).map_err(js_error)?.to_json())
+            }
+
+            /// The protobuf encoding of the `example.Withdrawn` in `json`.
+            #[wasm_bindgen(js_name = "encodeExampleWithdrawn")]
+            pub fn encode_withdrawn(json: &str) -> Result<Vec<u8>, JsError> {
+                Ok(Withdrawn::from_json(json).map_err(js_error)?.encode())
+            }
+
+            /// The `example.Withdrawn` in `data`, as proto3 JSON.
+            #[wasm_bindgen(js_name = "decodeExampleWithdrawn")]
+            pub fn decode_withdrawn(data: &[u8]) -> Result<String, JsError> {
+                Ok(Withdrawn::decode(data).map_err(js_error)?.to_json())
+            }
+
+            /// The protobuf encoding of the `example.Ledger` in `json`.
+            #[wasm_bindgen(js_name = "encodeExampleLedger")]
+            pub fn encode_ledger(json: &str) -> Result<Vec<u8>, JsError> {
+                Ok(Ledger::from_json(json).map_err(js_error)?.encode())
+            }
+
+            /// The `example.Ledger` in `data`, as proto3 JSON.
+            #[wasm_bindgen(js_name = "decodeExampleLedger")]
+            pub fn decode_ledger(data: &[u8]) -> Result<String, JsError> {
+                Ok(Ledger::decode(data).map_err(js_error)?.to_json())
+            }
+        }
+    }
+
+    pub mod service {
+        // Auto-generated by protoc-gen-solana from service.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct DepositRequest {
+            pub account: Vec<u8>,
+            pub amount: u64,
+        }
+
+        impl DepositRequest {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = DepositRequest {
+                account: Vec::new(),
+                amount: 0,
+            };
+        }
+
+        impl Default for DepositRequest {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl DepositRequest {
+            pub const ACCOUNT_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "DepositRequest.account" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "DepositRequest.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: account
+                if !self.account.is_empty() {
+                    w.write_bytes(&self.account);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.account.is_empty() {
+                    len += key_len(1) + bytes_len(self.account.len());
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.account.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_bytes(&self.account)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.account = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for DepositRequest {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                DepositRequest::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                DepositRequest::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                DepositRequest::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                DepositRequest::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                DepositRequest::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                DepositRequest::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                DepositRequest::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for DepositRequest {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                DepositRequest::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<DepositRequest> for Vec<u8> {
+            fn from(msg: DepositRequest) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for DepositRequest {
+            const FULL_NAME: &'static str = "example.DepositRequest";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct DepositRequestBuilder {
+            inner: DepositRequest,
+        }
+
+        impl DepositRequest {
+            pub fn builder() -> DepositRequestBuilder {
+                DepositRequestBuilder::default()
+            }
+        }
+
+        impl DepositRequestBuilder {
+            pub fn with_account(mut self, value: Vec<u8>) -> Self {
+                self.inner.account = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> DepositRequest {
+                self.inner
+            }
+        }
+
+        impl DepositRequest {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.account.is_empty() {
+                    out.write_bytes("account", &self.account);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for DepositRequest {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl DepositRequest {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.account.is_empty() {
+                    out.key("account");
+                    out.write_bytes(&self.account);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "account" => msg.account = json_to_bytes(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct DepositRequestRef<'a> {
+            raw: &'a [u8],
+            pub account: &'a [u8],
+            pub amount: u64,
+        }
+
+        impl<'a> DepositRequestRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = DepositRequestRef {
+                    raw: data,
+                    account: &[],
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.account = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(DepositRequest::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<DepositRequest, DecodeError> {
+                DepositRequest::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct WithdrawRequest {
+            pub account: Vec<u8>,
+            pub amount: u64,
+        }
+
+        impl WithdrawRequest {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = WithdrawRequest {
+                account: Vec::new(),
+                amount: 0,
+            };
+        }
+
+        impl Default for WithdrawRequest {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl WithdrawRequest {
+            pub const ACCOUNT_FIELD_NUMBER: u32 = 1;
+            pub const AMOUNT_FIELD_NUMBER: u32 = 2;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "WithdrawRequest.account" },
+                FieldWireType { number: 2, wire_type: 0, packed: false, name: "WithdrawRequest.amount" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 2: amount
+                if self.amount != 0 {
+                    w.write_varint(self.amount);
+                    w.write_key(0x10);
+                }
+
+                // field 1: account
+                if !self.account.is_empty() {
+                    w.write_bytes(&self.account);
+                    w.write_key(0x0a);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if !self.account.is_empty() {
+                    len += key_len(1) + bytes_len(self.account.len());
+                }
+                if self.amount != 0 {
+                    len += key_len(2) + varint_len(self.amount);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if !self.account.is_empty() {
+                    w.write_key(0x0a)?;
+                    w.write_bytes(&self.account)?;
+                }
+                if self.amount != 0 {
+                    w.write_key(0x10)?;
+                    w.write_varint(self.amount)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_bytes_with(data, pos, opts)?;
+                            self.account = v;
+                            pos = new_pos;
+                        }
+                        16 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.amount = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for WithdrawRequest {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                WithdrawRequest::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                WithdrawRequest::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                WithdrawRequest::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                WithdrawRequest::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                WithdrawRequest::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                WithdrawRequest::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                WithdrawRequest::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for WithdrawRequest {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                WithdrawRequest::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<WithdrawRequest> for Vec<u8> {
+            fn from(msg: WithdrawRequest) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for WithdrawRequest {
+            const FULL_NAME: &'static str = "example.WithdrawRequest";
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawRequestBuilder {
+            inner: WithdrawRequest,
+        }
+
+        impl WithdrawRequest {
+            pub fn builder() -> WithdrawRequestBuilder {
+                WithdrawRequestBuilder::default()
+            }
+        }
+
+        impl WithdrawRequestBuilder {
+            pub fn with_account(mut self, value: Vec<u8>) -> Self {
+                self.inner.account = value;
+                self
+            }
+
+            pub fn with_amount(mut self, value: u64) -> Self {
+                self.inner.amount = value;
+                self
+            }
+
+            pub fn build(self) -> WithdrawRequest {
+                self.inner
+            }
+        }
+
+        impl WithdrawRequest {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if !self.account.is_empty() {
+                    out.write_bytes("account", &self.account);
+                }
+                if self.amount != 0 {
+                    out.write_scalar("amount", self.amount);
+                }
+            }
+        }
+
+        impl core::fmt::Display for WithdrawRequest {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl WithdrawRequest {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if !self.account.is_empty() {
+                    out.key("account");
+                    out.write_bytes(&self.account);
+                }
+                if self.amount != 0 {
+                    out.key("amount");
+                    out.write_u64(self.amount);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "account" => msg.account = json_to_bytes(value)?,
+                        "amount" => msg.amount = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawRequestRef<'a> {
+            raw: &'a [u8],
+            pub account: &'a [u8],
+            pub amount: u64,
+        }
+
+        impl<'a> WithdrawRequestRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawRequestRef {
+                    raw: data,
+                    account: &[],
+                    amount: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_bytes_ref(data, pos)?;
+                        view.account = v;
+                        pos = new_pos;
+                    }
+                    16 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.amount = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(WithdrawRequest::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<WithdrawRequest, DecodeError> {
+                WithdrawRequest::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct WithdrawResponse {
+            pub remaining: u64,
+        }
+
+        impl WithdrawResponse {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = WithdrawResponse {
+                remaining: 0,
+            };
+        }
+
+        impl Default for WithdrawResponse {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl WithdrawResponse {
+            pub const REMAINING_FIELD_NUMBER: u32 = 1;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 0, packed: false, name: "WithdrawResponse.remaining" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 1: remaining
+                if self.remaining != 0 {
+                    w.write_varint(self.remaining);
+                    w.write_key(0x08);
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                if self.remaining != 0 {
+                    len += key_len(1) + varint_len(self.remaining);
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                if self.remaining != 0 {
+                    w.write_key(0x08)?;
+                    w.write_varint(self.remaining)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, _sizes: &mut SizeCache) -> usize {
+                self.encoded_len()
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, _sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                self.write_to(w)
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        8 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (v, new_pos) = decode_varint(data, pos)?;
+                            self.remaining = v;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, None));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Message for WithdrawResponse {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                WithdrawResponse::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                WithdrawResponse::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                WithdrawResponse::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                WithdrawResponse::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                WithdrawResponse::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                WithdrawResponse::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                WithdrawResponse::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for WithdrawResponse {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                WithdrawResponse::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<WithdrawResponse> for Vec<u8> {
+            fn from(msg: WithdrawResponse) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for WithdrawResponse {
+            const FULL_NAME: &'static str = "example.WithdrawResponse";
+        }
+
+        impl WithdrawResponse {
+            /// Longest encoding of a `WithdrawResponse` whose fields stay within
+            /// their `(solana.max_len)` / `(solana.max_count)` bounds, not
+            /// counting unknown fields.
+            pub const MAX_ENCODED_LEN: usize = 11;
+
+            /// Account space for any such value in the `encode_account_data`
+            /// layout, e.g. for `Rent::minimum_balance`.
+            pub const MAX_ACCOUNT_DATA_LEN: usize = ACCOUNT_HEADER_LEN + Self::MAX_ENCODED_LEN;
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct WithdrawResponseBuilder {
+            inner: WithdrawResponse,
+        }
+
+        impl WithdrawResponse {
+            pub fn builder() -> WithdrawResponseBuilder {
+                WithdrawResponseBuilder::default()
+            }
+        }
+
+        impl WithdrawResponseBuilder {
+            pub fn with_remaining(mut self, value: u64) -> Self {
+                self.inner.remaining = value;
+                self
+            }
+
+            pub fn build(self) -> WithdrawResponse {
+                self.inner
+            }
+        }
+
+        impl WithdrawResponse {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.remaining != 0 {
+                    out.write_scalar("remaining", self.remaining);
+                }
+            }
+        }
+
+        impl core::fmt::Display for WithdrawResponse {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl WithdrawResponse {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.remaining != 0 {
+                    out.key("remaining");
+                    out.write_u64(self.remaining);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "remaining" => msg.remaining = json_to_u64(value)?,
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct WithdrawResponseRef<'a> {
+            raw: &'a [u8],
+            pub remaining: u64,
+        }
+
+        impl<'a> WithdrawResponseRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = WithdrawResponseRef {
+                    raw: data,
+                    remaining: 0,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    8 => {
+                        let (v, new_pos) = decode_varint(data, pos)?;
+                        view.remaining = v;
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(WithdrawResponse::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<WithdrawResponse, DecodeError> {
+                WithdrawResponse::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            DepositRequest(DepositRequest),
+            WithdrawRequest(WithdrawRequest),
+            WithdrawResponse(WithdrawResponse),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    DepositRequest::FULL_NAME => AnyMessage::DepositRequest(DepositRequest::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    WithdrawRequest::FULL_NAME => AnyMessage::WithdrawRequest(WithdrawRequest::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    WithdrawResponse::FULL_NAME => AnyMessage::WithdrawResponse(WithdrawResponse::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::DepositRequest(msg) => Any::pack(msg),
+                    AnyMessage::WithdrawRequest(msg) => Any::pack(msg),
+                    AnyMessage::WithdrawResponse(msg) => Any::pack(msg),
+                }
+            }
+        }
+
+        /// Instructions of the `example.Vault` service, tagged by a leading `u8`.
+        ///
+        /// Streaming rpcs have no instruction form, so `WatchBalance` has no variant.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum VaultInstruction {
+            Deposit(DepositRequest),
+            Withdraw(WithdrawRequest),
+        }
+
+        impl VaultInstruction {
+            pub const DEPOSIT_TAG: u8 = 0;
+            pub const WITHDRAW_TAG: u8 = 1;
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                let (&tag, payload) = data.split_first().ok_or(DecodeError::BufferOverflow)?;
+                match tag {
+                    Self::DEPOSIT_TAG => Ok(VaultInstruction::Deposit(DepositRequest::decode_with(payload, opts)?)),
+                    Self::WITHDRAW_TAG => Ok(VaultInstruction::Withdraw(WithdrawRequest::decode_with(payload, opts)?)),
+                    _ => Err(DecodeError::InvalidData("unknown instruction tag")),
+                }
+            }
+
+            pub fn tag(&self) -> u8 {
+                match self {
+                    VaultInstruction::Deposit(_) => Self::DEPOSIT_TAG,
+                    VaultInstruction::Withdraw(_) => Self::WITHDRAW_TAG,
+                }
+            }
+
+            /// Instruction data: the tag followed by the encoded request.
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                buf.push(self.tag());
+                match self {
+                    VaultInstruction::Deposit(request) => request.encode_into(&mut buf),
+                    VaultInstruction::Withdraw(request) => request.encode_into(&mut buf),
+                }
+                buf
+            }
+
+            /// Run the handler for this instruction and encode its response.
+            pub fn dispatch<C, H: VaultHandler<C>>(self, handler: &mut H, ctx: C) -> Result<Vec<u8>, H::Error> {
+                match self {
+                    VaultInstruction::Deposit(request) => Ok(handler.deposit(ctx, request)?.encode()),
+                    VaultInstruction::Withdraw(request) => Ok(handler.withdraw(ctx, request)?.encode()),
+                }
+            }
+
+            /// Decode instruction data and dispatch it, e.g. from the program's
+            /// `process_instruction` entrypoint.
+            pub fn process<C, H: VaultHandler<C>>(handler: &mut H, ctx: C, data: &[u8]) -> Result<Vec<u8>, H::Error> {
+                Self::decode(data)?.dispatch(handler, ctx)
+            }
+        }
+
+        /// Exercises: service rpcs generated as program instructions and a handler
+        /// trait; (solana.accounts) on Withdraw for WithdrawAccounts and a keyed builder
+        ///
+        /// Program-side implementation of the `example.Vault` service; `C` is the
+        /// per-instruction context, e.g. the program id and accounts.
+        pub trait VaultHandler<C> {
+            type Error: From<DecodeError>;
+
+            fn deposit(&mut self, ctx: C, request: DepositRequest) -> Result<Empty, Self::Error>;
+
+            fn withdraw(&mut self, ctx: C, request: WithdrawRequest) -> Result<WithdrawResponse, Self::Error>;
+        }
+
+        /// Account validation and instruction builders for the `example.Vault` service.
+        #[cfg(feature = "solana-program")]
+        pub mod vault {
+            use solana_program::account_info::AccountInfo;
+            use solana_program::program_error::ProgramError;
+
+            /// Accounts of `Withdraw`, in order: `owner` (signer), `vault` (writable), `system_program`.
+            pub struct WithdrawAccounts<'a, 'info> {
+                pub owner: &'a AccountInfo<'info>,
+                pub vault: &'a AccountInfo<'info>,
+                pub system_program: &'a AccountInfo<'info>,
+                /// Accounts after the declared ones.
+                pub remaining: &'a [AccountInfo<'info>],
+            }
+
+            impl<'a, 'info> WithdrawAccounts<'a, 'info> {
+                pub const LEN: usize = 3;
+
+                /// Take the declared accounts from the front of `accounts`, checking
+                /// that each is a signer or writable where declared so.
+                pub fn from_accounts(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+                    let [owner, vault, system_program, remaining @ ..] = accounts else {
+                        return Err(ProgramError::NotEnoughAccountKeys);
+                    };
+                    if !owner.is_signer {
+                        return Err(ProgramError::MissingRequiredSignature);
+                    }
+                    if !vault.is_writable {
+                        return Err(ProgramError::Immutable);
+                    }
+                    Ok(Self { owner, vault, system_program, remaining })
+                }
+            }
+
+            pub mod instruction {
+                use super::super::*;
+
+                /// `Deposit`, with `request` encoded as `VaultInstruction` data.
+                pub fn deposit(
+                    program_id: Pubkey,
+                    accounts: Vec<solana_program::instruction::AccountMeta>,
+                    request: DepositRequest,
+                ) -> solana_program::instruction::Instruction {
+                    solana_program::instruction::Instruction {
+                        program_id,
+                        accounts,
+                        data: VaultInstruction::Deposit(request).encode(),
+                    }
+                }
+
+                /// `Withdraw`, with `request` encoded as `VaultInstruction` data.
+                pub fn withdraw(
+                    program_id: Pubkey,
+                    owner: Pubkey,
+                    vault: Pubkey,
+                    system_program: Pubkey,
+                    request: WithdrawRequest,
+                ) -> solana_program::instruction::Instruction {
+                    solana_program::instruction::Instruction {
+                        program_id,
+                        accounts: Vec::from([
+                            solana_program::instruction::AccountMeta::new_readonly(owner, true),
+                            solana_program::instruction::AccountMeta::new(vault, false),
+                            solana_program::instruction::AccountMeta::new_readonly(system_program, false),
+                        ]),
+                        data: VaultInstruction::Withdraw(request).encode(),
+                    }
+                }
+            }
+        }
+
+        /// wasm-bindgen exports encoding this file's messages and instructions from proto3 JSON.
+        #[cfg(feature = "wasm")]
+        pub mod wasm {
+            use super::*;
+            use wasm_bindgen::prelude::*;
+
+            /// The protobuf encoding of the `example.DepositRequest` in `json`.
+            #[wasm_bindgen(js_name = "encodeExampleDepositRequest")]
+            pub fn encode_deposit_request(json: &str) -> Result<Vec<u8>, JsError> {
+                Ok(DepositRequest::from_json(json).map_err(js_error)?.encode())
+            }
+
+            /// The `example.DepositRequest` in `data`, as proto3 JSON.
+            #[wasm_bindgen(js_name = "decodeExampleDepositRequest")]
+            pub fn decode_deposit_request(data: &[u8]) -> Result<String, JsError> {
+                Ok(DepositRequest::decode(data).map_err(js_error)?.to_json())
+            }
+
+            /// The protobuf encoding of the `example.WithdrawRequest` in `json`.
+            #[wasm_bindgen(js_name = "encodeExampleWithdrawRequest")]
+            pub fn encode_withdraw_request(json: &str) -> Result<Vec<u8>, JsError> {
+                Ok(WithdrawRequest::from_json(json).map_err(js_error)?.encode())
+            }
+
+            /// The `example.WithdrawRequest` in `data`, as proto3 JSON.
+            #[wasm_bindgen(js_name = "decodeExampleWithdrawRequest")]
+            pub fn decode_withdraw_request(data: &[u8]) -> Result<String, JsError> {
+                Ok(WithdrawRequest::decode(data).map_err(js_error)?.to_json())
+            }
+
+            /// The protobuf encoding of the `example.WithdrawResponse` in `json`.
+            #[wasm_bindgen(js_name = "encodeExampleWithdrawResponse")]
+            pub fn encode_withdraw_response(json: &str) -> Result<Vec<u8>, JsError> {
+                Ok(WithdrawResponse::from_json(json).map_err(js_error)?.encode())
+            }
+
+            /// The `example.WithdrawResponse` in `data`, as proto3 JSON.
+            #[wasm_bindgen(js_name = "decodeExampleWithdrawResponse")]
+            pub fn decode_withdraw_response(data: &[u8]) -> Result<String, JsError> {
+                Ok(WithdrawResponse::decode(data).map_err(js_error)?.to_json())
+            }
+
+            /// `VaultInstruction::Deposit` data for the request in `json`.
+            #[wasm_bindgen(js_name = "encodeExampleVaultDepositInstruction")]
+            pub fn encode_vault_deposit_instruction(json: &str) -> Result<Vec<u8>, JsError> {
+                let request = DepositRequest::from_json_value(&parse_json(json).map_err(js_error)?).map_err(js_error)?;
+                Ok(VaultInstruction::Deposit(request).encode())
+            }
+
+            /// `VaultInstruction::Withdraw` data for the request in `json`.
+            #[wasm_bindgen(js_name = "encodeExampleVaultWithdrawInstruction")]
+            pub fn encode_vault_withdraw_instruction(json: &str) -> Result<Vec<u8>, JsError> {
+                let request = WithdrawRequest::from_json_value(&parse_json(json).map_err(js_error)?).map_err(js_error)?;
+                Ok(VaultInstruction::Withdraw(request).encode())
+            }
+        }
+    }
+}
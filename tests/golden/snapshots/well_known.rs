@@ -0,0 +1,1167 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+//
+// Every generated file as one module, using the runtime at `runtime_path`:
+// declare it with `mod <file name>;` anywhere in the crate.
+
+pub mod example {
+    pub mod well_known {
+        // Auto-generated by protoc-gen-solana from well_known.proto
+        // DO NOT EDIT
+
+        #![allow(unused_imports, non_camel_case_types, dead_code, clippy::derivable_impls)]
+
+        use protoc_gen_solana_runtime::*;
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub enum LeaseCap {
+            Unset,
+            Limit(Int64Value),
+            Ratio(FloatValue),
+        }
+
+        impl LeaseCap {
+            pub const DEFAULT: Self = LeaseCap::Unset;
+        }
+
+        impl Default for LeaseCap {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        /// Exercises: Timestamp/Duration fields and wrapper types as Option\<T>
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+        pub struct Lease {
+            pub start: Timestamp,
+            pub term: Duration,
+            pub deposit: Option<u64>,
+            pub note: Option<String>,
+            pub renewable: Option<bool>,
+            pub priority: Option<i32>,
+            pub rate: Option<f64>,
+            pub tenant: Option<Vec<u8>>,
+            pub payments: Vec<Timestamp>,
+            pub slots: Vec<UInt32Value>,
+            pub grace: BTreeMap<String, Duration>,
+            pub cap: LeaseCap,
+            pub rent: Option<u64>,
+        }
+
+        impl Lease {
+            /// Every field at its proto3 default, for `const` and `static` items.
+            pub const DEFAULT: Self = Lease {
+                start: Timestamp::DEFAULT,
+                term: Duration::DEFAULT,
+                deposit: None,
+                note: None,
+                renewable: None,
+                priority: None,
+                rate: None,
+                tenant: None,
+                payments: Vec::new(),
+                slots: Vec::new(),
+                grace: BTreeMap::new(),
+                cap: LeaseCap::Unset,
+                rent: None,
+            };
+        }
+
+        impl Default for Lease {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+
+        impl Lease {
+            pub const START_FIELD_NUMBER: u32 = 1;
+            pub const TERM_FIELD_NUMBER: u32 = 2;
+            pub const DEPOSIT_FIELD_NUMBER: u32 = 3;
+            pub const NOTE_FIELD_NUMBER: u32 = 4;
+            pub const RENEWABLE_FIELD_NUMBER: u32 = 5;
+            pub const PRIORITY_FIELD_NUMBER: u32 = 6;
+            pub const RATE_FIELD_NUMBER: u32 = 7;
+            pub const TENANT_FIELD_NUMBER: u32 = 8;
+            pub const PAYMENTS_FIELD_NUMBER: u32 = 9;
+            pub const SLOTS_FIELD_NUMBER: u32 = 10;
+            pub const GRACE_FIELD_NUMBER: u32 = 11;
+            pub const LIMIT_FIELD_NUMBER: u32 = 12;
+            pub const RATIO_FIELD_NUMBER: u32 = 13;
+            pub const RENT_FIELD_NUMBER: u32 = 14;
+
+            const FIELD_WIRE_TYPES: &'static [FieldWireType] = &[
+                FieldWireType { number: 1, wire_type: 2, packed: false, name: "Lease.start" },
+                FieldWireType { number: 2, wire_type: 2, packed: false, name: "Lease.term" },
+                FieldWireType { number: 3, wire_type: 0, packed: false, name: "Lease.deposit" },
+                FieldWireType { number: 4, wire_type: 2, packed: false, name: "Lease.note" },
+                FieldWireType { number: 5, wire_type: 0, packed: false, name: "Lease.renewable" },
+                FieldWireType { number: 6, wire_type: 0, packed: false, name: "Lease.priority" },
+                FieldWireType { number: 7, wire_type: 1, packed: false, name: "Lease.rate" },
+                FieldWireType { number: 8, wire_type: 2, packed: false, name: "Lease.tenant" },
+                FieldWireType { number: 9, wire_type: 2, packed: false, name: "Lease.payments" },
+                FieldWireType { number: 10, wire_type: 2, packed: false, name: "Lease.slots" },
+                FieldWireType { number: 11, wire_type: 2, packed: false, name: "Lease.grace" },
+                FieldWireType { number: 12, wire_type: 2, packed: false, name: "Lease.limit" },
+                FieldWireType { number: 13, wire_type: 2, packed: false, name: "Lease.ratio" },
+                FieldWireType { number: 14, wire_type: 0, packed: false, name: "Lease.rent" },
+            ];
+
+            pub fn encode(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                self.write_reverse(&mut ReverseWriter::with_capacity(&mut buf, self.encoded_len()));
+                buf
+            }
+
+            pub fn encode_into(&self, buf: &mut Vec<u8>) {
+                self.write_reverse(&mut ReverseWriter::with_capacity(buf, self.encoded_len()));
+            }
+
+            pub fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                // field 14: rent
+                if let Some(elem) = &self.rent {
+                    w.write_nested(14, |w| UInt64Value::write_value_reverse(elem, w));
+                }
+
+                // field 13: ratio
+                if let LeaseCap::Ratio(elem) = &self.cap {
+                    w.write_message(13, elem);
+                }
+
+                // field 12: limit
+                if let LeaseCap::Limit(elem) = &self.cap {
+                    w.write_message(12, elem);
+                }
+
+                // field 11: grace
+                for (key, value) in self.grace.iter().rev() {
+                    w.write_nested(11, |w| {
+                        w.write_message(2, value);
+                        w.write_string(key);
+                        w.write_key(0x0a);
+                    });
+                }
+
+                // field 10: slots
+                for elem in self.slots.iter().rev() {
+                    w.write_message(10, elem);
+                }
+
+                // field 9: payments
+                for elem in self.payments.iter().rev() {
+                    w.write_message(9, elem);
+                }
+
+                // field 8: tenant
+                if let Some(elem) = &self.tenant {
+                    w.write_nested(8, |w| BytesValue::write_value_reverse(elem, w));
+                }
+
+                // field 7: rate
+                if let Some(elem) = &self.rate {
+                    w.write_nested(7, |w| DoubleValue::write_value_reverse(elem, w));
+                }
+
+                // field 6: priority
+                if let Some(elem) = &self.priority {
+                    w.write_nested(6, |w| Int32Value::write_value_reverse(elem, w));
+                }
+
+                // field 5: renewable
+                if let Some(elem) = &self.renewable {
+                    w.write_nested(5, |w| BoolValue::write_value_reverse(elem, w));
+                }
+
+                // field 4: note
+                if let Some(elem) = &self.note {
+                    w.write_nested(4, |w| StringValue::write_value_reverse(elem, w));
+                }
+
+                // field 3: deposit
+                if let Some(elem) = &self.deposit {
+                    w.write_nested(3, |w| UInt64Value::write_value_reverse(elem, w));
+                }
+
+                // field 2: term
+                w.write_message(2, &self.term);
+
+                // field 1: start
+                w.write_message(1, &self.start);
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                let mut len = 0;
+                len += key_len(1) + bytes_len(self.start.encoded_len());
+                len += key_len(2) + bytes_len(self.term.encoded_len());
+                if let Some(elem) = &self.deposit {
+                    len += key_len(3) + bytes_len(UInt64Value::value_len(elem));
+                }
+                if let Some(elem) = &self.note {
+                    len += key_len(4) + bytes_len(StringValue::value_len(elem));
+                }
+                if let Some(elem) = &self.renewable {
+                    len += key_len(5) + bytes_len(BoolValue::value_len(elem));
+                }
+                if let Some(elem) = &self.priority {
+                    len += key_len(6) + bytes_len(Int32Value::value_len(elem));
+                }
+                if let Some(elem) = &self.rate {
+                    len += key_len(7) + bytes_len(DoubleValue::value_len(elem));
+                }
+                if let Some(elem) = &self.tenant {
+                    len += key_len(8) + bytes_len(BytesValue::value_len(elem));
+                }
+                for elem in &self.payments {
+                    len += key_len(9) + bytes_len(elem.encoded_len());
+                }
+                for elem in &self.slots {
+                    len += key_len(10) + bytes_len(elem.encoded_len());
+                }
+                for (key, value) in &self.grace {
+                    len += key_len(11) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len()));
+                }
+                if let LeaseCap::Limit(elem) = &self.cap {
+                    len += key_len(12) + bytes_len(elem.encoded_len());
+                }
+                if let LeaseCap::Ratio(elem) = &self.cap {
+                    len += key_len(13) + bytes_len(elem.encoded_len());
+                }
+                if let Some(elem) = &self.rent {
+                    len += key_len(14) + bytes_len(UInt64Value::value_len(elem));
+                }
+                len
+            }
+
+            pub fn encode_to_slice(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+                if self.encoded_len() > buf.len() {
+                    return Err(DecodeError::BufferTooSmall);
+                }
+                let mut w = SliceWriter::new(buf);
+                self.write_to(&mut w)?;
+                Ok(w.position())
+            }
+
+            pub fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                w.write_message(1, &self.start)?;
+                w.write_message(2, &self.term)?;
+                if let Some(elem) = &self.deposit {
+                    w.write_key(0x1a)?;
+                    w.write_varint(UInt64Value::value_len(elem) as u64)?;
+                    UInt64Value::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.note {
+                    w.write_key(0x22)?;
+                    w.write_varint(StringValue::value_len(elem) as u64)?;
+                    StringValue::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.renewable {
+                    w.write_key(0x2a)?;
+                    w.write_varint(BoolValue::value_len(elem) as u64)?;
+                    BoolValue::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.priority {
+                    w.write_key(0x32)?;
+                    w.write_varint(Int32Value::value_len(elem) as u64)?;
+                    Int32Value::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.rate {
+                    w.write_key(0x3a)?;
+                    w.write_varint(DoubleValue::value_len(elem) as u64)?;
+                    DoubleValue::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.tenant {
+                    w.write_key(0x42)?;
+                    w.write_varint(BytesValue::value_len(elem) as u64)?;
+                    BytesValue::write_value(elem, w)?;
+                }
+                for elem in &self.payments {
+                    w.write_message(9, elem)?;
+                }
+                for elem in &self.slots {
+                    w.write_message(10, elem)?;
+                }
+                for (key, value) in &self.grace {
+                    w.write_key(0x5a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(value.encoded_len())) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message(2, value)?;
+                }
+                if let LeaseCap::Limit(elem) = &self.cap {
+                    w.write_message(12, elem)?;
+                }
+                if let LeaseCap::Ratio(elem) = &self.cap {
+                    w.write_message(13, elem)?;
+                }
+                if let Some(elem) = &self.rent {
+                    w.write_key(0x72)?;
+                    w.write_varint(UInt64Value::value_len(elem) as u64)?;
+                    UInt64Value::write_value(elem, w)?;
+                }
+                Ok(())
+            }
+
+            pub fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                let mut len = 0;
+                len += key_len(1) + bytes_len(sizes.record(&self.start));
+                len += key_len(2) + bytes_len(sizes.record(&self.term));
+                if let Some(elem) = &self.deposit {
+                    len += key_len(3) + bytes_len(UInt64Value::value_len(elem));
+                }
+                if let Some(elem) = &self.note {
+                    len += key_len(4) + bytes_len(StringValue::value_len(elem));
+                }
+                if let Some(elem) = &self.renewable {
+                    len += key_len(5) + bytes_len(BoolValue::value_len(elem));
+                }
+                if let Some(elem) = &self.priority {
+                    len += key_len(6) + bytes_len(Int32Value::value_len(elem));
+                }
+                if let Some(elem) = &self.rate {
+                    len += key_len(7) + bytes_len(DoubleValue::value_len(elem));
+                }
+                if let Some(elem) = &self.tenant {
+                    len += key_len(8) + bytes_len(BytesValue::value_len(elem));
+                }
+                for elem in &self.payments {
+                    len += key_len(9) + bytes_len(sizes.record(elem));
+                }
+                for elem in &self.slots {
+                    len += key_len(10) + bytes_len(sizes.record(elem));
+                }
+                for (key, value) in &self.grace {
+                    len += key_len(11) + bytes_len(key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.record(value)));
+                }
+                if let LeaseCap::Limit(elem) = &self.cap {
+                    len += key_len(12) + bytes_len(sizes.record(elem));
+                }
+                if let LeaseCap::Ratio(elem) = &self.cap {
+                    len += key_len(13) + bytes_len(sizes.record(elem));
+                }
+                if let Some(elem) = &self.rent {
+                    len += key_len(14) + bytes_len(UInt64Value::value_len(elem));
+                }
+                len
+            }
+
+            pub fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                w.write_message_sized(1, &self.start, sizes)?;
+                w.write_message_sized(2, &self.term, sizes)?;
+                if let Some(elem) = &self.deposit {
+                    w.write_key(0x1a)?;
+                    w.write_varint(UInt64Value::value_len(elem) as u64)?;
+                    UInt64Value::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.note {
+                    w.write_key(0x22)?;
+                    w.write_varint(StringValue::value_len(elem) as u64)?;
+                    StringValue::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.renewable {
+                    w.write_key(0x2a)?;
+                    w.write_varint(BoolValue::value_len(elem) as u64)?;
+                    BoolValue::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.priority {
+                    w.write_key(0x32)?;
+                    w.write_varint(Int32Value::value_len(elem) as u64)?;
+                    Int32Value::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.rate {
+                    w.write_key(0x3a)?;
+                    w.write_varint(DoubleValue::value_len(elem) as u64)?;
+                    DoubleValue::write_value(elem, w)?;
+                }
+                if let Some(elem) = &self.tenant {
+                    w.write_key(0x42)?;
+                    w.write_varint(BytesValue::value_len(elem) as u64)?;
+                    BytesValue::write_value(elem, w)?;
+                }
+                for elem in &self.payments {
+                    w.write_message_sized(9, elem, sizes)?;
+                }
+                for elem in &self.slots {
+                    w.write_message_sized(10, elem, sizes)?;
+                }
+                for (key, value) in &self.grace {
+                    w.write_key(0x5a)?;
+                    w.write_varint((key_len(1) + bytes_len(key.len()) + key_len(2) + bytes_len(sizes.peek()?)) as u64)?;
+                    w.write_key(0x0a)?;
+                    w.write_string(key)?;
+                    w.write_message_sized(2, value, sizes)?;
+                }
+                if let LeaseCap::Limit(elem) = &self.cap {
+                    w.write_message_sized(12, elem, sizes)?;
+                }
+                if let LeaseCap::Ratio(elem) = &self.cap {
+                    w.write_message_sized(13, elem, sizes)?;
+                }
+                if let Some(elem) = &self.rent {
+                    w.write_key(0x72)?;
+                    w.write_varint(UInt64Value::value_len(elem) as u64)?;
+                    UInt64Value::write_value(elem, w)?;
+                }
+                Ok(())
+            }
+
+            pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+                Self::decode_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_with(data: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_with(data, opts)?;
+                Ok(msg)
+            }
+
+            /// Decode only the fields numbered in `fields` (see the `*_FIELD_NUMBER`
+            /// constants), skipping the rest of `data` without allocating for it.
+            /// Every other field keeps its default.
+            pub fn decode_fields(data: &[u8], fields: &[u32]) -> Result<Self, DecodeError> {
+                Self::decode_fields_with(data, fields, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn decode_fields_with(data: &[u8], fields: &[u32], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+                if data.len() > opts.max_message_len {
+                    return Err(DecodeError::SizeLimitExceeded);
+                }
+                let mut msg = Self::default();
+                msg.merge_selected(data, opts, Some(fields))?;
+                Ok(msg)
+            }
+
+            /// Reset every field to its proto3 default.
+            pub fn clear(&mut self) {
+                *self = Self::default();
+            }
+
+            pub fn merge(&mut self, data: &[u8]) -> Result<(), DecodeError> {
+                self.merge_with(data, &DecodeOptions::DEFAULT)
+            }
+
+            pub fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                self.merge_selected(data, opts, None)
+            }
+
+            fn merge_selected(&mut self, data: &[u8], opts: &DecodeOptions, only: Option<&[u32]>) -> Result<(), DecodeError> {
+                let mut pos = 0usize;
+                let end = data.len();
+                let mut seen = [0u64; 1];
+
+                while pos < end {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+                    if !is_selected(only, tag >> 3) {
+                        pos = skip_field(data, pos, tag)?;
+                        continue;
+                    }
+
+                    let mut field = || -> Result<(), DecodeError> {
+                        match tag {
+                        10 => {
+                            mark_singular(&mut seen, 0, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.start.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        18 => {
+                            mark_singular(&mut seen, 1, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            self.term.merge_with(sub, &opts.nested()?)?;
+                            pos = new_pos;
+                        }
+                        26 => {
+                            mark_singular(&mut seen, 2, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.deposit.get_or_insert_with(Default::default);
+                            UInt64Value::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        34 => {
+                            mark_singular(&mut seen, 3, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.note.get_or_insert_with(Default::default);
+                            StringValue::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        42 => {
+                            mark_singular(&mut seen, 4, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.renewable.get_or_insert_with(Default::default);
+                            BoolValue::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        50 => {
+                            mark_singular(&mut seen, 5, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.priority.get_or_insert_with(Default::default);
+                            Int32Value::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        58 => {
+                            mark_singular(&mut seen, 6, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.rate.get_or_insert_with(Default::default);
+                            DoubleValue::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        66 => {
+                            mark_singular(&mut seen, 7, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.tenant.get_or_insert_with(Default::default);
+                            BytesValue::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        74 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.payments.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.payments.push(Timestamp::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        82 => {
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if self.slots.len() >= opts.max_repeated_len {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.slots.push(UInt32Value::decode_with(sub, &opts.nested()?)?);
+                            pos = new_pos;
+                        }
+                        90 => {
+                            let (entry, new_pos) = decode_message_slice(data, pos)?;
+                            let mut key = String::new();
+                            let mut value = Duration::DEFAULT;
+                            let mut entry_pos = 0usize;
+                            while entry_pos < entry.len() {
+                                let (entry_tag, next) = decode_key(entry, entry_pos)?;
+                                entry_pos = next;
+                                match entry_tag {
+                                    10 => {
+                                        let (v, next) = decode_string_with(entry, entry_pos, opts)?;
+                                        key = v;
+                                        entry_pos = next;
+                                    }
+                                    18 => {
+                                        let (sub, next) = decode_message_slice(entry, entry_pos)?;
+                                        value.merge_with(sub, &opts.nested()?)?;
+                                        entry_pos = next;
+                                    }
+                                    _ => {
+                                        entry_pos = skip_field(entry, entry_pos, entry_tag)?;
+                                    }
+                                }
+                            }
+                            if self.grace.len() >= opts.max_repeated_len && !self.grace.contains_key(&key) {
+                                return Err(DecodeError::SizeLimitExceeded);
+                            }
+                            self.grace.insert(key, value);
+                            pos = new_pos;
+                        }
+                        98 => {
+                            mark_singular(&mut seen, 8, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if let LeaseCap::Limit(existing) = &mut self.cap {
+                                existing.merge_with(sub, &opts.nested()?)?;
+                            } else {
+                                self.cap = LeaseCap::Limit(Int64Value::decode_with(sub, &opts.nested()?)?);
+                            }
+                            pos = new_pos;
+                        }
+                        106 => {
+                            mark_singular(&mut seen, 8, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            if let LeaseCap::Ratio(existing) = &mut self.cap {
+                                existing.merge_with(sub, &opts.nested()?)?;
+                            } else {
+                                self.cap = LeaseCap::Ratio(FloatValue::decode_with(sub, &opts.nested()?)?);
+                            }
+                            pos = new_pos;
+                        }
+                        114 => {
+                            mark_singular(&mut seen, 9, opts)?;
+                            let (sub, new_pos) = decode_message_slice(data, pos)?;
+                            let value = self.rent.get_or_insert_with(Default::default);
+                            UInt64Value::merge_value(value, sub, opts)?;
+                            pos = new_pos;
+                        }
+                        _ => {
+                            if opts.reject_wire_type_mismatch {
+                                check_wire_type(tag, field_start, Self::FIELD_WIRE_TYPES)?;
+                            }
+                            pos = skip_field(data, pos, tag)?;
+                        }
+                        }
+                        Ok(())
+                    };
+                    let result = field();
+                    trace_field(Self::FULL_NAME, tag, field_start, pos, result.as_ref().err());
+                    if let Err(e) = result {
+                        return Err(field_error(e, data, field_start, tag, Self::FIELD_WIRE_TYPES, self.next_index(tag >> 3)));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// The element of repeated field `number` being decoded, for `field_error`.
+            fn next_index(&self, number: u64) -> Option<usize> {
+                match number {
+                    9 => Some(self.payments.len()),
+                    10 => Some(self.slots.len()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Message for Lease {
+            fn encode_into(&self, buf: &mut Vec<u8>) {
+                Lease::encode_into(self, buf)
+            }
+
+            fn encoded_len(&self) -> usize {
+                Lease::encoded_len(self)
+            }
+
+            fn write_to(&self, w: &mut SliceWriter<'_>) -> Result<(), DecodeError> {
+                Lease::write_to(self, w)
+            }
+
+            fn write_reverse(&self, w: &mut ReverseWriter<'_>) {
+                Lease::write_reverse(self, w)
+            }
+
+            fn encoded_len_cached(&self, sizes: &mut SizeCache) -> usize {
+                Lease::encoded_len_cached(self, sizes)
+            }
+
+            fn write_to_sized(&self, w: &mut SliceWriter<'_>, sizes: &mut SizeCursor<'_>) -> Result<(), DecodeError> {
+                Lease::write_to_sized(self, w, sizes)
+            }
+
+            fn merge_with(&mut self, data: &[u8], opts: &DecodeOptions) -> Result<(), DecodeError> {
+                Lease::merge_with(self, data, opts)
+            }
+        }
+
+        impl TryFrom<&[u8]> for Lease {
+            type Error = DecodeError;
+
+            fn try_from(data: &[u8]) -> Result<Self, DecodeError> {
+                Lease::decode_with(data, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        impl From<Lease> for Vec<u8> {
+            fn from(msg: Lease) -> Vec<u8> {
+                msg.encode()
+            }
+        }
+
+        impl MessageName for Lease {
+            const FULL_NAME: &'static str = "example.Lease";
+        }
+
+        impl Lease {
+            pub fn limit(&self) -> Option<&Int64Value> {
+                match &self.cap {
+                    LeaseCap::Limit(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_limit(&mut self, value: Int64Value) {
+                self.cap = LeaseCap::Limit(value);
+            }
+
+            pub fn ratio(&self) -> Option<&FloatValue> {
+                match &self.cap {
+                    LeaseCap::Ratio(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            pub fn set_ratio(&mut self, value: FloatValue) {
+                self.cap = LeaseCap::Ratio(value);
+            }
+        }
+
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct LeaseBuilder {
+            inner: Lease,
+        }
+
+        impl Lease {
+            pub fn builder() -> LeaseBuilder {
+                LeaseBuilder::default()
+            }
+        }
+
+        impl LeaseBuilder {
+            pub fn with_start(mut self, value: Timestamp) -> Self {
+                self.inner.start = value;
+                self
+            }
+
+            pub fn with_term(mut self, value: Duration) -> Self {
+                self.inner.term = value;
+                self
+            }
+
+            pub fn with_deposit(mut self, value: u64) -> Self {
+                self.inner.deposit = Some(value);
+                self
+            }
+
+            pub fn with_note(mut self, value: String) -> Self {
+                self.inner.note = Some(value);
+                self
+            }
+
+            pub fn with_renewable(mut self, value: bool) -> Self {
+                self.inner.renewable = Some(value);
+                self
+            }
+
+            pub fn with_priority(mut self, value: i32) -> Self {
+                self.inner.priority = Some(value);
+                self
+            }
+
+            pub fn with_rate(mut self, value: f64) -> Self {
+                self.inner.rate = Some(value);
+                self
+            }
+
+            pub fn with_tenant(mut self, value: Vec<u8>) -> Self {
+                self.inner.tenant = Some(value);
+                self
+            }
+
+            pub fn with_payments(mut self, value: Vec<Timestamp>) -> Self {
+                self.inner.payments = value;
+                self
+            }
+
+            pub fn add_payments(mut self, value: Timestamp) -> Self {
+                self.inner.payments.push(value);
+                self
+            }
+
+            pub fn with_slots(mut self, value: Vec<UInt32Value>) -> Self {
+                self.inner.slots = value;
+                self
+            }
+
+            pub fn add_slots(mut self, value: UInt32Value) -> Self {
+                self.inner.slots.push(value);
+                self
+            }
+
+            pub fn with_grace(mut self, value: BTreeMap<String, Duration>) -> Self {
+                self.inner.grace = value;
+                self
+            }
+
+            pub fn insert_grace(mut self, key: String, value: Duration) -> Self {
+                self.inner.grace.insert(key, value);
+                self
+            }
+
+            pub fn with_limit(mut self, value: Int64Value) -> Self {
+                self.inner.cap = LeaseCap::Limit(value);
+                self
+            }
+
+            pub fn with_ratio(mut self, value: FloatValue) -> Self {
+                self.inner.cap = LeaseCap::Ratio(value);
+                self
+            }
+
+            pub fn with_rent(mut self, value: u64) -> Self {
+                self.inner.rent = Some(value);
+                self
+            }
+
+            pub fn build(self) -> Lease {
+                self.inner
+            }
+        }
+
+        impl Lease {
+            pub fn write_text(&self, out: &mut TextWriter) {
+                if self.start != Timestamp::default() {
+                    out.begin_message("start");
+                    self.start.write_text(out);
+                    out.end_message();
+                }
+                if self.term != Duration::default() {
+                    out.begin_message("term");
+                    self.term.write_text(out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.deposit {
+                    out.begin_message("deposit");
+                    UInt64Value::write_text_value(elem, out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.note {
+                    out.begin_message("note");
+                    StringValue::write_text_value(elem, out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.renewable {
+                    out.begin_message("renewable");
+                    BoolValue::write_text_value(elem, out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.priority {
+                    out.begin_message("priority");
+                    Int32Value::write_text_value(elem, out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.rate {
+                    out.begin_message("rate");
+                    DoubleValue::write_text_value(elem, out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.tenant {
+                    out.begin_message("tenant");
+                    BytesValue::write_text_value(elem, out);
+                    out.end_message();
+                }
+                for elem in &self.payments {
+                    out.begin_message("payments");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for elem in &self.slots {
+                    out.begin_message("slots");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                for (key, value) in &self.grace {
+                    out.begin_message("grace");
+                    out.write_str("key", key);
+                    out.begin_message("value");
+                    value.write_text(out);
+                    out.end_message();
+                    out.end_message();
+                }
+                if let LeaseCap::Limit(elem) = &self.cap {
+                    out.begin_message("limit");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if let LeaseCap::Ratio(elem) = &self.cap {
+                    out.begin_message("ratio");
+                    elem.write_text(out);
+                    out.end_message();
+                }
+                if let Some(elem) = &self.rent {
+                    out.begin_message("rent");
+                    UInt64Value::write_text_value(elem, out);
+                    out.end_message();
+                }
+            }
+        }
+
+        impl core::fmt::Display for Lease {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = TextWriter::new(f.alternate());
+                self.write_text(&mut out);
+                f.write_str(&out.finish())
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl Lease {
+            pub fn to_json(&self) -> String {
+                let mut out = JsonWriter::new();
+                self.write_json(&mut out);
+                out.finish()
+            }
+
+            pub fn write_json(&self, out: &mut JsonWriter) {
+                out.begin_object();
+                if self.start != Timestamp::default() {
+                    out.key("start");
+                    self.start.write_json(out);
+                }
+                if self.term != Duration::default() {
+                    out.key("term");
+                    self.term.write_json(out);
+                }
+                if let Some(elem) = &self.deposit {
+                    out.key("deposit");
+                    out.write_u64(*elem);
+                }
+                if let Some(elem) = &self.note {
+                    out.key("note");
+                    out.write_str(elem);
+                }
+                if let Some(elem) = &self.renewable {
+                    out.key("renewable");
+                    out.write_bool(*elem);
+                }
+                if let Some(elem) = &self.priority {
+                    out.key("priority");
+                    out.write_i32(*elem);
+                }
+                if let Some(elem) = &self.rate {
+                    out.key("rate");
+                    out.write_f64(*elem);
+                }
+                if let Some(elem) = &self.tenant {
+                    out.key("tenant");
+                    out.write_bytes(elem);
+                }
+                if !self.payments.is_empty() {
+                    out.key("payments");
+                    out.begin_array();
+                    for elem in &self.payments {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if !self.slots.is_empty() {
+                    out.key("slots");
+                    out.begin_array();
+                    for elem in &self.slots {
+                        elem.write_json(out);
+                    }
+                    out.end_array();
+                }
+                if !self.grace.is_empty() {
+                    out.key("grace");
+                    out.begin_object();
+                    for (key, value) in &self.grace {
+                        out.key(key);
+                        value.write_json(out);
+                    }
+                    out.end_object();
+                }
+                if let LeaseCap::Limit(elem) = &self.cap {
+                    out.key("limit");
+                    elem.write_json(out);
+                }
+                if let LeaseCap::Ratio(elem) = &self.cap {
+                    out.key("ratio");
+                    elem.write_json(out);
+                }
+                if let Some(elem) = &self.rent {
+                    out.key("rent");
+                    out.write_u64(*elem);
+                }
+                out.end_object();
+            }
+
+            pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+                Self::from_json_value(&parse_json(json)?)
+            }
+
+            pub fn from_json_value(value: &JsonValue) -> Result<Self, DecodeError> {
+                let mut msg = Self::default();
+                for (key, value) in json_object(value)? {
+                    if value.is_null() {
+                        continue;
+                    }
+                    match key.as_str() {
+                        "start" => msg.start = Timestamp::from_json_value(value)?,
+                        "term" => msg.term = Duration::from_json_value(value)?,
+                        "deposit" => msg.deposit = Some(json_to_u64(value)?),
+                        "note" => msg.note = Some(json_to_string(value)?),
+                        "renewable" => msg.renewable = Some(json_to_bool(value)?),
+                        "priority" => msg.priority = Some(json_to_i32(value)?),
+                        "rate" => msg.rate = Some(json_to_f64(value)?),
+                        "tenant" => msg.tenant = Some(json_to_bytes(value)?),
+                        "payments" => {
+                            for elem in json_array(value)? {
+                                msg.payments.push(Timestamp::from_json_value(elem)?);
+                            }
+                        }
+                        "slots" => {
+                            for elem in json_array(value)? {
+                                msg.slots.push(UInt32Value::from_json_value(elem)?);
+                            }
+                        }
+                        "grace" => {
+                            for (key, value) in json_object(value)? {
+                                msg.grace.insert(json_map_key(key)?, Duration::from_json_value(value)?);
+                            }
+                        }
+                        "limit" => msg.cap = LeaseCap::Limit(Int64Value::from_json_value(value)?),
+                        "ratio" => msg.cap = LeaseCap::Ratio(FloatValue::from_json_value(value)?),
+                        "rent" => msg.rent = Some(json_to_u64(value)?),
+                        _ => return Err(DecodeError::InvalidData("unknown JSON field")),
+                    }
+                }
+                Ok(msg)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct LeaseRef<'a> {
+            raw: &'a [u8],
+            pub start: Option<Timestamp>,
+            pub term: Option<Duration>,
+            pub deposit: Option<u64>,
+            pub note: Option<&'a str>,
+            pub renewable: Option<bool>,
+            pub priority: Option<i32>,
+            pub rate: Option<f64>,
+            pub tenant: Option<&'a [u8]>,
+            pub payments: RepeatedRef<'a, Timestamp>,
+            pub slots: RepeatedRef<'a, u32>,
+            pub limit: Option<i64>,
+            pub ratio: Option<f32>,
+            pub rent: Option<u64>,
+        }
+
+        impl<'a> LeaseRef<'a> {
+            pub fn decode(data: &'a [u8]) -> Result<Self, DecodeError> {
+                let mut view = LeaseRef {
+                    raw: data,
+                    start: None,
+                    term: None,
+                    deposit: None,
+                    note: None,
+                    renewable: None,
+                    priority: None,
+                    rate: None,
+                    tenant: None,
+                    payments: RepeatedRef::new(data, 74, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((Timestamp::decode(v)?, new_pos))
+                    }),
+                    slots: RepeatedRef::new(data, 82, |data, pos| {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        Ok((wrapper_value_ref(v, 8, |data, pos| decode_varint(data, pos).map(|(v, pos)| (v as u32, pos)))?, new_pos))
+                    }),
+                    limit: None,
+                    ratio: None,
+                    rent: None,
+                };
+
+                let mut pos = 0usize;
+                while pos < data.len() {
+                    let field_start = pos;
+                    let (tag, new_pos) = decode_key(data, pos)?;
+                    pos = new_pos;
+
+                    match tag {
+                    10 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.start = Some(Timestamp::decode(v)?);
+                        pos = new_pos;
+                    }
+                    18 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.term = Some(Duration::decode(v)?);
+                        pos = new_pos;
+                    }
+                    26 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.deposit = Some(wrapper_value_ref(v, 8, decode_varint)?);
+                        pos = new_pos;
+                    }
+                    34 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.note = Some(wrapper_value_ref(v, 10, decode_str_ref)?);
+                        pos = new_pos;
+                    }
+                    42 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.renewable = Some(wrapper_value_ref(v, 8, decode_bool)?);
+                        pos = new_pos;
+                    }
+                    50 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.priority = Some(wrapper_value_ref(v, 8, decode_int32)?);
+                        pos = new_pos;
+                    }
+                    58 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.rate = Some(wrapper_value_ref(v, 9, decode_double)?);
+                        pos = new_pos;
+                    }
+                    66 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.tenant = Some(wrapper_value_ref(v, 10, decode_bytes_ref)?);
+                        pos = new_pos;
+                    }
+                    74 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    82 => {
+                        pos = skip_field(data, pos, 2)?;
+                    }
+                    98 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.limit = Some(wrapper_value_ref(v, 8, decode_int64)?);
+                        view.ratio = None;
+                        pos = new_pos;
+                    }
+                    106 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.ratio = Some(wrapper_value_ref(v, 13, decode_float)?);
+                        view.limit = None;
+                        pos = new_pos;
+                    }
+                    114 => {
+                        let (v, new_pos) = decode_message_slice(data, pos)?;
+                        view.rent = Some(wrapper_value_ref(v, 8, decode_varint)?);
+                        pos = new_pos;
+                    }
+                    _ => {
+                        pos = skip_field(data, pos, tag)?;
+                    }
+                    }
+                    trace_field(Lease::FULL_NAME, tag, field_start, pos, None);
+                }
+
+                Ok(view)
+            }
+
+            /// The encoded message this view borrows from.
+            pub fn raw(&self) -> &'a [u8] {
+                self.raw
+            }
+
+            /// Decode the borrowed bytes into the owned message.
+            pub fn to_message(&self) -> Result<Lease, DecodeError> {
+                Lease::decode_with(self.raw, &DecodeOptions::ACCOUNT)
+            }
+        }
+
+        /// Every message in this file, for dispatching `Any` payloads by type URL.
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyMessage {
+            Lease(Lease),
+        }
+
+        impl AnyMessage {
+            /// Decode `any` as the message its type URL names, or `None` if that
+            /// message is not in this file.
+            pub fn unpack(any: &Any) -> Result<Option<Self>, DecodeError> {
+                Ok(Some(match any.type_name() {
+                    Lease::FULL_NAME => AnyMessage::Lease(Lease::decode_with(&any.value, &DecodeOptions::ACCOUNT)?),
+                    _ => return Ok(None),
+                }))
+            }
+
+            pub fn pack(&self) -> Any {
+                match self {
+                    AnyMessage::Lease(msg) => Any::pack(msg),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1058 @@
+//! Behaviour of the generated code. The snapshots are compiled in as
+//! modules, so these tests exercise exactly the code `golden.rs` checks
+//! in, against the runtime crate.
+
+// `pub`, as a crate exporting generated code would declare them, so
+// clippy lints the generated API as exported
+// Not `example`, which the snapshot's own outer module is named. Package
+// `example`'s file `example.rs` bundles as `example::example`
+#[allow(clippy::module_inception)]
+#[path = "../snapshots/example.rs"]
+pub mod example_snapshot;
+#[path = "../snapshots/discriminator.rs"]
+pub mod discriminator;
+#[path = "../snapshots/map.rs"]
+pub mod map;
+#[path = "../snapshots/nested.rs"]
+pub mod nested;
+#[path = "../snapshots/optional.rs"]
+pub mod optional;
+#[path = "../snapshots/packed.rs"]
+pub mod packed;
+#[path = "../snapshots/recursive.rs"]
+pub mod recursive;
+#[path = "../snapshots/required.rs"]
+pub mod required;
+#[path = "../snapshots/service.rs"]
+pub mod service;
+#[path = "../snapshots/unknown_fields.rs"]
+pub mod unknown_fields;
+#[path = "../snapshots/validate.rs"]
+pub mod validate;
+#[path = "../snapshots/versioned.rs"]
+pub mod versioned;
+
+use protoc_gen_solana_runtime::{
+    decode_bytes_ref, encode_bytes, encode_key, encode_packed_fixed32, encode_packed_uint32, encode_string,
+    encode_varint, DecodeError, DecodeOptions, Decoder, Empty, Message, SizeCache, SliceWriter, ValidationError,
+    ValidationRule,
+};
+
+use discriminator::example::discriminator::{FreezeAuthority, MintAuthority};
+use example_snapshot::example::example::{Instruction, InstructionKind, Transaction};
+use map::example::map::{Registry, Slot};
+use nested::example::nested::{Inner, Outer, OuterRef, Tagged};
+use optional::example::optional::AccountPatch;
+use packed::example::packed::Samples;
+use recursive::example::recursive::{Expr, ExprUnary, Ping, Rally, TreeNode};
+use required::example::required::{Withdraw, WithdrawRef, WithdrawRoute, WithdrawTarget};
+use service::example::service::{DepositRequest, VaultHandler, VaultInstruction, WithdrawRequest, WithdrawResponse};
+use unknown_fields::example::unknown_fields::{LegacyLeg, LegacyOrder, Order, OrderLeg};
+use validate::example::validate::{CreateMarket, CreateMarketOracle, MarketConfig};
+use versioned::example::versioned::{Gauge, GaugeMigrations, GaugeV1};
+
+/// `data` with every occurrence of field `number` taken out.
+fn without_field(data: &[u8], number: u32) -> Vec<u8> {
+    let mut decoder = Decoder::new(data);
+    let mut out = Vec::new();
+    while let Some(field) = decoder.next_field().unwrap() {
+        if field.number() != number {
+            out.extend_from_slice(field.raw);
+        }
+    }
+    out
+}
+
+#[test]
+fn merge_matches_concatenated_decode() {
+    // First half sets inner.a, second half sets only inner.b
+    let mut first = Vec::new();
+    encode_key(&mut first, 0x08);
+    encode_varint(&mut first, 1);
+    encode_key(&mut first, 0x10);
+    encode_varint(&mut first, 10);
+    let mut inner_a = Vec::new();
+    encode_key(&mut inner_a, 0x08);
+    encode_varint(&mut inner_a, 5);
+    encode_key(&mut first, 0x1a);
+    encode_bytes(&mut first, &inner_a);
+
+    let mut second = Vec::new();
+    encode_key(&mut second, 0x08);
+    encode_varint(&mut second, 2);
+    encode_key(&mut second, 0x12);
+    encode_packed_uint32(&mut second, &[20, 30]);
+    let mut inner_b = Vec::new();
+    encode_key(&mut inner_b, 0x12);
+    encode_string(&mut inner_b, "b");
+    encode_key(&mut second, 0x1a);
+    encode_bytes(&mut second, &inner_b);
+
+    let mut merged = Outer::default();
+    merged.merge(&first).unwrap();
+    merged.merge(&second).unwrap();
+
+    let concatenated = [first, second].concat();
+    assert_eq!(merged, Outer::decode(&concatenated).unwrap());
+
+    // scalar last-wins, repeated appends across unpacked and packed runs, nested merges field-by-field
+    assert_eq!(merged.scalar, 2);
+    assert_eq!(merged.items, vec![10, 20, 30]);
+    assert_eq!(merged.inner, Inner { a: 5, b: "b".to_string() });
+
+    merged.clear();
+    assert_eq!(merged, Outer::default());
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn borsh_layout_converts_to_protobuf() {
+    use protoc_gen_solana_runtime::{borsh_to_protobuf, protobuf_to_borsh};
+
+    let value = Tagged {
+        phase: -1,
+        limit: Some(0),
+        tags: vec!["a".to_string(), "bc".to_string()],
+        inner: Inner { a: 300, b: "x".to_string() },
+    };
+
+    // Borsh layout follows field declaration order
+    let account = borsh::to_vec(&value).unwrap();
+    assert_eq!(
+        account,
+        [
+            &[0xFF, 0xFF, 0xFF, 0xFF][..], // phase: i32 LE
+            &[1, 0, 0, 0, 0],              // limit: Some(0u32)
+            &[2, 0, 0, 0, 1, 0, 0, 0, b'a', 2, 0, 0, 0, b'b', b'c'],
+            &[0x2C, 0x01, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, b'x'],
+        ]
+        .concat()
+    );
+    let restored: Tagged = borsh::from_slice(&account).unwrap();
+    assert_eq!(restored, value);
+
+    // Directly encoded protobuf reference
+    let mut inner = Vec::new();
+    encode_key(&mut inner, 0x08);
+    encode_varint(&mut inner, 300);
+    encode_key(&mut inner, 0x12);
+    encode_string(&mut inner, "x");
+    let mut expected = Vec::new();
+    encode_key(&mut expected, 0x08);
+    encode_varint(&mut expected, -1i32 as u64);
+    encode_key(&mut expected, 0x10);
+    encode_varint(&mut expected, 0);
+    encode_key(&mut expected, 0x1a);
+    encode_string(&mut expected, "a");
+    encode_key(&mut expected, 0x1a);
+    encode_string(&mut expected, "bc");
+    encode_key(&mut expected, 0x22);
+    encode_bytes(&mut expected, &inner);
+
+    assert_eq!(restored.encode(), expected);
+    assert_eq!(borsh_to_protobuf::<Tagged>(&account).unwrap(), expected);
+    assert_eq!(protobuf_to_borsh::<Tagged>(&expected).unwrap(), account);
+
+    // None is a single zero tag byte
+    let unset = Tagged { limit: None, ..value };
+    assert_eq!(borsh::to_vec(&unset).unwrap()[4], 0);
+
+    assert!(borsh_to_protobuf::<Tagged>(&account[..account.len() - 1]).is_err());
+}
+
+#[test]
+fn default_scalars_omitted_and_messages_written() {
+    // Scalars and strings at their default are left out; the sub-message
+    // is written even when empty
+    assert!(Inner::default().encode().is_empty());
+    assert_eq!(Outer::default().encode(), vec![0x1a, 0x00]);
+    let zeros = Outer { scalar: 0, items: vec![], inner: Inner { a: 0, b: String::new() } };
+    assert_eq!(zeros.encode(), vec![0x1a, 0x00]);
+    assert_eq!(Outer::default().encoded_len(), 2);
+
+    // Only the non-default scalars are emitted, inside the sub-message too
+    let one = Outer { inner: Inner { a: 0, b: "x".to_string() }, ..Default::default() };
+    assert_eq!(one.encode(), vec![0x1a, 0x03, 0x12, 0x01, b'x']);
+    assert_eq!(Outer { scalar: 5, ..Default::default() }.encode(), vec![0x08, 0x05, 0x1a, 0x00]);
+
+    // Repeated zeros are still elements
+    assert_eq!(Outer { items: vec![0], ..Default::default() }.encode(), vec![0x12, 0x01, 0x00, 0x1a, 0x00]);
+    assert_eq!(Outer::decode(&[]).unwrap(), Outer::default());
+    assert_eq!(Outer::decode(&[0x1a, 0x00]).unwrap(), Outer::default());
+}
+
+#[test]
+fn encoded_len_matches_encode() {
+    let inner = Inner { a: 300, b: "x".repeat(200) };
+    let msg = Outer { scalar: u64::MAX, items: vec![0, 1, u32::MAX], inner };
+    for m in [msg.clone(), Outer::default()] {
+        assert_eq!(m.encoded_len(), m.encode().len());
+    }
+
+    // The sub-message is its own encoding behind a two-byte length prefix
+    let outer = Outer { scalar: 1, items: vec![2], inner: Inner { a: 3, b: "x".repeat(200) } };
+    let mut expected = Vec::new();
+    encode_key(&mut expected, 0x08);
+    encode_varint(&mut expected, 1);
+    encode_key(&mut expected, 0x12);
+    encode_packed_uint32(&mut expected, &[2]);
+    encode_key(&mut expected, 0x1a);
+    encode_bytes(&mut expected, &outer.inner.encode());
+    assert_eq!(outer.encode(), expected);
+}
+
+#[test]
+fn encode_into_reused_buffer() {
+    let first = Outer { scalar: 1, items: vec![2, 3], ..Default::default() };
+    let second = Outer { inner: Inner { a: 9, b: "z".to_string() }, ..Default::default() };
+
+    // encode_into appends, so the buffer is reused by clearing it
+    let mut buf = Vec::new();
+    let mut framed = Vec::new();
+    for msg in [&first, &second] {
+        buf.clear();
+        msg.encode_into(&mut buf);
+        assert_eq!(buf, msg.encode());
+        encode_bytes(&mut framed, &buf);
+    }
+
+    let (a, pos) = decode_bytes_ref(&framed, 0).unwrap();
+    let (b, end) = decode_bytes_ref(&framed, pos).unwrap();
+    assert_eq!(end, framed.len());
+    assert_eq!(Outer::decode(a).unwrap(), first);
+    assert_eq!(Outer::decode(b).unwrap(), second);
+
+    // Without clearing, the encodings concatenate
+    let mut joined = first.encode();
+    second.encode_into(&mut joined);
+    assert_eq!(joined.len(), a.len() + b.len());
+    assert!(joined.starts_with(a) && joined.ends_with(b));
+}
+
+#[test]
+fn encode_to_slice() {
+    let msg = Outer { scalar: 7, items: vec![1, 300], inner: Inner { a: 2, b: "x".repeat(130) } };
+    let expected = msg.encode();
+
+    let mut buf = [0u8; 256];
+    let n = msg.encode_to_slice(&mut buf).unwrap();
+    assert_eq!(&buf[..n], &expected[..]);
+
+    // One byte short: rejected before anything is written
+    let mut short = vec![0xEEu8; expected.len() - 1];
+    assert!(matches!(msg.encode_to_slice(&mut short), Err(DecodeError::BufferTooSmall)));
+    assert!(short.iter().all(|&b| b == 0xEE));
+
+    let mut empty = [0u8; 0];
+    assert_eq!(Inner::default().encode_to_slice(&mut empty).unwrap(), 0);
+    assert!(matches!(Outer::default().encode_to_slice(&mut empty), Err(DecodeError::BufferTooSmall)));
+}
+
+#[test]
+fn size_cache() {
+    let outer = Outer { scalar: 1, items: vec![2], inner: Inner { a: 3, b: "x".repeat(200) } };
+    let sizes = SizeCache::of(&outer);
+    assert_eq!(sizes.encoded_len(), outer.encoded_len());
+    assert_eq!(outer.encode_with_sizes(&sizes), outer.encode());
+    let mut buf = vec![0u8; 512];
+    let n = outer.encode_to_slice_with_sizes(&mut buf, &sizes).unwrap();
+    assert_eq!(buf[..n], outer.encode()[..]);
+
+    // A cache of a value of another length is caught at the end
+    let stale = SizeCache::of(&Outer { scalar: 300, ..outer.clone() });
+    assert!(matches!(outer.encode_to_slice_with_sizes(&mut buf, &stale), Err(DecodeError::InvalidData(_))));
+
+    // Sub-message lengths are read in recording order and checked
+    // against the body written
+    let mut cache = SizeCache::default();
+    cache.record(&Inner::default());
+    cache.record(&outer);
+    let mut cursor = cache.cursor();
+    assert_eq!(cursor.peek().unwrap(), outer.encoded_len());
+    let mut w = SliceWriter::new(&mut buf);
+    w.write_message_sized(1, &outer, &mut cursor).unwrap();
+    assert!(matches!(cursor.next_size(), Err(DecodeError::InvalidData(_))));
+    let mut w = SliceWriter::new(&mut buf);
+    let err = w.write_message_sized(1, &Outer::default(), &mut cache.cursor()).unwrap_err();
+    assert!(matches!(err, DecodeError::InvalidData(_)));
+}
+
+#[test]
+fn decode_limits_reach_sub_messages() {
+    let opts = DecodeOptions { max_alloc: 16, ..DecodeOptions::DEFAULT };
+
+    // A nested string over the cap, reached through the sub-message's merge_with
+    let mut inner = Vec::new();
+    encode_key(&mut inner, 0x12);
+    encode_string(&mut inner, "seventeen bytes!!");
+    let mut outer = Vec::new();
+    encode_key(&mut outer, 0x1a);
+    encode_bytes(&mut outer, &inner);
+    assert!(matches!(Outer::decode_with(&outer, &opts), Err(DecodeError::SizeLimitExceeded)));
+    assert!(Outer::decode(&outer).is_ok());
+
+    // Each sub-message takes one level of max_depth
+    let mut outer = Vec::new();
+    encode_key(&mut outer, 0x1a);
+    encode_bytes(&mut outer, &[0x08, 0x01]);
+    let flat = DecodeOptions { max_depth: 0, ..DecodeOptions::DEFAULT };
+    assert!(matches!(Outer::decode_with(&outer, &flat), Err(DecodeError::RecursionLimitExceeded)));
+    let one = DecodeOptions { max_depth: 1, ..DecodeOptions::DEFAULT };
+    assert_eq!(Outer::decode_with(&outer, &one).unwrap().inner.a, 1);
+    // Messages without sub-messages on the wire are unaffected
+    assert!(Outer::decode_with(&[0x08, 0x01], &flat).is_ok());
+}
+
+#[test]
+fn ref_view_borrows_input() {
+    let msg = Outer { scalar: 9, items: vec![1, 2], inner: Inner { a: 3, b: "vault".to_string() } };
+    let mut bytes = msg.encode();
+    // A later, unpacked occurrence of the repeated field, after the sub-message
+    bytes.extend_from_slice(&[0x10, 0x03]);
+
+    let view = OuterRef::decode(&bytes).unwrap();
+    assert_eq!(view.raw(), &bytes[..]);
+    assert_eq!(view.scalar, 9);
+    assert_eq!(view.items.iter().collect::<Result<Vec<_>, _>>().unwrap(), vec![1, 2, 3]);
+    let inner = view.inner.unwrap();
+    assert_eq!((inner.a, inner.b), (3, "vault"));
+    assert!(bytes.as_ptr_range().contains(&inner.b.as_ptr()));
+    assert_eq!(view.to_message().unwrap(), Outer { items: vec![1, 2, 3], ..msg });
+
+    let empty = OuterRef::decode(&[]).unwrap();
+    assert!(empty.items.is_empty() && empty.inner.is_none());
+
+    // Invalid UTF-8 fails the view decode
+    assert!(OuterRef::decode(&[0x1a, 0x03, 0x12, 0x01, 0xff]).is_err());
+}
+
+#[test]
+fn builder_encodes_like_literal() {
+    let built = Outer::builder().add_items(4).with_inner(Inner { a: 0, b: "b".to_string() }).add_items(5).build();
+    let literal = Outer { scalar: 0, items: vec![4, 5], inner: Inner { a: 0, b: "b".to_string() } };
+    assert_eq!(built, literal);
+    assert_eq!(built.encode(), literal.encode());
+    assert_eq!(Outer::builder().with_scalar(3).build().encode(), Outer { scalar: 3, ..Default::default() }.encode());
+
+    // with_ replaces the elements add_ accumulated
+    let replaced = Outer::builder().add_items(1).with_items(vec![2, 3]).add_items(4).build();
+    assert_eq!(replaced.items, vec![2, 3, 4]);
+    assert_eq!(Outer::builder().build(), Outer::default());
+}
+
+#[test]
+fn slice_and_vec_conversions() {
+    let msg = Outer { scalar: 300, items: vec![1, 2], inner: Inner { a: 1, b: "x".to_string() } };
+    let bytes: Vec<u8> = msg.clone().into();
+    assert_eq!(bytes, msg.encode());
+    assert_eq!(Outer::try_from(bytes.as_slice()).unwrap(), msg);
+
+    // Cutting the sub-message short leaves its length prefix unsatisfied
+    let err = Outer::try_from(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert!(matches!(err, DecodeError::BufferOverflow));
+}
+
+#[test]
+fn strict_rejects_duplicate_singular() {
+    // scalar = 1, then scalar = 2
+    let dup = [0x08, 0x01, 0x08, 0x02];
+    assert_eq!(Outer::decode(&dup).unwrap().scalar, 2);
+    assert!(matches!(
+        Outer::decode_with(&dup, &DecodeOptions::STRICT),
+        Err(DecodeError::InvalidData("duplicate singular field"))
+    ));
+
+    // A duplicate inside a sub-message is caught by the sub-message's decoder
+    let nested = [0x1a, 0x04, 0x08, 0x01, 0x08, 0x02];
+    assert_eq!(Outer::decode(&nested).unwrap().inner.a, 2);
+    assert!(Outer::decode_with(&nested, &DecodeOptions::STRICT).is_err());
+
+    // Repeated fields may appear any number of times in both modes, packed or not
+    let repeated = [0x10, 0x01, 0x08, 0x05, 0x12, 0x01, 0x02, 0x10, 0x03];
+    let expected = Outer { scalar: 5, items: vec![1, 2, 3], ..Default::default() };
+    assert_eq!(Outer::decode(&repeated).unwrap(), expected);
+    assert_eq!(Outer::decode_with(&repeated, &DecodeOptions::STRICT).unwrap(), expected);
+
+    // Strictness is per buffer: merging a second buffer still overwrites
+    let mut msg = Outer::decode_with(&dup[..2], &DecodeOptions::STRICT).unwrap();
+    msg.merge_with(&dup[2..], &DecodeOptions::STRICT).unwrap();
+    assert_eq!(msg.scalar, 2);
+}
+
+#[test]
+fn optional_scalars_keep_presence() {
+    let zero = AccountPatch { delta: Some(0), ..Default::default() };
+    let unset = AccountPatch::default();
+
+    // Some(0) is emitted on the wire, None is omitted
+    assert_eq!(zero.encode(), vec![0x08, 0x00]);
+    assert!(unset.encode().is_empty());
+
+    assert_eq!(AccountPatch::decode(&zero.encode()).unwrap(), zero);
+    assert_eq!(AccountPatch::decode(&unset.encode()).unwrap(), unset);
+
+    let neg = AccountPatch { delta: Some(-7), ..Default::default() };
+    assert_eq!(AccountPatch::decode(&neg.encode()).unwrap(), neg);
+}
+
+#[test]
+fn packed_runs_stop_at_max_repeated_len() {
+    let opts = DecodeOptions { max_repeated_len: 3, ..DecodeOptions::DEFAULT };
+
+    // fixed32s = 7: four elements in one run, over the budget of three
+    let mut data = Vec::new();
+    encode_key(&mut data, 0x3a);
+    encode_packed_fixed32(&mut data, &[1, 2, 3, 4]);
+    assert!(matches!(Samples::decode_with(&data, &opts), Err(DecodeError::SizeLimitExceeded)));
+    assert_eq!(Samples::decode(&data).unwrap().fixed32s, vec![1, 2, 3, 4]);
+
+    // uint32s = 9: two runs of two, the second taking the field past three
+    let mut data = Vec::new();
+    for run in [[1, 2], [3, 4]] {
+        encode_key(&mut data, 0x4a);
+        encode_packed_uint32(&mut data, &run);
+    }
+    assert!(matches!(Samples::decode_with(&data, &opts), Err(DecodeError::SizeLimitExceeded)));
+    assert_eq!(Samples::decode_with(&data[..data.len() / 2], &opts).unwrap().uint32s, vec![1, 2]);
+
+    // legacy = 15 [packed = false]: one key per element, counted the same
+    let mut data = Vec::new();
+    for v in 1..=4 {
+        encode_key(&mut data, 0x78);
+        encode_varint(&mut data, v);
+    }
+    assert!(matches!(Samples::decode_with(&data, &opts), Err(DecodeError::SizeLimitExceeded)));
+    let at_budget = Samples::decode_with(&data[..6], &opts).unwrap();
+    assert_eq!(at_budget.legacy, vec![1, 2, 3]);
+}
+
+#[test]
+fn required_fields_checked_on_decode() {
+    let msg = Withdraw {
+        authority: [1; 32],
+        amount: 5,
+        slot: Some(0),
+        fee: Some(0),
+        target: WithdrawTarget { vault: [2; 32], index: 0 },
+        ..Default::default()
+    };
+    let data = msg.encode();
+    assert_eq!(Withdraw::decode(&data).unwrap(), msg);
+    assert_eq!(WithdrawRef::decode(&data).unwrap().to_message().unwrap(), msg);
+
+    for number in [1, 2, 4, 5, 6] {
+        let missing = without_field(&data, number);
+        assert!(
+            matches!(Withdraw::decode(&missing), Err(DecodeError::MissingField { field }) if field == number),
+            "field {number}"
+        );
+        assert!(matches!(WithdrawRef::decode(&missing), Err(DecodeError::MissingField { field }) if field == number));
+    }
+    assert!(matches!(Withdraw::decode(&[]), Err(DecodeError::MissingField { field: 1 })));
+
+    // The check is on the wire: amount = 0 written out passes, but encode
+    // leaves a zero amount out, as it does a `None` slot
+    let mut zero = without_field(&data, 2);
+    encode_key(&mut zero, 0x10);
+    encode_varint(&mut zero, 0);
+    assert_eq!(Withdraw::decode(&zero).unwrap(), Withdraw { amount: 0, ..msg.clone() });
+    let zero = Withdraw { amount: 0, ..msg.clone() };
+    assert!(matches!(Withdraw::decode(&zero.encode()), Err(DecodeError::MissingField { field: 2 })));
+    let unset = Withdraw { slot: None, ..msg.clone() };
+    assert!(matches!(Withdraw::decode(&unset.encode()), Err(DecodeError::MissingField { field: 4 })));
+
+    // Checked in a sub-message too
+    let target = msg.target.encode();
+    assert_eq!(WithdrawTarget::decode(&target).unwrap(), msg.target);
+    let mut no_vault = without_field(&data, 6);
+    encode_key(&mut no_vault, 0x32);
+    encode_bytes(&mut no_vault, &without_field(&target, 1));
+    assert!(matches!(Withdraw::decode(&no_vault), Err(DecodeError::MissingField { field: 1 })));
+
+    // Fields that are not required, even with the option, may be absent
+    assert_eq!(Withdraw::decode(&without_field(&without_field(&data, 3), 7)).unwrap(), msg);
+    // Merging checks each buffer on its own
+    let mut merged = msg.clone();
+    assert!(matches!(merged.merge(&without_field(&data, 2)), Err(DecodeError::MissingField { field: 2 })));
+}
+
+#[test]
+fn oneof_later_arm_replaces_earlier() {
+    let transfer = Transaction { value: 7, ..Default::default() };
+    let mut data = Instruction { nonce: 1, kind: InstructionKind::Transfer(transfer.clone()) }.encode();
+    // The selected arm is written even at its default
+    assert_eq!(Instruction { nonce: 0, kind: InstructionKind::BurnAmount(0) }.encode(), vec![0x18, 0x00]);
+    assert!(Instruction::default().encode().is_empty());
+
+    // burn_amount = 3, then memo = 4: each arm replaces the one before
+    encode_key(&mut data, 0x18);
+    encode_varint(&mut data, 500);
+    assert_eq!(Instruction::decode(&data).unwrap().kind, InstructionKind::BurnAmount(500));
+    encode_key(&mut data, 0x22);
+    encode_string(&mut data, "memo");
+    assert_eq!(Instruction::decode(&data).unwrap().kind, InstructionKind::Memo("memo".to_string()));
+
+    // A transfer after another arm starts from an empty Transaction
+    let mut gas = Vec::new();
+    encode_key(&mut gas, 0x20);
+    encode_varint(&mut gas, 9);
+    encode_key(&mut data, 0x12);
+    encode_bytes(&mut data, &gas);
+    let decoded = Instruction::decode(&data).unwrap();
+    let fresh = Transaction { gas_limit: 9, ..Default::default() };
+    assert_eq!(decoded, Instruction { nonce: 1, kind: InstructionKind::Transfer(fresh) });
+
+    // Across merges too
+    let mut msg = Instruction { nonce: 1, kind: InstructionKind::Memo("m".to_string()) };
+    msg.merge(&[0x18, 0x02]).unwrap();
+    assert_eq!(msg.kind, InstructionKind::BurnAmount(2));
+
+    // STRICT takes a second arm, like a second value of one field, as a duplicate
+    assert!(matches!(
+        Instruction::decode_with(&[0x18, 0x01, 0x22, 0x00], &DecodeOptions::STRICT),
+        Err(DecodeError::InvalidData("duplicate singular field"))
+    ));
+}
+
+#[test]
+fn oneof_message_arm_merges() {
+    let first = Transaction { to: vec![1, 2], value: 3, ..Default::default() };
+    let second = Transaction { value: 4, gas_limit: 5, ..Default::default() };
+    let mut data = Vec::new();
+    encode_key(&mut data, 0x12);
+    encode_bytes(&mut data, &first.encode());
+    encode_key(&mut data, 0x12);
+    encode_bytes(&mut data, &second.encode());
+
+    // Two occurrences of the transfer arm merge field by field
+    let merged = Transaction { to: vec![1, 2], value: 4, gas_limit: 5, ..Default::default() };
+    assert_eq!(Instruction::decode(&data).unwrap().kind, InstructionKind::Transfer(merged.clone()));
+
+    // As do a transfer already set and one merged in
+    let mut msg = Instruction { nonce: 0, kind: InstructionKind::Transfer(first) };
+    msg.merge(&Instruction { nonce: 0, kind: InstructionKind::Transfer(second) }.encode()).unwrap();
+    assert_eq!(msg.kind, InstructionKind::Transfer(merged));
+}
+
+/// A `balances` entry (field 1) with whichever of its key and value are given.
+fn balance_entry(data: &mut Vec<u8>, key: Option<&str>, value: Option<u64>) {
+    let mut entry = Vec::new();
+    if let Some(key) = key {
+        encode_key(&mut entry, 0x0a);
+        encode_string(&mut entry, key);
+    }
+    if let Some(value) = value {
+        encode_key(&mut entry, 0x10);
+        encode_varint(&mut entry, value);
+    }
+    encode_key(data, 0x0a);
+    encode_bytes(data, &entry);
+}
+
+#[test]
+fn map_entries_decode_as_proto_maps() {
+    // A key seen twice keeps its last value
+    let mut data = Vec::new();
+    balance_entry(&mut data, Some("a"), Some(1));
+    balance_entry(&mut data, Some("b"), Some(2));
+    balance_entry(&mut data, Some("a"), Some(3));
+    let balances = Registry::decode(&data).unwrap().balances;
+    assert_eq!(balances.into_iter().collect::<Vec<_>>(), vec![("a".to_string(), 3), ("b".to_string(), 2)]);
+
+    // An entry missing its key or value takes the type's default
+    let mut data = Vec::new();
+    balance_entry(&mut data, None, Some(4));
+    balance_entry(&mut data, Some("c"), None);
+    let balances = Registry::decode(&data).unwrap().balances;
+    assert_eq!(balances.into_iter().collect::<Vec<_>>(), vec![(String::new(), 4), ("c".to_string(), 0)]);
+
+    // Message values too, and an empty entry is the default key and value
+    let mut data = Vec::new();
+    encode_key(&mut data, 0x42);
+    encode_bytes(&mut data, &[0x08, 0x07]);
+    encode_key(&mut data, 0x42);
+    encode_bytes(&mut data, &[]);
+    let slots = Registry::decode(&data).unwrap().slots;
+    assert_eq!(slots.into_iter().collect::<Vec<_>>(), vec![(0, Slot::default()), (7, Slot::default())]);
+
+    // Merging inserts over the keys already there
+    let mut msg = Registry { epoch: 1, ..Default::default() };
+    msg.balances.insert("a".to_string(), 1);
+    msg.balances.insert("b".to_string(), 2);
+    let mut more = Vec::new();
+    balance_entry(&mut more, Some("b"), Some(5));
+    msg.merge(&more).unwrap();
+    assert_eq!(msg.balances.into_iter().collect::<Vec<_>>(), vec![("a".to_string(), 1), ("b".to_string(), 5)]);
+}
+
+#[test]
+fn map_entries_stop_at_max_repeated_len() {
+    let opts = DecodeOptions { max_repeated_len: 2, ..DecodeOptions::DEFAULT };
+    let mut data = Vec::new();
+    for key in ["a", "b", "a", "b"] {
+        balance_entry(&mut data, Some(key), Some(1));
+    }
+    // Replacing a value under the budget's keys adds no entry
+    assert_eq!(Registry::decode_with(&data, &opts).unwrap().balances.len(), 2);
+
+    // A third distinct key does
+    balance_entry(&mut data, Some("c"), Some(1));
+    assert!(matches!(Registry::decode_with(&data, &opts), Err(DecodeError::SizeLimitExceeded)));
+    assert_eq!(Registry::decode(&data).unwrap().balances.len(), 3);
+
+    // Counted per map: two keys in each of two maps fit
+    let msg = Registry {
+        labels: [(1, "x".to_string()), (2, "y".to_string())].into_iter().collect(),
+        counts: [(false, 1), (true, 2)].into_iter().collect(),
+        ..Default::default()
+    };
+    assert_eq!(Registry::decode_with(&msg.encode(), &opts).unwrap(), msg);
+}
+
+/// Records the rpcs it is called with.
+#[derive(Default)]
+struct Vault {
+    calls: Vec<VaultInstruction>,
+}
+
+impl VaultHandler<u64> for Vault {
+    type Error = DecodeError;
+
+    fn deposit(&mut self, _ctx: u64, request: DepositRequest) -> Result<Empty, DecodeError> {
+        self.calls.push(VaultInstruction::Deposit(request));
+        Ok(Empty {})
+    }
+
+    fn withdraw(&mut self, ctx: u64, request: WithdrawRequest) -> Result<WithdrawResponse, DecodeError> {
+        let remaining = ctx - request.amount;
+        self.calls.push(VaultInstruction::Withdraw(request));
+        Ok(WithdrawResponse { remaining })
+    }
+}
+
+#[test]
+fn vault_instructions_round_trip_and_dispatch() {
+    let deposit = VaultInstruction::Deposit(DepositRequest { account: vec![1; 4], amount: 10 });
+    let withdraw = VaultInstruction::Withdraw(WithdrawRequest { account: vec![2; 4], amount: 3 });
+    for (instruction, tag) in [(&deposit, VaultInstruction::DEPOSIT_TAG), (&withdraw, VaultInstruction::WITHDRAW_TAG)] {
+        let data = instruction.encode();
+        assert_eq!(data[0], tag);
+        assert_eq!(instruction.tag(), tag);
+        assert_eq!(&VaultInstruction::decode(&data).unwrap(), instruction);
+    }
+    assert_eq!(VaultInstruction::WITHDRAW_TAG, 1);
+    // The request is the message's own encoding after the tag
+    assert_eq!(withdraw.encode()[1..], WithdrawRequest { account: vec![2; 4], amount: 3 }.encode());
+
+    // process decodes, runs the handler and encodes its response
+    let mut vault = Vault::default();
+    assert!(VaultInstruction::process(&mut vault, 0, &deposit.encode()).unwrap().is_empty());
+    let response = VaultInstruction::process(&mut vault, 8, &withdraw.encode()).unwrap();
+    assert_eq!(WithdrawResponse::decode(&response).unwrap().remaining, 5);
+    assert_eq!(vault.calls, vec![deposit, withdraw]);
+}
+
+#[test]
+fn vault_instruction_tags_checked() {
+    // Tag 2 would be WatchBalance's, a streaming rpc with no instruction
+    for tag in [2, 0xff] {
+        assert!(matches!(VaultInstruction::decode(&[tag]), Err(DecodeError::InvalidData("unknown instruction tag"))));
+    }
+    assert!(matches!(VaultInstruction::decode(&[]), Err(DecodeError::BufferOverflow)));
+
+    // A known tag with a malformed request fails as the request's decode does
+    assert!(matches!(VaultInstruction::decode(&[0, 0x10]), Err(DecodeError::BufferOverflow)));
+    let mut vault = Vault::default();
+    assert!(VaultInstruction::process(&mut vault, 0, &[7]).is_err());
+    assert!(vault.calls.is_empty());
+
+    // A tag alone is the request at its defaults
+    let empty = VaultInstruction::Withdraw(WithdrawRequest::default());
+    assert_eq!(VaultInstruction::decode(&[1]).unwrap(), empty);
+}
+
+#[test]
+fn unknown_fields_kept_and_dropped() {
+    let leg = |amount| OrderLeg { amount, asset: "SOL".to_string(), ..Default::default() };
+    let order = Order {
+        id: 1,
+        memo: "m".to_string(),
+        leg: leg(2),
+        flags: vec![3, 4],
+        fills: vec![leg(5)],
+        by_venue: [("dex".to_string(), leg(6))].into_iter().collect(),
+        fee: -7,
+        ..Default::default()
+    };
+    let data = order.encode();
+
+    // The older revision keeps what it does not know, in wire order, in sub-messages too
+    let legacy = LegacyOrder::decode(&data).unwrap();
+    assert_eq!(legacy.id, 1);
+    assert_eq!(legacy.unknown_fields.iter().map(|(tag, _)| tag >> 3).collect::<Vec<_>>(), vec![2, 4, 7]);
+    let asset = [0x12, 0x03, b'S', b'O', b'L'];
+    assert_eq!(legacy.leg.unknown_fields.as_bytes(), asset);
+    assert_eq!(legacy.fills[0].unknown_fields.as_bytes(), asset);
+    assert_eq!(legacy.by_venue["dex"].unknown_fields.as_bytes(), asset);
+
+    // and writes it back out, so the newer revision reads the message unchanged
+    let reencoded = legacy.encode();
+    assert_eq!(reencoded.len(), legacy.encoded_len());
+    assert_eq!(Order::decode(&reencoded).unwrap(), order);
+    assert_eq!(LegacyOrder::decode(&reencoded).unwrap(), legacy);
+
+    // encode_canonical leaves them out, everywhere, without touching the message
+    let known = Order {
+        id: 1,
+        leg: OrderLeg { amount: 2, ..Default::default() },
+        fills: vec![OrderLeg { amount: 5, ..Default::default() }],
+        by_venue: [("dex".to_string(), OrderLeg { amount: 6, ..Default::default() })].into_iter().collect(),
+        ..Default::default()
+    };
+    assert_eq!(Order::decode(&legacy.encode_canonical()).unwrap(), known);
+    assert_eq!(legacy.unknown_fields.iter().count(), 3);
+
+    // as does encoding after clear_unknown_fields
+    let mut cleared = legacy.clone();
+    cleared.clear_unknown_fields();
+    assert!(cleared.unknown_fields.is_empty() && cleared.leg.unknown_fields.is_empty());
+    assert!(cleared.fills[0].unknown_fields.is_empty() && cleared.by_venue["dex"].unknown_fields.is_empty());
+    assert_eq!(cleared.encode(), legacy.encode_canonical());
+    assert_eq!(cleared, LegacyOrder { leg: LegacyLeg { amount: 2, ..Default::default() }, ..cleared.clone() });
+
+    // Merging appends the unknown fields of each buffer
+    let mut merged = legacy.clone();
+    merged.merge(&[0x38, 0x01]).unwrap();
+    assert_eq!(merged.unknown_fields.iter().map(|(tag, _)| tag >> 3).collect::<Vec<_>>(), vec![2, 4, 7, 7]);
+    assert_eq!(Order::decode(&merged.encode()).unwrap().fee, -1);
+}
+
+/// Version 1 readings were in whole units.
+struct FromUnits;
+
+impl GaugeMigrations for FromUnits {
+    fn migrate_from_v1(payload: &[u8]) -> Result<Gauge, DecodeError> {
+        let old = GaugeV1::decode(payload)?;
+        Ok(Gauge { reading_milli: old.reading * 1000, ..Default::default() })
+    }
+}
+
+#[test]
+fn versioned_data_decodes_by_version() {
+    let v1 = GaugeV1 { reading: 3 };
+    let v2 = Gauge { reading_milli: 3500, label: "tank".to_string() };
+    let data = v2.encode_versioned();
+    assert_eq!(data[0], Gauge::VERSION);
+    assert_eq!(data[1..], v2.encode());
+    assert_eq!(Gauge::decode_versioned::<FromUnits>(&data).unwrap(), v2);
+
+    // Older data goes through the migration, and only through it
+    let old = v1.encode_versioned();
+    assert_eq!(old[0], GaugeV1::VERSION);
+    assert_eq!(GaugeV1::decode_versioned(&old).unwrap(), v1);
+    let migrated = Gauge::decode_versioned::<FromUnits>(&old).unwrap();
+    assert_eq!(migrated, Gauge { reading_milli: 3000, ..Default::default() });
+
+    // A version with no migration, ahead or behind, is reported as such
+    for version in [0, 3, 0xff] {
+        let mut data = v2.encode_versioned();
+        data[0] = version;
+        let result = Gauge::decode_versioned::<FromUnits>(&data);
+        assert!(matches!(result, Err(DecodeError::UnknownVersion { version: v }) if v == version));
+    }
+    assert!(matches!(GaugeV1::decode_versioned(&data), Err(DecodeError::UnknownVersion { version: 2 })));
+    assert!(matches!(GaugeV1::decode_versioned(&[]), Err(DecodeError::BufferOverflow)));
+    // The version byte alone is the message at its defaults
+    assert_eq!(Gauge::decode_versioned::<FromUnits>(&[2]).unwrap(), Gauge::default());
+}
+
+#[test]
+fn versioned_data_past_decode_cap() {
+    // Account data, so held to DecodeOptions::ACCOUNT rather than DEFAULT's 1232 bytes
+    let big = Gauge { reading_milli: 1, label: "g".repeat(2000) };
+    let data = big.encode_versioned();
+    assert!(data.len() > DecodeOptions::DEFAULT.max_message_len);
+    assert_eq!(Gauge::decode_versioned::<FromUnits>(&data).unwrap(), big);
+    assert!(matches!(Gauge::decode(&data[1..]), Err(DecodeError::SizeLimitExceeded)));
+}
+
+#[test]
+fn discriminators_tell_same_shaped_messages_apart() {
+    let mint = MintAuthority { authority: [1; 32], supply_cap: 9 };
+    let data = mint.encode_with_discriminator();
+    assert_eq!(data[..8], MintAuthority::TYPE_DISCRIMINATOR);
+    assert_eq!(data[8..], mint.encode());
+    assert_eq!(MintAuthority::decode_with_discriminator(&data).unwrap(), mint);
+
+    // The same bytes under the other message's discriminator are refused
+    assert_ne!(MintAuthority::TYPE_DISCRIMINATOR, FreezeAuthority::TYPE_DISCRIMINATOR);
+    let mismatch = |e| matches!(e, DecodeError::InvalidData("discriminator does not match the message"));
+    assert!(mismatch(FreezeAuthority::decode_with_discriminator(&data).unwrap_err()));
+    let mut flipped = data.clone();
+    flipped[7] ^= 1;
+    assert!(mismatch(MintAuthority::decode_with_discriminator(&flipped).unwrap_err()));
+    // while the payload alone decodes as either
+    assert_eq!(FreezeAuthority::decode(&data[8..]).unwrap().frozen_until, 9);
+
+    // Data shorter than the discriminator is truncated, and a payload cut short fails as decode does
+    for len in 0..8 {
+        assert!(matches!(MintAuthority::decode_with_discriminator(&data[..len]), Err(DecodeError::BufferOverflow)));
+    }
+    assert_eq!(MintAuthority::decode_with_discriminator(&data[..8]).unwrap(), MintAuthority::default());
+    let cut = &data[..data.len() - 1];
+    assert!(matches!(MintAuthority::decode(&cut[8..]), Err(DecodeError::BufferOverflow)));
+    assert!(matches!(MintAuthority::decode_with_discriminator(cut), Err(DecodeError::BufferOverflow)));
+}
+
+#[test]
+fn discriminated_data_past_decode_cap() {
+    // An account-sized payload: the message, then a 2000-byte field this revision does not know
+    let mint = MintAuthority { authority: [1; 32], supply_cap: 9 };
+    let mut data = mint.encode_with_discriminator();
+    encode_key(&mut data, 0x7a);
+    encode_bytes(&mut data, &[0; 2000]);
+    assert!(data.len() > DecodeOptions::DEFAULT.max_message_len);
+    assert_eq!(MintAuthority::decode_with_discriminator(&data).unwrap(), mint);
+    assert!(matches!(MintAuthority::decode(&data[8..]), Err(DecodeError::SizeLimitExceeded)));
+}
+
+#[test]
+fn validate_checks_each_rule_kind() {
+    let config = MarketConfig { tick_size: 1, ..Default::default() };
+    let valid = CreateMarket {
+        authority: [1; 32],
+        symbol: "SOL-PERP".to_string(),
+        fee_bps: 10000,
+        min_price: -1000,
+        tags: vec!["t:a".to_string(); 4],
+        cap: Some(1),
+        leverage: Some(20),
+        config: config.clone(),
+        history: vec![config.clone()],
+        by_region: [("eu".to_string(), config.clone())].into_iter().collect(),
+        oracle: CreateMarketOracle::Feed("pyth:SOL".to_string()),
+        tiers: vec![0],
+    };
+    // Most rules at one of their bounds
+    assert_eq!(valid.validate(), Ok(()));
+
+    // Values at the other bounds, or unset, pass too
+    let passes: Vec<fn(&mut CreateMarket)> = vec![
+        |m| m.symbol = "SOLAN-PERP".to_string(),
+        |m| m.min_price = 1000,
+        |m| m.tags.clear(),
+        |m| m.cap = None,
+        |m| m.leverage = None,
+        |m| m.leverage = Some(1),
+        |m| {
+            m.by_region.insert("asia".to_string(), MarketConfig { tick_size: 1, ..Default::default() });
+        },
+        |m| m.oracle = CreateMarketOracle::FixedPrice(1),
+        |m| m.oracle = CreateMarketOracle::Unset,
+        |m| m.history.clear(),
+    ];
+    for (i, change) in passes.into_iter().enumerate() {
+        let mut msg = valid.clone();
+        change(&mut msg);
+        assert_eq!(msg.validate(), Ok(()), "case {i}");
+    }
+
+    // One past a bound fails, naming the field and the rule
+    type Case = (fn(&mut CreateMarket), &'static str, ValidationRule);
+    let fails: Vec<Case> = vec![
+        (|m| m.authority = [0; 32], "authority", ValidationRule::NonDefault),
+        (|m| m.symbol = "-P".to_string(), "symbol", ValidationRule::MinLen(3)),
+        (|m| m.symbol = "SOLANA-PERP".to_string(), "symbol", ValidationRule::MaxLen(10)),
+        (|m| m.symbol = "SOL-SPOT".to_string(), "symbol", ValidationRule::Suffix("-PERP")),
+        (|m| m.fee_bps = 10001, "fee_bps", ValidationRule::Max(10000)),
+        (|m| m.min_price = -1001, "min_price", ValidationRule::Min(-1000)),
+        (|m| m.min_price = 1001, "min_price", ValidationRule::Max(1000)),
+        (|m| m.tags[2] = "a".to_string(), "tags[2]", ValidationRule::Prefix("t:")),
+        (|m| m.tags.push("t:e".to_string()), "tags", ValidationRule::MaxCount(4)),
+        (|m| m.cap = Some(0), "cap", ValidationRule::Min(1)),
+        (|m| m.leverage = Some(0), "leverage", ValidationRule::Min(1)),
+        (|m| m.leverage = Some(21), "leverage", ValidationRule::Max(20)),
+        (|m| m.config.tick_size = 0, "config.tick_size", ValidationRule::Min(1)),
+        (|m| m.history.push(MarketConfig::default()), "history[1].tick_size", ValidationRule::Min(1)),
+        (|m| m.by_region.get_mut("eu").unwrap().tick_size = 0, "by_region[\"eu\"].tick_size", ValidationRule::Min(1)),
+        (
+            |m| {
+                m.by_region.insert("europe".to_string(), MarketConfig { tick_size: 1, ..Default::default() });
+            },
+            "by_region[\"europe\"]",
+            ValidationRule::MaxLen(4),
+        ),
+        (|m| m.oracle = CreateMarketOracle::Feed("chainlink:SOL".to_string()), "feed", ValidationRule::Prefix("pyth:")),
+        (|m| m.oracle = CreateMarketOracle::FixedPrice(0), "fixed_price", ValidationRule::Min(1)),
+        (|m| m.tiers.clear(), "tiers", ValidationRule::NonDefault),
+    ];
+    for (change, path, rule) in fails {
+        let mut msg = valid.clone();
+        change(&mut msg);
+        assert_eq!(msg.validate(), Err(ValidationError { path: path.to_string(), rule }));
+    }
+
+    // The first broken rule, in field order, is the one reported
+    let broken = CreateMarket { fee_bps: 10001, tiers: Vec::new(), ..valid.clone() };
+    assert_eq!(broken.validate().unwrap_err().to_string(), "fee_bps: greater than 10000");
+    assert_eq!(CreateMarket::default().validate().unwrap_err().path, "authority");
+}
+
+#[test]
+fn typestate_builder_builds_once_required_set() {
+    // `build()` compiling at all is the typestate check; `lib.rs` has the
+    // doctests showing it is missing before the required setters
+    let target = WithdrawTarget::builder().with_index(3).with_vault([2; 32]).build();
+    assert_eq!(target, WithdrawTarget { vault: [2; 32], index: 3 });
+
+    let built = Withdraw::builder()
+        .with_memo("m".to_string())
+        .with_target(target.clone())
+        .with_fee(0)
+        .add_tags(1)
+        .with_slot(7)
+        .with_amount(5)
+        .with_hops(2)
+        .with_authority([1; 32])
+        .add_tags(2)
+        .build();
+    let expected = Withdraw {
+        authority: [1; 32],
+        amount: 5,
+        memo: "m".to_string(),
+        slot: Some(7),
+        fee: Some(0),
+        target,
+        tags: vec![1, 2],
+        route: WithdrawRoute::Hops(2),
+    };
+    assert_eq!(built, expected);
+    // and passes decode's own required-field check
+    assert_eq!(Withdraw::decode(&built.encode()).unwrap(), expected);
+
+    // Setting a field again, required or not, keeps the last value
+    let again = Withdraw::builder()
+        .with_authority([9; 32])
+        .with_authority([1; 32])
+        .with_amount(5)
+        .with_slot(7)
+        .with_fee(0)
+        .with_target(expected.target.clone())
+        .with_relay("r".to_string())
+        .with_hops(2)
+        .with_tags(vec![1, 2])
+        .with_memo("m".to_string())
+        .build();
+    assert_eq!(again, expected);
+}
+
+/// `leaf` inside `depth` levels of the length-delimited field `key`.
+fn nested_in(key: u64, depth: usize, leaf: &[u8]) -> Vec<u8> {
+    let mut data = leaf.to_vec();
+    for _ in 0..depth {
+        let mut outer = Vec::new();
+        encode_key(&mut outer, key);
+        encode_bytes(&mut outer, &data);
+        data = outer;
+    }
+    data
+}
+
+#[test]
+fn recursion_limit_bounds_nesting() {
+    let limit = DecodeOptions::DEFAULT.max_depth;
+    // literal = 1 at the bottom, so the innermost level is there to check
+    let leaf = [0x08, 0x01];
+
+    // Every way Expr holds itself: boxed, optional, a oneof arm and repeated
+    for key in [0x12, 0x1a, 0x22, 0x32] {
+        let mut expr = Expr::decode(&nested_in(key, limit, &leaf)).unwrap();
+        for _ in 0..limit {
+            expr = match key {
+                0x12 => *expr.left.unwrap(),
+                0x1a => *expr.right.unwrap(),
+                0x32 => expr.args.remove(0),
+                _ => match expr.unary {
+                    ExprUnary::Negate(inner) => *inner,
+                    other => panic!("{other:?}"),
+                },
+            };
+        }
+        assert_eq!(expr.literal, 1, "key {key:#x}");
+        let deep = nested_in(key, limit + 1, &leaf);
+        assert!(matches!(Expr::decode(&deep), Err(DecodeError::RecursionLimitExceeded)), "key {key:#x}");
+    }
+
+    // TreeNode's too; a map entry is not a level of its own, only its value is
+    let named = |depth| {
+        let mut data = Vec::new();
+        for _ in 0..depth {
+            let mut entry = Vec::new();
+            encode_key(&mut entry, 0x12);
+            encode_bytes(&mut entry, &data);
+            data.clear();
+            encode_key(&mut data, 0x1a);
+            encode_bytes(&mut data, &entry);
+        }
+        data
+    };
+    assert_eq!(TreeNode::decode(&named(limit)).unwrap().named[""].named.len(), 1);
+    assert!(matches!(TreeNode::decode(&named(limit + 1)), Err(DecodeError::RecursionLimitExceeded)));
+    assert!(TreeNode::decode(&nested_in(0x12, limit, &[])).is_ok());
+    assert!(matches!(TreeNode::decode(&nested_in(0x12, limit + 1, &[])), Err(DecodeError::RecursionLimitExceeded)));
+
+    // A cycle through two messages counts each of them, from wherever it starts
+    let rally = |depth| {
+        let mut data = Vec::new();
+        encode_key(&mut data, 0x0a);
+        encode_bytes(&mut data, &nested_in(0x0a, depth - 1, &[]));
+        data
+    };
+    assert!(Rally::decode(&rally(limit)).is_ok());
+    assert!(matches!(Rally::decode(&rally(limit + 1)), Err(DecodeError::RecursionLimitExceeded)));
+    assert!(Ping::decode(&nested_in(0x0a, limit, &[])).is_ok());
+
+    // The budget is the options', and merge spends it as decode does
+    let shallow = DecodeOptions { max_depth: 2, ..DecodeOptions::DEFAULT };
+    assert!(Expr::decode_with(&nested_in(0x12, 2, &leaf), &shallow).is_ok());
+    let deep = nested_in(0x12, 3, &leaf);
+    assert!(matches!(Expr::decode_with(&deep, &shallow), Err(DecodeError::RecursionLimitExceeded)));
+    let mut expr = Expr::default();
+    assert!(matches!(expr.merge(&nested_in(0x22, limit + 1, &leaf)), Err(DecodeError::RecursionLimitExceeded)));
+
+    // Far past the limit, decoding stops there rather than on the stack;
+    // encode has no limit, so it writes what decode then refuses
+    let far = nested_in(0x12, 5000, &leaf);
+    assert!(matches!(Expr::decode_with(&far, &DecodeOptions::ACCOUNT), Err(DecodeError::RecursionLimitExceeded)));
+    let mut built = Expr { literal: 1, ..Default::default() };
+    for _ in 0..=limit {
+        built = Expr { left: Some(Box::new(built)), ..Default::default() };
+    }
+    assert_eq!(built.encode(), nested_in(0x12, limit + 1, &leaf));
+    assert!(matches!(Expr::decode(&built.encode()), Err(DecodeError::RecursionLimitExceeded)));
+}
+
+#[test]
+fn view_of_split_sub_message_is_its_last_piece() {
+    // inner = 3 written twice, each piece setting one of its fields
+    let mut data = Vec::new();
+    encode_key(&mut data, 0x1a);
+    encode_bytes(&mut data, &Inner { a: 5, ..Default::default() }.encode());
+    encode_key(&mut data, 0x1a);
+    encode_bytes(&mut data, &Inner { b: "x".to_string(), ..Default::default() }.encode());
+
+    // The owned message merges the pieces, as protobuf does
+    let merged = Inner { a: 5, b: "x".to_string() };
+    assert_eq!(Outer::decode(&data).unwrap().inner, merged);
+
+    // The view only borrows, so it sees the last piece, while to_message() merges
+    let view = OuterRef::decode(&data).unwrap();
+    let inner = view.inner.unwrap();
+    assert_eq!((inner.a, inner.b), (0, "x"));
+    assert_eq!(view.to_message().unwrap().inner, merged);
+
+    // Encoding the merged message writes one piece, which the view reads whole
+    let whole = Outer::decode(&data).unwrap().encode();
+    let inner = OuterRef::decode(&whole).unwrap().inner.unwrap();
+    assert_eq!((inner.a, inner.b), (5, "x"));
+}
@@ -0,0 +1,190 @@
+//! Golden-file tests of the generated code. `snapshots` runs the plugin
+//! (`PROTOC_GEN_SOLANA`, else `dist/bin/protoc-gen-solana` from
+//! `pnpm dist`) over every fixture and compares each output with its
+//! snapshot; `UPDATE_GOLDEN=1` rewrites the ones that differ instead.
+//! `snapshots_compile` builds every snapshot against the runtime crate, so
+//! a generator change shows up both as a reviewable diff and as a compile
+//! error. `snapshots_breaking` checks the `breaking` command's report on
+//! `tests/protos/breaking/` against `snapshots/breaking.txt` the same way.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// `.proto` files generated together into one snapshot, with the plugin
+/// options. Each is generated with `bundle` and with the runtime crate as
+/// `runtime_path`, so a snapshot is one file without a copy of the runtime.
+struct Fixture {
+    name: &'static str,
+    protos: &'static [&'static str],
+    options: &'static [&'static str],
+}
+
+const fn fixture(name: &'static str, protos: &'static [&'static str], options: &'static [&'static str]) -> Fixture {
+    Fixture { name, protos, options }
+}
+
+/// Every file in `tests/protos/` with `ref_views`, with the options its
+/// messages are written for, plus a few common option variants.
+const FIXTURES: &[Fixture] = &[
+    fixture("any", &["any.proto"], &["ref_views"]),
+    fixture("bounded", &["bounded.proto"], &["ref_views"]),
+    fixture("bounded_tests", &["bounded.proto"], &["ref_views", "tests"]),
+    fixture("bounded_vec", &["bounded_vec.proto"], &["ref_views", "tests"]),
+    fixture("bounded_vec_option", &["bounded.proto"], &["ref_views", "bounded_vec"]),
+    fixture("canonical", &["canonical.proto"], &["ref_views"]),
+    fixture("client", &["discriminator.proto", "versioned.proto"], &["ref_views", "client"]),
+    fixture("client_pod", &["pod.proto"], &["ref_views", "pod", "client"]),
+    fixture("comments", &["comments.proto"], &["ref_views"]),
+    fixture("cow", &["cow.proto"], &["ref_views", "cow_structs", "tests"]),
+    fixture("deprecated", &["deprecated.proto"], &["ref_views"]),
+    fixture("deprecated_omitted", &["deprecated.proto"], &["ref_views", "omit_deprecated"]),
+    fixture("derive", &["derive.proto"], &["ref_views"]),
+    fixture("discriminator", &["discriminator.proto"], &["ref_views"]),
+    fixture("editions", &["editions.proto"], &["ref_views"]),
+    fixture("enums", &["enums.proto"], &["ref_views"]),
+    fixture("events", &["events.proto"], &["ref_views"]),
+    fixture("example", &["example.proto"], &["ref_views"]),
+    fixture("example_reflect", &["example.proto"], &["reflect"]),
+    fixture("example_unknown_fields", &["example.proto"], &["ref_views", "unknown_fields"]),
+    fixture("extensions", &["extensions.proto", "proto2.proto"], &["ref_views"]),
+    fixture("field_numbers", &["field_numbers.proto"], &["ref_views"]),
+    fixture("fixed_bytes", &["fixed_bytes.proto"], &["ref_views"]),
+    fixture("floats", &["floats.proto"], &["ref_views"]),
+    fixture("imports", &["imports.proto", "enums.proto", "events.proto", "example.proto"], &["ref_views"]),
+    fixture("map", &["map.proto"], &["ref_views"]),
+    fixture("nested", &["nested.proto"], &["ref_views"]),
+    fixture("optional", &["optional.proto"], &["ref_views"]),
+    fixture("packed", &["packed.proto"], &["ref_views"]),
+    fixture("pda", &["pda.proto"], &["ref_views"]),
+    fixture("pod", &["pod.proto"], &["ref_views", "pod"]),
+    fixture("proto2", &["proto2.proto"], &["ref_views"]),
+    fixture("pubkey", &["pubkey.proto"], &["ref_views"]),
+    fixture("recursive", &["recursive.proto"], &["ref_views"]),
+    fixture("rename", &["rename.proto"], &["ref_views", "overrides=../protos/rename.overrides.json"]),
+    fixture("required", &["required.proto"], &["ref_views"]),
+    fixture("service", &["service.proto"], &["ref_views"]),
+    fixture("unknown_fields", &["unknown_fields.proto"], &["ref_views", "unknown_fields"]),
+    fixture("utf8", &["utf8.proto"], &["ref_views"]),
+    fixture("validate", &["validate.proto"], &["ref_views"]),
+    fixture("versioned", &["versioned.proto"], &["ref_views"]),
+    fixture("wasm", &["service.proto", "events.proto"], &["ref_views", "wasm"]),
+    fixture("well_known", &["well_known.proto"], &["ref_views"]),
+];
+
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn snapshot_path(fixture: &Fixture) -> PathBuf {
+    manifest_dir().join("snapshots").join(format!("{}.rs", fixture.name))
+}
+
+/// The plugin binary, as `generate` runs it.
+fn plugin() -> PathBuf {
+    env::var_os("PROTOC_GEN_SOLANA")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| manifest_dir().join("../../dist/bin/protoc-gen-solana"))
+}
+
+/// The plugin's output for `fixture`, generated under `CARGO_TARGET_TMPDIR`.
+fn generate(fixture: &Fixture) -> String {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden").join(fixture.name);
+    let file = format!("{}.rs", fixture.name);
+    let mut config = protoc_gen_solana_build::Config::new();
+    config
+        .include(manifest_dir().join("../protos"))
+        .option("runtime_path=protoc_gen_solana_runtime")
+        .out_dir(&out_dir)
+        .bundle(&file)
+        .emit_rerun_if_changed(false);
+    for option in fixture.options {
+        config.option(*option);
+    }
+    if env::var_os("PROTOC_GEN_SOLANA").is_none() {
+        config.plugin(plugin());
+    }
+    config.compile(fixture.protos).unwrap_or_else(|err| panic!("generating {}: {err}", fixture.name));
+    fs::read_to_string(out_dir.join(file)).unwrap()
+}
+
+#[test]
+fn snapshots() {
+    let update = env::var_os("UPDATE_GOLDEN").is_some_and(|value| value != "0");
+    let mut changed = Vec::new();
+    for fixture in FIXTURES {
+        let generated = generate(fixture);
+        let path = snapshot_path(fixture);
+        let snapshot = fs::read_to_string(&path).ok();
+        if snapshot.as_deref() == Some(generated.as_str()) {
+            continue;
+        }
+        if update {
+            fs::write(&path, &generated).unwrap();
+            eprintln!("updated {}", path.display());
+            continue;
+        }
+        let line = match &snapshot {
+            Some(snapshot) => {
+                let same = snapshot.lines().zip(generated.lines()).take_while(|(a, b)| a == b).count();
+                format!("first difference on line {}", same + 1)
+            }
+            None => "no snapshot".to_string(),
+        };
+        changed.push(format!("{} ({line})", fixture.name));
+    }
+    assert!(
+        changed.is_empty(),
+        "generated code differs from snapshots/: {}; rerun with UPDATE_GOLDEN=1 and review the diff",
+        changed.join(", ")
+    );
+}
+
+#[test]
+fn snapshots_compile() {
+    // Each snapshot goes in a binary of its own, as the module the bundle option documents
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden-compile");
+    fs::create_dir_all(&dir).unwrap();
+    let cases = trybuild::TestCases::new();
+    for fixture in FIXTURES {
+        let main = dir.join(format!("{}.rs", fixture.name));
+        let source = format!(
+            "#![allow(dead_code, unused_imports)]\n\n#[path = {:?}]\nmod generated;\n\nfn main() {{}}\n",
+            snapshot_path(fixture)
+        );
+        fs::write(&main, source).unwrap();
+        cases.pass(&main);
+    }
+}
+
+#[test]
+fn snapshots_breaking() {
+    let protos = manifest_dir().join("../protos/breaking");
+    let baseline = Path::new(env!("CARGO_TARGET_TMPDIR")).join("breaking-v1.pb");
+    let run = |args: &[&str]| Command::new(plugin()).arg("breaking").args(args).output().unwrap();
+
+    let written = run(&[
+        &format!("--descriptor_set_out={}", baseline.display()),
+        &format!("-I{}", protos.join("v1").display()),
+        "ledger.proto",
+    ]);
+    assert!(written.status.success(), "writing the baseline: {}", String::from_utf8_lossy(&written.stderr));
+    let against = format!("--against={}", baseline.display());
+    let unchanged = run(&[&against, &format!("-I{}", protos.join("v1").display()), "ledger.proto"]);
+    assert!(unchanged.status.success() && unchanged.stdout.is_empty(), "the baseline breaks itself");
+
+    let changed = run(&[&against, &format!("-I{}", protos.join("v2").display()), "ledger.proto"]);
+    assert_eq!(changed.status.code(), Some(1), "{}", String::from_utf8_lossy(&changed.stderr));
+    let report = String::from_utf8(changed.stdout).unwrap();
+    let path = manifest_dir().join("snapshots/breaking.txt");
+    if env::var_os("UPDATE_GOLDEN").is_some_and(|value| value != "0") {
+        fs::write(&path, &report).unwrap();
+        return;
+    }
+    assert_eq!(
+        fs::read_to_string(&path).ok().as_deref(),
+        Some(report.as_str()),
+        "the breaking report differs from snapshots/breaking.txt; rerun with UPDATE_GOLDEN=1 and review the diff"
+    );
+}
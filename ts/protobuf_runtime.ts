@@ -0,0 +1,931 @@
+// Shared protobuf3 wire format primitives for protoc-gen-solana's
+// TypeScript codecs. This file is emitted by the plugin under `ts_out`.
+//
+// The codecs write the same bytes as `protobuf_runtime.rs` and decode
+// with the limits of its `DecodeOptions::DEFAULT`, or of `ACCOUNT` where
+// the Rust side decodes account data. 64-bit integers are
+// `bigint`s; bytes, pubkeys and `(solana.fixed_len)` fields are
+// `Uint8Array`s.
+
+// ── Errors ───────────────────────────────────────────────────────────
+
+/** The Rust `DecodeError` variant a decode failed with. */
+export type DecodeErrorCode =
+  | "BufferOverflow"
+  | "InvalidVarint"
+  | "UnknownWireType"
+  | "InvalidData"
+  | "SizeLimitExceeded"
+  | "RecursionLimitExceeded"
+  | "LimitExceeded"
+  | "MissingField"
+  | "UnknownVersion"
+  | "InvalidFieldNumber"
+
+export interface DecodeError extends Error {
+  code: DecodeErrorCode
+  /** Field number of a `LimitExceeded` or `MissingField` error */
+  field?: number
+}
+
+export function decodeError(code: DecodeErrorCode, message: string, field?: number): DecodeError {
+  return Object.assign(new Error(`protobuf: ${message}`), { name: "DecodeError", code, field })
+}
+
+export function isDecodeError(err: unknown): err is DecodeError {
+  return err instanceof Error && err.name === "DecodeError"
+}
+
+// ── Decode limits (`DecodeOptions::DEFAULT`) ─────────────────────────
+
+/** Largest `bytes` / `string` payload a single field may hold. */
+export const MAX_ALLOC = 10 * 1024 * 1024
+/** Most elements a single repeated or map field may hold. */
+export const MAX_REPEATED_LEN = 1 << 20
+/** Largest buffer a top-level decode accepts: a whole Solana transaction. */
+export const MAX_MESSAGE_LEN = 1232
+/**
+ * `MAX_MESSAGE_LEN` of `DecodeOptions::ACCOUNT`, for account data and the
+ * other payloads the Rust runtime decodes with it.
+ */
+export const MAX_ACCOUNT_LEN = 10 * 1024 * 1024
+/** Most levels of sub-messages below the message being decoded. */
+export const MAX_DEPTH = 32
+
+/** The depth for a sub-message one level down. */
+export function nested(depth: number): number {
+  if (depth === 0) throw decodeError("RecursionLimitExceeded", "message nesting exceeds the recursion limit")
+  return depth - 1
+}
+
+function limitExceeded(field: number): DecodeError {
+  return decodeError("LimitExceeded", `field ${field} exceeds its declared bound`, field)
+}
+
+/** Throw `MissingField` for the first `(solana.required)` field number in `required` not in `seen`. */
+export function checkRequired(seen: Set<number>, required: number[]): void {
+  for (const field of required) {
+    if (!seen.has(field)) throw decodeError("MissingField", `required field ${field} is missing`, field)
+  }
+}
+
+// ── Message codecs ───────────────────────────────────────────────────
+
+/** Encoder and decoder of one message type, as generated for each message. */
+export interface MessageCodec<T> {
+  /** A message with every field at its proto3 default, then `init` */
+  create(init?: Partial<T>): T
+  encode(msg: T): Uint8Array
+  /** Append the message's fields, in field number order */
+  write(w: Writer, msg: T): void
+  decode(data: Uint8Array): T
+  /** Merge the fields encoded in `data` into `msg` */
+  merge(msg: T, data: Uint8Array, depth?: number): void
+}
+
+/** A `(solana.event)` message, logged as `Program data: <base64>`. */
+export interface EventCodec<T> {
+  EVENT_DISCRIMINATOR: Uint8Array
+  /** The event in a transaction log line, or `undefined` if it holds none */
+  fromLog(line: string): T | undefined
+}
+
+/** A `(solana.discriminator)` message, written after its type discriminator. */
+export interface DiscriminatedCodec<T> {
+  TYPE_DISCRIMINATOR: Uint8Array
+  encodeWithDiscriminator(msg: T): Uint8Array
+  decodeWithDiscriminator(data: Uint8Array): T
+}
+
+/** A `(solana.version)` message, written after its version byte. */
+export interface VersionedCodec<T> {
+  VERSION: number
+  encodeVersioned(msg: T): Uint8Array
+  /** Data written at `VERSION`, or at an earlier version through its entry in `migrations` */
+  decodeVersioned(data: Uint8Array, migrations?: Record<number, (payload: Uint8Array) => T>): T
+}
+
+/** A message stored in account data under the `anchor` option. */
+export interface AccountCodec<T> {
+  DISCRIMINATOR: Uint8Array
+  encodeAccount(msg: T): Uint8Array
+  decodeAccount(data: Uint8Array): T
+}
+
+export function encode<T>(codec: MessageCodec<T>, msg: T): Uint8Array {
+  const w = writer()
+  codec.write(w, msg)
+  return finish(w)
+}
+
+/** Decode a top-level message of at most `maxLen` bytes. */
+export function decode<T>(codec: MessageCodec<T>, data: Uint8Array, maxLen = MAX_MESSAGE_LEN): T {
+  if (data.length > maxLen) throw decodeError("SizeLimitExceeded", "decode size limit exceeded")
+  const msg = codec.create()
+  codec.merge(msg, data)
+  return msg
+}
+
+// ── Writer ───────────────────────────────────────────────────────────
+
+export interface Writer {
+  buf: Uint8Array
+  len: number
+}
+
+export function writer(): Writer {
+  return { buf: new Uint8Array(64), len: 0 }
+}
+
+/** The bytes written so far. */
+export function finish(w: Writer): Uint8Array {
+  return w.buf.slice(0, w.len)
+}
+
+function reserve(w: Writer, n: number): void {
+  if (w.len + n <= w.buf.length) return
+  const grown = new Uint8Array(Math.max(w.buf.length * 2, w.len + n))
+  grown.set(w.buf.subarray(0, w.len))
+  w.buf = grown
+}
+
+export function writeRaw(w: Writer, bytes: Uint8Array): void {
+  reserve(w, bytes.length)
+  w.buf.set(bytes, w.len)
+  w.len += bytes.length
+}
+
+/** Varint of the 64-bit value `hi:lo`, both unsigned 32-bit halves. */
+function writeVarintParts(w: Writer, lo: number, hi: number): void {
+  reserve(w, 10)
+  while (hi !== 0 || lo > 0x7f) {
+    w.buf[w.len++] = (lo & 0x7f) | 0x80
+    lo = ((lo >>> 7) | (hi << 25)) >>> 0
+    hi >>>= 7
+  }
+  w.buf[w.len++] = lo
+}
+
+function writeFixed(w: Writer, width: number, set: (view: DataView) => void): void {
+  reserve(w, width)
+  set(new DataView(w.buf.buffer, w.buf.byteOffset + w.len, width))
+  w.len += width
+}
+
+export function writeKey(w: Writer, tag: number): void {
+  writeVarintParts(w, tag >>> 0, 0)
+}
+
+export function writeUint32(w: Writer, value: number): void {
+  writeVarintParts(w, value >>> 0, 0)
+}
+
+/** A negative value is sign-extended to 64 bits, so it takes ten bytes. */
+export function writeInt32(w: Writer, value: number): void {
+  writeVarintParts(w, value >>> 0, value < 0 ? 0xffffffff : 0)
+}
+
+export function writeUint64(w: Writer, value: bigint): void {
+  const u = BigInt.asUintN(64, value)
+  writeVarintParts(w, Number(u & 0xffffffffn), Number(u >> 32n))
+}
+
+export function writeInt64(w: Writer, value: bigint): void {
+  writeUint64(w, value)
+}
+
+export function writeSint32(w: Writer, value: number): void {
+  writeVarintParts(w, ((value << 1) ^ (value >> 31)) >>> 0, 0)
+}
+
+export function writeSint64(w: Writer, value: bigint): void {
+  const v = BigInt.asIntN(64, value)
+  writeUint64(w, (v << 1n) ^ (v >> 63n))
+}
+
+export function writeBool(w: Writer, value: boolean): void {
+  writeVarintParts(w, value ? 1 : 0, 0)
+}
+
+export function writeFixed32(w: Writer, value: number): void {
+  writeFixed(w, 4, view => view.setUint32(0, value >>> 0, true))
+}
+
+export function writeSfixed32(w: Writer, value: number): void {
+  writeFixed(w, 4, view => view.setInt32(0, value | 0, true))
+}
+
+export function writeFloat(w: Writer, value: number): void {
+  writeFixed(w, 4, view => view.setFloat32(0, value, true))
+}
+
+export function writeFixed64(w: Writer, value: bigint): void {
+  writeFixed(w, 8, view => view.setBigUint64(0, BigInt.asUintN(64, value), true))
+}
+
+export function writeSfixed64(w: Writer, value: bigint): void {
+  writeFixed(w, 8, view => view.setBigInt64(0, BigInt.asIntN(64, value), true))
+}
+
+export function writeDouble(w: Writer, value: number): void {
+  writeFixed(w, 8, view => view.setFloat64(0, value, true))
+}
+
+export function writeBytes(w: Writer, value: Uint8Array): void {
+  writeUint32(w, value.length)
+  writeRaw(w, value)
+}
+
+export function writeString(w: Writer, value: string): void {
+  writeBytes(w, UTF8_ENCODER.encode(value))
+}
+
+/** A `(solana.fixed_len)` value, which must be exactly `len` bytes. */
+export function writeFixedBytes(w: Writer, value: Uint8Array, len: number): void {
+  if (value.length !== len) throw new RangeError(`bytes field must be ${len} bytes, got ${value.length}`)
+  writeBytes(w, value)
+}
+
+export function writePubkey(w: Writer, value: Uint8Array): void {
+  if (value.length !== PUBKEY_LEN) throw new RangeError(`pubkey must be ${PUBKEY_LEN} bytes, got ${value.length}`)
+  writeBytes(w, value)
+}
+
+/**
+ * Length-delimited field `field` holding what `body` writes; nothing at
+ * all if `skipEmpty` and `body` writes nothing.
+ */
+export function writeDelimited(w: Writer, field: number, body: (w: Writer) => void, skipEmpty = false): void {
+  const sub = writer()
+  body(sub)
+  if (skipEmpty && sub.len === 0) return
+  writeKey(w, field * 8 + 2)
+  writeUint32(w, sub.len)
+  writeRaw(w, sub.buf.subarray(0, sub.len))
+}
+
+/** Sub-message field, written even when `msg` is at its default. */
+export function writeMessage<T>(w: Writer, field: number, codec: MessageCodec<T>, msg: T): void {
+  const sub = writer()
+  codec.write(sub, msg)
+  writeKey(w, field * 8 + 2)
+  writeUint32(w, sub.len)
+  writeRaw(w, sub.buf.subarray(0, sub.len))
+}
+
+/** Proto2 group field: start-group key, the body, then the end-group key. */
+export function writeGroup<T>(w: Writer, field: number, codec: MessageCodec<T>, msg: T): void {
+  const sub = writer()
+  codec.write(sub, msg)
+  writeKey(w, field * 8 + 3)
+  writeRaw(w, sub.buf.subarray(0, sub.len))
+  writeKey(w, field * 8 + 4)
+}
+
+/** Packed repeated scalar; an empty list is left out. */
+export function writePacked<T>(w: Writer, field: number, values: T[], write: (w: Writer, value: T) => void): void {
+  if (values.length === 0) return
+  writeDelimited(w, field, sub => {
+    for (const value of values) write(sub, value)
+  })
+}
+
+/**
+ * Map entries in the order of Rust's `BTreeMap`: numbers and bools by
+ * value, strings by their UTF-8 bytes.
+ */
+export function sortedEntries<K, V>(map: Map<K, V>): Array<[K, V]> {
+  return [...map].sort(([a], [b]) => compareKeys(a, b))
+}
+
+function compareKeys(a: unknown, b: unknown): number {
+  if (typeof a === "string" && typeof b === "string") return compareBytes(UTF8_ENCODER.encode(a), UTF8_ENCODER.encode(b))
+  return (a as number) < (b as number) ? -1 : (a as number) > (b as number) ? 1 : 0
+}
+
+function compareBytes(a: Uint8Array, b: Uint8Array): number {
+  for (let i = 0; i < a.length && i < b.length; i++) {
+    if (a[i] !== b[i]) return a[i] - b[i]
+  }
+  return a.length - b.length
+}
+
+// ── Reader ───────────────────────────────────────────────────────────
+
+export interface Reader {
+  data: Uint8Array
+  pos: number
+  end: number
+}
+
+export function reader(data: Uint8Array): Reader {
+  return { data, pos: 0, end: data.length }
+}
+
+export function done(r: Reader): boolean {
+  return r.pos >= r.end
+}
+
+/**
+ * Varint as unsigned 32-bit halves `[lo, hi]`. Bits past the 64th are
+ * dropped and an eleventh byte is `InvalidVarint`, as in Rust.
+ */
+function readVarintParts(r: Reader): [number, number] {
+  let lo = 0
+  let hi = 0
+  for (let i = 0; ; i++) {
+    if (r.pos >= r.end) throw decodeError("BufferOverflow", "buffer overflow")
+    const b = r.data[r.pos++]
+    const bits = b & 0x7f
+    if (i < 4) {
+      lo |= bits << (7 * i)
+    } else if (i === 4) {
+      lo |= bits << 28
+      hi |= bits >>> 4
+    } else {
+      hi |= bits << (7 * i - 32)
+    }
+    if ((b & 0x80) === 0) return [lo >>> 0, hi >>> 0]
+    if (i === 9) throw decodeError("InvalidVarint", "invalid varint")
+  }
+}
+
+/** End of a `width`-byte value (or length-delimited payload) at the cursor. */
+function checkedEnd(r: Reader, width: number): number {
+  if (width > r.end - r.pos) throw decodeError("BufferOverflow", "buffer overflow")
+  return r.pos + width
+}
+
+function readFixed<T>(r: Reader, width: number, get: (view: DataView) => T): T {
+  const end = checkedEnd(r, width)
+  const value = get(new DataView(r.data.buffer, r.data.byteOffset + r.pos, width))
+  r.pos = end
+  return value
+}
+
+/** Largest field number a schema can declare, `2^29 - 1`. */
+export const MAX_FIELD_NUMBER = 2 ** 29 - 1
+
+/**
+ * A field key: `field_number << 3 | wire_type`. A field number of 0 or
+ * above `MAX_FIELD_NUMBER`, or wire type 6 or 7, fails as in Rust.
+ */
+export function readKey(r: Reader): number {
+  const [lo, hi] = readVarintParts(r)
+  const number = hi * 0x20000000 + Math.floor(lo / 8)
+  if (number === 0 || number > MAX_FIELD_NUMBER) throw decodeError("InvalidFieldNumber", `invalid field number ${number}`)
+  if (lo % 8 > 5) throw decodeError("UnknownWireType", `unknown wire type ${lo % 8}`)
+  return hi * 0x100000000 + lo
+}
+
+/** The low 32 bits of the varint, as other `uint32` decoders keep them. */
+export function readUint32(r: Reader): number {
+  return readVarintParts(r)[0]
+}
+
+export function readInt32(r: Reader): number {
+  return readVarintParts(r)[0] | 0
+}
+
+export function readUint64(r: Reader): bigint {
+  const [lo, hi] = readVarintParts(r)
+  return (BigInt(hi) << 32n) | BigInt(lo)
+}
+
+export function readInt64(r: Reader): bigint {
+  return BigInt.asIntN(64, readUint64(r))
+}
+
+export function readSint32(r: Reader): number {
+  const n = readVarintParts(r)[0]
+  return (n >>> 1) ^ -(n & 1)
+}
+
+export function readSint64(r: Reader): bigint {
+  const n = readUint64(r)
+  return BigInt.asIntN(64, (n >> 1n) ^ -(n & 1n))
+}
+
+export function readBool(r: Reader): boolean {
+  const [lo, hi] = readVarintParts(r)
+  return lo !== 0 || hi !== 0
+}
+
+export function readFixed32(r: Reader): number {
+  return readFixed(r, 4, view => view.getUint32(0, true))
+}
+
+export function readSfixed32(r: Reader): number {
+  return readFixed(r, 4, view => view.getInt32(0, true))
+}
+
+export function readFloat(r: Reader): number {
+  return readFixed(r, 4, view => view.getFloat32(0, true))
+}
+
+export function readFixed64(r: Reader): bigint {
+  return readFixed(r, 8, view => view.getBigUint64(0, true))
+}
+
+export function readSfixed64(r: Reader): bigint {
+  return readFixed(r, 8, view => view.getBigInt64(0, true))
+}
+
+export function readDouble(r: Reader): number {
+  return readFixed(r, 8, view => view.getFloat64(0, true))
+}
+
+/** A length-delimited payload, borrowed from the input. */
+function readPayload(r: Reader, maxLen?: number, field?: number): Uint8Array {
+  const [lo, hi] = readVarintParts(r)
+  const len = hi * 0x100000000 + lo
+  if (maxLen !== undefined && len > maxLen) throw limitExceeded(field!)
+  if (len > MAX_ALLOC) throw decodeError("SizeLimitExceeded", "decode size limit exceeded")
+  const end = checkedEnd(r, len)
+  const payload = r.data.subarray(r.pos, end)
+  r.pos = end
+  return payload
+}
+
+/** A bytes value, over `maxLen` bytes failing with `LimitExceeded` for `field`. */
+export function readBytes(r: Reader, maxLen?: number, field?: number): Uint8Array {
+  return readPayload(r, maxLen, field).slice()
+}
+
+/** A string value, over `maxLen` bytes failing with `LimitExceeded` for `field`. */
+export function readString(r: Reader, maxLen?: number, field?: number): string {
+  const raw = readPayload(r, maxLen, field)
+  try {
+    return UTF8_DECODER.decode(raw)
+  } catch {
+    throw decodeError("InvalidData", "invalid UTF-8 in string field")
+  }
+}
+
+/**
+ * A `(solana.utf8) = "lossy"` string value: invalid UTF-8 is replaced
+ * with U+FFFD, and a replaced string over `maxLen` bytes fails too.
+ */
+export function readStringLossy(r: Reader, maxLen?: number, field?: number): string {
+  const raw = readPayload(r, maxLen, field)
+  const value = UTF8_LOSSY_DECODER.decode(raw)
+  if (maxLen !== undefined && UTF8_ENCODER.encode(value).length > maxLen) {
+    throw limitExceeded(field!)
+  }
+  return value
+}
+
+export function readFixedBytes(r: Reader, len: number): Uint8Array {
+  const raw = readMessage(r)
+  if (raw.length !== len) throw decodeError("InvalidData", "bytes field has the wrong length")
+  return raw.slice()
+}
+
+export function readPubkey(r: Reader): Uint8Array {
+  const raw = readMessage(r)
+  if (raw.length !== PUBKEY_LEN) throw decodeError("InvalidData", "pubkey must be 32 bytes")
+  return raw.slice()
+}
+
+/**
+ * The body of an embedded message (or any payload read without an
+ * allocation cap), borrowed from the input.
+ */
+export function readMessage(r: Reader): Uint8Array {
+  const [lo, hi] = readVarintParts(r)
+  const end = checkedEnd(r, hi * 0x100000000 + lo)
+  const body = r.data.subarray(r.pos, end)
+  r.pos = end
+  return body
+}
+
+/**
+ * Merge the embedded message at the cursor into `msg`, or into a new
+ * one, one level below `depth`; returns the merged message.
+ */
+export function mergeMessage<T>(codec: MessageCodec<T>, r: Reader, depth: number, msg: T = codec.create()): T {
+  const body = readMessage(r)
+  codec.merge(msg, body, nested(depth))
+  return msg
+}
+
+/**
+ * Body of proto2 group `field`, whose start-group key was just read: the
+ * fields up to its matching end-group key, which the cursor moves past.
+ */
+export function readGroup(r: Reader, field: number): Uint8Array {
+  const start = r.pos
+  const end = skipGroup(r, field, MAX_DEPTH)
+  return r.data.subarray(start, end)
+}
+
+/** `mergeMessage` for a proto2 group `field`. */
+export function mergeGroup<T>(codec: MessageCodec<T>, r: Reader, field: number, depth: number, msg: T = codec.create()): T {
+  const body = readGroup(r, field)
+  codec.merge(msg, body, nested(depth))
+  return msg
+}
+
+/**
+ * Append every element of a packed payload to `out`; fixed-width
+ * payloads (`width` bytes per element) must hold whole elements.
+ */
+export function readPacked<T>(
+  r: Reader,
+  out: T[],
+  read: (r: Reader) => T,
+  width = 0,
+  maxCount?: number,
+  field?: number
+): void {
+  const body = readMessage(r)
+  if (width > 0 && body.length % width !== 0) {
+    throw decodeError("InvalidData", "packed fixed field length not a multiple of element size")
+  }
+  const sub = reader(body)
+  while (!done(sub)) out.push(read(sub))
+  if (out.length > MAX_REPEATED_LEN) throw decodeError("SizeLimitExceeded", "decode size limit exceeded")
+  if (maxCount !== undefined && out.length > maxCount) throw limitExceeded(field!)
+}
+
+/** Append a decoded repeated element, within the field's element bounds. */
+export function push<T>(out: T[], value: T, maxCount?: number, field?: number): void {
+  if (out.length >= MAX_REPEATED_LEN) throw decodeError("SizeLimitExceeded", "decode size limit exceeded")
+  if (maxCount !== undefined && out.length >= maxCount) throw limitExceeded(field!)
+  out.push(value)
+}
+
+/** Insert a decoded map entry, replacing any earlier one with its key. */
+export function setEntry<K, V>(map: Map<K, V>, key: K, value: V, maxCount?: number, field?: number): void {
+  if (!map.has(key)) {
+    if (map.size >= MAX_REPEATED_LEN) throw decodeError("SizeLimitExceeded", "decode size limit exceeded")
+    if (maxCount !== undefined && map.size >= maxCount) throw limitExceeded(field!)
+  }
+  map.set(key, value)
+}
+
+/**
+ * Skip the value of a field whose key `tag` was just read; a group ends
+ * at the end-group key of the same field number.
+ */
+export function skipField(r: Reader, tag: number, depth = MAX_DEPTH): void {
+  switch (tag % 8) {
+    case 0:
+      readVarintParts(r)
+      return
+    case 1:
+      r.pos = checkedEnd(r, 8)
+      return
+    case 2:
+      readMessage(r)
+      return
+    case 5:
+      r.pos = checkedEnd(r, 4)
+      return
+    case 3:
+      skipGroup(r, Math.floor(tag / 8), depth)
+      return
+    case 4:
+      throw decodeError("InvalidData", "end-group tag without a start group")
+    default:
+      throw decodeError("UnknownWireType", `unknown wire type ${tag % 8}`)
+  }
+}
+
+/** Skip past group `field`'s end-group key, returning where that key starts. */
+function skipGroup(r: Reader, field: number, depth: number): number {
+  if (depth === 0) throw decodeError("RecursionLimitExceeded", "message nesting exceeds the recursion limit")
+  for (;;) {
+    const at = r.pos
+    const tag = readKey(r)
+    if (tag % 8 === 4) {
+      if (Math.floor(tag / 8) === field) return at
+      throw decodeError("InvalidData", "end-group tag does not match its start group")
+    }
+    if (tag % 8 === 3) skipGroup(r, Math.floor(tag / 8), depth - 1)
+    else skipField(r, tag)
+  }
+}
+
+// ── Byte helpers ─────────────────────────────────────────────────────
+
+export const PUBKEY_LEN = 32
+
+const UTF8_ENCODER = new TextEncoder()
+const UTF8_DECODER = new TextDecoder("utf-8", { fatal: true })
+const UTF8_LOSSY_DECODER = new TextDecoder("utf-8")
+
+/** True if any byte is set, i.e. a pubkey or fixed-length value is not its default. */
+export function isNonZero(bytes: Uint8Array): boolean {
+  return bytes.some(b => b !== 0)
+}
+
+export function startsWith(data: Uint8Array, prefix: Uint8Array): boolean {
+  return data.length >= prefix.length && prefix.every((b, i) => data[i] === b)
+}
+
+export function concat(...parts: Uint8Array[]): Uint8Array {
+  const out = new Uint8Array(parts.reduce((len, p) => len + p.length, 0))
+  let pos = 0
+  for (const p of parts) {
+    out.set(p, pos)
+    pos += p.length
+  }
+  return out
+}
+
+/** Accepts standard and URL-safe alphabets, with or without padding. */
+export function base64Decode(text: string): Uint8Array {
+  const out: number[] = []
+  let acc = 0
+  let bits = 0
+  for (const c of text.replace(/=+$/, "")) {
+    const v = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/".indexOf(c === "-" ? "+" : c === "_" ? "/" : c)
+    if (v < 0) throw decodeError("InvalidData", "invalid base64")
+    acc = ((acc << 6) | v) & 0xffffff
+    bits += 6
+    if (bits >= 8) {
+      bits -= 8
+      out.push((acc >> bits) & 0xff)
+    }
+  }
+  return Uint8Array.from(out)
+}
+
+// ── Instructions ─────────────────────────────────────────────────────
+
+/** A service instruction tag: a `u8` index or an 8-byte discriminator. */
+export type InstructionTag = number | Uint8Array
+
+function tagBytes(tag: InstructionTag): Uint8Array {
+  return typeof tag === "number" ? Uint8Array.of(tag) : tag
+}
+
+/** Instruction data: the tag followed by the encoded request. */
+export function instructionData<T>(tag: InstructionTag, codec: MessageCodec<T>, request: T): Uint8Array {
+  return concat(tagBytes(tag), codec.encode(request))
+}
+
+/** The request bytes after a `tagLen`-byte tag. */
+export function instructionPayload(data: Uint8Array, tagLen: number): Uint8Array {
+  if (data.length < tagLen) throw decodeError("BufferOverflow", "buffer overflow")
+  return data.subarray(tagLen)
+}
+
+export function hasTag(data: Uint8Array, tag: InstructionTag): boolean {
+  return startsWith(data, tagBytes(tag))
+}
+
+/** An entry of an rpc's `(solana.accounts)`, in instruction order. */
+export interface AccountSpec {
+  name: string
+  isSigner: boolean
+  isWritable: boolean
+}
+
+// ── Length-delimited streams ─────────────────────────────────────────
+
+/** `msg` prefixed with its encoded length as a varint, as Rust's `encode_length_delimited` writes it. */
+export function encodeLengthDelimited<T>(codec: MessageCodec<T>, msg: T): Uint8Array {
+  const w = writer()
+  writeBytes(w, codec.encode(msg))
+  return finish(w)
+}
+
+/**
+ * Every message of back-to-back `encodeLengthDelimited` frames, in
+ * order; a last frame that was cut short is `BufferOverflow`.
+ */
+export function decodeLengthDelimited<T>(codec: MessageCodec<T>, data: Uint8Array): T[] {
+  const r = reader(data)
+  const out: T[] = []
+  while (!done(r)) out.push(decode(codec, readMessage(r), MAX_ACCOUNT_LEN))
+  return out
+}
+
+// ── Account data (discriminator + length-prefixed message) ──────────
+
+export const ACCOUNT_DISCRIMINATOR_LEN = 8
+export const ACCOUNT_HEADER_LEN = ACCOUNT_DISCRIMINATOR_LEN + 4
+
+export function encodeAccountData<T>(discriminator: Uint8Array, codec: MessageCodec<T>, msg: T): Uint8Array {
+  const payload = codec.encode(msg)
+  const len = new Uint8Array(4)
+  new DataView(len.buffer).setUint32(0, payload.length, true)
+  return concat(discriminator, len, payload)
+}
+
+/**
+ * Decode account data written by `encodeAccountData`, checking the
+ * discriminator; bytes past the recorded payload length are ignored.
+ */
+export function decodeAccountData<T>(discriminator: Uint8Array, codec: MessageCodec<T>, data: Uint8Array): T {
+  if (data.length < ACCOUNT_DISCRIMINATOR_LEN) throw decodeError("BufferOverflow", "buffer overflow")
+  if (!startsWith(data, discriminator)) throw decodeError("InvalidData", "account discriminator mismatch")
+  if (data.length < ACCOUNT_HEADER_LEN) throw decodeError("BufferOverflow", "buffer overflow")
+  const len = new DataView(data.buffer, data.byteOffset + ACCOUNT_DISCRIMINATOR_LEN, 4).getUint32(0, true)
+  if (len > data.length - ACCOUNT_HEADER_LEN) throw decodeError("BufferOverflow", "buffer overflow")
+  return decode(codec, data.subarray(ACCOUNT_HEADER_LEN, ACCOUNT_HEADER_LEN + len), MAX_ACCOUNT_LEN)
+}
+
+// ── Type discriminators (`(solana.discriminator)` messages) ──────────
+
+export function encodeWithDiscriminator<T>(discriminator: Uint8Array, codec: MessageCodec<T>, msg: T): Uint8Array {
+  return concat(discriminator, codec.encode(msg))
+}
+
+export function decodeWithDiscriminator<T>(discriminator: Uint8Array, codec: MessageCodec<T>, data: Uint8Array): T {
+  if (data.length < discriminator.length) throw decodeError("BufferOverflow", "buffer overflow")
+  if (!startsWith(data, discriminator)) throw decodeError("InvalidData", "discriminator does not match the message")
+  return decode(codec, data.subarray(discriminator.length), MAX_ACCOUNT_LEN)
+}
+
+// ── Versioned data (`(solana.version)` messages) ─────────────────────
+
+export function encodeVersioned<T>(version: number, codec: MessageCodec<T>, msg: T): Uint8Array {
+  return concat(Uint8Array.of(version), codec.encode(msg))
+}
+
+export function decodeVersioned<T>(
+  version: number,
+  codec: MessageCodec<T>,
+  data: Uint8Array,
+  migrations: Record<number, (payload: Uint8Array) => T> = {}
+): T {
+  if (data.length === 0) throw decodeError("BufferOverflow", "buffer overflow")
+  const payload = data.subarray(1)
+  if (data[0] === version) return decode(codec, payload, MAX_ACCOUNT_LEN)
+  const migrate = data[0] < version ? migrations[data[0]] : undefined
+  if (!migrate) throw decodeError("UnknownVersion", `no migration from version ${data[0]}`)
+  return migrate(payload)
+}
+
+// ── Events (`(solana.event)` messages) ──────────────────────────────
+
+export const EVENT_DISCRIMINATOR_LEN = 8
+/** How the validator prefixes `sol_log_data` output in transaction logs. */
+export const PROGRAM_DATA_LOG_PREFIX = "Program data: "
+
+/**
+ * Decode the event in a `Program data:` log line. `undefined` if the line
+ * is some other log or its data starts with another discriminator; data
+ * logged in several chunks is joined first.
+ */
+export function decodeEventLog<T>(discriminator: Uint8Array, codec: MessageCodec<T>, line: string): T | undefined {
+  if (!line.startsWith(PROGRAM_DATA_LOG_PREFIX)) return undefined
+  const chunks = line.slice(PROGRAM_DATA_LOG_PREFIX.length).split(/\s+/).filter(c => c !== "")
+  const data = concat(...chunks.map(base64Decode))
+  if (!startsWith(data, discriminator)) return undefined
+  return codec.decode(data.subarray(discriminator.length))
+}
+
+// ── Well-known types (google/protobuf/*.proto) ───────────────────────
+//
+// Counterparts of the runtime's hand-written google.protobuf messages.
+// Singular wrapper fields are generated as optional scalars and use the
+// `*Value` codecs for their wire form.
+
+/** `google.protobuf.Timestamp` / `Duration` body: `seconds` = 1, `nanos` = 2. */
+function secondsNanosCodec(): MessageCodec<{ seconds: bigint; nanos: number }> {
+  const codec: MessageCodec<{ seconds: bigint; nanos: number }> = {
+    create: (init = {}) => ({ seconds: 0n, nanos: 0, ...init }),
+    encode: msg => encode(codec, msg),
+    decode: data => decode(codec, data),
+    write(w, msg) {
+      if (msg.seconds !== 0n) {
+        writeKey(w, 0x08)
+        writeInt64(w, msg.seconds)
+      }
+      if (msg.nanos !== 0) {
+        writeKey(w, 0x10)
+        writeInt32(w, msg.nanos)
+      }
+    },
+    merge(msg, data) {
+      const r = reader(data)
+      while (!done(r)) {
+        const tag = readKey(r)
+        if (tag === 0x08) msg.seconds = readInt64(r)
+        else if (tag === 0x10) msg.nanos = readInt32(r)
+        else skipField(r, tag)
+      }
+    }
+  }
+  return codec
+}
+
+export interface Timestamp {
+  seconds: bigint
+  nanos: number
+}
+export const Timestamp: MessageCodec<Timestamp> = secondsNanosCodec()
+
+export interface Duration {
+  seconds: bigint
+  nanos: number
+}
+export const Duration: MessageCodec<Duration> = secondsNanosCodec()
+
+export type Empty = Record<string, never>
+export const Empty: MessageCodec<Empty> = {
+  create: () => ({}),
+  encode: () => new Uint8Array(0),
+  decode: data => decode(Empty, data),
+  write() {},
+  merge(_msg, data) {
+    const r = reader(data)
+    while (!done(r)) skipField(r, readKey(r))
+  }
+}
+
+export interface Any {
+  typeUrl: string
+  value: Uint8Array
+}
+export const Any: MessageCodec<Any> = {
+  create: (init = {}) => ({ typeUrl: "", value: new Uint8Array(0), ...init }),
+  encode: msg => encode(Any, msg),
+  decode: data => decode(Any, data),
+  write(w, msg) {
+    if (msg.typeUrl.length > 0) {
+      writeKey(w, 0x0a)
+      writeString(w, msg.typeUrl)
+    }
+    if (msg.value.length > 0) {
+      writeKey(w, 0x12)
+      writeBytes(w, msg.value)
+    }
+  },
+  merge(msg, data) {
+    const r = reader(data)
+    while (!done(r)) {
+      const tag = readKey(r)
+      if (tag === 0x0a) msg.typeUrl = readString(r)
+      else if (tag === 0x12) msg.value = readBytes(r)
+      else skipField(r, tag)
+    }
+  }
+}
+
+/**
+ * A `google.protobuf.*Value` wrapper: a message whose `value` field 1 is
+ * left out at its default. `mergeValue` merges a wrapper body into an
+ * optional scalar field.
+ */
+export interface WrapperCodec<T> extends MessageCodec<{ value: T }> {
+  mergeValue(value: T | undefined, data: Uint8Array): T
+}
+
+function wrapperCodec<T>(
+  tag: number,
+  zero: T,
+  isSet: (value: T) => boolean,
+  write: (w: Writer, value: T) => void,
+  read: (r: Reader) => T
+): WrapperCodec<T> {
+  const codec: WrapperCodec<T> = {
+    create: (init = {}) => ({ value: zero, ...init }),
+    encode: msg => encode(codec, msg),
+    decode: data => decode(codec, data),
+    write(w, msg) {
+      if (isSet(msg.value)) {
+        writeKey(w, tag)
+        write(w, msg.value)
+      }
+    },
+    merge(msg, data) {
+      msg.value = codec.mergeValue(msg.value, data)
+    },
+    mergeValue(value, data) {
+      let merged = value ?? zero
+      const r = reader(data)
+      while (!done(r)) {
+        const key = readKey(r)
+        if (key === tag) merged = read(r)
+        else skipField(r, key)
+      }
+      return merged
+    }
+  }
+  return codec
+}
+
+export const DoubleValue = wrapperCodec(0x09, 0, v => !Object.is(v, 0), writeDouble, readDouble)
+export const FloatValue = wrapperCodec(0x0d, 0, v => !Object.is(Math.fround(v), 0), writeFloat, readFloat)
+export const Int64Value = wrapperCodec(0x08, 0n, v => v !== 0n, writeInt64, readInt64)
+export const UInt64Value = wrapperCodec(0x08, 0n, v => v !== 0n, writeUint64, readUint64)
+export const Int32Value = wrapperCodec(0x08, 0, v => v !== 0, writeInt32, readInt32)
+export const UInt32Value = wrapperCodec(0x08, 0, v => v !== 0, writeUint32, readUint32)
+export const BoolValue = wrapperCodec(0x08, false, v => v, writeBool, readBool)
+export const StringValue = wrapperCodec(0x0a, "", v => v.length > 0, writeString, readString)
+export const BytesValue = wrapperCodec(0x0a, new Uint8Array(0), v => v.length > 0, writeBytes, readBytes)
+
+export type DoubleValue = { value: number }
+export type FloatValue = { value: number }
+export type Int64Value = { value: bigint }
+export type UInt64Value = { value: bigint }
+export type Int32Value = { value: number }
+export type UInt32Value = { value: number }
+export type BoolValue = { value: boolean }
+export type StringValue = { value: string }
+export type BytesValue = { value: Uint8Array }
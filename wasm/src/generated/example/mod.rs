@@ -0,0 +1,5 @@
+// Auto-generated by protoc-gen-solana
+// DO NOT EDIT
+
+pub mod example;
+pub mod service;
@@ -0,0 +1,24 @@
+//! Build check that the runtime and generated code compile for
+//! `wasm32-unknown-unknown`, as a browser module exporting the codecs of
+//! the `wasm` option:
+//!
+//! ```bash
+//! cargo build --target wasm32-unknown-unknown --manifest-path wasm/Cargo.toml
+//! ```
+//!
+//! Nothing outside the `solana-program` feature may call into the
+//! validator, so the build leaves that feature off, as a dApp's would.
+//!
+//! `generated/` is checked in so the check builds without protoc. It is
+//! generated from `tests/protos/example.proto` and
+//! `tests/protos/service.proto` with `wasm` and `module_tree`; after a
+//! generator change, refresh it with `pnpm generate:wasm`.
+
+// `module_tree` names the module of `example.proto` after its package
+#![allow(clippy::module_inception)]
+
+#[path = "../../rs/protobuf_runtime.rs"]
+pub mod protobuf_runtime;
+
+#[path = "generated/example/mod.rs"]
+pub mod example;